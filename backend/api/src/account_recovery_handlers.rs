@@ -0,0 +1,1005 @@
+//! Account recovery
+//!
+//!   POST /api/recovery                  – ask to move an account whose key was lost to a new key
+//!   GET  /api/recovery/:id              – a recovery, its approvals and when it can run
+//!   POST /api/recovery/:id/approve      – approve or reject a recovery
+//!   POST /api/recovery/:id/cancel       – cancel a recovery, as the account's current holder
+//!   POST /api/recovery/:id/execute      – carry out an approved recovery after its timelock
+//!
+//! A publisher who loses their account key cannot sign in to revoke it. They
+//! open a recovery instead, naming a new key and proving they hold it, and
+//! collect approvals the way a multisig proposal does: from their other
+//! registered signing keys, each signing [`approval_message`], or from admins
+//! of an organization they belong to, signed in as themselves.
+//! [`RECOVERY_THRESHOLD`] approvals are needed, or one from each approver
+//! when there are fewer; a single rejection ends the recovery.
+//!
+//! An approved recovery waits [`RECOVERY_TIMELOCK_HOURS`], and whoever still
+//! holds the old key can cancel it meanwhile, so a recovery opened against
+//! an owner who has not lost their key goes nowhere. Executing it moves the
+//! publisher to the new account, registers the new key and revokes the old
+//! one from `compromised_since`, with what it signed since (see
+//! [`revoke_with_dependents`]).
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shared::{KeyValidity, SigningKey, TransparencyEntryType};
+use sqlx::PgConnection;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    mailer,
+    multisig_handlers::ApprovalDecision,
+    publisher_key_handlers::{
+        account_public_key, decode_public_key, fingerprint, key_error, refresh_key_windows,
+        revoke_with_dependents, verify_proof, RevokedKey,
+    },
+    signing_handlers::append_transparency_log_entry,
+    state::AppState,
+    tenant::Tenant,
+};
+
+/// Approvals a recovery needs when the publisher has at least this many
+/// approvers.
+pub const RECOVERY_THRESHOLD: usize = 2;
+/// Delay between approval and the earliest execution.
+pub const RECOVERY_TIMELOCK_HOURS: i64 = 72;
+/// How long a recovery can wait for approvals.
+const RECOVERY_EXPIRY_DAYS: i64 = 7;
+const MAX_REASON_CHARS: usize = 1000;
+
+const RECOVERY_COLUMNS: &str =
+    "id, publisher_id, lost_address, new_address, new_public_key, reason, compromised_since,
+     status, required_approvals, expires_at, approved_at, executed_at, rejected_at,
+     rejection_reason, cancelled_at, created_at, updated_at";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct OpenRecoveryRequest {
+    /// Stellar address of the account whose key was lost
+    pub publisher_address: String,
+    /// Base64 Ed25519 public key of the account to move to
+    pub new_public_key: String,
+    /// The new key's base64 signature over
+    /// `soroban-registry:recover-account:<publisher_address>:<new_public_key>`
+    pub proof: String,
+    pub reason: String,
+    /// When the old key may have fallen into other hands; what it signed
+    /// since is revoked with it. Defaults to the execution time
+    pub compromised_since: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RecoveryDecisionRequest {
+    /// Defaults to approved
+    pub decision: Option<ApprovalDecision>,
+    pub comment: Option<String>,
+    /// Base64 public key of the approving signing key; org admins sign in
+    /// instead
+    pub public_key: Option<String>,
+    /// The key's base64 signature over
+    /// `soroban-registry:recovery-decision:<id>:<decision>`
+    pub signature: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct AccountRecovery {
+    pub id: Uuid,
+    pub publisher_id: Uuid,
+    pub lost_address: String,
+    pub new_address: String,
+    pub new_public_key: String,
+    pub reason: String,
+    pub compromised_since: Option<DateTime<Utc>>,
+    /// `pending`, `approved`, `executed`, `rejected`, `cancelled` or `expired`
+    pub status: String,
+    pub required_approvals: i32,
+    pub expires_at: DateTime<Utc>,
+    pub approved_at: Option<DateTime<Utc>>,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub rejected_at: Option<DateTime<Utc>>,
+    pub rejection_reason: Option<String>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct RecoveryApproval {
+    /// Fingerprint of the approving key, or the org admin's address
+    pub approver: String,
+    /// `key` or `org_admin`
+    pub approver_kind: String,
+    pub decision: String,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RecoveryInfo {
+    pub recovery: AccountRecovery,
+    pub approvals: Vec<RecoveryApproval>,
+    pub approvals_needed: i64,
+    /// When an approved recovery clears its timelock
+    pub executable_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RecoveryDecisionResponse {
+    pub approvals_collected: i64,
+    pub approvals_needed: i64,
+    pub status: String,
+    pub executable_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RecoveryExecuted {
+    pub recovery: AccountRecovery,
+    /// The new account's key, now registered
+    pub new_key: SigningKey,
+    /// The old account key; `None` when it was already revoked
+    pub revoked: Option<RevokedKey>,
+}
+
+#[derive(sqlx::FromRow)]
+struct Account {
+    id: Uuid,
+    stellar_address: String,
+    email: Option<String>,
+    email_verified_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+// ── Approvers ─────────────────────────────────────────────────────────────────
+
+/// The message the new key signs to open a recovery.
+pub fn recovery_message(publisher_address: &str, new_public_key: &str) -> String {
+    format!(
+        "soroban-registry:recover-account:{}:{}",
+        publisher_address, new_public_key
+    )
+}
+
+/// The message an approving key signs.
+pub fn approval_message(recovery_id: Uuid, decision: ApprovalDecision) -> String {
+    format!(
+        "soroban-registry:recovery-decision:{}:{}",
+        recovery_id,
+        decision.as_str()
+    )
+}
+
+/// The publisher's keys that can approve a recovery at `at`: those valid
+/// then, other than the lost account key and the key the recovery moves to.
+fn approver_keys<'a>(
+    keys: &'a [SigningKey],
+    lost_key: Option<&str>,
+    new_key: &str,
+    at: DateTime<Utc>,
+) -> Vec<&'a SigningKey> {
+    keys.iter()
+        .filter(|key| key.window().validity_at(at) == KeyValidity::Valid)
+        .filter(|key| Some(key.public_key.as_str()) != lost_key && key.public_key != new_key)
+        .collect()
+}
+
+fn required_approvals(approvers: usize) -> usize {
+    approvers.min(RECOVERY_THRESHOLD)
+}
+
+fn executable_at(recovery: &AccountRecovery) -> Option<DateTime<Utc>> {
+    recovery
+        .approved_at
+        .map(|at| at + Duration::hours(RECOVERY_TIMELOCK_HOURS))
+}
+
+async fn publisher_keys(conn: &mut PgConnection, publisher_id: Uuid) -> ApiResult<Vec<SigningKey>> {
+    sqlx::query_as("SELECT * FROM signing_keys WHERE publisher_id = $1")
+        .bind(publisher_id)
+        .fetch_all(conn)
+        .await
+        .map_err(|err| db_internal_error("fetch publisher signing keys", err))
+}
+
+/// Admins of the publisher's organizations, other than the publisher.
+async fn count_org_admins(conn: &mut PgConnection, publisher_id: Uuid) -> ApiResult<i64> {
+    sqlx::query_scalar(
+        "SELECT COUNT(DISTINCT admin.publisher_id)
+         FROM organization_members member
+         JOIN organization_members admin ON admin.organization_id = member.organization_id
+         WHERE member.publisher_id = $1 AND admin.role = 'admin' AND admin.publisher_id <> $1",
+    )
+    .bind(publisher_id)
+    .fetch_one(conn)
+    .await
+    .map_err(|err| db_internal_error("count organization admins", err))
+}
+
+async fn is_org_admin(
+    conn: &mut PgConnection,
+    publisher_id: Uuid,
+    address: &str,
+) -> ApiResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(
+            SELECT 1
+            FROM organization_members member
+            JOIN organization_members admin ON admin.organization_id = member.organization_id
+            JOIN publishers p ON p.id = admin.publisher_id
+            WHERE member.publisher_id = $1 AND admin.role = 'admin'
+              AND p.stellar_address = $2 AND p.id <> $1
+         )",
+    )
+    .bind(publisher_id)
+    .bind(address)
+    .fetch_one(conn)
+    .await
+    .map_err(|err| db_internal_error("check organization admin", err))
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+fn parse_recovery_id(id: &str) -> ApiResult<Uuid> {
+    Uuid::parse_str(id)
+        .map_err(|_| ApiError::bad_request("InvalidRecoveryId", "recovery id must be a valid UUID"))
+}
+
+async fn load_recovery(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    id: Uuid,
+) -> ApiResult<AccountRecovery> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM account_recoveries WHERE id = $1 AND tenant_id = $2 FOR UPDATE",
+        RECOVERY_COLUMNS
+    ))
+    .bind(id)
+    .bind(tenant_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(|err| db_internal_error("load account recovery", err))?
+    .ok_or_else(|| ApiError::not_found("RecoveryNotFound", "account recovery not found"))
+}
+
+async fn count_approvals(conn: &mut PgConnection, recovery_id: Uuid) -> ApiResult<i64> {
+    sqlx::query_scalar(
+        "SELECT COUNT(*) FROM account_recovery_approvals
+         WHERE recovery_id = $1 AND decision = 'approved'",
+    )
+    .bind(recovery_id)
+    .fetch_one(conn)
+    .await
+    .map_err(|err| db_internal_error("count recovery approvals", err))
+}
+
+fn invalid_state(recovery: &AccountRecovery, action: &str) -> ApiError {
+    ApiError::conflict(
+        "InvalidRecoveryState",
+        format!("recovery cannot be {} while {}", action, recovery.status),
+    )
+}
+
+/// Tells the account's verified email that a recovery was opened, so an
+/// owner who still holds the key can cancel it. Failures are logged.
+async fn notify_owner(account: &Account, recovery: &AccountRecovery) {
+    let (Some(email), Some(_)) = (&account.email, account.email_verified_at) else {
+        return;
+    };
+    if !mailer::is_configured() {
+        return;
+    }
+    let cancel = match mailer::public_url() {
+        Some(base) => format!("POST {}/api/recovery/{}/cancel", base, recovery.id),
+        None => format!("soroban-registry recovery cancel {}", recovery.id),
+    };
+    let text = format!(
+        "A recovery of your Soroban Registry account {} was requested, moving it to {}.\n\n\
+         Reason given: {}\n\n\
+         If you still hold your account key and did not ask for this, sign in and cancel it \
+         ({}). Once approved it can run after {} hours.",
+        recovery.lost_address,
+        recovery.new_address,
+        recovery.reason,
+        cancel,
+        RECOVERY_TIMELOCK_HOURS
+    );
+    let sent = mailer::send(&mailer::Email {
+        to: email,
+        subject: "Recovery requested for your Soroban Registry account",
+        text: &text,
+    })
+    .await;
+    if let Err(err) = sent {
+        tracing::warn!(recovery = %recovery.id, error = %err, "failed to email recovery notice");
+    }
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/recovery",
+    request_body = OpenRecoveryRequest,
+    responses(
+        (status = 201, description = "Recovery opened and awaiting approvals", body = AccountRecovery),
+        (status = 400, description = "Malformed key, proof or reason"),
+        (status = 404, description = "No publisher with this address"),
+        (status = 409, description = "New account already in use, or a recovery is already open"),
+        (status = 422, description = "Proof is not the new key's signature, or nobody can approve")
+    ),
+    tag = "Publishers"
+)]
+pub async fn open_recovery(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Json(req): Json<OpenRecoveryRequest>,
+) -> ApiResult<(StatusCode, Json<AccountRecovery>)> {
+    let reason = req.reason.trim();
+    if reason.is_empty() || reason.chars().count() > MAX_REASON_CHARS {
+        return Err(ApiError::bad_request(
+            "InvalidRecovery",
+            format!(
+                "reason is required and at most {} characters",
+                MAX_REASON_CHARS
+            ),
+        ));
+    }
+    let now = Utc::now();
+    if req.compromised_since.is_some_and(|since| since > now) {
+        return Err(ApiError::bad_request(
+            "InvalidRecovery",
+            "compromised_since cannot be in the future",
+        ));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin account recovery", err))?;
+    let account: Account = sqlx::query_as(
+        "SELECT id, stellar_address, email, email_verified_at, created_at FROM publishers
+         WHERE stellar_address = $1 AND tenant_id = $2 AND deleted_at IS NULL",
+    )
+    .bind(req.publisher_address.trim())
+    .bind(tenant.id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("load publisher", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            format!("No publisher found with address: {}", req.publisher_address),
+        )
+    })?;
+
+    let (new_key, new_public_key) = decode_public_key(&req.new_public_key)?;
+    verify_proof(
+        &new_key,
+        &recovery_message(&account.stellar_address, &new_public_key),
+        &req.proof,
+    )?;
+    let new_address = format!("{}", stellar_strkey::ed25519::PublicKey(new_key.to_bytes()));
+    if new_address == account.stellar_address {
+        return Err(ApiError::bad_request(
+            "InvalidRecovery",
+            "The new key is the account's current key",
+        ));
+    }
+    let in_use: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM publishers WHERE stellar_address = $1)")
+            .bind(&new_address)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("check new account", err))?;
+    if in_use {
+        return Err(ApiError::conflict(
+            "AccountInUse",
+            format!("{} is already a publisher account", new_address),
+        ));
+    }
+
+    let registered: Option<SigningKey> =
+        sqlx::query_as("SELECT * FROM signing_keys WHERE public_key = $1")
+            .bind(&new_public_key)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("look up new key", err))?;
+    if let Some(key) = registered {
+        if key.publisher_id != account.id {
+            return Err(ApiError::conflict(
+                "SigningKeyExists",
+                "The new key is registered to another publisher",
+            ));
+        }
+        if let Some(err) = key_error(key.window().validity_at(now), &key) {
+            return Err(err);
+        }
+    }
+
+    let keys = publisher_keys(&mut tx, account.id).await?;
+    let lost_key = account_public_key(&account.stellar_address);
+    let approvers = approver_keys(&keys, lost_key.as_deref(), &new_public_key, now).len()
+        + count_org_admins(&mut tx, account.id).await? as usize;
+    if approvers == 0 {
+        return Err(ApiError::unprocessable(
+            "NoRecoveryApprovers",
+            "Nobody can approve a recovery of this account: it has no other valid signing \
+             keys and no organization admins",
+        ));
+    }
+
+    let recovery: AccountRecovery = sqlx::query_as(&format!(
+        "INSERT INTO account_recoveries (
+            tenant_id, publisher_id, lost_address, new_address, new_public_key, reason,
+            compromised_since, required_approvals, expires_at
+         )
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING {}",
+        RECOVERY_COLUMNS
+    ))
+    .bind(tenant.id)
+    .bind(account.id)
+    .bind(&account.stellar_address)
+    .bind(&new_address)
+    .bind(&new_public_key)
+    .bind(reason)
+    .bind(req.compromised_since)
+    .bind(required_approvals(approvers) as i32)
+    .bind(now + Duration::days(RECOVERY_EXPIRY_DAYS))
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "RecoveryInProgress",
+            "A recovery of this account is already open",
+        ),
+        _ => db_internal_error("open account recovery", err),
+    })?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit account recovery", err))?;
+
+    notify_owner(&account, &recovery).await;
+    tracing::info!(
+        recovery = %recovery.id,
+        publisher = %account.id,
+        required_approvals = recovery.required_approvals,
+        "account recovery opened"
+    );
+    Ok((StatusCode::CREATED, Json(recovery)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/recovery/{id}",
+    params(("id" = String, Path, description = "Recovery UUID")),
+    responses(
+        (status = 200, description = "The recovery and its approvals", body = RecoveryInfo),
+        (status = 400, description = "Invalid recovery ID"),
+        (status = 404, description = "Recovery not found")
+    ),
+    tag = "Publishers"
+)]
+pub async fn get_recovery(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<RecoveryInfo>> {
+    let id = parse_recovery_id(&id)?;
+    let recovery: AccountRecovery = sqlx::query_as(&format!(
+        "SELECT {} FROM account_recoveries WHERE id = $1 AND tenant_id = $2",
+        RECOVERY_COLUMNS
+    ))
+    .bind(id)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load account recovery", err))?
+    .ok_or_else(|| ApiError::not_found("RecoveryNotFound", "account recovery not found"))?;
+    let approvals: Vec<RecoveryApproval> = sqlx::query_as(
+        "SELECT approver, approver_kind, decision::TEXT AS decision, comment, created_at
+         FROM account_recovery_approvals
+         WHERE recovery_id = $1
+         ORDER BY created_at",
+    )
+    .bind(id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list recovery approvals", err))?;
+
+    let approved = approvals
+        .iter()
+        .filter(|approval| approval.decision == "approved")
+        .count() as i64;
+    Ok(Json(RecoveryInfo {
+        approvals_needed: (i64::from(recovery.required_approvals) - approved).max(0),
+        executable_at: executable_at(&recovery),
+        recovery,
+        approvals,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recovery/{id}/approve",
+    params(("id" = String, Path, description = "Recovery UUID")),
+    request_body = RecoveryDecisionRequest,
+    responses(
+        (status = 200, description = "Decision recorded", body = RecoveryDecisionResponse),
+        (status = 400, description = "Key without signature, or signature without key"),
+        (status = 401, description = "Neither a key signature nor a bearer token"),
+        (status = 403, description = "Not one of the recovery's approvers"),
+        (status = 409, description = "Already decided, or the recovery is no longer pending"),
+        (status = 422, description = "Signature does not verify")
+    ),
+    tag = "Publishers"
+)]
+pub async fn decide_recovery(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+    Json(req): Json<RecoveryDecisionRequest>,
+) -> ApiResult<Json<RecoveryDecisionResponse>> {
+    let id = parse_recovery_id(&id)?;
+    let decision = req.decision.unwrap_or(ApprovalDecision::Approved);
+    let now = Utc::now();
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin recovery decision", err))?;
+    let recovery = load_recovery(&mut tx, tenant.id, id).await?;
+    if recovery.status != "pending" {
+        return Err(invalid_state(&recovery, "decided"));
+    }
+    if recovery.expires_at <= now {
+        sqlx::query(
+            "UPDATE account_recoveries SET status = 'expired', updated_at = NOW() WHERE id = $1",
+        )
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("expire account recovery", err))?;
+        tx.commit()
+            .await
+            .map_err(|err| db_internal_error("commit recovery expiry", err))?;
+        return Err(ApiError::conflict(
+            "RecoveryExpired",
+            "the recovery expired before collecting its approvals",
+        ));
+    }
+
+    let (approver, approver_kind, signature) = match (&req.public_key, &req.signature, &claims) {
+        (Some(public_key), Some(signature), _) => {
+            let (key, public_key) = decode_public_key(public_key)?;
+            let keys = publisher_keys(&mut tx, recovery.publisher_id).await?;
+            let lost_key = account_public_key(&recovery.lost_address);
+            let approver = approver_keys(&keys, lost_key.as_deref(), &recovery.new_public_key, now)
+                .into_iter()
+                .find(|approver| approver.public_key == public_key)
+                .ok_or_else(|| {
+                    ApiError::forbidden(
+                        "This key is not one of the publisher's other valid signing keys",
+                    )
+                })?;
+            verify_proof(&key, &approval_message(id, decision), signature)?;
+            (
+                approver.key_fingerprint.clone(),
+                "key",
+                Some(signature.trim().to_string()),
+            )
+        }
+        (Some(_), None, _) | (None, Some(_), _) => {
+            return Err(ApiError::bad_request(
+                "InvalidRecoveryDecision",
+                "public_key and signature must be given together",
+            ))
+        }
+        (None, None, Some(claims)) => {
+            if !is_org_admin(&mut tx, recovery.publisher_id, &claims.sub).await? {
+                return Err(ApiError::forbidden(
+                    "Only admins of an organization the publisher belongs to can approve as themselves",
+                ));
+            }
+            (claims.sub.clone(), "org_admin", None)
+        }
+        (None, None, None) => {
+            return Err(ApiError::unauthorized(
+                "Sign the decision with one of the publisher's keys, or sign in as an organization admin",
+            ))
+        }
+    };
+
+    let inserted: Option<Uuid> = sqlx::query_scalar(
+        "INSERT INTO account_recovery_approvals (
+            recovery_id, approver, approver_kind, signature, decision, comment
+         )
+         VALUES ($1, $2, $3, $4, $5::approval_decision_type, $6)
+         ON CONFLICT (recovery_id, approver) DO NOTHING
+         RETURNING id",
+    )
+    .bind(id)
+    .bind(&approver)
+    .bind(approver_kind)
+    .bind(&signature)
+    .bind(decision.as_str())
+    .bind(req.comment.as_deref())
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("record recovery decision", err))?;
+    if inserted.is_none() {
+        return Err(ApiError::conflict(
+            "AlreadyDecided",
+            "this approver already decided on the recovery",
+        ));
+    }
+
+    let approvals_collected = count_approvals(&mut tx, id).await?;
+    let recovery: AccountRecovery = if decision == ApprovalDecision::Rejected {
+        sqlx::query_as(&format!(
+            "UPDATE account_recoveries
+             SET status = 'rejected', rejected_at = NOW(), rejection_reason = $2, updated_at = NOW()
+             WHERE id = $1
+             RETURNING {}",
+            RECOVERY_COLUMNS
+        ))
+        .bind(id)
+        .bind(req.comment.as_deref())
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("reject account recovery", err))?
+    } else if approvals_collected >= i64::from(recovery.required_approvals) {
+        sqlx::query_as(&format!(
+            "UPDATE account_recoveries
+             SET status = 'approved', approved_at = NOW(), updated_at = NOW()
+             WHERE id = $1
+             RETURNING {}",
+            RECOVERY_COLUMNS
+        ))
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("approve account recovery", err))?
+    } else {
+        recovery
+    };
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit recovery decision", err))?;
+
+    tracing::info!(
+        recovery = %id,
+        approver = %approver,
+        decision = decision.as_str(),
+        status = %recovery.status,
+        "account recovery decision"
+    );
+    Ok(Json(RecoveryDecisionResponse {
+        approvals_collected,
+        approvals_needed: (i64::from(recovery.required_approvals) - approvals_collected).max(0),
+        executable_at: executable_at(&recovery),
+        status: recovery.status,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recovery/{id}/cancel",
+    params(("id" = String, Path, description = "Recovery UUID")),
+    responses(
+        (status = 200, description = "Recovery cancelled", body = AccountRecovery),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 403, description = "Not signed in as the account being recovered"),
+        (status = 409, description = "Recovery already finished")
+    ),
+    tag = "Publishers"
+)]
+pub async fn cancel_recovery(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<Json<AccountRecovery>> {
+    let id = parse_recovery_id(&id)?;
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin recovery cancellation", err))?;
+    let recovery = load_recovery(&mut tx, tenant.id, id).await?;
+    if claims.sub != recovery.lost_address {
+        return Err(ApiError::forbidden(
+            "Only the holder of the account's current key can cancel its recovery",
+        ));
+    }
+    if !matches!(recovery.status.as_str(), "pending" | "approved") {
+        return Err(invalid_state(&recovery, "cancelled"));
+    }
+    let recovery: AccountRecovery = sqlx::query_as(&format!(
+        "UPDATE account_recoveries
+         SET status = 'cancelled', cancelled_at = NOW(), updated_at = NOW()
+         WHERE id = $1
+         RETURNING {}",
+        RECOVERY_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("cancel account recovery", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit recovery cancellation", err))?;
+
+    tracing::info!(recovery = %id, "account recovery cancelled by the account holder");
+    Ok(Json(recovery))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/recovery/{id}/execute",
+    params(("id" = String, Path, description = "Recovery UUID")),
+    responses(
+        (status = 200, description = "Account moved to the new key", body = RecoveryExecuted),
+        (status = 404, description = "Recovery not found"),
+        (status = 409, description = "Not approved, timelock running, or the account changed since")
+    ),
+    tag = "Publishers"
+)]
+pub async fn execute_recovery(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<RecoveryExecuted>> {
+    let id = parse_recovery_id(&id)?;
+    let now = Utc::now();
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin recovery execution", err))?;
+    let recovery = load_recovery(&mut tx, tenant.id, id).await?;
+    if recovery.status != "approved" {
+        return Err(ApiError::conflict(
+            "RecoveryNotApproved",
+            format!(
+                "recovery must be approved before execution; it is {}",
+                recovery.status
+            ),
+        ));
+    }
+    if let Some(at) = executable_at(&recovery).filter(|at| *at > now) {
+        return Err(ApiError::conflict(
+            "TimelockActive",
+            format!("the recovery can be executed from {}", at.to_rfc3339()),
+        ));
+    }
+
+    let account: Account = sqlx::query_as(
+        "SELECT id, stellar_address, email, email_verified_at, created_at FROM publishers
+         WHERE id = $1
+         FOR UPDATE",
+    )
+    .bind(recovery.publisher_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("load recovered publisher", err))?;
+    if account.stellar_address != recovery.lost_address {
+        return Err(ApiError::conflict(
+            "RecoveryStale",
+            "the publisher's account changed since the recovery was opened",
+        ));
+    }
+    sqlx::query("UPDATE publishers SET stellar_address = $2 WHERE id = $1")
+        .bind(account.id)
+        .bind(&recovery.new_address)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+                ApiError::conflict(
+                    "AccountInUse",
+                    format!("{} is already a publisher account", recovery.new_address),
+                )
+            }
+            _ => db_internal_error("move publisher account", err),
+        })?;
+
+    // The lost key is recorded as a registered key so its revocation reaches
+    // logins and attestations too.
+    let (lost_key, lost_public_key) = account_public_key(&recovery.lost_address)
+        .ok_or_else(|| ApiError::internal("Recovered account has no Ed25519 key"))
+        .and_then(|key| decode_public_key(&key))?;
+    let existing: Option<SigningKey> =
+        sqlx::query_as("SELECT * FROM signing_keys WHERE public_key = $1")
+            .bind(&lost_public_key)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("fetch lost account key", err))?;
+    let old = match existing {
+        Some(key) => key,
+        None => sqlx::query_as(
+            "INSERT INTO signing_keys (publisher_id, public_key, key_fingerprint, label, valid_from)
+             VALUES ($1, $2, $3, 'account key', $4)
+             RETURNING *",
+        )
+        .bind(account.id)
+        .bind(&lost_public_key)
+        .bind(fingerprint(&lost_key))
+        .bind(account.created_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("record lost account key", err))?,
+    };
+    let revoked = if old.revoked_at.is_none() {
+        let revoked_at = recovery
+            .compromised_since
+            .unwrap_or(now)
+            .max(old.valid_from);
+        let (key, signatures_revoked, badges_revoked) = revoke_with_dependents(
+            &mut tx,
+            account.id,
+            &recovery.lost_address,
+            &old,
+            revoked_at,
+            &format!("account recovered: {}", recovery.reason),
+            &recovery.new_address,
+        )
+        .await?;
+        Some(RevokedKey {
+            key,
+            signatures_revoked,
+            badges_revoked,
+        })
+    } else {
+        None
+    };
+
+    let (new_key, new_public_key) = decode_public_key(&recovery.new_public_key)?;
+    let inserted: Option<SigningKey> = sqlx::query_as(
+        "INSERT INTO signing_keys (publisher_id, public_key, key_fingerprint, label)
+         VALUES ($1, $2, $3, 'account key')
+         ON CONFLICT (key_fingerprint) DO NOTHING
+         RETURNING *",
+    )
+    .bind(account.id)
+    .bind(&new_public_key)
+    .bind(fingerprint(&new_key))
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("register recovered account key", err))?;
+    let new_key = match inserted {
+        Some(key) => key,
+        None => {
+            let key: SigningKey =
+                sqlx::query_as("SELECT * FROM signing_keys WHERE public_key = $1")
+                    .bind(&new_public_key)
+                    .fetch_one(&mut *tx)
+                    .await
+                    .map_err(|err| db_internal_error("fetch recovered account key", err))?;
+            if key.publisher_id != account.id {
+                return Err(ApiError::conflict(
+                    "SigningKeyExists",
+                    "The new key is registered to another publisher",
+                ));
+            }
+            if let Some(err) = key_error(key.window().validity_at(now), &key) {
+                return Err(err);
+            }
+            key
+        }
+    };
+
+    let recovery: AccountRecovery = sqlx::query_as(&format!(
+        "UPDATE account_recoveries
+         SET status = 'executed', executed_at = NOW(), updated_at = NOW()
+         WHERE id = $1
+         RETURNING {}",
+        RECOVERY_COLUMNS
+    ))
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("mark account recovery executed", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit recovery execution", err))?;
+
+    append_transparency_log_entry(
+        &state,
+        TransparencyEntryType::AccountRecovered,
+        None,
+        None,
+        &recovery.new_address,
+        Some(json!({
+            "recovery_id": recovery.id,
+            "lost_address": recovery.lost_address,
+            "new_address": recovery.new_address,
+            "old_fingerprint": old.key_fingerprint,
+            "new_fingerprint": new_key.key_fingerprint,
+            "signatures_revoked": revoked.as_ref().map_or(0, |r| r.signatures_revoked),
+            "badges_revoked": revoked.as_ref().map_or(0, |r| r.badges_revoked),
+        })),
+    )
+    .await?;
+    refresh_key_windows(&state).await;
+
+    tracing::info!(
+        recovery = %id,
+        publisher = %account.id,
+        new_address = %recovery.new_address,
+        "account recovered"
+    );
+    Ok(Json(RecoveryExecuted {
+        recovery,
+        new_key,
+        revoked,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(public_key: &str, valid_until: Option<DateTime<Utc>>) -> SigningKey {
+        let now = Utc::now();
+        SigningKey {
+            id: Uuid::new_v4(),
+            publisher_id: Uuid::nil(),
+            public_key: public_key.to_string(),
+            key_fingerprint: public_key.to_string(),
+            algorithm: "ed25519".to_string(),
+            is_active: true,
+            created_at: now - Duration::days(30),
+            deactivated_at: None,
+            label: None,
+            valid_from: now - Duration::days(30),
+            valid_until,
+            revoked_at: None,
+            revocation_reason: None,
+            replaced_by: None,
+        }
+    }
+
+    #[test]
+    fn only_other_valid_keys_approve() {
+        let now = Utc::now();
+        let keys = vec![
+            key("lost", None),
+            key("new", None),
+            key("backup", None),
+            key("rotated", Some(now - Duration::days(1))),
+        ];
+        let approvers = approver_keys(&keys, Some("lost"), "new", now);
+        assert_eq!(approvers.len(), 1);
+        assert_eq!(approvers[0].public_key, "backup");
+
+        assert_eq!(required_approvals(1), 1);
+        assert_eq!(required_approvals(5), RECOVERY_THRESHOLD);
+    }
+
+    #[test]
+    fn approvals_are_bound_to_the_recovery_and_decision() {
+        let id = Uuid::new_v4();
+        assert_ne!(
+            approval_message(id, ApprovalDecision::Approved),
+            approval_message(id, ApprovalDecision::Rejected)
+        );
+        assert_ne!(
+            approval_message(id, ApprovalDecision::Approved),
+            approval_message(Uuid::new_v4(), ApprovalDecision::Approved)
+        );
+    }
+}
@@ -0,0 +1,466 @@
+//! Accounting reports for token contracts
+//!
+//!   GET /api/contracts/:id/reports/accounting?from=&to=&format=json|csv
+//!
+//! Aggregates a token contract's ingested `transfer`, `mint`, `burn` and
+//! `clawback` events over a date range into per-address inflow, outflow and
+//! fees, alongside each address's invocation count. Event data is read as the
+//! ingestion pipeline decodes it: an `amount` (a number, or a string for i128
+//! values) with `from` / `to` addresses and an optional `fee` charged to the
+//! sender. Amounts are summed exactly and reported as strings.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{NaiveDate, NaiveTime};
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::{Network, OrganizationRole};
+use uuid::Uuid;
+
+use crate::{
+    anomalies::TOKEN_TOPICS,
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    org_handlers::check_org_role,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+/// Longest range one report may cover.
+const MAX_REPORT_DAYS: i64 = 366;
+
+const CSV_HEADER: &str = "address,inflow,outflow,net,fees,transfers,invocations\n";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct AccountingReportQuery {
+    /// First day of the report (UTC)
+    pub from: NaiveDate,
+    /// Last day of the report, inclusive (UTC)
+    pub to: NaiveDate,
+    /// `json` (default) or `csv`
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct AddressFlow {
+    pub address: String,
+    pub inflow: String,
+    pub outflow: String,
+    /// Inflow minus outflow
+    pub net: String,
+    /// Fees paid on this address's outgoing transfers
+    pub fees: String,
+    pub transfers: u64,
+    pub invocations: i64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ReportTotals {
+    pub transferred: String,
+    pub minted: String,
+    /// Burned and clawed back
+    pub burned: String,
+    pub fees: String,
+    pub events: u64,
+    /// Token events whose amount could not be read
+    pub skipped_events: u64,
+    /// Events counted in the totals that name no address
+    pub unattributed_events: u64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct AccountingReport {
+    pub contract_id: String,
+    pub contract_name: String,
+    pub network: Network,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub totals: ReportTotals,
+    pub addresses: Vec<AddressFlow>,
+}
+
+// ── Aggregation ───────────────────────────────────────────────────────────────
+
+#[derive(Debug, Default)]
+struct Flow {
+    inflow: i128,
+    outflow: i128,
+    fees: i128,
+    transfers: u64,
+    invocations: i64,
+}
+
+#[derive(Debug, Default)]
+struct Ledger {
+    addresses: BTreeMap<String, Flow>,
+    transferred: i128,
+    minted: i128,
+    burned: i128,
+    fees: i128,
+    events: u64,
+    skipped: u64,
+    unattributed: u64,
+}
+
+/// Token amount carried by event data: a bare integer, an integer string, or
+/// an object's `amount`.
+fn token_amount(data: &Value) -> Option<i128> {
+    match data {
+        Value::Number(n) => n
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| n.as_u64().map(i128::from)),
+        Value::String(s) => s.trim().parse().ok(),
+        Value::Object(map) => match map.get("amount")? {
+            amount @ (Value::Number(_) | Value::String(_)) => token_amount(amount),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn address<'a>(data: &'a Value, field: &str) -> Option<&'a str> {
+    data.get(field)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+}
+
+impl Ledger {
+    fn record_event(&mut self, topic: &str, data: Option<&Value>) {
+        let Some((data, amount)) = data.and_then(|d| token_amount(d).map(|a| (d, a))) else {
+            self.skipped += 1;
+            return;
+        };
+        let amount = amount.abs();
+        let fee = data.get("fee").and_then(token_amount).unwrap_or(0).abs();
+        self.events += 1;
+        self.fees = self.fees.saturating_add(fee);
+
+        // Mints have no sender and burns no recipient, whatever else the
+        // event carries.
+        let (from, to) = match topic {
+            "mint" => {
+                self.minted = self.minted.saturating_add(amount);
+                (None, address(data, "to"))
+            }
+            "burn" | "clawback" => {
+                self.burned = self.burned.saturating_add(amount);
+                (address(data, "from"), None)
+            }
+            _ => {
+                self.transferred = self.transferred.saturating_add(amount);
+                (address(data, "from"), address(data, "to"))
+            }
+        };
+        if from.is_none() && to.is_none() {
+            self.unattributed += 1;
+        }
+        if let Some(from) = from {
+            let flow = self.addresses.entry(from.to_string()).or_default();
+            flow.outflow = flow.outflow.saturating_add(amount);
+            flow.fees = flow.fees.saturating_add(fee);
+            flow.transfers += 1;
+        }
+        if let Some(to) = to {
+            let flow = self.addresses.entry(to.to_string()).or_default();
+            flow.inflow = flow.inflow.saturating_add(amount);
+            if from != Some(to) {
+                flow.transfers += 1;
+            }
+        }
+    }
+
+    fn record_invocations(&mut self, address: String, count: i64) {
+        self.addresses.entry(address).or_default().invocations += count;
+    }
+
+    fn into_parts(self) -> (ReportTotals, Vec<AddressFlow>) {
+        let totals = ReportTotals {
+            transferred: self.transferred.to_string(),
+            minted: self.minted.to_string(),
+            burned: self.burned.to_string(),
+            fees: self.fees.to_string(),
+            events: self.events,
+            skipped_events: self.skipped,
+            unattributed_events: self.unattributed,
+        };
+        let addresses = self
+            .addresses
+            .into_iter()
+            .map(|(address, flow)| AddressFlow {
+                address,
+                inflow: flow.inflow.to_string(),
+                outflow: flow.outflow.to_string(),
+                net: flow.inflow.saturating_sub(flow.outflow).to_string(),
+                fees: flow.fees.to_string(),
+                transfers: flow.transfers,
+                invocations: flow.invocations,
+            })
+            .collect();
+        (totals, addresses)
+    }
+}
+
+pub(crate) fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn to_csv(addresses: &[AddressFlow]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    for row in addresses {
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{}\n",
+            csv_field(&row.address),
+            row.inflow,
+            row.outflow,
+            row.net,
+            row.fees,
+            row.transfers,
+            row.invocations
+        ));
+    }
+    csv
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[derive(sqlx::FromRow)]
+struct ReportContract {
+    contract_id: String,
+    name: String,
+    network: Network,
+    is_private: bool,
+    organization_id: Option<Uuid>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/reports/accounting",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        AccountingReportQuery
+    ),
+    responses(
+        (status = 200, description = "Per-address flows over the range, as JSON or CSV", body = AccountingReport),
+        (status = 400, description = "Invalid range or format"),
+        (status = 403, description = "Private contract"),
+        (status = 404, description = "Contract not found"),
+        (status = 422, description = "The contract has never emitted token events")
+    ),
+    tag = "Analytics"
+)]
+pub async fn get_accounting_report(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+    Query(query): Query<AccountingReportQuery>,
+) -> ApiResult<Response> {
+    let csv = match query.format.as_deref().unwrap_or("json") {
+        "json" => false,
+        "csv" => true,
+        _ => {
+            return Err(ApiError::bad_request(
+                "InvalidFormat",
+                "format must be 'json' or 'csv'",
+            ))
+        }
+    };
+    if query.to < query.from {
+        return Err(ApiError::bad_request(
+            "InvalidRange",
+            "to must not be before from",
+        ));
+    }
+    if (query.to - query.from).num_days() >= MAX_REPORT_DAYS {
+        return Err(ApiError::bad_request(
+            "InvalidRange",
+            format!("A report covers at most {} days", MAX_REPORT_DAYS),
+        ));
+    }
+
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let contract: ReportContract = sqlx::query_as(
+        "SELECT contract_id, name, network, visibility = 'private' AS is_private, organization_id
+         FROM contracts WHERE id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch report contract", err))?;
+    if contract.is_private {
+        let (Some(claims), Some(org_id)) = (claims.as_ref(), contract.organization_id) else {
+            return Err(ApiError::forbidden("Access denied to private contract"));
+        };
+        check_org_role(&state.db, org_id, &claims.sub, OrganizationRole::Viewer).await?;
+    }
+
+    let is_token: bool = sqlx::query_scalar(
+        "SELECT EXISTS(
+             SELECT 1 FROM contract_events
+             WHERE contract_id = $1 AND network = $2 AND topic = ANY($3)
+         )",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .bind(&TOKEN_TOPICS[..])
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check token events", err))?;
+    if !is_token {
+        return Err(ApiError::unprocessable(
+            "NotTokenContract",
+            "Accounting reports need a contract that emits token events",
+        ));
+    }
+
+    let start = query.from.and_time(NaiveTime::MIN).and_utc();
+    let end = (query.to + chrono::Duration::days(1))
+        .and_time(NaiveTime::MIN)
+        .and_utc();
+
+    let mut ledger = Ledger::default();
+    let mut events = sqlx::query_as::<_, (String, Option<Value>)>(
+        "SELECT topic, data FROM contract_events
+         WHERE contract_id = $1 AND network = $2 AND topic = ANY($3)
+           AND timestamp >= $4 AND timestamp < $5",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .bind(&TOKEN_TOPICS[..])
+    .bind(start)
+    .bind(end)
+    .fetch(&state.db);
+    while let Some((topic, data)) = events
+        .try_next()
+        .await
+        .map_err(|err| db_internal_error("stream token events", err))?
+    {
+        ledger.record_event(&topic, data.as_ref());
+    }
+    drop(events);
+
+    let invocations: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT user_address, SUM(interaction_count)::BIGINT
+         FROM contract_interactions
+         WHERE contract_id = $1 AND interaction_type = 'invoke' AND user_address IS NOT NULL
+           AND interaction_timestamp >= $2 AND interaction_timestamp < $3
+         GROUP BY user_address",
+    )
+    .bind(contract_uuid)
+    .bind(start)
+    .bind(end)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("count invocations", err))?;
+    for (address, count) in invocations {
+        ledger.record_invocations(address, count);
+    }
+
+    let (totals, addresses) = ledger.into_parts();
+    if csv {
+        let filename = format!(
+            "attachment; filename=\"{}-{}-{}.csv\"",
+            contract.contract_id, query.from, query.to
+        );
+        return Ok((
+            StatusCode::OK,
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (header::CONTENT_DISPOSITION, filename),
+            ],
+            to_csv(&addresses),
+        )
+            .into_response());
+    }
+
+    Ok(Json(AccountingReport {
+        contract_id: contract.contract_id,
+        contract_name: contract.name,
+        network: contract.network,
+        from: query.from,
+        to: query.to,
+        totals,
+        addresses,
+    })
+    .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn amounts_are_read_exactly() {
+        assert_eq!(token_amount(&json!(42)), Some(42));
+        assert_eq!(
+            token_amount(&json!({ "amount": "170141183460469231731687303715884105727" })),
+            Some(i128::MAX)
+        );
+        assert_eq!(token_amount(&json!({ "amount": "1.5" })), None);
+        assert_eq!(token_amount(&json!({ "from": "GA" })), None);
+    }
+
+    #[test]
+    fn transfers_move_value_between_addresses() {
+        let mut ledger = Ledger::default();
+        ledger.record_event(
+            "transfer",
+            Some(&json!({ "from": "GA", "to": "GB", "amount": "100", "fee": "2" })),
+        );
+        ledger.record_event("mint", Some(&json!({ "to": "GA", "amount": 50 })));
+        ledger.record_event("burn", Some(&json!({ "from": "GB", "amount": "30" })));
+        ledger.record_event("transfer", Some(&json!("7")));
+        ledger.record_event("transfer", None);
+        ledger.record_invocations("GC".into(), 3);
+
+        let (totals, addresses) = ledger.into_parts();
+        assert_eq!(totals.transferred, "107");
+        assert_eq!(totals.minted, "50");
+        assert_eq!(totals.burned, "30");
+        assert_eq!(totals.fees, "2");
+        assert_eq!(totals.events, 4);
+        assert_eq!(totals.skipped_events, 1);
+        assert_eq!(totals.unattributed_events, 1);
+
+        let by_address: BTreeMap<_, _> = addresses
+            .iter()
+            .map(|flow| (flow.address.as_str(), flow))
+            .collect();
+        assert_eq!(by_address["GA"].inflow, "50");
+        assert_eq!(by_address["GA"].outflow, "100");
+        assert_eq!(by_address["GA"].net, "-50");
+        assert_eq!(by_address["GA"].fees, "2");
+        assert_eq!(by_address["GB"].net, "70");
+        assert_eq!(by_address["GC"].invocations, 3);
+        assert_eq!(by_address["GC"].transfers, 0);
+    }
+
+    #[test]
+    fn csv_quotes_unusual_addresses() {
+        let mut ledger = Ledger::default();
+        ledger.record_event("mint", Some(&json!({ "to": "a,b", "amount": 1 })));
+        let (_, addresses) = ledger.into_parts();
+        assert_eq!(
+            to_csv(&addresses),
+            format!("{}\"a,b\",1,0,1,0,1,0\n", CSV_HEADER)
+        );
+    }
+}
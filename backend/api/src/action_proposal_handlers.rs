@@ -0,0 +1,721 @@
+//! Governance action proposals
+//!
+//!   POST /api/multisig/action-proposals          – propose a privileged registry action
+//!
+//! Besides deployments, a proposal can carry one of the registry's sensitive
+//! operations, held back until the policy's signers approve it:
+//!
+//! - `apply_patch`: record a security patch as applied to a contract; the
+//!   proposer must be its publisher or hold `apply_patches` on it
+//! - `deprecate_contract`: schedule a contract's retirement
+//! - `transfer_ownership`: hand a contract to another publisher
+//! - `amend_policy`: change the approving policy's threshold, signers,
+//!   expiry or timelock
+//!
+//! The proposer is the signed-in caller, and except for `apply_patch` must be
+//! one of the policy's signers. An action on a contract can only go through a
+//! policy that governs it: one created by the contract's publisher or set for
+//! the contract's organization in `org_network_policies`.
+//!
+//! Signing, timelocks and expiry work as for any proposal. The action is
+//! checked when proposed and again when `POST /api/contracts/:id/execute`
+//! runs it, in the transaction that marks the proposal executed.
+
+use axum::{extract::State, Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use shared::{AuditActionType, Network};
+use sqlx::{FromRow, PgConnection};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    consumers::{self, Impact},
+    contract_permissions::{self, ContractCapability},
+    deprecation_handlers,
+    error::{ApiError, ApiResult},
+    handlers::write_contract_audit_log,
+    metrics,
+    multisig_handlers::{
+        validate_policy_shape, DeployProposal, MultisigPolicy, POLICY_COLUMNS, PROPOSAL_COLUMNS,
+    },
+    multisig_notifications::{self, Trigger},
+    state::AppState,
+    tenant::Tenant,
+    validation::validators::validate_stellar_address,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+/// A privileged action and its typed payload. Contracts are given by UUID or
+/// on-chain ID and stored by UUID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum GovernanceAction {
+    ApplyPatch {
+        contract_id: String,
+        patch_id: Uuid,
+    },
+    DeprecateContract {
+        contract_id: String,
+        retirement_at: DateTime<Utc>,
+        replacement_contract_id: Option<String>,
+        migration_guide_url: Option<String>,
+        notes: Option<String>,
+    },
+    TransferOwnership {
+        contract_id: String,
+        /// Stellar address of the new publisher
+        new_owner: String,
+    },
+    /// Amends the policy approving the proposal; omitted fields are kept
+    AmendPolicy {
+        threshold: Option<i32>,
+        signer_addresses: Option<Vec<String>>,
+        expiry_seconds: Option<i32>,
+        timelock_seconds: Option<i32>,
+    },
+}
+
+impl GovernanceAction {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::ApplyPatch { .. } => "apply_patch",
+            Self::DeprecateContract { .. } => "deprecate_contract",
+            Self::TransferOwnership { .. } => "transfer_ownership",
+            Self::AmendPolicy { .. } => "amend_policy",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateActionProposalRequest {
+    pub policy_id: Uuid,
+    pub description: Option<String>,
+    pub action: GovernanceAction,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+#[derive(Debug, FromRow)]
+struct TargetContract {
+    id: Uuid,
+    contract_id: String,
+    name: String,
+    network: Network,
+    wasm_hash: String,
+    publisher_id: Uuid,
+    organization_id: Option<Uuid>,
+}
+
+/// What a proposal row records about its action.
+struct Subject {
+    name: String,
+    contract_id: String,
+    network: Option<Network>,
+    wasm_hash: Option<String>,
+}
+
+impl From<&TargetContract> for Subject {
+    fn from(contract: &TargetContract) -> Self {
+        Self {
+            name: contract.name.clone(),
+            contract_id: contract.contract_id.clone(),
+            network: Some(contract.network),
+            wasm_hash: Some(contract.wasm_hash.clone()),
+        }
+    }
+}
+
+fn db_error(context: &'static str) -> impl FnOnce(sqlx::Error) -> ApiError {
+    move |e| {
+        tracing::error!(error = ?e, "{}", context);
+        ApiError::db_error("Failed to process governance action")
+    }
+}
+
+/// Parses a contract UUID written by [`check`].
+fn stored_uuid(id: &str) -> ApiResult<Uuid> {
+    Uuid::parse_str(id)
+        .map_err(|_| ApiError::internal("Governance action refers to an unresolved contract"))
+}
+
+async fn load_contract(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    id: &str,
+) -> ApiResult<TargetContract> {
+    let uuid = Uuid::parse_str(id).ok();
+    sqlx::query_as(
+        "SELECT id, contract_id, name, network, wasm_hash, publisher_id, organization_id
+         FROM contracts
+         WHERE (id = $1 OR contract_id = $2) AND tenant_id = $3 AND deleted_at IS NULL
+         ORDER BY id = $1 DESC
+         LIMIT 1
+         FOR UPDATE",
+    )
+    .bind(uuid)
+    .bind(id.trim())
+    .bind(tenant_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(db_error("failed to load action contract"))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "ContractNotFound",
+            format!("No contract found with ID: {}", id),
+        )
+    })
+}
+
+async fn load_policy(conn: &mut PgConnection, policy_id: Uuid) -> ApiResult<MultisigPolicy> {
+    sqlx::query_as::<_, MultisigPolicy>(&format!(
+        "SELECT {} FROM multisig_policies WHERE id = $1 FOR UPDATE",
+        POLICY_COLUMNS
+    ))
+    .bind(policy_id)
+    .fetch_optional(conn)
+    .await
+    .map_err(db_error("failed to load multisig policy"))?
+    .ok_or_else(|| ApiError::not_found("PolicyNotFound", "multisig policy not found"))
+}
+
+/// Rejects acting on a contract (given by its publisher and organization)
+/// through a policy that does not govern it, so signers of an unrelated
+/// policy cannot approve changes to it.
+pub(crate) async fn ensure_policy_governs(
+    conn: &mut PgConnection,
+    policy_id: Uuid,
+    publisher_id: Uuid,
+    organization_id: Option<Uuid>,
+) -> ApiResult<()> {
+    let governs: bool = sqlx::query_scalar(
+        "SELECT EXISTS(
+            SELECT 1 FROM multisig_policies mp
+            WHERE mp.id = $1
+              AND (
+                  mp.created_by = (SELECT stellar_address FROM publishers WHERE id = $2)
+                  OR EXISTS(
+                      SELECT 1 FROM org_network_policies onp
+                      WHERE onp.policy_id = mp.id AND onp.organization_id = $3
+                  )
+              )
+         )",
+    )
+    .bind(policy_id)
+    .bind(publisher_id)
+    .bind(organization_id)
+    .fetch_one(conn)
+    .await
+    .map_err(db_error("failed to check policy ownership"))?;
+    if governs {
+        Ok(())
+    } else {
+        Err(ApiError::forbidden_with_error(
+            "PolicyNotForContract",
+            "the policy was not created by the contract's publisher or set for its organization",
+        ))
+    }
+}
+
+/// Validates `action` against the registry as it is now, returning the
+/// action with its contracts resolved to UUIDs and what the proposal records.
+async fn check(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    policy_id: Uuid,
+    action: &GovernanceAction,
+) -> ApiResult<(GovernanceAction, Subject)> {
+    match action {
+        GovernanceAction::ApplyPatch {
+            contract_id,
+            patch_id,
+        } => {
+            let contract = load_contract(&mut *conn, tenant_id, contract_id).await?;
+            ensure_policy_governs(
+                &mut *conn,
+                policy_id,
+                contract.publisher_id,
+                contract.organization_id,
+            )
+            .await?;
+            let (target_version, new_wasm_hash): (String, String) = sqlx::query_as(
+                "SELECT target_version, new_wasm_hash FROM security_patches WHERE id = $1",
+            )
+            .bind(patch_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(db_error("failed to load security patch"))?
+            .ok_or_else(|| ApiError::not_found("PatchNotFound", "security patch not found"))?;
+            if !contract.wasm_hash.eq_ignore_ascii_case(&target_version) {
+                return Err(ApiError::conflict(
+                    "PatchNotApplicable",
+                    format!(
+                        "contract runs build {}, but the patch targets {}",
+                        contract.wasm_hash, target_version
+                    ),
+                ));
+            }
+            let applied: bool = sqlx::query_scalar(
+                "SELECT EXISTS(
+                    SELECT 1 FROM patch_audits WHERE contract_id = $1 AND patch_id = $2
+                 )",
+            )
+            .bind(contract.id)
+            .bind(patch_id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error("failed to check patch audits"))?;
+            if applied {
+                return Err(ApiError::conflict(
+                    "PatchAlreadyApplied",
+                    "the patch is already applied to this contract",
+                ));
+            }
+
+            let subject = Subject {
+                wasm_hash: Some(new_wasm_hash),
+                ..Subject::from(&contract)
+            };
+            let action = GovernanceAction::ApplyPatch {
+                contract_id: contract.id.to_string(),
+                patch_id: *patch_id,
+            };
+            Ok((action, subject))
+        }
+        GovernanceAction::DeprecateContract {
+            contract_id,
+            retirement_at,
+            replacement_contract_id,
+            migration_guide_url,
+            notes,
+        } => {
+            if migration_guide_url.is_none() && replacement_contract_id.is_none() {
+                return Err(ApiError::bad_request(
+                    "MissingMigrationPath",
+                    "Provide replacement_contract_id or migration_guide_url",
+                ));
+            }
+            if *retirement_at <= Utc::now() {
+                return Err(ApiError::bad_request(
+                    "InvalidRetirementDate",
+                    "retirement_at must be in the future",
+                ));
+            }
+            let contract = load_contract(&mut *conn, tenant_id, contract_id).await?;
+            ensure_policy_governs(
+                &mut *conn,
+                policy_id,
+                contract.publisher_id,
+                contract.organization_id,
+            )
+            .await?;
+            let replacement = match replacement_contract_id {
+                Some(id) => Some(load_contract(&mut *conn, tenant_id, id).await?.id),
+                None => None,
+            };
+
+            let action = GovernanceAction::DeprecateContract {
+                contract_id: contract.id.to_string(),
+                retirement_at: *retirement_at,
+                replacement_contract_id: replacement.map(|id| id.to_string()),
+                migration_guide_url: migration_guide_url.clone(),
+                notes: notes.clone(),
+            };
+            Ok((action, Subject::from(&contract)))
+        }
+        GovernanceAction::TransferOwnership {
+            contract_id,
+            new_owner,
+        } => {
+            validate_stellar_address(new_owner)
+                .map_err(|msg| ApiError::bad_request("InvalidOwner", msg))?;
+            let contract = load_contract(&mut *conn, tenant_id, contract_id).await?;
+            ensure_policy_governs(
+                &mut *conn,
+                policy_id,
+                contract.publisher_id,
+                contract.organization_id,
+            )
+            .await?;
+            let current_owner: Option<String> =
+                sqlx::query_scalar("SELECT stellar_address FROM publishers WHERE id = $1")
+                    .bind(contract.publisher_id)
+                    .fetch_optional(&mut *conn)
+                    .await
+                    .map_err(db_error("failed to load contract owner"))?;
+            if current_owner.as_deref() == Some(new_owner.trim()) {
+                return Err(ApiError::conflict(
+                    "AlreadyOwner",
+                    "new_owner already owns this contract",
+                ));
+            }
+
+            let action = GovernanceAction::TransferOwnership {
+                contract_id: contract.id.to_string(),
+                new_owner: new_owner.trim().to_string(),
+            };
+            Ok((action, Subject::from(&contract)))
+        }
+        GovernanceAction::AmendPolicy {
+            threshold,
+            signer_addresses,
+            expiry_seconds,
+            timelock_seconds,
+        } => {
+            if threshold.is_none()
+                && signer_addresses.is_none()
+                && expiry_seconds.is_none()
+                && timelock_seconds.is_none()
+            {
+                return Err(ApiError::bad_request(
+                    "EmptyAmendment",
+                    "amend_policy must change at least one setting",
+                ));
+            }
+            let policy = load_policy(&mut *conn, policy_id).await?;
+            validate_policy_shape(
+                threshold.unwrap_or(policy.threshold),
+                signer_addresses
+                    .as_ref()
+                    .unwrap_or(&policy.signer_addresses),
+                expiry_seconds.unwrap_or(policy.expiry_seconds),
+                timelock_seconds.unwrap_or(policy.timelock_seconds),
+            )?;
+
+            let subject = Subject {
+                name: policy.name.clone(),
+                contract_id: policy.id.to_string(),
+                network: None,
+                wasm_hash: None,
+            };
+            Ok((action.clone(), subject))
+        }
+    }
+}
+
+/// Re-checks and applies an approved action. Runs inside the transaction that
+/// marks the proposal executed; returns what changed, for the audit trail.
+pub(crate) async fn apply(
+    conn: &mut PgConnection,
+    tenant_id: Uuid,
+    policy_id: Uuid,
+    action: &GovernanceAction,
+) -> ApiResult<Value> {
+    let (action, subject) = check(&mut *conn, tenant_id, policy_id, action).await?;
+    match action {
+        GovernanceAction::ApplyPatch {
+            contract_id,
+            patch_id,
+        } => {
+            let contract = stored_uuid(&contract_id)?;
+            sqlx::query("INSERT INTO patch_audits (contract_id, patch_id) VALUES ($1, $2)")
+                .bind(contract)
+                .bind(patch_id)
+                .execute(&mut *conn)
+                .await
+                .map_err(db_error("failed to record patch application"))?;
+            Ok(json!({
+                "contract_id": subject.contract_id,
+                "patch_id": patch_id,
+                "wasm_hash": subject.wasm_hash,
+            }))
+        }
+        GovernanceAction::DeprecateContract {
+            contract_id,
+            retirement_at,
+            replacement_contract_id,
+            migration_guide_url,
+            notes,
+        } => {
+            let contract = stored_uuid(&contract_id)?;
+            let replacement = replacement_contract_id
+                .as_deref()
+                .and_then(|id| Uuid::parse_str(id).ok());
+            sqlx::query(
+                "INSERT INTO contract_deprecations (
+                    contract_id, retirement_at, replacement_contract_id, migration_guide_url, notes
+                 )
+                 VALUES ($1, $2, $3, $4, $5)
+                 ON CONFLICT (contract_id) DO UPDATE SET
+                    retirement_at = EXCLUDED.retirement_at,
+                    replacement_contract_id = EXCLUDED.replacement_contract_id,
+                    migration_guide_url = EXCLUDED.migration_guide_url,
+                    notes = EXCLUDED.notes,
+                    updated_at = NOW()",
+            )
+            .bind(contract)
+            .bind(retirement_at)
+            .bind(replacement)
+            .bind(&migration_guide_url)
+            .bind(&notes)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error("failed to record deprecation"))?;
+            Ok(json!({
+                "contract_id": subject.contract_id,
+                "retirement_at": retirement_at,
+                "replacement_contract_id": replacement,
+            }))
+        }
+        GovernanceAction::TransferOwnership {
+            contract_id,
+            new_owner,
+        } => {
+            let contract = stored_uuid(&contract_id)?;
+            let (previous_publisher, previous_owner): (Uuid, String) = sqlx::query_as(
+                "SELECT p.id, p.stellar_address
+                 FROM contracts c JOIN publishers p ON p.id = c.publisher_id
+                 WHERE c.id = $1",
+            )
+            .bind(contract)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error("failed to load contract owner"))?;
+            let publisher_id: Uuid = sqlx::query_scalar(
+                "INSERT INTO publishers (stellar_address, tenant_id) VALUES ($1, $2)
+                 ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
+                 RETURNING id",
+            )
+            .bind(&new_owner)
+            .bind(tenant_id)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error("failed to upsert new owner"))?;
+            sqlx::query(
+                "UPDATE contracts
+                    SET publisher_id = $2,
+                        lock_version = lock_version + 1,
+                        updated_at = NOW()
+                  WHERE id = $1",
+            )
+            .bind(contract)
+            .bind(publisher_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(db_error("failed to transfer contract"))?;
+            Ok(json!({
+                "contract_id": subject.contract_id,
+                "publisher_id": { "before": previous_publisher, "after": publisher_id },
+                "publisher_address": { "before": previous_owner, "after": new_owner },
+            }))
+        }
+        GovernanceAction::AmendPolicy {
+            threshold,
+            signer_addresses,
+            expiry_seconds,
+            timelock_seconds,
+        } => {
+            let before = load_policy(&mut *conn, policy_id).await?;
+            let after = sqlx::query_as::<_, MultisigPolicy>(&format!(
+                "UPDATE multisig_policies
+                 SET threshold = COALESCE($2, threshold),
+                     signer_addresses = COALESCE($3, signer_addresses),
+                     expiry_seconds = COALESCE($4, expiry_seconds),
+                     timelock_seconds = COALESCE($5, timelock_seconds)
+                 WHERE id = $1
+                 RETURNING {}",
+                POLICY_COLUMNS
+            ))
+            .bind(policy_id)
+            .bind(threshold)
+            .bind(signer_addresses)
+            .bind(expiry_seconds)
+            .bind(timelock_seconds)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(db_error("failed to amend multisig policy"))?;
+            Ok(json!({ "before": before, "after": after }))
+        }
+    }
+}
+
+/// Follow-ups of an executed action that run once it is committed: dependent
+/// and consumer notices for deprecations, the contract audit log for
+/// transfers. Failures are logged.
+pub(crate) async fn after_commit(state: &AppState, action: &GovernanceAction, result: &Value) {
+    match action {
+        GovernanceAction::DeprecateContract {
+            contract_id,
+            retirement_at,
+            ..
+        } => {
+            let Ok(contract) = Uuid::parse_str(contract_id) else {
+                return;
+            };
+            let on_chain_id = result["contract_id"].as_str().unwrap_or_default();
+            if let Err(err) = deprecation_handlers::notify_dependents(
+                &state.db,
+                contract,
+                on_chain_id,
+                *retirement_at,
+            )
+            .await
+            {
+                tracing::warn!(contract = %contract, error = ?err, "failed to notify dependents of deprecation");
+            }
+            consumers::notify(
+                &state.db,
+                Impact::Deprecation {
+                    contract_id: contract,
+                    retirement_at: *retirement_at,
+                },
+            )
+            .await;
+        }
+        GovernanceAction::TransferOwnership { contract_id, .. } => {
+            let Ok(contract) = Uuid::parse_str(contract_id) else {
+                return;
+            };
+            let publisher_id = result["publisher_id"]["after"]
+                .as_str()
+                .and_then(|id| Uuid::parse_str(id).ok())
+                .unwrap_or_default();
+            let changes = json!({
+                "publisher_id": result["publisher_id"],
+                "publisher_address": result["publisher_address"],
+            });
+            if let Err(err) = write_contract_audit_log(
+                &state.db,
+                AuditActionType::PublisherChanged,
+                contract,
+                publisher_id,
+                changes,
+                "multisig",
+            )
+            .await
+            {
+                tracing::warn!(contract = %contract, error = ?err, "failed to write transfer audit log");
+            }
+        }
+        GovernanceAction::ApplyPatch { .. } | GovernanceAction::AmendPolicy { .. } => {}
+    }
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+pub async fn create_action_proposal(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Json(payload): Json<CreateActionProposalRequest>,
+) -> ApiResult<Json<DeployProposal>> {
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to start transaction");
+        ApiError::db_error("Failed to create action proposal")
+    })?;
+
+    let policy = load_policy(&mut *tx, payload.policy_id).await?;
+    let (action, subject) = check(&mut *tx, tenant.id, policy.id, &payload.action).await?;
+    match &action {
+        GovernanceAction::ApplyPatch { contract_id, .. } => {
+            let contract_uuid = Uuid::parse_str(contract_id)
+                .map_err(|_| ApiError::internal("resolved contract ID is not a UUID"))?;
+            contract_permissions::authorize(
+                &state,
+                &claims,
+                contract_uuid,
+                ContractCapability::ApplyPatches,
+                "propose patches to it",
+            )
+            .await?;
+        }
+        _ if !policy.signer_addresses.contains(&claims.sub) => {
+            return Err(ApiError::forbidden_with_error(
+                "NotPolicySigner",
+                "only the policy's signers can propose this action",
+            ));
+        }
+        _ => {}
+    }
+    let expires_at = Utc::now() + chrono::Duration::seconds(i64::from(policy.expiry_seconds));
+
+    let proposal: DeployProposal = sqlx::query_as(&format!(
+        "INSERT INTO deploy_proposals (
+            contract_name, contract_id, wasm_hash, network, description, action,
+            action_payload, policy_id, status, expires_at, proposer, required_approvals,
+            tenant_id
+         )
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, 'pending', $9, $10, $11, $12)
+         RETURNING {}",
+        PROPOSAL_COLUMNS
+    ))
+    .bind(&subject.name)
+    .bind(&subject.contract_id)
+    .bind(&subject.wasm_hash)
+    .bind(subject.network)
+    .bind(payload.description.as_deref())
+    .bind(action.kind())
+    .bind(json!(action))
+    .bind(policy.id)
+    .bind(expires_at)
+    .bind(&claims.sub)
+    .bind(policy.threshold)
+    .bind(tenant.id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to create action proposal");
+        ApiError::db_error("Failed to create action proposal")
+    })?;
+
+    sqlx::query(
+        "INSERT INTO multisig_approval_audit_events (
+            proposal_id, actor_address, action, metadata
+         )
+         VALUES ($1, $2, 'proposal_created', $3)",
+    )
+    .bind(proposal.id)
+    .bind(&claims.sub)
+    .bind(json!({
+        "required_approvals": proposal.required_approvals,
+        "ordered_approvals": policy.ordered_approvals,
+        "action": action.kind(),
+    }))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to insert audit event");
+        ApiError::db_error("Failed to record audit trail")
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to commit action proposal transaction");
+        ApiError::db_error("Failed to finalize action proposal")
+    })?;
+
+    multisig_notifications::notify(&state.db, proposal.id, Trigger::Created).await;
+    metrics::MULTISIG_PROPOSALS.inc();
+
+    Ok(Json(proposal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn actions_are_tagged_by_type() {
+        let action: GovernanceAction = serde_json::from_value(json!({
+            "type": "transfer_ownership",
+            "contract_id": "CABC",
+            "new_owner": "GNEW",
+        }))
+        .unwrap();
+        assert_eq!(action.kind(), "transfer_ownership");
+        assert_eq!(json!(action)["type"], "transfer_ownership");
+
+        let amend: GovernanceAction =
+            serde_json::from_value(json!({ "type": "amend_policy", "threshold": 3 })).unwrap();
+        assert!(matches!(
+            amend,
+            GovernanceAction::AmendPolicy {
+                threshold: Some(3),
+                signer_addresses: None,
+                ..
+            }
+        ));
+        assert!(serde_json::from_value::<GovernanceAction>(json!({ "type": "mint" })).is_err());
+    }
+}
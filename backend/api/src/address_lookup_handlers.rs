@@ -0,0 +1,152 @@
+//! Contract lookups by on-chain address
+//!
+//!   GET /api/contracts/by-address/:address                    – the contract
+//!   GET /api/contracts/by-address/:address/versions           – its versions
+//!   GET /api/contracts/by-address/:address/versions/:version  – one version
+//!   GET /api/contracts/by-address/:address/events             – its events
+//!   GET /api/contracts/by-address/:address/migrations         – its migrations
+//!
+//! The wallet summary at `/api/contracts/by-address/:address/summary` lives
+//! in [`crate::contract_summary_handlers`].
+//!
+//! Wallets and explorers know a contract by its `C…` address, not its
+//! registry UUID. These routes take the address explicitly, reject anything
+//! else with 400, and answer exactly like the `/api/contracts/:id` routes
+//! they mirror. An address registered on several networks resolves to its
+//! mainnet contract unless `?network=` is given where supported.
+
+use axum::{
+    extract::{Path, Query, State},
+    response::{IntoResponse, Response},
+    Json,
+};
+use shared::{ContractGetResponse, ContractVersion};
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    event_query_handlers::{self, EventQuery, EventRecord},
+    handlers::{self, migrations::MigrationHistoryQuery, GetContractQuery},
+    state::AppState,
+    tenant::Tenant,
+    validation::validators::validate_contract_id,
+};
+
+fn require_address(address: &str) -> ApiResult<()> {
+    validate_contract_id(address)
+        .map_err(|msg| ApiError::bad_request("InvalidContractAddress", format!("address {}", msg)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}",
+    params(
+        ("address" = String, Path, description = "On-chain contract ID (C…)"),
+        GetContractQuery
+    ),
+    responses(
+        (status = 200, description = "Contract details, with activity stats when ?include=stats", body = ContractGetResponse),
+        (status = 400, description = "Not a contract address"),
+        (status = 404, description = "No contract at this address")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_contract_by_address(
+    state: State<AppState>,
+    claims: Option<AuthClaims>,
+    tenant: Tenant,
+    Path(address): Path<String>,
+    query: Query<GetContractQuery>,
+) -> ApiResult<Response> {
+    require_address(&address)?;
+    handlers::get_contract(state, claims, tenant, Path(address), query)
+        .await
+        .map(IntoResponse::into_response)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}/versions",
+    params(("address" = String, Path, description = "On-chain contract ID (C…)")),
+    responses(
+        (status = 200, description = "List of contract versions", body = [ContractVersion]),
+        (status = 400, description = "Not a contract address"),
+        (status = 404, description = "No contract at this address")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_versions_by_address(
+    state: State<AppState>,
+    Path(address): Path<String>,
+) -> ApiResult<Json<Vec<ContractVersion>>> {
+    require_address(&address)?;
+    handlers::get_contract_versions(state, Path(address)).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}/versions/{version}",
+    params(
+        ("address" = String, Path, description = "On-chain contract ID (C…)"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    responses(
+        (status = 200, description = "The contract version", body = ContractVersion),
+        (status = 400, description = "Not a contract address"),
+        (status = 404, description = "No contract at this address, or no such version")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_version_by_address(
+    state: State<AppState>,
+    Path((address, version)): Path<(String, String)>,
+) -> ApiResult<Json<ContractVersion>> {
+    require_address(&address)?;
+    handlers::get_specific_contract_version(state, Path((address, version))).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}/events",
+    params(
+        ("address" = String, Path, description = "On-chain contract ID (C…)"),
+        EventQuery
+    ),
+    responses(
+        (status = 200, description = "Events emitted by the contract", body = [EventRecord]),
+        (status = 400, description = "Not a contract address"),
+        (status = 404, description = "No contract at this address")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_events_by_address(
+    state: State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(address): Path<String>,
+    query: Query<EventQuery>,
+) -> ApiResult<Json<Vec<EventRecord>>> {
+    require_address(&address)?;
+    event_query_handlers::get_contract_events(state, tenant, claims, Path(address), query).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}/migrations",
+    params(("address" = String, Path, description = "On-chain contract ID (C…)")),
+    responses(
+        (status = 200, description = "Migration history of the contract, newest first"),
+        (status = 400, description = "Not a contract address")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_migrations_by_address(
+    state: State<AppState>,
+    Path(address): Path<String>,
+    query: Query<MigrationHistoryQuery>,
+) -> ApiResult<Response> {
+    require_address(&address)?;
+    handlers::migrations::get_contract_migrations(state, Path(address), query)
+        .await
+        .map(IntoResponse::into_response)
+}
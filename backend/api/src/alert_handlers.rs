@@ -0,0 +1,431 @@
+//! User-defined alert rules on contract events
+//!
+//!   POST   /api/alerts               – create a rule for the authenticated user
+//!   GET    /api/alerts               – list the caller's rules
+//!   DELETE /api/alerts/:id           – delete one of the caller's rules
+//!   GET    /api/alerts/:id/triggers  – recent firings of a rule
+//!
+//! Rules are evaluated against ingested events by [`crate::alerts`].
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+    validation::url_validation::validate_https_url_only,
+};
+
+const MAX_WINDOW_SECONDS: i32 = 7 * 24 * 3600;
+const MAX_RULES_PER_USER: i64 = 100;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateAlertRuleRequest {
+    /// Contract UUID or on-chain contract ID
+    pub contract_id: String,
+    pub name: Option<String>,
+    /// Event topic to match, e.g. `admin_change`
+    pub topic: String,
+    /// Dot-separated path into the event payload, e.g. `new_admin`
+    pub data_path: Option<String>,
+    /// Value the payload (or `data_path` within it) must equal
+    pub data_value: Option<serde_json::Value>,
+    /// Matching events needed within the window to fire (default 1)
+    pub threshold: Option<i32>,
+    /// Window length in seconds (default 300, at most 7 days)
+    pub window_seconds: Option<i32>,
+    /// HTTPS endpoint to POST to when the rule fires
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct AlertRule {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    /// On-chain contract ID
+    pub contract_address: String,
+    pub network: String,
+    pub name: String,
+    pub topic: String,
+    pub data_path: Option<String>,
+    pub data_value: Option<serde_json::Value>,
+    pub threshold: i32,
+    pub window_seconds: i32,
+    pub webhook_url: Option<String>,
+    pub is_active: bool,
+    pub last_triggered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct AlertTrigger {
+    pub id: Uuid,
+    pub event_count: i32,
+    pub first_ledger: i64,
+    pub last_ledger: i64,
+    /// `delivered`, `failed`, or `skipped` when the rule has no webhook
+    pub delivery_status: String,
+    pub delivery_error: Option<String>,
+    pub triggered_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListTriggersQuery {
+    pub limit: Option<i64>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+const RULE_COLUMNS: &str = "r.id, r.contract_id, c.contract_id AS contract_address,
+    c.network::TEXT AS network, r.name, r.topic, r.data_path, r.data_value, r.threshold,
+    r.window_seconds, r.webhook_url, r.is_active, r.last_triggered_at, r.created_at";
+
+/// Normalised, validated fields of a new rule.
+#[derive(Debug, PartialEq)]
+struct RuleSpec {
+    topic: String,
+    data_path: Option<String>,
+    threshold: i32,
+    window_seconds: i32,
+    webhook_url: Option<String>,
+}
+
+fn validate_rule(req: &CreateAlertRuleRequest) -> Result<RuleSpec, ApiError> {
+    let topic = req.topic.trim();
+    if topic.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidAlertRule",
+            "topic is required",
+        ));
+    }
+
+    let data_path = req
+        .data_path
+        .as_deref()
+        .map(str::trim)
+        .filter(|path| !path.is_empty());
+    if let Some(path) = data_path {
+        if req.data_value.is_none() {
+            return Err(ApiError::bad_request(
+                "InvalidAlertRule",
+                "data_path requires data_value",
+            ));
+        }
+        if path.split('.').any(str::is_empty) {
+            return Err(ApiError::bad_request(
+                "InvalidAlertRule",
+                format!("data_path '{}' has an empty segment", path),
+            ));
+        }
+    }
+
+    let threshold = req.threshold.unwrap_or(1);
+    if threshold < 1 {
+        return Err(ApiError::bad_request(
+            "InvalidAlertRule",
+            "threshold must be at least 1",
+        ));
+    }
+    let window_seconds = req.window_seconds.unwrap_or(300);
+    if !(1..=MAX_WINDOW_SECONDS).contains(&window_seconds) {
+        return Err(ApiError::bad_request(
+            "InvalidAlertRule",
+            format!(
+                "window_seconds must be between 1 and {}",
+                MAX_WINDOW_SECONDS
+            ),
+        ));
+    }
+
+    let webhook_url = req
+        .webhook_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|url| !url.is_empty());
+    if let Some(url) = webhook_url {
+        validate_https_url_only(url)
+            .map_err(|msg| ApiError::bad_request("InvalidWebhookUrl", msg))?;
+    }
+
+    Ok(RuleSpec {
+        topic: topic.to_string(),
+        data_path: data_path.map(str::to_string),
+        threshold,
+        window_seconds,
+        webhook_url: webhook_url.map(str::to_string),
+    })
+}
+
+async fn fetch_rule(state: &AppState, owner: &str, id: Uuid) -> ApiResult<AlertRule> {
+    sqlx::query_as(&format!(
+        "SELECT {} FROM event_alert_rules r JOIN contracts c ON c.id = r.contract_id
+         WHERE r.id = $1 AND r.owner_address = $2",
+        RULE_COLUMNS
+    ))
+    .bind(id)
+    .bind(owner)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch alert rule", err))?
+    .ok_or_else(|| ApiError::not_found("AlertNotFound", format!("No alert found with ID: {}", id)))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/alerts",
+    request_body = CreateAlertRuleRequest,
+    responses(
+        (status = 201, description = "Alert rule created", body = AlertRule),
+        (status = 400, description = "Invalid rule"),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Alerts"
+)]
+pub async fn create_alert_rule(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Json(req): Json<CreateAlertRuleRequest>,
+) -> ApiResult<(StatusCode, Json<AlertRule>)> {
+    let spec = validate_rule(&req)?;
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &req.contract_id).await?;
+
+    let (visibility, organization_id): (shared::VisibilityType, Option<Uuid>) =
+        sqlx::query_as("SELECT visibility, organization_id FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract visibility", err))?;
+    if visibility == shared::VisibilityType::Private {
+        let is_member = match organization_id {
+            Some(org_id) => crate::org_handlers::check_org_role(
+                &state.db,
+                org_id,
+                &claims.sub,
+                shared::OrganizationRole::Viewer,
+            )
+            .await
+            .is_ok(),
+            None => false,
+        };
+        if !is_member {
+            return Err(ApiError::forbidden_with_error(
+                "AccessDenied",
+                "This contract is private and you do not have access to it",
+            ));
+        }
+    }
+
+    let existing: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM event_alert_rules WHERE owner_address = $1")
+            .bind(&claims.sub)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("count alert rules", err))?;
+    if existing >= MAX_RULES_PER_USER {
+        return Err(ApiError::conflict(
+            "AlertLimitReached",
+            format!("At most {} alert rules per user", MAX_RULES_PER_USER),
+        ));
+    }
+
+    let name = req
+        .name
+        .as_deref()
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{} events", spec.topic));
+    let id: Uuid = sqlx::query_scalar(
+        "INSERT INTO event_alert_rules
+            (owner_address, contract_id, name, topic, data_path, data_value,
+             threshold, window_seconds, webhook_url)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING id",
+    )
+    .bind(&claims.sub)
+    .bind(contract_uuid)
+    .bind(&name)
+    .bind(&spec.topic)
+    .bind(&spec.data_path)
+    .bind(&req.data_value)
+    .bind(spec.threshold)
+    .bind(spec.window_seconds)
+    .bind(&spec.webhook_url)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("create alert rule", err))?;
+
+    let rule = fetch_rule(&state, &claims.sub, id).await?;
+    Ok((StatusCode::CREATED, Json(rule)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/alerts",
+    responses(
+        (status = 200, description = "The caller's alert rules", body = [AlertRule]),
+        (status = 401, description = "Authentication required")
+    ),
+    tag = "Alerts"
+)]
+pub async fn list_alert_rules(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+) -> ApiResult<Json<Vec<AlertRule>>> {
+    let rules: Vec<AlertRule> = sqlx::query_as(&format!(
+        "SELECT {} FROM event_alert_rules r JOIN contracts c ON c.id = r.contract_id
+         WHERE r.owner_address = $1
+         ORDER BY r.created_at DESC",
+        RULE_COLUMNS
+    ))
+    .bind(&claims.sub)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list alert rules", err))?;
+
+    Ok(Json(rules))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/alerts/{id}",
+    params(("id" = Uuid, Path, description = "Alert rule ID")),
+    responses(
+        (status = 204, description = "Alert rule deleted"),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Alert rule not found")
+    ),
+    tag = "Alerts"
+)]
+pub async fn delete_alert_rule(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let deleted = sqlx::query("DELETE FROM event_alert_rules WHERE id = $1 AND owner_address = $2")
+        .bind(id)
+        .bind(&claims.sub)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("delete alert rule", err))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(ApiError::not_found(
+            "AlertNotFound",
+            format!("No alert found with ID: {}", id),
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/alerts/{id}/triggers",
+    params(("id" = Uuid, Path, description = "Alert rule ID"), ListTriggersQuery),
+    responses(
+        (status = 200, description = "Most recent firings first", body = [AlertTrigger]),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Alert rule not found")
+    ),
+    tag = "Alerts"
+)]
+pub async fn list_alert_triggers(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListTriggersQuery>,
+) -> ApiResult<Json<Vec<AlertTrigger>>> {
+    fetch_rule(&state, &claims.sub, id).await?;
+    let triggers: Vec<AlertTrigger> = sqlx::query_as(
+        "SELECT id, event_count, first_ledger, last_ledger, delivery_status, delivery_error,
+                triggered_at
+         FROM event_alert_triggers
+         WHERE rule_id = $1
+         ORDER BY triggered_at DESC
+         LIMIT $2",
+    )
+    .bind(id)
+    .bind(query.limit.unwrap_or(20).clamp(1, 100))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list alert triggers", err))?;
+
+    Ok(Json(triggers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request() -> CreateAlertRuleRequest {
+        CreateAlertRuleRequest {
+            contract_id: "CABC".into(),
+            name: None,
+            topic: " admin_change ".into(),
+            data_path: None,
+            data_value: None,
+            threshold: None,
+            window_seconds: None,
+            webhook_url: None,
+        }
+    }
+
+    #[test]
+    fn defaults_are_applied() {
+        let spec = validate_rule(&request()).unwrap();
+        assert_eq!(
+            spec,
+            RuleSpec {
+                topic: "admin_change".into(),
+                data_path: None,
+                threshold: 1,
+                window_seconds: 300,
+                webhook_url: None,
+            }
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_predicates() {
+        let mut req = request();
+        req.data_path = Some("new_admin".into());
+        assert!(validate_rule(&req).is_err());
+
+        req.data_value = Some(serde_json::json!("GABC"));
+        assert!(validate_rule(&req).is_ok());
+
+        req.data_path = Some("args..admin".into());
+        assert!(validate_rule(&req).is_err());
+    }
+
+    #[test]
+    fn rejects_out_of_range_thresholds_and_plain_http() {
+        let mut req = request();
+        req.threshold = Some(0);
+        assert!(validate_rule(&req).is_err());
+
+        let mut req = request();
+        req.window_seconds = Some(MAX_WINDOW_SECONDS + 1);
+        assert!(validate_rule(&req).is_err());
+
+        let mut req = request();
+        req.webhook_url = Some("http://example.com/hook".into());
+        assert!(validate_rule(&req).is_err());
+    }
+}
@@ -0,0 +1,183 @@
+//! Evaluation and delivery of event alert rules
+//!
+//! Every `ALERT_EVAL_INTERVAL_SECS` (default 30) the evaluator counts, per
+//! active rule, the matching events ingested into `contract_events` since the
+//! rule last fired. Rules that reach their threshold within their window are
+//! marked triggered, recorded in `event_alert_triggers`, and POSTed to the
+//! rule's webhook if it has one. Rules are managed through
+//! [`crate::alert_handlers`].
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+const DEFAULT_INTERVAL_SECS: u64 = 30;
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug, sqlx::FromRow)]
+struct FiredRule {
+    id: Uuid,
+    name: String,
+    owner_address: String,
+    contract_id: String,
+    network: String,
+    topic: String,
+    window_seconds: i32,
+    webhook_url: Option<String>,
+    last_triggered_at: Option<DateTime<Utc>>,
+    event_count: i64,
+    first_ledger: i64,
+    last_ledger: i64,
+}
+
+/// Body POSTed to a rule's webhook when it fires.
+#[derive(Debug, Serialize)]
+struct AlertPayload<'a> {
+    alert_id: Uuid,
+    alert_name: &'a str,
+    contract_id: &'a str,
+    network: &'a str,
+    topic: &'a str,
+    event_count: i64,
+    window_seconds: i32,
+    first_ledger: i64,
+    last_ledger: i64,
+    triggered_at: DateTime<Utc>,
+}
+
+pub fn spawn_alert_evaluator(pool: PgPool) {
+    let interval_secs = std::env::var("ALERT_EVAL_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let client = match reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+            Ok(client) => client,
+            Err(err) => {
+                tracing::error!(error = ?err, "alerts: failed to build webhook client");
+                return;
+            }
+        };
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = evaluate(&pool, &client).await {
+                tracing::error!(error = ?err, "alerts: evaluation failed");
+            }
+        }
+    });
+}
+
+async fn evaluate(pool: &PgPool, client: &reqwest::Client) -> Result<(), sqlx::Error> {
+    let fired: Vec<FiredRule> = sqlx::query_as(
+        "SELECT r.id, r.name, r.owner_address, c.contract_id, c.network::TEXT AS network,
+                r.topic, r.window_seconds, r.webhook_url, r.last_triggered_at,
+                COUNT(e.id) AS event_count,
+                MIN(e.ledger_sequence) AS first_ledger,
+                MAX(e.ledger_sequence) AS last_ledger
+         FROM event_alert_rules r
+         JOIN contracts c ON c.id = r.contract_id AND c.deleted_at IS NULL
+         JOIN contract_events e
+           ON e.contract_id = c.contract_id
+          AND e.network = c.network
+          AND e.topic = r.topic
+          AND e.timestamp >= NOW() - make_interval(secs => r.window_seconds)
+          AND e.created_at > COALESCE(r.last_triggered_at, r.created_at)
+          AND (r.data_value IS NULL OR
+               (CASE WHEN r.data_path IS NULL THEN e.data
+                     ELSE e.data #> string_to_array(r.data_path, '.') END) = r.data_value)
+         WHERE r.is_active
+         GROUP BY r.id, c.contract_id, c.network
+         HAVING COUNT(e.id) >= r.threshold",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for rule in fired {
+        // Another replica may have fired this rule since the scan above.
+        let triggered_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+            "UPDATE event_alert_rules SET last_triggered_at = NOW()
+             WHERE id = $1 AND last_triggered_at IS NOT DISTINCT FROM $2
+             RETURNING last_triggered_at",
+        )
+        .bind(rule.id)
+        .bind(rule.last_triggered_at)
+        .fetch_optional(pool)
+        .await?
+        .flatten();
+        let Some(triggered_at) = triggered_at else {
+            continue;
+        };
+
+        let (delivery_status, delivery_error) = match rule.webhook_url.as_deref() {
+            None => ("skipped", None),
+            Some(url) => match deliver(client, url, &rule, triggered_at).await {
+                Ok(()) => ("delivered", None),
+                Err(err) => {
+                    tracing::warn!(rule = %rule.id, error = %err, "alerts: webhook delivery failed");
+                    ("failed", Some(err))
+                }
+            },
+        };
+
+        sqlx::query(
+            "INSERT INTO event_alert_triggers
+                (rule_id, event_count, first_ledger, last_ledger, delivery_status, delivery_error, triggered_at)
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(rule.id)
+        .bind(rule.event_count as i32)
+        .bind(rule.first_ledger)
+        .bind(rule.last_ledger)
+        .bind(delivery_status)
+        .bind(delivery_error)
+        .bind(triggered_at)
+        .execute(pool)
+        .await?;
+
+        tracing::info!(
+            rule = %rule.id,
+            owner = %rule.owner_address,
+            contract = %rule.contract_id,
+            topic = %rule.topic,
+            events = rule.event_count,
+            "alerts: rule triggered"
+        );
+    }
+    Ok(())
+}
+
+async fn deliver(
+    client: &reqwest::Client,
+    url: &str,
+    rule: &FiredRule,
+    triggered_at: DateTime<Utc>,
+) -> Result<(), String> {
+    let payload = AlertPayload {
+        alert_id: rule.id,
+        alert_name: &rule.name,
+        contract_id: &rule.contract_id,
+        network: &rule.network,
+        topic: &rule.topic,
+        event_count: rule.event_count,
+        window_seconds: rule.window_seconds,
+        first_ledger: rule.first_ledger,
+        last_ledger: rule.last_ledger,
+        triggered_at,
+    };
+    let response = client
+        .post(url)
+        .header("X-Registry-Alert-Id", rule.id.to_string())
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|err| err.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("webhook responded with {}", response.status()));
+    }
+    Ok(())
+}
@@ -0,0 +1,287 @@
+//! On-chain anchoring of contract records
+//!
+//! When `ANCHOR_SECRET_KEY` is set, every publish and ownership/metadata
+//! change queues an `anchor_contract` job. The job writes the record's digest
+//! (see [`shared::anchor`]) into the anchor account's data entry
+//! `sr:<contract_id>` with a ManageData transaction on the contract's network,
+//! so `soroban-registry verify-anchor` can detect a registry whose data no
+//! longer matches what was committed on-chain.
+//!
+//! Horizon endpoints default to the public SDF instances and can be
+//! overridden with `HORIZON_URL_{MAINNET,TESTNET,FUTURENET}`.
+
+use anyhow::{bail, Context};
+use async_trait::async_trait;
+use ed25519_dalek::{Signer, SigningKey};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use shared::{anchor::anchor_data_name, Contract, Network};
+use sqlx::PgPool;
+use stellar_xdr::curr::{
+    DataValue, DecoratedSignature, Hash, Limits, ManageDataOp, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, SequenceNumber, Signature, SignatureHint, String64, Transaction,
+    TransactionEnvelope, TransactionExt, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, TransactionV1Envelope, Uint256, WriteXdr,
+};
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const ANCHOR_JOB_KIND: &str = "anchor_contract";
+const FEE_STROOPS: u32 = 1_000;
+
+pub struct AnchorConfig {
+    signing_key: SigningKey,
+    /// `G...` account whose data entries hold the anchors
+    pub account: String,
+}
+
+impl AnchorConfig {
+    /// `None` when anchoring is not configured (or the key is unusable).
+    pub fn from_env() -> Option<Self> {
+        let secret = std::env::var("ANCHOR_SECRET_KEY").ok()?;
+        match stellar_strkey::ed25519::PrivateKey::from_string(secret.trim()) {
+            Ok(key) => {
+                let signing_key = SigningKey::from_bytes(&key.0);
+                let account =
+                    stellar_strkey::ed25519::PublicKey(signing_key.verifying_key().to_bytes())
+                        .to_string();
+                Some(Self {
+                    signing_key,
+                    account,
+                })
+            }
+            Err(_) => {
+                tracing::warn!(
+                    "ANCHOR_SECRET_KEY is not a valid Stellar secret key; anchoring disabled"
+                );
+                None
+            }
+        }
+    }
+
+    /// Build and sign a ManageData transaction as a base64 envelope.
+    fn manage_data_envelope(
+        &self,
+        network: &Network,
+        sequence: i64,
+        data_name: &str,
+        digest: &[u8; 32],
+    ) -> anyhow::Result<String> {
+        let operation = Operation {
+            source_account: None,
+            body: OperationBody::ManageData(ManageDataOp {
+                data_name: String64(data_name.try_into()?),
+                data_value: Some(DataValue(digest.to_vec().try_into()?)),
+            }),
+        };
+        let public_key = self.signing_key.verifying_key().to_bytes();
+        let tx = Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(public_key)),
+            fee: FEE_STROOPS,
+            seq_num: SequenceNumber(sequence),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![operation].try_into()?,
+            ext: TransactionExt::V0,
+        };
+
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(Sha256::digest(network_passphrase(network)).into()),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+        };
+        let tx_hash = Sha256::digest(payload.to_xdr(Limits::none())?);
+        let signature = DecoratedSignature {
+            hint: SignatureHint(public_key[28..].try_into()?),
+            signature: Signature(
+                self.signing_key
+                    .sign(&tx_hash)
+                    .to_bytes()
+                    .to_vec()
+                    .try_into()?,
+            ),
+        };
+
+        let envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: vec![signature].try_into()?,
+        });
+        Ok(envelope.to_xdr_base64(Limits::none())?)
+    }
+
+    /// Write `digest` to `data_name` and return the transaction hash.
+    pub async fn submit(
+        &self,
+        network: &Network,
+        data_name: &str,
+        digest: &[u8; 32],
+    ) -> anyhow::Result<String> {
+        #[derive(Deserialize)]
+        struct Account {
+            sequence: String,
+        }
+        #[derive(Deserialize)]
+        struct Submitted {
+            hash: String,
+        }
+
+        let horizon = horizon_url(network);
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(30))
+            .build()?;
+
+        let account: Account = client
+            .get(format!("{}/accounts/{}", horizon, self.account))
+            .send()
+            .await?
+            .error_for_status()
+            .context("anchor account not found on this network")?
+            .json()
+            .await?;
+        let sequence: i64 = account.sequence.parse()?;
+
+        let envelope = self.manage_data_envelope(network, sequence + 1, data_name, digest)?;
+        let response = client
+            .post(format!("{}/transactions", horizon))
+            .form(&[("tx", envelope)])
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            bail!("Horizon rejected anchor transaction ({}): {}", status, body);
+        }
+        Ok(response.json::<Submitted>().await?.hash)
+    }
+}
+
+pub(crate) fn network_passphrase(network: &Network) -> &'static str {
+    match network {
+        Network::Mainnet => "Public Global Stellar Network ; September 2015",
+        Network::Testnet => "Test SDF Network ; September 2015",
+        Network::Futurenet => "Test SDF Future Network ; October 2022",
+    }
+}
+
+fn horizon_url(network: &Network) -> String {
+    let (var, default) = match network {
+        Network::Mainnet => ("HORIZON_URL_MAINNET", "https://horizon.stellar.org"),
+        Network::Testnet => ("HORIZON_URL_TESTNET", "https://horizon-testnet.stellar.org"),
+        Network::Futurenet => (
+            "HORIZON_URL_FUTURENET",
+            "https://horizon-futurenet.stellar.org",
+        ),
+    };
+    std::env::var(var)
+        .unwrap_or_else(|_| default.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Queue an anchor for `contract_uuid` if anchoring is configured. Failures
+/// are logged rather than failing the write that triggered them.
+pub async fn enqueue_if_enabled(pool: &PgPool, contract_uuid: Uuid) {
+    if std::env::var_os("ANCHOR_SECRET_KEY").is_none() {
+        return;
+    }
+    if let Err(err) = job_queue::enqueue(
+        pool,
+        ANCHOR_JOB_KIND,
+        serde_json::json!({ "contract_id": contract_uuid }),
+    )
+    .await
+    {
+        tracing::warn!(contract = %contract_uuid, error = ?err, "failed to queue anchor job");
+    }
+}
+
+pub struct AnchorContractJob;
+
+#[async_trait]
+impl JobHandler for AnchorContractJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let config = AnchorConfig::from_env().context("ANCHOR_SECRET_KEY is not configured")?;
+        let contract_uuid: Uuid = serde_json::from_value(job.payload["contract_id"].clone())
+            .context("anchor job payload is missing contract_id")?;
+
+        let contract: Option<Contract> =
+            sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
+                .bind(contract_uuid)
+                .fetch_optional(pool)
+                .await?;
+        let Some(contract) = contract else {
+            return Ok(());
+        };
+
+        // Several writes in a row queue several jobs; only the first one
+        // after a change needs a transaction. Manual anchors always submit.
+        let digest = contract.anchor_fields().digest();
+        let digest_hex = hex::encode(digest);
+        let latest: Option<String> = sqlx::query_scalar(
+            "SELECT digest FROM contract_anchors
+             WHERE contract_id = $1 AND anchor_account = $2
+             ORDER BY anchored_at DESC LIMIT 1",
+        )
+        .bind(contract.id)
+        .bind(&config.account)
+        .fetch_optional(pool)
+        .await?;
+        let force = job.payload["force"].as_bool().unwrap_or(false);
+        if !force && latest.as_deref() == Some(digest_hex.as_str()) {
+            return Ok(());
+        }
+
+        let data_name = anchor_data_name(&contract.contract_id);
+        let tx_hash = config
+            .submit(&contract.network, &data_name, &digest)
+            .await?;
+
+        sqlx::query(
+            "INSERT INTO contract_anchors
+                (contract_id, network, digest, anchor_account, data_name, transaction_hash)
+             VALUES ($1, $2, $3, $4, $5, $6)",
+        )
+        .bind(contract.id)
+        .bind(&contract.network)
+        .bind(&digest_hex)
+        .bind(&config.account)
+        .bind(&data_name)
+        .bind(&tx_hash)
+        .execute(pool)
+        .await?;
+
+        tracing::info!(contract = %contract.id, tx_hash, "contract record anchored");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::ReadXdr;
+
+    #[test]
+    fn envelope_carries_signed_manage_data() {
+        let config = AnchorConfig {
+            signing_key: SigningKey::from_bytes(&[3u8; 32]),
+            account: String::new(),
+        };
+        let digest = [0xabu8; 32];
+        let b64 = config
+            .manage_data_envelope(&Network::Testnet, 42, "sr:CABC", &digest)
+            .unwrap();
+
+        let TransactionEnvelope::Tx(envelope) =
+            TransactionEnvelope::from_xdr_base64(b64, Limits::none()).unwrap()
+        else {
+            panic!("expected a v1 envelope");
+        };
+        assert_eq!(envelope.tx.seq_num, SequenceNumber(42));
+        assert_eq!(envelope.signatures.len(), 1);
+        let OperationBody::ManageData(op) = &envelope.tx.operations[0].body else {
+            panic!("expected ManageData");
+        };
+        assert_eq!(op.data_name.0.as_slice(), b"sr:CABC");
+        assert_eq!(op.data_value.as_ref().unwrap().0.as_slice(), &digest);
+    }
+}
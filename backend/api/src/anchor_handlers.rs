@@ -0,0 +1,150 @@
+//! On-chain anchor status and manual anchoring
+//!
+//!   GET  /api/contracts/:id/anchor        – current digest and latest on-chain anchor
+//!   POST /api/admin/contracts/:id/anchor  – queue an anchor transaction now
+//!
+//! See [`crate::anchor`] for how records are anchored.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shared::{anchor::anchor_data_name, Contract};
+use uuid::Uuid;
+
+use crate::{
+    anchor::{AnchorConfig, ANCHOR_JOB_KIND},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    job_queue,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractAnchor {
+    pub digest: String,
+    pub anchor_account: String,
+    pub data_name: String,
+    pub transaction_hash: String,
+    pub anchored_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct AnchorStatus {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub network: String,
+    /// Digest of the record as the registry serves it now
+    pub digest: String,
+    /// Data entry that holds the digest on-chain
+    pub data_name: String,
+    /// Account anchors are written to; `None` when anchoring is disabled
+    pub anchor_account: Option<String>,
+    pub latest_anchor: Option<ContractAnchor>,
+    /// Whether `latest_anchor` commits to `digest`
+    pub up_to_date: bool,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct AnchorQueued {
+    pub job_id: Uuid,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/anchor",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Anchor status", body = AnchorStatus),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_anchor_status(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<AnchorStatus>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch contract for anchor status", err))?;
+
+    let anchor_account = AnchorConfig::from_env().map(|config| config.account);
+    let latest_anchor: Option<ContractAnchor> = sqlx::query_as(
+        "SELECT digest, anchor_account, data_name, transaction_hash, anchored_at
+         FROM contract_anchors
+         WHERE contract_id = $1 AND ($2::TEXT IS NULL OR anchor_account = $2)
+         ORDER BY anchored_at DESC LIMIT 1",
+    )
+    .bind(contract.id)
+    .bind(anchor_account.as_deref())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch latest anchor", err))?;
+
+    let fields = contract.anchor_fields();
+    let digest = fields.digest_hex();
+    Ok(Json(AnchorStatus {
+        up_to_date: latest_anchor
+            .as_ref()
+            .is_some_and(|anchor| anchor.digest == digest),
+        id: contract.id,
+        contract_id: contract.contract_id.clone(),
+        network: fields.network.to_string(),
+        digest,
+        data_name: anchor_data_name(&contract.contract_id),
+        anchor_account,
+        latest_anchor,
+    }))
+}
+
+pub async fn anchor_contract_now(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<(StatusCode, Json<AnchorQueued>)> {
+    let contract_uuid = Uuid::parse_str(&id)
+        .map_err(|_| ApiError::bad_request("InvalidId", format!("Invalid UUID: {}", id)))?;
+    if AnchorConfig::from_env().is_none() {
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "AnchoringDisabled",
+            "Set ANCHOR_SECRET_KEY to enable on-chain anchoring",
+        ));
+    }
+
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1 AND deleted_at IS NULL)",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check contract for anchoring", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            format!("No contract found with ID: {}", id),
+        ));
+    }
+
+    let job_id = job_queue::enqueue(
+        &state.db,
+        ANCHOR_JOB_KIND,
+        serde_json::json!({ "contract_id": contract_uuid, "force": true }),
+    )
+    .await
+    .map_err(|err| db_internal_error("queue anchor job", err))?;
+
+    Ok((StatusCode::ACCEPTED, Json(AnchorQueued { job_id })))
+}
@@ -0,0 +1,374 @@
+//! Anomaly flags on ingested contract events
+//!
+//! A background scan follows `contract_events` in ingestion order (tracked in
+//! `event_anomaly_cursor`) and, for each batch of new events, flags:
+//!
+//! * `new_topic` — a contract with prior history emits a topic it never
+//!   emitted before;
+//! * `rate_spike` — a contract's events in the last hour reach
+//!   [`SPIKE_MIN_EVENTS`] and [`SPIKE_FACTOR`]× its hourly average over the
+//!   preceding week;
+//! * `large_transfer` — a SEP-41 `transfer`/`mint`/`burn`/`clawback` amount is
+//!   at least [`LARGE_TRANSFER_FACTOR`]× the contract's 30-day median for that
+//!   topic.
+//!
+//! Findings land in `contract_event_anomalies` and are served by
+//! [`crate::anomaly_handlers`]. The scan runs every
+//! `ANOMALY_SCAN_INTERVAL_SECS` (default 60).
+
+use chrono::{DateTime, Utc};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use uuid::Uuid;
+
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+const BATCH_SIZE: i64 = 1000;
+
+pub const SPIKE_MIN_EVENTS: i64 = 50;
+pub const SPIKE_FACTOR: f64 = 5.0;
+pub const LARGE_TRANSFER_FACTOR: f64 = 10.0;
+/// Transfers needed in the baseline before amounts are judged at all.
+const LARGE_TRANSFER_MIN_SAMPLES: i64 = 20;
+pub(crate) const TOKEN_TOPICS: [&str; 4] = ["transfer", "mint", "burn", "clawback"];
+
+#[derive(Debug, sqlx::FromRow)]
+struct IngestedEvent {
+    id: Uuid,
+    contract_id: String,
+    network: String,
+    topic: String,
+    data: Option<Value>,
+    ledger_sequence: i64,
+    created_at: DateTime<Utc>,
+}
+
+#[derive(Debug)]
+struct Anomaly {
+    contract_id: String,
+    network: String,
+    kind: &'static str,
+    topic: Option<String>,
+    event_id: Option<Uuid>,
+    ledger_sequence: Option<i64>,
+    details: Value,
+    dedupe_key: String,
+}
+
+pub fn spawn_anomaly_detector(pool: PgPool) {
+    let interval_secs = std::env::var("ANOMALY_SCAN_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            // Drain the backlog, one batch at a time.
+            loop {
+                match scan_batch(&pool).await {
+                    Ok(scanned) if scanned as i64 == BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(err) => {
+                        tracing::error!(error = ?err, "anomalies: scan failed");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn scan_batch(pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let (last_created_at, last_event_id): (DateTime<Utc>, Uuid) = sqlx::query_as(
+        "SELECT last_created_at, last_event_id FROM event_anomaly_cursor WHERE id = 1",
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let batch: Vec<IngestedEvent> = sqlx::query_as(
+        "SELECT id, contract_id, network::TEXT AS network, topic, data, ledger_sequence, created_at
+         FROM contract_events
+         WHERE (created_at, id) > ($1, $2)
+         ORDER BY created_at, id
+         LIMIT $3",
+    )
+    .bind(last_created_at)
+    .bind(last_event_id)
+    .bind(BATCH_SIZE)
+    .fetch_all(pool)
+    .await?;
+    let Some(last) = batch.last() else {
+        return Ok(0);
+    };
+
+    let mut anomalies = Vec::new();
+    detect_new_topics(pool, &batch, &mut anomalies).await?;
+    detect_rate_spikes(pool, &batch, &mut anomalies).await?;
+    detect_large_transfers(pool, &batch, &mut anomalies).await?;
+
+    let mut tx = pool.begin().await?;
+    for anomaly in &anomalies {
+        sqlx::query(
+            "INSERT INTO contract_event_anomalies
+                (contract_id, network, kind, topic, event_id, ledger_sequence, details, dedupe_key)
+             VALUES ($1, $2::network_type, $3, $4, $5, $6, $7, $8)
+             ON CONFLICT ON CONSTRAINT unique_event_anomaly DO NOTHING",
+        )
+        .bind(&anomaly.contract_id)
+        .bind(&anomaly.network)
+        .bind(anomaly.kind)
+        .bind(&anomaly.topic)
+        .bind(anomaly.event_id)
+        .bind(anomaly.ledger_sequence)
+        .bind(&anomaly.details)
+        .bind(&anomaly.dedupe_key)
+        .execute(&mut *tx)
+        .await?;
+    }
+    sqlx::query(
+        "UPDATE event_anomaly_cursor SET last_created_at = $1, last_event_id = $2
+         WHERE id = 1 AND (last_created_at, last_event_id) < ($1, $2)",
+    )
+    .bind(last.created_at)
+    .bind(last.id)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    if !anomalies.is_empty() {
+        tracing::info!(count = anomalies.len(), "anomalies: flagged new events");
+    }
+    Ok(batch.len())
+}
+
+/// First event of the batch per (contract, network, topic).
+fn first_per_topic(batch: &[IngestedEvent]) -> Vec<&IngestedEvent> {
+    let mut first = BTreeMap::new();
+    for event in batch {
+        first
+            .entry((&event.contract_id, &event.network, &event.topic))
+            .or_insert(event);
+    }
+    first.into_values().collect()
+}
+
+async fn detect_new_topics(
+    pool: &PgPool,
+    batch: &[IngestedEvent],
+    out: &mut Vec<Anomaly>,
+) -> Result<(), sqlx::Error> {
+    for event in first_per_topic(batch) {
+        let (seen_topic, has_history): (bool, bool) = sqlx::query_as(
+            "SELECT
+                EXISTS(SELECT 1 FROM contract_events
+                       WHERE contract_id = $1 AND network = $2::network_type AND topic = $3
+                         AND (created_at, id) < ($4, $5)),
+                EXISTS(SELECT 1 FROM contract_events
+                       WHERE contract_id = $1 AND network = $2::network_type
+                         AND (created_at, id) < ($4, $5))",
+        )
+        .bind(&event.contract_id)
+        .bind(&event.network)
+        .bind(&event.topic)
+        .bind(event.created_at)
+        .bind(event.id)
+        .fetch_one(pool)
+        .await?;
+
+        // A contract's very first events are not news.
+        if !seen_topic && has_history {
+            out.push(Anomaly {
+                contract_id: event.contract_id.clone(),
+                network: event.network.clone(),
+                kind: "new_topic",
+                topic: Some(event.topic.clone()),
+                event_id: Some(event.id),
+                ledger_sequence: Some(event.ledger_sequence),
+                details: json!({ "first_seen_ledger": event.ledger_sequence }),
+                dedupe_key: format!("new_topic:{}", event.topic),
+            });
+        }
+    }
+    Ok(())
+}
+
+pub fn is_rate_spike(last_hour: i64, baseline_per_hour: f64) -> bool {
+    last_hour >= SPIKE_MIN_EVENTS && last_hour as f64 >= SPIKE_FACTOR * baseline_per_hour.max(1.0)
+}
+
+async fn detect_rate_spikes(
+    pool: &PgPool,
+    batch: &[IngestedEvent],
+    out: &mut Vec<Anomaly>,
+) -> Result<(), sqlx::Error> {
+    let mut latest_ledger = BTreeMap::new();
+    for event in batch {
+        let ledger = latest_ledger
+            .entry((&event.contract_id, &event.network))
+            .or_insert(event.ledger_sequence);
+        *ledger = (*ledger).max(event.ledger_sequence);
+    }
+
+    let hour = Utc::now().format("%Y-%m-%dT%H:00Z").to_string();
+    for ((contract_id, network), ledger) in latest_ledger {
+        let (last_hour, prior_week): (i64, i64) = sqlx::query_as(
+            "SELECT
+                COUNT(*) FILTER (WHERE timestamp >= NOW() - INTERVAL '1 hour'),
+                COUNT(*) FILTER (WHERE timestamp < NOW() - INTERVAL '1 hour')
+             FROM contract_events
+             WHERE contract_id = $1 AND network = $2::network_type
+               AND timestamp >= NOW() - INTERVAL '7 days 1 hour'",
+        )
+        .bind(contract_id)
+        .bind(network)
+        .fetch_one(pool)
+        .await?;
+
+        let baseline = prior_week as f64 / (7.0 * 24.0);
+        if is_rate_spike(last_hour, baseline) {
+            out.push(Anomaly {
+                contract_id: contract_id.clone(),
+                network: network.clone(),
+                kind: "rate_spike",
+                topic: None,
+                event_id: None,
+                ledger_sequence: Some(ledger),
+                details: json!({
+                    "events_last_hour": last_hour,
+                    "baseline_per_hour": (baseline * 100.0).round() / 100.0,
+                }),
+                dedupe_key: format!("rate_spike:{}", hour),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Amount carried by a token event: a bare number, a numeric string (i128
+/// values are usually serialised as strings), or an object's `amount`.
+pub fn transfer_amount(data: &Value) -> Option<f64> {
+    let amount = match data {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.trim().parse().ok(),
+        Value::Object(map) => match map.get("amount")? {
+            amount @ (Value::Number(_) | Value::String(_)) => transfer_amount(amount),
+            _ => None,
+        },
+        _ => None,
+    };
+    amount.map(f64::abs)
+}
+
+pub fn is_large_transfer(amount: f64, median: f64, samples: i64) -> bool {
+    samples >= LARGE_TRANSFER_MIN_SAMPLES
+        && median > 0.0
+        && amount >= LARGE_TRANSFER_FACTOR * median
+}
+
+async fn detect_large_transfers(
+    pool: &PgPool,
+    batch: &[IngestedEvent],
+    out: &mut Vec<Anomaly>,
+) -> Result<(), sqlx::Error> {
+    let mut groups: BTreeMap<_, Vec<(&IngestedEvent, f64)>> = BTreeMap::new();
+    for event in batch {
+        if !TOKEN_TOPICS.contains(&event.topic.as_str()) {
+            continue;
+        }
+        if let Some(amount) = event.data.as_ref().and_then(transfer_amount) {
+            groups
+                .entry((&event.contract_id, &event.network, &event.topic))
+                .or_default()
+                .push((event, amount));
+        }
+    }
+
+    for ((contract_id, network, topic), events) in groups {
+        // Baseline: the 30 days before this batch's first such event.
+        let first = events[0].0;
+        let (samples, median): (i64, Option<f64>) = sqlx::query_as(
+            "SELECT COUNT(*),
+                    percentile_cont(0.5) WITHIN GROUP (ORDER BY ABS(amount::NUMERIC))::FLOAT8
+             FROM (
+                SELECT COALESCE(data ->> 'amount', data #>> '{}') AS amount
+                FROM contract_events
+                WHERE contract_id = $1 AND network = $2::network_type AND topic = $3
+                  AND timestamp >= NOW() - INTERVAL '30 days'
+                  AND (created_at, id) < ($4, $5)
+             ) t
+             WHERE amount ~ '^-?[0-9]+([.][0-9]+)?$'",
+        )
+        .bind(contract_id)
+        .bind(network)
+        .bind(topic)
+        .bind(first.created_at)
+        .bind(first.id)
+        .fetch_one(pool)
+        .await?;
+        let Some(median) = median else {
+            continue;
+        };
+
+        for (event, amount) in events {
+            if is_large_transfer(amount, median, samples) {
+                out.push(Anomaly {
+                    contract_id: contract_id.clone(),
+                    network: network.clone(),
+                    kind: "large_transfer",
+                    topic: Some(topic.clone()),
+                    event_id: Some(event.id),
+                    ledger_sequence: Some(event.ledger_sequence),
+                    details: json!({
+                        "amount": amount,
+                        "median_amount": median,
+                        "multiple_of_median": (amount / median * 10.0).round() / 10.0,
+                    }),
+                    dedupe_key: format!("large_transfer:{}", event.id),
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extracts_token_amounts() {
+        assert_eq!(transfer_amount(&json!(1500)), Some(1500.0));
+        assert_eq!(transfer_amount(&json!("-250")), Some(250.0));
+        assert_eq!(
+            transfer_amount(&json!({ "from": "GA", "amount": "10000000" })),
+            Some(10_000_000.0)
+        );
+        assert_eq!(transfer_amount(&json!({ "to": "GA" })), None);
+        assert_eq!(transfer_amount(&json!({ "amount": { "hi": 0 } })), None);
+        assert_eq!(transfer_amount(&json!("not a number")), None);
+    }
+
+    #[test]
+    fn spikes_need_volume_and_a_jump() {
+        assert!(!is_rate_spike(SPIKE_MIN_EVENTS - 1, 0.0));
+        assert!(is_rate_spike(SPIKE_MIN_EVENTS, 0.0));
+        assert!(!is_rate_spike(100, 40.0));
+        assert!(is_rate_spike(200, 40.0));
+    }
+
+    #[test]
+    fn large_transfers_need_a_baseline() {
+        assert!(!is_large_transfer(
+            1_000.0,
+            10.0,
+            LARGE_TRANSFER_MIN_SAMPLES - 1
+        ));
+        assert!(is_large_transfer(1_000.0, 10.0, LARGE_TRANSFER_MIN_SAMPLES));
+        assert!(!is_large_transfer(99.0, 10.0, 100));
+        assert!(!is_large_transfer(1_000.0, 0.0, 100));
+    }
+}
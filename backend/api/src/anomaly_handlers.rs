@@ -0,0 +1,117 @@
+//! Event anomalies for a contract
+//!
+//!   GET /api/contracts/:id/anomalies  – most recent anomaly flags first
+//!
+//! Anomalies are detected on ingestion by [`crate::anomalies`].
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const KINDS: [&str; 3] = ["rate_spike", "new_topic", "large_transfer"];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct AnomalyQuery {
+    /// Only this kind: rate_spike, new_topic or large_transfer
+    pub kind: Option<String>,
+    /// Only anomalies detected at or after this time
+    pub since: Option<DateTime<Utc>>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractAnomaly {
+    pub id: Uuid,
+    pub kind: String,
+    pub topic: Option<String>,
+    /// Event that triggered the flag, when it concerns a single event
+    pub event_id: Option<Uuid>,
+    pub ledger_sequence: Option<i64>,
+    pub details: serde_json::Value,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ContractAnomaliesResponse {
+    pub contract_id: String,
+    pub network: String,
+    pub anomalies: Vec<ContractAnomaly>,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/anomalies",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        AnomalyQuery
+    ),
+    responses(
+        (status = 200, description = "Anomalies flagged on the contract's events", body = ContractAnomaliesResponse),
+        (status = 400, description = "Unknown anomaly kind"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_contract_anomalies(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<AnomalyQuery>,
+) -> ApiResult<Json<ContractAnomaliesResponse>> {
+    if let Some(kind) = query.kind.as_deref() {
+        if !KINDS.contains(&kind) {
+            return Err(ApiError::bad_request(
+                "InvalidAnomalyKind",
+                format!("kind must be one of: {}", KINDS.join(", ")),
+            ));
+        }
+    }
+
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let (contract_id, network): (String, String) =
+        sqlx::query_as("SELECT contract_id, network::TEXT FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract for anomalies", err))?;
+
+    let anomalies: Vec<ContractAnomaly> = sqlx::query_as(
+        "SELECT id, kind, topic, event_id, ledger_sequence, details, detected_at
+         FROM contract_event_anomalies
+         WHERE contract_id = $1 AND network = $2::network_type
+           AND ($3::TEXT IS NULL OR kind = $3)
+           AND ($4::TIMESTAMPTZ IS NULL OR detected_at >= $4)
+         ORDER BY detected_at DESC
+         LIMIT $5",
+    )
+    .bind(&contract_id)
+    .bind(&network)
+    .bind(query.kind.as_deref())
+    .bind(query.since)
+    .bind(query.limit.unwrap_or(50).clamp(1, 500))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list contract anomalies", err))?;
+
+    Ok(Json(ContractAnomaliesResponse {
+        contract_id,
+        network,
+        anomalies,
+    }))
+}
@@ -0,0 +1,250 @@
+//! Assembles the API from its parts: shared state, background workers and the
+//! HTTP router. `main` serves the result; `registry-testkit` builds the same
+//! app in-process for integration tests.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use axum::extract::{DefaultBodyLimit, Request, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::{middleware, Router};
+use prometheus::Registry;
+use sqlx::PgPool;
+use tower_http::decompression::RequestDecompressionLayer;
+
+use crate::error::ApiError;
+use crate::rate_limit::RateLimitState;
+use crate::state::AppState;
+use crate::{
+    activity_feed_routes, aggregation, alerts, anchor, anomalies, artifact_gc, build_meta,
+    compression, consumers, cost_regressions, db_monitoring, defi, graphql, handlers,
+    health_monitor, http_security, idempotency, incident_routes, job_queue, multisig_notifications,
+    multisig_routes, nft_index, oracles, patch_rollout, publisher_key_handlers, quality,
+    rate_limit, release_notes_routes, request_tracing, reverification, routes, soft_delete,
+    token_meta, undo, validation,
+};
+
+async fn track_in_flight_middleware(
+    State(state): State<AppState>,
+    req: Request,
+    next: middleware::Next,
+) -> Result<Response, ApiError> {
+    if state.is_shutting_down.load(Ordering::Relaxed) {
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "SERVICE_UNAVAILABLE",
+            "Service is shutting down and temporarily unavailable",
+        ));
+    }
+    crate::metrics::HTTP_IN_FLIGHT.inc();
+    let res = next.run(req).await;
+    crate::metrics::HTTP_IN_FLIGHT.dec();
+    Ok(res)
+}
+
+/// Shared state over `pool`, with a fresh metrics registry and a running batch
+/// job engine. Reads the cache, auth and storage settings from the environment.
+pub async fn build_state(
+    pool: PgPool,
+    is_shutting_down: Arc<AtomicBool>,
+) -> anyhow::Result<AppState> {
+    let registry = Registry::new();
+    if let Err(e) = crate::metrics::register_all(&registry) {
+        tracing::error!("Failed to register metrics: {}", e);
+    }
+
+    let (job_engine, job_rx) = soroban_batch::engine::JobEngine::new();
+    let job_engine = Arc::new(job_engine);
+    let je = job_engine.clone();
+    tokio::spawn(async move { je.run_worker(job_rx).await });
+
+    Ok(AppState::new(pool, registry, job_engine, is_shutting_down).await?)
+}
+
+/// Starts the job queue workers, schedulers and monitors that run alongside
+/// the HTTP server.
+pub async fn spawn_background_tasks(state: &AppState, job_workers: usize) -> anyhow::Result<()> {
+    let pool = state.db.clone();
+
+    // Spawn the hourly analytics aggregation background task
+    aggregation::spawn_aggregation_task(pool.clone());
+
+    // Purge expired Idempotency-Key records
+    idempotency::spawn_cleanup_task(pool.clone());
+
+    // Persistent background job queue workers
+    let artifact_storage = Arc::new(shared::source_storage::SourceStorage::new().await?);
+    let job_registry = job_queue::JobRegistry::new()
+        .register(
+            soft_delete::PURGE_JOB_KIND,
+            soft_delete::PurgeSoftDeletedJob,
+        )
+        .register(anchor::ANCHOR_JOB_KIND, anchor::AnchorContractJob)
+        .register(undo::DEFERRED_ACTION_JOB_KIND, undo::DeferredActionJob)
+        .register(
+            reverification::REVERIFY_JOB_KIND,
+            reverification::AdvisoryReverificationJob,
+        )
+        .register(
+            consumers::NOTIFY_JOB_KIND,
+            consumers::ConsumerNotificationJob,
+        )
+        .register(
+            multisig_notifications::NOTIFY_JOB_KIND,
+            multisig_notifications::MultisigNotificationJob,
+        )
+        .register(quality::QUALITY_JOB_KIND, quality::QualityScoreJob)
+        .register(
+            cost_regressions::REGRESSION_JOB_KIND,
+            cost_regressions::CostRegressionJob,
+        )
+        .register(
+            artifact_gc::GC_JOB_KIND,
+            artifact_gc::ArtifactGcJob {
+                storage: artifact_storage,
+            },
+        );
+    job_queue::spawn_job_workers(pool.clone(), job_registry, job_workers);
+
+    // Hard-delete soft-deleted records once SOFT_DELETE_RETENTION_DAYS passes
+    soft_delete::spawn_purge_scheduler(pool.clone());
+
+    // Delete stored artifacts left unreferenced for ARTIFACT_GC_GRACE_HOURS
+    artifact_gc::spawn_gc_scheduler(pool.clone());
+
+    // Evaluate user-defined alert rules against newly ingested contract events
+    alerts::spawn_alert_evaluator(pool.clone());
+
+    // Flag rate spikes, new topics and large transfers in ingested events
+    anomalies::spawn_anomaly_detector(pool.clone());
+
+    // Track oracle feed updates and alert subscribers when a feed goes stale
+    oracles::spawn_oracle_monitor(pool.clone());
+
+    // Index NFT mint, transfer and burn events into per-token ownership
+    nft_index::spawn_nft_indexer(pool.clone());
+
+    // Read AMM reserves and fees over RPC and estimate each pool's TVL
+    defi::spawn_defi_refresher(pool.clone());
+
+    // Read the SDK, rustc and protocol versions embedded in published WASMs
+    build_meta::spawn_build_meta_reader(pool.clone());
+
+    // Read SEP-41 token names, symbols and decimals for contract summaries
+    token_meta::spawn_token_meta_reader(pool.clone());
+
+    // Apply scheduled security patch rollouts inside their maintenance windows
+    patch_rollout::spawn_patch_rollout_scheduler(pool.clone());
+
+    // Recompute contract quality scores daily
+    quality::spawn_quality_scheduler(pool.clone());
+
+    // Spawn the background DB and cache monitoring task
+    db_monitoring::spawn_db_monitoring_task(pool.clone(), state.cache.clone());
+
+    // Spawn the health monitor background task (Issue #333)
+    let hm_state = state.clone();
+    let hm_status = state.health_monitor_status.clone();
+    tokio::spawn(async move {
+        health_monitor::run_health_monitor(hm_state, hm_status).await;
+    });
+
+    let network_state = state.clone();
+    tokio::spawn(async move {
+        handlers::run_network_catalog_refresh(network_state).await;
+    });
+
+    let key_window_state = state.clone();
+    tokio::spawn(async move {
+        publisher_key_handlers::run_key_window_refresh(key_window_state).await;
+    });
+
+    // Warm up the cache
+    state.cache.clone().warm_up(pool);
+
+    Ok(())
+}
+
+/// The full HTTP router with its middleware stack. CORS, security header,
+/// compression and rate limit settings come from the environment.
+pub fn router(state: AppState) -> anyhow::Result<Router> {
+    // Initialize GraphQL schema
+    let schema = graphql::schema::build_schema(state.clone());
+
+    let rate_limit_state = RateLimitState::from_env();
+    rate_limit_state.spawn_eviction_task();
+
+    let cors = http_security::CorsConfig::from_env()?.layer();
+    let security_headers = Arc::new(http_security::SecurityHeadersConfig::from_env()?);
+    let compression = compression::CompressionConfig::from_env()?.layer();
+
+    Ok(Router::new()
+        .merge(routes::auth_routes())
+        .merge(routes::organization_routes())
+        .merge(routes::alert_routes())
+        .merge(routes::contract_routes())
+        .merge(routes::publisher_routes())
+        .merge(routes::tenant_routes())
+        .merge(routes::contributor_routes())
+        .merge(routes::health_routes())
+        .merge(routes::migration_routes())
+        .merge(incident_routes::incident_routes())
+        .merge(routes::network_routes())
+        .merge(routes::template_routes())
+        .merge(routes::openapi_routes())
+        .merge(routes::health_monitor_routes())
+        .merge(routes::admin_routes())
+        .merge(routes::category_routes())
+        .merge(routes::compatibility_dashboard_routes())
+        .merge(routes::canary_routes())
+        .merge(routes::ab_test_routes())
+        .merge(routes::performance_routes())
+        .merge(routes::federation_routes())
+        .merge(multisig_routes::routes())
+        .merge(routes::observability_routes())
+        .merge(routes::websocket_routes())
+        .merge(routes::validator_routes())
+        .merge(release_notes_routes::release_notes_routes())
+        .route(
+            "/api/graphql",
+            axum::routing::post(graphql::graphql_handler).with_state(schema),
+        )
+        .route(
+            "/api/graphql/playground",
+            axum::routing::get(graphql::graphql_playground),
+        )
+        .nest("/api", activity_feed_routes::routes())
+        .fallback(handlers::route_not_found)
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            idempotency::idempotency_middleware,
+        ))
+        .layer(middleware::from_fn(
+            validation::payload_size::payload_size_validation_middleware,
+        ))
+        // Routes with their own `DefaultBodyLimit` (uploads, restore) override this.
+        .layer(DefaultBodyLimit::max(
+            validation::payload_size::get_max_payload_bytes() as usize,
+        ))
+        .layer(middleware::from_fn(
+            validation::enhanced_extractors::validation_failure_tracking_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            track_in_flight_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            rate_limit_state,
+            rate_limit::rate_limit_middleware,
+        ))
+        .layer(middleware::from_fn_with_state(
+            security_headers,
+            http_security::security_headers_middleware,
+        ))
+        .layer(cors)
+        .layer(compression)
+        .layer(RequestDecompressionLayer::new())
+        .layer(middleware::from_fn(request_tracing::tracing_middleware))
+        .with_state(state))
+}
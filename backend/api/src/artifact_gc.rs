@@ -0,0 +1,294 @@
+//! Garbage collection of orphaned stored artifacts
+//!
+//! Uploaded sources, verification bundles and audit reports live in source
+//! storage (local disk, S3 or GCS) and are referenced by `contract_sources`
+//! and `contract_audits` rows. The objects outlive their rows when a purged
+//! contract takes its versions' rows with it, when a bundle is re-archived
+//! under a new key, or when an upload fails after storing its object.
+//! Yanked versions keep their artifacts, since deployments still resolve
+//! them.
+//!
+//! [`spawn_gc_scheduler`] queues an `artifact_gc` job every six hours. A run
+//! lists the storage backend, records objects no row references in
+//! `orphaned_artifacts`, and deletes those that have stayed unreferenced for
+//! the grace period. An object referenced again in the meantime (after a
+//! registry restore, say) is forgotten instead. The grace period also covers
+//! uploads between storing their object and inserting their row.
+//!
+//! - ARTIFACT_GC_GRACE_HOURS: how long an object stays unreferenced before
+//!   it is deleted (default 72)
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shared::source_storage::{SourceStorage, StoredArtifact};
+use sqlx::PgPool;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const GC_JOB_KIND: &str = "artifact_gc";
+const DEFAULT_GRACE_HOURS: i64 = 72;
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(6 * 3600);
+/// Deletions per run; the rest wait for the next one.
+const DELETE_BATCH: usize = 1000;
+
+pub fn grace_hours() -> i64 {
+    std::env::var("ARTIFACT_GC_GRACE_HOURS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|h| *h >= 0)
+        .unwrap_or(DEFAULT_GRACE_HOURS)
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct OrphanedArtifact {
+    pub storage_backend: String,
+    pub storage_key: String,
+    pub size_bytes: i64,
+    /// When a collection run first found the object unreferenced; absent
+    /// until the next run records it
+    pub first_seen_at: Option<DateTime<Utc>>,
+    /// When a run may delete it
+    pub delete_after: Option<DateTime<Utc>>,
+    /// The next run deletes it
+    pub due: bool,
+}
+
+/// The objects in `listed` that nothing references, oldest orphans first.
+fn find_orphans(
+    listed: Vec<StoredArtifact>,
+    referenced: &HashSet<(String, String)>,
+    recorded: &HashMap<(String, String), DateTime<Utc>>,
+    grace: chrono::Duration,
+    now: DateTime<Utc>,
+) -> Vec<OrphanedArtifact> {
+    let mut orphans: Vec<OrphanedArtifact> = listed
+        .into_iter()
+        .filter_map(|artifact| {
+            let id = (artifact.storage_backend, artifact.storage_key);
+            if referenced.contains(&id) {
+                return None;
+            }
+            let first_seen_at = recorded.get(&id).copied();
+            let delete_after = first_seen_at.map(|seen| seen + grace);
+            Some(OrphanedArtifact {
+                storage_backend: id.0,
+                storage_key: id.1,
+                size_bytes: artifact.size as i64,
+                first_seen_at,
+                delete_after,
+                due: delete_after.is_some_and(|after| after <= now),
+            })
+        })
+        .collect();
+    orphans.sort_by(|a, b| {
+        (a.first_seen_at.is_none(), a.first_seen_at, &a.storage_key).cmp(&(
+            b.first_seen_at.is_none(),
+            b.first_seen_at,
+            &b.storage_key,
+        ))
+    });
+    orphans
+}
+
+async fn referenced_keys(pool: &PgPool) -> Result<HashSet<(String, String)>, sqlx::Error> {
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT storage_backend::TEXT, storage_key FROM contract_sources
+         UNION
+         SELECT storage_backend, storage_key FROM contract_audits",
+    )
+    .fetch_all(pool)
+    .await?;
+    Ok(rows.into_iter().collect())
+}
+
+/// Every unreferenced object in storage, with when it was first recorded.
+/// Changes nothing; this is what the admin dry run shows.
+pub async fn orphaned_artifacts(
+    pool: &PgPool,
+    storage: &SourceStorage,
+) -> anyhow::Result<Vec<OrphanedArtifact>> {
+    // References are read after listing, so an upload that finishes in
+    // between is seen as referenced rather than orphaned.
+    let listed = storage.list_artifacts().await?;
+    let referenced = referenced_keys(pool).await?;
+    let recorded: Vec<(String, String, DateTime<Utc>)> = sqlx::query_as(
+        "SELECT storage_backend, storage_key, first_seen_at FROM orphaned_artifacts",
+    )
+    .fetch_all(pool)
+    .await?;
+    let recorded = recorded
+        .into_iter()
+        .map(|(backend, key, seen)| ((backend, key), seen))
+        .collect();
+    Ok(find_orphans(
+        listed,
+        &referenced,
+        &recorded,
+        chrono::Duration::hours(grace_hours()),
+        Utc::now(),
+    ))
+}
+
+async fn is_referenced(pool: &PgPool, backend: &str, key: &str) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contract_sources
+                       WHERE storage_backend::TEXT = $1 AND storage_key = $2)
+             OR EXISTS(SELECT 1 FROM contract_audits
+                       WHERE storage_backend = $1 AND storage_key = $2)",
+    )
+    .bind(backend)
+    .bind(key)
+    .fetch_one(pool)
+    .await
+}
+
+pub struct ArtifactGcJob {
+    pub storage: Arc<SourceStorage>,
+}
+
+#[async_trait]
+impl JobHandler for ArtifactGcJob {
+    async fn handle(&self, pool: &PgPool, _job: &Job) -> anyhow::Result<()> {
+        let orphans = orphaned_artifacts(pool, &self.storage).await?;
+        let backends: Vec<&str> = orphans.iter().map(|o| o.storage_backend.as_str()).collect();
+        let keys: Vec<&str> = orphans.iter().map(|o| o.storage_key.as_str()).collect();
+        let sizes: Vec<i64> = orphans.iter().map(|o| o.size_bytes).collect();
+
+        // Forget objects that are referenced again or gone, record new ones.
+        sqlx::query(
+            "DELETE FROM orphaned_artifacts o
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM UNNEST($1::TEXT[], $2::TEXT[]) AS c(backend, key)
+                 WHERE c.backend = o.storage_backend AND c.key = o.storage_key)",
+        )
+        .bind(&backends)
+        .bind(&keys)
+        .execute(pool)
+        .await?;
+        sqlx::query(
+            "INSERT INTO orphaned_artifacts (storage_backend, storage_key, size_bytes)
+             SELECT * FROM UNNEST($1::TEXT[], $2::TEXT[], $3::BIGINT[])
+             ON CONFLICT DO NOTHING",
+        )
+        .bind(&backends)
+        .bind(&keys)
+        .bind(&sizes)
+        .execute(pool)
+        .await?;
+
+        let (mut deleted, mut freed_bytes, mut failed) = (0u64, 0i64, 0u64);
+        for orphan in orphans.iter().filter(|o| o.due).take(DELETE_BATCH) {
+            let (backend, key) = (&orphan.storage_backend, &orphan.storage_key);
+            // A restore may have brought a reference back since the listing.
+            if is_referenced(pool, backend, key).await? {
+                continue;
+            }
+            if let Err(err) = self.storage.delete_source(backend, key).await {
+                failed += 1;
+                tracing::warn!(error = %err, %backend, %key, "artifact gc: delete failed");
+                continue;
+            }
+            sqlx::query(
+                "DELETE FROM orphaned_artifacts WHERE storage_backend = $1 AND storage_key = $2",
+            )
+            .bind(backend)
+            .bind(key)
+            .execute(pool)
+            .await?;
+            deleted += 1;
+            freed_bytes += orphan.size_bytes;
+        }
+        tracing::info!(
+            orphaned = orphans.len(),
+            deleted,
+            freed_bytes,
+            failed,
+            "artifact gc finished"
+        );
+        Ok(())
+    }
+}
+
+/// Queue a collection every six hours unless one is already pending.
+pub fn spawn_gc_scheduler(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let pending: Result<bool, _> = sqlx::query_scalar(
+                "SELECT EXISTS(SELECT 1 FROM background_jobs \
+                 WHERE kind = $1 AND status IN ('queued', 'running', 'failed'))",
+            )
+            .bind(GC_JOB_KIND)
+            .fetch_one(&pool)
+            .await;
+            match pending {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(err) =
+                        job_queue::enqueue(&pool, GC_JOB_KIND, serde_json::json!({})).await
+                    {
+                        tracing::error!(error = ?err, "artifact gc: failed to queue job");
+                    }
+                }
+                Err(err) => tracing::error!(error = ?err, "artifact gc: schedule check failed"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stored(key: &str) -> StoredArtifact {
+        StoredArtifact {
+            storage_backend: "local".to_string(),
+            storage_key: key.to_string(),
+            size: 10,
+        }
+    }
+
+    fn id(key: &str) -> (String, String) {
+        ("local".to_string(), key.to_string())
+    }
+
+    #[test]
+    fn orphans_become_due_after_the_grace_period() {
+        let now = Utc::now();
+        let grace = chrono::Duration::hours(72);
+        let referenced = HashSet::from([id("kept")]);
+        let recorded = HashMap::from([
+            (id("old"), now - chrono::Duration::hours(100)),
+            (id("recent"), now - chrono::Duration::hours(1)),
+            (id("kept"), now - chrono::Duration::hours(100)),
+        ]);
+
+        let orphans = find_orphans(
+            vec![
+                stored("kept"),
+                stored("new"),
+                stored("recent"),
+                stored("old"),
+            ],
+            &referenced,
+            &recorded,
+            grace,
+            now,
+        );
+        let keys: Vec<&str> = orphans.iter().map(|o| o.storage_key.as_str()).collect();
+        assert_eq!(keys, vec!["old", "recent", "new"]);
+        let due: Vec<bool> = orphans.iter().map(|o| o.due).collect();
+        assert_eq!(due, vec![true, false, false]);
+        assert_eq!(orphans[2].first_seen_at, None);
+        assert_eq!(
+            orphans[1].delete_after,
+            Some(now - chrono::Duration::hours(1) + grace)
+        );
+    }
+}
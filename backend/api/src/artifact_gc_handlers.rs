@@ -0,0 +1,65 @@
+//! Admin dry run of artifact garbage collection
+//!
+//!   GET /api/admin/artifacts/orphaned – stored objects nothing references,
+//!                                       and when the GC job will delete them
+//!
+//! Lists what the `artifact_gc` job would act on without recording or
+//! deleting anything (see [`crate::artifact_gc`]). Objects without
+//! `first_seen_at` are recorded by the next run and deleted a grace period
+//! after that.
+
+use axum::{extract::State, Json};
+use serde::Serialize;
+
+use crate::{
+    artifact_gc::{self, OrphanedArtifact},
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct OrphanedArtifactsReport {
+    pub grace_hours: i64,
+    pub total_bytes: i64,
+    /// Bytes the next run will reclaim
+    pub due_bytes: i64,
+    /// Oldest orphans first
+    pub artifacts: Vec<OrphanedArtifact>,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/artifacts/orphaned",
+    responses(
+        (status = 200, description = "Garbage collection candidates", body = OrphanedArtifactsReport),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Admin only"),
+        (status = 500, description = "Source storage unavailable")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Admin"
+)]
+pub async fn list_orphaned_artifacts(
+    State(state): State<AppState>,
+) -> ApiResult<Json<OrphanedArtifactsReport>> {
+    let artifacts = artifact_gc::orphaned_artifacts(&state.db, &state.source_storage)
+        .await
+        .map_err(|err| {
+            tracing::error!(error = ?err, "artifact gc dry run failed");
+            ApiError::internal("Failed to list orphaned artifacts")
+        })?;
+    Ok(Json(OrphanedArtifactsReport {
+        grace_hours: artifact_gc::grace_hours(),
+        total_bytes: artifacts.iter().map(|a| a.size_bytes).sum(),
+        due_bytes: artifacts
+            .iter()
+            .filter(|a| a.due)
+            .map(|a| a.size_bytes)
+            .sum(),
+        artifacts,
+    }))
+}
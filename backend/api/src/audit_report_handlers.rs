@@ -0,0 +1,470 @@
+//! Audit reports attached to contract versions
+//!
+//!   POST /api/contracts/:id/audits                    – attach a report (auditors only)
+//!   GET  /api/contracts/:id/audits                    – reports with their findings summaries
+//!   GET  /api/contracts/:id/audits/:audit_id/report   – download the report document
+//!
+//! Auditors are publishers an admin has recognised as attestation
+//! authorities for the `audited-by` badge (see [`crate::badge_handlers`]).
+//! A report is a PDF or Markdown document for one version plus the number
+//! of findings per severity, so users can judge an audit without opening
+//! it. Documents live in source storage and are integrity-checked against
+//! their SHA-256 on download.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{attestation::AUDITED_BY, source_storage::SourceFormat};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    badge_handlers::authority_for,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+/// Largest report document accepted.
+pub const MAX_REPORT_BYTES: usize = 10 * 1024 * 1024;
+/// Request body limit for attaching a report: the base64-encoded document
+/// plus room for the metadata.
+pub const ATTACH_BODY_LIMIT: usize = MAX_REPORT_BYTES / 3 * 4 + 64 * 1024;
+const MAX_TITLE_LEN: usize = 200;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+/// Number of findings per severity.
+#[derive(
+    Debug, Clone, Default, PartialEq, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema,
+)]
+pub struct FindingCounts {
+    #[serde(default)]
+    #[sqlx(rename = "critical_count")]
+    pub critical: i32,
+    #[serde(default)]
+    #[sqlx(rename = "high_count")]
+    pub high: i32,
+    #[serde(default)]
+    #[sqlx(rename = "medium_count")]
+    pub medium: i32,
+    #[serde(default)]
+    #[sqlx(rename = "low_count")]
+    pub low: i32,
+    #[serde(default)]
+    #[sqlx(rename = "informational_count")]
+    pub informational: i32,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct AttachAuditRequest {
+    /// Version of the contract that was audited
+    pub version: String,
+    pub title: String,
+    /// Short plain-text summary of the outcome
+    pub summary: Option<String>,
+    /// `pdf` or `markdown`
+    pub report_format: String,
+    /// Base64-encoded report document
+    pub report: String,
+    #[serde(default)]
+    pub findings: FindingCounts,
+    /// When the audit was completed; defaults to now
+    pub audited_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListAuditsQuery {
+    /// Only reports for this version
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractAudit {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub version: String,
+    pub auditor_id: Uuid,
+    pub auditor_name: String,
+    pub auditor_address: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub report_format: String,
+    /// SHA-256 of the report document
+    pub report_sha256: String,
+    pub report_size: i64,
+    #[sqlx(flatten)]
+    pub findings: FindingCounts,
+    pub audited_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct StoredReport {
+    onchain_id: String,
+    version: String,
+    report_format: String,
+    storage_backend: String,
+    storage_key: String,
+    report_sha256: String,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+const AUDIT_SELECT: &str = "SELECT ca.id, ca.contract_id, v.version, ca.authority_id AS auditor_id,
+            a.name AS auditor_name, p.stellar_address AS auditor_address, ca.title,
+            ca.summary, ca.report_format, ca.report_sha256, ca.report_size,
+            ca.critical_count, ca.high_count, ca.medium_count, ca.low_count,
+            ca.informational_count, ca.audited_at, ca.created_at
+       FROM contract_audits ca
+       JOIN contract_versions v ON v.id = ca.contract_version_id
+       JOIN attestation_authorities a ON a.id = ca.authority_id
+       JOIN publishers p ON p.id = a.publisher_id";
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidAuditReport", message)
+}
+
+fn already_attached() -> ApiError {
+    ApiError::conflict(
+        "AuditReportExists",
+        "This report is already attached to that version",
+    )
+}
+
+fn content_type(report_format: &str) -> &'static str {
+    match report_format {
+        "pdf" => "application/pdf",
+        _ => "text/markdown; charset=utf-8",
+    }
+}
+
+fn extension(report_format: &str) -> &'static str {
+    match report_format {
+        "pdf" => "pdf",
+        _ => "md",
+    }
+}
+
+/// Decodes the report and checks it is the document it claims to be.
+fn decode_report(report_format: &str, encoded: &str) -> Result<Vec<u8>, String> {
+    let bytes = BASE64
+        .decode(encoded.trim())
+        .map_err(|_| "report must be base64-encoded".to_string())?;
+    if bytes.is_empty() {
+        return Err("report is empty".to_string());
+    }
+    if bytes.len() > MAX_REPORT_BYTES {
+        return Err(format!(
+            "report is {} bytes; the limit is {}",
+            bytes.len(),
+            MAX_REPORT_BYTES
+        ));
+    }
+    match report_format {
+        "pdf" if !bytes.starts_with(b"%PDF-") => Err("report is not a PDF document".to_string()),
+        "markdown" if std::str::from_utf8(&bytes).is_err() => {
+            Err("Markdown report must be UTF-8 text".to_string())
+        }
+        "pdf" | "markdown" => Ok(bytes),
+        other => Err(format!(
+            "Unknown report_format '{}'; expected pdf or markdown",
+            other
+        )),
+    }
+}
+
+fn validate_request(req: &AttachAuditRequest) -> ApiResult<()> {
+    let title = req.title.trim();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LEN {
+        return Err(invalid(format!(
+            "title must be between 1 and {} characters",
+            MAX_TITLE_LEN
+        )));
+    }
+    let f = &req.findings;
+    if [f.critical, f.high, f.medium, f.low, f.informational]
+        .iter()
+        .any(|count| *count < 0)
+    {
+        return Err(invalid("finding counts cannot be negative"));
+    }
+    if req.audited_at.is_some_and(|at| at > Utc::now()) {
+        return Err(invalid("audited_at is in the future"));
+    }
+    Ok(())
+}
+
+async fn fetch_audit(state: &AppState, audit_id: Uuid) -> ApiResult<ContractAudit> {
+    sqlx::query_as(&format!("{} WHERE ca.id = $1", AUDIT_SELECT))
+        .bind(audit_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch audit report", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/audits",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = AttachAuditRequest,
+    responses(
+        (status = 201, description = "Report attached", body = ContractAudit),
+        (status = 400, description = "Invalid report or findings"),
+        (status = 403, description = "Caller is not a recognised auditor"),
+        (status = 404, description = "Contract or version not found"),
+        (status = 409, description = "The same report is already attached")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Audits"
+)]
+pub async fn attach_audit(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<AttachAuditRequest>,
+) -> ApiResult<(StatusCode, Json<ContractAudit>)> {
+    validate_request(&req)?;
+    let report = decode_report(&req.report_format, &req.report).map_err(invalid)?;
+    let authority_id = authority_for(&state, &claims.sub, AUDITED_BY).await?;
+
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let version: Option<(Uuid, String)> = sqlx::query_as(
+        "SELECT v.id, c.contract_id
+           FROM contract_versions v
+           JOIN contracts c ON c.id = v.contract_id
+          WHERE v.contract_id = $1 AND v.version = $2",
+    )
+    .bind(contract_uuid)
+    .bind(&req.version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch audited version", err))?;
+    let Some((version_id, onchain_id)) = version else {
+        return Err(ApiError::not_found(
+            "VersionNotFound",
+            format!("Version '{}' not found for this contract", req.version),
+        ));
+    };
+
+    // Check for a duplicate before storing, so a re-upload leaves no orphan.
+    let duplicate: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contract_audits
+          WHERE contract_version_id = $1 AND authority_id = $2 AND report_sha256 = $3)",
+    )
+    .bind(version_id)
+    .bind(authority_id)
+    .bind(shared::source_storage::compute_sha256(&report))
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check duplicate audit report", err))?;
+    if duplicate {
+        return Err(already_attached());
+    }
+
+    let (backend, storage_key, report_sha256) = state
+        .source_storage
+        .store_source(
+            &onchain_id,
+            &req.version,
+            SourceFormat::AuditReport,
+            &report,
+        )
+        .await
+        .map_err(|e| ApiError::internal(format!("source storage error: {}", e)))?;
+
+    let f = &req.findings;
+    let audit_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_audits
+            (contract_id, contract_version_id, authority_id, title, summary, report_format,
+             storage_backend, storage_key, report_sha256, report_size, critical_count,
+             high_count, medium_count, low_count, informational_count, audited_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+         RETURNING id",
+    )
+    .bind(contract_uuid)
+    .bind(version_id)
+    .bind(authority_id)
+    .bind(req.title.trim())
+    .bind(
+        req.summary
+            .as_deref()
+            .map(str::trim)
+            .filter(|s| !s.is_empty()),
+    )
+    .bind(&req.report_format)
+    .bind(&backend)
+    .bind(&storage_key)
+    .bind(&report_sha256)
+    .bind(report.len() as i64)
+    .bind(f.critical)
+    .bind(f.high)
+    .bind(f.medium)
+    .bind(f.low)
+    .bind(f.informational)
+    .bind(req.audited_at.unwrap_or_else(Utc::now))
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => already_attached(),
+        _ => db_internal_error("insert audit report", err),
+    })?;
+
+    let audit = fetch_audit(&state, audit_id).await?;
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %audit.version,
+        auditor = %audit.auditor_address,
+        critical = f.critical,
+        high = f.high,
+        "audit report attached"
+    );
+    Ok((StatusCode::CREATED, Json(audit)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/audits",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ListAuditsQuery
+    ),
+    responses(
+        (status = 200, description = "Audit reports, newest first", body = [ContractAudit]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Audits"
+)]
+pub async fn list_audits(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<ListAuditsQuery>,
+) -> ApiResult<Json<Vec<ContractAudit>>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let audits = sqlx::query_as(&format!(
+        "{} WHERE ca.contract_id = $1 AND ($2::TEXT IS NULL OR v.version = $2)
+          ORDER BY ca.audited_at DESC, ca.created_at DESC",
+        AUDIT_SELECT
+    ))
+    .bind(contract_uuid)
+    .bind(&query.version)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list audit reports", err))?;
+    Ok(Json(audits))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/audits/{audit_id}/report",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("audit_id" = Uuid, Path, description = "Audit report ID")
+    ),
+    responses(
+        (status = 200, description = "The report document (PDF or Markdown)"),
+        (status = 404, description = "Contract or audit report not found"),
+        (status = 500, description = "Integrity verification failed")
+    ),
+    tag = "Audits"
+)]
+pub async fn get_audit_report(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path((id, audit_id)): Path<(String, Uuid)>,
+) -> ApiResult<Response> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let stored: StoredReport = sqlx::query_as(
+        "SELECT c.contract_id AS onchain_id, v.version, ca.report_format, ca.storage_backend,
+                ca.storage_key, ca.report_sha256
+           FROM contract_audits ca
+           JOIN contract_versions v ON v.id = ca.contract_version_id
+           JOIN contracts c ON c.id = ca.contract_id
+          WHERE ca.id = $1 AND ca.contract_id = $2",
+    )
+    .bind(audit_id)
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch audit report", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "AuditReportNotFound",
+            format!("No audit report found with ID: {}", audit_id),
+        )
+    })?;
+
+    let report = state
+        .source_storage
+        .retrieve_source(&stored.storage_backend, &stored.storage_key)
+        .await
+        .map_err(|e| ApiError::internal(format!("source storage error: {}", e)))?;
+    if shared::source_storage::compute_sha256(&report) != stored.report_sha256 {
+        return Err(ApiError::internal("Audit report integrity check failed"));
+    }
+
+    let filename = format!(
+        "{}-{}-audit.{}",
+        stored.onchain_id,
+        stored.version,
+        extension(&stored.report_format)
+    );
+    Ok((
+        [
+            (
+                header::CONTENT_TYPE,
+                content_type(&stored.report_format).to_string(),
+            ),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+            (header::ETAG, format!("\"{}\"", stored.report_sha256)),
+        ],
+        report,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_pdf_and_markdown_documents() {
+        let pdf = BASE64.encode(b"%PDF-1.7\n...");
+        assert!(decode_report("pdf", &pdf).is_ok());
+        let md = BASE64.encode("# Audit\n\nNo critical findings.");
+        assert!(decode_report("markdown", &md).is_ok());
+    }
+
+    #[test]
+    fn rejects_mislabelled_or_oversized_reports() {
+        let md = BASE64.encode("# Audit");
+        assert!(decode_report("pdf", &md).is_err());
+        assert!(decode_report("markdown", &BASE64.encode([0xff, 0xfe])).is_err());
+        assert!(decode_report("docx", &md).is_err());
+        assert!(decode_report("markdown", "not base64!").is_err());
+
+        let huge = BASE64.encode(vec![b'a'; MAX_REPORT_BYTES + 1]);
+        assert!(decode_report("markdown", &huge).is_err());
+    }
+
+    #[test]
+    fn body_limit_fits_the_largest_report() {
+        let encoded = BASE64.encode(vec![0u8; MAX_REPORT_BYTES]);
+        assert!(encoded.len() < ATTACH_BODY_LIMIT);
+    }
+}
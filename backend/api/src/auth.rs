@@ -6,12 +6,16 @@ use ed25519_dalek::{Signature, Verifier, VerifyingKey};
 use jsonwebtoken::{decode, encode, Algorithm, DecodingKey, EncodingKey, Header, Validation};
 use rand::{distributions::Alphanumeric, Rng};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::{KeyValidity, KeyWindow};
 use std::collections::HashMap;
 use std::fmt;
 
-use crate::error::ApiError;
+use crate::{error::ApiError, state::AppState};
 
 pub const MIN_JWT_SECRET_LEN: usize = 32;
+/// Lifetime of tokens issued for SEP-10 challenges (see `web_auth`).
+pub const SESSION_TOKEN_TTL_SECONDS: i64 = 3600;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuthClaims {
@@ -22,6 +26,32 @@ pub struct AuthClaims {
     pub role: Option<String>,
     #[serde(default)]
     pub admin: bool,
+    /// Tenant slug the token is scoped to (see `tenant`); unset for tokens
+    /// issued by the challenge flow, which follow the request's Host.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant: Option<String>,
+    /// Registry that issued a SEP-10 session token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    /// Hash of the SEP-10 challenge a session token was issued for
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jti: Option<String>,
+    /// Fingerprint of the key that signed the login; the token stops being
+    /// accepted once that key is revoked or leaves its validity window
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_fingerprint: Option<String>,
+}
+
+/// A signed SEP-10 challenge that passed `WebAuthConfig::verify`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifiedChallenge {
+    /// `G...` account that signed the challenge
+    pub account: String,
+    pub public_key: [u8; 32],
+    /// Transaction hash, which identifies the challenge
+    pub hash: [u8; 32],
+    /// Unix time after which the challenge is no longer accepted
+    pub expires_at: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -32,6 +62,12 @@ pub struct ChallengeRecord {
 
 pub struct AuthManager {
     challenges: HashMap<String, ChallengeRecord>,
+    /// SEP-10 challenges already exchanged for a token, with when they
+    /// expire; kept until then so each is used once
+    redeemed_challenges: HashMap<[u8; 32], i64>,
+    /// Windows of registered publisher keys by fingerprint (see
+    /// `publisher_key_handlers`); keys without one are not restricted
+    key_windows: HashMap<String, KeyWindow>,
     encoding_key: EncodingKey,
     decoding_key: DecodingKey,
 }
@@ -64,6 +100,8 @@ impl AuthManager {
     pub fn new(secret: String) -> Self {
         Self {
             challenges: HashMap::new(),
+            redeemed_challenges: HashMap::new(),
+            key_windows: HashMap::new(),
             encoding_key: EncodingKey::from_secret(secret.as_bytes()),
             decoding_key: DecodingKey::from_secret(secret.as_bytes()),
         }
@@ -125,6 +163,7 @@ impl AuthManager {
         let sig = Signature::from_bytes(&signature);
         vk.verify(challenge.nonce.as_bytes(), &sig)
             .map_err(|_| "invalid_signature")?;
+        self.check_key_window(&public_key)?;
         let iat = Utc::now().timestamp();
         let exp = (Utc::now() + Duration::hours(24)).timestamp();
         let claims = AuthClaims {
@@ -133,29 +172,91 @@ impl AuthManager {
             exp,
             role: None,
             admin: false,
+            tenant: None,
+            iss: None,
+            jti: None,
+            key_fingerprint: Some(key_fingerprint(&public_key)),
+        };
+        encode(&Header::default(), &claims, &self.encoding_key).map_err(|_| "jwt_encode_failed")
+    }
+
+    /// Issue a session token for a verified SEP-10 challenge, once per
+    /// challenge.
+    pub fn issue_session_jwt(
+        &mut self,
+        challenge: &VerifiedChallenge,
+        issuer: &str,
+    ) -> Result<String, &'static str> {
+        let now = Utc::now().timestamp();
+        self.redeemed_challenges
+            .retain(|_, expires_at| *expires_at >= now);
+        if self.redeemed_challenges.contains_key(&challenge.hash) {
+            return Err("challenge_already_used");
+        }
+        self.check_key_window(&challenge.public_key)?;
+        self.redeemed_challenges
+            .insert(challenge.hash, challenge.expires_at);
+
+        let claims = AuthClaims {
+            sub: challenge.account.clone(),
+            iat: now,
+            exp: now + SESSION_TOKEN_TTL_SECONDS,
+            role: None,
+            admin: false,
+            tenant: None,
+            iss: Some(issuer.to_string()),
+            jti: Some(hex::encode(challenge.hash)),
+            key_fingerprint: Some(key_fingerprint(&challenge.public_key)),
         };
         encode(&Header::default(), &claims, &self.encoding_key).map_err(|_| "jwt_encode_failed")
     }
 
+    fn check_key_window(&self, public_key: &[u8; 32]) -> Result<(), &'static str> {
+        self.check_fingerprint_window(&key_fingerprint(public_key))
+    }
+
+    fn check_fingerprint_window(&self, fingerprint: &str) -> Result<(), &'static str> {
+        match self.key_windows.get(fingerprint) {
+            None => Ok(()),
+            Some(window) => match window.validity_at(Utc::now()) {
+                KeyValidity::Valid => Ok(()),
+                KeyValidity::Revoked => Err("key_revoked"),
+                KeyValidity::Expired => Err("key_expired"),
+                KeyValidity::NotYetValid => Err("key_not_yet_valid"),
+            },
+        }
+    }
+
+    /// Replaces the registered key windows logins are checked against.
+    pub fn set_key_windows(&mut self, windows: HashMap<[u8; 32], KeyWindow>) {
+        self.key_windows = windows
+            .into_iter()
+            .map(|(public_key, window)| (key_fingerprint(&public_key), window))
+            .collect();
+    }
+
+    /// Decodes `token`, rejecting it if it expired or the key its login was
+    /// signed with is no longer usable.
     pub fn validate_jwt(&self, token: &str) -> Result<AuthClaims, &'static str> {
         let mut validation = Validation::new(Algorithm::HS256);
         validation.validate_exp = true;
-        decode::<AuthClaims>(token, &self.decoding_key, &validation)
+        let claims = decode::<AuthClaims>(token, &self.decoding_key, &validation)
             .map(|data| data.claims)
-            .map_err(|_| "invalid_token")
+            .map_err(|_| "invalid_token")?;
+        if let Some(fingerprint) = &claims.key_fingerprint {
+            self.check_fingerprint_window(fingerprint)?;
+        }
+        Ok(claims)
     }
 }
 
 #[axum::async_trait]
-impl<S> axum::extract::FromRequestParts<S> for AuthClaims
-where
-    S: Send + Sync,
-{
+impl axum::extract::FromRequestParts<AppState> for AuthClaims {
     type Rejection = StatusCode;
 
     async fn from_request_parts(
         parts: &mut axum::http::request::Parts,
-        _state: &S,
+        state: &AppState,
     ) -> Result<Self, Self::Rejection> {
         let auth_header = parts
             .headers
@@ -164,14 +265,23 @@ where
             .and_then(|v| v.strip_prefix("Bearer "))
             .ok_or(StatusCode::UNAUTHORIZED)?;
 
-        let auth_manager =
-            AuthManager::from_env().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+        // The shared manager holds the key windows, so tokens from revoked
+        // keys are refused.
+        let auth_manager = state
+            .auth_mgr
+            .read()
+            .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
         auth_manager
             .validate_jwt(auth_header)
             .map_err(|_| StatusCode::UNAUTHORIZED)
     }
 }
 
+/// Fingerprint of an ed25519 public key, as `publisher_key_handlers` shows it.
+fn key_fingerprint(public_key: &[u8; 32]) -> String {
+    hex::encode(Sha256::digest(public_key))
+}
+
 fn extract_bearer_token(req: &Request) -> Option<&str> {
     req.headers()
         .get(header::AUTHORIZATION)
@@ -181,7 +291,7 @@ fn extract_bearer_token(req: &Request) -> Option<&str> {
         .filter(|token| !token.is_empty())
 }
 
-fn is_admin(claims: &AuthClaims) -> bool {
+pub(crate) fn is_admin(claims: &AuthClaims) -> bool {
     claims.admin || matches!(claims.role.as_deref(), Some("admin" | "ADMIN" | "Admin"))
 }
 
@@ -278,6 +388,105 @@ mod tests {
         assert!(second.is_err());
     }
 
+    #[test]
+    fn revoked_keys_cannot_log_in() {
+        let mut auth = AuthManager::new("test-secret".to_string());
+        let sk = SigningKey::from_bytes(&[11u8; 32]);
+        let vk_hex = hex_encode(sk.verifying_key().as_bytes());
+        let now = Utc::now();
+        let mut windows = HashMap::new();
+        windows.insert(
+            sk.verifying_key().to_bytes(),
+            KeyWindow {
+                valid_from: now - Duration::days(30),
+                valid_until: None,
+                revoked_at: Some(now - Duration::minutes(1)),
+            },
+        );
+        auth.set_key_windows(windows);
+
+        let nonce = auth.create_challenge(&vk_hex);
+        let sig = hex_encode(&sk.sign(nonce.as_bytes()).to_bytes());
+        assert_eq!(
+            auth.verify_and_issue_jwt(&vk_hex, &vk_hex, &sig),
+            Err("key_revoked")
+        );
+
+        auth.set_key_windows(HashMap::new());
+        let nonce = auth.create_challenge(&vk_hex);
+        let sig = hex_encode(&sk.sign(nonce.as_bytes()).to_bytes());
+        assert!(auth.verify_and_issue_jwt(&vk_hex, &vk_hex, &sig).is_ok());
+    }
+
+    #[test]
+    fn tokens_stop_working_when_their_key_is_revoked() {
+        let mut auth = AuthManager::new("test-secret".to_string());
+        let sk = SigningKey::from_bytes(&[13u8; 32]);
+        let vk_hex = hex_encode(sk.verifying_key().as_bytes());
+        let nonce = auth.create_challenge(&vk_hex);
+        let sig = hex_encode(&sk.sign(nonce.as_bytes()).to_bytes());
+        let token = auth
+            .verify_and_issue_jwt(&vk_hex, &vk_hex, &sig)
+            .expect("jwt must be issued");
+        assert!(auth.validate_jwt(&token).is_ok());
+
+        let now = Utc::now();
+        let mut windows = HashMap::new();
+        windows.insert(
+            sk.verifying_key().to_bytes(),
+            KeyWindow {
+                valid_from: now - Duration::days(30),
+                valid_until: None,
+                revoked_at: Some(now - Duration::seconds(1)),
+            },
+        );
+        auth.set_key_windows(windows.clone());
+        assert_eq!(auth.validate_jwt(&token).err(), Some("key_revoked"));
+
+        // Session tokens from a key that expired since are refused too.
+        let challenge = VerifiedChallenge {
+            account: "GACCOUNT".to_string(),
+            public_key: [14u8; 32],
+            hash: [2u8; 32],
+            expires_at: (now + Duration::minutes(5)).timestamp(),
+        };
+        let session = auth
+            .issue_session_jwt(&challenge, "https://registry.example")
+            .expect("jwt must be issued");
+        windows.insert(
+            [14u8; 32],
+            KeyWindow {
+                valid_from: now - Duration::days(30),
+                valid_until: Some(now - Duration::seconds(1)),
+                revoked_at: None,
+            },
+        );
+        auth.set_key_windows(windows);
+        assert_eq!(auth.validate_jwt(&session).err(), Some("key_expired"));
+    }
+
+    #[test]
+    fn session_tokens_are_issued_once_per_challenge() {
+        let mut auth = AuthManager::new("test-secret".to_string());
+        let challenge = VerifiedChallenge {
+            account: "GACCOUNT".to_string(),
+            public_key: [12u8; 32],
+            hash: [1u8; 32],
+            expires_at: (Utc::now() + Duration::minutes(5)).timestamp(),
+        };
+        let token = auth
+            .issue_session_jwt(&challenge, "https://registry.example")
+            .expect("jwt must be issued");
+        let claims = auth.validate_jwt(&token).expect("token must be valid");
+        assert_eq!(claims.sub, "GACCOUNT");
+        assert_eq!(claims.exp - claims.iat, SESSION_TOKEN_TTL_SECONDS);
+        assert_eq!(claims.jti, Some(hex_encode(&[1u8; 32])));
+        assert_eq!(
+            auth.issue_session_jwt(&challenge, "https://registry.example"),
+            Err("challenge_already_used")
+        );
+    }
+
     #[test]
     fn jwt_secret_length_is_enforced() {
         let too_short = "a".repeat(MIN_JWT_SECRET_LEN - 1);
@@ -1,15 +1,46 @@
 use axum::{
     extract::{Query, State},
     http::StatusCode,
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 
 use crate::{
+    auth::SESSION_TOKEN_TTL_SECONDS,
     error::{ApiError, ApiResult},
     state::AppState,
+    web_auth::{WebAuthConfig, WebAuthError},
 };
 
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct WebAuthChallengeQuery {
+    /// Stellar account (`G...`) to authenticate with SEP-10
+    pub account: Option<String>,
+    /// Home domain the challenge is for; must be the registry's own
+    pub home_domain: Option<String>,
+    /// Hex public key for the older nonce flow (`POST /api/auth/verify`);
+    /// used when `account` is absent
+    pub address: Option<String>,
+}
+
+/// SEP-10 challenge transaction
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct WebAuthChallenge {
+    /// Base64 XDR transaction envelope, signed by the registry, for the
+    /// account to sign
+    pub transaction: String,
+    /// Network passphrase to sign the transaction with
+    pub network_passphrase: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct WebAuthTokenRequest {
+    /// The challenge transaction with the account's signature added
+    pub transaction: String,
+}
+
 #[derive(Debug, Deserialize, utoipa::IntoParams)]
 pub struct ChallengeQuery {
     /// Stellar wallet address to authenticate
@@ -50,13 +81,104 @@ pub struct VerifyResponse {
 #[utoipa::path(
     get,
     path = "/api/auth/challenge",
-    params(ChallengeQuery),
+    params(WebAuthChallengeQuery),
+    responses(
+        (status = 200, description = "SEP-10 challenge for `account`, or a nonce for `address`", body = WebAuthChallenge),
+        (status = 400, description = "Invalid account, address or home domain")
+    ),
+    tag = "Authentication"
+)]
+pub async fn web_auth_challenge(
+    State(state): State<AppState>,
+    Query(query): Query<WebAuthChallengeQuery>,
+) -> ApiResult<Response> {
+    let Some(account) = query.account else {
+        let Some(address) = query.address else {
+            return Err(ApiError::bad_request(
+                "InvalidAccount",
+                "account is required",
+            ));
+        };
+        let Json(nonce) = get_challenge(State(state), Query(ChallengeQuery { address })).await?;
+        return Ok(Json(nonce).into_response());
+    };
+    let config = web_auth_config()?;
+    if let Some(home_domain) = query.home_domain {
+        if home_domain != config.home_domain {
+            return Err(ApiError::bad_request(
+                "InvalidHomeDomain",
+                format!(
+                    "this registry only authenticates for {}",
+                    config.home_domain
+                ),
+            ));
+        }
+    }
+    let transaction = config
+        .challenge(&account, Utc::now())
+        .map_err(|err| ApiError::bad_request("InvalidAccount", err.to_string()))?;
+    Ok(Json(WebAuthChallenge {
+        transaction,
+        network_passphrase: config.network_passphrase.to_string(),
+    })
+    .into_response())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/token",
+    request_body = WebAuthTokenRequest,
     responses(
-        (status = 200, description = "Challenge created", body = ChallengeResponse),
-        (status = 400, description = "Invalid address provided")
+        (status = 200, description = "Session token issued", body = VerifyResponse),
+        (status = 400, description = "Not a valid, unexpired challenge from this registry"),
+        (status = 401, description = "Challenge not signed by the account, or its key was revoked")
     ),
     tag = "Authentication"
 )]
+pub async fn web_auth_token(
+    State(state): State<AppState>,
+    Json(payload): Json<WebAuthTokenRequest>,
+) -> ApiResult<Json<VerifyResponse>> {
+    let config = web_auth_config()?;
+    let challenge = config
+        .verify(&payload.transaction, Utc::now())
+        .map_err(|err| match err {
+            WebAuthError::MissingClientSignature => {
+                ApiError::new(StatusCode::UNAUTHORIZED, "AuthFailed", err.to_string())
+            }
+            _ => ApiError::bad_request("InvalidChallenge", err.to_string()),
+        })?;
+    let mut mgr = state.auth_mgr.write().unwrap();
+    let token =
+        mgr.issue_session_jwt(&challenge, &config.issuer)
+            .map_err(|reason| match reason {
+                "challenge_already_used" => ApiError::bad_request(
+                    "InvalidChallenge",
+                    "challenge has already been used; request a new one",
+                ),
+                "key_revoked" | "key_expired" | "key_not_yet_valid" => ApiError::new(
+                    StatusCode::UNAUTHORIZED,
+                    "SigningKeyNotValid",
+                    "this key has been revoked or is outside its validity window",
+                ),
+                _ => ApiError::internal("Failed to issue session token"),
+            })?;
+    Ok(Json(VerifyResponse {
+        token,
+        token_type: "Bearer",
+        expires_in_seconds: SESSION_TOKEN_TTL_SECONDS as u64,
+    }))
+}
+
+fn web_auth_config() -> ApiResult<WebAuthConfig> {
+    WebAuthConfig::from_env().map_err(|err| {
+        tracing::error!(error = %err, "SEP-10 web auth is misconfigured");
+        ApiError::internal("Web authentication configuration error")
+    })
+}
+
+/// Nonce challenge for `address`, served by [`web_auth_challenge`] when no
+/// SEP-10 `account` is given.
 pub async fn get_challenge(
     State(state): State<AppState>,
     Query(query): Query<ChallengeQuery>,
@@ -103,12 +225,17 @@ pub async fn verify_challenge(
     let mut mgr = state.auth_mgr.write().unwrap();
     let token = mgr
         .verify_and_issue_jwt(&payload.address, &payload.public_key, &payload.signature)
-        .map_err(|_| {
-            ApiError::new(
+        .map_err(|reason| match reason {
+            "key_revoked" | "key_expired" | "key_not_yet_valid" => ApiError::new(
+                StatusCode::UNAUTHORIZED,
+                "SigningKeyNotValid",
+                "this key has been revoked or is outside its validity window",
+            ),
+            _ => ApiError::new(
                 StatusCode::UNAUTHORIZED,
                 "AuthFailed",
                 "invalid challenge response",
-            )
+            ),
         })?;
     Ok((
         StatusCode::OK,
@@ -147,6 +274,7 @@ mod tests {
         let (job_engine, _rx) = soroban_batch::engine::JobEngine::new();
         let (event_broadcaster, _) = tokio::sync::broadcast::channel(100);
         AppState {
+            db_read: db.clone(),
             db,
             started_at: Instant::now(),
             cache: Arc::new(CacheLayer::new(CacheConfig::default()).await),
@@ -0,0 +1,689 @@
+//! Contract badges granted by signed attestations
+//!
+//!   POST   /api/admin/attestation-authorities       – recognise a publisher as an authority (admin)
+//!   DELETE /api/admin/attestation-authorities/:id   – stop recognising an authority (admin)
+//!   GET    /api/attestation-authorities             – recognised authorities
+//!   POST   /api/contracts/:id/badges                – submit a signed attestation
+//!   GET    /api/contracts/:id/badges                – badges with their provenance
+//!   POST   /api/contracts/:id/badges/:badge_id/revoke – withdraw a badge
+//!
+//! Badges (`audited-by`, `verified-source`, `interface-conformant`,
+//! `team-verified`, `tested`) are only ever granted by an attestation: a
+//! statement about one build of the contract signed with the ed25519 key of
+//! a publisher an admin has recognised for that badge kind. The signature is
+//! the proof, so anyone may relay an attestation to the registry; only the
+//! authority or an admin can withdraw it. See [`shared::attestation`] for
+//! the signed text.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use shared::attestation::{self, AttestationStatement};
+use sqlx::{Postgres, QueryBuilder};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    publisher_key_handlers::require_usable_account_key,
+    state::AppState,
+    tenant::Tenant,
+    validation::url_validation::validate_https_url_only,
+};
+
+/// Tolerated clock skew between an authority's signing machine and ours.
+pub(crate) const MAX_ISSUE_SKEW_MINUTES: i64 = 5;
+const MAX_NAME_LEN: usize = 100;
+
+/// Conditions under which a badge row counts: not revoked, not expired, and
+/// granted by an authority that is still recognised. Expects the badge as
+/// `b` and its authority as `a`.
+pub(crate) const ACTIVE_BADGE: &str = "b.revoked_at IS NULL AND a.revoked_at IS NULL \
+     AND (b.expires_at IS NULL OR b.expires_at > NOW())";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateAuthorityRequest {
+    /// Stellar address of an existing publisher
+    pub stellar_address: String,
+    /// Display name, e.g. the audit firm
+    pub name: String,
+    pub website: Option<String>,
+    /// Badge kinds the authority may grant
+    pub badge_kinds: Vec<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct AttestationAuthority {
+    pub id: Uuid,
+    pub publisher_id: Uuid,
+    pub stellar_address: String,
+    pub name: String,
+    pub website: Option<String>,
+    pub badge_kinds: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubmitAttestationRequest {
+    /// Stellar address of the signing authority
+    pub authority_address: String,
+    /// Badge kind being granted
+    pub kind: String,
+    /// Build the attestation covers; must be the contract's current build
+    pub wasm_hash: String,
+    /// Link to the audit report or other evidence
+    pub report_url: Option<String>,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Hex-encoded ed25519 signature of the attestation message
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RevokeBadgeRequest {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListBadgesQuery {
+    /// Also return revoked and expired badges
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+/// A badge together with who granted it and what they signed.
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractBadge {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub kind: String,
+    pub authority_id: Uuid,
+    pub authority_name: String,
+    pub authority_address: String,
+    /// Build the attestation covers
+    pub wasm_hash: String,
+    /// Whether that build is still the contract's current one
+    pub covers_current_build: bool,
+    pub report_url: Option<String>,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Hex ed25519 signature; verifiable against `authority_address`
+    pub signature: String,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revocation_reason: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct BadgeTarget {
+    contract_id: String,
+    network: String,
+    wasm_hash: String,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Badges of the contract bound to `$1`, narrowed by `filter`.
+fn badge_select(filter: &str) -> String {
+    format!(
+        "SELECT b.id, b.contract_id, b.kind, b.authority_id, a.name AS authority_name,
+                p.stellar_address AS authority_address, b.wasm_hash,
+                b.wasm_hash = c.wasm_hash AS covers_current_build, b.report_url,
+                b.issued_at, b.expires_at, b.signature, ({active}) AS active,
+                b.created_at, b.revoked_at, b.revocation_reason
+           FROM contract_badges b
+           JOIN attestation_authorities a ON a.id = b.authority_id
+           JOIN publishers p ON p.id = a.publisher_id
+           JOIN contracts c ON c.id = b.contract_id
+          WHERE b.contract_id = $1 {filter}
+          ORDER BY b.kind, b.issued_at DESC",
+        active = ACTIVE_BADGE,
+        filter = filter,
+    )
+}
+
+async fn fetch_badge(
+    state: &AppState,
+    contract_id: Uuid,
+    badge_id: Uuid,
+) -> ApiResult<ContractBadge> {
+    sqlx::query_as(&badge_select("AND b.id = $2"))
+        .bind(contract_id)
+        .bind(badge_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch badge", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "BadgeNotFound",
+                format!("No badge found with ID: {}", badge_id),
+            )
+        })
+}
+
+/// Checks a hex ed25519 signature over `message` by a Stellar account key.
+pub(crate) fn verify_attestation(
+    authority_address: &str,
+    message: &str,
+    signature_hex: &str,
+) -> Result<(), &'static str> {
+    let public_key = stellar_strkey::ed25519::PublicKey::from_string(authority_address)
+        .map_err(|_| "authority_not_an_account")?;
+    let signature: [u8; 64] = hex::decode(signature_hex.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("invalid_signature_hex")?;
+    VerifyingKey::from_bytes(&public_key.0)
+        .map_err(|_| "invalid_public_key")?
+        .verify(message.as_bytes(), &Signature::from_bytes(&signature))
+        .map_err(|_| "invalid_signature")
+}
+
+fn validate_authority(req: &CreateAuthorityRequest) -> ApiResult<()> {
+    let invalid = |message: String| ApiError::bad_request("InvalidAuthority", message);
+    let name = req.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        return Err(invalid(format!(
+            "name must be between 1 and {} characters",
+            MAX_NAME_LEN
+        )));
+    }
+    if req.badge_kinds.is_empty() {
+        return Err(invalid("badge_kinds must not be empty".to_string()));
+    }
+    if let Some(kind) = req
+        .badge_kinds
+        .iter()
+        .find(|kind| !attestation::is_badge_kind(kind))
+    {
+        return Err(invalid(format!(
+            "Unknown badge kind '{}'; expected one of {}",
+            kind,
+            attestation::BADGE_KINDS.join(", ")
+        )));
+    }
+    if let Some(website) = &req.website {
+        validate_https_url_only(website).map_err(invalid)?;
+    }
+    Ok(())
+}
+
+/// Active badge kinds of each contract, for decorating contract listings.
+pub(crate) async fn active_badges(
+    db: &sqlx::PgPool,
+    contract_ids: &[Uuid],
+) -> sqlx::Result<HashMap<Uuid, Vec<String>>> {
+    let rows: Vec<(Uuid, String)> = sqlx::query_as(&format!(
+        "SELECT DISTINCT b.contract_id, b.kind
+           FROM contract_badges b
+           JOIN attestation_authorities a ON a.id = b.authority_id
+          WHERE b.contract_id = ANY($1) AND {}
+          ORDER BY b.contract_id, b.kind",
+        ACTIVE_BADGE
+    ))
+    .bind(contract_ids)
+    .fetch_all(db)
+    .await?;
+
+    let mut badges: HashMap<Uuid, Vec<String>> = HashMap::new();
+    for (contract_id, kind) in rows {
+        badges.entry(contract_id).or_default().push(kind);
+    }
+    Ok(badges)
+}
+
+/// Restricts a contract search to contracts holding an active `kind` badge.
+pub(crate) fn push_badge_filter<'a>(qb: &mut QueryBuilder<'a, Postgres>, kind: &'a str) {
+    qb.push(
+        " AND EXISTS (SELECT 1 FROM contract_badges b \
+         JOIN attestation_authorities a ON a.id = b.authority_id \
+         WHERE b.contract_id = c.id AND ",
+    );
+    qb.push(ACTIVE_BADGE);
+    qb.push(" AND b.kind = ");
+    qb.push_bind(kind);
+    qb.push(")");
+}
+
+/// The active authority at `address` recognised for `kind` badges.
+pub(crate) async fn authority_for(state: &AppState, address: &str, kind: &str) -> ApiResult<Uuid> {
+    sqlx::query_scalar(
+        "SELECT a.id
+           FROM attestation_authorities a
+           JOIN publishers p ON p.id = a.publisher_id
+          WHERE p.stellar_address = $1 AND a.revoked_at IS NULL AND $2 = ANY(a.badge_kinds)",
+    )
+    .bind(address)
+    .bind(kind)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch attestation authority", err))?
+    .ok_or_else(|| {
+        ApiError::forbidden_with_error(
+            "NotAnAuthority",
+            format!(
+                "{} is not a recognised authority for '{}' badges",
+                address, kind
+            ),
+        )
+    })
+}
+
+async fn fetch_authority(state: &AppState, id: Uuid) -> ApiResult<AttestationAuthority> {
+    sqlx::query_as(
+        "SELECT a.id, a.publisher_id, p.stellar_address, a.name, a.website, a.badge_kinds,
+                a.created_at, a.revoked_at
+           FROM attestation_authorities a
+           JOIN publishers p ON p.id = a.publisher_id
+          WHERE a.id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch attestation authority", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "AuthorityNotFound",
+            format!("No attestation authority found with ID: {}", id),
+        )
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/attestation-authorities",
+    request_body = CreateAuthorityRequest,
+    responses(
+        (status = 201, description = "Authority recognised", body = AttestationAuthority),
+        (status = 400, description = "Invalid name, website or badge kinds"),
+        (status = 404, description = "No publisher with that address")
+    ),
+    tag = "Badges"
+)]
+pub async fn create_authority(
+    State(state): State<AppState>,
+    Json(req): Json<CreateAuthorityRequest>,
+) -> ApiResult<(StatusCode, Json<AttestationAuthority>)> {
+    validate_authority(&req)?;
+
+    let publisher_id: Uuid =
+        sqlx::query_scalar("SELECT id FROM publishers WHERE stellar_address = $1")
+            .bind(req.stellar_address.trim())
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch authority publisher", err))?
+            .ok_or_else(|| {
+                ApiError::not_found(
+                    "PublisherNotFound",
+                    format!(
+                        "No publisher registered with address {}",
+                        req.stellar_address
+                    ),
+                )
+            })?;
+
+    // Re-recognising a revoked authority restores it with the new kinds.
+    let id: Uuid = sqlx::query_scalar(
+        "INSERT INTO attestation_authorities (publisher_id, name, website, badge_kinds)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (publisher_id) DO UPDATE
+            SET name = EXCLUDED.name,
+                website = EXCLUDED.website,
+                badge_kinds = EXCLUDED.badge_kinds,
+                revoked_at = NULL
+         RETURNING id",
+    )
+    .bind(publisher_id)
+    .bind(req.name.trim())
+    .bind(&req.website)
+    .bind(&req.badge_kinds)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("create attestation authority", err))?;
+
+    let authority = fetch_authority(&state, id).await?;
+    tracing::info!(
+        authority = %authority.id,
+        address = %authority.stellar_address,
+        kinds = ?authority.badge_kinds,
+        "attestation authority recognised"
+    );
+    Ok((StatusCode::CREATED, Json(authority)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/attestation-authorities/{id}",
+    params(("id" = Uuid, Path, description = "Authority ID")),
+    responses(
+        (status = 200, description = "Authority revoked; its badges stop showing", body = AttestationAuthority),
+        (status = 404, description = "Authority not found")
+    ),
+    tag = "Badges"
+)]
+pub async fn revoke_authority(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<AttestationAuthority>> {
+    sqlx::query(
+        "UPDATE attestation_authorities SET revoked_at = COALESCE(revoked_at, NOW())
+          WHERE id = $1",
+    )
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("revoke attestation authority", err))?;
+
+    let authority = fetch_authority(&state, id).await?;
+    tracing::info!(authority = %id, "attestation authority revoked");
+    Ok(Json(authority))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/attestation-authorities",
+    responses(
+        (status = 200, description = "Recognised authorities", body = [AttestationAuthority])
+    ),
+    tag = "Badges"
+)]
+pub async fn list_authorities(
+    State(state): State<AppState>,
+) -> ApiResult<Json<Vec<AttestationAuthority>>> {
+    let authorities = sqlx::query_as(
+        "SELECT a.id, a.publisher_id, p.stellar_address, a.name, a.website, a.badge_kinds,
+                a.created_at, a.revoked_at
+           FROM attestation_authorities a
+           JOIN publishers p ON p.id = a.publisher_id
+          WHERE a.revoked_at IS NULL
+          ORDER BY a.name",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list attestation authorities", err))?;
+    Ok(Json(authorities))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/badges",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SubmitAttestationRequest,
+    responses(
+        (status = 201, description = "Badge granted", body = ContractBadge),
+        (status = 400, description = "Malformed attestation, or it covers another build"),
+        (status = 401, description = "Signature does not match the authority's key"),
+        (status = 403, description = "Signer is not an authority for this badge kind"),
+        (status = 404, description = "Contract not found"),
+        (status = 409, description = "Attestation already submitted")
+    ),
+    tag = "Badges"
+)]
+pub async fn submit_attestation(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<SubmitAttestationRequest>,
+) -> ApiResult<(StatusCode, Json<ContractBadge>)> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let target: BadgeTarget = sqlx::query_as(
+        "SELECT contract_id, network::TEXT AS network, wasm_hash FROM contracts WHERE id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load badge target", err))?;
+
+    let statement = AttestationStatement {
+        network: &target.network,
+        contract_id: &target.contract_id,
+        kind: &req.kind,
+        wasm_hash: &req.wasm_hash,
+        report_url: req.report_url.as_deref(),
+        issued_at: req.issued_at,
+        expires_at: req.expires_at,
+    };
+    statement
+        .validate()
+        .map_err(|msg| ApiError::bad_request("InvalidAttestation", msg))?;
+    if let Some(url) = &req.report_url {
+        validate_https_url_only(url)
+            .map_err(|msg| ApiError::bad_request("InvalidAttestation", msg))?;
+    }
+    let now = Utc::now();
+    if req.issued_at > now + Duration::minutes(MAX_ISSUE_SKEW_MINUTES) {
+        return Err(ApiError::bad_request(
+            "InvalidAttestation",
+            "issued_at is in the future",
+        ));
+    }
+    if req.expires_at.is_some_and(|at| at <= now) {
+        return Err(ApiError::bad_request(
+            "AttestationExpired",
+            "The attestation has already expired",
+        ));
+    }
+    if req.wasm_hash != target.wasm_hash {
+        return Err(ApiError::bad_request(
+            "AttestationBuildMismatch",
+            format!(
+                "The attestation covers build {} but the contract's current build is {}",
+                req.wasm_hash, target.wasm_hash
+            ),
+        ));
+    }
+
+    let authority_id = authority_for(&state, &req.authority_address, &req.kind).await?;
+
+    verify_attestation(
+        &req.authority_address,
+        &statement.signing_message(),
+        &req.signature,
+    )
+    .map_err(|reason| {
+        tracing::info!(contract = %contract_uuid, reason, "attestation rejected");
+        ApiError::unauthorized("Signature does not match the authority's key")
+    })?;
+    // Checked at submission rather than `issued_at`, which whoever holds the
+    // key chooses.
+    require_usable_account_key(&state.db, &req.authority_address, now).await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin attestation tx", err))?;
+
+    // A newer attestation from the same authority replaces its live badge.
+    sqlx::query(
+        "UPDATE contract_badges
+            SET revoked_at = NOW(), revocation_reason = 'superseded'
+          WHERE contract_id = $1 AND kind = $2 AND authority_id = $3 AND revoked_at IS NULL",
+    )
+    .bind(contract_uuid)
+    .bind(&req.kind)
+    .bind(authority_id)
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("supersede badge", err))?;
+
+    let badge_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_badges
+            (contract_id, authority_id, kind, wasm_hash, report_url, issued_at, expires_at, signature)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         RETURNING id",
+    )
+    .bind(contract_uuid)
+    .bind(authority_id)
+    .bind(&req.kind)
+    .bind(&target.wasm_hash)
+    .bind(&req.report_url)
+    .bind(req.issued_at)
+    .bind(req.expires_at)
+    .bind(req.signature.trim().to_ascii_lowercase())
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "AttestationExists",
+            "This attestation has already been submitted",
+        ),
+        _ => db_internal_error("insert badge", err),
+    })?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit attestation tx", err))?;
+
+    let badge = fetch_badge(&state, contract_uuid, badge_id).await?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        kind = %badge.kind,
+        authority = %badge.authority_address,
+        "badge granted"
+    );
+    Ok((StatusCode::CREATED, Json(badge)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/badges",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ListBadgesQuery
+    ),
+    responses(
+        (status = 200, description = "Badges and their provenance", body = [ContractBadge]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Badges"
+)]
+pub async fn list_contract_badges(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<ListBadgesQuery>,
+) -> ApiResult<Json<Vec<ContractBadge>>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let filter = if query.include_inactive {
+        String::new()
+    } else {
+        format!("AND {}", ACTIVE_BADGE)
+    };
+    let badges = sqlx::query_as(&badge_select(&filter))
+        .bind(contract_uuid)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("list contract badges", err))?;
+    Ok(Json(badges))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/badges/{badge_id}/revoke",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("badge_id" = Uuid, Path, description = "Badge ID")
+    ),
+    request_body = RevokeBadgeRequest,
+    responses(
+        (status = 200, description = "Badge revoked", body = ContractBadge),
+        (status = 403, description = "Only the granting authority or an admin can revoke"),
+        (status = 404, description = "Badge not found")
+    ),
+    tag = "Badges"
+)]
+pub async fn revoke_badge(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path((id, badge_id)): Path<(String, Uuid)>,
+    Json(req): Json<RevokeBadgeRequest>,
+) -> ApiResult<Json<ContractBadge>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let badge = fetch_badge(&state, contract_uuid, badge_id).await?;
+    let is_admin = claims.admin || claims.role.as_deref() == Some("admin");
+    if badge.authority_address != claims.sub && !is_admin {
+        return Err(ApiError::forbidden_with_error(
+            "NotBadgeAuthority",
+            "Only the authority that granted a badge, or an admin, can revoke it",
+        ));
+    }
+
+    sqlx::query(
+        "UPDATE contract_badges
+            SET revoked_at = COALESCE(revoked_at, NOW()),
+                revocation_reason = COALESCE(revocation_reason, $2)
+          WHERE id = $1",
+    )
+    .bind(badge_id)
+    .bind(
+        req.reason
+            .as_deref()
+            .map(str::trim)
+            .filter(|r| !r.is_empty()),
+    )
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("revoke badge", err))?;
+
+    let badge = fetch_badge(&state, contract_uuid, badge_id).await?;
+    tracing::info!(contract = %contract_uuid, badge = %badge_id, revoked_by = %claims.sub, "badge revoked");
+    Ok(Json(badge))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn authority_request(kinds: &[&str]) -> CreateAuthorityRequest {
+        CreateAuthorityRequest {
+            stellar_address: "GA".to_string(),
+            name: "Example Audits".to_string(),
+            website: None,
+            badge_kinds: kinds.iter().map(|k| k.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn authority_kinds_must_be_known() {
+        assert!(validate_authority(&authority_request(&[attestation::AUDITED_BY])).is_ok());
+        assert!(validate_authority(&authority_request(&[])).is_err());
+        assert!(validate_authority(&authority_request(&["gold-star"])).is_err());
+    }
+
+    #[test]
+    fn verifies_signature_against_the_authority_address() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let address =
+            stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes()).to_string();
+        let message = "soroban-registry attestation v1";
+        let signature = hex::encode(key.sign(message.as_bytes()).to_bytes());
+
+        assert!(verify_attestation(&address, message, &signature).is_ok());
+        assert_eq!(
+            verify_attestation(&address, "something else", &signature),
+            Err("invalid_signature")
+        );
+        assert_eq!(
+            verify_attestation("not-an-address", message, &signature),
+            Err("authority_not_an_account")
+        );
+    }
+}
@@ -0,0 +1,643 @@
+//! Batch deployment proposals
+//!
+//!   POST /api/multisig/batch-proposals           – propose several deployments/migrations
+//!   POST /api/proposals/:id/steps/:index         – report a step's outcome
+//!
+//! The proposer is the caller's bearer token subject, and every step on a
+//! registered contract must be one the policy governs (see
+//! `action_proposal_handlers::ensure_policy_governs`). Step results can only
+//! be reported by the policy's signers, who are also the only ones allowed
+//! to execute.
+//!
+//! A batch proposal is signed like any deployment proposal, but once approved
+//! (and past the policy's timelock) its steps are executed in order by the
+//! executor, who reports each one. A failed step skips the steps after it and
+//! puts every executed step up for rollback, which is reported in reverse
+//! order. The proposal moves through `executing` to `executed`, or through
+//! `rolling_back` to `rolled_back` (`failed` when nothing had run yet).
+//!
+//! A `migrate` step records the contract's current build as its rollback
+//! target; a `deploy` step is rolled back by retiring the new deployment.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shared::Network;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    action_proposal_handlers::ensure_policy_governs,
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    metrics,
+    multisig_handlers::{
+        ensure_timelock_elapsed, DeployProposal, MultisigPolicy, ProposalStatus, POLICY_COLUMNS,
+    },
+    multisig_notifications::{self, Trigger},
+    state::AppState,
+    tenant::Tenant,
+};
+
+const MAX_BATCH_STEPS: usize = 20;
+
+const STEP_COLUMNS: &str = "step_index, action, contract_id, wasm_hash, rollback_wasm_hash,
+     description, status, tx_hash, rollback_tx_hash, error, executed_at, rolled_back_at";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct BatchStepRequest {
+    /// `deploy` or `migrate`
+    pub action: String,
+    pub contract_id: String,
+    pub wasm_hash: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateBatchProposalRequest {
+    /// Label for the batch, shown where a contract name would be
+    pub name: String,
+    pub network: Network,
+    pub description: Option<String>,
+    pub policy_id: Uuid,
+    pub steps: Vec<BatchStepRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StepResultRequest {
+    /// `executed`, `failed` or `rolled_back`
+    pub outcome: String,
+    pub tx_hash: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct ProposalStep {
+    pub step_index: i32,
+    pub action: String,
+    pub contract_id: String,
+    pub wasm_hash: String,
+    /// Build a `migrate` step is rolled back to
+    pub rollback_wasm_hash: Option<String>,
+    pub description: Option<String>,
+    pub status: String,
+    pub tx_hash: Option<String>,
+    pub rollback_tx_hash: Option<String>,
+    pub error: Option<String>,
+    pub executed_at: Option<DateTime<Utc>>,
+    pub rolled_back_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BatchProposalResponse {
+    pub proposal: DeployProposal,
+    pub steps: Vec<ProposalStep>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StepResultResponse {
+    pub proposal_status: String,
+    pub steps: Vec<ProposalStep>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepStatus {
+    Pending,
+    Executed,
+    Failed,
+    Skipped,
+    RollbackPending,
+    RolledBack,
+}
+
+impl StepStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Executed => "executed",
+            Self::Failed => "failed",
+            Self::Skipped => "skipped",
+            Self::RollbackPending => "rollback_pending",
+            Self::RolledBack => "rolled_back",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        Some(match value {
+            "pending" => Self::Pending,
+            "executed" => Self::Executed,
+            "failed" => Self::Failed,
+            "skipped" => Self::Skipped,
+            "rollback_pending" => Self::RollbackPending,
+            "rolled_back" => Self::RolledBack,
+            _ => return None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Executed,
+    Failed,
+    RolledBack,
+}
+
+/// Applies a reported outcome for step `index`, returning every step's new
+/// status and the proposal's. Steps run in order and roll back in reverse.
+fn apply_outcome(
+    steps: &[StepStatus],
+    index: usize,
+    outcome: Outcome,
+) -> ApiResult<(Vec<StepStatus>, ProposalStatus)> {
+    let mut next = steps.to_vec();
+    match outcome {
+        Outcome::Executed | Outcome::Failed => {
+            let expected = steps.iter().position(|s| *s == StepStatus::Pending);
+            if expected != Some(index) {
+                return Err(out_of_order(expected, "executed"));
+            }
+            if outcome == Outcome::Executed {
+                next[index] = StepStatus::Executed;
+                let done = next.iter().all(|s| *s == StepStatus::Executed);
+                let status = if done {
+                    ProposalStatus::Executed
+                } else {
+                    ProposalStatus::Executing
+                };
+                return Ok((next, status));
+            }
+            next[index] = StepStatus::Failed;
+            for step in next.iter_mut() {
+                *step = match *step {
+                    StepStatus::Pending => StepStatus::Skipped,
+                    StepStatus::Executed => StepStatus::RollbackPending,
+                    other => other,
+                };
+            }
+        }
+        Outcome::RolledBack => {
+            let expected = steps
+                .iter()
+                .rposition(|s| *s == StepStatus::RollbackPending);
+            if expected != Some(index) {
+                return Err(out_of_order(expected, "rolled back"));
+            }
+            next[index] = StepStatus::RolledBack;
+        }
+    }
+
+    let rolled_back = next.contains(&StepStatus::RolledBack);
+    let status = if next.contains(&StepStatus::RollbackPending) {
+        ProposalStatus::RollingBack
+    } else if rolled_back {
+        ProposalStatus::RolledBack
+    } else {
+        ProposalStatus::Failed
+    };
+    Ok((next, status))
+}
+
+fn out_of_order(expected: Option<usize>, verb: &str) -> ApiError {
+    match expected {
+        Some(expected) => ApiError::conflict(
+            "OutOfOrderStep",
+            format!("step {} must be {} next", expected, verb),
+        ),
+        None => ApiError::conflict(
+            "InvalidStepState",
+            format!("no step of this proposal can be {} now", verb),
+        ),
+    }
+}
+
+pub(crate) async fn load_steps(pool: &PgPool, proposal_id: Uuid) -> ApiResult<Vec<ProposalStep>> {
+    sqlx::query_as::<_, ProposalStep>(&format!(
+        "SELECT {} FROM deploy_proposal_steps WHERE proposal_id = $1 ORDER BY step_index",
+        STEP_COLUMNS
+    ))
+    .bind(proposal_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load proposal steps");
+        ApiError::db_error("Failed to load proposal steps")
+    })
+}
+
+fn invalid_step(index: usize, message: &str) -> ApiError {
+    ApiError::bad_request("InvalidBatchStep", format!("step {}: {}", index, message))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+pub async fn create_batch_proposal(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Json(payload): Json<CreateBatchProposalRequest>,
+) -> ApiResult<Json<BatchProposalResponse>> {
+    if payload.name.trim().is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidBatchName",
+            "name cannot be empty",
+        ));
+    }
+    if payload.steps.is_empty() || payload.steps.len() > MAX_BATCH_STEPS {
+        return Err(ApiError::bad_request(
+            "InvalidBatchStep",
+            format!("a batch needs between 1 and {} steps", MAX_BATCH_STEPS),
+        ));
+    }
+    for (index, step) in payload.steps.iter().enumerate() {
+        if step.action != "deploy" && step.action != "migrate" {
+            return Err(invalid_step(index, "action must be deploy or migrate"));
+        }
+        if step.contract_id.trim().is_empty() || step.wasm_hash.trim().is_empty() {
+            return Err(invalid_step(
+                index,
+                "contract_id and wasm_hash are required",
+            ));
+        }
+    }
+
+    let policy = sqlx::query_as::<_, MultisigPolicy>(&format!(
+        "SELECT {} FROM multisig_policies WHERE id = $1",
+        POLICY_COLUMNS
+    ))
+    .bind(payload.policy_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to fetch multisig policy");
+        ApiError::db_error("Failed to load multisig policy")
+    })?
+    .ok_or_else(|| ApiError::not_found("PolicyNotFound", "multisig policy not found"))?;
+
+    let mut conn = state.db.acquire().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to acquire connection");
+        ApiError::db_error("Failed to create batch proposal")
+    })?;
+
+    // Registered contracts must be governed by the policy; a migration rolls
+    // back to the build the contract runs today.
+    let mut rollback_hashes = Vec::with_capacity(payload.steps.len());
+    for (index, step) in payload.steps.iter().enumerate() {
+        let current: Option<(String, Uuid, Option<Uuid>)> = sqlx::query_as(
+            "SELECT wasm_hash, publisher_id, organization_id FROM contracts
+             WHERE contract_id = $1 AND network = $2 AND tenant_id = $3
+               AND deleted_at IS NULL",
+        )
+        .bind(step.contract_id.trim())
+        .bind(payload.network)
+        .bind(tenant.id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "failed to look up batch step contract");
+            ApiError::db_error("Failed to create batch proposal")
+        })?;
+        match current {
+            Some((hash, publisher_id, organization_id)) => {
+                ensure_policy_governs(&mut conn, policy.id, publisher_id, organization_id).await?;
+                rollback_hashes.push((step.action == "migrate").then_some(hash));
+            }
+            None if step.action == "migrate" => {
+                return Err(invalid_step(
+                    index,
+                    "migrate targets a contract not registered on this network",
+                ))
+            }
+            None => rollback_hashes.push(None),
+        }
+    }
+    drop(conn);
+
+    let expires_at = Utc::now() + chrono::Duration::seconds(i64::from(policy.expiry_seconds));
+    let first = &payload.steps[0];
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to start transaction");
+        ApiError::db_error("Failed to create batch proposal")
+    })?;
+
+    // The proposal row carries the first step's contract and build.
+    let proposal: DeployProposal = sqlx::query_as(&format!(
+        "INSERT INTO deploy_proposals (
+            contract_name, contract_id, wasm_hash, network, description,
+            policy_id, status, expires_at, proposer, required_approvals, tenant_id
+         )
+         VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $8, $9, $10)
+         RETURNING {}",
+        PROPOSAL_COLUMNS
+    ))
+    .bind(payload.name.trim())
+    .bind(first.contract_id.trim())
+    .bind(first.wasm_hash.trim())
+    .bind(payload.network)
+    .bind(payload.description.as_deref())
+    .bind(payload.policy_id)
+    .bind(expires_at)
+    .bind(&claims.sub)
+    .bind(policy.threshold)
+    .bind(tenant.id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to create batch proposal");
+        ApiError::db_error("Failed to create batch proposal")
+    })?;
+
+    for (index, (step, rollback)) in payload.steps.iter().zip(&rollback_hashes).enumerate() {
+        sqlx::query(
+            "INSERT INTO deploy_proposal_steps (
+                proposal_id, step_index, action, contract_id, wasm_hash,
+                rollback_wasm_hash, description
+             )
+             VALUES ($1, $2, $3, $4, $5, $6, $7)",
+        )
+        .bind(proposal.id)
+        .bind(index as i32)
+        .bind(&step.action)
+        .bind(step.contract_id.trim())
+        .bind(step.wasm_hash.trim())
+        .bind(rollback.as_deref())
+        .bind(step.description.as_deref())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "failed to insert batch step");
+            ApiError::db_error("Failed to create batch proposal")
+        })?;
+    }
+
+    sqlx::query(
+        "INSERT INTO multisig_approval_audit_events (
+            proposal_id, actor_address, action, metadata
+         )
+         VALUES ($1, $2, 'proposal_created', $3)",
+    )
+    .bind(proposal.id)
+    .bind(&claims.sub)
+    .bind(json!({
+        "required_approvals": proposal.required_approvals,
+        "ordered_approvals": policy.ordered_approvals,
+        "steps": payload.steps.len(),
+    }))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to insert audit event");
+        ApiError::db_error("Failed to record audit trail")
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to commit batch proposal transaction");
+        ApiError::db_error("Failed to finalize batch proposal")
+    })?;
+
+    multisig_notifications::notify(&state.db, proposal.id, Trigger::Created).await;
+    metrics::MULTISIG_PROPOSALS.inc();
+
+    let steps = load_steps(&state.db, proposal.id).await?;
+    Ok(Json(BatchProposalResponse { proposal, steps }))
+}
+
+pub async fn report_step_result(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, index)): Path<(String, i32)>,
+    Json(payload): Json<StepResultRequest>,
+) -> ApiResult<Json<StepResultResponse>> {
+    let proposal_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request("InvalidProposalId", "proposal id must be a valid UUID")
+    })?;
+    let outcome = match payload.outcome.as_str() {
+        "executed" => Outcome::Executed,
+        "failed" => Outcome::Failed,
+        "rolled_back" => Outcome::RolledBack,
+        _ => {
+            return Err(ApiError::bad_request(
+                "InvalidStepOutcome",
+                "outcome must be one of: executed, failed, rolled_back",
+            ))
+        }
+    };
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to start step transaction");
+        ApiError::db_error("Failed to record step result")
+    })?;
+
+    let (status, approved_at, timelock_seconds, signer_addresses): (
+        ProposalStatus,
+        Option<DateTime<Utc>>,
+        i32,
+        Vec<String>,
+    ) = sqlx::query_as(
+        "SELECT p.status, p.approved_at, mp.timelock_seconds, mp.signer_addresses
+         FROM deploy_proposals p
+         JOIN multisig_policies mp ON mp.id = p.policy_id
+         WHERE p.id = $1
+         FOR UPDATE OF p",
+    )
+    .bind(proposal_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load proposal");
+        ApiError::db_error("Failed to load proposal")
+    })?
+    .ok_or_else(|| ApiError::not_found("ProposalNotFound", "deployment proposal not found"))?;
+
+    if !signer_addresses.contains(&claims.sub) {
+        return Err(ApiError::forbidden_with_error(
+            "NotPolicySigner",
+            "only the policy's signers can report step results",
+        ));
+    }
+
+    let allowed = match outcome {
+        Outcome::Executed | Outcome::Failed => {
+            matches!(status, ProposalStatus::Approved | ProposalStatus::Executing)
+        }
+        Outcome::RolledBack => status == ProposalStatus::RollingBack,
+    };
+    if !allowed {
+        return Err(ApiError::conflict(
+            "InvalidProposalState",
+            format!(
+                "step results cannot be reported while the proposal is '{}'",
+                status.as_str()
+            ),
+        ));
+    }
+    if status == ProposalStatus::Approved {
+        ensure_timelock_elapsed(approved_at, timelock_seconds)?;
+    }
+
+    let rows: Vec<(i32, String)> = sqlx::query_as(
+        "SELECT step_index, status FROM deploy_proposal_steps
+         WHERE proposal_id = $1
+         ORDER BY step_index
+         FOR UPDATE",
+    )
+    .bind(proposal_id)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load proposal steps");
+        ApiError::db_error("Failed to load proposal steps")
+    })?;
+    if rows.is_empty() {
+        return Err(ApiError::conflict(
+            "NotBatchProposal",
+            "this proposal has no steps; execute it directly",
+        ));
+    }
+    let steps: Vec<StepStatus> = rows
+        .iter()
+        .map(|(_, status)| StepStatus::parse(status).unwrap_or(StepStatus::Pending))
+        .collect();
+    let position = rows
+        .iter()
+        .position(|(step_index, _)| *step_index == index)
+        .ok_or_else(|| ApiError::not_found("StepNotFound", "proposal step not found"))?;
+
+    let (next, proposal_status) = apply_outcome(&steps, position, outcome)?;
+
+    for ((step_index, _), (before, after)) in rows.iter().zip(steps.iter().zip(&next)) {
+        if before == after {
+            continue;
+        }
+        sqlx::query(
+            "UPDATE deploy_proposal_steps
+             SET status = $3,
+                 tx_hash = CASE WHEN $4 AND $3 <> 'rolled_back' THEN $5 ELSE tx_hash END,
+                 rollback_tx_hash = CASE WHEN $4 AND $3 = 'rolled_back' THEN $5
+                                         ELSE rollback_tx_hash END,
+                 error = CASE WHEN $4 THEN $6 ELSE error END,
+                 executed_at = CASE WHEN $3 = 'executed' THEN NOW() ELSE executed_at END,
+                 rolled_back_at = CASE WHEN $3 = 'rolled_back' THEN NOW()
+                                       ELSE rolled_back_at END
+             WHERE proposal_id = $1 AND step_index = $2",
+        )
+        .bind(proposal_id)
+        .bind(step_index)
+        .bind(after.as_str())
+        .bind(*step_index == index)
+        .bind(payload.tx_hash.as_deref())
+        .bind(payload.error.as_deref())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "failed to update proposal step");
+            ApiError::db_error("Failed to record step result")
+        })?;
+    }
+
+    sqlx::query(
+        "UPDATE deploy_proposals
+         SET status = $2,
+             executed_at = CASE WHEN $2 = 'executed' THEN NOW() ELSE executed_at END,
+             updated_at = NOW()
+         WHERE id = $1",
+    )
+    .bind(proposal_id)
+    .bind(proposal_status)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to update proposal status");
+        ApiError::db_error("Failed to update proposal status")
+    })?;
+
+    sqlx::query(
+        "INSERT INTO multisig_approval_audit_events (
+            proposal_id, actor_address, action, comment, metadata
+         )
+         VALUES ($1, $2, $3, $4, $5)",
+    )
+    .bind(proposal_id)
+    .bind(&claims.sub)
+    .bind(match outcome {
+        Outcome::Executed => "step_executed",
+        Outcome::Failed => "step_failed",
+        Outcome::RolledBack => "step_rolled_back",
+    })
+    .bind(payload.error.as_deref())
+    .bind(json!({
+        "step_index": index,
+        "tx_hash": payload.tx_hash,
+        "proposal_status": proposal_status.as_str(),
+    }))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to insert audit event");
+        ApiError::db_error("Failed to record audit trail")
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to commit step result");
+        ApiError::db_error("Failed to record step result")
+    })?;
+
+    if proposal_status == ProposalStatus::Executed {
+        metrics::MULTISIG_EXECUTIONS.inc();
+    }
+
+    Ok(Json(StepResultResponse {
+        proposal_status: proposal_status.as_str().to_string(),
+        steps: load_steps(&state.db, proposal_id).await?,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use StepStatus::*;
+
+    #[test]
+    fn steps_execute_in_order() {
+        let (steps, status) = apply_outcome(&[Pending, Pending], 0, Outcome::Executed).unwrap();
+        assert_eq!(
+            (steps.as_slice(), status),
+            (&[Executed, Pending][..], ProposalStatus::Executing)
+        );
+        let (_, status) = apply_outcome(&steps, 1, Outcome::Executed).unwrap();
+        assert_eq!(status, ProposalStatus::Executed);
+        assert!(apply_outcome(&[Pending, Pending], 1, Outcome::Executed).is_err());
+    }
+
+    #[test]
+    fn failures_skip_the_rest_and_roll_back_in_reverse() {
+        let (steps, status) =
+            apply_outcome(&[Executed, Executed, Pending, Pending], 2, Outcome::Failed).unwrap();
+        assert_eq!(
+            steps,
+            vec![RollbackPending, RollbackPending, Failed, Skipped]
+        );
+        assert_eq!(status, ProposalStatus::RollingBack);
+
+        assert!(apply_outcome(&steps, 0, Outcome::RolledBack).is_err());
+        let (steps, status) = apply_outcome(&steps, 1, Outcome::RolledBack).unwrap();
+        assert_eq!(status, ProposalStatus::RollingBack);
+        let (_, status) = apply_outcome(&steps, 0, Outcome::RolledBack).unwrap();
+        assert_eq!(status, ProposalStatus::RolledBack);
+    }
+
+    #[test]
+    fn a_failed_first_step_fails_the_proposal() {
+        let (steps, status) = apply_outcome(&[Pending, Pending], 0, Outcome::Failed).unwrap();
+        assert_eq!(steps, vec![Failed, Skipped]);
+        assert_eq!(status, ProposalStatus::Failed);
+    }
+}
@@ -3,7 +3,10 @@ use futures_util::stream::{self, StreamExt};
 use serde_json::{json, Value};
 use shared::{BatchVerifyItem, BatchVerifyRequest, Contract};
 
-use crate::{onchain_verification::OnChainVerifier, state::AppState};
+use crate::{
+    onchain_verification::OnChainVerifier, state::AppState, toolchain_handlers::resolve_toolchain,
+    verification_cache::verify_source,
+};
 
 pub async fn batch_verify_contracts(
     State(state): State<AppState>,
@@ -103,16 +106,38 @@ async fn verify_batch_item(
         }
     };
 
-    let source_verification = match (&item.source_code, &item.compiler_version) {
-        (Some(source_code), Some(compiler_version)) if !source_code.trim().is_empty() => Some(
-            verifier::verify_contract(
-                source_code,
-                &contract.wasm_hash,
-                Some(compiler_version),
-                item.build_params.as_ref(),
+    let toolchain = match item.toolchain.as_deref() {
+        Some(name) => match resolve_toolchain(state, name).await {
+            Ok((_, toolchain)) => Some(toolchain),
+            Err(err) => {
+                return json!({
+                    "contract_id": contract.contract_id,
+                    "verified": false,
+                    "error": err.to_string()
+                });
+            }
+        },
+        None => None,
+    };
+
+    let source_verification = match &item.source_code {
+        Some(source_code)
+            if !source_code.trim().is_empty()
+                && (item.compiler_version.is_some() || toolchain.is_some()) =>
+        {
+            Some(
+                verify_source(
+                    &state.db,
+                    None,
+                    source_code,
+                    &contract.wasm_hash,
+                    item.compiler_version.as_deref(),
+                    item.build_params.as_ref(),
+                    toolchain.as_ref(),
+                )
+                .await,
             )
-            .await,
-        ),
+        }
         _ => None,
     };
 
@@ -121,7 +146,7 @@ async fn verify_batch_item(
         && on_chain.abi_valid
         && source_verification
             .as_ref()
-            .map(|result| result.as_ref().map(|r| r.verified).unwrap_or(false))
+            .map(|result| result.as_ref().map(|r| r.result.verified).unwrap_or(false))
             .unwrap_or(true);
 
     json!({
@@ -130,11 +155,13 @@ async fn verify_batch_item(
         "network": contract.network.to_string(),
         "on_chain": on_chain,
         "source_verification": source_verification.map(|result| match result {
-            Ok(value) => json!({
-                "verified": value.verified,
-                "compiled_wasm_hash": value.compiled_wasm_hash,
-                "deployed_wasm_hash": value.deployed_wasm_hash,
-                "message": value.message
+            Ok(source) => json!({
+                "verified": source.result.verified,
+                "compiled_wasm_hash": source.result.compiled_wasm_hash,
+                "deployed_wasm_hash": source.result.deployed_wasm_hash,
+                "toolchain_digest": source.result.toolchain_digest,
+                "build_cached": source.cache_hit,
+                "message": source.result.message
             }),
             Err(err) => json!({
                 "verified": false,
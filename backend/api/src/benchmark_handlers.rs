@@ -0,0 +1,329 @@
+//! Function-level fee benchmarks
+//!
+//! Stores the per-function CPU, memory, and fee estimates produced by
+//! `soroban-registry bench` against a published contract version:
+//!
+//!   GET    /api/contracts/:id/versions/:version/benchmarks  – list results
+//!   POST   /api/contracts/:id/versions/:version/benchmarks  – record results
+//!   GET    /api/contracts/:id/versions/:version/regressions – list regressions
+//!
+//! Re-running a benchmark for the same version replaces the previous figures
+//! for each function, so a version always has a single current baseline.
+//! Recording benchmarks queues a comparison of each version's figures with
+//! the previous version's (see [`crate::cost_regressions`]).
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{self, ContractCapability},
+    cost_regressions::CostRegression,
+    error::{ApiError, ApiResult},
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct FunctionBenchmarkInput {
+    pub function_name: String,
+    pub cpu_instructions: i64,
+    pub memory_bytes: i64,
+    pub fee_stroops: i64,
+    /// Arguments the function was simulated with.
+    pub args: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RecordFunctionBenchmarksRequest {
+    pub results: Vec<FunctionBenchmarkInput>,
+    /// Version of the tool that produced the figures (e.g. soroban CLI version).
+    pub tool_version: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow, utoipa::ToSchema)]
+pub struct FunctionBenchmark {
+    pub function_name: String,
+    pub cpu_instructions: i64,
+    pub memory_bytes: i64,
+    pub fee_stroops: i64,
+    pub args: Option<serde_json::Value>,
+    pub tool_version: Option<String>,
+    pub recorded_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct VersionBenchmarksResponse {
+    pub contract_id: String,
+    pub version: String,
+    pub benchmarks: Vec<FunctionBenchmark>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct VersionRegressionsResponse {
+    pub contract_id: String,
+    pub version: String,
+    /// Previous version the benchmarks were compared with; null until both
+    /// versions have benchmarks and the comparison has run
+    pub baseline_version: Option<String>,
+    /// Growth in percent beyond which a metric counts as regressed
+    pub threshold_percent: Option<f64>,
+    pub regressions: Vec<CostRegression>,
+    pub compared_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, FromRow)]
+struct CostComparisonRow {
+    baseline_version: String,
+    threshold_percent: f64,
+    regressions: sqlx::types::Json<Vec<CostRegression>>,
+    compared_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+/// Resolve `(contract uuid, on-chain id, version uuid)` from path segments.
+async fn resolve_version(
+    state: &AppState,
+    tenant: &Tenant,
+    id: &str,
+    version: &str,
+) -> ApiResult<(Uuid, String, Uuid)> {
+    let contract_uuid = resolve_contract_uuid(state, tenant, id).await?;
+    let contract_id: String = sqlx::query_scalar("SELECT contract_id FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_err("fetch contract", err))?;
+
+    let version_id: Uuid = sqlx::query_scalar(
+        "SELECT id FROM contract_versions WHERE contract_id = $1 AND version = $2",
+    )
+    .bind(contract_uuid)
+    .bind(version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("fetch contract version", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "ContractVersionNotFound",
+            format!(
+                "Version '{}' not found for contract {}",
+                version, contract_id
+            ),
+        )
+    })?;
+
+    Ok((contract_uuid, contract_id, version_id))
+}
+
+async fn fetch_benchmarks(state: &AppState, version_id: Uuid) -> ApiResult<Vec<FunctionBenchmark>> {
+    sqlx::query_as(
+        "SELECT function_name, cpu_instructions, memory_bytes, fee_stroops, args, tool_version, recorded_at \
+         FROM contract_function_benchmarks WHERE contract_version_id = $1 ORDER BY function_name",
+    )
+    .bind(version_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list function benchmarks", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// List the function benchmarks recorded for a contract version.
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/versions/{version}/benchmarks",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Contract version")
+    ),
+    responses(
+        (status = 200, description = "Function benchmarks", body = VersionBenchmarksResponse),
+        (status = 404, description = "Contract or version not found")
+    ),
+    tag = "Performance"
+)]
+pub async fn list_function_benchmarks(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Json<VersionBenchmarksResponse>> {
+    let (_, contract_id, version_id) = resolve_version(&state, &tenant, &id, &version).await?;
+    let benchmarks = fetch_benchmarks(&state, version_id).await?;
+
+    Ok(Json(VersionBenchmarksResponse {
+        contract_id,
+        version,
+        benchmarks,
+    }))
+}
+
+/// Record (or replace) function benchmarks for a contract version.
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/versions/{version}/benchmarks",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Contract version")
+    ),
+    request_body = RecordFunctionBenchmarksRequest,
+    responses(
+        (status = 201, description = "Benchmarks recorded", body = VersionBenchmarksResponse),
+        (status = 400, description = "Invalid benchmark values"),
+        (status = 403, description = "Caller may not publish versions of the contract"),
+        (status = 404, description = "Contract or version not found")
+    ),
+    tag = "Performance"
+)]
+pub async fn record_function_benchmarks(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path((id, version)): Path<(String, String)>,
+    Json(req): Json<RecordFunctionBenchmarksRequest>,
+) -> ApiResult<(StatusCode, Json<VersionBenchmarksResponse>)> {
+    if req.results.is_empty() {
+        return Err(ApiError::bad_request(
+            "EmptyBenchmarks",
+            "results must contain at least one function benchmark",
+        ));
+    }
+    if let Some(bad) = req.results.iter().find(|r| {
+        r.function_name.trim().is_empty()
+            || r.cpu_instructions < 0
+            || r.memory_bytes < 0
+            || r.fee_stroops < 0
+    }) {
+        return Err(ApiError::bad_request(
+            "InvalidBenchmark",
+            format!(
+                "Benchmark for '{}' must have a name and non-negative values",
+                bad.function_name
+            ),
+        ));
+    }
+
+    let (contract_uuid, contract_id, version_id) =
+        resolve_version(&state, &tenant, &id, &version).await?;
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "record its benchmarks",
+    )
+    .await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin benchmark transaction", err))?;
+    for result in &req.results {
+        sqlx::query(
+            "INSERT INTO contract_function_benchmarks \
+               (contract_id, contract_version_id, function_name, cpu_instructions, memory_bytes, fee_stroops, args, tool_version) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+             ON CONFLICT (contract_version_id, function_name) DO UPDATE SET \
+               cpu_instructions = EXCLUDED.cpu_instructions, \
+               memory_bytes = EXCLUDED.memory_bytes, \
+               fee_stroops = EXCLUDED.fee_stroops, \
+               args = EXCLUDED.args, \
+               tool_version = EXCLUDED.tool_version, \
+               recorded_at = NOW()",
+        )
+        .bind(contract_uuid)
+        .bind(version_id)
+        .bind(result.function_name.trim())
+        .bind(result.cpu_instructions)
+        .bind(result.memory_bytes)
+        .bind(result.fee_stroops)
+        .bind(&result.args)
+        .bind(req.tool_version.as_deref())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_err("upsert function benchmark", err))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit benchmark transaction", err))?;
+
+    crate::cost_regressions::queue(&state.db, contract_uuid).await;
+
+    let benchmarks = fetch_benchmarks(&state, version_id).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(VersionBenchmarksResponse {
+            contract_id,
+            version,
+            benchmarks,
+        }),
+    ))
+}
+
+/// Functions whose estimated cost regressed against the previous version.
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/versions/{version}/regressions",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Contract version")
+    ),
+    responses(
+        (status = 200, description = "Cost regressions against the previous version", body = VersionRegressionsResponse),
+        (status = 404, description = "Contract or version not found")
+    ),
+    tag = "Performance"
+)]
+pub async fn list_cost_regressions(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Json<VersionRegressionsResponse>> {
+    let (_, contract_id, version_id) = resolve_version(&state, &tenant, &id, &version).await?;
+    let comparison: Option<CostComparisonRow> = sqlx::query_as(
+        "SELECT b.version AS baseline_version, c.threshold_percent, c.regressions, c.compared_at \
+         FROM contract_cost_comparisons c \
+         JOIN contract_versions b ON b.id = c.baseline_version_id \
+         WHERE c.contract_version_id = $1",
+    )
+    .bind(version_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("fetch cost comparison", err))?;
+
+    Ok(Json(match comparison {
+        Some(row) => VersionRegressionsResponse {
+            contract_id,
+            version,
+            baseline_version: Some(row.baseline_version),
+            threshold_percent: Some(row.threshold_percent),
+            regressions: row.regressions.0,
+            compared_at: Some(row.compared_at),
+        },
+        None => VersionRegressionsResponse {
+            contract_id,
+            version,
+            baseline_version: None,
+            threshold_percent: None,
+            regressions: Vec::new(),
+            compared_at: None,
+        },
+    }))
+}
@@ -0,0 +1,389 @@
+//! Bug bounty programs for contracts
+//!
+//!   GET    /api/contracts/:id/bug-bounty     – the contract's bounty program
+//!   PUT    /api/contracts/:id/bug-bounty     – set it (publisher only)
+//!   DELETE /api/contracts/:id/bug-bounty     – remove it (publisher only)
+//!   GET    /api/contracts/:id/security.txt   – the same as an RFC 9116 document
+//!
+//! Tells whitehats where to disclose a vulnerability in a contract: the
+//! program page, what is in scope, the largest payout and a contact.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::ensure_publisher,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    validation::url_validation::validate_https_url_only,
+};
+
+const MAX_SCOPE_LEN: usize = 2000;
+/// security.txt must expire; without an explicit date it lapses a year
+/// after the program was last updated.
+const DEFAULT_VALIDITY_DAYS: i64 = 365;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetBugBountyRequest {
+    /// HTTPS page describing the program
+    pub program_url: String,
+    /// Where to report: an email address or a mailto:, https: or tel: URI
+    pub contact: String,
+    /// What is in scope, e.g. "Mainnet deployment; excludes the admin UI"
+    pub scope: Option<String>,
+    /// Largest payout, in whole units of `payout_currency`
+    pub max_payout: Option<i64>,
+    /// Defaults to USD
+    pub payout_currency: Option<String>,
+    /// HTTPS disclosure policy, if separate from the program page
+    pub policy_url: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct BugBounty {
+    pub contract_id: Uuid,
+    pub program_url: String,
+    /// Contact URI (mailto:, https: or tel:)
+    pub contact: String,
+    pub scope: Option<String>,
+    pub max_payout: Option<i64>,
+    pub payout_currency: String,
+    pub policy_url: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidBugBounty", message)
+}
+
+/// Normalises a contact to the URI form security.txt expects; a bare email
+/// address becomes `mailto:`.
+fn normalize_contact(contact: &str) -> Result<String, String> {
+    let contact = contact.trim();
+    let invalid = || {
+        format!(
+            "contact '{}' is not an email address or mailto:, https: or tel: URI",
+            contact
+        )
+    };
+    if contact.starts_with("https://") {
+        validate_https_url_only(contact)?;
+        return Ok(contact.to_string());
+    }
+    if let Some(number) = contact.strip_prefix("tel:") {
+        if number.len() > 3
+            && number
+                .chars()
+                .all(|c| c.is_ascii_digit() || "+-() ".contains(c))
+        {
+            return Ok(contact.to_string());
+        }
+        return Err(invalid());
+    }
+    let address = contact.strip_prefix("mailto:").unwrap_or(contact);
+    match address.split_once('@') {
+        Some((user, domain))
+            if !user.is_empty()
+                && domain.contains('.')
+                && !address.contains(char::is_whitespace) =>
+        {
+            Ok(format!("mailto:{}", address))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn validate_currency(currency: &str) -> Result<String, String> {
+    let currency = currency.trim().to_ascii_uppercase();
+    if currency.is_empty()
+        || currency.len() > 10
+        || !currency.chars().all(|c| c.is_ascii_alphanumeric())
+    {
+        return Err(format!(
+            "payout_currency '{}' is not a currency code",
+            currency
+        ));
+    }
+    Ok(currency)
+}
+
+/// Renders the program as an RFC 9116 security.txt. Fields the RFC does not
+/// define (program page, scope, payout) are included as comments.
+fn security_txt(bounty: &BugBounty, contract_id: &str, name: &str) -> String {
+    let expires = bounty
+        .expires_at
+        .unwrap_or(bounty.updated_at + Duration::days(DEFAULT_VALIDITY_DAYS));
+    let mut out = format!(
+        "# Vulnerability disclosure for {} ({})\n",
+        name, contract_id
+    );
+    out.push_str(&format!("# Bug bounty program: {}\n", bounty.program_url));
+    if let Some(scope) = &bounty.scope {
+        for line in scope.lines() {
+            out.push_str(&format!("# Scope: {}\n", line));
+        }
+    }
+    if let Some(max) = bounty.max_payout {
+        out.push_str(&format!(
+            "# Max payout: {} {}\n",
+            max, bounty.payout_currency
+        ));
+    }
+    out.push('\n');
+    out.push_str(&format!("Contact: {}\n", bounty.contact));
+    out.push_str(&format!(
+        "Expires: {}\n",
+        expires.to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+    ));
+    out.push_str(&format!(
+        "Policy: {}\n",
+        bounty.policy_url.as_deref().unwrap_or(&bounty.program_url)
+    ));
+    out
+}
+
+async fn fetch_bounty(state: &AppState, contract_uuid: Uuid) -> ApiResult<BugBounty> {
+    sqlx::query_as("SELECT * FROM contract_bug_bounties WHERE contract_id = $1")
+        .bind(contract_uuid)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch bug bounty", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "BugBountyNotFound",
+                "This contract has no bug bounty program listed",
+            )
+        })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/bug-bounty",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "The contract's bug bounty program", body = BugBounty),
+        (status = 404, description = "Contract not found, or no program listed")
+    ),
+    tag = "Security"
+)]
+pub async fn get_bug_bounty(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<BugBounty>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    Ok(Json(fetch_bounty(&state, contract_uuid).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/contracts/{id}/bug-bounty",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SetBugBountyRequest,
+    responses(
+        (status = 200, description = "Program saved", body = BugBounty),
+        (status = 400, description = "Invalid URL, contact, payout or expiry"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Security"
+)]
+pub async fn set_bug_bounty(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(req): Json<SetBugBountyRequest>,
+) -> ApiResult<Json<BugBounty>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "list its bug bounty").await?;
+
+    let program_url = req.program_url.trim();
+    validate_https_url_only(program_url).map_err(invalid)?;
+    let policy_url = req
+        .policy_url
+        .as_deref()
+        .map(str::trim)
+        .filter(|u| !u.is_empty());
+    if let Some(url) = policy_url {
+        validate_https_url_only(url).map_err(invalid)?;
+    }
+    let contact = normalize_contact(&req.contact).map_err(invalid)?;
+    let scope = req
+        .scope
+        .as_deref()
+        .map(str::trim)
+        .filter(|s| !s.is_empty());
+    if scope.is_some_and(|s| s.chars().count() > MAX_SCOPE_LEN) {
+        return Err(invalid(format!(
+            "scope must be at most {} characters",
+            MAX_SCOPE_LEN
+        )));
+    }
+    if req.max_payout.is_some_and(|max| max <= 0) {
+        return Err(invalid("max_payout must be positive"));
+    }
+    let currency =
+        validate_currency(req.payout_currency.as_deref().unwrap_or("USD")).map_err(invalid)?;
+    if req.expires_at.is_some_and(|at| at <= Utc::now()) {
+        return Err(invalid("expires_at must be in the future"));
+    }
+
+    let bounty = sqlx::query_as(
+        "INSERT INTO contract_bug_bounties
+            (contract_id, program_url, contact, scope, max_payout, payout_currency,
+             policy_url, expires_at)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET program_url = EXCLUDED.program_url,
+                contact = EXCLUDED.contact,
+                scope = EXCLUDED.scope,
+                max_payout = EXCLUDED.max_payout,
+                payout_currency = EXCLUDED.payout_currency,
+                policy_url = EXCLUDED.policy_url,
+                expires_at = EXCLUDED.expires_at,
+                updated_at = NOW()
+         RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(program_url)
+    .bind(&contact)
+    .bind(scope)
+    .bind(req.max_payout)
+    .bind(&currency)
+    .bind(policy_url)
+    .bind(req.expires_at)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("save bug bounty", err))?;
+
+    tracing::info!(contract = %contract_uuid, "bug bounty program listed");
+    Ok(Json(bounty))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/bug-bounty",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 204, description = "Program removed"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found, or no program listed")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Security"
+)]
+pub async fn delete_bug_bounty(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "remove its bug bounty").await?;
+
+    let deleted = sqlx::query("DELETE FROM contract_bug_bounties WHERE contract_id = $1")
+        .bind(contract_uuid)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("delete bug bounty", err))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(ApiError::not_found(
+            "BugBountyNotFound",
+            "This contract has no bug bounty program listed",
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/security.txt",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "RFC 9116 security.txt", content_type = "text/plain"),
+        (status = 404, description = "Contract not found, or no program listed")
+    ),
+    tag = "Security"
+)]
+pub async fn get_security_txt(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
+    let bounty = fetch_bounty(&state, contract_uuid).await?;
+    let name: String = sqlx::query_scalar("SELECT name FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch contract name", err))?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "text/plain; charset=utf-8")],
+        security_txt(&bounty, &contract_id, &name),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn contacts_become_uris() {
+        assert_eq!(
+            normalize_contact("security@example.com").unwrap(),
+            "mailto:security@example.com"
+        );
+        assert_eq!(
+            normalize_contact("mailto:security@example.com").unwrap(),
+            "mailto:security@example.com"
+        );
+        assert_eq!(
+            normalize_contact("tel:+1-201-555-0123").unwrap(),
+            "tel:+1-201-555-0123"
+        );
+        assert!(normalize_contact("http://example.com/report").is_err());
+        assert!(normalize_contact("ask on discord").is_err());
+    }
+
+    #[test]
+    fn renders_rfc_9116_fields() {
+        let updated_at = Utc.with_ymd_and_hms(2026, 4, 1, 12, 0, 0).unwrap();
+        let bounty = BugBounty {
+            contract_id: Uuid::nil(),
+            program_url: "https://immunefi.com/bounty/example".to_string(),
+            contact: "mailto:security@example.com".to_string(),
+            scope: Some("Vault contract\nExcludes the web UI".to_string()),
+            max_payout: Some(250_000),
+            payout_currency: "USDC".to_string(),
+            policy_url: None,
+            expires_at: None,
+            created_at: updated_at,
+            updated_at,
+        };
+        let txt = security_txt(&bounty, "CABC", "Vault");
+        assert!(txt.contains("# Scope: Vault contract\n# Scope: Excludes the web UI\n"));
+        assert!(txt.contains("# Max payout: 250000 USDC\n"));
+        assert!(txt.contains("Contact: mailto:security@example.com\n"));
+        assert!(txt.contains("Expires: 2027-04-01T12:00:00Z\n"));
+        assert!(txt.contains("Policy: https://immunefi.com/bounty/example\n"));
+    }
+}
@@ -0,0 +1,182 @@
+//! Build metadata of published versions
+//!
+//! Soroban contracts embed how they were built: the SDK writes `rssdkver`
+//! (soroban-sdk version, `22.0.7#<commit>`) and `rsver` (rustc version) into
+//! the `contractmetav0` custom section, and the protocol the contract
+//! targets into `contractenvmetav0`. Every `BUILD_META_INTERVAL_SECS`
+//! (default 300) the reader downloads the code of versions not read yet
+//! through Soroban RPC and records those values on `contract_versions`;
+//! [`crate::sdk_matrix_handlers`] serves them.
+
+use std::io::Cursor;
+use std::time::Duration;
+
+use shared::{Network, RegistryError};
+use sqlx::PgPool;
+use stellar_xdr::curr::{Limited, Limits, ReadXdr, ScEnvMetaEntry, ScMetaEntry};
+use uuid::Uuid;
+use wasmparser::{Parser, Payload};
+
+use crate::onchain_verification::OnChainVerifier;
+
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+const BATCH_SIZE: i64 = 50;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct BuildMeta {
+    pub sdk_version: Option<String>,
+    pub rustc_version: Option<String>,
+    pub protocol_version: Option<u32>,
+}
+
+/// Drops the commit the SDK appends to its version (`22.0.7#0f1c…`).
+pub fn release_version(raw: &str) -> Option<String> {
+    let version = raw.split('#').next().unwrap_or_default().trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+fn xdr_entries<T: ReadXdr>(data: &[u8]) -> Result<Vec<T>, String> {
+    let mut reader = Limited::new(Cursor::new(data), Limits::none());
+    T::read_xdr_iter(&mut reader)
+        .map(|entry| entry.map_err(|err| err.to_string()))
+        .collect()
+}
+
+/// Reads the build metadata embedded in a contract's WASM. Sections the
+/// build did not write are left `None`.
+pub fn read_build_meta(wasm: &[u8]) -> Result<BuildMeta, String> {
+    let mut meta = BuildMeta::default();
+    for payload in Parser::new(0).parse_all(wasm) {
+        let Payload::CustomSection(section) = payload.map_err(|err| err.to_string())? else {
+            continue;
+        };
+        match section.name() {
+            "contractmetav0" => {
+                for ScMetaEntry::ScMetaV0(entry) in xdr_entries::<ScMetaEntry>(section.data())? {
+                    let value = entry.val.to_utf8_string_lossy();
+                    match entry.key.to_utf8_string_lossy().as_str() {
+                        "rssdkver" => meta.sdk_version = release_version(&value),
+                        "rsver" => meta.rustc_version = release_version(&value),
+                        _ => {}
+                    }
+                }
+            }
+            "contractenvmetav0" => {
+                for ScEnvMetaEntry::ScEnvMetaKindInterfaceVersion(version) in
+                    xdr_entries::<ScEnvMetaEntry>(section.data())?
+                {
+                    meta.protocol_version = Some(version.protocol);
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(meta)
+}
+
+// ── Reader ────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, sqlx::FromRow)]
+struct UnreadVersion {
+    id: Uuid,
+    wasm_hash: String,
+    network: Network,
+}
+
+pub fn spawn_build_meta_reader(pool: PgPool) {
+    let interval_secs = std::env::var("BUILD_META_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let verifier = OnChainVerifier::new();
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = read_batch(&pool, &verifier).await {
+                tracing::error!(error = ?err, "build meta: reading versions failed");
+            }
+        }
+    });
+}
+
+async fn read_batch(pool: &PgPool, verifier: &OnChainVerifier) -> Result<(), sqlx::Error> {
+    let versions: Vec<UnreadVersion> = sqlx::query_as(
+        "SELECT cv.id, cv.wasm_hash, c.network
+         FROM contract_versions cv
+         JOIN contracts c ON c.id = cv.contract_id
+         WHERE cv.build_meta_read_at IS NULL AND c.deleted_at IS NULL
+         ORDER BY cv.created_at DESC
+         LIMIT $1",
+    )
+    .bind(BATCH_SIZE)
+    .fetch_all(pool)
+    .await?;
+
+    let mut read = 0;
+    for version in &versions {
+        let meta = match verifier
+            .fetch_contract_code(&version.network, &version.wasm_hash)
+            .await
+        {
+            Ok(Some(code)) => read_build_meta(&code).unwrap_or_else(|err| {
+                tracing::warn!(version = %version.id, error = %err, "build meta: unreadable WASM");
+                BuildMeta::default()
+            }),
+            // Not on the ledger (never uploaded, or archived): nothing to read.
+            Ok(None) => BuildMeta::default(),
+            // The RPC is likely unavailable; try again next round.
+            Err(RegistryError::StellarRpc(err)) => {
+                tracing::warn!(error = %err, "build meta: RPC unavailable");
+                break;
+            }
+            Err(err) => {
+                tracing::warn!(version = %version.id, error = %err, "build meta: cannot fetch code");
+                BuildMeta::default()
+            }
+        };
+        sqlx::query(
+            "UPDATE contract_versions
+             SET sdk_version = $2, rustc_version = $3, protocol_version = $4,
+                 build_meta_read_at = NOW()
+             WHERE id = $1",
+        )
+        .bind(version.id)
+        .bind(&meta.sdk_version)
+        .bind(&meta.rustc_version)
+        .bind(meta.protocol_version.map(|p| p as i32))
+        .execute(pool)
+        .await?;
+        read += 1;
+    }
+
+    if read > 0 {
+        tracing::info!(versions = read, "build meta: read contract versions");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sdk_commit_is_dropped() {
+        assert_eq!(
+            release_version("22.0.7#0f1c2d3e4f").as_deref(),
+            Some("22.0.7")
+        );
+        assert_eq!(release_version("1.84.0").as_deref(), Some("1.84.0"));
+        assert_eq!(release_version("#abc"), None);
+    }
+
+    #[test]
+    fn wasm_without_meta_sections_has_no_build_meta() {
+        // An empty module: magic number and version only.
+        let wasm = b"\0asm\x01\0\0\0";
+        assert_eq!(read_build_meta(wasm), Ok(BuildMeta::default()));
+        assert!(read_build_meta(b"not wasm").is_err());
+    }
+}
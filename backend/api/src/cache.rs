@@ -1,6 +1,8 @@
 use moka::future::Cache as MokaCache;
 use redis::aio::ConnectionManager;
+use shared::GraphResponse;
 use sqlx::PgPool;
+use std::future::Future;
 use std::sync::Arc;
 use std::time::Duration;
 use redis::aio::ConnectionManager;
@@ -62,6 +64,7 @@ pub struct CacheLayer {
     pub verification_cache: MokaCache<String, String>,
     pub generic_cache: MokaCache<String, String>,
     pub contract_access_cache: MokaCache<String, bool>,
+    pub graph_cache: MokaCache<String, Arc<GraphResponse>>,
     config: CacheConfig,
     pub redis_cm: Option<ConnectionManager>,
 }
@@ -95,6 +98,14 @@ impl CacheLayer {
             .time_to_live(Duration::from_secs(60))
             .build();
 
+        // Materialised dependency graphs, one per network filter. Writes on
+        // this instance invalidate them; the TTL bounds staleness from writes
+        // handled by other instances.
+        let graph_cache = MokaCache::builder()
+            .max_capacity(16)
+            .time_to_live(Duration::from_secs(300))
+            .build();
+
         let redis_cm = if config.redis_enabled {
             if let Some(url) = &config.redis_url {
                 match redis::Client::open(url.as_str()) {
@@ -122,6 +133,7 @@ impl CacheLayer {
             verification_cache,
             generic_cache,
             contract_access_cache,
+            graph_cache,
             redis_cm,
             config,
         }
@@ -238,6 +250,40 @@ impl CacheLayer {
         self.generic_cache.invalidate(&namespaced_key).await;
     }
 
+    /// The cached dependency graph under `key`, running `build` on a miss.
+    /// Concurrent misses for the same key share a single build.
+    pub async fn dependency_graph(
+        &self,
+        key: &str,
+        build: impl Future<Output = anyhow::Result<GraphResponse>>,
+    ) -> anyhow::Result<Arc<GraphResponse>> {
+        if !self.config.enabled {
+            return build.await.map(Arc::new);
+        }
+
+        let mut built = false;
+        let graph = self
+            .graph_cache
+            .try_get_with(key.to_string(), async {
+                built = true;
+                build.await.map(Arc::new)
+            })
+            .await
+            .map_err(|err| anyhow::anyhow!("{}", err))?;
+
+        if built {
+            crate::metrics::CACHE_MISSES.inc();
+        } else {
+            crate::metrics::CACHE_HITS.inc();
+        }
+        Ok(graph)
+    }
+
+    /// Drops every materialised dependency graph, after a dependency change.
+    pub fn invalidate_dependency_graphs(&self) {
+        self.graph_cache.invalidate_all();
+    }
+
     pub async fn should_refresh_contract_access(&self, contract_id: &str) -> bool {
         if !self.config.enabled {
             return true;
@@ -461,4 +507,25 @@ mod tests {
         assert!(!cache.should_refresh_contract_access("contract-1").await);
         assert!(cache.should_refresh_contract_access("contract-2").await);
     }
+
+    #[tokio::test]
+    async fn test_dependency_graph_is_built_once_until_invalidated() {
+        let cache = CacheLayer::new(CacheConfig::default()).await;
+        let builds = std::sync::atomic::AtomicUsize::new(0);
+        let build = || async {
+            builds.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Ok(GraphResponse {
+                nodes: vec![],
+                edges: vec![],
+            })
+        };
+
+        cache.dependency_graph("all", build()).await.unwrap();
+        cache.dependency_graph("all", build()).await.unwrap();
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 1);
+
+        cache.invalidate_dependency_graphs();
+        cache.dependency_graph("all", build()).await.unwrap();
+        assert_eq!(builds.load(std::sync::atomic::Ordering::SeqCst), 2);
+    }
 }
@@ -8,10 +8,16 @@
 //!   PUT    /api/admin/categories/:id          – update a category
 //!   DELETE /api/admin/categories/:id          – delete a category
 //!
+//! Categories form a tree. Each has a `path` built from the slugs of its
+//! ancestors (`defi/amm`), and `contracts.category` is a foreign key to that
+//! path, so renaming or moving a category carries its contracts along.
+//! Searching by a category also matches everything beneath it.
+//!
 //! Deletion is guarded by a usage check: if any contracts currently reference
-//! the category by name the request is rejected with 409 Conflict unless the
+//! the category the request is rejected with 409 Conflict unless the
 //! `force=true` query parameter is supplied, in which case those contracts have
-//! their category field cleared before the category row is removed.
+//! their category field cleared before the category row is removed. Categories
+//! with subcategories cannot be deleted.
 
 use axum::{
     extract::{Path, Query, State},
@@ -62,16 +68,17 @@ pub struct DeleteCategoryQuery {
 
 /// Raw row returned by the list query (includes computed usage_count).
 #[derive(Debug, FromRow)]
-struct CategoryRow {
-    id: Uuid,
-    name: String,
-    slug: String,
-    description: Option<String>,
-    parent_id: Option<Uuid>,
-    is_default: bool,
-    usage_count: i64,
-    created_at: DateTime<Utc>,
-    updated_at: DateTime<Utc>,
+pub(crate) struct CategoryRow {
+    pub(crate) id: Uuid,
+    pub(crate) name: String,
+    pub(crate) slug: String,
+    pub(crate) path: String,
+    pub(crate) description: Option<String>,
+    pub(crate) parent_id: Option<Uuid>,
+    pub(crate) is_default: bool,
+    pub(crate) usage_count: i64,
+    pub(crate) created_at: DateTime<Utc>,
+    pub(crate) updated_at: DateTime<Utc>,
 }
 
 /// Public-facing category representation returned by all endpoints.
@@ -80,6 +87,9 @@ pub struct CategoryResponse {
     pub id: String,
     pub name: String,
     pub slug: String,
+    /// Slugs from the root down, e.g. `defi/amm`. This is the value stored in
+    /// a contract's `category`.
+    pub path: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     /// UUID of the parent category, or `null` for top-level categories.
@@ -100,6 +110,7 @@ impl From<CategoryRow> for CategoryResponse {
             id: row.id.to_string(),
             name: row.name,
             slug: row.slug,
+            path: row.path,
             description: row.description,
             parent_id: row.parent_id.map(|id| id.to_string()),
             is_default: row.is_default,
@@ -130,6 +141,22 @@ fn to_slug(name: &str) -> String {
         .join("-")
 }
 
+/// Path of a category with `slug` under the category at `parent_path`.
+fn child_path(parent_path: Option<&str>, slug: &str) -> String {
+    match parent_path {
+        Some(parent) => format!("{}/{}", parent, slug),
+        None => slug.to_string(),
+    }
+}
+
+/// Whether `path` is `ancestor` itself or lies beneath it.
+fn is_within(path: &str, ancestor: &str) -> bool {
+    path == ancestor
+        || path
+            .strip_prefix(ancestor)
+            .is_some_and(|rest| rest.starts_with('/'))
+}
+
 // ── Serde helper for nullable optional UUID ───────────────────────────────────
 
 /// Deserialises `Option<Option<String>>` so the caller can distinguish between
@@ -146,11 +173,13 @@ where
 
 // ── Internal helpers ──────────────────────────────────────────────────────────
 
-const LIST_QUERY: &str = r#"
+/// Category columns plus usage count; callers add WHERE / GROUP BY / ORDER BY.
+const CATEGORY_SELECT: &str = r#"
     SELECT
         cc.id,
         cc.name,
         cc.slug,
+        cc.path,
         cc.description,
         cc.parent_id,
         cc.is_default,
@@ -158,11 +187,12 @@ const LIST_QUERY: &str = r#"
         cc.updated_at,
         COUNT(c.id) AS usage_count
     FROM contract_categories cc
-    LEFT JOIN contracts c ON c.category = cc.name
-    GROUP BY cc.id
-    ORDER BY cc.is_default DESC, cc.name ASC
+    LEFT JOIN contracts c ON c.category = cc.path
 "#;
 
+/// Longest path `contracts.category` can hold.
+const MAX_PATH_LEN: usize = 100;
+
 fn db_err(op: &str, err: sqlx::Error) -> ApiError {
     tracing::error!(operation = op, error = ?err, "database operation failed");
     ApiError::internal("An unexpected database error occurred")
@@ -190,12 +220,85 @@ fn parse_optional_parent_id(raw: &Option<String>) -> ApiResult<Option<Uuid>> {
     }
 }
 
+async fn fetch_parent_path(state: &AppState, parent_id: Uuid) -> ApiResult<String> {
+    sqlx::query_scalar("SELECT path FROM contract_categories WHERE id = $1")
+        .bind(parent_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_err("fetch parent category", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ParentCategoryNotFound",
+                format!("No parent category found with ID: {}", parent_id),
+            )
+        })
+}
+
+/// Resolves what a publisher typed as a category to its canonical path.
+///
+/// Accepts a path (`defi/amm`) or a category name or slug (`AMM`), compared
+/// case-insensitively. A name shared by categories in different branches is
+/// rejected as ambiguous rather than guessed.
+pub(crate) async fn resolve_category(state: &AppState, raw: &str) -> ApiResult<String> {
+    let needle = raw.trim().to_lowercase();
+    let matches: Vec<String> = sqlx::query_scalar(
+        "SELECT path FROM contract_categories
+         WHERE path = $1 OR slug = $1 OR lower(name) = $1
+         ORDER BY (path = $1) DESC, path ASC",
+    )
+    .bind(&needle)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("resolve category", err))?;
+
+    match matches.as_slice() {
+        [] => Err(ApiError::bad_request(
+            "UnknownCategory",
+            format!(
+                "'{}' is not a registry category; see GET /api/categories",
+                raw.trim()
+            ),
+        )),
+        [only] => Ok(only.clone()),
+        [first, ..] if *first == needle => Ok(first.clone()),
+        several => Err(ApiError::bad_request(
+            "AmbiguousCategory",
+            format!(
+                "'{}' matches several categories; use one of: {}",
+                raw.trim(),
+                several.join(", ")
+            ),
+        )),
+    }
+}
+
+/// Restricts a contract query (aliased `c`) to `categories` and everything
+/// beneath them. Entries may be paths or category names.
+pub(crate) fn push_subtree_filter(
+    qb: &mut sqlx::QueryBuilder<'_, sqlx::Postgres>,
+    categories: &[String],
+) {
+    let needles: Vec<String> = categories
+        .iter()
+        .map(|category| category.trim().to_lowercase())
+        .collect();
+    qb.push(
+        " AND c.category IN (SELECT d.path FROM contract_categories p \
+         JOIN contract_categories d ON d.path = p.path OR starts_with(d.path, p.path || '/') \
+         WHERE p.path = ANY(",
+    );
+    qb.push_bind(needles.clone());
+    qb.push(") OR lower(p.name) = ANY(");
+    qb.push_bind(needles);
+    qb.push("))");
+}
+
 // ── Handlers ──────────────────────────────────────────────────────────────────
 
 /// List all categories with their current usage counts.
 ///
-/// Results are ordered so that default (seeded) categories appear first,
-/// followed by custom categories alphabetically.
+/// Results are ordered by path, so each category is followed by its
+/// subcategories.
 #[utoipa::path(
     get,
     path = "/api/categories",
@@ -207,10 +310,13 @@ fn parse_optional_parent_id(raw: &Option<String>) -> ApiResult<Option<Uuid>> {
 pub async fn list_categories(
     State(state): State<AppState>,
 ) -> ApiResult<Json<Vec<CategoryResponse>>> {
-    let rows: Vec<CategoryRow> = sqlx::query_as(LIST_QUERY)
-        .fetch_all(&state.db)
-        .await
-        .map_err(|err| db_err("list categories", err))?;
+    let rows: Vec<CategoryRow> = sqlx::query_as(&format!(
+        "{} GROUP BY cc.id ORDER BY cc.path ASC",
+        CATEGORY_SELECT
+    ))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list categories", err))?;
 
     Ok(Json(rows.into_iter().map(CategoryResponse::from).collect()))
 }
@@ -235,8 +341,8 @@ pub async fn get_category(
     let category_uuid = parse_category_id(&id)?;
 
     let row: CategoryRow = sqlx::query_as(&format!(
-        "{} WHERE cc.id = $1",
-        LIST_QUERY.trim_end_matches("ORDER BY cc.is_default DESC, cc.name ASC")
+        "{} WHERE cc.id = $1 GROUP BY cc.id",
+        CATEGORY_SELECT
     ))
     .bind(category_uuid)
     .fetch_one(&state.db)
@@ -254,8 +360,8 @@ pub async fn get_category(
 
 /// Create a new contract category.
 ///
-/// The slug is derived automatically from the name.  Returns 409 Conflict if a
-/// category with the same name or slug already exists.
+/// The slug is derived automatically from the name and appended to the
+/// parent's path.  Returns 409 Conflict if a sibling already has that slug.
 #[utoipa::path(
     post,
     path = "/api/admin/categories",
@@ -290,29 +396,26 @@ pub async fn create_category(
 
     let parent_uuid = parse_optional_parent_id(&req.parent_id)?;
 
-    // If a parent was supplied, verify it exists to give a clear error message
-    // rather than a cryptic FK violation.
-    if let Some(pid) = parent_uuid {
-        let parent_exists: bool =
-            sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contract_categories WHERE id = $1)")
-                .bind(pid)
-                .fetch_one(&state.db)
-                .await
-                .map_err(|err| db_err("verify parent category", err))?;
-
-        if !parent_exists {
-            return Err(ApiError::not_found(
-                "ParentCategoryNotFound",
-                format!("No parent category found with ID: {}", pid),
-            ));
-        }
+    let parent_path = match parent_uuid {
+        Some(pid) => Some(fetch_parent_path(&state, pid).await?),
+        None => None,
+    };
+    let path = child_path(parent_path.as_deref(), &slug);
+    if path.len() > MAX_PATH_LEN {
+        return Err(ApiError::bad_request(
+            "InvalidName",
+            format!(
+                "category path '{}' exceeds {} characters",
+                path, MAX_PATH_LEN
+            ),
+        ));
     }
 
     let row: CategoryRow = sqlx::query_as(
         r#"
         WITH inserted AS (
-            INSERT INTO contract_categories (name, slug, description, parent_id)
-            VALUES ($1, $2, $3, $4)
+            INSERT INTO contract_categories (name, slug, description, parent_id, path)
+            VALUES ($1, $2, $3, $4, $5)
             RETURNING *
         )
         SELECT i.*, 0::BIGINT AS usage_count
@@ -323,12 +426,13 @@ pub async fn create_category(
     .bind(&slug)
     .bind(req.description.as_deref())
     .bind(parent_uuid)
+    .bind(&path)
     .fetch_one(&state.db)
     .await
     .map_err(|err| match err {
         sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
             "CategoryAlreadyExists",
-            format!("A category named '{}' already exists", name),
+            format!("A category with path '{}' already exists", path),
         ),
         _ => db_err("create category", err),
     })?;
@@ -339,7 +443,9 @@ pub async fn create_category(
 /// Update a category's name, description, or parent.
 ///
 /// Only fields that are explicitly included in the JSON body are changed.
-/// Changing the name automatically regenerates the slug.
+/// Changing the name automatically regenerates the slug.  When the slug or
+/// parent changes, the paths of the category and all of its descendants are
+/// rewritten, and contracts filed under them follow via the foreign key.
 #[utoipa::path(
     put,
     path = "/api/admin/categories/{id}",
@@ -349,9 +455,9 @@ pub async fn create_category(
     request_body = UpdateCategoryRequest,
     responses(
         (status = 200, description = "Category updated successfully", body = CategoryResponse),
-        (status = 400, description = "Invalid input"),
+        (status = 400, description = "Invalid input, or the new parent is inside this category"),
         (status = 404, description = "Category not found"),
-        (status = 409, description = "A category with the new name already exists")
+        (status = 409, description = "A sibling category already has the new slug")
     ),
     tag = "Categories",
     security(("bearer_auth" = []))
@@ -363,150 +469,140 @@ pub async fn update_category(
 ) -> ApiResult<Json<CategoryResponse>> {
     let category_uuid = parse_category_id(&id)?;
 
-    // Confirm the category exists before attempting the update.
-    let exists: bool =
-        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contract_categories WHERE id = $1)")
+    let current: Option<(String, String, Option<Uuid>)> =
+        sqlx::query_as("SELECT slug, path, parent_id FROM contract_categories WHERE id = $1")
             .bind(category_uuid)
-            .fetch_one(&state.db)
+            .fetch_optional(&state.db)
             .await
-            .map_err(|err| db_err("check category existence", err))?;
-
-    if !exists {
-        return Err(ApiError::not_found(
+            .map_err(|err| db_err("fetch category for update", err))?;
+    let (current_slug, current_path, current_parent) = current.ok_or_else(|| {
+        ApiError::not_found(
             "CategoryNotFound",
             format!("No category found with ID: {}", id),
-        ));
-    }
+        )
+    })?;
 
-    // Resolve the new parent UUID if one was supplied.
-    let new_parent: Option<Option<Uuid>> = match &req.parent_id {
-        None => None, // field was omitted → do not change parent
-        Some(inner) => {
-            let uuid = parse_optional_parent_id(inner)?;
-            // Guard against self-referencing loops.
-            if let Some(pid) = uuid {
-                if pid == category_uuid {
-                    return Err(ApiError::bad_request(
-                        "CircularParent",
-                        "A category cannot be its own parent",
-                    ));
-                }
-                let parent_exists: bool = sqlx::query_scalar(
-                    "SELECT EXISTS(SELECT 1 FROM contract_categories WHERE id = $1)",
-                )
-                .bind(pid)
-                .fetch_one(&state.db)
-                .await
-                .map_err(|err| db_err("verify parent on update", err))?;
-
-                if !parent_exists {
-                    return Err(ApiError::not_found(
-                        "ParentCategoryNotFound",
-                        format!("No parent category found with ID: {}", pid),
-                    ));
-                }
-            }
-            Some(uuid)
+    let name_val = req.name.as_deref().map(str::trim);
+    if let Some(name) = name_val {
+        if name.is_empty() || name.len() > 100 {
+            return Err(ApiError::bad_request(
+                "InvalidName",
+                "name must be between 1 and 100 characters",
+            ));
         }
+    }
+    let slug = match name_val.map(to_slug) {
+        Some(slug) if slug.is_empty() => {
+            return Err(ApiError::bad_request(
+                "InvalidName",
+                "name must contain at least one alphanumeric character",
+            ))
+        }
+        Some(slug) => slug,
+        None => current_slug,
     };
 
-    // Build the UPDATE statement dynamically so we only touch supplied fields.
-    let updated: CategoryRow = {
-        let name_val = req.name.as_deref().map(str::trim);
-        if let Some(name) = name_val {
-            if name.is_empty() || name.len() > 100 {
+    // Resolve the parent: omitted keeps the current one, null makes it top-level.
+    let parent_uuid = match &req.parent_id {
+        None => current_parent,
+        Some(inner) => parse_optional_parent_id(inner)?,
+    };
+    let parent_path = match parent_uuid {
+        Some(pid) => {
+            let parent_path = fetch_parent_path(&state, pid).await?;
+            // Guard against loops: the parent cannot be this category or one
+            // of its descendants.
+            if is_within(&parent_path, &current_path) {
                 return Err(ApiError::bad_request(
-                    "InvalidName",
-                    "name must be between 1 and 100 characters",
+                    "CircularParent",
+                    "A category cannot be moved under itself or one of its subcategories",
                 ));
             }
+            Some(parent_path)
         }
+        None => None,
+    };
+    let path = child_path(parent_path.as_deref(), &slug);
+    if path.len() > MAX_PATH_LEN {
+        return Err(ApiError::bad_request(
+            "InvalidName",
+            format!(
+                "category path '{}' exceeds {} characters",
+                path, MAX_PATH_LEN
+            ),
+        ));
+    }
 
-        let new_slug = name_val.map(to_slug);
-
-        match new_parent {
-            // parent unchanged
-            None => sqlx::query_as(
-                r#"
-                WITH updated AS (
-                    UPDATE contract_categories
-                    SET
-                        name        = COALESCE($2, name),
-                        slug        = COALESCE($3, slug),
-                        description = CASE WHEN $4::BOOLEAN THEN $5 ELSE description END,
-                        updated_at  = NOW()
-                    WHERE id = $1
-                    RETURNING *
-                )
-                SELECT u.*, (
-                    SELECT COUNT(*) FROM contracts c WHERE c.category = u.name
-                )::BIGINT AS usage_count
-                FROM updated u
-                "#,
-            )
-            .bind(category_uuid)
-            .bind(name_val)
-            .bind(new_slug.as_deref())
-            .bind(req.description.is_some())
-            .bind(req.description.as_deref())
-            .fetch_one(&state.db)
-            .await
-            .map_err(|err| match err {
-                sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
-                    ApiError::conflict(
-                        "CategoryAlreadyExists",
-                        "A category with this name already exists",
-                    )
-                }
-                _ => db_err("update category", err),
-            })?,
-
-            // parent explicitly changed (possibly to NULL)
-            Some(parent_uuid) => sqlx::query_as(
-                r#"
-                WITH updated AS (
-                    UPDATE contract_categories
-                    SET
-                        name        = COALESCE($2, name),
-                        slug        = COALESCE($3, slug),
-                        description = CASE WHEN $4::BOOLEAN THEN $5 ELSE description END,
-                        parent_id   = $6,
-                        updated_at  = NOW()
-                    WHERE id = $1
-                    RETURNING *
-                )
-                SELECT u.*, (
-                    SELECT COUNT(*) FROM contracts c WHERE c.category = u.name
-                )::BIGINT AS usage_count
-                FROM updated u
-                "#,
-            )
-            .bind(category_uuid)
-            .bind(name_val)
-            .bind(new_slug.as_deref())
-            .bind(req.description.is_some())
-            .bind(req.description.as_deref())
-            .bind(parent_uuid)
-            .fetch_one(&state.db)
-            .await
-            .map_err(|err| match err {
-                sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
-                    ApiError::conflict(
-                        "CategoryAlreadyExists",
-                        "A category with this name already exists",
-                    )
-                }
-                _ => db_err("update category with parent", err),
-            })?,
-        }
+    let conflict = |err: sqlx::Error, op: &str| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "CategoryAlreadyExists",
+            format!("A category with path '{}' already exists", path),
+        ),
+        _ => db_err(op, err),
     };
 
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin category update", err))?;
+
+    // Descendants first, so the path constraint never sees a half-moved tree.
+    if path != current_path {
+        sqlx::query(
+            "UPDATE contract_categories
+             SET path = $2 || substr(path, length($1) + 1), updated_at = NOW()
+             WHERE starts_with(path, $1 || '/')",
+        )
+        .bind(&current_path)
+        .bind(&path)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| conflict(err, "move subcategories"))?;
+    }
+
+    let updated: CategoryRow = sqlx::query_as(
+        r#"
+        WITH updated AS (
+            UPDATE contract_categories
+            SET
+                name        = COALESCE($2, name),
+                slug        = $3,
+                description = CASE WHEN $4::BOOLEAN THEN $5 ELSE description END,
+                parent_id   = $6,
+                path        = $7,
+                updated_at  = NOW()
+            WHERE id = $1
+            RETURNING *
+        )
+        SELECT u.*, (
+            SELECT COUNT(*) FROM contracts c WHERE c.category = u.path
+        )::BIGINT AS usage_count
+        FROM updated u
+        "#,
+    )
+    .bind(category_uuid)
+    .bind(name_val)
+    .bind(&slug)
+    .bind(req.description.is_some())
+    .bind(req.description.as_deref())
+    .bind(parent_uuid)
+    .bind(&path)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| conflict(err, "update category"))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit category update", err))?;
+
     Ok(Json(CategoryResponse::from(updated)))
 }
 
 /// Delete a category.
 ///
-/// Default categories (`is_default = true`) cannot be deleted.
+/// Default categories (`is_default = true`) cannot be deleted, and neither can
+/// categories that still have subcategories.
 ///
 /// If any contracts are currently assigned to this category:
 ///   - Without `?force=true` the request fails with **409 Conflict** and
@@ -525,7 +621,7 @@ pub async fn update_category(
         (status = 400, description = "Invalid category ID"),
         (status = 403, description = "Cannot delete a default category"),
         (status = 404, description = "Category not found"),
-        (status = 409, description = "Category is in use; supply ?force=true to override")
+        (status = 409, description = "Category is in use or has subcategories")
     ),
     tag = "Categories",
     security(("bearer_auth" = []))
@@ -537,16 +633,16 @@ pub async fn delete_category(
 ) -> ApiResult<StatusCode> {
     let category_uuid = parse_category_id(&id)?;
 
-    // Fetch the category so we can check is_default and name in one query.
-    let row = sqlx::query_as::<_, (String, bool)>(
-        "SELECT name, is_default FROM contract_categories WHERE id = $1",
+    // Fetch the category so we can check is_default, name and path in one query.
+    let row = sqlx::query_as::<_, (String, String, bool)>(
+        "SELECT name, path, is_default FROM contract_categories WHERE id = $1",
     )
     .bind(category_uuid)
     .fetch_optional(&state.db)
     .await
     .map_err(|err| db_err("fetch category for delete", err))?;
 
-    let (name, is_default) = row.ok_or_else(|| {
+    let (name, path, is_default) = row.ok_or_else(|| {
         ApiError::not_found(
             "CategoryNotFound",
             format!("No category found with ID: {}", id),
@@ -561,9 +657,22 @@ pub async fn delete_category(
         ));
     }
 
+    let has_children: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM contract_categories WHERE parent_id = $1)")
+            .bind(category_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_err("check subcategories", err))?;
+    if has_children {
+        return Err(ApiError::conflict(
+            "CategoryHasChildren",
+            format!("'{}' has subcategories; move or delete them first", path),
+        ));
+    }
+
     // Count contracts currently using this category.
     let usage_count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE category = $1")
-        .bind(&name)
+        .bind(&path)
         .fetch_one(&state.db)
         .await
         .map_err(|err| db_err("count category usage", err))?;
@@ -582,7 +691,7 @@ pub async fn delete_category(
     // With force=true: clear the category field on affected contracts first.
     if usage_count > 0 && query.force {
         sqlx::query("UPDATE contracts SET category = NULL WHERE category = $1")
-            .bind(&name)
+            .bind(&path)
             .execute(&state.db)
             .await
             .map_err(|err| db_err("clear contracts category on force delete", err))?;
@@ -624,6 +733,20 @@ mod tests {
         assert_eq!(to_slug("  Bridge  "), "bridge");
     }
 
+    #[test]
+    fn child_path_joins_parent_and_slug() {
+        assert_eq!(child_path(None, "defi"), "defi");
+        assert_eq!(child_path(Some("defi"), "amm"), "defi/amm");
+    }
+
+    #[test]
+    fn is_within_matches_whole_segments_only() {
+        assert!(is_within("defi", "defi"));
+        assert!(is_within("defi/amm", "defi"));
+        assert!(!is_within("defi-tools", "defi"));
+        assert!(!is_within("defi", "defi/amm"));
+    }
+
     #[test]
     fn parse_category_id_rejects_non_uuid() {
         assert!(parse_category_id("not-a-uuid").is_err());
@@ -0,0 +1,180 @@
+//! Registry change feed
+//!
+//!   GET /api/changes?since=<seq>&limit=<n>
+//!
+//! An ordered log of what changed in the registry, for downstream indexers
+//! and mirrors to stay consistent without re-crawling: contracts created,
+//! updated, hidden, deleted and restored, versions published, yanked and
+//! unyanked, security patches published and applied. Entries are appended by
+//! database triggers (see the `registry_changes` migration), so no write path
+//! can skip them.
+//!
+//! Consumers keep the `next_since` of each page and pass it back as `since`.
+//! Triggers cannot number entries themselves without a slow transaction
+//! committing seq 10 after a consumer has read seq 11, so each read first
+//! numbers the entries whose transactions, and every transaction that could
+//! still write before them, have finished. Numbers are gap-free and never
+//! change; a long-running transaction holds the feed back until it ends.
+//! Entries carry identifiers only; consumers fetch current state from the
+//! regular endpoints.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const DEFAULT_LIMIT: i64 = 500;
+const MAX_LIMIT: i64 = 1000;
+/// Advisory lock serialising readers while they number entries.
+const NUMBERING_LOCK: i64 = 0x7265_6763_6867;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ChangeFeedQuery {
+    /// Only entries after this sequence number; 0 or omitted for the start
+    #[serde(default)]
+    pub since: i64,
+    /// Page size, 1-1000 (default 500)
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct RegistryChange {
+    pub seq: i64,
+    /// `contract_created`, `contract_updated`, `contract_hidden`,
+    /// `contract_deleted`, `contract_restored`, `version_published`,
+    /// `version_yanked`, `version_unyanked`, `patch_published` or
+    /// `patch_applied`
+    pub kind: String,
+    /// Registry UUID of the contract, for contract and version changes
+    pub contract_id: Option<Uuid>,
+    /// On-chain contract ID
+    pub contract_address: Option<String>,
+    pub network: Option<Network>,
+    /// For version changes
+    pub version: Option<String>,
+    /// For patch changes
+    pub patch_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ChangeFeedPage {
+    /// Ordered by `seq`
+    pub changes: Vec<RegistryChange>,
+    /// Pass as `since` for the next page; equal to the request's `since`
+    /// when there was nothing new
+    pub next_since: i64,
+    /// More entries are available right away
+    pub has_more: bool,
+}
+
+/// Assigns `seq` to entries no running transaction can precede any more.
+/// Every transaction older than the snapshot's xmin has ended, so no entry
+/// with a lower transaction ID can appear later; numbering in transaction
+/// order keeps each transaction's entries together.
+async fn number_settled_changes(state: &AppState) -> ApiResult<()> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin numbering changes", err))?;
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(NUMBERING_LOCK)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("lock change numbering", err))?;
+    sqlx::query(
+        "WITH last AS (SELECT COALESCE(MAX(seq), 0) AS seq FROM registry_changes),
+              settled AS (
+                  SELECT id, ROW_NUMBER() OVER (ORDER BY tx_id, id) AS n
+                  FROM registry_changes
+                  WHERE seq IS NULL AND tx_id < pg_snapshot_xmin(pg_current_snapshot())
+              )
+         UPDATE registry_changes ch
+         SET seq = last.seq + settled.n
+         FROM last, settled
+         WHERE ch.id = settled.id",
+    )
+    .execute(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("number changes", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit numbering changes", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/changes",
+    params(ChangeFeedQuery),
+    responses(
+        (status = 200, description = "The next page of the change feed", body = ChangeFeedPage),
+        (status = 400, description = "Invalid since or limit")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_changes(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Query(query): Query<ChangeFeedQuery>,
+) -> ApiResult<Json<ChangeFeedPage>> {
+    if query.since < 0 {
+        return Err(ApiError::bad_request(
+            "InvalidChangeFeedQuery",
+            "since must not be negative",
+        ));
+    }
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+    if !(1..=MAX_LIMIT).contains(&limit) {
+        return Err(ApiError::bad_request(
+            "InvalidChangeFeedQuery",
+            format!("limit must be between 1 and {}", MAX_LIMIT),
+        ));
+    }
+
+    number_settled_changes(&state).await?;
+
+    // Private contracts' entries are left out, except the one recording
+    // that a contract stopped being public, so mirrors can drop it.
+    let mut changes: Vec<RegistryChange> = sqlx::query_as(
+        "SELECT ch.seq, ch.kind, ch.contract_id, ch.contract_address, ch.network,
+                ch.version, ch.patch_id, ch.created_at
+         FROM registry_changes ch
+         LEFT JOIN contracts c ON c.id = ch.contract_id
+         WHERE ch.seq > $1
+           AND (ch.tenant_id = $2 OR ch.tenant_id IS NULL)
+           AND (c.id IS NULL OR c.visibility = 'public' OR ch.kind = 'contract_hidden')
+         ORDER BY ch.seq
+         LIMIT $3",
+    )
+    .bind(query.since)
+    .bind(tenant.id)
+    .bind(limit + 1)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("read change feed", err))?;
+
+    let has_more = changes.len() as i64 > limit;
+    changes.truncate(limit as usize);
+    let next_since = changes.last().map_or(query.since, |change| change.seq);
+    Ok(Json(ChangeFeedPage {
+        changes,
+        next_since,
+        has_more,
+    }))
+}
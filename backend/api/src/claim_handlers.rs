@@ -0,0 +1,416 @@
+//! Ownership claims for auto-indexed and third-party-registered contracts
+//!
+//!   POST /api/contracts/:id/claim/challenge  – issue a one-time message for the deployer to sign
+//!   POST /api/contracts/:id/claim            – submit the signature and take ownership
+//!
+//! The deployer is the source account of the contract's deploy transaction,
+//! as read from the network by the indexer (`contracts.deployer_address`).
+//! A valid ed25519 signature over the challenge message by that account's key
+//! moves the contract to the deployer's publisher record and unlocks metadata
+//! editing for auto-indexed contracts.
+
+use axum::{
+    extract::{Path, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use rand::{distributions::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use shared::{AuditActionType, Contract};
+use uuid::Uuid;
+
+use crate::{
+    anchor, concurrency,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, extract_ip_address, write_contract_audit_log},
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const CHALLENGE_TTL_MINUTES: i64 = 10;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ClaimChallengeResponse {
+    /// On-chain contract id
+    pub contract_id: String,
+    pub network: String,
+    /// Account that must sign `message`
+    pub deployer_address: String,
+    /// Exact bytes to sign with the deployer's ed25519 key
+    pub message: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct ClaimRequest {
+    /// Hex-encoded ed25519 signature of the challenge message
+    pub signature: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ClaimTarget {
+    contract_id: String,
+    network: String,
+    auto_indexed: bool,
+    claimed_at: Option<DateTime<Utc>>,
+    publisher_address: String,
+    deployer_address: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct StoredChallenge {
+    deployer_address: String,
+    nonce: String,
+    expires_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Metadata of a crawler-registered contract is read-only until its deployer
+/// claims it.
+pub(crate) fn ensure_claimed(contract: &Contract) -> ApiResult<()> {
+    if contract.auto_indexed && contract.claimed_at.is_none() {
+        return Err(ApiError::forbidden_with_error(
+            "ContractUnclaimed",
+            "This contract was registered by the indexer; its deployer must claim it \
+             (POST /api/contracts/:id/claim) before it can be edited",
+        ));
+    }
+    Ok(())
+}
+
+fn claim_message(network: &str, contract_id: &str, nonce: &str) -> String {
+    format!(
+        "soroban-registry:claim:{}:{}:{}",
+        network, contract_id, nonce
+    )
+}
+
+fn verify_claim_signature(
+    deployer_address: &str,
+    message: &str,
+    signature_hex: &str,
+) -> Result<(), &'static str> {
+    let public_key = stellar_strkey::ed25519::PublicKey::from_string(deployer_address)
+        .map_err(|_| "deployer_not_an_account")?;
+    let signature: [u8; 64] = hex::decode(signature_hex.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or("invalid_signature_hex")?;
+    VerifyingKey::from_bytes(&public_key.0)
+        .map_err(|_| "invalid_public_key")?
+        .verify(message.as_bytes(), &Signature::from_bytes(&signature))
+        .map_err(|_| "invalid_signature")
+}
+
+async fn load_claim_target(state: &AppState, contract_uuid: Uuid) -> ApiResult<ClaimTarget> {
+    sqlx::query_as(
+        "SELECT c.contract_id, c.network::TEXT AS network, c.auto_indexed, c.claimed_at,
+                p.stellar_address AS publisher_address, c.deployer_address
+           FROM contracts c
+           JOIN publishers p ON p.id = c.publisher_id
+          WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load claim target", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/claim/challenge",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Challenge issued, or the unexpired one already pending", body = ClaimChallengeResponse),
+        (status = 404, description = "Contract not found"),
+        (status = 409, description = "Already owned by its deployer, or deployer unknown")
+    ),
+    tag = "Contracts"
+)]
+pub async fn create_claim_challenge(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ClaimChallengeResponse>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let target = load_claim_target(&state, contract_uuid).await?;
+
+    let Some(deployer_address) = target.deployer_address else {
+        return Err(ApiError::conflict(
+            "DeployerUnknown",
+            "No deployment of this contract has been indexed, so there is no deployer to claim it",
+        ));
+    };
+    if target.publisher_address == deployer_address
+        && (target.claimed_at.is_some() || !target.auto_indexed)
+    {
+        return Err(ApiError::conflict(
+            "AlreadyClaimed",
+            "This contract is already owned by its deployer",
+        ));
+    }
+
+    // An unexpired challenge for the same deployer is handed out again rather
+    // than replaced, so a second request cannot invalidate one being signed.
+    let nonce: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect();
+    let expires_at = Utc::now() + Duration::minutes(CHALLENGE_TTL_MINUTES);
+
+    let issued: Option<StoredChallenge> = sqlx::query_as(
+        "INSERT INTO contract_claim_challenges (contract_id, deployer_address, nonce, expires_at)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET deployer_address = EXCLUDED.deployer_address,
+                nonce = EXCLUDED.nonce,
+                expires_at = EXCLUDED.expires_at,
+                created_at = NOW()
+          WHERE contract_claim_challenges.expires_at <= NOW()
+             OR contract_claim_challenges.deployer_address <> EXCLUDED.deployer_address
+         RETURNING deployer_address, nonce, expires_at",
+    )
+    .bind(contract_uuid)
+    .bind(&deployer_address)
+    .bind(&nonce)
+    .bind(expires_at)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("store claim challenge", err))?;
+
+    let challenge = match issued {
+        Some(challenge) => challenge,
+        None => sqlx::query_as(
+            "SELECT deployer_address, nonce, expires_at
+               FROM contract_claim_challenges WHERE contract_id = $1",
+        )
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("load pending claim challenge", err))?,
+    };
+
+    Ok(Json(ClaimChallengeResponse {
+        message: claim_message(&target.network, &target.contract_id, &challenge.nonce),
+        contract_id: target.contract_id,
+        network: target.network,
+        deployer_address: challenge.deployer_address,
+        expires_at: challenge.expires_at,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/claim",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = ClaimRequest,
+    responses(
+        (status = 200, description = "Ownership transferred to the deployer", body = Contract),
+        (status = 400, description = "No pending challenge, or it expired"),
+        (status = 401, description = "Signature does not match the deployer's key"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn claim_contract(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    headers: HeaderMap,
+    Json(req): Json<ClaimRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let target = load_claim_target(&state, contract_uuid).await?;
+
+    // The challenge stays usable until a valid signature consumes it, so a
+    // bad signature from anyone else cannot burn the deployer's challenge.
+    let challenge: StoredChallenge = sqlx::query_as(
+        "SELECT deployer_address, nonce, expires_at
+           FROM contract_claim_challenges WHERE contract_id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load claim challenge", err))?
+    .ok_or_else(|| {
+        ApiError::bad_request(
+            "ClaimChallengeNotFound",
+            "Request a challenge (POST /api/contracts/:id/claim/challenge) and sign it first",
+        )
+    })?;
+    if challenge.expires_at < Utc::now() {
+        return Err(ApiError::bad_request(
+            "ClaimChallengeExpired",
+            "The claim challenge expired; request a new one",
+        ));
+    }
+
+    // The challenge must still name the indexed deployer.
+    if target.deployer_address.as_deref() != Some(challenge.deployer_address.as_str()) {
+        return Err(ApiError::bad_request(
+            "ClaimChallengeNotFound",
+            "The claim challenge is no longer valid; request a new one",
+        ));
+    }
+
+    let message = claim_message(&target.network, &target.contract_id, &challenge.nonce);
+    verify_claim_signature(&challenge.deployer_address, &message, &req.signature).map_err(
+        |reason| {
+            tracing::info!(contract = %contract_uuid, reason, "contract claim rejected");
+            ApiError::unauthorized("Signature does not prove control of the deployer account")
+        },
+    )?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin claim tx", err))?;
+
+    // Consume exactly the challenge that was signed; a concurrent claim with
+    // the same signature finds it gone.
+    let consumed =
+        sqlx::query("DELETE FROM contract_claim_challenges WHERE contract_id = $1 AND nonce = $2")
+            .bind(contract_uuid)
+            .bind(&challenge.nonce)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("consume claim challenge", err))?;
+    if consumed.rows_affected() == 0 {
+        return Err(ApiError::bad_request(
+            "ClaimChallengeNotFound",
+            "The claim challenge was already used; request a new one",
+        ));
+    }
+
+    let publisher_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO publishers (stellar_address, tenant_id) VALUES ($1, $2)
+         ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
+         RETURNING id",
+    )
+    .bind(&challenge.deployer_address)
+    .bind(tenant.id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("upsert deployer publisher", err))?;
+
+    let before_publisher_id: Uuid =
+        sqlx::query_scalar("SELECT publisher_id FROM contracts WHERE id = $1 FOR UPDATE")
+            .bind(contract_uuid)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("lock claimed contract", err))?;
+
+    let after: Contract = sqlx::query_as(
+        "UPDATE contracts
+            SET publisher_id = $2,
+                claimed_at = NOW(),
+                lock_version = lock_version + 1,
+                updated_at = NOW()
+          WHERE id = $1 AND deleted_at IS NULL
+          RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(publisher_id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("transfer claimed contract", err))?
+    .ok_or_else(|| {
+        ApiError::not_found("ContractNotFound", format!("Contract '{}' not found", id))
+    })?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit claim tx", err))?;
+
+    let changes = json!({
+        "publisher_id": { "before": before_publisher_id, "after": publisher_id },
+        "publisher_address": {
+            "before": target.publisher_address,
+            "after": challenge.deployer_address
+        },
+        "claimed": { "before": false, "after": true }
+    });
+    write_contract_audit_log(
+        &state.db,
+        AuditActionType::PublisherChanged,
+        after.id,
+        publisher_id,
+        changes,
+        &extract_ip_address(&headers),
+    )
+    .await
+    .map_err(|err| db_internal_error("write claim audit log", err))?;
+    anchor::enqueue_if_enabled(&state.db, after.id).await;
+
+    tracing::info!(
+        contract = %after.id,
+        deployer = %challenge.deployer_address,
+        "contract claimed by deployer"
+    );
+    Ok((
+        [(header::ETAG, concurrency::etag(after.lock_version))],
+        Json(after),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn deployer() -> (SigningKey, String) {
+        let key = SigningKey::from_bytes(&[7u8; 32]);
+        let address =
+            stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes()).to_string();
+        (key, address)
+    }
+
+    #[test]
+    fn accepts_signature_from_deployer_key() {
+        let (key, address) = deployer();
+        let message = claim_message("testnet", "CABC", "nonce");
+        let signature = hex::encode(key.sign(message.as_bytes()).to_bytes());
+        assert!(verify_claim_signature(&address, &message, &signature).is_ok());
+    }
+
+    #[test]
+    fn rejects_other_messages_and_keys() {
+        let (key, address) = deployer();
+        let message = claim_message("testnet", "CABC", "nonce");
+        let signature = hex::encode(key.sign(message.as_bytes()).to_bytes());
+
+        let other_message = claim_message("testnet", "CABC", "other");
+        assert_eq!(
+            verify_claim_signature(&address, &other_message, &signature),
+            Err("invalid_signature")
+        );
+
+        let other_key = SigningKey::from_bytes(&[9u8; 32]);
+        let other_address =
+            stellar_strkey::ed25519::PublicKey(other_key.verifying_key().to_bytes()).to_string();
+        assert_eq!(
+            verify_claim_signature(&other_address, &message, &signature),
+            Err("invalid_signature")
+        );
+        assert_eq!(
+            verify_claim_signature(&address, &message, "zz"),
+            Err("invalid_signature_hex")
+        );
+        assert_eq!(
+            verify_claim_signature("CABC", &message, &signature),
+            Err("deployer_not_an_account")
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! CLI release channel
+//!
+//!   GET  /api/cli/releases/latest                 – newest release and minimum supported CLI
+//!   POST /api/admin/cli/releases                  – publish a release
+//!   POST /api/admin/cli/releases/:version/yank    – stop offering a release
+//!
+//! `soroban-registry self-update` reads the latest release of its channel and
+//! downloads the artifact for its target triple. Artifacts are signed with
+//! the release key embedded in the CLI, which checks the signature itself;
+//! the registry only records it. `CLI_MIN_SUPPORTED_VERSION` is returned with
+//! every manifest so older CLIs can warn that they need upgrading.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::semver::SemVer;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const CHANNELS: &[&str] = &["stable", "beta"];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct CliReleaseArtifact {
+    /// Rust target triple, e.g. `x86_64-unknown-linux-gnu`
+    pub target: String,
+    /// Where the binary is downloaded from
+    pub url: String,
+    /// Hex SHA-256 of the binary
+    pub sha256: String,
+    /// Base64 Ed25519 signature over the release entry
+    /// `soroban-registry release\nversion: <version>\ntarget: <target>\nsha256: <sha256>\n`,
+    /// with the digest in lowercase hex
+    pub signature: String,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct CliRelease {
+    #[serde(skip)]
+    pub id: Uuid,
+    pub version: String,
+    pub channel: String,
+    pub notes: Option<String>,
+    pub published_at: DateTime<Utc>,
+    pub yanked_at: Option<DateTime<Utc>>,
+    #[sqlx(skip)]
+    pub artifacts: Vec<CliReleaseArtifact>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct CliReleaseManifest {
+    pub channel: String,
+    /// Oldest CLI version the registry still supports, if one is set
+    pub min_supported_version: Option<String>,
+    /// Newest release offered on the channel
+    pub latest: Option<CliRelease>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct LatestReleaseQuery {
+    /// `stable` (default) or `beta`; beta also sees stable releases
+    pub channel: Option<String>,
+    /// Only return the artifact for this target triple
+    pub target: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PublishCliReleaseRequest {
+    pub version: String,
+    /// Defaults to `stable`
+    pub channel: Option<String>,
+    pub notes: Option<String>,
+    pub artifacts: Vec<CliReleaseArtifact>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidCliRelease", message)
+}
+
+fn parse_channel(channel: Option<&str>) -> ApiResult<&'static str> {
+    let channel = channel.unwrap_or("stable");
+    CHANNELS
+        .iter()
+        .copied()
+        .find(|c| *c == channel)
+        .ok_or_else(|| invalid("channel must be 'stable' or 'beta'"))
+}
+
+fn validate_release(req: &PublishCliReleaseRequest) -> ApiResult<()> {
+    if SemVer::parse(&req.version).is_none() {
+        return Err(invalid("version must be a semantic version, e.g. 0.4.0"));
+    }
+    parse_channel(req.channel.as_deref())?;
+    if req.artifacts.is_empty() {
+        return Err(invalid("a release needs at least one artifact"));
+    }
+    for (i, artifact) in req.artifacts.iter().enumerate() {
+        if artifact.target.is_empty() || artifact.target.contains(char::is_whitespace) {
+            return Err(invalid("artifact target must be a target triple"));
+        }
+        if req.artifacts[..i]
+            .iter()
+            .any(|a| a.target == artifact.target)
+        {
+            return Err(invalid(format!(
+                "duplicate artifact for target '{}'",
+                artifact.target
+            )));
+        }
+        if !artifact.url.starts_with("https://") {
+            return Err(invalid("artifact url must use https"));
+        }
+        if artifact.sha256.len() != 64 || !artifact.sha256.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid("artifact sha256 must be 64 hex characters"));
+        }
+        let signature_ok = BASE64
+            .decode(artifact.signature.trim())
+            .map(|bytes| bytes.len() == 64)
+            .unwrap_or(false);
+        if !signature_ok {
+            return Err(invalid(
+                "artifact signature must be a base64 Ed25519 signature",
+            ));
+        }
+    }
+    Ok(())
+}
+
+async fn fetch_artifacts(
+    state: &AppState,
+    release_id: Uuid,
+    target: Option<&str>,
+) -> ApiResult<Vec<CliReleaseArtifact>> {
+    sqlx::query_as(
+        "SELECT target, url, sha256, signature FROM cli_release_artifacts
+         WHERE release_id = $1 AND ($2::TEXT IS NULL OR target = $2)
+         ORDER BY target",
+    )
+    .bind(release_id)
+    .bind(target)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch cli release artifacts", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/cli/releases/latest",
+    params(LatestReleaseQuery),
+    responses(
+        (status = 200, description = "Latest release and minimum supported version", body = CliReleaseManifest),
+        (status = 400, description = "Unknown channel")
+    ),
+    tag = "CLI"
+)]
+pub async fn get_latest_release(
+    State(state): State<AppState>,
+    Query(query): Query<LatestReleaseQuery>,
+) -> ApiResult<Json<CliReleaseManifest>> {
+    let channel = parse_channel(query.channel.as_deref())?;
+    let channels: Vec<&str> = match channel {
+        "beta" => CHANNELS.to_vec(),
+        _ => vec!["stable"],
+    };
+
+    let releases: Vec<CliRelease> =
+        sqlx::query_as("SELECT * FROM cli_releases WHERE yanked_at IS NULL AND channel = ANY($1)")
+            .bind(&channels)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("list cli releases", err))?;
+
+    // Versions are semver, which SQL cannot order; there are few releases.
+    let latest = releases
+        .into_iter()
+        .filter_map(|release| SemVer::parse(&release.version).map(|v| (v, release)))
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, release)| release);
+    let latest = match latest {
+        Some(mut release) => {
+            release.artifacts =
+                fetch_artifacts(&state, release.id, query.target.as_deref()).await?;
+            Some(release)
+        }
+        None => None,
+    };
+
+    Ok(Json(CliReleaseManifest {
+        channel: channel.to_string(),
+        min_supported_version: std::env::var("CLI_MIN_SUPPORTED_VERSION")
+            .ok()
+            .filter(|v| !v.trim().is_empty()),
+        latest,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/cli/releases",
+    request_body = PublishCliReleaseRequest,
+    responses(
+        (status = 201, description = "Release published", body = CliRelease),
+        (status = 400, description = "Invalid release"),
+        (status = 409, description = "The version was already published")
+    ),
+    tag = "CLI"
+)]
+pub async fn publish_release(
+    State(state): State<AppState>,
+    Json(req): Json<PublishCliReleaseRequest>,
+) -> ApiResult<(StatusCode, Json<CliRelease>)> {
+    validate_release(&req)?;
+    let channel = parse_channel(req.channel.as_deref())?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin cli release transaction", err))?;
+    let mut release: CliRelease = sqlx::query_as(
+        "INSERT INTO cli_releases (version, channel, notes) VALUES ($1, $2, $3) RETURNING *",
+    )
+    .bind(&req.version)
+    .bind(channel)
+    .bind(&req.notes)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "CliReleaseExists",
+            format!("CLI version {} was already published", req.version),
+        ),
+        _ => db_internal_error("insert cli release", err),
+    })?;
+    for artifact in &req.artifacts {
+        sqlx::query(
+            "INSERT INTO cli_release_artifacts (release_id, target, url, sha256, signature)
+             VALUES ($1, $2, $3, $4, $5)",
+        )
+        .bind(release.id)
+        .bind(&artifact.target)
+        .bind(&artifact.url)
+        .bind(artifact.sha256.to_ascii_lowercase())
+        .bind(artifact.signature.trim())
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("insert cli release artifact", err))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit cli release", err))?;
+
+    tracing::info!(version = %req.version, channel, "CLI release published");
+    release.artifacts = fetch_artifacts(&state, release.id, None).await?;
+    Ok((StatusCode::CREATED, Json(release)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/cli/releases/{version}/yank",
+    params(("version" = String, Path, description = "CLI version")),
+    responses(
+        (status = 200, description = "Release yanked", body = CliRelease),
+        (status = 404, description = "No release with that version")
+    ),
+    tag = "CLI"
+)]
+pub async fn yank_release(
+    State(state): State<AppState>,
+    Path(version): Path<String>,
+) -> ApiResult<Json<CliRelease>> {
+    let release: Option<CliRelease> = sqlx::query_as(
+        "UPDATE cli_releases SET yanked_at = COALESCE(yanked_at, NOW())
+         WHERE version = $1
+         RETURNING *",
+    )
+    .bind(&version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("yank cli release", err))?;
+
+    let mut release = release.ok_or_else(|| {
+        ApiError::not_found("CliReleaseNotFound", format!("No CLI release {}", version))
+    })?;
+    release.artifacts = fetch_artifacts(&state, release.id, None).await?;
+    Ok(Json(release))
+}
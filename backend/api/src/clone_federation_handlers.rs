@@ -93,6 +93,10 @@ pub async fn clone_contract(
 
     // Build new name
     let new_name = req.name.unwrap_or_else(|| format!("{} Clone", original.name));
+    let category = match req.category.as_deref() {
+        Some(raw) => Some(crate::category_handlers::resolve_category(&state, raw).await?),
+        None => original.category.clone(),
+    };
 
     // Start transaction
     let mut tx = state.db.begin().await.map_err(|err| {
@@ -116,7 +120,7 @@ pub async fn clone_contract(
     .bind(req.description.as_deref().or(original.description.as_deref()))
     .bind(publisher_id)
     .bind(&target_network)
-    .bind(req.category.as_deref().or(original.category.as_deref()))
+    .bind(&category)
     .bind(req.tags.as_deref().unwrap_or(&original.tags))
     .bind(original.id)
     .bind(original.logical_id)
@@ -712,11 +716,13 @@ async fn copy_contract_versions(tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
         INSERT INTO contract_versions (
             contract_id, version, wasm_hash, source_url, commit_hash,
             release_notes, state_schema, signature, publisher_key,
-            signature_algorithm, change_notes, is_revert, reverted_from
+            signature_algorithm, change_notes, is_revert, reverted_from,
+            docs_coverage, license, copyright
         )
         SELECT $1, version, wasm_hash, source_url, commit_hash,
                release_notes, state_schema, signature, publisher_key,
-               signature_algorithm, change_notes, false, NULL
+               signature_algorithm, change_notes, false, NULL,
+               docs_coverage, license, copyright
         FROM contract_versions
         WHERE contract_id = $2
         "#,
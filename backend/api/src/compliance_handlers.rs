@@ -0,0 +1,165 @@
+//! License compliance report
+//!
+//!   GET /api/contracts/:id/compliance  – licenses across the dependency tree
+//!
+//! Walks the contract's declared registry dependencies transitively and lists
+//! the license (SPDX expression) and copyright notice each contract's current
+//! version was published with. Dependencies that never resolved to a registry
+//! contract are listed separately, since their terms cannot be looked up.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    error::ApiResult,
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+};
+
+/// Dependency chains deeper than this are cut off.
+const MAX_DEPTH: i32 = 20;
+
+// ── Response types ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ComplianceEntry {
+    #[serde(skip)]
+    pub id: Uuid,
+    pub contract_id: String,
+    pub name: String,
+    /// Version the license was read from; absent when none was published
+    pub version: Option<String>,
+    pub license: Option<String>,
+    pub copyright: Option<String>,
+    /// 0 for the contract itself, 1 for its direct dependencies, …
+    pub depth: i32,
+    /// Names of the contracts in the tree that depend on this one
+    pub required_by: Vec<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct LicenseCount {
+    pub license: String,
+    pub contracts: usize,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct UnresolvedDependency {
+    pub dependency_name: String,
+    pub version_constraint: String,
+    pub required_by: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ComplianceReport {
+    pub contract_id: String,
+    pub generated_at: DateTime<Utc>,
+    /// The contract first, then its dependencies nearest first
+    pub entries: Vec<ComplianceEntry>,
+    /// Distinct license expressions and how many contracts use each
+    pub licenses: Vec<LicenseCount>,
+    /// Contracts in the tree without a declared license
+    pub unlicensed: usize,
+    pub unresolved: Vec<UnresolvedDependency>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn license_counts(entries: &[ComplianceEntry]) -> Vec<LicenseCount> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for license in entries.iter().filter_map(|e| e.license.as_deref()) {
+        *counts.entry(license).or_default() += 1;
+    }
+    let mut licenses: Vec<LicenseCount> = counts
+        .into_iter()
+        .map(|(license, contracts)| LicenseCount {
+            license: license.to_string(),
+            contracts,
+        })
+        .collect();
+    licenses.sort_by(|a, b| b.contracts.cmp(&a.contracts));
+    licenses
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/compliance",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Licenses of the contract and its transitive dependencies", body = ComplianceReport),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_compliance_report(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ComplianceReport>> {
+    let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
+
+    // `path` stops cycles; a contract reached along several chains is
+    // reported once, at its nearest depth.
+    let entries: Vec<ComplianceEntry> = sqlx::query_as(
+        "WITH RECURSIVE tree (id, parent, depth, path) AS (
+             SELECT $1::uuid, NULL::uuid, 0, ARRAY[$1::uuid]
+             UNION ALL
+             SELECT cd.dependency_contract_id, t.id, t.depth + 1, t.path || cd.dependency_contract_id
+             FROM contract_dependencies cd
+             JOIN tree t ON cd.contract_id = t.id
+             WHERE cd.dependency_contract_id IS NOT NULL
+               AND NOT cd.dependency_contract_id = ANY(t.path)
+               AND t.depth < $2
+         )
+         SELECT c.id, c.contract_id, c.name, v.version, v.license, v.copyright,
+                MIN(t.depth) AS depth,
+                COALESCE(ARRAY_AGG(DISTINCT p.name) FILTER (WHERE p.name IS NOT NULL), '{}') AS required_by
+         FROM tree t
+         JOIN contracts c ON c.id = t.id AND c.deleted_at IS NULL
+         LEFT JOIN contracts p ON p.id = t.parent
+         LEFT JOIN LATERAL (
+             SELECT cv.version, cv.license, cv.copyright
+             FROM contract_versions cv
+             WHERE cv.contract_id = c.id
+             ORDER BY cv.version = c.current_version DESC, cv.created_at DESC
+             LIMIT 1
+         ) v ON TRUE
+         GROUP BY c.id, c.contract_id, c.name, v.version, v.license, v.copyright
+         ORDER BY depth, c.name",
+    )
+    .bind(contract_uuid)
+    .bind(MAX_DEPTH)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("walk dependency licenses", err))?;
+
+    let ids: Vec<Uuid> = entries.iter().map(|e| e.id).collect();
+    let unresolved: Vec<UnresolvedDependency> = sqlx::query_as(
+        "SELECT cd.dependency_name, cd.version_constraint, c.name AS required_by
+         FROM contract_dependencies cd
+         JOIN contracts c ON c.id = cd.contract_id
+         WHERE cd.contract_id = ANY($1) AND cd.dependency_contract_id IS NULL
+         ORDER BY cd.dependency_name, c.name",
+    )
+    .bind(&ids)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list unresolved dependencies", err))?;
+
+    Ok(Json(ComplianceReport {
+        contract_id,
+        generated_at: Utc::now(),
+        licenses: license_counts(&entries),
+        unlicensed: entries.iter().filter(|e| e.license.is_none()).count(),
+        entries,
+        unresolved,
+    }))
+}
@@ -0,0 +1,109 @@
+//! Compressed responses and request bodies
+//!
+//! Responses are gzip- or brotli-encoded when the client's `Accept-Encoding`
+//! allows it, which mostly pays off on ABI, documentation and event payloads.
+//! Bodies that are already compressed, images other than SVG, server-sent
+//! event streams and responses below the size threshold are sent as-is.
+//!
+//! Request bodies sent with `Content-Encoding: gzip` or `br` are decompressed
+//! (by tower-http's `RequestDecompressionLayer`, outside every other layer)
+//! before any middleware or handler reads them, so body limits apply to the
+//! decompressed size. Other encodings are refused with 415 and the accepted
+//! ones listed in `Accept-Encoding` (RFC 7694), so clients can fall back.
+//!
+//! - RESPONSE_COMPRESSION_ENABLED: `false` to leave compression to a proxy
+//!   in front of the API (default true)
+//! - RESPONSE_COMPRESSION_MIN_BYTES: smallest response worth compressing
+//!   (default 1024, at most 65535)
+
+use anyhow::{anyhow, Result};
+use tower_http::compression::{
+    predicate::{NotForContentType, Predicate, SizeAbove},
+    CompressionLayer,
+};
+
+const DEFAULT_MIN_BYTES: u16 = 1024;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressionConfig {
+    pub enabled: bool,
+    pub min_bytes: u16,
+}
+
+impl CompressionConfig {
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let enabled = get("RESPONSE_COMPRESSION_ENABLED")
+            .map(|v| !v.eq_ignore_ascii_case("false"))
+            .unwrap_or(true);
+        let min_bytes = get("RESPONSE_COMPRESSION_MIN_BYTES")
+            .map(|v| {
+                v.parse::<u16>()
+                    .map_err(|_| anyhow!("Invalid RESPONSE_COMPRESSION_MIN_BYTES: {:?}", v))
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_MIN_BYTES);
+        Ok(Self { enabled, min_bytes })
+    }
+
+    pub fn layer(&self) -> CompressionLayer<impl Predicate> {
+        let predicate = SizeAbove::new(self.min_bytes)
+            .and(NotForContentType::GRPC)
+            .and(NotForContentType::IMAGES)
+            .and(NotForContentType::SSE)
+            // Source bundles are tar.zst already
+            .and(NotForContentType::const_new("application/zstd"))
+            .and(NotForContentType::const_new("application/gzip"));
+        let layer = if self.enabled {
+            CompressionLayer::new()
+        } else {
+            // With no encodings left, every response goes out as identity.
+            CompressionLayer::new().no_gzip().no_br()
+        };
+        layer.compress_when(predicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn reads_compression_settings() {
+        assert_eq!(
+            CompressionConfig::from_lookup(lookup(&[])).unwrap(),
+            CompressionConfig {
+                enabled: true,
+                min_bytes: DEFAULT_MIN_BYTES,
+            }
+        );
+        assert_eq!(
+            CompressionConfig::from_lookup(lookup(&[
+                ("RESPONSE_COMPRESSION_ENABLED", "FALSE"),
+                ("RESPONSE_COMPRESSION_MIN_BYTES", "256"),
+            ]))
+            .unwrap(),
+            CompressionConfig {
+                enabled: false,
+                min_bytes: 256,
+            }
+        );
+        assert!(CompressionConfig::from_lookup(lookup(&[(
+            "RESPONSE_COMPRESSION_MIN_BYTES",
+            "100000"
+        )]))
+        .is_err());
+    }
+}
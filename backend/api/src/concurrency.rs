@@ -0,0 +1,114 @@
+//! Optimistic concurrency control for edits
+//!
+//! Mutable records carry a `lock_version` that every user-facing update bumps.
+//! Reads return it as a strong `ETag` (`"<lock_version>"`); updates must send
+//! it back in `If-Match` and fail with 409 if someone else changed the record
+//! in the meantime, instead of silently overwriting their edit.
+//!
+//! `If-Match: *` opts out of the check (last write wins).
+
+use axum::http::{header, HeaderMap, HeaderValue, StatusCode};
+
+use crate::error::{ApiError, ApiResult};
+
+/// `ETag` value for a record at `lock_version`.
+pub fn etag(lock_version: i64) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", lock_version))
+        .expect("formatted integer is a valid header value")
+}
+
+/// The version the client expects to overwrite, or `None` for `If-Match: *`.
+pub fn expected_version(headers: &HeaderMap) -> ApiResult<Option<i64>> {
+    let Some(raw) = headers.get(header::IF_MATCH) else {
+        return Err(ApiError::new(
+            StatusCode::PRECONDITION_REQUIRED,
+            "IfMatchRequired",
+            "Send the record's ETag in an If-Match header to update it",
+        ));
+    };
+    let raw = raw.to_str().unwrap_or_default().trim();
+    if raw == "*" {
+        return Ok(None);
+    }
+    parse_etag(raw).map(Some).ok_or_else(|| {
+        ApiError::bad_request(
+            "InvalidIfMatch",
+            format!(
+                "If-Match must be an ETag returned by the API, got {:?}",
+                raw
+            ),
+        )
+    })
+}
+
+fn parse_etag(raw: &str) -> Option<i64> {
+    raw.strip_prefix("W/")
+        .unwrap_or(raw)
+        .strip_prefix('"')?
+        .strip_suffix('"')?
+        .parse()
+        .ok()
+}
+
+/// Fail fast when the record is already past the version the client saw.
+pub fn check(expected: Option<i64>, current: i64) -> ApiResult<()> {
+    match expected {
+        Some(v) if v != current => Err(conflict()),
+        _ => Ok(()),
+    }
+}
+
+pub fn conflict() -> ApiError {
+    ApiError::conflict(
+        "EditConflict",
+        "The record was modified by someone else; reload it and retry",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::response::IntoResponse;
+
+    fn headers(if_match: Option<&str>) -> HeaderMap {
+        let mut h = HeaderMap::new();
+        if let Some(v) = if_match {
+            h.insert(header::IF_MATCH, HeaderValue::from_str(v).unwrap());
+        }
+        h
+    }
+
+    #[test]
+    fn etag_round_trips() {
+        let tag = etag(7);
+        assert_eq!(tag, "\"7\"");
+        assert_eq!(
+            expected_version(&headers(Some(tag.to_str().unwrap()))).unwrap(),
+            Some(7)
+        );
+        assert_eq!(
+            expected_version(&headers(Some("W/\"7\""))).unwrap(),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn if_match_is_required_and_validated() {
+        assert_eq!(
+            expected_version(&headers(None))
+                .unwrap_err()
+                .into_response()
+                .status(),
+            StatusCode::PRECONDITION_REQUIRED
+        );
+        assert!(expected_version(&headers(Some("7"))).is_err());
+        assert_eq!(expected_version(&headers(Some("*"))).unwrap(), None);
+    }
+
+    #[test]
+    fn check_detects_stale_versions() {
+        assert!(check(Some(3), 3).is_ok());
+        assert!(check(None, 9).is_ok());
+        assert!(check(Some(2), 3).is_err());
+    }
+}
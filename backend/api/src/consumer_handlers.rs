@@ -0,0 +1,358 @@
+//! Registered consumers of contracts
+//!
+//!   POST   /api/contracts/:id/consumers       – register the caller's app as a consumer
+//!   GET    /api/contracts/:id/consumers       – consumers of a contract
+//!   PATCH  /api/consumers/:id                 – change a consumer's pin or webhook
+//!   DELETE /api/consumers/:id                 – unregister a consumer
+//!   GET    /api/consumers/:id/notifications   – notifications sent to a consumer
+//!
+//! A consumer pins one version of the contract. Deprecations, security
+//! patches and breaking releases notify exactly the consumers they affect;
+//! see [`crate::consumers`]. The contract's publisher sees every consumer;
+//! anyone else only sees their own.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    validation::url_validation::validate_https_url_only,
+};
+
+const MAX_NAME_LEN: usize = 100;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RegisterConsumerRequest {
+    /// Name of the consuming app, unique per owner and contract
+    pub name: String,
+    /// Version of the contract the app uses
+    pub pinned_version: String,
+    /// HTTPS endpoint notifications are POSTed to
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateConsumerRequest {
+    pub pinned_version: Option<String>,
+    /// New webhook; an empty string removes it
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct Consumer {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    /// Stellar address of the account that registered the consumer
+    pub owner_address: String,
+    pub name: String,
+    pub pinned_version: String,
+    pub webhook_url: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ConsumerNotification {
+    pub id: Uuid,
+    /// `deprecation`, `patch` or `breaking_release`
+    pub kind: String,
+    /// The version the consumer had pinned when notified
+    pub pinned_version: String,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub delivery_error: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListNotificationsQuery {
+    pub limit: Option<i64>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidConsumer", message)
+}
+
+fn validate_webhook(url: Option<&str>) -> ApiResult<Option<String>> {
+    match url.map(str::trim).filter(|url| !url.is_empty()) {
+        Some(url) => {
+            validate_https_url_only(url)
+                .map_err(|msg| ApiError::bad_request("InvalidWebhookUrl", msg))?;
+            Ok(Some(url.to_string()))
+        }
+        None => Ok(None),
+    }
+}
+
+async fn ensure_version_exists(
+    state: &AppState,
+    contract_id: Uuid,
+    version: &str,
+) -> ApiResult<()> {
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contract_versions WHERE contract_id = $1 AND version = $2)",
+    )
+    .bind(contract_id)
+    .bind(version)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check contract version", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "VersionNotFound",
+            format!("Version '{}' not found for this contract", version),
+        ));
+    }
+    Ok(())
+}
+
+async fn fetch_own_consumer(state: &AppState, owner: &str, id: Uuid) -> ApiResult<Consumer> {
+    sqlx::query_as("SELECT * FROM contract_consumers WHERE id = $1 AND owner_address = $2")
+        .bind(id)
+        .bind(owner)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch consumer", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ConsumerNotFound",
+                format!("No consumer found with ID: {}", id),
+            )
+        })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/consumers",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract_id")),
+    request_body = RegisterConsumerRequest,
+    responses(
+        (status = 201, description = "Consumer registered", body = Consumer),
+        (status = 400, description = "Invalid name or webhook"),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Contract or version not found"),
+        (status = 409, description = "The caller already registered a consumer with this name")
+    ),
+    tag = "Consumers"
+)]
+pub async fn register_consumer(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(req): Json<RegisterConsumerRequest>,
+) -> ApiResult<(StatusCode, Json<Consumer>)> {
+    let name = req.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LEN {
+        return Err(invalid(format!(
+            "name must be 1 to {} characters",
+            MAX_NAME_LEN
+        )));
+    }
+    let pinned_version = req.pinned_version.trim();
+    let webhook_url = validate_webhook(req.webhook_url.as_deref())?;
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_version_exists(&state, contract_uuid, pinned_version).await?;
+
+    let consumer: Consumer = sqlx::query_as(
+        "INSERT INTO contract_consumers (contract_id, owner_address, name, pinned_version, webhook_url)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(&claims.sub)
+    .bind(name)
+    .bind(pinned_version)
+    .bind(&webhook_url)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "ConsumerExists",
+            format!(
+                "You already registered a consumer named '{}' for this contract",
+                name
+            ),
+        ),
+        _ => db_internal_error("register consumer", err),
+    })?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        consumer = %consumer.id,
+        pin = %consumer.pinned_version,
+        "consumer registered"
+    );
+    Ok((StatusCode::CREATED, Json(consumer)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/consumers",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract_id")),
+    responses(
+        (status = 200, description = "Every consumer for the publisher; the caller's own otherwise", body = [Consumer]),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Consumers"
+)]
+pub async fn list_consumers(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<Consumer>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let publisher: Option<String> = sqlx::query_scalar(
+        "SELECT p.stellar_address FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract publisher", err))?;
+    let owner_filter = (publisher.as_deref() != Some(claims.sub.as_str())).then_some(&claims.sub);
+
+    let consumers: Vec<Consumer> = sqlx::query_as(
+        "SELECT * FROM contract_consumers
+         WHERE contract_id = $1 AND ($2::TEXT IS NULL OR owner_address = $2)
+         ORDER BY created_at",
+    )
+    .bind(contract_uuid)
+    .bind(owner_filter)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list consumers", err))?;
+
+    Ok(Json(consumers))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/consumers/{id}",
+    params(("id" = Uuid, Path, description = "Consumer ID")),
+    request_body = UpdateConsumerRequest,
+    responses(
+        (status = 200, description = "Consumer updated", body = Consumer),
+        (status = 400, description = "Invalid webhook"),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Consumer or version not found")
+    ),
+    tag = "Consumers"
+)]
+pub async fn update_consumer(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateConsumerRequest>,
+) -> ApiResult<Json<Consumer>> {
+    let consumer = fetch_own_consumer(&state, &claims.sub, id).await?;
+    let pinned_version = match req.pinned_version.as_deref().map(str::trim) {
+        Some(version) => {
+            ensure_version_exists(&state, consumer.contract_id, version).await?;
+            version.to_string()
+        }
+        None => consumer.pinned_version,
+    };
+    let webhook_url = match req.webhook_url.as_deref() {
+        Some(url) => validate_webhook(Some(url))?,
+        None => consumer.webhook_url,
+    };
+
+    let updated: Consumer = sqlx::query_as(
+        "UPDATE contract_consumers
+         SET pinned_version = $2, webhook_url = $3, updated_at = NOW()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(id)
+    .bind(&pinned_version)
+    .bind(&webhook_url)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("update consumer", err))?;
+
+    Ok(Json(updated))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/consumers/{id}",
+    params(("id" = Uuid, Path, description = "Consumer ID")),
+    responses(
+        (status = 204, description = "Consumer unregistered"),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Consumer not found")
+    ),
+    tag = "Consumers"
+)]
+pub async fn delete_consumer(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let deleted =
+        sqlx::query("DELETE FROM contract_consumers WHERE id = $1 AND owner_address = $2")
+            .bind(id)
+            .bind(&claims.sub)
+            .execute(&state.db)
+            .await
+            .map_err(|err| db_internal_error("delete consumer", err))?
+            .rows_affected();
+    if deleted == 0 {
+        return Err(ApiError::not_found(
+            "ConsumerNotFound",
+            format!("No consumer found with ID: {}", id),
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/consumers/{id}/notifications",
+    params(("id" = Uuid, Path, description = "Consumer ID"), ListNotificationsQuery),
+    responses(
+        (status = 200, description = "Most recent notifications first", body = [ConsumerNotification]),
+        (status = 401, description = "Authentication required"),
+        (status = 404, description = "Consumer not found")
+    ),
+    tag = "Consumers"
+)]
+pub async fn list_consumer_notifications(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+    Query(query): Query<ListNotificationsQuery>,
+) -> ApiResult<Json<Vec<ConsumerNotification>>> {
+    fetch_own_consumer(&state, &claims.sub, id).await?;
+    let notifications: Vec<ConsumerNotification> = sqlx::query_as(
+        "SELECT id, kind, pinned_version, message, created_at, delivered_at, delivery_error
+         FROM consumer_notifications
+         WHERE consumer_id = $1
+         ORDER BY created_at DESC
+         LIMIT $2",
+    )
+    .bind(id)
+    .bind(query.limit.unwrap_or(20).clamp(1, 100))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list consumer notifications", err))?;
+
+    Ok(Json(notifications))
+}
@@ -0,0 +1,325 @@
+//! Targeted notifications for registered contract consumers
+//!
+//! Apps register as consumers of a contract with the version they pin
+//! (see [`crate::consumer_handlers`]). [`notify`] works out which of them an
+//! event affects and records one `consumer_notifications` row per consumer:
+//!
+//! * a deprecation affects every consumer of the contract;
+//! * a security patch affects consumers whose pinned version runs the
+//!   patched build (its WASM hash);
+//! * a release with breaking ABI changes affects consumers pinned below its
+//!   major version (below its minor version before 1.0).
+//!
+//! Notifications for consumers with a webhook are then POSTed by a
+//! `consumer_notification` job, which retries through the job queue.
+
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shared::semver::SemVer;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const NOTIFY_JOB_KIND: &str = "consumer_notification";
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Something that happened to a contract that pinned consumers may need to act on.
+pub enum Impact<'a> {
+    Deprecation {
+        contract_id: Uuid,
+        retirement_at: DateTime<Utc>,
+    },
+    /// A security patch replacing the build with this WASM hash
+    Patch {
+        target_wasm_hash: &'a str,
+        severity: &'a str,
+    },
+    BreakingRelease {
+        contract_id: Uuid,
+        version: &'a str,
+    },
+}
+
+impl Impact<'_> {
+    fn kind(&self) -> &'static str {
+        match self {
+            Impact::Deprecation { .. } => "deprecation",
+            Impact::Patch { .. } => "patch",
+            Impact::BreakingRelease { .. } => "breaking_release",
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct AffectedConsumer {
+    id: Uuid,
+    name: String,
+    contract_name: String,
+    pinned_version: String,
+    has_webhook: bool,
+}
+
+/// Whether `release` breaks consumers pinned to `pinned`: a higher major
+/// version, or a higher minor version while both are pre-1.0.
+fn breaks_pin(pinned: &str, release: &str) -> bool {
+    match (SemVer::parse(pinned), SemVer::parse(release)) {
+        (Some(pinned), Some(release)) if pinned.major == 0 && release.major == 0 => {
+            pinned.minor < release.minor
+        }
+        (Some(pinned), Some(release)) => pinned.major < release.major,
+        _ => false,
+    }
+}
+
+fn message(impact: &Impact<'_>, consumer: &AffectedConsumer) -> String {
+    match impact {
+        Impact::Deprecation { retirement_at, .. } => format!(
+            "{} is deprecated and retires on {}; \"{}\" pins {} and needs to migrate.",
+            consumer.contract_name,
+            retirement_at.format("%Y-%m-%d"),
+            consumer.name,
+            consumer.pinned_version
+        ),
+        Impact::Patch { severity, .. } => format!(
+            "A {} security patch was released for {} {}, which \"{}\" pins.",
+            severity, consumer.contract_name, consumer.pinned_version, consumer.name
+        ),
+        Impact::BreakingRelease { version, .. } => format!(
+            "{} {} has breaking interface changes; \"{}\" pins {} and must be updated before upgrading.",
+            consumer.contract_name, version, consumer.name, consumer.pinned_version
+        ),
+    }
+}
+
+async fn affected_consumers(
+    pool: &PgPool,
+    impact: &Impact<'_>,
+) -> sqlx::Result<Vec<AffectedConsumer>> {
+    const COLUMNS: &str = "cc.id, cc.name, c.name AS contract_name, cc.pinned_version,
+        cc.webhook_url IS NOT NULL AS has_webhook";
+    match impact {
+        Impact::Deprecation { contract_id, .. } => {
+            sqlx::query_as(&format!(
+                "SELECT {} FROM contract_consumers cc
+                 JOIN contracts c ON c.id = cc.contract_id
+                 WHERE cc.contract_id = $1",
+                COLUMNS
+            ))
+            .bind(contract_id)
+            .fetch_all(pool)
+            .await
+        }
+        Impact::Patch {
+            target_wasm_hash, ..
+        } => {
+            sqlx::query_as(&format!(
+                "SELECT {} FROM contract_consumers cc
+                 JOIN contracts c ON c.id = cc.contract_id
+                 JOIN contract_versions v
+                   ON v.contract_id = cc.contract_id AND v.version = cc.pinned_version
+                 WHERE v.wasm_hash = $1",
+                COLUMNS
+            ))
+            .bind(target_wasm_hash)
+            .fetch_all(pool)
+            .await
+        }
+        Impact::BreakingRelease {
+            contract_id,
+            version,
+        } => {
+            let consumers: Vec<AffectedConsumer> = sqlx::query_as(&format!(
+                "SELECT {} FROM contract_consumers cc
+                 JOIN contracts c ON c.id = cc.contract_id
+                 WHERE cc.contract_id = $1",
+                COLUMNS
+            ))
+            .bind(contract_id)
+            .fetch_all(pool)
+            .await?;
+            Ok(consumers
+                .into_iter()
+                .filter(|c| breaks_pin(&c.pinned_version, version))
+                .collect())
+        }
+    }
+}
+
+/// Records a notification for every consumer `impact` affects and queues
+/// webhook delivery. Failures are logged rather than failing the write that
+/// triggered them.
+pub async fn notify(pool: &PgPool, impact: Impact<'_>) {
+    if let Err(err) = record(pool, &impact).await {
+        tracing::warn!(kind = impact.kind(), error = ?err, "failed to notify consumers");
+    }
+}
+
+async fn record(pool: &PgPool, impact: &Impact<'_>) -> sqlx::Result<()> {
+    let consumers = affected_consumers(pool, impact).await?;
+    if consumers.is_empty() {
+        return Ok(());
+    }
+
+    let mut deliver = Vec::new();
+    for consumer in &consumers {
+        let id: Uuid = sqlx::query_scalar(
+            "INSERT INTO consumer_notifications (consumer_id, kind, pinned_version, message)
+             VALUES ($1, $2, $3, $4)
+             RETURNING id",
+        )
+        .bind(consumer.id)
+        .bind(impact.kind())
+        .bind(&consumer.pinned_version)
+        .bind(message(impact, consumer))
+        .fetch_one(pool)
+        .await?;
+        if consumer.has_webhook {
+            deliver.push(id);
+        }
+    }
+    tracing::info!(
+        kind = impact.kind(),
+        consumers = consumers.len(),
+        "consumers notified"
+    );
+
+    if !deliver.is_empty() {
+        job_queue::enqueue(
+            pool,
+            NOTIFY_JOB_KIND,
+            serde_json::json!({ "notification_ids": deliver }),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PendingDelivery {
+    id: Uuid,
+    consumer_id: Uuid,
+    consumer_name: String,
+    contract_id: String,
+    kind: String,
+    pinned_version: String,
+    message: String,
+    created_at: DateTime<Utc>,
+    webhook_url: String,
+}
+
+/// Body POSTed to a consumer's webhook.
+#[derive(Debug, Serialize)]
+struct NotificationPayload<'a> {
+    notification_id: Uuid,
+    consumer_id: Uuid,
+    consumer_name: &'a str,
+    contract_id: &'a str,
+    kind: &'a str,
+    pinned_version: &'a str,
+    message: &'a str,
+    created_at: DateTime<Utc>,
+}
+
+pub struct ConsumerNotificationJob;
+
+#[async_trait]
+impl JobHandler for ConsumerNotificationJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let ids: Vec<Uuid> = serde_json::from_value(job.payload["notification_ids"].clone())
+            .context("consumer notification job payload is missing notification_ids")?;
+
+        // Already-delivered notifications are skipped, so a retry only
+        // resends the ones that failed.
+        let pending: Vec<PendingDelivery> = sqlx::query_as(
+            "SELECT n.id, n.consumer_id, cc.name AS consumer_name, c.contract_id,
+                    n.kind, n.pinned_version, n.message, n.created_at, cc.webhook_url
+             FROM consumer_notifications n
+             JOIN contract_consumers cc ON cc.id = n.consumer_id
+             JOIN contracts c ON c.id = cc.contract_id
+             WHERE n.id = ANY($1) AND n.delivered_at IS NULL AND cc.webhook_url IS NOT NULL",
+        )
+        .bind(&ids)
+        .fetch_all(pool)
+        .await?;
+
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .context("failed to build webhook client")?;
+        let mut failed = 0;
+        for delivery in &pending {
+            let payload = NotificationPayload {
+                notification_id: delivery.id,
+                consumer_id: delivery.consumer_id,
+                consumer_name: &delivery.consumer_name,
+                contract_id: &delivery.contract_id,
+                kind: &delivery.kind,
+                pinned_version: &delivery.pinned_version,
+                message: &delivery.message,
+                created_at: delivery.created_at,
+            };
+            let result = client
+                .post(&delivery.webhook_url)
+                .header("X-Registry-Notification-Id", delivery.id.to_string())
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|response| {
+                    if response.status().is_success() {
+                        Ok(())
+                    } else {
+                        Err(format!("webhook responded with {}", response.status()))
+                    }
+                });
+
+            let error = result.err();
+            if error.is_some() {
+                failed += 1;
+            }
+            sqlx::query(
+                "UPDATE consumer_notifications
+                 SET delivered_at = CASE WHEN $2::TEXT IS NULL THEN NOW() END,
+                     delivery_error = $2
+                 WHERE id = $1",
+            )
+            .bind(delivery.id)
+            .bind(&error)
+            .execute(pool)
+            .await?;
+        }
+
+        if failed > 0 {
+            anyhow::bail!("{} of {} consumer webhooks failed", failed, pending.len());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn major_releases_break_older_pins() {
+        assert!(breaks_pin("1.4.2", "2.0.0"));
+        assert!(!breaks_pin("2.0.0", "2.0.0"));
+        assert!(!breaks_pin("2.1.0", "2.3.0"));
+    }
+
+    #[test]
+    fn pre_1_0_minor_releases_break_pins() {
+        assert!(breaks_pin("0.3.1", "0.4.0"));
+        assert!(!breaks_pin("0.4.0", "0.4.9"));
+        assert!(breaks_pin("0.9.0", "1.0.0"));
+    }
+
+    #[test]
+    fn unparseable_pins_are_not_matched() {
+        assert!(!breaks_pin("latest", "2.0.0"));
+    }
+}
@@ -604,6 +604,14 @@ mod tests {
             organization_id: None,
             visibility: shared::VisibilityType::Public,
             current_version: None,
+            lock_version: 1,
+            auto_indexed: false,
+            claimed_at: None,
+            badges: Vec::new(),
+            quality_score: None,
+            publisher: None,
+            latest_version: None,
+            linked_from: None,
         }
     }
 
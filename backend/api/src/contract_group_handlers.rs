@@ -0,0 +1,224 @@
+//! Contract groups: one codebase deployed on several networks
+//!
+//!   GET    /api/contracts/:id/group  – the contract's group and its deployments
+//!   POST   /api/contracts/:id/group  – add another deployment to the group
+//!   DELETE /api/contracts/:id/group  – take the contract out of its group
+//!
+//! A group is the set of contracts sharing `contracts.logical_id`; every
+//! contract starts in a group of its own. Linking and unlinking require the
+//! caller to be the publisher of every contract involved. Completed
+//! environment promotions link their target into the source's group.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use shared::models::GroupDeployment;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::ensure_publisher,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct LinkContractRequest {
+    /// Registry UUID or on-chain ID of the deployment to add to the group
+    pub contract_id: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ContractGroup {
+    pub group_id: Uuid,
+    /// Public deployments in the group, by network
+    pub deployments: Vec<GroupDeployment>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Members of `contract_id`'s group other than itself. Private members are
+/// only included when they belong to `organization_id`.
+pub(crate) async fn other_deployments(
+    db: &sqlx::PgPool,
+    contract_id: Uuid,
+    organization_id: Option<Uuid>,
+) -> ApiResult<Vec<GroupDeployment>> {
+    sqlx::query_as(
+        "SELECT m.id, m.contract_id, m.network, m.wasm_hash, m.is_verified
+         FROM contracts c
+         JOIN contracts m ON m.logical_id = c.logical_id AND m.tenant_id = c.tenant_id
+         WHERE c.id = $1 AND m.id <> c.id AND m.deleted_at IS NULL
+           AND (m.visibility = 'public' OR m.organization_id = $2)
+         ORDER BY m.network, m.created_at",
+    )
+    .bind(contract_id)
+    .bind(organization_id)
+    .fetch_all(db)
+    .await
+    .map_err(|err| db_internal_error("list contract group", err))
+}
+
+async fn fetch_group(state: &AppState, contract_id: Uuid) -> ApiResult<ContractGroup> {
+    let group_id: Uuid =
+        sqlx::query_scalar("SELECT COALESCE(logical_id, id) FROM contracts WHERE id = $1")
+            .bind(contract_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract group", err))?;
+    let deployments = sqlx::query_as(
+        "SELECT m.id, m.contract_id, m.network, m.wasm_hash, m.is_verified
+         FROM contracts c
+         JOIN contracts m ON m.logical_id = $2 AND m.tenant_id = c.tenant_id
+         WHERE c.id = $1 AND m.deleted_at IS NULL AND m.visibility = 'public'
+         ORDER BY m.network, m.created_at",
+    )
+    .bind(contract_id)
+    .bind(group_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list contract group", err))?;
+    Ok(ContractGroup {
+        group_id,
+        deployments,
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/group",
+    params(("id" = String, Path, description = "Contract UUID or on-chain ID")),
+    responses(
+        (status = 200, description = "The contract's group", body = ContractGroup),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_contract_group(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ContractGroup>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    Ok(Json(fetch_group(&state, contract_uuid).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/group",
+    params(("id" = String, Path, description = "Contract UUID or on-chain ID")),
+    request_body = LinkContractRequest,
+    responses(
+        (status = 200, description = "Deployment added to the group", body = ContractGroup),
+        (status = 400, description = "A contract cannot be linked to itself"),
+        (status = 403, description = "Caller does not publish both contracts"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn link_contract(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<LinkContractRequest>,
+) -> ApiResult<Json<ContractGroup>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let other_uuid = resolve_contract_uuid(&state, &tenant, &req.contract_id).await?;
+    if contract_uuid == other_uuid {
+        return Err(ApiError::bad_request(
+            "InvalidGroupLink",
+            "A contract cannot be linked to itself",
+        ));
+    }
+    ensure_publisher(&state, &claims, contract_uuid, "change its group").await?;
+    ensure_publisher(&state, &claims, other_uuid, "change its group").await?;
+
+    sqlx::query(
+        "UPDATE contracts SET logical_id = (SELECT logical_id FROM contracts WHERE id = $1)
+         WHERE id = $2",
+    )
+    .bind(contract_uuid)
+    .bind(other_uuid)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("link contract", err))?;
+
+    Ok(Json(fetch_group(&state, contract_uuid).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/group",
+    params(("id" = String, Path, description = "Contract UUID or on-chain ID")),
+    responses(
+        (status = 200, description = "Contract is now in a group of its own", body = ContractGroup),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn unlink_contract(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<ContractGroup>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "change its group").await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin unlink", err))?;
+
+    // The group ID is its first member's UUID; when that member leaves, the
+    // rest of the group (and its promotion history) moves to the next oldest.
+    let successor: Option<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM contracts
+         WHERE logical_id = $1 AND id <> $1
+         ORDER BY created_at, id
+         LIMIT 1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("find group successor", err))?;
+    if let Some(successor) = successor {
+        sqlx::query("UPDATE contracts SET logical_id = $2 WHERE logical_id = $1 AND id <> $1")
+            .bind(contract_uuid)
+            .bind(successor)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("re-root contract group", err))?;
+        sqlx::query(
+            "UPDATE contract_promotions SET logical_id = $2
+             WHERE logical_id = $1 AND source_contract_id <> $1",
+        )
+        .bind(contract_uuid)
+        .bind(successor)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("re-root contract promotions", err))?;
+    }
+    sqlx::query("UPDATE contracts SET logical_id = id WHERE id = $1")
+        .bind(contract_uuid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("unlink contract", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit unlink", err))?;
+    Ok(Json(fetch_group(&state, contract_uuid).await?))
+}
@@ -0,0 +1,205 @@
+//! Per-contract permission grants
+//!
+//!   GET    /api/contracts/:id/permissions                         – who holds which capability
+//!   POST   /api/contracts/:id/permissions                         – grant capabilities (publisher only)
+//!   DELETE /api/contracts/:id/permissions/:principal/:capability  – revoke one (publisher only)
+//!
+//! Grants let principals other than the publisher publish versions, apply
+//! patches, run migrations or edit metadata; see
+//! [`crate::contract_permissions`] for how they are checked.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{ensure_publisher, ContractCapability},
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    validation::validators::validate_stellar_address,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct GrantPermissionsRequest {
+    /// Stellar address of the account receiving the capabilities
+    pub principal: String,
+    pub capabilities: Vec<ContractCapability>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractPermission {
+    pub principal: String,
+    /// One of `publish_versions`, `apply_patches`, `run_migrations` or
+    /// `edit_metadata`
+    pub capability: String,
+    /// Publisher address that granted it
+    pub granted_by: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+async fn fetch_permissions(
+    state: &AppState,
+    contract_id: Uuid,
+    principal: Option<&str>,
+) -> ApiResult<Vec<ContractPermission>> {
+    sqlx::query_as(
+        "SELECT principal, capability, granted_by, created_at
+         FROM contract_permissions
+         WHERE contract_id = $1 AND ($2::TEXT IS NULL OR principal = $2)
+         ORDER BY principal, capability",
+    )
+    .bind(contract_id)
+    .bind(principal)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list contract permissions", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/permissions",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Capabilities granted on the contract", body = [ContractPermission]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn list_permissions(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<ContractPermission>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    Ok(Json(fetch_permissions(&state, contract_uuid, None).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/permissions",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = GrantPermissionsRequest,
+    responses(
+        (status = 200, description = "Every capability the principal now holds", body = [ContractPermission]),
+        (status = 400, description = "Invalid principal or no capabilities"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Contracts"
+)]
+pub async fn grant_permissions(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(req): Json<GrantPermissionsRequest>,
+) -> ApiResult<Json<Vec<ContractPermission>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "grant permissions on it").await?;
+
+    let principal = req.principal.trim();
+    validate_stellar_address(principal)
+        .map_err(|msg| ApiError::bad_request("InvalidPrincipal", msg))?;
+    if req.capabilities.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidCapability",
+            "capabilities must name at least one capability",
+        ));
+    }
+    let capabilities: Vec<&str> = req
+        .capabilities
+        .iter()
+        .map(|capability| capability.as_str())
+        .collect();
+
+    sqlx::query(
+        "INSERT INTO contract_permissions (contract_id, principal, capability, granted_by)
+         SELECT $1, $2, capability, $4 FROM UNNEST($3::TEXT[]) AS capability
+         ON CONFLICT (contract_id, principal, capability) DO NOTHING",
+    )
+    .bind(contract_uuid)
+    .bind(principal)
+    .bind(&capabilities)
+    .bind(&claims.sub)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("grant contract permissions", err))?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        principal = %principal,
+        capabilities = ?capabilities,
+        "contract permissions granted"
+    );
+    Ok(Json(
+        fetch_permissions(&state, contract_uuid, Some(principal)).await?,
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/permissions/{principal}/{capability}",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("principal" = String, Path, description = "Stellar address holding the capability"),
+        ("capability" = String, Path, description = "Capability to revoke")
+    ),
+    responses(
+        (status = 204, description = "Capability revoked"),
+        (status = 400, description = "Unknown capability"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found, or the capability was not granted")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Contracts"
+)]
+pub async fn revoke_permission(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, principal, capability)): Path<(String, String, String)>,
+) -> ApiResult<StatusCode> {
+    let capability: ContractCapability = capability.parse()?;
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "revoke permissions on it").await?;
+
+    let revoked = sqlx::query(
+        "DELETE FROM contract_permissions
+         WHERE contract_id = $1 AND principal = $2 AND capability = $3",
+    )
+    .bind(contract_uuid)
+    .bind(principal.trim())
+    .bind(capability.as_str())
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("revoke contract permission", err))?
+    .rows_affected();
+    if revoked == 0 {
+        return Err(ApiError::not_found(
+            "PermissionNotFound",
+            format!(
+                "{} does not hold {} on this contract",
+                principal, capability
+            ),
+        ));
+    }
+
+    tracing::info!(
+        contract = %contract_uuid,
+        principal = %principal,
+        capability = %capability,
+        "contract permission revoked"
+    );
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,164 @@
+//! Who may change a contract
+//!
+//! A contract's publisher may do anything to it. Other principals (Stellar
+//! accounts) can be granted specific capabilities on it, stored in
+//! `contract_permissions` and managed through
+//! [`crate::contract_permission_handlers`]:
+//!
+//! - `publish_versions`: publish, yank and roll out versions
+//! - `apply_patches`: propose applying security patches
+//! - `run_migrations`: record and update WASM migrations
+//! - `edit_metadata`: change the name, description, category, tags,
+//!   verification status and oracle cadence
+//!
+//! Every handler that changes a contract checks its caller here: with
+//! [`authorize`] for an action a grant can allow, or [`ensure_publisher`] for
+//! one only the publisher may take (granting permissions, handing the
+//! contract to another publisher, deprecation, groups, bounties,
+//! disclosures).
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ContractCapability {
+    PublishVersions,
+    ApplyPatches,
+    RunMigrations,
+    EditMetadata,
+}
+
+impl ContractCapability {
+    pub const ALL: [Self; 4] = [
+        Self::PublishVersions,
+        Self::ApplyPatches,
+        Self::RunMigrations,
+        Self::EditMetadata,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PublishVersions => "publish_versions",
+            Self::ApplyPatches => "apply_patches",
+            Self::RunMigrations => "run_migrations",
+            Self::EditMetadata => "edit_metadata",
+        }
+    }
+}
+
+impl fmt::Display for ContractCapability {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ContractCapability {
+    type Err = ApiError;
+
+    fn from_str(raw: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|capability| capability.as_str() == raw.trim())
+            .ok_or_else(|| {
+                ApiError::bad_request(
+                    "InvalidCapability",
+                    format!(
+                        "Unknown capability '{}': expected one of {}",
+                        raw,
+                        Self::ALL.map(Self::as_str).join(", ")
+                    ),
+                )
+            })
+    }
+}
+
+/// Fails with 403 unless the caller published `contract_id`.
+pub(crate) async fn ensure_publisher(
+    state: &AppState,
+    claims: &AuthClaims,
+    contract_id: Uuid,
+    action: &str,
+) -> ApiResult<()> {
+    let owner: Option<String> = sqlx::query_scalar(
+        "SELECT p.stellar_address FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract publisher", err))?;
+    if owner.as_deref() != Some(claims.sub.as_str()) {
+        return Err(ApiError::forbidden_with_error(
+            "NotContractPublisher",
+            format!("Only the contract's publisher can {}", action),
+        ));
+    }
+    Ok(())
+}
+
+/// Fails with 403 unless the caller published `contract_id` or was granted
+/// `capability` on it.
+pub(crate) async fn authorize(
+    state: &AppState,
+    claims: &AuthClaims,
+    contract_id: Uuid,
+    capability: ContractCapability,
+    action: &str,
+) -> ApiResult<()> {
+    let allowed: Option<bool> = sqlx::query_scalar(
+        "SELECT p.stellar_address = $2 OR EXISTS(
+                SELECT 1 FROM contract_permissions cp
+                WHERE cp.contract_id = c.id AND cp.principal = $2 AND cp.capability = $3
+            )
+         FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(contract_id)
+    .bind(&claims.sub)
+    .bind(capability.as_str())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check contract permission", err))?;
+    if allowed != Some(true) {
+        return Err(ApiError::forbidden_with_error(
+            "MissingContractPermission",
+            format!(
+                "Only the contract's publisher or a principal granted {} can {}",
+                capability, action
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capabilities_round_trip_through_their_names() {
+        for capability in ContractCapability::ALL {
+            assert_eq!(
+                capability.as_str().parse::<ContractCapability>().unwrap(),
+                capability
+            );
+            assert_eq!(
+                serde_json::to_value(capability).unwrap(),
+                serde_json::json!(capability.as_str())
+            );
+        }
+        assert!("admin".parse::<ContractCapability>().is_err());
+    }
+}
@@ -0,0 +1,386 @@
+//! Contract summary for wallets and explorers
+//!
+//!   GET /api/contracts/by-address/:address/summary
+//!
+//! A compact, stable view of a contract keyed by its on-chain address, for
+//! wallets to show when a user is about to interact with it: name,
+//! verification status, the catalog interfaces it conforms to, token
+//! metadata (see [`crate::token_meta`]) and risk flags. Responses carry a
+//! content `ETag` and a short public `Cache-Control`, and answer a matching
+//! `If-None-Match` with 304, so clients and CDNs can cache them cheaply.
+//!
+//! New fields may be added; existing ones keep their meaning. Risk flag
+//! codes are the `RiskCode` variants.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const CACHE_CONTROL: &str = "public, max-age=60";
+
+// ── Response types ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct SummaryQuery {
+    /// Network the address is deployed on; mainnet is preferred when omitted
+    pub network: Option<Network>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ContractSummary {
+    pub contract_id: String,
+    pub network: Network,
+    pub name: String,
+    /// Display name of the publisher
+    pub publisher: Option<String>,
+    pub verified: bool,
+    pub verified_at: Option<DateTime<Utc>>,
+    /// Catalog interfaces the newest ABI conforms to, as `name@version`
+    pub interfaces: Vec<String>,
+    /// Present for SEP-41 tokens whose metadata has been read
+    pub token: Option<TokenSummary>,
+    /// Most severe first; empty when nothing is known against the contract
+    pub risk_flags: Vec<RiskFlag>,
+    /// The contract's id in the registry API
+    pub registry_id: Uuid,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct TokenSummary {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: i32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum RiskLevel {
+    Critical,
+    High,
+    Medium,
+    Low,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RiskCode {
+    /// An unresolved security incident affects the contract
+    OpenSecurityIncident,
+    /// Dependency scans matched known CVEs
+    KnownVulnerabilities,
+    /// Past its announced retirement date
+    Retired,
+    /// Deprecated by its publisher
+    Deprecated,
+    /// In a maintenance window
+    Maintenance,
+    /// Source has not been verified against the deployed WASM
+    Unverified,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, utoipa::ToSchema)]
+pub struct RiskFlag {
+    pub code: RiskCode,
+    pub level: RiskLevel,
+    pub message: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SummaryRow {
+    id: Uuid,
+    contract_id: String,
+    network: Network,
+    name: String,
+    publisher: Option<String>,
+    verified_at: Option<DateTime<Utc>>,
+    #[sqlx(flatten)]
+    risk: RiskFacts,
+}
+
+#[derive(Debug, Default, sqlx::FromRow)]
+struct RiskFacts {
+    is_verified: bool,
+    is_maintenance: bool,
+    deprecated: bool,
+    retirement_at: Option<DateTime<Utc>>,
+    replacement: Option<String>,
+    open_incidents: i64,
+    /// `incident_severity` of the worst open incident
+    worst_incident: Option<String>,
+    vulnerabilities: i64,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn incident_level(severity: Option<&str>) -> RiskLevel {
+    match severity {
+        Some("critical") => RiskLevel::Critical,
+        Some("high") => RiskLevel::High,
+        Some("low") => RiskLevel::Low,
+        _ => RiskLevel::Medium,
+    }
+}
+
+fn risk_flags(facts: &RiskFacts, now: DateTime<Utc>) -> Vec<RiskFlag> {
+    let mut flags = Vec::new();
+    if facts.open_incidents > 0 {
+        flags.push(RiskFlag {
+            code: RiskCode::OpenSecurityIncident,
+            level: incident_level(facts.worst_incident.as_deref()),
+            message: format!(
+                "{} unresolved security incident(s) affect this contract",
+                facts.open_incidents
+            ),
+        });
+    }
+    if facts.vulnerabilities > 0 {
+        flags.push(RiskFlag {
+            code: RiskCode::KnownVulnerabilities,
+            level: RiskLevel::High,
+            message: format!(
+                "Dependencies match {} known vulnerabilit{}",
+                facts.vulnerabilities,
+                if facts.vulnerabilities == 1 {
+                    "y"
+                } else {
+                    "ies"
+                }
+            ),
+        });
+    }
+    if facts.deprecated {
+        let replacement = facts
+            .replacement
+            .as_deref()
+            .map(|address| format!("; use {} instead", address))
+            .unwrap_or_default();
+        match facts.retirement_at {
+            Some(at) if at <= now => flags.push(RiskFlag {
+                code: RiskCode::Retired,
+                level: RiskLevel::High,
+                message: format!("Retired on {}{}", at.format("%Y-%m-%d"), replacement),
+            }),
+            at => flags.push(RiskFlag {
+                code: RiskCode::Deprecated,
+                level: RiskLevel::Medium,
+                message: match at {
+                    Some(at) => format!(
+                        "Deprecated; retires on {}{}",
+                        at.format("%Y-%m-%d"),
+                        replacement
+                    ),
+                    None => format!("Deprecated{}", replacement),
+                },
+            }),
+        }
+    }
+    if facts.is_maintenance {
+        flags.push(RiskFlag {
+            code: RiskCode::Maintenance,
+            level: RiskLevel::Medium,
+            message: "In a maintenance window; calls may be paused".to_string(),
+        });
+    }
+    if !facts.is_verified {
+        flags.push(RiskFlag {
+            code: RiskCode::Unverified,
+            level: RiskLevel::Low,
+            message: "Source code has not been verified".to_string(),
+        });
+    }
+    flags.sort_by_key(|flag| flag.level);
+    flags
+}
+
+pub(crate) fn etag(body: &[u8]) -> String {
+    format!("\"{}\"", &hex::encode(Sha256::digest(body))[..32])
+}
+
+/// Whether `If-None-Match` already names `etag` (weak comparison).
+pub(crate) fn not_modified(headers: &HeaderMap, etag: &str) -> bool {
+    let Some(raw) = headers
+        .get(header::IF_NONE_MATCH)
+        .and_then(|v| v.to_str().ok())
+    else {
+        return false;
+    };
+    raw.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/by-address/{address}/summary",
+    params(
+        ("address" = String, Path, description = "On-chain contract ID (C…)"),
+        SummaryQuery
+    ),
+    responses(
+        (status = 200, description = "Compact contract summary", body = ContractSummary),
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 404, description = "No public contract at this address")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_contract_summary(
+    State(state): State<AppState>,
+    Path(address): Path<String>,
+    Query(query): Query<SummaryQuery>,
+    tenant: Tenant,
+    headers: HeaderMap,
+) -> ApiResult<Response> {
+    let row: SummaryRow = sqlx::query_as(
+        "SELECT c.id, c.contract_id, c.network, c.name, c.is_verified, c.is_maintenance,
+                c.verified_at, COALESCE(p.display_name, p.username) AS publisher,
+                (d.id IS NOT NULL) AS deprecated, d.retirement_at,
+                r.contract_id AS replacement,
+                (SELECT COUNT(*) FROM incident_affected_contracts iac
+                 JOIN security_incidents si ON si.id = iac.incident_id
+                 WHERE iac.contract_id = c.id
+                   AND si.status NOT IN ('resolved', 'closed')) AS open_incidents,
+                (SELECT MIN(si.severity)::TEXT FROM incident_affected_contracts iac
+                 JOIN security_incidents si ON si.id = iac.incident_id
+                 WHERE iac.contract_id = c.id
+                   AND si.status NOT IN ('resolved', 'closed')) AS worst_incident,
+                (SELECT COUNT(*) FROM contract_scan_results sr
+                 WHERE sr.contract_id = c.id AND NOT sr.is_false_positive) AS vulnerabilities
+         FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         LEFT JOIN contract_deprecations d ON d.contract_id = c.id
+         LEFT JOIN contracts r ON r.id = d.replacement_contract_id
+         WHERE c.contract_id = $1 AND c.tenant_id = $2
+           AND c.deleted_at IS NULL AND c.visibility = 'public'
+           AND ($3::network_type IS NULL OR c.network = $3)
+         ORDER BY (c.network = 'mainnet') DESC, c.created_at
+         LIMIT 1",
+    )
+    .bind(&address)
+    .bind(tenant.id)
+    .bind(&query.network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract summary", err))?
+    .ok_or_else(|| ApiError::not_found("ContractNotFound", "No public contract at this address"))?;
+
+    let interfaces: Vec<(String, String)> = sqlx::query_as(
+        "SELECT i.name, i.version FROM contract_interface_matches m
+         JOIN contract_interfaces i ON i.id = m.interface_id
+         WHERE m.contract_id = $1
+         ORDER BY i.name, i.version",
+    )
+    .bind(row.id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch summary interfaces", err))?;
+
+    let token: Option<TokenSummary> = sqlx::query_as(
+        "SELECT name, symbol, decimals FROM token_metadata
+         WHERE contract_id = $1 AND refreshed_at IS NOT NULL",
+    )
+    .bind(row.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch summary token metadata", err))?;
+
+    let summary = ContractSummary {
+        risk_flags: risk_flags(&row.risk, Utc::now()),
+        contract_id: row.contract_id,
+        network: row.network,
+        name: row.name,
+        publisher: row.publisher,
+        verified: row.risk.is_verified,
+        verified_at: row.verified_at,
+        interfaces: interfaces
+            .into_iter()
+            .map(|(name, version)| format!("{}@{}", name, version))
+            .collect(),
+        token,
+        registry_id: row.id,
+    };
+
+    let body = serde_json::to_vec(&summary)
+        .map_err(|err| ApiError::internal(format!("serialize contract summary: {}", err)))?;
+    let etag = etag(&body);
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+    ];
+    if not_modified(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, "application/json".to_string())],
+        body,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn flags_are_ordered_by_severity() {
+        let now = Utc::now();
+        let facts = RiskFacts {
+            is_verified: false,
+            deprecated: true,
+            retirement_at: Some(now - Duration::days(1)),
+            replacement: Some("CNEW".to_string()),
+            open_incidents: 1,
+            worst_incident: Some("critical".to_string()),
+            ..RiskFacts::default()
+        };
+        let flags = risk_flags(&facts, now);
+        let codes: Vec<RiskCode> = flags.iter().map(|f| f.code).collect();
+        assert_eq!(
+            codes,
+            [
+                RiskCode::OpenSecurityIncident,
+                RiskCode::Retired,
+                RiskCode::Unverified
+            ]
+        );
+        assert_eq!(flags[0].level, RiskLevel::Critical);
+        assert!(flags[1].message.ends_with("use CNEW instead"));
+
+        let clean = RiskFacts {
+            is_verified: true,
+            ..RiskFacts::default()
+        };
+        assert!(risk_flags(&clean, now).is_empty());
+    }
+
+    #[test]
+    fn if_none_match_accepts_lists_and_weak_tags() {
+        let tag = etag(b"{}");
+        let mut headers = HeaderMap::new();
+        assert!(!not_modified(&headers, &tag));
+        headers.insert(
+            header::IF_NONE_MATCH,
+            format!("\"stale\", W/{}", tag).parse().unwrap(),
+        );
+        assert!(not_modified(&headers, &tag));
+        headers.insert(header::IF_NONE_MATCH, "\"stale\"".parse().unwrap());
+        assert!(!not_modified(&headers, &tag));
+    }
+}
@@ -0,0 +1,246 @@
+//! Gas regression detection between versions
+//!
+//! When function benchmarks (see [`crate::benchmark_handlers`]) exist for two
+//! consecutive versions of a contract, in SemVer order, the later version's
+//! per-function CPU, memory and fee estimates are compared with the earlier
+//! one's. A metric regresses when it grew by more than
+//! `COST_REGRESSION_THRESHOLD_PERCENT` (default 10) over a non-zero baseline.
+//!
+//! A `cost_regressions` job (`{"contract_id": ...}`) re-compares every
+//! consecutive pair of the contract's versions; recording benchmarks queues
+//! one. Results are kept per version in `contract_cost_comparisons`.
+
+use anyhow::Context;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::semver::SemVer;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const REGRESSION_JOB_KIND: &str = "cost_regressions";
+const DEFAULT_THRESHOLD_PERCENT: f64 = 10.0;
+
+/// A function whose estimated cost grew beyond the threshold.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CostRegression {
+    pub function_name: String,
+    /// cpu_instructions, memory_bytes or fee_stroops
+    pub metric: String,
+    pub baseline: i64,
+    pub current: i64,
+    /// Growth over the baseline, in percent
+    pub increase_percent: f64,
+}
+
+/// One version's benchmark figures for a function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionCost {
+    pub function_name: String,
+    pub cpu_instructions: i64,
+    pub memory_bytes: i64,
+    pub fee_stroops: i64,
+}
+
+pub fn threshold_percent() -> f64 {
+    std::env::var("COST_REGRESSION_THRESHOLD_PERCENT")
+        .ok()
+        .and_then(|v| v.parse::<f64>().ok())
+        .filter(|t| t.is_finite() && *t >= 0.0)
+        .unwrap_or(DEFAULT_THRESHOLD_PERCENT)
+}
+
+/// Functions benchmarked in both versions whose cost grew by more than
+/// `threshold` percent, ordered by function then metric.
+pub fn detect_regressions(
+    baseline: &[FunctionCost],
+    current: &[FunctionCost],
+    threshold: f64,
+) -> Vec<CostRegression> {
+    let baseline: HashMap<&str, &FunctionCost> = baseline
+        .iter()
+        .map(|cost| (cost.function_name.as_str(), cost))
+        .collect();
+    let mut regressions = Vec::new();
+    for cost in current {
+        let Some(base) = baseline.get(cost.function_name.as_str()) else {
+            continue;
+        };
+        let metrics = [
+            (
+                "cpu_instructions",
+                base.cpu_instructions,
+                cost.cpu_instructions,
+            ),
+            ("memory_bytes", base.memory_bytes, cost.memory_bytes),
+            ("fee_stroops", base.fee_stroops, cost.fee_stroops),
+        ];
+        for (metric, before, after) in metrics {
+            // A zero baseline has no meaningful percentage change.
+            if before <= 0 {
+                continue;
+            }
+            let increase = (after - before) as f64 / before as f64 * 100.0;
+            if increase > threshold {
+                regressions.push(CostRegression {
+                    function_name: cost.function_name.clone(),
+                    metric: metric.to_string(),
+                    baseline: before,
+                    current: after,
+                    increase_percent: (increase * 10.0).round() / 10.0,
+                });
+            }
+        }
+    }
+    regressions.sort_by(|a, b| {
+        (a.function_name.as_str(), a.metric.as_str())
+            .cmp(&(b.function_name.as_str(), b.metric.as_str()))
+    });
+    regressions
+}
+
+/// Re-compares every consecutive pair of the contract's versions and returns
+/// how many versions have at least one regression.
+pub async fn compare_versions(pool: &PgPool, contract_id: Uuid) -> anyhow::Result<usize> {
+    let threshold = threshold_percent();
+    let versions: Vec<(Uuid, String)> =
+        sqlx::query_as("SELECT id, version FROM contract_versions WHERE contract_id = $1")
+            .bind(contract_id)
+            .fetch_all(pool)
+            .await
+            .context("fetch contract versions")?;
+    let mut versions: Vec<(SemVer, Uuid)> = versions
+        .into_iter()
+        .filter_map(|(id, version)| Some((SemVer::parse(&version)?, id)))
+        .collect();
+    versions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let rows: Vec<(Uuid, String, i64, i64, i64)> = sqlx::query_as(
+        "SELECT contract_version_id, function_name, cpu_instructions, memory_bytes, fee_stroops
+         FROM contract_function_benchmarks WHERE contract_id = $1",
+    )
+    .bind(contract_id)
+    .fetch_all(pool)
+    .await
+    .context("fetch function benchmarks")?;
+    let mut costs: HashMap<Uuid, Vec<FunctionCost>> = HashMap::new();
+    for (version_id, function_name, cpu_instructions, memory_bytes, fee_stroops) in rows {
+        costs.entry(version_id).or_default().push(FunctionCost {
+            function_name,
+            cpu_instructions,
+            memory_bytes,
+            fee_stroops,
+        });
+    }
+
+    let mut tx = pool.begin().await?;
+    let mut compared = HashSet::new();
+    let mut regressed = 0;
+    for pair in versions.windows(2) {
+        let (baseline_id, version_id) = (pair[0].1, pair[1].1);
+        let (Some(baseline), Some(current)) = (costs.get(&baseline_id), costs.get(&version_id))
+        else {
+            continue;
+        };
+        let regressions = detect_regressions(baseline, current, threshold);
+        if !regressions.is_empty() {
+            regressed += 1;
+        }
+        sqlx::query(
+            "INSERT INTO contract_cost_comparisons
+                (contract_version_id, contract_id, baseline_version_id, threshold_percent,
+                 regressions, compared_at)
+             VALUES ($1, $2, $3, $4, $5, NOW())
+             ON CONFLICT (contract_version_id) DO UPDATE
+                SET baseline_version_id = EXCLUDED.baseline_version_id,
+                    threshold_percent = EXCLUDED.threshold_percent,
+                    regressions = EXCLUDED.regressions,
+                    compared_at = EXCLUDED.compared_at",
+        )
+        .bind(version_id)
+        .bind(contract_id)
+        .bind(baseline_id)
+        .bind(threshold)
+        .bind(serde_json::to_value(&regressions)?)
+        .execute(&mut *tx)
+        .await?;
+        compared.insert(version_id);
+    }
+    // A version whose predecessor changed or lost its benchmarks has no
+    // comparison any more.
+    sqlx::query(
+        "DELETE FROM contract_cost_comparisons
+         WHERE contract_id = $1 AND NOT (contract_version_id = ANY($2))",
+    )
+    .bind(contract_id)
+    .bind(compared.into_iter().collect::<Vec<_>>())
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(regressed)
+}
+
+/// Queues a comparison for one contract, e.g. after benchmarks are recorded.
+pub async fn queue(pool: &PgPool, contract_id: Uuid) {
+    let payload = serde_json::json!({ "contract_id": contract_id });
+    if let Err(err) = job_queue::enqueue(pool, REGRESSION_JOB_KIND, payload).await {
+        tracing::error!(error = ?err, contract = %contract_id, "cost regressions: failed to queue job");
+    }
+}
+
+pub struct CostRegressionJob;
+
+#[async_trait]
+impl JobHandler for CostRegressionJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let contract_id: Uuid = serde_json::from_value(
+            job.payload
+                .get("contract_id")
+                .cloned()
+                .context("payload.contract_id")?,
+        )
+        .context("payload.contract_id")?;
+        let regressed = compare_versions(pool, contract_id).await?;
+        if regressed > 0 {
+            tracing::info!(contract = %contract_id, versions = regressed, "cost regressions detected");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cost(name: &str, cpu: i64, mem: i64, fee: i64) -> FunctionCost {
+        FunctionCost {
+            function_name: name.to_string(),
+            cpu_instructions: cpu,
+            memory_bytes: mem,
+            fee_stroops: fee,
+        }
+    }
+
+    #[test]
+    fn flags_metrics_that_grew_beyond_the_threshold() {
+        let baseline = [cost("transfer", 1_000, 500, 100), cost("mint", 0, 10, 10)];
+        let current = [
+            cost("transfer", 1_150, 540, 90),
+            cost("mint", 5_000, 11, 10),
+            cost("burn", 9_999, 9_999, 9_999),
+        ];
+        assert_eq!(
+            detect_regressions(&baseline, &current, 10.0),
+            vec![CostRegression {
+                function_name: "transfer".to_string(),
+                metric: "cpu_instructions".to_string(),
+                baseline: 1_000,
+                current: 1_150,
+                increase_percent: 15.0,
+            }]
+        );
+        assert_eq!(detect_regressions(&baseline, &current, 20.0), Vec::new());
+    }
+}
@@ -0,0 +1,217 @@
+//! Database connection pools
+//!
+//! Pool sizing, timeouts and statement caching come from the environment.
+//! An optional read replica gets a pool of its own, `AppState::db_read`,
+//! which the heavy read-only endpoints (search, dependency graphs, event
+//! queries) use so they do not compete with write transactions for primary
+//! connections. Without a replica `db_read` is the primary pool. A replica
+//! can lag the primary slightly, so anything that must see its own writes
+//! stays on `AppState::db`.
+//!
+//! - DB_MAX_POOL_SIZE: maximum connections (default twice the logical cores,
+//!   at least 10)
+//! - DB_MIN_CONNECTIONS: connections kept open while idle (default 0)
+//! - DB_ACQUIRE_TIMEOUT_SECS: how long a request waits for a connection
+//!   (default 30)
+//! - DB_IDLE_TIMEOUT_SECS: close connections idle this long, 0 never
+//!   (default 600)
+//! - DB_MAX_LIFETIME_SECS: recycle connections this old, 0 never
+//!   (default 1800)
+//! - DB_STATEMENT_CACHE_CAPACITY: prepared statements cached per connection
+//!   (default 100; 0 behind PgBouncer in transaction mode)
+//! - DB_STATEMENT_TIMEOUT_MS: server-side `statement_timeout` (default none)
+//! - DATABASE_READ_URL: read replica connection string
+//! - DB_READ_MAX_POOL_SIZE: maximum replica connections (default
+//!   DB_MAX_POOL_SIZE)
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Context, Result};
+use sqlx::postgres::{PgConnectOptions, PgPool, PgPoolOptions};
+
+const DEFAULT_MIN_MAX_CONNECTIONS: u32 = 10;
+const DEFAULT_ACQUIRE_TIMEOUT_SECS: u64 = 30;
+const DEFAULT_IDLE_TIMEOUT_SECS: u64 = 600;
+const DEFAULT_MAX_LIFETIME_SECS: u64 = 1800;
+const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DbPoolConfig {
+    pub max_connections: u32,
+    pub min_connections: u32,
+    pub acquire_timeout: Duration,
+    pub idle_timeout: Option<Duration>,
+    pub max_lifetime: Option<Duration>,
+    pub statement_cache_capacity: usize,
+    pub statement_timeout: Option<Duration>,
+    pub read_url: Option<String>,
+    pub read_max_connections: u32,
+}
+
+fn parse<T: FromStr>(get: &impl Fn(&str) -> Option<String>, key: &str) -> Result<Option<T>> {
+    get(key)
+        .filter(|v| !v.trim().is_empty())
+        .map(|v| {
+            v.trim()
+                .parse::<T>()
+                .map_err(|_| anyhow!("Invalid {}: {:?}", key, v))
+        })
+        .transpose()
+}
+
+/// Seconds to a duration, with 0 meaning "never".
+fn optional_secs(secs: u64) -> Option<Duration> {
+    (secs > 0).then(|| Duration::from_secs(secs))
+}
+
+impl DbPoolConfig {
+    pub fn from_env() -> Result<Self> {
+        let logical_cores = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+        Self::from_lookup(|key| std::env::var(key).ok(), logical_cores)
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>, logical_cores: usize) -> Result<Self> {
+        let default_max = (logical_cores as u32 * 2).max(DEFAULT_MIN_MAX_CONNECTIONS);
+        let max_connections = parse(&get, "DB_MAX_POOL_SIZE")?.unwrap_or(default_max);
+        let min_connections = parse(&get, "DB_MIN_CONNECTIONS")?.unwrap_or(0);
+        if max_connections == 0 {
+            bail!("DB_MAX_POOL_SIZE must be at least 1");
+        }
+        if min_connections > max_connections {
+            bail!(
+                "DB_MIN_CONNECTIONS ({}) exceeds DB_MAX_POOL_SIZE ({})",
+                min_connections,
+                max_connections
+            );
+        }
+        let read_max_connections = parse(&get, "DB_READ_MAX_POOL_SIZE")?.unwrap_or(max_connections);
+        if read_max_connections == 0 {
+            bail!("DB_READ_MAX_POOL_SIZE must be at least 1");
+        }
+
+        Ok(Self {
+            max_connections,
+            min_connections,
+            acquire_timeout: Duration::from_secs(
+                parse(&get, "DB_ACQUIRE_TIMEOUT_SECS")?.unwrap_or(DEFAULT_ACQUIRE_TIMEOUT_SECS),
+            ),
+            idle_timeout: optional_secs(
+                parse(&get, "DB_IDLE_TIMEOUT_SECS")?.unwrap_or(DEFAULT_IDLE_TIMEOUT_SECS),
+            ),
+            max_lifetime: optional_secs(
+                parse(&get, "DB_MAX_LIFETIME_SECS")?.unwrap_or(DEFAULT_MAX_LIFETIME_SECS),
+            ),
+            statement_cache_capacity: parse(&get, "DB_STATEMENT_CACHE_CAPACITY")?
+                .unwrap_or(DEFAULT_STATEMENT_CACHE_CAPACITY),
+            statement_timeout: parse(&get, "DB_STATEMENT_TIMEOUT_MS")?
+                .filter(|ms| *ms > 0)
+                .map(Duration::from_millis),
+            read_url: get("DATABASE_READ_URL").filter(|url| !url.trim().is_empty()),
+            read_max_connections,
+        })
+    }
+
+    fn connect_options(&self, url: &str) -> Result<PgConnectOptions> {
+        let mut options = PgConnectOptions::from_str(url)
+            .context("Invalid database connection string")?
+            .statement_cache_capacity(self.statement_cache_capacity);
+        if let Some(timeout) = self.statement_timeout {
+            options = options.options([("statement_timeout", timeout.as_millis())]);
+        }
+        Ok(options)
+    }
+
+    async fn connect_pool(&self, url: &str, max_connections: u32) -> Result<PgPool> {
+        let pool = PgPoolOptions::new()
+            .max_connections(max_connections)
+            .min_connections(self.min_connections.min(max_connections))
+            .acquire_timeout(self.acquire_timeout)
+            .idle_timeout(self.idle_timeout)
+            .max_lifetime(self.max_lifetime)
+            .connect_with(self.connect_options(url)?)
+            .await?;
+        Ok(pool)
+    }
+
+    /// The primary pool, used for writes and read-your-writes queries.
+    pub async fn connect(&self, url: &str) -> Result<PgPool> {
+        self.connect_pool(url, self.max_connections).await
+    }
+
+    /// The replica pool when DATABASE_READ_URL is set, `primary` otherwise.
+    pub async fn connect_read(&self, primary: &PgPool) -> Result<PgPool> {
+        match &self.read_url {
+            Some(url) => self
+                .connect_pool(url, self.read_max_connections)
+                .await
+                .context("Failed to connect to the read replica"),
+            None => Ok(primary.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn defaults_size_the_pool_from_the_cores() {
+        let config = DbPoolConfig::from_lookup(lookup(&[]), 8).unwrap();
+        assert_eq!(config.max_connections, 16);
+        assert_eq!(config.read_max_connections, 16);
+        assert_eq!(config.acquire_timeout, Duration::from_secs(30));
+        assert_eq!(config.idle_timeout, Some(Duration::from_secs(600)));
+        assert_eq!(config.statement_cache_capacity, 100);
+        assert_eq!(config.statement_timeout, None);
+        assert_eq!(config.read_url, None);
+
+        let config = DbPoolConfig::from_lookup(lookup(&[]), 2).unwrap();
+        assert_eq!(config.max_connections, 10);
+    }
+
+    #[test]
+    fn reads_explicit_settings() {
+        let config = DbPoolConfig::from_lookup(
+            lookup(&[
+                ("DB_MAX_POOL_SIZE", "40"),
+                ("DB_MIN_CONNECTIONS", "5"),
+                ("DB_IDLE_TIMEOUT_SECS", "0"),
+                ("DB_STATEMENT_CACHE_CAPACITY", "0"),
+                ("DB_STATEMENT_TIMEOUT_MS", "15000"),
+                ("DATABASE_READ_URL", "postgres://replica/registry"),
+                ("DB_READ_MAX_POOL_SIZE", "80"),
+            ]),
+            8,
+        )
+        .unwrap();
+        assert_eq!(config.max_connections, 40);
+        assert_eq!(config.min_connections, 5);
+        assert_eq!(config.idle_timeout, None);
+        assert_eq!(config.statement_cache_capacity, 0);
+        assert_eq!(config.statement_timeout, Some(Duration::from_secs(15)));
+        assert_eq!(
+            config.read_url.as_deref(),
+            Some("postgres://replica/registry")
+        );
+        assert_eq!(config.read_max_connections, 80);
+
+        assert!(DbPoolConfig::from_lookup(lookup(&[("DB_MAX_POOL_SIZE", "many")]), 8).is_err());
+        assert!(DbPoolConfig::from_lookup(
+            lookup(&[("DB_MAX_POOL_SIZE", "4"), ("DB_MIN_CONNECTIONS", "5")]),
+            8
+        )
+        .is_err());
+    }
+}
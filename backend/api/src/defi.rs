@@ -0,0 +1,402 @@
+//! AMM pool state
+//!
+//! Every `DEFI_REFRESH_INTERVAL_SECS` (default 300) the refresher reads the
+//! instance storage of each contract in the AMM category over Soroban RPC and
+//! records the pool's tokens, reserves and fee in `defi_pools`. Served by
+//! [`crate::defi_handlers`] and used to sort search results by liquidity.
+//!
+//! Pools are recognised by their storage keys, as symbols or unit enum
+//! variants and ignoring case and underscores: `Token0`/`TokenA`,
+//! `Token1`/`TokenB`, `Reserve0`/`ReserveA`, `Reserve1`/`ReserveB` and,
+//! optionally, `Fee`/`FeeBps`/`FeeFraction` in basis points.
+//!
+//! TVL is estimated in USD from the stablecoin contracts listed in
+//! `DEFI_USD_TOKENS` (comma-separated): a token paired with one of them is
+//! priced at that pool's ratio, the deepest such pool winning, and a pool is
+//! worth both of its sides. Pools with neither token priced have no estimate.
+//! All tokens are assumed to use the Stellar default of 7 decimals.
+
+use chrono::{DateTime, Utc};
+use shared::Network;
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use stellar_xdr::curr::{PublicKey, ScAddress, ScMapEntry, ScVal};
+use uuid::Uuid;
+
+use crate::onchain_verification::OnChainVerifier;
+
+const DEFAULT_INTERVAL_SECS: u64 = 300;
+const TOKEN_DECIMALS: i32 = 7;
+const MAX_FEE_BPS: i128 = 10_000;
+
+/// Category path of AMM contracts; its subcategories count too.
+pub const AMM_CATEGORY: &str = "defi/amm";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum StorageValue {
+    Int(i128),
+    Address(String),
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolState {
+    pub token_a: String,
+    pub token_b: String,
+    pub reserve_a: i128,
+    pub reserve_b: i128,
+    pub fee_bps: Option<i32>,
+}
+
+/// The name of a storage key: a symbol, or an enum variant without fields
+/// (`DataKey::Reserve0` is stored as `[Symbol("Reserve0")]`).
+fn storage_key(key: &ScVal) -> Option<String> {
+    let symbol = match key {
+        ScVal::Symbol(symbol) => symbol,
+        ScVal::Vec(Some(items)) => match items.0.as_slice() {
+            [ScVal::Symbol(symbol)] => symbol,
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(symbol.0.to_utf8_string_lossy())
+}
+
+fn storage_value(value: &ScVal) -> StorageValue {
+    match value {
+        ScVal::U32(n) => StorageValue::Int(i128::from(*n)),
+        ScVal::I32(n) => StorageValue::Int(i128::from(*n)),
+        ScVal::U64(n) => StorageValue::Int(i128::from(*n)),
+        ScVal::I64(n) => StorageValue::Int(i128::from(*n)),
+        ScVal::I128(parts) => {
+            StorageValue::Int((i128::from(parts.hi) << 64) | i128::from(parts.lo))
+        }
+        ScVal::U128(parts) => {
+            let n = (u128::from(parts.hi) << 64) | u128::from(parts.lo);
+            i128::try_from(n).map_or(StorageValue::Other, StorageValue::Int)
+        }
+        ScVal::Address(ScAddress::Contract(contract)) => {
+            StorageValue::Address(stellar_strkey::Contract((contract.0).0).to_string())
+        }
+        ScVal::Address(ScAddress::Account(account)) => {
+            let PublicKey::PublicKeyTypeEd25519(key) = &account.0;
+            StorageValue::Address(stellar_strkey::ed25519::PublicKey(key.0).to_string())
+        }
+        _ => StorageValue::Other,
+    }
+}
+
+/// Reads a pool from instance storage, or `None` when the storage does not
+/// hold two tokens and two non-negative reserves.
+pub fn read_pool<I>(entries: I) -> Option<PoolState>
+where
+    I: IntoIterator<Item = (String, StorageValue)>,
+{
+    let (mut token_a, mut token_b, mut reserve_a, mut reserve_b, mut fee_bps) =
+        (None, None, None, None, None);
+    for (key, value) in entries {
+        let name = key.to_ascii_lowercase().replace('_', "");
+        match (name.as_str(), value) {
+            ("token0" | "tokena", StorageValue::Address(address)) => token_a = Some(address),
+            ("token1" | "tokenb", StorageValue::Address(address)) => token_b = Some(address),
+            ("reserve0" | "reservea", StorageValue::Int(n)) if n >= 0 => reserve_a = Some(n),
+            ("reserve1" | "reserveb", StorageValue::Int(n)) if n >= 0 => reserve_b = Some(n),
+            ("fee" | "feebps" | "feefraction", StorageValue::Int(n))
+                if (0..=MAX_FEE_BPS).contains(&n) =>
+            {
+                fee_bps = Some(n as i32)
+            }
+            _ => {}
+        }
+    }
+    Some(PoolState {
+        token_a: token_a?,
+        token_b: token_b?,
+        reserve_a: reserve_a?,
+        reserve_b: reserve_b?,
+        fee_bps,
+    })
+}
+
+/// USD prices per token unit for the stablecoins and every token paired
+/// with one of them.
+pub fn token_prices<'a, I>(pools: I, usd_tokens: &HashSet<String>) -> HashMap<String, f64>
+where
+    I: IntoIterator<Item = &'a PoolState>,
+{
+    let mut prices: HashMap<String, f64> = usd_tokens.iter().map(|t| (t.clone(), 1.0)).collect();
+    // Token → (price, USD depth of the pool it was priced from)
+    let mut derived: HashMap<&str, (f64, i128)> = HashMap::new();
+    for pool in pools {
+        let (token, usd_reserve, other_reserve) = match (
+            usd_tokens.contains(&pool.token_a),
+            usd_tokens.contains(&pool.token_b),
+        ) {
+            (true, false) => (&pool.token_b, pool.reserve_a, pool.reserve_b),
+            (false, true) => (&pool.token_a, pool.reserve_b, pool.reserve_a),
+            _ => continue,
+        };
+        if usd_reserve == 0 || other_reserve == 0 {
+            continue;
+        }
+        if derived
+            .get(token.as_str())
+            .is_none_or(|&(_, depth)| usd_reserve > depth)
+        {
+            derived.insert(
+                token,
+                (usd_reserve as f64 / other_reserve as f64, usd_reserve),
+            );
+        }
+    }
+    for (token, (price, _)) in derived {
+        prices.insert(token.to_string(), price);
+    }
+    prices
+}
+
+/// Estimated USD value locked in `pool`; an unpriced side is assumed to be
+/// worth as much as the priced one.
+pub fn tvl_usd(pool: &PoolState, prices: &HashMap<String, f64>) -> Option<f64> {
+    let side = |token: &String, reserve: i128| prices.get(token).map(|p| reserve as f64 * p);
+    let units = match (
+        side(&pool.token_a, pool.reserve_a),
+        side(&pool.token_b, pool.reserve_b),
+    ) {
+        (Some(a), Some(b)) => a + b,
+        (Some(priced), None) | (None, Some(priced)) => 2.0 * priced,
+        (None, None) => return None,
+    };
+    Some(units / 10f64.powi(TOKEN_DECIMALS))
+}
+
+fn usd_tokens() -> HashSet<String> {
+    std::env::var("DEFI_USD_TOKENS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+// ── Refresher ─────────────────────────────────────────────────────────────────
+
+#[derive(Debug, sqlx::FromRow)]
+struct AmmContract {
+    id: Uuid,
+    contract_id: String,
+    network: Network,
+    network_key: String,
+}
+
+pub fn spawn_defi_refresher(pool: PgPool) {
+    let interval_secs = std::env::var("DEFI_REFRESH_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let verifier = OnChainVerifier::new();
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = refresh(&pool, &verifier).await {
+                tracing::error!(error = ?err, "defi: pool refresh failed");
+            }
+        }
+    });
+}
+
+async fn refresh(pool: &PgPool, verifier: &OnChainVerifier) -> Result<(), sqlx::Error> {
+    let contracts: Vec<AmmContract> = sqlx::query_as(
+        "SELECT id, contract_id, network, network::TEXT AS network_key
+         FROM contracts
+         WHERE deleted_at IS NULL
+           AND (category = $1 OR starts_with(category, $1 || '/'))",
+    )
+    .bind(AMM_CATEGORY)
+    .fetch_all(pool)
+    .await?;
+
+    let mut pools: Vec<(&AmmContract, PoolState)> = Vec::new();
+    for contract in &contracts {
+        let error = match verifier
+            .fetch_instance_storage(&contract.network, &contract.contract_id)
+            .await
+        {
+            Ok(Some(storage)) => {
+                let entries = storage.iter().filter_map(|ScMapEntry { key, val }| {
+                    Some((storage_key(key)?, storage_value(val)))
+                });
+                match read_pool(entries) {
+                    Some(state) => {
+                        pools.push((contract, state));
+                        continue;
+                    }
+                    None => "instance storage does not hold a recognised pool layout".to_string(),
+                }
+            }
+            Ok(None) => "contract instance not found on the ledger".to_string(),
+            Err(err) => err.to_string(),
+        };
+        record_error(pool, contract.id, &error).await?;
+    }
+
+    // Token addresses are per network, and so are prices.
+    let usd = usd_tokens();
+    let mut prices: HashMap<&str, HashMap<String, f64>> = HashMap::new();
+    for (contract, _) in &pools {
+        prices
+            .entry(contract.network_key.as_str())
+            .or_insert_with(|| {
+                token_prices(
+                    pools
+                        .iter()
+                        .filter(|(other, _)| other.network_key == contract.network_key)
+                        .map(|(_, state)| state),
+                    &usd,
+                )
+            });
+    }
+
+    let refreshed_at: DateTime<Utc> = Utc::now();
+    for (contract, state) in &pools {
+        let tvl = tvl_usd(state, &prices[contract.network_key.as_str()]);
+        sqlx::query(
+            "INSERT INTO defi_pools
+                (contract_id, token_a, token_b, reserve_a, reserve_b, fee_bps, tvl_usd,
+                 refreshed_at, checked_at, last_error)
+             VALUES ($1, $2, $3, $4::NUMERIC, $5::NUMERIC, $6, $7, $8, $8, NULL)
+             ON CONFLICT (contract_id) DO UPDATE
+                SET token_a = EXCLUDED.token_a,
+                    token_b = EXCLUDED.token_b,
+                    reserve_a = EXCLUDED.reserve_a,
+                    reserve_b = EXCLUDED.reserve_b,
+                    fee_bps = EXCLUDED.fee_bps,
+                    tvl_usd = EXCLUDED.tvl_usd,
+                    refreshed_at = EXCLUDED.refreshed_at,
+                    checked_at = EXCLUDED.checked_at,
+                    last_error = NULL",
+        )
+        .bind(contract.id)
+        .bind(&state.token_a)
+        .bind(&state.token_b)
+        .bind(state.reserve_a.to_string())
+        .bind(state.reserve_b.to_string())
+        .bind(state.fee_bps)
+        .bind(tvl)
+        .bind(refreshed_at)
+        .execute(pool)
+        .await?;
+    }
+
+    if !pools.is_empty() {
+        tracing::info!(
+            pools = pools.len(),
+            failed = contracts.len() - pools.len(),
+            "defi: refreshed pool state"
+        );
+    }
+    Ok(())
+}
+
+/// Keeps the last good reading and notes why this one failed.
+async fn record_error(pool: &PgPool, contract_id: Uuid, error: &str) -> Result<(), sqlx::Error> {
+    tracing::warn!(contract = %contract_id, error, "defi: could not read pool");
+    sqlx::query(
+        "INSERT INTO defi_pools (contract_id, checked_at, last_error)
+         VALUES ($1, NOW(), $2)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET checked_at = NOW(), last_error = EXCLUDED.last_error",
+    )
+    .bind(contract_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(a: &str, b: &str, reserve_a: i128, reserve_b: i128) -> PoolState {
+        PoolState {
+            token_a: a.to_string(),
+            token_b: b.to_string(),
+            reserve_a,
+            reserve_b,
+            fee_bps: None,
+        }
+    }
+
+    #[test]
+    fn pools_are_read_from_either_naming() {
+        let soroswap = vec![
+            (
+                "Token0".to_string(),
+                StorageValue::Address("CA".to_string()),
+            ),
+            (
+                "Token1".to_string(),
+                StorageValue::Address("CB".to_string()),
+            ),
+            ("Reserve0".to_string(), StorageValue::Int(10)),
+            ("Reserve1".to_string(), StorageValue::Int(20)),
+            (
+                "Factory".to_string(),
+                StorageValue::Address("CF".to_string()),
+            ),
+        ];
+        assert_eq!(read_pool(soroswap), Some(pool("CA", "CB", 10, 20)));
+
+        let aqua = vec![
+            (
+                "token_a".to_string(),
+                StorageValue::Address("CA".to_string()),
+            ),
+            (
+                "token_b".to_string(),
+                StorageValue::Address("CB".to_string()),
+            ),
+            ("reserve_a".to_string(), StorageValue::Int(1)),
+            ("reserve_b".to_string(), StorageValue::Int(2)),
+            ("FeeFraction".to_string(), StorageValue::Int(30)),
+        ];
+        assert_eq!(read_pool(aqua).and_then(|p| p.fee_bps), Some(30));
+
+        let missing_reserve = vec![
+            (
+                "Token0".to_string(),
+                StorageValue::Address("CA".to_string()),
+            ),
+            (
+                "Token1".to_string(),
+                StorageValue::Address("CB".to_string()),
+            ),
+            ("Reserve0".to_string(), StorageValue::Int(10)),
+            ("Reserve1".to_string(), StorageValue::Other),
+        ];
+        assert_eq!(read_pool(missing_reserve), None);
+    }
+
+    #[test]
+    fn tvl_prices_tokens_through_usd_pairs() {
+        const UNIT: i128 = 10_000_000;
+        let usd: HashSet<String> = ["USDC".to_string()].into();
+        let xlm_usdc = pool("XLM", "USDC", 1_000 * UNIT, 100 * UNIT);
+        // A shallower pool quoting XLM higher does not set its price.
+        let thin = pool("USDC", "XLM", UNIT, UNIT);
+        let xlm_aqua = pool("XLM", "AQUA", 50 * UNIT, 5_000 * UNIT);
+        let orphan = pool("FOO", "BAR", 1, 1);
+        let prices = token_prices([&xlm_usdc, &thin, &xlm_aqua, &orphan], &usd);
+
+        assert_eq!(prices["XLM"], 0.1);
+        assert_eq!(tvl_usd(&xlm_usdc, &prices), Some(200.0));
+        assert_eq!(tvl_usd(&xlm_aqua, &prices), Some(10.0));
+        assert_eq!(tvl_usd(&orphan, &prices), None);
+    }
+}
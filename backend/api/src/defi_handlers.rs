@@ -0,0 +1,77 @@
+//! AMM pool state
+//!
+//!   GET /api/contracts/:id/defi  – the pool's tokens, reserves, fee and TVL
+//!
+//! Read periodically from the contract's instance storage by
+//! [`crate::defi`]; only contracts in the AMM category are tracked.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    defi::AMM_CATEGORY,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+};
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct DefiPool {
+    pub contract_id: Uuid,
+    pub token_a: Option<String>,
+    pub token_b: Option<String>,
+    /// Raw reserve of `token_a` in its smallest unit (an i128, as a string)
+    pub reserve_a: Option<String>,
+    pub reserve_b: Option<String>,
+    pub fee_bps: Option<i32>,
+    /// Estimated value locked, in USD; absent when neither token is priced
+    pub tvl_usd: Option<f64>,
+    /// When the pool state was last read successfully
+    pub refreshed_at: Option<DateTime<Utc>>,
+    /// When the refresher last tried
+    pub checked_at: DateTime<Utc>,
+    /// Why the last attempt failed, if it did
+    pub last_error: Option<String>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/defi",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "The pool's latest on-chain state", body = DefiPool),
+        (status = 404, description = "Contract not found, or no pool state read yet")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_defi_pool(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<DefiPool>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let pool: DefiPool = sqlx::query_as(
+        "SELECT contract_id, token_a, token_b, reserve_a::TEXT AS reserve_a,
+                reserve_b::TEXT AS reserve_b, fee_bps, tvl_usd, refreshed_at, checked_at,
+                last_error
+         FROM defi_pools WHERE contract_id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch defi pool", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "DefiPoolNotFound",
+            format!(
+                "No pool state has been read for this contract; only contracts in the '{}' category are tracked",
+                AMM_CATEGORY
+            ),
+        )
+    })?;
+    Ok(Json(pool))
+}
@@ -186,7 +186,7 @@ pub async fn detect_cycle(pool: &PgPool, start_node: Uuid, potential_dep: Uuid)
     Ok(transitive_deps.contains(&start_node))
 }
 
-/// Build D3-compatible graph representation
+/// Build D3-compatible graph representation, nodes ordered by name
 pub async fn build_dependency_graph(
     pool: &PgPool,
     network: Option<shared::Network>,
@@ -194,7 +194,8 @@ pub async fn build_dependency_graph(
     let contracts: Vec<GraphNode> = sqlx::query_as(
         "SELECT id, contract_id, name, network, is_verified, category, tags 
          FROM contracts
-         WHERE ($1::network_type IS NULL OR network = $1)",
+         WHERE ($1::network_type IS NULL OR network = $1)
+         ORDER BY name, id",
     )
     .bind(network.as_ref())
     .fetch_all(pool)
@@ -525,6 +526,69 @@ pub async fn build_local_graph(
     })
 }
 
+/// Server-side selection from the full dependency graph.
+#[derive(Debug, Default)]
+pub struct GraphFilter {
+    /// Keep only nodes within `depth` hops of these, following edges both
+    /// ways; empty for the whole graph
+    pub roots: Vec<Uuid>,
+    pub depth: u32,
+    /// Keep only nodes in this category (case-insensitive); roots are kept
+    /// regardless
+    pub category: Option<String>,
+}
+
+/// The nodes of `graph` selected by `filter`, in graph order, and the edges
+/// between them.
+pub fn filter_graph<'a>(
+    graph: &'a GraphResponse,
+    filter: &GraphFilter,
+) -> (Vec<&'a GraphNode>, Vec<&'a GraphEdge>) {
+    let reachable: Option<HashSet<Uuid>> = if filter.roots.is_empty() {
+        None
+    } else {
+        let mut neighbours: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for edge in &graph.edges {
+            neighbours.entry(edge.source).or_default().push(edge.target);
+            neighbours.entry(edge.target).or_default().push(edge.source);
+        }
+        let mut seen: HashSet<Uuid> = filter.roots.iter().copied().collect();
+        let mut queue: VecDeque<(Uuid, u32)> = filter.roots.iter().map(|&id| (id, 0)).collect();
+        while let Some((node, hops)) = queue.pop_front() {
+            if hops == filter.depth {
+                continue;
+            }
+            for &next in neighbours.get(&node).into_iter().flatten() {
+                if seen.insert(next) {
+                    queue.push_back((next, hops + 1));
+                }
+            }
+        }
+        Some(seen)
+    };
+
+    let nodes: Vec<&GraphNode> = graph
+        .nodes
+        .iter()
+        .filter(|node| reachable.as_ref().is_none_or(|ids| ids.contains(&node.id)))
+        .filter(|node| {
+            filter.roots.contains(&node.id)
+                || filter.category.as_ref().is_none_or(|category| {
+                    node.category
+                        .as_ref()
+                        .is_some_and(|c| c.eq_ignore_ascii_case(category))
+                })
+        })
+        .collect();
+    let kept: HashSet<Uuid> = nodes.iter().map(|node| node.id).collect();
+    let edges = graph
+        .edges
+        .iter()
+        .filter(|edge| kept.contains(&edge.source) && kept.contains(&edge.target))
+        .collect();
+    (nodes, edges)
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -559,4 +623,80 @@ mod tests {
         let deps = detect_dependencies_from_abi(&abi);
         assert_eq!(deps.len(), 1);
     }
+
+    #[test]
+    fn test_filter_graph_by_root_depth_and_category() {
+        let node = |name: &str, category: &str| GraphNode {
+            id: Uuid::new_v4(),
+            contract_id: format!("C{}", name.to_uppercase()),
+            name: name.to_string(),
+            network: shared::Network::Testnet,
+            is_verified: false,
+            category: Some(category.to_string()),
+            tags: vec![],
+        };
+        let edge = |source: &GraphNode, target: &GraphNode| GraphEdge {
+            source: source.id,
+            target: target.id,
+            dependency_type: "calls".to_string(),
+            call_frequency: None,
+            call_volume: None,
+            is_estimated: false,
+            is_circular: false,
+        };
+        // amm -> token -> oracle, and lender -> token
+        let (amm, token, oracle, lender) = (
+            node("amm", "defi"),
+            node("token", "token"),
+            node("oracle", "oracle"),
+            node("lender", "defi"),
+        );
+        let graph = GraphResponse {
+            edges: vec![
+                edge(&amm, &token),
+                edge(&token, &oracle),
+                edge(&lender, &token),
+            ],
+            nodes: vec![amm.clone(), token.clone(), oracle.clone(), lender.clone()],
+        };
+        let names = |nodes: Vec<&GraphNode>| -> Vec<String> {
+            nodes.into_iter().map(|n| n.name.clone()).collect()
+        };
+
+        let (nodes, edges) = filter_graph(&graph, &GraphFilter::default());
+        assert_eq!((nodes.len(), edges.len()), (4, 3));
+
+        let one_hop = GraphFilter {
+            roots: vec![amm.id],
+            depth: 1,
+            category: None,
+        };
+        let (nodes, edges) = filter_graph(&graph, &one_hop);
+        assert_eq!(names(nodes), ["amm", "token"]);
+        assert_eq!(edges.len(), 1);
+
+        let two_hops = GraphFilter {
+            depth: 2,
+            ..one_hop
+        };
+        let (nodes, _) = filter_graph(&graph, &two_hops);
+        assert_eq!(names(nodes), ["amm", "token", "oracle", "lender"]);
+
+        let defi = GraphFilter {
+            category: Some("DeFi".to_string()),
+            ..GraphFilter::default()
+        };
+        let (nodes, edges) = filter_graph(&graph, &defi);
+        assert_eq!(names(nodes), ["amm", "lender"]);
+        assert!(edges.is_empty());
+
+        let token_neighbourhood = GraphFilter {
+            roots: vec![token.id],
+            depth: 1,
+            category: Some("defi".to_string()),
+        };
+        let (nodes, edges) = filter_graph(&graph, &token_neighbourhood);
+        assert_eq!(names(nodes), ["amm", "token", "lender"]);
+        assert_eq!(edges.len(), 2);
+    }
 }
@@ -1,13 +1,19 @@
 use axum::{
     extract::{Path, State},
+    http::StatusCode,
     Json,
 };
 use chrono::{DateTime, Utc};
 use shared::{DeprecateContractRequest, DeprecationInfo, DeprecationStatus};
+use sqlx::PgPool;
 use uuid::Uuid;
 
+use crate::auth::AuthClaims;
+use crate::consumers::{self, Impact};
+use crate::contract_permissions::ensure_publisher;
 use crate::error::{ApiError, ApiResult};
 use crate::state::AppState;
+use crate::undo::{self, DeferredAction, PendingAction};
 
 #[utoipa::path(
     get,
@@ -102,18 +108,22 @@ pub async fn get_deprecation_info(
     ),
     request_body = DeprecateContractRequest,
     responses(
-        (status = 200, description = "Contract deprecated successfully", body = DeprecationInfo),
-        (status = 404, description = "Contract not found"),
-        (status = 400, description = "Invalid input or missing migration path")
+        (status = 202, description = "Deprecation queued; undoable until apply_at", body = PendingAction),
+        (status = 400, description = "Invalid input or missing migration path"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found")
     ),
+    security(("bearer_auth" = [])),
     tag = "Maintenance"
 )]
 pub async fn deprecate_contract(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     Json(req): Json<DeprecateContractRequest>,
-) -> ApiResult<Json<DeprecationInfo>> {
-    let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
+) -> ApiResult<(StatusCode, Json<PendingAction>)> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(&state, &claims, contract_uuid, "deprecate it").await?;
 
     if req.migration_guide_url.is_none() && req.replacement_contract_id.is_none() {
         return Err(ApiError::bad_request(
@@ -135,6 +145,50 @@ pub async fn deprecate_contract(
         None
     };
 
+    let pending = undo::defer(
+        &state.db,
+        &claims.sub,
+        DeferredAction::Deprecate {
+            contract_id: contract_uuid,
+            retirement_at: req.retirement_at,
+            replacement_contract_id: replacement_uuid,
+            migration_guide_url: req.migration_guide_url,
+            notes: req.notes,
+        },
+    )
+    .await
+    .map_err(|err| db_internal_error("queue deprecation", err))?;
+    tracing::info!(
+        contract = %contract_uuid,
+        apply_at = %pending.apply_at,
+        "contract deprecation queued"
+    );
+    Ok((StatusCode::ACCEPTED, Json(pending)))
+}
+
+/// Records the deprecation of `contract_uuid` and notifies its dependents and
+/// consumers. Run by [`crate::undo::DeferredActionJob`] once the grace period
+/// has passed.
+pub(crate) async fn apply_deprecation(
+    db: &PgPool,
+    contract_uuid: Uuid,
+    retirement_at: DateTime<Utc>,
+    replacement_uuid: Option<Uuid>,
+    migration_guide_url: Option<&str>,
+    notes: Option<&str>,
+) -> ApiResult<()> {
+    let contract_id: String = sqlx::query_scalar("SELECT contract_id FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_optional(db)
+        .await
+        .map_err(|err| db_internal_error("fetch contract", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found with ID: {}", contract_uuid),
+            )
+        })?;
+
     sqlx::query(
         "INSERT INTO contract_deprecations (contract_id, retirement_at, replacement_contract_id, migration_guide_url, notes) \
          VALUES ($1, $2, $3, $4, $5) \
@@ -146,36 +200,43 @@ pub async fn deprecate_contract(
            updated_at = NOW()",
     )
     .bind(contract_uuid)
-    .bind(req.retirement_at)
+    .bind(retirement_at)
     .bind(replacement_uuid)
-    .bind(&req.migration_guide_url)
-    .bind(&req.notes)
-    .execute(&state.db)
+    .bind(migration_guide_url)
+    .bind(notes)
+    .execute(db)
     .await
     .map_err(|err| db_internal_error("upsert deprecation", err))?;
 
-    notify_dependents(&state, contract_uuid, &contract_id, req.retirement_at).await?;
-
-    get_deprecation_info(State(state), Path(contract_id)).await
+    notify_dependents(db, contract_uuid, &contract_id, retirement_at).await?;
+    consumers::notify(
+        db,
+        Impact::Deprecation {
+            contract_id: contract_uuid,
+            retirement_at,
+        },
+    )
+    .await;
+    Ok(())
 }
 
-async fn notify_dependents(
-    state: &AppState,
+pub(crate) async fn notify_dependents(
+    db: &PgPool,
     deprecated_id: Uuid,
     contract_id: &str,
     retirement_at: DateTime<Utc>,
 ) -> ApiResult<()> {
     let has_dep_contract_id =
-        column_exists(state, "contract_dependencies", "dependency_contract_id").await?;
-    let has_dep_name = column_exists(state, "contract_dependencies", "dependency_name").await?;
-    let has_package_name = column_exists(state, "contract_dependencies", "package_name").await?;
+        column_exists(db, "contract_dependencies", "dependency_contract_id").await?;
+    let has_dep_name = column_exists(db, "contract_dependencies", "dependency_name").await?;
+    let has_package_name = column_exists(db, "contract_dependencies", "package_name").await?;
 
     let dependents: Vec<Uuid> = if has_dep_contract_id {
         sqlx::query_scalar(
             "SELECT DISTINCT contract_id FROM contract_dependencies WHERE dependency_contract_id = $1",
         )
         .bind(deprecated_id)
-        .fetch_all(&state.db)
+        .fetch_all(db)
         .await
         .map_err(|err| db_internal_error("fetch dependents", err))?
     } else if has_dep_name || has_package_name {
@@ -192,7 +253,7 @@ async fn notify_dependents(
         );
         sqlx::query_scalar(&sql)
             .bind(contract_id)
-            .fetch_all(&state.db)
+            .fetch_all(db)
             .await
             .map_err(|err| db_internal_error("fetch dependents", err))?
     } else {
@@ -218,7 +279,7 @@ async fn notify_dependents(
         .bind(dependent)
         .bind(deprecated_id)
         .bind(&message)
-        .execute(&state.db)
+        .execute(db)
         .await
         .map_err(|err| db_internal_error("insert notification", err))?;
     }
@@ -284,13 +345,13 @@ fn db_internal_error(operation: &str, err: sqlx::Error) -> ApiError {
     ApiError::internal("Database operation failed")
 }
 
-async fn column_exists(state: &AppState, table: &str, column: &str) -> ApiResult<bool> {
+async fn column_exists(db: &PgPool, table: &str, column: &str) -> ApiResult<bool> {
     let exists = sqlx::query_scalar::<_, bool>(
         "SELECT EXISTS (SELECT 1 FROM information_schema.columns WHERE table_name = $1 AND column_name = $2)",
     )
     .bind(table)
     .bind(column)
-    .fetch_one(&state.db)
+    .fetch_one(db)
     .await
     .map_err(|err| db_internal_error("check column", err))?;
 
@@ -0,0 +1,345 @@
+//! Coordinated disclosure inbox
+//!
+//!   POST /api/contracts/:id/disclosures                      – submit a report (anyone)
+//!   GET  /api/contracts/:id/disclosures                      – sealed reports (publisher only)
+//!   POST /api/contracts/:id/disclosures/:report_id/status    – acknowledge or resolve (publisher only)
+//!
+//! Reports are sealed to the publisher's Stellar account key the moment they
+//! arrive (see [`shared::disclosure`]); the plaintext is neither stored nor
+//! logged. The publisher downloads the ciphertext and opens it locally with
+//! `soroban-registry disclosures read`.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::disclosure::{seal, DisclosureMessage};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::ensure_publisher,
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+};
+
+const MAX_TITLE_LEN: usize = 200;
+const MAX_BODY_BYTES: usize = 64 * 1024;
+const MAX_CONTACT_LEN: usize = 200;
+const STATUSES: [&str; 3] = ["new", "acknowledged", "resolved"];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubmitDisclosureRequest {
+    pub title: String,
+    /// The report itself, typically Markdown
+    pub body: String,
+    /// How the maintainers can reach you; sealed with the report
+    pub contact: Option<String>,
+}
+
+/// What the researcher gets back: proof of receipt, nothing of the content.
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct DisclosureReceipt {
+    pub id: Uuid,
+    /// Stellar address the report was sealed to
+    pub recipient_address: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct DisclosureRow {
+    id: Uuid,
+    recipient_address: String,
+    ephemeral_public: String,
+    nonce: String,
+    ciphertext: Vec<u8>,
+    status: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct SealedDisclosure {
+    pub id: Uuid,
+    pub recipient_address: String,
+    /// Hex-encoded ephemeral X25519 public key
+    pub ephemeral_public: String,
+    /// Hex-encoded AES-GCM nonce
+    pub nonce: String,
+    /// Base64-encoded AES-256-GCM ciphertext
+    pub ciphertext: String,
+    /// new, acknowledged or resolved
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<DisclosureRow> for SealedDisclosure {
+    fn from(row: DisclosureRow) -> Self {
+        Self {
+            id: row.id,
+            recipient_address: row.recipient_address,
+            ephemeral_public: row.ephemeral_public,
+            nonce: row.nonce,
+            ciphertext: BASE64.encode(row.ciphertext),
+            status: row.status,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListDisclosuresQuery {
+    /// Only reports in this status
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateDisclosureStatusRequest {
+    /// acknowledged or resolved
+    pub status: String,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidDisclosure", message)
+}
+
+fn validate_submission(req: &SubmitDisclosureRequest) -> Result<DisclosureMessage, String> {
+    let title = req.title.trim();
+    if title.is_empty() || title.chars().count() > MAX_TITLE_LEN {
+        return Err(format!("title must be 1-{} characters", MAX_TITLE_LEN));
+    }
+    if req.body.trim().is_empty() {
+        return Err("body must not be empty".to_string());
+    }
+    if req.body.len() > MAX_BODY_BYTES {
+        return Err(format!("body must be at most {} bytes", MAX_BODY_BYTES));
+    }
+    let contact = req
+        .contact
+        .as_deref()
+        .map(str::trim)
+        .filter(|c| !c.is_empty());
+    if contact.is_some_and(|c| c.chars().count() > MAX_CONTACT_LEN) {
+        return Err(format!(
+            "contact must be at most {} characters",
+            MAX_CONTACT_LEN
+        ));
+    }
+    Ok(DisclosureMessage {
+        title: title.to_string(),
+        body: req.body.clone(),
+        contact: contact.map(str::to_string),
+    })
+}
+
+fn validate_status(status: &str) -> ApiResult<()> {
+    if !STATUSES.contains(&status) {
+        return Err(invalid(format!(
+            "Unknown status '{}'; expected one of {}",
+            status,
+            STATUSES.join(", ")
+        )));
+    }
+    Ok(())
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/disclosures",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SubmitDisclosureRequest,
+    responses(
+        (status = 202, description = "Report sealed to the publisher's key and stored", body = DisclosureReceipt),
+        (status = 400, description = "Empty or oversized report"),
+        (status = 404, description = "Contract not found"),
+        (status = 422, description = "The publisher has no usable Stellar key")
+    ),
+    tag = "Security"
+)]
+pub async fn submit_disclosure(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Json(req): Json<SubmitDisclosureRequest>,
+) -> ApiResult<(StatusCode, Json<DisclosureReceipt>)> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let message = validate_submission(&req).map_err(invalid)?;
+
+    let recipient: Option<String> = sqlx::query_scalar(
+        "SELECT p.stellar_address FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract publisher", err))?;
+    let no_recipient = || {
+        ApiError::unprocessable(
+            "NoDisclosureRecipient",
+            "This contract's publisher has no Stellar key to seal reports to",
+        )
+    };
+    let recipient = recipient.ok_or_else(no_recipient)?;
+    let key =
+        stellar_strkey::ed25519::PublicKey::from_string(&recipient).map_err(|_| no_recipient())?;
+
+    let sealed = seal(&key.0, &message).map_err(|_| no_recipient())?;
+    let receipt: DisclosureReceipt = sqlx::query_as(
+        "INSERT INTO disclosure_reports
+            (contract_id, recipient_address, ephemeral_public, nonce, ciphertext)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING id, recipient_address, created_at",
+    )
+    .bind(contract_uuid)
+    .bind(&recipient)
+    .bind(hex::encode(sealed.ephemeral_public))
+    .bind(hex::encode(sealed.nonce))
+    .bind(&sealed.ciphertext)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("store disclosure report", err))?;
+
+    tracing::info!(contract = %contract_uuid, report = %receipt.id, "disclosure report received");
+    Ok((StatusCode::ACCEPTED, Json(receipt)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/disclosures",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ListDisclosuresQuery
+    ),
+    responses(
+        (status = 200, description = "Sealed reports, newest first", body = [SealedDisclosure]),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Security"
+)]
+pub async fn list_disclosures(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Query(query): Query<ListDisclosuresQuery>,
+) -> ApiResult<Json<Vec<SealedDisclosure>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(
+        &state,
+        &claims,
+        contract_uuid,
+        "read its disclosure reports",
+    )
+    .await?;
+    if let Some(status) = &query.status {
+        validate_status(status)?;
+    }
+
+    let rows: Vec<DisclosureRow> = sqlx::query_as(
+        "SELECT id, recipient_address, ephemeral_public, nonce, ciphertext, status,
+                created_at, updated_at
+         FROM disclosure_reports
+         WHERE contract_id = $1 AND ($2::TEXT IS NULL OR status = $2)
+         ORDER BY created_at DESC",
+    )
+    .bind(contract_uuid)
+    .bind(query.status.as_deref())
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list disclosure reports", err))?;
+    Ok(Json(rows.into_iter().map(SealedDisclosure::from).collect()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/disclosures/{report_id}/status",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("report_id" = Uuid, Path, description = "Disclosure report ID")
+    ),
+    request_body = UpdateDisclosureStatusRequest,
+    responses(
+        (status = 204, description = "Status updated"),
+        (status = 400, description = "Unknown status"),
+        (status = 403, description = "Caller is not the contract's publisher"),
+        (status = 404, description = "Contract or report not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Security"
+)]
+pub async fn update_disclosure_status(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, report_id)): Path<(String, Uuid)>,
+    Json(req): Json<UpdateDisclosureStatusRequest>,
+) -> ApiResult<StatusCode> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    ensure_publisher(
+        &state,
+        &claims,
+        contract_uuid,
+        "triage its disclosure reports",
+    )
+    .await?;
+    validate_status(&req.status)?;
+
+    let updated = sqlx::query(
+        "UPDATE disclosure_reports SET status = $3, updated_at = NOW()
+         WHERE id = $1 AND contract_id = $2",
+    )
+    .bind(report_id)
+    .bind(contract_uuid)
+    .bind(&req.status)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("update disclosure report", err))?
+    .rows_affected();
+    if updated == 0 {
+        return Err(ApiError::not_found(
+            "DisclosureNotFound",
+            format!("No disclosure report {} for this contract", report_id),
+        ));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(title: &str, body: &str) -> SubmitDisclosureRequest {
+        SubmitDisclosureRequest {
+            title: title.to_string(),
+            body: body.to_string(),
+            contact: Some("  ".to_string()),
+        }
+    }
+
+    #[test]
+    fn trims_title_and_drops_blank_contact() {
+        let message = validate_submission(&request("  Overflow in mint ", "details")).unwrap();
+        assert_eq!(message.title, "Overflow in mint");
+        assert_eq!(message.contact, None);
+    }
+
+    #[test]
+    fn rejects_empty_and_oversized_reports() {
+        assert!(validate_submission(&request("", "details")).is_err());
+        assert!(validate_submission(&request("Title", " \n")).is_err());
+        assert!(validate_submission(&request("Title", &"a".repeat(MAX_BODY_BYTES + 1))).is_err());
+    }
+}
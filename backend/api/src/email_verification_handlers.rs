@@ -0,0 +1,436 @@
+//! Publisher email verification
+//!
+//!   GET  /api/me/email                 – the caller's email and whether it is verified
+//!   POST /api/me/email/challenge       – email a one-time code and link (optionally to a new address)
+//!   POST /api/me/email/verify          – confirm with the code (`publisher verify-email`)
+//!   GET  /api/email/verify/:token      – confirm by following the emailed link
+//!
+//! A challenge names the address being verified; the publisher's email only
+//! changes to it once it is confirmed, so notifications never go to an
+//! address nobody has proven to own. Email notification channels stay
+//! locked until the publisher's email is verified (see
+//! [`crate::subscription_handlers`]).
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    mailer,
+    state::AppState,
+    tenant::Tenant,
+    validation::validators::validate_email,
+};
+
+const CHALLENGE_TTL_MINUTES: i64 = 30;
+/// A new challenge can be requested this long after the previous one.
+const RESEND_COOLDOWN_SECS: i64 = 60;
+/// Wrong codes allowed before the challenge is discarded.
+const MAX_ATTEMPTS: i32 = 5;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Default, Deserialize, utoipa::ToSchema)]
+pub struct EmailChallengeRequest {
+    /// Address to verify; defaults to the publisher's current email
+    pub email: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct EmailChallengeResponse {
+    /// Address the code was sent to
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct VerifyEmailRequest {
+    /// Six-digit code from the challenge email
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct EmailStatus {
+    pub email: Option<String>,
+    pub verified: bool,
+    pub verified_at: Option<DateTime<Utc>>,
+    /// Address of an outstanding challenge, if any
+    pub pending_email: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PublisherEmail {
+    id: Uuid,
+    email: Option<String>,
+    email_verified_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct StoredChallenge {
+    publisher_id: Uuid,
+    email: String,
+    code_hash: String,
+    attempts: i32,
+    expires_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn hash_secret(secret: &str) -> String {
+    hex::encode(Sha256::digest(secret.as_bytes()))
+}
+
+fn challenge_text(code: &str, link: Option<&str>) -> String {
+    let mut text = format!(
+        "Your Soroban Registry verification code is {}.\n\n\
+         Enter it with `soroban-registry publisher verify-email --code {}`",
+        code, code
+    );
+    match link {
+        Some(link) => text.push_str(&format!(" or open this link:\n\n{}\n", link)),
+        None => text.push_str(".\n"),
+    }
+    text.push_str(&format!(
+        "\nThe code expires in {} minutes. If you did not ask for it, ignore this email.\n",
+        CHALLENGE_TTL_MINUTES
+    ));
+    text
+}
+
+async fn load_publisher(
+    state: &AppState,
+    tenant: &Tenant,
+    claims: &AuthClaims,
+) -> ApiResult<PublisherEmail> {
+    sqlx::query_as(
+        "SELECT id, email, email_verified_at FROM publishers
+         WHERE stellar_address = $1 AND tenant_id = $2",
+    )
+    .bind(&claims.sub)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load publisher email", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            "No publisher is registered for this account",
+        )
+    })
+}
+
+async fn status(state: &AppState, publisher: PublisherEmail) -> ApiResult<EmailStatus> {
+    let pending_email: Option<String> = sqlx::query_scalar(
+        "SELECT email FROM publisher_email_challenges
+         WHERE publisher_id = $1 AND expires_at > NOW()",
+    )
+    .bind(publisher.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load email challenge", err))?;
+    Ok(EmailStatus {
+        verified: publisher.email.is_some() && publisher.email_verified_at.is_some(),
+        email: publisher.email,
+        verified_at: publisher.email_verified_at,
+        pending_email,
+    })
+}
+
+/// Marks the challenge's address as the publisher's verified email.
+async fn complete(state: &AppState, challenge: &StoredChallenge) -> ApiResult<PublisherEmail> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin email verification", err))?;
+    sqlx::query("DELETE FROM publisher_email_challenges WHERE publisher_id = $1")
+        .bind(challenge.publisher_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("consume email challenge", err))?;
+    let publisher: PublisherEmail = sqlx::query_as(
+        "UPDATE publishers SET email = $2, email_verified_at = NOW()
+         WHERE id = $1
+         RETURNING id, email, email_verified_at",
+    )
+    .bind(challenge.publisher_id)
+    .bind(&challenge.email)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("mark email verified", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit email verification", err))?;
+    Ok(publisher)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/me/email",
+    responses(
+        (status = 200, description = "The caller's email and its verification state", body = EmailStatus),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No publisher for this account")
+    ),
+    tag = "Publishers"
+)]
+pub async fn get_email_status(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+) -> ApiResult<Json<EmailStatus>> {
+    let publisher = load_publisher(&state, &tenant, &claims).await?;
+    Ok(Json(status(&state, publisher).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/email/challenge",
+    request_body = EmailChallengeRequest,
+    responses(
+        (status = 202, description = "Verification email sent", body = EmailChallengeResponse),
+        (status = 400, description = "No email to verify, or it is malformed"),
+        (status = 409, description = "The email is already verified"),
+        (status = 429, description = "A challenge was sent less than a minute ago"),
+        (status = 503, description = "No mail relay is configured")
+    ),
+    tag = "Publishers"
+)]
+pub async fn request_email_challenge(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    body: Option<Json<EmailChallengeRequest>>,
+) -> ApiResult<(StatusCode, Json<EmailChallengeResponse>)> {
+    let req = body.map(|Json(req)| req).unwrap_or_default();
+    let publisher = load_publisher(&state, &tenant, &claims).await?;
+
+    let email = match req.email.as_deref().map(str::trim) {
+        Some(email) => email.to_string(),
+        None => publisher.email.clone().ok_or_else(|| {
+            ApiError::bad_request(
+                "EmailMissing",
+                "The publisher has no email; pass one to verify",
+            )
+        })?,
+    };
+    validate_email(&email).map_err(|msg| ApiError::bad_request("InvalidEmail", msg))?;
+    if publisher.email_verified_at.is_some()
+        && publisher
+            .email
+            .as_deref()
+            .is_some_and(|current| current.eq_ignore_ascii_case(&email))
+    {
+        return Err(ApiError::conflict(
+            "EmailAlreadyVerified",
+            format!("{} is already verified", email),
+        ));
+    }
+    if !mailer::is_configured() {
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "EmailDeliveryUnavailable",
+            "Set MAIL_RELAY_URL to enable email verification",
+        ));
+    }
+
+    let recent: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM publisher_email_challenges
+                       WHERE publisher_id = $1 AND created_at > NOW() - make_interval(secs => $2))",
+    )
+    .bind(publisher.id)
+    .bind(RESEND_COOLDOWN_SECS as f64)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check email challenge cooldown", err))?;
+    if recent {
+        return Err(ApiError::rate_limited(
+            "A verification email was sent less than a minute ago",
+        ));
+    }
+
+    let (code, link_token) = {
+        let mut rng = rand::thread_rng();
+        let code = format!("{:06}", rng.gen_range(0..1_000_000));
+        let link_token = hex::encode(rng.gen::<[u8; 32]>());
+        (code, link_token)
+    };
+    let expires_at = Utc::now() + Duration::minutes(CHALLENGE_TTL_MINUTES);
+    sqlx::query(
+        "INSERT INTO publisher_email_challenges
+            (publisher_id, email, code_hash, link_token_hash, expires_at)
+         VALUES ($1, $2, $3, $4, $5)
+         ON CONFLICT (publisher_id) DO UPDATE
+            SET email = EXCLUDED.email,
+                code_hash = EXCLUDED.code_hash,
+                link_token_hash = EXCLUDED.link_token_hash,
+                attempts = 0,
+                expires_at = EXCLUDED.expires_at,
+                created_at = NOW()",
+    )
+    .bind(publisher.id)
+    .bind(&email)
+    .bind(hash_secret(&code))
+    .bind(hash_secret(&link_token))
+    .bind(expires_at)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("store email challenge", err))?;
+
+    let link = mailer::public_url().map(|base| format!("{}/api/email/verify/{}", base, link_token));
+    let text = challenge_text(&code, link.as_deref());
+    let sent = mailer::send(&mailer::Email {
+        to: &email,
+        subject: "Verify your Soroban Registry email",
+        text: &text,
+    })
+    .await;
+    if let Err(err) = sent {
+        tracing::warn!(publisher = %publisher.id, error = %err, "failed to send verification email");
+        return Err(ApiError::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "EmailDeliveryUnavailable",
+            "The verification email could not be sent; try again later",
+        ));
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(EmailChallengeResponse { email, expires_at }),
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/email/verify",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email verified", body = EmailStatus),
+        (status = 400, description = "Wrong or expired code"),
+        (status = 404, description = "No pending challenge")
+    ),
+    tag = "Publishers"
+)]
+pub async fn verify_email_code(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Json(req): Json<VerifyEmailRequest>,
+) -> ApiResult<Json<EmailStatus>> {
+    let publisher = load_publisher(&state, &tenant, &claims).await?;
+    let challenge: StoredChallenge = sqlx::query_as(
+        "SELECT publisher_id, email, code_hash, attempts, expires_at
+         FROM publisher_email_challenges WHERE publisher_id = $1",
+    )
+    .bind(publisher.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load email challenge", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "EmailChallengeNotFound",
+            "No verification is pending; request a new code",
+        )
+    })?;
+
+    if challenge.expires_at <= Utc::now() {
+        return Err(ApiError::bad_request(
+            "EmailChallengeExpired",
+            "The code has expired; request a new one",
+        ));
+    }
+    if hash_secret(req.code.trim()) != challenge.code_hash {
+        // Count the failure; the last allowed one discards the challenge.
+        let exhausted = challenge.attempts + 1 >= MAX_ATTEMPTS;
+        let query = if exhausted {
+            "DELETE FROM publisher_email_challenges WHERE publisher_id = $1"
+        } else {
+            "UPDATE publisher_email_challenges SET attempts = attempts + 1 WHERE publisher_id = $1"
+        };
+        sqlx::query(query)
+            .bind(publisher.id)
+            .execute(&state.db)
+            .await
+            .map_err(|err| db_internal_error("record failed email code", err))?;
+        let message = if exhausted {
+            "Wrong code; too many attempts, request a new one"
+        } else {
+            "Wrong code"
+        };
+        return Err(ApiError::bad_request("InvalidEmailCode", message));
+    }
+
+    let publisher = complete(&state, &challenge).await?;
+    Ok(Json(status(&state, publisher).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/email/verify/{token}",
+    params(("token" = String, Path, description = "Token from the verification link")),
+    responses(
+        (status = 200, description = "Email verified", body = EmailStatus),
+        (status = 404, description = "Link is invalid, used or expired")
+    ),
+    tag = "Publishers"
+)]
+pub async fn verify_email_link(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> ApiResult<Json<EmailStatus>> {
+    let challenge: StoredChallenge = sqlx::query_as(
+        "SELECT publisher_id, email, code_hash, attempts, expires_at
+         FROM publisher_email_challenges
+         WHERE link_token_hash = $1 AND expires_at > NOW()",
+    )
+    .bind(hash_secret(&token))
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load email challenge", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "EmailChallengeNotFound",
+            "The verification link is invalid, used or expired",
+        )
+    })?;
+
+    let publisher = complete(&state, &challenge).await?;
+    Ok(Json(status(&state, publisher).await?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn secrets_are_stored_hashed() {
+        let hash = hash_secret("123456");
+        assert_eq!(hash.len(), 64);
+        assert_ne!(hash, "123456");
+        assert_eq!(hash, hash_secret("123456"));
+    }
+
+    #[test]
+    fn challenge_email_mentions_code_and_link() {
+        let text = challenge_text(
+            "042917",
+            Some("https://registry.example/api/email/verify/ab"),
+        );
+        assert!(text.contains("code is 042917."));
+        assert!(text.contains("verify-email --code 042917"));
+        assert!(text.contains("https://registry.example/api/email/verify/ab"));
+        assert!(!challenge_text("042917", None).contains("link"));
+    }
+}
@@ -17,8 +17,10 @@ pub enum ErrorCode {
     Conflict,
     UnprocessableEntity,
     PayloadTooLarge,
+    PreconditionRequired,
     RateLimited,
     InternalError,
+    InsufficientStorage,
 }
 
 impl ErrorCode {
@@ -31,7 +33,9 @@ impl ErrorCode {
             StatusCode::CONFLICT => Self::Conflict,
             StatusCode::UNPROCESSABLE_ENTITY => Self::UnprocessableEntity,
             StatusCode::PAYLOAD_TOO_LARGE => Self::PayloadTooLarge,
+            StatusCode::PRECONDITION_REQUIRED => Self::PreconditionRequired,
             StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+            StatusCode::INSUFFICIENT_STORAGE => Self::InsufficientStorage,
             _ => Self::InternalError,
         }
     }
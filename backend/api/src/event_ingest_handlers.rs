@@ -0,0 +1,346 @@
+//! Bulk ingestion of contract events from external indexers
+//!
+//!   POST /api/events/batch  – upsert up to 1000 events (admin token required)
+//!
+//! Events are keyed by (network, transaction hash, event index). Re-sending an
+//! event updates the stored row only if its content changed, so indexers can
+//! retry a batch without duplicating rows. Each item gets its own result;
+//! invalid items are rejected without failing the rest of the batch.
+//! Ingested events feed the alert ([`crate::alerts`]) and anomaly
+//! ([`crate::anomalies`]) scans.
+
+use std::collections::HashMap;
+
+use axum::{extract::State, Json};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    validation::validators::validate_contract_id,
+};
+
+const MAX_BATCH_SIZE: usize = 1000;
+const MAX_TOPIC_LEN: usize = 255;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct IngestEvent {
+    /// On-chain contract ID (`C...`)
+    pub contract_id: String,
+    pub network: Network,
+    pub topic: String,
+    pub data: Option<serde_json::Value>,
+    pub ledger_sequence: i64,
+    /// Hex transaction hash
+    pub transaction_hash: String,
+    /// Position of the event within its transaction
+    pub event_index: i32,
+    /// Ledger close time
+    pub timestamp: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct IngestEventsRequest {
+    pub events: Vec<IngestEvent>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestStatus {
+    Inserted,
+    Updated,
+    /// Already stored with identical content
+    Unchanged,
+    /// Same key as an earlier item in this batch; that item was used
+    Duplicate,
+    Rejected,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IngestResult {
+    /// Position of the item in the request
+    pub index: usize,
+    pub status: IngestStatus,
+    pub id: Option<Uuid>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize, utoipa::ToSchema)]
+pub struct IngestEventsResponse {
+    pub inserted: usize,
+    pub updated: usize,
+    pub unchanged: usize,
+    pub duplicate: usize,
+    pub rejected: usize,
+    pub results: Vec<IngestResult>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+type EventKey = (String, String, i32);
+
+fn event_key(event: &IngestEvent) -> EventKey {
+    (
+        event.network.to_string(),
+        event.transaction_hash.trim().to_ascii_lowercase(),
+        event.event_index,
+    )
+}
+
+fn validate_event(event: &IngestEvent) -> Result<(), String> {
+    validate_contract_id(&event.contract_id)
+        .map_err(|msg| format!("invalid contract_id: {}", msg))?;
+    let topic = event.topic.trim();
+    if topic.is_empty() || topic.len() > MAX_TOPIC_LEN {
+        return Err(format!(
+            "topic must be between 1 and {} characters",
+            MAX_TOPIC_LEN
+        ));
+    }
+    let hash = event.transaction_hash.trim();
+    if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err("transaction_hash must be 64 hex characters".to_string());
+    }
+    if event.ledger_sequence <= 0 {
+        return Err("ledger_sequence must be positive".to_string());
+    }
+    if event.event_index < 0 {
+        return Err("event_index must not be negative".to_string());
+    }
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct StoredEvent {
+    id: Uuid,
+    network: String,
+    transaction_hash: String,
+    event_index: i32,
+    inserted: bool,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/events/batch",
+    request_body = IngestEventsRequest,
+    responses(
+        (status = 200, description = "Per-item ingestion results", body = IngestEventsResponse),
+        (status = 400, description = "Empty or oversized batch"),
+        (status = 401, description = "Admin token required")
+    ),
+    tag = "Contracts"
+)]
+pub async fn ingest_events_batch(
+    State(state): State<AppState>,
+    Json(req): Json<IngestEventsRequest>,
+) -> ApiResult<Json<IngestEventsResponse>> {
+    if req.events.is_empty() || req.events.len() > MAX_BATCH_SIZE {
+        return Err(ApiError::bad_request(
+            "InvalidBatch",
+            format!("events must contain between 1 and {} items", MAX_BATCH_SIZE),
+        ));
+    }
+
+    let mut results: Vec<IngestResult> = Vec::with_capacity(req.events.len());
+    // Key -> index of the item that will be written for it.
+    let mut accepted: HashMap<EventKey, usize> = HashMap::new();
+    for (index, event) in req.events.iter().enumerate() {
+        let (status, error) = match validate_event(event) {
+            Err(msg) => (IngestStatus::Rejected, Some(msg)),
+            Ok(()) => match accepted.get(&event_key(event)) {
+                Some(first) => (
+                    IngestStatus::Duplicate,
+                    Some(format!("same key as item {}", first)),
+                ),
+                None => {
+                    accepted.insert(event_key(event), index);
+                    (IngestStatus::Unchanged, None)
+                }
+            },
+        };
+        results.push(IngestResult {
+            index,
+            status,
+            id: None,
+            error,
+        });
+    }
+
+    let rows: Vec<&IngestEvent> = results
+        .iter()
+        .filter(|r| r.error.is_none())
+        .map(|r| &req.events[r.index])
+        .collect();
+    let mut stored: Vec<StoredEvent> = Vec::new();
+    if !rows.is_empty() {
+        stored = sqlx::query_as(
+            "INSERT INTO contract_events
+                (contract_id, network, topic, data, ledger_sequence, transaction_hash,
+                 event_index, timestamp)
+             SELECT t.contract_id, t.network::network_type, t.topic, t.data, t.ledger_sequence,
+                    t.transaction_hash, t.event_index, t.ts
+             FROM UNNEST($1::TEXT[], $2::TEXT[], $3::TEXT[], $4::JSONB[], $5::BIGINT[],
+                         $6::TEXT[], $7::INT[], $8::TIMESTAMPTZ[])
+                  AS t(contract_id, network, topic, data, ledger_sequence, transaction_hash,
+                       event_index, ts)
+             ON CONFLICT (network, transaction_hash, event_index) DO UPDATE SET
+                contract_id = EXCLUDED.contract_id,
+                topic = EXCLUDED.topic,
+                data = EXCLUDED.data,
+                ledger_sequence = EXCLUDED.ledger_sequence,
+                timestamp = EXCLUDED.timestamp
+             WHERE (contract_events.contract_id, contract_events.topic, contract_events.data,
+                    contract_events.ledger_sequence, contract_events.timestamp)
+                IS DISTINCT FROM
+                   (EXCLUDED.contract_id, EXCLUDED.topic, EXCLUDED.data,
+                    EXCLUDED.ledger_sequence, EXCLUDED.timestamp)
+             RETURNING id, network::TEXT AS network, transaction_hash, event_index,
+                       (xmax = 0) AS inserted",
+        )
+        .bind(
+            rows.iter()
+                .map(|e| e.contract_id.trim())
+                .collect::<Vec<_>>(),
+        )
+        .bind(
+            rows.iter()
+                .map(|e| e.network.to_string())
+                .collect::<Vec<_>>(),
+        )
+        .bind(rows.iter().map(|e| e.topic.trim()).collect::<Vec<_>>())
+        .bind(rows.iter().map(|e| e.data.clone()).collect::<Vec<_>>())
+        .bind(rows.iter().map(|e| e.ledger_sequence).collect::<Vec<_>>())
+        .bind(rows.iter().map(|e| event_key(e).1).collect::<Vec<_>>())
+        .bind(rows.iter().map(|e| e.event_index).collect::<Vec<_>>())
+        .bind(rows.iter().map(|e| e.timestamp).collect::<Vec<_>>())
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("ingest events batch", err))?;
+    }
+
+    let mut ids: HashMap<EventKey, (Uuid, IngestStatus)> = stored
+        .into_iter()
+        .map(|row| {
+            let status = if row.inserted {
+                IngestStatus::Inserted
+            } else {
+                IngestStatus::Updated
+            };
+            (
+                (row.network, row.transaction_hash, row.event_index),
+                (row.id, status),
+            )
+        })
+        .collect();
+
+    // Rows skipped by the upsert were already stored as sent.
+    let unchanged: Vec<EventKey> = accepted
+        .keys()
+        .filter(|key| !ids.contains_key(*key))
+        .cloned()
+        .collect();
+    if !unchanged.is_empty() {
+        let existing: Vec<(Uuid, String, String, i32)> = sqlx::query_as(
+            "SELECT e.id, e.network::TEXT, e.transaction_hash, e.event_index
+             FROM contract_events e
+             JOIN UNNEST($1::TEXT[], $2::TEXT[], $3::INT[]) AS k(network, transaction_hash, event_index)
+               ON e.network = k.network::network_type
+              AND e.transaction_hash = k.transaction_hash
+              AND e.event_index = k.event_index",
+        )
+        .bind(unchanged.iter().map(|k| k.0.clone()).collect::<Vec<_>>())
+        .bind(unchanged.iter().map(|k| k.1.clone()).collect::<Vec<_>>())
+        .bind(unchanged.iter().map(|k| k.2).collect::<Vec<_>>())
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("look up unchanged events", err))?;
+        for (id, network, hash, index) in existing {
+            ids.insert((network, hash, index), (id, IngestStatus::Unchanged));
+        }
+    }
+
+    let mut response = IngestEventsResponse::default();
+    for mut result in results {
+        if result.status != IngestStatus::Rejected {
+            if let Some((id, status)) = ids.get(&event_key(&req.events[result.index])) {
+                result.id = Some(*id);
+                if result.status != IngestStatus::Duplicate {
+                    result.status = *status;
+                }
+            }
+        }
+        match result.status {
+            IngestStatus::Inserted => response.inserted += 1,
+            IngestStatus::Updated => response.updated += 1,
+            IngestStatus::Unchanged => response.unchanged += 1,
+            IngestStatus::Duplicate => response.duplicate += 1,
+            IngestStatus::Rejected => response.rejected += 1,
+        }
+        response.results.push(result);
+    }
+
+    Ok(Json(response))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event() -> IngestEvent {
+        IngestEvent {
+            contract_id: format!("C{}", "A".repeat(55)),
+            network: Network::Testnet,
+            topic: "transfer".into(),
+            data: Some(serde_json::json!({ "amount": "100" })),
+            ledger_sequence: 1234,
+            transaction_hash: "AB".repeat(32),
+            event_index: 0,
+            timestamp: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn accepts_well_formed_events() {
+        assert!(validate_event(&event()).is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_fields() {
+        let mut e = event();
+        e.contract_id = "GABC".into();
+        assert!(validate_event(&e).is_err());
+
+        let mut e = event();
+        e.topic = "  ".into();
+        assert!(validate_event(&e).is_err());
+
+        let mut e = event();
+        e.transaction_hash = "xyz".into();
+        assert!(validate_event(&e).is_err());
+
+        let mut e = event();
+        e.ledger_sequence = 0;
+        assert!(validate_event(&e).is_err());
+
+        let mut e = event();
+        e.event_index = -1;
+        assert!(validate_event(&e).is_err());
+    }
+
+    #[test]
+    fn key_ignores_hash_case() {
+        let mut upper = event();
+        upper.transaction_hash = upper.transaction_hash.to_ascii_uppercase();
+        let mut lower = event();
+        lower.transaction_hash = lower.transaction_hash.to_ascii_lowercase();
+        assert_eq!(event_key(&upper), event_key(&lower));
+    }
+}
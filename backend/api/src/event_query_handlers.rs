@@ -0,0 +1,385 @@
+//! Querying ingested contract events
+//!
+//!   GET /api/contracts/:id/events  – events emitted by one contract
+//!   GET /api/events                – merged events from several contracts,
+//!                                    selected by ID list, tag or category
+//!   GET /api/events/export         – every event matching the same selectors,
+//!                                    streamed as NDJSON or CSV
+//!
+//! Results from all selected contracts are merged into one stream ordered by
+//! ledger (then event index), so a protocol team can follow its whole suite
+//! of contracts at once. Private contracts are only included for members of
+//! the owning organization.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    accounting_report_handlers::csv_field,
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    streaming::{stream_body, CHUNK_BYTES},
+    tenant::Tenant,
+};
+
+const MAX_SELECTED_IDS: usize = 50;
+const CSV_HEADER: &str =
+    "id,contract_id,topic,data,ledger_sequence,transaction_hash,timestamp,network\n";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct EventQuery {
+    /// Comma-separated contract UUIDs or on-chain IDs (`/api/events` only)
+    pub contracts: Option<String>,
+    /// Include every contract with this tag (`/api/events` only)
+    pub tag: Option<String>,
+    /// Include every contract in this category (`/api/events` only)
+    pub category: Option<String>,
+    /// Restrict selected contracts to one network
+    pub network: Option<String>,
+    /// Only events with this topic
+    pub topic: Option<String>,
+    /// Case-insensitive substring match on the JSON event data
+    pub data_pattern: Option<String>,
+    pub from_ledger: Option<i64>,
+    pub to_ledger: Option<i64>,
+    /// `desc` (newest first, default) or `asc`
+    pub order: Option<String>,
+    /// Page size, 1-1000 (default 50); exports are not paged
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+    /// Export format, `ndjson` (default) or `csv` (`/api/events/export` only)
+    pub format: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct EventRecord {
+    pub id: Uuid,
+    /// On-chain contract ID
+    pub contract_id: String,
+    /// Registry UUID of the contract
+    pub registry_id: Uuid,
+    pub contract_name: String,
+    pub topic: String,
+    pub data: Option<serde_json::Value>,
+    pub ledger_sequence: i64,
+    pub transaction_hash: Option<String>,
+    pub event_index: Option<i32>,
+    pub timestamp: DateTime<Utc>,
+    pub network: String,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+#[derive(sqlx::FromRow)]
+struct SelectedContract {
+    id: Uuid,
+    contract_id: String,
+}
+
+fn split_ids(raw: &str) -> Vec<String> {
+    let mut ids: Vec<String> = Vec::new();
+    for id in raw.split(',').map(str::trim).filter(|id| !id.is_empty()) {
+        if !ids.iter().any(|seen| seen == id) {
+            ids.push(id.to_string());
+        }
+    }
+    ids
+}
+
+/// The explicitly listed contract IDs of a multi-contract query, after
+/// checking that the query selects something.
+fn listed_ids(query: &EventQuery) -> ApiResult<Vec<String>> {
+    let ids = query
+        .contracts
+        .as_deref()
+        .map(split_ids)
+        .unwrap_or_default();
+    if ids.is_empty() && query.tag.is_none() && query.category.is_none() {
+        return Err(ApiError::bad_request(
+            "MissingSelector",
+            "Provide at least one of: contracts, tag, category",
+        ));
+    }
+    if ids.len() > MAX_SELECTED_IDS {
+        return Err(ApiError::bad_request(
+            "TooManyContracts",
+            format!("At most {} contract IDs per query", MAX_SELECTED_IDS),
+        ));
+    }
+    Ok(ids)
+}
+
+fn order_direction(order: Option<&str>) -> ApiResult<&'static str> {
+    match order.map(str::to_ascii_lowercase).as_deref() {
+        None | Some("desc") => Ok("DESC"),
+        Some("asc") => Ok("ASC"),
+        Some(_) => Err(ApiError::bad_request(
+            "InvalidOrder",
+            "order must be 'asc' or 'desc'",
+        )),
+    }
+}
+
+/// Contracts matched by any part of the selector (listed IDs, tag or
+/// category) that the caller may read. Every explicitly listed ID must
+/// resolve, so typos surface as 404s rather than silently thinning the stream.
+async fn select_contracts(
+    state: &AppState,
+    tenant: &Tenant,
+    claims: Option<&AuthClaims>,
+    ids: &[String],
+    query: &EventQuery,
+) -> ApiResult<Vec<SelectedContract>> {
+    let selected: Vec<SelectedContract> = sqlx::query_as(
+        "SELECT c.id, c.contract_id
+         FROM contracts c
+         WHERE c.deleted_at IS NULL AND c.tenant_id = $1
+           AND (c.visibility = 'public'
+                OR (c.visibility = 'private' AND c.organization_id IN (
+                    SELECT om.organization_id FROM organization_members om
+                    JOIN publishers p ON p.id = om.publisher_id
+                    WHERE p.stellar_address = $2)))
+           AND (c.id::TEXT = ANY($3) OR c.contract_id = ANY($3)
+                OR ($4::TEXT IS NOT NULL AND c.id IN (
+                    SELECT ct.contract_id FROM contract_tags ct
+                    JOIN tags t ON t.id = ct.tag_id WHERE t.name = $4))
+                OR ($5::TEXT IS NOT NULL AND c.category = $5))
+           AND ($6::TEXT IS NULL OR c.network::TEXT = $6)",
+    )
+    .bind(tenant.id)
+    .bind(claims.map(|c| c.sub.as_str()))
+    .bind(ids)
+    .bind(query.tag.as_deref())
+    .bind(query.category.as_deref())
+    .bind(query.network.as_deref())
+    .fetch_all(&state.db_read)
+    .await
+    .map_err(|err| db_internal_error("select contracts for events", err))?;
+
+    let missing: Vec<&str> = ids
+        .iter()
+        .filter(|id| {
+            !selected
+                .iter()
+                .any(|c| c.id.to_string() == **id || c.contract_id == **id)
+        })
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            format!("Contract(s) not found: {}", missing.join(", ")),
+        ));
+    }
+    Ok(selected)
+}
+
+/// Events of the selected contracts matching the query's filters, in the
+/// requested order; binds `$1`-`$5` (contract UUIDs, topic, data pattern,
+/// ledger range).
+fn events_sql(direction: &str) -> String {
+    format!(
+        "SELECT e.id, e.contract_id, c.id AS registry_id, c.name AS contract_name,
+                e.topic, e.data, e.ledger_sequence, e.transaction_hash, e.event_index, e.timestamp,
+                e.network::TEXT AS network, e.created_at
+         FROM contract_events e
+         JOIN contracts c ON c.id = ANY($1)
+          AND c.contract_id = e.contract_id AND c.network = e.network
+         WHERE ($2::TEXT IS NULL OR e.topic = $2)
+           AND ($3::TEXT IS NULL OR e.data::TEXT ILIKE '%' || $3 || '%')
+           AND ($4::BIGINT IS NULL OR e.ledger_sequence >= $4)
+           AND ($5::BIGINT IS NULL OR e.ledger_sequence <= $5)
+         ORDER BY e.ledger_sequence {dir}, e.event_index {dir} NULLS LAST,
+                  e.created_at {dir}, e.id",
+        dir = direction
+    )
+}
+
+async fn fetch_events(
+    state: &AppState,
+    contracts: &[SelectedContract],
+    query: &EventQuery,
+) -> ApiResult<Vec<EventRecord>> {
+    if contracts.is_empty() {
+        return Ok(Vec::new());
+    }
+    let direction = order_direction(query.order.as_deref())?;
+
+    let sql = format!("{} LIMIT $6 OFFSET $7", events_sql(direction));
+    sqlx::query_as(&sql)
+        .bind(contracts.iter().map(|c| c.id).collect::<Vec<_>>())
+        .bind(query.topic.as_deref())
+        .bind(query.data_pattern.as_deref())
+        .bind(query.from_ledger)
+        .bind(query.to_ledger)
+        .bind(query.limit.unwrap_or(50).clamp(1, 1000))
+        .bind(query.offset.unwrap_or(0).max(0))
+        .fetch_all(&state.db_read)
+        .await
+        .map_err(|err| db_internal_error("query contract events", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/events",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        EventQuery
+    ),
+    responses(
+        (status = 200, description = "Events emitted by the contract", body = [EventRecord]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_contract_events(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+    Query(query): Query<EventQuery>,
+) -> ApiResult<Json<Vec<EventRecord>>> {
+    let query = EventQuery {
+        contracts: None,
+        tag: None,
+        category: None,
+        ..query
+    };
+    let contracts = select_contracts(&state, &tenant, claims.as_ref(), &[id], &query).await?;
+    Ok(Json(fetch_events(&state, &contracts, &query).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/events",
+    params(EventQuery),
+    responses(
+        (status = 200, description = "Merged events from the selected contracts", body = [EventRecord]),
+        (status = 400, description = "No selector given, or too many contract IDs"),
+        (status = 404, description = "A listed contract was not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn query_events(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Query(query): Query<EventQuery>,
+) -> ApiResult<Json<Vec<EventRecord>>> {
+    let ids = listed_ids(&query)?;
+    let contracts = select_contracts(&state, &tenant, claims.as_ref(), &ids, &query).await?;
+    Ok(Json(fetch_events(&state, &contracts, &query).await?))
+}
+
+fn csv_row(event: &EventRecord) -> String {
+    let data = event
+        .data
+        .as_ref()
+        .map(|data| data.to_string())
+        .unwrap_or_default();
+    format!(
+        "{},{},{},{},{},{},{},{}\n",
+        event.id,
+        csv_field(&event.contract_id),
+        csv_field(&event.topic),
+        csv_field(&data),
+        event.ledger_sequence,
+        csv_field(event.transaction_hash.as_deref().unwrap_or("")),
+        event.timestamp.to_rfc3339(),
+        csv_field(&event.network),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/events/export",
+    params(EventQuery),
+    responses(
+        (status = 200, description = "Every matching event, streamed as NDJSON or, with format=csv, CSV", content_type = "application/x-ndjson"),
+        (status = 400, description = "No selector given, too many contract IDs, or an unknown format"),
+        (status = 404, description = "A listed contract was not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn export_events(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Query(query): Query<EventQuery>,
+) -> ApiResult<Response> {
+    let csv = match query.format.as_deref() {
+        None | Some("ndjson") => false,
+        Some("csv") => true,
+        Some(other) => {
+            return Err(ApiError::bad_request(
+                "InvalidExportFormat",
+                format!("Unknown export format '{}'; use ndjson or csv", other),
+            ))
+        }
+    };
+    let ids = listed_ids(&query)?;
+    let contracts = select_contracts(&state, &tenant, claims.as_ref(), &ids, &query).await?;
+    let sql = events_sql(order_direction(query.order.as_deref())?);
+    let contract_ids: Vec<Uuid> = contracts.iter().map(|c| c.id).collect();
+
+    let db = state.db_read.clone();
+    let body = stream_body(move |body| async move {
+        let mut rows = sqlx::query_as::<_, EventRecord>(&sql)
+            .bind(contract_ids)
+            .bind(query.topic.as_deref())
+            .bind(query.data_pattern.as_deref())
+            .bind(query.from_ledger)
+            .bind(query.to_ledger)
+            .fetch(&db);
+        let mut chunk = if csv {
+            CSV_HEADER.to_string()
+        } else {
+            String::new()
+        };
+        while let Some(event) = rows.try_next().await? {
+            if csv {
+                chunk.push_str(&csv_row(&event));
+            } else {
+                chunk.push_str(&serde_json::to_string(&event)?);
+                chunk.push('\n');
+            }
+            if chunk.len() >= CHUNK_BYTES {
+                body.write(std::mem::take(&mut chunk)).await?;
+            }
+        }
+        body.write(chunk).await?;
+        Ok(())
+    });
+
+    let (content_type, filename) = if csv {
+        ("text/csv; charset=utf-8", "events.csv")
+    } else {
+        ("application/x-ndjson", "events.ndjson")
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+        ],
+        body,
+    )
+        .into_response())
+}
@@ -1,3 +1,4 @@
+use crate::multisig_handlers::ProposalComment;
 use crate::state::{AppState, RealtimeEvent};
 use chrono::Utc;
 
@@ -53,3 +54,17 @@ pub fn emit_cicd_pipeline(
 
     let _ = state.event_broadcaster.send(event);
 }
+
+pub fn emit_proposal_comment(state: &AppState, contract_id: &str, comment: &ProposalComment) {
+    let event = RealtimeEvent::ProposalComment {
+        proposal_id: comment.proposal_id.to_string(),
+        contract_id: contract_id.to_string(),
+        comment_id: comment.id.to_string(),
+        parent_id: comment.parent_id.map(|id| id.to_string()),
+        author: comment.author_address.clone(),
+        body: comment.body.clone(),
+        timestamp: comment.created_at.to_rfc3339(),
+    };
+
+    let _ = state.event_broadcaster.send(event);
+}
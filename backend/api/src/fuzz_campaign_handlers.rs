@@ -0,0 +1,264 @@
+//! Signed fuzzing campaign summaries
+//!
+//!   POST /api/contracts/:id/fuzz-campaigns   – submit a signed campaign for a build
+//!   GET  /api/contracts/:id/fuzz-campaigns   – campaigns, optionally for one version
+//!
+//! Like test runs (see [`crate::test_run_handlers`]), a campaign summary is
+//! signed with the publisher's Stellar key over the `shared::fuzz_report`
+//! statement (see `soroban-registry fuzz attest`) and attached to the
+//! contract version with the fuzzed build.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::fuzz_report::FuzzCampaignStatement;
+use uuid::Uuid;
+
+use crate::{
+    badge_handlers::{verify_attestation, MAX_ISSUE_SKEW_MINUTES},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    publisher_key_handlers::require_usable_account_key,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubmitFuzzCampaignRequest {
+    /// Build that was fuzzed; must belong to one of the contract's versions
+    pub wasm_hash: String,
+    /// Fuzzer that ran the campaign, e.g. "cargo-fuzz"
+    pub engine: String,
+    /// Number of fuzz targets exercised
+    pub targets: u32,
+    /// Total inputs executed across all targets
+    pub executions: u64,
+    pub duration_secs: u64,
+    /// Distinct crashing inputs found
+    pub crashes: u32,
+    pub finished_at: DateTime<Utc>,
+    /// Hex-encoded ed25519 signature of the campaign message by the
+    /// publisher's Stellar key
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListFuzzCampaignsQuery {
+    /// Only campaigns for this version
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractFuzzCampaign {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub version: String,
+    pub wasm_hash: String,
+    pub engine: String,
+    pub targets: i32,
+    pub executions: i64,
+    pub duration_secs: i64,
+    pub crashes: i32,
+    pub finished_at: DateTime<Utc>,
+    /// Stellar address that signed the campaign
+    pub signer_address: String,
+    /// Hex ed25519 signature; verifiable against `signer_address`
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct FuzzCampaignTarget {
+    contract_id: String,
+    network: String,
+    publisher_address: Option<String>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+const FUZZ_CAMPAIGN_SELECT: &str = "SELECT f.id, f.contract_id, v.version, f.wasm_hash, f.engine,
+            f.targets, f.executions, f.duration_secs, f.crashes, f.finished_at,
+            f.signer_address, f.signature, f.created_at
+       FROM contract_fuzz_campaigns f
+       JOIN contract_versions v ON v.id = f.contract_version_id";
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidFuzzCampaign", message)
+}
+
+fn bigint_column(value: u64, name: &str) -> ApiResult<i64> {
+    i64::try_from(value).map_err(|_| invalid(format!("{} is too large", name)))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/fuzz-campaigns",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SubmitFuzzCampaignRequest,
+    responses(
+        (status = 201, description = "Fuzzing campaign recorded", body = ContractFuzzCampaign),
+        (status = 400, description = "Malformed campaign summary"),
+        (status = 401, description = "Signature does not match the publisher's key"),
+        (status = 404, description = "Contract not found, or no version has that build"),
+        (status = 409, description = "This campaign was already submitted"),
+        (status = 422, description = "The publisher has no Stellar key to verify against")
+    ),
+    tag = "Versions"
+)]
+pub async fn submit_fuzz_campaign(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<SubmitFuzzCampaignRequest>,
+) -> ApiResult<(StatusCode, Json<ContractFuzzCampaign>)> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let target: FuzzCampaignTarget = sqlx::query_as(
+        "SELECT c.contract_id, c.network::TEXT AS network, p.stellar_address AS publisher_address
+           FROM contracts c
+           LEFT JOIN publishers p ON p.id = c.publisher_id
+          WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load fuzz campaign target", err))?;
+
+    let statement = FuzzCampaignStatement {
+        network: &target.network,
+        contract_id: &target.contract_id,
+        wasm_hash: &req.wasm_hash,
+        engine: &req.engine,
+        targets: req.targets,
+        executions: req.executions,
+        duration_secs: req.duration_secs,
+        crashes: req.crashes,
+        finished_at: req.finished_at,
+    };
+    statement.validate().map_err(invalid)?;
+    if req.finished_at > Utc::now() + Duration::minutes(MAX_ISSUE_SKEW_MINUTES) {
+        return Err(invalid("finished_at is in the future"));
+    }
+    let targets = i32::try_from(req.targets).map_err(|_| invalid("targets is too large"))?;
+    let crashes = i32::try_from(req.crashes).map_err(|_| invalid("crashes is too large"))?;
+    let executions = bigint_column(req.executions, "executions")?;
+    let duration_secs = bigint_column(req.duration_secs, "duration_secs")?;
+
+    let Some(signer) = target.publisher_address else {
+        return Err(ApiError::unprocessable(
+            "NoFuzzCampaignSigner",
+            "This contract's publisher has no Stellar key to verify fuzzing campaigns against",
+        ));
+    };
+    verify_attestation(&signer, &statement.signing_message(), &req.signature).map_err(
+        |reason| {
+            tracing::info!(contract = %contract_uuid, reason, "fuzz campaign rejected");
+            ApiError::unauthorized("Signature does not match the publisher's key")
+        },
+    )?;
+    require_usable_account_key(&state.db, &signer, Utc::now()).await?;
+
+    // Reverts re-use a build, so attach the campaign to its newest version.
+    let version_id: Uuid = sqlx::query_scalar(
+        "SELECT id FROM contract_versions
+          WHERE contract_id = $1 AND wasm_hash = $2
+          ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(contract_uuid)
+    .bind(&req.wasm_hash)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch fuzzed version", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "VersionNotFound",
+            format!("No version of this contract has build {}", req.wasm_hash),
+        )
+    })?;
+
+    let campaign_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_fuzz_campaigns
+            (contract_id, contract_version_id, wasm_hash, engine, targets, executions,
+             duration_secs, crashes, finished_at, signer_address, signature)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+         RETURNING id",
+    )
+    .bind(contract_uuid)
+    .bind(version_id)
+    .bind(&req.wasm_hash)
+    .bind(&req.engine)
+    .bind(targets)
+    .bind(executions)
+    .bind(duration_secs)
+    .bind(crashes)
+    .bind(req.finished_at)
+    .bind(&signer)
+    .bind(req.signature.trim().to_ascii_lowercase())
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "FuzzCampaignExists",
+            "This fuzzing campaign has already been submitted",
+        ),
+        _ => db_internal_error("insert fuzz campaign", err),
+    })?;
+
+    let campaign: ContractFuzzCampaign =
+        sqlx::query_as(&format!("{} WHERE f.id = $1", FUZZ_CAMPAIGN_SELECT))
+            .bind(campaign_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch fuzz campaign", err))?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %campaign.version,
+        engine = %campaign.engine,
+        executions = campaign.executions,
+        crashes = campaign.crashes,
+        "fuzz campaign recorded"
+    );
+    Ok((StatusCode::CREATED, Json(campaign)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/fuzz-campaigns",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ListFuzzCampaignsQuery
+    ),
+    responses(
+        (status = 200, description = "Fuzzing campaigns, newest first", body = [ContractFuzzCampaign]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Versions"
+)]
+pub async fn list_fuzz_campaigns(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<ListFuzzCampaignsQuery>,
+) -> ApiResult<Json<Vec<ContractFuzzCampaign>>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let campaigns = sqlx::query_as(&format!(
+        "{} WHERE f.contract_id = $1 AND ($2::TEXT IS NULL OR v.version = $2)
+          ORDER BY f.finished_at DESC, f.created_at DESC",
+        FUZZ_CAMPAIGN_SELECT
+    ))
+    .bind(contract_uuid)
+    .bind(&query.version)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list fuzz campaigns", err))?;
+    Ok(Json(campaigns))
+}
@@ -14,7 +14,7 @@ impl Loader<Uuid> for DbLoader {
     type Error = Arc<sqlx::Error>;
 
     async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Self::Value>, Self::Error> {
-        let contracts: Vec<Contract> = sqlx::query_as("SELECT * FROM contracts WHERE id = ANY($1)")
+        let contracts: Vec<Contract> = sqlx::query_as("SELECT * FROM contracts WHERE id = ANY($1) AND deleted_at IS NULL")
             .bind(keys)
             .fetch_all(&self.pool)
             .await
@@ -99,7 +99,7 @@ impl Loader<Uuid> for CategoryLoader {
             r#"
             SELECT
                 cc.*,
-                (SELECT COUNT(*) FROM contracts c WHERE c.category = cc.name)::BIGINT AS usage_count
+                (SELECT COUNT(*) FROM contracts c WHERE c.category = cc.path AND c.deleted_at IS NULL)::BIGINT AS usage_count
             FROM contract_categories cc
             WHERE cc.id = ANY($1)
             "#,
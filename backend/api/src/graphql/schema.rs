@@ -28,12 +28,12 @@ impl Query {
         let page = page.unwrap_or(1).max(1);
         let offset = (page - 1) * limit;
 
-        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts")
+        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE deleted_at IS NULL")
             .fetch_one(&state.db)
             .await?;
 
         let rows: Vec<shared::models::Contract> = sqlx::query_as(
-            "SELECT * FROM contracts ORDER BY created_at DESC LIMIT $1 OFFSET $2",
+            "SELECT * FROM contracts WHERE deleted_at IS NULL ORDER BY created_at DESC LIMIT $1 OFFSET $2",
         )
         .bind(limit)
         .bind(offset)
@@ -59,7 +59,7 @@ impl Query {
     async fn contract(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<ContractType>> {
         let state = ctx.data::<AppState>()?;
         let row: Option<shared::models::Contract> =
-            sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+            sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
                 .bind(id)
                 .fetch_optional(&state.db)
                 .await?;
@@ -70,7 +70,7 @@ impl Query {
     async fn publisher(&self, ctx: &Context<'_>, id: Uuid) -> Result<Option<PublisherType>> {
         let state = ctx.data::<AppState>()?;
         let row: Option<shared::models::Publisher> =
-            sqlx::query_as("SELECT * FROM publishers WHERE id = $1")
+            sqlx::query_as("SELECT * FROM publishers WHERE id = $1 AND deleted_at IS NULL")
                 .bind(id)
                 .fetch_optional(&state.db)
                 .await?;
@@ -84,9 +84,9 @@ impl Query {
             r#"
             SELECT
                 cc.*,
-                (SELECT COUNT(*) FROM contracts c WHERE c.category = cc.name)::BIGINT AS usage_count
+                (SELECT COUNT(*) FROM contracts c WHERE c.category = cc.path AND c.deleted_at IS NULL)::BIGINT AS usage_count
             FROM contract_categories cc
-            ORDER BY cc.is_default DESC, cc.name ASC
+            ORDER BY cc.path ASC
             "#,
         )
         .fetch_all(&state.db)
@@ -98,7 +98,7 @@ impl Query {
     async fn publishers(&self, ctx: &Context<'_>) -> Result<Vec<PublisherType>> {
         let state = ctx.data::<AppState>()?;
         let rows: Vec<shared::models::Publisher> =
-            sqlx::query_as("SELECT * FROM publishers ORDER BY created_at DESC")
+            sqlx::query_as("SELECT * FROM publishers WHERE deleted_at IS NULL ORDER BY created_at DESC")
                 .fetch_all(&state.db)
                 .await?;
         Ok(rows.into_iter().map(PublisherType::from).collect())
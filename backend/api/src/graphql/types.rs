@@ -323,7 +323,7 @@ impl PublisherType {
     async fn contracts(&self, ctx: &Context<'_>) -> Result<Vec<ContractType>> {
         let state = ctx.data::<AppState>()?;
         let contracts: Vec<Contract> = sqlx::query_as(
-            "SELECT * FROM contracts WHERE publisher_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM contracts WHERE publisher_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(self.id)
         .fetch_all(&state.db)
@@ -382,7 +382,7 @@ impl OrganizationType {
     async fn contracts(&self, ctx: &Context<'_>) -> Result<Vec<ContractType>> {
         let state = ctx.data::<AppState>()?;
         let contracts: Vec<Contract> = sqlx::query_as(
-            "SELECT * FROM contracts WHERE organization_id = $1 ORDER BY created_at DESC",
+            "SELECT * FROM contracts WHERE organization_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
         )
         .bind(self.id)
         .fetch_all(&state.db)
@@ -422,6 +422,7 @@ pub struct CategoryType {
     pub id: Uuid,
     pub name: String,
     pub slug: String,
+    pub path: String,
     pub description: Option<String>,
     pub parent_id: Option<Uuid>,
     pub is_default: bool,
@@ -435,6 +436,8 @@ impl CategoryType {
     async fn id(&self) -> Uuid { self.id }
     async fn name(&self) -> &str { &self.name }
     async fn slug(&self) -> &str { &self.slug }
+    /// Slugs from the root down, e.g. `defi/amm`
+    async fn path(&self) -> &str { &self.path }
     async fn description(&self) -> Option<&str> { self.description.as_deref() }
     async fn is_default(&self) -> bool { self.is_default }
     async fn usage_count(&self) -> i64 { self.usage_count }
@@ -457,6 +460,7 @@ impl From<crate::category_handlers::CategoryRow> for CategoryType {
             id: row.id,
             name: row.name,
             slug: row.slug,
+            path: row.path,
             description: row.description,
             parent_id: row.parent_id,
             is_default: row.is_default,
@@ -1,7 +1,9 @@
+pub mod migrations;
 pub mod reviews;
 pub mod validators;
 
 use crate::validation::extractors::ValidatedJson;
+use crate::validation::validators::validate_contract_id;
 use axum::{
     extract::{
         rejection::{JsonRejection, QueryRejection},
@@ -24,7 +26,7 @@ use shared::{
     ContractMetadataExportEnvelope, ContractMetadataExportRecord, ContractSearchParams,
     ContractSource, ContractVersion, CreateContractVersionRequest, CreateInteractionBatchRequest,
     CreateInteractionRequest, DeploymentHistoryQueryParams, FavoriteSearch, FieldOperator,
-    GraphResponse, InteractionTimeSeriesPoint, InteractionTimeSeriesResponse,
+    GraphPageResponse, GraphResponse, InteractionTimeSeriesPoint, InteractionTimeSeriesResponse,
     InteractionsListResponse, InteractionsQueryParams, Network, NetworkConfig, NetworkEndpoints,
     NetworkInfo, NetworkListResponse, NetworkStatus, PaginatedResponse, PublishRequest, Publisher,
     QueryCondition, QueryNode, QueryOperator, SaveFavoriteSearchRequest, SearchSuggestion,
@@ -132,6 +134,16 @@ pub struct GetContractQuery {
     pub network: Option<Network>,
     pub from_search: Option<bool>,
     pub search_query: Option<String>,
+    /// Comma-separated extras to include. Supported: stats
+    pub include: Option<String>,
+}
+
+impl GetContractQuery {
+    fn includes(&self, section: &str) -> bool {
+        self.include
+            .as_deref()
+            .is_some_and(|list| list.split(',').any(|s| s.trim() == section))
+    }
 }
 
 #[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
@@ -143,12 +155,17 @@ pub struct BatchContractsQuery {
 
 use crate::{
     analytics,
+    anchor,
     auth::AuthClaims,
     breaking_changes::{diff_abi, has_breaking_changes, resolve_abi},
+    claim_handlers,
+    concurrency,
     contract_events::{ContractEventEnvelope, ContractEventVisibility},
+    contract_permissions::{self, ContractCapability},
     dependency,
     error::{ApiError, ApiResult},
     onchain_verification::OnChainVerifier,
+    org_network_policy_handlers,
     state::AppState,
     type_safety::parser::parse_json_spec,
     type_safety::{generate_openapi, to_json, to_yaml},
@@ -164,7 +181,7 @@ pub(crate) async fn fetch_contract_identity(
     id: &str,
 ) -> ApiResult<(Uuid, String)> {
     if let Ok(uuid) = Uuid::parse_str(id) {
-        let (contract_id,): (String,) = sqlx::query_as("SELECT contract_id FROM contracts WHERE id = $1")
+        let (contract_id,): (String,) = sqlx::query_as("SELECT contract_id FROM contracts WHERE id = $1 AND deleted_at IS NULL")
             .bind(uuid)
             .fetch_one(&state.db)
             .await
@@ -175,8 +192,14 @@ pub(crate) async fn fetch_contract_identity(
         return Ok((uuid, contract_id));
     }
 
+    // An address registered on several networks resolves to its mainnet
+    // contract, then the earliest registered.
     let (uuid, contract_id): (Uuid, String) =
-        sqlx::query_as("SELECT id, contract_id FROM contracts WHERE contract_id = $1")
+        sqlx::query_as(
+            "SELECT id, contract_id FROM contracts WHERE contract_id = $1 AND deleted_at IS NULL
+             ORDER BY (network = 'mainnet') DESC, created_at
+             LIMIT 1",
+        )
             .bind(id)
             .fetch_one(&state.db)
             .await
@@ -302,11 +325,11 @@ struct IndexerStateSnapshot {
 }
 
 #[derive(Debug, Clone)]
-struct StaticNetworkDefinition {
-    id: &'static str,
+pub(crate) struct StaticNetworkDefinition {
+    pub(crate) id: &'static str,
     name: &'static str,
     network_type: Network,
-    rpc_url: String,
+    pub(crate) rpc_url: String,
     explorer_url: String,
     friendbot_url: Option<String>,
 }
@@ -335,7 +358,7 @@ fn default_friendbot_url(network: &Network) -> Option<&'static str> {
     }
 }
 
-fn configured_networks() -> Vec<StaticNetworkDefinition> {
+pub(crate) fn configured_networks() -> Vec<StaticNetworkDefinition> {
     let entries = [
         (
             "mainnet",
@@ -602,7 +625,7 @@ fn validate_contract_list_pagination(
     Ok((limit, offset, page))
 }
 
-fn extract_ip_address(headers: &HeaderMap) -> String {
+pub(crate) fn extract_ip_address(headers: &HeaderMap) -> String {
     if let Some(forwarded_for) = headers
         .get("x-forwarded-for")
         .and_then(|value| value.to_str().ok())
@@ -677,7 +700,7 @@ fn contract_to_filtered_value(contract: &Contract, fields: Option<&HashSet<Strin
     Value::Object(out)
 }
 
-async fn write_contract_audit_log(
+pub(crate) async fn write_contract_audit_log(
     db: &sqlx::PgPool,
     action_type: AuditActionType,
     contract_id: Uuid,
@@ -950,16 +973,30 @@ struct ContractInteractionInsert<'a> {
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct HealthQuery {
+    /// Also check database connectivity and the schema version against the
+    /// migrations embedded in this binary.
+    #[serde(default)]
+    pub deep: bool,
+}
+
 #[utoipa::path(
     get,
     path = "/health",
+    params(
+        ("deep" = Option<bool>, Query, description = "Include database and schema version checks")
+    ),
     responses(
         (status = 200, description = "Service is healthy", body = Object),
         (status = 503, description = "Service is unavailable or degraded", body = Object)
     ),
     tag = "Observability"
 )]
-pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+pub async fn health_check(
+    State(state): State<AppState>,
+    Query(query): Query<HealthQuery>,
+) -> (StatusCode, Json<Value>) {
     let uptime = state.started_at.elapsed().as_secs();
     let now = chrono::Utc::now().to_rfc3339();
 
@@ -979,6 +1016,10 @@ pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<Va
         );
     }
 
+    if query.deep {
+        return deep_health_check(&state, &now).await;
+    }
+
     (
         StatusCode::OK,
         Json(json!({
@@ -989,6 +1030,45 @@ pub async fn health_check(State(state): State<AppState>) -> (StatusCode, Json<Va
     )
 }
 
+/// `/health?deep=true`: report database reachability and any drift between the
+/// applied schema and the migrations embedded in this binary.
+async fn deep_health_check(state: &AppState, now: &str) -> (StatusCode, Json<Value>) {
+    match crate::migration_handlers::embedded_schema_status(&state.db).await {
+        Ok(schema) => {
+            let healthy = schema.is_consistent();
+            if !healthy {
+                tracing::warn!(?schema, "deep health check: schema version mismatch");
+            }
+            (
+                if healthy {
+                    StatusCode::OK
+                } else {
+                    StatusCode::SERVICE_UNAVAILABLE
+                },
+                Json(json!({
+                    "status": if healthy { "healthy" } else { "degraded" },
+                    "version": VERSION,
+                    "timestamp": now,
+                    "database": "ok",
+                    "schema": schema
+                })),
+            )
+        }
+        Err(err) => {
+            tracing::error!(error = ?err, "deep health check: database unavailable");
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                Json(json!({
+                    "status": "unhealthy",
+                    "version": VERSION,
+                    "timestamp": now,
+                    "database": "unavailable"
+                })),
+            )
+        }
+    }
+}
+
 pub async fn health_check_live(State(state): State<AppState>) -> StatusCode {
     if state
         .is_shutting_down
@@ -1118,18 +1198,20 @@ pub async fn health_check_detailed(State(state): State<AppState>) -> (StatusCode
     tag = "Observability"
 )]
 pub async fn get_stats(State(state): State<AppState>) -> ApiResult<Json<Value>> {
-    let total_contracts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts")
+    let total_contracts: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE deleted_at IS NULL")
         .fetch_one(&state.db)
         .await
         .map_err(|err| db_internal_error("count contracts", err))?;
 
     let verified_contracts: i64 =
-        sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE is_verified = true")
+        sqlx::query_scalar(
+            "SELECT COUNT(*) FROM contracts WHERE is_verified = true AND deleted_at IS NULL",
+        )
             .fetch_one(&state.db)
             .await
             .map_err(|err| db_internal_error("count verified contracts", err))?;
 
-    let total_publishers: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM publishers")
+    let total_publishers: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM publishers WHERE deleted_at IS NULL")
         .fetch_one(&state.db)
         .await
         .map_err(|err| db_internal_error("count publishers", err))?;
@@ -1216,7 +1298,7 @@ pub async fn get_contract_search_suggestions(
                     CASE WHEN lower(name) LIKE $2 THEN 1.0 ELSE 0.0 END
                 ) AS score
             FROM contracts
-            WHERE lower(name) LIKE $2 OR lower(name) % $1
+            WHERE deleted_at IS NULL AND (lower(name) LIKE $2 OR lower(name) % $1)
 
             UNION ALL
 
@@ -1228,7 +1310,7 @@ pub async fn get_contract_search_suggestions(
                     CASE WHEN lower(category) LIKE $2 THEN 0.95 ELSE 0.0 END
                 ) AS score
             FROM contracts
-            WHERE category IS NOT NULL
+            WHERE category IS NOT NULL AND deleted_at IS NULL
               AND (lower(category) LIKE $2 OR lower(category) % $1)
         )
         SELECT text, kind, score
@@ -1241,7 +1323,7 @@ pub async fn get_contract_search_suggestions(
     .bind(&normalized)
     .bind(&prefix)
     .bind(limit)
-    .fetch_all(&state.db)
+    .fetch_all(&state.db_read)
     .await
     .map_err(|err| db_internal_error("fetch search suggestions", err))?;
 
@@ -1308,9 +1390,54 @@ pub async fn list_tags(
     Ok(Json(json!(tags)))
 }
 
+/// Whether a listing embeds `section` in each result. Publishers were
+/// embedded before `include` existed, so they stay on when it is omitted.
+fn listing_includes(params: &ContractSearchParams, section: &str) -> bool {
+    match params.include.as_deref() {
+        None => section == "publisher",
+        Some(list) => list.split(',').any(|s| s.trim() == section),
+    }
+}
+
+/// Latest release of each contract in one query, keyed by contract id.
+async fn latest_versions(
+    db: &sqlx::PgPool,
+    contract_ids: &[Uuid],
+) -> sqlx::Result<HashMap<Uuid, shared::LatestVersion>> {
+    let rows: Vec<(Uuid, String, chrono::DateTime<chrono::Utc>, bool)> = sqlx::query_as(
+        "SELECT c.id, v.version, v.created_at, v.yanked_at IS NOT NULL
+         FROM contracts c
+         JOIN LATERAL (
+             SELECT version, created_at, yanked_at
+             FROM contract_versions cv
+             WHERE cv.contract_id = c.id
+             ORDER BY (cv.yanked_at IS NULL) DESC, cv.created_at DESC
+             LIMIT 1
+         ) v ON TRUE
+         WHERE c.id = ANY($1)",
+    )
+    .bind(contract_ids)
+    .fetch_all(db)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(id, version, released_at, yanked)| {
+            (
+                id,
+                shared::LatestVersion {
+                    version,
+                    released_at,
+                    yanked,
+                },
+            )
+        })
+        .collect())
+}
+
 pub async fn list_contracts(
     State(state): State<AppState>,
     claims: Option<crate::auth::AuthClaims>,
+    tenant: crate::tenant::Tenant,
     params: Result<Query<ContractSearchParams>, QueryRejection>,
 ) -> axum::response::Response {
     let search_started_at = std::time::Instant::now();
@@ -1335,7 +1462,9 @@ pub async fn list_contracts(
     let mut qb: QueryBuilder<'_, sqlx::Postgres> = QueryBuilder::new(
         "SELECT c.* FROM contracts c LEFT JOIN contract_interactions ci ON c.id = ci.contract_id ",
     );
-    qb.push("WHERE c.visibility = 'public'");
+    qb.push("WHERE c.deleted_at IS NULL AND c.tenant_id = ");
+    qb.push_bind(tenant.id);
+    qb.push(" AND (c.visibility = 'public'");
 
     if let Some(claims) = &claims {
         qb.push(" OR (c.visibility = 'private' AND c.organization_id IN (");
@@ -1344,6 +1473,7 @@ pub async fn list_contracts(
         qb.push_bind(&claims.sub);
         qb.push("))");
     }
+    qb.push(")");
 
     if params.verified_only.unwrap_or(false) {
         qb.push(" AND c.is_verified = true");
@@ -1355,8 +1485,7 @@ pub async fn list_contracts(
     }
 
     if let Some(category) = &params.category {
-        qb.push(" AND c.category = ");
-        qb.push_bind(category);
+        crate::category_handlers::push_subtree_filter(&mut qb, std::slice::from_ref(category));
     }
 
     if let Some(networks) = params
@@ -1374,6 +1503,31 @@ pub async fn list_contracts(
         separated.push_unseparated(")");
     }
 
+    if let Some(group_id) = params.group_id {
+        qb.push(" AND c.logical_id = ");
+        qb.push_bind(group_id);
+    }
+
+    if let Some(network) = &params.deployed_on {
+        qb.push(" AND EXISTS (SELECT 1 FROM contracts g WHERE g.logical_id = c.logical_id AND g.deleted_at IS NULL AND g.network = ");
+        qb.push_bind(network);
+        qb.push(")");
+    }
+
+    if let Some(badge) = &params.badge {
+        crate::badge_handlers::push_badge_filter(&mut qb, badge);
+    }
+
+    if let Some(freshness) = params.oracle_freshness {
+        crate::oracle_handlers::push_freshness_filter(&mut qb, freshness);
+    }
+
+    crate::sdk_matrix_handlers::push_build_filter(
+        &mut qb,
+        params.sdk_version.as_deref(),
+        params.protocol_version,
+    );
+
     if let Some(tags) = &params.tags {
         if !tags.is_empty() {
             qb.push(" AND c.id IN (SELECT contract_id FROM contract_tags ct JOIN tags t ON t.id = ct.tag_id WHERE t.name IN (");
@@ -1401,6 +1555,10 @@ pub async fn list_contracts(
         shared::SortBy::VerifiedAt => qb.push("c.verified_at "),
         shared::SortBy::LastAccessedAt => qb.push("c.last_accessed_at "),
         shared::SortBy::Popularity | shared::SortBy::Interactions => qb.push("COUNT(ci.id) "),
+        shared::SortBy::Quality => qb.push("COALESCE(c.quality_score, -1) "),
+        shared::SortBy::Tvl => qb.push(
+            "COALESCE((SELECT dp.tvl_usd FROM defi_pools dp WHERE dp.contract_id = c.id), -1) ",
+        ),
         _ => qb.push("c.created_at "),
     };
     qb.push(direction);
@@ -1411,7 +1569,7 @@ pub async fn list_contracts(
     qb.push(" OFFSET ");
     qb.push_bind(offset);
 
-    let mut contracts: Vec<Contract> = match qb.build_query_as().fetch_all(&state.db).await {
+    let mut contracts: Vec<Contract> = match qb.build_query_as().fetch_all(&state.db_read).await {
         Ok(rows) => rows,
         Err(err) => return db_internal_error("list contracts", err).into_response(),
     };
@@ -1428,7 +1586,7 @@ pub async fn list_contracts(
             "#,
             &contract_ids
         )
-        .fetch_all(&state.db)
+        .fetch_all(&state.db_read)
         .await
         {
             Ok(rows) => rows,
@@ -1444,15 +1602,46 @@ pub async fn list_contracts(
             });
         }
 
+        let mut badges_map = match crate::badge_handlers::active_badges(&state.db_read, &contract_ids).await {
+            Ok(map) => map,
+            Err(err) => return db_internal_error("fetch badges", err).into_response(),
+        };
+
+        let publishers = if listing_includes(&params, "publisher") {
+            let publisher_ids: Vec<Uuid> = contracts.iter().map(|c| c.publisher_id).collect();
+            match crate::publisher_profile_handlers::profiles(&state.db_read, &publisher_ids).await {
+                Ok(map) => map,
+                Err(err) => return db_internal_error("fetch publisher profiles", err).into_response(),
+            }
+        } else {
+            HashMap::new()
+        };
+
+        let mut latest_versions = if listing_includes(&params, "latest_version") {
+            match latest_versions(&state.db_read, &contract_ids).await {
+                Ok(map) => map,
+                Err(err) => return db_internal_error("fetch latest versions", err).into_response(),
+            }
+        } else {
+            HashMap::new()
+        };
+
         for contract in &mut contracts {
+            contract.publisher = publishers.get(&contract.publisher_id).cloned();
+            contract.latest_version = latest_versions.remove(&contract.id);
             if let Some(tags) = tags_map.remove(&contract.id) {
                 contract.tags = tags;
             }
+            if let Some(badges) = badges_map.remove(&contract.id) {
+                contract.badges = badges;
+            }
         }
     }
 
     let mut count_qb: QueryBuilder<'_, sqlx::Postgres> =
-        QueryBuilder::new("SELECT COUNT(*) FROM contracts c WHERE c.visibility = 'public'");
+        QueryBuilder::new("SELECT COUNT(*) FROM contracts c WHERE c.deleted_at IS NULL AND c.tenant_id = ");
+    count_qb.push_bind(tenant.id);
+    count_qb.push(" AND (c.visibility = 'public'");
 
     if let Some(claims) = &claims {
         count_qb.push(" OR (c.visibility = 'private' AND c.organization_id IN (");
@@ -1461,6 +1650,7 @@ pub async fn list_contracts(
         count_qb.push_bind(&claims.sub);
         count_qb.push("))");
     }
+    count_qb.push(")");
     if params.verified_only.unwrap_or(false) {
         count_qb.push(" AND c.is_verified = true");
     }
@@ -1469,9 +1659,28 @@ pub async fn list_contracts(
         count_qb.push_bind(status);
     }
     if let Some(category) = &params.category {
-        count_qb.push(" AND c.category = ");
-        count_qb.push_bind(category);
+        crate::category_handlers::push_subtree_filter(&mut count_qb, std::slice::from_ref(category));
+    }
+    if let Some(group_id) = params.group_id {
+        count_qb.push(" AND c.logical_id = ");
+        count_qb.push_bind(group_id);
+    }
+    if let Some(network) = &params.deployed_on {
+        count_qb.push(" AND EXISTS (SELECT 1 FROM contracts g WHERE g.logical_id = c.logical_id AND g.deleted_at IS NULL AND g.network = ");
+        count_qb.push_bind(network);
+        count_qb.push(")");
+    }
+    if let Some(badge) = &params.badge {
+        crate::badge_handlers::push_badge_filter(&mut count_qb, badge);
     }
+    if let Some(freshness) = params.oracle_freshness {
+        crate::oracle_handlers::push_freshness_filter(&mut count_qb, freshness);
+    }
+    crate::sdk_matrix_handlers::push_build_filter(
+        &mut count_qb,
+        params.sdk_version.as_deref(),
+        params.protocol_version,
+    );
     if let Some(tags) = &params.tags {
         if !tags.is_empty() {
             count_qb.push(" AND c.id IN (SELECT contract_id FROM contract_tags ct JOIN tags t ON t.id = ct.tag_id WHERE t.name IN (");
@@ -1491,7 +1700,7 @@ pub async fn list_contracts(
         count_qb.push(")");
     }
 
-    let total: i64 = match count_qb.build_query_scalar().fetch_one(&state.db).await {
+    let total: i64 = match count_qb.build_query_scalar().fetch_one(&state.db_read).await {
         Ok(v) => v,
         Err(err) => return db_internal_error("count contracts", err).into_response(),
     };
@@ -1520,6 +1729,7 @@ pub async fn list_contracts(
             LEFT JOIN contract_interactions ci ON c.id = ci.contract_id
             LEFT JOIN contract_versions cv ON c.id = cv.contract_id
             LEFT JOIN reviews r ON c.id = r.contract_id AND r.is_flagged = FALSE
+            WHERE c.deleted_at IS NULL
             GROUP BY c.id
         ),\n",
     );
@@ -1635,18 +1845,18 @@ fn render_contract_export(
         LOG(1 + cs.user_interaction_count) as personal_boost
     FROM contracts c
     JOIN contract_stats cs ON c.id = cs.id
-    WHERE (c.visibility = 'public'",
+    WHERE c.deleted_at IS NULL AND (c.visibility = 'public'",
     );
 
     let mut count_query =
-        sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT COUNT(*) FROM contracts c WHERE (c.visibility = 'public'");
+        sqlx::QueryBuilder::<sqlx::Postgres>::new("SELECT COUNT(*) FROM contracts c WHERE c.deleted_at IS NULL AND (c.visibility = 'public'");
 
 fn apply_contract_export_filters<'a>(
     query: &mut QueryBuilder<'a, Postgres>,
     filters: &'a ContractSearchParams,
     claims: Option<&'a crate::auth::AuthClaims>,
 ) {
-    query.push(" FROM contracts c JOIN publishers p ON p.id = c.publisher_id WHERE (c.visibility = 'public'");
+    query.push(" FROM contracts c JOIN publishers p ON p.id = c.publisher_id WHERE c.deleted_at IS NULL AND (c.visibility = 'public'");
     if let Some(claims) = claims {
         query.push(" OR (c.visibility = 'private' AND c.organization_id IN (SELECT organization_id FROM organization_members om JOIN publishers p ON om.publisher_id = p.id WHERE p.stellar_address = ");
         query.push_bind(&claims.sub);
@@ -1682,8 +1892,7 @@ fn apply_contract_export_filters<'a>(
     }
 
     if let Some(category) = filters.category.as_ref() {
-        query.push(" AND c.category = ");
-        query.push_bind(category);
+        crate::category_handlers::push_subtree_filter(&mut query, std::slice::from_ref(category));
     }
 
     if let Some(categories) = filters
@@ -1691,14 +1900,23 @@ fn apply_contract_export_filters<'a>(
         .as_ref()
         .filter(|categories| !categories.is_empty())
     {
-        query.push(" AND c.category IN (");
-        let mut separated = query.separated(", ");
-        for category in categories {
-            separated.push_bind(category);
-        }
-        separated.push_unseparated(")");
+        crate::category_handlers::push_subtree_filter(&mut query, categories);
     }
 
+    if let Some(badge) = filters.badge.as_ref() {
+        crate::badge_handlers::push_badge_filter(query, badge);
+    }
+
+    if let Some(freshness) = filters.oracle_freshness {
+        crate::oracle_handlers::push_freshness_filter(query, freshness);
+    }
+
+    crate::sdk_matrix_handlers::push_build_filter(
+        query,
+        filters.sdk_version.as_deref(),
+        filters.protocol_version,
+    );
+
     if let Some(tags) = filters.tags.as_ref().filter(|tags| !tags.is_empty()) {
         query.push(" AND c.id IN (SELECT contract_id FROM contract_tags ct JOIN tags t ON t.id = ct.tag_id WHERE t.name IN (");
         let mut separated = query.separated(", ");
@@ -1830,6 +2048,12 @@ async fn fetch_contract_export_rows(
         shared::SortBy::Deployments => {
             query.push("c.deployment_count ");
         }
+        shared::SortBy::Quality => {
+            query.push("c.quality_score ");
+        }
+        shared::SortBy::Tvl => {
+            query.push("(SELECT dp.tvl_usd FROM defi_pools dp WHERE dp.contract_id = c.id) ");
+        }
         shared::SortBy::Relevance => {
             if let Some(query_text) = filters
                 .query
@@ -2097,11 +2321,11 @@ pub async fn download_contract_export(Path(job_id): Path<Uuid>) -> ApiResult<Res
     get,
     path = "/api/contracts/{id}",
     params(
-        ("id" = String, Path, description = "Contract UUID"),
+        ("id" = String, Path, description = "Contract UUID, on-chain contract ID or slug"),
         GetContractQuery
     ),
     responses(
-        (status = 200, description = "Contract details", body = ContractGetResponse),
+        (status = 200, description = "Contract details, with activity stats when ?include=stats", body = ContractGetResponse),
         (status = 404, description = "Contract not found"),
         (status = 400, description = "Invalid contract ID format")
     ),
@@ -2110,12 +2334,16 @@ pub async fn download_contract_export(Path(job_id): Path<Uuid>) -> ApiResult<Res
 pub async fn get_contract(
     State(state): State<AppState>,
     claims: Option<crate::auth::AuthClaims>,
+    tenant: crate::tenant::Tenant,
     Path(id): Path<String>,
     Query(query): Query<GetContractQuery>,
-) -> ApiResult<Json<ContractGetResponse>> {
+) -> ApiResult<impl IntoResponse> {
     let mut contract: Contract = if let Ok(contract_uuid) = Uuid::parse_str(&id) {
-        sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+        sqlx::query_as(
+            "SELECT * FROM contracts WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL",
+        )
             .bind(contract_uuid)
+            .bind(tenant.id)
             .fetch_one(&state.db)
             .await
             .map_err(|err| match err {
@@ -2125,21 +2353,47 @@ pub async fn get_contract(
                 ),
                 _ => db_internal_error("get contract by id", err),
             })?
+    } else if validate_contract_id(&id).is_ok() {
+        // Fetch by on-chain address; the same address may be registered on
+        // several networks, mainnet wins unless one is asked for
+        sqlx::query_as(
+            "SELECT * FROM contracts
+             WHERE contract_id = $1 AND ($2::network_type IS NULL OR network = $2)
+               AND tenant_id = $3 AND deleted_at IS NULL
+             ORDER BY (network = 'mainnet') DESC, created_at
+             LIMIT 1",
+        )
+        .bind(&id)
+        .bind(&query.network)
+        .bind(tenant.id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("get contract by address", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found at address: {}", id),
+            )
+        })?
     } else {
         // Fetch by slug
         let network = query.network.clone().unwrap_or(Network::Mainnet);
-        sqlx::query_as("SELECT * FROM contracts WHERE slug = $1 AND network = $2")
-            .bind(&id)
-            .bind(&network)
-            .fetch_one(&state.db)
-            .await
-            .map_err(|err| match err {
-                sqlx::Error::RowNotFound => ApiError::not_found(
-                    "ContractNotFound",
-                    format!("No contract found with slug: {} on network: {}", id, network),
-                ),
-                _ => db_internal_error("get contract by slug", err),
-            })?
+        sqlx::query_as(
+            "SELECT * FROM contracts
+             WHERE slug = $1 AND network = $2 AND tenant_id = $3 AND deleted_at IS NULL",
+        )
+        .bind(&id)
+        .bind(&network)
+        .bind(tenant.id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| match err {
+            sqlx::Error::RowNotFound => ApiError::not_found(
+                "ContractNotFound",
+                format!("No contract found with slug: {} on network: {}", id, network),
+            ),
+            _ => db_internal_error("get contract by slug", err),
+        })?
     };
 
     // Fetch tags
@@ -2159,6 +2413,17 @@ pub async fn get_contract(
         color: r.color,
     }).collect();
 
+    contract.badges = crate::badge_handlers::active_badges(&state.db, &[contract.id])
+        .await
+        .map_err(|err| db_internal_error("fetch badges", err))?
+        .remove(&contract.id)
+        .unwrap_or_default();
+
+    contract.publisher = crate::publisher_profile_handlers::profiles(&state.db, &[contract.publisher_id])
+        .await
+        .map_err(|err| db_internal_error("fetch publisher profile", err))?
+        .remove(&contract.publisher_id);
+
     // Visibility check
     if contract.visibility == shared::VisibilityType::Private {
         let is_member = if let Some(ref claims) = claims {
@@ -2222,11 +2487,72 @@ pub async fn get_contract(
     }
     track_contract_access(&state, contract.id).await;
 
-    Ok(Json(ContractGetResponse {
-        contract,
-        current_network,
-        network_config,
-    }))
+    let stats = if query.includes("stats") {
+        Some(fetch_contract_activity_stats(&state.db, &contract).await?)
+    } else {
+        None
+    };
+
+    let also_deployed_on = crate::contract_group_handlers::other_deployments(
+        &state.db,
+        contract.id,
+        contract.organization_id,
+    )
+    .await?;
+
+    let etag = concurrency::etag(contract.lock_version);
+    Ok((
+        [(header::ETAG, etag)],
+        Json(ContractGetResponse {
+            contract,
+            current_network,
+            network_config,
+            stats,
+            also_deployed_on,
+        }),
+    ))
+}
+
+/// Event volume from indexed events plus distinct callers from recorded
+/// interactions, for GET /contracts/:id?include=stats.
+async fn fetch_contract_activity_stats(
+    db: &sqlx::PgPool,
+    contract: &Contract,
+) -> ApiResult<shared::ContractActivityStats> {
+    sqlx::query_as(
+        "SELECT
+            COALESCE(e.events_24h, 0) AS events_24h,
+            COALESCE(e.events_7d, 0) AS events_7d,
+            e.last_activity_ledger,
+            e.last_activity_at,
+            COALESCE(i.unique_callers_24h, 0) AS unique_callers_24h,
+            COALESCE(i.unique_callers_7d, 0) AS unique_callers_7d
+         FROM (
+            SELECT
+                COUNT(*) FILTER (WHERE timestamp >= NOW() - INTERVAL '24 hours') AS events_24h,
+                COUNT(*) FILTER (WHERE timestamp >= NOW() - INTERVAL '7 days') AS events_7d,
+                MAX(ledger_sequence) AS last_activity_ledger,
+                MAX(timestamp) AS last_activity_at
+            FROM contract_events
+            WHERE contract_id = $1 AND network = $2
+         ) e,
+         (
+            SELECT
+                COUNT(DISTINCT user_address)
+                    FILTER (WHERE interaction_timestamp >= NOW() - INTERVAL '24 hours')
+                    AS unique_callers_24h,
+                COUNT(DISTINCT user_address) AS unique_callers_7d
+            FROM contract_interactions
+            WHERE contract_id = $3
+              AND interaction_timestamp >= NOW() - INTERVAL '7 days'
+         ) i",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .bind(contract.id)
+    .fetch_one(db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract activity stats", err))
 }
 
 /// Fetch multiple contracts in a single request, preserving request order.
@@ -2272,8 +2598,8 @@ pub async fn get_contracts_batch(
 
     let contracts: Vec<Contract> = sqlx::query_as(
         "SELECT * FROM contracts
-         WHERE id = ANY($1)
-            OR contract_id = ANY($2)",
+         WHERE (id = ANY($1) OR contract_id = ANY($2))
+           AND deleted_at IS NULL",
     )
     .bind(&parsed_uuids)
     .bind(&normalized_contract_ids)
@@ -2308,12 +2634,11 @@ pub async fn get_contracts_batch(
     get,
     path = "/api/contracts/{id}/versions",
     params(
-        ("id" = String, Path, description = "Contract UUID")
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID")
     ),
     responses(
         (status = 200, description = "List of contract versions", body = [ContractVersion]),
-        (status = 404, description = "Contract not found"),
-        (status = 400, description = "Invalid contract ID format")
+        (status = 404, description = "Contract not found")
     ),
     tag = "Versions"
 )]
@@ -2321,12 +2646,7 @@ pub async fn get_contract_versions(
     State(state): State<AppState>,
     Path(id): Path<String>,
 ) -> ApiResult<Json<Vec<ContractVersion>>> {
-    let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
-        ApiError::bad_request(
-            "InvalidContractId",
-            format!("Invalid contract ID format: {}", id),
-        )
-    })?;
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
 
     let versions: Vec<ContractVersion> = sqlx::query_as(
         "SELECT * FROM contract_versions WHERE contract_id = $1 ORDER BY created_at DESC",
@@ -2574,8 +2894,8 @@ pub async fn revert_contract_version(
 
     let version_row: ContractVersion = sqlx::query_as(
         "INSERT INTO contract_versions \
-            (contract_id, version, wasm_hash, source_url, commit_hash, release_notes, change_notes, is_revert, reverted_from) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7, TRUE, $8) \
+            (contract_id, version, wasm_hash, source_url, commit_hash, release_notes, change_notes, is_revert, reverted_from, docs_coverage, license, copyright) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, TRUE, $8, $9, $10, $11) \
          RETURNING *",
     )
     .bind(contract_uuid)
@@ -2586,6 +2906,9 @@ pub async fn revert_contract_version(
     .bind(Option::<String>::None) // release_notes left empty for reverts
     .bind(&change_notes)
     .bind(&target_version)
+    .bind(target.docs_coverage)
+    .bind(&target.license)
+    .bind(&target.copyright)
     .fetch_one(&mut *tx)
     .await
     .map_err(|err| db_internal_error("insert revert version", err))?;
@@ -2665,8 +2988,11 @@ pub struct ContractSourceDiffResponse {
     request_body = UploadContractSourceRequest,
     responses(
         (status = 201, description = "Source uploaded", body = ContractSourceResponse),
-        (status = 400, description = "Invalid input"),
-        (status = 404, description = "Contract version not found")
+        (status = 400, description = "Invalid input, or content not matching source_format"),
+        (status = 404, description = "Contract version not found"),
+        (status = 413, description = "Body over the upload size limit"),
+        (status = 422, description = "Tarball with unsafe entries; details list each one"),
+        (status = 507, description = "Publisher's storage quota exceeded")
     ),
     tag = "Source"
 )]
@@ -2713,6 +3039,16 @@ pub async fn upload_contract_source(
         }
     };
 
+    crate::validation::source_content::validate_source_content(&source_format, &source_bytes)
+        .await?;
+
+    crate::publisher_usage_handlers::ensure_storage_quota(
+        &state,
+        contract_uuid,
+        source_bytes.len() as i64,
+    )
+    .await?;
+
     let (backend, storage_key, source_hash) = state
         .source_storage
         .store_source(&contract_id, &version, source_format.clone(), &source_bytes)
@@ -3072,18 +3408,29 @@ pub async fn get_contract_changelog(
     ),
     request_body = CreateContractVersionRequest,
     responses(
-        (status = 201, description = "Version created successfully", body = ContractVersion),
-        (status = 400, description = "Invalid input or version conflict"),
+        (status = 201, description = "Version created successfully; `linked_from` is set when a registered build with the same WASM hash lent it its ABI and analysis", body = ContractVersion),
+        (status = 400, description = "Invalid input or version conflict, or abi omitted with nothing to inherit"),
+        (status = 403, description = "Caller may not publish versions of the contract"),
         (status = 404, description = "Contract not found")
     ),
+    security(("bearer_auth" = [])),
     tag = "Versions"
 )]
 pub async fn create_contract_version(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     ValidatedJson(req): ValidatedJson<CreateContractVersionRequest>,
 ) -> ApiResult<Json<ContractVersion>> {
     let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "publish its versions",
+    )
+    .await?;
     if !req.contract_id.trim().is_empty() && req.contract_id != contract_id {
         return Err(ApiError::bad_request(
             "ContractMismatch",
@@ -3183,6 +3530,42 @@ pub async fn create_contract_version(
             }
         };
 
+    // A build the registry already knows with this hash lends the upload its
+    // ABI and analysis instead of them being extracted again.
+    let (tenant_id, publisher_id): (Uuid, Uuid) =
+        sqlx::query_as("SELECT tenant_id, publisher_id FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract owner", err))?;
+    if let Some(publisher_key) = &version_publisher_key {
+        crate::publisher_key_handlers::require_usable_key(
+            &state.db,
+            publisher_id,
+            publisher_key,
+            chrono::Utc::now(),
+        )
+        .await?;
+    }
+    let wasm_source =
+        crate::wasm_links::find_source(&state.db, &req.wasm_hash, tenant_id, publisher_id)
+            .await
+            .map_err(|err| db_internal_error("find build with the same wasm hash", err))?;
+    let inherits_abi = req.abi.is_null();
+    let abi = if inherits_abi {
+        wasm_source
+            .as_ref()
+            .and_then(|source| source.abi.clone())
+            .ok_or_else(|| {
+                ApiError::bad_request(
+                    "AbiRequired",
+                    "abi is required: no registered build with this WASM hash has one",
+                )
+            })?
+    } else {
+        req.abi.clone()
+    };
+
     let existing_versions: Vec<String> =
         sqlx::query_scalar("SELECT version FROM contract_versions WHERE contract_id = $1")
             .bind(contract_uuid)
@@ -3192,6 +3575,7 @@ pub async fn create_contract_version(
 
     // prev_snapshot is populated when there is a prior version; used for delta storage.
     let mut prev_snapshot: Option<crate::patch_handlers::VersionSnapshot> = None;
+    let mut breaking = false;
 
     if !existing_versions.is_empty() {
         let mut parsed: Vec<SemVer> = Vec::with_capacity(existing_versions.len());
@@ -3216,7 +3600,7 @@ pub async fn create_contract_version(
                 })?;
 
             let new_spec =
-                crate::type_safety::parser::parse_json_spec(&req.abi.to_string(), &contract_id)
+                crate::type_safety::parser::parse_json_spec(&abi.to_string(), &contract_id)
                     .map_err(|e| {
                         ApiError::bad_request(
                             "InvalidABI",
@@ -3225,7 +3609,8 @@ pub async fn create_contract_version(
                     })?;
 
             let changes = diff_abi(&old_spec, &new_spec);
-            if has_breaking_changes(&changes) && new_version.major == old_version.major {
+            breaking = has_breaking_changes(&changes);
+            if breaking && new_version.major == old_version.major {
                 return Err(ApiError::unprocessable(
                     "BreakingChangeWithoutMajorBump",
                     format!(
@@ -3272,16 +3657,29 @@ pub async fn create_contract_version(
         }
     }
 
+    // Measured here, while the ABI is extracted, so the quality score and
+    // `info` read it from the version instead of re-parsing every ABI. An
+    // inherited ABI comes with its measurement.
+    let inherited_docs_coverage = wasm_source
+        .as_ref()
+        .filter(|_| inherits_abi)
+        .and_then(|source| source.docs_coverage);
+    let docs_coverage = inherited_docs_coverage.or_else(|| {
+        contract_abi::json_docs_coverage(&abi.to_string())
+            .ok()
+            .and_then(|coverage| coverage.ratio())
+    });
+
     let mut tx = state
         .db
         .begin()
         .await
         .map_err(|err| db_internal_error("begin transaction", err))?;
 
-    let version_row: ContractVersion = sqlx::query_as(
+    let mut version_row: ContractVersion = sqlx::query_as(
         "INSERT INTO contract_versions \
-            (contract_id, version, wasm_hash, source_url, commit_hash, release_notes, change_notes, signature, publisher_key, signature_algorithm) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) \
+            (contract_id, version, wasm_hash, source_url, commit_hash, release_notes, change_notes, signature, publisher_key, signature_algorithm, docs_coverage, license, copyright) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
          RETURNING *",
     )
     .bind(contract_uuid)
@@ -3294,6 +3692,9 @@ pub async fn create_contract_version(
     .bind(&version_signature)
     .bind(&version_publisher_key)
     .bind(&version_algorithm)
+    .bind(docs_coverage)
+    .bind(&req.license)
+    .bind(&req.copyright)
     .fetch_one(&mut *tx)
     .await
     .map_err(|err| match err {
@@ -3324,20 +3725,76 @@ pub async fn create_contract_version(
     )
     .bind(contract_uuid)
     .bind(&req.version)
-    .bind(&req.abi)
+    .bind(&abi)
     .execute(&mut *tx)
     .await
     .map_err(|err| db_internal_error("insert contract abi", err))?;
 
+    if let Some(source) = &wasm_source {
+        let mut inherited = Vec::new();
+        if inherits_abi {
+            inherited.push(crate::wasm_links::INHERITED_ABI);
+        }
+        if inherited_docs_coverage.is_some() {
+            inherited.push(crate::wasm_links::INHERITED_DOCS_COVERAGE);
+        }
+        if let Some(source_version_id) = source.version_id {
+            let copied = crate::wasm_links::copy_benchmarks(
+                &mut *tx,
+                source_version_id,
+                contract_uuid,
+                version_row.id,
+            )
+            .await
+            .map_err(|err| db_internal_error("copy linked benchmarks", err))?;
+            if copied > 0 {
+                inherited.push(crate::wasm_links::INHERITED_BENCHMARKS);
+            }
+        }
+        let link = source.link(inherited);
+        crate::wasm_links::record(
+            &mut *tx,
+            contract_uuid,
+            Some(&req.version),
+            &req.wasm_hash,
+            source,
+            &link,
+        )
+        .await
+        .map_err(|err| db_internal_error("record wasm link", err))?;
+        tracing::info!(
+            contract = %contract_uuid,
+            version = %req.version,
+            source = %source.contract_id,
+            inherited = ?link.inherited,
+            "linked version to a build with the same wasm hash"
+        );
+        version_row.linked_from = Some(link);
+    }
+
+    // A staged version becomes the default only when its rollout completes.
     sqlx::query(
-        "UPDATE contracts SET deployment_count = deployment_count + 1, current_version = $2 WHERE id = $1",
+        "UPDATE contracts SET deployment_count = deployment_count + 1, \
+         current_version = CASE WHEN $3 THEN current_version ELSE $2 END WHERE id = $1",
     )
     .bind(contract_uuid)
     .bind(&req.version)
+    .bind(req.rollout_percentage.is_some())
     .execute(&mut *tx)
     .await
     .map_err(|err| db_internal_error("update current_version", err))?;
 
+    if let Some(percentage) = req.rollout_percentage {
+        crate::version_rollout_handlers::start_rollout(
+            &mut tx,
+            contract_uuid,
+            &req.version,
+            percentage,
+            None,
+        )
+        .await?;
+    }
+
     tx.commit()
         .await
         .map_err(|err| db_internal_error("commit contract version", err))?;
@@ -3357,7 +3814,7 @@ pub async fn create_contract_version(
         commit_hash: req.commit_hash.clone(),
         release_notes: req.release_notes.clone(),
         state_schema: None,
-        abi: req.abi.clone(),
+        abi: abi.clone(),
     };
     if let Err(e) = crate::patch_handlers::store_patch(
         &state.db,
@@ -3374,8 +3831,34 @@ pub async fn create_contract_version(
         );
     }
 
+    if breaking {
+        crate::consumers::notify(
+            &state.db,
+            crate::consumers::Impact::BreakingRelease {
+                contract_id: contract_uuid,
+                version: &req.version,
+            },
+        )
+        .await;
+    }
+
+    // A new version can change ABI stability, docs coverage and audit presence.
+    crate::quality::queue(&state.db, contract_uuid).await;
+
+    // Match the new ABI against the interface catalog.
+    if let Err(e) =
+        crate::interfaces::match_contract_abi(&state.db, contract_uuid, &req.version, &abi)
+            .await
+    {
+        tracing::error!(
+            "Failed to match interfaces for version {}: {}",
+            req.version,
+            e
+        );
+    }
+
     // Post-commit dependency analysis
-    let detected_deps = dependency::detect_dependencies_from_abi(&req.abi);
+    let detected_deps = dependency::detect_dependencies_from_abi(&abi);
     if !detected_deps.is_empty() {
         if let Err(e) =
             dependency::save_dependencies(&state.db, contract_uuid, &detected_deps).await
@@ -3386,11 +3869,7 @@ pub async fn create_contract_version(
                 e
             );
         }
-        // Invalidate global graph cache
-        state
-            .cache
-            .invalidate("system", "global:dependency_graph")
-            .await;
+        state.cache.invalidate_dependency_graphs();
     }
 
     let _ = analytics::record_event(
@@ -3516,6 +3995,7 @@ async fn generate_unique_slug(
     request_body = PublishRequest,
     responses(
         (status = 201, description = "Contract published successfully", body = Contract),
+        (status = 202, description = "The network requires multisig approval; a deploy proposal is open"),
         (status = 400, description = "Invalid input or contract ID"),
         (status = 409, description = "Contract already registered")
     ),
@@ -3524,20 +4004,54 @@ async fn generate_unique_slug(
 pub async fn publish_contract(
     State(state): State<AppState>,
     headers: HeaderMap,
+    tenant: crate::tenant::Tenant,
     ValidatedJson(req): ValidatedJson<PublishRequest>,
-) -> ApiResult<Json<Contract>> {
+) -> ApiResult<Response> {
+    crate::tenant::ensure_quota(&state.db, &tenant, crate::tenant::QuotaResource::Contracts)
+        .await?;
+
+    // Networks an organization gates behind multisig only take the
+    // deployment once an executed proposal covers it.
+    if let Some(gate) = org_network_policy_handlers::gate_for_publisher(
+        &state,
+        &req.publisher_address,
+        &req.network,
+    )
+    .await?
+    {
+        let deployment = org_network_policy_handlers::GatedDeployment {
+            contract_name: &req.name,
+            contract_id: &req.contract_id,
+            wasm_hash: &req.wasm_hash,
+            network: &req.network,
+            proposer: Some(req.publisher_address.as_str()),
+        };
+        if let Some(proposal) =
+            org_network_policy_handlers::require_approval(&state, tenant.id, &gate, deployment)
+                .await?
+        {
+            return Ok(org_network_policy_handlers::approval_required(gate, proposal));
+        }
+    }
+
     let mut tx = state.db.begin().await.map_err(|err| db_internal_error("begin publish tx", err))?;
 
     let publisher: Publisher = sqlx::query_as(
-        "INSERT INTO publishers (stellar_address) VALUES ($1)
+        "INSERT INTO publishers (stellar_address, tenant_id) VALUES ($1, $2)
          ON CONFLICT (stellar_address) DO UPDATE SET stellar_address = EXCLUDED.stellar_address
          RETURNING *",
     )
     .bind(&req.publisher_address)
+    .bind(tenant.id)
     .fetch_one(&mut *tx)
     .await
     .map_err(|err| db_internal_error("upsert publisher", err))?;
 
+    let wasm_source =
+        crate::wasm_links::find_source(&state.db, &req.wasm_hash, tenant.id, publisher.id)
+            .await
+            .map_err(|err| db_internal_error("find build with the same wasm hash", err))?;
+
     let wasm_hash = req.wasm_hash.clone();
     let network_key = req.network.to_string();
     let mut config_map = serde_json::Map::new();
@@ -3553,10 +4067,14 @@ pub async fn publish_contract(
     let network_configs = serde_json::Value::Object(config_map);
 
     let slug = generate_unique_slug(&state.db, &req.name, &req.network, req.slug.clone()).await?;
+    let category = match req.category.as_deref() {
+        Some(raw) => Some(crate::category_handlers::resolve_category(&state, raw).await?),
+        None => None,
+    };
 
     let contract: Contract = sqlx::query_as(
-        "INSERT INTO contracts (contract_id, wasm_hash, name, slug, description, publisher_id, network, category, tags, logical_id, network_configs)
-         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        "INSERT INTO contracts (contract_id, wasm_hash, name, slug, description, publisher_id, network, category, tags, logical_id, network_configs, tenant_id)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
          RETURNING *"
     )
     .bind(&req.contract_id)
@@ -3566,10 +4084,11 @@ pub async fn publish_contract(
     .bind(&req.description)
     .bind(publisher.id)
     .bind(&req.network)
-    .bind(&req.category)
+    .bind(&category)
     .bind(&req.tags)
     .bind(Option::<Uuid>::None as Option<Uuid>)
     .bind(&network_configs)
+    .bind(tenant.id)
     .fetch_one(&state.db)
     .await
     .map_err(|err| {
@@ -3603,11 +4122,42 @@ pub async fn publish_contract(
         .execute(&state.db)
         .await;
 
-    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+    // A registered build with the same hash lends the contract its ABI.
+    let mut linked_from = None;
+    if let Some(source) = &wasm_source {
+        let mut inherited = Vec::new();
+        if source.abi.is_some() {
+            inherited.push(crate::wasm_links::INHERITED_ABI);
+        }
+        let link = source.link(inherited);
+        let linked: sqlx::Result<()> = async {
+            if let Some(abi) = &source.abi {
+                sqlx::query("UPDATE contracts SET abi = $2 WHERE id = $1")
+                    .bind(contract.id)
+                    .bind(abi)
+                    .execute(&state.db)
+                    .await?;
+            }
+            crate::wasm_links::record(&state.db, contract.id, None, &wasm_hash, source, &link)
+                .await
+        }
+        .await;
+        match linked {
+            Ok(()) => linked_from = Some(link),
+            Err(err) => tracing::error!(
+                contract = %contract.id,
+                error = %err,
+                "failed to link contract to a build with the same wasm hash"
+            ),
+        }
+    }
+
+    let mut contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
         .bind(contract.id)
         .fetch_one(&state.db)
         .await
         .map_err(|err| db_internal_error("fetch contract after insert", err))?;
+    contract.linked_from = linked_from;
 
     // Save dependencies if provided
     if !req.dependencies.is_empty() {
@@ -3620,12 +4170,9 @@ pub async fn publish_contract(
                 e
             );
         }
-        // Invalidate global graph cache
-        state
-            .cache
-            .invalidate("system", "global:dependency_graph")
-            .await;
     }
+    // The new contract is a node of the graph even without dependencies
+    state.cache.invalidate_dependency_graphs();
 
     let creation_changes = json!({
         "contract_id": { "before": Value::Null, "after": contract.contract_id },
@@ -3695,7 +4242,9 @@ pub async fn publish_contract(
         );
     }
 
-    Ok(Json(contract))
+    anchor::enqueue_if_enabled(&state.db, contract.id).await;
+
+    Ok(Json(contract).into_response())
 }
 
 #[utoipa::path(
@@ -3710,11 +4259,16 @@ pub async fn publish_contract(
 )]
 pub async fn create_publisher(
     State(state): State<AppState>,
+    tenant: crate::tenant::Tenant,
     ValidatedJson(publisher): ValidatedJson<Publisher>,
 ) -> ApiResult<Json<Publisher>> {
+    crate::tenant::ensure_quota(&state.db, &tenant, crate::tenant::QuotaResource::Publishers)
+        .await?;
+
     let created: Publisher = sqlx::query_as(
-        "INSERT INTO publishers (stellar_address, username, email, github_url, website)
-         VALUES ($1, $2, $3, $4, $5)
+        "INSERT INTO publishers (stellar_address, username, email, github_url, website, tenant_id,
+                                 display_name, pronouns, avatar_url)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
          RETURNING *",
     )
     .bind(&publisher.stellar_address)
@@ -3722,6 +4276,10 @@ pub async fn create_publisher(
     .bind(&publisher.email)
     .bind(&publisher.github_url)
     .bind(&publisher.website)
+    .bind(tenant.id)
+    .bind(&publisher.display_name)
+    .bind(&publisher.pronouns)
+    .bind(&publisher.avatar_url)
     .fetch_one(&state.db)
     .await
     .map_err(|err| db_internal_error("create publisher", err))?;
@@ -3754,6 +4312,7 @@ pub async fn create_publisher(
 )]
 pub async fn get_publisher(
     State(state): State<AppState>,
+    tenant: crate::tenant::Tenant,
     Path(id): Path<String>,
 ) -> ApiResult<Json<Publisher>> {
     let publisher_uuid = Uuid::parse_str(&id).map_err(|_| {
@@ -3763,17 +4322,21 @@ pub async fn get_publisher(
         )
     })?;
 
-    let publisher: Publisher = sqlx::query_as("SELECT * FROM publishers WHERE id = $1")
-        .bind(publisher_uuid)
-        .fetch_one(&state.db)
-        .await
-        .map_err(|err| match err {
-            sqlx::Error::RowNotFound => ApiError::not_found(
-                "PublisherNotFound",
-                format!("No publisher found with ID: {}", id),
-            ),
-            _ => db_internal_error("get publisher by id", err),
-        })?;
+    let publisher: Publisher =
+        sqlx::query_as(
+            "SELECT * FROM publishers WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL",
+        )
+            .bind(publisher_uuid)
+            .bind(tenant.id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| match err {
+                sqlx::Error::RowNotFound => ApiError::not_found(
+                    "PublisherNotFound",
+                    format!("No publisher found with ID: {}", id),
+                ),
+                _ => db_internal_error("get publisher by id", err),
+            })?;
 
     Ok(Json(publisher))
 }
@@ -3807,7 +4370,9 @@ pub async fn get_publisher_contracts(
     let offset = query.offset.max(0);
 
     // Get total count
-    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM contracts WHERE publisher_id = $1")
+    let total: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM contracts WHERE publisher_id = $1 AND deleted_at IS NULL",
+    )
         .bind(publisher_uuid)
         .fetch_one(&state.db)
         .await
@@ -3815,7 +4380,8 @@ pub async fn get_publisher_contracts(
 
     // Fetch paginated results
     let contracts: Vec<Contract> = sqlx::query_as(
-        "SELECT * FROM contracts WHERE publisher_id = $1 ORDER BY created_at DESC LIMIT $2 OFFSET $3",
+        "SELECT * FROM contracts WHERE publisher_id = $1 AND deleted_at IS NULL
+         ORDER BY created_at DESC LIMIT $2 OFFSET $3",
     )
     .bind(publisher_uuid)
     .bind(limit)
@@ -4033,52 +4599,136 @@ pub async fn get_contract_dependents(
     Ok(Json(json!({ "dependents": dependents })))
 }
 
+const GRAPH_DEFAULT_DEPTH: u32 = 2;
+const GRAPH_MAX_DEPTH: u32 = 10;
+const GRAPH_DEFAULT_LIMIT: i64 = 1000;
+const GRAPH_MAX_LIMIT: i64 = 5000;
+
+#[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
+pub struct ContractGraphParams {
+    pub network: Option<Network>,
+    /// Only the neighbourhood of this contract (UUID or on-chain contract_id)
+    pub root: Option<String>,
+    /// Hops from `root`, following dependencies both ways (default 2, max 10)
+    pub depth: Option<u32>,
+    /// Only contracts in this category; the root is always included
+    pub category: Option<String>,
+    /// Page of nodes, from 1
+    pub page: Option<i64>,
+    /// Nodes per page (default 1000, max 5000)
+    pub limit: Option<i64>,
+}
+
 #[utoipa::path(
     get,
     path = "/api/contracts/graph",
+    params(ContractGraphParams),
     responses(
-        (status = 200, description = "Full contract dependency graph", body = GraphResponse)
+        (status = 200, description = "A page of the contract dependency graph", body = GraphPageResponse),
+        (status = 400, description = "Invalid depth, page or limit"),
+        (status = 404, description = "Root contract not in the graph")
     ),
     tag = "Graphs"
 )]
 pub async fn get_contract_graph(
     State(state): State<AppState>,
-    Query(query): Query<GetContractQuery>,
-) -> ApiResult<Json<shared::GraphResponse>> {
-    // Try cache first
-    let cache_key = format!(
-        "global:dependency_graph:{}",
-        query
-            .network
-            .as_ref()
-            .map(|network| network.to_string())
-            .unwrap_or_else(|| "all".to_string())
-    );
-    if let (Some(cached), true) = state.cache.get("system", &cache_key).await {
-        if let Ok(graph) = serde_json::from_str(&cached) {
-            return Ok(Json(graph));
-        }
+    Query(params): Query<ContractGraphParams>,
+) -> ApiResult<Json<GraphPageResponse>> {
+    if params.depth.is_some() && params.root.is_none() {
+        return Err(ApiError::bad_request(
+            "InvalidGraphQuery",
+            "depth requires a root",
+        ));
+    }
+    let depth = params.depth.unwrap_or(GRAPH_DEFAULT_DEPTH);
+    if depth > GRAPH_MAX_DEPTH {
+        return Err(ApiError::bad_request(
+            "InvalidGraphQuery",
+            format!("depth must be at most {}", GRAPH_MAX_DEPTH),
+        ));
+    }
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(GRAPH_DEFAULT_LIMIT);
+    if page < 1 || !(1..=GRAPH_MAX_LIMIT).contains(&limit) {
+        return Err(ApiError::bad_request(
+            "InvalidGraphQuery",
+            format!(
+                "page must be at least 1 and limit between 1 and {}",
+                GRAPH_MAX_LIMIT
+            ),
+        ));
     }
 
-    let graph = dependency::build_dependency_graph(&state.db, query.network)
+    // The whole graph is materialised once per network filter; roots,
+    // categories and pages are all cut from the cached copy.
+    let cache_key = params
+        .network
+        .as_ref()
+        .map(|network| network.to_string())
+        .unwrap_or_else(|| "all".to_string());
+    let graph = state
+        .cache
+        .dependency_graph(
+            &cache_key,
+            dependency::build_dependency_graph(&state.db_read, params.network.clone()),
+        )
         .await
         .map_err(|e| ApiError::internal(format!("Failed to build graph: {}", e)))?;
 
-    // Invalidate/Refresh cache
-    if let Ok(serialized) = serde_json::to_string(&graph) {
-        state
-            .cache
-            .put(
-                "system",
-                &cache_key,
-                serialized,
-                Some(Duration::from_secs(300)),
-            )
-            .await;
-    }
+    // An address registered on several networks roots each of them.
+    let roots: Vec<Uuid> = match params.root.as_deref() {
+        None => Vec::new(),
+        Some(root) => {
+            let root_uuid = Uuid::parse_str(root).ok();
+            let roots: Vec<Uuid> = graph
+                .nodes
+                .iter()
+                .filter(|node| Some(node.id) == root_uuid || node.contract_id == root)
+                .map(|node| node.id)
+                .collect();
+            if roots.is_empty() {
+                return Err(ApiError::not_found(
+                    "ContractNotFound",
+                    format!("No contract found with ID: {}", root),
+                ));
+            }
+            roots
+        }
+    };
+    let filter = dependency::GraphFilter {
+        roots,
+        depth,
+        category: params
+            .category
+            .filter(|category| !category.trim().is_empty()),
+    };
+    let (nodes, edges) = dependency::filter_graph(&graph, &filter);
 
+    let total_nodes = nodes.len() as i64;
+    let offset = ((page - 1) * limit).min(total_nodes) as usize;
+    let nodes: Vec<shared::GraphNode> = nodes
+        .into_iter()
+        .skip(offset)
+        .take(limit as usize)
+        .cloned()
+        .collect();
+    // Each edge is listed with its source node, so walking every page yields
+    // every edge exactly once.
+    let on_page: HashSet<Uuid> = nodes.iter().map(|node| node.id).collect();
+    let edges = edges
+        .into_iter()
+        .filter(|edge| on_page.contains(&edge.source))
+        .cloned()
+        .collect();
 
-    Ok(Json(graph))
+    Ok(Json(GraphPageResponse {
+        has_more: offset as i64 + (nodes.len() as i64) < total_nodes,
+        nodes,
+        edges,
+        total_nodes,
+        page,
+        limit,
+    }))
 }
 
 #[derive(Debug, serde::Deserialize, utoipa::IntoParams)]
@@ -4108,7 +4758,7 @@ pub async fn get_contract_local_graph(
 
     let depth = params.depth.unwrap_or(1).clamp(1, 3);
 
-    let graph = dependency::build_local_graph(&state.db, contract_uuid, depth)
+    let graph = dependency::build_local_graph(&state.db_read, contract_uuid, depth)
         .await
         .map_err(|e| ApiError::internal(format!("Failed to build local graph: {}", e)))?;
 
@@ -4150,7 +4800,7 @@ pub async fn get_impact_analysis(
 
     // Fetch details for affected contracts
     let affected_contracts: Vec<shared::Contract> = if !affected_ids.is_empty() {
-        sqlx::query_as("SELECT * FROM contracts WHERE id = ANY($1)")
+        sqlx::query_as("SELECT * FROM contracts WHERE id = ANY($1) AND deleted_at IS NULL")
             .bind(&affected_ids)
             .fetch_all(&state.db)
             .await
@@ -4217,6 +4867,7 @@ pub async fn get_trending_contracts(
             LEFT JOIN contract_interaction_daily_aggregates a
               ON a.contract_id = c.id
              AND a.day >= CURRENT_DATE - make_interval(days => $1)
+            WHERE c.deleted_at IS NULL
             GROUP BY c.id, c.contract_id, c.name, c.network
         )
         SELECT
@@ -4297,7 +4948,8 @@ pub async fn verify_contract(
     ValidatedJson(req): ValidatedJson<VerifyRequest>,
 ) -> ApiResult<Json<Value>> {
     let contract: Contract = sqlx::query_as(
-        "SELECT * FROM contracts WHERE contract_id = $1 ORDER BY created_at DESC LIMIT 1",
+        "SELECT * FROM contracts WHERE contract_id = $1 AND deleted_at IS NULL
+         ORDER BY created_at DESC LIMIT 1",
     )
     .bind(&req.contract_id)
     .fetch_one(&state.db)
@@ -4318,26 +4970,52 @@ pub async fn verify_contract(
     .await
     .map_err(|err| db_internal_error("fetch previous verification status", err))?;
 
+    let toolchain = match req.toolchain.as_deref() {
+        Some(name) => Some(crate::toolchain_handlers::resolve_toolchain(&state, name).await?),
+        None => None,
+    };
+    let compiler_version = match &toolchain {
+        Some((_, toolchain)) => toolchain.soroban_sdk_version.clone(),
+        None => req.compiler_version.clone(),
+    };
+
     let verification_id: Uuid = sqlx::query_scalar(
-        "INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version, verified_at, error_message)
-         VALUES ($1, 'pending', $2, $3, $4, NULL, NULL)
+        "INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version, verified_at, error_message, toolchain_id)
+         VALUES ($1, 'pending', $2, $3, $4, NULL, NULL, $5)
          RETURNING id",
     )
     .bind(contract.id)
     .bind(&req.source_code)
     .bind(&req.build_params)
-    .bind(&req.compiler_version)
+    .bind(&compiler_version)
+    .bind(toolchain.as_ref().map(|(id, _)| *id))
     .fetch_one(&state.db)
     .await
     .map_err(|err| db_internal_error("insert verification record", err))?;
 
-    let verification_result = verifier::verify_contract(
+    let verification_result = crate::verification_cache::verify_source(
+        &state.db,
+        Some(verification_id),
         &req.source_code,
         &contract.wasm_hash,
-        Some(&req.compiler_version),
+        Some(&compiler_version),
         Some(&req.build_params),
+        toolchain.as_ref().map(|(_, toolchain)| toolchain),
     )
     .await;
+    let build_cached = matches!(&verification_result, Ok(source) if source.cache_hit);
+    if let Ok(source) = &verification_result {
+        sqlx::query(
+            "UPDATE verifications SET source_digest = $2, reused_build_from = $3 WHERE id = $1",
+        )
+        .bind(verification_id)
+        .bind(&source.source_digest)
+        .bind(source.reused_build_from)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("record verification build source", err))?;
+    }
+    let verification_result = verification_result.map(|source| source.result);
     let onchain_verifier = OnChainVerifier::new();
     let abi_json = resolve_abi(&state, &contract.contract_id, false).await.ok();
     let onchain_result = onchain_verifier
@@ -4356,30 +5034,44 @@ pub async fn verify_contract(
         {
             sqlx::query(
                 "UPDATE verifications
-                 SET status = 'verified', verified_at = NOW(), error_message = NULL
+                 SET status = 'verified', verified_at = NOW(), error_message = NULL,
+                     completed_at = NOW(), toolchain_digest = $2
                  WHERE id = $1",
             )
             .bind(verification_id)
+            .bind(&result.toolchain_digest)
             .execute(&state.db)
             .await
             .map_err(|err| db_internal_error("mark verification as verified", err))?;
 
             // Update contract metadata (Issue #401)
             sqlx::query(
-                "UPDATE contracts SET is_verified = true, verified_at = NOW(), updated_at = NOW() WHERE id = $1",
+                "UPDATE contracts SET is_verified = true, verified_at = NOW(), verification_status = 'verified', updated_at = NOW() WHERE id = $1",
             )
             .bind(contract.id)
             .execute(&state.db)
             .await
             .map_err(|err| db_internal_error("mark contract verified", err))?;
 
+            crate::source_bundle_handlers::archive_verified_source(
+                &state,
+                &contract,
+                verification_id,
+                &req.source_code,
+                Some(&compiler_version),
+                Some(&req.build_params),
+                toolchain.as_ref().map(|(_, toolchain)| toolchain),
+            )
+            .await;
+
             let verification_changes = json!({
                 "verification_id": { "before": Value::Null, "after": verification_id },
                 "status": { "before": Value::Null, "after": "verified" },
-                "compiler_version": { "before": Value::Null, "after": req.compiler_version },
+                "compiler_version": { "before": Value::Null, "after": compiler_version },
                 "verified_at": { "before": Value::Null, "after": chrono::Utc::now() },
                 "compiled_wasm_hash": { "before": Value::Null, "after": result.compiled_wasm_hash },
-                "deployed_wasm_hash": { "before": Value::Null, "after": result.deployed_wasm_hash }
+                "deployed_wasm_hash": { "before": Value::Null, "after": result.deployed_wasm_hash },
+                "toolchain_digest": { "before": Value::Null, "after": result.toolchain_digest }
             });
 
             write_contract_audit_log(
@@ -4456,6 +5148,8 @@ pub async fn verify_contract(
                 "contract_id": contract.id,
                 "compiled_wasm_hash": result.compiled_wasm_hash,
                 "deployed_wasm_hash": result.deployed_wasm_hash,
+                "toolchain_digest": result.toolchain_digest,
+                "build_cached": build_cached,
                 "on_chain": onchain
             })))
         }
@@ -4482,11 +5176,13 @@ pub async fn verify_contract(
 
             sqlx::query(
                 "UPDATE verifications
-                 SET status = 'failed', verified_at = NULL, error_message = $2
+                 SET status = 'failed', verified_at = NULL, error_message = $2,
+                     completed_at = NOW(), toolchain_digest = $3
                  WHERE id = $1",
             )
             .bind(verification_id)
             .bind(&failure_message)
+            .bind(&result.toolchain_digest)
             .execute(&state.db)
             .await
             .map_err(|err| db_internal_error("mark verification as failed", err))?;
@@ -4494,7 +5190,7 @@ pub async fn verify_contract(
             let verification_changes = json!({
                 "verification_id": { "before": Value::Null, "after": verification_id },
                 "status": { "before": Value::Null, "after": "failed" },
-                "compiler_version": { "before": Value::Null, "after": req.compiler_version },
+                "compiler_version": { "before": Value::Null, "after": compiler_version },
                 "error_message": { "before": Value::Null, "after": failure_message },
                 "compiled_wasm_hash": { "before": Value::Null, "after": result.compiled_wasm_hash },
                 "deployed_wasm_hash": { "before": Value::Null, "after": result.deployed_wasm_hash }
@@ -4537,7 +5233,8 @@ pub async fn verify_contract(
 
             sqlx::query(
                 "UPDATE verifications
-                 SET status = 'failed', verified_at = NULL, error_message = $2
+                 SET status = 'failed', verified_at = NULL, error_message = $2,
+                     completed_at = NOW()
                  WHERE id = $1",
             )
             .bind(verification_id)
@@ -4549,7 +5246,7 @@ pub async fn verify_contract(
             let verification_changes = json!({
                 "verification_id": { "before": Value::Null, "after": verification_id },
                 "status": { "before": Value::Null, "after": "failed" },
-                "compiler_version": { "before": Value::Null, "after": req.compiler_version },
+                "compiler_version": { "before": Value::Null, "after": compiler_version },
                 "error_message": { "before": Value::Null, "after": failure_message }
             });
             write_contract_audit_log(
@@ -4600,16 +5297,20 @@ pub async fn verify_contract(
     responses(
         (status = 200, description = "Metadata updated successfully", body = Contract),
         (status = 404, description = "Contract not found"),
-        (status = 400, description = "Invalid input")
+        (status = 400, description = "Invalid input"),
+        (status = 403, description = "Caller may not edit the contract's metadata")
     ),
+    security(("bearer_auth" = [])),
     tag = "Contracts"
 )]
 pub async fn update_contract_metadata(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     headers: HeaderMap,
     ValidatedJson(req): ValidatedJson<UpdateContractMetadataRequest>,
-) -> ApiResult<Json<Contract>> {
+) -> ApiResult<impl IntoResponse> {
+    let expected_version = concurrency::expected_version(&headers)?;
     if req.name.is_none()
         && req.description.is_none()
         && req.category.is_none()
@@ -4628,7 +5329,7 @@ pub async fn update_contract_metadata(
         )
     })?;
 
-    let before: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+    let before: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
         .bind(contract_uuid)
         .fetch_one(&state.db)
         .await
@@ -4639,6 +5340,20 @@ pub async fn update_contract_metadata(
             ),
             _ => db_internal_error("fetch contract for metadata update", err),
         })?;
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::EditMetadata,
+        "edit its metadata",
+    )
+    .await?;
+    concurrency::check(expected_version, before.lock_version)?;
+    claim_handlers::ensure_claimed(&before)?;
+    let category = match req.category.as_deref() {
+        Some(raw) => Some(crate::category_handlers::resolve_category(&state, raw).await?),
+        None => None,
+    };
 
     // Fetch before tags for audit log
     let before_tag_rows = sqlx::query!(
@@ -4657,17 +5372,20 @@ pub async fn update_contract_metadata(
             SET name = COALESCE($2, name),
                 description = COALESCE($3, description),
                 category = COALESCE($4, category),
+                lock_version = lock_version + 1,
                 updated_at = NOW()
-          WHERE id = $1
+          WHERE id = $1 AND ($5::BIGINT IS NULL OR lock_version = $5)
           RETURNING *",
     )
     .bind(contract_uuid)
     .bind(req.name.as_deref())
     .bind(req.description.as_deref())
-    .bind(req.category.as_deref())
-    .fetch_one(&mut *tx)
+    .bind(category.as_deref())
+    .bind(expected_version)
+    .fetch_optional(&mut *tx)
     .await
-    .map_err(|err| db_internal_error("update contract metadata", err))?;
+    .map_err(|err| db_internal_error("update contract metadata", err))?
+    .ok_or_else(|| concurrency::conflict())?;
 
     let mut after_tag_names = before_tag_names.clone();
     if let Some(tag_names) = &req.tags {
@@ -4780,9 +5498,10 @@ pub async fn update_contract_metadata(
                 changes_value,
                 ContractEventVisibility::Public,
             ));
+        anchor::enqueue_if_enabled(&state.db, after.id).await;
     }
 
-    Ok(Json(after))
+    Ok(([(header::ETAG, concurrency::etag(after.lock_version))], Json(after)))
 }
 
 #[utoipa::path(
@@ -4794,16 +5513,20 @@ pub async fn update_contract_metadata(
     request_body = ChangePublisherRequest,
     responses(
         (status = 200, description = "Publisher changed successfully", body = Contract),
-        (status = 404, description = "Contract not found")
+        (status = 404, description = "Contract not found"),
+        (status = 403, description = "Caller is not the contract's publisher")
     ),
+    security(("bearer_auth" = [])),
     tag = "Contracts"
 )]
 pub async fn change_contract_publisher(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     headers: HeaderMap,
     ValidatedJson(req): ValidatedJson<ChangePublisherRequest>,
-) -> ApiResult<Json<Contract>> {
+) -> ApiResult<impl IntoResponse> {
+    let expected_version = concurrency::expected_version(&headers)?;
     let contract_uuid = Uuid::parse_str(&id).map_err(|_| {
         ApiError::bad_request(
             "InvalidContractId",
@@ -4811,7 +5534,7 @@ pub async fn change_contract_publisher(
         )
     })?;
 
-    let before: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+    let before: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
         .bind(contract_uuid)
         .fetch_one(&state.db)
         .await
@@ -4822,6 +5545,15 @@ pub async fn change_contract_publisher(
             ),
             _ => db_internal_error("fetch contract for publisher change", err),
         })?;
+    contract_permissions::ensure_publisher(
+        &state,
+        &claims,
+        contract_uuid,
+        "hand it to another publisher",
+    )
+    .await?;
+    concurrency::check(expected_version, before.lock_version)?;
+    claim_handlers::ensure_claimed(&before)?;
 
     let old_publisher_address: String =
         sqlx::query_scalar("SELECT stellar_address FROM publishers WHERE id = $1")
@@ -4844,15 +5576,18 @@ pub async fn change_contract_publisher(
     let after: Contract = sqlx::query_as(
         "UPDATE contracts
             SET publisher_id = $2,
+                lock_version = lock_version + 1,
                 updated_at = NOW()
-          WHERE id = $1
+          WHERE id = $1 AND ($3::BIGINT IS NULL OR lock_version = $3)
           RETURNING *",
     )
     .bind(contract_uuid)
     .bind(new_publisher.id)
-    .fetch_one(&state.db)
+    .bind(expected_version)
+    .fetch_optional(&state.db)
     .await
-    .map_err(|err| db_internal_error("update contract publisher", err))?;
+    .map_err(|err| db_internal_error("update contract publisher", err))?
+    .ok_or_else(|| concurrency::conflict())?;
 
     if before.publisher_id != after.publisher_id {
         let changes = json!({
@@ -4869,9 +5604,10 @@ pub async fn change_contract_publisher(
         )
         .await
         .map_err(|err| db_internal_error("write publisher_changed audit log", err))?;
+        anchor::enqueue_if_enabled(&state.db, after.id).await;
     }
 
-    Ok(Json(after))
+    Ok(([(header::ETAG, concurrency::etag(after.lock_version))], Json(after)))
 }
 
 #[utoipa::path(
@@ -4884,16 +5620,20 @@ pub async fn change_contract_publisher(
     responses(
         (status = 200, description = "Status updated successfully", body = Object),
         (status = 404, description = "Contract not found"),
-        (status = 400, description = "Invalid status")
+        (status = 400, description = "Invalid status"),
+        (status = 403, description = "Caller may not edit the contract's metadata")
     ),
+    security(("bearer_auth" = [])),
     tag = "Contracts"
 )]
 pub async fn update_contract_status(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     headers: HeaderMap,
     ValidatedJson(req): ValidatedJson<UpdateContractStatusRequest>,
-) -> ApiResult<Json<Value>> {
+) -> ApiResult<impl IntoResponse> {
+    let expected_version = concurrency::expected_version(&headers)?;
     let normalized_status = req.status.to_ascii_lowercase();
     if normalized_status != "pending"
         && normalized_status != "verified"
@@ -4912,7 +5652,7 @@ pub async fn update_contract_status(
         )
     })?;
 
-    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1")
+    let contract: Contract = sqlx::query_as("SELECT * FROM contracts WHERE id = $1 AND deleted_at IS NULL")
         .bind(contract_uuid)
         .fetch_one(&state.db)
         .await
@@ -4923,6 +5663,15 @@ pub async fn update_contract_status(
             ),
             _ => db_internal_error("fetch contract for status update", err),
         })?;
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::EditMetadata,
+        "change its verification status",
+    )
+    .await?;
+    concurrency::check(expected_version, contract.lock_version)?;
 
     let previous_status: Option<String> = sqlx::query_scalar(
         "SELECT status::text FROM verifications WHERE contract_id = $1 ORDER BY created_at DESC LIMIT 1",
@@ -4939,35 +5688,41 @@ pub async fn update_contract_status(
     };
     let is_verified_after = normalized_status == "verified";
 
-    let verification_id: Uuid = sqlx::query_scalar(
-        "INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version, verified_at, error_message)
-         VALUES ($1, $2::verification_status, NULL, NULL, NULL, $3, $4)
-         RETURNING id",
-    )
-    .bind(contract_uuid)
-    .bind(&normalized_status)
-    .bind(verified_at)
-    .bind(req.error_message.as_deref())
-    .fetch_one(&state.db)
-    .await
-    .map_err(|err| db_internal_error("insert status verification row", err))?;
-
-    let verified_at = if is_verified_after {
+    let contract_verified_at = if is_verified_after {
         Some(chrono::Utc::now())
     } else {
         contract.verified_at
     };
 
-    sqlx::query("UPDATE contracts SET is_verified = $2, verified_at = COALESCE($3, verified_at), verification_status = $4::verification_status, verified_by = $5, verification_notes = $6, updated_at = NOW() WHERE id = $1")
+    // Update the contract first so a concurrent edit is rejected before any
+    // verification row is written.
+    let updated = sqlx::query("UPDATE contracts SET is_verified = $2, verified_at = COALESCE($3, verified_at), verification_status = $4::verification_status, verified_by = $5, verification_notes = $6, lock_version = lock_version + 1, updated_at = NOW() WHERE id = $1 AND ($7::BIGINT IS NULL OR lock_version = $7)")
         .bind(contract_uuid)
         .bind(is_verified_after)
-        .bind(verified_at)
+        .bind(contract_verified_at)
         .bind(&normalized_status)
         .bind(req.user_id)
         .bind(req.error_message.as_deref())
+        .bind(expected_version)
         .execute(&state.db)
         .await
         .map_err(|err| db_internal_error("update contract verification flag from status", err))?;
+    if updated.rows_affected() == 0 {
+        return Err(concurrency::conflict());
+    }
+
+    let verification_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO verifications (contract_id, status, source_code, build_params, compiler_version, verified_at, error_message)
+         VALUES ($1, $2::verification_status, NULL, NULL, NULL, $3, $4)
+         RETURNING id",
+    )
+    .bind(contract_uuid)
+    .bind(&normalized_status)
+    .bind(verified_at)
+    .bind(req.error_message.as_deref())
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("insert status verification row", err))?;
 
     let before_status = previous_status.unwrap_or_else(|| "pending".to_string());
     if before_status != normalized_status || contract.is_verified != is_verified_after {
@@ -5047,12 +5802,15 @@ pub async fn update_contract_status(
             ));
     }
 
-    Ok(Json(json!({
-        "contract_id": contract_uuid,
-        "verification_id": verification_id,
-        "status": normalized_status,
-        "is_verified": is_verified_after
-    })))
+    Ok((
+        [(header::ETAG, concurrency::etag(contract_after.lock_version))],
+        Json(json!({
+            "contract_id": contract_uuid,
+            "verification_id": verification_id,
+            "status": normalized_status,
+            "is_verified": is_verified_after
+        })),
+    ))
 }
 
 pub async fn bulk_update_contract_status(
@@ -5854,7 +6612,7 @@ mod tests {
             .await
             .unwrap();
 
-        let (status, json) = health_check(State(state)).await;
+        let (status, json) = health_check(State(state), Query(HealthQuery::default())).await;
 
         assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
         let value = json.0;
@@ -5924,6 +6682,14 @@ mod tests {
             relevance_score: None,
             visibility: shared::VisibilityType::Public,
             current_version: None,
+            lock_version: 1,
+            auto_indexed: false,
+            claimed_at: None,
+            badges: Vec::new(),
+            quality_score: None,
+            publisher: None,
+            latest_version: None,
+            linked_from: None,
         };
 
         assert_eq!(
@@ -6127,7 +6893,7 @@ pub async fn advanced_search_contracts(
     // Add joins for sorting/filtering if needed
     query_builder.push("LEFT JOIN contract_interactions ci ON c.id = ci.contract_id ");
     query_builder.push("LEFT JOIN contract_versions cv ON c.id = cv.contract_id ");
-    query_builder.push("WHERE 1=1 ");
+    query_builder.push("WHERE c.deleted_at IS NULL ");
 
     // Recursively build the WHERE clause
     build_where_clause(&mut query_builder, &req.query)?;
@@ -6157,6 +6923,14 @@ pub async fn advanced_search_contracts(
         shared::SortBy::Deployments => {
             query_builder.push("COUNT(DISTINCT cv.id) ");
         }
+        shared::SortBy::Quality => {
+            query_builder.push("COALESCE(c.quality_score, -1) ");
+        }
+        shared::SortBy::Tvl => {
+            query_builder.push(
+                "COALESCE((SELECT dp.tvl_usd FROM defi_pools dp WHERE dp.contract_id = c.id), -1) ",
+            );
+        }
         shared::SortBy::Relevance => {
             query_builder.push("c.created_at "); // Default relevance if no query term
         }
@@ -6182,7 +6956,7 @@ pub async fn advanced_search_contracts(
     // Count total matches (naively for now, same filters)
     let mut count_builder: sqlx::QueryBuilder<'_, sqlx::Postgres> =
         sqlx::QueryBuilder::new("SELECT COUNT(DISTINCT c.id) FROM contracts c ");
-    count_builder.push("WHERE 1=1 ");
+    count_builder.push("WHERE c.deleted_at IS NULL ");
     build_where_clause(&mut count_builder, &req.query)?;
 
     let total: i64 = count_builder
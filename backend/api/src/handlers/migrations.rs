@@ -1,53 +1,223 @@
 use crate::validation::extractors::ValidatedJson;
 use axum::{
     extract::{Path, Query, State},
-    http::StatusCode,
+    http::{header, HeaderMap, StatusCode},
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     Json,
 };
+use chrono::{DateTime, Utc};
+use futures_util::stream;
+use serde::{Deserialize, Serialize};
 use shared::models::{
     CreateMigrationRequest, Migration, MigrationStatus, PaginatedResponse,
     UpdateMigrationStatusRequest,
 };
+use sqlx::PgPool;
+use std::collections::VecDeque;
+use std::convert::Infallible;
+use std::time::Duration;
 use uuid::Uuid;
 
-use super::db_internal_error;
+use super::{db_internal_error, fetch_contract_identity};
+use crate::auth::AuthClaims;
+use crate::contract_permissions::{self, ContractCapability};
 use crate::error::ApiError;
+use crate::org_network_policy_handlers::{self, GatedDeployment};
 use crate::state::AppState;
+use crate::tenant::Tenant;
 
-/// Create a new migration
+const MIGRATION_COLUMNS: &str = "id, contract_id, status, wasm_hash, log_output,
+    post_upgrade_function, post_upgrade_args, post_upgrade_status, post_upgrade_output,
+    transaction_hash, created_at, updated_at, completed_at";
+
+const DEFAULT_PAGE_SIZE: i64 = 20;
+const MAX_PAGE_SIZE: i64 = 100;
+
+/// Largest log chunk a migration may append at once.
+const MAX_LOG_CHUNK_BYTES: usize = 64 * 1024;
+/// Chunks returned per request, or per poll while following.
+const LOG_CHUNK_BATCH: i64 = 500;
+const LOG_FOLLOW_POLL: Duration = Duration::from_secs(1);
+
+/// Filters for a contract's migration history
+#[derive(Debug, Deserialize)]
+pub struct MigrationHistoryQuery {
+    pub status: Option<MigrationStatus>,
+    /// Only migrations created at or after this time
+    pub since: Option<DateTime<Utc>>,
+    /// Only migrations created before this time
+    pub until: Option<DateTime<Utc>>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+/// A migration with its duration and a link to its log output
+#[derive(Debug, Serialize)]
+pub struct MigrationHistoryEntry {
+    #[serde(flatten)]
+    pub migration: Migration,
+    pub duration_ms: Option<i64>,
+    pub log_url: Option<String>,
+}
+
+impl From<Migration> for MigrationHistoryEntry {
+    fn from(migration: Migration) -> Self {
+        Self {
+            duration_ms: migration
+                .completed_at
+                .map(|done| (done - migration.created_at).num_milliseconds()),
+            log_url: migration
+                .log_output
+                .as_ref()
+                .map(|_| format!("/api/migrations/{}/log", migration.id)),
+            migration,
+        }
+    }
+}
+
+/// Fails unless the caller may run migrations on `contract_id`.
+async fn authorize_migration(
+    state: &AppState,
+    claims: &AuthClaims,
+    contract_id: &str,
+) -> Result<(), ApiError> {
+    let (contract_uuid, _) = fetch_contract_identity(state, contract_id).await?;
+    contract_permissions::authorize(
+        state,
+        claims,
+        contract_uuid,
+        ContractCapability::RunMigrations,
+        "run its migrations",
+    )
+    .await
+}
+
+/// Create a new migration. When the contract's organization gates its
+/// network behind multisig, this answers `202 Accepted` with the deploy
+/// proposal the upgrade waits on instead.
 pub async fn create_migration(
     State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
     ValidatedJson(payload): ValidatedJson<CreateMigrationRequest>,
-) -> Result<Json<Migration>, ApiError> {
-    let migration: Migration = sqlx::query_as(
-        "INSERT INTO migrations (contract_id, wasm_hash, status)
-        VALUES ($1, $2, 'pending')
-        RETURNING id, contract_id, status, wasm_hash, log_output, created_at, updated_at",
-    )
+) -> Result<Response, ApiError> {
+    authorize_migration(&state, &claims, &payload.contract_id).await?;
+    if let Some((gate, contract_name, network)) =
+        org_network_policy_handlers::gate_for_contract(&state, &payload.contract_id).await?
+    {
+        let deployment = GatedDeployment {
+            contract_name: &contract_name,
+            contract_id: &payload.contract_id,
+            wasm_hash: &payload.wasm_hash,
+            network: &network,
+            proposer: payload.proposer.as_deref(),
+        };
+        if let Some(proposal) =
+            org_network_policy_handlers::require_approval(&state, tenant.id, &gate, deployment)
+                .await?
+        {
+            return Ok(org_network_policy_handlers::approval_required(
+                gate, proposal,
+            ));
+        }
+    }
+
+    let hook = payload.post_upgrade.as_ref();
+    let migration: Migration = sqlx::query_as(&format!(
+        "INSERT INTO migrations
+            (contract_id, wasm_hash, status, post_upgrade_function, post_upgrade_args,
+             post_upgrade_status)
+        VALUES ($1, $2, 'pending', $3, $4, CASE WHEN $3 IS NULL THEN NULL ELSE 'pending' END)
+        RETURNING {}",
+        MIGRATION_COLUMNS
+    ))
     .bind(&payload.contract_id)
     .bind(&payload.wasm_hash)
+    .bind(hook.map(|h| &h.function))
+    .bind(hook.and_then(|h| h.args.as_ref()))
     .fetch_one(&state.db)
     .await
     .map_err(|e| db_internal_error("create migration", e))?;
 
-    Ok(Json(migration))
+    Ok(Json(migration).into_response())
+}
+
+/// Post-upgrade hook status to record for an update, or why the update is
+/// not allowed. A migration with a hook only succeeds if its hook did.
+fn post_upgrade_status(
+    has_hook: bool,
+    payload: &UpdateMigrationStatusRequest,
+) -> Result<Option<&'static str>, ApiError> {
+    let result = payload.post_upgrade_result.as_ref();
+    if !has_hook {
+        return match result {
+            Some(_) => Err(ApiError::bad_request(
+                "NoPostUpgradeHook",
+                "Migration does not declare a post-upgrade hook",
+            )),
+            None => Ok(None),
+        };
+    }
+
+    match (&payload.status, result) {
+        (MigrationStatus::Success, Some(r)) if r.success => Ok(Some("success")),
+        (MigrationStatus::Success, _) => Err(ApiError::conflict(
+            "PostUpgradeHookIncomplete",
+            "Migration cannot succeed until its post-upgrade hook has succeeded",
+        )),
+        (_, Some(r)) => Ok(Some(if r.success { "success" } else { "failed" })),
+        (MigrationStatus::Failed, None) => Ok(Some("skipped")),
+        _ => Ok(None),
+    }
 }
 
 /// Update a migration status
 pub async fn update_migration(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<Uuid>,
     ValidatedJson(payload): ValidatedJson<UpdateMigrationStatusRequest>,
 ) -> Result<Json<Migration>, ApiError> {
-    let migration: Migration = sqlx::query_as(
+    let row: Option<(String, Option<String>)> =
+        sqlx::query_as("SELECT contract_id, post_upgrade_function FROM migrations WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| db_internal_error("get migration", e))?;
+    let Some((contract_id, hook)) = row else {
+        return Err(ApiError::not_found(
+            "MigrationNotFound",
+            "Migration not found",
+        ));
+    };
+    authorize_migration(&state, &claims, &contract_id).await?;
+    let hook_status = post_upgrade_status(hook.is_some(), &payload)?;
+    let hook_output = payload
+        .post_upgrade_result
+        .as_ref()
+        .and_then(|r| r.output.clone());
+
+    let migration: Migration = sqlx::query_as(&format!(
         "UPDATE migrations
-        SET status = $1, log_output = COALESCE($2, log_output)
+        SET status = $1, log_output = COALESCE($2, log_output),
+            post_upgrade_status = COALESCE($4, post_upgrade_status),
+            post_upgrade_output = COALESCE($5, post_upgrade_output),
+            transaction_hash = COALESCE($6, transaction_hash),
+            completed_at = CASE WHEN $1 = 'pending' THEN NULL
+                                ELSE COALESCE(completed_at, NOW()) END
         WHERE id = $3
-        RETURNING id, contract_id, status, wasm_hash, log_output, created_at, updated_at",
-    )
+        RETURNING {}",
+        MIGRATION_COLUMNS
+    ))
     .bind(payload.status)
     .bind(payload.log_output)
     .bind(id)
+    .bind(hook_status)
+    .bind(hook_output)
+    .bind(payload.transaction_hash)
     .fetch_one(&state.db)
     .await
     .map_err(|e| db_internal_error("update migration", e))?;
@@ -60,12 +230,13 @@ pub async fn get_migrations(
     State(state): State<AppState>,
 ) -> Result<Json<PaginatedResponse<Migration>>, ApiError> {
     // For simplicity, we'll just return the last 50 migrations
-    let migrations: Vec<Migration> = sqlx::query_as(
-        "SELECT id, contract_id, status, wasm_hash, log_output, created_at, updated_at
+    let migrations: Vec<Migration> = sqlx::query_as(&format!(
+        "SELECT {}
         FROM migrations
         ORDER BY created_at DESC
         LIMIT 50",
-    )
+        MIGRATION_COLUMNS
+    ))
     .fetch_all(&state.db)
     .await
     .map_err(|e| db_internal_error("get migrations", e))?;
@@ -81,11 +252,12 @@ pub async fn get_migration(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<Migration>, ApiError> {
-    let migration: Migration = sqlx::query_as(
-        "SELECT id, contract_id, status, wasm_hash, log_output, created_at, updated_at
+    let migration: Migration = sqlx::query_as(&format!(
+        "SELECT {}
         FROM migrations
         WHERE id = $1",
-    )
+        MIGRATION_COLUMNS
+    ))
     .bind(id)
     .fetch_optional(&state.db)
     .await
@@ -97,3 +269,413 @@ pub async fn get_migration(
 
     Ok(Json(migration))
 }
+
+/// List a contract's migrations, newest first
+pub async fn get_contract_migrations(
+    State(state): State<AppState>,
+    Path(contract): Path<String>,
+    Query(query): Query<MigrationHistoryQuery>,
+) -> Result<Json<PaginatedResponse<MigrationHistoryEntry>>, ApiError> {
+    if let (Some(since), Some(until)) = (query.since, query.until) {
+        if since >= until {
+            return Err(ApiError::bad_request(
+                "InvalidTimeRange",
+                "`since` must be earlier than `until`",
+            ));
+        }
+    }
+    let page = query.page.unwrap_or(1).max(1);
+    let page_size = query
+        .page_size
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+
+    // Migrations store the on-chain contract ID; the path may also be the
+    // registry UUID.
+    let filter = "(contract_id = $1
+           OR contract_id IN (SELECT contract_id FROM contracts WHERE id = $2))
+          AND ($3::migration_status IS NULL OR status = $3)
+          AND ($4::timestamptz IS NULL OR created_at >= $4)
+          AND ($5::timestamptz IS NULL OR created_at < $5)";
+
+    let total: i64 =
+        sqlx::query_scalar(&format!("SELECT COUNT(*) FROM migrations WHERE {}", filter))
+            .bind(&contract)
+            .bind(Uuid::parse_str(&contract).ok())
+            .bind(&query.status)
+            .bind(query.since)
+            .bind(query.until)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|e| db_internal_error("count contract migrations", e))?;
+
+    let migrations: Vec<Migration> = sqlx::query_as(&format!(
+        "SELECT {}
+        FROM migrations
+        WHERE {}
+        ORDER BY created_at DESC, id
+        LIMIT $6 OFFSET $7",
+        MIGRATION_COLUMNS, filter
+    ))
+    .bind(&contract)
+    .bind(Uuid::parse_str(&contract).ok())
+    .bind(&query.status)
+    .bind(query.since)
+    .bind(query.until)
+    .bind(page_size)
+    .bind((page - 1) * page_size)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| db_internal_error("list contract migrations", e))?;
+
+    let entries = migrations.into_iter().map(Into::into).collect();
+    Ok(Json(PaginatedResponse::new(
+        entries, total, page, page_size,
+    )))
+}
+
+/// A piece of migration log output
+#[derive(Debug, Deserialize)]
+pub struct AppendLogRequest {
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow)]
+pub struct MigrationLogChunk {
+    pub seq: i32,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MigrationLogsQuery {
+    /// Only chunks after this sequence number
+    pub after: Option<i32>,
+    /// Stream new chunks as server-sent events until the migration finishes
+    #[serde(default)]
+    pub follow: bool,
+}
+
+#[derive(Debug, Serialize)]
+pub struct MigrationLogsResponse {
+    pub migration_id: Uuid,
+    pub status: MigrationStatus,
+    pub chunks: Vec<MigrationLogChunk>,
+    /// Pass as `after` to fetch the next chunks
+    pub next_after: i32,
+}
+
+/// Append a chunk of log output to a pending migration
+pub async fn append_migration_log(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<Uuid>,
+    Json(payload): Json<AppendLogRequest>,
+) -> Result<(StatusCode, Json<MigrationLogChunk>), ApiError> {
+    if payload.content.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidLogChunk",
+            "Log chunk must not be empty",
+        ));
+    }
+    if payload.content.len() > MAX_LOG_CHUNK_BYTES {
+        return Err(ApiError::bad_request(
+            "InvalidLogChunk",
+            format!("Log chunks are limited to {} bytes", MAX_LOG_CHUNK_BYTES),
+        ));
+    }
+
+    // Authorize before taking the row lock, so a caller without access
+    // cannot hold up the migration's appends and status updates.
+    let contract_id: Option<String> =
+        sqlx::query_scalar("SELECT contract_id FROM migrations WHERE id = $1")
+            .bind(id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| db_internal_error("get migration", e))?;
+    let Some(contract_id) = contract_id else {
+        return Err(ApiError::not_found(
+            "MigrationNotFound",
+            "Migration not found",
+        ));
+    };
+    authorize_migration(&state, &claims, &contract_id).await?;
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| db_internal_error("begin migration log append", e))?;
+
+    // Lock the migration so concurrent appends get consecutive sequence
+    // numbers and cannot race a status update.
+    let status: MigrationStatus =
+        sqlx::query_scalar("SELECT status FROM migrations WHERE id = $1 FOR UPDATE")
+            .bind(id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| db_internal_error("lock migration", e))?
+            .ok_or_else(|| ApiError::not_found("MigrationNotFound", "Migration not found"))?;
+    match status {
+        MigrationStatus::Pending => {}
+        _ => {
+            return Err(ApiError::conflict(
+                "MigrationFinished",
+                "Logs can only be appended while a migration is pending",
+            ))
+        }
+    }
+
+    let chunk: MigrationLogChunk = sqlx::query_as(
+        "INSERT INTO migration_log_chunks (migration_id, seq, content)
+        SELECT $1, COALESCE(MAX(seq), 0) + 1, $2
+        FROM migration_log_chunks WHERE migration_id = $1
+        RETURNING seq, content, created_at",
+    )
+    .bind(id)
+    .bind(&payload.content)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| db_internal_error("append migration log", e))?;
+
+    tx.commit()
+        .await
+        .map_err(|e| db_internal_error("commit migration log append", e))?;
+    Ok((StatusCode::CREATED, Json(chunk)))
+}
+
+/// Migration status followed by the chunks after `after`. The status is read
+/// first: chunks can only be appended while pending, so once it is final the
+/// chunks read afterwards are complete.
+async fn fetch_log_chunks(
+    db: &PgPool,
+    id: Uuid,
+    after: i32,
+) -> Result<Option<(MigrationStatus, Vec<MigrationLogChunk>)>, sqlx::Error> {
+    let status: Option<MigrationStatus> =
+        sqlx::query_scalar("SELECT status FROM migrations WHERE id = $1")
+            .bind(id)
+            .fetch_optional(db)
+            .await?;
+    let Some(status) = status else {
+        return Ok(None);
+    };
+    let chunks = sqlx::query_as(
+        "SELECT seq, content, created_at FROM migration_log_chunks
+        WHERE migration_id = $1 AND seq > $2
+        ORDER BY seq
+        LIMIT $3",
+    )
+    .bind(id)
+    .bind(after)
+    .bind(LOG_CHUNK_BATCH)
+    .fetch_all(db)
+    .await?;
+    Ok(Some((status, chunks)))
+}
+
+/// Read a migration's chunked log, or follow it as server-sent events
+///
+/// Each `log` event carries one chunk with its sequence number as the event
+/// ID, so reconnecting clients resume via `Last-Event-ID`. A final `end`
+/// event carries the migration's status.
+pub async fn get_migration_logs(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<MigrationLogsQuery>,
+    headers: HeaderMap,
+) -> Result<Response, ApiError> {
+    let resume_from = headers
+        .get("last-event-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.parse::<i32>().ok());
+    let after = resume_from.or(query.after).unwrap_or(0).max(0);
+
+    if !query.follow {
+        let (status, chunks) = fetch_log_chunks(&state.db, id, after)
+            .await
+            .map_err(|e| db_internal_error("get migration logs", e))?
+            .ok_or(ApiError::not_found(
+                "MigrationNotFound",
+                "Migration not found",
+            ))?;
+        let next_after = chunks.last().map_or(after, |c| c.seq);
+        return Ok(Json(MigrationLogsResponse {
+            migration_id: id,
+            status,
+            chunks,
+            next_after,
+        })
+        .into_response());
+    }
+
+    // Fail fast with a 404 rather than an empty stream.
+    let exists: bool = sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM migrations WHERE id = $1)")
+        .bind(id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| db_internal_error("get migration", e))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "MigrationNotFound",
+            "Migration not found",
+        ));
+    }
+
+    let follow = LogFollower {
+        db: state.db.clone(),
+        id,
+        after,
+        queued: VecDeque::new(),
+        polled: false,
+        done: false,
+    };
+    let events = stream::unfold(follow, |mut f| async move {
+        f.next_event()
+            .await
+            .map(|event| (Ok::<_, Infallible>(event), f))
+    });
+    Ok(Sse::new(events)
+        .keep_alive(KeepAlive::default())
+        .into_response())
+}
+
+struct LogFollower {
+    db: PgPool,
+    id: Uuid,
+    after: i32,
+    queued: VecDeque<Event>,
+    polled: bool,
+    done: bool,
+}
+
+impl LogFollower {
+    async fn next_event(&mut self) -> Option<Event> {
+        loop {
+            if let Some(event) = self.queued.pop_front() {
+                return Some(event);
+            }
+            if self.done {
+                return None;
+            }
+            if self.polled {
+                tokio::time::sleep(LOG_FOLLOW_POLL).await;
+            }
+            self.polled = true;
+
+            match fetch_log_chunks(&self.db, self.id, self.after).await {
+                Ok(Some((status, chunks))) => {
+                    let drained = (chunks.len() as i64) < LOG_CHUNK_BATCH;
+                    for chunk in chunks {
+                        self.after = chunk.seq;
+                        self.queued.push_back(
+                            Event::default()
+                                .event("log")
+                                .id(chunk.seq.to_string())
+                                .data(chunk.content),
+                        );
+                    }
+                    if status != MigrationStatus::Pending && drained {
+                        let status = serde_json::to_value(&status)
+                            .ok()
+                            .and_then(|v| v.as_str().map(str::to_string))
+                            .unwrap_or_default();
+                        self.queued
+                            .push_back(Event::default().event("end").data(status));
+                        self.done = true;
+                    }
+                }
+                Ok(None) => {
+                    self.queued
+                        .push_back(Event::default().event("error").data("Migration not found"));
+                    self.done = true;
+                }
+                Err(err) => {
+                    tracing::error!(migration_id = %self.id, error = ?err, "migration log follow failed");
+                    self.queued.push_back(
+                        Event::default()
+                            .event("error")
+                            .data("Failed to read migration logs"),
+                    );
+                    self.done = true;
+                }
+            }
+        }
+    }
+}
+
+/// Raw log output of a migration: its chunked log if it streamed one,
+/// otherwise the log recorded with its final status
+pub async fn get_migration_log(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<impl IntoResponse, ApiError> {
+    let log: Option<Option<String>> = sqlx::query_scalar(
+        "SELECT COALESCE(
+            (SELECT string_agg(content, '' ORDER BY seq)
+             FROM migration_log_chunks WHERE migration_id = m.id),
+            m.log_output)
+        FROM migrations m WHERE m.id = $1",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| db_internal_error("get migration log", e))?;
+
+    let log = log
+        .ok_or(ApiError::not_found(
+            "MigrationNotFound",
+            "Migration not found",
+        ))?
+        .unwrap_or_default();
+    Ok(([(header::CONTENT_TYPE, "text/plain; charset=utf-8")], log))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shared::models::PostUpgradeHookResult;
+
+    fn update(status: MigrationStatus, success: Option<bool>) -> UpdateMigrationStatusRequest {
+        UpdateMigrationStatusRequest {
+            status,
+            log_output: None,
+            transaction_hash: None,
+            post_upgrade_result: success.map(|success| PostUpgradeHookResult {
+                success,
+                output: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn success_requires_a_successful_hook() {
+        assert_eq!(
+            post_upgrade_status(true, &update(MigrationStatus::Success, Some(true))).unwrap(),
+            Some("success")
+        );
+        assert!(post_upgrade_status(true, &update(MigrationStatus::Success, Some(false))).is_err());
+        assert!(post_upgrade_status(true, &update(MigrationStatus::Success, None)).is_err());
+    }
+
+    #[test]
+    fn failed_migration_records_or_skips_the_hook() {
+        assert_eq!(
+            post_upgrade_status(true, &update(MigrationStatus::Failed, Some(false))).unwrap(),
+            Some("failed")
+        );
+        assert_eq!(
+            post_upgrade_status(true, &update(MigrationStatus::Failed, None)).unwrap(),
+            Some("skipped")
+        );
+    }
+
+    #[test]
+    fn hook_result_needs_a_declared_hook() {
+        assert!(post_upgrade_status(false, &update(MigrationStatus::Success, Some(true))).is_err());
+        assert_eq!(
+            post_upgrade_status(false, &update(MigrationStatus::Success, None)).unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,322 @@
+//! CORS and security response headers
+//!
+//! Both are driven by environment variables so browser frontends and
+//! third-party dapps can call the API directly without a proxy.
+//!
+//! CORS:
+//! - CORS_ALLOWED_ORIGINS: comma-separated origins, or `*` for any
+//!   (falls back to ALLOWED_ORIGINS, then the built-in defaults)
+//! - CORS_ALLOWED_METHODS: comma-separated methods (default GET,POST,PUT,PATCH,DELETE,OPTIONS)
+//! - CORS_ALLOWED_HEADERS: comma-separated request headers (default
+//!   content-type, authorization, if-match, x-request-id, x-correlation-id,
+//!   idempotency-key)
+//! - CORS_EXPOSED_HEADERS: extra response headers readable by the browser
+//! - CORS_ALLOW_CREDENTIALS: `true` to allow cookies / credentials (not with `*`)
+//! - CORS_MAX_AGE_SECS: preflight cache lifetime (default 3600)
+//!
+//! Security headers:
+//! - SECURITY_HEADERS_ENABLED: `false` to disable the middleware (default true)
+//! - SECURITY_CSP: Content-Security-Policy for non-HTML responses
+//!   (default `default-src 'none'; frame-ancestors 'none'`)
+//! - SECURITY_HSTS_MAX_AGE_SECS: send Strict-Transport-Security with this
+//!   max-age; unset by default because TLS is usually terminated upstream
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{anyhow, bail, Result};
+use axum::{
+    extract::{Request, State},
+    http::{header, HeaderName, HeaderValue, Method},
+    middleware::Next,
+    response::Response,
+};
+use tower_http::cors::{AllowOrigin, CorsLayer};
+
+use crate::request_tracing::{X_CORRELATION_ID, X_REQUEST_ID};
+
+const DEFAULT_ORIGINS: &str = "http://localhost:3000,https://soroban-registry.vercel.app";
+const DEFAULT_METHODS: &str = "GET,POST,PUT,PATCH,DELETE,OPTIONS";
+const DEFAULT_CSP: &str = "default-src 'none'; frame-ancestors 'none'";
+const DEFAULT_MAX_AGE_SECS: u64 = 3600;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllowedOrigins {
+    Any,
+    List(Vec<HeaderValue>),
+}
+
+#[derive(Debug, Clone)]
+pub struct CorsConfig {
+    pub origins: AllowedOrigins,
+    pub methods: Vec<Method>,
+    pub headers: Vec<HeaderName>,
+    pub expose_headers: Vec<HeaderName>,
+    pub allow_credentials: bool,
+    pub max_age: Duration,
+}
+
+impl CorsConfig {
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let origins_raw = get("CORS_ALLOWED_ORIGINS")
+            .or_else(|| get("ALLOWED_ORIGINS"))
+            .unwrap_or_else(|| DEFAULT_ORIGINS.to_string());
+        let origins = if origins_raw.trim() == "*" {
+            AllowedOrigins::Any
+        } else {
+            AllowedOrigins::List(
+                split_list(&origins_raw)
+                    .map(|o| {
+                        HeaderValue::from_str(o)
+                            .map_err(|_| anyhow!("Invalid CORS origin: {:?}", o))
+                    })
+                    .collect::<Result<_>>()?,
+            )
+        };
+
+        let methods =
+            split_list(&get("CORS_ALLOWED_METHODS").unwrap_or_else(|| DEFAULT_METHODS.into()))
+                .map(|m| {
+                    Method::from_bytes(m.to_ascii_uppercase().as_bytes())
+                        .map_err(|_| anyhow!("Invalid CORS method: {:?}", m))
+                })
+                .collect::<Result<_>>()?;
+
+        let headers = match get("CORS_ALLOWED_HEADERS") {
+            Some(raw) => parse_header_names(&raw)?,
+            None => vec![
+                header::CONTENT_TYPE,
+                header::AUTHORIZATION,
+                header::IF_MATCH,
+                X_REQUEST_ID.clone(),
+                X_CORRELATION_ID.clone(),
+                HeaderName::from_static(crate::idempotency::IDEMPOTENCY_KEY_HEADER),
+            ],
+        };
+
+        let mut expose_headers = vec![
+            header::ETAG,
+            X_REQUEST_ID.clone(),
+            X_CORRELATION_ID.clone(),
+            HeaderName::from_static(crate::idempotency::REPLAYED_HEADER),
+        ];
+        if let Some(raw) = get("CORS_EXPOSED_HEADERS") {
+            expose_headers.extend(parse_header_names(&raw)?);
+        }
+
+        let allow_credentials = get("CORS_ALLOW_CREDENTIALS")
+            .map(|v| v.eq_ignore_ascii_case("true"))
+            .unwrap_or(false);
+        if allow_credentials && origins == AllowedOrigins::Any {
+            bail!("CORS_ALLOW_CREDENTIALS=true cannot be combined with CORS_ALLOWED_ORIGINS=*");
+        }
+
+        let max_age = get("CORS_MAX_AGE_SECS")
+            .map(|v| {
+                v.parse::<u64>()
+                    .map_err(|_| anyhow!("Invalid CORS_MAX_AGE_SECS: {:?}", v))
+            })
+            .transpose()?
+            .unwrap_or(DEFAULT_MAX_AGE_SECS);
+
+        Ok(Self {
+            origins,
+            methods,
+            headers,
+            expose_headers,
+            allow_credentials,
+            max_age: Duration::from_secs(max_age),
+        })
+    }
+
+    pub fn layer(&self) -> CorsLayer {
+        let origin = match &self.origins {
+            AllowedOrigins::Any => AllowOrigin::any(),
+            AllowedOrigins::List(list) => AllowOrigin::list(list.clone()),
+        };
+        CorsLayer::new()
+            .allow_origin(origin)
+            .allow_methods(self.methods.clone())
+            .allow_headers(self.headers.clone())
+            .expose_headers(self.expose_headers.clone())
+            .allow_credentials(self.allow_credentials)
+            .max_age(self.max_age)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersConfig {
+    pub enabled: bool,
+    pub csp: Option<HeaderValue>,
+    pub hsts: Option<HeaderValue>,
+}
+
+impl SecurityHeadersConfig {
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let enabled = get("SECURITY_HEADERS_ENABLED")
+            .map(|v| !v.eq_ignore_ascii_case("false"))
+            .unwrap_or(true);
+
+        let csp = match get("SECURITY_CSP") {
+            Some(v) if v.trim().is_empty() => None,
+            Some(v) => {
+                Some(HeaderValue::from_str(&v).map_err(|_| anyhow!("Invalid SECURITY_CSP"))?)
+            }
+            None => Some(HeaderValue::from_static(DEFAULT_CSP)),
+        };
+
+        let hsts = get("SECURITY_HSTS_MAX_AGE_SECS")
+            .map(|v| {
+                let secs: u64 = v
+                    .parse()
+                    .map_err(|_| anyhow!("Invalid SECURITY_HSTS_MAX_AGE_SECS: {:?}", v))?;
+                Ok::<_, anyhow::Error>(HeaderValue::from_str(&format!(
+                    "max-age={}; includeSubDomains",
+                    secs
+                ))?)
+            })
+            .transpose()?;
+
+        Ok(Self { enabled, csp, hsts })
+    }
+}
+
+fn split_list(raw: &str) -> impl Iterator<Item = &str> {
+    raw.split(',').map(str::trim).filter(|s| !s.is_empty())
+}
+
+fn parse_header_names(raw: &str) -> Result<Vec<HeaderName>> {
+    split_list(raw)
+        .map(|h| {
+            HeaderName::from_bytes(h.as_bytes())
+                .map_err(|_| anyhow!("Invalid header name: {:?}", h))
+        })
+        .collect()
+}
+
+/// Adds standard security headers to every response that does not already
+/// set them. The CSP is skipped for HTML so Swagger UI and the GraphQL
+/// playground keep working.
+pub async fn security_headers_middleware(
+    State(config): State<Arc<SecurityHeadersConfig>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    let mut response = next.run(req).await;
+    if !config.enabled {
+        return response;
+    }
+
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("text/html"));
+
+    let headers = response.headers_mut();
+    let defaults = [
+        (
+            header::X_CONTENT_TYPE_OPTIONS,
+            HeaderValue::from_static("nosniff"),
+        ),
+        (header::X_FRAME_OPTIONS, HeaderValue::from_static("DENY")),
+        (
+            header::REFERRER_POLICY,
+            HeaderValue::from_static("no-referrer"),
+        ),
+        (
+            HeaderName::from_static("cross-origin-opener-policy"),
+            HeaderValue::from_static("same-origin"),
+        ),
+        (
+            HeaderName::from_static("permissions-policy"),
+            HeaderValue::from_static("camera=(), microphone=(), geolocation=()"),
+        ),
+    ];
+    for (name, value) in defaults {
+        headers.entry(name).or_insert(value);
+    }
+    if let (Some(csp), false) = (&config.csp, is_html) {
+        headers
+            .entry(header::CONTENT_SECURITY_POLICY)
+            .or_insert(csp.clone());
+    }
+    if let Some(hsts) = &config.hsts {
+        headers
+            .entry(header::STRICT_TRANSPORT_SECURITY)
+            .or_insert(hsts.clone());
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn lookup(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let map: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        move |key| map.get(key).cloned()
+    }
+
+    #[test]
+    fn cors_defaults_keep_the_legacy_origins() {
+        let config = CorsConfig::from_lookup(lookup(&[])).unwrap();
+        assert_eq!(
+            config.origins,
+            AllowedOrigins::List(vec![
+                HeaderValue::from_static("http://localhost:3000"),
+                HeaderValue::from_static("https://soroban-registry.vercel.app"),
+            ])
+        );
+        assert!(config.methods.contains(&Method::PUT));
+        assert!(!config.allow_credentials);
+    }
+
+    #[test]
+    fn cors_reads_explicit_settings() {
+        let config = CorsConfig::from_lookup(lookup(&[
+            ("CORS_ALLOWED_ORIGINS", "*"),
+            ("CORS_ALLOWED_METHODS", "get, post"),
+            ("CORS_ALLOWED_HEADERS", "content-type,x-api-key"),
+            ("CORS_MAX_AGE_SECS", "60"),
+        ]))
+        .unwrap();
+        assert_eq!(config.origins, AllowedOrigins::Any);
+        assert_eq!(config.methods, vec![Method::GET, Method::POST]);
+        assert_eq!(config.headers[1].as_str(), "x-api-key");
+        assert_eq!(config.max_age, Duration::from_secs(60));
+    }
+
+    #[test]
+    fn cors_rejects_credentials_with_wildcard_origin() {
+        let err = CorsConfig::from_lookup(lookup(&[
+            ("CORS_ALLOWED_ORIGINS", "*"),
+            ("CORS_ALLOW_CREDENTIALS", "true"),
+        ]));
+        assert!(err.is_err());
+    }
+
+    #[test]
+    fn security_headers_config_parses_hsts_and_empty_csp() {
+        let config = SecurityHeadersConfig::from_lookup(lookup(&[
+            ("SECURITY_CSP", ""),
+            ("SECURITY_HSTS_MAX_AGE_SECS", "31536000"),
+        ]))
+        .unwrap();
+        assert!(config.enabled);
+        assert!(config.csp.is_none());
+        assert_eq!(config.hsts.unwrap(), "max-age=31536000; includeSubDomains");
+    }
+}
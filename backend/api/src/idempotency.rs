@@ -0,0 +1,328 @@
+//! `Idempotency-Key` support for write endpoints
+//!
+//! Clients that retry a request after a timeout cannot tell whether the first
+//! attempt was applied. When a request to one of [`IDEMPOTENT_ROUTES`] carries
+//! an `Idempotency-Key` header, the first response is stored and any retry
+//! with the same key, path and body replays it (with `Idempotent-Replayed:
+//! true`) instead of publishing a second contract or proposal.
+//!
+//! - Reusing a key with a different body (or credentials) returns 422.
+//! - A retry that arrives while the original is still running returns 409
+//!   with `Retry-After`.
+//! - 5xx responses are not stored, so the request can be retried as new.
+//!
+//! Keys expire after `IDEMPOTENCY_KEY_TTL_HOURS` (default 24).
+
+use std::time::Duration;
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::{MatchedPath, State},
+    http::{header, HeaderValue, Request, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
+
+use crate::{error::ApiError, state::AppState};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+pub const REPLAYED_HEADER: &str = "idempotent-replayed";
+const MAX_KEY_LEN: usize = 255;
+const DEFAULT_TTL_HOURS: i64 = 24;
+
+/// POST routes that honour `Idempotency-Key`. Version creation also records
+/// the differential patch, so it covers patch creation too.
+const IDEMPOTENT_ROUTES: &[&str] = &[
+    "/api/contracts",
+    "/api/contracts/:id/versions",
+    "/api/contracts/deploy-proposal",
+];
+
+fn ttl_hours() -> i64 {
+    std::env::var("IDEMPOTENCY_KEY_TTL_HOURS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|h| *h > 0)
+        .unwrap_or(DEFAULT_TTL_HOURS)
+}
+
+fn db_err(op: &str, err: sqlx::Error) -> Response {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred").into_response()
+}
+
+fn valid_key(key: &str) -> bool {
+    !key.is_empty() && key.len() <= MAX_KEY_LEN && key.bytes().all(|b| b.is_ascii_graphic())
+}
+
+/// Fingerprint of everything that makes two requests "the same request".
+fn request_hash(method: &str, path: &str, authorization: Option<&[u8]>, body: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    for part in [
+        method.as_bytes(),
+        path.as_bytes(),
+        authorization.unwrap_or_default(),
+    ] {
+        hasher.update((part.len() as u64).to_be_bytes());
+        hasher.update(part);
+    }
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+#[derive(sqlx::FromRow)]
+struct StoredResponse {
+    request_hash: String,
+    status_code: Option<i16>,
+    content_type: Option<String>,
+    response_body: Option<Vec<u8>>,
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let status = stored
+        .status_code
+        .and_then(|s| StatusCode::from_u16(s as u16).ok())
+        .unwrap_or(StatusCode::OK);
+    let mut response = (status, stored.response_body.unwrap_or_default()).into_response();
+    let headers = response.headers_mut();
+    if let Some(ct) = stored
+        .content_type
+        .and_then(|ct| HeaderValue::from_str(&ct).ok())
+    {
+        headers.insert(header::CONTENT_TYPE, ct);
+    }
+    headers.insert(REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+fn in_progress() -> Response {
+    (
+        [(header::RETRY_AFTER, HeaderValue::from_static("1"))],
+        ApiError::conflict(
+            "IdempotentRequestInProgress",
+            "A request with this Idempotency-Key is still being processed",
+        ),
+    )
+        .into_response()
+}
+
+pub async fn idempotency_middleware(
+    State(state): State<AppState>,
+    matched_path: Option<MatchedPath>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let applies = req.method() == axum::http::Method::POST
+        && matched_path
+            .as_ref()
+            .is_some_and(|p| IDEMPOTENT_ROUTES.contains(&p.as_str()));
+    let Some(key) = req
+        .headers()
+        .get(IDEMPOTENCY_KEY_HEADER)
+        .filter(|_| applies)
+        .map(|v| v.to_str().map(str::to_string))
+    else {
+        return next.run(req).await;
+    };
+    let key = match key {
+        Ok(key) if valid_key(&key) => key,
+        _ => {
+            return ApiError::bad_request(
+                "InvalidIdempotencyKey",
+                format!(
+                    "Idempotency-Key must be 1-{} visible ASCII characters",
+                    MAX_KEY_LEN
+                ),
+            )
+            .into_response()
+        }
+    };
+
+    let (parts, body) = req.into_parts();
+    let limit = crate::validation::payload_size::get_max_payload_bytes() as usize;
+    let body = match to_bytes(body, limit).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return ApiError::new(
+                StatusCode::PAYLOAD_TOO_LARGE,
+                "PayloadTooLarge",
+                "Request body could not be read",
+            )
+            .into_response()
+        }
+    };
+    let path = parts.uri.path().to_string();
+    let hash = request_hash(
+        parts.method.as_str(),
+        &path,
+        parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .map(HeaderValue::as_bytes),
+        &body,
+    );
+
+    // Claim the key. Expired records are dropped first so keys can be reused.
+    if let Err(err) = sqlx::query(
+        "DELETE FROM idempotency_keys
+         WHERE idempotency_key = $1 AND request_path = $2
+           AND created_at < NOW() - make_interval(hours => $3::int)",
+    )
+    .bind(&key)
+    .bind(&path)
+    .bind(ttl_hours() as i32)
+    .execute(&state.db)
+    .await
+    {
+        return db_err("expire idempotency key", err);
+    }
+    let claimed = match sqlx::query(
+        "INSERT INTO idempotency_keys (idempotency_key, request_path, request_hash)
+         VALUES ($1, $2, $3)
+         ON CONFLICT (idempotency_key, request_path) DO NOTHING",
+    )
+    .bind(&key)
+    .bind(&path)
+    .bind(&hash)
+    .execute(&state.db)
+    .await
+    {
+        Ok(result) => result.rows_affected() == 1,
+        Err(err) => return db_err("claim idempotency key", err),
+    };
+
+    if !claimed {
+        let stored: Option<StoredResponse> = match sqlx::query_as(
+            "SELECT request_hash, status_code, content_type, response_body
+             FROM idempotency_keys
+             WHERE idempotency_key = $1 AND request_path = $2",
+        )
+        .bind(&key)
+        .bind(&path)
+        .fetch_optional(&state.db)
+        .await
+        {
+            Ok(row) => row,
+            Err(err) => return db_err("load idempotency key", err),
+        };
+
+        return match stored {
+            Some(stored) if stored.request_hash.trim() != hash => ApiError::new(
+                StatusCode::UNPROCESSABLE_ENTITY,
+                "IdempotencyKeyReused",
+                "Idempotency-Key was already used for a different request",
+            )
+            .into_response(),
+            Some(stored) if stored.status_code.is_none() => in_progress(),
+            Some(stored) => replay(stored),
+            // Released by a failed original request in the meantime.
+            None => in_progress(),
+        };
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+
+    if response.status().is_server_error() {
+        if let Err(err) = sqlx::query(
+            "DELETE FROM idempotency_keys WHERE idempotency_key = $1 AND request_path = $2",
+        )
+        .bind(&key)
+        .bind(&path)
+        .execute(&state.db)
+        .await
+        {
+            tracing::warn!(error = ?err, "failed to release idempotency key");
+        }
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            tracing::error!(error = ?err, "failed to buffer response for idempotency record");
+            return ApiError::internal("Failed to read response body").into_response();
+        }
+    };
+    let content_type = parts
+        .headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok());
+    if let Err(err) = sqlx::query(
+        "UPDATE idempotency_keys
+         SET status_code = $3, content_type = $4, response_body = $5, completed_at = NOW()
+         WHERE idempotency_key = $1 AND request_path = $2",
+    )
+    .bind(&key)
+    .bind(&path)
+    .bind(parts.status.as_u16() as i16)
+    .bind(content_type)
+    .bind(body.as_ref())
+    .execute(&state.db)
+    .await
+    {
+        tracing::warn!(error = ?err, "failed to store idempotent response");
+    }
+
+    Response::from_parts(parts, Body::from(body))
+}
+
+/// Hourly purge of expired idempotency records.
+pub fn spawn_cleanup_task(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(3600));
+        loop {
+            interval.tick().await;
+            let result = sqlx::query(
+                "DELETE FROM idempotency_keys
+                 WHERE created_at < NOW() - make_interval(hours => $1::int)",
+            )
+            .bind(ttl_hours() as i32)
+            .execute(&pool)
+            .await;
+            match result {
+                Ok(r) if r.rows_affected() > 0 => {
+                    tracing::debug!(
+                        removed = r.rows_affected(),
+                        "idempotency: expired keys purged"
+                    )
+                }
+                Ok(_) => {}
+                Err(err) => tracing::error!(error = ?err, "idempotency: cleanup failed"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn key_validation() {
+        assert!(valid_key("3f2b9c1e-publish-1"));
+        assert!(!valid_key(""));
+        assert!(!valid_key("has space"));
+        assert!(!valid_key(&"k".repeat(MAX_KEY_LEN + 1)));
+    }
+
+    #[test]
+    fn hash_depends_on_body_and_credentials() {
+        let base = request_hash("POST", "/api/contracts", Some(b"Bearer a"), b"{}");
+        assert_eq!(base.len(), 64);
+        assert_eq!(
+            base,
+            request_hash("POST", "/api/contracts", Some(b"Bearer a"), b"{}")
+        );
+        assert_ne!(
+            base,
+            request_hash("POST", "/api/contracts", Some(b"Bearer b"), b"{}")
+        );
+        assert_ne!(
+            base,
+            request_hash("POST", "/api/contracts", Some(b"Bearer a"), b"{ }")
+        );
+    }
+}
@@ -18,6 +18,7 @@ use uuid::Uuid;
 
 use crate::{
     error::{ApiError, ApiResult},
+    reverification,
     state::AppState,
 };
 
@@ -105,6 +106,9 @@ pub struct SecurityAdvisory {
     pub severity: IncidentSeverity,
     pub affected_versions: Option<String>,
     pub mitigation: Option<String>,
+    /// Build input (`soroban-sdk` or `rust`) that `affected_versions` refers
+    /// to; verified contracts built with an affected version go stale.
+    pub affected_package: Option<String>,
     pub published_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -153,6 +157,7 @@ pub struct PublishAdvisoryRequest {
     pub severity: IncidentSeverity,
     pub affected_versions: Option<String>,
     pub mitigation: Option<String>,
+    pub affected_package: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -563,11 +568,30 @@ pub async fn publish_advisory(
             "title must not be empty",
         ));
     }
+    if let Some(package) = &req.affected_package {
+        if !reverification::AFFECTED_PACKAGES.contains(&package.as_str()) {
+            return Err(ApiError::bad_request(
+                "InvalidAdvisory",
+                format!(
+                    "affected_package must be one of {}",
+                    reverification::AFFECTED_PACKAGES.join(", ")
+                ),
+            ));
+        }
+        let ranges = req.affected_versions.as_deref().unwrap_or_default();
+        if let Err(bad) = reverification::validate_ranges(ranges) {
+            return Err(ApiError::bad_request(
+                "InvalidAdvisory",
+                format!("affected_versions has an invalid range: '{}'", bad),
+            ));
+        }
+    }
 
     let advisory: SecurityAdvisory = sqlx::query_as(
         "INSERT INTO security_advisories \
-            (incident_id, title, summary, details, severity, affected_versions, mitigation) \
-         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+            (incident_id, title, summary, details, severity, affected_versions, mitigation, \
+             affected_package) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
          RETURNING *",
     )
     .bind(req.incident_id)
@@ -577,10 +601,15 @@ pub async fn publish_advisory(
     .bind(&req.severity)
     .bind(&req.affected_versions)
     .bind(&req.mitigation)
+    .bind(&req.affected_package)
     .fetch_one(&state.db)
     .await
     .map_err(|e| ApiError::internal(format!("insert advisory: {}", e)))?;
 
+    if advisory.affected_package.is_some() {
+        reverification::enqueue(&state.db, advisory.id).await;
+    }
+
     Ok((StatusCode::CREATED, Json(advisory)))
 }
 
@@ -0,0 +1,385 @@
+//! Static JSON index for mirrors and resolvers
+//!
+//!   GET /api/index/config.json              – index layout
+//!   GET /api/index/changes.json?since=…     – prefixes changed since a time
+//!   GET /api/index/:prefix.json             – contracts whose slug starts with `prefix`
+//!
+//! Modelled on the cargo sparse index: public contracts are sharded into
+//! files by the first two characters of their slug (one-character slugs live
+//! in a one-character file), and each file lists slug → deployments per
+//! network → versions → WASM hashes, with yanked versions flagged. Every
+//! file is plain, cacheable JSON with a content `ETag`, so a mirror can serve
+//! the index as static files and a resolver can work from one without a
+//! database.
+//!
+//! Mirrors stay current by polling `changes.json` with the `generated_at` of
+//! their previous poll and refetching the listed prefixes. Omitting `since`
+//! lists every prefix, which is also how a mirror bootstraps. A contract
+//! that is deleted or made private drops out of its file; its prefix shows
+//! up as changed.
+
+use std::collections::HashMap;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    contract_summary_handlers::{etag, not_modified},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+pub const INDEX_VERSION: u32 = 1;
+/// Characters of the slug that pick its file.
+pub const PREFIX_LEN: usize = 2;
+const CACHE_CONTROL: &str = "public, max-age=60";
+
+// ── Response types ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct IndexQuery {
+    /// For `changes.json`: only prefixes changed after this time, normally
+    /// the `generated_at` of the previous poll
+    pub since: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexConfig {
+    pub version: u32,
+    pub prefix_length: usize,
+    /// Template of the prefix files' URLs
+    pub files: String,
+    /// URL of the change feed
+    pub changes: String,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct IndexChange {
+    pub prefix: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexChanges {
+    /// Pass as `since` on the next poll
+    pub generated_at: DateTime<Utc>,
+    pub prefixes: Vec<IndexChange>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexFile {
+    pub prefix: String,
+    /// Ordered by slug
+    pub packages: Vec<IndexPackage>,
+}
+
+/// Contracts sharing a slug, one per network.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexPackage {
+    pub slug: String,
+    pub deployments: Vec<IndexDeployment>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexDeployment {
+    pub network: Network,
+    pub name: String,
+    /// On-chain contract ID
+    pub contract_id: String,
+    /// The current build
+    pub wasm_hash: String,
+    /// Oldest first
+    pub versions: Vec<IndexVersion>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct IndexVersion {
+    pub version: String,
+    pub wasm_hash: String,
+    pub published_at: DateTime<Utc>,
+    /// Kept for existing deployments; not to be picked for new ones
+    pub yanked: bool,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct DeploymentRow {
+    id: Uuid,
+    slug: String,
+    network: Network,
+    name: String,
+    contract_id: String,
+    wasm_hash: String,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct VersionRow {
+    contract_id: Uuid,
+    version: String,
+    wasm_hash: String,
+    created_at: DateTime<Utc>,
+    yanked: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Requested {
+    Config,
+    Changes,
+    Prefix(String),
+}
+
+fn requested_file(file: &str) -> Option<Requested> {
+    let name = file.strip_suffix(".json")?;
+    match name {
+        "config" => Some(Requested::Config),
+        "changes" => Some(Requested::Changes),
+        // Slugs start with a letter or digit; a hyphen can follow.
+        prefix
+            if (1..=PREFIX_LEN).contains(&prefix.chars().count())
+                && prefix.chars().enumerate().all(|(i, c)| {
+                    (c.is_alphanumeric() && !c.is_uppercase()) || (i > 0 && c == '-')
+                }) =>
+        {
+            Some(Requested::Prefix(prefix.to_string()))
+        }
+        _ => None,
+    }
+}
+
+/// Groups deployments, ordered by slug, into packages with their versions.
+fn packages(
+    deployments: Vec<DeploymentRow>,
+    mut versions: HashMap<Uuid, Vec<IndexVersion>>,
+) -> Vec<IndexPackage> {
+    let mut packages: Vec<IndexPackage> = Vec::new();
+    for row in deployments {
+        let deployment = IndexDeployment {
+            network: row.network,
+            name: row.name,
+            contract_id: row.contract_id,
+            wasm_hash: row.wasm_hash,
+            versions: versions.remove(&row.id).unwrap_or_default(),
+        };
+        match packages.last_mut() {
+            Some(package) if package.slug == row.slug => package.deployments.push(deployment),
+            _ => packages.push(IndexPackage {
+                slug: row.slug,
+                deployments: vec![deployment],
+            }),
+        }
+    }
+    packages
+}
+
+fn cached_json<T: Serialize>(headers: &HeaderMap, value: &T) -> ApiResult<Response> {
+    let body = serde_json::to_vec(value)
+        .map_err(|err| ApiError::internal(format!("serialize index file: {}", err)))?;
+    let etag = etag(&body);
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+    ];
+    if not_modified(headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, "application/json".to_string())],
+        body,
+    )
+        .into_response())
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/index/{file}",
+    params(
+        ("file" = String, Path, description = "`config.json`, `changes.json` or `{prefix}.json`"),
+        IndexQuery
+    ),
+    responses(
+        (status = 200, description = "The index file: IndexConfig, IndexChanges or IndexFile"),
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 400, description = "Not an index file name")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_index_file(
+    State(state): State<AppState>,
+    Path(file): Path<String>,
+    Query(query): Query<IndexQuery>,
+    tenant: Tenant,
+    headers: HeaderMap,
+) -> ApiResult<Response> {
+    let requested = requested_file(&file).ok_or_else(|| {
+        ApiError::bad_request(
+            "InvalidIndexFile",
+            format!(
+                "{} is not config.json, changes.json or a prefix of 1-{} lower-case characters",
+                file, PREFIX_LEN
+            ),
+        )
+    })?;
+
+    match requested {
+        Requested::Config => cached_json(
+            &headers,
+            &IndexConfig {
+                version: INDEX_VERSION,
+                prefix_length: PREFIX_LEN,
+                files: "/api/index/{prefix}.json".to_string(),
+                changes: "/api/index/changes.json".to_string(),
+            },
+        ),
+        Requested::Changes => {
+            // Taken before the query, so a change committed meanwhile is
+            // reported again on the next poll rather than missed.
+            let generated_at = Utc::now();
+            let prefixes: Vec<IndexChange> = sqlx::query_as(
+                "SELECT LEFT(LOWER(c.slug), $3) AS prefix,
+                        MAX(GREATEST(c.updated_at, v.latest)) AS updated_at
+                 FROM contracts c
+                 LEFT JOIN (SELECT contract_id, MAX(created_at) AS latest
+                            FROM contract_versions GROUP BY contract_id) v
+                        ON v.contract_id = c.id
+                 WHERE c.tenant_id = $1
+                 GROUP BY 1
+                 HAVING $2::TIMESTAMPTZ IS NULL OR MAX(GREATEST(c.updated_at, v.latest)) > $2
+                 ORDER BY 1",
+            )
+            .bind(tenant.id)
+            .bind(query.since)
+            .bind(PREFIX_LEN as i32)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("list changed index prefixes", err))?;
+            cached_json(
+                &headers,
+                &IndexChanges {
+                    generated_at,
+                    prefixes,
+                },
+            )
+        }
+        Requested::Prefix(prefix) => {
+            let deployments: Vec<DeploymentRow> = sqlx::query_as(
+                "SELECT id, slug, network, name, contract_id, wasm_hash
+                 FROM contracts
+                 WHERE tenant_id = $1 AND deleted_at IS NULL AND visibility = 'public'
+                   AND LEFT(LOWER(slug), $3) = $2
+                 ORDER BY slug, network",
+            )
+            .bind(tenant.id)
+            .bind(&prefix)
+            .bind(PREFIX_LEN as i32)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch index deployments", err))?;
+
+            let ids: Vec<Uuid> = deployments.iter().map(|row| row.id).collect();
+            let rows: Vec<VersionRow> = sqlx::query_as(
+                "SELECT contract_id, version, wasm_hash, created_at,
+                        yanked_at IS NOT NULL AS yanked
+                 FROM contract_versions
+                 WHERE contract_id = ANY($1)
+                 ORDER BY created_at, version",
+            )
+            .bind(&ids)
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch index versions", err))?;
+            let mut versions: HashMap<Uuid, Vec<IndexVersion>> = HashMap::new();
+            for row in rows {
+                versions
+                    .entry(row.contract_id)
+                    .or_default()
+                    .push(IndexVersion {
+                        version: row.version,
+                        wasm_hash: row.wasm_hash,
+                        published_at: row.created_at,
+                        yanked: row.yanked,
+                    });
+            }
+
+            cached_json(
+                &headers,
+                &IndexFile {
+                    packages: packages(deployments, versions),
+                    prefix,
+                },
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognises_index_file_names() {
+        assert_eq!(requested_file("config.json"), Some(Requested::Config));
+        assert_eq!(requested_file("changes.json"), Some(Requested::Changes));
+        assert_eq!(
+            requested_file("so.json"),
+            Some(Requested::Prefix("so".to_string()))
+        );
+        assert_eq!(
+            requested_file("x.json"),
+            Some(Requested::Prefix("x".to_string()))
+        );
+        assert_eq!(requested_file("sor.json"), None);
+        assert_eq!(requested_file("So.json"), None);
+        assert_eq!(
+            requested_file("a-.json"),
+            Some(Requested::Prefix("a-".to_string()))
+        );
+        assert_eq!(requested_file("-a.json"), None);
+        assert_eq!(requested_file("so"), None);
+    }
+
+    #[test]
+    fn groups_deployments_by_slug() {
+        let row = |slug: &str, network| DeploymentRow {
+            id: Uuid::new_v4(),
+            slug: slug.to_string(),
+            network,
+            name: slug.to_string(),
+            contract_id: format!("C{}", slug.to_uppercase()),
+            wasm_hash: "ab".repeat(32),
+        };
+        let rows = vec![
+            row("swap", Network::Mainnet),
+            row("swap", Network::Testnet),
+            row("swapper", Network::Testnet),
+        ];
+        let mut versions = HashMap::new();
+        versions.insert(
+            rows[0].id,
+            vec![IndexVersion {
+                version: "1.0.0".to_string(),
+                wasm_hash: "ab".repeat(32),
+                published_at: Utc::now(),
+                yanked: false,
+            }],
+        );
+
+        let packages = packages(rows, versions);
+        assert_eq!(packages.len(), 2);
+        assert_eq!(packages[0].slug, "swap");
+        assert_eq!(packages[0].deployments.len(), 2);
+        assert_eq!(packages[0].deployments[0].versions.len(), 1);
+        assert!(packages[0].deployments[1].versions.is_empty());
+        assert_eq!(packages[1].slug, "swapper");
+    }
+}
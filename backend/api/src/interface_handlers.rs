@@ -0,0 +1,379 @@
+//! Interface catalog
+//!
+//!   GET    /api/interfaces                – known interfaces
+//!   GET    /api/interfaces/:id            – one interface
+//!   GET    /api/contracts/:id/interfaces  – interfaces a contract conforms to
+//!   POST   /api/admin/interfaces          – add an interface
+//!   PUT    /api/admin/interfaces/:id      – replace its definition
+//!   DELETE /api/admin/interfaces/:id      – remove it
+//!
+//! Each interface is a named, versioned set of function signatures. Adding
+//! or changing one re-matches every contract's newest ABI against it; see
+//! [`crate::interfaces`] for what counts as conforming.
+
+use std::collections::HashSet;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::types::Json as SqlJson;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    interfaces::{self, FunctionSignature},
+    state::AppState,
+};
+
+const MAX_FUNCTIONS: usize = 100;
+/// Soroban limits function names to 32 characters.
+const MAX_FUNCTION_NAME_LEN: usize = 32;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct InterfaceRequest {
+    /// Catalog name, e.g. `sep-41`
+    pub name: String,
+    /// Version of the standard, e.g. `1.0`
+    pub version: String,
+    pub description: Option<String>,
+    /// Functions a conforming contract must expose
+    pub functions: Vec<FunctionSignature>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct InterfaceRow {
+    id: Uuid,
+    name: String,
+    version: String,
+    description: Option<String>,
+    functions: SqlJson<Vec<FunctionSignature>>,
+    contract_count: i64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ContractInterface {
+    pub id: Uuid,
+    pub name: String,
+    pub version: String,
+    pub description: Option<String>,
+    pub functions: Vec<FunctionSignature>,
+    /// Contracts whose newest ABI conforms
+    pub contract_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<InterfaceRow> for ContractInterface {
+    fn from(row: InterfaceRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            version: row.version,
+            description: row.description,
+            functions: row.functions.0,
+            contract_count: row.contract_count,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct InterfaceMatch {
+    pub interface_id: Uuid,
+    pub name: String,
+    pub version: String,
+    /// Contract version whose ABI was matched
+    pub contract_version: String,
+    pub matched_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+const SELECT_INTERFACE: &str = "SELECT i.*,
+        (SELECT COUNT(*) FROM contract_interface_matches m WHERE m.interface_id = i.id)
+            AS contract_count
+     FROM contract_interfaces i";
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidInterface", message)
+}
+
+fn interface_not_found(id: Uuid) -> ApiError {
+    ApiError::not_found("InterfaceNotFound", format!("No interface with id {}", id))
+}
+
+/// Checks the request and returns its functions with normalized types.
+fn validate_request(req: &InterfaceRequest) -> Result<Vec<FunctionSignature>, ApiError> {
+    let name_ok = !req.name.is_empty()
+        && req.name.len() <= 64
+        && req
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-_.".contains(c));
+    if !name_ok {
+        return Err(invalid(
+            "name must be 1-64 lowercase letters, digits, '-', '_' or '.'",
+        ));
+    }
+    if req.version.is_empty() || req.version.len() > 32 || req.version.contains(char::is_whitespace)
+    {
+        return Err(invalid("version must be 1-32 characters without spaces"));
+    }
+    if req.functions.is_empty() || req.functions.len() > MAX_FUNCTIONS {
+        return Err(invalid(format!(
+            "an interface needs between 1 and {} functions",
+            MAX_FUNCTIONS
+        )));
+    }
+
+    let mut seen = HashSet::new();
+    let mut functions = Vec::with_capacity(req.functions.len());
+    for function in &req.functions {
+        let function = function.normalized();
+        let name_ok = !function.name.is_empty()
+            && function.name.len() <= MAX_FUNCTION_NAME_LEN
+            && function
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !name_ok {
+            return Err(invalid(format!(
+                "'{}' is not a valid function name",
+                function.name
+            )));
+        }
+        if !seen.insert(function.name.clone()) {
+            return Err(invalid(format!(
+                "function '{}' is listed twice",
+                function.name
+            )));
+        }
+        if function
+            .params
+            .iter()
+            .chain(std::iter::once(&function.returns))
+            .any(|ty| ty.is_empty())
+        {
+            return Err(invalid(format!(
+                "function '{}' has an empty type",
+                function.name
+            )));
+        }
+        functions.push(function);
+    }
+    Ok(functions)
+}
+
+async fn fetch_interface(state: &AppState, id: Uuid) -> ApiResult<ContractInterface> {
+    let row: InterfaceRow = sqlx::query_as(&format!("{} WHERE i.id = $1", SELECT_INTERFACE))
+        .bind(id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch interface", err))?
+        .ok_or_else(|| interface_not_found(id))?;
+    Ok(row.into())
+}
+
+fn conflict(req: &InterfaceRequest, err: sqlx::Error, context: &str) -> ApiError {
+    match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "InterfaceExists",
+            format!(
+                "Interface '{}' version '{}' is already in the catalog",
+                req.name, req.version
+            ),
+        ),
+        _ => db_internal_error(context, err),
+    }
+}
+
+async fn rematch(state: &AppState, id: Uuid, functions: &[FunctionSignature]) -> ApiResult<()> {
+    let matched = interfaces::rematch_interface(&state.db, id, functions)
+        .await
+        .map_err(|err| db_internal_error("match interface", err))?;
+    tracing::info!(interface = %id, contracts = matched, "interface matched against ABIs");
+    Ok(())
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/interfaces",
+    responses(
+        (status = 200, description = "Known interfaces, by name and version", body = [ContractInterface])
+    ),
+    tag = "Contracts"
+)]
+pub async fn list_interfaces(
+    State(state): State<AppState>,
+) -> ApiResult<Json<Vec<ContractInterface>>> {
+    let rows: Vec<InterfaceRow> =
+        sqlx::query_as(&format!("{} ORDER BY i.name, i.version", SELECT_INTERFACE))
+            .fetch_all(&state.db)
+            .await
+            .map_err(|err| db_internal_error("list interfaces", err))?;
+    Ok(Json(rows.into_iter().map(Into::into).collect()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/interfaces/{id}",
+    params(("id" = Uuid, Path, description = "Interface ID")),
+    responses(
+        (status = 200, description = "The interface", body = ContractInterface),
+        (status = 404, description = "No interface with that ID")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_interface(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ContractInterface>> {
+    fetch_interface(&state, id).await.map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/interfaces",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Interfaces the contract's newest ABI conforms to", body = [InterfaceMatch]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn list_contract_interfaces(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<InterfaceMatch>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let matches = sqlx::query_as(
+        "SELECT m.interface_id, i.name, i.version, m.contract_version, m.matched_at
+         FROM contract_interface_matches m
+         JOIN contract_interfaces i ON i.id = m.interface_id
+         WHERE m.contract_id = $1
+         ORDER BY i.name, i.version",
+    )
+    .bind(contract_uuid)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list contract interfaces", err))?;
+    Ok(Json(matches))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/interfaces",
+    request_body = InterfaceRequest,
+    responses(
+        (status = 201, description = "Interface added and matched", body = ContractInterface),
+        (status = 400, description = "Invalid interface definition"),
+        (status = 409, description = "That name and version are already in the catalog")
+    ),
+    tag = "Contracts"
+)]
+pub async fn create_interface(
+    State(state): State<AppState>,
+    Json(req): Json<InterfaceRequest>,
+) -> ApiResult<(StatusCode, Json<ContractInterface>)> {
+    let functions = validate_request(&req)?;
+
+    let id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_interfaces (name, version, description, functions)
+         VALUES ($1, $2, $3, $4)
+         RETURNING id",
+    )
+    .bind(&req.name)
+    .bind(&req.version)
+    .bind(&req.description)
+    .bind(SqlJson(&functions))
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| conflict(&req, err, "create interface"))?;
+
+    tracing::info!(interface = %req.name, version = %req.version, "interface added to catalog");
+    rematch(&state, id, &functions).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(fetch_interface(&state, id).await?),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/interfaces/{id}",
+    params(("id" = Uuid, Path, description = "Interface ID")),
+    request_body = InterfaceRequest,
+    responses(
+        (status = 200, description = "Interface replaced and re-matched", body = ContractInterface),
+        (status = 400, description = "Invalid interface definition"),
+        (status = 404, description = "No interface with that ID"),
+        (status = 409, description = "That name and version are already in the catalog")
+    ),
+    tag = "Contracts"
+)]
+pub async fn update_interface(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<InterfaceRequest>,
+) -> ApiResult<Json<ContractInterface>> {
+    let functions = validate_request(&req)?;
+
+    let updated = sqlx::query(
+        "UPDATE contract_interfaces
+         SET name = $2, version = $3, description = $4, functions = $5, updated_at = NOW()
+         WHERE id = $1",
+    )
+    .bind(id)
+    .bind(&req.name)
+    .bind(&req.version)
+    .bind(&req.description)
+    .bind(SqlJson(&functions))
+    .execute(&state.db)
+    .await
+    .map_err(|err| conflict(&req, err, "update interface"))?
+    .rows_affected();
+    if updated == 0 {
+        return Err(interface_not_found(id));
+    }
+
+    rematch(&state, id, &functions).await?;
+    Ok(Json(fetch_interface(&state, id).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/interfaces/{id}",
+    params(("id" = Uuid, Path, description = "Interface ID")),
+    responses(
+        (status = 204, description = "Interface removed with its matches"),
+        (status = 404, description = "No interface with that ID")
+    ),
+    tag = "Contracts"
+)]
+pub async fn delete_interface(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<StatusCode> {
+    let deleted = sqlx::query("DELETE FROM contract_interfaces WHERE id = $1")
+        .bind(id)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("delete interface", err))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(interface_not_found(id));
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
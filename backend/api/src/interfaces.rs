@@ -0,0 +1,205 @@
+//! Interface conformance
+//!
+//! Admins describe known contract interfaces (SEP-41 tokens, NFT standards,
+//! …) in the catalog served by [`crate::interface_handlers`], each as a set
+//! of function signatures. A contract conforms to an interface when its
+//! newest ABI has every one of those functions with the same parameter and
+//! return types; extra functions are fine and parameter names are ignored.
+//!
+//! Matches are recorded in `contract_interface_matches` when a version's ABI
+//! is published, and recomputed across all contracts when a catalog entry is
+//! added or changed.
+
+use std::collections::HashSet;
+
+use contract_abi::{parse_json_spec, SorobanType};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::{types::Json, PgPool};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct FunctionSignature {
+    pub name: String,
+    /// Parameter types in order, e.g. `["Address", "i128"]`
+    #[serde(default)]
+    pub params: Vec<String>,
+    /// Return type; `void` when omitted
+    #[serde(default = "void")]
+    pub returns: String,
+}
+
+fn void() -> String {
+    "void".to_string()
+}
+
+/// Spells a type the way ABI signatures are compared (`address` → `Address`,
+/// `option<u32>` → `Option<u32>`).
+pub fn normalize_type(type_str: &str) -> String {
+    SorobanType::from_type_string(type_str).display_name()
+}
+
+impl FunctionSignature {
+    pub fn normalized(&self) -> Self {
+        Self {
+            name: self.name.trim().to_string(),
+            params: self.params.iter().map(|p| normalize_type(p)).collect(),
+            returns: normalize_type(&self.returns),
+        }
+    }
+}
+
+/// The signatures of every function in a stored ABI, or `None` when the ABI
+/// cannot be parsed.
+pub fn abi_signatures(abi: &Value) -> Option<HashSet<FunctionSignature>> {
+    let abi = parse_json_spec(&abi.to_string(), "contract").ok()?;
+    Some(
+        abi.functions
+            .iter()
+            .map(|function| FunctionSignature {
+                name: function.name.clone(),
+                params: function
+                    .params
+                    .iter()
+                    .map(|param| param.param_type.display_name())
+                    .collect(),
+                returns: function.return_type.display_name(),
+            })
+            .collect(),
+    )
+}
+
+pub fn conforms(abi: &HashSet<FunctionSignature>, interface: &[FunctionSignature]) -> bool {
+    interface.iter().all(|function| abi.contains(function))
+}
+
+// ── Matching ──────────────────────────────────────────────────────────────────
+
+/// Records which catalog interfaces a newly published ABI conforms to,
+/// replacing the contract's previous matches.
+pub(crate) async fn match_contract_abi(
+    pool: &PgPool,
+    contract_id: Uuid,
+    version: &str,
+    abi: &Value,
+) -> Result<usize, sqlx::Error> {
+    let catalog: Vec<(Uuid, Json<Vec<FunctionSignature>>)> =
+        sqlx::query_as("SELECT id, functions FROM contract_interfaces")
+            .fetch_all(pool)
+            .await?;
+    let signatures = abi_signatures(abi).unwrap_or_default();
+    let matched: Vec<Uuid> = catalog
+        .into_iter()
+        .filter(|(_, functions)| conforms(&signatures, functions))
+        .map(|(id, _)| id)
+        .collect();
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM contract_interface_matches WHERE contract_id = $1")
+        .bind(contract_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO contract_interface_matches (contract_id, interface_id, contract_version)
+         SELECT $1, interface_id, $3 FROM UNNEST($2::UUID[]) AS interface_id",
+    )
+    .bind(contract_id)
+    .bind(&matched)
+    .bind(version)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(matched.len())
+}
+
+/// Recomputes which contracts conform to one catalog interface, from each
+/// contract's newest ABI.
+pub(crate) async fn rematch_interface(
+    pool: &PgPool,
+    interface_id: Uuid,
+    functions: &[FunctionSignature],
+) -> Result<usize, sqlx::Error> {
+    let abis: Vec<(Uuid, String, Value)> = sqlx::query_as(
+        "SELECT DISTINCT ON (contract_id) contract_id, version, abi
+         FROM contract_abis
+         ORDER BY contract_id, created_at DESC",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let (contract_ids, versions): (Vec<Uuid>, Vec<String>) = abis
+        .into_iter()
+        .filter(|(_, _, abi)| {
+            abi_signatures(abi).is_some_and(|signatures| conforms(&signatures, functions))
+        })
+        .map(|(contract_id, version, _)| (contract_id, version))
+        .unzip();
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM contract_interface_matches WHERE interface_id = $1")
+        .bind(interface_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO contract_interface_matches (contract_id, interface_id, contract_version)
+         SELECT contract_id, $1, version FROM UNNEST($2::UUID[], $3::TEXT[]) AS m(contract_id, version)",
+    )
+    .bind(interface_id)
+    .bind(&contract_ids)
+    .bind(&versions)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(contract_ids.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn signature(name: &str, params: &[&str], returns: &str) -> FunctionSignature {
+        FunctionSignature {
+            name: name.to_string(),
+            params: params.iter().map(|p| p.to_string()).collect(),
+            returns: returns.to_string(),
+        }
+        .normalized()
+    }
+
+    #[test]
+    fn abis_conform_by_name_and_types() {
+        let abi = json!([
+            {
+                "type": "function",
+                "name": "balance",
+                "inputs": [{ "name": "id", "value": { "type": "address" } }],
+                "outputs": [{ "type": "i128" }]
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "inputs": [
+                    { "name": "from", "value": { "type": "address" } },
+                    { "name": "to", "value": { "type": "address" } },
+                    { "name": "amount", "value": { "type": "i128" } }
+                ],
+                "outputs": []
+            },
+            { "type": "function", "name": "mint_extra", "inputs": [], "outputs": [] }
+        ]);
+        let signatures = abi_signatures(&abi).unwrap();
+
+        let token = [
+            signature("balance", &["address"], "i128"),
+            signature("transfer", &["Address", "Address", "i128"], "void"),
+        ];
+        assert!(conforms(&signatures, &token));
+
+        let wrong_types = [signature("balance", &["address"], "u64")];
+        assert!(!conforms(&signatures, &wrong_types));
+
+        let missing = [signature("decimals", &[], "u32")];
+        assert!(!conforms(&signatures, &missing));
+    }
+}
@@ -0,0 +1,237 @@
+//! Persistent background job queue backed by the `background_jobs` table.
+//!
+//! Producers call [`enqueue`] with a job kind and JSON payload; worker tasks
+//! spawned by [`spawn_job_workers`] claim runnable jobs with
+//! `FOR UPDATE SKIP LOCKED`, so any number of API replicas can share the
+//! queue without double-processing. Failed attempts are retried with
+//! exponential backoff and dead-lettered once `max_attempts` is reached.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Default number of attempts before a job is dead-lettered.
+pub const DEFAULT_MAX_ATTEMPTS: i32 = 5;
+
+/// Jobs stuck in `running` longer than this (e.g. the worker crashed) are
+/// handed back to the queue.
+const STALE_LOCK_SECS: i64 = 600;
+
+const IDLE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const MAX_BACKOFF_SECS: i64 = 3600;
+
+/// A job claimed by a worker.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct Job {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    /// Attempt number of the current run (1-based).
+    pub attempts: i32,
+    pub max_attempts: i32,
+}
+
+/// Processes jobs of one kind. Returning `Err` schedules a retry.
+#[async_trait]
+pub trait JobHandler: Send + Sync {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()>;
+}
+
+/// Maps job kinds to their handlers.
+#[derive(Default, Clone)]
+pub struct JobRegistry {
+    handlers: HashMap<String, Arc<dyn JobHandler>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(mut self, kind: &str, handler: impl JobHandler + 'static) -> Self {
+        self.handlers.insert(kind.to_string(), Arc::new(handler));
+        self
+    }
+
+    fn get(&self, kind: &str) -> Option<Arc<dyn JobHandler>> {
+        self.handlers.get(kind).cloned()
+    }
+}
+
+/// Queue a job to run as soon as a worker is free.
+pub async fn enqueue(
+    pool: &PgPool,
+    kind: &str,
+    payload: serde_json::Value,
+) -> Result<Uuid, sqlx::Error> {
+    enqueue_at(
+        pool,
+        kind,
+        payload,
+        chrono::Utc::now(),
+        DEFAULT_MAX_ATTEMPTS,
+    )
+    .await
+}
+
+/// Queue a job to run no earlier than `run_at`. Pass a transaction to queue
+/// it together with other writes.
+pub async fn enqueue_at(
+    executor: impl sqlx::PgExecutor<'_>,
+    kind: &str,
+    payload: serde_json::Value,
+    run_at: chrono::DateTime<chrono::Utc>,
+    max_attempts: i32,
+) -> Result<Uuid, sqlx::Error> {
+    sqlx::query_scalar(
+        "INSERT INTO background_jobs (kind, payload, run_at, max_attempts) \
+         VALUES ($1, $2, $3, $4) RETURNING id",
+    )
+    .bind(kind)
+    .bind(payload)
+    .bind(run_at)
+    .bind(max_attempts)
+    .fetch_one(executor)
+    .await
+}
+
+/// Spawn `concurrency` worker tasks polling the queue.
+pub fn spawn_job_workers(pool: PgPool, registry: JobRegistry, concurrency: usize) {
+    let registry = Arc::new(registry);
+    for n in 0..concurrency.max(1) {
+        let pool = pool.clone();
+        let registry = registry.clone();
+        let worker_id = format!("{}-{}", std::process::id(), n);
+        tokio::spawn(async move {
+            tracing::info!(worker = %worker_id, "job queue: worker started");
+            loop {
+                match run_next(&pool, &registry, &worker_id).await {
+                    Ok(true) => continue,
+                    Ok(false) => tokio::time::sleep(IDLE_POLL_INTERVAL).await,
+                    Err(err) => {
+                        tracing::error!(worker = %worker_id, error = ?err, "job queue: poll failed");
+                        tokio::time::sleep(IDLE_POLL_INTERVAL).await;
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// Claim and run a single job. Returns `false` when the queue was empty.
+async fn run_next(
+    pool: &PgPool,
+    registry: &JobRegistry,
+    worker_id: &str,
+) -> Result<bool, sqlx::Error> {
+    release_stale_locks(pool).await?;
+
+    let Some(job) = claim(pool, worker_id).await? else {
+        return Ok(false);
+    };
+
+    let outcome = match registry.get(&job.kind) {
+        Some(handler) => handler.handle(pool, &job).await,
+        None => Err(anyhow::anyhow!(
+            "no handler registered for job kind '{}'",
+            job.kind
+        )),
+    };
+
+    match outcome {
+        Ok(()) => {
+            sqlx::query(
+                "UPDATE background_jobs SET status = 'succeeded', completed_at = NOW(), \
+                 locked_at = NULL, locked_by = NULL, updated_at = NOW() WHERE id = $1",
+            )
+            .bind(job.id)
+            .execute(pool)
+            .await?;
+        }
+        Err(err) => {
+            let dead = job.attempts >= job.max_attempts;
+            tracing::warn!(
+                job_id = %job.id,
+                kind = %job.kind,
+                attempt = job.attempts,
+                dead_lettered = dead,
+                error = %err,
+                "job queue: job failed"
+            );
+            sqlx::query(
+                "UPDATE background_jobs SET status = $2, last_error = $3, \
+                 run_at = NOW() + make_interval(secs => $4), \
+                 locked_at = NULL, locked_by = NULL, updated_at = NOW() WHERE id = $1",
+            )
+            .bind(job.id)
+            .bind(if dead { "dead" } else { "failed" })
+            .bind(format!("{:#}", err))
+            .bind(backoff_secs(job.attempts) as f64)
+            .execute(pool)
+            .await?;
+        }
+    }
+    Ok(true)
+}
+
+async fn claim(pool: &PgPool, worker_id: &str) -> Result<Option<Job>, sqlx::Error> {
+    sqlx::query_as(
+        r#"
+        UPDATE background_jobs
+        SET status = 'running', attempts = attempts + 1,
+            locked_at = NOW(), locked_by = $1, updated_at = NOW()
+        WHERE id = (
+            SELECT id FROM background_jobs
+            WHERE status IN ('queued', 'failed') AND run_at <= NOW()
+            ORDER BY run_at
+            FOR UPDATE SKIP LOCKED
+            LIMIT 1
+        )
+        RETURNING id, kind, payload, attempts, max_attempts
+        "#,
+    )
+    .bind(worker_id)
+    .fetch_optional(pool)
+    .await
+}
+
+async fn release_stale_locks(pool: &PgPool) -> Result<(), sqlx::Error> {
+    let released = sqlx::query(
+        "UPDATE background_jobs \
+         SET status = CASE WHEN attempts >= max_attempts THEN 'dead' ELSE 'failed' END, \
+         locked_at = NULL, locked_by = NULL, last_error = 'worker lock expired', \
+         updated_at = NOW() \
+         WHERE status = 'running' AND locked_at < NOW() - make_interval(secs => $1)",
+    )
+    .bind(STALE_LOCK_SECS as f64)
+    .execute(pool)
+    .await?
+    .rows_affected();
+    if released > 0 {
+        tracing::warn!(released, "job queue: released stale job locks");
+    }
+    Ok(())
+}
+
+/// Exponential backoff: 2^attempt seconds, capped at one hour.
+fn backoff_secs(attempt: i32) -> i64 {
+    2i64.saturating_pow(attempt.clamp(0, 30) as u32)
+        .min(MAX_BACKOFF_SECS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_grows_and_caps() {
+        assert_eq!(backoff_secs(1), 2);
+        assert_eq!(backoff_secs(3), 8);
+        assert_eq!(backoff_secs(12), MAX_BACKOFF_SECS);
+        assert_eq!(backoff_secs(i32::MAX), MAX_BACKOFF_SECS);
+    }
+}
@@ -0,0 +1,185 @@
+//! Background job queue admin handlers
+//!
+//! Operational visibility into the persistent job queue (see `job_queue`):
+//!
+//!   GET    /api/admin/jobs              – list jobs (filter by `status`, `kind`)
+//!   POST   /api/admin/jobs/:id/retry    – requeue a failed or dead-lettered job
+//!
+//! Both endpoints sit behind `auth::require_admin` via `admin_routes()`.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+const JOB_STATUSES: &[&str] = &["queued", "running", "failed", "succeeded", "dead"];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ListJobsQuery {
+    /// One of `queued`, `running`, `failed`, `succeeded`, `dead`.
+    /// Defaults to everything that has not succeeded.
+    pub status: Option<String>,
+    pub kind: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Serialize, FromRow, utoipa::ToSchema)]
+pub struct BackgroundJob {
+    pub id: Uuid,
+    pub kind: String,
+    pub payload: serde_json::Value,
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    pub locked_by: Option<String>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, FromRow, utoipa::ToSchema)]
+pub struct JobStatusCount {
+    pub status: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct JobListResponse {
+    pub counts: Vec<JobStatusCount>,
+    pub jobs: Vec<BackgroundJob>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// List background jobs, newest first, with per-status totals.
+#[utoipa::path(
+    get,
+    path = "/api/admin/jobs",
+    params(
+        ("status" = Option<String>, Query, description = "queued | running | failed | succeeded | dead"),
+        ("kind" = Option<String>, Query, description = "Filter by job kind"),
+        ("limit" = Option<i64>, Query, description = "Max rows (default 50, max 500)")
+    ),
+    responses(
+        (status = 200, description = "Jobs and status counts", body = JobListResponse),
+        (status = 400, description = "Unknown status")
+    ),
+    tag = "Admin"
+)]
+pub async fn list_jobs(
+    State(state): State<AppState>,
+    Query(query): Query<ListJobsQuery>,
+) -> ApiResult<Json<JobListResponse>> {
+    if let Some(status) = query.status.as_deref() {
+        if !JOB_STATUSES.contains(&status) {
+            return Err(ApiError::bad_request(
+                "InvalidJobStatus",
+                format!("status must be one of: {}", JOB_STATUSES.join(", ")),
+            ));
+        }
+    }
+    let limit = query.limit.unwrap_or(50).clamp(1, 500);
+
+    let jobs: Vec<BackgroundJob> = sqlx::query_as(
+        r#"
+        SELECT id, kind, payload, status, attempts, max_attempts, run_at, locked_by,
+               last_error, created_at, updated_at, completed_at
+        FROM background_jobs
+        WHERE (($1::TEXT IS NULL AND status <> 'succeeded') OR status = $1)
+          AND ($2::TEXT IS NULL OR kind = $2)
+        ORDER BY created_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(query.status.as_deref())
+    .bind(query.kind.as_deref())
+    .bind(limit)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list background jobs", err))?;
+
+    let counts: Vec<JobStatusCount> = sqlx::query_as(
+        "SELECT status, COUNT(*) AS count FROM background_jobs \
+         WHERE ($1::TEXT IS NULL OR kind = $1) GROUP BY status ORDER BY status",
+    )
+    .bind(query.kind.as_deref())
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("count background jobs", err))?;
+
+    Ok(Json(JobListResponse { counts, jobs }))
+}
+
+/// Requeue a failed or dead-lettered job with a fresh attempt budget.
+#[utoipa::path(
+    post,
+    path = "/api/admin/jobs/{id}/retry",
+    params(("id" = Uuid, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Job requeued", body = BackgroundJob),
+        (status = 404, description = "Job not found"),
+        (status = 409, description = "Job is not in a retryable state")
+    ),
+    tag = "Admin"
+)]
+pub async fn retry_job(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<BackgroundJob>> {
+    let job: Option<BackgroundJob> = sqlx::query_as(
+        r#"
+        UPDATE background_jobs
+        SET status = 'queued', attempts = 0, run_at = NOW(), updated_at = NOW()
+        WHERE id = $1 AND status IN ('failed', 'dead')
+        RETURNING id, kind, payload, status, attempts, max_attempts, run_at, locked_by,
+                  last_error, created_at, updated_at, completed_at
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("retry background job", err))?;
+
+    if let Some(job) = job {
+        return Ok(Json(job));
+    }
+
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM background_jobs WHERE id = $1)")
+            .bind(id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_err("look up background job", err))?;
+
+    if exists {
+        Err(ApiError::conflict(
+            "JobNotRetryable",
+            "Only failed or dead-lettered jobs can be retried",
+        ))
+    } else {
+        Err(ApiError::not_found(
+            "JobNotFound",
+            format!("No background job found with ID: {}", id),
+        ))
+    }
+}
@@ -1,18 +1,162 @@
+mod ab_test_handlers;
+mod account_recovery_handlers;
+mod accounting_report_handlers;
+mod action_proposal_handlers;
+mod activity_feed_handlers;
+mod activity_feed_routes;
+mod address_lookup_handlers;
+mod aggregation;
+mod alert_handlers;
+mod alerts;
+mod analytics;
+mod analytics_handlers;
+mod anchor;
+mod anchor_handlers;
+mod anomalies;
+mod anomaly_handlers;
+pub mod app;
+mod artifact_gc;
+mod artifact_gc_handlers;
+mod audit_report_handlers;
 pub mod auth;
+mod auth_handlers;
 pub mod backup_handlers;
 pub mod backup_routes;
+mod badge_handlers;
+mod batch_proposal_handlers;
+mod batch_verify_handlers;
+mod benchmark_handlers;
+mod breaking_changes;
+mod bug_bounty_handlers;
+mod build_meta;
 pub mod cache;
+mod canary_handlers;
+mod category_handlers;
+mod change_feed_handlers;
+mod claim_handlers;
+mod cli_release_handlers;
+mod compatibility_testing_handlers;
+mod compliance_handlers;
+mod compression;
+mod concurrency;
+mod consumer_handlers;
+mod consumers;
 pub mod contract_events;
+mod contract_group_handlers;
+mod contract_permission_handlers;
+mod contract_permissions;
+mod contract_summary_handlers;
+mod contributor_handlers;
+mod cost_regressions;
+mod custom_metrics_handlers;
+mod db_monitoring;
+pub mod db_pool;
+mod defi;
+mod defi_handlers;
+mod dependency;
+mod dependency_handlers;
+mod deprecation_handlers;
 pub mod disaster_recovery_models;
+mod disclosure_handlers;
+mod email_verification_handlers;
 pub mod error;
+mod event_ingest_handlers;
+mod event_query_handlers;
+mod events;
+mod fuzz_campaign_handlers;
+mod graphql;
+mod handlers;
+mod health;
 pub mod health_monitor;
+#[cfg(test)]
+mod health_tests;
+mod http_security;
+mod idempotency;
+mod incident_handlers;
+mod incident_routes;
+mod index_handlers;
+mod interface_handlers;
+mod interfaces;
+mod interoperability;
+mod interoperability_handlers;
+mod job_queue;
+mod job_queue_handlers;
+mod mailer;
 pub mod metrics;
+mod metrics_handler;
+pub mod migration_handlers;
+mod models;
+mod multisig_handlers;
+mod multisig_notifications;
+mod multisig_routes;
+mod nft_handlers;
+mod nft_index;
 pub mod notification_handlers;
 pub mod notification_routes;
+mod onchain_verification;
+#[cfg(feature = "openapi")]
+mod openapi;
+mod oracle_handlers;
+mod oracles;
+mod org_handlers;
+mod org_network_policy_handlers;
+mod org_policy_handlers;
+mod patch_handlers;
+mod patch_rollout;
+mod patch_rollout_handlers;
+mod performance_handlers;
 pub mod post_incident_handlers;
 pub mod post_incident_routes;
+mod probe_handlers;
+mod promotion_handlers;
+mod publisher_key_handlers;
+mod publisher_profile_handlers;
+mod publisher_stats_handlers;
+mod publisher_usage_handlers;
+mod quality;
+mod quality_handlers;
+mod rate_limit;
+mod recommendation_handlers;
+mod registry_dump_handlers;
+mod release_notes_handlers;
+mod release_notes_routes;
 pub mod request_tracing;
+mod resource_handlers;
 pub mod resource_tracking;
+mod reverification;
+mod routes;
+mod sdk_matrix_handlers;
+pub mod security_log;
 pub mod security_scan_handlers;
+pub mod signing_handlers;
+mod similarity_handlers;
+mod simulation;
+mod simulation_handlers;
+mod soft_delete;
+mod soft_delete_handlers;
+mod source_bundle_handlers;
 pub mod state;
+mod status_badge_handlers;
+mod streaming;
 pub mod subscription_handlers;
+mod telemetry_handlers;
+mod template_handlers;
+mod tenant;
+mod tenant_handlers;
+mod test_run_handlers;
+mod tlog_handlers;
+pub mod tls;
+mod token_meta;
+mod toolchain_handlers;
+mod type_safety;
+mod undo;
+mod undo_handlers;
+mod validation;
+mod verification_cache;
+mod verification_metrics_handlers;
+mod version_rollout_handlers;
+mod version_yank_handlers;
+mod wasm_handlers;
+mod wasm_links;
+pub mod web_auth;
+mod websocket;
@@ -0,0 +1,67 @@
+//! Outgoing email
+//!
+//! The registry does not speak SMTP itself: messages are POSTed as JSON
+//! (`{ "from", "to", "subject", "text" }`) to the HTTP relay at
+//! `MAIL_RELAY_URL`, authenticated with `MAIL_RELAY_TOKEN` when set. Most
+//! transactional mail services accept this shape directly or through a thin
+//! adapter. Without a relay, features that need email report it as
+//! unavailable.
+
+use std::time::Duration;
+
+use serde::Serialize;
+
+const RELAY_TIMEOUT: Duration = Duration::from_secs(10);
+const DEFAULT_FROM: &str = "Soroban Registry <no-reply@soroban-registry.local>";
+
+#[derive(Debug, Serialize)]
+pub struct Email<'a> {
+    pub to: &'a str,
+    pub subject: &'a str,
+    pub text: &'a str,
+}
+
+#[derive(Serialize)]
+struct RelayMessage<'a> {
+    from: &'a str,
+    #[serde(flatten)]
+    email: &'a Email<'a>,
+}
+
+fn relay_url() -> Option<String> {
+    std::env::var("MAIL_RELAY_URL")
+        .ok()
+        .filter(|url| !url.trim().is_empty())
+}
+
+/// Whether a relay is configured, i.e. whether [`send`] can succeed.
+pub fn is_configured() -> bool {
+    relay_url().is_some()
+}
+
+/// Public base URL of the registry API, for links in messages
+/// (`REGISTRY_PUBLIC_URL`); `None` leaves links out.
+pub fn public_url() -> Option<String> {
+    std::env::var("REGISTRY_PUBLIC_URL")
+        .ok()
+        .map(|url| url.trim().trim_end_matches('/').to_string())
+        .filter(|url| !url.is_empty())
+}
+
+pub async fn send(email: &Email<'_>) -> Result<(), String> {
+    let url = relay_url().ok_or("MAIL_RELAY_URL is not set")?;
+    let from = std::env::var("MAIL_FROM").unwrap_or_else(|_| DEFAULT_FROM.to_string());
+    let client = reqwest::Client::builder()
+        .timeout(RELAY_TIMEOUT)
+        .build()
+        .map_err(|err| err.to_string())?;
+    let mut request = client.post(&url).json(&RelayMessage { from: &from, email });
+    if let Ok(token) = std::env::var("MAIL_RELAY_TOKEN") {
+        request = request.bearer_auth(token);
+    }
+    let response = request.send().await.map_err(|err| err.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("mail relay responded with {}", response.status()));
+    }
+    Ok(())
+}
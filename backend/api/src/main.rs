@@ -1,104 +1,39 @@
 #![warn(unused_imports)]
 
-mod ab_test_handlers;
-mod aggregation;
-mod analytics;
-mod auth;
-mod auth_handlers;
-mod batch_verify_handlers;
-mod breaking_changes;
-mod cache;
-mod canary_handlers;
-mod compatibility_testing_handlers;
-mod contract_events;
-mod contributor_handlers;
-mod db_monitoring;
-mod graphql;
-mod interoperability;
-mod interoperability_handlers;
-
-mod activity_feed_handlers;
-mod activity_feed_routes;
-mod analytics_handlers;
-mod category_handlers;
-mod custom_metrics_handlers;
-mod dependency;
-mod dependency_handlers;
-mod deprecation_handlers;
-mod error;
-mod events;
-mod handlers;
-mod health;
-pub mod health_monitor;
-#[cfg(test)]
-mod health_tests;
-mod incident_handlers;
-mod incident_routes;
-mod metrics;
-mod metrics_handler;
-mod migration_handlers;
-mod models;
-mod multisig_handlers;
-mod multisig_routes;
-mod onchain_verification;
-#[cfg(feature = "openapi")]
-mod openapi;
-mod org_handlers;
-mod patch_handlers;
-mod performance_handlers;
-mod rate_limit;
-mod recommendation_handlers;
-mod release_notes_handlers;
-mod release_notes_routes;
-pub mod request_tracing;
-mod resource_handlers;
-mod resource_tracking;
-mod routes;
-pub mod security_log;
-pub mod signing_handlers;
-mod similarity_handlers;
-mod simulation;
-mod simulation_handlers;
-mod state;
-
-mod type_safety;
-mod validation;
-mod websocket;
-
 use anyhow::Result;
-use axum::extract::{Request, State};
-use axum::http::{header, HeaderValue, Method, StatusCode};
-use axum::response::Response;
-use axum::{middleware, Router};
+use api::{app, auth, db_pool, migration_handlers, request_tracing, tls, web_auth};
 use dotenv::dotenv;
-use prometheus::Registry;
-use sqlx::postgres::PgPoolOptions;
 use std::net::SocketAddr;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
-use tower_http::cors::CorsLayer;
-
-async fn track_in_flight_middleware(
-    State(state): State<AppState>,
-    req: Request,
-    next: middleware::Next,
-) -> Result<Response, ApiError> {
-    if state.is_shutting_down.load(Ordering::Relaxed) {
-        return Err(ApiError::new(
-            StatusCode::SERVICE_UNAVAILABLE,
-            "SERVICE_UNAVAILABLE",
-            "Service is shutting down and temporarily unavailable",
-        ));
+
+/// Resolves on SIGTERM/SIGINT after notifying the drain loop in `main`.
+async fn shutdown_signal(tx: tokio::sync::mpsc::Sender<()>) {
+    let ctrl_c = async {
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to install Ctrl+C handler");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("failed to install signal handler")
+            .recv()
+            .await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
     }
-    crate::metrics::HTTP_IN_FLIGHT.inc();
-    let res = next.run(req).await;
-    crate::metrics::HTTP_IN_FLIGHT.dec();
-    Ok(res)
-}
 
-use crate::error::ApiError;
-use crate::rate_limit::RateLimitState;
-use crate::state::AppState;
+    tracing::info!("SIGTERM/SIGINT received. Failing health checks and stopping new requests...");
+    let _ = tx.send(()).await;
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -108,8 +43,17 @@ async fn main() -> Result<()> {
     // Initialize structured JSON tracing (ELK/Splunk compatible)
     request_tracing::init_json_tracing();
 
+    // `--migrate-only` applies the embedded migrations and exits, for init
+    // containers and upgrade scripts; it does not need the HTTP configuration.
+    let migrate_only = std::env::args().any(|arg| arg == "--migrate-only");
+
     // Fail fast on startup when JWT configuration is invalid.
-    if let Err(err) = auth::AuthManager::from_env() {
+    let auth_config = if migrate_only {
+        Ok(())
+    } else {
+        auth::AuthManager::from_env().map(|_| ())
+    };
+    if let Err(err) = auth_config {
         tracing::error!(
             error = %err,
             "JWT authentication configuration is invalid. Set JWT_SECRET to a strong value with at least {} characters.",
@@ -120,43 +64,58 @@ async fn main() -> Result<()> {
             err
         ));
     }
+    if !migrate_only {
+        if let Err(err) = web_auth::WebAuthConfig::from_env() {
+            tracing::error!(error = %err, "SEP-10 web authentication configuration is invalid");
+            return Err(anyhow::anyhow!(
+                "Invalid SEP-10 web authentication configuration: {}",
+                err
+            ));
+        }
+    }
 
-    // Database connection with dynamic pool size
+    // Database connection; pool settings come from the environment
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-    let logical_cores = std::thread::available_parallelism()
-        .map(|n| n.get())
-        .unwrap_or(4);
-
-    let default_max_pool = (logical_cores * 2).max(10);
-    let max_pool_size = std::env::var("DB_MAX_POOL_SIZE")
-        .ok()
-        .and_then(|s| s.parse::<u32>().ok())
-        .unwrap_or(default_max_pool as u32);
-
+    let pool_config = db_pool::DbPoolConfig::from_env()?;
     tracing::info!(
-        max_pool_size = max_pool_size,
-        logical_cores = logical_cores,
+        max_pool_size = pool_config.max_connections,
+        read_replica = pool_config.read_url.is_some(),
         "Initializing database connection pool"
     );
 
-    let pool = PgPoolOptions::new()
-        .max_connections(max_pool_size)
-        .acquire_timeout(std::time::Duration::from_secs(30))
-        .connect(&database_url)
-        .await?;
+    let pool = pool_config.connect(&database_url).await?;
 
-    // Run migrations (skip if SKIP_MIGRATIONS=true, useful when migrations were applied manually)
+    // Run the embedded migrations (skip if SKIP_MIGRATIONS=true, useful when
+    // migrations were applied manually)
     let skip_migrations = std::env::var("SKIP_MIGRATIONS")
         .map(|v| v == "true" || v == "1")
         .unwrap_or(false);
 
-    if skip_migrations {
+    if skip_migrations && !migrate_only {
         tracing::info!("Skipping automatic migrations (SKIP_MIGRATIONS=true)");
     } else {
-        sqlx::migrate!("../../database/migrations")
-            .run(&pool)
-            .await?;
+        migration_handlers::MIGRATOR.run(&pool).await?;
+    }
+
+    if migrate_only {
+        let status = migration_handlers::embedded_schema_status(&pool).await?;
+        tracing::info!(
+            version = ?status.applied_version,
+            consistent = status.is_consistent(),
+            "Migrations applied (--migrate-only); exiting"
+        );
+        pool.close().await;
+        return Ok(());
+    }
+
+    match migration_handlers::embedded_schema_status(&pool).await {
+        Ok(status) if !status.is_consistent() => tracing::warn!(
+            ?status,
+            "Database schema does not match the migrations embedded in this binary"
+        ),
+        Ok(_) => {}
+        Err(err) => tracing::warn!(error = ?err, "Could not verify database schema version"),
     }
 
     tracing::info!("Database connected and migrations applied");
@@ -164,136 +123,23 @@ async fn main() -> Result<()> {
     // Check migration versioning state on startup (Issue #252)
     migration_handlers::check_migrations_on_startup(&pool).await;
 
-    // Spawn the hourly analytics aggregation background task
-    aggregation::spawn_aggregation_task(pool.clone());
-
-    // Create prometheus registry for metrics
-    let registry = Registry::new();
-    if let Err(e) = crate::metrics::register_all(&registry) {
-        tracing::error!("Failed to register metrics: {}", e);
-    }
-
-    // Job engine omitted: optional dependency; add soroban_batch and uncomment to enable.
-    // let (job_engine, job_rx) = soroban_batch::engine::JobEngine::new();
-    // let job_engine = Arc::new(job_engine);
-    // tokio::spawn(async move { job_engine.clone().run_worker(job_rx).await });
+    // Persistent background job queue workers (JOB_WORKERS, default 4)
+    let job_workers = std::env::var("JOB_WORKERS")
+        .ok()
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(4);
 
     // Create app state
     let is_shutting_down = Arc::new(AtomicBool::new(false));
-    // Job engine: initialize for background batch processing
-    let (job_engine, job_rx) = soroban_batch::engine::JobEngine::new();
-    let job_engine = Arc::new(job_engine);
-    let je = job_engine.clone();
-    tokio::spawn(async move { je.run_worker(job_rx).await });
-
-    let state = AppState::new(pool.clone(), registry, job_engine, is_shutting_down.clone()).await?;
-
-    // Initialize GraphQL schema
-    let schema = graphql::schema::build_schema(state.clone());
-
-    // Spawn the background DB and cache monitoring task
-    db_monitoring::spawn_db_monitoring_task(pool.clone(), state.cache.clone());
-
-    // Spawn the health monitor background task (Issue #333)
-    let hm_state = state.clone();
-    let hm_status = state.health_monitor_status.clone();
-    tokio::spawn(async move {
-        health_monitor::run_health_monitor(hm_state, hm_status).await;
-    });
-
-    let network_state = state.clone();
-    tokio::spawn(async move {
-        handlers::run_network_catalog_refresh(network_state).await;
-    });
-
-    // Warm up the cache
-    state.cache.clone().warm_up(pool.clone());
-
-    let rate_limit_state = RateLimitState::from_env();
-    rate_limit_state.spawn_eviction_task();
-
-    let allowed_origins = std::env::var("ALLOWED_ORIGINS").unwrap_or_else(|_| {
-        "http://localhost:3000,https://soroban-registry.vercel.app".to_string()
-    });
-
-    let origins: Vec<HeaderValue> = allowed_origins
-        .split(',')
-        .filter_map(|s| {
-            let s = s.trim();
-            if s.is_empty() {
-                None
-            } else {
-                Some(HeaderValue::from_str(s).expect("Invalid allowed origin"))
-            }
-        })
-        .collect();
-
-    let cors = CorsLayer::new()
-        .allow_origin(origins)
-        .allow_methods([
-            Method::GET,
-            Method::POST,
-            Method::PATCH,
-            Method::DELETE,
-            Method::OPTIONS,
-        ])
-        .allow_headers([
-            header::CONTENT_TYPE,
-            header::AUTHORIZATION,
-            crate::request_tracing::X_REQUEST_ID.clone(),
-            crate::request_tracing::X_CORRELATION_ID.clone(),
-        ])
-        .expose_headers([
-            crate::request_tracing::X_REQUEST_ID.clone(),
-            crate::request_tracing::X_CORRELATION_ID.clone(),
-        ]);
+    let read_pool = pool_config.connect_read(&pool).await?;
+    let state = app::build_state(pool.clone(), is_shutting_down.clone())
+        .await?
+        .with_read_pool(read_pool);
+
+    app::spawn_background_tasks(&state, job_workers).await?;
 
     // Build router
-    let app = Router::new()
-        .merge(routes::auth_routes())
-        .merge(routes::organization_routes())
-        .merge(routes::contract_routes())
-        .merge(routes::publisher_routes())
-        .merge(routes::contributor_routes())
-        .merge(routes::health_routes())
-        .merge(routes::migration_routes())
-        .merge(incident_routes::incident_routes())
-        .merge(routes::network_routes())
-        .merge(routes::openapi_routes())
-        .merge(routes::health_monitor_routes())
-        .merge(routes::admin_routes())
-        .merge(routes::category_routes())
-        .merge(routes::compatibility_dashboard_routes())
-        .merge(routes::canary_routes())
-        .merge(routes::ab_test_routes())
-        .merge(routes::performance_routes())
-        .merge(routes::federation_routes())
-        .merge(multisig_routes::routes())
-        .merge(routes::observability_routes())
-        .merge(routes::websocket_routes())
-        .merge(routes::validator_routes())
-        .merge(release_notes_routes::release_notes_routes())
-        .route("/api/graphql", axum::routing::post(graphql::graphql_handler).with_state(schema))
-        .route("/api/graphql/playground", axum::routing::get(graphql::graphql_playground))
-        .nest("/api", activity_feed_routes::routes())
-        .fallback(handlers::route_not_found)
-        .layer(middleware::from_fn(
-            validation::payload_size::payload_size_validation_middleware,
-        ))
-        .layer(middleware::from_fn(
-            validation::enhanced_extractors::validation_failure_tracking_middleware,
-        ))
-        .layer(middleware::from_fn_with_state(
-            state.clone(),
-            track_in_flight_middleware,
-        ))
-        .layer(middleware::from_fn_with_state(
-            rate_limit_state,
-            rate_limit::rate_limit_middleware,
-        ))
-        .layer(cors)
-        .layer(middleware::from_fn(request_tracing::tracing_middleware))
-        .with_state(state.clone());
+    let app = app::router(state)?;
 
     // Start server (port configurable via PORT env var, default 3001)
     let port: u16 = std::env::var("PORT")
@@ -301,53 +147,56 @@ async fn main() -> Result<()> {
         .and_then(|s| s.parse().ok())
         .unwrap_or(3001);
     let addr = SocketAddr::from(([0, 0, 0, 0], port));
+    let tls_settings = tls::TlsSettings::from_env()?;
     tracing::info!("API server listening on {}", addr);
 
-    let listener = tokio::net::TcpListener::bind(addr).await?;
     let (tx, mut rx) = tokio::sync::mpsc::channel::<()>(1);
-
-    let server = axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .with_graceful_shutdown(async move {
-        let ctrl_c = async {
-            tokio::signal::ctrl_c()
-                .await
-                .expect("failed to install Ctrl+C handler");
-        };
-
-        #[cfg(unix)]
-        let terminate = async {
-            tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
-                .expect("failed to install signal handler")
-                .recv()
-                .await;
-        };
-
-        #[cfg(not(unix))]
-        let terminate = std::future::pending::<()>();
-
-        tokio::select! {
-            _ = ctrl_c => {},
-            _ = terminate => {},
+    let make_service = app.into_make_service_with_connect_info::<SocketAddr>();
+
+    let server_task = match tls_settings {
+        None => {
+            let listener = tokio::net::TcpListener::bind(addr).await?;
+            let server =
+                axum::serve(listener, make_service).with_graceful_shutdown(shutdown_signal(tx));
+            tokio::spawn(async move {
+                if let Err(e) = server.await {
+                    tracing::error!("Server error: {}", e);
+                }
+            })
         }
-
-        tracing::info!(
-            "SIGTERM/SIGINT received. Failing health checks and stopping new requests..."
-        );
-        let _ = tx.send(()).await;
-    });
-
-    let server_task = tokio::spawn(async move {
-        if let Err(e) = server.await {
-            tracing::error!("Server error: {}", e);
+        Some(settings) => {
+            let rustls_config = axum_server::tls_rustls::RustlsConfig::from_config(Arc::new(
+                settings.server_config()?,
+            ));
+            tracing::info!(
+                access_mode = ?settings.mode,
+                "TLS enabled{}",
+                if settings.mode == tls::AccessMode::Mtls {
+                    "; client certificates required"
+                } else {
+                    ""
+                }
+            );
+            let handle = axum_server::Handle::new();
+            let shutdown_handle = handle.clone();
+            tokio::spawn(async move {
+                shutdown_signal(tx).await;
+                shutdown_handle.graceful_shutdown(None);
+            });
+            let server = axum_server::bind_rustls(addr, rustls_config)
+                .handle(handle)
+                .serve(make_service);
+            tokio::spawn(async move {
+                if let Err(e) = server.await {
+                    tracing::error!("Server error: {}", e);
+                }
+            })
         }
-    });
+    };
 
     if let Some(()) = rx.recv().await {
         is_shutting_down.store(true, Ordering::SeqCst);
-        let initial_in_flight = crate::metrics::HTTP_IN_FLIGHT.get();
+        let initial_in_flight = api::metrics::HTTP_IN_FLIGHT.get();
         tracing::info!(
             "Graceful shutdown initiated. In-flight requests: {}",
             initial_in_flight
@@ -363,7 +212,7 @@ async fn main() -> Result<()> {
 
         let mut success = false;
         loop {
-            let in_flight = crate::metrics::HTTP_IN_FLIGHT.get();
+            let in_flight = api::metrics::HTTP_IN_FLIGHT.get();
             if in_flight == 0 {
                 tracing::info!(
                     "All in-flight requests completed in {}ms. In-flight: 0",
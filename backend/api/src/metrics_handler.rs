@@ -43,6 +43,7 @@ mod tests {
         let (event_broadcaster, _) = tokio::sync::broadcast::channel(100);
         AppState {
             db: create_test_pool(),
+            db_read: create_test_pool(),
             started_at: Instant::now(),
             cache: Arc::new(CacheLayer::new(CacheConfig::default()).await),
             registry,
@@ -626,3 +626,123 @@ pub async fn check_migrations_on_startup(pool: &sqlx::PgPool) {
         }
     }
 }
+
+// ─────────────────────────────────────────────────────────
+// Embedded sqlx migrations
+// ─────────────────────────────────────────────────────────
+
+/// Migrations from `database/migrations`, compiled into the binary so
+/// self-hosted deployments never need to ship SQL files alongside it.
+pub static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("../../database/migrations");
+
+/// Comparison between the embedded migrations and `_sqlx_migrations`.
+#[derive(Debug, Clone, Default, Serialize, PartialEq, Eq)]
+pub struct EmbeddedSchemaStatus {
+    /// Latest migration embedded in this binary.
+    pub expected_version: Option<i64>,
+    /// Latest migration successfully applied to the database.
+    pub applied_version: Option<i64>,
+    /// Embedded but not yet applied.
+    pub pending: Vec<i64>,
+    /// Applied but unknown to this binary (database is newer than the code).
+    pub unknown: Vec<i64>,
+    /// Applied with a checksum that differs from the embedded file.
+    pub checksum_mismatches: Vec<i64>,
+    /// Recorded as failed (`success = false`).
+    pub failed: Vec<i64>,
+}
+
+impl EmbeddedSchemaStatus {
+    pub fn is_consistent(&self) -> bool {
+        self.pending.is_empty()
+            && self.unknown.is_empty()
+            && self.checksum_mismatches.is_empty()
+            && self.failed.is_empty()
+    }
+}
+
+/// Compare `(version, checksum)` pairs from the binary against
+/// `(version, success, checksum)` rows from the database.
+fn compare_migrations(
+    embedded: &[(i64, Vec<u8>)],
+    applied: &[(i64, bool, Vec<u8>)],
+) -> EmbeddedSchemaStatus {
+    let mut status = EmbeddedSchemaStatus {
+        expected_version: embedded.iter().map(|(v, _)| *v).max(),
+        applied_version: applied
+            .iter()
+            .filter(|(_, ok, _)| *ok)
+            .map(|(v, _, _)| *v)
+            .max(),
+        ..Default::default()
+    };
+
+    for (version, checksum) in embedded {
+        match applied.iter().find(|(v, _, _)| v == version) {
+            None => status.pending.push(*version),
+            Some((_, false, _)) => status.failed.push(*version),
+            Some((_, true, applied_checksum)) if applied_checksum != checksum => {
+                status.checksum_mismatches.push(*version)
+            }
+            Some(_) => {}
+        }
+    }
+    for (version, _, _) in applied {
+        if !embedded.iter().any(|(v, _)| v == version) {
+            status.unknown.push(*version);
+        }
+    }
+    status
+}
+
+/// Report how the database schema compares to the migrations in this binary.
+pub async fn embedded_schema_status(
+    pool: &sqlx::PgPool,
+) -> Result<EmbeddedSchemaStatus, sqlx::Error> {
+    let embedded: Vec<(i64, Vec<u8>)> = MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| (m.version, m.checksum.to_vec()))
+        .collect();
+
+    let table_exists: bool =
+        sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(pool)
+            .await?;
+    let applied: Vec<(i64, bool, Vec<u8>)> = if table_exists {
+        sqlx::query_as("SELECT version, success, checksum FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(pool)
+            .await?
+    } else {
+        Vec::new()
+    };
+
+    Ok(compare_migrations(&embedded, &applied))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent_when_everything_applied() {
+        let embedded = vec![(1, vec![1]), (2, vec![2])];
+        let applied = vec![(1, true, vec![1]), (2, true, vec![2])];
+        let status = compare_migrations(&embedded, &applied);
+        assert!(status.is_consistent());
+        assert_eq!(status.expected_version, Some(2));
+        assert_eq!(status.applied_version, Some(2));
+    }
+
+    #[test]
+    fn reports_pending_unknown_and_mismatched_versions() {
+        let embedded = vec![(1, vec![1]), (2, vec![2]), (3, vec![3])];
+        let applied = vec![(1, true, vec![9]), (2, false, vec![2]), (4, true, vec![4])];
+        let status = compare_migrations(&embedded, &applied);
+        assert!(!status.is_consistent());
+        assert_eq!(status.pending, vec![3]);
+        assert_eq!(status.failed, vec![2]);
+        assert_eq!(status.checksum_mismatches, vec![1]);
+        assert_eq!(status.unknown, vec![4]);
+    }
+}
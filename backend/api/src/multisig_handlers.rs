@@ -10,35 +10,80 @@ use sqlx::FromRow;
 use uuid::Uuid;
 
 use crate::{
+    action_proposal_handlers::{self, GovernanceAction},
+    auth::AuthClaims,
+    batch_proposal_handlers::{self, ProposalStep},
     error::{ApiError, ApiResult},
     metrics,
+    multisig_notifications::{self, Trigger},
     state::AppState,
+    tenant::Tenant,
+    validation::url_validation::validate_https_url_only,
 };
 
+pub(crate) const POLICY_COLUMNS: &str =
+    "id, name, threshold, signer_addresses, expiry_seconds, timelock_seconds,
+     ordered_approvals, created_by, created_at";
+
+pub(crate) const PROPOSAL_COLUMNS: &str =
+    "id, contract_name, contract_id, wasm_hash, network, description, action, action_payload,
+     policy_id, status, expires_at, executed_at, approved_at, rejected_at,
+     rejection_reason, proposer, required_approvals, fulfilled_at, created_at, updated_at";
+
+/// Longest delay a policy may impose between approval and execution.
+const MAX_TIMELOCK_SECONDS: i32 = 30 * 24 * 60 * 60;
+
+const MAX_COMMENT_LENGTH: usize = 4000;
+
+/// Longest signing delegation a signer may grant.
+const MAX_DELEGATION_DAYS: i64 = 90;
+
+/// Delegator whose seat `$2` holds on policy `$1` right now, if any.
+const ACTIVE_DELEGATOR_SQL: &str = "SELECT delegator_address FROM multisig_signer_delegations
+     WHERE policy_id = $1 AND delegate_address = $2
+       AND revoked_at IS NULL AND starts_at <= NOW() AND expires_at > NOW()
+     ORDER BY created_at DESC
+     LIMIT 1";
+
+const DELEGATION_COLUMNS: &str =
+    "id, policy_id, delegator_address, delegate_address, starts_at, expires_at, revoked_at,
+     created_at";
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, sqlx::Type)]
-#[serde(rename_all = "lowercase")]
-#[sqlx(type_name = "proposal_status", rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "proposal_status", rename_all = "snake_case")]
 pub enum ProposalStatus {
     Pending,
     Approved,
+    /// A batch proposal with some steps executed
+    Executing,
     Executed,
     Expired,
     Rejected,
+    /// A batch step failed and executed steps are being rolled back
+    RollingBack,
+    RolledBack,
+    /// A batch step failed before any step was executed
+    Failed,
 }
 
 impl ProposalStatus {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             Self::Pending => "pending",
             Self::Approved => "approved",
+            Self::Executing => "executing",
             Self::Executed => "executed",
             Self::Expired => "expired",
             Self::Rejected => "rejected",
+            Self::RollingBack => "rolling_back",
+            Self::RolledBack => "rolled_back",
+            Self::Failed => "failed",
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum ApprovalDecision {
     Approved,
@@ -46,7 +91,7 @@ pub enum ApprovalDecision {
 }
 
 impl ApprovalDecision {
-    fn as_str(self) -> &'static str {
+    pub(crate) fn as_str(self) -> &'static str {
         match self {
             Self::Approved => "approved",
             Self::Rejected => "rejected",
@@ -60,7 +105,8 @@ pub struct CreateMultisigPolicyRequest {
     pub threshold: i32,
     pub signer_addresses: Vec<String>,
     pub expiry_seconds: Option<i32>,
-    pub created_by: String,
+    /// Delay between approval and earliest execution (default 0)
+    pub timelock_seconds: Option<i32>,
     pub ordered_approvals: Option<bool>,
 }
 
@@ -72,17 +118,43 @@ pub struct CreateDeployProposalRequest {
     pub network: Network,
     pub description: Option<String>,
     pub policy_id: Uuid,
-    pub proposer: String,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct SignProposalRequest {
-    pub signer_address: String,
     pub signature_data: Option<String>,
     pub decision: Option<ApprovalDecision>,
     pub comment: Option<String>,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct CreateProposalCommentRequest {
+    pub body: String,
+    /// Comment on the same proposal this one replies to
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CreateDelegationRequest {
+    pub delegate_address: String,
+    /// Defaults to now
+    pub starts_at: Option<DateTime<Utc>>,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignerChannelsRequest {
+    /// HTTPS endpoint notified when a proposal awaits this signer; empty clears it
+    pub webhook_url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SignerNotificationsQuery {
+    /// Only notifications not yet acknowledged
+    pub unread: Option<bool>,
+    pub limit: Option<i64>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct ListProposalsQuery {
     pub status: Option<String>,
@@ -96,6 +168,7 @@ pub struct MultisigPolicy {
     pub threshold: i32,
     pub signer_addresses: Vec<String>,
     pub expiry_seconds: i32,
+    pub timelock_seconds: i32,
     pub ordered_approvals: bool,
     pub created_by: String,
     pub created_at: DateTime<Utc>,
@@ -105,10 +178,17 @@ pub struct MultisigPolicy {
 pub struct DeployProposal {
     pub id: Uuid,
     pub contract_name: String,
+    /// Contract deployed or acted on; the amended policy for `amend_policy`
     pub contract_id: String,
-    pub wasm_hash: String,
-    pub network: Network,
+    /// Empty for actions that involve no build
+    pub wasm_hash: Option<String>,
+    /// Empty for actions that are not network-specific
+    pub network: Option<Network>,
     pub description: Option<String>,
+    /// `deploy`, or the governance action the proposal carries
+    pub action: String,
+    /// The action's typed payload (see [`GovernanceAction`])
+    pub action_payload: Option<serde_json::Value>,
     pub policy_id: Uuid,
     pub status: ProposalStatus,
     pub expires_at: DateTime<Utc>,
@@ -118,6 +198,9 @@ pub struct DeployProposal {
     pub rejection_reason: Option<String>,
     pub proposer: String,
     pub required_approvals: i32,
+    /// When the gated publish or migration this proposal cleared went
+    /// through (see `org_network_policy_handlers`)
+    pub fulfilled_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -131,6 +214,50 @@ pub struct ProposalSignature {
     pub comment: Option<String>,
     pub step_index: Option<i32>,
     pub reviewed_at: DateTime<Utc>,
+    /// Address that signed on `signer_address`'s behalf, under a delegation
+    pub delegate_address: Option<String>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct SignerDelegation {
+    pub id: Uuid,
+    pub policy_id: Uuid,
+    pub delegator_address: String,
+    pub delegate_address: String,
+    pub starts_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow)]
+pub struct ProposalComment {
+    pub id: Uuid,
+    pub proposal_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author_address: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct SignerChannels {
+    pub signer_address: String,
+    pub webhook_url: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, FromRow)]
+pub struct SignerNotification {
+    pub id: Uuid,
+    pub proposal_id: Uuid,
+    pub notification_type: String,
+    /// Proposal summary and the `sign_command` to run
+    pub payload: Option<serde_json::Value>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub acknowledged_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Serialize)]
@@ -149,9 +276,12 @@ pub struct SignProposalResponse {
 
 #[derive(Debug, Serialize)]
 pub struct ExecuteProposalResponse {
+    pub action: String,
     pub contract_id: String,
-    pub wasm_hash: String,
+    pub wasm_hash: Option<String>,
     pub executed_at: DateTime<Utc>,
+    /// What a governance action changed
+    pub result: Option<serde_json::Value>,
 }
 
 #[derive(Debug, Serialize)]
@@ -160,10 +290,19 @@ pub struct ProposalInfoResponse {
     pub policy: MultisigPolicy,
     pub signatures: Vec<ProposalSignature>,
     pub signatures_needed: i64,
+    /// When an approved proposal clears the policy's timelock
+    pub executable_at: Option<DateTime<Utc>>,
+    /// Discussion thread, oldest first; replies point at their parent
+    pub comments: Vec<ProposalComment>,
+    /// Signing delegations in force on the policy
+    pub delegations: Vec<SignerDelegation>,
+    /// Ordered steps of a batch proposal; empty for single deployments
+    pub steps: Vec<ProposalStep>,
 }
 
 #[derive(Debug, FromRow)]
 struct ProposalSigningState {
+    policy_id: Uuid,
     status: ProposalStatus,
     expires_at: DateTime<Utc>,
     required_approvals: i32,
@@ -171,48 +310,57 @@ struct ProposalSigningState {
     ordered_approvals: bool,
 }
 
-pub async fn create_policy(
-    State(state): State<AppState>,
-    Json(payload): Json<CreateMultisigPolicyRequest>,
-) -> ApiResult<Json<MultisigPolicy>> {
-    if payload.name.trim().is_empty() {
-        return Err(ApiError::bad_request(
-            "InvalidName",
-            "Policy name cannot be empty",
-        ));
-    }
+#[derive(Debug, FromRow)]
+struct ProposalParticipants {
+    contract_id: String,
+    proposer: String,
+    signer_addresses: Vec<String>,
+}
 
-    if payload.created_by.trim().is_empty() {
-        return Err(ApiError::bad_request(
-            "InvalidCreator",
-            "created_by cannot be empty",
-        ));
-    }
+#[derive(Debug, FromRow)]
+struct ProposalExecutionState {
+    contract_id: String,
+    wasm_hash: Option<String>,
+    action: String,
+    action_payload: Option<serde_json::Value>,
+    policy_id: Uuid,
+    tenant_id: Uuid,
+    status: ProposalStatus,
+    approved_at: Option<DateTime<Utc>>,
+    timelock_seconds: i32,
+    signer_addresses: Vec<String>,
+}
 
-    if payload.signer_addresses.is_empty() {
+/// Checks a policy's threshold, signers, expiry and timelock, for new policies
+/// and amendments alike.
+pub(crate) fn validate_policy_shape(
+    threshold: i32,
+    signer_addresses: &[String],
+    expiry_seconds: i32,
+    timelock_seconds: i32,
+) -> ApiResult<()> {
+    if signer_addresses.is_empty() {
         return Err(ApiError::bad_request(
             "InvalidSigners",
             "At least one signer is required",
         ));
     }
 
-    if payload.threshold < 1 || payload.threshold as usize > payload.signer_addresses.len() {
+    if threshold < 1 || threshold as usize > signer_addresses.len() {
         return Err(ApiError::bad_request(
             "InvalidThreshold",
             "threshold must be between 1 and the number of signers",
         ));
     }
 
-    let unique_signers: std::collections::HashSet<&String> =
-        payload.signer_addresses.iter().collect();
-    if unique_signers.len() != payload.signer_addresses.len() {
+    let unique_signers: std::collections::HashSet<&String> = signer_addresses.iter().collect();
+    if unique_signers.len() != signer_addresses.len() {
         return Err(ApiError::bad_request(
             "DuplicateSigners",
             "signer_addresses must not contain duplicates",
         ));
     }
 
-    let expiry_seconds = payload.expiry_seconds.unwrap_or(86400);
     if expiry_seconds < 60 {
         return Err(ApiError::bad_request(
             "InvalidExpiry",
@@ -220,20 +368,57 @@ pub async fn create_policy(
         ));
     }
 
+    if !(0..=MAX_TIMELOCK_SECONDS).contains(&timelock_seconds) {
+        return Err(ApiError::bad_request(
+            "InvalidTimelock",
+            format!(
+                "timelock_seconds must be between 0 and {}",
+                MAX_TIMELOCK_SECONDS
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+pub async fn create_policy(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Json(payload): Json<CreateMultisigPolicyRequest>,
+) -> ApiResult<Json<MultisigPolicy>> {
+    if payload.name.trim().is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidName",
+            "Policy name cannot be empty",
+        ));
+    }
+
+    let expiry_seconds = payload.expiry_seconds.unwrap_or(86400);
+    let timelock_seconds = payload.timelock_seconds.unwrap_or(0);
+    validate_policy_shape(
+        payload.threshold,
+        &payload.signer_addresses,
+        expiry_seconds,
+        timelock_seconds,
+    )?;
+
     let ordered_approvals = payload.ordered_approvals.unwrap_or(false);
 
-    let policy: MultisigPolicy = sqlx::query_as(
+    let policy: MultisigPolicy = sqlx::query_as(&format!(
         "INSERT INTO multisig_policies (
-            name, threshold, signer_addresses, expiry_seconds, created_by, ordered_approvals
+            name, threshold, signer_addresses, expiry_seconds, timelock_seconds, created_by,
+            ordered_approvals
         )
-        VALUES ($1, $2, $3, $4, $5, $6)
-        RETURNING id, name, threshold, signer_addresses, expiry_seconds, ordered_approvals, created_by, created_at",
-    )
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING {}",
+        POLICY_COLUMNS
+    ))
     .bind(payload.name.trim())
     .bind(payload.threshold)
     .bind(payload.signer_addresses)
     .bind(expiry_seconds)
-    .bind(payload.created_by.trim())
+    .bind(timelock_seconds)
+    .bind(&claims.sub)
     .bind(ordered_approvals)
     .fetch_one(&state.db)
     .await
@@ -245,10 +430,90 @@ pub async fn create_policy(
     Ok(Json(policy))
 }
 
+pub async fn get_policy(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<MultisigPolicy>> {
+    let policy_id = parse_policy_id(&id)?;
+
+    let policy = sqlx::query_as::<_, MultisigPolicy>(&format!(
+        "SELECT {} FROM multisig_policies WHERE id = $1",
+        POLICY_COLUMNS
+    ))
+    .bind(policy_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to fetch multisig policy");
+        ApiError::db_error("Failed to load multisig policy")
+    })?
+    .ok_or_else(|| ApiError::not_found("PolicyNotFound", "multisig policy not found"))?;
+
+    Ok(Json(policy))
+}
+
 pub async fn create_deploy_proposal(
     State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
     Json(payload): Json<CreateDeployProposalRequest>,
 ) -> ApiResult<Json<DeployProposal>> {
+    ensure_may_propose_deploy(&state, &claims, tenant.id, &payload).await?;
+    open_deploy_proposal(&state, tenant.id, &claims.sub, &payload)
+        .await
+        .map(Json)
+}
+
+/// Opening a proposal notifies the policy's signers, so only the contract's
+/// publisher or one of those signers may do it.
+async fn ensure_may_propose_deploy(
+    state: &AppState,
+    claims: &AuthClaims,
+    tenant_id: Uuid,
+    payload: &CreateDeployProposalRequest,
+) -> ApiResult<()> {
+    let allowed: Option<bool> = sqlx::query_scalar(
+        "SELECT $2 = ANY(mp.signer_addresses) OR EXISTS(
+                SELECT 1 FROM contracts c
+                JOIN publishers p ON p.id = c.publisher_id
+                WHERE c.contract_id = $3 AND c.tenant_id = $4 AND c.deleted_at IS NULL
+                  AND p.stellar_address = $2
+            )
+         FROM multisig_policies mp WHERE mp.id = $1",
+    )
+    .bind(payload.policy_id)
+    .bind(&claims.sub)
+    .bind(payload.contract_id.trim())
+    .bind(tenant_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to check deploy proposer");
+        ApiError::db_error("Failed to load multisig policy")
+    })?;
+
+    match allowed {
+        None => Err(ApiError::not_found(
+            "PolicyNotFound",
+            "multisig policy not found",
+        )),
+        Some(false) => Err(ApiError::forbidden_with_error(
+            "NotPolicySigner",
+            "only the contract's publisher or the policy's signers can propose a deployment",
+        )),
+        Some(true) => Ok(()),
+    }
+}
+
+/// Opens a pending deploy proposal by `proposer` and notifies its signers.
+/// Shared with the network policy gate, which opens proposals on the
+/// deployer's behalf.
+pub(crate) async fn open_deploy_proposal(
+    state: &AppState,
+    tenant_id: Uuid,
+    proposer: &str,
+    payload: &CreateDeployProposalRequest,
+) -> ApiResult<DeployProposal> {
     if payload.contract_name.trim().is_empty() {
         return Err(ApiError::bad_request(
             "InvalidContractName",
@@ -267,18 +532,11 @@ pub async fn create_deploy_proposal(
             "wasm_hash cannot be empty",
         ));
     }
-    if payload.proposer.trim().is_empty() {
-        return Err(ApiError::bad_request(
-            "InvalidProposer",
-            "proposer cannot be empty",
-        ));
-    }
 
-    let policy = sqlx::query_as::<_, MultisigPolicy>(
-        "SELECT id, name, threshold, signer_addresses, expiry_seconds, ordered_approvals, created_by, created_at
-         FROM multisig_policies
-         WHERE id = $1",
-    )
+    let policy = sqlx::query_as::<_, MultisigPolicy>(&format!(
+        "SELECT {} FROM multisig_policies WHERE id = $1",
+        POLICY_COLUMNS
+    ))
     .bind(payload.policy_id)
     .fetch_optional(&state.db)
     .await
@@ -295,17 +553,15 @@ pub async fn create_deploy_proposal(
         ApiError::db_error("Failed to create deploy proposal")
     })?;
 
-    let proposal: DeployProposal = sqlx::query_as(
+    let proposal: DeployProposal = sqlx::query_as(&format!(
         "INSERT INTO deploy_proposals (
             contract_name, contract_id, wasm_hash, network, description,
-            policy_id, status, expires_at, proposer, required_approvals
+            policy_id, status, expires_at, proposer, required_approvals, tenant_id
          )
-         VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $8, $9)
-         RETURNING
-            id, contract_name, contract_id, wasm_hash, network, description,
-            policy_id, status, expires_at, executed_at, approved_at, rejected_at,
-            rejection_reason, proposer, required_approvals, created_at, updated_at",
-    )
+         VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $8, $9, $10)
+         RETURNING {}",
+        PROPOSAL_COLUMNS
+    ))
     .bind(payload.contract_name.trim())
     .bind(payload.contract_id.trim())
     .bind(payload.wasm_hash.trim())
@@ -313,8 +569,9 @@ pub async fn create_deploy_proposal(
     .bind(payload.description.as_deref())
     .bind(payload.policy_id)
     .bind(expires_at)
-    .bind(payload.proposer.trim())
+    .bind(proposer)
     .bind(policy.threshold)
+    .bind(tenant_id)
     .fetch_one(&mut *tx)
     .await
     .map_err(|e| {
@@ -322,28 +579,6 @@ pub async fn create_deploy_proposal(
         ApiError::db_error("Failed to create deploy proposal")
     })?;
 
-    for signer in &policy.signer_addresses {
-        sqlx::query(
-            "INSERT INTO multisig_approval_notifications (
-                proposal_id, signer_address, notification_type, payload
-             )
-             VALUES ($1, $2, 'approval_requested', $3)",
-        )
-        .bind(proposal.id)
-        .bind(signer)
-        .bind(json!({
-            "proposal_id": proposal.id,
-            "contract_id": &proposal.contract_id,
-            "network": &proposal.network,
-        }))
-        .execute(&mut *tx)
-        .await
-        .map_err(|e| {
-            tracing::error!(error = ?e, "failed to queue multisig notification");
-            ApiError::db_error("Failed to queue multisig notifications")
-        })?;
-    }
-
     sqlx::query(
         "INSERT INTO multisig_approval_audit_events (
             proposal_id, actor_address, action, metadata
@@ -351,7 +586,7 @@ pub async fn create_deploy_proposal(
          VALUES ($1, $2, 'proposal_created', $3)",
     )
     .bind(proposal.id)
-    .bind(payload.proposer.trim())
+    .bind(proposer)
     .bind(json!({
         "required_approvals": proposal.required_approvals,
         "ordered_approvals": policy.ordered_approvals,
@@ -368,12 +603,15 @@ pub async fn create_deploy_proposal(
         ApiError::db_error("Failed to finalize deploy proposal")
     })?;
 
+    multisig_notifications::notify(&state.db, proposal.id, Trigger::Created).await;
+
     metrics::MULTISIG_PROPOSALS.inc();
-    Ok(Json(proposal))
+    Ok(proposal)
 }
 
 pub async fn sign_proposal(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
     Json(payload): Json<SignProposalRequest>,
 ) -> ApiResult<Json<SignProposalResponse>> {
@@ -381,15 +619,8 @@ pub async fn sign_proposal(
         ApiError::bad_request("InvalidProposalId", "proposal id must be a valid UUID")
     })?;
 
-    if payload.signer_address.trim().is_empty() {
-        return Err(ApiError::bad_request(
-            "InvalidSigner",
-            "signer_address cannot be empty",
-        ));
-    }
-
     let decision = payload.decision.unwrap_or(ApprovalDecision::Approved);
-    let signer = payload.signer_address.trim().to_string();
+    let signer = claims.sub;
 
     let mut tx = state.db.begin().await.map_err(|e| {
         tracing::error!(error = ?e, "failed to start signing transaction");
@@ -398,6 +629,7 @@ pub async fn sign_proposal(
 
     let signing_state = sqlx::query_as::<_, ProposalSigningState>(
         "SELECT
+            p.policy_id,
             p.status,
             p.expires_at,
             p.required_approvals,
@@ -448,21 +680,33 @@ pub async fn sign_proposal(
         ));
     }
 
-    if !signing_state.signer_addresses.iter().any(|s| s == &signer) {
-        return Err(ApiError::forbidden(
-            "signer_address is not authorized by this multisig policy",
-        ));
-    }
+    // The seat is the policy signer the decision counts for: the signer
+    // themselves, or the signer who delegated to them.
+    let (seat, delegate) = if signing_state.signer_addresses.contains(&signer) {
+        (signer.clone(), None)
+    } else {
+        let delegator = sqlx::query_scalar::<_, String>(ACTIVE_DELEGATOR_SQL)
+            .bind(signing_state.policy_id)
+            .bind(&signer)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "failed to look up signing delegation");
+                ApiError::db_error("Failed to sign proposal")
+            })?
+            .filter(|delegator| signing_state.signer_addresses.contains(delegator))
+            .ok_or_else(|| ApiError::forbidden("you are not a signer of this multisig policy"))?;
+        (delegator, Some(signer.clone()))
+    };
 
     let mut step_index: Option<i32> = None;
     if signing_state.ordered_approvals {
         let signer_position = signing_state
             .signer_addresses
             .iter()
-            .position(|address| address == &signer)
-            .ok_or_else(|| {
-                ApiError::forbidden("signer_address is not authorized by this multisig policy")
-            })? as i32;
+            .position(|address| address == &seat)
+            .ok_or_else(|| ApiError::forbidden("you are not a signer of this multisig policy"))?
+            as i32;
 
         let approved_count: i64 = sqlx::query_scalar(
             "SELECT COUNT(*) FROM proposal_signatures
@@ -487,18 +731,20 @@ pub async fn sign_proposal(
 
     let inserted = sqlx::query_scalar::<_, Uuid>(
         "INSERT INTO proposal_signatures (
-            proposal_id, signer_address, signature_data, decision, comment, step_index
+            proposal_id, signer_address, signature_data, decision, comment, step_index,
+            delegate_address
          )
-         VALUES ($1, $2, $3, $4::approval_decision_type, $5, $6)
+         VALUES ($1, $2, $3, $4::approval_decision_type, $5, $6, $7)
          ON CONFLICT (proposal_id, signer_address) DO NOTHING
          RETURNING id",
     )
     .bind(proposal_id)
-    .bind(&signer)
+    .bind(&seat)
     .bind(payload.signature_data.as_deref())
     .bind(decision.as_str())
     .bind(payload.comment.as_deref())
     .bind(step_index)
+    .bind(delegate.as_deref())
     .fetch_optional(&mut *tx)
     .await
     .map_err(|e| {
@@ -529,7 +775,10 @@ pub async fn sign_proposal(
     })
     .bind(decision.as_str())
     .bind(payload.comment.as_deref())
-    .bind(json!({ "step_index": step_index }))
+    .bind(json!({
+        "step_index": step_index,
+        "on_behalf_of": delegate.as_ref().map(|_| &seat),
+    }))
     .execute(&mut *tx)
     .await
     .map_err(|e| {
@@ -616,6 +865,10 @@ pub async fn sign_proposal(
         ApiError::db_error("Failed to finalize signature")
     })?;
 
+    if proposal_status == ProposalStatus::Pending {
+        multisig_notifications::notify(&state.db, proposal_id, Trigger::Signed).await;
+    }
+
     let threshold_met = proposal_status == ProposalStatus::Approved;
     let signatures_needed =
         (i64::from(signing_state.required_approvals) - signatures_collected).max(0);
@@ -628,8 +881,29 @@ pub async fn sign_proposal(
     }))
 }
 
+/// Rejects execution while the policy's timelock since approval is running.
+pub(crate) fn ensure_timelock_elapsed(
+    approved_at: Option<DateTime<Utc>>,
+    timelock_seconds: i32,
+) -> ApiResult<()> {
+    if let Some(approved_at) = approved_at {
+        let executable_at = approved_at + chrono::Duration::seconds(i64::from(timelock_seconds));
+        if executable_at > Utc::now() {
+            return Err(ApiError::conflict(
+                "TimelockActive",
+                format!(
+                    "the policy's timelock allows execution from {}",
+                    executable_at.to_rfc3339()
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
 pub async fn execute_proposal(
     State(state): State<AppState>,
+    claims: AuthClaims,
     Path(id): Path<String>,
 ) -> ApiResult<Json<ExecuteProposalResponse>> {
     let proposal_id = Uuid::parse_str(&id).map_err(|_| {
@@ -641,11 +915,24 @@ pub async fn execute_proposal(
         ApiError::db_error("Failed to execute proposal")
     })?;
 
-    let proposal = sqlx::query_as::<_, (String, String, ProposalStatus)>(
-        "SELECT contract_id, wasm_hash, status
-         FROM deploy_proposals
-         WHERE id = $1
-         FOR UPDATE",
+    let ProposalExecutionState {
+        contract_id,
+        wasm_hash,
+        action,
+        action_payload,
+        policy_id,
+        tenant_id,
+        status,
+        approved_at,
+        timelock_seconds,
+        signer_addresses,
+    } = sqlx::query_as(
+        "SELECT p.contract_id, p.wasm_hash, p.action, p.action_payload, p.policy_id,
+                p.tenant_id, p.status, p.approved_at, mp.timelock_seconds, mp.signer_addresses
+         FROM deploy_proposals p
+         JOIN multisig_policies mp ON mp.id = p.policy_id
+         WHERE p.id = $1
+         FOR UPDATE OF p",
     )
     .bind(proposal_id)
     .fetch_optional(&mut *tx)
@@ -656,14 +943,54 @@ pub async fn execute_proposal(
     })?
     .ok_or_else(|| ApiError::not_found("ProposalNotFound", "deployment proposal not found"))?;
 
-    let (contract_id, wasm_hash, status) = proposal;
-
+    if !signer_addresses.contains(&claims.sub) {
+        return Err(ApiError::forbidden_with_error(
+            "NotPolicySigner",
+            "only the policy's signers can execute its proposals",
+        ));
+    }
     if status != ProposalStatus::Approved {
         return Err(ApiError::conflict(
             "ProposalNotApproved",
             "proposal must be approved before execution",
         ));
     }
+    ensure_timelock_elapsed(approved_at, timelock_seconds)?;
+
+    let is_batch: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM deploy_proposal_steps WHERE proposal_id = $1)",
+    )
+    .bind(proposal_id)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to check proposal steps");
+        ApiError::db_error("Failed to load proposal")
+    })?;
+    if is_batch {
+        return Err(ApiError::conflict(
+            "BatchProposal",
+            "batch proposals are executed step by step; report each step's result instead",
+        ));
+    }
+
+    // Governance actions take effect in the transaction that executes them.
+    let governance_action = match action_payload {
+        Some(payload) if action != "deploy" => Some(
+            serde_json::from_value::<GovernanceAction>(payload).map_err(|e| {
+                tracing::error!(error = ?e, proposal = %proposal_id, "unreadable action payload");
+                ApiError::db_error("Failed to load proposal action")
+            })?,
+        ),
+        _ => None,
+    };
+    let result = match &governance_action {
+        Some(governance_action) => Some(
+            action_proposal_handlers::apply(&mut *tx, tenant_id, policy_id, governance_action)
+                .await?,
+        ),
+        None => None,
+    };
 
     let executed_at = sqlx::query_scalar::<_, DateTime<Utc>>(
         "UPDATE deploy_proposals
@@ -683,14 +1010,17 @@ pub async fn execute_proposal(
 
     sqlx::query(
         "INSERT INTO multisig_approval_audit_events (
-            proposal_id, action, metadata
+            proposal_id, actor_address, action, metadata
          )
-         VALUES ($1, 'proposal_executed', $2)",
+         VALUES ($1, $2, 'proposal_executed', $3)",
     )
     .bind(proposal_id)
+    .bind(&claims.sub)
     .bind(json!({
+        "action": &action,
         "contract_id": &contract_id,
         "executed_at": executed_at,
+        "result": &result,
     }))
     .execute(&mut *tx)
     .await
@@ -704,12 +1034,17 @@ pub async fn execute_proposal(
         ApiError::db_error("Failed to finalize execution")
     })?;
 
+    if let (Some(governance_action), Some(result)) = (&governance_action, &result) {
+        action_proposal_handlers::after_commit(&state, governance_action, result).await;
+    }
     metrics::MULTISIG_EXECUTIONS.inc();
 
     Ok(Json(ExecuteProposalResponse {
+        action,
         contract_id,
         wasm_hash,
         executed_at,
+        result,
     }))
 }
 
@@ -721,14 +1056,12 @@ pub async fn proposal_info(
         ApiError::bad_request("InvalidProposalId", "proposal id must be a valid UUID")
     })?;
 
-    let proposal = sqlx::query_as::<_, DeployProposal>(
-        "SELECT
-            id, contract_name, contract_id, wasm_hash, network, description,
-            policy_id, status, expires_at, executed_at, approved_at, rejected_at,
-            rejection_reason, proposer, required_approvals, created_at, updated_at
+    let proposal = sqlx::query_as::<_, DeployProposal>(&format!(
+        "SELECT {}
          FROM deploy_proposals
          WHERE id = $1",
-    )
+        PROPOSAL_COLUMNS
+    ))
     .bind(proposal_id)
     .fetch_optional(&state.db)
     .await
@@ -738,11 +1071,10 @@ pub async fn proposal_info(
     })?
     .ok_or_else(|| ApiError::not_found("ProposalNotFound", "deployment proposal not found"))?;
 
-    let policy = sqlx::query_as::<_, MultisigPolicy>(
-        "SELECT id, name, threshold, signer_addresses, expiry_seconds, ordered_approvals, created_by, created_at
-         FROM multisig_policies
-         WHERE id = $1",
-    )
+    let policy = sqlx::query_as::<_, MultisigPolicy>(&format!(
+        "SELECT {} FROM multisig_policies WHERE id = $1",
+        POLICY_COLUMNS
+    ))
     .bind(proposal.policy_id)
     .fetch_one(&state.db)
     .await
@@ -759,7 +1091,8 @@ pub async fn proposal_info(
             decision::text AS decision,
             comment,
             step_index,
-            reviewed_at
+            reviewed_at,
+            delegate_address
          FROM proposal_signatures
          WHERE proposal_id = $1
          ORDER BY signed_at ASC",
@@ -784,37 +1117,222 @@ pub async fn proposal_info(
         ApiError::db_error("Failed to evaluate proposal threshold")
     })?;
 
+    let comments = load_comments(&state, proposal_id).await?;
+    let steps = batch_proposal_handlers::load_steps(&state.db, proposal_id).await?;
+
+    let delegations = sqlx::query_as::<_, SignerDelegation>(&format!(
+        "SELECT {} FROM multisig_signer_delegations
+         WHERE policy_id = $1 AND revoked_at IS NULL AND expires_at > NOW()
+         ORDER BY starts_at ASC",
+        DELEGATION_COLUMNS
+    ))
+    .bind(proposal.policy_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load signing delegations");
+        ApiError::db_error("Failed to load signing delegations")
+    })?;
+
     let signatures_needed = (i64::from(proposal.required_approvals) - signatures_collected).max(0);
+    let executable_at = proposal.approved_at.map(|approved_at| {
+        approved_at + chrono::Duration::seconds(i64::from(policy.timelock_seconds))
+    });
 
     Ok(Json(ProposalInfoResponse {
         proposal,
         policy,
         signatures,
         signatures_needed,
+        executable_at,
+        comments,
+        delegations,
+        steps,
     }))
 }
 
+async fn load_comments(state: &AppState, proposal_id: Uuid) -> ApiResult<Vec<ProposalComment>> {
+    sqlx::query_as::<_, ProposalComment>(
+        "SELECT id, proposal_id, parent_id, author_address, body, created_at
+         FROM proposal_comments
+         WHERE proposal_id = $1
+         ORDER BY created_at ASC, id ASC",
+    )
+    .bind(proposal_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load proposal comments");
+        ApiError::db_error("Failed to load proposal comments")
+    })
+}
+
+pub async fn create_proposal_comment(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(payload): Json<CreateProposalCommentRequest>,
+) -> ApiResult<Json<ProposalComment>> {
+    let proposal_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request("InvalidProposalId", "proposal id must be a valid UUID")
+    })?;
+
+    let author = claims.sub;
+    let body = payload.body.trim();
+    if body.is_empty() || body.chars().count() > MAX_COMMENT_LENGTH {
+        return Err(ApiError::bad_request(
+            "InvalidCommentBody",
+            format!(
+                "body must be between 1 and {} characters",
+                MAX_COMMENT_LENGTH
+            ),
+        ));
+    }
+
+    let participants = sqlx::query_as::<_, ProposalParticipants>(
+        "SELECT p.contract_id, p.proposer, mp.signer_addresses
+         FROM deploy_proposals p
+         JOIN multisig_policies mp ON mp.id = p.policy_id
+         WHERE p.id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to load proposal");
+        ApiError::db_error("Failed to load proposal")
+    })?
+    .ok_or_else(|| ApiError::not_found("ProposalNotFound", "deployment proposal not found"))?;
+
+    if participants.proposer != author && !participants.signer_addresses.contains(&author) {
+        return Err(ApiError::forbidden(
+            "only the proposer and the policy's signers can comment on a proposal",
+        ));
+    }
+
+    if let Some(parent_id) = payload.parent_id {
+        let parent_exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS(
+                SELECT 1 FROM proposal_comments WHERE id = $1 AND proposal_id = $2
+             )",
+        )
+        .bind(parent_id)
+        .bind(proposal_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "failed to look up parent comment");
+            ApiError::db_error("Failed to load proposal comments")
+        })?;
+        if !parent_exists {
+            return Err(ApiError::not_found(
+                "CommentNotFound",
+                "parent comment not found on this proposal",
+            ));
+        }
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to start comment transaction");
+        ApiError::db_error("Failed to record comment")
+    })?;
+
+    let comment = sqlx::query_as::<_, ProposalComment>(
+        "INSERT INTO proposal_comments (proposal_id, parent_id, author_address, body)
+         VALUES ($1, $2, $3, $4)
+         RETURNING id, proposal_id, parent_id, author_address, body, created_at",
+    )
+    .bind(proposal_id)
+    .bind(payload.parent_id)
+    .bind(&author)
+    .bind(body)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to insert proposal comment");
+        ApiError::db_error("Failed to record comment")
+    })?;
+
+    sqlx::query(
+        "INSERT INTO multisig_approval_audit_events (
+            proposal_id, actor_address, action, comment, metadata
+         )
+         VALUES ($1, $2, 'comment_added', $3, $4)",
+    )
+    .bind(proposal_id)
+    .bind(&author)
+    .bind(body)
+    .bind(json!({ "comment_id": comment.id, "parent_id": comment.parent_id }))
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to insert audit event");
+        ApiError::db_error("Failed to record audit trail")
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to commit comment transaction");
+        ApiError::db_error("Failed to record comment")
+    })?;
+
+    crate::events::emit_proposal_comment(&state, &participants.contract_id, &comment);
+
+    Ok(Json(comment))
+}
+
+pub async fn list_proposal_comments(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<ProposalComment>>> {
+    let proposal_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request("InvalidProposalId", "proposal id must be a valid UUID")
+    })?;
+
+    let exists: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM deploy_proposals WHERE id = $1)")
+            .bind(proposal_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "failed to load proposal");
+                ApiError::db_error("Failed to load proposal")
+            })?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "ProposalNotFound",
+            "deployment proposal not found",
+        ));
+    }
+
+    Ok(Json(load_comments(&state, proposal_id).await?))
+}
+
 pub async fn list_proposals(
     State(state): State<AppState>,
+    tenant: Tenant,
     Query(query): Query<ListProposalsQuery>,
 ) -> ApiResult<Json<ListProposalsResponse>> {
     let limit = query.limit.unwrap_or(20).clamp(1, 100) as i64;
 
     let (items, total) = if let Some(status) = query.status.as_deref() {
         match status {
-            "pending" | "approved" | "executed" | "expired" | "rejected" => {}
+            "pending" | "approved" | "executing" | "executed" | "expired" | "rejected"
+            | "rolling_back" | "rolled_back" | "failed" => {}
             _ => {
                 return Err(ApiError::bad_request(
                     "InvalidStatus",
-                    "status must be one of: pending, approved, executed, expired, rejected",
+                    "status must be one of: pending, approved, executing, executed, expired, \
+                     rejected, rolling_back, rolled_back, failed",
                 ))
             }
         }
 
         let total: i64 = sqlx::query_scalar(
-            "SELECT COUNT(*) FROM deploy_proposals WHERE status = $1::proposal_status",
+            "SELECT COUNT(*) FROM deploy_proposals
+             WHERE status = $1::proposal_status AND tenant_id = $2",
         )
         .bind(status)
+        .bind(tenant.id)
         .fetch_one(&state.db)
         .await
         .map_err(|e| {
@@ -822,17 +1340,16 @@ pub async fn list_proposals(
             ApiError::db_error("Failed to load proposals")
         })?;
 
-        let items = sqlx::query_as::<_, DeployProposal>(
-            "SELECT
-                id, contract_name, contract_id, wasm_hash, network, description,
-                policy_id, status, expires_at, executed_at, approved_at, rejected_at,
-                rejection_reason, proposer, required_approvals, created_at, updated_at
+        let items = sqlx::query_as::<_, DeployProposal>(&format!(
+            "SELECT {}
              FROM deploy_proposals
-             WHERE status = $1::proposal_status
+             WHERE status = $1::proposal_status AND tenant_id = $2
              ORDER BY created_at DESC
-             LIMIT $2",
-        )
+             LIMIT $3",
+            PROPOSAL_COLUMNS
+        ))
         .bind(status)
+        .bind(tenant.id)
         .bind(limit)
         .fetch_all(&state.db)
         .await
@@ -843,23 +1360,25 @@ pub async fn list_proposals(
 
         (items, total)
     } else {
-        let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM deploy_proposals")
-            .fetch_one(&state.db)
-            .await
-            .map_err(|e| {
-                tracing::error!(error = ?e, "failed to count proposals");
-                ApiError::db_error("Failed to load proposals")
-            })?;
-
-        let items = sqlx::query_as::<_, DeployProposal>(
-            "SELECT
-                id, contract_name, contract_id, wasm_hash, network, description,
-                policy_id, status, expires_at, executed_at, approved_at, rejected_at,
-                rejection_reason, proposer, required_approvals, created_at, updated_at
+        let total: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM deploy_proposals WHERE tenant_id = $1")
+                .bind(tenant.id)
+                .fetch_one(&state.db)
+                .await
+                .map_err(|e| {
+                    tracing::error!(error = ?e, "failed to count proposals");
+                    ApiError::db_error("Failed to load proposals")
+                })?;
+
+        let items = sqlx::query_as::<_, DeployProposal>(&format!(
+            "SELECT {}
              FROM deploy_proposals
+             WHERE tenant_id = $1
              ORDER BY created_at DESC
-             LIMIT $1",
-        )
+             LIMIT $2",
+            PROPOSAL_COLUMNS
+        ))
+        .bind(tenant.id)
         .bind(limit)
         .fetch_all(&state.db)
         .await
@@ -873,3 +1392,330 @@ pub async fn list_proposals(
 
     Ok(Json(ListProposalsResponse { items, total }))
 }
+
+pub async fn set_signer_channels(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(address): Path<String>,
+    Json(payload): Json<SignerChannelsRequest>,
+) -> ApiResult<Json<SignerChannels>> {
+    let address = address.trim();
+    if address.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidSigner",
+            "signer address cannot be empty",
+        ));
+    }
+    if address != claims.sub {
+        return Err(ApiError::forbidden(
+            "signers can only set their own notification channels",
+        ));
+    }
+    let webhook_url = match payload.webhook_url.as_deref().map(str::trim) {
+        Some(url) if !url.is_empty() => {
+            validate_https_url_only(url)
+                .map_err(|msg| ApiError::bad_request("InvalidWebhookUrl", msg))?;
+            Some(url)
+        }
+        _ => None,
+    };
+
+    let channels = sqlx::query_as::<_, SignerChannels>(
+        "INSERT INTO multisig_signer_channels (signer_address, webhook_url)
+         VALUES ($1, $2)
+         ON CONFLICT (signer_address) DO UPDATE
+            SET webhook_url = EXCLUDED.webhook_url, updated_at = NOW()
+         RETURNING signer_address, webhook_url, updated_at",
+    )
+    .bind(address)
+    .bind(webhook_url)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to save signer channels");
+        ApiError::db_error("Failed to save notification channels")
+    })?;
+
+    Ok(Json(channels))
+}
+
+pub async fn list_signer_notifications(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(address): Path<String>,
+    Query(query): Query<SignerNotificationsQuery>,
+) -> ApiResult<Json<Vec<SignerNotification>>> {
+    if address.trim() != claims.sub {
+        return Err(ApiError::forbidden(
+            "signers can only read their own notifications",
+        ));
+    }
+    let limit = query.limit.unwrap_or(50).clamp(1, 200);
+
+    let notifications = sqlx::query_as::<_, SignerNotification>(
+        "SELECT id, proposal_id, notification_type, payload, status, created_at,
+                sent_at, acknowledged_at
+         FROM multisig_approval_notifications
+         WHERE signer_address = $1 AND ($2 = FALSE OR acknowledged_at IS NULL)
+         ORDER BY created_at DESC
+         LIMIT $3",
+    )
+    .bind(address.trim())
+    .bind(query.unread.unwrap_or(false))
+    .bind(limit)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to list signer notifications");
+        ApiError::db_error("Failed to load notifications")
+    })?;
+
+    Ok(Json(notifications))
+}
+
+pub async fn acknowledge_notification(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<Json<SignerNotification>> {
+    let notification_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidNotificationId",
+            "notification id must be a valid UUID",
+        )
+    })?;
+
+    let signer_address: String = sqlx::query_scalar(
+        "SELECT signer_address FROM multisig_approval_notifications WHERE id = $1",
+    )
+    .bind(notification_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to fetch notification");
+        ApiError::db_error("Failed to load notification")
+    })?
+    .ok_or_else(|| ApiError::not_found("NotificationNotFound", "notification not found"))?;
+
+    if signer_address != claims.sub {
+        return Err(ApiError::forbidden(
+            "only the notified signer can acknowledge a notification",
+        ));
+    }
+
+    let notification = sqlx::query_as::<_, SignerNotification>(
+        "UPDATE multisig_approval_notifications
+         SET acknowledged_at = COALESCE(acknowledged_at, NOW())
+         WHERE id = $1
+         RETURNING id, proposal_id, notification_type, payload, status, created_at,
+                   sent_at, acknowledged_at",
+    )
+    .bind(notification_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to acknowledge notification");
+        ApiError::db_error("Failed to update notification")
+    })?
+    .ok_or_else(|| ApiError::not_found("NotificationNotFound", "notification not found"))?;
+
+    Ok(Json(notification))
+}
+
+fn parse_policy_id(id: &str) -> ApiResult<Uuid> {
+    Uuid::parse_str(id)
+        .map_err(|_| ApiError::bad_request("InvalidPolicyId", "policy id must be a valid UUID"))
+}
+
+pub async fn create_delegation(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(payload): Json<CreateDelegationRequest>,
+) -> ApiResult<Json<SignerDelegation>> {
+    let policy_id = parse_policy_id(&id)?;
+    let delegator = claims.sub.as_str();
+    let delegate = payload.delegate_address.trim();
+    if delegate.is_empty() || delegate == delegator {
+        return Err(ApiError::bad_request(
+            "InvalidDelegate",
+            "delegate_address must be a different, non-empty address",
+        ));
+    }
+
+    let now = Utc::now();
+    let starts_at = payload.starts_at.unwrap_or(now).max(now);
+    if payload.expires_at <= starts_at
+        || payload.expires_at - starts_at > chrono::Duration::days(MAX_DELEGATION_DAYS)
+    {
+        return Err(ApiError::bad_request(
+            "InvalidDelegationWindow",
+            format!(
+                "expires_at must be after starts_at and within {} days of it",
+                MAX_DELEGATION_DAYS
+            ),
+        ));
+    }
+
+    let signer_addresses: Vec<String> =
+        sqlx::query_scalar("SELECT signer_addresses FROM multisig_policies WHERE id = $1")
+            .bind(policy_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|e| {
+                tracing::error!(error = ?e, "failed to fetch multisig policy");
+                ApiError::db_error("Failed to load multisig policy")
+            })?
+            .ok_or_else(|| ApiError::not_found("PolicyNotFound", "multisig policy not found"))?;
+
+    if !signer_addresses.iter().any(|s| s == delegator) {
+        return Err(ApiError::forbidden_with_error(
+            "NotPolicySigner",
+            "only the policy's signers can delegate their seat",
+        ));
+    }
+    // A delegate holding a second seat would let one key meet two signatures.
+    if signer_addresses.iter().any(|s| s == delegate) {
+        return Err(ApiError::bad_request(
+            "InvalidDelegate",
+            "delegate_address is already a signer of this policy",
+        ));
+    }
+
+    let mut tx = state.db.begin().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to start delegation transaction");
+        ApiError::db_error("Failed to create delegation")
+    })?;
+
+    // Serialise delegation changes per policy so the overlap check holds.
+    sqlx::query("SELECT id FROM multisig_policies WHERE id = $1 FOR UPDATE")
+        .bind(policy_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            tracing::error!(error = ?e, "failed to lock multisig policy");
+            ApiError::db_error("Failed to create delegation")
+        })?;
+
+    let overlapping: bool = sqlx::query_scalar(
+        "SELECT EXISTS(
+            SELECT 1 FROM multisig_signer_delegations
+            WHERE policy_id = $1 AND revoked_at IS NULL
+              AND (delegator_address = $2 OR delegate_address = $3)
+              AND starts_at < $5 AND expires_at > $4
+         )",
+    )
+    .bind(policy_id)
+    .bind(delegator)
+    .bind(delegate)
+    .bind(starts_at)
+    .bind(payload.expires_at)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to check overlapping delegations");
+        ApiError::db_error("Failed to create delegation")
+    })?;
+    if overlapping {
+        return Err(ApiError::conflict(
+            "DelegationOverlap",
+            "the delegator or delegate already has a delegation on this policy in that window",
+        ));
+    }
+
+    let delegation = sqlx::query_as::<_, SignerDelegation>(&format!(
+        "INSERT INTO multisig_signer_delegations (
+            policy_id, delegator_address, delegate_address, starts_at, expires_at
+         )
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING {}",
+        DELEGATION_COLUMNS
+    ))
+    .bind(policy_id)
+    .bind(delegator)
+    .bind(delegate)
+    .bind(starts_at)
+    .bind(payload.expires_at)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to create delegation");
+        ApiError::db_error("Failed to create delegation")
+    })?;
+
+    tx.commit().await.map_err(|e| {
+        tracing::error!(error = ?e, "failed to commit delegation");
+        ApiError::db_error("Failed to create delegation")
+    })?;
+
+    Ok(Json(delegation))
+}
+
+pub async fn list_delegations(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<SignerDelegation>>> {
+    let policy_id = parse_policy_id(&id)?;
+
+    let delegations = sqlx::query_as::<_, SignerDelegation>(&format!(
+        "SELECT {} FROM multisig_signer_delegations
+         WHERE policy_id = $1
+         ORDER BY created_at DESC",
+        DELEGATION_COLUMNS
+    ))
+    .bind(policy_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to list delegations");
+        ApiError::db_error("Failed to load signing delegations")
+    })?;
+
+    Ok(Json(delegations))
+}
+
+pub async fn revoke_delegation(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<Json<SignerDelegation>> {
+    let delegation_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request("InvalidDelegationId", "delegation id must be a valid UUID")
+    })?;
+
+    let delegation = sqlx::query_as::<_, SignerDelegation>(&format!(
+        "SELECT {} FROM multisig_signer_delegations WHERE id = $1",
+        DELEGATION_COLUMNS
+    ))
+    .bind(delegation_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to fetch delegation");
+        ApiError::db_error("Failed to load signing delegation")
+    })?
+    .ok_or_else(|| ApiError::not_found("DelegationNotFound", "signing delegation not found"))?;
+
+    if delegation.delegator_address != claims.sub {
+        return Err(ApiError::forbidden(
+            "only the delegator can revoke a signing delegation",
+        ));
+    }
+
+    let delegation = sqlx::query_as::<_, SignerDelegation>(&format!(
+        "UPDATE multisig_signer_delegations
+         SET revoked_at = COALESCE(revoked_at, NOW())
+         WHERE id = $1
+         RETURNING {}",
+        DELEGATION_COLUMNS
+    ))
+    .bind(delegation_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| {
+        tracing::error!(error = ?e, "failed to revoke delegation");
+        ApiError::db_error("Failed to revoke signing delegation")
+    })?;
+
+    Ok(Json(delegation))
+}
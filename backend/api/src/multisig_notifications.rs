@@ -0,0 +1,327 @@
+//! Signer notifications for multisig deployment proposals
+//!
+//! When a proposal is created, and each time it collects an approval while
+//! still pending, [`notify`] records a `multisig_approval_notifications` row
+//! for every signer whose decision is still needed: all undecided signers,
+//! or only the next one in line under an ordered-approvals policy. Each row
+//! carries the `soroban-registry multisig sign` command the signer runs. A
+//! signer's active delegate (see `multisig_signer_delegations`) is notified
+//! alongside them.
+//!
+//! Rows form the signer's inbox (`GET /api/multisig/signers/:address/notifications`).
+//! Signers who registered a webhook (`multisig_signer_channels`) also get the
+//! notification POSTed by a `multisig_notification` job, which retries
+//! through the job queue.
+
+use anyhow::Context;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const NOTIFY_JOB_KIND: &str = "multisig_notification";
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What moved the proposal forward.
+#[derive(Debug, Clone, Copy)]
+pub enum Trigger {
+    Created,
+    Signed,
+}
+
+impl Trigger {
+    fn notification_type(self) -> &'static str {
+        match self {
+            Trigger::Created => "approval_requested",
+            Trigger::Signed => "approval_reminder",
+        }
+    }
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ProposalSnapshot {
+    policy_id: Uuid,
+    contract_name: String,
+    contract_id: String,
+    network: Option<String>,
+    action: String,
+    proposer: String,
+    required_approvals: i32,
+    expires_at: DateTime<Utc>,
+    signer_addresses: Vec<String>,
+    ordered_approvals: bool,
+}
+
+/// Signers whose decision the proposal still needs, in policy order. Under
+/// ordered approvals only the next signer in line can act.
+fn awaiting_signers<'a>(
+    signers: &'a [String],
+    decided: &[String],
+    approvals: usize,
+    ordered: bool,
+) -> Vec<&'a str> {
+    if ordered {
+        return signers
+            .get(approvals)
+            .filter(|next| !decided.contains(next))
+            .map(|next| vec![next.as_str()])
+            .unwrap_or_default();
+    }
+    signers
+        .iter()
+        .filter(|signer| !decided.contains(signer))
+        .map(String::as_str)
+        .collect()
+}
+
+fn sign_command(proposal_id: Uuid, signer: &str) -> String {
+    format!(
+        "soroban-registry multisig sign {} --signer {}",
+        proposal_id, signer
+    )
+}
+
+/// Notifies the signers `proposal_id` is waiting on and queues webhook
+/// delivery. Failures are logged rather than failing the write that
+/// triggered them.
+pub async fn notify(pool: &PgPool, proposal_id: Uuid, trigger: Trigger) {
+    if let Err(err) = record(pool, proposal_id, trigger).await {
+        tracing::warn!(proposal = %proposal_id, error = ?err, "failed to notify multisig signers");
+    }
+}
+
+async fn record(pool: &PgPool, proposal_id: Uuid, trigger: Trigger) -> sqlx::Result<()> {
+    // Decided proposals need no more signatures.
+    let Some(proposal) = sqlx::query_as::<_, ProposalSnapshot>(
+        "SELECT p.policy_id, p.contract_name, p.contract_id, p.network::TEXT AS network,
+                p.action, p.proposer, p.required_approvals, p.expires_at, mp.signer_addresses,
+                mp.ordered_approvals
+         FROM deploy_proposals p
+         JOIN multisig_policies mp ON mp.id = p.policy_id
+         WHERE p.id = $1 AND p.status = 'pending'",
+    )
+    .bind(proposal_id)
+    .fetch_optional(pool)
+    .await?
+    else {
+        return Ok(());
+    };
+
+    let decisions: Vec<(String, String)> = sqlx::query_as(
+        "SELECT signer_address, decision::TEXT FROM proposal_signatures WHERE proposal_id = $1",
+    )
+    .bind(proposal_id)
+    .fetch_all(pool)
+    .await?;
+    let decided: Vec<String> = decisions.iter().map(|(s, _)| s.clone()).collect();
+    let approvals = decisions.iter().filter(|(_, d)| d == "approved").count();
+
+    let awaiting = awaiting_signers(
+        &proposal.signer_addresses,
+        &decided,
+        approvals,
+        proposal.ordered_approvals,
+    );
+    if awaiting.is_empty() {
+        return Ok(());
+    }
+
+    let delegations: Vec<(String, String)> = sqlx::query_as(
+        "SELECT delegator_address, delegate_address FROM multisig_signer_delegations
+         WHERE policy_id = $1 AND revoked_at IS NULL
+           AND starts_at <= NOW() AND expires_at > NOW()",
+    )
+    .bind(proposal.policy_id)
+    .fetch_all(pool)
+    .await?;
+    let recipients: Vec<&str> = awaiting
+        .iter()
+        .flat_map(|seat| {
+            let delegates = delegations
+                .iter()
+                .filter(move |(delegator, _)| delegator == seat)
+                .map(|(_, delegate)| delegate.as_str());
+            std::iter::once(*seat).chain(delegates)
+        })
+        .collect();
+
+    let mut deliver = Vec::new();
+    for signer in &recipients {
+        let payload = serde_json::json!({
+            "contract_name": &proposal.contract_name,
+            "contract_id": &proposal.contract_id,
+            "network": &proposal.network,
+            "action": &proposal.action,
+            "proposer": &proposal.proposer,
+            "signatures_collected": approvals,
+            "required_approvals": proposal.required_approvals,
+            "expires_at": proposal.expires_at,
+            "sign_command": sign_command(proposal_id, signer),
+        });
+        let (id, has_webhook): (Uuid, bool) = sqlx::query_as(
+            "INSERT INTO multisig_approval_notifications (
+                proposal_id, signer_address, notification_type, payload
+             )
+             VALUES ($1, $2, $3, $4)
+             RETURNING id, EXISTS(
+                SELECT 1 FROM multisig_signer_channels
+                WHERE signer_address = $2 AND webhook_url IS NOT NULL
+             )",
+        )
+        .bind(proposal_id)
+        .bind(signer)
+        .bind(trigger.notification_type())
+        .bind(payload)
+        .fetch_one(pool)
+        .await?;
+        if has_webhook {
+            deliver.push(id);
+        }
+    }
+    tracing::info!(
+        proposal = %proposal_id,
+        signers = recipients.len(),
+        "multisig signers notified"
+    );
+
+    if !deliver.is_empty() {
+        job_queue::enqueue(
+            pool,
+            NOTIFY_JOB_KIND,
+            serde_json::json!({ "notification_ids": deliver }),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct PendingDelivery {
+    id: Uuid,
+    proposal_id: Uuid,
+    signer_address: String,
+    notification_type: String,
+    payload: serde_json::Value,
+    created_at: DateTime<Utc>,
+    webhook_url: String,
+}
+
+/// Body POSTed to a signer's webhook.
+#[derive(Debug, Serialize)]
+struct NotificationPayload<'a> {
+    notification_id: Uuid,
+    proposal_id: Uuid,
+    signer_address: &'a str,
+    notification_type: &'a str,
+    #[serde(flatten)]
+    details: &'a serde_json::Value,
+    created_at: DateTime<Utc>,
+}
+
+pub struct MultisigNotificationJob;
+
+#[async_trait]
+impl JobHandler for MultisigNotificationJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let ids: Vec<Uuid> = serde_json::from_value(job.payload["notification_ids"].clone())
+            .context("multisig notification job payload is missing notification_ids")?;
+
+        // Sent notifications are skipped, so a retry only resends the ones
+        // that failed.
+        let pending: Vec<PendingDelivery> = sqlx::query_as(
+            "SELECT n.id, n.proposal_id, n.signer_address, n.notification_type, n.payload,
+                    n.created_at, ch.webhook_url
+             FROM multisig_approval_notifications n
+             JOIN multisig_signer_channels ch ON ch.signer_address = n.signer_address
+             WHERE n.id = ANY($1) AND n.sent_at IS NULL AND ch.webhook_url IS NOT NULL",
+        )
+        .bind(&ids)
+        .fetch_all(pool)
+        .await?;
+
+        let client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .context("failed to build webhook client")?;
+        let mut failed = 0;
+        for delivery in &pending {
+            let payload = NotificationPayload {
+                notification_id: delivery.id,
+                proposal_id: delivery.proposal_id,
+                signer_address: &delivery.signer_address,
+                notification_type: &delivery.notification_type,
+                details: &delivery.payload,
+                created_at: delivery.created_at,
+            };
+            let result = client
+                .post(&delivery.webhook_url)
+                .header("X-Registry-Notification-Id", delivery.id.to_string())
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|err| err.to_string())
+                .and_then(|response| {
+                    if response.status().is_success() {
+                        Ok(())
+                    } else {
+                        Err(format!("webhook responded with {}", response.status()))
+                    }
+                });
+
+            match result {
+                Ok(()) => {
+                    sqlx::query(
+                        "UPDATE multisig_approval_notifications
+                         SET status = 'sent', sent_at = NOW()
+                         WHERE id = $1",
+                    )
+                    .bind(delivery.id)
+                    .execute(pool)
+                    .await?;
+                }
+                Err(error) => {
+                    failed += 1;
+                    tracing::warn!(notification = %delivery.id, error = %error, "multisig webhook failed");
+                }
+            }
+        }
+
+        if failed > 0 {
+            anyhow::bail!("{} of {} signer webhooks failed", failed, pending.len());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn addresses(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    #[test]
+    fn unordered_policies_notify_every_undecided_signer() {
+        let signers = addresses(&["GA", "GB", "GC"]);
+        assert_eq!(
+            awaiting_signers(&signers, &addresses(&["GB"]), 1, false),
+            vec!["GA", "GC"]
+        );
+    }
+
+    #[test]
+    fn ordered_policies_notify_only_the_next_signer() {
+        let signers = addresses(&["GA", "GB", "GC"]);
+        assert_eq!(awaiting_signers(&signers, &[], 0, true), vec!["GA"]);
+        assert_eq!(
+            awaiting_signers(&signers, &addresses(&["GA"]), 1, true),
+            vec!["GB"]
+        );
+        assert!(awaiting_signers(&signers, &addresses(&["GA", "GB", "GC"]), 3, true).is_empty());
+    }
+}
@@ -1,9 +1,11 @@
 use axum::{
-    routing::{get, post},
+    routing::{get, post, put},
     Router,
 };
 
-use crate::{multisig_handlers, state::AppState};
+use crate::{
+    action_proposal_handlers, batch_proposal_handlers, multisig_handlers, state::AppState,
+};
 
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -11,6 +13,30 @@ pub fn routes() -> Router<AppState> {
             "/api/multisig/policies",
             post(multisig_handlers::create_policy),
         )
+        .route(
+            "/api/multisig/policies/:id",
+            get(multisig_handlers::get_policy),
+        )
+        .route(
+            "/api/multisig/policies/:id/delegations",
+            get(multisig_handlers::list_delegations).post(multisig_handlers::create_delegation),
+        )
+        .route(
+            "/api/multisig/delegations/:id/revoke",
+            post(multisig_handlers::revoke_delegation),
+        )
+        .route(
+            "/api/multisig/signers/:address/channels",
+            put(multisig_handlers::set_signer_channels),
+        )
+        .route(
+            "/api/multisig/signers/:address/notifications",
+            get(multisig_handlers::list_signer_notifications),
+        )
+        .route(
+            "/api/multisig/notifications/:id/ack",
+            post(multisig_handlers::acknowledge_notification),
+        )
         .route(
             "/api/multisig/proposals",
             get(multisig_handlers::list_proposals),
@@ -19,6 +45,14 @@ pub fn routes() -> Router<AppState> {
             "/api/contracts/deploy-proposal",
             post(multisig_handlers::create_deploy_proposal),
         )
+        .route(
+            "/api/multisig/batch-proposals",
+            post(batch_proposal_handlers::create_batch_proposal),
+        )
+        .route(
+            "/api/multisig/action-proposals",
+            post(action_proposal_handlers::create_action_proposal),
+        )
         .route(
             "/api/contracts/:id/sign",
             post(multisig_handlers::sign_proposal),
@@ -31,4 +65,13 @@ pub fn routes() -> Router<AppState> {
             "/api/contracts/:id/proposal",
             get(multisig_handlers::proposal_info),
         )
+        .route(
+            "/api/proposals/:id/comments",
+            get(multisig_handlers::list_proposal_comments)
+                .post(multisig_handlers::create_proposal_comment),
+        )
+        .route(
+            "/api/proposals/:id/steps/:index",
+            post(batch_proposal_handlers::report_step_result),
+        )
 }
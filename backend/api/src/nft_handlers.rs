@@ -0,0 +1,294 @@
+//! NFT collection views
+//!
+//!   GET /api/contracts/:id/nft/tokens            – the collection, optionally by owner
+//!   GET /api/contracts/:id/nft/tokens/:token_id  – one token and its owner
+//!
+//! Served from the index built by [`crate::nft_index`] from the contract's
+//! ingested mint, transfer and burn events. Each token carries the URI it
+//! was minted with and `metadata_url`, the same URI resolved to something a
+//! marketplace can fetch (`ipfs://` through `NFT_IPFS_GATEWAY`, `ar://`
+//! through arweave.net).
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{Network, OrganizationRole};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    nft_index::{ipfs_gateway, resolve_uri},
+    org_handlers::check_org_role,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const DEFAULT_PAGE_SIZE: i64 = 100;
+const MAX_PAGE_SIZE: i64 = 500;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct NftTokensQuery {
+    /// Only tokens currently held by this address
+    pub owner: Option<String>,
+    /// Include burned tokens
+    #[serde(default)]
+    pub include_burned: bool,
+    /// Token ID to continue after (the previous page's `next_after`)
+    pub after: Option<String>,
+    /// Page size (default 100, max 500)
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct NftTokenRow {
+    token_id: String,
+    owner: Option<String>,
+    token_uri: Option<String>,
+    minted_at: Option<DateTime<Utc>>,
+    minted_ledger: Option<i64>,
+    last_transfer_at: Option<DateTime<Utc>>,
+    last_ledger: i64,
+    transfer_count: i32,
+    burned_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct NftToken {
+    pub token_id: String,
+    /// Current holder; absent once burned
+    pub owner: Option<String>,
+    /// URI the token was minted with
+    pub token_uri: Option<String>,
+    /// `token_uri` resolved to a fetchable URL
+    pub metadata_url: Option<String>,
+    pub minted_at: Option<DateTime<Utc>>,
+    pub minted_ledger: Option<i64>,
+    pub last_transfer_at: Option<DateTime<Utc>>,
+    /// Ledger of the newest event applied to this token
+    pub last_ledger: i64,
+    pub transfers: i32,
+    pub burned_at: Option<DateTime<Utc>>,
+}
+
+impl NftToken {
+    fn from_row(row: NftTokenRow, gateway: &str) -> Self {
+        let metadata_url = row
+            .token_uri
+            .as_deref()
+            .and_then(|uri| resolve_uri(uri, &row.token_id, gateway));
+        Self {
+            token_id: row.token_id,
+            owner: row.owner,
+            token_uri: row.token_uri,
+            metadata_url,
+            minted_at: row.minted_at,
+            minted_ledger: row.minted_ledger,
+            last_transfer_at: row.last_transfer_at,
+            last_ledger: row.last_ledger,
+            transfers: row.transfer_count,
+            burned_at: row.burned_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct NftCollection {
+    pub contract_id: String,
+    pub network: Network,
+    /// Tokens minted and not burned
+    pub total_supply: i64,
+    /// Distinct current owners
+    pub holders: i64,
+    pub tokens: Vec<NftToken>,
+    /// Pass as `after` to fetch the next page
+    pub next_after: Option<String>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+#[derive(sqlx::FromRow)]
+struct CollectionContract {
+    contract_id: String,
+    network: Network,
+    is_private: bool,
+    organization_id: Option<Uuid>,
+}
+
+/// The contract's on-chain identity, once the caller may see it and it has
+/// indexed NFT activity.
+async fn fetch_collection(
+    state: &AppState,
+    tenant: &Tenant,
+    claims: Option<&AuthClaims>,
+    id: &str,
+) -> ApiResult<CollectionContract> {
+    let contract_uuid = resolve_contract_uuid(state, tenant, id).await?;
+    let contract: CollectionContract = sqlx::query_as(
+        "SELECT contract_id, network, visibility = 'private' AS is_private, organization_id
+         FROM contracts WHERE id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch collection contract", err))?;
+    if contract.is_private {
+        let (Some(claims), Some(org_id)) = (claims, contract.organization_id) else {
+            return Err(ApiError::forbidden("Access denied to private contract"));
+        };
+        check_org_role(&state.db, org_id, &claims.sub, OrganizationRole::Viewer).await?;
+    }
+
+    let indexed: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM nft_tokens WHERE contract_id = $1 AND network = $2)",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check nft tokens", err))?;
+    if !indexed {
+        return Err(ApiError::unprocessable(
+            "NotNftCollection",
+            "No NFT mint or transfer events have been indexed for this contract",
+        ));
+    }
+    Ok(contract)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/nft/tokens",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        NftTokensQuery
+    ),
+    responses(
+        (status = 200, description = "A page of the collection's tokens", body = NftCollection),
+        (status = 403, description = "Private contract"),
+        (status = 404, description = "Contract not found"),
+        (status = 422, description = "No NFT activity indexed for the contract")
+    ),
+    tag = "Contracts"
+)]
+pub async fn list_nft_tokens(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+    Query(query): Query<NftTokensQuery>,
+) -> ApiResult<Json<NftCollection>> {
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_PAGE_SIZE)
+        .clamp(1, MAX_PAGE_SIZE);
+    let contract = fetch_collection(&state, &tenant, claims.as_ref(), &id).await?;
+    let owner = query
+        .owner
+        .as_deref()
+        .map(str::trim)
+        .filter(|o| !o.is_empty());
+
+    let (total_supply, holders): (i64, i64) = sqlx::query_as(
+        "SELECT COUNT(*) FILTER (WHERE burned_at IS NULL), COUNT(DISTINCT owner)
+         FROM nft_tokens WHERE contract_id = $1 AND network = $2",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("count nft tokens", err))?;
+
+    // Numeric ids sort numerically: by length, then text.
+    let mut rows: Vec<NftTokenRow> = sqlx::query_as(
+        "SELECT token_id, owner, token_uri, minted_at, minted_ledger, last_transfer_at,
+                last_ledger, transfer_count, burned_at
+         FROM nft_tokens
+         WHERE contract_id = $1 AND network = $2
+           AND ($3::TEXT IS NULL OR owner = $3)
+           AND ($4 OR burned_at IS NULL)
+           AND ($5::TEXT IS NULL OR (length(token_id), token_id) > (length($5), $5))
+         ORDER BY length(token_id), token_id
+         LIMIT $6",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .bind(owner)
+    .bind(query.include_burned)
+    .bind(query.after.as_deref())
+    .bind(limit + 1)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list nft tokens", err))?;
+
+    let next_after = if rows.len() as i64 > limit {
+        rows.truncate(limit as usize);
+        rows.last().map(|row| row.token_id.clone())
+    } else {
+        None
+    };
+    let gateway = ipfs_gateway();
+    Ok(Json(NftCollection {
+        contract_id: contract.contract_id,
+        network: contract.network,
+        total_supply,
+        holders,
+        tokens: rows
+            .into_iter()
+            .map(|row| NftToken::from_row(row, &gateway))
+            .collect(),
+        next_after,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/nft/tokens/{token_id}",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("token_id" = String, Path, description = "Token ID")
+    ),
+    responses(
+        (status = 200, description = "The token and its current owner", body = NftToken),
+        (status = 403, description = "Private contract"),
+        (status = 404, description = "Contract or token not found"),
+        (status = 422, description = "No NFT activity indexed for the contract")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_nft_token(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path((id, token_id)): Path<(String, String)>,
+) -> ApiResult<Json<NftToken>> {
+    let contract = fetch_collection(&state, &tenant, claims.as_ref(), &id).await?;
+    let row: NftTokenRow = sqlx::query_as(
+        "SELECT token_id, owner, token_uri, minted_at, minted_ledger, last_transfer_at,
+                last_ledger, transfer_count, burned_at
+         FROM nft_tokens
+         WHERE contract_id = $1 AND network = $2 AND token_id = $3",
+    )
+    .bind(&contract.contract_id)
+    .bind(&contract.network)
+    .bind(&token_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch nft token", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "NftTokenNotFound",
+            format!("No token '{}' has been indexed for this contract", token_id),
+        )
+    })?;
+    Ok(Json(NftToken::from_row(row, &ipfs_gateway())))
+}
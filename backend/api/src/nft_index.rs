@@ -0,0 +1,324 @@
+//! NFT collection index
+//!
+//! A background indexer follows `contract_events` in ingestion order
+//! (tracked in `nft_index_cursor`) and folds NFT `mint`, `transfer` and
+//! `burn` events into `nft_tokens`, one row per token with its current owner
+//! and mint URI. Served by [`crate::nft_handlers`].
+//!
+//! An event is an NFT event when its data carries a `token_id` (a number or
+//! string) rather than a SEP-41 `amount`; addresses are read from `from` /
+//! `to` and the URI from `token_uri` or `uri` on the mint. Events older
+//! (by ledger) than a token's stored state are ignored. The indexer runs
+//! every `NFT_INDEX_INTERVAL_SECS` (default 30).
+
+use chrono::{DateTime, Utc};
+use serde_json::Value;
+use sqlx::{PgPool, Postgres, Transaction};
+use std::time::Duration;
+use uuid::Uuid;
+
+const DEFAULT_INTERVAL_SECS: u64 = 30;
+const BATCH_SIZE: i64 = 1000;
+const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io";
+
+pub(crate) const NFT_TOPICS: [&str; 3] = ["mint", "transfer", "burn"];
+
+#[derive(Debug, PartialEq)]
+pub enum NftEvent {
+    Mint {
+        token_id: String,
+        to: String,
+        uri: Option<String>,
+    },
+    Transfer {
+        token_id: String,
+        to: String,
+    },
+    Burn {
+        token_id: String,
+    },
+}
+
+fn token_id(data: &Value) -> Option<String> {
+    match data.get("token_id")? {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        _ => None,
+    }
+}
+
+fn field<'a>(data: &'a Value, name: &str) -> Option<&'a str> {
+    data.get(name)
+        .and_then(Value::as_str)
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+}
+
+/// Reads an NFT event, or `None` for anything else (including SEP-41 token
+/// events, which carry an `amount` instead of a `token_id`).
+pub fn parse_event(topic: &str, data: Option<&Value>) -> Option<NftEvent> {
+    let data = data?;
+    let token_id = token_id(data)?;
+    match topic {
+        "mint" => Some(NftEvent::Mint {
+            token_id,
+            to: field(data, "to")?.to_string(),
+            uri: field(data, "token_uri")
+                .or_else(|| field(data, "uri"))
+                .map(str::to_string),
+        }),
+        "transfer" => Some(NftEvent::Transfer {
+            token_id,
+            to: field(data, "to")?.to_string(),
+        }),
+        "burn" => Some(NftEvent::Burn { token_id }),
+        _ => None,
+    }
+}
+
+/// Turns a token URI into a URL a client can fetch: `ipfs://` and `ar://`
+/// go through a gateway, and an ERC-1155 style `{id}` placeholder is filled
+/// with the token id. Schemes a browser cannot load resolve to `None`.
+pub fn resolve_uri(uri: &str, token_id: &str, ipfs_gateway: &str) -> Option<String> {
+    let uri = uri.trim().replace("{id}", token_id);
+    let gateway = ipfs_gateway.trim_end_matches('/');
+    if let Some(path) = uri.strip_prefix("ipfs://") {
+        let path = path.strip_prefix("ipfs/").unwrap_or(path);
+        return Some(format!("{}/ipfs/{}", gateway, path));
+    }
+    if let Some(path) = uri.strip_prefix("ar://") {
+        return Some(format!("https://arweave.net/{}", path));
+    }
+    if uri.starts_with("https://") || uri.starts_with("http://") || uri.starts_with("data:") {
+        return Some(uri);
+    }
+    None
+}
+
+pub fn ipfs_gateway() -> String {
+    std::env::var("NFT_IPFS_GATEWAY").unwrap_or_else(|_| DEFAULT_IPFS_GATEWAY.to_string())
+}
+
+// ── Indexer ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, sqlx::FromRow)]
+struct IngestedEvent {
+    id: Uuid,
+    contract_id: String,
+    network: String,
+    topic: String,
+    data: Option<Value>,
+    ledger_sequence: i64,
+    timestamp: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+pub fn spawn_nft_indexer(pool: PgPool) {
+    let interval_secs = std::env::var("NFT_INDEX_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            // Drain the backlog, one batch at a time.
+            loop {
+                match index_batch(&pool).await {
+                    Ok(scanned) if scanned as i64 == BATCH_SIZE => continue,
+                    Ok(_) => break,
+                    Err(err) => {
+                        tracing::error!(error = ?err, "nft: indexing failed");
+                        break;
+                    }
+                }
+            }
+        }
+    });
+}
+
+async fn index_batch(pool: &PgPool) -> Result<usize, sqlx::Error> {
+    let (last_created_at, last_event_id): (DateTime<Utc>, Uuid) =
+        sqlx::query_as("SELECT last_created_at, last_event_id FROM nft_index_cursor WHERE id = 1")
+            .fetch_one(pool)
+            .await?;
+
+    let batch: Vec<IngestedEvent> = sqlx::query_as(
+        "SELECT id, contract_id, network::TEXT AS network, topic, data, ledger_sequence,
+                timestamp, created_at
+         FROM contract_events
+         WHERE (created_at, id) > ($1, $2)
+         ORDER BY created_at, id
+         LIMIT $3",
+    )
+    .bind(last_created_at)
+    .bind(last_event_id)
+    .bind(BATCH_SIZE)
+    .fetch_all(pool)
+    .await?;
+    let Some(last) = batch.last() else {
+        return Ok(0);
+    };
+
+    let mut tx = pool.begin().await?;
+    let mut indexed = 0;
+    for event in &batch {
+        if !NFT_TOPICS.contains(&event.topic.as_str()) {
+            continue;
+        }
+        if let Some(nft) = parse_event(&event.topic, event.data.as_ref()) {
+            apply(&mut tx, event, &nft).await?;
+            indexed += 1;
+        }
+    }
+    sqlx::query(
+        "UPDATE nft_index_cursor SET last_created_at = $1, last_event_id = $2
+         WHERE id = 1 AND (last_created_at, last_event_id) < ($1, $2)",
+    )
+    .bind(last.created_at)
+    .bind(last.id)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+
+    if indexed > 0 {
+        tracing::info!(events = indexed, "nft: indexed token events");
+    }
+    Ok(batch.len())
+}
+
+async fn apply(
+    tx: &mut Transaction<'_, Postgres>,
+    event: &IngestedEvent,
+    nft: &NftEvent,
+) -> Result<(), sqlx::Error> {
+    let query = match nft {
+        NftEvent::Mint { token_id, to, uri } => sqlx::query(
+            "INSERT INTO nft_tokens
+                (contract_id, network, token_id, owner, token_uri, minted_at, minted_ledger,
+                 last_ledger)
+             VALUES ($1, $2::network_type, $3, $4, $5, $6, $7, $7)
+             ON CONFLICT (contract_id, network, token_id) DO UPDATE
+                SET owner = EXCLUDED.owner,
+                    token_uri = COALESCE(EXCLUDED.token_uri, nft_tokens.token_uri),
+                    minted_at = EXCLUDED.minted_at,
+                    minted_ledger = EXCLUDED.minted_ledger,
+                    last_ledger = EXCLUDED.last_ledger,
+                    burned_at = NULL,
+                    updated_at = NOW()
+                WHERE nft_tokens.last_ledger <= EXCLUDED.last_ledger",
+        )
+        .bind(&event.contract_id)
+        .bind(&event.network)
+        .bind(token_id)
+        .bind(to)
+        .bind(uri)
+        .bind(event.timestamp)
+        .bind(event.ledger_sequence),
+        // A transfer of a token whose mint was never ingested still records
+        // its owner.
+        NftEvent::Transfer { token_id, to } => sqlx::query(
+            "INSERT INTO nft_tokens
+                (contract_id, network, token_id, owner, last_transfer_at, last_ledger,
+                 transfer_count)
+             VALUES ($1, $2::network_type, $3, $4, $5, $6, 1)
+             ON CONFLICT (contract_id, network, token_id) DO UPDATE
+                SET owner = EXCLUDED.owner,
+                    last_transfer_at = EXCLUDED.last_transfer_at,
+                    last_ledger = EXCLUDED.last_ledger,
+                    transfer_count = nft_tokens.transfer_count + 1,
+                    updated_at = NOW()
+                WHERE nft_tokens.last_ledger <= EXCLUDED.last_ledger",
+        )
+        .bind(&event.contract_id)
+        .bind(&event.network)
+        .bind(token_id)
+        .bind(to)
+        .bind(event.timestamp)
+        .bind(event.ledger_sequence),
+        NftEvent::Burn { token_id } => sqlx::query(
+            "UPDATE nft_tokens
+             SET owner = NULL, burned_at = $4, last_ledger = $5, updated_at = NOW()
+             WHERE contract_id = $1 AND network = $2::network_type AND token_id = $3
+               AND last_ledger <= $5",
+        )
+        .bind(&event.contract_id)
+        .bind(&event.network)
+        .bind(token_id)
+        .bind(event.timestamp)
+        .bind(event.ledger_sequence),
+    };
+    query.execute(&mut **tx).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn nft_events_need_a_token_id() {
+        assert_eq!(
+            parse_event(
+                "mint",
+                Some(&json!({ "token_id": 7, "to": "GA", "token_uri": "ipfs://cid/7.json" }))
+            ),
+            Some(NftEvent::Mint {
+                token_id: "7".to_string(),
+                to: "GA".to_string(),
+                uri: Some("ipfs://cid/7.json".to_string()),
+            })
+        );
+        assert_eq!(
+            parse_event(
+                "transfer",
+                Some(&json!({ "token_id": "a1", "from": "GA", "to": "GB" }))
+            ),
+            Some(NftEvent::Transfer {
+                token_id: "a1".to_string(),
+                to: "GB".to_string(),
+            })
+        );
+        // SEP-41 transfers move an amount, not a token.
+        assert_eq!(
+            parse_event(
+                "transfer",
+                Some(&json!({ "amount": 5, "from": "GA", "to": "GB" }))
+            ),
+            None
+        );
+        assert_eq!(
+            parse_event("transfer", Some(&json!({ "token_id": 1 }))),
+            None
+        );
+        assert_eq!(
+            parse_event("approve", Some(&json!({ "token_id": 1 }))),
+            None
+        );
+    }
+
+    #[test]
+    fn uris_resolve_through_gateways() {
+        let gateway = "https://gw.example/";
+        assert_eq!(
+            resolve_uri("ipfs://bafy/7.json", "7", gateway).as_deref(),
+            Some("https://gw.example/ipfs/bafy/7.json")
+        );
+        assert_eq!(
+            resolve_uri("ipfs://ipfs/bafy", "7", gateway).as_deref(),
+            Some("https://gw.example/ipfs/bafy")
+        );
+        assert_eq!(
+            resolve_uri("ar://tx123", "7", gateway).as_deref(),
+            Some("https://arweave.net/tx123")
+        );
+        assert_eq!(
+            resolve_uri("https://meta.example/{id}.json", "42", gateway).as_deref(),
+            Some("https://meta.example/42.json")
+        );
+        assert_eq!(resolve_uri("javascript:alert(1)", "7", gateway), None);
+    }
+}
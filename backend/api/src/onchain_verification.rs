@@ -7,7 +7,7 @@ use stellar_strkey::{Contract as ContractStrkey, Strkey};
 use stellar_xdr::curr::{
     ContractCodeEntry, ContractDataDurability, ContractExecutable, ContractId, Hash, LedgerEntry,
     LedgerEntryData, LedgerKey, LedgerKeyContractCode, LedgerKeyContractData, Limits, ReadXdr,
-    ScAddress, ScContractInstance, ScVal, WriteXdr,
+    ScAddress, ScContractInstance, ScMapEntry, ScVal, WriteXdr,
 };
 
 use crate::cache::CacheLayer;
@@ -295,6 +295,47 @@ impl OnChainVerifier {
         config: &NetworkConfig,
         wasm_hash: &str,
     ) -> Result<Option<String>, RegistryError> {
+        Ok(self
+            .fetch_code(config, wasm_hash)
+            .await?
+            .map(|code| verifier::hash_wasm(&code)))
+    }
+
+    /// Downloads the WASM installed on `network` under `wasm_hash`, or `None`
+    /// when no such code is live on the ledger (never uploaded, or archived).
+    pub async fn fetch_contract_code(
+        &self,
+        network: &Network,
+        wasm_hash: &str,
+    ) -> Result<Option<Vec<u8>>, RegistryError> {
+        self.fetch_code(&NetworkConfig::from_env(network), wasm_hash)
+            .await
+    }
+
+    /// Reads the instance storage of `contract_id` on `network`, or `None`
+    /// when the contract is not live on the ledger.
+    pub async fn fetch_instance_storage(
+        &self,
+        network: &Network,
+        contract_id: &str,
+    ) -> Result<Option<Vec<ScMapEntry>>, RegistryError> {
+        let config = NetworkConfig::from_env(network);
+        Ok(self
+            .fetch_contract_instance(&config, contract_id)
+            .await?
+            .map(|(instance, _)| {
+                instance
+                    .storage
+                    .map(|storage| storage.0.to_vec())
+                    .unwrap_or_default()
+            }))
+    }
+
+    async fn fetch_code(
+        &self,
+        config: &NetworkConfig,
+        wasm_hash: &str,
+    ) -> Result<Option<Vec<u8>>, RegistryError> {
         let key = build_contract_code_ledger_key(wasm_hash)?;
         let response = self
             .rpc_call::<GetLedgerEntriesResult>(
@@ -325,7 +366,7 @@ impl OnChainVerifier {
             ));
         };
 
-        Ok(Some(verifier::hash_wasm(code.as_slice())))
+        Ok(Some(code.to_vec()))
     }
 
     async fn fetch_recent_activity_count(
@@ -590,6 +631,14 @@ mod tests {
             relevance_score: None,
             visibility: shared::VisibilityType::Public,
             current_version: None,
+            lock_version: 1,
+            auto_indexed: false,
+            claimed_at: None,
+            badges: Vec::new(),
+            quality_score: None,
+            publisher: None,
+            latest_version: None,
+            linked_from: None,
         };
 
         assert_eq!(
@@ -45,6 +45,130 @@ use utoipa::OpenApi;
         handlers::update_contract_metadata,
         handlers::change_contract_publisher,
         handlers::update_contract_status,
+        crate::claim_handlers::create_claim_challenge,
+        crate::claim_handlers::claim_contract,
+        crate::anchor_handlers::get_anchor_status,
+        crate::anomaly_handlers::get_contract_anomalies,
+        crate::event_ingest_handlers::ingest_events_batch,
+        crate::event_query_handlers::get_contract_events,
+        crate::event_query_handlers::query_events,
+        crate::event_query_handlers::export_events,
+        crate::accounting_report_handlers::get_accounting_report,
+        crate::patch_rollout_handlers::create_patch_rollout,
+        crate::patch_rollout_handlers::get_patch_rollout,
+        crate::patch_rollout_handlers::pause_patch_rollout,
+        crate::patch_rollout_handlers::resume_patch_rollout,
+        crate::alert_handlers::create_alert_rule,
+        crate::alert_handlers::list_alert_rules,
+        crate::alert_handlers::delete_alert_rule,
+        crate::alert_handlers::list_alert_triggers,
+        crate::promotion_handlers::promote_contract,
+        crate::promotion_handlers::list_contract_promotions,
+        crate::promotion_handlers::complete_promotion,
+        crate::contract_group_handlers::get_contract_group,
+        crate::contract_group_handlers::link_contract,
+        crate::contract_group_handlers::unlink_contract,
+        crate::contract_permission_handlers::list_permissions,
+        crate::contract_permission_handlers::grant_permissions,
+        crate::contract_permission_handlers::revoke_permission,
+        crate::publisher_stats_handlers::get_publisher_stats,
+        crate::publisher_usage_handlers::get_publisher_usage,
+        crate::verification_metrics_handlers::get_verification_metrics,
+        crate::toolchain_handlers::list_toolchains,
+        crate::toolchain_handlers::get_toolchain,
+        crate::toolchain_handlers::create_toolchain,
+        crate::toolchain_handlers::deprecate_toolchain,
+        crate::interface_handlers::list_interfaces,
+        crate::interface_handlers::get_interface,
+        crate::interface_handlers::list_contract_interfaces,
+        crate::interface_handlers::create_interface,
+        crate::interface_handlers::update_interface,
+        crate::interface_handlers::delete_interface,
+        crate::source_bundle_handlers::get_source_bundle,
+        crate::cli_release_handlers::get_latest_release,
+        crate::cli_release_handlers::publish_release,
+        crate::cli_release_handlers::yank_release,
+        crate::telemetry_handlers::ingest_telemetry,
+        crate::telemetry_handlers::get_command_usage,
+        crate::org_policy_handlers::get_org_policy,
+        crate::org_policy_handlers::put_org_policy,
+        crate::org_policy_handlers::check_org_policy,
+        crate::org_network_policy_handlers::list_network_policies,
+        crate::org_network_policy_handlers::set_network_policy,
+        crate::org_network_policy_handlers::clear_network_policy,
+        crate::version_rollout_handlers::resolve_version,
+        crate::version_rollout_handlers::get_version_rollout,
+        crate::version_rollout_handlers::set_version_rollout,
+        crate::version_rollout_handlers::abort_version_rollout,
+        crate::version_yank_handlers::yank_version,
+        crate::version_yank_handlers::unyank_version,
+        crate::undo_handlers::list_pending_actions,
+        crate::undo_handlers::undo_last_action,
+        crate::consumer_handlers::register_consumer,
+        crate::consumer_handlers::list_consumers,
+        crate::consumer_handlers::update_consumer,
+        crate::consumer_handlers::delete_consumer,
+        crate::consumer_handlers::list_consumer_notifications,
+        crate::badge_handlers::create_authority,
+        crate::badge_handlers::revoke_authority,
+        crate::badge_handlers::list_authorities,
+        crate::badge_handlers::submit_attestation,
+        crate::badge_handlers::list_contract_badges,
+        crate::badge_handlers::revoke_badge,
+        crate::audit_report_handlers::attach_audit,
+        crate::audit_report_handlers::list_audits,
+        crate::audit_report_handlers::get_audit_report,
+        crate::bug_bounty_handlers::get_bug_bounty,
+        crate::bug_bounty_handlers::set_bug_bounty,
+        crate::bug_bounty_handlers::delete_bug_bounty,
+        crate::bug_bounty_handlers::get_security_txt,
+        crate::defi_handlers::get_defi_pool,
+        crate::sdk_matrix_handlers::get_sdk_matrix,
+        crate::sdk_matrix_handlers::get_contract_build_meta,
+        crate::compliance_handlers::get_compliance_report,
+        crate::email_verification_handlers::get_email_status,
+        crate::email_verification_handlers::request_email_challenge,
+        crate::email_verification_handlers::verify_email_code,
+        crate::email_verification_handlers::verify_email_link,
+        crate::publisher_profile_handlers::update_profile,
+        crate::publisher_key_handlers::list_my_keys,
+        crate::publisher_key_handlers::register_key,
+        crate::publisher_key_handlers::rotate_key,
+        crate::publisher_key_handlers::revoke_key,
+        crate::publisher_key_handlers::list_publisher_keys,
+        crate::publisher_key_handlers::lookup_key,
+        crate::account_recovery_handlers::open_recovery,
+        crate::account_recovery_handlers::get_recovery,
+        crate::account_recovery_handlers::decide_recovery,
+        crate::account_recovery_handlers::cancel_recovery,
+        crate::account_recovery_handlers::execute_recovery,
+        crate::contract_summary_handlers::get_contract_summary,
+        crate::address_lookup_handlers::get_contract_by_address,
+        crate::address_lookup_handlers::get_versions_by_address,
+        crate::address_lookup_handlers::get_version_by_address,
+        crate::address_lookup_handlers::get_events_by_address,
+        crate::address_lookup_handlers::get_migrations_by_address,
+        crate::wasm_handlers::get_wasm_contracts,
+        crate::status_badge_handlers::get_status_badge,
+        crate::index_handlers::get_index_file,
+        crate::change_feed_handlers::get_changes,
+        crate::tlog_handlers::get_tlog_head,
+        crate::tlog_handlers::get_tlog_proof,
+        crate::tlog_handlers::get_tlog_consistency,
+        crate::nft_handlers::list_nft_tokens,
+        crate::nft_handlers::get_nft_token,
+        crate::oracle_handlers::get_oracle_feed,
+        crate::oracle_handlers::set_oracle_feed,
+        crate::oracle_handlers::delete_oracle_feed,
+        crate::disclosure_handlers::submit_disclosure,
+        crate::disclosure_handlers::list_disclosures,
+        crate::disclosure_handlers::update_disclosure_status,
+        crate::quality_handlers::get_quality,
+        crate::test_run_handlers::submit_test_run,
+        crate::test_run_handlers::list_test_runs,
+        crate::fuzz_campaign_handlers::submit_fuzz_campaign,
+        crate::fuzz_campaign_handlers::list_fuzz_campaigns,
+        crate::wasm_handlers::get_contract_wasm,
         handlers::get_contract_audit_log,
         handlers::get_all_audit_logs,
         handlers::get_deployment_status,
@@ -53,7 +177,8 @@ use utoipa::OpenApi;
         handlers::get_contract_interactions,
         handlers::post_contract_interaction,
         handlers::post_contract_interactions_batch,
-        crate::auth_handlers::get_challenge,
+        crate::auth_handlers::web_auth_challenge,
+        crate::auth_handlers::web_auth_token,
         crate::auth_handlers::verify_challenge,
         breaking_changes::get_breaking_changes,
         custom_metrics_handlers::get_metric_catalog,
@@ -77,6 +202,8 @@ use utoipa::OpenApi;
             ContractExportAcceptedResponse,
             ContractExportStatusResponse,
             ContractGetResponse,
+            ContractActivityStats,
+            GroupDeployment,
             NetworkConfig,
             Network,
             UpgradeStrategy,
@@ -85,10 +212,14 @@ use utoipa::OpenApi;
             VerificationStatus,
             MaturityLevel,
             Publisher,
+            PublisherProfile,
+            LatestVersion,
+            WasmLink,
             ContractStats,
             GraphNode,
             GraphEdge,
             GraphResponse,
+            GraphPageResponse,
             ProtocolComplianceStatus,
             InteroperabilityCapabilityKind,
             InteroperabilityProtocolMatch,
@@ -136,8 +267,150 @@ use utoipa::OpenApi;
             CreateInteractionRequest,
             CreateInteractionBatchRequest,
             crate::auth_handlers::ChallengeResponse,
+            crate::auth_handlers::WebAuthChallenge,
+            crate::auth_handlers::WebAuthTokenRequest,
             crate::auth_handlers::VerifyRequest,
             crate::auth_handlers::VerifyResponse,
+            crate::claim_handlers::ClaimChallengeResponse,
+            crate::claim_handlers::ClaimRequest,
+            crate::anchor_handlers::AnchorStatus,
+            crate::anchor_handlers::ContractAnchor,
+            crate::anomaly_handlers::ContractAnomaly,
+            crate::anomaly_handlers::ContractAnomaliesResponse,
+            crate::event_ingest_handlers::IngestEvent,
+            crate::event_ingest_handlers::IngestEventsRequest,
+            crate::event_ingest_handlers::IngestStatus,
+            crate::event_ingest_handlers::IngestResult,
+            crate::event_ingest_handlers::IngestEventsResponse,
+            crate::event_query_handlers::EventRecord,
+            crate::accounting_report_handlers::AccountingReport,
+            crate::accounting_report_handlers::ReportTotals,
+            crate::accounting_report_handlers::AddressFlow,
+            crate::patch_rollout_handlers::CreateRolloutRequest,
+            crate::patch_rollout_handlers::PatchRolloutStatus,
+            crate::alert_handlers::CreateAlertRuleRequest,
+            crate::alert_handlers::AlertRule,
+            crate::alert_handlers::AlertTrigger,
+            crate::promotion_handlers::PromoteContractRequest,
+            crate::promotion_handlers::CompletePromotionRequest,
+            crate::promotion_handlers::PromotionCheck,
+            crate::promotion_handlers::ContractPromotion,
+            crate::contract_group_handlers::LinkContractRequest,
+            crate::contract_group_handlers::ContractGroup,
+            crate::contract_permission_handlers::GrantPermissionsRequest,
+            crate::contract_permission_handlers::ContractPermission,
+            crate::contract_permissions::ContractCapability,
+            crate::publisher_stats_handlers::PublisherStats,
+            crate::publisher_stats_handlers::PublisherContractStats,
+            crate::publisher_usage_handlers::PublisherUsage,
+            crate::publisher_usage_handlers::FormatUsage,
+            crate::verification_metrics_handlers::VerificationMetrics,
+            crate::verification_metrics_handlers::VerificationBucket,
+            crate::verification_metrics_handlers::FailureReason,
+            crate::toolchain_handlers::Toolchain,
+            crate::toolchain_handlers::CreateToolchainRequest,
+            crate::interfaces::FunctionSignature,
+            crate::interface_handlers::InterfaceRequest,
+            crate::interface_handlers::ContractInterface,
+            crate::interface_handlers::InterfaceMatch,
+            crate::cli_release_handlers::CliReleaseArtifact,
+            crate::cli_release_handlers::CliRelease,
+            crate::cli_release_handlers::CliReleaseManifest,
+            crate::cli_release_handlers::PublishCliReleaseRequest,
+            crate::telemetry_handlers::TelemetryBatch,
+            crate::telemetry_handlers::TelemetryEvent,
+            crate::telemetry_handlers::TelemetryAccepted,
+            crate::telemetry_handlers::CommandUsage,
+            shared::policy::OrgPolicy,
+            shared::policy::ProjectFacts,
+            shared::policy::PolicyViolation,
+            crate::org_policy_handlers::OrgPolicyResponse,
+            crate::org_policy_handlers::PolicyReport,
+            crate::org_network_policy_handlers::SetNetworkPolicyRequest,
+            crate::org_network_policy_handlers::NetworkPolicy,
+            crate::org_network_policy_handlers::NetworkPoliciesResponse,
+            crate::version_rollout_handlers::VersionRollout,
+            crate::version_rollout_handlers::SetRolloutRequest,
+            crate::version_rollout_handlers::ResolvedVersion,
+            crate::consumer_handlers::RegisterConsumerRequest,
+            crate::consumer_handlers::UpdateConsumerRequest,
+            crate::consumer_handlers::Consumer,
+            crate::consumer_handlers::ConsumerNotification,
+            crate::badge_handlers::CreateAuthorityRequest,
+            crate::badge_handlers::AttestationAuthority,
+            crate::badge_handlers::SubmitAttestationRequest,
+            crate::badge_handlers::RevokeBadgeRequest,
+            crate::badge_handlers::ContractBadge,
+            crate::audit_report_handlers::FindingCounts,
+            crate::audit_report_handlers::AttachAuditRequest,
+            crate::audit_report_handlers::ContractAudit,
+            crate::bug_bounty_handlers::SetBugBountyRequest,
+            crate::bug_bounty_handlers::BugBounty,
+            crate::defi_handlers::DefiPool,
+            crate::sdk_matrix_handlers::SdkMatrix,
+            crate::sdk_matrix_handlers::SdkMatrixCell,
+            crate::sdk_matrix_handlers::VersionBuildMeta,
+            crate::compliance_handlers::ComplianceReport,
+            crate::compliance_handlers::ComplianceEntry,
+            crate::compliance_handlers::LicenseCount,
+            crate::compliance_handlers::UnresolvedDependency,
+            crate::email_verification_handlers::EmailChallengeRequest,
+            crate::email_verification_handlers::EmailChallengeResponse,
+            crate::email_verification_handlers::VerifyEmailRequest,
+            crate::email_verification_handlers::EmailStatus,
+            crate::publisher_profile_handlers::UpdateProfileRequest,
+            SigningKey,
+            RegisterSigningKeyRequest,
+            RotateSigningKeyRequest,
+            RevokeSigningKeyRequest,
+            crate::publisher_key_handlers::RevokedKey,
+            crate::account_recovery_handlers::OpenRecoveryRequest,
+            crate::account_recovery_handlers::RecoveryDecisionRequest,
+            crate::account_recovery_handlers::AccountRecovery,
+            crate::account_recovery_handlers::RecoveryApproval,
+            crate::account_recovery_handlers::RecoveryInfo,
+            crate::account_recovery_handlers::RecoveryDecisionResponse,
+            crate::account_recovery_handlers::RecoveryExecuted,
+            crate::multisig_handlers::ApprovalDecision,
+            crate::contract_summary_handlers::ContractSummary,
+            crate::contract_summary_handlers::TokenSummary,
+            crate::contract_summary_handlers::RiskFlag,
+            crate::contract_summary_handlers::RiskCode,
+            crate::contract_summary_handlers::RiskLevel,
+            crate::wasm_handlers::WasmDeployment,
+            crate::wasm_handlers::WasmDeploymentsResponse,
+            crate::status_badge_handlers::BadgeStyle,
+            crate::index_handlers::IndexConfig,
+            crate::index_handlers::IndexChanges,
+            crate::index_handlers::IndexChange,
+            crate::index_handlers::IndexFile,
+            crate::index_handlers::IndexPackage,
+            crate::index_handlers::IndexDeployment,
+            crate::index_handlers::IndexVersion,
+            crate::change_feed_handlers::RegistryChange,
+            crate::change_feed_handlers::ChangeFeedPage,
+            crate::tlog_handlers::TlogHead,
+            crate::tlog_handlers::TlogEntry,
+            crate::tlog_handlers::InclusionProof,
+            crate::tlog_handlers::ConsistencyProof,
+            crate::version_yank_handlers::YankRequest,
+            crate::undo::PendingAction,
+            crate::undo::DeferredAction,
+            crate::nft_handlers::NftToken,
+            crate::nft_handlers::NftCollection,
+            crate::oracle_handlers::SetOracleFeedRequest,
+            crate::oracle_handlers::OracleFeed,
+            OracleFreshness,
+            crate::disclosure_handlers::SubmitDisclosureRequest,
+            crate::disclosure_handlers::DisclosureReceipt,
+            crate::disclosure_handlers::SealedDisclosure,
+            crate::disclosure_handlers::UpdateDisclosureStatusRequest,
+            crate::quality::QualityComponent,
+            crate::quality_handlers::QualityBreakdown,
+            crate::test_run_handlers::SubmitTestRunRequest,
+            crate::test_run_handlers::ContractTestRun,
+            crate::fuzz_campaign_handlers::SubmitFuzzCampaignRequest,
+            crate::fuzz_campaign_handlers::ContractFuzzCampaign,
             breaking_changes::ChangeSeverity,
             breaking_changes::BreakingChange,
             breaking_changes::BreakingChangeReport,
@@ -179,8 +452,14 @@ use utoipa::OpenApi;
         (name = "Administration", description = "Administrative audit logs"),
         (name = "Deployments", description = "Deployment management"),
         (name = "Versions", description = "Contract version history and management"),
-        (name = "Security", description = "Security and trust score assessments"),
+        (name = "Security", description = "Security and trust score assessments, bug bounty programs, security.txt and the disclosure inbox"),
         (name = "Reviews", description = "Contract reviews and ratings"),
+        (name = "Alerts", description = "User-defined alert rules on contract events"),
+        (name = "CLI", description = "CLI release channel, self-update manifests and opt-in usage telemetry"),
+        (name = "Organizations", description = "Organization publish policies"),
+        (name = "Consumers", description = "Apps registered against pinned contract versions and the notifications they receive"),
+        (name = "Badges", description = "Contract badges granted by signed attestations from recognised authorities"),
+        (name = "Audits", description = "Audit reports and findings summaries attached to contract versions"),
     ),
     modifiers(&SecurityAddon)
 )]
@@ -0,0 +1,302 @@
+//! Oracle feed cadence and freshness
+//!
+//!   GET    /api/contracts/:id/oracle  – the feed's cadence and freshness
+//!   PUT    /api/contracts/:id/oracle  – declare the cadence (publisher only)
+//!   DELETE /api/contracts/:id/oracle  – stop tracking the feed (publisher only)
+//!
+//! Only contracts in the oracle category can declare a cadence. Last-update
+//! times come from ingested events and staleness alerts from the monitor in
+//! [`crate::oracles`].
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::models::OracleFreshness;
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{authorize, ContractCapability},
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    oracles::{self, ORACLE_CATEGORY, STALE_CONDITION},
+    state::AppState,
+};
+
+/// Thirty days; slower feeds are not worth alerting on.
+const MAX_INTERVAL_SECONDS: i32 = 30 * 86_400;
+const MAX_UPDATE_TOPICS: usize = 20;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetOracleFeedRequest {
+    /// How often the feed is expected to update, in seconds
+    pub update_interval_seconds: i32,
+    /// Extra slack before the feed counts as stale; defaults to 0
+    pub grace_seconds: Option<i32>,
+    /// Event topics that count as an update; any event when omitted
+    pub update_topics: Option<Vec<String>>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct OracleFeedRow {
+    contract_id: Uuid,
+    update_interval_seconds: i32,
+    grace_seconds: i32,
+    update_topics: Vec<String>,
+    last_update_at: Option<DateTime<Utc>>,
+    last_update_ledger: Option<i64>,
+    stale_since: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct OracleFeed {
+    pub contract_id: Uuid,
+    pub update_interval_seconds: i32,
+    pub grace_seconds: i32,
+    pub update_topics: Vec<String>,
+    /// Timestamp of the newest matching ingested event
+    pub last_update_at: Option<DateTime<Utc>>,
+    pub last_update_ledger: Option<i64>,
+    pub freshness: OracleFreshness,
+    /// When the feed goes (or went) stale without another update
+    pub stale_after: DateTime<Utc>,
+    /// Set by the monitor when it raised the current staleness alert
+    pub stale_since: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<OracleFeedRow> for OracleFeed {
+    fn from(row: OracleFeedRow) -> Self {
+        let stale_after = oracles::stale_after(
+            row.last_update_at,
+            row.created_at,
+            row.update_interval_seconds,
+            row.grace_seconds,
+        );
+        Self {
+            contract_id: row.contract_id,
+            update_interval_seconds: row.update_interval_seconds,
+            grace_seconds: row.grace_seconds,
+            update_topics: row.update_topics,
+            last_update_at: row.last_update_at,
+            last_update_ledger: row.last_update_ledger,
+            freshness: oracles::freshness(stale_after, Utc::now()),
+            stale_after,
+            stale_since: row.stale_since,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidOracleFeed", message)
+}
+
+fn feed_not_found() -> ApiError {
+    ApiError::not_found(
+        "OracleFeedNotFound",
+        "This contract has not declared an oracle update cadence",
+    )
+}
+
+/// Restricts a contract query (aliased `c`) to oracle feeds in `freshness`.
+pub(crate) fn push_freshness_filter(
+    qb: &mut QueryBuilder<'_, Postgres>,
+    freshness: OracleFreshness,
+) {
+    qb.push(" AND EXISTS (SELECT 1 FROM oracle_feeds o WHERE o.contract_id = c.id AND ");
+    match freshness {
+        OracleFreshness::Stale => qb.push(STALE_CONDITION),
+        OracleFreshness::Fresh => qb.push("NOT (").push(STALE_CONDITION).push(")"),
+    };
+    qb.push(")");
+}
+
+fn validate_topics(topics: Option<Vec<String>>) -> Result<Vec<String>, String> {
+    let mut topics: Vec<String> = topics
+        .unwrap_or_default()
+        .into_iter()
+        .map(|topic| topic.trim().to_string())
+        .collect();
+    if topics.iter().any(String::is_empty) {
+        return Err("update_topics must not contain empty topics".to_string());
+    }
+    topics.sort();
+    topics.dedup();
+    if topics.len() > MAX_UPDATE_TOPICS {
+        return Err(format!(
+            "at most {} update_topics are allowed",
+            MAX_UPDATE_TOPICS
+        ));
+    }
+    Ok(topics)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/oracle",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "The feed's cadence and freshness", body = OracleFeed),
+        (status = 404, description = "Contract not found, or no cadence declared")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_oracle_feed(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<OracleFeed>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let row: OracleFeedRow = sqlx::query_as("SELECT * FROM oracle_feeds WHERE contract_id = $1")
+        .bind(contract_uuid)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch oracle feed", err))?
+        .ok_or_else(feed_not_found)?;
+    Ok(Json(row.into()))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/contracts/{id}/oracle",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SetOracleFeedRequest,
+    responses(
+        (status = 200, description = "Cadence saved", body = OracleFeed),
+        (status = 400, description = "Invalid interval, grace or topics"),
+        (status = 403, description = "Caller may not edit the contract's metadata"),
+        (status = 404, description = "Contract not found"),
+        (status = 422, description = "Contract is not in the oracle category")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Contracts"
+)]
+pub async fn set_oracle_feed(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(req): Json<SetOracleFeedRequest>,
+) -> ApiResult<Json<OracleFeed>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::EditMetadata,
+        "declare its oracle cadence",
+    )
+    .await?;
+
+    let category: Option<String> =
+        sqlx::query_scalar("SELECT category FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract category", err))?;
+    if !oracles::is_oracle_category(category.as_deref()) {
+        return Err(ApiError::unprocessable(
+            "NotAnOracle",
+            format!(
+                "Only contracts in the '{}' category can declare an update cadence",
+                ORACLE_CATEGORY
+            ),
+        ));
+    }
+
+    if !(1..=MAX_INTERVAL_SECONDS).contains(&req.update_interval_seconds) {
+        return Err(invalid(format!(
+            "update_interval_seconds must be between 1 and {}",
+            MAX_INTERVAL_SECONDS
+        )));
+    }
+    let grace_seconds = req.grace_seconds.unwrap_or(0);
+    if !(0..=MAX_INTERVAL_SECONDS).contains(&grace_seconds) {
+        return Err(invalid(format!(
+            "grace_seconds must be between 0 and {}",
+            MAX_INTERVAL_SECONDS
+        )));
+    }
+    let topics = validate_topics(req.update_topics).map_err(invalid)?;
+
+    // A new cadence starts a new staleness spell; the monitor re-judges it.
+    let row: OracleFeedRow = sqlx::query_as(
+        "INSERT INTO oracle_feeds
+            (contract_id, update_interval_seconds, grace_seconds, update_topics)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET update_interval_seconds = EXCLUDED.update_interval_seconds,
+                grace_seconds = EXCLUDED.grace_seconds,
+                update_topics = EXCLUDED.update_topics,
+                stale_since = NULL,
+                updated_at = NOW()
+         RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(req.update_interval_seconds)
+    .bind(grace_seconds)
+    .bind(&topics)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("save oracle feed", err))?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        interval = req.update_interval_seconds,
+        "oracle cadence declared"
+    );
+    Ok(Json(row.into()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/oracle",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 204, description = "Feed no longer tracked"),
+        (status = 403, description = "Caller may not edit the contract's metadata"),
+        (status = 404, description = "Contract not found, or no cadence declared")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Contracts"
+)]
+pub async fn delete_oracle_feed(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<StatusCode> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::EditMetadata,
+        "stop tracking its oracle feed",
+    )
+    .await?;
+
+    let deleted = sqlx::query("DELETE FROM oracle_feeds WHERE contract_id = $1")
+        .bind(contract_uuid)
+        .execute(&state.db)
+        .await
+        .map_err(|err| db_internal_error("delete oracle feed", err))?
+        .rows_affected();
+    if deleted == 0 {
+        return Err(feed_not_found());
+    }
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,251 @@
+//! Oracle feed freshness
+//!
+//! Publishers of oracle contracts declare how often the feed updates (see
+//! [`crate::oracle_handlers`]). Every `ORACLE_MONITOR_INTERVAL_SECS`
+//! (default 60) the monitor:
+//!
+//! * copies the newest ingested event matching a feed's `update_topics`
+//!   (any topic when empty) into `last_update_at`;
+//! * clears `stale_since` on feeds that have updated again;
+//! * sets `stale_since` on feeds whose last update, or declaration before
+//!   the first one, is older than their cadence plus grace, and queues an
+//!   `oracle_stale` notification for every active subscription following
+//!   that kind.
+
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use shared::models::OracleFreshness;
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+
+/// Category path of oracle contracts; its subcategories count too.
+pub const ORACLE_CATEGORY: &str = "infra/oracle";
+
+/// Holds, for an `oracle_feeds` row aliased `o`, while the feed is stale.
+/// Mirrors [`stale_after`].
+pub(crate) const STALE_CONDITION: &str = "COALESCE(o.last_update_at, o.created_at) \
+     + make_interval(secs => o.update_interval_seconds + o.grace_seconds) < NOW()";
+
+pub fn is_oracle_category(category: Option<&str>) -> bool {
+    category.is_some_and(|path| {
+        path == ORACLE_CATEGORY
+            || path
+                .strip_prefix(ORACLE_CATEGORY)
+                .is_some_and(|rest| rest.starts_with('/'))
+    })
+}
+
+/// When a feed goes stale without another update: its cadence plus grace
+/// after the last update, or after it was declared if none has been seen.
+pub fn stale_after(
+    last_update_at: Option<DateTime<Utc>>,
+    declared_at: DateTime<Utc>,
+    interval_seconds: i32,
+    grace_seconds: i32,
+) -> DateTime<Utc> {
+    last_update_at.unwrap_or(declared_at)
+        + ChronoDuration::seconds(i64::from(interval_seconds) + i64::from(grace_seconds))
+}
+
+pub fn freshness(stale_after: DateTime<Utc>, now: DateTime<Utc>) -> OracleFreshness {
+    if now > stale_after {
+        OracleFreshness::Stale
+    } else {
+        OracleFreshness::Fresh
+    }
+}
+
+fn format_duration(seconds: i64) -> String {
+    match seconds {
+        s if s >= 86_400 => format!("{}d {}h", s / 86_400, s % 86_400 / 3600),
+        s if s >= 3600 => format!("{}h {}m", s / 3600, s % 3600 / 60),
+        s if s >= 60 => format!("{}m {}s", s / 60, s % 60),
+        s => format!("{}s", s),
+    }
+}
+
+fn stale_message(
+    name: &str,
+    last_update_at: Option<DateTime<Utc>>,
+    interval_seconds: i32,
+    now: DateTime<Utc>,
+) -> String {
+    let expected = format_duration(i64::from(interval_seconds));
+    match last_update_at {
+        Some(at) => format!(
+            "Oracle \"{}\" has not updated for {} (expected every {})",
+            name,
+            format_duration((now - at).num_seconds()),
+            expected
+        ),
+        None => format!(
+            "Oracle \"{}\" has not reported an update since its cadence was declared (expected every {})",
+            name, expected
+        ),
+    }
+}
+
+// ── Monitor ───────────────────────────────────────────────────────────────────
+
+#[derive(Debug, sqlx::FromRow)]
+struct StaleFeed {
+    contract_id: Uuid,
+    name: String,
+    last_update_at: Option<DateTime<Utc>>,
+    update_interval_seconds: i32,
+    stale_since: DateTime<Utc>,
+}
+
+pub fn spawn_oracle_monitor(pool: PgPool) {
+    let interval_secs = std::env::var("ORACLE_MONITOR_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = scan(&pool).await {
+                tracing::error!(error = ?err, "oracles: freshness scan failed");
+            }
+        }
+    });
+}
+
+async fn scan(pool: &PgPool) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "WITH latest AS (
+            SELECT o.contract_id, e.timestamp, e.ledger_sequence
+            FROM oracle_feeds o
+            JOIN contracts c ON c.id = o.contract_id
+            CROSS JOIN LATERAL (
+                SELECT ev.timestamp, ev.ledger_sequence
+                FROM contract_events ev
+                WHERE ev.contract_id = c.contract_id AND ev.network = c.network
+                  AND (cardinality(o.update_topics) = 0 OR ev.topic = ANY(o.update_topics))
+                ORDER BY ev.timestamp DESC
+                LIMIT 1
+            ) e
+         )
+         UPDATE oracle_feeds o
+         SET last_update_at = latest.timestamp,
+             last_update_ledger = latest.ledger_sequence,
+             updated_at = NOW()
+         FROM latest
+         WHERE o.contract_id = latest.contract_id
+           AND (o.last_update_at IS NULL OR latest.timestamp > o.last_update_at)",
+    )
+    .execute(pool)
+    .await?;
+
+    let recovered = sqlx::query(&format!(
+        "UPDATE oracle_feeds o SET stale_since = NULL, updated_at = NOW()
+         WHERE o.stale_since IS NOT NULL AND NOT ({})",
+        STALE_CONDITION
+    ))
+    .execute(pool)
+    .await?
+    .rows_affected();
+    if recovered > 0 {
+        tracing::info!(feeds = recovered, "oracles: feeds fresh again");
+    }
+
+    let newly_stale: Vec<StaleFeed> = sqlx::query_as(&format!(
+        "WITH stale AS (
+            UPDATE oracle_feeds o SET stale_since = NOW(), updated_at = NOW()
+            WHERE o.stale_since IS NULL AND {}
+              AND EXISTS (SELECT 1 FROM contracts c WHERE c.id = o.contract_id AND c.deleted_at IS NULL)
+            RETURNING o.contract_id, o.last_update_at, o.update_interval_seconds, o.stale_since
+         )
+         SELECT s.contract_id, c.name, s.last_update_at, s.update_interval_seconds,
+                s.stale_since
+         FROM stale s
+         JOIN contracts c ON c.id = s.contract_id",
+        STALE_CONDITION
+    ))
+    .fetch_all(pool)
+    .await?;
+
+    for feed in &newly_stale {
+        let message = stale_message(
+            &feed.name,
+            feed.last_update_at,
+            feed.update_interval_seconds,
+            feed.stale_since,
+        );
+        let queued = sqlx::query(
+            "INSERT INTO notification_queue (
+                subscription_id, notification_type, title, message, contract_id, channels,
+                priority, metadata
+             )
+             SELECT s.id, 'oracle_stale', $2, $3, s.contract_id, s.channels, 2, $4
+             FROM contract_subscriptions s
+             WHERE s.contract_id = $1 AND s.status = 'active'
+               AND 'oracle_stale' = ANY(s.notification_types)",
+        )
+        .bind(feed.contract_id)
+        .bind("Oracle feed is stale")
+        .bind(&message)
+        .bind(serde_json::json!({
+            "last_update_at": feed.last_update_at,
+            "update_interval_seconds": feed.update_interval_seconds,
+            "stale_since": feed.stale_since,
+        }))
+        .execute(pool)
+        .await?
+        .rows_affected();
+        tracing::info!(
+            contract = %feed.contract_id,
+            subscribers = queued,
+            "oracles: feed went stale"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 4, 2, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn oracle_category_includes_subcategories() {
+        assert!(is_oracle_category(Some("infra/oracle")));
+        assert!(is_oracle_category(Some("infra/oracle/price")));
+        assert!(!is_oracle_category(Some("infra/oracles")));
+        assert!(!is_oracle_category(Some("infra")));
+        assert!(!is_oracle_category(None));
+    }
+
+    #[test]
+    fn feeds_go_stale_after_cadence_plus_grace() {
+        let deadline = stale_after(Some(at(12, 0)), at(9, 0), 300, 60);
+        assert_eq!(deadline, at(12, 6));
+        assert_eq!(freshness(deadline, at(12, 6)), OracleFreshness::Fresh);
+        assert_eq!(freshness(deadline, at(12, 7)), OracleFreshness::Stale);
+    }
+
+    #[test]
+    fn feeds_without_updates_are_judged_from_declaration() {
+        let deadline = stale_after(None, at(9, 0), 3600, 0);
+        assert_eq!(freshness(deadline, at(9, 30)), OracleFreshness::Fresh);
+        assert_eq!(freshness(deadline, at(10, 1)), OracleFreshness::Stale);
+    }
+
+    #[test]
+    fn stale_message_reports_age_and_cadence() {
+        assert_eq!(
+            stale_message("XLM/USD", Some(at(9, 0)), 300, at(10, 30)),
+            "Oracle \"XLM/USD\" has not updated for 1h 30m (expected every 5m 0s)"
+        );
+        assert!(stale_message("XLM/USD", None, 86_400, at(10, 0)).contains("expected every 1d 0h"));
+    }
+}
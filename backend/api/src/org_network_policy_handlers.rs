@@ -0,0 +1,415 @@
+//! Per-network deployment policies
+//!
+//!   GET    /api/organizations/:id/network-policies            – the org's gated networks
+//!   PUT    /api/organizations/:id/network-policies/:network   – require a multisig policy (org admins)
+//!   DELETE /api/organizations/:id/network-policies/:network   – lift the requirement (org admins)
+//!
+//! Once a network is gated, `POST /api/contracts` by a member of the
+//! organization and `POST /api/migrations` for one of its contracts no longer
+//! run directly on that network. The first attempt opens a deploy proposal
+//! under the policy and answers `202 Accepted` with it; retrying while the
+//! proposal is in flight returns the same proposal. After the signers approve
+//! and the proposal is executed, the next attempt goes through and marks the
+//! proposal fulfilled, so each approval clears exactly one deployment.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{Network, OrganizationRole};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    multisig_handlers::{self, CreateDeployProposalRequest, DeployProposal, PROPOSAL_COLUMNS},
+    org_handlers::check_org_role,
+    org_policy_handlers::visible_org,
+    state::AppState,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetNetworkPolicyRequest {
+    /// Multisig policy every deployment on the network must pass
+    pub policy_id: Uuid,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct NetworkPolicy {
+    pub network: Network,
+    pub policy_id: Uuid,
+    pub policy_name: String,
+    pub threshold: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct NetworkPoliciesResponse {
+    pub organization_id: Uuid,
+    pub organization_slug: String,
+    pub policies: Vec<NetworkPolicy>,
+}
+
+/// Body of the `202 Accepted` a gated publish or migration answers with.
+#[derive(Debug, Serialize)]
+pub struct ApprovalRequired {
+    pub organization: String,
+    pub message: String,
+    pub proposal: DeployProposal,
+}
+
+/// The organization policy a deployment falls under.
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct DeploymentGate {
+    pub organization_slug: String,
+    pub policy_id: Uuid,
+}
+
+/// What is being put on the network.
+pub(crate) struct GatedDeployment<'a> {
+    pub contract_name: &'a str,
+    pub contract_id: &'a str,
+    pub wasm_hash: &'a str,
+    pub network: &'a Network,
+    pub proposer: Option<&'a str>,
+}
+
+// ── Enforcement ───────────────────────────────────────────────────────────────
+
+/// Policy gating deployments on `network` by `publisher_address`, through any
+/// organization they belong to.
+pub(crate) async fn gate_for_publisher(
+    state: &AppState,
+    publisher_address: &str,
+    network: &Network,
+) -> ApiResult<Option<DeploymentGate>> {
+    sqlx::query_as(
+        "SELECT o.slug AS organization_slug, onp.policy_id
+         FROM org_network_policies onp
+         JOIN organizations o ON o.id = onp.organization_id
+         JOIN organization_members om ON om.organization_id = onp.organization_id
+         JOIN publishers p ON p.id = om.publisher_id
+         WHERE p.stellar_address = $1 AND onp.network = $2
+         ORDER BY onp.created_at
+         LIMIT 1",
+    )
+    .bind(publisher_address)
+    .bind(network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch network policy for publisher", err))
+}
+
+/// Policy gating upgrades of the registered contract `contract_id`, with the
+/// contract's name and network. The contract is covered when it belongs to the
+/// organization or its publisher is a member.
+pub(crate) async fn gate_for_contract(
+    state: &AppState,
+    contract_id: &str,
+) -> ApiResult<Option<(DeploymentGate, String, Network)>> {
+    let row: Option<(String, Uuid, String, Network)> = sqlx::query_as(
+        "SELECT o.slug, onp.policy_id, c.name, c.network
+         FROM contracts c
+         JOIN org_network_policies onp
+           ON onp.network = c.network
+          AND (onp.organization_id = c.organization_id
+               OR onp.organization_id IN (
+                   SELECT om.organization_id FROM organization_members om
+                   WHERE om.publisher_id = c.publisher_id
+               ))
+         JOIN organizations o ON o.id = onp.organization_id
+         WHERE c.contract_id = $1 AND c.deleted_at IS NULL
+         ORDER BY onp.created_at
+         LIMIT 1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch network policy for contract", err))?;
+
+    Ok(row.map(|(organization_slug, policy_id, name, network)| {
+        (
+            DeploymentGate {
+                organization_slug,
+                policy_id,
+            },
+            name,
+            network,
+        )
+    }))
+}
+
+/// Checks a gated deployment against its policy. Returns `None` when an
+/// executed proposal clears it, which is then spent, or the proposal the
+/// deployment is waiting on, opening one if none is in flight.
+pub(crate) async fn require_approval(
+    state: &AppState,
+    tenant_id: Uuid,
+    gate: &DeploymentGate,
+    deployment: GatedDeployment<'_>,
+) -> ApiResult<Option<DeployProposal>> {
+    let cleared: Option<Uuid> = sqlx::query_scalar(
+        "UPDATE deploy_proposals SET fulfilled_at = NOW(), updated_at = NOW()
+         WHERE id = (
+             SELECT id FROM deploy_proposals
+             WHERE policy_id = $1 AND contract_id = $2 AND wasm_hash = $3 AND network = $4
+               AND action = 'deploy' AND status = 'executed' AND fulfilled_at IS NULL
+             ORDER BY executed_at
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED
+         )
+         RETURNING id",
+    )
+    .bind(gate.policy_id)
+    .bind(deployment.contract_id)
+    .bind(deployment.wasm_hash)
+    .bind(deployment.network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fulfil deploy proposal", err))?;
+    if let Some(proposal_id) = cleared {
+        tracing::info!(
+            proposal = %proposal_id,
+            contract = %deployment.contract_id,
+            network = %deployment.network,
+            "gated deployment cleared by executed proposal"
+        );
+        return Ok(None);
+    }
+
+    let in_flight: Option<DeployProposal> = sqlx::query_as(&format!(
+        "SELECT {} FROM deploy_proposals
+         WHERE policy_id = $1 AND contract_id = $2 AND wasm_hash = $3 AND network = $4
+           AND action = 'deploy'
+           AND (status = 'approved' OR (status = 'pending' AND expires_at > NOW()))
+         ORDER BY created_at DESC
+         LIMIT 1",
+        PROPOSAL_COLUMNS
+    ))
+    .bind(gate.policy_id)
+    .bind(deployment.contract_id)
+    .bind(deployment.wasm_hash)
+    .bind(deployment.network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch in-flight deploy proposal", err))?;
+    if in_flight.is_some() {
+        return Ok(in_flight);
+    }
+
+    let proposer = deployment
+        .proposer
+        .filter(|p| !p.is_empty())
+        .ok_or_else(|| {
+            ApiError::bad_request(
+                "ProposerRequired",
+                format!(
+                    "{} deployments for {} require multisig approval; provide a proposer to open a proposal",
+                    deployment.network, gate.organization_slug
+                ),
+            )
+        })?;
+    let proposal = multisig_handlers::open_deploy_proposal(
+        state,
+        tenant_id,
+        proposer,
+        &CreateDeployProposalRequest {
+            contract_name: deployment.contract_name.to_string(),
+            contract_id: deployment.contract_id.to_string(),
+            wasm_hash: deployment.wasm_hash.to_string(),
+            network: deployment.network.clone(),
+            description: Some(format!(
+                "Required by the {} policy for {} deployments",
+                gate.organization_slug, deployment.network
+            )),
+            policy_id: gate.policy_id,
+        },
+    )
+    .await?;
+    tracing::info!(
+        proposal = %proposal.id,
+        organization = %gate.organization_slug,
+        network = %deployment.network,
+        "gated deployment held for multisig approval"
+    );
+    Ok(Some(proposal))
+}
+
+/// `202 Accepted` response for a deployment held by `proposal`.
+pub(crate) fn approval_required(gate: DeploymentGate, proposal: DeployProposal) -> Response {
+    let message = format!(
+        "{} requires multisig approval for this deployment; rerun it once proposal {} is executed",
+        gate.organization_slug, proposal.id
+    );
+    (
+        StatusCode::ACCEPTED,
+        Json(ApprovalRequired {
+            organization: gate.organization_slug,
+            message,
+            proposal,
+        }),
+    )
+        .into_response()
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+async fn load_network_policies(state: &AppState, org_id: Uuid) -> ApiResult<Vec<NetworkPolicy>> {
+    sqlx::query_as(
+        "SELECT onp.network, onp.policy_id, mp.name AS policy_name, mp.threshold, onp.updated_at
+         FROM org_network_policies onp
+         JOIN multisig_policies mp ON mp.id = onp.policy_id
+         WHERE onp.organization_id = $1
+         ORDER BY onp.network",
+    )
+    .bind(org_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch network policies", err))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{id}/network-policies",
+    params(("id" = String, Path, description = "Organization UUID or slug")),
+    responses(
+        (status = 200, description = "Networks whose deployments require multisig approval", body = NetworkPoliciesResponse),
+        (status = 403, description = "Private organization"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations"
+)]
+pub async fn list_network_policies(
+    State(state): State<AppState>,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<NetworkPoliciesResponse>> {
+    let org = visible_org(&state, claims.as_ref(), &id).await?;
+    let policies = load_network_policies(&state, org.id).await?;
+    Ok(Json(NetworkPoliciesResponse {
+        organization_id: org.id,
+        organization_slug: org.slug,
+        policies,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizations/{id}/network-policies/{network}",
+    params(
+        ("id" = String, Path, description = "Organization UUID or slug"),
+        ("network" = Network, Path, description = "Network to gate")
+    ),
+    request_body = SetNetworkPolicyRequest,
+    responses(
+        (status = 200, description = "Network gated", body = NetworkPoliciesResponse),
+        (status = 403, description = "Caller is not an organization admin"),
+        (status = 404, description = "Organization or multisig policy not found")
+    ),
+    tag = "Organizations"
+)]
+pub async fn set_network_policy(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, network)): Path<(String, Network)>,
+    Json(payload): Json<SetNetworkPolicyRequest>,
+) -> ApiResult<Json<NetworkPoliciesResponse>> {
+    let org = visible_org(&state, Some(&claims), &id).await?;
+    check_org_role(&state.db, org.id, &claims.sub, OrganizationRole::Admin).await?;
+
+    let policy_exists: bool =
+        sqlx::query_scalar("SELECT EXISTS(SELECT 1 FROM multisig_policies WHERE id = $1)")
+            .bind(payload.policy_id)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("check multisig policy", err))?;
+    if !policy_exists {
+        return Err(ApiError::not_found(
+            "PolicyNotFound",
+            "multisig policy not found",
+        ));
+    }
+
+    sqlx::query(
+        "INSERT INTO org_network_policies (organization_id, network, policy_id, updated_by)
+         VALUES ($1, $2, $3, (SELECT id FROM publishers WHERE stellar_address = $4))
+         ON CONFLICT (organization_id, network) DO UPDATE
+            SET policy_id = EXCLUDED.policy_id,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = NOW()",
+    )
+    .bind(org.id)
+    .bind(&network)
+    .bind(payload.policy_id)
+    .bind(&claims.sub)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("upsert network policy", err))?;
+
+    tracing::info!(
+        organization = %org.slug,
+        network = %network,
+        policy = %payload.policy_id,
+        by = %claims.sub,
+        "network deployment policy set"
+    );
+    let policies = load_network_policies(&state, org.id).await?;
+    Ok(Json(NetworkPoliciesResponse {
+        organization_id: org.id,
+        organization_slug: org.slug,
+        policies,
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/organizations/{id}/network-policies/{network}",
+    params(
+        ("id" = String, Path, description = "Organization UUID or slug"),
+        ("network" = Network, Path, description = "Network to stop gating")
+    ),
+    responses(
+        (status = 200, description = "Requirement lifted", body = NetworkPoliciesResponse),
+        (status = 403, description = "Caller is not an organization admin"),
+        (status = 404, description = "Organization not found or network not gated")
+    ),
+    tag = "Organizations"
+)]
+pub async fn clear_network_policy(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, network)): Path<(String, Network)>,
+) -> ApiResult<Json<NetworkPoliciesResponse>> {
+    let org = visible_org(&state, Some(&claims), &id).await?;
+    check_org_role(&state.db, org.id, &claims.sub, OrganizationRole::Admin).await?;
+
+    let removed =
+        sqlx::query("DELETE FROM org_network_policies WHERE organization_id = $1 AND network = $2")
+            .bind(org.id)
+            .bind(&network)
+            .execute(&state.db)
+            .await
+            .map_err(|err| db_internal_error("delete network policy", err))?
+            .rows_affected();
+    if removed == 0 {
+        return Err(ApiError::not_found(
+            "NetworkPolicyNotFound",
+            format!("{} deployments are not gated for {}", network, org.slug),
+        ));
+    }
+
+    tracing::info!(organization = %org.slug, network = %network, by = %claims.sub, "network deployment policy cleared");
+    let policies = load_network_policies(&state, org.id).await?;
+    Ok(Json(NetworkPoliciesResponse {
+        organization_id: org.id,
+        organization_slug: org.slug,
+        policies,
+    }))
+}
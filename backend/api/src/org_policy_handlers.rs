@@ -0,0 +1,193 @@
+//! Organization publish policies
+//!
+//!   GET  /api/organizations/:id/policy          – the org's policy set
+//!   PUT  /api/organizations/:id/policy          – replace it (org admins)
+//!   POST /api/organizations/:id/policy/check    – evaluate a project against it
+//!
+//! `:id` is the organization's UUID or slug. Rules are evaluated by
+//! `shared::policy`, which `soroban-registry policy check` also runs locally,
+//! so CI fails with the same violation codes the registry reports. Private
+//! organizations only show their policy to members.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use shared::{
+    policy::{OrgPolicy, PolicyViolation, ProjectFacts},
+    Organization, OrganizationRole,
+};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    org_handlers::check_org_role,
+    state::AppState,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct OrgPolicyResponse {
+    pub organization_id: Uuid,
+    pub organization_slug: String,
+    pub policy: OrgPolicy,
+    /// `None` while the organization has not set a policy
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PolicyReport {
+    pub passed: bool,
+    pub violations: Vec<PolicyViolation>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Resolves `:id` and enforces visibility of private organizations.
+pub(crate) async fn visible_org(
+    state: &AppState,
+    claims: Option<&AuthClaims>,
+    id_or_slug: &str,
+) -> ApiResult<Organization> {
+    let org: Organization =
+        sqlx::query_as("SELECT * FROM organizations WHERE id::TEXT = $1 OR slug = $1 LIMIT 1")
+            .bind(id_or_slug)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch organization", err))?
+            .ok_or_else(|| ApiError::not_found("OrganizationNotFound", "Organization not found"))?;
+
+    if org.is_private {
+        let Some(claims) = claims else {
+            return Err(ApiError::forbidden("Access denied to private organization"));
+        };
+        check_org_role(&state.db, org.id, &claims.sub, OrganizationRole::Viewer).await?;
+    }
+    Ok(org)
+}
+
+async fn load_policy(
+    state: &AppState,
+    org_id: Uuid,
+) -> ApiResult<(OrgPolicy, Option<DateTime<Utc>>)> {
+    let row: Option<(sqlx::types::Json<OrgPolicy>, DateTime<Utc>)> =
+        sqlx::query_as("SELECT rules, updated_at FROM org_policies WHERE organization_id = $1")
+            .bind(org_id)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch org policy", err))?;
+    Ok(match row {
+        Some((rules, updated_at)) => (rules.0, Some(updated_at)),
+        None => (OrgPolicy::default(), None),
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/organizations/{id}/policy",
+    params(("id" = String, Path, description = "Organization UUID or slug")),
+    responses(
+        (status = 200, description = "The organization's policy; empty when none is set", body = OrgPolicyResponse),
+        (status = 403, description = "Private organization"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations"
+)]
+pub async fn get_org_policy(
+    State(state): State<AppState>,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<OrgPolicyResponse>> {
+    let org = visible_org(&state, claims.as_ref(), &id).await?;
+    let (policy, updated_at) = load_policy(&state, org.id).await?;
+    Ok(Json(OrgPolicyResponse {
+        organization_id: org.id,
+        organization_slug: org.slug,
+        policy,
+        updated_at,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/organizations/{id}/policy",
+    params(("id" = String, Path, description = "Organization UUID or slug")),
+    request_body = OrgPolicy,
+    responses(
+        (status = 200, description = "Policy replaced", body = OrgPolicyResponse),
+        (status = 400, description = "Invalid rules"),
+        (status = 403, description = "Caller is not an organization admin"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations"
+)]
+pub async fn put_org_policy(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+    Json(policy): Json<OrgPolicy>,
+) -> ApiResult<Json<OrgPolicyResponse>> {
+    let org = visible_org(&state, Some(&claims), &id).await?;
+    check_org_role(&state.db, org.id, &claims.sub, OrganizationRole::Admin).await?;
+    policy
+        .validate()
+        .map_err(|message| ApiError::bad_request("InvalidOrgPolicy", message))?;
+
+    let updated_at: DateTime<Utc> = sqlx::query_scalar(
+        "INSERT INTO org_policies (organization_id, rules, updated_by, updated_at)
+         VALUES ($1, $2, (SELECT id FROM publishers WHERE stellar_address = $3), NOW())
+         ON CONFLICT (organization_id) DO UPDATE
+            SET rules = EXCLUDED.rules,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+         RETURNING updated_at",
+    )
+    .bind(org.id)
+    .bind(sqlx::types::Json(&policy))
+    .bind(&claims.sub)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("upsert org policy", err))?;
+
+    tracing::info!(organization = %org.slug, by = %claims.sub, "organization policy updated");
+    Ok(Json(OrgPolicyResponse {
+        organization_id: org.id,
+        organization_slug: org.slug,
+        policy,
+        updated_at: Some(updated_at),
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/organizations/{id}/policy/check",
+    params(("id" = String, Path, description = "Organization UUID or slug")),
+    request_body = ProjectFacts,
+    responses(
+        (status = 200, description = "Evaluation result; `passed` is false when any rule is broken", body = PolicyReport),
+        (status = 403, description = "Private organization"),
+        (status = 404, description = "Organization not found")
+    ),
+    tag = "Organizations"
+)]
+pub async fn check_org_policy(
+    State(state): State<AppState>,
+    claims: Option<AuthClaims>,
+    Path(id): Path<String>,
+    Json(facts): Json<ProjectFacts>,
+) -> ApiResult<Json<PolicyReport>> {
+    let org = visible_org(&state, claims.as_ref(), &id).await?;
+    let (policy, _) = load_policy(&state, org.id).await?;
+    let violations = policy.evaluate(&facts);
+    Ok(Json(PolicyReport {
+        passed: violations.is_empty(),
+        violations,
+    }))
+}
@@ -19,6 +19,7 @@ use crate::{
     error::{ApiError, ApiResult},
     handlers::db_internal_error,
     state::AppState,
+    tenant::Tenant,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -438,9 +439,10 @@ async fn fetch_abi(
 )]
 pub async fn list_contract_patches(
     State(state): State<AppState>,
+    tenant: Tenant,
     Path(id): Path<String>,
 ) -> ApiResult<Json<PatchListResponse>> {
-    let contract_uuid = resolve_contract_uuid(&state, &id).await?;
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
 
     let patches: Vec<ContractPatch> = sqlx::query_as(
         "SELECT id, contract_id, from_version, to_version, patch, \
@@ -490,9 +492,10 @@ pub async fn list_contract_patches(
 )]
 pub async fn get_patch_between_versions(
     State(state): State<AppState>,
+    tenant: Tenant,
     Path((id, from_ver, to_ver)): Path<(String, String, String)>,
 ) -> ApiResult<Json<ContractPatch>> {
-    let contract_uuid = resolve_contract_uuid(&state, &id).await?;
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
     // "base" is the user-facing alias for the sentinel empty string.
     let from_str: &str = if from_ver == "base" { "" } else { &from_ver };
 
@@ -535,10 +538,11 @@ pub async fn get_patch_between_versions(
 )]
 pub async fn reconstruct_contract_version(
     State(state): State<AppState>,
+    tenant: Tenant,
     Path(id): Path<String>,
     Json(req): Json<ReconstructRequest>,
 ) -> ApiResult<Json<ReconstructedVersion>> {
-    let contract_uuid = resolve_contract_uuid(&state, &id).await?;
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
 
     let result = reconstruct_version(&state.db, contract_uuid, &req.target_version)
         .await
@@ -570,6 +574,7 @@ pub async fn reconstruct_contract_version(
 )]
 pub async fn bulk_apply_patches(
     State(state): State<AppState>,
+    tenant: Tenant,
     Json(req): Json<BulkApplyRequest>,
 ) -> ApiResult<Json<BulkApplyResponse>> {
     if req.targets.is_empty() {
@@ -589,7 +594,7 @@ pub async fn bulk_apply_patches(
 
     for target in &req.targets {
         let result = async {
-            let contract_uuid = resolve_contract_uuid(&state, &target.contract_id).await?;
+            let contract_uuid = resolve_contract_uuid(&state, &tenant, &target.contract_id).await?;
             let version = reconstruct_version(&state.db, contract_uuid, &target.target_version)
                 .await
                 .map_err(|err| db_internal_error("reconstruct version (bulk)", err))?;
@@ -629,18 +634,21 @@ pub async fn bulk_apply_patches(
 // Helpers
 // ─────────────────────────────────────────────────────────────────────────────
 
-async fn resolve_contract_uuid(state: &AppState, id: &str) -> ApiResult<Uuid> {
-    // Try parsing directly as UUID first.
-    if let Ok(uuid) = Uuid::parse_str(id) {
-        return Ok(uuid);
-    }
-    // Fall back to looking up by contract_id string.
-    let row: Option<(Uuid,)> =
-        sqlx::query_as("SELECT id FROM contracts WHERE contract_id = $1 LIMIT 1")
-            .bind(id)
-            .fetch_optional(&state.db)
-            .await
-            .map_err(|err| db_internal_error("resolve contract uuid", err))?;
+pub(crate) async fn resolve_contract_uuid(state: &AppState, tenant: &Tenant, id: &str) -> ApiResult<Uuid> {
+    // Accept either the registry UUID or the on-chain contract_id, but only
+    // within the caller's tenant.
+    let row: Option<(Uuid,)> = sqlx::query_as(
+        "SELECT id FROM contracts
+         WHERE (id = $1 OR ($1 IS NULL AND contract_id = $2))
+           AND tenant_id = $3 AND deleted_at IS NULL
+         LIMIT 1",
+    )
+    .bind(Uuid::parse_str(id).ok())
+    .bind(id)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("resolve contract uuid", err))?;
 
     row.map(|(uuid,)| uuid).ok_or_else(|| {
         ApiError::not_found("ContractNotFound", format!("Contract '{}' not found", id))
@@ -0,0 +1,357 @@
+//! Scheduled rollouts of security patches
+//!
+//! Every `PATCH_ROLLOUT_INTERVAL_SECS` (default 60) the scheduler picks up
+//! rollouts that are scheduled or running and whose maintenance window is
+//! open. For each, it records the patch as applied (`patch_audits`) on the
+//! next batch of contracts still running the patch's target build, oldest
+//! first, never exceeding the patch's rollout percentage of eligible
+//! contracts. A rollout completes once that quota is reached. Rollouts are
+//! created, paused and resumed through [`crate::patch_rollout_handlers`].
+
+use chrono::{DateTime, Datelike, Duration as ChronoDuration, NaiveTime, Utc};
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+const DEFAULT_INTERVAL_SECS: u64 = 60;
+/// Contracts patched per rollout per tick.
+const BATCH_SIZE: i64 = 25;
+
+const WEEKDAYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// Recurring UTC maintenance window, e.g. `Sat 02:00-04:00 UTC`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MaintenanceWindow {
+    /// ISO weekdays (1 = Monday) the window opens on; empty means daily
+    pub days: Vec<i16>,
+    pub start: NaiveTime,
+    /// May be earlier than `start` for windows that cross midnight
+    pub end: NaiveTime,
+}
+
+impl MaintenanceWindow {
+    /// Parses `[days] HH:MM-HH:MM [UTC]`, where days is a comma-separated
+    /// list of weekdays or ranges (`Sat`, `Sat,Sun`, `Mon-Fri`).
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let mut tokens: Vec<&str> = spec.split_whitespace().collect();
+        if let Some(last) = tokens.last() {
+            if last.eq_ignore_ascii_case("utc") || *last == "Z" {
+                tokens.pop();
+            } else if last.chars().all(|c| c.is_ascii_alphabetic()) && tokens.len() > 1 {
+                return Err(format!(
+                    "unsupported time zone '{}'; windows are in UTC",
+                    last
+                ));
+            }
+        }
+        let (days, range) = match tokens.as_slice() {
+            [range] => (Vec::new(), *range),
+            [days, range] => (parse_days(days)?, *range),
+            _ => {
+                return Err(
+                    "expected a window like 'Sat 02:00-04:00 UTC' or '02:00-04:00'".to_string(),
+                )
+            }
+        };
+
+        let (start, end) = range
+            .split_once('-')
+            .ok_or_else(|| format!("expected a time range like 02:00-04:00, got '{}'", range))?;
+        let start = parse_time(start)?;
+        let end = parse_time(end)?;
+        if start == end {
+            return Err("window start and end must differ".to_string());
+        }
+        Ok(Self { days, start, end })
+    }
+
+    fn opens_on(&self, iso_day: u32) -> bool {
+        self.days.is_empty() || self.days.contains(&(iso_day as i16))
+    }
+
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        let time = now.time();
+        let today = now.weekday().number_from_monday();
+        if self.start < self.end {
+            self.opens_on(today) && time >= self.start && time < self.end
+        } else {
+            let yesterday = now.weekday().pred().number_from_monday();
+            (self.opens_on(today) && time >= self.start)
+                || (self.opens_on(yesterday) && time < self.end)
+        }
+    }
+
+    /// Start of the next window, or `now` while a window is open.
+    pub fn next_open(&self, now: DateTime<Utc>) -> DateTime<Utc> {
+        if self.is_open(now) {
+            return now;
+        }
+        (0..=7)
+            .map(|offset| now.date_naive() + ChronoDuration::days(offset))
+            .filter(|date| self.opens_on(date.weekday().number_from_monday()))
+            .map(|date| date.and_time(self.start).and_utc())
+            .find(|start| *start > now)
+            .unwrap_or(now)
+    }
+}
+
+fn parse_days(raw: &str) -> Result<Vec<i16>, String> {
+    let mut days = Vec::new();
+    for part in raw.split(',').filter(|p| !p.is_empty()) {
+        let (first, last) = match part.split_once('-') {
+            Some((a, b)) => (weekday(a)?, weekday(b)?),
+            None => (weekday(part)?, weekday(part)?),
+        };
+        let mut day = first;
+        loop {
+            if !days.contains(&day) {
+                days.push(day);
+            }
+            if day == last {
+                break;
+            }
+            day = day % 7 + 1;
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+fn weekday(name: &str) -> Result<i16, String> {
+    let lower = name.to_ascii_lowercase();
+    let prefix = lower.get(..3).unwrap_or_default();
+    WEEKDAYS
+        .iter()
+        .position(|d| *d == prefix)
+        .map(|i| i as i16 + 1)
+        .ok_or_else(|| format!("unknown weekday '{}'", name))
+}
+
+fn parse_time(raw: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(raw, "%H:%M")
+        .map_err(|_| format!("invalid time '{}'; use HH:MM (24h)", raw))
+}
+
+/// Number of contracts a patch may be applied to: `rollout_percentage` of
+/// the eligible contracts, rounded up.
+pub fn rollout_quota(eligible: i64, rollout_percentage: i32) -> i64 {
+    (eligible * rollout_percentage.clamp(0, 100) as i64 + 99) / 100
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct DueRollout {
+    id: Uuid,
+    patch_id: Uuid,
+    window_days: Option<Vec<i16>>,
+    window_start: Option<NaiveTime>,
+    window_end: Option<NaiveTime>,
+    target_version: String,
+    rollout_percentage: i32,
+}
+
+impl DueRollout {
+    fn window(&self) -> Option<MaintenanceWindow> {
+        Some(MaintenanceWindow {
+            days: self.window_days.clone().unwrap_or_default(),
+            start: self.window_start?,
+            end: self.window_end?,
+        })
+    }
+}
+
+pub fn spawn_patch_rollout_scheduler(pool: PgPool) {
+    let interval_secs = std::env::var("PATCH_ROLLOUT_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = run_due_rollouts(&pool).await {
+                tracing::error!(error = ?err, "patch rollout: scheduling pass failed");
+            }
+        }
+    });
+}
+
+async fn run_due_rollouts(pool: &PgPool) -> Result<(), sqlx::Error> {
+    let rollouts: Vec<DueRollout> = sqlx::query_as(
+        "SELECT r.id, r.patch_id, r.window_days, r.window_start, r.window_end,
+                p.target_version, p.rollout_percentage
+         FROM patch_rollouts r
+         JOIN security_patches p ON p.id = r.patch_id
+         WHERE r.status IN ('scheduled', 'running')
+         ORDER BY p.severity, r.created_at",
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let now = Utc::now();
+    for rollout in rollouts {
+        if let Some(window) = rollout.window() {
+            if !window.is_open(now) {
+                continue;
+            }
+        }
+        if let Err(err) = apply_batch(pool, &rollout).await {
+            tracing::error!(rollout_id = %rollout.id, error = ?err, "patch rollout: batch failed");
+            sqlx::query(
+                "UPDATE patch_rollouts SET last_error = $2, updated_at = NOW() WHERE id = $1",
+            )
+            .bind(rollout.id)
+            .bind(err.to_string())
+            .execute(pool)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn apply_batch(pool: &PgPool, rollout: &DueRollout) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    // Re-check under lock so a concurrent pause wins.
+    let status: Option<String> =
+        sqlx::query_scalar("SELECT status FROM patch_rollouts WHERE id = $1 FOR UPDATE")
+            .bind(rollout.id)
+            .fetch_optional(&mut *tx)
+            .await?;
+    if !matches!(status.as_deref(), Some("scheduled" | "running")) {
+        return Ok(());
+    }
+
+    let eligible: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM contracts WHERE wasm_hash = $1 AND deleted_at IS NULL",
+    )
+    .bind(&rollout.target_version)
+    .fetch_one(&mut *tx)
+    .await?;
+    let applied: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM patch_audits WHERE patch_id = $1")
+        .bind(rollout.patch_id)
+        .fetch_one(&mut *tx)
+        .await?;
+    let quota = rollout_quota(eligible, rollout.rollout_percentage);
+
+    let batch = (quota - applied).clamp(0, BATCH_SIZE);
+    let inserted = if batch > 0 {
+        sqlx::query(
+            "INSERT INTO patch_audits (contract_id, patch_id)
+             SELECT c.id, $1 FROM contracts c
+             WHERE c.wasm_hash = $2 AND c.deleted_at IS NULL
+               AND NOT EXISTS (
+                   SELECT 1 FROM patch_audits a
+                   WHERE a.contract_id = c.id AND a.patch_id = $1)
+             ORDER BY c.created_at, c.id
+             LIMIT $3
+             ON CONFLICT (contract_id, patch_id) DO NOTHING",
+        )
+        .bind(rollout.patch_id)
+        .bind(&rollout.target_version)
+        .bind(batch)
+        .execute(&mut *tx)
+        .await?
+        .rows_affected() as i64
+    } else {
+        0
+    };
+
+    let applied = applied + inserted;
+    let done = applied >= quota || inserted < batch;
+    sqlx::query(
+        "UPDATE patch_rollouts
+         SET applied_count = $2,
+             status = CASE WHEN $3 THEN 'completed' ELSE 'running' END,
+             completed_at = CASE WHEN $3 THEN NOW() ELSE NULL END,
+             last_run_at = NOW(), last_error = NULL, updated_at = NOW()
+         WHERE id = $1",
+    )
+    .bind(rollout.id)
+    .bind(applied as i32)
+    .bind(done)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    if inserted > 0 {
+        tracing::info!(
+            rollout_id = %rollout.id,
+            patch_id = %rollout.patch_id,
+            inserted,
+            applied,
+            quota,
+            "patch rollout: applied batch"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn at(y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_window_specs() {
+        let w = MaintenanceWindow::parse("Sat 02:00-04:00 UTC").unwrap();
+        assert_eq!(w.days, vec![6]);
+        assert_eq!(w.start, NaiveTime::from_hms_opt(2, 0, 0).unwrap());
+        assert_eq!(w.end, NaiveTime::from_hms_opt(4, 0, 0).unwrap());
+
+        assert_eq!(
+            MaintenanceWindow::parse("Mon-Wed,Sun 22:00-01:00")
+                .unwrap()
+                .days,
+            vec![1, 2, 3, 7]
+        );
+        assert_eq!(
+            MaintenanceWindow::parse("Fri-Mon 00:00-01:00")
+                .unwrap()
+                .days,
+            vec![1, 5, 6, 7]
+        );
+        assert!(MaintenanceWindow::parse("02:00-04:00")
+            .unwrap()
+            .days
+            .is_empty());
+
+        assert!(MaintenanceWindow::parse("Sat 02:00-04:00 PST").is_err());
+        assert!(MaintenanceWindow::parse("Funday 02:00-04:00").is_err());
+        assert!(MaintenanceWindow::parse("Sat 2am-4am").is_err());
+        assert!(MaintenanceWindow::parse("Sat 02:00-02:00").is_err());
+    }
+
+    #[test]
+    fn window_opens_only_on_its_days() {
+        let w = MaintenanceWindow::parse("Sat 02:00-04:00 UTC").unwrap();
+        // 2026-10-17 is a Saturday
+        assert!(w.is_open(at(2026, 10, 17, 2, 0)));
+        assert!(w.is_open(at(2026, 10, 17, 3, 59)));
+        assert!(!w.is_open(at(2026, 10, 17, 4, 0)));
+        assert!(!w.is_open(at(2026, 10, 16, 3, 0)));
+        assert_eq!(w.next_open(at(2026, 10, 16, 12, 0)), at(2026, 10, 17, 2, 0));
+        assert_eq!(w.next_open(at(2026, 10, 17, 5, 0)), at(2026, 10, 24, 2, 0));
+    }
+
+    #[test]
+    fn window_can_cross_midnight() {
+        let w = MaintenanceWindow::parse("Sat 23:00-01:00").unwrap();
+        assert!(w.is_open(at(2026, 10, 17, 23, 30)));
+        assert!(w.is_open(at(2026, 10, 18, 0, 30)));
+        assert!(!w.is_open(at(2026, 10, 18, 23, 30)));
+    }
+
+    #[test]
+    fn quota_rounds_up() {
+        assert_eq!(rollout_quota(10, 100), 10);
+        assert_eq!(rollout_quota(3, 50), 2);
+        assert_eq!(rollout_quota(0, 100), 0);
+        assert_eq!(rollout_quota(10, 0), 0);
+    }
+}
@@ -0,0 +1,343 @@
+//! Scheduled security patch rollouts
+//!
+//!   POST /api/patches/:id/rollout         – schedule a rollout (admin)
+//!   GET  /api/patches/:id/rollout         – rollout progress
+//!   POST /api/patches/:id/rollout/pause   – pause a rollout (admin)
+//!   POST /api/patches/:id/rollout/resume  – resume a paused rollout (admin)
+//!
+//! Rollouts are executed inside their maintenance window by
+//! [`crate::patch_rollout`].
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    consumers::{self, Impact},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_rollout::{rollout_quota, MaintenanceWindow},
+    state::AppState,
+};
+
+const ROLLOUT_COLUMNS: &str = "id, patch_id, window_spec, window_days, window_start, window_end,
+     status, last_error, last_run_at, completed_at, created_by, created_at";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateRolloutRequest {
+    /// Maintenance window such as `Sat 02:00-04:00 UTC`; omit to start now
+    pub window: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PatchRolloutStatus {
+    pub id: Uuid,
+    pub patch_id: Uuid,
+    /// scheduled, running, paused or completed
+    pub status: String,
+    pub window: Option<String>,
+    pub window_open: bool,
+    /// Start of the next window the scheduler will work in
+    pub next_window_at: Option<DateTime<Utc>>,
+    pub rollout_percentage: i32,
+    /// Contracts running the patch's target build
+    pub eligible: i64,
+    /// Contracts the patch may be applied to under its rollout percentage
+    pub quota: i64,
+    pub applied: i64,
+    pub progress_pct: f64,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub created_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+#[derive(Debug, sqlx::FromRow)]
+struct RolloutRow {
+    id: Uuid,
+    patch_id: Uuid,
+    window_spec: Option<String>,
+    window_days: Option<Vec<i16>>,
+    window_start: Option<NaiveTime>,
+    window_end: Option<NaiveTime>,
+    status: String,
+    last_error: Option<String>,
+    last_run_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    created_by: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+async fn fetch_rollout(state: &AppState, patch_id: Uuid) -> ApiResult<RolloutRow> {
+    let sql = format!(
+        "SELECT {} FROM patch_rollouts WHERE patch_id = $1",
+        ROLLOUT_COLUMNS
+    );
+    sqlx::query_as(&sql)
+        .bind(patch_id)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch patch rollout", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "RolloutNotFound",
+                format!("No rollout scheduled for patch {}", patch_id),
+            )
+        })
+}
+
+async fn rollout_status(state: &AppState, row: RolloutRow) -> ApiResult<PatchRolloutStatus> {
+    let (target_version, rollout_percentage): (String, i32) = sqlx::query_as(
+        "SELECT target_version, rollout_percentage FROM security_patches WHERE id = $1",
+    )
+    .bind(row.patch_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch patch for rollout", err))?;
+    let (eligible, applied): (i64, i64) = sqlx::query_as(
+        "SELECT
+            (SELECT COUNT(*) FROM contracts WHERE wasm_hash = $1 AND deleted_at IS NULL),
+            (SELECT COUNT(*) FROM patch_audits WHERE patch_id = $2)",
+    )
+    .bind(&target_version)
+    .bind(row.patch_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("count patch rollout progress", err))?;
+
+    let quota = rollout_quota(eligible, rollout_percentage);
+    let now = Utc::now();
+    let window = match (row.window_start, row.window_end) {
+        (Some(start), Some(end)) => Some(MaintenanceWindow {
+            days: row.window_days.unwrap_or_default(),
+            start,
+            end,
+        }),
+        _ => None,
+    };
+    let active = matches!(row.status.as_str(), "scheduled" | "running");
+
+    Ok(PatchRolloutStatus {
+        id: row.id,
+        patch_id: row.patch_id,
+        window_open: window.as_ref().is_none_or(|w| w.is_open(now)),
+        next_window_at: active.then(|| window.as_ref().map_or(now, |w| w.next_open(now))),
+        status: row.status,
+        window: row.window_spec,
+        rollout_percentage,
+        eligible,
+        quota,
+        applied,
+        progress_pct: if quota > 0 {
+            (applied.min(quota) as f64 / quota as f64 * 100.0).round()
+        } else {
+            100.0
+        },
+        last_run_at: row.last_run_at,
+        last_error: row.last_error,
+        completed_at: row.completed_at,
+        created_by: row.created_by,
+        created_at: row.created_at,
+    })
+}
+
+/// Moves a rollout from one of `from` to `to`, or explains why it can't.
+async fn transition(
+    state: &AppState,
+    patch_id: Uuid,
+    from: &[&str],
+    to: &str,
+) -> ApiResult<PatchRolloutStatus> {
+    let current = fetch_rollout(state, patch_id).await?;
+    if !from.contains(&current.status.as_str()) {
+        return Err(ApiError::conflict(
+            "InvalidRolloutState",
+            format!("Rollout is {}; cannot change it to {}", current.status, to),
+        ));
+    }
+
+    let sql = format!(
+        "UPDATE patch_rollouts SET status = $2, updated_at = NOW()
+         WHERE patch_id = $1 AND status = ANY($3)
+         RETURNING {}",
+        ROLLOUT_COLUMNS
+    );
+    let row: RolloutRow = sqlx::query_as(&sql)
+        .bind(patch_id)
+        .bind(to)
+        .bind(from)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("update patch rollout status", err))?
+        .ok_or_else(|| ApiError::conflict("InvalidRolloutState", "Rollout changed state; retry"))?;
+    rollout_status(state, row).await
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/patches/{id}/rollout",
+    params(("id" = Uuid, Path, description = "Security patch ID")),
+    request_body = CreateRolloutRequest,
+    responses(
+        (status = 201, description = "Rollout scheduled", body = PatchRolloutStatus),
+        (status = 400, description = "Invalid maintenance window"),
+        (status = 404, description = "Patch not found"),
+        (status = 409, description = "Patch already has an active rollout")
+    ),
+    tag = "Security"
+)]
+pub async fn create_patch_rollout(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(patch_id): Path<Uuid>,
+    Json(req): Json<CreateRolloutRequest>,
+) -> ApiResult<(StatusCode, Json<PatchRolloutStatus>)> {
+    let spec = req
+        .window
+        .as_deref()
+        .map(str::trim)
+        .filter(|w| !w.is_empty());
+    let window = spec
+        .map(MaintenanceWindow::parse)
+        .transpose()
+        .map_err(|msg| ApiError::bad_request("InvalidWindow", msg))?;
+
+    let (target_wasm_hash, severity): (String, String) = sqlx::query_as(
+        "SELECT target_version, severity::TEXT FROM security_patches WHERE id = $1",
+    )
+    .bind(patch_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch security patch", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PatchNotFound",
+            format!("No security patch with ID {}", patch_id),
+        )
+    })?;
+
+    // A completed rollout can be rescheduled (e.g. after the rollout
+    // percentage is raised); an active or paused one cannot.
+    let sql = format!(
+        "INSERT INTO patch_rollouts
+            (patch_id, window_spec, window_days, window_start, window_end, created_by)
+         VALUES ($1, $2, $3, $4, $5, $6)
+         ON CONFLICT (patch_id) DO UPDATE SET
+            window_spec = EXCLUDED.window_spec,
+            window_days = EXCLUDED.window_days,
+            window_start = EXCLUDED.window_start,
+            window_end = EXCLUDED.window_end,
+            created_by = EXCLUDED.created_by,
+            status = 'scheduled',
+            last_error = NULL,
+            completed_at = NULL,
+            created_at = NOW(),
+            updated_at = NOW()
+         WHERE patch_rollouts.status = 'completed'
+         RETURNING {}",
+        ROLLOUT_COLUMNS
+    );
+    let row: RolloutRow = sqlx::query_as(&sql)
+        .bind(patch_id)
+        .bind(spec)
+        .bind(window.as_ref().map(|w| w.days.clone()))
+        .bind(window.as_ref().map(|w| w.start))
+        .bind(window.as_ref().map(|w| w.end))
+        .bind(&claims.sub)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("create patch rollout", err))?
+        .ok_or_else(|| {
+            ApiError::conflict(
+                "RolloutExists",
+                "Patch already has an active or paused rollout",
+            )
+        })?;
+
+    // Consumers pinned to the patched build hear about it when the rollout
+    // is scheduled, before anything is swapped under them.
+    consumers::notify(
+        &state.db,
+        Impact::Patch {
+            target_wasm_hash: &target_wasm_hash,
+            severity: &severity,
+        },
+    )
+    .await;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(rollout_status(&state, row).await?),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/patches/{id}/rollout",
+    params(("id" = Uuid, Path, description = "Security patch ID")),
+    responses(
+        (status = 200, description = "Rollout progress", body = PatchRolloutStatus),
+        (status = 404, description = "No rollout for this patch")
+    ),
+    tag = "Security"
+)]
+pub async fn get_patch_rollout(
+    State(state): State<AppState>,
+    Path(patch_id): Path<Uuid>,
+) -> ApiResult<Json<PatchRolloutStatus>> {
+    let row = fetch_rollout(&state, patch_id).await?;
+    Ok(Json(rollout_status(&state, row).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/patches/{id}/rollout/pause",
+    params(("id" = Uuid, Path, description = "Security patch ID")),
+    responses(
+        (status = 200, description = "Rollout paused", body = PatchRolloutStatus),
+        (status = 404, description = "No rollout for this patch"),
+        (status = 409, description = "Rollout is not active")
+    ),
+    tag = "Security"
+)]
+pub async fn pause_patch_rollout(
+    State(state): State<AppState>,
+    Path(patch_id): Path<Uuid>,
+) -> ApiResult<Json<PatchRolloutStatus>> {
+    Ok(Json(
+        transition(&state, patch_id, &["scheduled", "running"], "paused").await?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/patches/{id}/rollout/resume",
+    params(("id" = Uuid, Path, description = "Security patch ID")),
+    responses(
+        (status = 200, description = "Rollout resumed", body = PatchRolloutStatus),
+        (status = 404, description = "No rollout for this patch"),
+        (status = 409, description = "Rollout is not paused")
+    ),
+    tag = "Security"
+)]
+pub async fn resume_patch_rollout(
+    State(state): State<AppState>,
+    Path(patch_id): Path<Uuid>,
+) -> ApiResult<Json<PatchRolloutStatus>> {
+    Ok(Json(
+        transition(&state, patch_id, &["paused"], "scheduled").await?,
+    ))
+}
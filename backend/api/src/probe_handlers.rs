@@ -0,0 +1,364 @@
+//! Kubernetes liveness and readiness probes
+//!
+//!   GET    /livez    – process is up and not shutting down (no dependencies)
+//!   GET    /readyz   – dependency checks plus connection-pool utilisation
+//!
+//! `/readyz` runs every check and reports each one. Only *critical* checks
+//! (database, schema) take the pod out of rotation with a 503; the others
+//! (Stellar RPC reachability, job-queue lag, a database newer than this
+//! binary) only downgrade the status to `degraded`, so an outage of a public
+//! RPC endpoint does not take the whole API down with it.
+//!
+//! Configuration (environment):
+//!
+//! * `READYZ_RPC_TIMEOUT_MS`     – per-network RPC probe timeout (default 2000)
+//! * `READYZ_MAX_JOB_LAG_SECS`   – oldest runnable job age before warning (default 300)
+
+use std::time::{Duration, Instant};
+
+use axum::{extract::State, http::StatusCode, Json};
+use futures_util::future::join_all;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::{handlers::configured_networks, migration_handlers, state::AppState};
+
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+const DEFAULT_RPC_TIMEOUT_MS: u64 = 2_000;
+const DEFAULT_MAX_JOB_LAG_SECS: i64 = 300;
+
+// ── Response types ────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ProbeCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    /// Whether a failure of this check makes the instance not ready.
+    pub critical: bool,
+    pub latency_ms: u64,
+    pub detail: Value,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn env_or<T: std::str::FromStr>(key: &str, default: T) -> T {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+fn elapsed_ms(started: Instant) -> u64 {
+    started.elapsed().as_millis() as u64
+}
+
+/// Collapse individual checks into an HTTP status and overall label.
+fn readiness_outcome(checks: &[ProbeCheck]) -> (StatusCode, &'static str) {
+    let critical_failure = checks
+        .iter()
+        .any(|c| c.critical && c.status == CheckStatus::Fail);
+    if critical_failure {
+        (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+    } else if checks.iter().any(|c| c.status != CheckStatus::Pass) {
+        (StatusCode::OK, "degraded")
+    } else {
+        (StatusCode::OK, "ready")
+    }
+}
+
+async fn check_database(state: &AppState) -> ProbeCheck {
+    let started = Instant::now();
+    let result = sqlx::query_scalar::<_, i32>("SELECT 1")
+        .fetch_one(&state.db)
+        .await;
+    ProbeCheck {
+        name: "database",
+        status: if result.is_ok() {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Fail
+        },
+        critical: true,
+        latency_ms: elapsed_ms(started),
+        detail: match result {
+            Ok(_) => json!({}),
+            Err(err) => {
+                tracing::warn!(error = ?err, "readiness: database unreachable");
+                json!({ "error": "database connection failed" })
+            }
+        },
+    }
+}
+
+async fn check_migrations(state: &AppState) -> ProbeCheck {
+    let started = Instant::now();
+    let (status, detail) = match migration_handlers::embedded_schema_status(&state.db).await {
+        Ok(schema) => {
+            // A database that is *ahead* of this binary is expected mid-rollout,
+            // so only missing or broken migrations are fatal.
+            let broken = !schema.pending.is_empty()
+                || !schema.failed.is_empty()
+                || !schema.checksum_mismatches.is_empty();
+            let status = if broken {
+                CheckStatus::Fail
+            } else if !schema.unknown.is_empty() {
+                CheckStatus::Warn
+            } else {
+                CheckStatus::Pass
+            };
+            (status, json!(schema))
+        }
+        Err(err) => {
+            tracing::warn!(error = ?err, "readiness: could not read schema version");
+            (
+                CheckStatus::Fail,
+                json!({ "error": "could not read applied migrations" }),
+            )
+        }
+    };
+    ProbeCheck {
+        name: "migrations",
+        status,
+        critical: true,
+        latency_ms: elapsed_ms(started),
+        detail,
+    }
+}
+
+async fn check_rpc() -> ProbeCheck {
+    let started = Instant::now();
+    let timeout = Duration::from_millis(env_or("READYZ_RPC_TIMEOUT_MS", DEFAULT_RPC_TIMEOUT_MS));
+    let client = reqwest::Client::builder()
+        .timeout(timeout)
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+
+    let networks = configured_networks();
+    let probes = networks.iter().map(|network| {
+        let client = client.clone();
+        async move {
+            let started = Instant::now();
+            let reachable = client
+                .post(&network.rpc_url)
+                .json(&json!({ "jsonrpc": "2.0", "id": 1, "method": "getHealth" }))
+                .send()
+                .await
+                .map(|resp| resp.status().is_success())
+                .unwrap_or(false);
+            json!({
+                "network": network.id,
+                "reachable": reachable,
+                "latency_ms": elapsed_ms(started),
+            })
+        }
+    });
+    let results = join_all(probes).await;
+    let all_reachable = results.iter().all(|r| r["reachable"] == true);
+
+    ProbeCheck {
+        name: "stellar_rpc",
+        status: if all_reachable {
+            CheckStatus::Pass
+        } else {
+            CheckStatus::Warn
+        },
+        critical: false,
+        latency_ms: elapsed_ms(started),
+        detail: json!({ "networks": results }),
+    }
+}
+
+async fn check_job_queue(state: &AppState) -> ProbeCheck {
+    let started = Instant::now();
+    let max_lag = env_or("READYZ_MAX_JOB_LAG_SECS", DEFAULT_MAX_JOB_LAG_SECS);
+    let row: Result<(i64, Option<i64>), sqlx::Error> = sqlx::query_as(
+        r#"
+        SELECT COUNT(*),
+               EXTRACT(EPOCH FROM NOW() - MIN(run_at))::BIGINT
+        FROM background_jobs
+        WHERE status = 'queued' AND run_at <= NOW()
+        "#,
+    )
+    .fetch_one(&state.db)
+    .await;
+
+    let (status, detail) = match row {
+        Ok((runnable, lag)) => {
+            let lag = lag.unwrap_or(0);
+            (
+                if lag > max_lag {
+                    CheckStatus::Warn
+                } else {
+                    CheckStatus::Pass
+                },
+                json!({ "runnable_jobs": runnable, "lag_secs": lag, "max_lag_secs": max_lag }),
+            )
+        }
+        Err(err) => {
+            tracing::warn!(error = ?err, "readiness: could not read job queue");
+            (
+                CheckStatus::Warn,
+                json!({ "error": "could not read job queue" }),
+            )
+        }
+    };
+    ProbeCheck {
+        name: "job_queue",
+        status,
+        critical: false,
+        latency_ms: elapsed_ms(started),
+        detail,
+    }
+}
+
+fn pool_stats(state: &AppState) -> Value {
+    let size = state.db.size();
+    let idle = state.db.num_idle() as u32;
+    let max = state.db.options().get_max_connections();
+    let in_use = size.saturating_sub(idle);
+    json!({
+        "size": size,
+        "idle": idle,
+        "in_use": in_use,
+        "max_connections": max,
+        "utilization": if max == 0 { 0.0 } else { in_use as f64 / max as f64 },
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// Liveness: the process is running and not draining.
+#[utoipa::path(
+    get,
+    path = "/livez",
+    responses(
+        (status = 200, description = "Process is alive", body = Object),
+        (status = 503, description = "Process is shutting down", body = Object)
+    ),
+    tag = "Observability"
+)]
+pub async fn livez(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let shutting_down = state
+        .is_shutting_down
+        .load(std::sync::atomic::Ordering::SeqCst);
+    (
+        if shutting_down {
+            StatusCode::SERVICE_UNAVAILABLE
+        } else {
+            StatusCode::OK
+        },
+        Json(json!({
+            "status": if shutting_down { "shutting_down" } else { "alive" },
+            "version": VERSION,
+            "uptime_secs": state.started_at.elapsed().as_secs(),
+        })),
+    )
+}
+
+/// Readiness: dependency checks and connection-pool utilisation.
+#[utoipa::path(
+    get,
+    path = "/readyz",
+    responses(
+        (status = 200, description = "Ready (possibly degraded)", body = Object),
+        (status = 503, description = "Not ready to serve traffic", body = Object)
+    ),
+    tag = "Observability"
+)]
+pub async fn readyz(State(state): State<AppState>) -> (StatusCode, Json<Value>) {
+    let now = chrono::Utc::now().to_rfc3339();
+
+    if state
+        .is_shutting_down
+        .load(std::sync::atomic::Ordering::SeqCst)
+    {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({
+                "status": "not_ready",
+                "reason": "shutting_down",
+                "version": VERSION,
+                "timestamp": now,
+            })),
+        );
+    }
+
+    let (database, migrations, rpc, job_queue) = tokio::join!(
+        check_database(&state),
+        check_migrations(&state),
+        check_rpc(),
+        check_job_queue(&state),
+    );
+    let checks = vec![database, migrations, rpc, job_queue];
+    let (status_code, status) = readiness_outcome(&checks);
+    if status_code != StatusCode::OK {
+        tracing::warn!(?checks, "readiness check failed");
+    }
+
+    (
+        status_code,
+        Json(json!({
+            "status": status,
+            "version": VERSION,
+            "timestamp": now,
+            "uptime_secs": state.started_at.elapsed().as_secs(),
+            "checks": checks,
+            "pool": pool_stats(&state),
+        })),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(status: CheckStatus, critical: bool) -> ProbeCheck {
+        ProbeCheck {
+            name: "test",
+            status,
+            critical,
+            latency_ms: 0,
+            detail: Value::Null,
+        }
+    }
+
+    #[test]
+    fn all_passing_is_ready() {
+        let checks = [
+            check(CheckStatus::Pass, true),
+            check(CheckStatus::Pass, false),
+        ];
+        assert_eq!(readiness_outcome(&checks), (StatusCode::OK, "ready"));
+    }
+
+    #[test]
+    fn non_critical_failure_only_degrades() {
+        let checks = [
+            check(CheckStatus::Pass, true),
+            check(CheckStatus::Fail, false),
+        ];
+        assert_eq!(readiness_outcome(&checks), (StatusCode::OK, "degraded"));
+        let checks = [check(CheckStatus::Warn, true)];
+        assert_eq!(readiness_outcome(&checks), (StatusCode::OK, "degraded"));
+    }
+
+    #[test]
+    fn critical_failure_is_not_ready() {
+        let checks = [
+            check(CheckStatus::Fail, true),
+            check(CheckStatus::Pass, false),
+        ];
+        assert_eq!(
+            readiness_outcome(&checks),
+            (StatusCode::SERVICE_UNAVAILABLE, "not_ready")
+        );
+    }
+}
@@ -0,0 +1,529 @@
+//! Environment promotions (e.g. testnet → mainnet)
+//!
+//!   POST /api/contracts/:id/promotions  – request a promotion of a build
+//!   GET  /api/contracts/:id/promotions  – promotion history of a contract
+//!   POST /api/promotions/:id/complete   – link the deployed target contract
+//!
+//! Contracts on different networks are the same logical contract when they
+//! share `contracts.logical_id`. A build may be promoted out of a network only
+//! once it has been exercised there for `PROMOTION_MIN_SOAK_HOURS` (default
+//! 72) with no failed migrations to it. Every request is recorded with the
+//! checks it ran; an approved promotion is completed once the build is
+//! registered on the target network, which joins it to the logical contract.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::models::Network;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{self, ContractCapability},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const DEFAULT_MIN_SOAK_HOURS: i32 = 72;
+/// Interaction types that count as exercising a build.
+const EXERCISE_TYPES: [&str; 3] = ["invoke", "transfer", "query"];
+
+const PROMOTION_COLUMNS: &str = "id, logical_id, source_contract_id, from_network, to_network,
+     wasm_hash, min_soak_hours, status, checks, target_contract_id, requested_by,
+     created_at, completed_at";
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct PromoteContractRequest {
+    pub from_network: Network,
+    pub to_network: Network,
+    /// Build to promote; defaults to the source contract's current WASM
+    pub wasm_hash: Option<String>,
+    /// Soak period to require; cannot be lower than the server minimum
+    pub min_soak_hours: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CompletePromotionRequest {
+    /// Registry UUID or on-chain ID of the contract on the target network
+    pub contract_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+pub struct PromotionCheck {
+    pub name: String,
+    pub passed: bool,
+    pub detail: String,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractPromotion {
+    pub id: Uuid,
+    pub logical_id: Uuid,
+    pub source_contract_id: Uuid,
+    pub from_network: Network,
+    pub to_network: Network,
+    pub wasm_hash: String,
+    pub min_soak_hours: i32,
+    /// approved, rejected or completed
+    pub status: String,
+    #[schema(value_type = Vec<PromotionCheck>)]
+    pub checks: sqlx::types::Json<Vec<PromotionCheck>>,
+    pub target_contract_id: Option<Uuid>,
+    pub requested_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn server_min_soak_hours() -> i32 {
+    std::env::var("PROMOTION_MIN_SOAK_HOURS")
+        .ok()
+        .and_then(|s| s.parse::<i32>().ok())
+        .filter(|&h| h >= 0)
+        .unwrap_or(DEFAULT_MIN_SOAK_HOURS)
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct SourceContract {
+    id: Uuid,
+    contract_id: String,
+    wasm_hash: String,
+    logical_id: Uuid,
+}
+
+/// What the source network has recorded about a build.
+#[derive(Debug, Default)]
+struct SoakEvidence {
+    /// When the build was first deployed on the source contract
+    deployed_at: Option<DateTime<Utc>>,
+    /// First interaction with the build after it was deployed
+    first_exercised_at: Option<DateTime<Utc>>,
+    interactions: i64,
+    failed_migrations: i64,
+}
+
+fn evaluate(
+    evidence: &SoakEvidence,
+    min_soak_hours: i32,
+    now: DateTime<Utc>,
+) -> Vec<PromotionCheck> {
+    let check = |name: &str, passed: bool, detail: String| PromotionCheck {
+        name: name.to_string(),
+        passed,
+        detail,
+    };
+    let min_soak = Duration::hours(i64::from(min_soak_hours));
+
+    vec![
+        match evidence.deployed_at {
+            Some(at) => check(
+                "deployed_on_source",
+                true,
+                format!("deployed {}", at.to_rfc3339()),
+            ),
+            None => check(
+                "deployed_on_source",
+                false,
+                "build was never deployed on the source contract".to_string(),
+            ),
+        },
+        check(
+            "exercised_on_source",
+            evidence.interactions > 0,
+            format!("{} interaction(s) since deployment", evidence.interactions),
+        ),
+        match evidence.first_exercised_at {
+            Some(at) if now - at >= min_soak => check(
+                "soak_period",
+                true,
+                format!(
+                    "exercised for {}h (minimum {}h)",
+                    (now - at).num_hours(),
+                    min_soak_hours
+                ),
+            ),
+            Some(at) => check(
+                "soak_period",
+                false,
+                format!(
+                    "exercised for {}h; {}h required",
+                    (now - at).num_hours(),
+                    min_soak_hours
+                ),
+            ),
+            None => check(
+                "soak_period",
+                false,
+                format!("not exercised yet; {}h required", min_soak_hours),
+            ),
+        },
+        check(
+            "no_failed_migrations",
+            evidence.failed_migrations == 0,
+            format!(
+                "{} failed migration(s) to this build",
+                evidence.failed_migrations
+            ),
+        ),
+    ]
+}
+
+async fn soak_evidence(
+    state: &AppState,
+    source: &SourceContract,
+    wasm_hash: &str,
+) -> ApiResult<SoakEvidence> {
+    let deployed_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT LEAST(
+            (SELECT MIN(created_at) FROM contract_versions
+             WHERE contract_id = $1 AND wasm_hash = $2),
+            (SELECT created_at FROM contracts WHERE id = $1 AND wasm_hash = $2))",
+    )
+    .bind(source.id)
+    .bind(wasm_hash)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("find build deployment", err))?;
+
+    let (interactions, first_exercised_at) = match deployed_at {
+        Some(since) => sqlx::query_as::<_, (i64, Option<DateTime<Utc>>)>(
+            "SELECT COUNT(*), MIN(interaction_timestamp) FROM contract_interactions
+             WHERE contract_id = $1 AND interaction_timestamp >= $2
+               AND interaction_type = ANY($3)",
+        )
+        .bind(source.id)
+        .bind(since)
+        .bind(&EXERCISE_TYPES[..])
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("count build interactions", err))?,
+        None => (0, None),
+    };
+
+    let failed_migrations: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM migrations
+         WHERE contract_id = $1 AND wasm_hash = $2 AND status = 'failed'",
+    )
+    .bind(&source.contract_id)
+    .bind(wasm_hash)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("count failed migrations", err))?;
+
+    Ok(SoakEvidence {
+        deployed_at,
+        first_exercised_at,
+        interactions,
+        failed_migrations,
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/promotions",
+    params(("id" = String, Path, description = "Contract UUID or on-chain ID (any network)")),
+    request_body = PromoteContractRequest,
+    responses(
+        (status = 201, description = "Promotion recorded; `status` says whether it was approved", body = ContractPromotion),
+        (status = 400, description = "Invalid networks or WASM hash"),
+        (status = 403, description = "Caller may not publish versions of the source contract"),
+        (status = 404, description = "No contract on the source network")
+    ),
+    tag = "Contracts"
+)]
+pub async fn promote_contract(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<PromoteContractRequest>,
+) -> ApiResult<(StatusCode, Json<ContractPromotion>)> {
+    if req.from_network.to_string() == req.to_network.to_string() {
+        return Err(ApiError::bad_request(
+            "InvalidPromotion",
+            "Source and target networks must differ",
+        ));
+    }
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+
+    let source: SourceContract = sqlx::query_as(
+        "SELECT s.id, s.contract_id, s.wasm_hash, COALESCE(s.logical_id, s.id) AS logical_id
+         FROM contracts c
+         JOIN contracts s ON COALESCE(s.logical_id, s.id) = COALESCE(c.logical_id, c.id)
+         WHERE c.id = $1 AND s.network = $2 AND s.deleted_at IS NULL
+         ORDER BY s.created_at DESC
+         LIMIT 1",
+    )
+    .bind(contract_uuid)
+    .bind(&req.from_network)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("find promotion source", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "ContractNotFound",
+            format!(
+                "Contract '{}' has no deployment on {}",
+                id, req.from_network
+            ),
+        )
+    })?;
+
+    let wasm_hash = match req.wasm_hash.as_deref().map(str::trim) {
+        Some(hash) if hash.len() == 64 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            hash.to_ascii_lowercase()
+        }
+        Some(_) => {
+            return Err(ApiError::bad_request(
+                "InvalidPromotion",
+                "wasm_hash must be a 64-character hex SHA-256 hash",
+            ))
+        }
+        None => source.wasm_hash.clone(),
+    };
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        source.id,
+        ContractCapability::PublishVersions,
+        "promote its builds",
+    )
+    .await?;
+    let min_soak_hours = req.min_soak_hours.unwrap_or(0).max(server_min_soak_hours());
+
+    let evidence = soak_evidence(&state, &source, &wasm_hash).await?;
+    let checks = evaluate(&evidence, min_soak_hours, Utc::now());
+    let status = if checks.iter().all(|c| c.passed) {
+        "approved"
+    } else {
+        "rejected"
+    };
+
+    let sql = format!(
+        "INSERT INTO contract_promotions
+            (logical_id, source_contract_id, from_network, to_network, wasm_hash,
+             min_soak_hours, status, checks, requested_by)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+         RETURNING {}",
+        PROMOTION_COLUMNS
+    );
+    let promotion: ContractPromotion = sqlx::query_as(&sql)
+        .bind(source.logical_id)
+        .bind(source.id)
+        .bind(&req.from_network)
+        .bind(&req.to_network)
+        .bind(&wasm_hash)
+        .bind(min_soak_hours)
+        .bind(status)
+        .bind(sqlx::types::Json(&checks))
+        .bind(&claims.sub)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("record promotion", err))?;
+
+    Ok((StatusCode::CREATED, Json(promotion)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/promotions",
+    params(("id" = String, Path, description = "Contract UUID or on-chain ID (any network)")),
+    responses(
+        (status = 200, description = "Promotions of the logical contract, newest first", body = [ContractPromotion]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Contracts"
+)]
+pub async fn list_contract_promotions(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<ContractPromotion>>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let sql = format!(
+        "SELECT {} FROM contract_promotions
+         WHERE logical_id = (SELECT COALESCE(logical_id, id) FROM contracts WHERE id = $1)
+         ORDER BY created_at DESC
+         LIMIT 100",
+        PROMOTION_COLUMNS
+    );
+    let promotions = sqlx::query_as(&sql)
+        .bind(contract_uuid)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("list promotions", err))?;
+    Ok(Json(promotions))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/promotions/{id}/complete",
+    params(("id" = Uuid, Path, description = "Promotion ID")),
+    request_body = CompletePromotionRequest,
+    responses(
+        (status = 200, description = "Target contract linked", body = ContractPromotion),
+        (status = 403, description = "Caller may not publish versions of the target contract"),
+        (status = 404, description = "Promotion or target contract not found"),
+        (status = 409, description = "Promotion not approved, or target runs a different build")
+    ),
+    tag = "Contracts"
+)]
+pub async fn complete_promotion(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(promotion_id): Path<Uuid>,
+    Json(req): Json<CompletePromotionRequest>,
+) -> ApiResult<Json<ContractPromotion>> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin promotion completion", err))?;
+
+    let sql = format!(
+        "SELECT {} FROM contract_promotions
+         WHERE id = $1
+           AND source_contract_id IN (SELECT id FROM contracts WHERE tenant_id = $2)
+         FOR UPDATE",
+        PROMOTION_COLUMNS
+    );
+    let promotion: ContractPromotion = sqlx::query_as(&sql)
+        .bind(promotion_id)
+        .bind(tenant.id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("fetch promotion", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "PromotionNotFound",
+                format!("No promotion with ID {}", promotion_id),
+            )
+        })?;
+    if promotion.status != "approved" {
+        return Err(ApiError::conflict(
+            "InvalidPromotionState",
+            format!(
+                "Promotion is {}; only approved promotions can be completed",
+                promotion.status
+            ),
+        ));
+    }
+
+    let target_uuid = resolve_contract_uuid(&state, &tenant, &req.contract_id).await?;
+    contract_permissions::authorize(
+        &state,
+        &claims,
+        target_uuid,
+        ContractCapability::PublishVersions,
+        "link it to a promotion",
+    )
+    .await?;
+    let (network, wasm_hash): (Network, String) =
+        sqlx::query_as("SELECT network, wasm_hash FROM contracts WHERE id = $1")
+            .bind(target_uuid)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("fetch promotion target", err))?;
+    if network.to_string() != promotion.to_network.to_string() {
+        return Err(ApiError::conflict(
+            "PromotionTargetMismatch",
+            format!(
+                "Contract '{}' is on {}, not {}",
+                req.contract_id, network, promotion.to_network
+            ),
+        ));
+    }
+    if !wasm_hash.eq_ignore_ascii_case(&promotion.wasm_hash) {
+        return Err(ApiError::conflict(
+            "PromotionTargetMismatch",
+            format!(
+                "Contract '{}' runs WASM {}, but the promoted build is {}",
+                req.contract_id, wasm_hash, promotion.wasm_hash
+            ),
+        ));
+    }
+
+    sqlx::query("UPDATE contracts SET logical_id = $2 WHERE id = $1")
+        .bind(target_uuid)
+        .bind(promotion.logical_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("link promoted contract", err))?;
+    let sql = format!(
+        "UPDATE contract_promotions
+         SET status = 'completed', target_contract_id = $2, completed_at = NOW()
+         WHERE id = $1
+         RETURNING {}",
+        PROMOTION_COLUMNS
+    );
+    let promotion: ContractPromotion = sqlx::query_as(&sql)
+        .bind(promotion_id)
+        .bind(target_uuid)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("complete promotion", err))?;
+
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit promotion completion", err))?;
+    Ok(Json(promotion))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn passed(checks: &[PromotionCheck]) -> Vec<&str> {
+        checks
+            .iter()
+            .filter(|c| c.passed)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn approves_a_soaked_build() {
+        let now = Utc::now();
+        let evidence = SoakEvidence {
+            deployed_at: Some(now - Duration::days(5)),
+            first_exercised_at: Some(now - Duration::days(4)),
+            interactions: 12,
+            failed_migrations: 0,
+        };
+        assert!(evaluate(&evidence, 72, now).iter().all(|c| c.passed));
+    }
+
+    #[test]
+    fn rejects_short_soak_and_failed_migrations() {
+        let now = Utc::now();
+        let evidence = SoakEvidence {
+            deployed_at: Some(now - Duration::days(2)),
+            first_exercised_at: Some(now - Duration::hours(10)),
+            interactions: 3,
+            failed_migrations: 1,
+        };
+        assert_eq!(
+            passed(&evaluate(&evidence, 72, now)),
+            vec!["deployed_on_source", "exercised_on_source"]
+        );
+    }
+
+    #[test]
+    fn rejects_an_unexercised_build() {
+        let checks = evaluate(&SoakEvidence::default(), 0, Utc::now());
+        assert_eq!(passed(&checks), vec!["no_failed_migrations"]);
+    }
+}
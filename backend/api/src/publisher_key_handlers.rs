@@ -0,0 +1,828 @@
+//! Publisher signing keys
+//!
+//!   GET  /api/me/keys                   – the caller's keys
+//!   POST /api/me/keys                   – register a key
+//!   POST /api/me/keys/:key/rotate       – replace a key, keeping it valid for an overlap
+//!   POST /api/me/keys/:key/revoke       – revoke a key from a point in time
+//!   GET  /api/publishers/:id/keys       – a publisher's keys
+//!   GET  /api/keys/lookup?public_key=   – registrations of a public key
+//!
+//! A publisher with registered keys must sign releases with one valid at the
+//! time (see [`require_usable_key`]); one without keeps the old behaviour of
+//! accepting any key. Logins and attestations by a registered key, and the
+//! session tokens its logins were issued, are refused outside its window
+//! ([`require_usable_account_key`] and `AuthManager`'s key windows, refreshed
+//! by [`run_key_window_refresh`]).
+//!
+//! Revoking a key also revokes the package signatures and badges the
+//! registry received from the revocation time on, since whoever signed them
+//! may not have been the publisher.
+
+use std::collections::HashMap;
+use std::time::Duration as StdDuration;
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Duration, Utc};
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use shared::{
+    KeyValidity, KeyWindow, RegisterSigningKeyRequest, RevokeSigningKeyRequest,
+    RotateSigningKeyRequest, SigningKey, TransparencyEntryType,
+};
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    signing_handlers::append_transparency_log_entry,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const DEFAULT_OVERLAP_HOURS: i64 = 24 * 7;
+const MAX_OVERLAP_HOURS: i64 = 24 * 90;
+const MAX_LABEL_CHARS: usize = 64;
+/// Shortest fingerprint prefix accepted to name a key.
+const MIN_FINGERPRINT_PREFIX: usize = 16;
+const KEY_WINDOW_REFRESH: StdDuration = StdDuration::from_secs(60);
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct KeyLookupQuery {
+    /// Base64 Ed25519 public key
+    pub public_key: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RevokedKey {
+    pub key: SigningKey,
+    /// Package signatures revoked with the key
+    pub signatures_revoked: u64,
+    /// Badges revoked with the key
+    pub badges_revoked: u64,
+}
+
+#[derive(sqlx::FromRow)]
+struct Caller {
+    id: Uuid,
+    stellar_address: String,
+}
+
+// ── Key validity ──────────────────────────────────────────────────────────────
+
+/// The message a key signs to prove the publisher holds it.
+pub fn registration_message(publisher_address: &str, public_key: &str) -> String {
+    format!(
+        "soroban-registry:register-key:{}:{}",
+        publisher_address, public_key
+    )
+}
+
+/// Decodes a base64 Ed25519 public key and returns it canonically encoded.
+pub(crate) fn decode_public_key(public_key: &str) -> ApiResult<(VerifyingKey, String)> {
+    let invalid = || {
+        ApiError::bad_request(
+            "InvalidPublicKey",
+            "public_key must be a base64-encoded 32-byte Ed25519 key",
+        )
+    };
+    let bytes: [u8; 32] = BASE64
+        .decode(public_key.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(invalid)?;
+    let key = VerifyingKey::from_bytes(&bytes).map_err(|_| invalid())?;
+    Ok((key, BASE64.encode(bytes)))
+}
+
+pub(crate) fn fingerprint(key: &VerifyingKey) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+/// The fingerprint prefix keys are shown by.
+fn short_fingerprint(key: &SigningKey) -> &str {
+    key.key_fingerprint
+        .get(..MIN_FINGERPRINT_PREFIX)
+        .unwrap_or(&key.key_fingerprint)
+}
+
+pub(crate) fn key_error(validity: KeyValidity, key: &SigningKey) -> Option<ApiError> {
+    let fingerprint = short_fingerprint(key);
+    let (code, message) = match (validity, key.revoked_at, key.valid_until) {
+        (KeyValidity::Valid, _, _) => return None,
+        (KeyValidity::Revoked, Some(at), _) => (
+            "SigningKeyRevoked",
+            format!("Key {} was revoked at {}", fingerprint, at.to_rfc3339()),
+        ),
+        (KeyValidity::Expired, _, Some(at)) => (
+            "SigningKeyExpired",
+            format!(
+                "Key {} was rotated out and stopped being valid at {}",
+                fingerprint,
+                at.to_rfc3339()
+            ),
+        ),
+        _ => (
+            "SigningKeyNotYetValid",
+            format!(
+                "Key {} is not valid until {}",
+                fingerprint,
+                key.valid_from.to_rfc3339()
+            ),
+        ),
+    };
+    Some(ApiError::unprocessable(code, message))
+}
+
+/// Refuses a release signed by `public_key` at `at` unless it is one of the
+/// publisher's keys valid then. Publishers without registered keys are not
+/// restricted.
+pub(crate) async fn require_usable_key(
+    db: &PgPool,
+    publisher_id: Uuid,
+    public_key: &str,
+    at: DateTime<Utc>,
+) -> ApiResult<()> {
+    let keys: Vec<SigningKey> =
+        sqlx::query_as("SELECT * FROM signing_keys WHERE publisher_id = $1")
+            .bind(publisher_id)
+            .fetch_all(db)
+            .await
+            .map_err(|err| db_internal_error("fetch publisher signing keys", err))?;
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let (_, public_key) = decode_public_key(public_key)?;
+    let key = keys
+        .iter()
+        .find(|key| key.public_key == public_key)
+        .ok_or_else(|| {
+            ApiError::unprocessable(
+                "SigningKeyNotRegistered",
+                "The publisher signs with registered keys and this is not one of them",
+            )
+        })?;
+    match key_error(key.window().validity_at(at), key) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+/// Refuses a signature by the Stellar account `address` at `at` when its key
+/// is registered and outside its window.
+pub(crate) async fn require_usable_account_key(
+    db: &PgPool,
+    address: &str,
+    at: DateTime<Utc>,
+) -> ApiResult<()> {
+    let Some(account_key) = account_public_key(address) else {
+        return Ok(());
+    };
+    let key: Option<SigningKey> =
+        sqlx::query_as("SELECT * FROM signing_keys WHERE public_key = $1")
+            .bind(account_key)
+            .fetch_optional(db)
+            .await
+            .map_err(|err| db_internal_error("fetch account signing key", err))?;
+    match key.and_then(|key| key_error(key.window().validity_at(at), &key)) {
+        Some(err) => Err(err),
+        None => Ok(()),
+    }
+}
+
+async fn load_key_windows(db: &PgPool) -> Result<HashMap<[u8; 32], KeyWindow>, sqlx::Error> {
+    let keys: Vec<SigningKey> = sqlx::query_as("SELECT * FROM signing_keys")
+        .fetch_all(db)
+        .await?;
+    Ok(keys
+        .iter()
+        .filter_map(|key| {
+            let bytes: [u8; 32] = BASE64.decode(&key.public_key).ok()?.try_into().ok()?;
+            Some((bytes, key.window()))
+        })
+        .collect())
+}
+
+pub(crate) async fn refresh_key_windows(state: &AppState) {
+    match load_key_windows(&state.db).await {
+        Ok(windows) => state.auth_mgr.write().unwrap().set_key_windows(windows),
+        Err(err) => tracing::warn!(error = %err, "failed to refresh signing key windows"),
+    }
+}
+
+/// Keeps the key windows logins are checked against in step with the
+/// database, including changes made through other instances.
+pub async fn run_key_window_refresh(state: AppState) {
+    let mut interval = tokio::time::interval(KEY_WINDOW_REFRESH);
+    loop {
+        interval.tick().await;
+        refresh_key_windows(&state).await;
+    }
+}
+
+// ── Helpers ───────────────────────────────────────────────────────────────────
+
+async fn caller(state: &AppState, tenant: &Tenant, claims: &AuthClaims) -> ApiResult<Caller> {
+    sqlx::query_as(
+        "SELECT id, stellar_address FROM publishers
+         WHERE stellar_address = $1 AND tenant_id = $2",
+    )
+    .bind(&claims.sub)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load publisher", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            "No publisher is registered for this account",
+        )
+    })
+}
+
+/// The caller's key named by `selector`: its UUID, public key or a
+/// fingerprint prefix of at least 16 characters.
+async fn owned_key(state: &AppState, publisher_id: Uuid, selector: &str) -> ApiResult<SigningKey> {
+    let selector = selector.trim();
+    let id = Uuid::parse_str(selector).ok();
+    let prefix = (selector.len() >= MIN_FINGERPRINT_PREFIX
+        && selector.chars().all(|c| c.is_ascii_hexdigit()))
+    .then(|| format!("{}%", selector.to_ascii_lowercase()));
+    let mut keys: Vec<SigningKey> = sqlx::query_as(
+        "SELECT * FROM signing_keys
+         WHERE publisher_id = $1
+           AND (id = $2 OR public_key = $3 OR key_fingerprint LIKE $4)
+         LIMIT 2",
+    )
+    .bind(publisher_id)
+    .bind(id)
+    .bind(selector)
+    .bind(prefix)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch signing key", err))?;
+    match keys.len() {
+        1 => Ok(keys.remove(0)),
+        0 => Err(ApiError::not_found(
+            "SigningKeyNotFound",
+            format!("You have no signing key matching {}", selector),
+        )),
+        _ => Err(ApiError::bad_request(
+            "AmbiguousSigningKey",
+            format!("More than one of your keys matches {}", selector),
+        )),
+    }
+}
+
+fn validate_label(label: Option<&str>) -> ApiResult<Option<String>> {
+    let Some(label) = label.map(str::trim).filter(|label| !label.is_empty()) else {
+        return Ok(None);
+    };
+    if label.chars().count() > MAX_LABEL_CHARS {
+        return Err(ApiError::bad_request(
+            "InvalidSigningKey",
+            format!("label must be at most {} characters", MAX_LABEL_CHARS),
+        ));
+    }
+    Ok(Some(label.to_string()))
+}
+
+/// Checks that `proof` is `key`'s base64 signature over `message`.
+pub(crate) fn verify_proof(key: &VerifyingKey, message: &str, proof: &str) -> ApiResult<()> {
+    let proof: [u8; 64] = BASE64
+        .decode(proof.trim())
+        .ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| {
+            ApiError::bad_request(
+                "InvalidKeyProof",
+                "proof must be a base64 64-byte signature",
+            )
+        })?;
+    key.verify(message.as_bytes(), &Signature::from_bytes(&proof))
+        .map_err(|_| {
+            ApiError::unprocessable(
+                "InvalidKeyProof",
+                format!("proof must be the key's signature over \"{}\"", message),
+            )
+        })
+}
+
+/// Checks the key and its proof of possession, returning the canonical
+/// public key and fingerprint.
+fn check_new_key(caller: &Caller, public_key: &str, proof: &str) -> ApiResult<(String, String)> {
+    let (key, public_key) = decode_public_key(public_key)?;
+    verify_proof(
+        &key,
+        &registration_message(&caller.stellar_address, &public_key),
+        proof,
+    )?;
+    Ok((public_key, fingerprint(&key)))
+}
+
+/// The account key of the Stellar address `address`, base64-encoded as
+/// signing keys are stored.
+pub(crate) fn account_public_key(address: &str) -> Option<String> {
+    stellar_strkey::ed25519::PublicKey::from_string(address)
+        .ok()
+        .map(|account| BASE64.encode(account.0))
+}
+
+/// Revokes `key` from `revoked_at`, with the package signatures the registry
+/// received since then and, when it is `account_address`'s key, the badges.
+/// Returns the updated key and how many signatures and badges went with it.
+pub(crate) async fn revoke_with_dependents(
+    conn: &mut PgConnection,
+    publisher_id: Uuid,
+    account_address: &str,
+    key: &SigningKey,
+    revoked_at: DateTime<Utc>,
+    reason: &str,
+    revoked_by: &str,
+) -> ApiResult<(SigningKey, u64, u64)> {
+    let key: SigningKey = sqlx::query_as(
+        "UPDATE signing_keys
+            SET revoked_at = $2, revocation_reason = $3, is_active = FALSE, deactivated_at = NOW()
+          WHERE id = $1
+          RETURNING *",
+    )
+    .bind(key.id)
+    .bind(revoked_at)
+    .bind(reason)
+    .fetch_one(&mut *conn)
+    .await
+    .map_err(|err| db_internal_error("revoke signing key", err))?;
+
+    let revocation_reason = format!("signing key revoked: {}", reason);
+    let signatures_revoked = sqlx::query(
+        "UPDATE package_signatures
+            SET status = 'revoked', revoked_at = NOW(), revoked_by = $3, revoked_reason = $4,
+                updated_at = NOW()
+          WHERE public_key = $1 AND signed_at >= $2 AND status = 'valid'",
+    )
+    .bind(&key.public_key)
+    .bind(revoked_at)
+    .bind(revoked_by)
+    .bind(&revocation_reason)
+    .execute(&mut *conn)
+    .await
+    .map_err(|err| db_internal_error("revoke signatures by key", err))?
+    .rows_affected();
+
+    // Badges are signed with the authority's account key.
+    let is_account_key =
+        account_public_key(account_address).as_deref() == Some(key.public_key.as_str());
+    let badges_revoked = if is_account_key {
+        sqlx::query(
+            "UPDATE contract_badges
+                SET revoked_at = NOW(), revocation_reason = $3
+              WHERE authority_id IN (SELECT id FROM attestation_authorities WHERE publisher_id = $1)
+                AND created_at >= $2 AND revoked_at IS NULL",
+        )
+        .bind(publisher_id)
+        .bind(revoked_at)
+        .bind(&revocation_reason)
+        .execute(&mut *conn)
+        .await
+        .map_err(|err| db_internal_error("revoke badges by key", err))?
+        .rows_affected()
+    } else {
+        0
+    };
+    Ok((key, signatures_revoked, badges_revoked))
+}
+
+fn map_insert_error(err: sqlx::Error) -> ApiError {
+    match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "SigningKeyExists",
+            "This key is already registered; a revoked or rotated key cannot be registered again",
+        ),
+        _ => db_internal_error("register signing key", err),
+    }
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/me/keys",
+    responses(
+        (status = 200, description = "The caller's signing keys, newest first", body = [SigningKey]),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No publisher for this account")
+    ),
+    tag = "Publishers"
+)]
+pub async fn list_my_keys(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+) -> ApiResult<Json<Vec<SigningKey>>> {
+    let caller = caller(&state, &tenant, &claims).await?;
+    publisher_keys(&state, caller.id).await.map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/keys",
+    request_body = RegisterSigningKeyRequest,
+    responses(
+        (status = 201, description = "Key registered", body = SigningKey),
+        (status = 400, description = "Malformed key, proof or label"),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 409, description = "Key already registered"),
+        (status = 422, description = "Proof is not the key's signature")
+    ),
+    tag = "Publishers"
+)]
+pub async fn register_key(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Json(req): Json<RegisterSigningKeyRequest>,
+) -> ApiResult<(StatusCode, Json<SigningKey>)> {
+    let caller = caller(&state, &tenant, &claims).await?;
+    if req
+        .algorithm
+        .as_deref()
+        .is_some_and(|algo| algo != "ed25519")
+    {
+        return Err(ApiError::bad_request(
+            "InvalidSigningKey",
+            "Only ed25519 keys are supported",
+        ));
+    }
+    let label = validate_label(req.label.as_deref())?;
+    let (public_key, key_fingerprint) = check_new_key(&caller, &req.public_key, &req.proof)?;
+
+    let key: SigningKey = sqlx::query_as(
+        "INSERT INTO signing_keys (publisher_id, public_key, key_fingerprint, label)
+         VALUES ($1, $2, $3, $4)
+         RETURNING *",
+    )
+    .bind(caller.id)
+    .bind(&public_key)
+    .bind(&key_fingerprint)
+    .bind(&label)
+    .fetch_one(&state.db)
+    .await
+    .map_err(map_insert_error)?;
+
+    append_transparency_log_entry(
+        &state,
+        TransparencyEntryType::KeyRegistered,
+        None,
+        None,
+        &caller.stellar_address,
+        Some(json!({ "key_id": key.id, "fingerprint": key.key_fingerprint })),
+    )
+    .await?;
+    refresh_key_windows(&state).await;
+
+    tracing::info!(publisher = %caller.id, fingerprint = %key.key_fingerprint, "signing key registered");
+    Ok((StatusCode::CREATED, Json(key)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/keys/{key}/rotate",
+    params(("key" = String, Path, description = "Key UUID, public key or fingerprint prefix")),
+    request_body = RotateSigningKeyRequest,
+    responses(
+        (status = 201, description = "The replacement key", body = SigningKey),
+        (status = 400, description = "Malformed key, proof or overlap"),
+        (status = 404, description = "No such key"),
+        (status = 409, description = "Replacement already registered"),
+        (status = 422, description = "Proof is not the key's signature, or the old key is no longer valid")
+    ),
+    tag = "Publishers"
+)]
+pub async fn rotate_key(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Path(selector): Path<String>,
+    Json(req): Json<RotateSigningKeyRequest>,
+) -> ApiResult<(StatusCode, Json<SigningKey>)> {
+    let caller = caller(&state, &tenant, &claims).await?;
+    let old = owned_key(&state, caller.id, &selector).await?;
+    let now = Utc::now();
+    if let Some(err) = key_error(old.window().validity_at(now), &old) {
+        return Err(err);
+    }
+    let overlap_hours = req.overlap_hours.unwrap_or(DEFAULT_OVERLAP_HOURS);
+    if !(0..=MAX_OVERLAP_HOURS).contains(&overlap_hours) {
+        return Err(ApiError::bad_request(
+            "InvalidOverlap",
+            format!("overlap_hours must be between 0 and {}", MAX_OVERLAP_HOURS),
+        ));
+    }
+    let label = validate_label(req.label.as_deref())?.or_else(|| old.label.clone());
+    let (public_key, key_fingerprint) = check_new_key(&caller, &req.public_key, &req.proof)?;
+    // An earlier rotation's overlap is never extended.
+    let valid_until = old
+        .valid_until
+        .map_or(now + Duration::hours(overlap_hours), |until| {
+            until.min(now + Duration::hours(overlap_hours))
+        });
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin key rotation", err))?;
+    let new: SigningKey = sqlx::query_as(
+        "INSERT INTO signing_keys (publisher_id, public_key, key_fingerprint, label)
+         VALUES ($1, $2, $3, $4)
+         RETURNING *",
+    )
+    .bind(caller.id)
+    .bind(&public_key)
+    .bind(&key_fingerprint)
+    .bind(&label)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(map_insert_error)?;
+    sqlx::query("UPDATE signing_keys SET valid_until = $2, replaced_by = $3 WHERE id = $1")
+        .bind(old.id)
+        .bind(valid_until)
+        .bind(new.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("end rotated signing key", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit key rotation", err))?;
+
+    append_transparency_log_entry(
+        &state,
+        TransparencyEntryType::KeyRotated,
+        None,
+        None,
+        &caller.stellar_address,
+        Some(json!({
+            "old_fingerprint": old.key_fingerprint,
+            "new_fingerprint": new.key_fingerprint,
+            "old_valid_until": valid_until,
+        })),
+    )
+    .await?;
+    refresh_key_windows(&state).await;
+
+    tracing::info!(
+        publisher = %caller.id,
+        old = %old.key_fingerprint,
+        new = %new.key_fingerprint,
+        "signing key rotated"
+    );
+    Ok((StatusCode::CREATED, Json(new)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/keys/{key}/revoke",
+    params(("key" = String, Path, description = "Key UUID, public key or fingerprint prefix")),
+    request_body = RevokeSigningKeyRequest,
+    responses(
+        (status = 200, description = "Key revoked, with what was revoked with it", body = RevokedKey),
+        (status = 400, description = "Missing reason or revocation time in the future"),
+        (status = 404, description = "No such key"),
+        (status = 409, description = "Key already revoked")
+    ),
+    tag = "Publishers"
+)]
+pub async fn revoke_key(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Path(selector): Path<String>,
+    Json(req): Json<RevokeSigningKeyRequest>,
+) -> ApiResult<Json<RevokedKey>> {
+    let caller = caller(&state, &tenant, &claims).await?;
+    let key = owned_key(&state, caller.id, &selector).await?;
+    let reason = req.reason.trim();
+    if reason.is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidRevocation",
+            "reason is required",
+        ));
+    }
+    if let Some(revoked_at) = key.revoked_at {
+        return Err(ApiError::conflict(
+            "SigningKeyRevoked",
+            format!(
+                "This key was already revoked at {}",
+                revoked_at.to_rfc3339()
+            ),
+        ));
+    }
+    let now = Utc::now();
+    let revoked_at = req.revoked_at.unwrap_or(now);
+    if revoked_at > now {
+        return Err(ApiError::bad_request(
+            "InvalidRevocation",
+            "revoked_at cannot be in the future; rotate the key to retire it later",
+        ));
+    }
+    let revoked_at = revoked_at.max(key.valid_from);
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin key revocation", err))?;
+    let (key, signatures_revoked, badges_revoked) = revoke_with_dependents(
+        &mut tx,
+        caller.id,
+        &caller.stellar_address,
+        &key,
+        revoked_at,
+        reason,
+        &caller.stellar_address,
+    )
+    .await?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit key revocation", err))?;
+
+    append_transparency_log_entry(
+        &state,
+        TransparencyEntryType::KeyRevoked,
+        None,
+        None,
+        &caller.stellar_address,
+        Some(json!({
+            "fingerprint": key.key_fingerprint,
+            "revoked_at": revoked_at,
+            "reason": reason,
+            "signatures_revoked": signatures_revoked,
+            "badges_revoked": badges_revoked,
+        })),
+    )
+    .await?;
+    refresh_key_windows(&state).await;
+
+    tracing::info!(
+        publisher = %caller.id,
+        fingerprint = %key.key_fingerprint,
+        signatures_revoked,
+        badges_revoked,
+        "signing key revoked"
+    );
+    Ok(Json(RevokedKey {
+        key,
+        signatures_revoked,
+        badges_revoked,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/publishers/{id}/keys",
+    params(("id" = String, Path, description = "Publisher UUID")),
+    responses(
+        (status = 200, description = "The publisher's signing keys, newest first", body = [SigningKey]),
+        (status = 400, description = "Invalid publisher ID"),
+        (status = 404, description = "Publisher not found")
+    ),
+    tag = "Publishers"
+)]
+pub async fn list_publisher_keys(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<SigningKey>>> {
+    let publisher_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidPublisherId",
+            format!("Invalid publisher ID format: {}", id),
+        )
+    })?;
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM publishers
+                        WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL)",
+    )
+    .bind(publisher_id)
+    .bind(tenant.id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check publisher", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "PublisherNotFound",
+            format!("No publisher found with ID: {}", id),
+        ));
+    }
+    publisher_keys(&state, publisher_id).await.map(Json)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/keys/lookup",
+    params(KeyLookupQuery),
+    responses(
+        (status = 200, description = "Registrations of the key; empty when it is not registered", body = [SigningKey]),
+        (status = 400, description = "Malformed public key")
+    ),
+    tag = "Publishers"
+)]
+pub async fn lookup_key(
+    State(state): State<AppState>,
+    Query(query): Query<KeyLookupQuery>,
+) -> ApiResult<Json<Vec<SigningKey>>> {
+    let (_, public_key) = decode_public_key(&query.public_key)?;
+    let keys: Vec<SigningKey> = sqlx::query_as("SELECT * FROM signing_keys WHERE public_key = $1")
+        .bind(public_key)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("look up signing key", err))?;
+    Ok(Json(keys))
+}
+
+async fn publisher_keys(state: &AppState, publisher_id: Uuid) -> ApiResult<Vec<SigningKey>> {
+    sqlx::query_as(
+        "SELECT * FROM signing_keys WHERE publisher_id = $1 ORDER BY valid_from DESC, created_at DESC",
+    )
+    .bind(publisher_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list signing keys", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_honor_overlap_and_revocation() {
+        let start = Utc::now() - Duration::days(10);
+        let rotated = KeyWindow {
+            valid_from: start,
+            valid_until: Some(start + Duration::days(5)),
+            revoked_at: None,
+        };
+        assert_eq!(
+            rotated.validity_at(start - Duration::seconds(1)),
+            KeyValidity::NotYetValid
+        );
+        assert_eq!(
+            rotated.validity_at(start + Duration::days(4)),
+            KeyValidity::Valid
+        );
+        assert_eq!(
+            rotated.validity_at(start + Duration::days(5)),
+            KeyValidity::Expired
+        );
+
+        let revoked = KeyWindow {
+            revoked_at: Some(start + Duration::days(2)),
+            ..rotated
+        };
+        assert_eq!(
+            revoked.validity_at(start + Duration::days(1)),
+            KeyValidity::Valid
+        );
+        assert_eq!(
+            revoked.validity_at(start + Duration::days(3)),
+            KeyValidity::Revoked
+        );
+        assert_eq!(
+            revoked.validity_at(start + Duration::days(6)),
+            KeyValidity::Revoked
+        );
+    }
+
+    #[test]
+    fn registration_requires_the_private_key() {
+        use ed25519_dalek::{Signer, SigningKey as Ed25519Key};
+
+        let key = Ed25519Key::from_bytes(&[5u8; 32]);
+        let public_key = BASE64.encode(key.verifying_key().to_bytes());
+        let caller = Caller {
+            id: Uuid::nil(),
+            stellar_address: "GPUBLISHER".to_string(),
+        };
+        let proof = key.sign(registration_message("GPUBLISHER", &public_key).as_bytes());
+        let (canonical, fingerprint) =
+            check_new_key(&caller, &public_key, &BASE64.encode(proof.to_bytes())).unwrap();
+        assert_eq!(canonical, public_key);
+        assert_eq!(fingerprint.len(), 64);
+
+        let other = Caller {
+            stellar_address: "GSOMEONEELSE".to_string(),
+            ..caller
+        };
+        assert!(check_new_key(&other, &public_key, &BASE64.encode(proof.to_bytes())).is_err());
+    }
+}
@@ -0,0 +1,222 @@
+//! Publisher display metadata
+//!
+//!   PATCH /api/me/profile  – set the caller's display name, pronouns and avatar
+//!
+//! Contracts are returned with a [`PublisherProfile`] of their publisher (see
+//! [`profiles`]), so listings can show who maintains a contract without a
+//! second request. A publisher without an explicit avatar gets the Gravatar
+//! of their email once it is verified; unverified addresses are never
+//! hashed into a public URL.
+
+use std::collections::HashMap;
+
+use axum::{extract::State, Json};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use shared::{Publisher, PublisherProfile};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+    validation::{url_validation::validate_https_url_only, validators::validate_no_html},
+};
+
+const MAX_DISPLAY_NAME_CHARS: usize = 100;
+const MAX_PRONOUNS_CHARS: usize = 32;
+const MAX_AVATAR_URL_CHARS: usize = 500;
+
+// ── Request types ─────────────────────────────────────────────────────────────
+
+/// Fields to change; an empty string clears a field.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateProfileRequest {
+    pub display_name: Option<String>,
+    /// e.g. "she/her", "they/them"
+    pub pronouns: Option<String>,
+    /// HTTPS image URL; clear it to fall back to Gravatar
+    pub avatar_url: Option<String>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct ProfileRow {
+    id: Uuid,
+    stellar_address: String,
+    username: Option<String>,
+    display_name: Option<String>,
+    pronouns: Option<String>,
+    avatar_url: Option<String>,
+    email: Option<String>,
+    email_verified_at: Option<DateTime<Utc>>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Gravatar URL for an email; Gravatar accepts the SHA-256 of the trimmed,
+/// lowercased address.
+fn gravatar_url(email: &str) -> String {
+    let hash = hex::encode(Sha256::digest(email.trim().to_lowercase().as_bytes()));
+    format!("https://www.gravatar.com/avatar/{}?d=identicon", hash)
+}
+
+fn profile(row: ProfileRow) -> PublisherProfile {
+    let gravatar = match (&row.email, row.email_verified_at) {
+        (Some(email), Some(_)) => Some(gravatar_url(email)),
+        _ => None,
+    };
+    PublisherProfile {
+        id: row.id,
+        stellar_address: row.stellar_address,
+        name: row.display_name.or(row.username),
+        pronouns: row.pronouns,
+        avatar_url: row.avatar_url.or(gravatar),
+    }
+}
+
+/// Profiles of the given publishers, keyed by publisher id.
+pub(crate) async fn profiles(
+    db: &sqlx::PgPool,
+    publisher_ids: &[Uuid],
+) -> sqlx::Result<HashMap<Uuid, PublisherProfile>> {
+    let rows: Vec<ProfileRow> = sqlx::query_as(
+        "SELECT id, stellar_address, username, display_name, pronouns, avatar_url,
+                email, email_verified_at
+         FROM publishers WHERE id = ANY($1)",
+    )
+    .bind(publisher_ids)
+    .fetch_all(db)
+    .await?;
+    Ok(rows.into_iter().map(|row| (row.id, profile(row))).collect())
+}
+
+/// `None` leaves a field alone, `Some(None)` clears it.
+fn field(value: Option<String>) -> Option<Option<String>> {
+    value.map(|v| {
+        let v = v.trim().to_string();
+        (!v.is_empty()).then_some(v)
+    })
+}
+
+fn validate_text(field: &str, value: &str, max_chars: usize) -> ApiResult<()> {
+    if value.chars().count() > max_chars {
+        return Err(ApiError::bad_request(
+            "InvalidProfile",
+            format!("{} must be at most {} characters", field, max_chars),
+        ));
+    }
+    validate_no_html(value)
+        .map_err(|msg| ApiError::bad_request("InvalidProfile", format!("{}: {}", field, msg)))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    patch,
+    path = "/api/me/profile",
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Updated publisher", body = Publisher),
+        (status = 400, description = "A field is too long, contains HTML, or the avatar is not an HTTPS URL"),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No publisher for this account")
+    ),
+    tag = "Publishers"
+)]
+pub async fn update_profile(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: AuthClaims,
+    Json(req): Json<UpdateProfileRequest>,
+) -> ApiResult<Json<Publisher>> {
+    let display_name = field(req.display_name);
+    let pronouns = field(req.pronouns);
+    let avatar_url = field(req.avatar_url);
+
+    if let Some(Some(name)) = &display_name {
+        validate_text("display_name", name, MAX_DISPLAY_NAME_CHARS)?;
+    }
+    if let Some(Some(pronouns)) = &pronouns {
+        validate_text("pronouns", pronouns, MAX_PRONOUNS_CHARS)?;
+    }
+    if let Some(Some(url)) = &avatar_url {
+        validate_text("avatar_url", url, MAX_AVATAR_URL_CHARS)?;
+        validate_https_url_only(url).map_err(|msg| {
+            ApiError::bad_request("InvalidProfile", format!("avatar_url: {}", msg))
+        })?;
+    }
+
+    let publisher: Publisher = sqlx::query_as(
+        "UPDATE publishers SET
+            display_name = CASE WHEN $3 THEN $4 ELSE display_name END,
+            pronouns = CASE WHEN $5 THEN $6 ELSE pronouns END,
+            avatar_url = CASE WHEN $7 THEN $8 ELSE avatar_url END
+         WHERE stellar_address = $1 AND tenant_id = $2
+         RETURNING *",
+    )
+    .bind(&claims.sub)
+    .bind(tenant.id)
+    .bind(display_name.is_some())
+    .bind(display_name.flatten())
+    .bind(pronouns.is_some())
+    .bind(pronouns.flatten())
+    .bind(avatar_url.is_some())
+    .bind(avatar_url.flatten())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("update publisher profile", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            "No publisher is registered for this account",
+        )
+    })?;
+
+    Ok(Json(publisher))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(email_verified: bool, avatar_url: Option<&str>) -> ProfileRow {
+        ProfileRow {
+            id: Uuid::nil(),
+            stellar_address: "GABC".to_string(),
+            username: Some("sorobandev".to_string()),
+            display_name: None,
+            pronouns: Some("they/them".to_string()),
+            avatar_url: avatar_url.map(str::to_string),
+            email: Some(" Dev@Soroban.io ".to_string()),
+            email_verified_at: email_verified.then(Utc::now),
+        }
+    }
+
+    #[test]
+    fn gravatar_only_for_verified_emails() {
+        let verified = profile(row(true, None));
+        assert_eq!(verified.avatar_url, Some(gravatar_url("dev@soroban.io")));
+        assert_eq!(verified.name.as_deref(), Some("sorobandev"));
+
+        assert_eq!(profile(row(false, None)).avatar_url, None);
+
+        let explicit = profile(row(true, Some("https://img.example/me.png")));
+        assert_eq!(
+            explicit.avatar_url.as_deref(),
+            Some("https://img.example/me.png")
+        );
+    }
+
+    #[test]
+    fn blank_fields_clear() {
+        assert_eq!(field(None), None);
+        assert_eq!(field(Some("  ".to_string())), Some(None));
+        assert_eq!(
+            field(Some(" she/her ".to_string())),
+            Some(Some("she/her".to_string()))
+        );
+    }
+}
@@ -0,0 +1,184 @@
+//! Publisher dashboard statistics
+//!
+//!   GET /api/publishers/:id/stats – health overview across all of a
+//!                                   publisher's contracts
+//!
+//! Downloads are source retrievals recorded in `source_access_logs`;
+//! dependents are contracts declaring a dependency on one of the publisher's
+//! contracts; advisories count while their incident is not resolved or
+//! closed; pending proposals are governance proposals still pending or in
+//! voting plus unexpired multisig deploy proposals. Private contracts are
+//! only included when the publisher themselves asks.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::Serialize;
+use shared::models::Network;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PublisherStats {
+    pub publisher_id: Uuid,
+    pub contracts: i64,
+    pub downloads: i64,
+    /// Distinct contracts depending on any of the publisher's contracts
+    pub dependents: i64,
+    pub verified_contracts: i64,
+    /// Share of contracts that are verified, 0–100
+    pub verification_coverage_pct: f64,
+    /// Distinct unresolved advisories affecting any of the contracts
+    pub open_advisories: i64,
+    pub pending_proposals: i64,
+    pub per_contract: Vec<PublisherContractStats>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct PublisherContractStats {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub name: String,
+    pub network: Network,
+    pub is_verified: bool,
+    pub downloads: i64,
+    pub dependents: i64,
+    pub open_advisories: i64,
+    pub pending_proposals: i64,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn coverage_pct(verified: i64, total: i64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (verified as f64 * 1000.0 / total as f64).round() / 10.0
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/publishers/{id}/stats",
+    params(("id" = Uuid, Path, description = "Publisher ID")),
+    responses(
+        (status = 200, description = "Aggregated statistics", body = PublisherStats),
+        (status = 400, description = "Invalid publisher ID"),
+        (status = 404, description = "Publisher not found")
+    ),
+    tag = "Publishers"
+)]
+pub async fn get_publisher_stats(
+    State(state): State<AppState>,
+    claims: Option<AuthClaims>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<PublisherStats>> {
+    let publisher_id = Uuid::parse_str(&id).map_err(|_| {
+        ApiError::bad_request(
+            "InvalidPublisherId",
+            format!("Invalid publisher ID format: {}", id),
+        )
+    })?;
+    let address: String = sqlx::query_scalar(
+        "SELECT stellar_address FROM publishers
+         WHERE id = $1 AND tenant_id = $2 AND deleted_at IS NULL",
+    )
+    .bind(publisher_id)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("get publisher", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            format!("No publisher found with ID: {}", id),
+        )
+    })?;
+    let include_private = claims.is_some_and(|c| c.sub == address);
+
+    let per_contract: Vec<PublisherContractStats> = sqlx::query_as(
+        "SELECT c.id, c.contract_id, c.name, c.network, c.is_verified,
+            (SELECT COUNT(*) FROM source_access_logs l
+             JOIN contract_sources s ON s.id = l.contract_source_id
+             JOIN contract_versions v ON v.id = s.contract_version_id
+             WHERE v.contract_id = c.id AND l.action = 'retrieve') AS downloads,
+            (SELECT COUNT(DISTINCT d.contract_id) FROM contract_dependencies d
+             WHERE d.dependency_contract_id = c.id) AS dependents,
+            (SELECT COUNT(DISTINCT sa.id) FROM security_advisories sa
+             JOIN security_incidents si ON si.id = sa.incident_id
+             JOIN incident_affected_contracts iac ON iac.incident_id = si.id
+             WHERE iac.contract_id = c.id
+               AND si.status NOT IN ('resolved', 'closed')) AS open_advisories,
+            (SELECT COUNT(*) FROM governance_proposals gp
+             WHERE gp.contract_id = c.id AND gp.status IN ('pending', 'active'))
+            + (SELECT COUNT(*) FROM deploy_proposals dp
+               WHERE dp.contract_id = c.contract_id AND dp.network = c.network
+                 AND dp.status = 'pending' AND dp.expires_at > NOW()) AS pending_proposals
+         FROM contracts c
+         WHERE c.publisher_id = $1 AND c.deleted_at IS NULL
+           AND ($2 OR c.visibility = 'public')
+         ORDER BY c.name, c.network",
+    )
+    .bind(publisher_id)
+    .bind(include_private)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("publisher contract stats", err))?;
+
+    // A dependent or advisory touching several of the publisher's contracts
+    // counts once in the totals.
+    let contract_ids: Vec<Uuid> = per_contract.iter().map(|c| c.id).collect();
+    let (dependents, open_advisories): (i64, i64) = sqlx::query_as(
+        "SELECT
+            (SELECT COUNT(DISTINCT d.contract_id) FROM contract_dependencies d
+             WHERE d.dependency_contract_id = ANY($1)),
+            (SELECT COUNT(DISTINCT sa.id) FROM security_advisories sa
+             JOIN security_incidents si ON si.id = sa.incident_id
+             JOIN incident_affected_contracts iac ON iac.incident_id = si.id
+             WHERE iac.contract_id = ANY($1)
+               AND si.status NOT IN ('resolved', 'closed'))",
+    )
+    .bind(&contract_ids)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("publisher stats totals", err))?;
+
+    let contracts = per_contract.len() as i64;
+    let verified_contracts = per_contract.iter().filter(|c| c.is_verified).count() as i64;
+    Ok(Json(PublisherStats {
+        publisher_id,
+        contracts,
+        downloads: per_contract.iter().map(|c| c.downloads).sum(),
+        dependents,
+        verified_contracts,
+        verification_coverage_pct: coverage_pct(verified_contracts, contracts),
+        open_advisories,
+        pending_proposals: per_contract.iter().map(|c| c.pending_proposals).sum(),
+        per_contract,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coverage_is_a_rounded_percentage() {
+        assert_eq!(coverage_pct(0, 0), 0.0);
+        assert_eq!(coverage_pct(1, 3), 33.3);
+        assert_eq!(coverage_pct(2, 3), 66.7);
+        assert_eq!(coverage_pct(4, 4), 100.0);
+    }
+}
@@ -0,0 +1,208 @@
+//! Publisher storage usage and quotas
+//!
+//!   GET /api/publishers/:id/usage – stored bytes against the publisher's
+//!                                   quota (the publisher or an admin)
+//!
+//! Sources stored for a publisher's contracts, both uploaded sources and the
+//! bundles archived on verification, count against a quota: the publisher's
+//! `storage_quota_bytes` when set, PUBLISHER_STORAGE_QUOTA_MB otherwise
+//! (default 256, 0 for none). Uploads that would go over it are refused with
+//! 507 before anything is stored. Archiving a verified bundle is never
+//! refused, so a full quota cannot fail a verification, but the bundle still
+//! counts. Soft-deleted contracts keep their sources until they are purged.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Serialize;
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::{
+    auth::{self, AuthClaims},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const DEFAULT_QUOTA_MB: i64 = 256;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PublisherUsage {
+    pub publisher_id: Uuid,
+    pub used_bytes: i64,
+    /// Absent when the publisher has no quota
+    pub quota_bytes: Option<i64>,
+    pub remaining_bytes: Option<i64>,
+    /// Share of the quota in use, 0–100
+    pub used_pct: Option<f64>,
+    pub files: i64,
+    pub by_format: Vec<FormatUsage>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct FormatUsage {
+    /// `rust`, `wasm` or `bundle`
+    pub source_format: String,
+    pub files: i64,
+    pub bytes: i64,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// The quota of publishers without one of their own; `None` when unlimited.
+fn default_quota_bytes() -> Option<i64> {
+    let mb = std::env::var("PUBLISHER_STORAGE_QUOTA_MB")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .unwrap_or(DEFAULT_QUOTA_MB);
+    (mb > 0).then(|| mb * 1024 * 1024)
+}
+
+fn used_pct(used: i64, quota: i64) -> f64 {
+    if quota == 0 {
+        return 100.0;
+    }
+    (used as f64 * 1000.0 / quota as f64).round() / 10.0
+}
+
+async fn usage_by_format(state: &AppState, publisher_id: Uuid) -> ApiResult<Vec<FormatUsage>> {
+    sqlx::query_as(
+        "SELECT s.source_format::TEXT AS source_format, COUNT(*) AS files,
+                COALESCE(SUM(s.source_size), 0)::BIGINT AS bytes
+         FROM contract_sources s
+         JOIN contract_versions v ON v.id = s.contract_version_id
+         JOIN contracts c ON c.id = v.contract_id
+         WHERE c.publisher_id = $1
+         GROUP BY s.source_format
+         ORDER BY s.source_format",
+    )
+    .bind(publisher_id)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("publisher storage usage", err))
+}
+
+/// Refuses to store `requested` more bytes for `contract_id` when that would
+/// take its publisher over quota. Contracts without a publisher are not
+/// limited.
+pub async fn ensure_storage_quota(
+    state: &AppState,
+    contract_id: Uuid,
+    requested: i64,
+) -> ApiResult<()> {
+    let publisher: Option<(Uuid, Option<i64>)> = sqlx::query_as(
+        "SELECT p.id, p.storage_quota_bytes FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.id = $1",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load publisher quota", err))?;
+    let Some((publisher_id, own_quota)) = publisher else {
+        return Ok(());
+    };
+    let Some(quota) = own_quota.or_else(default_quota_bytes) else {
+        return Ok(());
+    };
+
+    let used: i64 = usage_by_format(state, publisher_id)
+        .await?
+        .iter()
+        .map(|format| format.bytes)
+        .sum();
+    if used + requested <= quota {
+        return Ok(());
+    }
+    Err(ApiError::new(
+        StatusCode::INSUFFICIENT_STORAGE,
+        "StorageQuotaExceeded",
+        format!(
+            "Storing {} bytes would exceed the publisher's storage quota ({} of {} bytes used)",
+            requested, used, quota
+        ),
+    )
+    .with_details(json!({
+        "reason": "StorageQuotaExceeded",
+        "publisher_id": publisher_id,
+        "quota_bytes": quota,
+        "used_bytes": used,
+        "requested_bytes": requested,
+    })))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/publishers/{id}/usage",
+    params(("id" = String, Path, description = "Publisher ID or Stellar address")),
+    responses(
+        (status = 200, description = "Storage used against the quota", body = PublisherUsage),
+        (status = 401, description = "Authentication required"),
+        (status = 403, description = "Not this publisher or an admin"),
+        (status = 404, description = "Publisher not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Publishers"
+)]
+pub async fn get_publisher_usage(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    tenant: Tenant,
+    Path(id): Path<String>,
+) -> ApiResult<Json<PublisherUsage>> {
+    let (publisher_id, address, own_quota): (Uuid, String, Option<i64>) = sqlx::query_as(
+        "SELECT id, stellar_address, storage_quota_bytes FROM publishers
+         WHERE (id::TEXT = $1 OR stellar_address = $1)
+           AND tenant_id = $2 AND deleted_at IS NULL",
+    )
+    .bind(&id)
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("get publisher", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "PublisherNotFound",
+            format!("No publisher found with ID: {}", id),
+        )
+    })?;
+    if claims.sub != address && !auth::is_admin(&claims) {
+        return Err(ApiError::forbidden(
+            "Only the publisher or an admin can see its storage usage",
+        ));
+    }
+
+    let by_format = usage_by_format(&state, publisher_id).await?;
+    let used_bytes = by_format.iter().map(|format| format.bytes).sum();
+    let quota_bytes = own_quota.or_else(default_quota_bytes);
+    Ok(Json(PublisherUsage {
+        publisher_id,
+        used_bytes,
+        quota_bytes,
+        remaining_bytes: quota_bytes.map(|quota| (quota - used_bytes).max(0)),
+        used_pct: quota_bytes.map(|quota| used_pct(used_bytes, quota)),
+        files: by_format.iter().map(|format| format.files).sum(),
+        by_format,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_is_a_rounded_percentage() {
+        assert_eq!(used_pct(0, 1024), 0.0);
+        assert_eq!(used_pct(1, 3), 33.3);
+        assert_eq!(used_pct(2048, 1024), 200.0);
+        assert_eq!(used_pct(0, 0), 100.0);
+    }
+}
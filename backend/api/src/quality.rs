@@ -0,0 +1,474 @@
+//! Contract quality score
+//!
+//! A 0-100 score built from five components, each worth a fixed share:
+//!
+//! * verified source (25) – the published source was verified against the
+//!   deployed WASM;
+//! * documentation (20) – the latest version's `docs_coverage`: the share
+//!   of its spec functions and types with a doc comment;
+//! * ABI stability (20) – share of version-to-version upgrades without
+//!   breaking ABI changes; a single version counts as stable;
+//! * test attestation (15) – an authority attested with a `tested` badge
+//!   that the current build's test suite passes; two thirds when only the
+//!   publisher's signed CI runs report the current build passing (see
+//!   [`crate::test_run_handlers`]);
+//! * audit (20) – an audit report is attached to the current version or an
+//!   `audited-by` badge covers the current build; half when only an older
+//!   version was audited.
+//!
+//! A `quality_score` job recomputes one contract (`{"contract_id": ...}`) or,
+//! with an empty payload, every contract. The scheduler queues a full run
+//! daily and publishing a version queues its contract. The score is stored
+//! on `contracts.quality_score` for sorting and with its breakdown in
+//! `contract_quality_scores` (see [`crate::quality_handlers`]).
+
+use anyhow::Context;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use shared::{attestation, semver::SemVer};
+use sqlx::PgPool;
+use std::time::Duration;
+use uuid::Uuid;
+
+use crate::{
+    badge_handlers::ACTIVE_BADGE,
+    breaking_changes::{diff_abi, has_breaking_changes},
+    job_queue::{self, Job, JobHandler},
+    type_safety::parser::parse_json_spec,
+};
+
+pub const QUALITY_JOB_KIND: &str = "quality_score";
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(24 * 3600);
+
+const VERIFIED_SOURCE_WEIGHT: i32 = 25;
+const DOCUMENTATION_WEIGHT: i32 = 20;
+const ABI_STABILITY_WEIGHT: i32 = 20;
+const TEST_ATTESTATION_WEIGHT: i32 = 15;
+/// Self-reported CI results earn less than an independent attestation.
+const CI_TESTS_POINTS: i32 = 10;
+const AUDIT_WEIGHT: i32 = 20;
+
+/// One line of the breakdown: what the component is worth, what the
+/// contract earned and why.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct QualityComponent {
+    /// verified_source, documentation, abi_stability, test_attestation or audit
+    pub name: String,
+    pub weight: i32,
+    pub points: i32,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum TestEvidence {
+    /// A `tested` badge covers the current build
+    Attested,
+    /// The latest signed CI run of every suite passed on the current build
+    CiPassing,
+    /// At least one suite's latest CI run on the current build failed
+    CiFailing,
+    None,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum AuditPresence {
+    CurrentBuild,
+    OlderVersion,
+    None,
+}
+
+/// What the score is computed from.
+#[derive(Debug, Clone, PartialEq)]
+struct QualityInputs {
+    is_verified: bool,
+    /// Docs coverage (0-1) of the latest version, if it was measured
+    docs_coverage: Option<f64>,
+    /// `(breaking, total)` upgrades between consecutive versions
+    breaking_upgrades: (usize, usize),
+    tests: TestEvidence,
+    audit: AuditPresence,
+}
+
+fn component(name: &str, weight: i32, points: i32, detail: String) -> QualityComponent {
+    QualityComponent {
+        name: name.to_string(),
+        weight,
+        points,
+        detail,
+    }
+}
+
+/// `weight * part / whole`, rounded to the nearest point.
+fn share(weight: i32, part: usize, whole: usize) -> i32 {
+    ((weight as f64 * part as f64 / whole as f64).round()) as i32
+}
+
+fn score(inputs: &QualityInputs) -> (i32, Vec<QualityComponent>) {
+    let verified = if inputs.is_verified {
+        component(
+            "verified_source",
+            VERIFIED_SOURCE_WEIGHT,
+            VERIFIED_SOURCE_WEIGHT,
+            "Source verified against the deployed WASM".to_string(),
+        )
+    } else {
+        component(
+            "verified_source",
+            VERIFIED_SOURCE_WEIGHT,
+            0,
+            "Source not verified".to_string(),
+        )
+    };
+
+    let documentation = match inputs.docs_coverage {
+        Some(coverage) => component(
+            "documentation",
+            DOCUMENTATION_WEIGHT,
+            (DOCUMENTATION_WEIGHT as f64 * coverage).round() as i32,
+            format!("{:.0}% of functions and types documented", coverage * 100.0),
+        ),
+        None => component(
+            "documentation",
+            DOCUMENTATION_WEIGHT,
+            0,
+            "No documented spec published".to_string(),
+        ),
+    };
+
+    let stability = match inputs.breaking_upgrades {
+        (_, 0) => component(
+            "abi_stability",
+            ABI_STABILITY_WEIGHT,
+            ABI_STABILITY_WEIGHT,
+            "No upgrades yet".to_string(),
+        ),
+        (breaking, total) => component(
+            "abi_stability",
+            ABI_STABILITY_WEIGHT,
+            share(ABI_STABILITY_WEIGHT, total - breaking, total),
+            format!("{} of {} upgrades broke the ABI", breaking, total),
+        ),
+    };
+
+    let tested = match inputs.tests {
+        TestEvidence::Attested => component(
+            "test_attestation",
+            TEST_ATTESTATION_WEIGHT,
+            TEST_ATTESTATION_WEIGHT,
+            "Current build attested as tested".to_string(),
+        ),
+        TestEvidence::CiPassing => component(
+            "test_attestation",
+            TEST_ATTESTATION_WEIGHT,
+            CI_TESTS_POINTS,
+            "Publisher's CI reports the current build passing".to_string(),
+        ),
+        TestEvidence::CiFailing => component(
+            "test_attestation",
+            TEST_ATTESTATION_WEIGHT,
+            0,
+            "Publisher's CI reports failing tests on the current build".to_string(),
+        ),
+        TestEvidence::None => component(
+            "test_attestation",
+            TEST_ATTESTATION_WEIGHT,
+            0,
+            "No test attestation for the current build".to_string(),
+        ),
+    };
+
+    let audit = match inputs.audit {
+        AuditPresence::CurrentBuild => component(
+            "audit",
+            AUDIT_WEIGHT,
+            AUDIT_WEIGHT,
+            "Current version audited".to_string(),
+        ),
+        AuditPresence::OlderVersion => component(
+            "audit",
+            AUDIT_WEIGHT,
+            AUDIT_WEIGHT / 2,
+            "Only an older version was audited".to_string(),
+        ),
+        AuditPresence::None => component("audit", AUDIT_WEIGHT, 0, "No audit".to_string()),
+    };
+
+    let components = vec![verified, documentation, stability, tested, audit];
+    let total = components.iter().map(|c| c.points).sum();
+    (total, components)
+}
+
+/// Whether an active badge of `kind` covers the build with `wasm_hash`.
+async fn badge_on_build(
+    pool: &PgPool,
+    contract_id: Uuid,
+    kind: &str,
+    wasm_hash: &str,
+) -> sqlx::Result<bool> {
+    sqlx::query_scalar(&format!(
+        "SELECT EXISTS(
+            SELECT 1 FROM contract_badges b
+            JOIN attestation_authorities a ON a.id = b.authority_id
+            WHERE b.contract_id = $1 AND b.kind = $2 AND b.wasm_hash = $3 AND {})",
+        ACTIVE_BADGE
+    ))
+    .bind(contract_id)
+    .bind(kind)
+    .bind(wasm_hash)
+    .fetch_one(pool)
+    .await
+}
+
+async fn gather(pool: &PgPool, contract_id: Uuid) -> anyhow::Result<QualityInputs> {
+    let (name, is_verified, wasm_hash): (String, bool, String) =
+        sqlx::query_as("SELECT name, is_verified, wasm_hash FROM contracts WHERE id = $1")
+            .bind(contract_id)
+            .fetch_one(pool)
+            .await
+            .context("fetch contract")?;
+
+    // ABIs in version order; unparseable versions and ABIs are skipped.
+    let rows: Vec<(String, String)> =
+        sqlx::query_as("SELECT version, abi::text FROM contract_abis WHERE contract_id = $1")
+            .bind(contract_id)
+            .fetch_all(pool)
+            .await
+            .context("fetch contract ABIs")?;
+    let mut abis: Vec<_> = rows
+        .into_iter()
+        .filter_map(|(version, abi)| {
+            let parsed = SemVer::parse(&version)?;
+            let spec = parse_json_spec(&abi, &name).ok()?;
+            Some((parsed, spec))
+        })
+        .collect();
+    abis.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let breaking = abis
+        .windows(2)
+        .filter(|pair| has_breaking_changes(&diff_abi(&pair[0].1, &pair[1].1)))
+        .count();
+    let breaking_upgrades = (breaking, abis.len().saturating_sub(1));
+
+    // Coverage is measured when a version is published.
+    let coverages: Vec<(String, Option<f64>)> = sqlx::query_as(
+        "SELECT version, docs_coverage FROM contract_versions WHERE contract_id = $1",
+    )
+    .bind(contract_id)
+    .fetch_all(pool)
+    .await
+    .context("fetch docs coverage")?;
+    let docs_coverage = coverages
+        .into_iter()
+        .filter_map(|(version, coverage)| Some((SemVer::parse(&version)?, coverage)))
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .and_then(|(_, coverage)| coverage);
+
+    let tests = if badge_on_build(pool, contract_id, attestation::TESTED, &wasm_hash)
+        .await
+        .context("check test attestation")?
+    {
+        TestEvidence::Attested
+    } else {
+        // Only each suite's latest run counts: a fixed suite clears an
+        // earlier failure.
+        let (suites, failing): (i64, i64) = sqlx::query_as(
+            "SELECT COUNT(*), COUNT(*) FILTER (WHERE failed > 0)
+             FROM (SELECT DISTINCT ON (suite) failed FROM contract_test_runs
+                   WHERE contract_id = $1 AND wasm_hash = $2
+                   ORDER BY suite, run_at DESC) latest",
+        )
+        .bind(contract_id)
+        .bind(&wasm_hash)
+        .fetch_one(pool)
+        .await
+        .context("check CI test runs")?;
+        match (suites, failing) {
+            (0, _) => TestEvidence::None,
+            (_, 0) => TestEvidence::CiPassing,
+            _ => TestEvidence::CiFailing,
+        }
+    };
+
+    let (audited_current, audited_any): (bool, bool) = sqlx::query_as(
+        "SELECT COALESCE(bool_or(cv.wasm_hash = $2), false), COUNT(*) > 0
+         FROM contract_audits ca
+         JOIN contract_versions cv ON cv.id = ca.contract_version_id
+         WHERE ca.contract_id = $1",
+    )
+    .bind(contract_id)
+    .bind(&wasm_hash)
+    .fetch_one(pool)
+    .await
+    .context("check audit reports")?;
+    let audit = if audited_current
+        || badge_on_build(pool, contract_id, attestation::AUDITED_BY, &wasm_hash)
+            .await
+            .context("check audit badge")?
+    {
+        AuditPresence::CurrentBuild
+    } else if audited_any {
+        AuditPresence::OlderVersion
+    } else {
+        AuditPresence::None
+    };
+
+    Ok(QualityInputs {
+        is_verified,
+        docs_coverage,
+        breaking_upgrades,
+        tests,
+        audit,
+    })
+}
+
+/// Recomputes and stores one contract's score.
+pub async fn recompute(pool: &PgPool, contract_id: Uuid) -> anyhow::Result<i32> {
+    let (total, components) = score(&gather(pool, contract_id).await?);
+
+    let mut tx = pool.begin().await?;
+    sqlx::query("UPDATE contracts SET quality_score = $2 WHERE id = $1")
+        .bind(contract_id)
+        .bind(total)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query(
+        "INSERT INTO contract_quality_scores (contract_id, score, components, computed_at)
+         VALUES ($1, $2, $3, NOW())
+         ON CONFLICT (contract_id) DO UPDATE
+            SET score = EXCLUDED.score,
+                components = EXCLUDED.components,
+                computed_at = EXCLUDED.computed_at",
+    )
+    .bind(contract_id)
+    .bind(total)
+    .bind(serde_json::to_value(&components)?)
+    .execute(&mut *tx)
+    .await?;
+    tx.commit().await?;
+    Ok(total)
+}
+
+/// Queues a recomputation for one contract, e.g. after a new version.
+pub async fn queue(pool: &PgPool, contract_id: Uuid) {
+    let payload = serde_json::json!({ "contract_id": contract_id });
+    if let Err(err) = job_queue::enqueue(pool, QUALITY_JOB_KIND, payload).await {
+        tracing::error!(error = ?err, contract = %contract_id, "quality: failed to queue job");
+    }
+}
+
+pub struct QualityScoreJob;
+
+#[async_trait]
+impl JobHandler for QualityScoreJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        if let Some(id) = job.payload.get("contract_id") {
+            let contract_id: Uuid =
+                serde_json::from_value(id.clone()).context("payload.contract_id")?;
+            let total = recompute(pool, contract_id).await?;
+            tracing::debug!(contract = %contract_id, score = total, "quality score updated");
+            return Ok(());
+        }
+
+        let ids: Vec<Uuid> =
+            sqlx::query_scalar("SELECT id FROM contracts WHERE deleted_at IS NULL")
+                .fetch_all(pool)
+                .await?;
+        let (mut scored, mut failed) = (0, 0);
+        for contract_id in ids {
+            match recompute(pool, contract_id).await {
+                Ok(_) => scored += 1,
+                Err(err) => {
+                    failed += 1;
+                    tracing::warn!(error = ?err, contract = %contract_id, "quality: scoring failed");
+                }
+            }
+        }
+        tracing::info!(scored, failed, "quality scores recomputed");
+        Ok(())
+    }
+}
+
+/// Queue a full recomputation daily, unless one is already pending.
+pub fn spawn_quality_scheduler(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let pending: Result<bool, _> = sqlx::query_scalar(
+                "SELECT EXISTS(SELECT 1 FROM background_jobs \
+                 WHERE kind = $1 AND payload = '{}'::jsonb \
+                 AND status IN ('queued', 'running', 'failed'))",
+            )
+            .bind(QUALITY_JOB_KIND)
+            .fetch_one(&pool)
+            .await;
+            match pending {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(err) =
+                        job_queue::enqueue(&pool, QUALITY_JOB_KIND, serde_json::json!({})).await
+                    {
+                        tracing::error!(error = ?err, "quality: failed to queue scoring job");
+                    }
+                }
+                Err(err) => tracing::error!(error = ?err, "quality: schedule check failed"),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inputs() -> QualityInputs {
+        QualityInputs {
+            is_verified: true,
+            docs_coverage: Some(0.75),
+            breaking_upgrades: (1, 4),
+            tests: TestEvidence::None,
+            audit: AuditPresence::OlderVersion,
+        }
+    }
+
+    #[test]
+    fn sums_weighted_components() {
+        let (total, components) = score(&inputs());
+        let points: Vec<i32> = components.iter().map(|c| c.points).collect();
+        assert_eq!(points, vec![25, 15, 15, 0, 10]);
+        assert_eq!(total, 65);
+        assert_eq!(
+            components[1].detail,
+            "75% of functions and types documented"
+        );
+        assert_eq!(
+            components.iter().map(|c| c.weight).sum::<i32>(),
+            100,
+            "weights must add up to 100"
+        );
+    }
+
+    #[test]
+    fn unmeasured_docs_earn_no_documentation_points() {
+        let bare = QualityInputs {
+            is_verified: false,
+            docs_coverage: None,
+            breaking_upgrades: (0, 0),
+            tests: TestEvidence::None,
+            audit: AuditPresence::None,
+        };
+        let (total, components) = score(&bare);
+        // Only stability is earned: nothing has been upgraded yet.
+        assert_eq!(total, ABI_STABILITY_WEIGHT);
+        assert_eq!(components[1].points, 0);
+    }
+
+    #[test]
+    fn ci_results_earn_less_than_an_attestation() {
+        let points = |tests| score(&QualityInputs { tests, ..inputs() }).1[3].points;
+        assert_eq!(points(TestEvidence::Attested), TEST_ATTESTATION_WEIGHT);
+        assert_eq!(points(TestEvidence::CiPassing), CI_TESTS_POINTS);
+        assert_eq!(points(TestEvidence::CiFailing), 0);
+    }
+}
@@ -0,0 +1,67 @@
+//! Contract quality score breakdown
+//!
+//!   GET /api/contracts/:id/quality – the score and what each component earned
+//!
+//! Scores are computed by the `quality_score` background job (see
+//! [`crate::quality`]); this endpoint only reads the latest result.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    quality::QualityComponent,
+    state::AppState,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct QualityBreakdown {
+    pub contract_id: Uuid,
+    /// 0-100; the sum of the components' points
+    pub score: i32,
+    #[schema(value_type = Vec<QualityComponent>)]
+    pub components: sqlx::types::Json<Vec<QualityComponent>>,
+    pub computed_at: DateTime<Utc>,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/quality",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Quality score with its per-component breakdown", body = QualityBreakdown),
+        (status = 404, description = "Contract not found, or not scored yet")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_quality(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<QualityBreakdown>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let breakdown = sqlx::query_as(
+        "SELECT contract_id, score, components, computed_at
+         FROM contract_quality_scores WHERE contract_id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch quality score", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "QualityScoreNotFound",
+            "This contract has not been scored yet; scores are recomputed daily",
+        )
+    })?;
+    Ok(Json(breakdown))
+}
@@ -0,0 +1,265 @@
+//! Full registry export / import handlers
+//!
+//! Portable snapshots of the registry's metadata for backups and for moving
+//! to a new instance:
+//!
+//!   GET    /api/admin/dump      – export every registry table as one JSON archive
+//!   POST   /api/admin/restore   – import an archive (`?force=true` skips the
+//!                                 schema version check)
+//!
+//! Rows are serialised with `to_jsonb` and restored with
+//! `jsonb_populate_recordset`, so the archive always carries every column of
+//! the exporting schema. Restores run in a single transaction and skip rows
+//! whose primary key already exists, which makes them safe to re-run.
+//!
+//! Dumps are streamed row by row from a single read-only snapshot, so the
+//! archive is consistent across tables and never held in memory whole.
+
+use std::collections::BTreeMap;
+
+use axum::{
+    extract::{Query, State},
+    http::header,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    migration_handlers,
+    state::AppState,
+    streaming::{stream_body, CHUNK_BYTES},
+};
+
+/// Archive layout version; bump when the envelope (not the schema) changes.
+pub const DUMP_FORMAT_VERSION: u32 = 1;
+
+/// Request body cap for restores; archives are far larger than normal payloads.
+pub const RESTORE_BODY_LIMIT: usize = 512 * 1024 * 1024;
+
+/// Tables included in a dump, in foreign-key order (parents first).
+const DUMP_TABLES: &[&str] = &[
+    "tenants",
+    "organizations",
+    "publishers",
+    "contract_categories",
+    "contracts",
+    "contract_versions",
+    "contract_abis",
+    "verifications",
+    "contract_dependencies",
+    "security_patches",
+    "patch_audits",
+    "contract_patches",
+    "multisig_policies",
+    "deploy_proposals",
+    "proposal_signatures",
+    "governance_proposals",
+    "governance_votes",
+];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RegistryDump {
+    pub format_version: u32,
+    /// Latest migration applied on the exporting instance.
+    pub schema_version: Option<i64>,
+    pub exported_at: DateTime<Utc>,
+    /// Table name → array of row objects.
+    pub tables: BTreeMap<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RestoreQuery {
+    /// Restore even if the archive's schema version differs from this instance.
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RestoreSummary {
+    /// Table name → rows inserted (existing rows are skipped).
+    pub inserted: BTreeMap<String, u64>,
+    pub skipped_tables: Vec<String>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+async fn table_exists(state: &AppState, table: &str) -> ApiResult<bool> {
+    sqlx::query_scalar("SELECT to_regclass($1) IS NOT NULL")
+        .bind(table)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_err("check table exists", err))
+}
+
+async fn current_schema_version(state: &AppState) -> ApiResult<Option<i64>> {
+    migration_handlers::embedded_schema_status(&state.db)
+        .await
+        .map(|status| status.applied_version)
+        .map_err(|err| db_err("read schema version", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// Export the registry as a single JSON archive.
+#[utoipa::path(
+    get,
+    path = "/api/admin/dump",
+    responses(
+        (status = 200, description = "Registry archive", body = RegistryDump)
+    ),
+    tag = "Admin"
+)]
+pub async fn dump_registry(State(state): State<AppState>) -> ApiResult<Response> {
+    let schema_version = current_schema_version(&state).await?;
+    let mut tables = Vec::new();
+    for table in DUMP_TABLES {
+        if table_exists(&state, table).await? {
+            tables.push(*table);
+        }
+    }
+
+    // Written by hand rather than through `RegistryDump`, which would need
+    // every row in memory; the layout is the same.
+    let db = state.db.clone();
+    let body = stream_body(move |body| async move {
+        let mut tx = db.begin().await?;
+        sqlx::query("SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ ONLY")
+            .execute(&mut *tx)
+            .await?;
+
+        let mut chunk = format!(
+            "{{\"format_version\":{},\"schema_version\":{},\"exported_at\":{},\"tables\":{{",
+            DUMP_FORMAT_VERSION,
+            serde_json::to_string(&schema_version)?,
+            serde_json::to_string(&Utc::now())?,
+        );
+        for (i, table) in tables.iter().enumerate() {
+            if i > 0 {
+                chunk.push(',');
+            }
+            chunk.push_str(&format!("\"{}\":[", table));
+            let sql = format!("SELECT to_jsonb(t)::TEXT FROM {} t", table);
+            let mut rows = sqlx::query_scalar::<_, String>(&sql).fetch(&mut *tx);
+            let mut first = true;
+            while let Some(row) = rows.try_next().await? {
+                if !first {
+                    chunk.push(',');
+                }
+                first = false;
+                chunk.push_str(&row);
+                if chunk.len() >= CHUNK_BYTES {
+                    body.write(std::mem::take(&mut chunk)).await?;
+                }
+            }
+            chunk.push(']');
+        }
+        chunk.push_str("}}");
+        body.write(chunk).await?;
+        tx.commit().await?;
+        Ok(())
+    });
+
+    Ok(([(header::CONTENT_TYPE, "application/json")], body).into_response())
+}
+
+/// Import an archive produced by `dump_registry`.
+#[utoipa::path(
+    post,
+    path = "/api/admin/restore",
+    params(
+        ("force" = Option<bool>, Query, description = "Ignore schema version mismatch")
+    ),
+    request_body = RegistryDump,
+    responses(
+        (status = 200, description = "Rows restored per table", body = RestoreSummary),
+        (status = 400, description = "Unsupported archive format"),
+        (status = 409, description = "Archive schema version differs from this instance")
+    ),
+    tag = "Admin"
+)]
+pub async fn restore_registry(
+    State(state): State<AppState>,
+    Query(query): Query<RestoreQuery>,
+    Json(dump): Json<RegistryDump>,
+) -> ApiResult<Json<RestoreSummary>> {
+    if dump.format_version != DUMP_FORMAT_VERSION {
+        return Err(ApiError::bad_request(
+            "UnsupportedDumpFormat",
+            format!(
+                "Archive format version {} is not supported (expected {})",
+                dump.format_version, DUMP_FORMAT_VERSION
+            ),
+        ));
+    }
+
+    let local_version = current_schema_version(&state).await?;
+    if dump.schema_version != local_version && !query.force {
+        return Err(ApiError::conflict(
+            "SchemaVersionMismatch",
+            format!(
+                "Archive was exported at schema version {:?} but this instance is at {:?}; \
+                 migrate both to the same version or pass force=true",
+                dump.schema_version, local_version
+            ),
+        ));
+    }
+
+    let mut summary = RestoreSummary {
+        inserted: BTreeMap::new(),
+        skipped_tables: Vec::new(),
+    };
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_err("begin restore", err))?;
+
+    // Walk our own table list (never the archive's keys) so only known tables
+    // are interpolated into SQL, and parents are inserted before children.
+    for table in DUMP_TABLES {
+        let Some(rows) = dump.tables.get(*table) else {
+            continue;
+        };
+        if !table_exists(&state, table).await? {
+            summary.skipped_tables.push(table.to_string());
+            continue;
+        }
+        let result = sqlx::query(&format!(
+            "INSERT INTO {table} SELECT * FROM jsonb_populate_recordset(NULL::{table}, $1) \
+             ON CONFLICT DO NOTHING"
+        ))
+        .bind(rows)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_err("restore table", err))?;
+        summary
+            .inserted
+            .insert(table.to_string(), result.rows_affected());
+    }
+
+    for table in dump.tables.keys() {
+        if !DUMP_TABLES.contains(&table.as_str()) {
+            summary.skipped_tables.push(table.clone());
+        }
+    }
+
+    tx.commit()
+        .await
+        .map_err(|err| db_err("commit restore", err))?;
+    state.cache.invalidate_dependency_graphs();
+
+    tracing::info!(inserted = ?summary.inserted, "registry restore completed");
+    Ok(Json(summary))
+}
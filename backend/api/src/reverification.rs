@@ -0,0 +1,247 @@
+//! Differential re-verification when advisories land
+//!
+//! An advisory can name a build input in `affected_package` – `soroban-sdk`,
+//! the dependency every verification build compiles against, or `rust`, the
+//! compiler of a catalog toolchain – with version ranges in
+//! `affected_versions`. Publishing one queues an `advisory_reverification`
+//! job that checks each verified contract's latest successful build: if it
+//! used an affected version, the contract drops to the `stale` verification
+//! status and its publisher is sent a notice to rebuild. Contracts built with
+//! other versions are untouched; a new successful verification makes a stale
+//! contract `verified` again.
+//!
+//! `affected_versions` is a comma-separated list of alternatives, each a
+//! space-separated set of comparators that must all hold, e.g.
+//! `<21.7.7, >=22.0.0 <22.0.3`. A bare version matches itself and, if
+//! shorter, every version it prefixes (`21.7` matches `21.7.4`).
+
+use anyhow::Context;
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const REVERIFY_JOB_KIND: &str = "advisory_reverification";
+pub const AFFECTED_PACKAGES: &[&str] = &["soroban-sdk", "rust"];
+
+type Version = [u64; 3];
+
+/// Parses `21`, `21.7` or `21.7.7` (optionally prefixed with `v`); returns
+/// the padded version and how many components were given.
+fn parse_version(value: &str) -> Option<(Version, usize)> {
+    let value = value.trim().trim_start_matches('v');
+    let parts: Vec<&str> = value.split('.').collect();
+    if value.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    let mut version = [0; 3];
+    for (slot, part) in version.iter_mut().zip(&parts) {
+        *slot = part.parse().ok()?;
+    }
+    Some((version, parts.len()))
+}
+
+fn comparator_holds(comparator: &str, version: &Version) -> Option<bool> {
+    let (op, rest) = ["<=", ">=", "<", ">", "="]
+        .iter()
+        .find_map(|op| comparator.strip_prefix(op).map(|rest| (*op, rest)))
+        .unwrap_or(("", comparator));
+    let (bound, given) = parse_version(rest)?;
+    Some(match op {
+        "<" => *version < bound,
+        "<=" => *version <= bound,
+        ">" => *version > bound,
+        ">=" => *version >= bound,
+        _ => version[..given] == bound[..given],
+    })
+}
+
+/// Checks that `ranges` parses; returns the offending comparator otherwise.
+pub fn validate_ranges(ranges: &str) -> Result<(), String> {
+    let mut any = false;
+    for comparator in ranges.split(',').flat_map(str::split_whitespace) {
+        any = true;
+        if comparator_holds(comparator, &[0; 3]).is_none() {
+            return Err(comparator.to_string());
+        }
+    }
+    if any {
+        Ok(())
+    } else {
+        Err(ranges.to_string())
+    }
+}
+
+/// Whether `version` falls in `ranges`. Unparseable versions never match.
+pub fn version_affected(version: &str, ranges: &str) -> bool {
+    let Some((version, _)) = parse_version(version.trim_start_matches('=')) else {
+        return false;
+    };
+    ranges.split(',').any(|alternative| {
+        let comparators: Vec<&str> = alternative.split_whitespace().collect();
+        !comparators.is_empty()
+            && comparators
+                .iter()
+                .all(|c| comparator_holds(c, &version) == Some(true))
+    })
+}
+
+/// Queue re-verification for `advisory_id`. Failures are logged rather than
+/// failing the advisory publication.
+pub async fn enqueue(pool: &PgPool, advisory_id: Uuid) {
+    if let Err(err) = job_queue::enqueue(
+        pool,
+        REVERIFY_JOB_KIND,
+        serde_json::json!({ "advisory_id": advisory_id }),
+    )
+    .await
+    {
+        tracing::warn!(
+            advisory = %advisory_id,
+            error = ?err,
+            "failed to queue re-verification job"
+        );
+    }
+}
+
+#[derive(sqlx::FromRow)]
+struct VerifiedBuild {
+    contract_id: Uuid,
+    name: String,
+    publisher_address: String,
+    soroban_sdk_version: Option<String>,
+    rust_version: Option<String>,
+}
+
+pub struct AdvisoryReverificationJob;
+
+#[async_trait]
+impl JobHandler for AdvisoryReverificationJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let advisory_id: Uuid = serde_json::from_value(job.payload["advisory_id"].clone())
+            .context("re-verification job payload is missing advisory_id")?;
+
+        let advisory: Option<(String, Option<String>, Option<String>)> = sqlx::query_as(
+            "SELECT title, affected_package, affected_versions
+             FROM security_advisories WHERE id = $1",
+        )
+        .bind(advisory_id)
+        .fetch_optional(pool)
+        .await?;
+        let Some((title, Some(package), Some(ranges))) = advisory else {
+            return Ok(());
+        };
+
+        // Each verified contract's latest successful source build; manual
+        // status overrides carry no build inputs and are skipped.
+        let builds: Vec<VerifiedBuild> = sqlx::query_as(
+            "SELECT DISTINCT ON (c.id)
+                c.id AS contract_id, c.name, p.stellar_address AS publisher_address,
+                COALESCE(t.soroban_sdk_version, v.compiler_version) AS soroban_sdk_version,
+                t.rust_version
+             FROM contracts c
+             JOIN publishers p ON p.id = c.publisher_id
+             JOIN verifications v ON v.contract_id = c.id
+             LEFT JOIN toolchains t ON t.id = v.toolchain_id
+             WHERE c.is_verified AND c.deleted_at IS NULL
+               AND v.status = 'verified' AND v.source_code IS NOT NULL
+             ORDER BY c.id, v.created_at DESC",
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let message_for = |name: &str, version: &str| {
+            format!(
+                "Advisory \"{}\" affects {} {}, which \"{}\" was verified with. \
+                 Its verification is stale: rebuild with a fixed version and verify again.",
+                title, package, version, name
+            )
+        };
+
+        let mut stale = 0;
+        for build in builds {
+            let version = match package.as_str() {
+                "soroban-sdk" => build.soroban_sdk_version.as_deref(),
+                "rust" => build.rust_version.as_deref(),
+                _ => None,
+            };
+            let Some(version) = version.filter(|v| version_affected(v, &ranges)) else {
+                continue;
+            };
+
+            let message = message_for(&build.name, version);
+            let updated = sqlx::query(
+                "UPDATE contracts
+                 SET verification_status = 'stale', is_verified = false,
+                     verification_notes = $2, updated_at = NOW()
+                 WHERE id = $1 AND is_verified",
+            )
+            .bind(build.contract_id)
+            .bind(&message)
+            .execute(pool)
+            .await?;
+            if updated.rows_affected() == 0 {
+                continue;
+            }
+
+            sqlx::query(
+                "INSERT INTO advisory_notification_log
+                    (advisory_id, contract_id, recipient, message)
+                 VALUES ($1, $2, $3, $4)",
+            )
+            .bind(advisory_id)
+            .bind(build.contract_id)
+            .bind(&build.publisher_address)
+            .bind(&message)
+            .execute(pool)
+            .await?;
+            tracing::info!(
+                advisory = %advisory_id,
+                contract = %build.contract_id,
+                recipient = %build.publisher_address,
+                "verification marked stale; publisher notified"
+            );
+            stale += 1;
+        }
+
+        tracing::info!(
+            advisory = %advisory_id,
+            package = %package,
+            stale,
+            "advisory re-verification finished"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_alternatives_and_conjunctions() {
+        let ranges = "<21.7.7, >=22.0.0 <22.0.3";
+        assert!(version_affected("21.6.0", ranges));
+        assert!(version_affected("22.0.2", ranges));
+        assert!(!version_affected("21.7.7", ranges));
+        assert!(!version_affected("22.0.3", ranges));
+        // Exact requirements recorded for catalog toolchains.
+        assert!(version_affected("=21.0.1", ranges));
+    }
+
+    #[test]
+    fn bare_versions_match_by_prefix() {
+        assert!(version_affected("1.81.0", "1.81"));
+        assert!(!version_affected("1.810.0", "1.81"));
+        assert!(version_affected("21.7.7", "=21.7.7"));
+        assert!(!version_affected("nightly", "1.81"));
+    }
+
+    #[test]
+    fn validates_range_syntax() {
+        assert!(validate_ranges("<21.7.7, >=22.0.0 <22.0.3").is_ok());
+        assert_eq!(validate_ranges(">=x.1"), Err(">=x.1".to_string()));
+        assert!(validate_ranges(" , ").is_err());
+    }
+}
@@ -1,17 +1,18 @@
 #[cfg(feature = "openapi")]
 use crate::openapi;
 use crate::{
-    ab_test_handlers, analytics_handlers, auth, auth_handlers, batch_verify_handlers,
-    breaking_changes, canary_handlers, category_handlers, clone_federation_handlers,
-    compatibility_testing_handlers, contract_events, custom_metrics_handlers,
-    deprecation_handlers, handlers, interoperability_handlers, metrics_handler,
-    migration_handlers, org_handlers, performance_handlers, resource_handlers,
-    security_scan_handlers, similarity_handlers, simulation_handlers, state::AppState,
-    subscription_handlers, websocket,
+    ab_test_handlers, account_recovery_handlers, accounting_report_handlers, address_lookup_handlers, alert_handlers, analytics_handlers, anchor_handlers, anomaly_handlers, artifact_gc_handlers, audit_report_handlers, benchmark_handlers, auth, auth_handlers, badge_handlers, batch_verify_handlers,
+    breaking_changes, bug_bounty_handlers, canary_handlers, category_handlers, change_feed_handlers, claim_handlers, cli_release_handlers, clone_federation_handlers,
+    compatibility_testing_handlers, compliance_handlers, consumer_handlers, contract_events, contract_group_handlers, contract_permission_handlers, contract_summary_handlers, custom_metrics_handlers, defi_handlers,
+    deprecation_handlers, disclosure_handlers, email_verification_handlers, publisher_key_handlers, publisher_profile_handlers, event_ingest_handlers, event_query_handlers, fuzz_campaign_handlers, handlers, index_handlers, interface_handlers, interoperability_handlers, job_queue_handlers, metrics_handler,
+    migration_handlers, nft_handlers, oracle_handlers, org_handlers, org_network_policy_handlers, org_policy_handlers, patch_rollout_handlers, performance_handlers, probe_handlers, promotion_handlers, publisher_stats_handlers, publisher_usage_handlers, quality_handlers, registry_dump_handlers, resource_handlers,
+    sdk_matrix_handlers, security_scan_handlers, similarity_handlers, simulation_handlers, soft_delete_handlers, source_bundle_handlers, state::AppState, status_badge_handlers,
+    subscription_handlers, telemetry_handlers, template_handlers, tenant_handlers, test_run_handlers, tlog_handlers, toolchain_handlers, undo_handlers, validation, verification_metrics_handlers, version_rollout_handlers, version_yank_handlers, wasm_handlers, websocket,
 };
 
 
 use axum::{
+    extract::DefaultBodyLimit,
     middleware,
     routing::{delete, get, patch, post, put},
     Router,
@@ -21,13 +22,19 @@ use utoipa::OpenApi;
 #[cfg(feature = "openapi")]
 use utoipa_swagger_ui::SwaggerUi;
 
+/// The body limit for routes listed as uploads in `payload_size`.
+fn upload_body_limit() -> DefaultBodyLimit {
+    DefaultBodyLimit::max(validation::payload_size::get_max_upload_bytes() as usize)
+}
+
 pub fn observability_routes() -> Router<AppState> {
     Router::new().route("/metrics", get(metrics_handler::metrics_endpoint))
 }
 
 pub fn auth_routes() -> Router<AppState> {
     Router::new()
-        .route("/api/auth/challenge", get(auth_handlers::get_challenge))
+        .route("/api/auth/challenge", get(auth_handlers::web_auth_challenge))
+        .route("/api/auth/token", post(auth_handlers::web_auth_token))
         .route("/api/auth/verify", post(auth_handlers::verify_challenge))
 }
 
@@ -77,6 +84,30 @@ pub fn contract_routes() -> Router<AppState> {
         .route("/api/contracts/batch", post(handlers::get_contracts_batch))
         .route("/contracts/batch", post(handlers::get_contracts_batch))
         .route("/api/contracts/graph", get(handlers::get_contract_graph))
+        .route(
+            "/api/contracts/by-address/:address",
+            get(address_lookup_handlers::get_contract_by_address),
+        )
+        .route(
+            "/api/contracts/by-address/:address/versions",
+            get(address_lookup_handlers::get_versions_by_address),
+        )
+        .route(
+            "/api/contracts/by-address/:address/versions/:version",
+            get(address_lookup_handlers::get_version_by_address),
+        )
+        .route(
+            "/api/contracts/by-address/:address/events",
+            get(address_lookup_handlers::get_events_by_address),
+        )
+        .route(
+            "/api/contracts/by-address/:address/migrations",
+            get(address_lookup_handlers::get_migrations_by_address),
+        )
+        .route(
+            "/api/contracts/by-address/:address/summary",
+            get(contract_summary_handlers::get_contract_summary),
+        )
         .route("/api/contracts/:id", get(handlers::get_contract))
         .route(
             "/api/contracts/:id/metadata",
@@ -90,6 +121,60 @@ pub fn contract_routes() -> Router<AppState> {
             "/api/contracts/:id/status",
             patch(handlers::update_contract_status),
         )
+        .route(
+            "/api/contracts/:id/claim/challenge",
+            post(claim_handlers::create_claim_challenge),
+        )
+        .route(
+            "/api/contracts/:id/claim",
+            post(claim_handlers::claim_contract),
+        )
+        .route(
+            "/api/contracts/:id/anchor",
+            get(anchor_handlers::get_anchor_status),
+        )
+        .route(
+            "/api/contracts/:id/anomalies",
+            get(anomaly_handlers::get_contract_anomalies),
+        )
+        .route(
+            "/api/contracts/:id/events",
+            get(event_query_handlers::get_contract_events),
+        )
+        .route(
+            "/api/contracts/:id/reports/accounting",
+            get(accounting_report_handlers::get_accounting_report),
+        )
+        .route("/api/events", get(event_query_handlers::query_events))
+        .route("/api/events/export", get(event_query_handlers::export_events))
+        .route(
+            "/api/patches/:id/rollout",
+            get(patch_rollout_handlers::get_patch_rollout),
+        )
+        .route(
+            "/api/contracts/:id/group",
+            get(contract_group_handlers::get_contract_group)
+                .post(contract_group_handlers::link_contract)
+                .delete(contract_group_handlers::unlink_contract),
+        )
+        .route(
+            "/api/contracts/:id/permissions",
+            get(contract_permission_handlers::list_permissions)
+                .post(contract_permission_handlers::grant_permissions),
+        )
+        .route(
+            "/api/contracts/:id/permissions/:principal/:capability",
+            delete(contract_permission_handlers::revoke_permission),
+        )
+        .route(
+            "/api/contracts/:id/promotions",
+            get(promotion_handlers::list_contract_promotions)
+                .post(promotion_handlers::promote_contract),
+        )
+        .route(
+            "/api/promotions/:id/complete",
+            post(promotion_handlers::complete_promotion),
+        )
         .route(
             "/api/contracts/:id/audit-log",
             get(handlers::get_contract_audit_log),
@@ -107,16 +192,147 @@ pub fn contract_routes() -> Router<AppState> {
             "/api/contracts/:id/versions",
             get(handlers::get_contract_versions).post(handlers::create_contract_version),
         )
-        // Static segment "compare" must be registered before the dynamic ":version" route
+        // Static segments "compare" and "resolve" must be registered before the dynamic ":version" route
         // so Axum resolves it correctly.
         .route(
             "/api/contracts/:id/versions/compare",
             get(handlers::compare_contract_versions),
         )
+        .route(
+            "/api/contracts/:id/versions/resolve",
+            get(version_rollout_handlers::resolve_version),
+        )
         .route(
             "/api/contracts/:id/versions/:version",
             get(handlers::get_specific_contract_version),
         )
+        .route(
+            "/api/contracts/:id/versions/:version/rollout",
+            get(version_rollout_handlers::get_version_rollout)
+                .put(version_rollout_handlers::set_version_rollout)
+                .delete(version_rollout_handlers::abort_version_rollout),
+        )
+        .route(
+            "/api/contracts/:id/versions/:version/yank",
+            put(version_yank_handlers::yank_version)
+                .delete(version_yank_handlers::unyank_version),
+        )
+        .route(
+            "/api/contracts/:id/consumers",
+            get(consumer_handlers::list_consumers).post(consumer_handlers::register_consumer),
+        )
+        .route(
+            "/api/consumers/:id",
+            patch(consumer_handlers::update_consumer).delete(consumer_handlers::delete_consumer),
+        )
+        .route(
+            "/api/consumers/:id/notifications",
+            get(consumer_handlers::list_consumer_notifications),
+        )
+        .route(
+            "/api/contracts/:id/badges",
+            get(badge_handlers::list_contract_badges).post(badge_handlers::submit_attestation),
+        )
+        .route(
+            "/api/contracts/:id/badges/:badge_id/revoke",
+            post(badge_handlers::revoke_badge),
+        )
+        .route(
+            "/api/attestation-authorities",
+            get(badge_handlers::list_authorities),
+        )
+        .route(
+            "/api/contracts/:id/audits",
+            get(audit_report_handlers::list_audits).post(audit_report_handlers::attach_audit)
+                .layer(DefaultBodyLimit::max(audit_report_handlers::ATTACH_BODY_LIMIT)),
+        )
+        .route(
+            "/api/contracts/:id/audits/:audit_id/report",
+            get(audit_report_handlers::get_audit_report),
+        )
+        .route(
+            "/api/contracts/:id/test-runs",
+            get(test_run_handlers::list_test_runs).post(test_run_handlers::submit_test_run),
+        )
+        .route(
+            "/api/contracts/:id/fuzz-campaigns",
+            get(fuzz_campaign_handlers::list_fuzz_campaigns)
+                .post(fuzz_campaign_handlers::submit_fuzz_campaign),
+        )
+        .route(
+            "/api/contracts/:id/wasm",
+            get(wasm_handlers::get_contract_wasm),
+        )
+        .route(
+            "/api/wasm/:hash/contracts",
+            get(wasm_handlers::get_wasm_contracts),
+        )
+        .route(
+            "/api/contracts/:id/badge.svg",
+            get(status_badge_handlers::get_status_badge),
+        )
+        // Static index for mirrors: config.json, changes.json, {prefix}.json
+        .route("/api/index/:file", get(index_handlers::get_index_file))
+        .route("/api/changes", get(change_feed_handlers::get_changes))
+        // Release transparency log
+        .route("/api/tlog/head", get(tlog_handlers::get_tlog_head))
+        .route("/api/tlog/proof", get(tlog_handlers::get_tlog_proof))
+        .route(
+            "/api/tlog/consistency",
+            get(tlog_handlers::get_tlog_consistency),
+        )
+        .route(
+            "/api/contracts/:id/quality",
+            get(quality_handlers::get_quality),
+        )
+        .route(
+            "/api/contracts/:id/interfaces",
+            get(interface_handlers::list_contract_interfaces),
+        )
+        .route(
+            "/api/contracts/:id/defi",
+            get(defi_handlers::get_defi_pool),
+        )
+        .route(
+            "/api/contracts/:id/compliance",
+            get(compliance_handlers::get_compliance_report),
+        )
+        .route(
+            "/api/contracts/:id/build-meta",
+            get(sdk_matrix_handlers::get_contract_build_meta),
+        )
+        .route(
+            "/api/contracts/:id/nft/tokens",
+            get(nft_handlers::list_nft_tokens),
+        )
+        .route(
+            "/api/contracts/:id/nft/tokens/:token_id",
+            get(nft_handlers::get_nft_token),
+        )
+        .route(
+            "/api/contracts/:id/oracle",
+            get(oracle_handlers::get_oracle_feed)
+                .put(oracle_handlers::set_oracle_feed)
+                .delete(oracle_handlers::delete_oracle_feed),
+        )
+        .route(
+            "/api/contracts/:id/bug-bounty",
+            get(bug_bounty_handlers::get_bug_bounty)
+                .put(bug_bounty_handlers::set_bug_bounty)
+                .delete(bug_bounty_handlers::delete_bug_bounty),
+        )
+        .route(
+            "/api/contracts/:id/security.txt",
+            get(bug_bounty_handlers::get_security_txt),
+        )
+        .route(
+            "/api/contracts/:id/disclosures",
+            get(disclosure_handlers::list_disclosures).post(disclosure_handlers::submit_disclosure),
+        )
+        .route(
+            "/api/contracts/:id/disclosures/:report_id/status",
+            post(disclosure_handlers::update_disclosure_status),
+        )
         .route(
             "/api/contracts/:id/changelog",
             get(handlers::get_contract_changelog),
@@ -138,14 +354,29 @@ pub fn contract_routes() -> Router<AppState> {
             "/api/contracts/patches/bulk-apply",
             post(patch_handlers::bulk_apply_patches),
         )
+        .route(
+            "/api/contracts/:id/versions/:version/benchmarks",
+            get(benchmark_handlers::list_function_benchmarks)
+                .post(benchmark_handlers::record_function_benchmarks),
+        )
+        .route(
+            "/api/contracts/:id/versions/:version/regressions",
+            get(benchmark_handlers::list_cost_regressions),
+        )
         .route(
             "/api/contracts/:id/versions/:version/source",
-            get(handlers::get_contract_source).post(handlers::upload_contract_source),
+            get(handlers::get_contract_source)
+                .post(handlers::upload_contract_source)
+                .layer(upload_body_limit()),
         )
         .route(
             "/api/contracts/:id/versions/:version/source/diff",
             get(handlers::get_contract_source_diff),
         )
+        .route(
+            "/api/contracts/:id/source/:version",
+            get(source_bundle_handlers::get_source_bundle),
+        )
         .route(
             "/contracts/:id/changelog",
             get(handlers::get_contract_changelog),
@@ -223,6 +454,22 @@ pub fn contract_routes() -> Router<AppState> {
             get(similarity_handlers::get_similar_contracts),
         )
         .route("/api/contracts/verify", post(handlers::verify_contract))
+        .route("/api/toolchains", get(toolchain_handlers::list_toolchains))
+        .route(
+            "/api/toolchains/:name",
+            get(toolchain_handlers::get_toolchain),
+        )
+        .route("/api/interfaces", get(interface_handlers::list_interfaces))
+        .route(
+            "/api/interfaces/:id",
+            get(interface_handlers::get_interface),
+        )
+        .route("/api/sdk-matrix", get(sdk_matrix_handlers::get_sdk_matrix))
+        .route(
+            "/api/cli/releases/latest",
+            get(cli_release_handlers::get_latest_release),
+        )
+        .route("/api/telemetry", post(telemetry_handlers::ingest_telemetry))
         .route(
             "/api/contracts/batch-verify",
             post(batch_verify_handlers::batch_verify_contracts),
@@ -299,7 +546,7 @@ pub fn contract_routes() -> Router<AppState> {
         )
         .route(
             "/api/contracts/simulate-deploy",
-            post(simulation_handlers::simulate_deploy),
+            post(simulation_handlers::simulate_deploy).layer(upload_body_limit()),
         )
         // Gas usage estimation (Issue #496)
         // Static segment "gas-estimate/batch" registered before dynamic ":method"
@@ -332,6 +579,28 @@ pub fn contract_routes() -> Router<AppState> {
             "/api/contracts/:id/rating-stats",
             get(handlers::reviews::get_rating_stats),
         )
+        // Contract migration records
+        .route(
+            "/api/migrations",
+            get(handlers::migrations::get_migrations).post(handlers::migrations::create_migration),
+        )
+        .route(
+            "/api/migrations/:id",
+            get(handlers::migrations::get_migration).put(handlers::migrations::update_migration),
+        )
+        .route(
+            "/api/migrations/:id/log",
+            get(handlers::migrations::get_migration_log),
+        )
+        .route(
+            "/api/migrations/:id/logs",
+            get(handlers::migrations::get_migration_logs)
+                .post(handlers::migrations::append_migration_log),
+        )
+        .route(
+            "/api/contracts/:id/migrations",
+            get(handlers::migrations::get_contract_migrations),
+        )
         // Contract clone endpoints (#487)
         .route(
             "/api/contracts/:id/clone",
@@ -362,6 +631,23 @@ pub fn organization_routes() -> Router<AppState> {
             "/api/organizations/:id/invitations",
             post(org_handlers::invite_member),
         )
+        .route(
+            "/api/organizations/:id/policy",
+            get(org_policy_handlers::get_org_policy).put(org_policy_handlers::put_org_policy),
+        )
+        .route(
+            "/api/organizations/:id/policy/check",
+            post(org_policy_handlers::check_org_policy),
+        )
+        .route(
+            "/api/organizations/:id/network-policies",
+            get(org_network_policy_handlers::list_network_policies),
+        )
+        .route(
+            "/api/organizations/:id/network-policies/:network",
+            put(org_network_policy_handlers::set_network_policy)
+                .delete(org_network_policy_handlers::clear_network_policy),
+        )
         .route(
             "/api/organizations/invitations/:token/accept",
             post(org_handlers::accept_invitation),
@@ -386,6 +672,80 @@ pub fn publisher_routes() -> Router<AppState> {
             "/api/publishers/:id/contracts",
             get(handlers::get_publisher_contracts),
         )
+        .route(
+            "/api/publishers/:id/stats",
+            get(publisher_stats_handlers::get_publisher_stats),
+        )
+        .route(
+            "/api/publishers/:id/usage",
+            get(publisher_usage_handlers::get_publisher_usage),
+        )
+        .route(
+            "/api/publishers/:id/keys",
+            get(publisher_key_handlers::list_publisher_keys),
+        )
+        .route(
+            "/api/me/keys",
+            get(publisher_key_handlers::list_my_keys).post(publisher_key_handlers::register_key),
+        )
+        .route(
+            "/api/me/keys/:key/rotate",
+            post(publisher_key_handlers::rotate_key),
+        )
+        .route(
+            "/api/me/keys/:key/revoke",
+            post(publisher_key_handlers::revoke_key),
+        )
+        .route("/api/keys/lookup", get(publisher_key_handlers::lookup_key))
+        .route(
+            "/api/recovery",
+            post(account_recovery_handlers::open_recovery),
+        )
+        .route(
+            "/api/recovery/:id",
+            get(account_recovery_handlers::get_recovery),
+        )
+        .route(
+            "/api/recovery/:id/approve",
+            post(account_recovery_handlers::decide_recovery),
+        )
+        .route(
+            "/api/recovery/:id/cancel",
+            post(account_recovery_handlers::cancel_recovery),
+        )
+        .route(
+            "/api/recovery/:id/execute",
+            post(account_recovery_handlers::execute_recovery),
+        )
+        .route(
+            "/api/me/email",
+            get(email_verification_handlers::get_email_status),
+        )
+        .route(
+            "/api/me/email/challenge",
+            post(email_verification_handlers::request_email_challenge),
+        )
+        .route(
+            "/api/me/email/verify",
+            post(email_verification_handlers::verify_email_code),
+        )
+        .route(
+            "/api/email/verify/:token",
+            get(email_verification_handlers::verify_email_link),
+        )
+        .route(
+            "/api/me/profile",
+            patch(publisher_profile_handlers::update_profile),
+        )
+        .route(
+            "/api/me/pending-actions",
+            get(undo_handlers::list_pending_actions),
+        )
+        .route("/api/me/undo", post(undo_handlers::undo_last_action))
+}
+
+pub fn tenant_routes() -> Router<AppState> {
+    Router::new().route("/api/tenant", get(tenant_handlers::get_current_tenant))
 }
 
 pub fn contributor_routes() -> Router<AppState> {
@@ -418,12 +778,28 @@ pub fn favorite_routes() -> Router<AppState> {
         )
 }
 
+pub fn alert_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/alerts",
+            get(alert_handlers::list_alert_rules).post(alert_handlers::create_alert_rule),
+        )
+        .route("/api/alerts/:id", delete(alert_handlers::delete_alert_rule))
+        .route(
+            "/api/alerts/:id/triggers",
+            get(alert_handlers::list_alert_triggers),
+        )
+}
+
 pub fn health_routes() -> Router<AppState> {
     Router::new()
         .route("/health", get(handlers::health_check))
         .route("/health/live", get(handlers::health_check_live))
         .route("/health/ready", get(handlers::health_check_ready))
         .route("/health/detailed", get(handlers::health_check_detailed))
+        // Kubernetes probes: dependency-free liveness, dependency-aware readiness
+        .route("/livez", get(probe_handlers::livez))
+        .route("/readyz", get(probe_handlers::readyz))
         .route("/api/stats", get(handlers::get_stats))
         // Registry-wide analytics summary (issue #415)
         .route(
@@ -438,6 +814,16 @@ pub fn category_routes() -> Router<AppState> {
         .route("/api/categories/:id", get(category_handlers::get_category))
 }
 
+pub fn template_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/templates", get(template_handlers::list_templates))
+        .route("/api/templates/:slug", get(template_handlers::get_template))
+        .route(
+            "/api/templates/:slug/install",
+            post(template_handlers::record_template_install),
+        )
+}
+
 pub fn network_routes() -> Router<AppState> {
     Router::new()
         .route("/networks", get(handlers::list_networks))
@@ -613,6 +999,119 @@ pub fn admin_routes() -> Router<AppState> {
             "/api/admin/contracts/:id/versions/:version/revert",
             post(handlers::revert_contract_version),
         )
+        // Background job queue – inspect queued/failed/dead-lettered jobs
+        .route("/api/admin/jobs", get(job_queue_handlers::list_jobs))
+        .route(
+            "/api/admin/jobs/:id/retry",
+            post(job_queue_handlers::retry_job),
+        )
+        // Verification pipeline throughput, build times and failure reasons
+        .route(
+            "/api/admin/verification-metrics",
+            get(verification_metrics_handlers::get_verification_metrics),
+        )
+        // Pinned toolchain catalog for reproducible verification builds
+        .route(
+            "/api/admin/toolchains",
+            post(toolchain_handlers::create_toolchain),
+        )
+        .route(
+            "/api/admin/toolchains/:name/deprecate",
+            post(toolchain_handlers::deprecate_toolchain),
+        )
+        // Known contract interfaces that published ABIs are matched against
+        .route(
+            "/api/admin/interfaces",
+            post(interface_handlers::create_interface),
+        )
+        .route(
+            "/api/admin/interfaces/:id",
+            put(interface_handlers::update_interface).delete(interface_handlers::delete_interface),
+        )
+        // Signed CLI releases offered to `soroban-registry self-update`
+        .route(
+            "/api/admin/cli/releases",
+            post(cli_release_handlers::publish_release),
+        )
+        .route(
+            "/api/admin/cli/releases/:version/yank",
+            post(cli_release_handlers::yank_release),
+        )
+        // Publishers recognised to grant contract badges
+        .route(
+            "/api/admin/attestation-authorities",
+            post(badge_handlers::create_authority),
+        )
+        .route(
+            "/api/admin/attestation-authorities/:id",
+            delete(badge_handlers::revoke_authority),
+        )
+        // Opt-in CLI usage telemetry, aggregated per command
+        .route(
+            "/api/admin/telemetry/commands",
+            get(telemetry_handlers::get_command_usage),
+        )
+        // Full registry export / import for backups and instance migration
+        .route("/api/admin/dump", get(registry_dump_handlers::dump_registry))
+        .route(
+            "/api/admin/restore",
+            post(registry_dump_handlers::restore_registry)
+                .layer(DefaultBodyLimit::max(
+                    registry_dump_handlers::RESTORE_BODY_LIMIT,
+                )),
+        )
+        // Multi-tenancy: tenant provisioning, hostname mapping and quotas
+        .route(
+            "/api/admin/tenants",
+            get(tenant_handlers::list_tenants).post(tenant_handlers::create_tenant),
+        )
+        .route(
+            "/api/admin/tenants/:id",
+            patch(tenant_handlers::update_tenant),
+        )
+        // Soft-delete: deleted records stay restorable until purged
+        .route(
+            "/api/admin/contracts/:id",
+            delete(soft_delete_handlers::delete_contract),
+        )
+        .route(
+            "/api/admin/contracts/:id/restore",
+            post(soft_delete_handlers::restore_contract),
+        )
+        .route(
+            "/api/admin/publishers/:id",
+            delete(soft_delete_handlers::delete_publisher),
+        )
+        .route(
+            "/api/admin/publishers/:id/restore",
+            post(soft_delete_handlers::restore_publisher),
+        )
+        .route("/api/admin/trash", get(soft_delete_handlers::list_trash))
+        .route(
+            "/api/admin/artifacts/orphaned",
+            get(artifact_gc_handlers::list_orphaned_artifacts),
+        )
+        // On-chain anchoring of contract records
+        .route(
+            "/api/admin/contracts/:id/anchor",
+            post(anchor_handlers::anchor_contract_now),
+        )
+        .route(
+            "/api/events/batch",
+            post(event_ingest_handlers::ingest_events_batch),
+        )
+        .route(
+            "/api/patches/:id/rollout",
+            post(patch_rollout_handlers::create_patch_rollout),
+        )
+        .route(
+            "/api/patches/:id/rollout/pause",
+            post(patch_rollout_handlers::pause_patch_rollout),
+        )
+        .route(
+            "/api/patches/:id/rollout/resume",
+            post(patch_rollout_handlers::resume_patch_rollout),
+        )
         .route_layer(middleware::from_fn(auth::require_admin))
 }
 
@@ -0,0 +1,256 @@
+//! SDK compatibility matrix
+//!
+//!   GET /api/sdk-matrix                – contracts per soroban-sdk and protocol version
+//!   GET /api/contracts/:id/build-meta  – how each version of a contract was built
+//!
+//! Build metadata is read from each version's WASM by [`crate::build_meta`].
+//! The matrix counts each contract's current version unless `all_versions`
+//! is set, so it answers "which SDKs are contracts on this registry built
+//! with today". Search narrows the same way with `sdk_version` and
+//! `protocol_version` (see [`push_build_filter`]).
+
+use std::cmp::Reverse;
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use sqlx::{Postgres, QueryBuilder};
+
+use crate::{
+    error::ApiResult,
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct SdkMatrixQuery {
+    /// Count every published version, not only each contract's current one
+    #[serde(default)]
+    pub all_versions: bool,
+    /// Only contracts on this network
+    pub network: Option<Network>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct SdkMatrixCell {
+    /// soroban-sdk version; absent for builds that did not record one
+    pub sdk_version: Option<String>,
+    pub protocol_version: Option<i32>,
+    pub contracts: i64,
+    pub versions: i64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct SdkMatrix {
+    pub cells: Vec<SdkMatrixCell>,
+    /// SDK versions present, newest first
+    pub sdk_versions: Vec<String>,
+    /// Protocol versions present, newest first
+    pub protocol_versions: Vec<i32>,
+    /// Versions whose build metadata has not been read yet
+    pub unread_versions: i64,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct VersionBuildMeta {
+    pub version: String,
+    pub wasm_hash: String,
+    pub sdk_version: Option<String>,
+    pub rustc_version: Option<String>,
+    pub protocol_version: Option<i32>,
+    /// When the WASM was read; absent until then
+    pub read_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Numeric components of a version for ordering; pre-release suffixes are
+/// ignored (`22.0.0-rc.3` sorts with `22.0.0`).
+fn version_key(version: &str) -> Vec<u64> {
+    version
+        .split('-')
+        .next()
+        .unwrap_or_default()
+        .split('.')
+        .map(|part| part.parse().unwrap_or(0))
+        .collect()
+}
+
+/// The `sdk_version` filter as typed (`22`, `22.0`, `v22.0.7`), or `None`
+/// when it is not a version.
+fn sdk_filter(raw: &str) -> Option<String> {
+    let version = raw.trim().trim_start_matches('v');
+    let valid = !version.is_empty()
+        && version
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-');
+    valid.then(|| version.to_string())
+}
+
+/// Restricts a contract query (aliased `c`) to contracts whose current
+/// version was built with `sdk_version` (a whole version or a prefix of one,
+/// `22` matching `22.0.7`) and/or targets `protocol_version`.
+pub(crate) fn push_build_filter(
+    qb: &mut QueryBuilder<'_, Postgres>,
+    sdk_version: Option<&str>,
+    protocol_version: Option<i32>,
+) {
+    if sdk_version.is_none() && protocol_version.is_none() {
+        return;
+    }
+    qb.push(
+        " AND EXISTS (SELECT 1 FROM contract_versions bv \
+         WHERE bv.contract_id = c.id AND bv.version = c.current_version",
+    );
+    if let Some(raw) = sdk_version {
+        match sdk_filter(raw) {
+            Some(version) => {
+                qb.push(" AND (bv.sdk_version = ")
+                    .push_bind(version.clone())
+                    .push(" OR bv.sdk_version LIKE ")
+                    .push_bind(format!("{}.%", version))
+                    .push(")");
+            }
+            None => {
+                qb.push(" AND FALSE");
+            }
+        }
+    }
+    if let Some(protocol) = protocol_version {
+        qb.push(" AND bv.protocol_version = ").push_bind(protocol);
+    }
+    qb.push(")");
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/sdk-matrix",
+    params(SdkMatrixQuery),
+    responses(
+        (status = 200, description = "Contracts per SDK and protocol version", body = SdkMatrix)
+    ),
+    tag = "Versions"
+)]
+pub async fn get_sdk_matrix(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Query(query): Query<SdkMatrixQuery>,
+) -> ApiResult<Json<SdkMatrix>> {
+    const SCOPE: &str = "FROM contract_versions cv
+         JOIN contracts c ON c.id = cv.contract_id
+         WHERE c.tenant_id = $1 AND c.deleted_at IS NULL AND c.visibility = 'public'
+           AND ($2 OR cv.version = c.current_version)
+           AND ($3::network_type IS NULL OR c.network = $3)";
+
+    let mut cells: Vec<SdkMatrixCell> = sqlx::query_as(&format!(
+        "SELECT cv.sdk_version, cv.protocol_version,
+                COUNT(DISTINCT cv.contract_id) AS contracts, COUNT(*) AS versions
+         {} AND cv.build_meta_read_at IS NOT NULL
+         GROUP BY cv.sdk_version, cv.protocol_version",
+        SCOPE
+    ))
+    .bind(tenant.id)
+    .bind(query.all_versions)
+    .bind(&query.network)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("build sdk matrix", err))?;
+
+    let unread_versions: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) {} AND cv.build_meta_read_at IS NULL",
+        SCOPE
+    ))
+    .bind(tenant.id)
+    .bind(query.all_versions)
+    .bind(&query.network)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("count unread versions", err))?;
+
+    cells.sort_by_key(|cell| {
+        (
+            Reverse(cell.sdk_version.as_deref().map(version_key)),
+            Reverse(cell.protocol_version),
+        )
+    });
+    let mut sdk_versions: Vec<String> = Vec::new();
+    for cell in &cells {
+        if let Some(version) = &cell.sdk_version {
+            if !sdk_versions.contains(version) {
+                sdk_versions.push(version.clone());
+            }
+        }
+    }
+    let mut protocol_versions: Vec<i32> = cells
+        .iter()
+        .filter_map(|cell| cell.protocol_version)
+        .collect();
+    protocol_versions.sort_unstable_by(|a, b| b.cmp(a));
+    protocol_versions.dedup();
+
+    Ok(Json(SdkMatrix {
+        cells,
+        sdk_versions,
+        protocol_versions,
+        unread_versions,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/build-meta",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    responses(
+        (status = 200, description = "Build metadata per version, newest first", body = [VersionBuildMeta]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_contract_build_meta(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Vec<VersionBuildMeta>>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let versions = sqlx::query_as(
+        "SELECT version, wasm_hash, sdk_version, rustc_version, protocol_version,
+                build_meta_read_at AS read_at, created_at
+         FROM contract_versions
+         WHERE contract_id = $1
+         ORDER BY created_at DESC",
+    )
+    .bind(contract_uuid)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list build metadata", err))?;
+    Ok(Json(versions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn versions_order_numerically() {
+        let mut versions = vec!["9.0.1", "22.0.0-rc.3", "21.7.7", "22.0.7"];
+        versions.sort_by_key(|v| Reverse(version_key(v)));
+        assert_eq!(versions, ["22.0.7", "22.0.0-rc.3", "21.7.7", "9.0.1"]);
+    }
+
+    #[test]
+    fn sdk_filters_are_plain_versions() {
+        assert_eq!(sdk_filter(" v22.0 ").as_deref(), Some("22.0"));
+        assert_eq!(sdk_filter("22.0.0-rc.3").as_deref(), Some("22.0.0-rc.3"));
+        assert_eq!(sdk_filter("22%"), None);
+        assert_eq!(sdk_filter(""), None);
+    }
+}
@@ -60,6 +60,18 @@ pub async fn sign_package(
     }
 
     let contract_uuid = parse_contract_uuid(&state, &req.contract_id).await?;
+    let publisher_id: Uuid = sqlx::query_scalar("SELECT publisher_id FROM contracts WHERE id = $1")
+        .bind(contract_uuid)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch contract publisher", err))?;
+    crate::publisher_key_handlers::require_usable_key(
+        &state.db,
+        publisher_id,
+        &req.public_key,
+        Utc::now(),
+    )
+    .await?;
 
     let algorithm = req
         .algorithm
@@ -564,7 +576,7 @@ pub async fn verify_transparency_log(
     }))
 }
 
-async fn append_transparency_log_entry(
+pub(crate) async fn append_transparency_log_entry(
     state: &AppState,
     entry_type: TransparencyEntryType,
     contract_id: Option<Uuid>,
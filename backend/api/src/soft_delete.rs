@@ -0,0 +1,117 @@
+//! Retention and purging of soft-deleted contracts and publishers
+//!
+//! Deleted rows stay restorable for `SOFT_DELETE_RETENTION_DAYS` (default 30).
+//! [`spawn_purge_scheduler`] queues a `purge_soft_deleted` job on the
+//! background job queue every hour; the job hard-deletes rows past the
+//! window. Rows still referenced by tables without `ON DELETE CASCADE` are
+//! kept (and logged) rather than failing the whole run.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::job_queue::{self, Job, JobHandler};
+
+pub const PURGE_JOB_KIND: &str = "purge_soft_deleted";
+const DEFAULT_RETENTION_DAYS: i64 = 30;
+const SCHEDULE_INTERVAL: Duration = Duration::from_secs(3600);
+const PURGE_BATCH: i64 = 500;
+
+pub fn retention_days() -> i64 {
+    std::env::var("SOFT_DELETE_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|d| *d >= 0)
+        .unwrap_or(DEFAULT_RETENTION_DAYS)
+}
+
+/// When a row deleted at `deleted_at` becomes eligible for purging.
+pub fn purge_after(deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+    deleted_at + chrono::Duration::days(retention_days())
+}
+
+fn is_foreign_key_violation(err: &sqlx::Error) -> bool {
+    matches!(err, sqlx::Error::Database(e) if e.code().as_deref() == Some("23503"))
+}
+
+/// Hard-delete expired rows of `table`, one at a time so a referenced row
+/// does not block the rest. Returns `(purged, retained)`.
+async fn purge_table(pool: &PgPool, table: &str) -> Result<(u64, u64), sqlx::Error> {
+    let ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+        "SELECT id FROM {} \
+         WHERE deleted_at IS NOT NULL AND deleted_at < NOW() - make_interval(days => $1::int) \
+         ORDER BY deleted_at LIMIT $2",
+        table
+    ))
+    .bind(retention_days() as i32)
+    .bind(PURGE_BATCH)
+    .fetch_all(pool)
+    .await?;
+
+    let (mut purged, mut retained) = (0, 0);
+    for id in ids {
+        match sqlx::query(&format!("DELETE FROM {} WHERE id = $1", table))
+            .bind(id)
+            .execute(pool)
+            .await
+        {
+            Ok(_) => purged += 1,
+            Err(err) if is_foreign_key_violation(&err) => {
+                tracing::warn!(table, %id, "soft delete purge: row still referenced, keeping");
+                retained += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+    Ok((purged, retained))
+}
+
+pub struct PurgeSoftDeletedJob;
+
+#[async_trait]
+impl JobHandler for PurgeSoftDeletedJob {
+    async fn handle(&self, pool: &PgPool, _job: &Job) -> anyhow::Result<()> {
+        // Contracts first: a publisher can only go once its contracts have.
+        for table in ["contracts", "publishers"] {
+            let (purged, retained) = purge_table(pool, table).await?;
+            if purged > 0 || retained > 0 {
+                tracing::info!(table, purged, retained, "soft delete purge finished");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Queue the purge job hourly unless one is already pending. Every replica
+/// runs this; the pending check keeps the queue from filling with duplicates.
+pub fn spawn_purge_scheduler(pool: PgPool) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SCHEDULE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let pending: Result<bool, _> = sqlx::query_scalar(
+                "SELECT EXISTS(SELECT 1 FROM background_jobs \
+                 WHERE kind = $1 AND status IN ('queued', 'running', 'failed'))",
+            )
+            .bind(PURGE_JOB_KIND)
+            .fetch_one(&pool)
+            .await;
+            match pending {
+                Ok(true) => {}
+                Ok(false) => {
+                    if let Err(err) =
+                        job_queue::enqueue(&pool, PURGE_JOB_KIND, serde_json::json!({})).await
+                    {
+                        tracing::error!(error = ?err, "soft delete: failed to queue purge job");
+                    }
+                }
+                Err(err) => {
+                    tracing::error!(error = ?err, "soft delete: purge schedule check failed")
+                }
+            }
+        }
+    });
+}
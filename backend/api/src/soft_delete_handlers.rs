@@ -0,0 +1,318 @@
+//! Admin delete, restore and trash endpoints for contracts and publishers
+//!
+//!   DELETE /api/admin/contracts/:id            – soft-delete a contract
+//!   POST   /api/admin/contracts/:id/restore    – undo a contract deletion
+//!   DELETE /api/admin/publishers/:id           – soft-delete a publisher and its contracts
+//!   POST   /api/admin/publishers/:id/restore   – undo a publisher deletion
+//!   GET    /api/admin/trash                    – everything awaiting purge
+//!
+//! Deleted rows are hidden from every read endpoint and purged for good by
+//! the `purge_soft_deleted` job once the retention window has passed (see
+//! [`crate::soft_delete`]). Deleting a contract only takes effect after the
+//! grace period in [`crate::undo`], during which it can be undone.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    soft_delete,
+    state::AppState,
+    undo::{self, DeferredAction, PendingAction},
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct DeletionResponse {
+    pub id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+    /// When the purge job may remove the record permanently.
+    pub purge_after: DateTime<Utc>,
+    /// Contracts deleted along with a publisher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts_deleted: Option<u64>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RestoreResponse {
+    pub id: Uuid,
+    /// Contracts restored along with a publisher.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contracts_restored: Option<u64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct TrashedContract {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub name: String,
+    pub publisher_id: Uuid,
+    pub deleted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct TrashedPublisher {
+    pub id: Uuid,
+    pub stellar_address: String,
+    pub username: Option<String>,
+    pub deleted_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TrashResponse {
+    pub retention_days: i64,
+    pub contracts: Vec<TrashedContract>,
+    pub publishers: Vec<TrashedPublisher>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+fn parse_id(id: &str) -> ApiResult<Uuid> {
+    Uuid::parse_str(id)
+        .map_err(|_| ApiError::bad_request("InvalidId", format!("Invalid UUID: {}", id)))
+}
+
+/// Soft-deletes an active contract, returning `None` if it was already
+/// deleted or does not exist. Run by [`crate::undo::DeferredActionJob`].
+pub(crate) async fn soft_delete_contract(
+    db: &PgPool,
+    id: Uuid,
+) -> Result<Option<DateTime<Utc>>, sqlx::Error> {
+    // Bumping lock_version invalidates ETags held by editors of the contract.
+    let deleted_at = sqlx::query_scalar(
+        "UPDATE contracts SET deleted_at = NOW(), lock_version = lock_version + 1 \
+         WHERE id = $1 AND deleted_at IS NULL RETURNING deleted_at",
+    )
+    .bind(id)
+    .fetch_optional(db)
+    .await?;
+    if deleted_at.is_some() {
+        tracing::info!(contract = %id, "contract soft-deleted");
+    }
+    Ok(deleted_at)
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+pub async fn delete_contract(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(id): Path<String>,
+) -> ApiResult<(StatusCode, Json<PendingAction>)> {
+    let id = parse_id(&id)?;
+    let active: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1 AND deleted_at IS NULL)",
+    )
+    .bind(id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| db_err("load contract", e))?;
+    if !active {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            "No active contract with this id",
+        ));
+    }
+
+    let pending = undo::defer(
+        &state.db,
+        &claims.sub,
+        DeferredAction::Delete { contract_id: id },
+    )
+    .await
+    .map_err(|e| db_err("queue contract delete", e))?;
+    tracing::info!(contract = %id, apply_at = %pending.apply_at, "contract delete queued");
+    Ok((StatusCode::ACCEPTED, Json(pending)))
+}
+
+pub async fn restore_contract(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<RestoreResponse>> {
+    let id = parse_id(&id)?;
+    let publisher_deleted: Option<bool> = sqlx::query_scalar(
+        "SELECT p.deleted_at IS NOT NULL FROM contracts c \
+         JOIN publishers p ON p.id = c.publisher_id \
+         WHERE c.id = $1 AND c.deleted_at IS NOT NULL",
+    )
+    .bind(id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|e| db_err("load deleted contract", e))?;
+
+    match publisher_deleted {
+        None => {
+            return Err(ApiError::not_found(
+                "ContractNotFound",
+                "No deleted contract with this id",
+            ))
+        }
+        Some(true) => {
+            return Err(ApiError::conflict(
+                "PublisherDeleted",
+                "The contract's publisher is deleted; restore the publisher first",
+            ))
+        }
+        Some(false) => {}
+    }
+
+    let restored = sqlx::query(
+        "UPDATE contracts SET deleted_at = NULL, lock_version = lock_version + 1 \
+         WHERE id = $1 AND deleted_at IS NOT NULL",
+    )
+    .bind(id)
+    .execute(&state.db)
+    .await
+    .map_err(|e| db_err("restore contract", e))?;
+    if restored.rows_affected() == 0 {
+        return Err(ApiError::not_found(
+            "ContractNotFound",
+            "No deleted contract with this id",
+        ));
+    }
+
+    // A publisher's deletion still in its grace period would delete it again.
+    let cancelled = undo::cancel_queued(&state.db, &DeferredAction::Delete { contract_id: id })
+        .await
+        .map_err(|e| db_err("cancel queued contract delete", e))?;
+
+    tracing::info!(contract = %id, cancelled_deletes = cancelled, "contract restored");
+    Ok(Json(RestoreResponse {
+        id,
+        contracts_restored: None,
+    }))
+}
+
+pub async fn delete_publisher(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<DeletionResponse>> {
+    let id = parse_id(&id)?;
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| db_err("begin publisher delete", e))?;
+
+    let deleted_at: DateTime<Utc> = sqlx::query_scalar(
+        "UPDATE publishers SET deleted_at = NOW() \
+         WHERE id = $1 AND deleted_at IS NULL RETURNING deleted_at",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| db_err("soft delete publisher", e))?
+    .ok_or_else(|| ApiError::not_found("PublisherNotFound", "No active publisher with this id"))?;
+
+    // Same timestamp as the publisher, so a restore brings back exactly these
+    // contracts and not ones that were deleted on their own earlier.
+    let contracts = sqlx::query(
+        "UPDATE contracts SET deleted_at = $2, lock_version = lock_version + 1 \
+         WHERE publisher_id = $1 AND deleted_at IS NULL",
+    )
+    .bind(id)
+    .bind(deleted_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| db_err("soft delete publisher contracts", e))?
+    .rows_affected();
+
+    tx.commit()
+        .await
+        .map_err(|e| db_err("commit publisher delete", e))?;
+
+    tracing::info!(publisher = %id, contracts, "publisher soft-deleted");
+    Ok(Json(DeletionResponse {
+        id,
+        deleted_at,
+        purge_after: soft_delete::purge_after(deleted_at),
+        contracts_deleted: Some(contracts),
+    }))
+}
+
+pub async fn restore_publisher(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<RestoreResponse>> {
+    let id = parse_id(&id)?;
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| db_err("begin publisher restore", e))?;
+
+    let deleted_at: DateTime<Utc> = sqlx::query_scalar(
+        "SELECT deleted_at FROM publishers \
+         WHERE id = $1 AND deleted_at IS NOT NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|e| db_err("load deleted publisher", e))?
+    .ok_or_else(|| ApiError::not_found("PublisherNotFound", "No deleted publisher with this id"))?;
+
+    sqlx::query("UPDATE publishers SET deleted_at = NULL WHERE id = $1")
+        .bind(id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| db_err("restore publisher", e))?;
+
+    let contracts = sqlx::query(
+        "UPDATE contracts SET deleted_at = NULL, lock_version = lock_version + 1 \
+         WHERE publisher_id = $1 AND deleted_at = $2",
+    )
+    .bind(id)
+    .bind(deleted_at)
+    .execute(&mut *tx)
+    .await
+    .map_err(|e| db_err("restore publisher contracts", e))?
+    .rows_affected();
+
+    tx.commit()
+        .await
+        .map_err(|e| db_err("commit publisher restore", e))?;
+
+    tracing::info!(publisher = %id, contracts, "publisher restored");
+    Ok(Json(RestoreResponse {
+        id,
+        contracts_restored: Some(contracts),
+    }))
+}
+
+pub async fn list_trash(State(state): State<AppState>) -> ApiResult<Json<TrashResponse>> {
+    let contracts: Vec<TrashedContract> = sqlx::query_as(
+        "SELECT id, contract_id, name, publisher_id, deleted_at FROM contracts \
+         WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| db_err("list deleted contracts", e))?;
+
+    let publishers: Vec<TrashedPublisher> = sqlx::query_as(
+        "SELECT id, stellar_address, username, deleted_at FROM publishers \
+         WHERE deleted_at IS NOT NULL ORDER BY deleted_at DESC",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|e| db_err("list deleted publishers", e))?;
+
+    Ok(Json(TrashResponse {
+        retention_days: soft_delete::retention_days(),
+        contracts,
+        publishers,
+    }))
+}
@@ -0,0 +1,263 @@
+//! Verified source mirroring
+//!
+//!   GET /api/contracts/:id/source/:version   – verified source tree (tar.zst)
+//!
+//! When a verification succeeds, [`archive_verified_source`] packs the exact
+//! project the build compiled (see [`verifier::bundle`]) and stores it in
+//! source storage as the `bundle` source of the contract version whose WASM
+//! was verified. The archive is served from the registry, so verified source
+//! remains available even if the upstream git repository disappears.
+//! Archiving is best-effort and never fails the verification itself.
+//!
+//! Bundles are streamed from storage rather than loaded whole. The hash is
+//! checked as the bytes go out, and the final chunk is held back until it
+//! matches, so a corrupted archive never reaches the client complete.
+
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+};
+use futures_util::TryStreamExt;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use shared::{source_storage::SourceFormat, Contract, ContractSource};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    streaming::stream_body,
+};
+
+const BUNDLE_CONTENT_TYPE: &str = "application/zstd";
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+/// Archives the source of a successful verification. Failures are logged
+/// rather than surfaced.
+pub(crate) async fn archive_verified_source(
+    state: &AppState,
+    contract: &Contract,
+    verification_id: Uuid,
+    source_code: &str,
+    compiler_version: Option<&str>,
+    build_params: Option<&Value>,
+    toolchain: Option<&verifier::Toolchain>,
+) {
+    let archived = archive(
+        state,
+        contract,
+        verification_id,
+        source_code,
+        compiler_version,
+        build_params,
+        toolchain,
+    )
+    .await;
+    if let Err(err) = archived {
+        tracing::warn!(
+            contract = %contract.id,
+            verification = %verification_id,
+            error = %err,
+            "failed to archive verified source"
+        );
+    }
+}
+
+async fn archive(
+    state: &AppState,
+    contract: &Contract,
+    verification_id: Uuid,
+    source_code: &str,
+    compiler_version: Option<&str>,
+    build_params: Option<&Value>,
+    toolchain: Option<&verifier::Toolchain>,
+) -> anyhow::Result<()> {
+    let Some(bundle) =
+        verifier::bundle::source_bundle(source_code, compiler_version, build_params, toolchain)?
+    else {
+        return Ok(());
+    };
+
+    // The bundle belongs to the version carrying the WASM that was verified.
+    let version: Option<(Uuid, String)> = sqlx::query_as(
+        "SELECT id, version FROM contract_versions
+         WHERE contract_id = $1 AND wasm_hash = $2
+         ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(contract.id)
+    .bind(&contract.wasm_hash)
+    .fetch_optional(&state.db)
+    .await?;
+    let Some((version_id, version)) = version else {
+        tracing::info!(
+            contract = %contract.id,
+            "no version matches the verified WASM; source not archived"
+        );
+        return Ok(());
+    };
+
+    let bundle_hash = shared::source_storage::compute_sha256(&bundle);
+    let existing: Option<String> = sqlx::query_scalar(
+        "SELECT source_hash FROM contract_sources
+         WHERE contract_version_id = $1 AND source_format = 'bundle'",
+    )
+    .bind(version_id)
+    .fetch_optional(&state.db)
+    .await?;
+    if existing.as_deref() == Some(bundle_hash.as_str()) {
+        return Ok(());
+    }
+
+    let (backend, storage_key, source_hash) = state
+        .source_storage
+        .store_source(
+            &contract.contract_id,
+            &version,
+            SourceFormat::Bundle,
+            &bundle,
+        )
+        .await?;
+
+    let source_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_sources
+            (contract_version_id, source_format, storage_backend, storage_key,
+             source_hash, source_size, verification_id)
+         VALUES ($1, 'bundle', $2, $3, $4, $5, $6)
+         ON CONFLICT (contract_version_id, source_format) DO UPDATE
+         SET storage_backend = EXCLUDED.storage_backend,
+             storage_key = EXCLUDED.storage_key,
+             source_hash = EXCLUDED.source_hash,
+             source_size = EXCLUDED.source_size,
+             verification_id = EXCLUDED.verification_id,
+             created_at = NOW()
+         RETURNING id",
+    )
+    .bind(version_id)
+    .bind(&backend)
+    .bind(&storage_key)
+    .bind(&source_hash)
+    .bind(bundle.len() as i64)
+    .bind(verification_id)
+    .fetch_one(&state.db)
+    .await?;
+
+    sqlx::query(
+        "INSERT INTO source_access_logs (contract_source_id, action, details)
+         VALUES ($1, 'archive', $2)",
+    )
+    .bind(source_id)
+    .bind(json!({ "verification_id": verification_id }))
+    .execute(&state.db)
+    .await?;
+
+    tracing::info!(
+        contract = %contract.id,
+        version = %version,
+        verification = %verification_id,
+        size = bundle.len(),
+        "verified source archived"
+    );
+    Ok(())
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/source/{version}",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ("version" = String, Path, description = "Contract version")
+    ),
+    responses(
+        (status = 200, description = "tar.zst of the verified source tree", content_type = "application/zstd"),
+        (status = 404, description = "Contract, version or archived source not found"),
+        (status = 500, description = "Source storage unavailable")
+    ),
+    tag = "Source"
+)]
+pub async fn get_source_bundle(
+    State(state): State<AppState>,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Response> {
+    let (contract_uuid, contract_id) = fetch_contract_identity(&state, &id).await?;
+
+    let source: Option<ContractSource> = sqlx::query_as(
+        "SELECT s.id, s.contract_version_id, s.source_format::TEXT AS source_format,
+                s.storage_backend, s.storage_key, s.source_hash, s.source_size, s.created_at
+         FROM contract_sources s
+         JOIN contract_versions v ON v.id = s.contract_version_id
+         WHERE v.contract_id = $1 AND v.version = $2 AND s.source_format = 'bundle'",
+    )
+    .bind(contract_uuid)
+    .bind(&version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch source bundle", err))?;
+    let source = source.ok_or_else(|| {
+        ApiError::not_found(
+            "SourceBundleNotFound",
+            format!(
+                "No verified source archived for version '{}' of contract {}",
+                version, contract_id
+            ),
+        )
+    })?;
+
+    let mut chunks = state
+        .source_storage
+        .stream_source(&source.storage_backend, &source.storage_key)
+        .await
+        .map_err(|e| ApiError::internal(format!("source storage error: {}", e)))?;
+
+    sqlx::query(
+        "INSERT INTO source_access_logs (contract_source_id, action, details)
+         VALUES ($1, 'retrieve', $2)",
+    )
+    .bind(source.id)
+    .bind(json!({ "source_format": "bundle" }))
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_internal_error("insert source access log", err))?;
+
+    let filename = format!("{}-{}-source.tar.zst", contract_id, version);
+    let etag = format!("\"{}\"", source.source_hash);
+    let content_length = source.source_size.to_string();
+    let body = stream_body(move |body| async move {
+        let mut hasher = Sha256::new();
+        let mut held_back = None;
+        while let Some(chunk) = chunks.try_next().await? {
+            hasher.update(&chunk);
+            if let Some(previous) = held_back.replace(chunk) {
+                body.write(previous).await?;
+            }
+        }
+        if hex::encode(hasher.finalize()) != source.source_hash {
+            anyhow::bail!(
+                "source bundle {} failed its integrity check",
+                source.storage_key
+            );
+        }
+        if let Some(last) = held_back {
+            body.write(last).await?;
+        }
+        Ok(())
+    });
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, BUNDLE_CONTENT_TYPE.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", filename),
+            ),
+            (header::CONTENT_LENGTH, content_length),
+            (header::ETAG, etag),
+        ],
+        body,
+    )
+        .into_response())
+}
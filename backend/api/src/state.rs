@@ -38,12 +38,24 @@ pub enum RealtimeEvent {
         total_steps: u32,
         timestamp: String,
     },
+    ProposalComment {
+        proposal_id: String,
+        contract_id: String,
+        comment_id: String,
+        parent_id: Option<String>,
+        author: String,
+        body: String,
+        timestamp: String,
+    },
 }
 
 /// Application state shared across handlers
 #[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
+    /// Read replica for heavy read-only queries; the primary pool when no
+    /// replica is configured (see `db_pool`)
+    pub db_read: PgPool,
     pub started_at: Instant,
     pub cache: Arc<CacheLayer>,
     pub contract_events: Arc<ContractEventHub>,
@@ -76,6 +88,7 @@ impl AppState {
         let source_storage = Arc::new(SourceStorage::new().await?);
         let (event_broadcaster, _) = broadcast::channel(100);
         Ok(Self {
+            db_read: db.clone(),
             db,
             started_at: Instant::now(),
             cache: Arc::new(CacheLayer::new(config).await),
@@ -96,4 +109,10 @@ impl AppState {
             ),
         })
     }
+
+    /// Serve `db_read` queries from `pool` rather than the primary.
+    pub fn with_read_pool(mut self, pool: PgPool) -> Self {
+        self.db_read = pool;
+        self
+    }
 }
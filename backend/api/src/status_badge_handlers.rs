@@ -0,0 +1,300 @@
+//! Embeddable status badges
+//!
+//!   GET /api/contracts/:id/badge.svg – shields.io-style badge of the contract
+//!
+//! A badge reads e.g. `soroban registry | verified · v1.4.0 · mainnet`, green
+//! once the source is verified and yellow until then, for maintainers to
+//! embed live registry status in READMEs and docs sites:
+//!
+//! ```markdown
+//! ![registry](https://registry.example/api/contracts/C…/badge.svg)
+//! ```
+//!
+//! Image embeds cannot handle errors, so an unknown or private contract gets
+//! a grey `not found` badge rather than a 404. Responses are public, cached
+//! for five minutes and carry a content `ETag`.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+};
+use serde::Deserialize;
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    contract_summary_handlers::{etag, not_modified},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+const CACHE_CONTROL: &str = "public, max-age=300";
+const DEFAULT_LABEL: &str = "soroban registry";
+const MAX_LABEL_CHARS: usize = 40;
+
+const COLOR_LABEL: &str = "#555";
+const COLOR_VERIFIED: &str = "#4c1";
+const COLOR_UNVERIFIED: &str = "#dfb317";
+const COLOR_NOT_FOUND: &str = "#9f9f9f";
+
+// ── Request types ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, Default, Deserialize, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum BadgeStyle {
+    #[default]
+    Flat,
+    FlatSquare,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct BadgeQuery {
+    /// Network the contract is deployed on, when `id` is an on-chain address
+    /// registered on several; mainnet is preferred when omitted
+    pub network: Option<Network>,
+    /// Left-hand text; defaults to "soroban registry"
+    pub label: Option<String>,
+    /// `flat` (default) or `flat-square`
+    #[serde(default)]
+    pub style: BadgeStyle,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct BadgeRow {
+    network: Network,
+    is_verified: bool,
+    version: Option<String>,
+}
+
+// ── Rendering ─────────────────────────────────────────────────────────────────
+
+/// Approximate width in pixels of `text` in 11px Verdana, as shields.io
+/// lays badges out. Close enough that text neither overflows nor floats.
+fn text_width(text: &str) -> f64 {
+    text.chars()
+        .map(|c| match c {
+            'i' | 'j' | 'l' | '.' | ',' | ':' | ';' | '!' | '|' | '\'' => 3.5,
+            'f' | 'r' | 't' | 'I' | ' ' | '(' | ')' | '[' | ']' | '-' | '·' => 4.5,
+            'm' | 'w' => 10.0,
+            'M' | 'W' => 11.0,
+            c if c.is_ascii_uppercase() => 7.5,
+            _ => 7.0,
+        })
+        .sum()
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// The badge's right-hand text and colour.
+fn badge_message(row: Option<&BadgeRow>) -> (String, &'static str) {
+    let Some(row) = row else {
+        return ("not found".to_string(), COLOR_NOT_FOUND);
+    };
+    let mut parts = vec![if row.is_verified {
+        "verified".to_string()
+    } else {
+        "unverified".to_string()
+    }];
+    if let Some(version) = &row.version {
+        parts.push(format!("v{}", version.trim_start_matches('v')));
+    }
+    parts.push(row.network.to_string());
+    let color = if row.is_verified {
+        COLOR_VERIFIED
+    } else {
+        COLOR_UNVERIFIED
+    };
+    (parts.join(" · "), color)
+}
+
+fn render_svg(label: &str, message: &str, color: &str, style: BadgeStyle) -> String {
+    const PADDING: f64 = 10.0;
+    let label_width = (text_width(label) + 2.0 * PADDING).round();
+    let message_width = (text_width(message) + 2.0 * PADDING).round();
+    let width = label_width + message_width;
+    let label_x = label_width / 2.0;
+    let message_x = label_width + message_width / 2.0;
+    let (label, message) = (escape_xml(label), escape_xml(message));
+
+    let (radius, gradient, overlay) = match style {
+        BadgeStyle::Flat => (
+            3,
+            "<linearGradient id=\"s\" x2=\"0\" y2=\"100%\">\
+             <stop offset=\"0\" stop-color=\"#bbb\" stop-opacity=\".1\"/>\
+             <stop offset=\"1\" stop-opacity=\".1\"/></linearGradient>",
+            format!("<rect width=\"{}\" height=\"20\" fill=\"url(#s)\"/>", width),
+        ),
+        BadgeStyle::FlatSquare => (0, "", String::new()),
+    };
+    let shadow = |x: f64, text: &str| match style {
+        BadgeStyle::Flat => format!(
+            "<text x=\"{}\" y=\"15\" fill=\"#010101\" fill-opacity=\".3\">{}</text>",
+            x, text
+        ),
+        BadgeStyle::FlatSquare => String::new(),
+    };
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"20\" \
+         role=\"img\" aria-label=\"{label}: {message}\">\
+         <title>{label}: {message}</title>{gradient}\
+         <clipPath id=\"r\"><rect width=\"{width}\" height=\"20\" rx=\"{radius}\" fill=\"#fff\"/></clipPath>\
+         <g clip-path=\"url(#r)\">\
+         <rect width=\"{label_width}\" height=\"20\" fill=\"{COLOR_LABEL}\"/>\
+         <rect x=\"{label_width}\" width=\"{message_width}\" height=\"20\" fill=\"{color}\"/>{overlay}</g>\
+         <g fill=\"#fff\" text-anchor=\"middle\" \
+         font-family=\"Verdana,Geneva,DejaVu Sans,sans-serif\" font-size=\"11\">\
+         {label_shadow}<text x=\"{label_x}\" y=\"14\">{label}</text>\
+         {message_shadow}<text x=\"{message_x}\" y=\"14\">{message}</text></g></svg>",
+        label_shadow = shadow(label_x, &label),
+        message_shadow = shadow(message_x, &message),
+    )
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/badge.svg",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        BadgeQuery
+    ),
+    responses(
+        (status = 200, description = "Status badge; `not found` when there is no public contract", content_type = "image/svg+xml"),
+        (status = 304, description = "Unchanged since the ETag in If-None-Match"),
+        (status = 400, description = "Label too long")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_status_badge(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<BadgeQuery>,
+    tenant: Tenant,
+    headers: HeaderMap,
+) -> ApiResult<Response> {
+    let label = query
+        .label
+        .as_deref()
+        .map(str::trim)
+        .unwrap_or(DEFAULT_LABEL);
+    if label.chars().count() > MAX_LABEL_CHARS {
+        return Err(ApiError::bad_request(
+            "InvalidBadgeLabel",
+            format!("label must be at most {} characters", MAX_LABEL_CHARS),
+        ));
+    }
+
+    let row: Option<BadgeRow> = sqlx::query_as(
+        "SELECT c.network, c.is_verified,
+                COALESCE(c.current_version,
+                         (SELECT v.version FROM contract_versions v
+                          WHERE v.contract_id = c.id
+                          ORDER BY v.created_at DESC LIMIT 1)) AS version
+         FROM contracts c
+         WHERE (c.id = $4 OR c.contract_id = $1) AND c.tenant_id = $2
+           AND c.deleted_at IS NULL AND c.visibility = 'public'
+           AND ($3::network_type IS NULL OR c.network = $3)
+         ORDER BY (c.network = 'mainnet') DESC, c.created_at
+         LIMIT 1",
+    )
+    .bind(&id)
+    .bind(tenant.id)
+    .bind(&query.network)
+    .bind(Uuid::parse_str(&id).ok())
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch contract badge status", err))?;
+
+    let (message, color) = badge_message(row.as_ref());
+    let svg = render_svg(label, &message, color, query.style);
+
+    let etag = etag(svg.as_bytes());
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, CACHE_CONTROL.to_string()),
+    ];
+    if not_modified(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, "image/svg+xml".to_string())],
+        svg,
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_reflects_verification_version_and_network() {
+        let row = BadgeRow {
+            network: Network::Mainnet,
+            is_verified: true,
+            version: Some("1.4.0".to_string()),
+        };
+        assert_eq!(
+            badge_message(Some(&row)),
+            ("verified · v1.4.0 · mainnet".to_string(), COLOR_VERIFIED)
+        );
+
+        let row = BadgeRow {
+            network: Network::Testnet,
+            is_verified: false,
+            version: None,
+        };
+        assert_eq!(
+            badge_message(Some(&row)),
+            ("unverified · testnet".to_string(), COLOR_UNVERIFIED)
+        );
+        assert_eq!(
+            badge_message(None),
+            ("not found".to_string(), COLOR_NOT_FOUND)
+        );
+    }
+
+    #[test]
+    fn svg_escapes_text_and_sizes_to_it() {
+        let svg = render_svg("a<b>&\"c\"", "ok", COLOR_VERIFIED, BadgeStyle::Flat);
+        assert!(svg.contains("a&lt;b&gt;&amp;&quot;c&quot;"));
+        assert!(!svg.contains("a<b>"));
+
+        let width = |svg: &str| -> f64 {
+            let start = svg.find("width=\"").unwrap() + 7;
+            let end = start + svg[start..].find('"').unwrap();
+            svg[start..end].parse().unwrap()
+        };
+        let short = render_svg("registry", "ok", COLOR_VERIFIED, BadgeStyle::Flat);
+        let long = render_svg(
+            "registry",
+            "verified · v10.0.0",
+            COLOR_VERIFIED,
+            BadgeStyle::Flat,
+        );
+        assert!(width(&long) > width(&short));
+
+        let square = render_svg("registry", "ok", COLOR_VERIFIED, BadgeStyle::FlatSquare);
+        assert!(square.contains("rx=\"0\""));
+        assert!(!square.contains("linearGradient"));
+    }
+}
@@ -0,0 +1,79 @@
+//! Streamed response bodies
+//!
+//! Large artifacts (source bundles, registry dumps, event exports) are sent
+//! with chunked transfer encoding while they are produced, instead of being
+//! assembled in memory first. [`stream_body`] runs a producer task that
+//! writes chunks through a bounded channel, so a slow client holds the
+//! producer back rather than letting the artifact pile up in memory, and a
+//! client that disconnects stops it at its next write.
+//!
+//! Headers are sent before the first chunk, so a producer that fails halfway
+//! cannot turn the response into an error status any more. It aborts the
+//! body instead: the client sees a truncated transfer, never a complete
+//! looking but partial artifact. Anything that can be checked up front
+//! (permissions, that the artifact exists) belongs in the handler, before the
+//! body is created.
+
+use std::future::Future;
+use std::io;
+
+use axum::body::{Body, Bytes};
+use futures_util::stream;
+use tokio::sync::mpsc;
+
+/// Chunks buffered between the producer and the connection.
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Producers writing many small records collect them into chunks of about
+/// this size before each write.
+pub const CHUNK_BYTES: usize = 64 * 1024;
+
+/// The client went away before the body was complete.
+#[derive(Debug)]
+pub struct ClientGone;
+
+impl std::fmt::Display for ClientGone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("client disconnected during a streamed response")
+    }
+}
+
+impl std::error::Error for ClientGone {}
+
+/// The producer's end of a streamed body.
+pub struct BodyWriter {
+    tx: mpsc::Sender<io::Result<Bytes>>,
+}
+
+impl BodyWriter {
+    /// Sends a chunk, waiting while the client is behind.
+    pub async fn write(&self, chunk: impl Into<Bytes>) -> Result<(), ClientGone> {
+        self.tx.send(Ok(chunk.into())).await.map_err(|_| ClientGone)
+    }
+}
+
+/// A body fed by `produce`, which runs on its own task until it returns or
+/// the client disconnects.
+pub fn stream_body<F, Fut>(produce: F) -> Body
+where
+    F: FnOnce(BodyWriter) -> Fut,
+    Fut: Future<Output = anyhow::Result<()>> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel(CHANNEL_CAPACITY);
+    let producer = produce(BodyWriter { tx: tx.clone() });
+    tokio::spawn(async move {
+        let Err(err) = producer.await else {
+            return;
+        };
+        if err.is::<ClientGone>() {
+            tracing::debug!("client disconnected from a streamed response");
+            return;
+        }
+        tracing::error!(error = %err, "streamed response aborted");
+        let _ = tx.send(Err(io::Error::other(err.to_string()))).await;
+    });
+
+    Body::from_stream(stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|chunk| (chunk, rx))
+    }))
+}
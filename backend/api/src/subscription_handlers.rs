@@ -29,6 +29,26 @@ pub struct ListSubscriptionsQuery {
     pub status: Option<String>,
 }
 
+/// Email channels deliver to the publisher's address, so they can only be
+/// enabled once it is verified (see `email_verification_handlers`).
+async fn ensure_email_verified(state: &AppState, publisher_id: Uuid) -> ApiResult<()> {
+    let verified: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM publishers
+                       WHERE id = $1 AND email IS NOT NULL AND email_verified_at IS NOT NULL)",
+    )
+    .bind(publisher_id)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
+    if !verified {
+        return Err(ApiError::forbidden_with_error(
+            "EmailNotVerified",
+            "Verify your email (`soroban-registry publisher verify-email`) before enabling email notifications",
+        ));
+    }
+    Ok(())
+}
+
 /// Subscribe to a contract
 ///
 /// POST /api/contracts/:id/subscribe
@@ -38,32 +58,39 @@ pub async fn subscribe_to_contract(
     auth_user: auth::AuthenticatedUser,
     Json(req): Json<SubscribeRequest>,
 ) -> ApiResult<Json<ContractSubscription>> {
-    // Verify contract exists
-    let contract_exists: bool = sqlx::query_scalar(
-        "SELECT EXISTS(SELECT 1 FROM contracts WHERE id = $1)",
+    // Verify contract exists, noting whether it is a tracked oracle feed
+    let is_oracle_feed: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM oracle_feeds WHERE contract_id = c.id)
+         FROM contracts c WHERE c.id = $1",
     )
     .bind(contract_id)
-    .fetch_one(&state.db)
+    .fetch_optional(&state.db)
     .await
-    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?;
-
-    if !contract_exists {
-        return Err(ApiError::not_found("contract", "Contract not found"));
-    }
+    .map_err(|e| ApiError::internal(format!("Database error: {}", e)))?
+    .ok_or_else(|| ApiError::not_found("contract", "Contract not found"))?;
 
     // Get user's publisher_id
     let user_id = auth_user.publisher_id;
 
     // Default values
-    let notification_types = req.notification_types.unwrap_or(vec![
-        NotificationType::NewVersion,
-        NotificationType::VerificationStatus,
-        NotificationType::SecurityIssue,
-    ]);
+    let notification_types = req.notification_types.unwrap_or_else(|| {
+        let mut types = vec![
+            NotificationType::NewVersion,
+            NotificationType::VerificationStatus,
+            NotificationType::SecurityIssue,
+        ];
+        if is_oracle_feed {
+            types.push(NotificationType::OracleStale);
+        }
+        types
+    });
 
     let channels = req
         .channels
         .unwrap_or(vec![NotificationChannel::InApp]);
+    if channels.contains(&NotificationChannel::Email) {
+        ensure_email_verified(&state, user_id).await?;
+    }
 
     let frequency = req.frequency.unwrap_or(NotificationFrequency::Realtime);
 
@@ -204,6 +231,14 @@ pub async fn update_subscription(
 ) -> ApiResult<Json<ContractSubscription>> {
     let user_id = auth_user.publisher_id;
 
+    if req
+        .channels
+        .as_ref()
+        .is_some_and(|channels| channels.contains(&NotificationChannel::Email))
+    {
+        ensure_email_verified(&state, user_id).await?;
+    }
+
     // Build dynamic update query
     let mut updates = Vec::new();
     if let Some(status) = &req.status {
@@ -292,6 +327,15 @@ pub async fn update_notification_preferences(
 ) -> ApiResult<Json<UserNotificationPreferences>> {
     let user_id = auth_user.publisher_id;
 
+    let enables_email = req.email_notifications_enabled == Some(true)
+        || req
+            .notification_channels
+            .as_ref()
+            .is_some_and(|channels| channels.contains(&NotificationChannel::Email));
+    if enables_email {
+        ensure_email_verified(&state, user_id).await?;
+    }
+
     // Build dynamic update
     let mut updates = Vec::new();
     let mut param_count = 1;
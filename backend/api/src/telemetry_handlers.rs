@@ -0,0 +1,269 @@
+//! Opt-in CLI usage telemetry
+//!
+//!   POST /api/telemetry                    – a batch of CLI command events
+//!   GET  /api/admin/telemetry/commands     – usage per command
+//!
+//! CLIs only report after `soroban-registry telemetry on`. An event is the
+//! command path (`verify`, `events replay`), its duration and whether it
+//! succeeded; arguments are never sent. Batches carry a random install ID,
+//! the CLI version and the OS/architecture. Nothing identifying the caller,
+//! such as the request IP, is stored. The admin summary lets maintainers see
+//! which commands are actually used.
+
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::QueryBuilder;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const MAX_BATCH: usize = 100;
+const MAX_COMMAND_LEN: usize = 64;
+/// Events older than this are dropped rather than stored.
+const MAX_EVENT_AGE_DAYS: i64 = 30;
+const DEFAULT_DAYS: i64 = 30;
+const MAX_DAYS: i64 = 365;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct TelemetryBatch {
+    /// Random ID generated when telemetry was turned on
+    pub install_id: Uuid,
+    pub cli_version: String,
+    /// `std::env::consts::OS`, e.g. `linux`
+    pub os: String,
+    /// `std::env::consts::ARCH`, e.g. `x86_64`
+    pub arch: String,
+    pub events: Vec<TelemetryEvent>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct TelemetryEvent {
+    /// Subcommand path without arguments, e.g. `events replay`
+    pub command: String,
+    pub duration_ms: i64,
+    pub success: bool,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TelemetryAccepted {
+    pub accepted: usize,
+    /// Events too old to record
+    pub dropped: usize,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct CommandUsageQuery {
+    /// Window length in days (default 30, max 365)
+    pub days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct CommandUsage {
+    pub command: String,
+    pub invocations: i64,
+    /// Distinct installs that ran the command
+    pub installs: i64,
+    /// Share of invocations that succeeded, 0–100
+    pub success_rate: f64,
+    pub median_duration_ms: Option<f64>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidTelemetry", message)
+}
+
+/// Short lowercase tokens only, so free-form values (paths, addresses) are
+/// rejected rather than stored.
+fn is_token(value: &str, max_len: usize, allow_spaces: bool) -> bool {
+    !value.is_empty()
+        && value.len() <= max_len
+        && value.chars().all(|c| {
+            c.is_ascii_lowercase()
+                || c.is_ascii_digit()
+                || "-_.".contains(c)
+                || (allow_spaces && c == ' ')
+        })
+}
+
+fn validate_batch(batch: &TelemetryBatch) -> ApiResult<()> {
+    if batch.events.is_empty() || batch.events.len() > MAX_BATCH {
+        return Err(invalid(format!(
+            "a batch carries 1 to {} events",
+            MAX_BATCH
+        )));
+    }
+    if !is_token(&batch.cli_version, 32, false)
+        || !is_token(&batch.os, 16, false)
+        || !is_token(&batch.arch, 16, false)
+    {
+        return Err(invalid(
+            "cli_version, os and arch must be short identifiers",
+        ));
+    }
+    for event in &batch.events {
+        if !is_token(&event.command, MAX_COMMAND_LEN, true) {
+            return Err(invalid(format!(
+                "command must be a subcommand path of at most {} characters",
+                MAX_COMMAND_LEN
+            )));
+        }
+        if event.duration_ms < 0 {
+            return Err(invalid("duration_ms cannot be negative"));
+        }
+    }
+    Ok(())
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/telemetry",
+    request_body = TelemetryBatch,
+    responses(
+        (status = 202, description = "Events recorded", body = TelemetryAccepted),
+        (status = 400, description = "Malformed batch")
+    ),
+    tag = "CLI"
+)]
+pub async fn ingest_telemetry(
+    State(state): State<AppState>,
+    Json(batch): Json<TelemetryBatch>,
+) -> ApiResult<(StatusCode, Json<TelemetryAccepted>)> {
+    validate_batch(&batch)?;
+
+    // Future timestamps from skewed clocks are clamped to now.
+    let now = Utc::now();
+    let cutoff = now - Duration::days(MAX_EVENT_AGE_DAYS);
+    let events: Vec<&TelemetryEvent> = batch
+        .events
+        .iter()
+        .filter(|event| event.occurred_at >= cutoff)
+        .collect();
+    let dropped = batch.events.len() - events.len();
+
+    if !events.is_empty() {
+        let mut insert = QueryBuilder::new(
+            "INSERT INTO cli_telemetry_events
+                (install_id, cli_version, os, arch, command, duration_ms, success, occurred_at) ",
+        );
+        insert.push_values(&events, |mut row, event| {
+            row.push_bind(batch.install_id)
+                .push_bind(&batch.cli_version)
+                .push_bind(&batch.os)
+                .push_bind(&batch.arch)
+                .push_bind(&event.command)
+                .push_bind(event.duration_ms)
+                .push_bind(event.success)
+                .push_bind(event.occurred_at.min(now));
+        });
+        insert
+            .build()
+            .execute(&state.db)
+            .await
+            .map_err(|err| db_internal_error("insert telemetry events", err))?;
+    }
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(TelemetryAccepted {
+            accepted: events.len(),
+            dropped,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/telemetry/commands",
+    params(CommandUsageQuery),
+    responses(
+        (status = 200, description = "Usage per command, most used first", body = [CommandUsage]),
+        (status = 400, description = "Invalid window")
+    ),
+    tag = "CLI"
+)]
+pub async fn get_command_usage(
+    State(state): State<AppState>,
+    Query(query): Query<CommandUsageQuery>,
+) -> ApiResult<Json<Vec<CommandUsage>>> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(ApiError::bad_request(
+            "InvalidMetricsQuery",
+            format!("days must be between 1 and {}", MAX_DAYS),
+        ));
+    }
+
+    let usage = sqlx::query_as(
+        "SELECT command,
+            COUNT(*) AS invocations,
+            COUNT(DISTINCT install_id) AS installs,
+            ROUND(100.0 * COUNT(*) FILTER (WHERE success) / COUNT(*), 1)::FLOAT8 AS success_rate,
+            (PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY duration_ms))::FLOAT8
+                AS median_duration_ms
+         FROM cli_telemetry_events
+         WHERE occurred_at >= $1
+         GROUP BY command
+         ORDER BY invocations DESC, command",
+    )
+    .bind(Utc::now() - Duration::days(days))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("aggregate telemetry", err))?;
+    Ok(Json(usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn batch(command: &str) -> TelemetryBatch {
+        TelemetryBatch {
+            install_id: Uuid::new_v4(),
+            cli_version: "0.1.0".to_string(),
+            os: "linux".to_string(),
+            arch: "x86_64".to_string(),
+            events: vec![TelemetryEvent {
+                command: command.to_string(),
+                duration_ms: 120,
+                success: true,
+                occurred_at: Utc::now(),
+            }],
+        }
+    }
+
+    #[test]
+    fn accepts_subcommand_paths() {
+        assert!(validate_batch(&batch("verify")).is_ok());
+        assert!(validate_batch(&batch("events replay")).is_ok());
+    }
+
+    #[test]
+    fn rejects_values_that_look_like_arguments() {
+        assert!(validate_batch(&batch("info CAB3XYZ")).is_err());
+        assert!(validate_batch(&batch("publish ./contract.wasm")).is_err());
+        assert!(validate_batch(&batch("")).is_err());
+    }
+
+    #[test]
+    fn rejects_empty_batches() {
+        let mut empty = batch("verify");
+        empty.events.clear();
+        assert!(validate_batch(&empty).is_err());
+    }
+}
@@ -0,0 +1,214 @@
+//! Contract template catalog handlers
+//!
+//! Serves the project templates used by `soroban-registry new`. Every template
+//! is backed by a verified contract hosted in this registry, so scaffolded
+//! projects always start from source that has been reproducibly built:
+//!
+//!   GET    /api/templates                 – list templates (optional `category`)
+//!   GET    /api/templates/:slug           – template detail including sources
+//!   POST   /api/templates/:slug/install   – record a scaffold of the template
+//!
+//! Installs are counted once per signed-in account, so the catalog's
+//! install ordering cannot be inflated by repeating the request.
+//!
+//! Templates whose backing contract has lost its verified status are hidden
+//! from the catalog rather than served with a warning.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ListTemplatesQuery {
+    /// Restrict results to a single template category (e.g. `token`, `dao`).
+    pub category: Option<String>,
+}
+
+/// Catalog entry returned by the list endpoint (sources omitted).
+#[derive(Debug, Serialize, FromRow, utoipa::ToSchema)]
+pub struct TemplateSummary {
+    pub id: Uuid,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: String,
+    pub version: String,
+    pub install_count: i64,
+    /// Registry UUID of the verified contract this template was derived from.
+    pub contract_id: Uuid,
+    /// On-chain address of the backing contract.
+    pub contract_address: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Full template including the files needed to scaffold a project.
+#[derive(Debug, Serialize, FromRow, utoipa::ToSchema)]
+pub struct TemplateDetail {
+    pub id: Uuid,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub category: String,
+    pub version: String,
+    pub install_count: i64,
+    pub contract_id: Uuid,
+    pub contract_address: String,
+    pub network: String,
+    /// Contents of `src/lib.rs`.
+    pub source_code: String,
+    /// Contents of `Cargo.toml`; `{{name}}` is substituted by the CLI.
+    pub cargo_toml: Option<String>,
+    /// Constructor parameters documented by the template author.
+    pub parameters: serde_json::Value,
+    pub updated_at: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+fn template_not_found(slug: &str) -> ApiError {
+    ApiError::not_found(
+        "TemplateNotFound",
+        format!("No verified template found with slug: {}", slug),
+    )
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+/// List templates backed by verified contracts, most installed first.
+#[utoipa::path(
+    get,
+    path = "/api/templates",
+    params(
+        ("category" = Option<String>, Query, description = "Filter by template category")
+    ),
+    responses(
+        (status = 200, description = "Template catalog", body = [TemplateSummary])
+    ),
+    tag = "Templates"
+)]
+pub async fn list_templates(
+    State(state): State<AppState>,
+    Query(query): Query<ListTemplatesQuery>,
+) -> ApiResult<Json<Vec<TemplateSummary>>> {
+    let rows: Vec<TemplateSummary> = sqlx::query_as(
+        r#"
+        SELECT t.id, t.slug, t.name, t.description, t.category, t.version,
+               t.install_count, c.id AS contract_id, c.contract_id AS contract_address,
+               t.updated_at
+        FROM contract_templates t
+        JOIN contracts c ON c.id = t.contract_id
+        WHERE c.is_verified = TRUE
+          AND ($1::TEXT IS NULL OR LOWER(t.category) = LOWER($1))
+        ORDER BY t.install_count DESC, t.name ASC
+        "#,
+    )
+    .bind(query.category.as_deref())
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list templates", err))?;
+
+    Ok(Json(rows))
+}
+
+/// Fetch a template with its source files.
+#[utoipa::path(
+    get,
+    path = "/api/templates/{slug}",
+    params(
+        ("slug" = String, Path, description = "Template slug (e.g. token, nft, dao, escrow)")
+    ),
+    responses(
+        (status = 200, description = "Template detail", body = TemplateDetail),
+        (status = 404, description = "Template not found or not verified")
+    ),
+    tag = "Templates"
+)]
+pub async fn get_template(
+    State(state): State<AppState>,
+    Path(slug): Path<String>,
+) -> ApiResult<Json<TemplateDetail>> {
+    let row: Option<TemplateDetail> = sqlx::query_as(
+        r#"
+        SELECT t.id, t.slug, t.name, t.description, t.category, t.version,
+               t.install_count, c.id AS contract_id, c.contract_id AS contract_address,
+               c.network::TEXT AS network, t.source_code, t.cargo_toml, t.parameters,
+               t.updated_at
+        FROM contract_templates t
+        JOIN contracts c ON c.id = t.contract_id
+        WHERE t.slug = $1 AND c.is_verified = TRUE
+        "#,
+    )
+    .bind(&slug)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_err("get template", err))?;
+
+    row.map(Json).ok_or_else(|| template_not_found(&slug))
+}
+
+/// Record that the caller scaffolded a template, counting each account once.
+#[utoipa::path(
+    post,
+    path = "/api/templates/{slug}/install",
+    params(
+        ("slug" = String, Path, description = "Template slug")
+    ),
+    responses(
+        (status = 204, description = "Install recorded"),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "Template not found")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Templates"
+)]
+pub async fn record_template_install(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path(slug): Path<String>,
+) -> ApiResult<StatusCode> {
+    let template_id: Option<Uuid> =
+        sqlx::query_scalar("SELECT id FROM contract_templates WHERE slug = $1")
+            .bind(&slug)
+            .fetch_optional(&state.db)
+            .await
+            .map_err(|err| db_err("fetch template", err))?;
+
+    let template_id = template_id.ok_or_else(|| template_not_found(&slug))?;
+
+    // Only an account's first install of the template bumps the counter.
+    sqlx::query(
+        "WITH installed AS ( \
+             INSERT INTO template_installs (template_id, user_address) VALUES ($1, $2) \
+             ON CONFLICT (template_id, user_address) DO NOTHING \
+             RETURNING template_id \
+         ) \
+         UPDATE contract_templates SET install_count = install_count + 1, updated_at = NOW() \
+         WHERE id IN (SELECT template_id FROM installed)",
+    )
+    .bind(template_id)
+    .bind(&claims.sub)
+    .execute(&state.db)
+    .await
+    .map_err(|err| db_err("record template install", err))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,244 @@
+//! Tenant resolution and quotas
+//!
+//! One deployment can serve several isolated teams. Each request is mapped to
+//! a [`Tenant`] and scoped queries filter on `tenant_id`:
+//!
+//! 1. the `tenant` claim (tenant slug) of a valid bearer token, else
+//! 2. the request's `Host` header matched against `tenants.hostnames`, else
+//! 3. the built-in `default` tenant.
+//!
+//! Resolution is disabled unless `MULTI_TENANCY_ENABLED=true`; single-team
+//! deployments then always run as the default tenant without extra queries.
+//! Lookups are cached for [`CACHE_TTL`], so hostname and quota changes take up
+//! to a minute to apply.
+
+use std::time::Duration;
+
+use axum::{extract::FromRequestParts, http::request::Parts, http::StatusCode};
+use chrono::{DateTime, Utc};
+use moka::future::Cache;
+use once_cell::sync::Lazy;
+use serde::Serialize;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// ID of the tenant that owns all pre-existing data (see the tenants migration).
+pub const DEFAULT_TENANT_ID: Uuid = Uuid::from_u128(1);
+const CACHE_TTL: Duration = Duration::from_secs(60);
+
+static TENANT_CACHE: Lazy<Cache<String, Option<Tenant>>> = Lazy::new(|| {
+    Cache::builder()
+        .max_capacity(10_000)
+        .time_to_live(CACHE_TTL)
+        .build()
+});
+
+#[derive(Debug, Clone, Serialize, FromRow, utoipa::ToSchema)]
+pub struct Tenant {
+    pub id: Uuid,
+    pub slug: String,
+    pub name: String,
+    pub hostnames: Vec<String>,
+    /// Maximum contracts; `None` is unlimited.
+    pub quota_contracts: Option<i32>,
+    /// Maximum publishers; `None` is unlimited.
+    pub quota_publishers: Option<i32>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Tenant {
+    fn builtin_default() -> Self {
+        Self {
+            id: DEFAULT_TENANT_ID,
+            slug: "default".to_string(),
+            name: "Default".to_string(),
+            hostnames: Vec::new(),
+            quota_contracts: None,
+            quota_publishers: None,
+            created_at: DateTime::<Utc>::UNIX_EPOCH,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum QuotaResource {
+    Contracts,
+    Publishers,
+}
+
+pub fn multi_tenancy_enabled() -> bool {
+    std::env::var("MULTI_TENANCY_ENABLED")
+        .map(|v| v.eq_ignore_ascii_case("true"))
+        .unwrap_or(false)
+}
+
+/// `Host` header value without port, lower-cased.
+fn normalize_host(host: &str) -> String {
+    let host = host.trim();
+    let host = match host.strip_prefix('[') {
+        // IPv6 literal: keep the brackets' contents only.
+        Some(rest) => rest.split(']').next().unwrap_or(rest),
+        None => host.split(':').next().unwrap_or(host),
+    };
+    host.trim_end_matches('.').to_ascii_lowercase()
+}
+
+pub(crate) const TENANT_COLUMNS: &str =
+    "id, slug, name, hostnames, quota_contracts, quota_publishers, created_at";
+
+async fn lookup(
+    db: &PgPool,
+    key: String,
+    column: &'static str,
+    value: String,
+) -> ApiResult<Option<Tenant>> {
+    if let Some(cached) = TENANT_CACHE.get(&key).await {
+        return Ok(cached);
+    }
+    let condition = match column {
+        "slug" => "slug = $1",
+        "hostname" => "$1 = ANY(hostnames)",
+        _ => "id = $1::uuid",
+    };
+    let tenant: Option<Tenant> = sqlx::query_as(&format!(
+        "SELECT {} FROM tenants WHERE {} LIMIT 1",
+        TENANT_COLUMNS, condition
+    ))
+    .bind(&value)
+    .fetch_optional(db)
+    .await
+    .map_err(|err| {
+        tracing::error!(error = ?err, "tenant lookup failed");
+        ApiError::internal("An unexpected database error occurred")
+    })?;
+    TENANT_CACHE.insert(key, tenant.clone()).await;
+    Ok(tenant)
+}
+
+/// Drop cached lookups after tenants are created or edited.
+pub fn invalidate_cache() {
+    TENANT_CACHE.invalidate_all();
+}
+
+async fn resolve(
+    db: &PgPool,
+    claims: Option<&AuthClaims>,
+    host: Option<&str>,
+) -> ApiResult<Tenant> {
+    if let Some(slug) = claims.and_then(|c| c.tenant.as_deref()) {
+        return lookup(db, format!("slug:{}", slug), "slug", slug.to_string())
+            .await?
+            .ok_or_else(|| {
+                ApiError::forbidden_with_error(
+                    "UnknownTenant",
+                    format!("Token is scoped to unknown tenant '{}'", slug),
+                )
+            });
+    }
+    if let Some(host) = host.map(normalize_host).filter(|h| !h.is_empty()) {
+        if let Some(tenant) = lookup(db, format!("host:{}", host), "hostname", host).await? {
+            return Ok(tenant);
+        }
+    }
+    Ok(lookup(
+        db,
+        "default".to_string(),
+        "id",
+        DEFAULT_TENANT_ID.to_string(),
+    )
+    .await?
+    .unwrap_or_else(Tenant::builtin_default))
+}
+
+#[axum::async_trait]
+impl FromRequestParts<AppState> for Tenant {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        if let Some(tenant) = parts.extensions.get::<Tenant>() {
+            return Ok(tenant.clone());
+        }
+        if !multi_tenancy_enabled() {
+            return Ok(Tenant::builtin_default());
+        }
+
+        // An invalid or missing token is not an error here; the handler's own
+        // auth extractors decide whether authentication is required.
+        let claims = AuthClaims::from_request_parts(parts, state).await.ok();
+        let host = parts
+            .headers
+            .get(axum::http::header::HOST)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string);
+        let tenant = resolve(&state.db, claims.as_ref(), host.as_deref()).await?;
+        parts.extensions.insert(tenant.clone());
+        Ok(tenant)
+    }
+}
+
+/// Reject the creation of one more `resource` if the tenant is at its quota.
+pub async fn ensure_quota(db: &PgPool, tenant: &Tenant, resource: QuotaResource) -> ApiResult<()> {
+    let (limit, table, label) = match resource {
+        QuotaResource::Contracts => (tenant.quota_contracts, "contracts", "contracts"),
+        QuotaResource::Publishers => (tenant.quota_publishers, "publishers", "publishers"),
+    };
+    let Some(limit) = limit else {
+        return Ok(());
+    };
+    let used: i64 = sqlx::query_scalar(&format!(
+        "SELECT COUNT(*) FROM {} WHERE tenant_id = $1 AND deleted_at IS NULL",
+        table
+    ))
+    .bind(tenant.id)
+    .fetch_one(db)
+    .await
+    .map_err(|err| {
+        tracing::error!(error = ?err, "tenant quota check failed");
+        ApiError::internal("An unexpected database error occurred")
+    })?;
+
+    if used >= i64::from(limit) {
+        return Err(ApiError::new(
+            StatusCode::FORBIDDEN,
+            "TenantQuotaExceeded",
+            format!(
+                "Tenant '{}' has reached its quota of {} {}",
+                tenant.slug, limit, label
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_host_strips_port_and_case() {
+        assert_eq!(
+            normalize_host("Team-A.Registry.io:443"),
+            "team-a.registry.io"
+        );
+        assert_eq!(normalize_host("registry.io."), "registry.io");
+        assert_eq!(normalize_host("[::1]:3001"), "::1");
+        assert_eq!(normalize_host("localhost"), "localhost");
+    }
+
+    #[test]
+    fn default_tenant_id_matches_migration() {
+        assert_eq!(
+            DEFAULT_TENANT_ID.to_string(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+    }
+}
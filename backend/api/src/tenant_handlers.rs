@@ -0,0 +1,292 @@
+//! Tenant management and usage handlers
+//!
+//!   GET    /api/tenant              – the caller's tenant with usage and quotas
+//!   GET    /api/admin/tenants       – every tenant with usage
+//!   POST   /api/admin/tenants       – create a tenant
+//!   PATCH  /api/admin/tenants/:id   – rename, remap hostnames or change quotas
+//!
+//! See [`crate::tenant`] for how requests are mapped to tenants.
+
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+    tenant::{self, Tenant, TENANT_COLUMNS},
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Default, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct TenantUsage {
+    pub contracts: i64,
+    pub contract_versions: i64,
+    pub publishers: i64,
+    pub patches: i64,
+    pub deploy_proposals: i64,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TenantStats {
+    #[serde(flatten)]
+    pub tenant: Tenant,
+    pub usage: TenantUsage,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateTenantRequest {
+    pub slug: String,
+    pub name: String,
+    #[serde(default)]
+    pub hostnames: Vec<String>,
+    pub quota_contracts: Option<i32>,
+    pub quota_publishers: Option<i32>,
+}
+
+/// Omitted fields are left unchanged.
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct UpdateTenantRequest {
+    pub name: Option<String>,
+    pub hostnames: Option<Vec<String>>,
+    pub quota_contracts: Option<i32>,
+    pub quota_publishers: Option<i32>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn db_err(op: &str, err: sqlx::Error) -> ApiError {
+    tracing::error!(operation = op, error = ?err, "database operation failed");
+    ApiError::internal("An unexpected database error occurred")
+}
+
+fn validate_slug(slug: &str) -> ApiResult<()> {
+    let valid = !slug.is_empty()
+        && slug.len() <= 64
+        && !slug.starts_with('-')
+        && slug
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-');
+    if valid {
+        Ok(())
+    } else {
+        Err(ApiError::bad_request(
+            "InvalidTenantSlug",
+            "slug must be 1-64 lowercase letters, digits or '-', not starting with '-'",
+        ))
+    }
+}
+
+fn validate_quota(field: &str, quota: Option<i32>) -> ApiResult<()> {
+    match quota {
+        Some(q) if q < 0 => Err(ApiError::bad_request(
+            "InvalidQuota",
+            format!("{} must not be negative", field),
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn normalize_hostnames(hostnames: Vec<String>) -> Vec<String> {
+    let mut out: Vec<String> = hostnames
+        .into_iter()
+        .map(|h| h.trim().trim_end_matches('.').to_ascii_lowercase())
+        .filter(|h| !h.is_empty())
+        .collect();
+    out.sort();
+    out.dedup();
+    out
+}
+
+fn map_write_err(op: &str, err: sqlx::Error) -> ApiError {
+    if let sqlx::Error::Database(ref e) = err {
+        if e.constraint() == Some("tenants_slug_key") {
+            return ApiError::conflict("TenantExists", "A tenant with this slug already exists");
+        }
+    }
+    db_err(op, err)
+}
+
+async fn usage(db: &PgPool, tenant_id: Uuid) -> ApiResult<TenantUsage> {
+    sqlx::query_as(
+        "SELECT
+            (SELECT COUNT(*) FROM contracts
+              WHERE tenant_id = $1 AND deleted_at IS NULL) AS contracts,
+            (SELECT COUNT(*) FROM contract_versions v
+               JOIN contracts c ON c.id = v.contract_id
+              WHERE c.tenant_id = $1) AS contract_versions,
+            (SELECT COUNT(*) FROM publishers
+              WHERE tenant_id = $1 AND deleted_at IS NULL) AS publishers,
+            (SELECT COUNT(*) FROM contract_patches WHERE tenant_id = $1) AS patches,
+            (SELECT COUNT(*) FROM deploy_proposals WHERE tenant_id = $1) AS deploy_proposals",
+    )
+    .bind(tenant_id)
+    .fetch_one(db)
+    .await
+    .map_err(|err| db_err("tenant usage", err))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/tenant",
+    responses((status = 200, description = "Current tenant and its usage", body = TenantStats)),
+    tag = "Tenants"
+)]
+pub async fn get_current_tenant(
+    State(state): State<AppState>,
+    tenant: Tenant,
+) -> ApiResult<Json<TenantStats>> {
+    let usage = usage(&state.db, tenant.id).await?;
+    Ok(Json(TenantStats { tenant, usage }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/tenants",
+    responses((status = 200, description = "All tenants with usage", body = [TenantStats])),
+    tag = "Tenants"
+)]
+pub async fn list_tenants(State(state): State<AppState>) -> ApiResult<Json<Vec<TenantStats>>> {
+    let tenants: Vec<Tenant> = sqlx::query_as(&format!(
+        "SELECT {} FROM tenants ORDER BY created_at, slug",
+        TENANT_COLUMNS
+    ))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_err("list tenants", err))?;
+
+    let mut out = Vec::with_capacity(tenants.len());
+    for tenant in tenants {
+        let usage = usage(&state.db, tenant.id).await?;
+        out.push(TenantStats { tenant, usage });
+    }
+    Ok(Json(out))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/tenants",
+    request_body = CreateTenantRequest,
+    responses(
+        (status = 200, description = "Tenant created", body = Tenant),
+        (status = 400, description = "Invalid slug or quota"),
+        (status = 409, description = "Slug already in use")
+    ),
+    tag = "Tenants"
+)]
+pub async fn create_tenant(
+    State(state): State<AppState>,
+    Json(req): Json<CreateTenantRequest>,
+) -> ApiResult<Json<Tenant>> {
+    validate_slug(&req.slug)?;
+    if req.name.trim().is_empty() {
+        return Err(ApiError::bad_request(
+            "InvalidTenantName",
+            "name must not be empty",
+        ));
+    }
+    validate_quota("quota_contracts", req.quota_contracts)?;
+    validate_quota("quota_publishers", req.quota_publishers)?;
+
+    let tenant: Tenant = sqlx::query_as(&format!(
+        "INSERT INTO tenants (slug, name, hostnames, quota_contracts, quota_publishers)
+         VALUES ($1, $2, $3, $4, $5)
+         RETURNING {}",
+        TENANT_COLUMNS
+    ))
+    .bind(&req.slug)
+    .bind(req.name.trim())
+    .bind(normalize_hostnames(req.hostnames))
+    .bind(req.quota_contracts)
+    .bind(req.quota_publishers)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| map_write_err("create tenant", err))?;
+
+    tenant::invalidate_cache();
+    tracing::info!(tenant = %tenant.slug, "tenant created");
+    Ok(Json(tenant))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/admin/tenants/{id}",
+    params(("id" = Uuid, Path, description = "Tenant ID")),
+    request_body = UpdateTenantRequest,
+    responses(
+        (status = 200, description = "Tenant updated", body = Tenant),
+        (status = 404, description = "Tenant not found")
+    ),
+    tag = "Tenants"
+)]
+pub async fn update_tenant(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(req): Json<UpdateTenantRequest>,
+) -> ApiResult<Json<Tenant>> {
+    if req.name.as_deref().is_some_and(|n| n.trim().is_empty()) {
+        return Err(ApiError::bad_request(
+            "InvalidTenantName",
+            "name must not be empty",
+        ));
+    }
+    validate_quota("quota_contracts", req.quota_contracts)?;
+    validate_quota("quota_publishers", req.quota_publishers)?;
+
+    let tenant: Tenant = sqlx::query_as(&format!(
+        "UPDATE tenants SET
+            name = COALESCE($2, name),
+            hostnames = COALESCE($3, hostnames),
+            quota_contracts = COALESCE($4, quota_contracts),
+            quota_publishers = COALESCE($5, quota_publishers)
+         WHERE id = $1
+         RETURNING {}",
+        TENANT_COLUMNS
+    ))
+    .bind(id)
+    .bind(req.name.as_deref().map(str::trim))
+    .bind(req.hostnames.map(normalize_hostnames))
+    .bind(req.quota_contracts)
+    .bind(req.quota_publishers)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| map_write_err("update tenant", err))?
+    .ok_or_else(|| ApiError::not_found("TenantNotFound", format!("No tenant with ID {}", id)))?;
+
+    tenant::invalidate_cache();
+    Ok(Json(tenant))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slug_validation_matches_migration_check() {
+        assert!(validate_slug("team-a").is_ok());
+        assert!(validate_slug("a1").is_ok());
+        assert!(validate_slug("").is_err());
+        assert!(validate_slug("-team").is_err());
+        assert!(validate_slug("Team").is_err());
+        assert!(validate_slug("team_a").is_err());
+    }
+
+    #[test]
+    fn hostnames_are_normalized_and_deduplicated() {
+        assert_eq!(
+            normalize_hostnames(vec![
+                " Team-A.Registry.io. ".into(),
+                "team-a.registry.io".into(),
+                "".into(),
+            ]),
+            vec!["team-a.registry.io".to_string()]
+        );
+    }
+}
@@ -0,0 +1,285 @@
+//! Signed CI test results
+//!
+//!   POST /api/contracts/:id/test-runs   – submit a signed test run for a build
+//!   GET  /api/contracts/:id/test-runs   – test runs, optionally for one version
+//!
+//! The publisher's CI signs the `shared::test_report` statement for the
+//! build it tested with the publisher's Stellar key (see
+//! `soroban-registry attest-tests`); no bearer token is needed. A run is
+//! attached to the contract version with that build and counts toward the
+//! test component of the quality score (see [`crate::quality`]).
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use shared::test_report::TestReportStatement;
+use uuid::Uuid;
+
+use crate::{
+    badge_handlers::{verify_attestation, MAX_ISSUE_SKEW_MINUTES},
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    patch_handlers::resolve_contract_uuid,
+    publisher_key_handlers::require_usable_account_key,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SubmitTestRunRequest {
+    /// Build that was tested; must belong to one of the contract's versions
+    pub wasm_hash: String,
+    /// Name of the test suite, e.g. "unit"
+    pub suite: String,
+    pub passed: u32,
+    pub failed: u32,
+    #[serde(default)]
+    pub skipped: u32,
+    /// Line coverage in percent
+    pub coverage: Option<f64>,
+    pub run_at: DateTime<Utc>,
+    /// Hex-encoded ed25519 signature of the test report message by the
+    /// publisher's Stellar key
+    pub signature: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListTestRunsQuery {
+    /// Only runs for this version
+    pub version: Option<String>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractTestRun {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub version: String,
+    pub wasm_hash: String,
+    pub suite: String,
+    pub passed: i32,
+    pub failed: i32,
+    pub skipped: i32,
+    pub coverage: Option<f64>,
+    pub run_at: DateTime<Utc>,
+    /// Stellar address that signed the run
+    pub signer_address: String,
+    /// Hex ed25519 signature; verifiable against `signer_address`
+    pub signature: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TestRunTarget {
+    contract_id: String,
+    network: String,
+    publisher_address: Option<String>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+const TEST_RUN_SELECT: &str = "SELECT r.id, r.contract_id, v.version, r.wasm_hash, r.suite,
+            r.passed, r.failed, r.skipped, r.coverage, r.run_at, r.signer_address,
+            r.signature, r.created_at
+       FROM contract_test_runs r
+       JOIN contract_versions v ON v.id = r.contract_version_id";
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidTestRun", message)
+}
+
+/// Coverage as signed and stored: two decimals.
+fn round_coverage(coverage: f64) -> f64 {
+    (coverage * 100.0).round() / 100.0
+}
+
+fn count_column(value: u32, name: &str) -> ApiResult<i32> {
+    i32::try_from(value).map_err(|_| invalid(format!("{} is too large", name)))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    post,
+    path = "/api/contracts/{id}/test-runs",
+    params(("id" = String, Path, description = "Contract UUID or on-chain contract ID")),
+    request_body = SubmitTestRunRequest,
+    responses(
+        (status = 201, description = "Test run recorded", body = ContractTestRun),
+        (status = 400, description = "Malformed test run"),
+        (status = 401, description = "Signature does not match the publisher's key"),
+        (status = 404, description = "Contract not found, or no version has that build"),
+        (status = 409, description = "This run was already submitted"),
+        (status = 422, description = "The publisher has no Stellar key to verify against")
+    ),
+    tag = "Versions"
+)]
+pub async fn submit_test_run(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Json(req): Json<SubmitTestRunRequest>,
+) -> ApiResult<(StatusCode, Json<ContractTestRun>)> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let target: TestRunTarget = sqlx::query_as(
+        "SELECT c.contract_id, c.network::TEXT AS network, p.stellar_address AS publisher_address
+           FROM contracts c
+           LEFT JOIN publishers p ON p.id = c.publisher_id
+          WHERE c.id = $1",
+    )
+    .bind(contract_uuid)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("load test run target", err))?;
+
+    let statement = TestReportStatement {
+        network: &target.network,
+        contract_id: &target.contract_id,
+        wasm_hash: &req.wasm_hash,
+        suite: &req.suite,
+        passed: req.passed,
+        failed: req.failed,
+        skipped: req.skipped,
+        coverage: req.coverage,
+        run_at: req.run_at,
+    };
+    statement.validate().map_err(invalid)?;
+    if req.run_at > Utc::now() + Duration::minutes(MAX_ISSUE_SKEW_MINUTES) {
+        return Err(invalid("run_at is in the future"));
+    }
+    let passed = count_column(req.passed, "passed")?;
+    let failed = count_column(req.failed, "failed")?;
+    let skipped = count_column(req.skipped, "skipped")?;
+
+    let Some(signer) = target.publisher_address else {
+        return Err(ApiError::unprocessable(
+            "NoTestRunSigner",
+            "This contract's publisher has no Stellar key to verify test runs against",
+        ));
+    };
+    verify_attestation(&signer, &statement.signing_message(), &req.signature).map_err(
+        |reason| {
+            tracing::info!(contract = %contract_uuid, reason, "test run rejected");
+            ApiError::unauthorized("Signature does not match the publisher's key")
+        },
+    )?;
+    require_usable_account_key(&state.db, &signer, Utc::now()).await?;
+
+    // Reverts re-use a build, so attach the run to its newest version.
+    let version_id: Uuid = sqlx::query_scalar(
+        "SELECT id FROM contract_versions
+          WHERE contract_id = $1 AND wasm_hash = $2
+          ORDER BY created_at DESC LIMIT 1",
+    )
+    .bind(contract_uuid)
+    .bind(&req.wasm_hash)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch tested version", err))?
+    .ok_or_else(|| {
+        ApiError::not_found(
+            "VersionNotFound",
+            format!("No version of this contract has build {}", req.wasm_hash),
+        )
+    })?;
+
+    let run_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_test_runs
+            (contract_id, contract_version_id, wasm_hash, suite, passed, failed, skipped,
+             coverage, run_at, signer_address, signature)
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+         RETURNING id",
+    )
+    .bind(contract_uuid)
+    .bind(version_id)
+    .bind(&req.wasm_hash)
+    .bind(&req.suite)
+    .bind(passed)
+    .bind(failed)
+    .bind(skipped)
+    .bind(req.coverage.map(round_coverage))
+    .bind(req.run_at)
+    .bind(&signer)
+    .bind(req.signature.trim().to_ascii_lowercase())
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => {
+            ApiError::conflict("TestRunExists", "This test run has already been submitted")
+        }
+        _ => db_internal_error("insert test run", err),
+    })?;
+
+    let run: ContractTestRun = sqlx::query_as(&format!("{} WHERE r.id = $1", TEST_RUN_SELECT))
+        .bind(run_id)
+        .fetch_one(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch test run", err))?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %run.version,
+        suite = %run.suite,
+        passed = run.passed,
+        failed = run.failed,
+        "test run recorded"
+    );
+    crate::quality::queue(&state.db, contract_uuid).await;
+    Ok((StatusCode::CREATED, Json(run)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/test-runs",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        ListTestRunsQuery
+    ),
+    responses(
+        (status = 200, description = "Test runs, newest first", body = [ContractTestRun]),
+        (status = 404, description = "Contract not found")
+    ),
+    tag = "Versions"
+)]
+pub async fn list_test_runs(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<ListTestRunsQuery>,
+) -> ApiResult<Json<Vec<ContractTestRun>>> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let runs = sqlx::query_as(&format!(
+        "{} WHERE r.contract_id = $1 AND ($2::TEXT IS NULL OR v.version = $2)
+          ORDER BY r.run_at DESC, r.created_at DESC",
+        TEST_RUN_SELECT
+    ))
+    .bind(contract_uuid)
+    .bind(&query.version)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list test runs", err))?;
+    Ok(Json(runs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stores_coverage_as_signed() {
+        let signed = format!("{:.2}", 87.456_f64);
+        assert_eq!(round_coverage(87.456).to_string(), signed);
+        assert_eq!(round_coverage(100.0), 100.0);
+    }
+
+    #[test]
+    fn rejects_counts_beyond_the_column() {
+        assert_eq!(count_column(42, "passed").ok(), Some(42));
+        assert!(count_column(u32::MAX, "passed").is_err());
+    }
+}
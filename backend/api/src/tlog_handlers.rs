@@ -0,0 +1,315 @@
+//! Release transparency log
+//!
+//!   GET /api/tlog/head                                     – current tree size and root
+//!   GET /api/tlog/proof?contract_id=&version=&tree_size=   – inclusion proof for a release
+//!   GET /api/tlog/consistency?first=&second=               – proof a tree extends an older one
+//!
+//! Every published version is a leaf of a Merkle tree (see
+//! [`shared::tlog`]); a trigger on `contract_versions` appends it (see the
+//! `release_tlog` migration). Clients check a release's inclusion proof
+//! against a root, and remember that root: a later consistency proof from it
+//! to the current root shows no earlier release was changed or removed.
+//!
+//! Like the change feed, entries are numbered by readers once no running
+//! transaction can add an earlier one, so leaf positions never change. Trees
+//! are rebuilt from the stored leaf hashes on each request.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::tlog::{self, Hash, ReleaseLeaf};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    tenant::Tenant,
+};
+
+/// Advisory lock serialising readers while they number entries.
+const NUMBERING_LOCK: i64 = 0x746c_6f67_6c65_6166;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct TlogHead {
+    pub tree_size: i64,
+    /// Hex SHA-256 root of the tree
+    pub root_hash: String,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TlogProofQuery {
+    /// Contract UUID or on-chain contract ID
+    pub contract_id: String,
+    /// Version to prove; the contract's latest logged release when omitted
+    pub version: Option<String>,
+    /// Prove against the tree of this size rather than the current one
+    pub tree_size: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct TlogConsistencyQuery {
+    /// Size of the older tree
+    pub first: i64,
+    /// Size of the newer tree; the current size when omitted
+    pub second: Option<i64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct TlogEntry {
+    pub leaf_index: i64,
+    /// Registry UUID of the contract
+    pub contract_id: Uuid,
+    /// On-chain contract ID
+    pub contract_address: String,
+    pub network: String,
+    pub name: String,
+    pub version: String,
+    pub wasm_hash: String,
+    /// Publisher's signature over the version, if it was signed
+    pub signature: Option<String>,
+    pub publisher_key: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct InclusionProof {
+    pub entry: TlogEntry,
+    pub leaf_hash: String,
+    pub tree_size: i64,
+    pub root_hash: String,
+    /// Sibling hashes from the leaf up to the root
+    pub proof: Vec<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ConsistencyProof {
+    pub first: i64,
+    pub second: i64,
+    pub root_hash: String,
+    pub proof: Vec<String>,
+}
+
+// ── Tree ──────────────────────────────────────────────────────────────────────
+
+#[derive(sqlx::FromRow)]
+struct PendingLeaf {
+    id: i64,
+    contract_id: Uuid,
+    contract_address: String,
+    network: String,
+    name: String,
+    version: String,
+    wasm_hash: String,
+    signature: Option<String>,
+    publisher_key: Option<String>,
+}
+
+/// Numbers entries no running transaction can precede any more, in
+/// transaction order, and records their leaf hashes.
+async fn number_settled_entries(state: &AppState) -> ApiResult<()> {
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin numbering tlog entries", err))?;
+    sqlx::query("SELECT pg_advisory_xact_lock($1)")
+        .bind(NUMBERING_LOCK)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("lock tlog numbering", err))?;
+
+    let pending: Vec<PendingLeaf> = sqlx::query_as(
+        "SELECT id, contract_id, contract_address, network, name, version, wasm_hash,
+                signature, publisher_key
+         FROM release_tlog
+         WHERE leaf_index IS NULL AND tx_id < pg_snapshot_xmin(pg_current_snapshot())
+         ORDER BY tx_id, id",
+    )
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("fetch unnumbered tlog entries", err))?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+
+    let next: i64 = sqlx::query_scalar("SELECT COALESCE(MAX(leaf_index) + 1, 0) FROM release_tlog")
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("fetch tlog size", err))?;
+    for (offset, leaf) in pending.iter().enumerate() {
+        let contract_id = leaf.contract_id.to_string();
+        let hash = ReleaseLeaf {
+            contract_id: &contract_id,
+            contract_address: &leaf.contract_address,
+            network: &leaf.network,
+            name: &leaf.name,
+            version: &leaf.version,
+            wasm_hash: &leaf.wasm_hash,
+            signature: leaf.signature.as_deref(),
+            publisher_key: leaf.publisher_key.as_deref(),
+        }
+        .hash();
+        sqlx::query("UPDATE release_tlog SET leaf_index = $2, leaf_hash = $3 WHERE id = $1")
+            .bind(leaf.id)
+            .bind(next + offset as i64)
+            .bind(hex::encode(hash))
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("number tlog entry", err))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit numbering tlog entries", err))
+}
+
+/// Leaf hashes of the numbered entries, in order.
+async fn load_leaves(state: &AppState) -> ApiResult<Vec<Hash>> {
+    let hashes: Vec<String> = sqlx::query_scalar(
+        "SELECT leaf_hash FROM release_tlog WHERE leaf_index IS NOT NULL ORDER BY leaf_index",
+    )
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch tlog leaves", err))?;
+    hashes
+        .iter()
+        .map(|hash| {
+            tlog::parse_hash(hash)
+                .ok_or_else(|| ApiError::internal(format!("Malformed tlog leaf hash: {}", hash)))
+        })
+        .collect()
+}
+
+fn tree_size_in_range(size: i64, min: i64, max: usize) -> ApiResult<usize> {
+    if size < min || size as u64 > max as u64 {
+        return Err(ApiError::bad_request(
+            "InvalidTreeSize",
+            format!("tree size must be between {} and {}", min, max),
+        ));
+    }
+    Ok(size as usize)
+}
+
+fn hex_all(hashes: Vec<Hash>) -> Vec<String> {
+    hashes.into_iter().map(hex::encode).collect()
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/tlog/head",
+    responses(
+        (status = 200, description = "Current size and root of the release log", body = TlogHead)
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_tlog_head(State(state): State<AppState>) -> ApiResult<Json<TlogHead>> {
+    number_settled_entries(&state).await?;
+    let leaves = load_leaves(&state).await?;
+    Ok(Json(TlogHead {
+        tree_size: leaves.len() as i64,
+        root_hash: hex::encode(tlog::root(&leaves)),
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tlog/proof",
+    params(TlogProofQuery),
+    responses(
+        (status = 200, description = "The logged release and its inclusion proof", body = InclusionProof),
+        (status = 400, description = "Tree size out of range"),
+        (status = 404, description = "Release not in the log")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_tlog_proof(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Query(query): Query<TlogProofQuery>,
+) -> ApiResult<Json<InclusionProof>> {
+    number_settled_entries(&state).await?;
+
+    // Private contracts' releases are in the tree but not served.
+    let entry: Option<TlogEntry> = sqlx::query_as(
+        "SELECT t.leaf_index, t.contract_id, t.contract_address, t.network, t.name,
+                t.version, t.wasm_hash, t.signature, t.publisher_key, t.created_at
+         FROM release_tlog t
+         LEFT JOIN contracts c ON c.id = t.contract_id
+         WHERE t.leaf_index IS NOT NULL
+           AND (t.contract_id::TEXT = $1 OR t.contract_address = $1)
+           AND ($2::TEXT IS NULL OR t.version = $2)
+           AND (t.tenant_id = $3 OR t.tenant_id IS NULL)
+           AND (c.id IS NULL OR c.visibility = 'public')
+         ORDER BY t.leaf_index DESC
+         LIMIT 1",
+    )
+    .bind(&query.contract_id)
+    .bind(query.version.as_deref())
+    .bind(tenant.id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch tlog entry", err))?;
+    let entry = entry.ok_or_else(|| {
+        ApiError::not_found(
+            "ReleaseNotLogged",
+            match &query.version {
+                Some(version) => format!(
+                    "No logged release {} of {}; new releases appear once their transaction settles",
+                    version, query.contract_id
+                ),
+                None => format!("No logged releases of {}", query.contract_id),
+            },
+        )
+    })?;
+
+    // Loaded after the entry, so the tree always contains it.
+    let leaves = load_leaves(&state).await?;
+    let size = tree_size_in_range(
+        query.tree_size.unwrap_or(leaves.len() as i64),
+        entry.leaf_index + 1,
+        leaves.len(),
+    )?;
+    let tree = &leaves[..size];
+    let index = entry.leaf_index as usize;
+    Ok(Json(InclusionProof {
+        leaf_hash: hex::encode(tree[index]),
+        tree_size: size as i64,
+        root_hash: hex::encode(tlog::root(tree)),
+        proof: hex_all(tlog::inclusion_proof(tree, index)),
+        entry,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/tlog/consistency",
+    params(TlogConsistencyQuery),
+    responses(
+        (status = 200, description = "Proof that the newer tree extends the older one", body = ConsistencyProof),
+        (status = 400, description = "Tree size out of range")
+    ),
+    tag = "Contracts"
+)]
+pub async fn get_tlog_consistency(
+    State(state): State<AppState>,
+    Query(query): Query<TlogConsistencyQuery>,
+) -> ApiResult<Json<ConsistencyProof>> {
+    number_settled_entries(&state).await?;
+    let leaves = load_leaves(&state).await?;
+    let second = tree_size_in_range(query.second.unwrap_or(leaves.len() as i64), 0, leaves.len())?;
+    let first = tree_size_in_range(query.first, 0, second)?;
+    let tree = &leaves[..second];
+    Ok(Json(ConsistencyProof {
+        first: first as i64,
+        second: second as i64,
+        root_hash: hex::encode(tlog::root(tree)),
+        proof: hex_all(tlog::consistency_proof(tree, first)),
+    }))
+}
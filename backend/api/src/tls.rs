@@ -0,0 +1,195 @@
+//! In-process TLS and mutual-TLS ("private registry") access mode
+//!
+//! By default the API speaks plain HTTP and expects TLS to be terminated
+//! upstream. Self-hosted private registries can instead terminate TLS here
+//! and require every client to present a certificate issued by their own CA,
+//! so only provisioned machines can reach the registry at all.
+//!
+//! Configuration via environment variables:
+//! - REGISTRY_ACCESS_MODE: `public` (default) or `mtls`
+//! - TLS_CERT_PATH / TLS_KEY_PATH: PEM server certificate chain and private
+//!   key; setting them enables HTTPS even in `public` mode
+//! - TLS_CLIENT_CA_PATH: PEM bundle of CAs trusted to issue client
+//!   certificates (required for `mtls`)
+//!
+//! Client certificates are verified during the handshake, so unauthenticated
+//! connections never reach the router. Kubernetes probes should therefore use
+//! `tcpSocket` or `exec` checks when running in `mtls` mode.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use anyhow::{anyhow, bail, Context, Result};
+use rustls::pki_types::{CertificateDer, PrivateKeyDer};
+use rustls::server::WebPkiClientVerifier;
+use rustls::{RootCertStore, ServerConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    Public,
+    Mtls,
+}
+
+impl AccessMode {
+    fn parse(raw: Option<&str>) -> Result<Self> {
+        match raw.map(|s| s.trim().to_ascii_lowercase()).as_deref() {
+            None | Some("") | Some("public") => Ok(Self::Public),
+            Some("mtls") => Ok(Self::Mtls),
+            Some(other) => bail!(
+                "Invalid REGISTRY_ACCESS_MODE {:?}: expected `public` or `mtls`",
+                other
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TlsSettings {
+    pub mode: AccessMode,
+    pub cert_path: PathBuf,
+    pub key_path: PathBuf,
+    pub client_ca_path: Option<PathBuf>,
+}
+
+impl TlsSettings {
+    /// `None` when the server should speak plain HTTP.
+    pub fn from_env() -> Result<Option<Self>> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Result<Option<Self>> {
+        let mode = AccessMode::parse(get("REGISTRY_ACCESS_MODE").as_deref())?;
+        let cert = get("TLS_CERT_PATH").map(PathBuf::from);
+        let key = get("TLS_KEY_PATH").map(PathBuf::from);
+        let client_ca = get("TLS_CLIENT_CA_PATH").map(PathBuf::from);
+
+        let (cert_path, key_path) = match (cert, key) {
+            (Some(cert), Some(key)) => (cert, key),
+            (None, None) if mode == AccessMode::Public => return Ok(None),
+            _ => bail!(
+                "TLS_CERT_PATH and TLS_KEY_PATH must both be set{}",
+                if mode == AccessMode::Mtls {
+                    " when REGISTRY_ACCESS_MODE=mtls"
+                } else {
+                    ""
+                }
+            ),
+        };
+        if mode == AccessMode::Mtls && client_ca.is_none() {
+            bail!("REGISTRY_ACCESS_MODE=mtls requires TLS_CLIENT_CA_PATH");
+        }
+
+        Ok(Some(Self {
+            mode,
+            cert_path,
+            key_path,
+            client_ca_path: if mode == AccessMode::Mtls {
+                client_ca
+            } else {
+                None
+            },
+        }))
+    }
+
+    /// Build the rustls server configuration, loading certificates from disk.
+    pub fn server_config(&self) -> Result<ServerConfig> {
+        let provider = Arc::new(rustls::crypto::ring::default_provider());
+        let builder = ServerConfig::builder_with_provider(provider.clone())
+            .with_safe_default_protocol_versions()
+            .context("Unsupported TLS protocol configuration")?;
+
+        let builder = match &self.client_ca_path {
+            Some(ca_path) => {
+                let mut roots = RootCertStore::empty();
+                for cert in load_certs(ca_path)? {
+                    roots
+                        .add(cert)
+                        .with_context(|| format!("Invalid CA certificate in {:?}", ca_path))?;
+                }
+                let verifier =
+                    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+                        .build()
+                        .context("Failed to build client certificate verifier")?;
+                builder.with_client_cert_verifier(verifier)
+            }
+            None => builder.with_no_client_auth(),
+        };
+
+        let mut config = builder
+            .with_single_cert(load_certs(&self.cert_path)?, load_key(&self.key_path)?)
+            .context("Server certificate and key do not match")?;
+        config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+        Ok(config)
+    }
+}
+
+fn load_certs(path: &Path) -> Result<Vec<CertificateDer<'static>>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+    let certs = rustls_pemfile::certs(&mut BufReader::new(file))
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("Failed to parse certificates in {:?}", path))?;
+    if certs.is_empty() {
+        bail!("No certificates found in {:?}", path);
+    }
+    Ok(certs)
+}
+
+fn load_key(path: &Path) -> Result<PrivateKeyDer<'static>> {
+    let file = File::open(path).with_context(|| format!("Failed to open {:?}", path))?;
+    rustls_pemfile::private_key(&mut BufReader::new(file))
+        .with_context(|| format!("Failed to parse private key in {:?}", path))?
+        .ok_or_else(|| anyhow!("No private key found in {:?}", path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn settings(vars: &[(&str, &str)]) -> Result<Option<TlsSettings>> {
+        let map: HashMap<&str, &str> = vars.iter().copied().collect();
+        TlsSettings::from_lookup(|key| map.get(key).map(|v| v.to_string()))
+    }
+
+    #[test]
+    fn plain_http_by_default() {
+        assert!(settings(&[]).unwrap().is_none());
+    }
+
+    #[test]
+    fn public_mode_with_certs_enables_tls_without_client_auth() {
+        let tls = settings(&[("TLS_CERT_PATH", "c.pem"), ("TLS_KEY_PATH", "k.pem")])
+            .unwrap()
+            .unwrap();
+        assert_eq!(tls.mode, AccessMode::Public);
+        assert!(tls.client_ca_path.is_none());
+    }
+
+    #[test]
+    fn mtls_requires_certs_and_client_ca() {
+        assert!(settings(&[("REGISTRY_ACCESS_MODE", "mtls")]).is_err());
+        assert!(settings(&[
+            ("REGISTRY_ACCESS_MODE", "mtls"),
+            ("TLS_CERT_PATH", "c.pem"),
+            ("TLS_KEY_PATH", "k.pem"),
+        ])
+        .is_err());
+        let tls = settings(&[
+            ("REGISTRY_ACCESS_MODE", "MTLS"),
+            ("TLS_CERT_PATH", "c.pem"),
+            ("TLS_KEY_PATH", "k.pem"),
+            ("TLS_CLIENT_CA_PATH", "ca.pem"),
+        ])
+        .unwrap()
+        .unwrap();
+        assert_eq!(tls.mode, AccessMode::Mtls);
+        assert_eq!(tls.client_ca_path, Some(PathBuf::from("ca.pem")));
+    }
+
+    #[test]
+    fn rejects_unknown_mode() {
+        assert!(settings(&[("REGISTRY_ACCESS_MODE", "private")]).is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! Token metadata of SEP-41 contracts
+//!
+//! Tokens built with the Soroban token SDK, and Stellar Asset Contracts,
+//! keep their display metadata in instance storage under `METADATA`: a map
+//! of `name`, `symbol` and `decimal`. Every `TOKEN_META_INTERVAL_SECS`
+//! (default 3600) the reader fetches it over Soroban RPC for contracts in the
+//! token category or conforming to a SEP-41 interface from the catalog, and
+//! records it in `token_metadata` for [`crate::contract_summary_handlers`].
+
+use std::time::Duration;
+
+use shared::Network;
+use sqlx::PgPool;
+use stellar_xdr::curr::{ScMapEntry, ScVal};
+use uuid::Uuid;
+
+use crate::onchain_verification::OnChainVerifier;
+
+const DEFAULT_INTERVAL_SECS: u64 = 3600;
+/// Soroban tokens cannot usefully have more decimals than an i128 holds.
+const MAX_DECIMALS: u32 = 38;
+
+/// Category path of token contracts; its subcategories count too.
+pub const TOKEN_CATEGORY: &str = "token";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetadataValue {
+    Text(String),
+    Number(u32),
+    Other,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TokenMetadata {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: u32,
+}
+
+#[derive(Debug, sqlx::FromRow)]
+struct TokenContract {
+    id: Uuid,
+    contract_id: String,
+    network: Network,
+}
+
+/// Fields of the `METADATA` entry of instance storage, or `None` when there
+/// is no such entry or it is not a map.
+fn metadata_fields(storage: &[ScMapEntry]) -> Option<Vec<(String, MetadataValue)>> {
+    let entry = storage.iter().find(|ScMapEntry { key, .. }| {
+        matches!(key, ScVal::Symbol(symbol) if symbol.0.as_slice() == b"METADATA")
+    })?;
+    let ScVal::Map(Some(map)) = &entry.val else {
+        return None;
+    };
+    Some(
+        map.0
+            .iter()
+            .filter_map(|ScMapEntry { key, val }| {
+                let ScVal::Symbol(key) = key else {
+                    return None;
+                };
+                let value = match val {
+                    ScVal::String(text) => MetadataValue::Text(text.0.to_utf8_string_lossy()),
+                    ScVal::U32(n) => MetadataValue::Number(*n),
+                    _ => MetadataValue::Other,
+                };
+                Some((key.0.to_utf8_string_lossy(), value))
+            })
+            .collect(),
+    )
+}
+
+/// Reads token metadata from the fields of `METADATA`, or `None` when one of
+/// `name`, `symbol` and `decimal` is missing or out of range.
+pub fn read_metadata<I>(fields: I) -> Option<TokenMetadata>
+where
+    I: IntoIterator<Item = (String, MetadataValue)>,
+{
+    let (mut name, mut symbol, mut decimals) = (None, None, None);
+    for (key, value) in fields {
+        match (key.as_str(), value) {
+            ("name", MetadataValue::Text(text)) => name = Some(text),
+            ("symbol", MetadataValue::Text(text)) if !text.trim().is_empty() => symbol = Some(text),
+            ("decimal" | "decimals", MetadataValue::Number(n)) if n <= MAX_DECIMALS => {
+                decimals = Some(n)
+            }
+            _ => {}
+        }
+    }
+    Some(TokenMetadata {
+        name: name?,
+        symbol: symbol?,
+        decimals: decimals?,
+    })
+}
+
+pub fn spawn_token_meta_reader(pool: PgPool) {
+    let interval_secs = std::env::var("TOKEN_META_INTERVAL_SECS")
+        .ok()
+        .and_then(|s| s.parse::<u64>().ok())
+        .filter(|&secs| secs > 0)
+        .unwrap_or(DEFAULT_INTERVAL_SECS);
+
+    tokio::spawn(async move {
+        let verifier = OnChainVerifier::new();
+        let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            interval.tick().await;
+            if let Err(err) = refresh(&pool, &verifier).await {
+                tracing::error!(error = ?err, "token_meta: refresh failed");
+            }
+        }
+    });
+}
+
+async fn refresh(pool: &PgPool, verifier: &OnChainVerifier) -> Result<(), sqlx::Error> {
+    let contracts: Vec<TokenContract> = sqlx::query_as(
+        "SELECT c.id, c.contract_id, c.network
+         FROM contracts c
+         WHERE c.deleted_at IS NULL
+           AND (c.category = $1 OR starts_with(c.category, $1 || '/')
+                OR EXISTS (SELECT 1 FROM contract_interface_matches m
+                           JOIN contract_interfaces i ON i.id = m.interface_id
+                           WHERE m.contract_id = c.id
+                             AND REPLACE(LOWER(i.name), '-', '') LIKE 'sep41%'))",
+    )
+    .bind(TOKEN_CATEGORY)
+    .fetch_all(pool)
+    .await?;
+
+    let mut read = 0;
+    for contract in &contracts {
+        let error = match verifier
+            .fetch_instance_storage(&contract.network, &contract.contract_id)
+            .await
+        {
+            Ok(Some(storage)) => match metadata_fields(&storage).and_then(read_metadata) {
+                Some(metadata) => {
+                    record(pool, contract.id, &metadata).await?;
+                    read += 1;
+                    continue;
+                }
+                None => "instance storage holds no SEP-41 METADATA entry".to_string(),
+            },
+            Ok(None) => "contract instance not found on the ledger".to_string(),
+            Err(err) => err.to_string(),
+        };
+        record_error(pool, contract.id, &error).await?;
+    }
+
+    if read > 0 {
+        tracing::info!(
+            tokens = read,
+            failed = contracts.len() - read,
+            "token_meta: refreshed token metadata"
+        );
+    }
+    Ok(())
+}
+
+async fn record(
+    pool: &PgPool,
+    contract_id: Uuid,
+    metadata: &TokenMetadata,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO token_metadata
+            (contract_id, name, symbol, decimals, refreshed_at, checked_at, last_error)
+         VALUES ($1, $2, $3, $4, NOW(), NOW(), NULL)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET name = EXCLUDED.name,
+                symbol = EXCLUDED.symbol,
+                decimals = EXCLUDED.decimals,
+                refreshed_at = EXCLUDED.refreshed_at,
+                checked_at = EXCLUDED.checked_at,
+                last_error = NULL",
+    )
+    .bind(contract_id)
+    .bind(&metadata.name)
+    .bind(&metadata.symbol)
+    .bind(metadata.decimals as i32)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Keeps the last good reading and notes why this one failed.
+async fn record_error(pool: &PgPool, contract_id: Uuid, error: &str) -> Result<(), sqlx::Error> {
+    tracing::warn!(contract = %contract_id, error, "token_meta: could not read metadata");
+    sqlx::query(
+        "INSERT INTO token_metadata (contract_id, checked_at, last_error)
+         VALUES ($1, NOW(), $2)
+         ON CONFLICT (contract_id) DO UPDATE
+            SET checked_at = NOW(), last_error = EXCLUDED.last_error",
+    )
+    .bind(contract_id)
+    .bind(error)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(entries: &[(&str, MetadataValue)]) -> Vec<(String, MetadataValue)> {
+        entries
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.clone()))
+            .collect()
+    }
+
+    #[test]
+    fn reads_sdk_metadata_map() {
+        let metadata = read_metadata(fields(&[
+            ("decimal", MetadataValue::Number(7)),
+            ("name", MetadataValue::Text("USD Coin".to_string())),
+            ("symbol", MetadataValue::Text("USDC".to_string())),
+        ]));
+        assert_eq!(
+            metadata,
+            Some(TokenMetadata {
+                name: "USD Coin".to_string(),
+                symbol: "USDC".to_string(),
+                decimals: 7,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_or_implausible_metadata() {
+        assert_eq!(
+            read_metadata(fields(&[
+                ("name", MetadataValue::Text("Token".to_string())),
+                ("symbol", MetadataValue::Text("TKN".to_string())),
+            ])),
+            None
+        );
+        assert_eq!(
+            read_metadata(fields(&[
+                ("decimal", MetadataValue::Number(255)),
+                ("name", MetadataValue::Text("Token".to_string())),
+                ("symbol", MetadataValue::Text("TKN".to_string())),
+            ])),
+            None
+        );
+        assert_eq!(
+            read_metadata(fields(&[
+                ("decimal", MetadataValue::Other),
+                ("name", MetadataValue::Text("Token".to_string())),
+                ("symbol", MetadataValue::Text(" ".to_string())),
+            ])),
+            None
+        );
+    }
+}
@@ -0,0 +1,342 @@
+//! Pinned toolchain catalog
+//!
+//!   GET  /api/toolchains                          – approved builder toolchains
+//!   GET  /api/toolchains/:name                    – one toolchain
+//!   POST /api/admin/toolchains                    – add a toolchain
+//!   POST /api/admin/toolchains/:name/deprecate    – hide it from the catalog
+//!
+//! A toolchain pins a digest-addressed builder image together with the Rust
+//! and soroban-sdk versions and the cargo target/flags it builds with.
+//! Verification requests (and `Registry.toml`'s `[build] toolchain`) select
+//! one by name, so the same source rebuilds identically even after newer
+//! toolchains are added. Deprecated toolchains are left out of the listing
+//! unless asked for but remain selectable, so old builds stay reproducible.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const DEFAULT_TARGET: &str = "wasm32-unknown-unknown";
+
+/// Cargo flags the verifier sets itself or that would escape the build
+/// project; catalog entries may not override them. Unstable `-Z` flags are
+/// rejected as well.
+const RESERVED_FLAGS: &[&str] = &["--target", "--target-dir", "--manifest-path", "--config"];
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct Toolchain {
+    pub id: Uuid,
+    pub name: String,
+    pub rust_version: String,
+    pub soroban_sdk_version: String,
+    /// Builder image pinned by digest, `image@sha256:…`
+    pub image: String,
+    pub target: String,
+    pub cargo_flags: Vec<String>,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub deprecated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ListToolchainsQuery {
+    /// Include deprecated toolchains (default false)
+    pub include_deprecated: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct CreateToolchainRequest {
+    /// Catalog name, e.g. `soroban-21.7-rust-1.81`
+    pub name: String,
+    pub rust_version: String,
+    pub soroban_sdk_version: String,
+    pub image: String,
+    /// Defaults to `wasm32-unknown-unknown`
+    pub target: Option<String>,
+    #[serde(default)]
+    pub cargo_flags: Vec<String>,
+    pub description: Option<String>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidToolchain", message)
+}
+
+fn is_version(value: &str) -> bool {
+    !value.is_empty()
+        && value.split('.').count() <= 3
+        && value
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+fn validate_request(req: &CreateToolchainRequest) -> ApiResult<()> {
+    let name_ok = !req.name.is_empty()
+        && req.name.len() <= 64
+        && req
+            .name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || "-_.".contains(c));
+    if !name_ok {
+        return Err(invalid(
+            "name must be 1-64 lowercase letters, digits, '-', '_' or '.'",
+        ));
+    }
+    if !is_version(&req.rust_version) {
+        return Err(invalid("rust_version must look like 1.81 or 1.81.0"));
+    }
+    if !is_version(&req.soroban_sdk_version) {
+        return Err(invalid(
+            "soroban_sdk_version must be an exact version, e.g. 21.7.7",
+        ));
+    }
+
+    let pinned = matches!(req.image.split_once("@sha256:"), Some((repo, hash))
+        if !repo.is_empty()
+            && hash.len() == 64
+            && hash.chars().all(|c| c.is_ascii_hexdigit()));
+    if !pinned {
+        return Err(invalid(
+            "image must be pinned by digest: <image>@sha256:<64 hex>",
+        ));
+    }
+
+    if let Some(target) = &req.target {
+        if target.is_empty() || target.contains(char::is_whitespace) {
+            return Err(invalid("target must be a single target triple"));
+        }
+    }
+    for flag in &req.cargo_flags {
+        if !flag.starts_with('-') || flag.contains(char::is_whitespace) {
+            return Err(invalid(format!(
+                "cargo_flags entries must be single flags like --locked, not '{}'",
+                flag
+            )));
+        }
+        let name = flag.split('=').next().unwrap_or(flag);
+        if name.starts_with("-Z") || RESERVED_FLAGS.contains(&name) {
+            return Err(invalid(format!(
+                "cargo flag '{}' cannot be set by a toolchain",
+                name
+            )));
+        }
+    }
+    Ok(())
+}
+
+async fn fetch_toolchain(state: &AppState, name: &str) -> ApiResult<Option<Toolchain>> {
+    sqlx::query_as("SELECT * FROM toolchains WHERE name = $1")
+        .bind(name)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch toolchain", err))
+}
+
+/// Resolves a toolchain selected in a verification request. Deprecated
+/// toolchains are accepted so earlier builds can be reproduced.
+pub(crate) async fn resolve_toolchain(
+    state: &AppState,
+    name: &str,
+) -> ApiResult<(Uuid, verifier::Toolchain)> {
+    let toolchain = fetch_toolchain(state, name).await?.ok_or_else(|| {
+        ApiError::bad_request(
+            "UnknownToolchain",
+            format!(
+                "No toolchain named '{}' in the catalog (see GET /api/toolchains)",
+                name
+            ),
+        )
+    })?;
+    Ok((
+        toolchain.id,
+        verifier::Toolchain {
+            image: toolchain.image,
+            soroban_sdk_version: toolchain.soroban_sdk_version,
+            target: toolchain.target,
+            cargo_flags: toolchain.cargo_flags,
+        },
+    ))
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/toolchains",
+    params(ListToolchainsQuery),
+    responses(
+        (status = 200, description = "Approved toolchains, newest SDK first", body = [Toolchain])
+    ),
+    tag = "Verification"
+)]
+pub async fn list_toolchains(
+    State(state): State<AppState>,
+    Query(query): Query<ListToolchainsQuery>,
+) -> ApiResult<Json<Vec<Toolchain>>> {
+    let toolchains = sqlx::query_as(
+        "SELECT * FROM toolchains
+         WHERE $1 OR deprecated_at IS NULL
+         ORDER BY string_to_array(soroban_sdk_version, '.')::INT[] DESC,
+                  string_to_array(rust_version, '.')::INT[] DESC, name",
+    )
+    .bind(query.include_deprecated.unwrap_or(false))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list toolchains", err))?;
+    Ok(Json(toolchains))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/toolchains/{name}",
+    params(("name" = String, Path, description = "Toolchain name")),
+    responses(
+        (status = 200, description = "The toolchain", body = Toolchain),
+        (status = 404, description = "No toolchain with that name")
+    ),
+    tag = "Verification"
+)]
+pub async fn get_toolchain(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<Toolchain>> {
+    fetch_toolchain(&state, &name)
+        .await?
+        .map(Json)
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "ToolchainNotFound",
+                format!("No toolchain named '{}'", name),
+            )
+        })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/toolchains",
+    request_body = CreateToolchainRequest,
+    responses(
+        (status = 201, description = "Toolchain added", body = Toolchain),
+        (status = 400, description = "Invalid toolchain definition"),
+        (status = 409, description = "A toolchain with that name exists")
+    ),
+    tag = "Verification"
+)]
+pub async fn create_toolchain(
+    State(state): State<AppState>,
+    Json(req): Json<CreateToolchainRequest>,
+) -> ApiResult<(StatusCode, Json<Toolchain>)> {
+    validate_request(&req)?;
+
+    let toolchain = sqlx::query_as(
+        "INSERT INTO toolchains
+            (name, rust_version, soroban_sdk_version, image, target, cargo_flags, description)
+         VALUES ($1, $2, $3, $4, $5, $6, $7)
+         RETURNING *",
+    )
+    .bind(&req.name)
+    .bind(&req.rust_version)
+    .bind(&req.soroban_sdk_version)
+    .bind(&req.image)
+    .bind(req.target.as_deref().unwrap_or(DEFAULT_TARGET))
+    .bind(&req.cargo_flags)
+    .bind(&req.description)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "ToolchainExists",
+            format!("A toolchain named '{}' already exists", req.name),
+        ),
+        _ => db_internal_error("create toolchain", err),
+    })?;
+
+    tracing::info!(toolchain = %req.name, image = %req.image, "toolchain added to catalog");
+    Ok((StatusCode::CREATED, Json(toolchain)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/toolchains/{name}/deprecate",
+    params(("name" = String, Path, description = "Toolchain name")),
+    responses(
+        (status = 200, description = "Toolchain deprecated", body = Toolchain),
+        (status = 404, description = "No toolchain with that name")
+    ),
+    tag = "Verification"
+)]
+pub async fn deprecate_toolchain(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> ApiResult<Json<Toolchain>> {
+    let toolchain: Option<Toolchain> = sqlx::query_as(
+        "UPDATE toolchains SET deprecated_at = COALESCE(deprecated_at, NOW())
+         WHERE name = $1
+         RETURNING *",
+    )
+    .bind(&name)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("deprecate toolchain", err))?;
+
+    toolchain.map(Json).ok_or_else(|| {
+        ApiError::not_found(
+            "ToolchainNotFound",
+            format!("No toolchain named '{}'", name),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(image: &str, cargo_flags: &[&str]) -> CreateToolchainRequest {
+        CreateToolchainRequest {
+            name: "soroban-21.7-rust-1.81".to_string(),
+            rust_version: "1.81".to_string(),
+            soroban_sdk_version: "21.7.7".to_string(),
+            image: image.to_string(),
+            target: None,
+            cargo_flags: cargo_flags.iter().map(|f| f.to_string()).collect(),
+            description: None,
+        }
+    }
+
+    fn pinned() -> String {
+        format!("registry/soroban-build@sha256:{}", "a".repeat(64))
+    }
+
+    #[test]
+    fn accepts_a_digest_pinned_toolchain() {
+        assert!(validate_request(&request(&pinned(), &["--locked"])).is_ok());
+    }
+
+    #[test]
+    fn rejects_tags_and_short_digests() {
+        assert!(validate_request(&request("registry/soroban-build:21", &[])).is_err());
+        assert!(validate_request(&request("registry/soroban-build@sha256:abc", &[])).is_err());
+    }
+
+    #[test]
+    fn rejects_reserved_or_malformed_flags() {
+        assert!(validate_request(&request(&pinned(), &["--target-dir=/tmp"])).is_err());
+        assert!(validate_request(&request(&pinned(), &["-Zbuild-std"])).is_err());
+        assert!(validate_request(&request(&pinned(), &["locked"])).is_err());
+    }
+}
@@ -0,0 +1,330 @@
+//! Grace period for destructive contract actions
+//!
+//! Yanking a version, deprecating a contract and deleting one are not
+//! committed when requested. The handler checks the request and queues a
+//! `deferred_contract_action` job to run `UNDO_GRACE_SECONDS` (default 60)
+//! later; until a worker picks it up, the caller can take it back with
+//! `POST /api/me/undo` (`soroban-registry undo`), which removes the job from
+//! the queue. See [`crate::undo_handlers`].
+//!
+//! Asking again for a yank or deletion you already have waiting returns the
+//! queued action instead of queuing it twice. Another account asking for the
+//! same one queues its own, which only it can undo.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    deprecation_handlers,
+    job_queue::{self, Job, JobHandler},
+    soft_delete_handlers, version_yank_handlers,
+};
+
+pub const DEFERRED_ACTION_JOB_KIND: &str = "deferred_contract_action";
+const DEFAULT_GRACE_SECONDS: i64 = 60;
+
+pub fn grace_seconds() -> i64 {
+    std::env::var("UNDO_GRACE_SECONDS")
+        .ok()
+        .and_then(|v| v.parse::<i64>().ok())
+        .filter(|s| *s >= 0)
+        .unwrap_or(DEFAULT_GRACE_SECONDS)
+}
+
+/// A destructive action waiting out its grace period. Contracts are stored
+/// by UUID.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DeferredAction {
+    Yank {
+        contract_id: Uuid,
+        version: String,
+        reason: Option<String>,
+    },
+    Deprecate {
+        contract_id: Uuid,
+        retirement_at: DateTime<Utc>,
+        replacement_contract_id: Option<Uuid>,
+        migration_guide_url: Option<String>,
+        notes: Option<String>,
+    },
+    Delete {
+        contract_id: Uuid,
+    },
+}
+
+impl DeferredAction {
+    /// The part of the job payload naming what the action applies to, for
+    /// yanks and deletions.
+    fn target(&self) -> Option<serde_json::Value> {
+        match self {
+            Self::Yank {
+                contract_id,
+                version,
+                ..
+            } => Some(json!({ "type": "yank", "contract_id": contract_id, "version": version })),
+            Self::Delete { contract_id } => {
+                Some(json!({ "type": "delete", "contract_id": contract_id }))
+            }
+            // A later deprecation replaces the schedule of an earlier one.
+            Self::Deprecate { .. } => None,
+        }
+    }
+
+    /// The part of the job payload that identifies a duplicate request by
+    /// `actor`, for actions each account only queues once at a time.
+    fn identity(&self, actor: &str) -> Option<serde_json::Value> {
+        self.target()
+            .map(|action| json!({ "actor": actor, "action": action }))
+    }
+}
+
+/// A queued action as the API reports it.
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct PendingAction {
+    /// ID of the job that will apply the action
+    pub id: Uuid,
+    pub action: DeferredAction,
+    /// Account that requested the action; only it can undo it
+    pub requested_by: String,
+    pub requested_at: DateTime<Utc>,
+    /// When the action is committed, after which it can no longer be undone
+    pub apply_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DeferredPayload {
+    actor: String,
+    action: DeferredAction,
+}
+
+/// Job row as read back for [`PendingAction`].
+#[derive(Debug, sqlx::FromRow)]
+pub(crate) struct DeferredJobRow {
+    id: Uuid,
+    payload: serde_json::Value,
+    run_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<DeferredJobRow> for PendingAction {
+    type Error = serde_json::Error;
+
+    fn try_from(row: DeferredJobRow) -> Result<Self, Self::Error> {
+        let payload: DeferredPayload = serde_json::from_value(row.payload)?;
+        Ok(Self {
+            id: row.id,
+            action: payload.action,
+            requested_by: payload.actor,
+            requested_at: row.created_at,
+            apply_at: row.run_at,
+        })
+    }
+}
+
+/// Queue `action` on behalf of `actor` to be applied once the grace period
+/// has passed. A yank or deletion `actor` already has waiting for the same
+/// target is returned as is.
+///
+/// Applying a yank or deletion twice changes nothing, so a second account's
+/// request gets a job of its own that it can list and undo.
+pub async fn defer(
+    pool: &PgPool,
+    actor: &str,
+    action: DeferredAction,
+) -> Result<PendingAction, sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    if let Some(identity) = action.identity(actor) {
+        // Serializes requests for the same target, so two of them cannot
+        // both find nothing queued.
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtext($1))")
+            .bind(identity.to_string())
+            .execute(&mut *tx)
+            .await?;
+        let queued: Option<DeferredJobRow> = sqlx::query_as(
+            "SELECT id, payload, run_at, created_at FROM background_jobs
+             WHERE kind = $1 AND status = 'queued' AND run_at > NOW() AND payload @> $2
+             ORDER BY created_at
+             LIMIT 1",
+        )
+        .bind(DEFERRED_ACTION_JOB_KIND)
+        .bind(&identity)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if let Some(row) = queued {
+            return PendingAction::try_from(row).map_err(|err| sqlx::Error::Decode(err.into()));
+        }
+    }
+
+    let requested_at = Utc::now();
+    let apply_at = requested_at + chrono::Duration::seconds(grace_seconds());
+    let payload = DeferredPayload {
+        actor: actor.to_string(),
+        action,
+    };
+    let id = job_queue::enqueue_at(
+        &mut *tx,
+        DEFERRED_ACTION_JOB_KIND,
+        serde_json::to_value(&payload).expect("deferred action serializes"),
+        apply_at,
+        job_queue::DEFAULT_MAX_ATTEMPTS,
+    )
+    .await?;
+    tx.commit().await?;
+    Ok(PendingAction {
+        id,
+        action: payload.action,
+        requested_by: payload.actor,
+        requested_at,
+        apply_at,
+    })
+}
+
+/// Drop every queued job that would apply `action`, whoever requested it.
+/// Called when the action is reversed directly (an unyank or a restore), so
+/// the grace period running out does not apply it again afterwards.
+pub async fn cancel_queued(pool: &PgPool, action: &DeferredAction) -> Result<u64, sqlx::Error> {
+    let Some(target) = action.target() else {
+        return Ok(0);
+    };
+    let cancelled = sqlx::query(
+        "DELETE FROM background_jobs
+         WHERE kind = $1 AND status = 'queued' AND payload @> $2",
+    )
+    .bind(DEFERRED_ACTION_JOB_KIND)
+    .bind(json!({ "action": target }))
+    .execute(pool)
+    .await?;
+    Ok(cancelled.rows_affected())
+}
+
+pub struct DeferredActionJob;
+
+#[async_trait]
+impl JobHandler for DeferredActionJob {
+    async fn handle(&self, pool: &PgPool, job: &Job) -> anyhow::Result<()> {
+        let payload: DeferredPayload = serde_json::from_value(job.payload.clone())?;
+        match &payload.action {
+            DeferredAction::Yank {
+                contract_id,
+                version,
+                reason,
+            } => {
+                version_yank_handlers::set_yank(
+                    pool,
+                    *contract_id,
+                    version,
+                    true,
+                    reason.as_deref(),
+                )
+                .await
+                .map_err(|err| anyhow::anyhow!("{}", err))?;
+            }
+            DeferredAction::Deprecate {
+                contract_id,
+                retirement_at,
+                replacement_contract_id,
+                migration_guide_url,
+                notes,
+            } => {
+                deprecation_handlers::apply_deprecation(
+                    pool,
+                    *contract_id,
+                    *retirement_at,
+                    *replacement_contract_id,
+                    migration_guide_url.as_deref(),
+                    notes.as_deref(),
+                )
+                .await
+                .map_err(|err| anyhow::anyhow!("{}", err))?;
+            }
+            DeferredAction::Delete { contract_id } => {
+                // Deleted some other way in the meantime: nothing left to do.
+                soft_delete_handlers::soft_delete_contract(pool, *contract_id).await?;
+            }
+        }
+        tracing::info!(
+            job_id = %job.id,
+            actor = %payload.actor,
+            action = ?payload.action,
+            "deferred contract action applied"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identity_is_contained_in_the_job_payload() {
+        let action = DeferredAction::Yank {
+            contract_id: Uuid::new_v4(),
+            version: "1.2.0".to_string(),
+            reason: Some("broken".to_string()),
+        };
+        let identity = action.identity("GACTOR").unwrap();
+        let other = action.identity("GOTHER").unwrap();
+        let payload = serde_json::to_value(DeferredPayload {
+            actor: "GACTOR".to_string(),
+            action,
+        })
+        .unwrap();
+        assert_eq!(payload["actor"], identity["actor"]);
+        for (key, value) in identity["action"].as_object().unwrap() {
+            assert_eq!(&payload["action"][key], value, "{}", key);
+        }
+        // Another account's request for the same yank does not match the job.
+        assert_ne!(payload["actor"], other["actor"]);
+        // Cancelling matches the target alone, which is the same for both.
+        assert_eq!(identity["action"], other["action"]);
+
+        let deprecate = DeferredAction::Deprecate {
+            contract_id: Uuid::new_v4(),
+            retirement_at: Utc::now(),
+            replacement_contract_id: None,
+            migration_guide_url: None,
+            notes: None,
+        };
+        assert!(deprecate.identity("GACTOR").is_none());
+    }
+
+    #[test]
+    fn job_row_reads_back_as_the_pending_action() {
+        let contract_id = Uuid::new_v4();
+        let payload = DeferredPayload {
+            actor: "GACTOR".to_string(),
+            action: DeferredAction::Yank {
+                contract_id,
+                version: "1.2.0".to_string(),
+                reason: Some("CVE-2026-0001".to_string()),
+            },
+        };
+        let payload = serde_json::to_value(&payload).unwrap();
+        assert_eq!(payload["action"]["type"], "yank");
+
+        let now = Utc::now();
+        let pending = PendingAction::try_from(DeferredJobRow {
+            id: Uuid::new_v4(),
+            payload,
+            run_at: now + chrono::Duration::seconds(60),
+            created_at: now,
+        })
+        .unwrap();
+        assert_eq!(pending.requested_by, "GACTOR");
+        assert_eq!(
+            pending.apply_at - pending.requested_at,
+            chrono::Duration::seconds(60)
+        );
+        assert!(matches!(
+            pending.action,
+            DeferredAction::Yank { contract_id: id, ref version, .. }
+                if id == contract_id && version == "1.2.0"
+        ));
+    }
+}
@@ -0,0 +1,107 @@
+//! Taking back destructive contract actions
+//!
+//!   GET  /api/me/pending-actions – the caller's actions still in their grace period
+//!   POST /api/me/undo            – cancel the caller's most recent one
+//!
+//! Undoing removes the queued job in the same statement that finds it, and
+//! skips a job a worker has already claimed, so an action is either undone
+//! or applied, never both. See [`crate::undo`].
+
+use axum::{extract::State, Json};
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+    undo::{DeferredJobRow, PendingAction, DEFERRED_ACTION_JOB_KIND},
+};
+
+fn to_pending(row: DeferredJobRow) -> ApiResult<PendingAction> {
+    PendingAction::try_from(row).map_err(|err| {
+        tracing::error!(error = ?err, "unreadable deferred action payload");
+        ApiError::internal("Failed to read a pending action")
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/me/pending-actions",
+    responses(
+        (status = 200, description = "The caller's pending actions, newest first", body = [PendingAction]),
+        (status = 401, description = "Missing or invalid bearer token")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Maintenance"
+)]
+pub async fn list_pending_actions(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+) -> ApiResult<Json<Vec<PendingAction>>> {
+    let rows: Vec<DeferredJobRow> = sqlx::query_as(
+        "SELECT id, payload, run_at, created_at FROM background_jobs
+         WHERE kind = $1 AND status = 'queued' AND run_at > NOW()
+           AND payload->>'actor' = $2
+         ORDER BY created_at DESC",
+    )
+    .bind(DEFERRED_ACTION_JOB_KIND)
+    .bind(&claims.sub)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("list pending actions", err))?;
+
+    Ok(Json(
+        rows.into_iter().map(to_pending).collect::<ApiResult<_>>()?,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/me/undo",
+    responses(
+        (status = 200, description = "The action that was cancelled", body = PendingAction),
+        (status = 401, description = "Missing or invalid bearer token"),
+        (status = 404, description = "No action left to undo")
+    ),
+    security(("bearer_auth" = [])),
+    tag = "Maintenance"
+)]
+pub async fn undo_last_action(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+) -> ApiResult<Json<PendingAction>> {
+    let row: Option<DeferredJobRow> = sqlx::query_as(
+        "DELETE FROM background_jobs
+         WHERE id = (
+             SELECT id FROM background_jobs
+             WHERE kind = $1 AND status = 'queued' AND run_at > NOW()
+               AND payload->>'actor' = $2
+             ORDER BY created_at DESC
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED
+         )
+         RETURNING id, payload, run_at, created_at",
+    )
+    .bind(DEFERRED_ACTION_JOB_KIND)
+    .bind(&claims.sub)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("undo pending action", err))?;
+
+    let row = row.ok_or_else(|| {
+        ApiError::not_found(
+            "NothingToUndo",
+            "No yank, deprecation or deletion of yours is still in its grace period",
+        )
+    })?;
+    let pending = to_pending(row)?;
+    tracing::info!(
+        job_id = %pending.id,
+        actor = %pending.requested_by,
+        action = ?pending.action,
+        "deferred contract action undone"
+    );
+    Ok(Json(pending))
+}
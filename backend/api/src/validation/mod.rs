@@ -69,6 +69,7 @@ pub mod payload_size;
 pub mod requests;
 #[allow(dead_code)]
 pub mod sanitizers;
+pub mod source_content;
 #[allow(dead_code)]
 pub mod url_validation;
 #[allow(dead_code)]
@@ -98,7 +99,7 @@ pub use url_validation::{
 };
 #[allow(unused_imports)]
 pub use validators::{
-    validate_category_whitelist, validate_contract_id, validate_length, validate_name_format,
+    validate_contract_id, validate_length, validate_name_format,
     validate_network_config_versions, validate_no_html, validate_no_xss, validate_required,
     validate_semver, validate_source_code_size, validate_stellar_address,
     validate_stellar_address_optional, validate_tags, validate_url, validate_url_optional,
@@ -3,8 +3,15 @@
 //! This middleware enforces maximum request body size limits to prevent
 //! denial-of-service attacks and consume excessive resources.
 //!
+//! Routes that take uploads (base64-encoded WASM binaries, source archives
+//! and audit reports) get a larger limit than plain JSON routes. The limits
+//! are checked against Content-Length here; bodies sent without one are cut
+//! off at the same limits by `DefaultBodyLimit` when they are read.
+//!
 //! Configuration via environment variables:
-//! - MAX_PAYLOAD_SIZE_MB: Maximum payload size in MB (default: 5)
+//! - MAX_PAYLOAD_SIZE_MB: Maximum JSON payload size in MB (default: 5)
+//! - MAX_UPLOAD_SIZE_MB: Maximum payload size for upload routes in MB
+//!   (default: 32)
 
 use axum::{
     body::Body,
@@ -18,21 +25,50 @@ use std::net::SocketAddr;
 use crate::error::ApiError;
 
 const DEFAULT_MAX_PAYLOAD_MB: u64 = 5;
+const DEFAULT_MAX_UPLOAD_MB: u64 = 32;
 const HEADER_CONTENT_LENGTH: &str = "content-length";
 
-/// Get configured max payload size in bytes
-pub fn get_max_payload_bytes() -> u64 {
-    let env_mb = std::env::var("MAX_PAYLOAD_SIZE_MB")
+/// Routes that enforce their own (larger) body limit via `DefaultBodyLimit`.
+const EXEMPT_PATHS: &[&str] = &["/api/admin/restore"];
+
+/// Routes that accept uploads and get the upload limit.
+const UPLOAD_PATHS: &[&str] = &[
+    "/api/contracts/:id/versions/:version/source",
+    "/api/contracts/:id/audits",
+    "/api/contracts/simulate-deploy",
+];
+
+fn env_mb(key: &str, default_mb: u64) -> u64 {
+    let env_mb = std::env::var(key)
         .ok()
         .and_then(|v| v.parse::<u64>().ok())
-        .unwrap_or(DEFAULT_MAX_PAYLOAD_MB);
+        .unwrap_or(default_mb);
 
     env_mb * 1024 * 1024
 }
 
+/// Get configured max payload size in bytes
+pub fn get_max_payload_bytes() -> u64 {
+    env_mb("MAX_PAYLOAD_SIZE_MB", DEFAULT_MAX_PAYLOAD_MB)
+}
+
+/// Get configured max payload size for upload routes in bytes
+pub fn get_max_upload_bytes() -> u64 {
+    env_mb("MAX_UPLOAD_SIZE_MB", DEFAULT_MAX_UPLOAD_MB)
+}
+
+/// The body limit for the route matched as `path`.
+pub fn max_bytes_for(path: Option<&str>) -> u64 {
+    if path.is_some_and(|p| UPLOAD_PATHS.contains(&p)) {
+        get_max_upload_bytes()
+    } else {
+        get_max_payload_bytes()
+    }
+}
+
 /// Middleware that validates request payload size
 ///
-/// Returns 413 Payload Too Large if the request body exceeds the configured limit.
+/// Returns 413 Payload Too Large if the request body exceeds the route's limit.
 /// The limit is checked via the Content-Length header when available.
 pub async fn payload_size_validation_middleware(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
@@ -40,7 +76,14 @@ pub async fn payload_size_validation_middleware(
     req: Request<Body>,
     next: Next,
 ) -> Result<Response, Response> {
-    let max_bytes = get_max_payload_bytes();
+    if matched_path
+        .as_ref()
+        .is_some_and(|p| EXEMPT_PATHS.contains(&p.as_str()))
+    {
+        return Ok(next.run(req).await);
+    }
+
+    let max_bytes = max_bytes_for(matched_path.as_ref().map(|p| p.as_str()));
     let request_id = crate::request_tracing::get_or_create_request_id(&req);
 
     // Check Content-Length header
@@ -98,4 +141,17 @@ mod tests {
         let max = get_max_payload_bytes();
         assert_eq!(max, 5 * 1024 * 1024);
     }
+
+    #[test]
+    fn test_upload_routes_get_the_upload_limit() {
+        assert_eq!(
+            max_bytes_for(Some("/api/contracts/:id/versions/:version/source")),
+            32 * 1024 * 1024
+        );
+        assert_eq!(
+            max_bytes_for(Some("/api/contracts/:id")),
+            get_max_payload_bytes()
+        );
+        assert_eq!(max_bytes_for(None), get_max_payload_bytes());
+    }
 }
@@ -15,10 +15,12 @@ use super::sanitizers::{
     normalize_contract_id, normalize_stellar_address, sanitize_description_optional, sanitize_name,
     sanitize_tags, sanitize_url_optional, trim,
 };
+use super::url_validation::validate_https_url_only;
 use super::validators::{
-    validate_category_whitelist, validate_contract_id, validate_json_depth, validate_length,
-    validate_name_format, validate_no_xss, validate_semver, validate_source_code_size,
-    validate_stellar_address, validate_tags, validate_url_optional, validate_wasm_hash,
+    validate_contract_id, validate_email, validate_function_name, validate_json_depth,
+    validate_length, validate_name_format, validate_no_xss, validate_semver,
+    validate_source_code_size, validate_stellar_address, validate_tags, validate_url_optional,
+    validate_wasm_hash,
 };
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -39,14 +41,9 @@ const MAX_TAG_LENGTH: usize = 50;
 const MAX_SOURCE_CODE_BYTES: usize = 1024 * 1024;
 /// Maximum JSON nesting depth
 const MAX_JSON_DEPTH: usize = 10;
-/// Allowed categories for contracts.
-///
-/// TODO(issue #414): Replace this static whitelist with a live database lookup
-/// against the `contract_categories` table once the category management system
-/// is fully integrated.  The seeded default categories below match the rows
-/// inserted by migration 051_contract_categories.sql, so existing contract data
-/// continues to validate correctly in the meantime.
-const ALLOWED_CATEGORIES: &[&str] = &["DEX", "Lending", "Bridge", "Oracle", "Token", "Other"];
+/// Maximum length for a category path or name; the handler resolves it
+/// against the `contract_categories` taxonomy.
+const MAX_CATEGORY_LENGTH: usize = 100;
 /// Maximum length for dependency name
 const MAX_DEPENDENCY_NAME_LENGTH: usize = 255;
 /// Maximum length for version constraint
@@ -111,9 +108,7 @@ impl Validatable for PublishRequest {
         builder.check("source_url", || validate_url_optional(&self.source_url));
 
         if let Some(ref cat) = self.category {
-            builder.check("category", || {
-                validate_category_whitelist(cat, ALLOWED_CATEGORIES)
-            });
+            builder.check("category", || validate_length(cat, 1, MAX_CATEGORY_LENGTH));
             builder.check("category", || validate_no_xss(cat));
         }
 
@@ -151,6 +146,7 @@ impl Validatable for VerifyRequest {
     fn sanitize(&mut self) {
         self.contract_id = normalize_contract_id(&self.contract_id);
         self.compiler_version = trim(&self.compiler_version);
+        super::sanitizers::trim_optional(&mut self.toolchain);
         self.source_code = super::sanitizers::sanitize_source_code(&self.source_code);
         super::sanitizers::sanitize_json_value(&mut self.build_params);
     }
@@ -169,7 +165,10 @@ impl Validatable for VerifyRequest {
 
         builder.check("compiler_version", || {
             if self.compiler_version.is_empty() {
-                return Err("compiler_version is required".to_string());
+                if self.toolchain.is_some() {
+                    return Ok(());
+                }
+                return Err("compiler_version is required unless a toolchain is given".to_string());
             }
             validate_semver(&self.compiler_version)
         });
@@ -262,12 +261,31 @@ impl Validatable for CreateMigrationRequest {
     fn sanitize(&mut self) {
         self.contract_id = normalize_contract_id(&self.contract_id);
         self.wasm_hash = trim(&self.wasm_hash);
+        if let Some(ref mut hook) = self.post_upgrade {
+            hook.function = trim(&hook.function);
+        }
+        if let Some(ref mut proposer) = self.proposer {
+            *proposer = trim(proposer);
+        }
     }
 
     fn validate(&self) -> Result<(), Vec<FieldError>> {
         let mut builder = ValidationBuilder::new();
         builder.check("contract_id", || validate_contract_id(&self.contract_id));
         builder.check("wasm_hash", || validate_wasm_hash(&self.wasm_hash));
+        if let Some(ref hook) = self.post_upgrade {
+            builder.check("post_upgrade.function", || {
+                validate_function_name(&hook.function)
+            });
+            if let Some(ref args) = hook.args {
+                builder.check("post_upgrade.args", || match args {
+                    serde_json::Value::Object(_) | serde_json::Value::Null => {
+                        validate_json_depth(args, MAX_JSON_DEPTH)
+                    }
+                    _ => Err("must be an object of named arguments".to_string()),
+                });
+            }
+        }
         builder.build()
     }
 }
@@ -294,6 +312,12 @@ impl Validatable for CreateContractVersionRequest {
         if let Some(ref mut p) = self.publisher_key {
             *p = trim(p);
         }
+        if let Some(ref mut l) = self.license {
+            *l = trim(l);
+        }
+        if let Some(ref mut c) = self.copyright {
+            *c = trim(c);
+        }
         super::sanitizers::sanitize_json_value(&mut self.abi);
     }
 
@@ -309,6 +333,18 @@ impl Validatable for CreateContractVersionRequest {
         }
 
         builder.check("abi", || validate_json_depth(&self.abi, MAX_JSON_DEPTH));
+        builder.check_condition(
+            self.rollout_percentage
+                .is_some_and(|p| !(0..100).contains(&p)),
+            "rollout_percentage",
+            "rollout_percentage must be between 0 and 99; omit it to release to everyone",
+        );
+        if let Some(ref license) = self.license {
+            builder.check("license", || shared::spdx::validate_expression(license));
+        }
+        if let Some(ref copyright) = self.copyright {
+            builder.check("copyright", || validate_length(copyright, 1, 500));
+        }
 
         builder.build()
     }
@@ -410,10 +446,21 @@ impl Validatable for UpdateMigrationStatusRequest {
                 self.log_output = None;
             }
         }
+        if let Some(ref mut hash) = self.transaction_hash {
+            *hash = trim(hash).to_ascii_lowercase();
+            if hash.is_empty() {
+                self.transaction_hash = None;
+            }
+        }
     }
 
     fn validate(&self) -> Result<(), Vec<FieldError>> {
-        Ok(())
+        let mut builder = ValidationBuilder::new();
+        if let Some(ref hash) = self.transaction_hash {
+            // Transaction hashes are SHA-256 hex digests, like WASM hashes
+            builder.check("transaction_hash", || validate_wasm_hash(hash));
+        }
+        builder.build()
     }
 }
 
@@ -460,9 +507,7 @@ impl Validatable for UpdateContractMetadataRequest {
         }
 
         if let Some(ref cat) = self.category {
-            builder.check("category", || {
-                validate_category_whitelist(cat, ALLOWED_CATEGORIES)
-            });
+            builder.check("category", || validate_length(cat, 1, MAX_CATEGORY_LENGTH));
             builder.check("category", || validate_no_xss(cat));
         }
 
@@ -543,6 +588,15 @@ impl Validatable for Publisher {
         if let Some(ref mut w) = self.website {
             *w = trim(w);
         }
+        if let Some(ref mut d) = self.display_name {
+            *d = trim(d);
+        }
+        if let Some(ref mut p) = self.pronouns {
+            *p = trim(p);
+        }
+        if let Some(ref mut a) = self.avatar_url {
+            *a = trim(a);
+        }
     }
 
     fn validate(&self) -> Result<(), Vec<FieldError>> {
@@ -553,6 +607,20 @@ impl Validatable for Publisher {
         if let Some(ref u) = self.username {
             builder.check("username", || validate_length(u, 1, MAX_NAME_LENGTH));
         }
+        if let Some(ref e) = self.email {
+            builder.check("email", || validate_email(e));
+        }
+        if let Some(ref d) = self.display_name {
+            builder.check("display_name", || validate_length(d, 1, 100));
+            builder.check("display_name", || validate_no_xss(d));
+        }
+        if let Some(ref p) = self.pronouns {
+            builder.check("pronouns", || validate_length(p, 1, 32));
+            builder.check("pronouns", || validate_no_xss(p));
+        }
+        if let Some(ref a) = self.avatar_url {
+            builder.check("avatar_url", || validate_https_url_only(a));
+        }
         builder.build()
     }
 }
@@ -695,4 +763,22 @@ mod tests {
             Some("https://github.com/user/repo".to_string())
         );
     }
+
+    #[test]
+    fn test_verify_request_compiler_version_optional_with_toolchain() {
+        let mut req = VerifyRequest {
+            contract_id: valid_contract_id(),
+            source_code: "#![no_std]".to_string(),
+            build_params: serde_json::json!({}),
+            compiler_version: String::new(),
+            toolchain: Some("  soroban-21.7-rust-1.81  ".to_string()),
+        };
+        req.sanitize();
+        assert_eq!(req.toolchain.as_deref(), Some("soroban-21.7-rust-1.81"));
+        assert!(req.validate().is_ok());
+
+        req.toolchain = None;
+        let errors = req.validate().unwrap_err();
+        assert!(errors.iter().any(|e| e.field == "compiler_version"));
+    }
 }
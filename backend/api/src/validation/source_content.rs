@@ -0,0 +1,72 @@
+//! Content checks for uploaded contract sources
+//!
+//! The declared `source_format` is checked against what the bytes actually
+//! are: `wasm` uploads must be WebAssembly modules, and `rust` uploads must be
+//! either UTF-8 source or a gzipped tarball of the project. Tarballs are
+//! scanned with [`shared::archive_scan`] before they are stored, since
+//! `clone` and `import` later unpack them on users' machines. A tarball with
+//! unsafe entries is refused with 422 and the full list of rejected entries.
+//!
+//! - ARCHIVE_MAX_ENTRIES: entries per tarball (default 10000)
+//! - ARCHIVE_MAX_ENTRY_MB: unpacked size of any one file (default 64)
+//! - ARCHIVE_MAX_UNPACKED_MB: unpacked size of the whole tarball (default 256)
+
+use serde_json::json;
+use shared::archive_scan::{self, ArchiveLimits};
+use shared::source_storage::SourceFormat;
+
+use crate::error::ApiError;
+
+fn unexpected_content(format: &SourceFormat, found: &str) -> ApiError {
+    ApiError::bad_request(
+        "UnexpectedContentType",
+        format!(
+            "Content does not match source_format '{}': found {}",
+            format, found
+        ),
+    )
+    .with_details(json!({
+        "reason": "UnexpectedContentType",
+        "source_format": format.to_string(),
+        "detected": found,
+    }))
+}
+
+/// Refuses uploads whose content does not match `format`, and tarballs with
+/// entries that would be unsafe to extract.
+pub async fn validate_source_content(format: &SourceFormat, bytes: &[u8]) -> Result<(), ApiError> {
+    let detected = archive_scan::sniff(bytes);
+    match format {
+        SourceFormat::Wasm if detected == "application/wasm" => Ok(()),
+        SourceFormat::Rust if detected == "text/plain" => Ok(()),
+        SourceFormat::Rust if detected == "application/gzip" => {
+            let tarball = bytes.to_vec();
+            let report = tokio::task::spawn_blocking(move || {
+                archive_scan::scan_tar_gz(tarball.as_slice(), &ArchiveLimits::from_env())
+            })
+            .await
+            .map_err(|err| ApiError::internal(format!("archive scan failed: {}", err)))?
+            .map_err(|err| {
+                ApiError::bad_request("InvalidArchive", format!("Unreadable tarball: {}", err))
+            })?;
+            if report.is_clean() {
+                return Ok(());
+            }
+            Err(ApiError::unprocessable(
+                "ArchiveRejected",
+                format!(
+                    "{} of {} archive entries failed validation",
+                    report.rejected.len(),
+                    report.entries
+                ),
+            )
+            .with_details(json!({
+                "reason": "ArchiveRejected",
+                "entries": report.entries,
+                "unpacked_bytes": report.unpacked_bytes,
+                "rejected": report.rejected,
+            })))
+        }
+        _ => Err(unexpected_content(format, detected)),
+    }
+}
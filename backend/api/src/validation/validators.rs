@@ -36,6 +36,9 @@ lazy_static! {
 
     /// Contract name pattern: Alphanumeric, spaces, hyphens, and underscores
     static ref NAME_FORMAT_REGEX: Regex = Regex::new(r"^[a-zA-Z0-9\s\-_]+$").unwrap();
+
+    /// Contract function name: a Soroban symbol of at most 32 characters
+    static ref FUNCTION_NAME_REGEX: Regex = Regex::new(r"^[a-zA-Z_][a-zA-Z0-9_]{0,31}$").unwrap();
 }
 
 /// Validate that a string is not empty after trimming
@@ -165,14 +168,13 @@ pub fn validate_name_format(name: &str) -> Result<(), String> {
     Ok(())
 }
 
-/// Validate category against a whitelist
-pub fn validate_category_whitelist(category: &str, whitelist: &[&str]) -> Result<(), String> {
-    if !whitelist.contains(&category) {
-        return Err(format!(
-            "invalid category '{}'. allowed: [{}]",
-            category,
-            whitelist.join(", ")
-        ));
+/// Validate a contract function name
+/// Letters, digits and underscores, at most 32 characters
+pub fn validate_function_name(name: &str) -> Result<(), String> {
+    if !FUNCTION_NAME_REGEX.is_match(name) {
+        return Err(
+            "must be a contract function name (letters, digits, underscores; max 32)".to_string(),
+        );
     }
     Ok(())
 }
@@ -200,6 +202,27 @@ pub fn validate_url_optional(url: &Option<String>) -> Result<(), String> {
     }
 }
 
+/// Validate an email address: one `@`, a non-empty local part and a dotted
+/// domain, no whitespace
+pub fn validate_email(email: &str) -> Result<(), String> {
+    let valid = email.len() <= 255
+        && !email.contains(char::is_whitespace)
+        && match email.split_once('@') {
+            Some((user, domain)) => {
+                !user.is_empty()
+                    && !domain.contains('@')
+                    && domain.contains('.')
+                    && !domain.starts_with('.')
+                    && !domain.ends_with('.')
+            }
+            None => false,
+        };
+    if !valid {
+        return Err(format!("'{}' is not an email address", email));
+    }
+    Ok(())
+}
+
 /// Validate that a string contains no HTML tags
 pub fn validate_no_html(value: &str) -> Result<(), String> {
     if HTML_TAG_REGEX.is_match(value) {
@@ -382,6 +405,17 @@ mod tests {
         assert!(validate_url("ftp://invalid.com").is_err());
     }
 
+    #[test]
+    fn test_validate_email() {
+        assert!(validate_email("dev@soroban.io").is_ok());
+        assert!(validate_email("first.last+tag@mail.example.com").is_ok());
+        assert!(validate_email("dev@localhost").is_err());
+        assert!(validate_email("dev@@soroban.io").is_err());
+        assert!(validate_email("@soroban.io").is_err());
+        assert!(validate_email("dev @soroban.io").is_err());
+        assert!(validate_email("dev@soroban.").is_err());
+    }
+
     #[test]
     fn test_validate_semver() {
         assert!(validate_semver("1.0.0").is_ok());
@@ -408,9 +442,11 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_category_whitelist() {
-        let whitelist = vec!["DEX", "Lending"];
-        assert!(validate_category_whitelist("DEX", &whitelist).is_ok());
-        assert!(validate_category_whitelist("Bridge", &whitelist).is_err());
+    fn test_validate_function_name() {
+        assert!(validate_function_name("migrate").is_ok());
+        assert!(validate_function_name("init_v2").is_ok());
+        assert!(validate_function_name("2fast").is_err());
+        assert!(validate_function_name("init-v2").is_err());
+        assert!(validate_function_name(&"a".repeat(33)).is_err());
     }
 }
@@ -0,0 +1,144 @@
+//! Verification build cache
+//!
+//! A verification build is determined by its inputs, hashed into a source
+//! digest (see [`verifier::source_digest`]), and the toolchain image it runs
+//! in. [`verify_source`] answers from `verification_build_cache` when the
+//! same inputs were already built in the same toolchain – for this contract
+//! or any other registry entry – and only compares WASM hashes; otherwise it
+//! builds and caches the result. Unsandboxed builds have no toolchain digest
+//! and are never cached. Cache reads and writes are best-effort: a failure
+//! falls back to building.
+
+use serde_json::Value;
+use shared::RegistryError;
+use sqlx::PgPool;
+use uuid::Uuid;
+use verifier::{Toolchain, VerificationResult};
+
+pub struct SourceVerification {
+    pub result: VerificationResult,
+    pub source_digest: String,
+    /// The result came from an earlier build rather than a new one
+    pub cache_hit: bool,
+    /// Verification whose build was reused, if it still exists
+    pub reused_build_from: Option<Uuid>,
+}
+
+/// Verifies `source_code` against `deployed_wasm_hash`, reusing a cached
+/// build when one exists. `verification_id` is recorded as the origin of a
+/// newly cached build.
+pub async fn verify_source(
+    db: &PgPool,
+    verification_id: Option<Uuid>,
+    source_code: &str,
+    deployed_wasm_hash: &str,
+    compiler_version: Option<&str>,
+    build_params: Option<&Value>,
+    toolchain: Option<&Toolchain>,
+) -> Result<SourceVerification, RegistryError> {
+    let source_digest =
+        verifier::source_digest(source_code, compiler_version, build_params, toolchain);
+    let toolchain_digest =
+        match verifier::toolchain_digest(source_code, compiler_version, toolchain).await {
+            Ok(digest) => digest,
+            Err(err) => {
+                tracing::warn!(error = %err, "could not pin toolchain; skipping build cache");
+                None
+            }
+        };
+
+    if let Some(toolchain_digest) = toolchain_digest {
+        if let Some((compiled_wasm_hash, origin)) =
+            lookup(db, &source_digest, &toolchain_digest).await
+        {
+            tracing::info!(
+                source_digest = %source_digest,
+                toolchain = %toolchain_digest,
+                "reusing cached verification build"
+            );
+            let result = verifier::compare_build(
+                compiled_wasm_hash,
+                deployed_wasm_hash,
+                Some(toolchain_digest),
+            )?;
+            return Ok(SourceVerification {
+                result,
+                source_digest,
+                cache_hit: true,
+                reused_build_from: origin,
+            });
+        }
+    }
+
+    let result = verifier::verify_contract(
+        source_code,
+        deployed_wasm_hash,
+        compiler_version,
+        build_params,
+        toolchain,
+    )
+    .await?;
+    // Keyed by the digest the build actually ran in, which is the one that
+    // will be looked up next time.
+    if let Some(toolchain_digest) = &result.toolchain_digest {
+        store(
+            db,
+            &source_digest,
+            toolchain_digest,
+            &result.compiled_wasm_hash,
+            verification_id,
+        )
+        .await;
+    }
+
+    Ok(SourceVerification {
+        result,
+        source_digest,
+        cache_hit: false,
+        reused_build_from: None,
+    })
+}
+
+async fn lookup(
+    db: &PgPool,
+    source_digest: &str,
+    toolchain_digest: &str,
+) -> Option<(String, Option<Uuid>)> {
+    sqlx::query_as(
+        "UPDATE verification_build_cache SET hits = hits + 1, last_hit_at = NOW()
+         WHERE source_digest = $1 AND toolchain_digest = $2
+         RETURNING compiled_wasm_hash, verification_id",
+    )
+    .bind(source_digest)
+    .bind(toolchain_digest)
+    .fetch_optional(db)
+    .await
+    .unwrap_or_else(|err| {
+        tracing::warn!(error = %err, "verification build cache lookup failed");
+        None
+    })
+}
+
+async fn store(
+    db: &PgPool,
+    source_digest: &str,
+    toolchain_digest: &str,
+    compiled_wasm_hash: &str,
+    verification_id: Option<Uuid>,
+) {
+    let stored = sqlx::query(
+        "INSERT INTO verification_build_cache
+            (source_digest, toolchain_digest, compiled_wasm_hash, verification_id)
+         VALUES ($1, $2, $3, $4)
+         ON CONFLICT (source_digest, toolchain_digest) DO NOTHING",
+    )
+    .bind(source_digest)
+    .bind(toolchain_digest)
+    .bind(compiled_wasm_hash)
+    .bind(verification_id)
+    .execute(db)
+    .await;
+    if let Err(err) = stored {
+        tracing::warn!(error = %err, "failed to cache verification build");
+    }
+}
@@ -0,0 +1,217 @@
+//! Verification pipeline metrics
+//!
+//!   GET /api/admin/verification-metrics – queue depth, build times,
+//!                                          success ratios and failure reasons
+//!
+//! Covers verification builds (`POST /api/contracts/verify`) that finished
+//! inside the window; manual status overrides are not builds and are left
+//! out. Build time runs from submission to completion. Failure reasons are
+//! grouped on the first reason of the error with hashes and numbers masked,
+//! so the same systemic problem counts once however many contracts hit it.
+//! Sits behind `auth::require_admin` via `admin_routes()`.
+
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    state::AppState,
+};
+
+const DEFAULT_DAYS: i64 = 7;
+const MAX_DAYS: i64 = 90;
+const TOP_REASONS: i64 = 10;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct VerificationMetricsQuery {
+    /// Window length in days (default 7, max 90)
+    pub days: Option<i64>,
+    /// Series granularity: `hour` or `day` (default `day`)
+    pub bucket: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct VerificationMetrics {
+    /// Verifications submitted but not finished
+    pub queue_depth: i64,
+    pub oldest_pending_at: Option<DateTime<Utc>>,
+    pub window_start: DateTime<Utc>,
+    pub bucket: String,
+    pub totals: VerificationBucket,
+    /// Finished builds per bucket, oldest first; empty buckets are omitted
+    pub series: Vec<VerificationBucket>,
+    pub top_failure_reasons: Vec<FailureReason>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct VerificationBucket {
+    /// Start of the bucket; the window start for totals
+    pub bucket_start: DateTime<Utc>,
+    pub verified: i64,
+    pub failed: i64,
+    /// Share of finished builds that verified, 0–100
+    pub success_rate: f64,
+    pub median_build_ms: Option<f64>,
+    pub p95_build_ms: Option<f64>,
+}
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct FailureReason {
+    pub reason: String,
+    pub count: i64,
+    pub first_seen: DateTime<Utc>,
+    pub last_seen: DateTime<Utc>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn parse_query(query: &VerificationMetricsQuery) -> ApiResult<(i64, &'static str)> {
+    let days = query.days.unwrap_or(DEFAULT_DAYS);
+    if !(1..=MAX_DAYS).contains(&days) {
+        return Err(ApiError::bad_request(
+            "InvalidMetricsQuery",
+            format!("days must be between 1 and {}", MAX_DAYS),
+        ));
+    }
+    let bucket = match query.bucket.as_deref().unwrap_or("day") {
+        "hour" => "hour",
+        "day" => "day",
+        other => {
+            return Err(ApiError::bad_request(
+                "InvalidMetricsQuery",
+                format!("Unsupported bucket '{}', expected 'hour' or 'day'", other),
+            ))
+        }
+    };
+    Ok((days, bucket))
+}
+
+/// Aggregates finished builds; `$1` is the window start and `{bucket}` the
+/// grouping expression.
+const BUCKET_SQL: &str = "SELECT {bucket} AS bucket_start,
+        COUNT(*) FILTER (WHERE status = 'verified') AS verified,
+        COUNT(*) FILTER (WHERE status = 'failed') AS failed,
+        COALESCE(ROUND(100.0 * COUNT(*) FILTER (WHERE status = 'verified')
+            / NULLIF(COUNT(*), 0), 1), 0)::FLOAT8 AS success_rate,
+        (PERCENTILE_CONT(0.5) WITHIN GROUP (ORDER BY build_ms))::FLOAT8 AS median_build_ms,
+        (PERCENTILE_CONT(0.95) WITHIN GROUP (ORDER BY build_ms))::FLOAT8 AS p95_build_ms
+     FROM (
+        SELECT status, completed_at,
+            EXTRACT(EPOCH FROM (completed_at - created_at)) * 1000 AS build_ms
+        FROM verifications
+        WHERE completed_at >= $1 AND status IN ('verified', 'failed')
+     ) builds";
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/verification-metrics",
+    params(VerificationMetricsQuery),
+    responses(
+        (status = 200, description = "Verification pipeline metrics", body = VerificationMetrics),
+        (status = 400, description = "Invalid window or bucket")
+    ),
+    tag = "Verification"
+)]
+pub async fn get_verification_metrics(
+    State(state): State<AppState>,
+    Query(query): Query<VerificationMetricsQuery>,
+) -> ApiResult<Json<VerificationMetrics>> {
+    let (days, bucket) = parse_query(&query)?;
+    let window_start = Utc::now() - Duration::days(days);
+
+    let (queue_depth, oldest_pending_at): (i64, Option<DateTime<Utc>>) = sqlx::query_as(
+        "SELECT COUNT(*), MIN(created_at) FROM verifications
+         WHERE status = 'pending' AND source_code IS NOT NULL",
+    )
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("verification queue depth", err))?;
+
+    let totals: VerificationBucket =
+        sqlx::query_as(&BUCKET_SQL.replace("{bucket}", "$1::TIMESTAMPTZ"))
+            .bind(window_start)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("verification totals", err))?;
+
+    let series_sql = format!(
+        "{} GROUP BY 1 ORDER BY 1",
+        BUCKET_SQL.replace(
+            "{bucket}",
+            &format!("date_trunc('{}', completed_at)", bucket)
+        )
+    );
+    let series = sqlx::query_as(&series_sql)
+        .bind(window_start)
+        .fetch_all(&state.db)
+        .await
+        .map_err(|err| db_internal_error("verification series", err))?;
+
+    let top_failure_reasons = sqlx::query_as(
+        "SELECT reason, COUNT(*) AS count,
+            MIN(completed_at) AS first_seen, MAX(completed_at) AS last_seen
+         FROM (
+            SELECT completed_at,
+                LEFT(regexp_replace(regexp_replace(
+                    btrim(split_part(COALESCE(error_message, 'unknown error'), ';', 1)),
+                    '[0-9a-fA-F]{16,}', '<hash>', 'g'), '[0-9]+', 'N', 'g'), 200) AS reason
+            FROM verifications
+            WHERE status = 'failed' AND completed_at >= $1
+         ) failures
+         GROUP BY reason
+         ORDER BY count DESC, last_seen DESC
+         LIMIT $2",
+    )
+    .bind(window_start)
+    .bind(TOP_REASONS)
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("verification failure reasons", err))?;
+
+    Ok(Json(VerificationMetrics {
+        queue_depth,
+        oldest_pending_at,
+        window_start,
+        bucket: bucket.to_string(),
+        totals,
+        series,
+        top_failure_reasons,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn query(days: Option<i64>, bucket: Option<&str>) -> VerificationMetricsQuery {
+        VerificationMetricsQuery {
+            days,
+            bucket: bucket.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn defaults_to_a_week_of_daily_buckets() {
+        assert_eq!(parse_query(&query(None, None)).unwrap(), (7, "day"));
+        assert_eq!(
+            parse_query(&query(Some(2), Some("hour"))).unwrap(),
+            (2, "hour")
+        );
+    }
+
+    #[test]
+    fn rejects_out_of_range_windows_and_unknown_buckets() {
+        assert!(parse_query(&query(Some(0), None)).is_err());
+        assert!(parse_query(&query(Some(91), None)).is_err());
+        assert!(parse_query(&query(None, Some("week"))).is_err());
+    }
+}
@@ -0,0 +1,495 @@
+//! Staged rollouts of contract versions
+//!
+//!   GET    /api/contracts/:id/versions/resolve            – version a consumer should use
+//!   GET    /api/contracts/:id/versions/:version/rollout   – latest rollout of a version
+//!   PUT    /api/contracts/:id/versions/:version/rollout   – start or adjust a rollout (publisher)
+//!   DELETE /api/contracts/:id/versions/:version/rollout   – abort a rollout (publisher)
+//!
+//! While a rollout is active, `resolve` hands its version to `percentage`% of
+//! consumers and the contract's default version to everyone else. A consumer
+//! lands in a bucket 0–99 derived from the contract, the version and its own
+//! identifier, so it gets the same answer on every call and stays in the
+//! cohort as the percentage grows. Setting 100% completes the rollout and
+//! makes the version the contract's `current_version`. A version created with
+//! `rollout_percentage` starts out staged instead of becoming the default.
+
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::ContractVersion;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{authorize, ContractCapability},
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+};
+
+const MAX_CONSUMER_LEN: usize = 128;
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct VersionRollout {
+    pub id: Uuid,
+    pub contract_id: Uuid,
+    pub version: String,
+    /// Share of consumers resolved to this version, 0–100
+    pub percentage: i32,
+    /// active, completed or aborted
+    pub status: String,
+    pub started_by: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct SetRolloutRequest {
+    /// Share of consumers to resolve to the version, 0–100; 100 completes the rollout
+    pub percentage: i32,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct ResolveQuery {
+    /// Stable identifier of the resolving consumer (account, install or
+    /// deployment ID); without one only the default version is returned
+    pub consumer: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct ResolvedVersion {
+    pub version: String,
+    pub wasm_hash: String,
+    /// Whether the version came from an active staged rollout
+    pub staged: bool,
+    /// Version everyone outside the rollout resolves to
+    pub default_version: Option<String>,
+    /// The active rollout, if any
+    pub rollout: Option<VersionRollout>,
+}
+
+// ── Internal helpers ──────────────────────────────────────────────────────────
+
+fn invalid(message: impl Into<String>) -> ApiError {
+    ApiError::bad_request("InvalidRollout", message)
+}
+
+/// Deterministic bucket 0–99 for a consumer of one version's rollout.
+fn rollout_bucket(contract_id: Uuid, version: &str, consumer: &str) -> u8 {
+    let digest = Sha256::digest(format!("{}:{}:{}", contract_id, version, consumer));
+    let mut prefix = [0u8; 8];
+    prefix.copy_from_slice(&digest[..8]);
+    (u64::from_be_bytes(prefix) % 100) as u8
+}
+
+/// Whether a consumer in `bucket` receives a rollout at `percentage`.
+fn in_cohort(bucket: u8, percentage: i32) -> bool {
+    i32::from(bucket) < percentage
+}
+
+async fn active_rollout(state: &AppState, contract_id: Uuid) -> ApiResult<Option<VersionRollout>> {
+    sqlx::query_as(
+        "SELECT * FROM contract_version_rollouts
+         WHERE contract_id = $1 AND status = 'active'",
+    )
+    .bind(contract_id)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch active version rollout", err))
+}
+
+/// The contract's `current_version`, falling back to its newest version.
+/// `exclude` keeps a version under rollout from becoming its own default.
+async fn default_version(
+    state: &AppState,
+    contract_id: Uuid,
+    exclude: Option<&str>,
+) -> ApiResult<Option<ContractVersion>> {
+    sqlx::query_as(
+        "SELECT cv.* FROM contract_versions cv
+         JOIN contracts c ON c.id = cv.contract_id
+         WHERE cv.contract_id = $1 AND ($2::TEXT IS NULL OR cv.version <> $2)
+         ORDER BY (cv.version = c.current_version) DESC NULLS LAST, cv.created_at DESC
+         LIMIT 1",
+    )
+    .bind(contract_id)
+    .bind(exclude)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch default contract version", err))
+}
+
+async fn fetch_version(
+    state: &AppState,
+    contract_id: Uuid,
+    version: &str,
+) -> ApiResult<ContractVersion> {
+    sqlx::query_as("SELECT * FROM contract_versions WHERE contract_id = $1 AND version = $2")
+        .bind(contract_id)
+        .bind(version)
+        .fetch_optional(&state.db)
+        .await
+        .map_err(|err| db_internal_error("fetch contract version", err))?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "VersionNotFound",
+                format!(
+                    "Version '{}' not found for contract {}",
+                    version, contract_id
+                ),
+            )
+        })
+}
+
+/// Starts a rollout inside `tx`; fails if the contract already has an
+/// active one. Also used when a version is created with `rollout_percentage`.
+pub(crate) async fn start_rollout(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    contract_id: Uuid,
+    version: &str,
+    percentage: i32,
+    started_by: Option<&str>,
+) -> ApiResult<Uuid> {
+    sqlx::query_scalar(
+        "INSERT INTO contract_version_rollouts (contract_id, version, percentage, started_by)
+         VALUES ($1, $2, $3, $4)
+         RETURNING id",
+    )
+    .bind(contract_id)
+    .bind(version)
+    .bind(percentage)
+    .bind(started_by)
+    .fetch_one(&mut **tx)
+    .await
+    .map_err(|err| match err {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::conflict(
+            "RolloutInProgress",
+            "Another version of this contract is being rolled out; complete or abort it first",
+        ),
+        _ => db_internal_error("insert version rollout", err),
+    })
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/versions/resolve",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ResolveQuery
+    ),
+    responses(
+        (status = 200, description = "Version the consumer should use", body = ResolvedVersion),
+        (status = 400, description = "Invalid consumer identifier"),
+        (status = 404, description = "Contract not found or has no versions")
+    ),
+    tag = "Versions"
+)]
+pub async fn resolve_version(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<ResolveQuery>,
+) -> ApiResult<Json<ResolvedVersion>> {
+    let consumer = query.consumer.as_deref().map(str::trim);
+    if let Some(consumer) = consumer {
+        if consumer.is_empty() || consumer.len() > MAX_CONSUMER_LEN {
+            return Err(invalid(format!(
+                "consumer must be 1 to {} characters",
+                MAX_CONSUMER_LEN
+            )));
+        }
+    }
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+
+    let rollout = active_rollout(&state, contract_uuid).await?;
+    let default = default_version(
+        &state,
+        contract_uuid,
+        rollout.as_ref().map(|r| r.version.as_str()),
+    )
+    .await?;
+
+    // A rollout of a contract's first version reaches everyone: there is
+    // nothing else to resolve to.
+    let staged = match (&rollout, consumer) {
+        (Some(_), _) if default.is_none() => true,
+        (Some(rollout), Some(consumer)) => in_cohort(
+            rollout_bucket(contract_uuid, &rollout.version, consumer),
+            rollout.percentage,
+        ),
+        _ => false,
+    };
+    let chosen = match (&rollout, staged) {
+        (Some(rollout), true) => fetch_version(&state, contract_uuid, &rollout.version).await?,
+        _ => default.clone().ok_or_else(|| {
+            ApiError::not_found(
+                "VersionNotFound",
+                format!("Contract {} has no versions", id),
+            )
+        })?,
+    };
+
+    Ok(Json(ResolvedVersion {
+        version: chosen.version,
+        wasm_hash: chosen.wasm_hash,
+        staged,
+        default_version: default.map(|v| v.version),
+        rollout,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/versions/{version}/rollout",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    responses(
+        (status = 200, description = "Most recent rollout of the version", body = VersionRollout),
+        (status = 404, description = "The version was never rolled out")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_version_rollout(
+    State(state): State<AppState>,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Json<VersionRollout>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    let rollout: Option<VersionRollout> = sqlx::query_as(
+        "SELECT * FROM contract_version_rollouts
+         WHERE contract_id = $1 AND version = $2
+         ORDER BY created_at DESC
+         LIMIT 1",
+    )
+    .bind(contract_uuid)
+    .bind(&version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("fetch version rollout", err))?;
+
+    rollout.map(Json).ok_or_else(|| {
+        ApiError::not_found(
+            "RolloutNotFound",
+            format!("Version '{}' has no staged rollout", version),
+        )
+    })
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/contracts/{id}/versions/{version}/rollout",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    request_body = SetRolloutRequest,
+    responses(
+        (status = 200, description = "Rollout started, adjusted or completed", body = VersionRollout),
+        (status = 400, description = "Invalid percentage, or the version is already the default"),
+        (status = 403, description = "Caller may not publish versions of the contract"),
+        (status = 404, description = "Version not found"),
+        (status = 409, description = "Another version is being rolled out")
+    ),
+    tag = "Versions"
+)]
+pub async fn set_version_rollout(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, version)): Path<(String, String)>,
+    Json(req): Json<SetRolloutRequest>,
+) -> ApiResult<Json<VersionRollout>> {
+    if !(0..=100).contains(&req.percentage) {
+        return Err(invalid("percentage must be between 0 and 100"));
+    }
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "roll out its versions",
+    )
+    .await?;
+    fetch_version(&state, contract_uuid, &version).await?;
+
+    let current: Option<String> =
+        sqlx::query_scalar("SELECT current_version FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch current version", err))?;
+    if current.as_deref() == Some(version.as_str()) {
+        return Err(invalid(format!(
+            "{} is already the default version",
+            version
+        )));
+    }
+
+    let mut tx = state
+        .db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin version rollout", err))?;
+    let active: Option<VersionRollout> = sqlx::query_as(
+        "SELECT * FROM contract_version_rollouts
+         WHERE contract_id = $1 AND status = 'active'
+         FOR UPDATE",
+    )
+    .bind(contract_uuid)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("lock active version rollout", err))?;
+
+    let rollout_id = match active {
+        Some(active) if active.version != version => {
+            return Err(ApiError::conflict(
+                "RolloutInProgress",
+                format!(
+                    "Version {} is being rolled out ({}%); complete or abort it first",
+                    active.version, active.percentage
+                ),
+            ))
+        }
+        Some(active) => active.id,
+        None => {
+            start_rollout(
+                &mut tx,
+                contract_uuid,
+                &version,
+                req.percentage,
+                Some(&claims.sub),
+            )
+            .await?
+        }
+    };
+
+    let complete = req.percentage == 100;
+    let rollout: VersionRollout = sqlx::query_as(
+        "UPDATE contract_version_rollouts
+         SET percentage = $2,
+             status = CASE WHEN $3 THEN 'completed' ELSE status END,
+             finished_at = CASE WHEN $3 THEN NOW() ELSE finished_at END,
+             updated_at = NOW()
+         WHERE id = $1
+         RETURNING *",
+    )
+    .bind(rollout_id)
+    .bind(req.percentage)
+    .bind(complete)
+    .fetch_one(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("update version rollout", err))?;
+    if complete {
+        sqlx::query("UPDATE contracts SET current_version = $2 WHERE id = $1")
+            .bind(contract_uuid)
+            .bind(&version)
+            .execute(&mut *tx)
+            .await
+            .map_err(|err| db_internal_error("promote rolled out version", err))?;
+    }
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit version rollout", err))?;
+
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %version,
+        percentage = req.percentage,
+        "version rollout updated"
+    );
+    Ok(Json(rollout))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/versions/{version}/rollout",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    responses(
+        (status = 200, description = "Rollout aborted; everyone resolves to the default again", body = VersionRollout),
+        (status = 403, description = "Caller may not publish versions of the contract"),
+        (status = 404, description = "The version has no active rollout")
+    ),
+    tag = "Versions"
+)]
+pub async fn abort_version_rollout(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Json<VersionRollout>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "roll out its versions",
+    )
+    .await?;
+
+    let rollout: Option<VersionRollout> = sqlx::query_as(
+        "UPDATE contract_version_rollouts
+         SET status = 'aborted', finished_at = NOW(), updated_at = NOW()
+         WHERE contract_id = $1 AND version = $2 AND status = 'active'
+         RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(&version)
+    .fetch_optional(&state.db)
+    .await
+    .map_err(|err| db_internal_error("abort version rollout", err))?;
+
+    let rollout = rollout.ok_or_else(|| {
+        ApiError::not_found(
+            "RolloutNotFound",
+            format!("Version '{}' has no active rollout", version),
+        )
+    })?;
+    Ok(Json(rollout))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_are_stable_per_consumer() {
+        let contract = Uuid::from_u128(42);
+        let first = rollout_bucket(contract, "2.0.0", "GABC");
+        assert_eq!(first, rollout_bucket(contract, "2.0.0", "GABC"));
+        assert!(first < 100);
+    }
+
+    #[test]
+    fn raising_the_percentage_keeps_earlier_consumers() {
+        let contract = Uuid::from_u128(7);
+        for consumer in (0..200).map(|i| format!("consumer-{}", i)) {
+            let bucket = rollout_bucket(contract, "1.1.0", &consumer);
+            if in_cohort(bucket, 10) {
+                assert!(in_cohort(bucket, 50));
+            }
+            assert!(!in_cohort(bucket, 0));
+            assert!(in_cohort(bucket, 100));
+        }
+    }
+
+    #[test]
+    fn percentage_roughly_matches_share_of_consumers() {
+        let contract = Uuid::from_u128(1);
+        let staged = (0..10_000)
+            .filter(|i| in_cohort(rollout_bucket(contract, "3.0.0", &i.to_string()), 25))
+            .count();
+        assert!((2_200..2_800).contains(&staged), "staged {}", staged);
+    }
+}
@@ -0,0 +1,215 @@
+//! Yanking contract versions
+//!
+//!   PUT    /api/contracts/:id/versions/:version/yank – yank a version (publisher)
+//!   DELETE /api/contracts/:id/versions/:version/yank – undo a yank (publisher)
+//!
+//! As with crates, a yanked version is not deleted: existing deployments
+//! keep resolving it, but it is flagged in version listings, the static
+//! index and the change feed so tooling stops picking it for new ones.
+//!
+//! A yank is applied after the grace period in [`crate::undo`] and can be
+//! taken back until then; unyanking takes effect immediately and also drops
+//! any yank of the version still waiting out its grace period.
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use serde::Deserialize;
+use shared::ContractVersion;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    contract_permissions::{authorize, ContractCapability},
+    error::{ApiError, ApiResult},
+    handlers::{db_internal_error, fetch_contract_identity},
+    state::AppState,
+    undo::{self, DeferredAction, PendingAction},
+};
+
+const MAX_REASON_LEN: usize = 500;
+
+#[derive(Debug, Default, Deserialize, utoipa::ToSchema)]
+pub struct YankRequest {
+    /// Why the version should not be used, e.g. a security advisory
+    pub reason: Option<String>,
+}
+
+/// Yanks or unyanks `version` and touches the contract, whose index file
+/// lists the flag.
+pub(crate) async fn set_yank(
+    db: &PgPool,
+    contract_uuid: Uuid,
+    version: &str,
+    yanked: bool,
+    reason: Option<&str>,
+) -> ApiResult<ContractVersion> {
+    let mut tx = db
+        .begin()
+        .await
+        .map_err(|err| db_internal_error("begin yank", err))?;
+    let row: Option<ContractVersion> = sqlx::query_as(
+        "UPDATE contract_versions
+         SET yanked_at = CASE WHEN $3 THEN COALESCE(yanked_at, NOW()) END,
+             yank_reason = CASE WHEN $3 THEN $4 END
+         WHERE contract_id = $1 AND version = $2
+         RETURNING *",
+    )
+    .bind(contract_uuid)
+    .bind(version)
+    .bind(yanked)
+    .bind(reason)
+    .fetch_optional(&mut *tx)
+    .await
+    .map_err(|err| db_internal_error("update version yank", err))?;
+    let row = row.ok_or_else(|| {
+        ApiError::not_found(
+            "VersionNotFound",
+            format!("Version '{}' not found for this contract", version),
+        )
+    })?;
+    sqlx::query("UPDATE contracts SET updated_at = NOW() WHERE id = $1")
+        .bind(contract_uuid)
+        .execute(&mut *tx)
+        .await
+        .map_err(|err| db_internal_error("touch yanked contract", err))?;
+    tx.commit()
+        .await
+        .map_err(|err| db_internal_error("commit yank", err))?;
+    Ok(row)
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/contracts/{id}/versions/{version}/yank",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    request_body = YankRequest,
+    responses(
+        (status = 202, description = "Yank queued, or the one already queued for this version; undoable until apply_at", body = PendingAction),
+        (status = 400, description = "Reason too long"),
+        (status = 403, description = "Caller may not publish versions of the contract"),
+        (status = 404, description = "Contract or version not found")
+    ),
+    tag = "Versions"
+)]
+pub async fn yank_version(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, version)): Path<(String, String)>,
+    body: Option<Json<YankRequest>>,
+) -> ApiResult<(StatusCode, Json<PendingAction>)> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "yank its versions",
+    )
+    .await?;
+
+    let reason = body
+        .and_then(|Json(req)| req.reason)
+        .map(|reason| reason.trim().to_string())
+        .filter(|reason| !reason.is_empty());
+    if reason
+        .as_ref()
+        .is_some_and(|reason| reason.chars().count() > MAX_REASON_LEN)
+    {
+        return Err(ApiError::bad_request(
+            "InvalidYank",
+            format!("reason must be at most {} characters", MAX_REASON_LEN),
+        ));
+    }
+
+    let exists: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM contract_versions WHERE contract_id = $1 AND version = $2)",
+    )
+    .bind(contract_uuid)
+    .bind(&version)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check version exists", err))?;
+    if !exists {
+        return Err(ApiError::not_found(
+            "VersionNotFound",
+            format!("Version '{}' not found for this contract", version),
+        ));
+    }
+
+    let pending = undo::defer(
+        &state.db,
+        &claims.sub,
+        DeferredAction::Yank {
+            contract_id: contract_uuid,
+            version: version.clone(),
+            reason,
+        },
+    )
+    .await
+    .map_err(|err| db_internal_error("queue version yank", err))?;
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %version,
+        apply_at = %pending.apply_at,
+        "version yank queued"
+    );
+    Ok((StatusCode::ACCEPTED, Json(pending)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/contracts/{id}/versions/{version}/yank",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract_id"),
+        ("version" = String, Path, description = "Version string")
+    ),
+    responses(
+        (status = 200, description = "Yank undone", body = ContractVersion),
+        (status = 403, description = "Caller may not publish versions of the contract"),
+        (status = 404, description = "Contract or version not found")
+    ),
+    tag = "Versions"
+)]
+pub async fn unyank_version(
+    State(state): State<AppState>,
+    claims: AuthClaims,
+    Path((id, version)): Path<(String, String)>,
+) -> ApiResult<Json<ContractVersion>> {
+    let (contract_uuid, _) = fetch_contract_identity(&state, &id).await?;
+    authorize(
+        &state,
+        &claims,
+        contract_uuid,
+        ContractCapability::PublishVersions,
+        "yank its versions",
+    )
+    .await?;
+
+    // A yank still in its grace period would otherwise re-apply later.
+    let cancelled = undo::cancel_queued(
+        &state.db,
+        &DeferredAction::Yank {
+            contract_id: contract_uuid,
+            version: version.clone(),
+            reason: None,
+        },
+    )
+    .await
+    .map_err(|err| db_internal_error("cancel queued yank", err))?;
+
+    let row = set_yank(&state.db, contract_uuid, &version, false, None).await?;
+    tracing::info!(
+        contract = %contract_uuid,
+        version = %version,
+        cancelled_yanks = cancelled,
+        "version unyanked"
+    );
+    Ok(Json(row))
+}
@@ -0,0 +1,212 @@
+//! Contract WASM downloads and reverse lookup
+//!
+//!   GET /api/contracts/:id/wasm   – a build of the contract, as `application/wasm`
+//!   GET /api/wasm/:hash/contracts – every contract and version built from a hash
+//!
+//! The registry does not store binaries; the code is read from the contract's
+//! network through Soroban RPC (see [`crate::onchain_verification`]). Only
+//! builds the registry knows for the contract can be fetched: its current
+//! build, the build of any of its versions, and builds shipped by security
+//! patches targeting one of those.
+//!
+//! The reverse lookup lets incident responders find every deployment of a
+//! vulnerable binary: contracts whose current build is the hash, and
+//! contracts with a version built from it.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use serde::{Deserialize, Serialize};
+use shared::Network;
+use uuid::Uuid;
+
+use crate::{
+    auth::AuthClaims,
+    error::{ApiError, ApiResult},
+    handlers::db_internal_error,
+    onchain_verification::OnChainVerifier,
+    patch_handlers::resolve_contract_uuid,
+    state::AppState,
+    tenant::Tenant,
+};
+
+// ── Request / response types ──────────────────────────────────────────────────
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+pub struct WasmQuery {
+    /// Build to download; defaults to the contract's current build
+    pub hash: Option<String>,
+}
+
+/// A contract built from the looked-up hash, now or in one of its versions.
+#[derive(Debug, Serialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct WasmDeployment {
+    /// Registry UUID
+    pub id: Uuid,
+    /// On-chain contract ID
+    pub contract_id: String,
+    pub name: String,
+    pub network: Network,
+    /// Stellar address of the publisher
+    pub publisher: String,
+    pub is_verified: bool,
+    /// Whether the hash is the contract's current build
+    pub is_current: bool,
+    /// Versions built from the hash, oldest first
+    pub versions: Vec<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct WasmDeploymentsResponse {
+    pub wasm_hash: String,
+    pub contracts: Vec<WasmDeployment>,
+}
+
+// ── Handlers ──────────────────────────────────────────────────────────────────
+
+#[utoipa::path(
+    get,
+    path = "/api/contracts/{id}/wasm",
+    params(
+        ("id" = String, Path, description = "Contract UUID or on-chain contract ID"),
+        WasmQuery
+    ),
+    responses(
+        (status = 200, description = "Contract WASM", content_type = "application/wasm"),
+        (status = 400, description = "Malformed hash"),
+        (status = 404, description = "Contract not found, build unknown to the registry, or not on the ledger"),
+        (status = 502, description = "The network's RPC could not be reached")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_contract_wasm(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    Path(id): Path<String>,
+    Query(query): Query<WasmQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let contract_uuid = resolve_contract_uuid(&state, &tenant, &id).await?;
+    let (network, current_hash): (Network, String) =
+        sqlx::query_as("SELECT network, wasm_hash FROM contracts WHERE id = $1")
+            .bind(contract_uuid)
+            .fetch_one(&state.db)
+            .await
+            .map_err(|err| db_internal_error("fetch contract build", err))?;
+
+    let requested = query.hash.as_deref().unwrap_or(&current_hash);
+    let hash = verifier::normalize_hash(requested).ok_or_else(|| {
+        ApiError::bad_request("InvalidWasmHash", "hash must be 64 hexadecimal characters")
+    })?;
+
+    let known: bool = sqlx::query_scalar(
+        "WITH builds AS (
+             SELECT LOWER(wasm_hash) AS hash FROM contracts WHERE id = $1
+             UNION
+             SELECT LOWER(wasm_hash) FROM contract_versions WHERE contract_id = $1
+         )
+         SELECT EXISTS(SELECT 1 FROM builds WHERE hash = $2)
+             OR EXISTS(
+                 SELECT 1 FROM security_patches
+                  WHERE LOWER(new_wasm_hash) = $2
+                    AND LOWER(target_version) IN (SELECT hash FROM builds)
+             )",
+    )
+    .bind(contract_uuid)
+    .bind(&hash)
+    .fetch_one(&state.db)
+    .await
+    .map_err(|err| db_internal_error("check contract build", err))?;
+    if !known {
+        return Err(ApiError::not_found(
+            "WasmNotFound",
+            format!("Build {} is not a known build of this contract", hash),
+        ));
+    }
+
+    let code = OnChainVerifier::new()
+        .fetch_contract_code(&network, &hash)
+        .await
+        .map_err(|err| {
+            tracing::warn!(contract = %contract_uuid, hash = %hash, error = %err, "wasm download failed");
+            ApiError::new(
+                StatusCode::BAD_GATEWAY,
+                "WasmUnavailable",
+                format!("Could not read build {} from {}", hash, network),
+            )
+        })?
+        .ok_or_else(|| {
+            ApiError::not_found(
+                "WasmNotFound",
+                format!("Build {} is not live on {}", hash, network),
+            )
+        })?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/wasm".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}.wasm\"", hash),
+            ),
+            (header::ETAG, format!("\"{}\"", hash)),
+        ],
+        code,
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/wasm/{hash}/contracts",
+    params(("hash" = String, Path, description = "WASM hash, 64 hexadecimal characters")),
+    responses(
+        (status = 200, description = "Contracts built from the hash; empty when none", body = WasmDeploymentsResponse),
+        (status = 400, description = "Malformed hash")
+    ),
+    tag = "Versions"
+)]
+pub async fn get_wasm_contracts(
+    State(state): State<AppState>,
+    tenant: Tenant,
+    claims: Option<AuthClaims>,
+    Path(hash): Path<String>,
+) -> ApiResult<Json<WasmDeploymentsResponse>> {
+    let hash = verifier::normalize_hash(&hash).ok_or_else(|| {
+        ApiError::bad_request("InvalidWasmHash", "hash must be 64 hexadecimal characters")
+    })?;
+
+    // Private contracts are listed to members of their organization only,
+    // as in search.
+    let contracts: Vec<WasmDeployment> = sqlx::query_as(
+        "SELECT c.id, c.contract_id, c.name, c.network, p.stellar_address AS publisher,
+                c.is_verified, LOWER(c.wasm_hash) = $1 AS is_current,
+                ARRAY(SELECT v.version FROM contract_versions v
+                      WHERE v.contract_id = c.id AND LOWER(v.wasm_hash) = $1
+                      ORDER BY v.created_at) AS versions
+         FROM contracts c
+         JOIN publishers p ON p.id = c.publisher_id
+         WHERE c.deleted_at IS NULL AND c.tenant_id = $2
+           AND (c.visibility = 'public'
+                OR (c.visibility = 'private' AND c.organization_id IN (
+                    SELECT om.organization_id FROM organization_members om
+                    JOIN publishers mp ON mp.id = om.publisher_id
+                    WHERE mp.stellar_address = $3)))
+           AND (LOWER(c.wasm_hash) = $1
+                OR EXISTS (SELECT 1 FROM contract_versions v
+                           WHERE v.contract_id = c.id AND LOWER(v.wasm_hash) = $1))
+         ORDER BY c.network, c.name, c.contract_id",
+    )
+    .bind(&hash)
+    .bind(tenant.id)
+    .bind(claims.as_ref().map(|claims| claims.sub.as_str()))
+    .fetch_all(&state.db)
+    .await
+    .map_err(|err| db_internal_error("look up contracts by wasm hash", err))?;
+
+    Ok(Json(WasmDeploymentsResponse {
+        wasm_hash: hash,
+        contracts,
+    }))
+}
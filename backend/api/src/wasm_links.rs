@@ -0,0 +1,135 @@
+//! Linking uploads that reuse a WASM hash
+//!
+//! The same binary is often published more than once: a factory deploys many
+//! instances, a project registers its testnet and mainnet deployments, a
+//! version is re-uploaded unchanged. When a publish or version upload carries
+//! a WASM hash the registry already knows, it is linked to the earliest such
+//! build and takes over what was extracted for it: the ABI, the docs coverage
+//! measured from it and per-function benchmarks. The link is recorded in
+//! `wasm_links` and returned as `linked_from`.
+//!
+//! Only builds the uploader could see are linked: public contracts of the
+//! same tenant, or the publisher's own.
+
+use serde_json::Value;
+use shared::WasmLink;
+use sqlx::{PgExecutor, PgPool};
+use uuid::Uuid;
+
+pub const INHERITED_ABI: &str = "abi";
+pub const INHERITED_DOCS_COVERAGE: &str = "docs_coverage";
+pub const INHERITED_BENCHMARKS: &str = "benchmarks";
+
+/// The registered build an upload is linked to.
+#[derive(Debug, sqlx::FromRow)]
+pub struct WasmSource {
+    pub contract_id: Uuid,
+    pub contract_address: String,
+    /// Set when the build is a version rather than a contract's own build
+    pub version_id: Option<Uuid>,
+    pub version: Option<String>,
+    pub abi: Option<Value>,
+    pub docs_coverage: Option<f64>,
+}
+
+impl WasmSource {
+    pub fn link(&self, inherited: Vec<&str>) -> WasmLink {
+        WasmLink {
+            contract_id: self.contract_id,
+            contract_address: self.contract_address.clone(),
+            version: self.version.clone(),
+            inherited: inherited.into_iter().map(str::to_string).collect(),
+        }
+    }
+}
+
+/// Earliest registered build with `wasm_hash`, preferring builds with an
+/// ABI and versions over contracts' own builds.
+pub async fn find_source(
+    db: &PgPool,
+    wasm_hash: &str,
+    tenant_id: Uuid,
+    publisher_id: Uuid,
+) -> sqlx::Result<Option<WasmSource>> {
+    sqlx::query_as(
+        "WITH visible AS (
+             SELECT * FROM contracts
+             WHERE tenant_id = $2 AND deleted_at IS NULL
+               AND (visibility = 'public' OR publisher_id = $3)
+         ),
+         builds AS (
+             SELECT c.id AS contract_id, c.contract_id AS contract_address,
+                    v.id AS version_id, v.version, a.abi, v.docs_coverage,
+                    0 AS rank, v.created_at
+             FROM contract_versions v
+             JOIN visible c ON c.id = v.contract_id
+             LEFT JOIN contract_abis a ON a.contract_id = v.contract_id AND a.version = v.version
+             WHERE LOWER(v.wasm_hash) = $1
+             UNION ALL
+             SELECT c.id, c.contract_id, NULL, NULL, c.abi, NULL, 1, c.created_at
+             FROM visible c
+             WHERE LOWER(c.wasm_hash) = $1
+         )
+         SELECT contract_id, contract_address, version_id, version, abi, docs_coverage
+         FROM builds
+         ORDER BY abi IS NULL, rank, created_at
+         LIMIT 1",
+    )
+    .bind(wasm_hash.trim().to_ascii_lowercase())
+    .bind(tenant_id)
+    .bind(publisher_id)
+    .fetch_optional(db)
+    .await
+}
+
+/// Copies the source version's function benchmarks onto the new version;
+/// returns how many were copied.
+pub async fn copy_benchmarks(
+    executor: impl PgExecutor<'_>,
+    source_version_id: Uuid,
+    contract_id: Uuid,
+    version_id: Uuid,
+) -> sqlx::Result<u64> {
+    let result = sqlx::query(
+        "INSERT INTO contract_function_benchmarks
+            (contract_id, contract_version_id, function_name, cpu_instructions,
+             memory_bytes, fee_stroops, args, tool_version, recorded_at)
+         SELECT $2, $3, function_name, cpu_instructions, memory_bytes, fee_stroops,
+                args, tool_version, recorded_at
+         FROM contract_function_benchmarks
+         WHERE contract_version_id = $1
+         ON CONFLICT (contract_version_id, function_name) DO NOTHING",
+    )
+    .bind(source_version_id)
+    .bind(contract_id)
+    .bind(version_id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Records that `contract_id` (at `version`, or its own build when `None`)
+/// was linked to `source`.
+pub async fn record(
+    executor: impl PgExecutor<'_>,
+    contract_id: Uuid,
+    version: Option<&str>,
+    wasm_hash: &str,
+    source: &WasmSource,
+    link: &WasmLink,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        "INSERT INTO wasm_links
+            (contract_id, version, wasm_hash, source_contract_id, source_version, inherited)
+         VALUES ($1, $2, LOWER($3), $4, $5, $6)",
+    )
+    .bind(contract_id)
+    .bind(version)
+    .bind(wasm_hash.trim())
+    .bind(source.contract_id)
+    .bind(&source.version)
+    .bind(&link.inherited)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
@@ -0,0 +1,499 @@
+//! SEP-10 web authentication
+//!
+//! `GET /api/auth/challenge?account=G...` returns a challenge transaction
+//! built the way SEP-10 describes it: sequence number 0 so it can never be
+//! submitted, time bounds [`CHALLENGE_TTL_SECONDS`] long, a `ManageData`
+//! operation sourced from the client account named `<home_domain> auth`
+//! holding a random nonce, and a `web_auth_domain` operation sourced from the
+//! registry's server account, which signs it. The wallet adds the account's
+//! signature and posts it to `POST /api/auth/token` in exchange for a
+//! short-lived JWT (see `AuthManager::issue_session_jwt`).
+//!
+//! Only the account's master key is accepted: the registry does not look up
+//! on-chain signers, so an account whose master key was removed cannot log
+//! in, and muxed (`M...`) accounts are not supported.
+//!
+//! Configuration:
+//!
+//! - `SEP10_SIGNING_KEY` — the server account's secret (`S...`); without it a
+//!   key is generated per process, which is fine for a single instance but
+//!   not for several behind a load balancer
+//! - `SEP10_HOME_DOMAIN` — defaults to the host of `REGISTRY_PUBLIC_URL`,
+//!   else `localhost`
+//! - `SEP10_WEB_AUTH_DOMAIN` — defaults to the home domain
+//! - `STELLAR_NETWORK` — whose passphrase challenges are signed for;
+//!   `testnet` by default
+
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use once_cell::sync::Lazy;
+use rand::RngCore;
+use sha2::{Digest, Sha256};
+use shared::Network;
+use std::fmt;
+use stellar_xdr::curr::{
+    DataValue, DecoratedSignature, Hash, Limits, ManageDataOp, Memo, MuxedAccount, Operation,
+    OperationBody, Preconditions, ReadXdr, SequenceNumber, Signature, SignatureHint, String64,
+    TimeBounds, TimePoint, Transaction, TransactionEnvelope, TransactionExt,
+    TransactionSignaturePayload, TransactionSignaturePayloadTaggedTransaction,
+    TransactionV1Envelope, Uint256, WriteXdr,
+};
+
+use crate::anchor::network_passphrase;
+use crate::auth::VerifiedChallenge;
+
+/// How long a challenge can be signed and exchanged for a token.
+pub const CHALLENGE_TTL_SECONDS: u64 = 300;
+/// Leeway for a client clock running behind the challenge's `min_time`.
+const CLOCK_SKEW_SECONDS: u64 = 60;
+const BASE_FEE_STROOPS: u32 = 100;
+/// Random bytes in the nonce, base64-encoded to the 64 bytes SEP-10 expects.
+const NONCE_BYTES: usize = 48;
+const WEB_AUTH_DOMAIN_KEY: &str = "web_auth_domain";
+/// Upper bound on a posted challenge: two operations and two signatures.
+const MAX_TRANSACTION_BYTES: usize = 4 * 1024;
+
+/// Server key used when `SEP10_SIGNING_KEY` is unset, shared by every
+/// request this process serves.
+static EPHEMERAL_SIGNING_KEY: Lazy<SigningKey> = Lazy::new(|| {
+    tracing::warn!(
+        "SEP10_SIGNING_KEY is not set; signing web auth challenges with a per-process key"
+    );
+    SigningKey::generate(&mut rand::rngs::OsRng)
+});
+
+pub struct WebAuthConfig {
+    signing_key: SigningKey,
+    /// `G...` server account challenges are sourced from and signed by
+    pub account: String,
+    pub home_domain: String,
+    pub web_auth_domain: String,
+    pub network_passphrase: &'static str,
+    /// `iss` of the tokens issued for signed challenges
+    pub issuer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebAuthError {
+    InvalidConfig(String),
+    InvalidAccount,
+    Malformed,
+    NotFromServer,
+    Expired,
+    InvalidOperations,
+    MissingClientSignature,
+    UnexpectedSignature,
+}
+
+impl fmt::Display for WebAuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebAuthError::InvalidConfig(reason) => write!(f, "{}", reason),
+            WebAuthError::InvalidAccount => {
+                write!(f, "account must be a Stellar account ID (G...)")
+            }
+            WebAuthError::Malformed => write!(f, "transaction is not a challenge transaction"),
+            WebAuthError::NotFromServer => {
+                write!(f, "challenge was not issued by this registry")
+            }
+            WebAuthError::Expired => write!(f, "challenge has expired; request a new one"),
+            WebAuthError::InvalidOperations => {
+                write!(f, "challenge operations do not match this registry")
+            }
+            WebAuthError::MissingClientSignature => {
+                write!(f, "challenge is not signed by the account's key")
+            }
+            WebAuthError::UnexpectedSignature => {
+                write!(f, "challenge carries signatures from other keys")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WebAuthError {}
+
+impl WebAuthConfig {
+    pub fn from_env() -> Result<Self, WebAuthError> {
+        let signing_key = match std::env::var("SEP10_SIGNING_KEY") {
+            Ok(secret) => stellar_strkey::ed25519::PrivateKey::from_string(secret.trim())
+                .map(|key| SigningKey::from_bytes(&key.0))
+                .map_err(|_| {
+                    WebAuthError::InvalidConfig(
+                        "SEP10_SIGNING_KEY is not a valid Stellar secret key".to_string(),
+                    )
+                })?,
+            Err(_) => EPHEMERAL_SIGNING_KEY.clone(),
+        };
+        let home_domain = env_domain("SEP10_HOME_DOMAIN")
+            .or_else(|| {
+                crate::mailer::public_url()
+                    .and_then(|url| reqwest::Url::parse(&url).ok())
+                    .and_then(|url| url.host_str().map(str::to_string))
+            })
+            .unwrap_or_else(|| "localhost".to_string());
+        let web_auth_domain =
+            env_domain("SEP10_WEB_AUTH_DOMAIN").unwrap_or_else(|| home_domain.clone());
+        let network = match std::env::var("STELLAR_NETWORK")
+            .unwrap_or_else(|_| "testnet".to_string())
+            .to_lowercase()
+            .as_str()
+        {
+            "mainnet" => Network::Mainnet,
+            "testnet" => Network::Testnet,
+            "futurenet" => Network::Futurenet,
+            other => {
+                return Err(WebAuthError::InvalidConfig(format!(
+                    "STELLAR_NETWORK must be mainnet, testnet or futurenet (got {})",
+                    other
+                )))
+            }
+        };
+        Self::new(
+            signing_key,
+            home_domain,
+            web_auth_domain,
+            network_passphrase(&network),
+        )
+    }
+
+    fn new(
+        signing_key: SigningKey,
+        home_domain: String,
+        web_auth_domain: String,
+        network_passphrase: &'static str,
+    ) -> Result<Self, WebAuthError> {
+        // Both end up in 64-byte ManageData names and values.
+        if auth_data_name(&home_domain).len() > 64 || web_auth_domain.len() > 64 {
+            return Err(WebAuthError::InvalidConfig(
+                "SEP-10 home and web auth domains must be at most 59 characters".to_string(),
+            ));
+        }
+        let account = format!(
+            "{}",
+            stellar_strkey::ed25519::PublicKey(signing_key.verifying_key().to_bytes())
+        );
+        let issuer =
+            crate::mailer::public_url().unwrap_or_else(|| format!("https://{}", web_auth_domain));
+        Ok(Self {
+            signing_key,
+            account,
+            home_domain,
+            web_auth_domain,
+            network_passphrase,
+            issuer,
+        })
+    }
+
+    /// Build a challenge for `client_account` as a base64 envelope signed by
+    /// the server account.
+    pub fn challenge(
+        &self,
+        client_account: &str,
+        now: DateTime<Utc>,
+    ) -> Result<String, WebAuthError> {
+        let client = stellar_strkey::ed25519::PublicKey::from_string(client_account.trim())
+            .map_err(|_| WebAuthError::InvalidAccount)?;
+
+        let mut nonce = [0u8; NONCE_BYTES];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let nonce = base64::engine::general_purpose::STANDARD.encode(nonce);
+        let server = self.server_account();
+        let operations = vec![
+            manage_data(
+                MuxedAccount::Ed25519(Uint256(client.0)),
+                &auth_data_name(&self.home_domain),
+                nonce.as_bytes(),
+            )?,
+            manage_data(
+                server.clone(),
+                WEB_AUTH_DOMAIN_KEY,
+                self.web_auth_domain.as_bytes(),
+            )?,
+        ];
+        let now = now.timestamp().max(0) as u64;
+        let tx = Transaction {
+            source_account: server,
+            fee: BASE_FEE_STROOPS * operations.len() as u32,
+            seq_num: SequenceNumber(0),
+            cond: Preconditions::Time(TimeBounds {
+                min_time: TimePoint(now),
+                max_time: TimePoint(now + CHALLENGE_TTL_SECONDS),
+            }),
+            memo: Memo::None,
+            operations: operations.try_into().map_err(|_| WebAuthError::Malformed)?,
+            ext: TransactionExt::V0,
+        };
+
+        let hash = transaction_hash(&tx, self.network_passphrase)?;
+        let public_key = self.signing_key.verifying_key().to_bytes();
+        let signature = DecoratedSignature {
+            hint: signature_hint(&public_key),
+            signature: Signature(
+                self.signing_key
+                    .sign(&hash)
+                    .to_bytes()
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| WebAuthError::Malformed)?,
+            ),
+        };
+        let envelope = TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: vec![signature]
+                .try_into()
+                .map_err(|_| WebAuthError::Malformed)?,
+        });
+        envelope
+            .to_xdr_base64(Limits::none())
+            .map_err(|_| WebAuthError::Malformed)
+    }
+
+    /// Check a challenge the client signed: issued by this server, still
+    /// within its time bounds, and signed by exactly the server and the
+    /// client account named in its first operation.
+    pub fn verify(
+        &self,
+        transaction: &str,
+        now: DateTime<Utc>,
+    ) -> Result<VerifiedChallenge, WebAuthError> {
+        let limits = Limits {
+            depth: 32,
+            len: MAX_TRANSACTION_BYTES,
+        };
+        let TransactionEnvelope::Tx(envelope) =
+            TransactionEnvelope::from_xdr_base64(transaction.trim(), limits)
+                .map_err(|_| WebAuthError::Malformed)?
+        else {
+            return Err(WebAuthError::Malformed);
+        };
+        let tx = &envelope.tx;
+        let server = self.server_account();
+        if tx.source_account != server {
+            return Err(WebAuthError::NotFromServer);
+        }
+        if tx.seq_num != SequenceNumber(0) || tx.memo != Memo::None {
+            return Err(WebAuthError::Malformed);
+        }
+        let Preconditions::Time(bounds) = &tx.cond else {
+            return Err(WebAuthError::Malformed);
+        };
+        let now = now.timestamp().max(0) as u64;
+        if now + CLOCK_SKEW_SECONDS < bounds.min_time.0 || now > bounds.max_time.0 {
+            return Err(WebAuthError::Expired);
+        }
+
+        let (first, rest) = tx
+            .operations
+            .split_first()
+            .ok_or(WebAuthError::InvalidOperations)?;
+        let client = match (&first.source_account, &first.body) {
+            (Some(MuxedAccount::Ed25519(Uint256(client))), OperationBody::ManageData(op))
+                if op.data_name.0.as_slice() == auth_data_name(&self.home_domain).as_bytes()
+                    && op.data_value.as_ref().map(|value| value.0.len())
+                        == Some(NONCE_BYTES / 3 * 4) =>
+            {
+                *client
+            }
+            _ => return Err(WebAuthError::InvalidOperations),
+        };
+        for operation in rest {
+            let OperationBody::ManageData(op) = &operation.body else {
+                return Err(WebAuthError::InvalidOperations);
+            };
+            if operation.source_account.as_ref() != Some(&server) {
+                return Err(WebAuthError::InvalidOperations);
+            }
+            if op.data_name.0.as_slice() == WEB_AUTH_DOMAIN_KEY.as_bytes()
+                && op.data_value.as_ref().map(|value| value.0.as_slice())
+                    != Some(self.web_auth_domain.as_bytes())
+            {
+                return Err(WebAuthError::InvalidOperations);
+            }
+        }
+
+        let hash = transaction_hash(tx, self.network_passphrase)?;
+        let server_key = self.signing_key.verifying_key();
+        let client_key =
+            VerifyingKey::from_bytes(&client).map_err(|_| WebAuthError::InvalidOperations)?;
+        let (mut server_signed, mut client_signed) = (false, false);
+        for signature in envelope.signatures.iter() {
+            if !server_signed && signed_by(signature, &server_key, &hash) {
+                server_signed = true;
+            } else if !client_signed && signed_by(signature, &client_key, &hash) {
+                client_signed = true;
+            } else {
+                return Err(WebAuthError::UnexpectedSignature);
+            }
+        }
+        if !server_signed {
+            return Err(WebAuthError::NotFromServer);
+        }
+        if !client_signed {
+            return Err(WebAuthError::MissingClientSignature);
+        }
+
+        Ok(VerifiedChallenge {
+            account: format!("{}", stellar_strkey::ed25519::PublicKey(client)),
+            public_key: client,
+            hash,
+            expires_at: bounds.max_time.0.min(i64::MAX as u64) as i64,
+        })
+    }
+
+    fn server_account(&self) -> MuxedAccount {
+        MuxedAccount::Ed25519(Uint256(self.signing_key.verifying_key().to_bytes()))
+    }
+}
+
+fn env_domain(var: &str) -> Option<String> {
+    std::env::var(var)
+        .ok()
+        .map(|domain| domain.trim().to_string())
+        .filter(|domain| !domain.is_empty())
+}
+
+fn auth_data_name(home_domain: &str) -> String {
+    format!("{} auth", home_domain)
+}
+
+fn manage_data(source: MuxedAccount, name: &str, value: &[u8]) -> Result<Operation, WebAuthError> {
+    Ok(Operation {
+        source_account: Some(source),
+        body: OperationBody::ManageData(ManageDataOp {
+            data_name: String64(name.try_into().map_err(|_| WebAuthError::Malformed)?),
+            data_value: Some(DataValue(
+                value
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| WebAuthError::Malformed)?,
+            )),
+        }),
+    })
+}
+
+fn transaction_hash(tx: &Transaction, network_passphrase: &str) -> Result<[u8; 32], WebAuthError> {
+    let payload = TransactionSignaturePayload {
+        network_id: Hash(Sha256::digest(network_passphrase).into()),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+    let bytes = payload
+        .to_xdr(Limits::none())
+        .map_err(|_| WebAuthError::Malformed)?;
+    Ok(Sha256::digest(bytes).into())
+}
+
+fn signature_hint(public_key: &[u8; 32]) -> SignatureHint {
+    SignatureHint([
+        public_key[28],
+        public_key[29],
+        public_key[30],
+        public_key[31],
+    ])
+}
+
+fn signed_by(signature: &DecoratedSignature, key: &VerifyingKey, hash: &[u8; 32]) -> bool {
+    if signature.hint != signature_hint(key.as_bytes()) {
+        return false;
+    }
+    ed25519_dalek::Signature::from_slice(signature.signature.0.as_slice())
+        .map(|sig| key.verify(hash, &sig).is_ok())
+        .unwrap_or(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn config() -> WebAuthConfig {
+        WebAuthConfig::new(
+            SigningKey::from_bytes(&[5u8; 32]),
+            "registry.example".to_string(),
+            "api.registry.example".to_string(),
+            network_passphrase(&Network::Testnet),
+        )
+        .unwrap()
+    }
+
+    fn client_account(key: &SigningKey) -> String {
+        format!(
+            "{}",
+            stellar_strkey::ed25519::PublicKey(key.verifying_key().to_bytes())
+        )
+    }
+
+    /// Sign `challenge` the way a wallet would.
+    fn sign(challenge: &str, key: &SigningKey) -> String {
+        let TransactionEnvelope::Tx(mut envelope) =
+            TransactionEnvelope::from_xdr_base64(challenge, Limits::none()).unwrap()
+        else {
+            panic!("expected a v1 envelope");
+        };
+        let hash = transaction_hash(&envelope.tx, network_passphrase(&Network::Testnet)).unwrap();
+        let mut signatures = envelope.signatures.to_vec();
+        signatures.push(DecoratedSignature {
+            hint: signature_hint(key.verifying_key().as_bytes()),
+            signature: Signature(key.sign(&hash).to_bytes().to_vec().try_into().unwrap()),
+        });
+        envelope.signatures = signatures.try_into().unwrap();
+        TransactionEnvelope::Tx(envelope)
+            .to_xdr_base64(Limits::none())
+            .unwrap()
+    }
+
+    #[test]
+    fn signed_challenge_identifies_the_account() {
+        let config = config();
+        let client = SigningKey::from_bytes(&[6u8; 32]);
+        let now = Utc::now();
+        let challenge = config.challenge(&client_account(&client), now).unwrap();
+
+        assert_eq!(
+            config.verify(&challenge, now),
+            Err(WebAuthError::MissingClientSignature)
+        );
+        let verified = config.verify(&sign(&challenge, &client), now).unwrap();
+        assert_eq!(verified.account, client_account(&client));
+        assert_eq!(verified.public_key, client.verifying_key().to_bytes());
+        assert_eq!(
+            verified.expires_at,
+            now.timestamp() + CHALLENGE_TTL_SECONDS as i64
+        );
+    }
+
+    #[test]
+    fn rejects_stale_foreign_and_oversigned_challenges() {
+        let config = config();
+        let client = SigningKey::from_bytes(&[6u8; 32]);
+        let other = SigningKey::from_bytes(&[7u8; 32]);
+        let now = Utc::now();
+        let signed = sign(
+            &config.challenge(&client_account(&client), now).unwrap(),
+            &client,
+        );
+
+        let later = now + Duration::seconds(CHALLENGE_TTL_SECONDS as i64 + 1);
+        assert_eq!(config.verify(&signed, later), Err(WebAuthError::Expired));
+        assert_eq!(
+            config.verify(&sign(&signed, &other), now),
+            Err(WebAuthError::UnexpectedSignature)
+        );
+
+        let elsewhere = WebAuthConfig::new(
+            SigningKey::from_bytes(&[8u8; 32]),
+            "registry.example".to_string(),
+            "api.registry.example".to_string(),
+            network_passphrase(&Network::Testnet),
+        )
+        .unwrap();
+        assert_eq!(
+            elsewhere.verify(&signed, now),
+            Err(WebAuthError::NotFromServer)
+        );
+        assert_eq!(
+            config.challenge("not-an-account", now),
+            Err(WebAuthError::InvalidAccount)
+        );
+    }
+}
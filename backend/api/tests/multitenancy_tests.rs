@@ -20,11 +20,14 @@ async fn test_state() -> AppState {
     let (job_engine, _rx) = soroban_batch::engine::JobEngine::new();
     let (event_broadcaster, _) = tokio::sync::broadcast::channel(100);
 
+    let db: sqlx::PgPool = sqlx::pool::PoolOptions::new()
+        .max_connections(1)
+        .connect_lazy("postgres://localhost/test")
+        .unwrap();
+
     AppState {
-        db: sqlx::pool::PoolOptions::new()
-            .max_connections(1)
-            .connect_lazy("postgres://localhost/test")
-            .unwrap(),
+        db_read: db.clone(),
+        db,
         started_at: Instant::now(),
         cache: Arc::new(CacheLayer::new(CacheConfig::default()).await),
         registry,
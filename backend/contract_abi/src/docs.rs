@@ -0,0 +1,71 @@
+//! Documentation coverage of a contract spec.
+//!
+//! Counts the spec's functions and user-defined types (structs, unions,
+//! enums and error enums) and how many of them carry a non-empty doc string.
+
+use crate::parser::{ParseError, RawContractSpec};
+
+const DOCUMENTED_KINDS: [&str; 5] = ["function", "struct", "union", "enum", "error_enum"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DocsCoverage {
+    pub documented: usize,
+    pub total: usize,
+}
+
+impl DocsCoverage {
+    /// Fraction of items documented, from 0.0 to 1.0; `None` for a spec with
+    /// nothing to document.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.total > 0).then(|| self.documented as f64 / self.total as f64)
+    }
+}
+
+pub fn docs_coverage(specs: &[RawContractSpec]) -> DocsCoverage {
+    specs
+        .iter()
+        .filter(|spec| DOCUMENTED_KINDS.contains(&spec.spec_type.as_str()))
+        .fold(DocsCoverage::default(), |mut coverage, spec| {
+            coverage.total += 1;
+            if spec
+                .doc
+                .as_deref()
+                .is_some_and(|doc| !doc.trim().is_empty())
+            {
+                coverage.documented += 1;
+            }
+            coverage
+        })
+}
+
+pub fn json_docs_coverage(json: &str) -> Result<DocsCoverage, ParseError> {
+    let specs: Vec<RawContractSpec> = serde_json::from_str(json)
+        .map_err(|e| ParseError::new(format!("Failed to parse JSON: {}", e)))?;
+    Ok(docs_coverage(&specs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_documented_functions_and_types() {
+        let spec = r#"[
+            {"type": "function", "name": "transfer", "doc": "Move tokens"},
+            {"type": "function", "name": "balance", "doc": "  "},
+            {"type": "struct", "name": "Config", "doc": "Admin settings"},
+            {"type": "error_enum", "name": "Error"},
+            {"type": "event", "name": "Transfer", "doc": "Not counted"}
+        ]"#;
+        let coverage = json_docs_coverage(spec).unwrap();
+        assert_eq!(
+            coverage,
+            DocsCoverage {
+                documented: 2,
+                total: 4
+            }
+        );
+        assert_eq!(coverage.ratio(), Some(0.5));
+        assert_eq!(json_docs_coverage("[]").unwrap().ratio(), None);
+    }
+}
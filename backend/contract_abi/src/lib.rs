@@ -1,9 +1,11 @@
 //! Parse Soroban contract ABI and generate OpenAPI 3.0 documentation.
 
+pub mod docs;
 pub mod openapi;
 pub mod parser;
 pub mod types;
 
+pub use docs::{docs_coverage, json_docs_coverage, DocsCoverage};
 pub use openapi::{generate_openapi, to_json, to_yaml, OpenApiDoc};
 pub use parser::{parse_contract_abi, parse_json_spec, ParseError, RawContractSpec};
 pub use types::*;
@@ -62,13 +62,15 @@ impl DatabaseWriter {
                 "Contract already exists in database: {}",
                 deployment.contract_id
             );
+            self.record_deployer(deployment, network_str).await?;
             return Ok(false);
         }
 
         // Create a publisher record for the deployer if it doesn't exist
         let publisher_id = self.get_or_create_publisher(&deployment.deployer).await?;
 
-        // Insert new contract with is_verified = false
+        // Insert new contract with is_verified = false; it stays unclaimed until
+        // the deployer proves key control through the API claim flow
         let contract_id = Uuid::new_v4();
         let now = chrono::Utc::now();
         let slug = shared::slugify(&deployment.contract_id);
@@ -84,9 +86,11 @@ impl DatabaseWriter {
                 publisher_id,
                 network,
                 is_verified,
+                auto_indexed,
+                deployer_address,
                 created_at,
                 updated_at
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7::network_type, $8, $9, $10)
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7::network_type, $8, TRUE, $9, $10, $11)
         "#,
         )
         .bind(contract_id)
@@ -97,6 +101,7 @@ impl DatabaseWriter {
         .bind(publisher_id)
         .bind(network_str)
         .bind(false)
+        .bind(&deployment.deployer)
         .bind(now)
         .bind(now)
         .execute(&self.pool)
@@ -205,7 +210,7 @@ impl DatabaseWriter {
         let mut query_builder: QueryBuilder<sqlx::Postgres> = QueryBuilder::new(
             "INSERT INTO contracts \
              (id, contract_id, wasm_hash, name, slug, publisher_id, network, \
-              is_verified, created_at, updated_at) ",
+              is_verified, auto_indexed, deployer_address, created_at, updated_at) ",
         );
 
         query_builder.push_values(deployments.iter(), |mut b, deployment| {
@@ -220,6 +225,8 @@ impl DatabaseWriter {
                 .push_bind(publisher_id)
                 .push_bind(network_str)
                 .push_bind(false)
+                .push_bind(true)
+                .push_bind(&deployment.deployer)
                 .push_bind(now)
                 .push_bind(now);
         });
@@ -240,6 +247,30 @@ impl DatabaseWriter {
         let new_count = inserted_ids.len();
         let duplicate_count = deployments.len() - new_count;
 
+        // Contracts registered before the indexer saw them still get their
+        // on-chain deployer recorded.
+        for deployment in deployments
+            .iter()
+            .filter(|d| !inserted_ids.contains(&d.contract_id))
+        {
+            sqlx::query(
+                r#"
+                UPDATE contracts SET deployer_address = $1
+                WHERE contract_id = $2 AND network = $3::network_type
+                  AND deployer_address IS NULL
+                "#,
+            )
+            .bind(&deployment.deployer)
+            .bind(&deployment.contract_id)
+            .bind(network_str)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("Failed to record contract deployer: {}", e);
+                DatabaseError::SqlError(e.to_string())
+            })?;
+        }
+
         tx.commit().await.map_err(|e| {
             error!("Failed to commit transaction: {}", e);
             DatabaseError::SqlError(e.to_string())
@@ -253,6 +284,35 @@ impl DatabaseWriter {
         Ok((new_count, duplicate_count))
     }
 
+    /// Record the on-chain deployer of a contract that was registered before
+    /// the indexer saw its deployment. An already recorded deployer is kept.
+    async fn record_deployer(
+        &self,
+        deployment: &ContractDeployment,
+        network_str: &str,
+    ) -> Result<(), DatabaseError> {
+        sqlx::query(
+            r#"
+            UPDATE contracts SET deployer_address = $1
+            WHERE contract_id = $2 AND network = $3::network_type
+              AND deployer_address IS NULL
+            "#,
+        )
+        .bind(&deployment.deployer)
+        .bind(&deployment.contract_id)
+        .bind(network_str)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            error!(
+                "Failed to record deployer for contract {}: {}",
+                deployment.contract_id, e
+            );
+            DatabaseError::SqlError(e.to_string())
+        })?;
+        Ok(())
+    }
+
     /// Get or create a publisher record — transaction-scoped variant.
     ///
     /// Uses `DO NOTHING` + follow-up `SELECT` (same safe pattern as the
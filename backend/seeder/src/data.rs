@@ -41,15 +41,16 @@ const DESCRIPTIONS: &[&str] = &[
     "Identity verification system with privacy-preserving credentials",
 ];
 
+/// Paths in the category taxonomy seeded by the migrations.
 const CATEGORIES: &[&str] = &[
-    "DeFi",
-    "NFT",
-    "Governance",
-    "Infrastructure",
-    "Payment",
-    "Identity",
-    "Gaming",
-    "Social",
+    "defi/amm",
+    "defi/lending",
+    "nft/collection",
+    "governance",
+    "infra/oracle",
+    "payments",
+    "identity",
+    "gaming",
 ];
 
 const TAGS_POOL: &[&str] = &[
@@ -297,6 +298,179 @@ pub async fn create_verifications(
     Ok(count)
 }
 
+pub async fn create_dependencies(
+    pool: &PgPool,
+    contracts: &[Contract],
+    rng: &mut StdRng,
+) -> Result<usize> {
+    let mut count = 0;
+
+    // Only depend on earlier contracts so the seeded graph is acyclic.
+    for (i, contract) in contracts.iter().enumerate().skip(1) {
+        let dep_count = rng.gen_range(0..=2).min(i);
+        for _ in 0..dep_count {
+            let dependency = &contracts[rng.gen_range(0..i)];
+            let constraint = format!("^1.{}", rng.gen_range(0..3));
+
+            let result = sqlx::query(
+                "INSERT INTO contract_dependencies
+                    (contract_id, dependency_name, dependency_contract_id, version_constraint)
+                 VALUES ($1, $2, $3, $4)
+                 ON CONFLICT (contract_id, dependency_name) DO NOTHING",
+            )
+            .bind(contract.id)
+            .bind(&dependency.name)
+            .bind(dependency.id)
+            .bind(&constraint)
+            .execute(pool)
+            .await
+            .context("Failed to create dependency")?;
+
+            count += result.rows_affected() as usize;
+        }
+    }
+
+    Ok(count)
+}
+
+pub async fn create_events(
+    pool: &PgPool,
+    contracts: &[Contract],
+    publishers: &[Publisher],
+    rng: &mut StdRng,
+) -> Result<usize> {
+    const EVENT_TYPES: &[&str] = &[
+        "contract_published",
+        "contract_verified",
+        "contract_deployed",
+        "version_created",
+    ];
+    let mut count = 0;
+
+    // Spread activity over the last 30 days so analytics charts have shape.
+    for contract in contracts {
+        // Re-runs leave existing history alone instead of piling on more events.
+        let existing: i64 =
+            sqlx::query_scalar("SELECT COUNT(*) FROM analytics_events WHERE contract_id = $1")
+                .bind(contract.id)
+                .fetch_one(pool)
+                .await
+                .context("Failed to count analytics events")?;
+        if existing > 0 {
+            continue;
+        }
+
+        let event_count = rng.gen_range(3..=12);
+        for _ in 0..event_count {
+            let event_type = EVENT_TYPES[rng.gen_range(0..EVENT_TYPES.len())];
+            let user = &publishers[rng.gen_range(0..publishers.len())];
+            let created_at =
+                chrono::Utc::now() - chrono::Duration::minutes(rng.gen_range(0..30 * 24 * 60));
+
+            sqlx::query(
+                "INSERT INTO analytics_events (event_type, contract_id, user_address, network, created_at)
+                 VALUES ($1::analytics_event_type, $2, $3, $4, $5)",
+            )
+            .bind(event_type)
+            .bind(contract.id)
+            .bind(&user.stellar_address)
+            .bind(&contract.network)
+            .bind(created_at)
+            .execute(pool)
+            .await
+            .context("Failed to create analytics event")?;
+
+            count += 1;
+        }
+    }
+
+    Ok(count)
+}
+
+pub async fn create_patches(pool: &PgPool, rng: &mut StdRng) -> Result<usize> {
+    const PATCHES: &[(&str, &str, &str)] = &[
+        ("1.1.0", "critical", "Fix unchecked arithmetic in reward accrual"),
+        ("1.2.0", "high", "Enforce authorization on admin-only setters"),
+        ("1.1.0", "medium", "Bound storage growth of per-user history"),
+        ("1.3.0", "low", "Emit missing transfer events"),
+    ];
+
+    let mut count = 0;
+
+    for (target_version, severity, description) in PATCHES {
+        let result = sqlx::query(
+            "INSERT INTO security_patches (target_version, severity, new_wasm_hash, rollout_percentage, description)
+             SELECT $1, $2::patch_severity, $3, $4, $5
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM security_patches WHERE target_version = $1 AND description = $5
+             )",
+        )
+        .bind(target_version)
+        .bind(severity)
+        .bind(generate_wasm_hash(rng))
+        .bind([10, 50, 100][rng.gen_range(0..3)])
+        .bind(description)
+        .execute(pool)
+        .await
+        .context("Failed to create security patch")?;
+
+        count += result.rows_affected() as usize;
+    }
+
+    Ok(count)
+}
+
+pub async fn create_proposals(
+    pool: &PgPool,
+    contracts: &[Contract],
+    publishers: &[Publisher],
+    rng: &mut StdRng,
+) -> Result<usize> {
+    const TITLES: &[&str] = &[
+        "Raise protocol fee to 0.3%",
+        "Add new oracle price source",
+        "Upgrade to the latest contract version",
+        "Extend staking reward period",
+        "Rotate admin key to multisig",
+    ];
+    const STATUSES: &[&str] = &["pending", "active", "passed", "rejected", "executed"];
+    const MODELS: &[&str] = &["token_weighted", "quadratic", "multisig", "timelock"];
+    let mut count = 0;
+
+    for contract in contracts.iter().step_by(4) {
+        let title = TITLES[rng.gen_range(0..TITLES.len())];
+        let status = STATUSES[rng.gen_range(0..STATUSES.len())];
+        let proposer = &publishers[rng.gen_range(0..publishers.len())];
+        let starts_at = chrono::Utc::now() - chrono::Duration::days(rng.gen_range(0..14));
+        let ends_at = starts_at + chrono::Duration::days(7);
+
+        let result = sqlx::query(
+            "INSERT INTO governance_proposals
+                (contract_id, title, description, governance_model, proposer, status,
+                 voting_starts_at, voting_ends_at)
+             SELECT $1, $2, $3, $4::governance_model, $5, $6::governance_proposal_status, $7, $8
+             WHERE NOT EXISTS (
+                 SELECT 1 FROM governance_proposals WHERE contract_id = $1 AND title = $2
+             )",
+        )
+        .bind(contract.id)
+        .bind(title)
+        .bind(format!("{} for {}.", title, contract.name))
+        .bind(MODELS[rng.gen_range(0..MODELS.len())])
+        .bind(proposer.id)
+        .bind(status)
+        .bind(starts_at)
+        .bind(ends_at)
+        .execute(pool)
+        .await
+        .context("Failed to create governance proposal")?;
+
+        count += result.rows_affected() as usize;
+    }
+
+    Ok(count)
+}
+
 fn generate_stellar_address(rng: &mut StdRng) -> String {
     const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
     let mut address = String::from("G");
@@ -92,6 +92,18 @@ async fn main() -> Result<()> {
     let verifications = data::create_verifications(&pool, &contracts, &mut rng).await?;
     println!("{} Created {} verifications", "✓".green(), verifications);
 
+    let dependencies = data::create_dependencies(&pool, &contracts, &mut rng).await?;
+    println!("{} Created {} dependency edges", "✓".green(), dependencies);
+
+    let events = data::create_events(&pool, &contracts, &publishers, &mut rng).await?;
+    println!("{} Created {} analytics events", "✓".green(), events);
+
+    let patches = data::create_patches(&pool, &mut rng).await?;
+    println!("{} Created {} security patches", "✓".green(), patches);
+
+    let proposals = data::create_proposals(&pool, &contracts, &publishers, &mut rng).await?;
+    println!("{} Created {} governance proposals", "✓".green(), proposals);
+
     let elapsed = start_time.elapsed();
     println!();
     println!("{}", "=".repeat(80).cyan());
@@ -0,0 +1,128 @@
+//! On-chain anchoring of registry records.
+//!
+//! A contract's registry record is committed to as a SHA-256 digest over the
+//! fields that identify and describe it (including its WASM hash). The API
+//! writes that digest into a Stellar account's data entries; anyone can
+//! recompute it from the registry's response and compare it with the ledger
+//! to detect tampering.
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::models::{Contract, Network};
+
+/// Prefix of the data entry name; the rest is the on-chain contract id.
+pub const ANCHOR_DATA_NAME_PREFIX: &str = "sr:";
+const DIGEST_DOMAIN: &str = "soroban-registry-anchor-v1";
+
+/// The anchored subset of a contract record.
+#[derive(Debug, Clone, Copy)]
+pub struct AnchorFields<'a> {
+    pub contract_id: &'a str,
+    pub network: &'a str,
+    pub name: &'a str,
+    pub description: Option<&'a str>,
+    pub category: Option<&'a str>,
+    pub publisher_id: Uuid,
+    pub wasm_hash: &'a str,
+}
+
+impl AnchorFields<'_> {
+    /// SHA-256 over the length-prefixed fields, so no two records collide by
+    /// shifting text between fields.
+    pub fn digest(&self) -> [u8; 32] {
+        let publisher_id = self.publisher_id.to_string();
+        let mut hasher = Sha256::new();
+        for field in [
+            DIGEST_DOMAIN,
+            self.contract_id,
+            self.network,
+            self.name,
+            self.description.unwrap_or_default(),
+            self.category.unwrap_or_default(),
+            &publisher_id,
+            self.wasm_hash,
+        ] {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hasher.finalize().into()
+    }
+
+    pub fn digest_hex(&self) -> String {
+        hex::encode(self.digest())
+    }
+}
+
+impl Contract {
+    pub fn anchor_fields(&self) -> AnchorFields<'_> {
+        AnchorFields {
+            contract_id: &self.contract_id,
+            network: match self.network {
+                Network::Mainnet => "mainnet",
+                Network::Testnet => "testnet",
+                Network::Futurenet => "futurenet",
+            },
+            name: &self.name,
+            description: self.description.as_deref(),
+            category: self.category.as_deref(),
+            publisher_id: self.publisher_id,
+            wasm_hash: &self.wasm_hash,
+        }
+    }
+}
+
+/// Data entry name holding the digest for `contract_id` (at most 64 bytes).
+pub fn anchor_data_name(contract_id: &str) -> String {
+    format!("{}{}", ANCHOR_DATA_NAME_PREFIX, contract_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> AnchorFields<'static> {
+        AnchorFields {
+            contract_id: "CCONTRACT",
+            network: "testnet",
+            name: "Token",
+            description: Some("A token"),
+            category: None,
+            publisher_id: Uuid::nil(),
+            wasm_hash: "abc123",
+        }
+    }
+
+    #[test]
+    fn digest_is_stable_and_field_sensitive() {
+        let base = fields();
+        assert_eq!(base.digest(), fields().digest());
+        assert_eq!(base.digest_hex().len(), 64);
+
+        let renamed = AnchorFields {
+            name: "Token2",
+            ..base
+        };
+        assert_ne!(base.digest(), renamed.digest());
+
+        // Moving text from one field to its neighbour must change the digest.
+        let shifted = AnchorFields {
+            name: "TokenA",
+            description: Some(" token"),
+            ..base
+        };
+        assert_ne!(base.digest(), shifted.digest());
+
+        let rebuilt = AnchorFields {
+            wasm_hash: "def456",
+            ..base
+        };
+        assert_ne!(base.digest(), rebuilt.digest());
+    }
+
+    #[test]
+    fn data_name_fits_stellar_limit() {
+        let id = "C".repeat(56);
+        assert!(anchor_data_name(&id).len() <= 64);
+    }
+}
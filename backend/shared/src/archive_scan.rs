@@ -0,0 +1,442 @@
+//! Validation of uploaded and downloaded tar archives.
+//!
+//! Source tarballs and `export` archives come from publishers, so they are
+//! checked before anything is extracted. An entry is rejected when its path is
+//! absolute or climbs out with `..`, when it is a symlink, hard link or device
+//! node, when it is over the per-entry size limit, when it is a native
+//! executable, or when its content does not match its extension (a `.rs` file
+//! holding binary data). The scan stops early, with a rejection, once the
+//! archive has more entries or unpacks to more bytes than the limits allow, so
+//! a decompression bomb is never fully inflated.
+//!
+//! Every problem is collected into an [`ArchiveReport`] rather than failing on
+//! the first one, so publishers can fix them all at once.
+
+use flate2::read::GzDecoder;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::{self, Read};
+use std::path::{Component, Path, PathBuf};
+
+/// Bytes of each entry read to sniff its content type.
+const SNIFF_BYTES: usize = 512;
+
+const DEFAULT_MAX_ENTRIES: u64 = 10_000;
+const DEFAULT_MAX_ENTRY_MB: u64 = 64;
+const DEFAULT_MAX_UNPACKED_MB: u64 = 256;
+
+/// Extensions whose content must be text.
+const TEXT_EXTENSIONS: &[&str] = &[
+    "rs", "toml", "lock", "json", "md", "txt", "yml", "yaml", "sh", "ts", "js",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArchiveLimits {
+    pub max_entries: u64,
+    pub max_entry_bytes: u64,
+    pub max_unpacked_bytes: u64,
+}
+
+impl Default for ArchiveLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_entry_bytes: DEFAULT_MAX_ENTRY_MB * 1024 * 1024,
+            max_unpacked_bytes: DEFAULT_MAX_UNPACKED_MB * 1024 * 1024,
+        }
+    }
+}
+
+impl ArchiveLimits {
+    /// Limits from ARCHIVE_MAX_ENTRIES, ARCHIVE_MAX_ENTRY_MB and
+    /// ARCHIVE_MAX_UNPACKED_MB, falling back to the defaults.
+    pub fn from_env() -> Self {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .and_then(|v| v.parse::<u64>().ok())
+                .filter(|v| *v > 0)
+        };
+        let defaults = Self::default();
+        Self {
+            max_entries: var("ARCHIVE_MAX_ENTRIES").unwrap_or(defaults.max_entries),
+            max_entry_bytes: var("ARCHIVE_MAX_ENTRY_MB")
+                .map(|mb| mb * 1024 * 1024)
+                .unwrap_or(defaults.max_entry_bytes),
+            max_unpacked_bytes: var("ARCHIVE_MAX_UNPACKED_MB")
+                .map(|mb| mb * 1024 * 1024)
+                .unwrap_or(defaults.max_unpacked_bytes),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RejectReason {
+    PathTraversal,
+    AbsolutePath,
+    Symlink,
+    HardLink,
+    SpecialFile,
+    EntryTooLarge,
+    TooManyEntries,
+    ArchiveTooLarge,
+    Executable,
+    ContentMismatch,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RejectedEntry {
+    pub path: String,
+    pub reason: RejectReason,
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ArchiveReport {
+    /// Entries scanned, including rejected ones
+    pub entries: u64,
+    /// Total size of the scanned entries once unpacked
+    pub unpacked_bytes: u64,
+    pub rejected: Vec<RejectedEntry>,
+}
+
+impl ArchiveReport {
+    pub fn is_clean(&self) -> bool {
+        self.rejected.is_empty()
+    }
+
+    fn reject(&mut self, path: impl Into<String>, reason: RejectReason, detail: impl Into<String>) {
+        self.rejected.push(RejectedEntry {
+            path: path.into(),
+            reason,
+            detail: detail.into(),
+        });
+    }
+}
+
+/// An archive with at least one rejected entry; nothing was extracted.
+#[derive(Debug)]
+pub struct ArchiveRejected(pub ArchiveReport);
+
+impl fmt::Display for ArchiveRejected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "archive rejected: {} of {} entries failed validation",
+            self.0.rejected.len(),
+            self.0.entries
+        )?;
+        for entry in &self.0.rejected {
+            write!(f, "\n  {}: {}", entry.path, entry.detail)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ArchiveRejected {}
+
+/// The path an entry may be extracted to, relative to the destination, or
+/// why it may not. `./` components are dropped; `None` means the entry names
+/// the destination itself.
+pub fn safe_relative_path(path: &Path) -> Result<Option<PathBuf>, RejectReason> {
+    let mut safe = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => safe.push(part),
+            Component::CurDir => {}
+            Component::ParentDir => return Err(RejectReason::PathTraversal),
+            Component::RootDir | Component::Prefix(_) => return Err(RejectReason::AbsolutePath),
+        }
+    }
+    Ok((!safe.as_os_str().is_empty()).then_some(safe))
+}
+
+/// The content type `bytes` start like. Only the formats the registry cares
+/// about are told apart; anything else is text or octet-stream.
+pub fn sniff(bytes: &[u8]) -> &'static str {
+    const MAGIC: &[(&[u8], &str)] = &[
+        (b"\0asm", "application/wasm"),
+        (b"\x7fELF", "application/x-executable"),
+        (b"MZ", "application/vnd.microsoft.portable-executable"),
+        (b"\xfe\xed\xfa\xce", "application/x-mach-binary"),
+        (b"\xfe\xed\xfa\xcf", "application/x-mach-binary"),
+        (b"\xce\xfa\xed\xfe", "application/x-mach-binary"),
+        (b"\xcf\xfa\xed\xfe", "application/x-mach-binary"),
+        (b"\xca\xfe\xba\xbe", "application/x-mach-binary"),
+        (b"\x1f\x8b", "application/gzip"),
+        (b"\x28\xb5\x2f\xfd", "application/zstd"),
+        (b"PK\x03\x04", "application/zip"),
+        (b"%PDF-", "application/pdf"),
+    ];
+    if let Some((_, mime)) = MAGIC.iter().find(|(magic, _)| bytes.starts_with(magic)) {
+        return mime;
+    }
+    if looks_like_text(bytes) {
+        "text/plain"
+    } else {
+        "application/octet-stream"
+    }
+}
+
+/// UTF-8 without NUL bytes; a character cut off at the end of a sniffed
+/// prefix does not count against it.
+fn looks_like_text(bytes: &[u8]) -> bool {
+    let valid = match std::str::from_utf8(bytes) {
+        Ok(_) => true,
+        Err(err) => err.error_len().is_none(),
+    };
+    valid && !bytes.contains(&0)
+}
+
+fn is_executable(mime: &str) -> bool {
+    matches!(
+        mime,
+        "application/x-executable"
+            | "application/vnd.microsoft.portable-executable"
+            | "application/x-mach-binary"
+    )
+}
+
+/// Scans a tar stream without extracting it.
+pub fn scan_tar<R: Read>(reader: R, limits: &ArchiveLimits) -> io::Result<ArchiveReport> {
+    let mut archive = tar::Archive::new(reader);
+    let mut report = ArchiveReport::default();
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let kind = entry.header().entry_type();
+        if kind.is_pax_global_extensions() {
+            continue;
+        }
+        let path = entry.path()?.into_owned();
+        let name = path.to_string_lossy().into_owned();
+        let size = entry.size();
+
+        report.entries += 1;
+        if report.entries > limits.max_entries {
+            report.reject(
+                name,
+                RejectReason::TooManyEntries,
+                format!("archive has more than {} entries", limits.max_entries),
+            );
+            break;
+        }
+        report.unpacked_bytes += size;
+        if report.unpacked_bytes > limits.max_unpacked_bytes {
+            report.reject(
+                name,
+                RejectReason::ArchiveTooLarge,
+                format!(
+                    "archive unpacks to more than {} bytes",
+                    limits.max_unpacked_bytes
+                ),
+            );
+            break;
+        }
+
+        match safe_relative_path(&path) {
+            Err(RejectReason::PathTraversal) => {
+                report.reject(
+                    name,
+                    RejectReason::PathTraversal,
+                    "path escapes the extraction directory",
+                );
+                continue;
+            }
+            Err(reason) => {
+                report.reject(name, reason, "path is absolute");
+                continue;
+            }
+            Ok(_) => {}
+        }
+
+        if kind.is_symlink() {
+            let target = entry
+                .link_name()?
+                .map(|t| t.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            report.reject(
+                name,
+                RejectReason::Symlink,
+                format!("symlink to {}", target),
+            );
+            continue;
+        }
+        if kind.is_hard_link() {
+            report.reject(name, RejectReason::HardLink, "hard link");
+            continue;
+        }
+        if kind.is_dir() {
+            continue;
+        }
+        if !kind.is_file() {
+            report.reject(
+                name,
+                RejectReason::SpecialFile,
+                "device, FIFO or other special file",
+            );
+            continue;
+        }
+        if size > limits.max_entry_bytes {
+            report.reject(
+                name,
+                RejectReason::EntryTooLarge,
+                format!(
+                    "{} bytes, over the {} byte limit per file",
+                    size, limits.max_entry_bytes
+                ),
+            );
+            continue;
+        }
+
+        let mut head = Vec::with_capacity(SNIFF_BYTES);
+        (&mut entry)
+            .take(SNIFF_BYTES as u64)
+            .read_to_end(&mut head)?;
+        let mime = sniff(&head);
+        if is_executable(mime) {
+            report.reject(
+                name,
+                RejectReason::Executable,
+                format!("native executable ({})", mime),
+            );
+            continue;
+        }
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        let expects_text = extension
+            .as_deref()
+            .is_some_and(|ext| TEXT_EXTENSIONS.contains(&ext));
+        if expects_text && mime != "text/plain" {
+            report.reject(
+                name,
+                RejectReason::ContentMismatch,
+                format!(".{} file holds {}", extension.unwrap_or_default(), mime),
+            );
+        }
+    }
+
+    Ok(report)
+}
+
+/// Scans a gzipped tar stream without extracting it.
+pub fn scan_tar_gz<R: Read>(reader: R, limits: &ArchiveLimits) -> io::Result<ArchiveReport> {
+    scan_tar(GzDecoder::new(reader), limits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn append(builder: &mut tar::Builder<Vec<u8>>, path: &str, contents: &[u8]) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        // `append_data` refuses `..`, so write the name into the header directly.
+        header.as_gnu_mut().unwrap().name[..path.len()].copy_from_slice(path.as_bytes());
+        header.set_cksum();
+        builder.append(&header, contents).unwrap();
+    }
+
+    fn archive(build: impl FnOnce(&mut tar::Builder<Vec<u8>>)) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        build(&mut builder);
+        builder.into_inner().unwrap()
+    }
+
+    fn reasons(report: &ArchiveReport) -> Vec<(&str, RejectReason)> {
+        report
+            .rejected
+            .iter()
+            .map(|entry| (entry.path.as_str(), entry.reason))
+            .collect()
+    }
+
+    #[test]
+    fn clean_archives_pass() {
+        let bytes = archive(|b| {
+            append(b, "Cargo.toml", b"[package]\nname = \"c\"\n");
+            append(b, "./src/lib.rs", b"#![no_std]\n");
+            append(b, "target/c.wasm", b"\0asm\x01\0\0\0");
+        });
+        let report = scan_tar(bytes.as_slice(), &ArchiveLimits::default()).unwrap();
+        assert!(report.is_clean(), "{:?}", report.rejected);
+        assert_eq!(report.entries, 3);
+    }
+
+    #[test]
+    fn unsafe_entries_are_all_reported() {
+        let bytes = archive(|b| {
+            append(b, "../escape.rs", b"fn main() {}");
+            append(b, "/etc/passwd", b"root");
+            append(b, "src/lib.rs", b"\x7fELF\x02\x01");
+            append(b, "README.md", b"\0\0\0\0binary");
+            let mut link = tar::Header::new_gnu();
+            link.set_entry_type(tar::EntryType::Symlink);
+            link.set_size(0);
+            b.append_link(&mut link, "src/key", "/root/.ssh/id_ed25519")
+                .unwrap();
+        });
+        let report = scan_tar(bytes.as_slice(), &ArchiveLimits::default()).unwrap();
+        assert_eq!(
+            reasons(&report),
+            vec![
+                ("../escape.rs", RejectReason::PathTraversal),
+                ("/etc/passwd", RejectReason::AbsolutePath),
+                ("src/lib.rs", RejectReason::Executable),
+                ("README.md", RejectReason::ContentMismatch),
+                ("src/key", RejectReason::Symlink),
+            ]
+        );
+        assert_eq!(
+            report.rejected[4].detail,
+            "symlink to /root/.ssh/id_ed25519"
+        );
+    }
+
+    #[test]
+    fn scanning_stops_at_the_limits() {
+        let limits = ArchiveLimits {
+            max_entries: 2,
+            max_entry_bytes: 4,
+            max_unpacked_bytes: 10,
+        };
+        let bytes = archive(|b| {
+            append(b, "a.txt", b"12345");
+            append(b, "b.txt", b"1");
+            append(b, "c.txt", b"1");
+        });
+        let report = scan_tar(bytes.as_slice(), &limits).unwrap();
+        assert_eq!(
+            reasons(&report),
+            vec![
+                ("a.txt", RejectReason::EntryTooLarge),
+                ("c.txt", RejectReason::TooManyEntries),
+            ]
+        );
+
+        let bytes = archive(|b| append(b, "big.txt", &[b'x'; 11]));
+        let report = scan_tar(bytes.as_slice(), &limits).unwrap();
+        assert_eq!(
+            reasons(&report),
+            vec![("big.txt", RejectReason::ArchiveTooLarge)]
+        );
+    }
+
+    #[test]
+    fn sniffs_common_formats() {
+        assert_eq!(sniff(b"\0asm\x01\0\0\0"), "application/wasm");
+        assert_eq!(sniff(b"\x1f\x8b\x08"), "application/gzip");
+        assert_eq!(
+            sniff(b"MZ\x90\0"),
+            "application/vnd.microsoft.portable-executable"
+        );
+        assert_eq!(sniff("pub fn ünïcode() {}".as_bytes()), "text/plain");
+        // A multi-byte character cut off by the sniff window is still text.
+        assert_eq!(sniff(&"é".as_bytes()[..1]), "text/plain");
+        assert_eq!(sniff(b"\xff\xfe\0"), "application/octet-stream");
+    }
+}
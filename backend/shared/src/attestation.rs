@@ -0,0 +1,153 @@
+//! Contract badge attestations.
+//!
+//! A badge is granted by an attestation authority signing a statement about a
+//! specific build of a contract. The statement's text is defined here so the
+//! registry and the CLI sign and verify exactly the same bytes.
+
+use chrono::{DateTime, Utc};
+
+pub const AUDITED_BY: &str = "audited-by";
+pub const VERIFIED_SOURCE: &str = "verified-source";
+pub const INTERFACE_CONFORMANT: &str = "interface-conformant";
+pub const TEAM_VERIFIED: &str = "team-verified";
+/// The build's test suite was run and passed
+pub const TESTED: &str = "tested";
+
+/// Every badge kind an authority can be allowed to grant.
+pub const BADGE_KINDS: [&str; 5] = [
+    AUDITED_BY,
+    VERIFIED_SOURCE,
+    INTERFACE_CONFORMANT,
+    TEAM_VERIFIED,
+    TESTED,
+];
+
+pub fn is_badge_kind(kind: &str) -> bool {
+    BADGE_KINDS.contains(&kind)
+}
+
+/// What an authority attests to. `wasm_hash` pins the badge to one build.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttestationStatement<'a> {
+    pub network: &'a str,
+    pub contract_id: &'a str,
+    pub kind: &'a str,
+    pub wasm_hash: &'a str,
+    /// Link to the audit report or other evidence
+    pub report_url: Option<&'a str>,
+    pub issued_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+impl AttestationStatement<'_> {
+    /// Rejects statements whose fields could not be told apart in the signed
+    /// text, so one signature can never be read as a different statement.
+    pub fn validate(&self) -> Result<(), String> {
+        if !is_badge_kind(self.kind) {
+            return Err(format!(
+                "Unknown badge kind '{}'; expected one of {}",
+                self.kind,
+                BADGE_KINDS.join(", ")
+            ));
+        }
+        let fields = [
+            ("network", Some(self.network)),
+            ("contract_id", Some(self.contract_id)),
+            ("wasm_hash", Some(self.wasm_hash)),
+            ("report_url", self.report_url),
+        ];
+        for (name, value) in fields {
+            if value.is_some_and(|v| v.is_empty() || v.contains(['\n', '\r'])) {
+                return Err(format!("{} must be a non-empty single line", name));
+            }
+        }
+        if self.expires_at.is_some_and(|at| at <= self.issued_at) {
+            return Err("expires_at must be after issued_at".to_string());
+        }
+        Ok(())
+    }
+
+    /// The exact text the authority signs with its ed25519 key.
+    pub fn signing_message(&self) -> String {
+        format!(
+            "soroban-registry attestation v1\n\
+             network: {}\n\
+             contract: {}\n\
+             badge: {}\n\
+             wasm_hash: {}\n\
+             report_url: {}\n\
+             issued_at: {}\n\
+             expires_at: {}",
+            self.network,
+            self.contract_id,
+            self.kind,
+            self.wasm_hash,
+            self.report_url.unwrap_or("-"),
+            self.issued_at.timestamp(),
+            self.expires_at
+                .map(|at| at.timestamp().to_string())
+                .unwrap_or_else(|| "-".to_string()),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn statement() -> AttestationStatement<'static> {
+        AttestationStatement {
+            network: "mainnet",
+            contract_id: "CABC",
+            kind: AUDITED_BY,
+            wasm_hash: "deadbeef",
+            report_url: Some("https://audits.example/report.pdf"),
+            issued_at: Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+            expires_at: None,
+        }
+    }
+
+    #[test]
+    fn message_is_stable() {
+        assert_eq!(
+            statement().signing_message(),
+            "soroban-registry attestation v1\n\
+             network: mainnet\n\
+             contract: CABC\n\
+             badge: audited-by\n\
+             wasm_hash: deadbeef\n\
+             report_url: https://audits.example/report.pdf\n\
+             issued_at: 1775001600\n\
+             expires_at: -"
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_kinds_and_multiline_fields() {
+        assert!(statement().validate().is_ok());
+        assert!(AttestationStatement {
+            kind: "gold-star",
+            ..statement()
+        }
+        .validate()
+        .is_err());
+        assert!(AttestationStatement {
+            report_url: Some("https://a.example\nbadge: team-verified"),
+            ..statement()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_expiry_before_issue() {
+        let s = statement();
+        assert!(AttestationStatement {
+            expires_at: Some(s.issued_at),
+            ..s
+        }
+        .validate()
+        .is_err());
+    }
+}
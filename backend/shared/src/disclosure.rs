@@ -0,0 +1,137 @@
+//! Coordinated disclosure reports.
+//!
+//! Reports are sealed to the contract publisher's Stellar account key as soon
+//! as they arrive, so the registry only ever stores ciphertext. The scheme is
+//! shared with the CLI, which opens reports locally with the publisher's
+//! secret key:
+//!
+//!   * the ed25519 account key is converted to its X25519 form,
+//!   * an ephemeral X25519 key agrees a secret with it,
+//!   * SHA-256 over the secret and both public keys gives an AES-256-GCM key.
+
+use aes_gcm::{
+    aead::{Aead, KeyInit},
+    Aes256Gcm, Nonce,
+};
+use curve25519_dalek::{edwards::CompressedEdwardsY, montgomery::MontgomeryPoint};
+use rand::{rngs::OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256, Sha512};
+
+const KEY_CONTEXT: &[u8] = b"soroban-registry disclosure v1";
+
+/// What a researcher submits; sealed as JSON.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DisclosureMessage {
+    pub title: String,
+    pub body: String,
+    /// How the maintainers can reach the researcher, if they want a reply
+    pub contact: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SealedReport {
+    pub ephemeral_public: [u8; 32],
+    pub nonce: [u8; 12],
+    pub ciphertext: Vec<u8>,
+}
+
+fn cipher(
+    shared: &MontgomeryPoint,
+    ephemeral: &MontgomeryPoint,
+    recipient: &MontgomeryPoint,
+) -> Result<Aes256Gcm, String> {
+    // A low-order recipient or ephemeral key yields the identity; nothing
+    // sealed that way would be secret.
+    if shared.to_bytes() == [0u8; 32] {
+        return Err("Key agreement produced a degenerate secret".to_string());
+    }
+    let key = Sha256::new()
+        .chain_update(KEY_CONTEXT)
+        .chain_update(shared.as_bytes())
+        .chain_update(ephemeral.as_bytes())
+        .chain_update(recipient.as_bytes())
+        .finalize();
+    Aes256Gcm::new_from_slice(&key).map_err(|e| e.to_string())
+}
+
+/// Seals `message` to the holder of the ed25519 public key `recipient`.
+pub fn seal(recipient: &[u8; 32], message: &DisclosureMessage) -> Result<SealedReport, String> {
+    let recipient = CompressedEdwardsY(*recipient)
+        .decompress()
+        .ok_or("Recipient is not a valid ed25519 public key")?
+        .to_montgomery();
+
+    let mut secret = [0u8; 32];
+    OsRng.fill_bytes(&mut secret);
+    let ephemeral = MontgomeryPoint::mul_base_clamped(secret);
+    let shared = recipient.mul_clamped(secret);
+
+    let mut nonce = [0u8; 12];
+    OsRng.fill_bytes(&mut nonce);
+    let plaintext = serde_json::to_vec(message).map_err(|e| e.to_string())?;
+    let ciphertext = cipher(&shared, &ephemeral, &recipient)?
+        .encrypt(Nonce::from_slice(&nonce), plaintext.as_slice())
+        .map_err(|_| "Failed to seal report".to_string())?;
+
+    Ok(SealedReport {
+        ephemeral_public: ephemeral.to_bytes(),
+        nonce,
+        ciphertext,
+    })
+}
+
+/// Opens a report with the recipient's 32-byte ed25519 seed.
+pub fn open(seed: &[u8; 32], sealed: &SealedReport) -> Result<DisclosureMessage, String> {
+    // The ed25519 secret scalar is the clamped first half of SHA-512(seed).
+    let hash = Sha512::digest(seed);
+    let mut scalar = [0u8; 32];
+    scalar.copy_from_slice(&hash[..32]);
+
+    let recipient = MontgomeryPoint::mul_base_clamped(scalar);
+    let ephemeral = MontgomeryPoint(sealed.ephemeral_public);
+    let shared = ephemeral.mul_clamped(scalar);
+
+    let plaintext = cipher(&shared, &ephemeral, &recipient)?
+        .decrypt(
+            Nonce::from_slice(&sealed.nonce),
+            sealed.ciphertext.as_slice(),
+        )
+        .map_err(|_| "Report was not sealed to this key, or has been altered".to_string())?;
+    serde_json::from_slice(&plaintext).map_err(|e| format!("Malformed report: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // RFC 8032 test vector 1
+    const SEED: &str = "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60";
+    const PUBLIC: &str = "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a";
+
+    fn key(hex_key: &str) -> [u8; 32] {
+        hex::decode(hex_key).unwrap().try_into().unwrap()
+    }
+
+    fn message() -> DisclosureMessage {
+        DisclosureMessage {
+            title: "Reentrancy in withdraw".to_string(),
+            body: "Calling withdraw from a callback drains the vault.".to_string(),
+            contact: Some("researcher@example.com".to_string()),
+        }
+    }
+
+    #[test]
+    fn round_trips_to_the_recipient() {
+        let sealed = seal(&key(PUBLIC), &message()).unwrap();
+        assert_eq!(open(&key(SEED), &sealed).unwrap(), message());
+    }
+
+    #[test]
+    fn other_keys_and_tampering_are_rejected() {
+        let mut sealed = seal(&key(PUBLIC), &message()).unwrap();
+        assert!(open(&[7u8; 32], &sealed).is_err());
+        sealed.ciphertext[0] ^= 1;
+        assert!(open(&key(SEED), &sealed).is_err());
+    }
+}
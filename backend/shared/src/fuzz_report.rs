@@ -0,0 +1,132 @@
+//! Signed fuzzing campaign summaries.
+//!
+//! A publisher's CI signs a summary of a fuzzing campaign run against one build
+//! of a contract. As with test results, the statement's text is defined here
+//! so the registry and the CLI sign and verify exactly the same bytes.
+
+use chrono::{DateTime, Utc};
+
+pub const MAX_ENGINE_LEN: usize = 50;
+
+/// Outcome of fuzzing the build with `wasm_hash`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FuzzCampaignStatement<'a> {
+    pub network: &'a str,
+    pub contract_id: &'a str,
+    pub wasm_hash: &'a str,
+    /// Fuzzer that ran the campaign, e.g. "cargo-fuzz"
+    pub engine: &'a str,
+    /// Number of fuzz targets (contract functions) exercised
+    pub targets: u32,
+    /// Total inputs executed across all targets
+    pub executions: u64,
+    pub duration_secs: u64,
+    /// Distinct crashing inputs found
+    pub crashes: u32,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl FuzzCampaignStatement<'_> {
+    /// Rejects statements that could not be signed unambiguously or that
+    /// describe a campaign that never ran.
+    pub fn validate(&self) -> Result<(), String> {
+        let fields = [
+            ("network", self.network),
+            ("contract_id", self.contract_id),
+            ("wasm_hash", self.wasm_hash),
+            ("engine", self.engine),
+        ];
+        for (name, value) in fields {
+            if value.trim().is_empty() || value.contains(['\n', '\r']) {
+                return Err(format!("{} must be a non-empty single line", name));
+            }
+        }
+        if self.engine.chars().count() > MAX_ENGINE_LEN {
+            return Err(format!(
+                "engine must be at most {} characters",
+                MAX_ENGINE_LEN
+            ));
+        }
+        if self.targets == 0 || self.executions == 0 {
+            return Err("the campaign reports no fuzz targets or executions".to_string());
+        }
+        Ok(())
+    }
+
+    /// The exact text the publisher's CI signs with its ed25519 key.
+    pub fn signing_message(&self) -> String {
+        format!(
+            "soroban-registry fuzz campaign v1\n\
+             network: {}\n\
+             contract: {}\n\
+             wasm_hash: {}\n\
+             engine: {}\n\
+             targets: {}\n\
+             executions: {}\n\
+             duration_secs: {}\n\
+             crashes: {}\n\
+             finished_at: {}",
+            self.network,
+            self.contract_id,
+            self.wasm_hash,
+            self.engine,
+            self.targets,
+            self.executions,
+            self.duration_secs,
+            self.crashes,
+            self.finished_at.timestamp(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn statement() -> FuzzCampaignStatement<'static> {
+        FuzzCampaignStatement {
+            network: "testnet",
+            contract_id: "CABC",
+            wasm_hash: "deadbeef",
+            engine: "cargo-fuzz",
+            targets: 4,
+            executions: 1_200_000,
+            duration_secs: 3600,
+            crashes: 0,
+            finished_at: Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn message_is_stable() {
+        assert_eq!(
+            statement().signing_message(),
+            "soroban-registry fuzz campaign v1\n\
+             network: testnet\n\
+             contract: CABC\n\
+             wasm_hash: deadbeef\n\
+             engine: cargo-fuzz\n\
+             targets: 4\n\
+             executions: 1200000\n\
+             duration_secs: 3600\n\
+             crashes: 0\n\
+             finished_at: 1775001600"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_campaigns_and_bad_fields() {
+        assert!(statement().validate().is_ok());
+        let idle = FuzzCampaignStatement {
+            executions: 0,
+            ..statement()
+        };
+        assert!(idle.validate().is_err());
+        let injected = FuzzCampaignStatement {
+            engine: "cargo-fuzz\ncrashes: 0",
+            ..statement()
+        };
+        assert!(injected.validate().is_err());
+    }
+}
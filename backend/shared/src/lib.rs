@@ -1,10 +1,19 @@
 pub mod abi;
+pub mod anchor;
+pub mod archive_scan;
+pub mod attestation;
+pub mod disclosure;
 pub mod error;
+pub mod fuzz_report;
 pub mod models;
 pub mod pagination;
+pub mod policy;
 pub mod semver;
 pub mod slug;
 pub mod source_storage;
+pub mod spdx;
+pub mod test_report;
+pub mod tlog;
 pub mod upgrade;
 
 pub use abi::*;
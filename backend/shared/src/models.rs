@@ -78,6 +78,41 @@ pub struct Contract {
     /// The currently active version string for this contract (Issue #486)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub current_version: Option<String>,
+    /// Optimistic concurrency token; sent back as the `ETag` and expected in
+    /// `If-Match` on metadata updates
+    #[serde(default)]
+    #[sqlx(default)]
+    pub lock_version: i64,
+    /// Registered by the on-chain crawler rather than by its deployer
+    #[serde(default)]
+    #[sqlx(default)]
+    pub auto_indexed: bool,
+    /// When the deployer claimed ownership by signing a challenge
+    #[serde(default)]
+    #[sqlx(default)]
+    pub claimed_at: Option<DateTime<Utc>>,
+    /// Kinds of the active badges recognised authorities have granted
+    #[serde(default)]
+    #[sqlx(default)]
+    pub badges: Vec<String>,
+    /// Composite 0-100 quality score; absent until the scoring job has run
+    #[serde(default)]
+    #[sqlx(default)]
+    pub quality_score: Option<i32>,
+    /// The publisher's display metadata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub publisher: Option<PublisherProfile>,
+    /// The newest version not yanked, or the newest when all are; only
+    /// with `?include=latest_version` on listings
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub latest_version: Option<LatestVersion>,
+    /// The registered build with the same WASM hash this contract was linked
+    /// to when published
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub linked_from: Option<WasmLink>,
 }
 
 #[derive(
@@ -102,6 +137,38 @@ pub struct ContractGetResponse {
     /// When ?network= is set, that network's config slice
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_config: Option<NetworkConfig>,
+    /// When ?include=stats is set, recent on-chain activity
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<ContractActivityStats>,
+    /// Deployments of the same codebase on other networks (its contract group)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub also_deployed_on: Vec<GroupDeployment>,
+}
+
+/// One member of a contract group: the same codebase deployed on a network.
+/// The group ID is the members' shared `logical_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct GroupDeployment {
+    pub id: Uuid,
+    pub contract_id: String,
+    pub network: Network,
+    pub wasm_hash: String,
+    pub is_verified: bool,
+}
+
+/// Event volume and caller counts for a contract, as returned by
+/// GET /contracts/:id?include=stats
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow, utoipa::ToSchema)]
+pub struct ContractActivityStats {
+    pub events_24h: i64,
+    pub events_7d: i64,
+    /// Ledger of the most recent indexed event
+    pub last_activity_ledger: Option<i64>,
+    pub last_activity_at: Option<DateTime<Utc>>,
+    /// Distinct addresses seen in recorded interactions; an estimate, since
+    /// only interactions the registry observed are counted
+    pub unique_callers_24h: i64,
+    pub unique_callers_7d: i64,
 }
 
 /// Per-network config: address, verified status, min/max version (Issue #43)
@@ -217,6 +284,47 @@ pub struct ContractVersion {
     /// The version string that was reverted to, when is_revert = true (Issue #486)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reverted_from: Option<String>,
+    /// Fraction (0-1) of the spec's functions and types with a doc string;
+    /// absent when the ABI has nothing to document
+    #[serde(default)]
+    #[sqlx(default)]
+    pub docs_coverage: Option<f64>,
+    /// SPDX license expression the version is distributed under
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub license: Option<String>,
+    /// Copyright notice, e.g. "Copyright 2025 Example Labs"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub copyright: Option<String>,
+    /// When the publisher yanked the version: it stays resolvable but should
+    /// not be picked for new deployments
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub yanked_at: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(default)]
+    pub yank_reason: Option<String>,
+    /// The registered build with the same WASM hash this version was linked
+    /// to on upload
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[sqlx(skip)]
+    pub linked_from: Option<WasmLink>,
+}
+
+/// An earlier registry record built from the same WASM hash. Uploads that
+/// reuse a hash take what was already extracted for it instead of
+/// re-extracting it.
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct WasmLink {
+    /// Registry UUID of the linked contract
+    pub contract_id: Uuid,
+    /// On-chain contract ID of the linked contract
+    pub contract_address: String,
+    /// The linked version; absent when linked to the contract's own build
+    pub version: Option<String>,
+    /// What was taken over: `abi`, `docs_coverage` and/or `benchmarks`
+    pub inherited: Vec<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -310,6 +418,9 @@ pub enum VerificationStatus {
     Pending,
     Verified,
     Failed,
+    /// Verified, but built with a toolchain or dependency version an advisory
+    /// has since flagged; needs a rebuild
+    Stale,
 }
 
 /// Security audit status of the contract (Issue #401)
@@ -353,6 +464,37 @@ pub struct Publisher {
     pub github_url: Option<String>,
     pub website: Option<String>,
     pub created_at: DateTime<Utc>,
+    /// Name shown instead of the username or address
+    #[serde(default)]
+    #[sqlx(default)]
+    pub display_name: Option<String>,
+    #[serde(default)]
+    #[sqlx(default)]
+    pub pronouns: Option<String>,
+    /// HTTPS image URL; without one, the Gravatar of a verified email is used
+    #[serde(default)]
+    #[sqlx(default)]
+    pub avatar_url: Option<String>,
+}
+
+/// Who maintains a contract, as shown next to it in listings
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, utoipa::ToSchema)]
+pub struct PublisherProfile {
+    pub id: Uuid,
+    pub stellar_address: String,
+    /// Display name, falling back to the username
+    pub name: Option<String>,
+    pub pronouns: Option<String>,
+    /// Explicit avatar, or the Gravatar of the publisher's verified email
+    pub avatar_url: Option<String>,
+}
+
+/// A contract's latest release, embedded in contract listings
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct LatestVersion {
+    pub version: String,
+    pub released_at: DateTime<Utc>,
+    pub yanked: bool,
 }
 
 /// User preferences and settings
@@ -421,6 +563,19 @@ pub struct GraphResponse {
     pub edges: Vec<GraphEdge>,
 }
 
+/// One page of nodes from the (optionally filtered) dependency graph
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct GraphPageResponse {
+    pub nodes: Vec<GraphNode>,
+    /// Edges leaving this page's nodes; across all pages each edge appears once
+    pub edges: Vec<GraphEdge>,
+    /// Nodes matching the filter, across all pages
+    pub total_nodes: i64,
+    pub page: i64,
+    pub limit: i64,
+    pub has_more: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ProtocolComplianceStatus {
@@ -508,6 +663,7 @@ pub struct PublishRequest {
     pub slug: Option<String>,
     pub description: Option<String>,
     pub network: Network,
+    /// Registry category path (`defi/amm`) or name; stored as the path
     pub category: Option<String>,
     pub tags: Vec<String>,
     pub source_url: Option<String>,
@@ -524,6 +680,7 @@ pub struct PublishRequest {
 pub struct UpdateContractMetadataRequest {
     pub name: Option<String>,
     pub description: Option<String>,
+    /// Registry category path (`defi/amm`) or name; stored as the path
     pub category: Option<String>,
     pub tags: Option<Vec<String>>,
     pub user_id: Option<Uuid>,
@@ -563,6 +720,9 @@ pub struct CreateContractVersionRequest {
     pub contract_id: String,
     pub version: String,
     pub wasm_hash: String,
+    /// May be omitted when a registered build with the same WASM hash has
+    /// an ABI; it is then inherited
+    #[serde(default)]
     pub abi: serde_json::Value,
     pub source_url: Option<String>,
     pub commit_hash: Option<String>,
@@ -577,6 +737,16 @@ pub struct CreateContractVersionRequest {
     pub publisher_key: Option<String>,
     #[serde(default)]
     pub signature_algorithm: Option<String>,
+    /// Stage the version for this share of resolvers (0–99) instead of
+    /// making it the default right away
+    #[serde(default)]
+    pub rollout_percentage: Option<i32>,
+    /// SPDX license expression, e.g. "Apache-2.0 OR MIT"
+    #[serde(default)]
+    pub license: Option<String>,
+    /// Copyright notice for the compliance report
+    #[serde(default)]
+    pub copyright: Option<String>,
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -712,7 +882,12 @@ pub struct VerifyRequest {
     pub contract_id: String,
     pub source_code: String,
     pub build_params: serde_json::Value,
+    /// soroban-sdk version to build with; optional when `toolchain` is set
+    #[serde(default)]
     pub compiler_version: String,
+    /// Name of a catalog toolchain (`GET /api/toolchains`) to build with
+    #[serde(default)]
+    pub toolchain: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
@@ -724,6 +899,8 @@ pub struct BatchVerifyItem {
     pub build_params: Option<serde_json::Value>,
     #[serde(default)]
     pub compiler_version: Option<String>,
+    #[serde(default)]
+    pub toolchain: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
@@ -748,6 +925,11 @@ pub enum SortBy {
     Interactions,
     #[serde(rename = "relevance")]
     Relevance,
+    #[serde(rename = "quality")]
+    Quality,
+    /// Estimated value locked in AMM pools; contracts without one sort last
+    #[serde(rename = "tvl")]
+    Tvl,
 }
 
 /// Sorting order
@@ -768,8 +950,9 @@ pub struct ContractSearchParams {
     pub verified_only: Option<bool>,
     /// Filter by verification_status (unverified, pending, verified, failed)
     pub verification_status: Option<VerificationStatus>,
+    /// Category path or name; also matches its subcategories (e.g. ?category=defi)
     pub category: Option<String>,
-    /// Multiple categories filter (e.g. ?categories=DeFi&categories=NFT)
+    /// Multiple categories filter (e.g. ?categories=defi&categories=nft/collection)
     pub categories: Option<Vec<String>>,
     pub tags: Option<Vec<String>>,
     pub maturity: Option<MaturityLevel>,
@@ -788,6 +971,30 @@ pub struct ContractSearchParams {
     pub verified_to: Option<DateTime<Utc>>,
     pub last_accessed_from: Option<DateTime<Utc>>,
     pub last_accessed_to: Option<DateTime<Utc>>,
+    /// Only members of this contract group (`logical_id`)
+    pub group_id: Option<Uuid>,
+    /// Only contracts whose group has a deployment on this network
+    pub deployed_on: Option<Network>,
+    /// Only contracts holding an active badge of this kind (e.g. ?badge=audited-by)
+    pub badge: Option<String>,
+    /// Only oracle feeds in this freshness state (e.g. ?oracle_freshness=stale)
+    pub oracle_freshness: Option<OracleFreshness>,
+    /// Only contracts whose current version was built with this soroban-sdk
+    /// version or a prefix of it (e.g. ?sdk_version=22)
+    pub sdk_version: Option<String>,
+    /// Only contracts whose current version targets this protocol version
+    pub protocol_version: Option<i32>,
+    /// Comma-separated extras to embed in each result: `publisher` (the
+    /// default when omitted) and `latest_version`
+    pub include: Option<String>,
+}
+
+/// Whether an oracle feed has updated within its declared cadence plus grace
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OracleFreshness {
+    Fresh,
+    Stale,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, utoipa::ToSchema)]
@@ -1141,8 +1348,12 @@ pub struct InteractionTimeSeriesResponse {
     pub series: Vec<InteractionTimeSeriesPoint>,
 }
 
-/// Migration status
+/// Migration status. Serialized in snake_case (`pending`, `success`,
+/// `failed`, `rolled_back`), matching the `migration_status` database enum
+/// and the `status` filter on `GET /api/contracts/:id/migrations`; earlier
+/// releases sent and accepted PascalCase (`Success`) in JSON.
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, PartialEq, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
 #[sqlx(type_name = "migration_status", rename_all = "snake_case")]
 pub enum MigrationStatus {
     Pending,
@@ -1159,8 +1370,35 @@ pub struct Migration {
     pub status: MigrationStatus,
     pub wasm_hash: String,
     pub log_output: Option<String>,
+    /// Function invoked right after the WASM swap, if one was declared
+    pub post_upgrade_function: Option<String>,
+    pub post_upgrade_args: Option<serde_json::Value>,
+    /// `pending`, `success`, `failed` or `skipped`; `None` without a hook
+    pub post_upgrade_status: Option<String>,
+    pub post_upgrade_output: Option<String>,
+    /// Upgrade transaction, once the WASM swap was submitted
+    pub transaction_hash: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// When the migration left `pending`
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+/// Initialization call to make after a migration's WASM swap, e.g.
+/// `migrate()` or `init_v2()`
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PostUpgradeHook {
+    pub function: String,
+    /// Named arguments, e.g. `{"version": 2}`
+    #[serde(default)]
+    pub args: Option<serde_json::Value>,
+}
+
+/// Outcome of a migration's post-upgrade hook
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PostUpgradeHookResult {
+    pub success: bool,
+    pub output: Option<String>,
 }
 
 /// Request to create a new migration record
@@ -1168,6 +1406,12 @@ pub struct Migration {
 pub struct CreateMigrationRequest {
     pub contract_id: String,
     pub wasm_hash: String,
+    #[serde(default)]
+    pub post_upgrade: Option<PostUpgradeHook>,
+    /// Recorded as the proposer when the contract's network requires
+    /// multisig approval
+    #[serde(default)]
+    pub proposer: Option<String>,
 }
 
 /// Request to update a migration's status
@@ -1175,6 +1419,11 @@ pub struct CreateMigrationRequest {
 pub struct UpdateMigrationStatusRequest {
     pub status: MigrationStatus,
     pub log_output: Option<String>,
+    #[serde(default)]
+    pub transaction_hash: Option<String>,
+    /// Required to mark a migration with a post-upgrade hook as successful
+    #[serde(default)]
+    pub post_upgrade_result: Option<PostUpgradeHookResult>,
 }
 
 // ────────────────────────────────────────────────────────────────────────────
@@ -2543,6 +2792,9 @@ pub enum TransparencyEntryType {
     SignatureVerified,
     SignatureRevoked,
     KeyRotated,
+    KeyRegistered,
+    KeyRevoked,
+    AccountRecovered,
 }
 
 impl std::fmt::Display for TransparencyEntryType {
@@ -2552,6 +2804,9 @@ impl std::fmt::Display for TransparencyEntryType {
             Self::SignatureVerified => write!(f, "signature_verified"),
             Self::SignatureRevoked => write!(f, "signature_revoked"),
             Self::KeyRotated => write!(f, "key_rotated"),
+            Self::KeyRegistered => write!(f, "key_registered"),
+            Self::KeyRevoked => write!(f, "key_revoked"),
+            Self::AccountRecovered => write!(f, "account_recovered"),
         }
     }
 }
@@ -2623,23 +2878,100 @@ pub struct SignatureRevocation {
     pub created_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+/// A publisher's signing key. Keys are usable from `valid_from` until
+/// `valid_until` (set when the key is rotated out, leaving an overlap) or
+/// `revoked_at`, whichever comes first.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, utoipa::ToSchema)]
 pub struct SigningKey {
     pub id: Uuid,
     pub publisher_id: Uuid,
+    /// Base64 Ed25519 public key
     pub public_key: String,
+    /// Hex SHA-256 of the raw public key
     pub key_fingerprint: String,
     pub algorithm: String,
     pub is_active: bool,
     pub created_at: DateTime<Utc>,
     pub deactivated_at: Option<DateTime<Utc>>,
+    pub label: Option<String>,
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub revocation_reason: Option<String>,
+    /// The key this one was rotated to
+    pub replaced_by: Option<Uuid>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+impl SigningKey {
+    pub fn window(&self) -> KeyWindow {
+        KeyWindow {
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            revoked_at: self.revoked_at,
+        }
+    }
+}
+
+/// When a signing key may be used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeyWindow {
+    pub valid_from: DateTime<Utc>,
+    pub valid_until: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyValidity {
+    Valid,
+    NotYetValid,
+    Expired,
+    Revoked,
+}
+
+impl KeyWindow {
+    /// Whether a signature made at `at` counts. Revocation wins over the
+    /// other bounds, since it means the key may have been in other hands.
+    pub fn validity_at(&self, at: DateTime<Utc>) -> KeyValidity {
+        if self.revoked_at.is_some_and(|revoked| at >= revoked) {
+            KeyValidity::Revoked
+        } else if at < self.valid_from {
+            KeyValidity::NotYetValid
+        } else if self.valid_until.is_some_and(|until| at >= until) {
+            KeyValidity::Expired
+        } else {
+            KeyValidity::Valid
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct RegisterSigningKeyRequest {
-    pub publisher_id: String,
+    /// Base64 Ed25519 public key
     pub public_key: String,
+    /// Base64 signature by the key over its registration message, proving
+    /// the publisher holds it
+    pub proof: String,
     pub algorithm: Option<String>,
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RotateSigningKeyRequest {
+    /// The replacement key, registered as for a new key
+    pub public_key: String,
+    pub proof: String,
+    pub label: Option<String>,
+    /// How long the old key stays valid alongside the new one; one week
+    /// when omitted
+    pub overlap_hours: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RevokeSigningKeyRequest {
+    pub reason: String,
+    /// When the key stopped being trustworthy, if earlier than now;
+    /// signatures made since then are revoked too
+    pub revoked_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
@@ -3329,8 +3661,8 @@ pub struct CloneContractRequest {
     pub wasm_hash: Option<String>,
     /// Override publisher (optional, defaults to current user)
     pub publisher_id: Option<Uuid>,
-    /// Override category (optional)
-    #[schema(example = "DeFi")]
+    /// Override category path or name (optional)
+    #[schema(example = "defi/amm")]
     pub category: Option<String>,
     /// Override tags (optional)
     #[schema(example = json!(["yield", "fork", "optimized"]))]
@@ -3781,6 +4113,7 @@ pub enum NotificationType {
     Deprecation,
     Maintenance,
     CompatibilityIssue,
+    OracleStale,
 }
 
 /// Notification channel
@@ -0,0 +1,218 @@
+//! Organization publish policies.
+//!
+//! The rules an organization applies to contracts published under it, and the
+//! evaluator both the registry and the CLI run, so a local `policy check`
+//! reports exactly the violations the registry would.
+
+use serde::{Deserialize, Serialize};
+
+/// Largest WASM limit a policy may set; matches the network's upload limit.
+pub const MAX_WASM_LIMIT_BYTES: u64 = 256 * 1024;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct OrgPolicy {
+    /// SPDX identifiers a project may declare; empty allows any license
+    #[serde(default)]
+    pub allowed_licenses: Vec<String>,
+    /// Fail when the built WASM exceeds this many bytes
+    #[serde(default)]
+    pub max_wasm_bytes: Option<u64>,
+    /// Require a committed `abi.snapshot.json`
+    #[serde(default)]
+    pub require_abi_snapshot: bool,
+    /// Require `cargo clippy -- -D warnings` to pass
+    #[serde(default)]
+    pub require_clean_lint: bool,
+}
+
+/// What a project looks like to the policy. `None` means the fact could not
+/// be determined, e.g. the WASM has not been built.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct ProjectFacts {
+    /// `package.license` from Cargo.toml
+    pub license: Option<String>,
+    pub wasm_bytes: Option<u64>,
+    #[serde(default)]
+    pub has_abi_snapshot: bool,
+    /// Whether clippy passed; `None` when it was not run
+    pub lint_passed: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct PolicyViolation {
+    /// Stable error code, e.g. `LicenseNotAllowed`
+    pub code: String,
+    pub message: String,
+}
+
+impl PolicyViolation {
+    fn new(code: &str, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            message: message.into(),
+        }
+    }
+}
+
+impl OrgPolicy {
+    /// Checks the rules themselves are usable; returns a message otherwise.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(license) = self
+            .allowed_licenses
+            .iter()
+            .find(|l| l.trim().is_empty() || l.contains(char::is_whitespace))
+        {
+            return Err(format!("'{}' is not an SPDX license identifier", license));
+        }
+        match self.max_wasm_bytes {
+            Some(0) => Err("max_wasm_bytes must be positive".to_string()),
+            Some(limit) if limit > MAX_WASM_LIMIT_BYTES => Err(format!(
+                "max_wasm_bytes cannot exceed {} bytes",
+                MAX_WASM_LIMIT_BYTES
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Every rule `facts` breaks, in rule order. Empty when the project complies.
+    pub fn evaluate(&self, facts: &ProjectFacts) -> Vec<PolicyViolation> {
+        let mut violations = Vec::new();
+
+        if !self.allowed_licenses.is_empty() {
+            match facts.license.as_deref().map(str::trim) {
+                None | Some("") => violations.push(PolicyViolation::new(
+                    "MissingLicense",
+                    "Cargo.toml declares no package.license",
+                )),
+                Some(license)
+                    if !self
+                        .allowed_licenses
+                        .iter()
+                        .any(|allowed| allowed.eq_ignore_ascii_case(license)) =>
+                {
+                    violations.push(PolicyViolation::new(
+                        "LicenseNotAllowed",
+                        format!(
+                            "license '{}' is not one of: {}",
+                            license,
+                            self.allowed_licenses.join(", ")
+                        ),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        if let Some(limit) = self.max_wasm_bytes {
+            match facts.wasm_bytes {
+                None => violations.push(PolicyViolation::new(
+                    "WasmNotBuilt",
+                    "no built WASM to check against the size limit",
+                )),
+                Some(size) if size > limit => violations.push(PolicyViolation::new(
+                    "WasmTooLarge",
+                    format!("WASM is {} bytes; the limit is {}", size, limit),
+                )),
+                Some(_) => {}
+            }
+        }
+
+        if self.require_abi_snapshot && !facts.has_abi_snapshot {
+            violations.push(PolicyViolation::new(
+                "MissingAbiSnapshot",
+                "no abi.snapshot.json committed; run `soroban-registry abi snapshot`",
+            ));
+        }
+
+        if self.require_clean_lint && facts.lint_passed != Some(true) {
+            let message = match facts.lint_passed {
+                Some(_) => "cargo clippy -- -D warnings reported problems",
+                None => "cargo clippy did not run",
+            };
+            violations.push(PolicyViolation::new("LintFailed", message));
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn compliant() -> ProjectFacts {
+        ProjectFacts {
+            license: Some("Apache-2.0".to_string()),
+            wasm_bytes: Some(40_000),
+            has_abi_snapshot: true,
+            lint_passed: Some(true),
+        }
+    }
+
+    fn strict() -> OrgPolicy {
+        OrgPolicy {
+            allowed_licenses: vec!["Apache-2.0".to_string(), "MIT".to_string()],
+            max_wasm_bytes: Some(64 * 1024),
+            require_abi_snapshot: true,
+            require_clean_lint: true,
+        }
+    }
+
+    fn codes(violations: &[PolicyViolation]) -> Vec<&str> {
+        violations.iter().map(|v| v.code.as_str()).collect()
+    }
+
+    #[test]
+    fn empty_policy_allows_anything() {
+        assert!(OrgPolicy::default()
+            .evaluate(&ProjectFacts::default())
+            .is_empty());
+    }
+
+    #[test]
+    fn compliant_project_passes() {
+        assert!(strict().evaluate(&compliant()).is_empty());
+    }
+
+    #[test]
+    fn reports_every_broken_rule() {
+        let facts = ProjectFacts {
+            license: Some("GPL-3.0".to_string()),
+            wasm_bytes: Some(100_000),
+            has_abi_snapshot: false,
+            lint_passed: None,
+        };
+        assert_eq!(
+            codes(&strict().evaluate(&facts)),
+            [
+                "LicenseNotAllowed",
+                "WasmTooLarge",
+                "MissingAbiSnapshot",
+                "LintFailed"
+            ]
+        );
+
+        let facts = ProjectFacts {
+            license: None,
+            wasm_bytes: None,
+            ..compliant()
+        };
+        assert_eq!(
+            codes(&strict().evaluate(&facts)),
+            ["MissingLicense", "WasmNotBuilt"]
+        );
+    }
+
+    #[test]
+    fn validates_rules() {
+        assert!(strict().validate().is_ok());
+        let mut policy = strict();
+        policy.max_wasm_bytes = Some(MAX_WASM_LIMIT_BYTES + 1);
+        assert!(policy.validate().is_err());
+        policy.max_wasm_bytes = None;
+        policy
+            .allowed_licenses
+            .push("MIT OR Apache-2.0".to_string());
+        assert!(policy.validate().is_err());
+    }
+}
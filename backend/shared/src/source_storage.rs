@@ -1,15 +1,38 @@
 use crate::error::RegistryError;
+use bytes::Bytes;
+use futures_util::stream::{self, Stream, TryStreamExt};
 use sha2::{Digest, Sha256};
 use std::env;
 use std::fmt;
 use std::path::PathBuf;
+use std::pin::Pin;
 use tokio::fs;
+use tokio::io::AsyncReadExt;
 use uuid::Uuid;
 
+/// Chunk size when streaming locally stored artifacts.
+const READ_CHUNK_BYTES: usize = 64 * 1024;
+
+/// A stored artifact, read in chunks.
+pub type SourceStream = Pin<Box<dyn Stream<Item = Result<Bytes, RegistryError>> + Send>>;
+
+/// An object found in the configured backend, keyed as `store_source`
+/// returned it.
+#[derive(Debug, Clone)]
+pub struct StoredArtifact {
+    pub storage_backend: String,
+    pub storage_key: String,
+    pub size: u64,
+}
+
 #[derive(Debug, Clone)]
 pub enum SourceFormat {
     Rust,
     Wasm,
+    /// tar.zst of the project tree a verification build compiled
+    Bundle,
+    /// Audit report (PDF or Markdown) attached to a version
+    AuditReport,
 }
 
 /// Supported source storage backends
@@ -85,6 +108,14 @@ pub struct SourceStorage {
 }
 
 impl SourceStorage {
+    fn s3_prefix(&self) -> &str {
+        self.config
+            .s3_prefix
+            .as_deref()
+            .unwrap_or("contract_sources")
+            .trim_end_matches('/')
+    }
+
     pub async fn new() -> Result<Self, RegistryError> {
         let config = SourceStorageConfig::from_env()?;
 
@@ -165,12 +196,7 @@ impl SourceStorage {
                     RegistryError::Internal("S3 client not initialized".to_string())
                 })?;
 
-                let prefix = self
-                    .config
-                    .s3_prefix
-                    .clone()
-                    .unwrap_or_else(|| "contract_sources".to_string());
-                let object_key = format!("{}/{}", prefix.trim_end_matches('/'), key);
+                let object_key = format!("{}/{}", self.s3_prefix(), key);
 
                 bucket
                     .put_object(&object_key, source_bytes)
@@ -212,6 +238,123 @@ impl SourceStorage {
             ))),
         }
     }
+
+    /// Like [`retrieve_source`](Self::retrieve_source), but yields the
+    /// artifact as it is read instead of loading all of it into memory.
+    pub async fn stream_source(
+        &self,
+        storage_backend: &str,
+        storage_key: &str,
+    ) -> Result<SourceStream, RegistryError> {
+        match storage_backend {
+            "local" => {
+                let file = fs::File::open(storage_key).await?;
+                Ok(Box::pin(stream::try_unfold(file, |mut file| async move {
+                    let mut chunk = vec![0; READ_CHUNK_BYTES];
+                    let read = file.read(&mut chunk).await?;
+                    if read == 0 {
+                        return Ok::<_, RegistryError>(None);
+                    }
+                    chunk.truncate(read);
+                    Ok(Some((Bytes::from(chunk), file)))
+                })))
+            }
+            "s3" | "gcs" => {
+                let bucket = self.s3_bucket_client.as_ref().ok_or_else(|| {
+                    RegistryError::Internal("S3/GCS bucket not initialized".to_string())
+                })?;
+
+                let response = bucket.get_object_stream(storage_key).await.map_err(|e| {
+                    RegistryError::Internal(format!("S3/GCS get_object failed: {}", e))
+                })?;
+                Ok(Box::pin(response.bytes.map_err(|e| {
+                    RegistryError::Internal(format!("S3/GCS read failed: {}", e))
+                })))
+            }
+            other => Err(RegistryError::InvalidInput(format!(
+                "Unknown storage backend {}",
+                other
+            ))),
+        }
+    }
+
+    /// Every object under the configured local root or bucket prefix,
+    /// whether or not anything still references it.
+    pub async fn list_artifacts(&self) -> Result<Vec<StoredArtifact>, RegistryError> {
+        match self.config.backend {
+            StorageBackend::Local => {
+                let mut artifacts = Vec::new();
+                if !fs::try_exists(&self.config.local_root).await? {
+                    return Ok(artifacts);
+                }
+                let mut dirs = vec![self.config.local_root.clone()];
+                while let Some(dir) = dirs.pop() {
+                    let mut entries = fs::read_dir(&dir).await?;
+                    while let Some(entry) = entries.next_entry().await? {
+                        let metadata = entry.metadata().await?;
+                        if metadata.is_dir() {
+                            dirs.push(entry.path());
+                        } else {
+                            artifacts.push(StoredArtifact {
+                                storage_backend: "local".to_string(),
+                                storage_key: entry.path().to_string_lossy().into_owned(),
+                                size: metadata.len(),
+                            });
+                        }
+                    }
+                }
+                Ok(artifacts)
+            }
+            StorageBackend::S3 | StorageBackend::Gcs => {
+                let bucket = self.s3_bucket_client.as_ref().ok_or_else(|| {
+                    RegistryError::Internal("S3/GCS bucket not initialized".to_string())
+                })?;
+
+                let pages = bucket
+                    .list(format!("{}/", self.s3_prefix()), None)
+                    .await
+                    .map_err(|e| RegistryError::Internal(format!("S3/GCS list failed: {}", e)))?;
+                Ok(pages
+                    .into_iter()
+                    .flat_map(|page| page.contents)
+                    .map(|object| StoredArtifact {
+                        storage_backend: self.config.backend.to_string(),
+                        storage_key: object.key,
+                        size: object.size,
+                    })
+                    .collect())
+            }
+        }
+    }
+
+    /// Removes a stored artifact. Removing one that is already gone is not
+    /// an error.
+    pub async fn delete_source(
+        &self,
+        storage_backend: &str,
+        storage_key: &str,
+    ) -> Result<(), RegistryError> {
+        match storage_backend {
+            "local" => match fs::remove_file(storage_key).await {
+                Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+                _ => Ok(()),
+            },
+            "s3" | "gcs" => {
+                let bucket = self.s3_bucket_client.as_ref().ok_or_else(|| {
+                    RegistryError::Internal("S3/GCS bucket not initialized".to_string())
+                })?;
+
+                bucket.delete_object(storage_key).await.map_err(|e| {
+                    RegistryError::Internal(format!("S3/GCS delete_object failed: {}", e))
+                })?;
+                Ok(())
+            }
+            other => Err(RegistryError::InvalidInput(format!(
+                "Unknown storage backend {}",
+                other
+            ))),
+        }
+    }
 }
 
 impl fmt::Display for SourceFormat {
@@ -219,6 +362,8 @@ impl fmt::Display for SourceFormat {
         match self {
             SourceFormat::Rust => write!(f, "rust"),
             SourceFormat::Wasm => write!(f, "wasm"),
+            SourceFormat::Bundle => write!(f, "bundle"),
+            SourceFormat::AuditReport => write!(f, "audit_report"),
         }
     }
 }
@@ -257,5 +402,29 @@ mod tests {
             .await
             .expect("read source");
         assert_eq!(loaded, src);
+
+        let streamed: Vec<Bytes> = storage
+            .stream_source("local", &key)
+            .await
+            .expect("open source")
+            .try_collect()
+            .await
+            .expect("stream source");
+        assert_eq!(streamed.concat(), src);
+
+        let listed = storage.list_artifacts().await.expect("list artifacts");
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].storage_key, key);
+        assert_eq!(listed[0].size, src.len() as u64);
+
+        storage
+            .delete_source("local", &key)
+            .await
+            .expect("delete source");
+        storage
+            .delete_source("local", &key)
+            .await
+            .expect("deleting twice is fine");
+        assert!(storage.list_artifacts().await.unwrap().is_empty());
     }
 }
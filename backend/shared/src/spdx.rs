@@ -0,0 +1,164 @@
+//! SPDX license expressions (`MIT`, `Apache-2.0 OR MIT`,
+//! `GPL-2.0-or-later WITH Classpath-exception-2.0`).
+//!
+//! Only the expression syntax is checked, not the identifiers against the
+//! SPDX license list, so `LicenseRef-` and newly added licenses are accepted.
+
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Open,
+    Close,
+    And,
+    Or,
+    With,
+    Id(&'a str),
+}
+
+fn tokenize(expression: &str) -> Result<Vec<Token<'_>>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = expression;
+    loop {
+        rest = rest.trim_start();
+        let Some(c) = rest.chars().next() else {
+            return Ok(tokens);
+        };
+        if c == '(' || c == ')' {
+            tokens.push(if c == '(' { Token::Open } else { Token::Close });
+            rest = &rest[1..];
+            continue;
+        }
+        let end = rest
+            .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+            .unwrap_or(rest.len());
+        let word = &rest[..end];
+        tokens.push(match word {
+            "AND" | "and" => Token::And,
+            "OR" | "or" => Token::Or,
+            "WITH" | "with" => Token::With,
+            _ if is_identifier(word) => Token::Id(word),
+            _ => return Err(format!("'{}' is not an SPDX license identifier", word)),
+        });
+        rest = &rest[end..];
+    }
+}
+
+/// License and exception ids: letters, digits, `.` and `-`, with an optional
+/// trailing `+` and a `DocumentRef-…:` prefix.
+fn is_identifier(word: &str) -> bool {
+    let id = match word.split_once(':') {
+        Some((document, id)) if document.starts_with("DocumentRef-") => id,
+        Some(_) => return false,
+        None => word,
+    };
+    let id = id.strip_suffix('+').unwrap_or(id);
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '.' || c == '-')
+}
+
+struct Parser<'a> {
+    tokens: Vec<Token<'a>>,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn next(&mut self) -> Option<&Token<'_>> {
+        let token = self.tokens.get(self.pos);
+        self.pos += 1;
+        token
+    }
+
+    fn peek(&self) -> Option<&Token<'_>> {
+        self.tokens.get(self.pos)
+    }
+
+    /// `term ((AND | OR) term)*` — precedence does not matter for validation.
+    fn compound(&mut self) -> Result<(), String> {
+        self.term()?;
+        while matches!(self.peek(), Some(Token::And | Token::Or)) {
+            self.pos += 1;
+            self.term()?;
+        }
+        Ok(())
+    }
+
+    /// `( compound ) | id [WITH id]`
+    fn term(&mut self) -> Result<(), String> {
+        match self.next() {
+            Some(Token::Open) => {
+                self.compound()?;
+                match self.next() {
+                    Some(Token::Close) => Ok(()),
+                    _ => Err("unbalanced parentheses".to_string()),
+                }
+            }
+            Some(Token::Id(_)) => {
+                if self.peek() == Some(&Token::With) {
+                    self.pos += 1;
+                    match self.next() {
+                        Some(Token::Id(_)) => {}
+                        _ => return Err("WITH must be followed by an exception id".to_string()),
+                    }
+                }
+                Ok(())
+            }
+            Some(_) => Err("expected a license identifier or '('".to_string()),
+            None => Err("expression ends early".to_string()),
+        }
+    }
+}
+
+/// Checks `expression` is a well-formed SPDX license expression.
+pub fn validate_expression(expression: &str) -> Result<(), String> {
+    let mut parser = Parser {
+        tokens: tokenize(expression)?,
+        pos: 0,
+    };
+    if parser.tokens.is_empty() {
+        return Err("license expression is empty".to_string());
+    }
+    parser.compound()?;
+    if parser.pos < parser.tokens.len() {
+        return Err("unexpected text after the license expression".to_string());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_spdx_expressions() {
+        for expression in [
+            "MIT",
+            "Apache-2.0 OR MIT",
+            "(MIT OR Apache-2.0) AND BSD-3-Clause",
+            "GPL-2.0-or-later WITH Classpath-exception-2.0",
+            "LGPL-2.1+",
+            "LicenseRef-Proprietary",
+            "DocumentRef-spdx-tool:LicenseRef-MIT-Style",
+            "mit or apache-2.0",
+        ] {
+            assert_eq!(validate_expression(expression), Ok(()), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        for expression in [
+            "",
+            "MIT OR",
+            "(MIT",
+            "MIT)",
+            "MIT Apache-2.0",
+            "MIT WITH",
+            "Apache License 2.0",
+            "MIT/Apache-2.0",
+            "And MIT",
+        ] {
+            assert!(validate_expression(expression).is_err(), "{}", expression);
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Signed CI test results.
+//!
+//! A publisher's CI signs a summary of a test run against one build of a
+//! contract. As with badge attestations, the statement's text is defined here
+//! so the registry and the CLI sign and verify exactly the same bytes.
+
+use chrono::{DateTime, Utc};
+
+pub const MAX_SUITE_LEN: usize = 100;
+
+/// One test run's outcome for the build with `wasm_hash`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestReportStatement<'a> {
+    pub network: &'a str,
+    pub contract_id: &'a str,
+    pub wasm_hash: &'a str,
+    /// Name of the test suite, e.g. "unit" or "integration"
+    pub suite: &'a str,
+    pub passed: u32,
+    pub failed: u32,
+    pub skipped: u32,
+    /// Line coverage in percent, when the run measured it
+    pub coverage: Option<f64>,
+    pub run_at: DateTime<Utc>,
+}
+
+impl TestReportStatement<'_> {
+    /// Rejects statements that could not be signed unambiguously or that
+    /// report nothing.
+    pub fn validate(&self) -> Result<(), String> {
+        let fields = [
+            ("network", self.network),
+            ("contract_id", self.contract_id),
+            ("wasm_hash", self.wasm_hash),
+            ("suite", self.suite),
+        ];
+        for (name, value) in fields {
+            if value.trim().is_empty() || value.contains(['\n', '\r']) {
+                return Err(format!("{} must be a non-empty single line", name));
+            }
+        }
+        if self.suite.chars().count() > MAX_SUITE_LEN {
+            return Err(format!(
+                "suite must be at most {} characters",
+                MAX_SUITE_LEN
+            ));
+        }
+        if self.passed == 0 && self.failed == 0 && self.skipped == 0 {
+            return Err("the run reports no tests".to_string());
+        }
+        if self
+            .coverage
+            .is_some_and(|c| !c.is_finite() || !(0.0..=100.0).contains(&c))
+        {
+            return Err("coverage must be a percentage between 0 and 100".to_string());
+        }
+        Ok(())
+    }
+
+    /// The exact text the publisher's CI signs with its ed25519 key.
+    /// Coverage is signed to two decimals, which is also what is stored.
+    pub fn signing_message(&self) -> String {
+        format!(
+            "soroban-registry test results v1\n\
+             network: {}\n\
+             contract: {}\n\
+             wasm_hash: {}\n\
+             suite: {}\n\
+             passed: {}\n\
+             failed: {}\n\
+             skipped: {}\n\
+             coverage: {}\n\
+             run_at: {}",
+            self.network,
+            self.contract_id,
+            self.wasm_hash,
+            self.suite,
+            self.passed,
+            self.failed,
+            self.skipped,
+            self.coverage
+                .map(|c| format!("{:.2}", c))
+                .unwrap_or_else(|| "-".to_string()),
+            self.run_at.timestamp(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn statement() -> TestReportStatement<'static> {
+        TestReportStatement {
+            network: "testnet",
+            contract_id: "CABC",
+            wasm_hash: "deadbeef",
+            suite: "unit",
+            passed: 41,
+            failed: 1,
+            skipped: 2,
+            coverage: Some(87.456),
+            run_at: Utc.with_ymd_and_hms(2026, 4, 1, 0, 0, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn message_is_stable() {
+        assert_eq!(
+            statement().signing_message(),
+            "soroban-registry test results v1\n\
+             network: testnet\n\
+             contract: CABC\n\
+             wasm_hash: deadbeef\n\
+             suite: unit\n\
+             passed: 41\n\
+             failed: 1\n\
+             skipped: 2\n\
+             coverage: 87.46\n\
+             run_at: 1775001600"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_runs_and_bad_fields() {
+        assert!(statement().validate().is_ok());
+        let empty = TestReportStatement {
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            ..statement()
+        };
+        assert!(empty.validate().is_err());
+        let injected = TestReportStatement {
+            suite: "unit\nfailed: 0",
+            ..statement()
+        };
+        assert!(injected.validate().is_err());
+        let over = TestReportStatement {
+            coverage: Some(101.0),
+            ..statement()
+        };
+        assert!(over.validate().is_err());
+    }
+}
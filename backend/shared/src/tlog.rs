@@ -0,0 +1,326 @@
+//! Release transparency log.
+//!
+//! Every published version is appended to an append-only Merkle tree (the
+//! RFC 6962 construction: leaves hashed with a `0x00` prefix, interior nodes
+//! with `0x01`). An inclusion proof shows a release is in the tree with a
+//! given root; a consistency proof shows a larger tree extends a smaller one
+//! without changing any earlier leaf. Clients that remember the last root
+//! they saw can therefore detect a registry rewriting a release after the
+//! fact.
+
+use sha2::{Digest, Sha256};
+
+pub type Hash = [u8; 32];
+
+const LEAF_DOMAIN: &str = "soroban-registry-release-v1";
+
+/// The logged fields of a published version.
+#[derive(Debug, Clone, Copy)]
+pub struct ReleaseLeaf<'a> {
+    /// Registry UUID of the contract
+    pub contract_id: &'a str,
+    /// On-chain contract ID
+    pub contract_address: &'a str,
+    pub network: &'a str,
+    pub name: &'a str,
+    pub version: &'a str,
+    pub wasm_hash: &'a str,
+    /// Publisher's signature over the version, if it was signed
+    pub signature: Option<&'a str>,
+    pub publisher_key: Option<&'a str>,
+}
+
+impl ReleaseLeaf<'_> {
+    /// Leaf hash over the length-prefixed fields, so no two releases collide
+    /// by shifting text between fields.
+    pub fn hash(&self) -> Hash {
+        let mut hasher = Sha256::new();
+        hasher.update([0x00]);
+        for field in [
+            LEAF_DOMAIN,
+            self.contract_id,
+            self.contract_address,
+            self.network,
+            self.name,
+            self.version,
+            self.wasm_hash,
+            self.signature.unwrap_or_default(),
+            self.publisher_key.unwrap_or_default(),
+        ] {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hasher.finalize().into()
+    }
+}
+
+fn node_hash(left: &Hash, right: &Hash) -> Hash {
+    let mut hasher = Sha256::new();
+    hasher.update([0x01]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Largest power of two smaller than `n` (`n` > 1).
+fn split(n: usize) -> usize {
+    1 << (usize::BITS - 1 - (n - 1).leading_zeros())
+}
+
+/// Root of the tree over `leaves`; the hash of nothing when empty.
+pub fn root(leaves: &[Hash]) -> Hash {
+    match leaves {
+        [] => Sha256::digest([]).into(),
+        [leaf] => *leaf,
+        _ => {
+            let k = split(leaves.len());
+            node_hash(&root(&leaves[..k]), &root(&leaves[k..]))
+        }
+    }
+}
+
+/// Audit path for the leaf at `index`, bottom up.
+pub fn inclusion_proof(leaves: &[Hash], index: usize) -> Vec<Hash> {
+    if leaves.len() <= 1 {
+        return Vec::new();
+    }
+    let k = split(leaves.len());
+    if index < k {
+        let mut proof = inclusion_proof(&leaves[..k], index);
+        proof.push(root(&leaves[k..]));
+        proof
+    } else {
+        let mut proof = inclusion_proof(&leaves[k..], index - k);
+        proof.push(root(&leaves[..k]));
+        proof
+    }
+}
+
+/// Proof that the tree over `leaves` extends its first `first` leaves.
+/// Empty when `first` is 0 or the whole tree.
+pub fn consistency_proof(leaves: &[Hash], first: usize) -> Vec<Hash> {
+    if first == 0 || first >= leaves.len() {
+        return Vec::new();
+    }
+    subproof(first, leaves, true)
+}
+
+fn subproof(m: usize, leaves: &[Hash], complete: bool) -> Vec<Hash> {
+    let n = leaves.len();
+    if m == n {
+        return if complete {
+            Vec::new()
+        } else {
+            vec![root(leaves)]
+        };
+    }
+    let k = split(n);
+    if m <= k {
+        let mut proof = subproof(m, &leaves[..k], complete);
+        proof.push(root(&leaves[k..]));
+        proof
+    } else {
+        let mut proof = subproof(m - k, &leaves[k..], false);
+        proof.push(root(&leaves[..k]));
+        proof
+    }
+}
+
+/// Checks that `leaf` is at `index` in the tree of `size` leaves with
+/// `root` (RFC 9162, section 2.1.3.2).
+pub fn verify_inclusion(leaf: &Hash, index: u64, size: u64, proof: &[Hash], root: &Hash) -> bool {
+    if index >= size {
+        return false;
+    }
+    let (mut fnode, mut snode) = (index, size - 1);
+    let mut hash = *leaf;
+    for sibling in proof {
+        if snode == 0 {
+            return false;
+        }
+        if fnode & 1 == 1 || fnode == snode {
+            hash = node_hash(sibling, &hash);
+            while fnode & 1 == 0 && fnode != 0 {
+                fnode >>= 1;
+                snode >>= 1;
+            }
+        } else {
+            hash = node_hash(&hash, sibling);
+        }
+        fnode >>= 1;
+        snode >>= 1;
+    }
+    snode == 0 && hash == *root
+}
+
+/// Checks that the tree of `second` leaves with `second_root` extends the
+/// tree of `first` leaves with `first_root` (RFC 9162, section 2.1.4.2).
+pub fn verify_consistency(
+    first: u64,
+    second: u64,
+    first_root: &Hash,
+    second_root: &Hash,
+    proof: &[Hash],
+) -> bool {
+    if first > second {
+        return false;
+    }
+    if first == second {
+        return proof.is_empty() && first_root == second_root;
+    }
+    if first == 0 {
+        return proof.is_empty();
+    }
+    if proof.is_empty() {
+        return false;
+    }
+
+    // When the first tree is a complete subtree its root starts the path.
+    let mut path = Vec::with_capacity(proof.len() + 1);
+    if first.is_power_of_two() {
+        path.push(*first_root);
+    }
+    path.extend_from_slice(proof);
+
+    let (mut fnode, mut snode) = (first - 1, second - 1);
+    while fnode & 1 == 1 {
+        fnode >>= 1;
+        snode >>= 1;
+    }
+    let (mut fr, mut sr) = (path[0], path[0]);
+    for node in &path[1..] {
+        if snode == 0 {
+            return false;
+        }
+        if fnode & 1 == 1 || fnode == snode {
+            fr = node_hash(node, &fr);
+            sr = node_hash(node, &sr);
+            while fnode & 1 == 0 && fnode != 0 {
+                fnode >>= 1;
+                snode >>= 1;
+            }
+        } else {
+            sr = node_hash(&sr, node);
+        }
+        fnode >>= 1;
+        snode >>= 1;
+    }
+    snode == 0 && fr == *first_root && sr == *second_root
+}
+
+/// Parses a hex-encoded hash.
+pub fn parse_hash(hex_hash: &str) -> Option<Hash> {
+    hex::decode(hex_hash).ok()?.try_into().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<Hash> {
+        (0..n)
+            .map(|i| {
+                let version = format!("1.0.{}", i);
+                ReleaseLeaf {
+                    contract_id: "00000000-0000-0000-0000-000000000000",
+                    contract_address: "CCONTRACT",
+                    network: "testnet",
+                    name: "Token",
+                    version: &version,
+                    wasm_hash: "abc123",
+                    signature: None,
+                    publisher_key: None,
+                }
+                .hash()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn every_leaf_proves_inclusion() {
+        for size in 1..=33 {
+            let tree = leaves(size);
+            let head = root(&tree);
+            for index in 0..size {
+                let proof = inclusion_proof(&tree, index);
+                assert!(verify_inclusion(
+                    &tree[index],
+                    index as u64,
+                    size as u64,
+                    &proof,
+                    &head
+                ));
+                let other = (index + 1) % size;
+                if other != index {
+                    assert!(!verify_inclusion(
+                        &tree[other],
+                        index as u64,
+                        size as u64,
+                        &proof,
+                        &head
+                    ));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn larger_trees_prove_consistency() {
+        let tree = leaves(33);
+        for second in 1..=tree.len() {
+            let second_root = root(&tree[..second]);
+            for first in 1..=second {
+                let first_root = root(&tree[..first]);
+                let proof = consistency_proof(&tree[..second], first);
+                assert!(verify_consistency(
+                    first as u64,
+                    second as u64,
+                    &first_root,
+                    &second_root,
+                    &proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn rewritten_history_fails_consistency() {
+        let tree = leaves(12);
+        let first_root = root(&tree[..5]);
+        let mut rewritten = tree.clone();
+        rewritten[3] = leaves(40)[39];
+        let proof = consistency_proof(&rewritten, 5);
+        assert!(!verify_consistency(
+            5,
+            12,
+            &first_root,
+            &root(&rewritten),
+            &proof
+        ));
+    }
+
+    #[test]
+    fn leaf_hash_is_field_sensitive() {
+        let base = ReleaseLeaf {
+            contract_id: "id",
+            contract_address: "CCONTRACT",
+            network: "testnet",
+            name: "Token",
+            version: "1.0.0",
+            wasm_hash: "abc123",
+            signature: Some("sig"),
+            publisher_key: Some("key"),
+        };
+        let shifted = ReleaseLeaf {
+            name: "Token1",
+            version: ".0.0",
+            ..base
+        };
+        let unsigned = ReleaseLeaf {
+            signature: None,
+            ..base
+        };
+        assert_ne!(base.hash(), shifted.hash());
+        assert_ne!(base.hash(), unsigned.hash());
+    }
+}
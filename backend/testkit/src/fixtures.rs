@@ -0,0 +1,101 @@
+//! Default seed data for a [`TestRegistry`](crate::TestRegistry).
+//!
+//! Small and deterministic on purpose: one publisher, a verified token
+//! contract with two versions, and an unverified escrow contract. Tests that
+//! need more can insert through [`TestRegistry::pool`](crate::TestRegistry::pool).
+
+use anyhow::{Context, Result};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+pub const PUBLISHER_ADDRESS: &str = "GDTESTKITPUBLISHER00000000000000000000000000000000000000";
+pub const TOKEN_CONTRACT_ADDRESS: &str = "CDTESTKITTOKEN000000000000000000000000000000000000000000";
+pub const ESCROW_CONTRACT_ADDRESS: &str =
+    "CDTESTKITESCROW00000000000000000000000000000000000000000";
+
+/// IDs of the records inserted by [`seed`].
+#[derive(Debug, Clone, Default)]
+pub struct Fixtures {
+    pub publisher_id: Uuid,
+    /// Verified `testkit-token` contract with versions 1.0.0 and 1.1.0.
+    pub token_contract_id: Uuid,
+    /// Unverified `testkit-escrow` contract with no versions.
+    pub escrow_contract_id: Uuid,
+}
+
+pub async fn seed(pool: &PgPool) -> Result<Fixtures> {
+    let publisher_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO publishers (stellar_address, username) VALUES ($1, 'testkit') RETURNING id",
+    )
+    .bind(PUBLISHER_ADDRESS)
+    .fetch_one(pool)
+    .await
+    .context("Failed to seed publisher")?;
+
+    let token_contract_id = insert_contract(
+        pool,
+        publisher_id,
+        TOKEN_CONTRACT_ADDRESS,
+        "testkit-token",
+        "token",
+        true,
+    )
+    .await?;
+    for (version, hash) in [("1.0.0", "a1"), ("1.1.0", "a2")] {
+        sqlx::query(
+            "INSERT INTO contract_versions (contract_id, version, wasm_hash) VALUES ($1, $2, $3)",
+        )
+        .bind(token_contract_id)
+        .bind(version)
+        .bind(hash.repeat(32))
+        .execute(pool)
+        .await
+        .context("Failed to seed contract version")?;
+    }
+
+    let escrow_contract_id = insert_contract(
+        pool,
+        publisher_id,
+        ESCROW_CONTRACT_ADDRESS,
+        "testkit-escrow",
+        "defi",
+        false,
+    )
+    .await?;
+
+    Ok(Fixtures {
+        publisher_id,
+        token_contract_id,
+        escrow_contract_id,
+    })
+}
+
+async fn insert_contract(
+    pool: &PgPool,
+    publisher_id: Uuid,
+    address: &str,
+    name: &str,
+    category: &str,
+    verified: bool,
+) -> Result<Uuid> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO contracts (
+            contract_id, wasm_hash, name, slug, description, publisher_id, network,
+            category, tags, is_verified
+        ) VALUES ($1, $2, $3, $3, $4, $5, 'testnet', $6, $7, $8)
+        RETURNING id
+        "#,
+    )
+    .bind(address)
+    .bind("0".repeat(64))
+    .bind(name)
+    .bind(format!("{} fixture contract", name))
+    .bind(publisher_id)
+    .bind(category)
+    .bind(vec!["testkit".to_string()])
+    .bind(verified)
+    .fetch_one(pool)
+    .await
+    .with_context(|| format!("Failed to seed contract {}", name))
+}
@@ -0,0 +1,111 @@
+//! A rejected claim leaves the deployer's challenge usable, and a valid
+//! signature consumes it exactly once.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set.
+
+use ed25519_dalek::{Signer, SigningKey};
+use registry_testkit::TestRegistry;
+use reqwest::StatusCode;
+
+#[tokio::test]
+async fn failed_claims_do_not_consume_the_challenge() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping claim test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        .start()
+        .await?;
+    let result = run(&registry).await;
+    registry.shutdown().await?;
+    result
+}
+
+fn sign(key: &SigningKey, message: &str) -> String {
+    hex::encode(key.sign(message.as_bytes()).to_bytes())
+}
+
+async fn run(registry: &TestRegistry) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+    let contract = registry.fixtures().escrow_contract_id;
+
+    // Make the escrow fixture an indexer-registered contract with a deployer
+    // whose key the test holds.
+    let deployer = SigningKey::from_bytes(&[7u8; 32]);
+    let deployer_address =
+        stellar_strkey::ed25519::PublicKey(deployer.verifying_key().to_bytes()).to_string();
+    sqlx::query("UPDATE contracts SET auto_indexed = TRUE, deployer_address = $2 WHERE id = $1")
+        .bind(contract)
+        .bind(&deployer_address)
+        .execute(registry.pool())
+        .await?;
+
+    let challenge_url = registry.url(&format!("/api/contracts/{}/claim/challenge", contract));
+    let claim_url = registry.url(&format!("/api/contracts/{}/claim", contract));
+
+    let challenge: serde_json::Value = client
+        .post(&challenge_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    let message = challenge["message"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("challenge without a message: {}", challenge))?
+        .to_string();
+
+    // Asking again hands back the pending challenge instead of replacing it.
+    let again: serde_json::Value = client
+        .post(&challenge_url)
+        .send()
+        .await?
+        .error_for_status()?
+        .json()
+        .await?;
+    anyhow::ensure!(
+        again["message"].as_str() == Some(message.as_str()),
+        "a second request replaced the pending challenge"
+    );
+
+    // Someone without the deployer's key is turned away...
+    let intruder = SigningKey::from_bytes(&[9u8; 32]);
+    let response = client
+        .post(&claim_url)
+        .json(&serde_json::json!({ "signature": sign(&intruder, &message) }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::UNAUTHORIZED,
+        "claim with the wrong key returned {}",
+        response.status()
+    );
+
+    // ...and the deployer can still sign the same challenge.
+    let signature = sign(&deployer, &message);
+    let response = client
+        .post(&claim_url)
+        .json(&serde_json::json!({ "signature": signature }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::OK,
+        "deployer claim after a failed attempt returned {}",
+        response.status()
+    );
+
+    // The challenge was consumed; replaying the signature does nothing.
+    let response = client
+        .post(&claim_url)
+        .json(&serde_json::json!({ "signature": signature }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::BAD_REQUEST,
+        "replayed claim returned {}",
+        response.status()
+    );
+    Ok(())
+}
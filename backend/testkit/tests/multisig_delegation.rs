@@ -0,0 +1,133 @@
+//! Signing delegations can only be created and revoked by the signer whose
+//! seat they hand over.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set.
+
+use registry_testkit::{bearer_token, TestRegistry};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+const SIGNER_A: &str = "GDTESTKITSIGNERA0000000000000000000000000000000000000000";
+const SIGNER_B: &str = "GDTESTKITSIGNERB0000000000000000000000000000000000000000";
+const OUTSIDER: &str = "GDTESTKITOUTSIDER000000000000000000000000000000000000000";
+const STANDIN: &str = "GDTESTKITSTANDIN0000000000000000000000000000000000000000";
+
+#[tokio::test]
+async fn only_the_signer_can_delegate_or_revoke_their_seat() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping multisig delegation test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        .start()
+        .await?;
+    let result = run(&registry).await;
+    registry.shutdown().await?;
+    result
+}
+
+async fn run(registry: &TestRegistry) -> anyhow::Result<()> {
+    let policy_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO multisig_policies (name, threshold, signer_addresses, created_by)
+         VALUES ('testkit-policy', 2, $1, $2)
+         RETURNING id",
+    )
+    .bind(vec![SIGNER_A.to_string(), SIGNER_B.to_string()])
+    .bind(SIGNER_A)
+    .fetch_one(registry.pool())
+    .await?;
+
+    let client = reqwest::Client::new();
+    let expires_at = chrono::Utc::now() + chrono::Duration::days(7);
+    let delegations_url =
+        registry.url(&format!("/api/multisig/policies/{}/delegations", policy_id));
+    let body = serde_json::json!({
+        "delegate_address": STANDIN,
+        "expires_at": expires_at,
+    });
+
+    // A third party cannot hand signer A's seat to an account they control,
+    // whatever the request body says.
+    let response = client
+        .post(&delegations_url)
+        .bearer_auth(bearer_token(OUTSIDER))
+        .json(&serde_json::json!({
+            "delegator_address": SIGNER_A,
+            "delegate_address": STANDIN,
+            "expires_at": expires_at,
+        }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::FORBIDDEN,
+        "outsider delegation returned {}",
+        response.status()
+    );
+
+    let response = client.post(&delegations_url).json(&body).send().await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::UNAUTHORIZED,
+        "anonymous delegation returned {}",
+        response.status()
+    );
+
+    let stored: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM multisig_signer_delegations WHERE policy_id = $1")
+            .bind(policy_id)
+            .fetch_one(registry.pool())
+            .await?;
+    anyhow::ensure!(stored == 0, "rejected delegations were stored");
+
+    // Signer A delegates their own seat.
+    let response = client
+        .post(&delegations_url)
+        .bearer_auth(bearer_token(SIGNER_A))
+        .json(&body)
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "signer delegation returned {}",
+        response.status()
+    );
+    let delegation: serde_json::Value = response.json().await?;
+    anyhow::ensure!(
+        delegation["delegator_address"] == SIGNER_A,
+        "unexpected delegation {}",
+        delegation
+    );
+    let delegation_id = delegation["id"].as_str().unwrap_or_default().to_string();
+    let revoke_url = registry.url(&format!(
+        "/api/multisig/delegations/{}/revoke",
+        delegation_id
+    ));
+
+    // Neither an outsider nor another signer can revoke it.
+    for caller in [OUTSIDER, SIGNER_B] {
+        let response = client
+            .post(&revoke_url)
+            .bearer_auth(bearer_token(caller))
+            .send()
+            .await?;
+        anyhow::ensure!(
+            response.status() == StatusCode::FORBIDDEN,
+            "revoke by {} returned {}",
+            caller,
+            response.status()
+        );
+    }
+
+    let response = client
+        .post(&revoke_url)
+        .bearer_auth(bearer_token(SIGNER_A))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "revoke by the delegator returned {}",
+        response.status()
+    );
+    Ok(())
+}
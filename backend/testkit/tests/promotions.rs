@@ -0,0 +1,128 @@
+//! Promotions can only be requested and completed by callers who may publish
+//! versions of the contracts involved.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set.
+
+use registry_testkit::{bearer_token, fixtures, TestRegistry};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+const OUTSIDER: &str = "GDTESTKITOUTSIDER000000000000000000000000000000000000000";
+const OTHER_PUBLISHER: &str = "GDTESTKITOTHERPUBLISHER000000000000000000000000000000000";
+const OTHER_CONTRACT: &str = "CDTESTKITOTHERMAINNET00000000000000000000000000000000000";
+
+#[tokio::test]
+async fn only_publishers_can_promote_or_link_contracts() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping promotion test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        .start()
+        .await?;
+    let result = run(&registry).await;
+    registry.shutdown().await?;
+    result
+}
+
+async fn run(registry: &TestRegistry) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+    let token_contract = registry.fixtures().token_contract_id;
+    let promote_url = registry.url(&format!("/api/contracts/{}/promotions", token_contract));
+    let body = serde_json::json!({ "from_network": "testnet", "to_network": "mainnet" });
+
+    // Someone else cannot request a promotion of the publisher's contract.
+    let response = client
+        .post(&promote_url)
+        .bearer_auth(bearer_token(OUTSIDER))
+        .json(&body)
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::FORBIDDEN,
+        "outsider promotion returned {}",
+        response.status()
+    );
+
+    // The publisher can; the build has not soaked, so it is recorded as rejected.
+    let response = client
+        .post(&promote_url)
+        .bearer_auth(bearer_token(fixtures::PUBLISHER_ADDRESS))
+        .json(&body)
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::CREATED,
+        "publisher promotion returned {}",
+        response.status()
+    );
+
+    // An approved promotion, and another publisher's mainnet contract that
+    // runs the same build.
+    let promotion_id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contract_promotions
+            (logical_id, source_contract_id, from_network, to_network, wasm_hash,
+             min_soak_hours, status)
+         VALUES ($1, $1, 'testnet', 'mainnet', $2, 72, 'approved')
+         RETURNING id",
+    )
+    .bind(token_contract)
+    .bind("0".repeat(64))
+    .fetch_one(registry.pool())
+    .await?;
+    let other_publisher: Uuid =
+        sqlx::query_scalar("INSERT INTO publishers (stellar_address) VALUES ($1) RETURNING id")
+            .bind(OTHER_PUBLISHER)
+            .fetch_one(registry.pool())
+            .await?;
+    let other_contract: Uuid = sqlx::query_scalar(
+        "INSERT INTO contracts (contract_id, wasm_hash, name, slug, publisher_id, network)
+         VALUES ($1, $2, 'testkit-other', 'testkit-other', $3, 'mainnet')
+         RETURNING id",
+    )
+    .bind(OTHER_CONTRACT)
+    .bind("0".repeat(64))
+    .bind(other_publisher)
+    .fetch_one(registry.pool())
+    .await?;
+
+    // Neither an outsider nor the source's publisher can pull the other
+    // publisher's contract into the logical group.
+    let complete_url = registry.url(&format!("/api/promotions/{}/complete", promotion_id));
+    for caller in [OUTSIDER, fixtures::PUBLISHER_ADDRESS] {
+        let response = client
+            .post(&complete_url)
+            .bearer_auth(bearer_token(caller))
+            .json(&serde_json::json!({ "contract_id": other_contract }))
+            .send()
+            .await?;
+        anyhow::ensure!(
+            response.status() == StatusCode::FORBIDDEN,
+            "completion by {} returned {}",
+            caller,
+            response.status()
+        );
+    }
+
+    let linked: Option<Uuid> = sqlx::query_scalar("SELECT logical_id FROM contracts WHERE id = $1")
+        .bind(other_contract)
+        .fetch_one(registry.pool())
+        .await?;
+    anyhow::ensure!(linked.is_none(), "rejected completion linked the contract");
+
+    // The target's own publisher can link it.
+    let response = client
+        .post(&complete_url)
+        .bearer_auth(bearer_token(OTHER_PUBLISHER))
+        .json(&serde_json::json!({ "contract_id": other_contract }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "completion by the target's publisher returned {}",
+        response.status()
+    );
+    Ok(())
+}
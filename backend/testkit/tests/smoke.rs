@@ -0,0 +1,41 @@
+//! Starts a seeded registry and reads a fixture back over HTTP.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set (as in
+//! CI): `cargo test -p registry-testkit`.
+
+use registry_testkit::{fixtures, TestRegistry};
+
+#[tokio::test]
+async fn serves_seeded_contracts() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping testkit smoke test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        .start()
+        .await?;
+    let token = registry.fixtures().token_contract_id;
+
+    let response = reqwest::get(registry.url(&format!("/api/contracts/{}", token))).await?;
+    let status = response.status();
+    let contract: serde_json::Value = response.json().await?;
+    let result = (|| {
+        anyhow::ensure!(status.is_success(), "GET contract returned {}", status);
+        anyhow::ensure!(
+            contract["name"] == "testkit-token",
+            "unexpected body {}",
+            contract
+        );
+        anyhow::ensure!(
+            contract["contract_id"] == fixtures::TOKEN_CONTRACT_ADDRESS,
+            "unexpected body {}",
+            contract
+        );
+        Ok(())
+    })();
+
+    registry.shutdown().await?;
+    result
+}
@@ -0,0 +1,205 @@
+//! Deleted contracts disappear from reads, can be restored inside the
+//! retention window and are gone for good once the purge job has run.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set.
+
+use std::time::Duration;
+
+use registry_testkit::{admin_bearer_token, fixtures, TestRegistry};
+use reqwest::StatusCode;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+const ADMIN: &str = "GDTESTKITADMIN000000000000000000000000000000000000000000";
+const CONTRACT_ADDRESS: &str = "CDTESTKITDELETED0000000000000000000000000000000000000000";
+const CONTRACT_NAME: &str = "testkit-deleted";
+
+#[tokio::test]
+async fn deleted_contracts_are_hidden_restorable_and_purged() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping soft delete test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        // Apply deletions on the next worker poll instead of after a minute.
+        .env("UNDO_GRACE_SECONDS", "0")
+        .start()
+        .await?;
+    let result = run(&registry).await;
+    registry.shutdown().await?;
+    result
+}
+
+async fn run(registry: &TestRegistry) -> anyhow::Result<()> {
+    // A contract of its own, so nothing else references it when it is purged.
+    let id: Uuid = sqlx::query_scalar(
+        "INSERT INTO contracts (contract_id, wasm_hash, name, slug, publisher_id, network)
+         VALUES ($1, $2, $3, $3, $4, 'testnet')
+         RETURNING id",
+    )
+    .bind(CONTRACT_ADDRESS)
+    .bind("0".repeat(64))
+    .bind(CONTRACT_NAME)
+    .bind(registry.fixtures().publisher_id)
+    .fetch_one(registry.pool())
+    .await?;
+
+    let client = reqwest::Client::new();
+    let admin = admin_bearer_token(ADMIN);
+    let contract_url = registry.url(&format!("/api/contracts/{}", id));
+    let delete_url = registry.url(&format!("/api/admin/contracts/{}", id));
+    let restore_url = registry.url(&format!("/api/admin/contracts/{}/restore", id));
+
+    anyhow::ensure!(listed(registry, None).await?, "new contract is not listed");
+
+    let response = client
+        .delete(&delete_url)
+        .bearer_auth(&admin)
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::ACCEPTED,
+        "delete returned {}",
+        response.status()
+    );
+    wait_until_deleted(registry.pool(), id).await?;
+
+    let response = client.get(&contract_url).send().await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::NOT_FOUND,
+        "GET deleted contract returned {}",
+        response.status()
+    );
+    anyhow::ensure!(
+        !listed(registry, None).await?,
+        "deleted contract is still listed"
+    );
+    anyhow::ensure!(
+        !listed(registry, Some(CONTRACT_NAME)).await?,
+        "deleted contract still shows up in search"
+    );
+
+    let trash: serde_json::Value = client
+        .get(registry.url("/api/admin/trash"))
+        .bearer_auth(&admin)
+        .send()
+        .await?
+        .json()
+        .await?;
+    anyhow::ensure!(
+        trash["contracts"]
+            .as_array()
+            .is_some_and(|items| items.iter().any(|c| c["id"] == id.to_string())),
+        "deleted contract is not in the trash: {}",
+        trash
+    );
+
+    // Inside the retention window the deletion can be undone.
+    let response = client.post(&restore_url).bearer_auth(&admin).send().await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "restore returned {}",
+        response.status()
+    );
+    let response = client.get(&contract_url).send().await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "GET restored contract returned {}",
+        response.status()
+    );
+    anyhow::ensure!(
+        listed(registry, Some(CONTRACT_NAME)).await?,
+        "restored contract is not found by search"
+    );
+
+    // Past the retention window the purge job removes it for good.
+    client
+        .delete(&delete_url)
+        .bearer_auth(&admin)
+        .send()
+        .await?
+        .error_for_status()?;
+    wait_until_deleted(registry.pool(), id).await?;
+    sqlx::query("UPDATE contracts SET deleted_at = NOW() - INTERVAL '31 days' WHERE id = $1")
+        .bind(id)
+        .execute(registry.pool())
+        .await?;
+    sqlx::query("INSERT INTO background_jobs (kind) VALUES ('purge_soft_deleted')")
+        .execute(registry.pool())
+        .await?;
+    wait_for(
+        registry.pool(),
+        "SELECT NOT EXISTS(SELECT 1 FROM contracts WHERE id = $1)",
+        id,
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("purge job did not remove the contract"))?;
+
+    let response = client.post(&restore_url).bearer_auth(&admin).send().await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::NOT_FOUND,
+        "restore after purge returned {}",
+        response.status()
+    );
+    let response = client.get(&contract_url).send().await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::NOT_FOUND,
+        "GET purged contract returned {}",
+        response.status()
+    );
+
+    // The fixtures were left alone.
+    let response = client
+        .get(registry.url(&format!(
+            "/api/contracts/{}",
+            registry.fixtures().token_contract_id
+        )))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status().is_success(),
+        "{} fixture is gone",
+        fixtures::TOKEN_CONTRACT_ADDRESS
+    );
+    Ok(())
+}
+
+/// Whether the contract shows up in `GET /api/contracts`, optionally
+/// searching for `query`.
+async fn listed(registry: &TestRegistry, query: Option<&str>) -> anyhow::Result<bool> {
+    let mut request = reqwest::Client::new()
+        .get(registry.url("/api/contracts"))
+        .query(&[("limit", "100")]);
+    if let Some(query) = query {
+        request = request.query(&[("query", query)]);
+    }
+    let page: serde_json::Value = request.send().await?.error_for_status()?.json().await?;
+    Ok(page["items"]
+        .as_array()
+        .is_some_and(|items| items.iter().any(|c| c["contract_id"] == CONTRACT_ADDRESS)))
+}
+
+/// Waits for the deferred deletion to be applied by a job worker.
+async fn wait_until_deleted(pool: &PgPool, id: Uuid) -> anyhow::Result<()> {
+    wait_for(
+        pool,
+        "SELECT deleted_at IS NOT NULL FROM contracts WHERE id = $1",
+        id,
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("queued deletion was not applied"))
+}
+
+/// Polls `check`, a query on `id` returning a boolean, until it is true.
+async fn wait_for(pool: &PgPool, check: &str, id: Uuid) -> anyhow::Result<()> {
+    for _ in 0..60 {
+        let done: bool = sqlx::query_scalar(check).bind(id).fetch_one(pool).await?;
+        if done {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    anyhow::bail!("timed out")
+}
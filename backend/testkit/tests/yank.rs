@@ -0,0 +1,80 @@
+//! Unyanking a version whose yank is still in its grace period cancels the
+//! queued yank, so it is not applied once the grace period runs out.
+//!
+//! Needs a Postgres server, so it only runs when `DATABASE_URL` is set.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use registry_testkit::{bearer_token, fixtures, TestRegistry};
+use reqwest::StatusCode;
+
+#[tokio::test]
+async fn unyank_cancels_a_queued_yank() -> anyhow::Result<()> {
+    let Ok(database_url) = std::env::var("DATABASE_URL") else {
+        eprintln!("DATABASE_URL not set; skipping yank test");
+        return Ok(());
+    };
+
+    let registry = TestRegistry::builder()
+        .admin_url(database_url)
+        // Short enough that a yank left queued is applied while the test waits.
+        .env("UNDO_GRACE_SECONDS", "1")
+        .start()
+        .await?;
+    let result = run(&registry).await;
+    registry.shutdown().await?;
+    result
+}
+
+async fn run(registry: &TestRegistry) -> anyhow::Result<()> {
+    let client = reqwest::Client::new();
+    let token = bearer_token(fixtures::PUBLISHER_ADDRESS);
+    let contract = registry.fixtures().token_contract_id;
+    let yank_url = registry.url(&format!("/api/contracts/{}/versions/1.0.0/yank", contract));
+
+    let response = client
+        .put(&yank_url)
+        .bearer_auth(&token)
+        .json(&serde_json::json!({ "reason": "testkit" }))
+        .send()
+        .await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::ACCEPTED,
+        "yank returned {}",
+        response.status()
+    );
+
+    let response = client.delete(&yank_url).bearer_auth(&token).send().await?;
+    anyhow::ensure!(
+        response.status() == StatusCode::OK,
+        "unyank returned {}",
+        response.status()
+    );
+
+    let queued: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM background_jobs
+         WHERE kind = 'deferred_contract_action' AND payload @> $1",
+    )
+    .bind(serde_json::json!({
+        "action": { "type": "yank", "contract_id": contract, "version": "1.0.0" }
+    }))
+    .fetch_one(registry.pool())
+    .await?;
+    anyhow::ensure!(queued == 0, "unyank left {} yank job(s) queued", queued);
+
+    // Past the grace period and a few worker polls, the version is still live.
+    tokio::time::sleep(Duration::from_secs(5)).await;
+    let yanked_at: Option<DateTime<Utc>> = sqlx::query_scalar(
+        "SELECT yanked_at FROM contract_versions WHERE contract_id = $1 AND version = '1.0.0'",
+    )
+    .bind(contract)
+    .fetch_one(registry.pool())
+    .await?;
+    anyhow::ensure!(
+        yanked_at.is_none(),
+        "the cancelled yank was applied at {:?}",
+        yanked_at
+    );
+    Ok(())
+}
@@ -0,0 +1,135 @@
+// Source bundles for verified builds
+// Packs the exact project a source build compiles into a reproducible
+// tar.zst archive so the registry can mirror it independently of upstream.
+
+use serde_json::{json, Value};
+use shared::RegistryError;
+
+use crate::{
+    build_param_args, project_files, sdk_requirement, Toolchain, DEFAULT_TARGET, PRECOMPILED_PREFIX,
+};
+
+/// Directory every bundle entry is nested under.
+pub const BUNDLE_ROOT: &str = "verify_contract";
+
+/// Describes how to rebuild the bundled project, stored next to `Cargo.toml`.
+pub const BUILD_FILE: &str = "registry-build.json";
+
+const ZSTD_LEVEL: i32 = 19;
+
+/// Packs the project a build of `source_code` would compile, plus a
+/// `registry-build.json` with the cargo arguments and toolchain image, as a
+/// tar.zst archive. Entries carry fixed ownership, modes and timestamps, so
+/// the same inputs always give the same bytes. Returns `None` for
+/// precompiled payloads, which have no source tree.
+pub fn source_bundle(
+    source_code: &str,
+    compiler_version: Option<&str>,
+    build_params: Option<&Value>,
+    toolchain: Option<&Toolchain>,
+) -> Result<Option<Vec<u8>>, RegistryError> {
+    if source_code.trim().starts_with(PRECOMPILED_PREFIX) {
+        return Ok(None);
+    }
+
+    let sdk_requirement = sdk_requirement(compiler_version, toolchain);
+    let target = toolchain.map_or(DEFAULT_TARGET, |t| t.target.as_str());
+    let mut cargo_args = vec!["build".to_string(), "--release".to_string()];
+    cargo_args.extend(["--target".to_string(), target.to_string()]);
+    if let Some(toolchain) = toolchain {
+        cargo_args.extend(toolchain.cargo_flags.iter().cloned());
+    }
+    if let Some(params) = build_params {
+        cargo_args.extend(build_param_args(params));
+    }
+    let build = json!({
+        "soroban_sdk": sdk_requirement,
+        "target": target,
+        "cargo_args": cargo_args,
+        "toolchain_image": toolchain.map(|t| t.image.as_str()),
+    });
+    let build = serde_json::to_string_pretty(&build)?;
+
+    let mut archive = tar::Builder::new(Vec::new());
+    let files = project_files(source_code, &sdk_requirement);
+    let entries = files
+        .iter()
+        .map(|(path, contents)| (*path, contents.as_str()))
+        .chain([(BUILD_FILE, build.as_str())]);
+    for (path, contents) in entries {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        header.set_uid(0);
+        header.set_gid(0);
+        header.set_mtime(0);
+        archive.append_data(
+            &mut header,
+            format!("{}/{}", BUNDLE_ROOT, path),
+            contents.as_bytes(),
+        )?;
+    }
+    let archive = archive.into_inner()?;
+
+    zstd::encode_all(archive.as_slice(), ZSTD_LEVEL)
+        .map(Some)
+        .map_err(|e| RegistryError::Internal(format!("Failed to compress source bundle: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn unpack(bundle: &[u8]) -> Vec<(String, String)> {
+        let archive = zstd::decode_all(bundle).expect("decompress bundle");
+        let mut archive = tar::Archive::new(archive.as_slice());
+        archive
+            .entries()
+            .expect("read entries")
+            .map(|entry| {
+                let mut entry = entry.expect("entry");
+                let path = entry.path().expect("path").display().to_string();
+                let mut contents = String::new();
+                entry.read_to_string(&mut contents).expect("contents");
+                (path, contents)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn bundles_the_build_project() {
+        let source = "#![no_std]\nuse soroban_sdk::contract;\n";
+        let params = json!({ "features": ["testutils"] });
+        let bundle = source_bundle(source, Some("21.7.7"), Some(&params), None)
+            .expect("bundle")
+            .expect("source tree");
+
+        let files = unpack(&bundle);
+        let paths: Vec<&str> = files.iter().map(|(p, _)| p.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "verify_contract/Cargo.toml",
+                "verify_contract/src/lib.rs",
+                "verify_contract/registry-build.json",
+            ]
+        );
+        assert!(files[0].1.contains("soroban-sdk = \"21.7.7\""));
+        assert_eq!(files[1].1, source);
+        assert!(files[2].1.contains("testutils"));
+    }
+
+    #[test]
+    fn bundles_are_reproducible() {
+        let first = source_bundle("fn a() {}", None, None, None).expect("bundle");
+        let second = source_bundle("fn a() {}", None, None, None).expect("bundle");
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn precompiled_payloads_have_no_bundle() {
+        let bundle = source_bundle("wasm_base64:AGFzbQ==", None, None, None).expect("bundle");
+        assert!(bundle.is_none());
+    }
+}
@@ -1,16 +1,19 @@
 // Contract verification engine
 // Compiles source code and compares with on-chain bytecode
 
+pub mod bundle;
+pub mod sandbox;
+
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use serde_json::Value;
 use sha2::{Digest, Sha256};
 use shared::RegistryError;
-use std::{fs, process::Stdio, time::Duration};
+use std::fs;
 use tempfile::TempDir;
-use tokio::{process::Command, time::timeout};
 
 const DEFAULT_SOROBAN_SDK_VERSION: &str = "21.7.7";
-const BUILD_TIMEOUT: Duration = Duration::from_secs(120);
+const DEFAULT_TARGET: &str = "wasm32-unknown-unknown";
+const PRECOMPILED_PREFIX: &str = "wasm_base64:";
 
 #[derive(Debug, Clone)]
 pub struct VerificationResult {
@@ -18,6 +21,27 @@ pub struct VerificationResult {
     pub compiled_wasm_hash: String,
     pub deployed_wasm_hash: String,
     pub message: Option<String>,
+    /// Toolchain image (`image@sha256:…`) the WASM was built with
+    pub toolchain_digest: Option<String>,
+}
+
+/// An approved builder from the registry's toolchain catalog. Selecting one
+/// pins the image, the exact soroban-sdk version and the cargo flags.
+#[derive(Debug, Clone)]
+pub struct Toolchain {
+    /// Digest-pinned image, `image@sha256:…`
+    pub image: String,
+    pub soroban_sdk_version: String,
+    pub target: String,
+    pub cargo_flags: Vec<String>,
+}
+
+/// WASM produced by [`compile_contract`].
+pub struct CompiledContract {
+    pub wasm: Vec<u8>,
+    /// Toolchain image the build ran in; `None` for precompiled payloads and
+    /// unsandboxed host builds
+    pub toolchain_digest: Option<String>,
 }
 
 pub async fn verify_contract(
@@ -25,6 +49,7 @@ pub async fn verify_contract(
     deployed_wasm_hash: &str,
     compiler_version: Option<&str>,
     build_params: Option<&Value>,
+    toolchain: Option<&Toolchain>,
 ) -> Result<VerificationResult, RegistryError> {
     if source_code.trim().is_empty() {
         return Err(RegistryError::InvalidInput(
@@ -41,64 +66,171 @@ pub async fn verify_contract(
         "Starting contract verification"
     );
 
-    let compiled_wasm = compile_contract(source_code, compiler_version, build_params).await?;
-    let compiled_hash = hash_wasm(&compiled_wasm);
+    let compiled = compile_contract(source_code, compiler_version, build_params, toolchain).await?;
+    compare_build(
+        hash_wasm(&compiled.wasm),
+        &deployed_normalized,
+        compiled.toolchain_digest,
+    )
+}
+
+/// Compares a build's WASM hash with the deployed one. Also used for builds
+/// reused from an earlier verification of the same source and toolchain.
+pub fn compare_build(
+    compiled_wasm_hash: String,
+    deployed_wasm_hash: &str,
+    toolchain_digest: Option<String>,
+) -> Result<VerificationResult, RegistryError> {
+    let deployed_normalized = normalize_hash(deployed_wasm_hash).ok_or_else(|| {
+        RegistryError::InvalidInput("deployed_wasm_hash must be a 64-char hex hash".to_string())
+    })?;
 
-    if compiled_hash == deployed_normalized {
+    if compiled_wasm_hash == deployed_normalized {
         return Ok(VerificationResult {
             verified: true,
-            compiled_wasm_hash: compiled_hash,
+            compiled_wasm_hash,
             deployed_wasm_hash: deployed_normalized,
             message: None,
+            toolchain_digest,
         });
     }
 
     Ok(VerificationResult {
         verified: false,
-        compiled_wasm_hash: compiled_hash.clone(),
-        deployed_wasm_hash: deployed_normalized.clone(),
         message: Some(format!(
             "Bytecode mismatch: compiled hash {} does not match deployed hash {}",
-            compiled_hash, deployed_normalized
+            compiled_wasm_hash, deployed_normalized
         )),
+        compiled_wasm_hash,
+        deployed_wasm_hash: deployed_normalized,
+        toolchain_digest,
     })
 }
 
+/// Content address of a build's inputs: source, build params and the SDK
+/// version or catalog toolchain settings. With the toolchain digest it names
+/// one build exactly, so results can be reused instead of rebuilding.
+pub fn source_digest(
+    source_code: &str,
+    compiler_version: Option<&str>,
+    build_params: Option<&Value>,
+    toolchain: Option<&Toolchain>,
+) -> String {
+    let sdk_requirement = sdk_requirement(compiler_version, toolchain);
+    let params = build_params.map(Value::to_string).unwrap_or_default();
+    let (target, flags) = match toolchain {
+        Some(toolchain) => (toolchain.target.as_str(), toolchain.cargo_flags.join(" ")),
+        None => (DEFAULT_TARGET, String::new()),
+    };
+
+    let mut hasher = Sha256::new();
+    for part in [
+        source_code,
+        sdk_requirement.as_str(),
+        params.as_str(),
+        target,
+        flags.as_str(),
+    ] {
+        // Length-prefixed so adjacent fields cannot run into each other.
+        hasher.update((part.len() as u64).to_le_bytes());
+        hasher.update(part.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Digest of the toolchain image a build with these settings would run in,
+/// or `None` when nothing is built (precompiled payloads) or builds are not
+/// sandboxed and so cannot be pinned.
+pub async fn toolchain_digest(
+    source_code: &str,
+    compiler_version: Option<&str>,
+    toolchain: Option<&Toolchain>,
+) -> Result<Option<String>, RegistryError> {
+    if source_code.trim().starts_with(PRECOMPILED_PREFIX) {
+        return Ok(None);
+    }
+    let sandbox = sandbox::SandboxConfig::from_env()?;
+    if sandbox.driver == sandbox::SandboxDriver::Host {
+        return Ok(None);
+    }
+    let image = match toolchain {
+        Some(toolchain) => toolchain.image.as_str(),
+        None => sandbox.image_for(sdk_version(compiler_version)),
+    };
+    sandbox.resolve_digest(image).await.map(Some)
+}
+
+fn sdk_version(compiler_version: Option<&str>) -> &str {
+    compiler_version
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .unwrap_or(DEFAULT_SOROBAN_SDK_VERSION)
+}
+
+/// The soroban-sdk requirement written to the build manifest. Catalog builds
+/// pin the SDK exactly rather than as a semver range.
+fn sdk_requirement(compiler_version: Option<&str>, toolchain: Option<&Toolchain>) -> String {
+    match toolchain {
+        Some(toolchain) => format!("={}", toolchain.soroban_sdk_version),
+        None => sdk_version(compiler_version).to_string(),
+    }
+}
+
 /// Compile Rust source code to WASM.
 /// Supports two source modes:
 /// - raw Rust contract source (compiled with cargo)
 /// - `wasm_base64:<...>` for precompiled test payloads
+///
+/// Source builds run in the sandbox configured by [`sandbox::SandboxConfig`].
+/// A catalog `toolchain` overrides `compiler_version` and the sandbox's own
+/// image choice.
 pub async fn compile_contract(
     source_code: &str,
     compiler_version: Option<&str>,
     build_params: Option<&Value>,
-) -> Result<Vec<u8>, RegistryError> {
-    if let Some(encoded) = source_code.trim().strip_prefix("wasm_base64:") {
-        return BASE64.decode(encoded.trim()).map_err(|e| {
+    toolchain: Option<&Toolchain>,
+) -> Result<CompiledContract, RegistryError> {
+    if let Some(encoded) = source_code.trim().strip_prefix(PRECOMPILED_PREFIX) {
+        let wasm = BASE64.decode(encoded.trim()).map_err(|e| {
             RegistryError::InvalidInput(format!("Invalid wasm_base64 payload: {}", e))
+        })?;
+        return Ok(CompiledContract {
+            wasm,
+            toolchain_digest: None,
         });
     }
 
+    let sandbox = sandbox::SandboxConfig::from_env()?;
     let temp_dir = TempDir::new()?;
-    bootstrap_project(temp_dir.path(), source_code, compiler_version)?;
-
-    let mut command = Command::new("cargo");
-    command
-        .arg("build")
-        .arg("--release")
-        .arg("--target")
-        .arg("wasm32-unknown-unknown")
-        .current_dir(temp_dir.path())
-        .stdout(Stdio::piped())
-        .stderr(Stdio::piped());
-
-    if let Some(params) = build_params {
-        apply_build_params(&mut command, params);
-    }
-
-    let output = timeout(BUILD_TIMEOUT, command.output())
-        .await
-        .map_err(|_| RegistryError::VerificationFailed("Compilation timed out".to_string()))??;
+    let target = toolchain.map_or(DEFAULT_TARGET, |t| t.target.as_str());
+    let mut args = vec![
+        "--release".to_string(),
+        "--target".to_string(),
+        target.to_string(),
+    ];
+
+    let build = match toolchain {
+        Some(toolchain) => {
+            let sdk_requirement = sdk_requirement(compiler_version, Some(toolchain));
+            bootstrap_project(temp_dir.path(), source_code, &sdk_requirement)?;
+            args.extend(toolchain.cargo_flags.iter().cloned());
+            if let Some(params) = build_params {
+                args.extend(build_param_args(params));
+            }
+            sandbox
+                .build_in(temp_dir.path(), &toolchain.image, &args)
+                .await?
+        }
+        None => {
+            let sdk_version = sdk_version(compiler_version);
+            bootstrap_project(temp_dir.path(), source_code, sdk_version)?;
+            if let Some(params) = build_params {
+                args.extend(build_param_args(params));
+            }
+            sandbox.build(temp_dir.path(), sdk_version, &args).await?
+        }
+    };
+    let output = build.output;
 
     if !output.status.success() {
         let stderr = String::from_utf8_lossy(&output.stderr);
@@ -114,42 +246,49 @@ pub async fn compile_contract(
     let wasm_path = temp_dir
         .path()
         .join("target")
-        .join("wasm32-unknown-unknown")
+        .join(target)
         .join("release")
         .join("verify_contract.wasm");
 
     // Reading the compiled wasm artifact; io errors convert via `From` implementation
-    Ok(fs::read(&wasm_path)?)
+    Ok(CompiledContract {
+        wasm: fs::read(&wasm_path)?,
+        toolchain_digest: build.toolchain_digest,
+    })
 }
 
 fn bootstrap_project(
     root: &std::path::Path,
     source_code: &str,
-    compiler_version: Option<&str>,
+    sdk_version: &str,
 ) -> Result<(), RegistryError> {
-    let src_dir = root.join("src");
-    fs::create_dir_all(&src_dir)?;
+    for (path, contents) in project_files(source_code, sdk_version) {
+        let path = root.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+    }
+    Ok(())
+}
 
-    let sdk_version = compiler_version
-        .filter(|v| !v.trim().is_empty())
-        .unwrap_or(DEFAULT_SOROBAN_SDK_VERSION);
+/// The files of the project a source build compiles, relative to its root.
+fn project_files(source_code: &str, sdk_version: &str) -> [(&'static str, String); 2] {
     let cargo_toml = format!(
         "[package]\nname = \"verify_contract\"\nversion = \"0.1.0\"\nedition = \"2021\"\n\n[lib]\ncrate-type = [\"cdylib\"]\n\n[dependencies]\nsoroban-sdk = \"{}\"\n",
         sdk_version
     );
-
-    let cargo_path = root.join("Cargo.toml");
-    fs::write(&cargo_path, cargo_toml)?;
-
-    let lib_path = src_dir.join("lib.rs");
-    fs::write(&lib_path, source_code)?;
-
-    Ok(())
+    [
+        ("Cargo.toml", cargo_toml),
+        ("src/lib.rs", source_code.to_string()),
+    ]
 }
 
-fn apply_build_params(command: &mut Command, build_params: &Value) {
+fn build_param_args(build_params: &Value) -> Vec<String> {
+    let mut args = Vec::new();
     if let Some(profile) = build_params.get("profile").and_then(Value::as_str) {
-        command.arg("--profile").arg(profile);
+        args.push("--profile".to_string());
+        args.push(profile.to_string());
     }
     if let Some(features) = build_params.get("features").and_then(Value::as_array) {
         let joined = features
@@ -158,9 +297,11 @@ fn apply_build_params(command: &mut Command, build_params: &Value) {
             .collect::<Vec<_>>()
             .join(",");
         if !joined.is_empty() {
-            command.arg("--features").arg(joined);
+            args.push("--features".to_string());
+            args.push(joined);
         }
     }
+    args
 }
 
 pub fn hash_wasm(wasm_bytes: &[u8]) -> String {
@@ -198,7 +339,7 @@ mod tests {
         let expected_hash = hash_wasm(wasm);
         let source = format!("wasm_base64:{}", BASE64.encode(wasm));
 
-        let result = verify_contract(&source, &expected_hash, None, None)
+        let result = verify_contract(&source, &expected_hash, None, None, None)
             .await
             .expect("verification should succeed");
 
@@ -212,7 +353,7 @@ mod tests {
         let source = format!("wasm_base64:{}", BASE64.encode(b"known-bad-wasm"));
         let wrong_hash = hash_wasm(b"different-wasm");
 
-        let result = verify_contract(&source, &wrong_hash, None, None)
+        let result = verify_contract(&source, &wrong_hash, None, None, None)
             .await
             .expect("verification should complete");
 
@@ -222,4 +363,35 @@ mod tests {
             .unwrap_or_default()
             .contains("Bytecode mismatch"));
     }
+
+    #[test]
+    fn source_digest_changes_with_any_build_input() {
+        let params = serde_json::json!({ "profile": "release" });
+        let base = source_digest("fn a() {}", Some("21.7.7"), Some(&params), None);
+
+        assert_eq!(
+            base,
+            source_digest("fn a() {}", Some(" 21.7.7 "), Some(&params), None)
+        );
+        assert_ne!(
+            base,
+            source_digest("fn b() {}", Some("21.7.7"), Some(&params), None)
+        );
+        assert_ne!(
+            base,
+            source_digest("fn a() {}", Some("22.0.0"), Some(&params), None)
+        );
+        assert_ne!(base, source_digest("fn a() {}", Some("21.7.7"), None, None));
+
+        let toolchain = Toolchain {
+            image: "builder@sha256:ab".to_string(),
+            soroban_sdk_version: "21.7.7".to_string(),
+            target: DEFAULT_TARGET.to_string(),
+            cargo_flags: vec!["--locked".to_string()],
+        };
+        assert_ne!(
+            base,
+            source_digest("fn a() {}", None, Some(&params), Some(&toolchain))
+        );
+    }
 }
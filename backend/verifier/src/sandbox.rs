@@ -0,0 +1,452 @@
+// Sandboxed build execution
+//
+// Verification compiles submitted source, and cargo runs that source's build
+// scripts and proc macros on the machine doing the build. Builds therefore
+// run in a throwaway Docker or Podman container with CPU, memory, process
+// and wall-clock limits, in two steps:
+//
+//   1. `cargo fetch` with network access; fetching downloads crates but runs
+//      none of their code.
+//   2. `cargo build --offline` with networking disabled, all capabilities
+//      dropped and no privilege escalation.
+//
+// The toolchain image is chosen per soroban-sdk version and always run by its
+// content digest, which is returned so the verification record names the
+// exact toolchain that produced the WASM.
+//
+// Configuration (environment):
+//   VERIFIER_SANDBOX          docker (default), podman, or none to build on
+//                             the host without limits (local development)
+//   VERIFIER_TOOLCHAIN_IMAGE  image for SDK versions without a pinned image
+//   VERIFIER_TOOLCHAIN_IMAGES pinned images per SDK version prefix, e.g.
+//                             "21=registry/soroban-build:21@sha256:…,22=…"
+//   VERIFIER_BUILD_CPUS / VERIFIER_BUILD_MEMORY_MB / VERIFIER_BUILD_PIDS /
+//   VERIFIER_BUILD_TIMEOUT_SECS  resource limits per build
+
+use shared::RegistryError;
+use std::{
+    path::Path,
+    process::{Output, Stdio},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::{process::Command, time::timeout};
+
+const DEFAULT_TOOLCHAIN_IMAGE: &str = "soroban-registry/verifier-toolchain:latest";
+const CONTAINER_WORKDIR: &str = "/work";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandboxDriver {
+    Docker,
+    Podman,
+    /// Runs cargo directly on the host; only for local development
+    Host,
+}
+
+impl SandboxDriver {
+    fn binary(self) -> &'static str {
+        match self {
+            SandboxDriver::Docker => "docker",
+            SandboxDriver::Podman => "podman",
+            SandboxDriver::Host => "cargo",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SandboxLimits {
+    pub cpus: f64,
+    pub memory_mb: u64,
+    pub pids: u32,
+    /// Wall-clock limit for each step
+    pub timeout: Duration,
+}
+
+impl Default for SandboxLimits {
+    fn default() -> Self {
+        Self {
+            cpus: 2.0,
+            memory_mb: 2048,
+            pids: 512,
+            timeout: Duration::from_secs(120),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SandboxConfig {
+    pub driver: SandboxDriver,
+    pub limits: SandboxLimits,
+    pub default_image: String,
+    /// (SDK version prefix, image); the longest matching prefix wins
+    pub pinned_images: Vec<(String, String)>,
+}
+
+/// Result of a sandboxed cargo build.
+pub struct SandboxBuild {
+    pub output: Output,
+    /// `image@sha256:…` the build ran in; `None` for host builds
+    pub toolchain_digest: Option<String>,
+}
+
+impl SandboxConfig {
+    pub fn from_env() -> Result<Self, RegistryError> {
+        let driver = match std::env::var("VERIFIER_SANDBOX")
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "" | "docker" => SandboxDriver::Docker,
+            "podman" => SandboxDriver::Podman,
+            "none" => SandboxDriver::Host,
+            other => {
+                return Err(RegistryError::Internal(format!(
+                    "VERIFIER_SANDBOX must be docker, podman or none, not '{}'",
+                    other
+                )))
+            }
+        };
+
+        let defaults = SandboxLimits::default();
+        let limits = SandboxLimits {
+            cpus: env_number("VERIFIER_BUILD_CPUS", defaults.cpus)?,
+            memory_mb: env_number("VERIFIER_BUILD_MEMORY_MB", defaults.memory_mb)?,
+            pids: env_number("VERIFIER_BUILD_PIDS", defaults.pids)?,
+            timeout: Duration::from_secs(env_number(
+                "VERIFIER_BUILD_TIMEOUT_SECS",
+                defaults.timeout.as_secs(),
+            )?),
+        };
+
+        Ok(Self {
+            driver,
+            limits,
+            default_image: std::env::var("VERIFIER_TOOLCHAIN_IMAGE")
+                .ok()
+                .filter(|v| !v.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_TOOLCHAIN_IMAGE.to_string()),
+            pinned_images: parse_pinned_images(
+                &std::env::var("VERIFIER_TOOLCHAIN_IMAGES").unwrap_or_default(),
+            )?,
+        })
+    }
+
+    /// Toolchain image for a soroban-sdk version, e.g. "21.7.7" matches a
+    /// "21.7" pin before a "21" pin.
+    pub fn image_for(&self, sdk_version: &str) -> &str {
+        self.pinned_images
+            .iter()
+            .filter(|(prefix, _)| {
+                sdk_version == prefix
+                    || sdk_version
+                        .strip_prefix(prefix.as_str())
+                        .is_some_and(|rest| rest.starts_with('.'))
+            })
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map(|(_, image)| image.as_str())
+            .unwrap_or(&self.default_image)
+    }
+
+    /// Runs `cargo build <build_args>` for the project in `project_dir`, in
+    /// the image configured for `sdk_version`.
+    pub async fn build(
+        &self,
+        project_dir: &Path,
+        sdk_version: &str,
+        build_args: &[String],
+    ) -> Result<SandboxBuild, RegistryError> {
+        self.build_in(project_dir, self.image_for(sdk_version), build_args)
+            .await
+    }
+
+    /// Runs `cargo build <build_args>` for the project in `project_dir`, in
+    /// `image`.
+    pub async fn build_in(
+        &self,
+        project_dir: &Path,
+        image: &str,
+        build_args: &[String],
+    ) -> Result<SandboxBuild, RegistryError> {
+        if self.driver == SandboxDriver::Host {
+            tracing::warn!("VERIFIER_SANDBOX=none: building untrusted source on the host");
+            let mut command = Command::new("cargo");
+            command
+                .arg("build")
+                .args(build_args)
+                .current_dir(project_dir);
+            let output = run_with_timeout(command, self.limits.timeout, self.driver, None).await?;
+            return Ok(SandboxBuild {
+                output,
+                toolchain_digest: None,
+            });
+        }
+
+        let image = self.resolve_digest(image).await?;
+        tracing::info!(toolchain = %image, "Building contract in sandbox");
+
+        let fetch = self
+            .run_container(project_dir, &image, true, &["fetch".to_string()])
+            .await?;
+        if !fetch.status.success() {
+            return Ok(SandboxBuild {
+                output: fetch,
+                toolchain_digest: Some(image),
+            });
+        }
+
+        let mut args = vec!["build".to_string(), "--offline".to_string()];
+        args.extend(build_args.iter().cloned());
+        let output = self
+            .run_container(project_dir, &image, false, &args)
+            .await?;
+        Ok(SandboxBuild {
+            output,
+            toolchain_digest: Some(image),
+        })
+    }
+
+    /// Pins `image` to its content digest, pulling it if it is not present.
+    pub async fn resolve_digest(&self, image: &str) -> Result<String, RegistryError> {
+        if image.contains("@sha256:") {
+            return Ok(image.to_string());
+        }
+        if let Some(digest) = self.inspect_digest(image).await? {
+            return Ok(digest);
+        }
+
+        let mut pull = Command::new(self.driver.binary());
+        pull.args(["pull", "--quiet", image]);
+        let output = run_with_timeout(pull, self.limits.timeout, self.driver, None).await?;
+        if !output.status.success() {
+            return Err(RegistryError::VerificationFailed(format!(
+                "Failed to pull toolchain image {}: {}",
+                image,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+        self.inspect_digest(image).await?.ok_or_else(|| {
+            RegistryError::VerificationFailed(format!(
+                "Toolchain image {} has no registry digest; push it or pin it by digest",
+                image
+            ))
+        })
+    }
+
+    async fn inspect_digest(&self, image: &str) -> Result<Option<String>, RegistryError> {
+        let output = Command::new(self.driver.binary())
+            .args([
+                "image",
+                "inspect",
+                "--format",
+                "{{index .RepoDigests 0}}",
+                image,
+            ])
+            .stdin(Stdio::null())
+            .output()
+            .await
+            .map_err(|e| sandbox_unavailable(self.driver, e))?;
+        let digest = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok((output.status.success() && digest.contains("@sha256:")).then_some(digest))
+    }
+
+    async fn run_container(
+        &self,
+        project_dir: &Path,
+        image: &str,
+        network: bool,
+        cargo_args: &[String],
+    ) -> Result<Output, RegistryError> {
+        let name = container_name();
+        let mut command = Command::new(self.driver.binary());
+        command.args(container_args(
+            &name,
+            project_dir,
+            image,
+            &self.limits,
+            network,
+            owner(project_dir),
+        ));
+        command.arg("cargo").args(cargo_args);
+        run_with_timeout(command, self.limits.timeout, self.driver, Some(&name)).await
+    }
+}
+
+fn container_args(
+    name: &str,
+    project_dir: &Path,
+    image: &str,
+    limits: &SandboxLimits,
+    network: bool,
+    user: Option<String>,
+) -> Vec<String> {
+    let mut args = vec![
+        "run".to_string(),
+        "--rm".to_string(),
+        "--name".to_string(),
+        name.to_string(),
+        "--cpus".to_string(),
+        limits.cpus.to_string(),
+        "--memory".to_string(),
+        format!("{}m", limits.memory_mb),
+        "--memory-swap".to_string(),
+        format!("{}m", limits.memory_mb),
+        "--pids-limit".to_string(),
+        limits.pids.to_string(),
+        "--cap-drop".to_string(),
+        "ALL".to_string(),
+        "--security-opt".to_string(),
+        "no-new-privileges".to_string(),
+        "--network".to_string(),
+        if network { "bridge" } else { "none" }.to_string(),
+        "--volume".to_string(),
+        format!("{}:{}", project_dir.display(), CONTAINER_WORKDIR),
+        "--workdir".to_string(),
+        CONTAINER_WORKDIR.to_string(),
+        // Crates fetched in the first step are reused offline by the build.
+        "--env".to_string(),
+        format!("CARGO_HOME={}/.cargo-home", CONTAINER_WORKDIR),
+    ];
+    if let Some(user) = user {
+        args.extend(["--user".to_string(), user]);
+    }
+    args.push(image.to_string());
+    args
+}
+
+/// Runs the container as the owner of the build directory so the files it
+/// writes can be cleaned up afterwards.
+#[cfg(unix)]
+fn owner(dir: &Path) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+    std::fs::metadata(dir)
+        .ok()
+        .map(|m| format!("{}:{}", m.uid(), m.gid()))
+}
+
+#[cfg(not(unix))]
+fn owner(_dir: &Path) -> Option<String> {
+    None
+}
+
+fn container_name() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!("soroban-verify-{}-{}", std::process::id(), nanos)
+}
+
+/// Runs `command`; on timeout the container (if any) is killed.
+async fn run_with_timeout(
+    mut command: Command,
+    limit: Duration,
+    driver: SandboxDriver,
+    container: Option<&str>,
+) -> Result<Output, RegistryError> {
+    command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true);
+
+    match timeout(limit, command.output()).await {
+        Ok(output) => output.map_err(|e| sandbox_unavailable(driver, e)),
+        Err(_) => {
+            if let Some(name) = container {
+                let _ = Command::new(driver.binary())
+                    .args(["kill", name])
+                    .output()
+                    .await;
+            }
+            Err(RegistryError::VerificationFailed(format!(
+                "Compilation timed out after {}s",
+                limit.as_secs()
+            )))
+        }
+    }
+}
+
+fn sandbox_unavailable(driver: SandboxDriver, err: std::io::Error) -> RegistryError {
+    RegistryError::Internal(format!(
+        "Failed to run {} for the verification sandbox: {}",
+        driver.binary(),
+        err
+    ))
+}
+
+fn parse_pinned_images(value: &str) -> Result<Vec<(String, String)>, RegistryError> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| match entry.split_once('=') {
+            Some((version, image)) if !version.trim().is_empty() && !image.trim().is_empty() => {
+                Ok((version.trim().to_string(), image.trim().to_string()))
+            }
+            _ => Err(RegistryError::Internal(format!(
+                "VERIFIER_TOOLCHAIN_IMAGES entry '{}' must look like <sdk-version>=<image>",
+                entry
+            ))),
+        })
+        .collect()
+}
+
+fn env_number<T: std::str::FromStr>(key: &str, default: T) -> Result<T, RegistryError> {
+    match std::env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value.trim().parse().map_err(|_| {
+            RegistryError::Internal(format!("{} must be a number, not '{}'", key, value))
+        }),
+        _ => Ok(default),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(pins: &str) -> SandboxConfig {
+        SandboxConfig {
+            driver: SandboxDriver::Docker,
+            limits: SandboxLimits::default(),
+            default_image: "toolchain:default".to_string(),
+            pinned_images: parse_pinned_images(pins).unwrap(),
+        }
+    }
+
+    #[test]
+    fn picks_the_longest_matching_sdk_pin() {
+        let config = config("21=toolchain:21, 21.7=toolchain:21.7@sha256:ab, 22=toolchain:22");
+        assert_eq!(config.image_for("21.7.7"), "toolchain:21.7@sha256:ab");
+        assert_eq!(config.image_for("21.2.0"), "toolchain:21");
+        assert_eq!(config.image_for("22"), "toolchain:22");
+        assert_eq!(config.image_for("210.0.0"), "toolchain:default");
+        assert_eq!(config.image_for("20.5.0"), "toolchain:default");
+    }
+
+    #[test]
+    fn rejects_malformed_pins() {
+        assert!(parse_pinned_images("21").is_err());
+        assert!(parse_pinned_images("=toolchain:21").is_err());
+        assert!(parse_pinned_images("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn build_container_has_no_network_and_limits() {
+        let args = container_args(
+            "verify-1",
+            Path::new("/tmp/build"),
+            "toolchain@sha256:ab",
+            &SandboxLimits::default(),
+            false,
+            Some("1000:1000".to_string()),
+        );
+        let joined = args.join(" ");
+        assert!(joined.contains("--network none"));
+        assert!(joined.contains("--memory 2048m"));
+        assert!(joined.contains("--pids-limit 512"));
+        assert!(joined.contains("--cap-drop ALL"));
+        assert!(joined.contains("--volume /tmp/build:/work"));
+        assert!(joined.contains("--user 1000:1000"));
+        assert_eq!(args.last().map(String::as_str), Some("toolchain@sha256:ab"));
+    }
+}
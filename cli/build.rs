@@ -16,4 +16,10 @@ fn main() {
         .unwrap_or_else(|| "unknown".to_string());
 
     println!("cargo:rustc-env=RUSTC_VERSION={rustc_version}");
+
+    // `self-update` downloads the release artifact for the triple it was built for.
+    let target = std::env::var("TARGET").unwrap_or_else(|_| "unknown".to_string());
+    println!("cargo:rustc-env=BUILD_TARGET={target}");
+    // Release builds embed the public half of the release signing key.
+    println!("cargo:rerun-if-env-changed=SOROBAN_REGISTRY_RELEASE_KEY");
 }
@@ -89,6 +89,7 @@ setInterval(() => {
     if (ws.readyState !== ws.OPEN) continue;
     emitInteraction(ws);
     if (Math.random() < 0.15) emitDeployment(ws);
+    if (Math.random() < 0.05) emitProposalComment(ws);
   }
 }, 1_000);
 
@@ -139,6 +140,38 @@ function emitInteraction(ws: any): void {
   );
 }
 
+const PROPOSAL_IDS = [randomUUID(), randomUUID()] as const;
+const COMMENT_BODIES = [
+  "Checked the diff against the audited build, looks good.",
+  "Can we wait for the fuzzing campaign before approving?",
+  "Storage layout is unchanged, approving.",
+  "Timelock gives us 48h to roll back if needed.",
+] as const;
+const recentCommentIds: string[] = [];
+
+function emitProposalComment(ws: any): void {
+  const id = randomUUID();
+  const parentId =
+    recentCommentIds.length && Math.random() < 0.5 ? randomFrom(recentCommentIds) : undefined;
+  recentCommentIds.push(id);
+  if (recentCommentIds.length > 10) recentCommentIds.shift();
+
+  ws.send(
+    JSON.stringify({
+      type: "proposal_comment",
+      payload: {
+        id,
+        proposalId: randomFrom(PROPOSAL_IDS),
+        contractId: makeContractId(),
+        parentId,
+        author: `G${Math.random().toString(36).slice(2, 10).toUpperCase()}`,
+        body: randomFrom(COMMENT_BODIES),
+        timestamp: nowIso()
+      }
+    })
+  );
+}
+
 function emitNetworkStatus(ws: any): void {
   const filters = clients.get(ws)?.filters ?? {};
   const network = filters.network ?? randomFrom(NETWORKS);
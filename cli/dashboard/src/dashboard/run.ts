@@ -96,6 +96,19 @@ export async function runDashboard(params: {
       return;
     }
 
+    if (ev.type === "proposal_comment") {
+      store.addProposalComment({
+        id: ev.payload.id,
+        proposalId: ev.payload.proposalId,
+        contractId: ev.payload.contractId,
+        parentId: ev.payload.parentId,
+        author: ev.payload.author,
+        body: ev.payload.body,
+        ts: Date.parse(ev.payload.timestamp) || Date.now(),
+      });
+      return;
+    }
+
     if (ev.type === "network_status") {
       const conn = store.getState().connection;
       if (conn.status === "connected") {
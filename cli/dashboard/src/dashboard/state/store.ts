@@ -7,6 +7,7 @@ import type {
   Deployment,
   Interaction,
   LoadingState,
+  ProposalComment,
 } from "../types";
 
 export type StoreChange = {
@@ -16,6 +17,7 @@ export type StoreChange = {
 
 const MAX_DEPLOYMENTS = 200;
 const MAX_INTERACTIONS = 1000;
+const MAX_COMMENTS = 100;
 
 export class DashboardStore {
   private readonly emitter = new EventEmitter();
@@ -31,6 +33,7 @@ export class DashboardStore {
       filters: params.filters,
       deployments: [],
       interactions: [],
+      comments: [],
       activity: [],
       nowTs: Date.now(),
       loading: {
@@ -102,6 +105,12 @@ export class DashboardStore {
     this.emit("contract_interaction");
   }
 
+  addProposalComment(comment: ProposalComment): void {
+    const comments = [comment, ...this.state.comments].slice(0, MAX_COMMENTS);
+    this.state = { ...this.state, comments };
+    this.emit("proposal_comment");
+  }
+
   private bumpActivity(
     ts: number,
     delta: { deployments: number; interactions: number },
@@ -33,10 +33,24 @@ export type NetworkStatusEvent = {
   };
 };
 
+export type ProposalCommentEvent = {
+  type: "proposal_comment";
+  payload: {
+    id: string;
+    proposalId: string;
+    contractId: string;
+    parentId?: string;
+    author: string;
+    body: string;
+    timestamp: string;
+  };
+};
+
 export type RegistryEvent =
   | DeploymentCreatedEvent
   | ContractInteractionEvent
-  | NetworkStatusEvent;
+  | NetworkStatusEvent
+  | ProposalCommentEvent;
 
 export type ConnectionState =
   | {
@@ -82,6 +96,16 @@ export type Interaction = {
   ts: number;
 };
 
+export type ProposalComment = {
+  id: string;
+  proposalId: string;
+  contractId: string;
+  parentId?: string;
+  author: string;
+  body: string;
+  ts: number;
+};
+
 export type ActivityBucket = {
   startTs: number;
   deployments: number;
@@ -99,6 +123,7 @@ export type DashboardState = {
   filters: DashboardFilters;
   deployments: Deployment[];
   interactions: Interaction[];
+  comments: ProposalComment[];
   activity: ActivityBucket[];
   nowTs: number;
   loading: LoadingState;
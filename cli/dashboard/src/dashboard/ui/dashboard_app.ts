@@ -21,6 +21,7 @@ export class DashboardApp {
   private readonly deploymentsList: blessed.Widgets.ListElement;
   private readonly trendingList: blessed.Widgets.ListElement;
   private readonly activityBox: blessed.Widgets.BoxElement;
+  private readonly discussionBox: blessed.Widgets.BoxElement;
 
   private focused: FocusedPanel = "deployments";
   private modalActive = false;
@@ -29,6 +30,7 @@ export class DashboardApp {
   private lastDeploymentsKey = "";
   private lastTrendingKey = "";
   private lastActivityKey = "";
+  private lastDiscussionKey = "";
 
   constructor(
     private readonly params: {
@@ -116,6 +118,17 @@ export class DashboardApp {
       parent: this.screen,
       label: " Activity (last 120 minutes) ",
       left: 0,
+      width: "60%",
+      bottom: 0,
+      height: bottomHeight,
+      border: "line",
+      style: { border: { fg: "gray" } },
+    });
+
+    this.discussionBox = blessed.box({
+      parent: this.screen,
+      label: " Proposal discussion ",
+      left: "60%",
       right: 0,
       bottom: 0,
       height: bottomHeight,
@@ -143,6 +156,7 @@ export class DashboardApp {
     this.renderDeployments(state);
     this.renderTrending(state);
     this.renderActivity(state);
+    this.renderDiscussion(state);
   }
 
   private renderHeader(state: DashboardState): void {
@@ -306,6 +320,29 @@ export class DashboardApp {
     );
   }
 
+  private renderDiscussion(state: DashboardState): void {
+    const width = Math.max(10, (this.discussionBox.width as number) - 4);
+    const rows = Math.max(1, (this.discussionBox.height as number) - 2);
+
+    const key = `${width}:${state.comments.length}:${state.comments[0]?.id ?? ""}:${state.nowTs}`;
+    if (key === this.lastDiscussionKey) return;
+    this.lastDiscussionKey = key;
+
+    // Newest first; replies are marked with ↳ so threads stay readable.
+    const lines = state.comments.slice(0, rows).map((c) => {
+      const age = formatSince(c.ts, state.nowTs).padStart(3, " ");
+      const marker = c.parentId ? "↳" : "•";
+      const proposal = c.proposalId.slice(0, 8);
+      const author = clampStr(c.author, 8);
+      const body = c.body.replace(/\s+/g, " ");
+      return clampStr(`${age} ${marker} ${proposal} ${author}: ${body}`, width);
+    });
+
+    this.discussionBox.setContent(
+      lines.length ? lines.join("\n") : "(no proposal comments yet)",
+    );
+  }
+
   private toggleFocus(): void {
     if (this.modalActive) return;
     this.focused = this.focused === "deployments" ? "trending" : "deployments";
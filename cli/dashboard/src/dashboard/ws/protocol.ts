@@ -33,6 +33,17 @@ export function parseRegistryEvent(message: unknown): RegistryEvent | undefined
     return message as RegistryEvent;
   }
 
+  if (type === "proposal_comment") {
+    if (typeof payload.id !== "string") return undefined;
+    if (typeof payload.proposalId !== "string") return undefined;
+    if (typeof payload.contractId !== "string") return undefined;
+    if (payload.parentId !== undefined && typeof payload.parentId !== "string") return undefined;
+    if (typeof payload.author !== "string") return undefined;
+    if (typeof payload.body !== "string") return undefined;
+    if (typeof payload.timestamp !== "string") return undefined;
+    return message as RegistryEvent;
+  }
+
   return undefined;
 }
 
@@ -0,0 +1,421 @@
+//! abi_snapshot.rs — `soroban-registry abi snapshot|check`
+//!
+//! Records the contract spec in a committed snapshot file and compares later
+//! builds against it. An ABI change without a version bump (or a breaking
+//! change without a major bump) fails the check, and `publish` runs the same
+//! check whenever a snapshot is present in the project.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use contract_abi::{ContractABI, ContractError, ContractEvent, ContractFunction, SorobanType};
+use serde::{Deserialize, Serialize};
+use shared::semver::SemVer;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const ABI_SNAPSHOT_FILE: &str = "abi.snapshot.json";
+
+/// Normalized, deterministic view of a contract ABI.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbiSnapshot {
+    /// Crate version the snapshot was taken at.
+    pub version: String,
+    pub functions: BTreeMap<String, ContractFunction>,
+    pub types: BTreeMap<String, SorobanType>,
+    pub events: Vec<ContractEvent>,
+    pub errors: Vec<ContractError>,
+}
+
+impl AbiSnapshot {
+    pub fn from_abi(abi: ContractABI, version: String) -> Self {
+        let mut events = abi.events;
+        events.sort_by(|a, b| a.name.cmp(&b.name));
+        let mut errors = abi.errors;
+        errors.sort_by_key(|e| e.code);
+        Self {
+            version,
+            functions: abi
+                .functions
+                .into_iter()
+                .map(|f| (f.name.clone(), f))
+                .collect(),
+            types: abi.types.into_iter().collect(),
+            events,
+            errors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AbiChangeKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Debug, Clone)]
+pub struct AbiChange {
+    pub kind: AbiChangeKind,
+    pub item: String,
+    pub breaking: bool,
+}
+
+/// Compare two snapshots. Additions are compatible; removals and signature
+/// changes break existing callers.
+pub fn diff(old: &AbiSnapshot, new: &AbiSnapshot) -> Vec<AbiChange> {
+    let mut changes = Vec::new();
+    diff_map(&old.functions, &new.functions, "fn", &mut changes);
+    diff_map(&old.types, &new.types, "type", &mut changes);
+
+    let old_events = serde_json::to_value(&old.events).unwrap_or_default();
+    let new_events = serde_json::to_value(&new.events).unwrap_or_default();
+    if old_events != new_events {
+        changes.push(AbiChange {
+            kind: AbiChangeKind::Changed,
+            item: "events".into(),
+            breaking: old.events.iter().any(|e| {
+                !new.events
+                    .iter()
+                    .any(|n| serde_json::to_value(n).ok() == serde_json::to_value(e).ok())
+            }),
+        });
+    }
+
+    for err in &old.errors {
+        match new.errors.iter().find(|e| e.code == err.code) {
+            None => changes.push(AbiChange {
+                kind: AbiChangeKind::Removed,
+                item: format!("error {}", err.name),
+                breaking: true,
+            }),
+            Some(e) if e.name != err.name => changes.push(AbiChange {
+                kind: AbiChangeKind::Changed,
+                item: format!("error {}", err.name),
+                breaking: true,
+            }),
+            _ => {}
+        }
+    }
+    for err in &new.errors {
+        if !old.errors.iter().any(|e| e.code == err.code) {
+            changes.push(AbiChange {
+                kind: AbiChangeKind::Added,
+                item: format!("error {}", err.name),
+                breaking: false,
+            });
+        }
+    }
+
+    changes
+}
+
+fn diff_map<T: Serialize>(
+    old: &BTreeMap<String, T>,
+    new: &BTreeMap<String, T>,
+    label: &str,
+    changes: &mut Vec<AbiChange>,
+) {
+    for (name, old_item) in old {
+        match new.get(name) {
+            None => changes.push(AbiChange {
+                kind: AbiChangeKind::Removed,
+                item: format!("{} {}", label, name),
+                breaking: true,
+            }),
+            Some(new_item) => {
+                if serde_json::to_value(old_item).ok() != serde_json::to_value(new_item).ok() {
+                    changes.push(AbiChange {
+                        kind: AbiChangeKind::Changed,
+                        item: format!("{} {}", label, name),
+                        breaking: true,
+                    });
+                }
+            }
+        }
+    }
+    for name in new.keys().filter(|n| !old.contains_key(*n)) {
+        changes.push(AbiChange {
+            kind: AbiChangeKind::Added,
+            item: format!("{} {}", label, name),
+            breaking: false,
+        });
+    }
+}
+
+/// Returns an error message when `changes` are not covered by the bump from
+/// `old_version` to `new_version`. Pre-1.0 crates treat a minor bump as major.
+pub fn required_bump_violation(
+    changes: &[AbiChange],
+    old_version: &str,
+    new_version: &str,
+) -> Option<String> {
+    if changes.is_empty() {
+        return None;
+    }
+    let (old, new) = match (SemVer::parse(old_version), SemVer::parse(new_version)) {
+        (Some(o), Some(n)) => (o, n),
+        _ => {
+            return (old_version == new_version).then(|| {
+                format!(
+                    "ABI changed but version is still {}; bump the crate version",
+                    new_version
+                )
+            })
+        }
+    };
+
+    if new <= old {
+        return Some(format!(
+            "ABI changed but version {} was not bumped past snapshot {}",
+            new_version, old_version
+        ));
+    }
+
+    let breaking = changes.iter().any(|c| c.breaking);
+    let major_bumped = if old.major == 0 {
+        new.major > 0 || new.minor > old.minor
+    } else {
+        new.major > old.major
+    };
+    if breaking && !major_bumped {
+        return Some(format!(
+            "Breaking ABI change requires a major version bump (snapshot {}, crate {})",
+            old_version, new_version
+        ));
+    }
+    None
+}
+
+pub fn snapshot(contract_path: &str, wasm: Option<&str>, output: Option<&str>) -> Result<()> {
+    let project = Path::new(contract_path);
+    let current = load_current(project, wasm)?;
+    let out = output
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project.join(ABI_SNAPSHOT_FILE));
+    fs::write(&out, serde_json::to_string_pretty(&current)? + "\n")
+        .with_context(|| format!("Failed to write {}", out.display()))?;
+
+    println!(
+        "{} ABI snapshot written to {} ({} functions, version {})",
+        "✓".green(),
+        out.display(),
+        current.functions.len(),
+        current.version
+    );
+    Ok(())
+}
+
+pub fn check(contract_path: &str, wasm: Option<&str>, snapshot: Option<&str>) -> Result<()> {
+    let project = Path::new(contract_path);
+    let path = snapshot
+        .map(PathBuf::from)
+        .unwrap_or_else(|| project.join(ABI_SNAPSHOT_FILE));
+    let recorded = load_snapshot(&path)?;
+    let current = load_current(project, wasm)?;
+    let changes = diff(&recorded, &current);
+
+    if changes.is_empty() {
+        println!("{} ABI matches snapshot {}", "✓".green(), path.display());
+        return Ok(());
+    }
+
+    println!("\n{}", "ABI changes since snapshot:".bold());
+    for change in &changes {
+        let marker = match change.kind {
+            AbiChangeKind::Added => "+".green(),
+            AbiChangeKind::Removed => "-".red(),
+            AbiChangeKind::Changed => "~".yellow(),
+        };
+        let note = if change.breaking {
+            " (breaking)".red().to_string()
+        } else {
+            String::new()
+        };
+        println!("  {} {}{}", marker, change.item, note);
+    }
+
+    if let Some(msg) = required_bump_violation(&changes, &recorded.version, &current.version) {
+        anyhow::bail!("{}", msg);
+    }
+
+    println!(
+        "\n{} version bump {} → {} covers these changes; run `abi snapshot` to update the file",
+        "✓".green(),
+        recorded.version,
+        current.version
+    );
+    Ok(())
+}
+
+/// Publish-time hook: a no-op unless the project has committed a snapshot.
+pub fn check_before_publish(contract_path: &str) -> Result<()> {
+    let project = Path::new(contract_path);
+    if !project.join(ABI_SNAPSHOT_FILE).exists() {
+        return Ok(());
+    }
+    println!("\n{}", "Checking ABI against snapshot...".bold().cyan());
+    check(contract_path, None, None).context("ABI snapshot check failed; refusing to publish")
+}
+
+/// Share of spec functions and types (in percent) below which `publish`
+/// warns about missing doc comments.
+pub const DEFAULT_MIN_DOCS_COVERAGE: f64 = 50.0;
+
+/// Publish-time hook: warns when the built contract's spec documents fewer
+/// than `min_percent` of its functions and types. Never fails the publish;
+/// a project that has not been built yet is skipped.
+pub fn warn_on_docs_coverage(contract_path: &str, min_percent: f64) {
+    let project = Path::new(contract_path);
+    let Ok((name, _)) = crate_identity(project) else {
+        return;
+    };
+    let wasm_path = default_wasm_path(project, &name);
+    if !wasm_path.is_file() {
+        return;
+    }
+    let coverage = crate::commands::load_abi_json(&wasm_path.to_string_lossy())
+        .ok()
+        .and_then(|json| contract_abi::json_docs_coverage(&json).ok());
+    let Some(coverage) = coverage else {
+        return;
+    };
+    let Some(ratio) = coverage.ratio() else {
+        return;
+    };
+    if ratio * 100.0 < min_percent {
+        println!(
+            "{} only {} of {} functions and types have doc comments ({:.0}%, below {:.0}%)",
+            "⚠".yellow().bold(),
+            coverage.documented,
+            coverage.total,
+            ratio * 100.0,
+            min_percent
+        );
+    }
+}
+
+fn load_snapshot(path: &Path) -> Result<AbiSnapshot> {
+    let raw = fs::read_to_string(path).with_context(|| {
+        format!(
+            "No ABI snapshot at {}; run `soroban-registry abi snapshot` first",
+            path.display()
+        )
+    })?;
+    serde_json::from_str(&raw).context("ABI snapshot file is malformed")
+}
+
+fn load_current(project: &Path, wasm: Option<&str>) -> Result<AbiSnapshot> {
+    let (name, version) = crate_identity(project)?;
+    let wasm_path = match wasm {
+        Some(w) => PathBuf::from(w),
+        None => default_wasm_path(project, &name),
+    };
+    if !wasm_path.is_file() {
+        anyhow::bail!(
+            "WASM not found at {}; build the contract or pass --wasm",
+            wasm_path.display()
+        );
+    }
+    let abi_json = crate::commands::load_abi_json(&wasm_path.to_string_lossy())?;
+    let abi = contract_abi::parse_json_spec(&abi_json, &name)
+        .map_err(|e| anyhow::anyhow!("Failed to parse ABI: {}", e))?;
+    Ok(AbiSnapshot::from_abi(abi, version))
+}
+
+fn crate_identity(project: &Path) -> Result<(String, String)> {
+    let raw = fs::read_to_string(project.join("Cargo.toml"))
+        .with_context(|| format!("No Cargo.toml in {}", project.display()))?;
+    let manifest: toml::Value = toml::from_str(&raw).context("Failed to parse Cargo.toml")?;
+    let package = manifest
+        .get("package")
+        .context("Cargo.toml has no [package]")?;
+    let name = package
+        .get("name")
+        .and_then(|v| v.as_str())
+        .context("Cargo.toml has no package name")?;
+    let version = package
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("0.0.0");
+    Ok((name.to_string(), version.to_string()))
+}
+
+pub(crate) fn default_wasm_path(project: &Path, name: &str) -> PathBuf {
+    project
+        .join("target/wasm32-unknown-unknown/release")
+        .join(format!("{}.wasm", name.replace('-', "_")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contract_abi::FunctionVisibility;
+
+    fn func(name: &str, params: &[&str]) -> ContractFunction {
+        ContractFunction {
+            name: name.into(),
+            visibility: FunctionVisibility::Public,
+            params: params
+                .iter()
+                .map(|p| contract_abi::FunctionParam {
+                    name: (*p).into(),
+                    param_type: SorobanType::U32,
+                    doc: None,
+                })
+                .collect(),
+            return_type: SorobanType::Void,
+            doc: None,
+            is_mutable: true,
+        }
+    }
+
+    fn snap(version: &str, fns: Vec<ContractFunction>) -> AbiSnapshot {
+        AbiSnapshot {
+            version: version.into(),
+            functions: fns.into_iter().map(|f| (f.name.clone(), f)).collect(),
+            types: BTreeMap::new(),
+            events: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn identical_snapshots_have_no_changes() {
+        let a = snap("1.0.0", vec![func("transfer", &["amount"])]);
+        assert!(diff(&a, &a.clone()).is_empty());
+    }
+
+    #[test]
+    fn added_function_needs_only_a_bump() {
+        let old = snap("1.0.0", vec![func("transfer", &["amount"])]);
+        let new = snap(
+            "1.1.0",
+            vec![func("transfer", &["amount"]), func("burn", &[])],
+        );
+        let changes = diff(&old, &new);
+        assert_eq!(changes.len(), 1);
+        assert!(!changes[0].breaking);
+        assert!(required_bump_violation(&changes, "1.0.0", "1.1.0").is_none());
+        assert!(required_bump_violation(&changes, "1.0.0", "1.0.0").is_some());
+    }
+
+    #[test]
+    fn changed_signature_requires_major_bump() {
+        let old = snap("1.2.0", vec![func("transfer", &["amount"])]);
+        let new = snap("1.3.0", vec![func("transfer", &["amount", "memo"])]);
+        let changes = diff(&old, &new);
+        assert!(changes[0].breaking);
+        assert!(required_bump_violation(&changes, "1.2.0", "1.3.0").is_some());
+        assert!(required_bump_violation(&changes, "1.2.0", "2.0.0").is_none());
+    }
+
+    #[test]
+    fn pre_release_crates_treat_minor_as_major() {
+        let old = snap("0.1.0", vec![func("transfer", &["amount"])]);
+        let new = snap("0.2.0", vec![]);
+        let changes = diff(&old, &new);
+        assert!(required_bump_violation(&changes, "0.1.0", "0.1.1").is_some());
+        assert!(required_bump_violation(&changes, "0.1.0", "0.2.0").is_none());
+    }
+}
@@ -0,0 +1,188 @@
+//! admin.rs — `soroban-registry admin dump|restore`
+//!
+//! Exports the whole registry (publishers, contracts, versions, dependency
+//! edges, patches, proposals, …) into a portable gzip'd JSON archive and
+//! imports such an archive into another instance. Both call the admin-only
+//! `/api/admin/dump` and `/api/admin/restore` endpoints, so an admin bearer
+//! token is required.
+//!
+//! Dumps are streamed straight into the gzip file as they arrive; the table
+//! counts shown afterwards are read back from that file without loading it.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::de::{Deserializer, IgnoredAny, SeqAccess, Visitor};
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::io::{BufReader, Read};
+use std::time::Duration;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+/// Large dumps can take longer than any fixed limit to transfer, so only a
+/// stalled connection times out.
+fn client() -> Result<reqwest::Client> {
+    crate::http::builder()
+        .read_timeout(Duration::from_secs(600))
+        .build()
+        .context("Failed to build HTTP client")
+}
+
+async fn error_message(resp: reqwest::Response) -> String {
+    let status = resp.status();
+    let body = resp.text().await.unwrap_or_default();
+    if let Some(message) = crate::http::size_error(status, &body) {
+        return message;
+    }
+    let message = serde_json::from_str::<Value>(&body)
+        .ok()
+        .and_then(|v| v["message"].as_str().map(str::to_string))
+        .unwrap_or(body);
+    format!("{} {}", status, message)
+}
+
+pub async fn dump(api_url: &str, token: &str, output: &str) -> Result<()> {
+    let url = format!("{}/api/admin/dump", api_url);
+    log::debug!("GET {}", url);
+    let resp = client()?
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach the registry")?;
+    if !resp.status().is_success() {
+        bail!("Dump failed: {}", error_message(resp).await);
+    }
+
+    let file =
+        std::fs::File::create(output).with_context(|| format!("Failed to create {}", output))?;
+    let mut encoder = GzEncoder::new(file, Compression::default());
+    if let Err(err) = crate::http::download(resp, &mut encoder, "Downloading dump").await {
+        let _ = std::fs::remove_file(output);
+        return Err(err);
+    }
+    encoder.finish()?;
+
+    let file =
+        std::fs::File::open(output).with_context(|| format!("Failed to reopen {}", output))?;
+    let summary: DumpSummary = serde_json::from_reader(BufReader::new(GzDecoder::new(file)))
+        .context("Registry returned an invalid dump")?;
+
+    println!("\n{}", "Registry Dump".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    for (table, RowCount(count)) in &summary.tables {
+        println!("  {:<28} {:>8} rows", table, count);
+    }
+    println!(
+        "\n{} Wrote {} (schema version {})",
+        "✓".green(),
+        output.bold(),
+        summary.schema_version
+    );
+    Ok(())
+}
+
+pub async fn restore(api_url: &str, token: &str, file: &str, force: bool) -> Result<()> {
+    let raw = std::fs::read(file).with_context(|| format!("Failed to read {}", file))?;
+    let (gzipped, body) = if raw.starts_with(&GZIP_MAGIC) {
+        let mut json = Vec::new();
+        GzDecoder::new(raw.as_slice())
+            .read_to_end(&mut json)
+            .with_context(|| format!("Failed to decompress {}", file))?;
+        (Some(raw), json)
+    } else {
+        (None, raw)
+    };
+    let archive: Value = serde_json::from_slice(&body)
+        .with_context(|| format!("{} is not a registry dump", file))?;
+    if !archive["tables"].is_object() {
+        bail!("{} is not a registry dump (no `tables` object)", file);
+    }
+
+    let url = format!("{}/api/admin/restore?force={}", api_url, force);
+    log::debug!("POST {}", url);
+    let request = client()?
+        .post(&url)
+        .bearer_auth(token)
+        .header(reqwest::header::CONTENT_TYPE, "application/json");
+    // A .json.gz dump is uploaded as-is, without recompressing it.
+    let resp = match gzipped {
+        Some(gzipped) => crate::http::send_gzipped(request, gzipped, body).await,
+        None => crate::http::send_compressed(request, body).await,
+    }
+    .context("Failed to reach the registry")?;
+    if resp.status() == reqwest::StatusCode::CONFLICT {
+        bail!(
+            "Restore refused: {}\nRe-run with --force to restore anyway.",
+            error_message(resp).await
+        );
+    }
+    if !resp.status().is_success() {
+        bail!("Restore failed: {}", error_message(resp).await);
+    }
+    let summary: Value = resp.json().await.context("Invalid restore response")?;
+
+    println!("\n{}", "Registry Restore".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    let mut total = 0;
+    if let Some(inserted) = summary["inserted"].as_object() {
+        for (table, count) in inserted {
+            let count = count.as_u64().unwrap_or(0);
+            total += count;
+            println!("  {:<28} {:>8} inserted", table, count);
+        }
+    }
+    if let Some(skipped) = summary["skipped_tables"].as_array() {
+        for table in skipped.iter().filter_map(Value::as_str) {
+            println!(
+                "  {} {} skipped (unknown to this instance)",
+                "⚠".yellow(),
+                table
+            );
+        }
+    }
+    println!(
+        "\n{} Restored {} rows from {}",
+        "✓".green(),
+        total,
+        file.bold()
+    );
+    Ok(())
+}
+
+/// The parts of a dump `dump` reports on; rows are counted, not kept.
+#[derive(Deserialize)]
+struct DumpSummary {
+    schema_version: Value,
+    tables: BTreeMap<String, RowCount>,
+}
+
+struct RowCount(usize);
+
+impl<'de> Deserialize<'de> for RowCount {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct CountRows;
+
+        impl<'de> Visitor<'de> for CountRows {
+            type Value = RowCount;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("an array of rows")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut rows: A) -> Result<RowCount, A::Error> {
+                let mut count = 0;
+                while rows.next_element::<IgnoredAny>()?.is_some() {
+                    count += 1;
+                }
+                Ok(RowCount(count))
+            }
+        }
+
+        deserializer.deserialize_seq(CountRows)
+    }
+}
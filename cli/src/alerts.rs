@@ -0,0 +1,215 @@
+//! alerts.rs — `soroban-registry alerts create|list|delete`
+//!
+//! Manages the caller's alert rules on contract events. A rule fires when a
+//! topic (optionally with a payload value) is emitted at least `threshold`
+//! times within a window, and is delivered to its webhook. All calls use the
+//! bearer token issued by the registry's wallet login.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub struct NewAlert<'a> {
+    pub contract_id: &'a str,
+    pub topic: &'a str,
+    pub name: Option<&'a str>,
+    /// `path=value` payload predicate
+    pub matching: Option<&'a str>,
+    pub threshold: i32,
+    pub window: &'a str,
+    pub webhook: Option<&'a str>,
+}
+
+pub async fn create(api_url: &str, token: &str, alert: NewAlert<'_>) -> Result<()> {
+    let window_seconds = parse_window(alert.window)?;
+    let (data_path, data_value) = match alert.matching {
+        Some(predicate) => {
+            let (path, value) = parse_predicate(predicate)?;
+            (path, Some(value))
+        }
+        None => (None, None),
+    };
+
+    let body = json!({
+        "contract_id": alert.contract_id,
+        "name": alert.name,
+        "topic": alert.topic,
+        "data_path": data_path,
+        "data_value": data_value,
+        "threshold": alert.threshold,
+        "window_seconds": window_seconds,
+        "webhook_url": alert.webhook,
+    });
+    let response = crate::http::client()
+        .post(format!("{}/api/alerts", api_url))
+        .bearer_auth(token)
+        .json(&body)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let rule = parse_response(response).await?;
+
+    println!("\n{}", "✓ Alert created".green().bold());
+    print_rule(&rule);
+    if rule["webhook_url"].is_null() {
+        println!(
+            "  {} No webhook set; firings are recorded but not delivered.",
+            "!".yellow().bold()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn list(api_url: &str, token: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/alerts", api_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let rules = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&rules)?);
+        return Ok(());
+    }
+
+    let rules = rules.as_array().cloned().unwrap_or_default();
+    println!("\n{}", "Alert Rules".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    if rules.is_empty() {
+        println!("  No alert rules. Create one with `soroban-registry alerts create`.");
+    }
+    for rule in &rules {
+        print_rule(rule);
+    }
+    println!("{}", "=".repeat(80).cyan());
+    println!("  {} rule(s)\n", rules.len());
+    Ok(())
+}
+
+pub async fn delete(api_url: &str, token: &str, alert_id: &str) -> Result<()> {
+    let response = crate::http::client()
+        .delete(format!("{}/api/alerts/{}", api_url, alert_id))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    parse_response(response).await?;
+
+    println!("{} Alert {} deleted", "✓".green().bold(), alert_id);
+    Ok(())
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_rule(rule: &Value) {
+    println!(
+        "\n  {} {}",
+        rule["name"].as_str().unwrap_or("?").bold(),
+        format!("({})", rule["id"].as_str().unwrap_or("?")).bright_black()
+    );
+    println!(
+        "    {}: {} [{}]",
+        "Contract".bold(),
+        rule["contract_address"].as_str().unwrap_or("?"),
+        rule["network"].as_str().unwrap_or("?")
+    );
+    let mut condition = format!("topic = {}", rule["topic"].as_str().unwrap_or("?"));
+    if !rule["data_value"].is_null() {
+        let path = rule["data_path"].as_str().unwrap_or("data");
+        condition.push_str(&format!(" and {} = {}", path, rule["data_value"]));
+    }
+    println!("    {}: {}", "When".bold(), condition);
+    println!(
+        "    {}: {} event(s) within {}s",
+        "Fires at".bold(),
+        rule["threshold"].as_i64().unwrap_or(1),
+        rule["window_seconds"].as_i64().unwrap_or(0)
+    );
+    println!(
+        "    {}: {}",
+        "Webhook".bold(),
+        rule["webhook_url"].as_str().unwrap_or("-")
+    );
+    println!(
+        "    {}: {}",
+        "Last fired".bold(),
+        rule["last_triggered_at"].as_str().unwrap_or("never")
+    );
+}
+
+/// `300`, `300s`, `5m`, `1h` or `2d` to seconds.
+fn parse_window(window: &str) -> Result<i64> {
+    let window = window.trim();
+    let (digits, unit) = match window.find(|c: char| !c.is_ascii_digit()) {
+        Some(idx) => window.split_at(idx),
+        None => (window, "s"),
+    };
+    let value: i64 = digits
+        .parse()
+        .with_context(|| format!("Invalid window {:?}; use e.g. 300, 5m, 1h", window))?;
+    let multiplier = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        _ => bail!("Invalid window unit {:?}; use s, m, h or d", unit),
+    };
+    if value == 0 {
+        bail!("Window must be greater than zero");
+    }
+    Ok(value * multiplier)
+}
+
+/// `path=value` (or `=value` for the whole payload). The value is parsed as
+/// JSON, falling back to a plain string, so `new_admin=GABC...` and
+/// `amount=1000` both work.
+fn parse_predicate(predicate: &str) -> Result<(Option<String>, Value)> {
+    let (path, value) = predicate
+        .split_once('=')
+        .context("Expected --match in the form path=value")?;
+    let path = path.trim();
+    let value = value.trim();
+    let value = serde_json::from_str(value).unwrap_or_else(|_| Value::String(value.to_string()));
+    Ok(((!path.is_empty()).then(|| path.to_string()), value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_windows() {
+        assert_eq!(parse_window("300").unwrap(), 300);
+        assert_eq!(parse_window("5m").unwrap(), 300);
+        assert_eq!(parse_window("1h").unwrap(), 3600);
+        assert_eq!(parse_window("2d").unwrap(), 172_800);
+        assert!(parse_window("0").is_err());
+        assert!(parse_window("5w").is_err());
+        assert!(parse_window("m").is_err());
+    }
+
+    #[test]
+    fn parses_predicates() {
+        assert_eq!(
+            parse_predicate("new_admin=GABC").unwrap(),
+            (Some("new_admin".to_string()), json!("GABC"))
+        );
+        assert_eq!(
+            parse_predicate("args.amount=1000").unwrap(),
+            (Some("args.amount".to_string()), json!(1000))
+        );
+        assert_eq!(parse_predicate("=true").unwrap(), (None, json!(true)));
+        assert!(parse_predicate("no_equals").is_err());
+    }
+}
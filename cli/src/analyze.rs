@@ -101,7 +101,7 @@ pub async fn run(
         output
     );
 
-    let client = reqwest::Client::builder()
+    let client = crate::http::builder()
         .timeout(Duration::from_secs(30))
         .build()
         .context("Failed to build HTTP client")?;
@@ -121,7 +121,9 @@ pub async fn run(
     let detail = fetch_detail(&client, api_url, &contract).await;
 
     // ── 3. Fetch dependency list ──────────────────────────────────────────────
-    let deps_raw = fetch_dependencies(&client, api_url, contract_id).await;
+    // The dependency endpoint takes the registry UUID.
+    let registry_id = contract["id"].as_str().unwrap_or(contract_id);
+    let deps_raw = fetch_dependencies(&client, api_url, registry_id).await;
 
     // ── 4. Run analysis ───────────────────────────────────────────────────────
     if report_format != "json" {
@@ -0,0 +1,161 @@
+//! anchor.rs — check a registry record against its on-chain anchor
+//!
+//! The registry can write a digest of each contract record into a Stellar
+//! account's data entries. `verify-anchor` recomputes that digest locally
+//! from the record the registry serves and compares it with the value read
+//! straight from Horizon, so a tampered registry cannot vouch for itself.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use colored::Colorize;
+use serde_json::Value;
+use shared::anchor::AnchorFields;
+
+fn default_horizon(network: &str) -> Option<&'static str> {
+    match network {
+        "mainnet" => Some("https://horizon.stellar.org"),
+        "testnet" => Some("https://horizon-testnet.stellar.org"),
+        "futurenet" => Some("https://horizon-futurenet.stellar.org"),
+        _ => None,
+    }
+}
+
+pub async fn verify(
+    api_url: &str,
+    contract_id: &str,
+    anchor_account: Option<&str>,
+    horizon_url: Option<&str>,
+) -> Result<()> {
+    let client = crate::http::client();
+
+    let status = get_json(
+        &client,
+        &format!("{}/api/contracts/{}/anchor", api_url, contract_id),
+    )
+    .await
+    .context("Failed to fetch anchor status from the registry")?;
+    let id = status["id"]
+        .as_str()
+        .context("Anchor status is missing id")?;
+    let data_name = status["data_name"]
+        .as_str()
+        .context("Anchor status is missing data_name")?;
+
+    let record = get_json(&client, &format!("{}/api/contracts/{}", api_url, id))
+        .await
+        .context("Failed to fetch contract record from the registry")?;
+    let network = record["network"].as_str().unwrap_or_default();
+    let fields = AnchorFields {
+        contract_id: record["contract_id"].as_str().unwrap_or_default(),
+        network,
+        name: record["name"].as_str().unwrap_or_default(),
+        description: record["description"].as_str(),
+        category: record["category"].as_str(),
+        publisher_id: record["publisher_id"]
+            .as_str()
+            .and_then(|s| s.parse().ok())
+            .context("Contract record is missing publisher_id")?,
+        wasm_hash: record["wasm_hash"].as_str().unwrap_or_default(),
+    };
+    let digest = fields.digest();
+
+    let account = match anchor_account {
+        Some(account) => account.to_string(),
+        None => {
+            let Some(account) = status["anchor_account"].as_str() else {
+                bail!(
+                    "This registry does not anchor records on-chain (no anchor account configured)"
+                );
+            };
+            println!(
+                "{} using the anchor account reported by the registry; pin it with --anchor-account",
+                "!".yellow().bold()
+            );
+            account.to_string()
+        }
+    };
+    let horizon = match horizon_url {
+        Some(url) => url.trim_end_matches('/').to_string(),
+        None => default_horizon(network)
+            .with_context(|| format!("Unknown network {:?}; pass --horizon-url", network))?
+            .to_string(),
+    };
+
+    println!("\n{}", "Verifying on-chain anchor...".bold().cyan());
+    println!("  {}: {}", "Contract".bold(), fields.contract_id);
+    println!("  {}: {}", "Network".bold(), network);
+    println!(
+        "  {}: {}",
+        "Anchor account".bold(),
+        account.bright_magenta()
+    );
+    println!("  {}: {}", "Data entry".bold(), data_name);
+    println!(
+        "  {}: {}",
+        "Local digest".bold(),
+        hex::encode(digest).bright_black()
+    );
+
+    let response = client
+        .get(format!(
+            "{}/accounts/{}/data/{}",
+            horizon, account, data_name
+        ))
+        .send()
+        .await
+        .context("Failed to reach Horizon")?;
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        bail!("No anchor found on-chain for this contract");
+    }
+    if !response.status().is_success() {
+        bail!("Horizon error ({})", response.status());
+    }
+    let entry: Value = response.json().await?;
+    let on_chain = BASE64
+        .decode(entry["value"].as_str().unwrap_or_default())
+        .context("On-chain data entry is not valid base64")?;
+    println!(
+        "  {}: {}",
+        "On-chain digest".bold(),
+        hex::encode(&on_chain).bright_black()
+    );
+
+    if on_chain != digest {
+        println!(
+            "{}",
+            "\n✗ Registry record does NOT match its on-chain anchor"
+                .red()
+                .bold()
+        );
+        if status["up_to_date"].as_bool() == Some(false) {
+            println!(
+                "  {} The registry reports a pending anchor; retry once it is submitted.",
+                "→".bright_black()
+            );
+        }
+        bail!("Anchor mismatch for {}", fields.contract_id);
+    }
+
+    println!(
+        "{}",
+        "\n✓ Registry record matches its on-chain anchor\n"
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+async fn get_json(client: &reqwest::Client, url: &str) -> Result<Value> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
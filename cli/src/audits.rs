@@ -0,0 +1,128 @@
+//! audits.rs — `soroban-registry audits <contract>`
+//!
+//! Lists the audit reports auditors attached to a contract's versions, with
+//! each report's findings per severity and where to download the document.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+fn count(findings: &Value, severity: &str) -> i64 {
+    findings[severity].as_i64().unwrap_or(0)
+}
+
+/// "2 critical, 1 high, 3 low"; severities without findings are left out.
+fn findings_line(findings: &Value) -> String {
+    let parts: Vec<String> = ["critical", "high", "medium", "low", "informational"]
+        .iter()
+        .filter(|severity| count(findings, severity) > 0)
+        .map(|severity| format!("{} {}", count(findings, severity), severity))
+        .collect();
+    if parts.is_empty() {
+        "no findings".to_string()
+    } else {
+        parts.join(", ")
+    }
+}
+
+pub async fn show(
+    api_url: &str,
+    contract_id: &str,
+    version: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let base_url = api_url.trim_end_matches('/');
+    let mut request =
+        crate::http::client().get(format!("{}/api/contracts/{}/audits", base_url, contract_id));
+    if let Some(version) = version {
+        request = request.query(&[("version", version)]);
+    }
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    let audits = body.as_array().cloned().unwrap_or_default();
+    println!("\n{}", format!("Audits for {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    if audits.is_empty() {
+        println!("  {}\n", "No audit reports attached.".bright_black());
+        return Ok(());
+    }
+
+    for audit in &audits {
+        let findings = &audit["findings"];
+        let severe = count(findings, "critical") + count(findings, "high");
+        let line = findings_line(findings);
+        println!(
+            "\n  {} {}",
+            audit["title"].as_str().unwrap_or("?").bold(),
+            format!("(v{})", audit["version"].as_str().unwrap_or("?")).bright_black()
+        );
+        println!(
+            "    {}: {} ({})",
+            "Auditor".bold(),
+            audit["auditor_name"].as_str().unwrap_or("?"),
+            audit["auditor_address"].as_str().unwrap_or("?")
+        );
+        println!(
+            "    {}: {}",
+            "Completed".bold(),
+            audit["audited_at"]
+                .as_str()
+                .and_then(|at| at.get(..10))
+                .unwrap_or("?")
+        );
+        println!(
+            "    {}: {}",
+            "Findings".bold(),
+            if severe > 0 {
+                line.red().to_string()
+            } else {
+                line.green().to_string()
+            }
+        );
+        if let Some(summary) = audit["summary"].as_str() {
+            println!("    {}: {}", "Summary".bold(), summary);
+        }
+        println!(
+            "    {}: {}",
+            "Report".bold(),
+            format!(
+                "{}/api/contracts/{}/audits/{}/report",
+                base_url,
+                contract_id,
+                audit["id"].as_str().unwrap_or("?")
+            )
+            .blue()
+            .underline()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn summarises_only_severities_with_findings() {
+        let findings =
+            json!({ "critical": 0, "high": 1, "medium": 0, "low": 3, "informational": 0 });
+        assert_eq!(findings_line(&findings), "1 high, 3 low");
+        assert_eq!(findings_line(&json!({})), "no findings");
+    }
+}
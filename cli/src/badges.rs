@@ -0,0 +1,209 @@
+//! badges.rs — `soroban-registry badges list|attest|revoke`
+//!
+//! Badges are granted by attestation authorities (auditors and other
+//! publishers a registry admin has recognised). `attest` signs the
+//! `shared::attestation` statement for the contract's current build locally
+//! with the authority's secret key and submits it; the key never leaves the
+//! machine. `revoke` lets the authority withdraw a badge it granted.
+
+use anyhow::{bail, Context, Result};
+use chrono::{Duration, Utc};
+use colored::Colorize;
+use ed25519_dalek::Signer;
+use serde_json::{json, Value};
+use shared::attestation::AttestationStatement;
+
+use crate::claim::{decode_secret_key, encode_strkey, VERSION_ACCOUNT};
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_badge(badge: &Value) {
+    let kind = badge["kind"].as_str().unwrap_or("?");
+    let status = if badge["active"].as_bool().unwrap_or(false) {
+        "active".green()
+    } else if badge["revoked_at"].is_string() {
+        "revoked".red()
+    } else {
+        "expired".yellow()
+    };
+    println!(
+        "  {} [{}] {}",
+        kind.bright_magenta().bold(),
+        status,
+        badge["id"].as_str().unwrap_or("?").bright_black()
+    );
+    println!(
+        "      {}: {} ({})",
+        "Authority".bold(),
+        badge["authority_name"].as_str().unwrap_or("?"),
+        badge["authority_address"].as_str().unwrap_or("?")
+    );
+    println!(
+        "      {}: {}",
+        "Build".bold(),
+        badge["wasm_hash"].as_str().unwrap_or("?")
+    );
+    println!(
+        "      {}: {}{}",
+        "Issued".bold(),
+        badge["issued_at"].as_str().unwrap_or("?"),
+        badge["expires_at"]
+            .as_str()
+            .map(|at| format!(", expires {}", at))
+            .unwrap_or_default()
+    );
+    if let Some(report) = badge["report_url"].as_str() {
+        println!("      {}: {}", "Report".bold(), report);
+    }
+    if let Some(reason) = badge["revocation_reason"].as_str() {
+        println!("      {}: {}", "Revoked".bold(), reason);
+    }
+}
+
+pub async fn list(api_url: &str, contract_id: &str, all: bool, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/badges",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .query(&[("include_inactive", all)])
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let badges = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&badges)?);
+        return Ok(());
+    }
+    let badges = badges.as_array().cloned().unwrap_or_default();
+    println!("\n{}", format!("Badges for {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    if badges.is_empty() {
+        println!("  {}\n", "No badges.".bright_black());
+        return Ok(());
+    }
+    for badge in &badges {
+        print_badge(badge);
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn attest(
+    api_url: &str,
+    contract_id: &str,
+    kind: &str,
+    secret_key: &str,
+    report_url: Option<&str>,
+    expires_in_days: Option<i64>,
+) -> Result<()> {
+    let base_url = api_url.trim_end_matches('/');
+    let signing_key = decode_secret_key(secret_key)?;
+    let address = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+    let client = crate::http::client();
+
+    let response = client
+        .get(format!("{}/api/contracts/{}", base_url, contract_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let contract = check(response).await?;
+    let field = |name: &str| -> Result<String> {
+        contract[name]
+            .as_str()
+            .map(str::to_string)
+            .with_context(|| format!("Contract record is missing {}", name))
+    };
+    let (uuid, onchain_id, network, wasm_hash) = (
+        field("id")?,
+        field("contract_id")?,
+        field("network")?,
+        field("wasm_hash")?,
+    );
+
+    let issued_at = Utc::now();
+    let statement = AttestationStatement {
+        network: &network,
+        contract_id: &onchain_id,
+        kind,
+        wasm_hash: &wasm_hash,
+        report_url,
+        issued_at,
+        expires_at: expires_in_days.map(|days| issued_at + Duration::days(days)),
+    };
+    statement.validate().map_err(anyhow::Error::msg)?;
+
+    println!("\n{}", "Signing attestation...".bold().cyan());
+    println!(
+        "  {}: {} on {}",
+        "Contract".bold(),
+        onchain_id.bright_black(),
+        network
+    );
+    println!("  {}: {}", "Badge".bold(), kind.bright_magenta());
+    println!("  {}: {}", "Build".bold(), wasm_hash.bright_black());
+    println!("  {}: {}", "Authority".bold(), address.bright_magenta());
+
+    let signature = hex::encode(
+        signing_key
+            .sign(statement.signing_message().as_bytes())
+            .to_bytes(),
+    );
+    let response = client
+        .post(format!("{}/api/contracts/{}/badges", base_url, uuid))
+        .json(&json!({
+            "authority_address": address,
+            "kind": kind,
+            "wasm_hash": wasm_hash,
+            "report_url": report_url,
+            "issued_at": statement.issued_at,
+            "expires_at": statement.expires_at,
+            "signature": signature,
+        }))
+        .send()
+        .await
+        .context("Failed to submit attestation")?;
+    let badge = check(response).await?;
+
+    println!("{}", "\n✓ Badge granted!".green().bold());
+    print_badge(&badge);
+    println!();
+    Ok(())
+}
+
+pub async fn revoke(
+    api_url: &str,
+    contract_id: &str,
+    badge_id: &str,
+    reason: Option<&str>,
+    token: &str,
+) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/contracts/{}/badges/{}/revoke",
+            api_url.trim_end_matches('/'),
+            contract_id,
+            badge_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "reason": reason }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let badge = check(response).await?;
+
+    println!("{}", "\n✓ Badge revoked".green().bold());
+    print_badge(&badge);
+    println!();
+    Ok(())
+}
@@ -337,7 +337,7 @@ async fn register_all(
     skipped_duplicates: usize,
     json: bool,
 ) -> Result<RegistrationSummary> {
-    let client = reqwest::Client::builder()
+    let client = crate::http::builder()
         .timeout(std::time::Duration::from_secs(REGISTER_TIMEOUT_SECS))
         .build()?;
 
@@ -399,10 +399,7 @@ async fn register_one(
     let contract_id = entry.payload.contract_id.clone();
     let name = entry.payload.name.clone();
 
-    let response = client
-        .post(url)
-        .json(&entry.payload)
-        .send()
+    let response = crate::http::send_idempotent(client.post(url).json(&entry.payload))
         .await
         .context("Failed to reach registry API")?;
 
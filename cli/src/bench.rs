@@ -0,0 +1,504 @@
+//! bench.rs — `soroban-registry bench <wasm|contract>`
+//!
+//! Simulates every public ABI function with generated (or user-supplied)
+//! arguments via `soroban contract invoke --sim-only --cost`, reports CPU,
+//! memory, and fee estimates per function, optionally stores them on a
+//! registry version, and shows deltas against another version's baseline.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use contract_abi::{ContractFunction, SorobanType};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionCost {
+    pub function_name: String,
+    pub cpu_instructions: i64,
+    pub memory_bytes: i64,
+    pub fee_stroops: i64,
+    pub args: Vec<String>,
+}
+
+pub struct BenchOptions<'a> {
+    pub target: &'a str,
+    pub network: &'a str,
+    pub source: &'a str,
+    pub args_file: Option<&'a str>,
+    /// Registry contract (UUID or address) to store/compare results against.
+    pub contract: Option<&'a str>,
+    pub version: Option<&'a str>,
+    pub compare: Option<&'a str>,
+    pub json: bool,
+    /// Bearer token, required when storing results.
+    pub token: Option<&'a str>,
+}
+
+pub async fn run(api_url: &str, opts: BenchOptions<'_>) -> Result<()> {
+    let user_args = load_user_args(opts.args_file)?;
+
+    let (contract_address, abi_json) = if opts.target.ends_with(".wasm") {
+        let abi_json = crate::commands::load_abi_json(opts.target)?;
+        if !opts.json {
+            println!("{}", "Deploying WASM for simulation...".bright_black());
+        }
+        let address = soroban(&[
+            "contract",
+            "deploy",
+            "--wasm",
+            opts.target,
+            "--source",
+            opts.source,
+            "--network",
+            opts.network,
+        ])
+        .context("Failed to deploy WASM for benchmarking")?;
+        (address, abi_json)
+    } else {
+        let wasm = tempfile::Builder::new().suffix(".wasm").tempfile()?;
+        let wasm_path = wasm.path().to_string_lossy().into_owned();
+        soroban(&[
+            "contract",
+            "fetch",
+            "--id",
+            opts.target,
+            "--network",
+            opts.network,
+            "--out-file",
+            &wasm_path,
+        ])
+        .context("Failed to fetch contract WASM from the network")?;
+        (
+            opts.target.to_string(),
+            crate::commands::load_abi_json(&wasm_path)?,
+        )
+    };
+
+    let name = Path::new(opts.target)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("contract");
+    let abi = contract_abi::parse_json_spec(&abi_json, name)
+        .map_err(|e| anyhow::anyhow!("Failed to parse ABI: {}", e))?;
+    let source_address = soroban(&["keys", "address", opts.source]).ok();
+
+    let mut results = Vec::new();
+    let mut skipped = Vec::new();
+    for func in abi.public_functions() {
+        let args = match user_args.get(&func.name) {
+            Some(a) => a.clone(),
+            None => match generate_args(func, source_address.as_deref()) {
+                Some(a) => a,
+                None => {
+                    skipped.push(func.name.clone());
+                    continue;
+                }
+            },
+        };
+
+        let mut cmd = vec![
+            "contract",
+            "invoke",
+            "--id",
+            &contract_address,
+            "--source",
+            opts.source,
+            "--network",
+            opts.network,
+            "--sim-only",
+            "--cost",
+            "--",
+            &func.name,
+        ];
+        cmd.extend(args.iter().map(String::as_str));
+
+        match soroban_with_stderr(&cmd) {
+            Ok(output) => {
+                let (cpu, mem, fee) = parse_cost_output(&output);
+                results.push(FunctionCost {
+                    function_name: func.name.clone(),
+                    cpu_instructions: cpu,
+                    memory_bytes: mem,
+                    fee_stroops: fee,
+                    args,
+                });
+            }
+            Err(e) => {
+                log::debug!("simulation of {} failed: {}", func.name, e);
+                skipped.push(func.name.clone());
+            }
+        }
+    }
+
+    let baseline = match (opts.contract, opts.compare) {
+        (Some(contract), Some(version)) => fetch_baseline(api_url, contract, version).await?,
+        _ => HashMap::new(),
+    };
+
+    if opts.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "contract": contract_address,
+                "results": results,
+                "skipped": skipped,
+                "baseline_version": opts.compare,
+            }))?
+        );
+    } else {
+        print_table(&results, &baseline, opts.compare);
+        if !skipped.is_empty() {
+            println!(
+                "\n{} {} (supply arguments with --args)",
+                "Skipped:".yellow(),
+                skipped.join(", ")
+            );
+        }
+    }
+
+    if let (Some(contract), Some(version)) = (opts.contract, opts.version) {
+        let token = opts
+            .token
+            .context("Storing benchmarks needs --token (or SOROBAN_REGISTRY_TOKEN)")?;
+        store_results(api_url, token, contract, version, &results).await?;
+        if !opts.json {
+            println!(
+                "\n{} Stored {} benchmark(s) on version {}",
+                "✓".green(),
+                results.len(),
+                version
+            );
+        }
+    }
+
+    Ok(())
+}
+
+fn load_user_args(path: Option<&str>) -> Result<HashMap<String, Vec<String>>> {
+    let Some(path) = path else {
+        return Ok(HashMap::new());
+    };
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read arguments file {}", path))?;
+    serde_json::from_str(&raw)
+        .context("Arguments file must map function names to lists of CLI arguments")
+}
+
+/// Build `--param value` pairs for types we can fill with a sensible default.
+fn generate_args(func: &ContractFunction, source_address: Option<&str>) -> Option<Vec<String>> {
+    let mut args = Vec::new();
+    for param in &func.params {
+        let value = sample_value(&param.param_type, source_address)?;
+        args.push(format!("--{}", param.name));
+        args.push(value);
+    }
+    Some(args)
+}
+
+fn sample_value(ty: &SorobanType, source_address: Option<&str>) -> Option<String> {
+    Some(match ty {
+        SorobanType::Bool => "true".into(),
+        SorobanType::I32
+        | SorobanType::I64
+        | SorobanType::I128
+        | SorobanType::I256
+        | SorobanType::U32
+        | SorobanType::U64
+        | SorobanType::U128
+        | SorobanType::U256
+        | SorobanType::Timepoint
+        | SorobanType::Duration => "1".into(),
+        SorobanType::Symbol | SorobanType::String => "bench".into(),
+        SorobanType::Bytes => "00".into(),
+        SorobanType::BytesN { n } => "00".repeat(*n as usize),
+        SorobanType::Address => source_address?.to_string(),
+        SorobanType::Vec { .. } => "[]".into(),
+        SorobanType::Map { .. } => "{}".into(),
+        SorobanType::Option { .. } | SorobanType::Void => "null".into(),
+        _ => return None,
+    })
+}
+
+/// Extract `(cpu, memory, fee)` from `soroban ... --cost` output. Unknown
+/// figures are reported as zero rather than failing the whole run.
+fn parse_cost_output(output: &str) -> (i64, i64, i64) {
+    let mut cpu = 0;
+    let mut mem = 0;
+    let mut fee = 0;
+    for line in output.lines() {
+        let lower = line.to_lowercase();
+        let Some(value) = lower
+            .split(|c: char| c == ':' || c == '=')
+            .nth(1)
+            .and_then(|v| {
+                v.trim()
+                    .replace([',', '_'], "")
+                    .split_whitespace()
+                    .next()
+                    .map(str::to_string)
+            })
+            .and_then(|v| v.parse::<i64>().ok())
+        else {
+            continue;
+        };
+        if lower.contains("cpu") {
+            cpu = value;
+        } else if lower.contains("mem") {
+            mem = value;
+        } else if lower.contains("fee") {
+            fee = value;
+        }
+    }
+    (cpu, mem, fee)
+}
+
+fn soroban(args: &[&str]) -> Result<String> {
+    let out = Command::new("soroban")
+        .args(args)
+        .output()
+        .context("soroban CLI not found; install it to run benchmarks")?;
+    if !out.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&out.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+/// Cost figures are printed on stderr, so capture both streams.
+fn soroban_with_stderr(args: &[&str]) -> Result<String> {
+    let out = Command::new("soroban")
+        .args(args)
+        .output()
+        .context("soroban CLI not found; install it to run benchmarks")?;
+    if !out.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&out.stderr).trim());
+    }
+    Ok(format!(
+        "{}\n{}",
+        String::from_utf8_lossy(&out.stdout),
+        String::from_utf8_lossy(&out.stderr)
+    ))
+}
+
+async fn fetch_baseline(
+    api_url: &str,
+    contract: &str,
+    version: &str,
+) -> Result<HashMap<String, FunctionCost>> {
+    let url = format!(
+        "{}/api/contracts/{}/versions/{}/benchmarks",
+        api_url.trim_end_matches('/'),
+        contract,
+        version
+    );
+    let body: serde_json::Value = crate::http::client()
+        .get(&url)
+        .send()
+        .await?
+        .error_for_status()
+        .with_context(|| format!("No benchmarks recorded for version {}", version))?
+        .json()
+        .await?;
+
+    let mut map = HashMap::new();
+    for b in body["benchmarks"].as_array().cloned().unwrap_or_default() {
+        let name = b["function_name"].as_str().unwrap_or_default().to_string();
+        map.insert(
+            name.clone(),
+            FunctionCost {
+                function_name: name,
+                cpu_instructions: b["cpu_instructions"].as_i64().unwrap_or(0),
+                memory_bytes: b["memory_bytes"].as_i64().unwrap_or(0),
+                fee_stroops: b["fee_stroops"].as_i64().unwrap_or(0),
+                args: Vec::new(),
+            },
+        );
+    }
+    Ok(map)
+}
+
+async fn store_results(
+    api_url: &str,
+    token: &str,
+    contract: &str,
+    version: &str,
+    results: &[FunctionCost],
+) -> Result<()> {
+    let tool_version = soroban(&["--version"])
+        .ok()
+        .and_then(|v| v.lines().next().map(str::to_string));
+    let payload = json!({
+        "tool_version": tool_version,
+        "results": results.iter().map(|r| json!({
+            "function_name": r.function_name,
+            "cpu_instructions": r.cpu_instructions,
+            "memory_bytes": r.memory_bytes,
+            "fee_stroops": r.fee_stroops,
+            "args": r.args,
+        })).collect::<Vec<_>>(),
+    });
+
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/contracts/{}/versions/{}/benchmarks",
+            api_url.trim_end_matches('/'),
+            contract,
+            version
+        ))
+        .bearer_auth(token)
+        .json(&payload)
+        .send()
+        .await
+        .context("Failed to store benchmarks")?;
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Failed to store benchmarks: {}", body);
+    }
+    Ok(())
+}
+
+fn print_table(
+    results: &[FunctionCost],
+    baseline: &HashMap<String, FunctionCost>,
+    baseline_version: Option<&str>,
+) {
+    println!("\n{}", "Function Benchmarks".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    println!(
+        "{:<28} {:>16} {:>14} {:>12}",
+        "FUNCTION".bold(),
+        "CPU INSNS".bold(),
+        "MEM BYTES".bold(),
+        "FEE".bold()
+    );
+    for r in results {
+        println!(
+            "{:<28} {:>16} {:>14} {:>12}",
+            r.function_name, r.cpu_instructions, r.memory_bytes, r.fee_stroops
+        );
+        if let Some(base) = baseline.get(&r.function_name) {
+            println!(
+                "{:<28} {:>16} {:>14} {:>12}",
+                format!("  vs {}", baseline_version.unwrap_or("baseline")).bright_black(),
+                delta(base.cpu_instructions, r.cpu_instructions),
+                delta(base.memory_bytes, r.memory_bytes),
+                delta(base.fee_stroops, r.fee_stroops)
+            );
+        }
+    }
+}
+
+/// Warns about cost regressions the registry flagged on the contract's
+/// newest version against the one before it. Best effort: a registry that
+/// cannot answer produces no warning.
+pub async fn warn_on_regressions(api_url: &str, contract: &str) {
+    let base_url = api_url.trim_end_matches('/');
+    let client = crate::http::client();
+    let versions: Vec<serde_json::Value> = match client
+        .get(format!("{}/api/contracts/{}/versions", base_url, contract))
+        .send()
+        .await
+    {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        _ => return,
+    };
+    // Versions come newest first.
+    let Some(version) = versions.first().and_then(|v| v["version"].as_str()) else {
+        return;
+    };
+    let body: serde_json::Value = match client
+        .get(format!(
+            "{}/api/contracts/{}/versions/{}/regressions",
+            base_url, contract, version
+        ))
+        .send()
+        .await
+    {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        Ok(res) => {
+            log::debug!("regression check for {} returned {}", version, res.status());
+            return;
+        }
+        Err(err) => {
+            log::debug!("regression check for {} failed: {}", version, err);
+            return;
+        }
+    };
+    let regressions = body["regressions"].as_array().cloned().unwrap_or_default();
+    if regressions.is_empty() {
+        return;
+    }
+
+    println!(
+        "\n{} {} function cost regression(s) in {} vs {} (threshold {}%):",
+        "⚠".yellow().bold(),
+        regressions.len(),
+        version,
+        body["baseline_version"]
+            .as_str()
+            .unwrap_or("the previous version"),
+        body["threshold_percent"].as_f64().unwrap_or_default()
+    );
+    for regression in &regressions {
+        println!(
+            "  {} {:<24} {:<16} {} → {} ({})",
+            "•".yellow(),
+            regression["function_name"].as_str().unwrap_or("?"),
+            regression["metric"].as_str().unwrap_or("?"),
+            regression["baseline"].as_i64().unwrap_or(0),
+            regression["current"].as_i64().unwrap_or(0),
+            format!(
+                "+{:.1}%",
+                regression["increase_percent"].as_f64().unwrap_or(0.0)
+            )
+            .red()
+        );
+    }
+}
+
+fn delta(old: i64, new: i64) -> colored::ColoredString {
+    if old == 0 {
+        return "n/a".normal();
+    }
+    let pct = (new - old) as f64 / old as f64 * 100.0;
+    let text = format!("{:+.1}%", pct);
+    if pct > 0.5 {
+        text.red()
+    } else if pct < -0.5 {
+        text.green()
+    } else {
+        text.normal()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_soroban_cost_lines() {
+        let out = "Cpu Insns: 1,234,567\nMem Bytes: 45_678\nResource fee: 9012 stroops\n";
+        assert_eq!(parse_cost_output(out), (1_234_567, 45_678, 9012));
+    }
+
+    #[test]
+    fn ignores_unrelated_lines() {
+        let out = "result: \"ok\"\nCpu Insns: 10\n";
+        assert_eq!(parse_cost_output(out), (10, 0, 0));
+    }
+
+    #[test]
+    fn address_params_need_a_source_account() {
+        assert!(sample_value(&SorobanType::Address, None).is_none());
+        assert_eq!(
+            sample_value(&SorobanType::Address, Some("GABC")).as_deref(),
+            Some("GABC")
+        );
+        assert_eq!(
+            sample_value(&SorobanType::BytesN { n: 2 }, None).as_deref(),
+            Some("0000")
+        );
+    }
+}
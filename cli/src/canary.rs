@@ -0,0 +1,237 @@
+//! canary.rs — `soroban-registry migrate apply <old> <new> --canary`
+//!
+//! Before migrating the production contract, the new WASM is deployed to a
+//! fresh canary instance (or upgraded onto a designated staging contract)
+//! and a user-provided list of smoke-test invocations is run against it.
+//! The migration only proceeds when every smoke test passes.
+//!
+//! Smoke tests are a JSON array:
+//!
+//! ```json
+//! [
+//!   { "name": "decimals", "method": "decimals", "expect": 7 },
+//!   { "method": "balance", "args": { "id": "GABC..." } }
+//! ]
+//! ```
+//!
+//! `args` are named invocation arguments. When `expect` is set, the
+//! invocation's output must equal it; otherwise the call only has to succeed.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::Value;
+use std::process::Command;
+
+pub struct CanaryOptions<'a> {
+    pub wasm: &'a str,
+    /// JSON file with the smoke-test invocations
+    pub smoke_tests: &'a str,
+    /// Upgrade this staging contract instead of deploying a fresh canary
+    pub staging: Option<&'a str>,
+    pub rpc_url: &'a str,
+    pub network_passphrase: &'a str,
+    pub source: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+struct SmokeTest {
+    #[serde(default)]
+    name: Option<String>,
+    method: String,
+    #[serde(default)]
+    args: Option<Value>,
+    #[serde(default)]
+    expect: Option<Value>,
+}
+
+impl SmokeTest {
+    fn label(&self) -> &str {
+        self.name.as_deref().unwrap_or(&self.method)
+    }
+}
+
+/// Runs the canary and, if every smoke test passes, the regular migration.
+pub fn run(old_id: &str, new_id: &str, opts: CanaryOptions<'_>) -> Result<()> {
+    let tests = load_smoke_tests(opts.smoke_tests)?;
+
+    println!("\n{}", "Canary Migration".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    let canary_id = deploy_canary(&opts)?;
+    println!("  {}: {}", "Canary".bold(), canary_id);
+    println!("  {}: {} smoke test(s)\n", "Checks".bold(), tests.len());
+
+    let mut failed = 0usize;
+    for test in &tests {
+        match run_smoke_test(&opts, &canary_id, test) {
+            Ok(()) => println!("  {} {}", "✓".green(), test.label()),
+            Err(e) => {
+                failed += 1;
+                println!("  {} {}: {}", "✗".red(), test.label(), e);
+            }
+        }
+    }
+    println!("{}", "=".repeat(80).cyan());
+
+    if failed > 0 {
+        bail!(
+            "Canary failed {} of {} smoke test(s); production contract {} was not migrated",
+            failed,
+            tests.len(),
+            old_id
+        );
+    }
+    println!(
+        "{}\n",
+        "✓ Canary passed, migrating production contract"
+            .green()
+            .bold()
+    );
+    crate::migration::apply(old_id, new_id)
+}
+
+fn load_smoke_tests(path: &str) -> Result<Vec<SmokeTest>> {
+    let data = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read smoke tests from {}", path))?;
+    parse_smoke_tests(&data).with_context(|| format!("Invalid smoke tests in {}", path))
+}
+
+fn parse_smoke_tests(data: &str) -> Result<Vec<SmokeTest>> {
+    let tests: Vec<SmokeTest> = serde_json::from_str(data)?;
+    if tests.is_empty() {
+        bail!("at least one smoke test is required");
+    }
+    if let Some(test) = tests
+        .iter()
+        .find(|t| crate::replay::invocation_args(t.args.as_ref()).is_none())
+    {
+        bail!(
+            "'{}': args must be an object of named arguments",
+            test.label()
+        );
+    }
+    Ok(tests)
+}
+
+/// Contract the smoke tests run against: a fresh deployment of the WASM, or
+/// the staging contract after upgrading it to the WASM.
+fn deploy_canary(opts: &CanaryOptions<'_>) -> Result<String> {
+    let network = [
+        "--source",
+        opts.source,
+        "--rpc-url",
+        opts.rpc_url,
+        "--network-passphrase",
+        opts.network_passphrase,
+    ];
+
+    let Some(staging) = opts.staging else {
+        println!("{}", "Deploying canary instance...".bright_black());
+        let mut args = vec!["contract", "deploy", "--wasm", opts.wasm];
+        args.extend(network);
+        return soroban(&args).context("Failed to deploy canary instance");
+    };
+
+    println!(
+        "{}",
+        format!("Upgrading staging contract {}...", staging).bright_black()
+    );
+    let mut install = vec!["contract", "install", "--wasm", opts.wasm];
+    install.extend(network);
+    let wasm_hash = soroban(&install).context("Failed to install WASM")?;
+
+    let mut upgrade = vec!["contract", "invoke", "--id", staging];
+    upgrade.extend(network);
+    upgrade.extend(["--", "upgrade", "--new_wasm_hash", wasm_hash.as_str()]);
+    soroban(&upgrade).context("Failed to upgrade staging contract")?;
+    Ok(staging.to_string())
+}
+
+fn run_smoke_test(opts: &CanaryOptions<'_>, contract_id: &str, test: &SmokeTest) -> Result<()> {
+    let call_args = crate::replay::invocation_args(test.args.as_ref()).unwrap_or_default();
+    let mut args = vec![
+        "contract",
+        "invoke",
+        "--id",
+        contract_id,
+        "--source",
+        opts.source,
+        "--rpc-url",
+        opts.rpc_url,
+        "--network-passphrase",
+        opts.network_passphrase,
+        "--",
+        &test.method,
+    ];
+    args.extend(call_args.iter().map(String::as_str));
+
+    let output = soroban(&args)?;
+    match &test.expect {
+        Some(expected) if !output_matches(&output, expected) => bail!(
+            "expected {}, got {}",
+            value_text(expected),
+            if output.is_empty() {
+                "no output"
+            } else {
+                &output
+            }
+        ),
+        _ => Ok(()),
+    }
+}
+
+/// `soroban contract invoke` prints results as JSON; numbers may come back
+/// quoted, so `"7"` and `7` both match an expected `7`.
+fn output_matches(output: &str, expected: &Value) -> bool {
+    let actual = serde_json::from_str(output).unwrap_or_else(|_| Value::String(output.into()));
+    actual == *expected || value_text(&actual) == value_text(expected)
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn soroban(args: &[&str]) -> Result<String> {
+    let out = Command::new("soroban")
+        .args(args)
+        .output()
+        .context("soroban CLI not found; install it to run a canary migration")?;
+    if !out.status.success() {
+        bail!("{}", String::from_utf8_lossy(&out.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_smoke_tests() {
+        let tests = parse_smoke_tests(
+            r#"[{"name": "decimals", "method": "decimals", "expect": 7},
+                {"method": "balance", "args": {"id": "GABC"}}]"#,
+        )
+        .unwrap();
+        assert_eq!(tests.len(), 2);
+        assert_eq!(tests[0].label(), "decimals");
+        assert_eq!(tests[1].label(), "balance");
+
+        assert!(parse_smoke_tests("[]").is_err());
+        assert!(parse_smoke_tests(r#"[{"method": "balance", "args": ["GABC"]}]"#).is_err());
+    }
+
+    #[test]
+    fn compares_output_to_expectation() {
+        assert!(output_matches("7", &json!(7)));
+        assert!(output_matches("\"7\"", &json!(7)));
+        assert!(output_matches("\"hello\"", &json!("hello")));
+        assert!(output_matches("hello", &json!("hello")));
+        assert!(output_matches("{\"a\":1}", &json!({ "a": 1 })));
+        assert!(!output_matches("8", &json!(7)));
+    }
+}
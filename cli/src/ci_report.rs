@@ -0,0 +1,367 @@
+//! ci_report.rs — machine-readable output for `verify --report junit|github`
+//!
+//! Runs the registry checks a pipeline cares about (package signature,
+//! registry verification, cargo-audit, clippy, ABI snapshot) and renders the
+//! outcomes as JUnit XML or GitHub Actions workflow commands, so CI systems
+//! can surface failures natively instead of scraping logs.
+
+use anyhow::Result;
+use std::fmt;
+use std::path::Path;
+use std::process::Command;
+use std::str::FromStr;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Junit,
+    Github,
+}
+
+impl FromStr for ReportFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "junit" => Ok(ReportFormat::Junit),
+            "github" => Ok(ReportFormat::Github),
+            _ => anyhow::bail!(
+                "Invalid report format: {}. Allowed values: junit, github",
+                s
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckStatus {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct CheckOutcome {
+    pub suite: &'static str,
+    pub name: String,
+    pub status: CheckStatus,
+    pub duration_ms: u128,
+}
+
+impl CheckOutcome {
+    fn failed(&self) -> bool {
+        matches!(self.status, CheckStatus::Failed(_))
+    }
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckStatus::Passed => write!(f, "passed"),
+            CheckStatus::Failed(msg) => write!(f, "failed: {}", msg),
+            CheckStatus::Skipped(msg) => write!(f, "skipped: {}", msg),
+        }
+    }
+}
+
+pub struct ReportInput<'a> {
+    pub api_url: &'a str,
+    pub package: &'a str,
+    pub contract_id: &'a str,
+    pub version: Option<&'a str>,
+    pub signature: Option<&'a str>,
+    pub contract_path: &'a str,
+}
+
+/// Run every check, render the report, and fail if any check failed.
+pub async fn run(input: ReportInput<'_>, format: ReportFormat, output: Option<&str>) -> Result<()> {
+    let outcomes = collect(&input).await;
+    let rendered = match format {
+        ReportFormat::Junit => render_junit(&outcomes),
+        ReportFormat::Github => render_github(&outcomes),
+    };
+
+    match output {
+        Some(path) => std::fs::write(path, &rendered)?,
+        None => print!("{}", rendered),
+    }
+
+    let failures = outcomes.iter().filter(|o| o.failed()).count();
+    if failures > 0 {
+        anyhow::bail!("{} check(s) failed", failures);
+    }
+    Ok(())
+}
+
+async fn collect(input: &ReportInput<'_>) -> Vec<CheckOutcome> {
+    let mut outcomes = Vec::new();
+
+    let started = Instant::now();
+    let status = match crate::package_signing::verify_package(
+        input.api_url,
+        input.package,
+        input.contract_id,
+        input.version,
+        input.signature,
+    )
+    .await
+    {
+        Ok(()) => CheckStatus::Passed,
+        Err(e) => CheckStatus::Failed(e.to_string()),
+    };
+    outcomes.push(outcome(
+        "verification",
+        "package signature",
+        status,
+        started,
+    ));
+
+    let started = Instant::now();
+    let status = registry_status(input.api_url, input.contract_id).await;
+    outcomes.push(outcome(
+        "verification",
+        "registry verification",
+        status,
+        started,
+    ));
+
+    let started = Instant::now();
+    let status = run_tool(input.contract_path, "cargo", &["audit"], "cargo-audit");
+    outcomes.push(outcome("audit", "cargo audit", status, started));
+
+    let started = Instant::now();
+    let status = run_tool(
+        input.contract_path,
+        "cargo",
+        &["clippy", "--quiet", "--", "-D", "warnings"],
+        "cargo clippy",
+    );
+    outcomes.push(outcome("lint", "clippy", status, started));
+
+    let started = Instant::now();
+    let status = if Path::new(input.contract_path)
+        .join(crate::abi_snapshot::ABI_SNAPSHOT_FILE)
+        .exists()
+    {
+        match crate::abi_snapshot::check(input.contract_path, None, None) {
+            Ok(()) => CheckStatus::Passed,
+            Err(e) => CheckStatus::Failed(e.to_string()),
+        }
+    } else {
+        CheckStatus::Skipped("no ABI snapshot committed".into())
+    };
+    outcomes.push(outcome("abi", "abi snapshot", status, started));
+
+    outcomes
+}
+
+fn outcome(suite: &'static str, name: &str, status: CheckStatus, started: Instant) -> CheckOutcome {
+    CheckOutcome {
+        suite,
+        name: name.to_string(),
+        status,
+        duration_ms: started.elapsed().as_millis(),
+    }
+}
+
+async fn registry_status(api_url: &str, contract_id: &str) -> CheckStatus {
+    let url = format!(
+        "{}/api/contracts/{}",
+        api_url.trim_end_matches('/'),
+        contract_id
+    );
+    let response = match crate::http::client().get(&url).send().await {
+        Ok(r) => r,
+        Err(e) => return CheckStatus::Failed(format!("registry unreachable: {}", e)),
+    };
+    if !response.status().is_success() {
+        return CheckStatus::Failed(format!("registry returned {}", response.status()));
+    }
+    match response.json::<serde_json::Value>().await {
+        Ok(body) if body["contract"]["is_verified"].as_bool() == Some(true) => CheckStatus::Passed,
+        Ok(_) => CheckStatus::Failed("contract is not verified in the registry".into()),
+        Err(e) => CheckStatus::Failed(format!("invalid registry response: {}", e)),
+    }
+}
+
+fn run_tool(dir: &str, program: &str, args: &[&str], label: &str) -> CheckStatus {
+    match Command::new(program).args(args).current_dir(dir).output() {
+        Ok(out) if out.status.success() => CheckStatus::Passed,
+        Ok(out) => {
+            let stderr = String::from_utf8_lossy(&out.stderr);
+            if stderr.contains("no such command") {
+                CheckStatus::Skipped(format!("{} not installed", label))
+            } else {
+                CheckStatus::Failed(stderr.trim().to_string())
+            }
+        }
+        Err(_) => CheckStatus::Skipped(format!("{} not installed", label)),
+    }
+}
+
+pub fn render_junit(outcomes: &[CheckOutcome]) -> String {
+    let mut suites: Vec<&'static str> = Vec::new();
+    for o in outcomes {
+        if !suites.contains(&o.suite) {
+            suites.push(o.suite);
+        }
+    }
+
+    let total_failures = outcomes.iter().filter(|o| o.failed()).count();
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xml.push_str(&format!(
+        "<testsuites name=\"soroban-registry\" tests=\"{}\" failures=\"{}\">\n",
+        outcomes.len(),
+        total_failures
+    ));
+
+    for suite in suites {
+        let cases: Vec<&CheckOutcome> = outcomes.iter().filter(|o| o.suite == suite).collect();
+        let failures = cases.iter().filter(|o| o.failed()).count();
+        let skipped = cases
+            .iter()
+            .filter(|o| matches!(o.status, CheckStatus::Skipped(_)))
+            .count();
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\">\n",
+            xml_escape(suite),
+            cases.len(),
+            failures,
+            skipped
+        ));
+        for case in cases {
+            let time = case.duration_ms as f64 / 1000.0;
+            match &case.status {
+                CheckStatus::Passed => xml.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"/>\n",
+                    xml_escape(suite),
+                    xml_escape(&case.name),
+                    time
+                )),
+                CheckStatus::Failed(msg) => xml.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\">\n      <failure message=\"{}\">{}</failure>\n    </testcase>\n",
+                    xml_escape(suite),
+                    xml_escape(&case.name),
+                    time,
+                    xml_escape(first_line(msg)),
+                    xml_escape(msg)
+                )),
+                CheckStatus::Skipped(msg) => xml.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\">\n      <skipped message=\"{}\"/>\n    </testcase>\n",
+                    xml_escape(suite),
+                    xml_escape(&case.name),
+                    time,
+                    xml_escape(msg)
+                )),
+            }
+        }
+        xml.push_str("  </testsuite>\n");
+    }
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// GitHub Actions workflow commands: failures become `::error` annotations,
+/// skips become `::warning`, passes are reported as `::notice`.
+pub fn render_github(outcomes: &[CheckOutcome]) -> String {
+    let mut out = String::new();
+    for o in outcomes {
+        let title = format!("{}: {}", o.suite, o.name);
+        let (level, message) = match &o.status {
+            CheckStatus::Passed => ("notice", "passed".to_string()),
+            CheckStatus::Failed(msg) => ("error", msg.clone()),
+            CheckStatus::Skipped(msg) => ("warning", format!("skipped: {}", msg)),
+        };
+        out.push_str(&format!(
+            "::{} title={}::{}\n",
+            level,
+            gh_escape_property(&title),
+            gh_escape_data(&message)
+        ));
+    }
+    out
+}
+
+fn first_line(s: &str) -> &str {
+    s.lines().next().unwrap_or("")
+}
+
+fn xml_escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn gh_escape_data(s: &str) -> String {
+    s.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn gh_escape_property(s: &str) -> String {
+    gh_escape_data(s).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Vec<CheckOutcome> {
+        vec![
+            CheckOutcome {
+                suite: "lint",
+                name: "clippy".into(),
+                status: CheckStatus::Passed,
+                duration_ms: 1200,
+            },
+            CheckOutcome {
+                suite: "abi",
+                name: "abi snapshot".into(),
+                status: CheckStatus::Failed("fn transfer <changed>\nsecond line".into()),
+                duration_ms: 5,
+            },
+            CheckOutcome {
+                suite: "audit",
+                name: "cargo audit".into(),
+                status: CheckStatus::Skipped("cargo-audit not installed".into()),
+                duration_ms: 0,
+            },
+        ]
+    }
+
+    #[test]
+    fn junit_report_counts_and_escapes() {
+        let xml = render_junit(&sample());
+        assert!(xml.contains("tests=\"3\" failures=\"1\""));
+        assert!(xml.contains("<failure message=\"fn transfer &lt;changed&gt;\">"));
+        assert!(xml.contains("<skipped message=\"cargo-audit not installed\"/>"));
+        assert!(xml.contains("time=\"1.200\""));
+    }
+
+    #[test]
+    fn github_report_uses_annotation_levels() {
+        let out = render_github(&sample());
+        let lines: Vec<&str> = out.lines().collect();
+        assert_eq!(lines[0], "::notice title=lint%3A clippy::passed");
+        assert_eq!(
+            lines[1],
+            "::error title=abi%3A abi snapshot::fn transfer <changed>%0Asecond line"
+        );
+        assert!(lines[2].starts_with("::warning "));
+    }
+
+    #[test]
+    fn report_format_parses_case_insensitively() {
+        assert_eq!(
+            "JUnit".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Junit
+        );
+        assert_eq!(
+            "github".parse::<ReportFormat>().unwrap(),
+            ReportFormat::Github
+        );
+        assert!("xml".parse::<ReportFormat>().is_err());
+    }
+}
@@ -103,6 +103,7 @@ pub async fn run_pipeline(
         None,
         false,
         0.0,
+        crate::abi_snapshot::DEFAULT_MIN_DOCS_COVERAGE,
         false,
     ).await?;
 
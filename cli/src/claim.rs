@@ -0,0 +1,215 @@
+//! claim.rs — take ownership of a contract the registry indexed on its own
+//!
+//! Contracts picked up by the on-chain crawler (or registered by someone
+//! other than their deployer) can be claimed by the deploying account:
+//!
+//! 1. `POST /api/contracts/:id/claim/challenge` returns a one-time message;
+//! 2. the message is signed locally with the deployer's secret key;
+//! 3. `POST /api/contracts/:id/claim` submits the signature.
+//!
+//! The secret key never leaves the machine.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use colored::Colorize;
+use ed25519_dalek::{Signer, SigningKey};
+use serde_json::{json, Value};
+
+/// Strkey version bytes (`S...` secret seeds and `G...` account ids).
+const VERSION_SEED: u8 = 18 << 3;
+pub(crate) const VERSION_ACCOUNT: u8 = 6 << 3;
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+pub async fn run(api_url: &str, contract_id: &str, secret_key: &str) -> Result<()> {
+    let signing_key = decode_secret_key(secret_key)?;
+    let address = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+
+    println!("\n{}", "Claiming contract...".bold().cyan());
+    println!("  {}: {}", "Contract".bold(), contract_id.bright_black());
+    println!("  {}: {}", "Signer".bold(), address.bright_magenta());
+
+    let client = crate::http::client();
+    let challenge = post_json(
+        &client,
+        &format!("{}/api/contracts/{}/claim/challenge", api_url, contract_id),
+        &json!({}),
+    )
+    .await
+    .context("Failed to request a claim challenge")?;
+
+    let deployer = challenge["deployer_address"].as_str().unwrap_or_default();
+    if deployer != address {
+        bail!(
+            "This contract was deployed by {}, but the secret key belongs to {}",
+            deployer,
+            address
+        );
+    }
+    let message = challenge["message"]
+        .as_str()
+        .context("Challenge response is missing the message to sign")?;
+    let signature = hex::encode(signing_key.sign(message.as_bytes()).to_bytes());
+
+    let contract = post_json(
+        &client,
+        &format!("{}/api/contracts/{}/claim", api_url, contract_id),
+        &json!({ "signature": signature }),
+    )
+    .await
+    .context("Failed to submit claim")?;
+
+    println!("{}", "\n✓ Contract claimed!".green().bold());
+    println!(
+        "  {}: {}",
+        "Name".bold(),
+        contract["name"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Network".bold(),
+        contract["network"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Owner".bold(),
+        contract["publisher_id"].as_str().unwrap_or("?")
+    );
+    println!(
+        "\n  {} Metadata for this contract can now be edited.\n",
+        "→".bright_black()
+    );
+
+    Ok(())
+}
+
+async fn post_json(client: &reqwest::Client, url: &str, body: &Value) -> Result<Value> {
+    let response = client
+        .post(url)
+        .json(body)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+/// Accepts a Stellar secret seed (`S...`) or a base64-encoded 32-byte
+/// ed25519 seed as produced by `soroban-registry keys generate`.
+pub(crate) fn decode_secret_key(key: &str) -> Result<SigningKey> {
+    let key = key.trim();
+    let seed = if key.starts_with('S') && key.len() == 56 {
+        decode_strkey(VERSION_SEED, key).context("Invalid Stellar secret key")?
+    } else {
+        BASE64
+            .decode(key)
+            .context("Secret key must be a Stellar secret (S...) or base64 ed25519 seed")?
+    };
+    let seed: [u8; 32] = seed
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Secret key must be 32 bytes"))?;
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+fn crc16_xmodem(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in data {
+        crc ^= (*byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+pub(crate) fn encode_strkey(version: u8, payload: &[u8; 32]) -> String {
+    let mut data = Vec::with_capacity(35);
+    data.push(version);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&crc16_xmodem(&data).to_le_bytes());
+
+    let mut out = String::with_capacity(56);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for byte in data {
+        buffer = (buffer << 8) | byte as u32;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            out.push(BASE32_ALPHABET[((buffer >> bits) & 31) as usize] as char);
+        }
+    }
+    if bits > 0 {
+        out.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 31) as usize] as char);
+    }
+    out
+}
+
+fn decode_strkey(version: u8, key: &str) -> Result<Vec<u8>> {
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    let mut data = Vec::with_capacity(35);
+    for c in key.bytes() {
+        let value = BASE32_ALPHABET
+            .iter()
+            .position(|&a| a == c)
+            .with_context(|| format!("Invalid character {:?}", c as char))?;
+        buffer = (buffer << 5) | value as u32;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            data.push((buffer >> bits) as u8);
+        }
+    }
+    if data.len() != 35 || data[0] != version {
+        bail!("Unexpected key type or length");
+    }
+    let (body, checksum) = data.split_at(33);
+    if crc16_xmodem(body).to_le_bytes() != checksum {
+        bail!("Checksum mismatch");
+    }
+    Ok(body[1..].to_vec())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_known_account_id() {
+        assert_eq!(
+            encode_strkey(VERSION_ACCOUNT, &[0u8; 32]),
+            "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"
+        );
+    }
+
+    #[test]
+    fn secret_seed_round_trips() {
+        let seed = [42u8; 32];
+        let secret = encode_strkey(VERSION_SEED, &seed);
+        assert!(secret.starts_with('S'));
+        assert_eq!(decode_secret_key(&secret).unwrap().to_bytes(), seed);
+        assert_eq!(
+            decode_secret_key(&BASE64.encode(seed)).unwrap().to_bytes(),
+            seed
+        );
+    }
+
+    #[test]
+    fn rejects_corrupted_or_wrong_type_keys() {
+        let secret = encode_strkey(VERSION_SEED, &[42u8; 32]);
+        let mut corrupted = secret.into_bytes();
+        corrupted[10] = if corrupted[10] == b'A' { b'B' } else { b'A' };
+        assert!(decode_secret_key(std::str::from_utf8(&corrupted).unwrap()).is_err());
+
+        let account = encode_strkey(VERSION_ACCOUNT, &[42u8; 32]);
+        assert!(decode_strkey(VERSION_SEED, &account).is_err());
+    }
+}
@@ -20,7 +20,7 @@ pub enum Network {
 
 use std::path::Path;
 
-use crate::patch::{PatchManager, Severity};
+use crate::patch::{CheckStatus, PatchManager, Severity};
 use crate::profiler;
 use crate::test_framework;
 
@@ -117,6 +117,16 @@ pub fn profile(
     Ok(())
 }
 
+/// Values accepted by `search --sort`; results come highest first.
+pub const SEARCH_SORTS: [&str; 6] = [
+    "created_at",
+    "updated_at",
+    "popularity",
+    "deployments",
+    "quality",
+    "tvl",
+];
+
 #[allow(clippy::too_many_arguments)]
 pub async fn search(
     api_url: &str,
@@ -125,17 +135,24 @@ pub async fn search(
     verified_only: bool,
     networks: Vec<String>,
     category: Option<&str>,
+    deployed_on: Option<&str>,
+    badge: Option<&str>,
+    oracle_freshness: Option<&str>,
+    sdk_version: Option<&str>,
+    protocol: Option<u32>,
+    sort: Option<&str>,
     limit: usize,
     offset: usize,
     json: bool,
 ) -> Result<()> {
     let t0 = std::time::Instant::now();
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let mut params: Vec<(&str, String)> = vec![
         ("query", query.to_string()),
         ("limit", limit.to_string()),
         ("offset", offset.to_string()),
+        ("include", "publisher".to_string()),
     ];
 
     if !networks.is_empty() {
@@ -152,6 +169,30 @@ pub async fn search(
         params.push(("category", cat.to_string()));
     }
 
+    if let Some(other) = deployed_on {
+        params.push(("deployed_on", other.to_string()));
+    }
+
+    if let Some(kind) = badge {
+        params.push(("badge", kind.to_string()));
+    }
+
+    if let Some(freshness) = oracle_freshness {
+        params.push(("oracle_freshness", freshness.to_string()));
+    }
+
+    if let Some(version) = sdk_version {
+        params.push(("sdk_version", version.to_string()));
+    }
+
+    if let Some(protocol) = protocol {
+        params.push(("protocol_version", protocol.to_string()));
+    }
+
+    if let Some(field) = sort {
+        params.push(("sort_by", field.to_string()));
+    }
+
     let response = client
         .get(format!("{}/api/contracts", api_url))
         .query(&params)
@@ -173,6 +214,9 @@ pub async fn search(
                     "is_verified": crate::conversions::as_bool(&c["is_verified"], "is_verified")?,
                     "network":     crate::conversions::as_str(&c["network"], "network")?,
                     "category":    c["category"].as_str().unwrap_or(""),
+                    "badges":      badge_list(c),
+                    "publisher":   publisher_label(&c["publisher"]),
+                    "quality_score": c["quality_score"].as_i64(),
                     "links": { "detail": format!("{}/contracts/{}", api_url, contract_id) },
                 }))
             })
@@ -194,6 +238,24 @@ pub async fn search(
     if let Some(cat) = category {
         active_filters.push(format!("category: {}", cat));
     }
+    if let Some(other) = deployed_on {
+        active_filters.push(format!("also on: {}", other));
+    }
+    if let Some(kind) = badge {
+        active_filters.push(format!("badge: {}", kind));
+    }
+    if let Some(freshness) = oracle_freshness {
+        active_filters.push(format!("oracle: {}", freshness));
+    }
+    if let Some(version) = sdk_version {
+        active_filters.push(format!("sdk: {}", version));
+    }
+    if let Some(protocol) = protocol {
+        active_filters.push(format!("protocol: {}", protocol));
+    }
+    if let Some(field) = sort {
+        active_filters.push(format!("sorted by: {}", field));
+    }
     if verified_only {
         active_filters.push("verified only".to_string());
     }
@@ -212,6 +274,15 @@ pub async fn search(
         if category.is_some() {
             println!("  • Remove the --category filter to see all contract types");
         }
+        if deployed_on.is_some() {
+            println!("  • Remove --deployed-on to include contracts on a single network");
+        }
+        if badge.is_some() {
+            println!("  • Remove --badge to include contracts without that badge");
+        }
+        if sdk_version.is_some() || protocol.is_some() {
+            println!("  • Remove --sdk-version / --protocol to include contracts built with other versions");
+        }
         if !networks.is_empty() {
             println!("  • Try adding more networks: --network mainnet,testnet,futurenet");
         }
@@ -246,6 +317,19 @@ pub async fn search(
         .max("Category".len());
     // "○ Unverified" is the longest possible verified cell value (12 visible chars).
     let ver_w = "○ Unverified".chars().count();
+    let publisher_w = items
+        .iter()
+        .filter_map(|c| publisher_label(&c["publisher"]))
+        .map(|s| s.chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Publisher".len());
+    let badge_w = items
+        .iter()
+        .map(|c| badge_list(c).join(", ").chars().count())
+        .max()
+        .unwrap_or(0)
+        .max("Badges".len());
     let link_prefix = format!("{}/contracts/", api_url);
     let link_w = items
         .iter()
@@ -278,13 +362,39 @@ pub async fn search(
         } else {
             "○ Unverified".yellow().to_string()
         };
+        let badges = badge_list(contract);
+        let badge_cell = if badges.is_empty() {
+            "—".bright_black().to_string()
+        } else {
+            badges.join(", ").bright_magenta().to_string()
+        };
+        let publisher_cell = match publisher_label(&contract["publisher"]) {
+            Some(label) => label.to_string(),
+            None => "—".bright_black().to_string(),
+        };
         let link_cell = link.bright_black().to_string();
 
-        rows.push(vec![name_cell, net_cell, cat_cell, ver_cell, link_cell]);
-    }
-
-    let col_widths = [name_w, net_w, cat_w, ver_w, link_w];
-    let headers = ["Name", "Network", "Category", "Verified", "Links"];
+        rows.push(vec![
+            name_cell,
+            net_cell,
+            cat_cell,
+            publisher_cell,
+            ver_cell,
+            badge_cell,
+            link_cell,
+        ]);
+    }
+
+    let col_widths = [name_w, net_w, cat_w, publisher_w, ver_w, badge_w, link_w];
+    let headers = [
+        "Name",
+        "Network",
+        "Category",
+        "Publisher",
+        "Verified",
+        "Badges",
+        "Links",
+    ];
     print!(
         "{}",
         crate::table_format::render_table(&headers, &col_widths, &rows)
@@ -302,6 +412,45 @@ pub async fn search(
     Ok(())
 }
 
+/// Badge kinds on a contract record from the registry, e.g. `audited-by`.
+fn badge_list(contract: &serde_json::Value) -> Vec<&str> {
+    contract["badges"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|b| b.as_str())
+        .collect()
+}
+
+/// Who maintains a contract, from the publisher profile on its record,
+/// e.g. `Ada (she/her) GABC…`.
+fn publisher_line(publisher: &serde_json::Value) -> Option<String> {
+    let address = publisher["stellar_address"].as_str()?;
+    let mut line = String::new();
+    if let Some(name) = publisher["name"].as_str() {
+        line.push_str(&format!("{} ", name.bold()));
+    }
+    if let Some(pronouns) = publisher["pronouns"].as_str() {
+        line.push_str(&format!("({}) ", pronouns));
+    }
+    line.push_str(&address.bright_black().to_string());
+    Some(line)
+}
+
+/// A publisher's name for table cells, or a shortened address when they
+/// have not set one.
+fn publisher_label(publisher: &serde_json::Value) -> Option<String> {
+    if let Some(name) = publisher["name"].as_str() {
+        return Some(name.to_string());
+    }
+    let address = publisher["stellar_address"].as_str()?;
+    let tail = address.len().saturating_sub(4);
+    match (address.get(..4), address.get(tail..)) {
+        (Some(head), Some(tail)) if address.len() > 8 => Some(format!("{}…{}", head, tail)),
+        _ => Some(address.to_string()),
+    }
+}
+
 /// Analyze two contract versions or schema files for breaking changes.
 pub async fn upgrade_analyze(
     api_url: &str,
@@ -341,7 +490,7 @@ pub async fn upgrade_analyze(
     }
 
     // Otherwise try to fetch versions from the API (assumes endpoint exists)
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contract_versions/{}", api_url, old_id);
     let old_res = client
         .get(&url)
@@ -483,6 +632,7 @@ pub async fn publish(
     test_command: Option<&str>,
     require_coverage: bool,
     coverage_threshold: f64,
+    min_docs_coverage: f64,
     skip_tests: bool,
 ) -> Result<()> {
     if !skip_tests {
@@ -496,7 +646,11 @@ pub async fn publish(
         .await?;
     }
 
-    let client = reqwest::Client::new();
+    crate::abi_snapshot::check_before_publish(contract_path)?;
+    crate::abi_snapshot::warn_on_docs_coverage(contract_path, min_docs_coverage);
+    crate::policy::check_before_publish(api_url, contract_path).await?;
+
+    let client = crate::http::client();
     let url = format!("{}/api/contracts", api_url);
 
     let mut payload = json!({
@@ -515,17 +669,23 @@ pub async fn publish(
 
     println!("\n{}", "Publishing contract...".bold().cyan());
 
-    let response = client
-        .post(&url)
-        .json(&payload)
-        .send()
+    let response = crate::http::send_idempotent(client.post(&url).json(&payload))
         .await
         .context("Failed to publish contract")?;
 
     if !response.status().is_success() {
+        let status = response.status();
         let error_text = response.text().await?;
+        if let Some(message) = crate::http::size_error(status, &error_text) {
+            anyhow::bail!("Failed to publish: {}", message);
+        }
         anyhow::bail!("Failed to publish: {}", error_text);
     }
+    if response.status() == reqwest::StatusCode::ACCEPTED {
+        let held: serde_json::Value = response.json().await?;
+        crate::multisig::print_approval_required(&held);
+        return Ok(());
+    }
 
     let contract: serde_json::Value = response.json().await?;
 
@@ -545,6 +705,31 @@ pub async fn publish(
         "Network".bold(),
         crate::conversions::as_str(&contract["network"], "network")?.bright_blue()
     );
+    if let Some(link) = contract["linked_from"].as_object() {
+        let inherited: Vec<&str> = link["inherited"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(serde_json::Value::as_str)
+            .collect();
+        println!(
+            "{}: same WASM as {}{}{}",
+            "Linked".bold(),
+            link["contract_address"].as_str().unwrap_or("?"),
+            link["version"]
+                .as_str()
+                .map(|version| format!(" v{}", version))
+                .unwrap_or_default(),
+            if inherited.is_empty() {
+                String::new()
+            } else {
+                format!(" (inherited {})", inherited.join(", "))
+            }
+        );
+    }
+    if let Some(uuid) = contract["id"].as_str() {
+        crate::bench::warn_on_regressions(api_url, uuid).await;
+    }
     println!();
 
     Ok(())
@@ -806,9 +991,9 @@ mod contract_test_helpers_tests {
 }
 
 pub async fn list(api_url: &str, limit: usize, network: Network, json: bool) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
-        "{}/api/contracts?page_size={}&network={}",
+        "{}/api/contracts?page_size={}&network={}&include=publisher,latest_version",
         api_url, limit, network
     );
 
@@ -830,6 +1015,8 @@ pub async fn list(api_url: &str, limit: usize, network: Network, json: bool) ->
                     "name":        crate::conversions::as_str(&c["name"], "name")?,
                     "is_verified": crate::conversions::as_bool(&c["is_verified"], "is_verified")?,
                     "network":     crate::conversions::as_str(&c["network"], "network")?,
+                    "publisher":   publisher_label(&c["publisher"]),
+                    "latest_version": c["latest_version"]["version"].as_str(),
                 }))
             })
             .collect::<Result<_, _>>()?;
@@ -864,11 +1051,21 @@ pub async fn list(api_url: &str, limit: usize, network: Network, json: bool) ->
                 "".normal()
             }
         );
-        println!(
+        let mut details = format!(
             "   {} | {}",
             contract_id.bright_black(),
             network.bright_blue()
         );
+        if let Some(version) = contract["latest_version"]["version"].as_str() {
+            details.push_str(&format!(" | v{}", version));
+            if contract["latest_version"]["yanked"].as_bool() == Some(true) {
+                details.push_str(&format!(" {}", "(yanked)".red()));
+            }
+        }
+        println!("{}", details);
+        if let Some(publisher) = publisher_line(&contract["publisher"]) {
+            println!("   by {}", publisher);
+        }
     }
 
     println!("\n{}", "=".repeat(80).cyan());
@@ -888,8 +1085,31 @@ fn extract_migration_id(migration: &serde_json::Value) -> Result<String> {
 
     Ok(migration_id.to_string())
 }
+/// Stream a line of migration output to the registry so it can be followed
+/// live. Failures are logged, not fatal: the full log is also sent with the
+/// final status.
+async fn append_migration_log(
+    client: &reqwest::Client,
+    api_url: &str,
+    token: &str,
+    migration_id: &str,
+    line: &str,
+) {
+    let result = client
+        .post(format!("{}/api/migrations/{}/logs", api_url, migration_id))
+        .bearer_auth(token)
+        .json(&json!({ "content": format!("{}\n", line) }))
+        .send()
+        .await;
+    match result {
+        Ok(resp) if resp.status().is_success() => {}
+        Ok(resp) => log::warn!("Failed to stream migration log: HTTP {}", resp.status()),
+        Err(err) => log::warn!("Failed to stream migration log: {}", err),
+    }
+}
+
 pub async fn breaking_changes(api_url: &str, old_id: &str, new_id: &str, json: bool) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
         "{}/api/contracts/breaking-changes?old_id={}&new_id={}",
         api_url, old_id, new_id
@@ -953,8 +1173,11 @@ pub async fn migrate(
     api_url: &str,
     contract_id: &str,
     wasm_path: &str,
+    post_upgrade: Option<shared::models::PostUpgradeHook>,
     simulate_fail: bool,
+    simulate_hook_fail: bool,
     dry_run: bool,
+    token: Option<&str>,
 ) -> Result<()> {
     use sha2::{Digest, Sha256};
     use tokio::process::Command;
@@ -974,6 +1197,21 @@ pub async fn migrate(
     println!("Contract ID: {}", contract_id.green());
     println!("WASM Hash: {}", wasm_hash.bright_black());
 
+    let hook_invocation = match &post_upgrade {
+        Some(hook) => {
+            let Some(args) = crate::replay::invocation_args(hook.args.as_ref()) else {
+                anyhow::bail!("Post-upgrade arguments must be a JSON object of named arguments");
+            };
+            let invocation = std::iter::once(hook.function.clone())
+                .chain(args)
+                .collect::<Vec<_>>()
+                .join(" ");
+            println!("Post-upgrade Hook: {}", invocation.bright_black());
+            Some(invocation)
+        }
+        None => None,
+    };
+
     if dry_run {
         println!(
             "\n{}",
@@ -985,19 +1223,24 @@ pub async fn migrate(
         );
         return Ok(());
     }
+    let token = token.context(
+        "Pass --token (or set SOROBAN_REGISTRY_TOKEN) as the publisher or an account granted run_migrations",
+    )?;
 
     // 3. Create Migration Record (Pending)
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let create_url = format!("{}/api/migrations", api_url);
 
     let payload = json!({
         "contract_id": contract_id,
         "wasm_hash": wasm_hash,
+        "post_upgrade": post_upgrade,
     });
 
     print!("\nInitializing migration... ");
     let response = client
         .post(&create_url)
+        .bearer_auth(token)
         .json(&payload)
         .send()
         .await
@@ -1013,6 +1256,14 @@ pub async fn migrate(
     let migration_id = extract_migration_id(&migration)?;
     println!("{}", "OK".green());
     println!("Migration ID: {}", migration_id);
+    println!(
+        "{}",
+        format!(
+            "Follow live with `soroban-registry migrations logs {} --follow`",
+            migration_id
+        )
+        .bright_black()
+    );
 
     // 4. Execute Migration (Mock or Real)
     println!("\n{}", "Executing migration logic...".bold());
@@ -1059,15 +1310,44 @@ pub async fn migrate(
         }
     };
 
-    // 5. Update Status
+    append_migration_log(&client, api_url, token, &migration_id, &log_output).await;
+
+    // 5. Run the post-upgrade hook in the same flow. The migration only
+    // succeeds if the hook does; it is skipped when the swap failed.
+    let mut status = status;
+    let mut log_output = log_output;
+    let hook_result = match hook_invocation {
+        Some(invocation) if status == shared::models::MigrationStatus::Success => {
+            println!("\n{} {}", "Invoking post-upgrade hook:".bold(), invocation);
+            let (success, output) = if simulate_hook_fail {
+                (false, format!("Simulation: {} failed as requested.", invocation))
+            } else {
+                (true, format!("Simulation: {} invoked (mocked).", invocation))
+            };
+            append_migration_log(&client, api_url, token, &migration_id, &output).await;
+            if success {
+                println!("{}", "✓ Post-upgrade hook succeeded".green());
+            } else {
+                println!("{}", "✗ Post-upgrade hook failed".red());
+                status = shared::models::MigrationStatus::Failed;
+                log_output = format!("{}\n{}", log_output, output);
+            }
+            Some(json!({ "success": success, "output": output }))
+        }
+        _ => None,
+    };
+
+    // 6. Update Status
     let update_url = format!("{}/api/migrations/{}", api_url, migration_id);
     let update_payload = json!({
         "status": status,
-        "log_output": log_output
+        "log_output": log_output,
+        "post_upgrade_result": hook_result,
     });
 
     let update_res = client
         .put(&update_url)
+        .bearer_auth(token)
         .json(&update_payload)
         .send()
         .await
@@ -1087,24 +1367,47 @@ pub async fn migrate(
     Ok(())
 }
 
-pub async fn export(_api_url: &str, id: &str, output: &str, contract_dir: &str) -> Result<()> {
+pub async fn export(
+    api_url: &str,
+    id: &str,
+    output: &str,
+    contract_dir: &str,
+    profile: &str,
+    sign_key: Option<&str>,
+) -> Result<()> {
+    let profile: crate::manifest::ExportProfile = profile.parse()?;
     let source = std::path::Path::new(contract_dir);
     anyhow::ensure!(
         source.is_dir(),
         "contract directory does not exist: {}",
         contract_dir
     );
-    crate::export::create_archive(
+    let registry_files = crate::export::fetch_registry_files(api_url, id, profile).await;
+    let manifest = crate::export::create_archive(
         source,
         std::path::Path::new(output),
         id,
         "contract",
         "testnet",
+        profile,
+        &registry_files,
+        sign_key,
     )?;
     println!("{}", "✓ Export complete!".green().bold());
     println!("  {}: {}", "Output".bold(), output);
     println!("  {}: {}", "Contract".bold(), id.bright_black());
-    println!("  {}: contract\n", "Name".bold());
+    println!("  {}: contract", "Name".bold());
+    let components: Vec<&str> = profile.components().iter().map(|c| c.label()).collect();
+    println!(
+        "  {}: {} ({})",
+        "Profile".bold(),
+        profile,
+        components.join(", ")
+    );
+    match &manifest.signature {
+        Some(signature) => println!("  {}: {}\n", "Signed By".bold(), signature.signing_address),
+        None => println!("  {}: {}\n", "Signed By".bold(), "unsigned".bright_black()),
+    }
     Ok(())
 }
 
@@ -1113,15 +1416,28 @@ pub async fn import(
     archive: &str,
     network: Network,
     output_dir: &str,
+    list: bool,
+    only: &[String],
+    policy: crate::trust::TrustPolicy,
 ) -> Result<()> {
-    println!("\n{}", "Importing contract...".bold().cyan());
-
     let archive_path = std::path::Path::new(archive);
     anyhow::ensure!(archive_path.is_file(), "archive not found: {}", archive);
 
+    if list {
+        return import_preview(api_url, archive_path, only, policy).await;
+    }
+
+    println!("\n{}", "Importing contract...".bold().cyan());
+
     let dest = std::path::Path::new(output_dir);
 
-    let manifest = crate::import::extract_and_verify(archive_path, dest)?;
+    let manifest =
+        crate::import::read_manifest(archive_path).map_err(crate::import::explain_rejection)?;
+    let registered = crate::trust::registered_keys(api_url, &manifest).await;
+    let extracted =
+        crate::import::extract_and_verify(archive_path, dest, only, policy, &registered)
+            .map_err(crate::import::explain_rejection)?;
+    let manifest = &extracted.manifest;
 
     println!(
         "{}",
@@ -1140,11 +1456,31 @@ pub async fn import(
     );
     println!("  {}: {}", "SHA-256".bold(), manifest.sha256.bright_black());
     println!("  {}: {}", "Exported At".bold(), manifest.exported_at);
+    println!("  {}: {}", "Profile".bold(), manifest.profile);
     println!(
-        "  {}: {} file(s)",
-        "Contents".bold(),
-        manifest.contents.len()
+        "  {}: {}",
+        "Signature".bold(),
+        extracted.signature.describe()
     );
+    if only.is_empty() {
+        println!("  {}: {} file(s)", "Contents".bold(), extracted.files);
+    } else {
+        println!(
+            "  {}: {} of {} file(s) matching {}",
+            "Contents".bold(),
+            extracted.files,
+            manifest.contents.len(),
+            only.join(", ")
+        );
+    }
+    if extracted.outside_profile > 0 {
+        println!(
+            "  {} skipped {} file(s) outside the {} profile",
+            "⚠".yellow(),
+            extracted.outside_profile,
+            manifest.profile
+        );
+    }
     println!("  {}: {}", "Extracted To".bold(), output_dir);
 
     println!(
@@ -1160,6 +1496,112 @@ pub async fn import(
     Ok(())
 }
 
+/// `import --list`: what an archive holds and whether it would import,
+/// without writing anything.
+async fn import_preview(
+    api_url: &str,
+    archive_path: &Path,
+    only: &[String],
+    policy: crate::trust::TrustPolicy,
+) -> Result<()> {
+    let preview = crate::import::preview(archive_path).map_err(crate::import::explain_rejection)?;
+    let manifest = &preview.manifest;
+
+    println!("\n{}", "Archive Contents".bold().cyan());
+    println!(
+        "  {}: {}",
+        "Contract".bold(),
+        manifest.contract_id.bright_black()
+    );
+    println!("  {}: {}", "Name".bold(), manifest.name);
+    println!("  {}: {}", "Network".bold(), manifest.network);
+    println!("  {}: {}", "Exported At".bold(), manifest.exported_at);
+    let components: Vec<&str> = manifest
+        .profile
+        .components()
+        .iter()
+        .map(|c| c.label())
+        .collect();
+    println!(
+        "  {}: {} ({})",
+        "Profile".bold(),
+        manifest.profile,
+        components.join(", ")
+    );
+    let registered = crate::trust::registered_keys(api_url, manifest).await;
+    let signature = crate::trust::check_manifest(manifest, &registered)?;
+    println!("  {}: {}", "Signature".bold(), signature.describe());
+    if preview.verified() {
+        println!(
+            "  {}: {} {}",
+            "SHA-256".bold(),
+            preview.computed_sha256.bright_black(),
+            "✓ matches manifest".green()
+        );
+    } else {
+        println!(
+            "  {}: {} {} {}",
+            "SHA-256".bold(),
+            preview.computed_sha256,
+            "✗ manifest records".red(),
+            manifest.sha256
+        );
+    }
+
+    let selected: Vec<_> = preview
+        .entries
+        .iter()
+        .filter(|entry| crate::import::matches_any(only, Path::new(&entry.path)))
+        .collect();
+    let bytes: u64 = selected.iter().map(|entry| entry.size).sum();
+    if only.is_empty() {
+        println!(
+            "  {}: {} file(s), {} bytes\n",
+            "Files".bold(),
+            selected.len(),
+            bytes
+        );
+    } else {
+        println!(
+            "  {}: {} of {} file(s) matching {}, {} bytes\n",
+            "Files".bold(),
+            selected.len(),
+            preview.entries.len(),
+            only.join(", "),
+            bytes
+        );
+    }
+    for entry in &selected {
+        let note = match entry.manifest_size {
+            _ if !entry.in_profile => "outside profile; not imported".red().to_string(),
+            None => "not in manifest".yellow().to_string(),
+            Some(size) if size != entry.size => {
+                format!("manifest records {} bytes", size).red().to_string()
+            }
+            Some(_) => String::new(),
+        };
+        println!("  {:>10}  {}  {}", entry.size, entry.path, note);
+    }
+
+    if !preview.report.is_clean() {
+        crate::import::print_rejections(&preview.report);
+        anyhow::bail!("archive would be rejected on import");
+    }
+    if !preview.verified() {
+        println!();
+        anyhow::bail!("integrity check failed: the archive does not match its manifest");
+    }
+    if let Err(err) = policy.enforce(&signature, "archive") {
+        println!();
+        return Err(err);
+    }
+    println!(
+        "\n{}\n",
+        "✓ Archive verified; nothing was extracted".green().bold()
+    );
+    Ok(())
+}
+
 fn severity_colored(sev: &Severity) -> colored::ColoredString {
     match sev {
         Severity::Critical => "CRITICAL".red().bold(),
@@ -1215,7 +1657,7 @@ pub async fn trust_score(api_url: &str, contract_id: &str, network: Network) ->
     let url = format!("{}/api/contracts/{}/trust-score", api_url, contract_id);
     log::debug!("GET {}", url);
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let resp = client
         .get(&url)
         .query(&[("network", network.to_string())])
@@ -1321,8 +1763,48 @@ pub async fn patch_notify(api_url: &str, patch_id: &str) -> Result<()> {
     Ok(())
 }
 
-pub async fn patch_apply(api_url: &str, contract_id: &str, patch_id: &str) -> Result<()> {
-    println!("\n{}", "Applying security patch...".bold().cyan());
+pub async fn patch_apply(
+    api_url: &str,
+    contract_id: &str,
+    patch_id: &str,
+    wasm: Option<&str>,
+    force: bool,
+) -> Result<()> {
+    println!("\n{}", "Patch Preflight".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+
+    let report = PatchManager::preflight(api_url, contract_id, patch_id, wasm).await?;
+    println!(
+        "  {}: {} ({} → {})",
+        "Patch".bold(),
+        report.patch.id,
+        report.patch.target_version.bright_black(),
+        report.patch.new_wasm_hash.bright_black()
+    );
+    for check in &report.checks {
+        let marker = match check.status {
+            CheckStatus::Pass => "✓".green(),
+            CheckStatus::Warn => "!".yellow(),
+            CheckStatus::Fail => "✗".red(),
+        };
+        println!("  {} {}: {}", marker, check.name.bold(), check.detail);
+    }
+    println!("{}", "=".repeat(80).cyan());
+
+    if report.is_go() {
+        println!("  {}\n", "GO".green().bold());
+    } else if force {
+        println!(
+            "  {} {}\n",
+            "NO-GO".red().bold(),
+            "(overridden with --force)".yellow()
+        );
+    } else {
+        println!("  {}\n", "NO-GO".red().bold());
+        anyhow::bail!("Preflight failed; resolve the issues above or re-run with --force");
+    }
+
+    println!("{}", "Applying security patch...".bold().cyan());
 
     let audit = PatchManager::apply(api_url, contract_id, patch_id).await?;
 
@@ -1335,7 +1817,8 @@ pub async fn patch_apply(api_url: &str, contract_id: &str, patch_id: &str) -> Re
 }
 
 pub async fn deps_list(api_url: &str, contract_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
+    let contract_id = crate::resolve::contract_uuid(api_url, contract_id, None).await?;
     let url = format!("{}/api/contracts/{}/dependencies", api_url, contract_id);
 
     let response = client
@@ -1610,7 +2093,7 @@ pub fn incident_trigger(contract_id: &str, severity_str: &str) -> Result<()> {
 }
 
 pub async fn config_get(api_url: &str, contract_id: &str, environment: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
         "{}/api/contracts/{}/config?environment={}",
         api_url, contract_id, environment
@@ -1670,7 +2153,7 @@ pub async fn config_set(
     secrets_data: Option<&str>,
     created_by: &str,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/config", api_url, contract_id);
 
     let mut payload = json!({
@@ -1719,7 +2202,7 @@ pub async fn config_set(
 }
 
 pub async fn config_history(api_url: &str, contract_id: &str, environment: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
         "{}/api/contracts/{}/config/history?environment={}",
         api_url, contract_id, environment
@@ -1769,7 +2252,7 @@ pub async fn config_rollback(
     version: i32,
     created_by: &str,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
         "{}/api/contracts/{}/config/rollback?environment={}",
         api_url, contract_id, environment
@@ -1860,7 +2343,7 @@ pub async fn scan_deps(
 ) -> Result<()> {
     println!("\n{}", "Scanning Dependencies...".bold().cyan());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/scan", api_url, contract_id);
 
     // Parse dependencies
@@ -2130,7 +2613,7 @@ pub async fn validate_call(
     params: &[String],
     strict: bool,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/validate-call", api_url, contract_id);
 
     let body = json!({
@@ -2252,7 +2735,7 @@ pub async fn generate_bindings(
     language: &str,
     output: Option<&str>,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!(
         "{}/api/contracts/{}/bindings?language={}",
         api_url, contract_id, language
@@ -2294,7 +2777,7 @@ pub async fn generate_bindings(
 
 /// List functions available on a contract
 pub async fn list_functions(api_url: &str, contract_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/functions", api_url, contract_id);
 
     log::debug!("GET {}", url);
@@ -2387,18 +2870,22 @@ pub async fn info(
     highlight_method: Option<&str>,
     network: crate::config::Network,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let base_url = api_url.trim_end_matches('/');
 
     if format == "text" {
         println!("\n{}", "Fetching contract information...".bold().cyan());
     }
 
-    // 1. Fetch Metadata
+    // 1. Fetch Metadata (an on-chain address is resolved on the selected network)
+    let id = crate::resolve::contract_uuid(api_url, id, Some(&network.to_string())).await?;
     let metadata_url = format!("{}/api/contracts/{}", base_url, id);
     let metadata_res = client
         .get(&metadata_url)
-        .query(&[("network", network.to_string())])
+        .query(&[
+            ("network", network.to_string()),
+            ("include", "stats".to_string()),
+        ])
         .send()
         .await?;
 
@@ -2409,12 +2896,14 @@ pub async fn info(
         );
     }
     let metadata: serde_json::Value = metadata_res.json().await?;
+    // The contract record is flattened into the response body.
+    let contract = metadata.get("contract").unwrap_or(&metadata);
 
     // Extract genuine UUID if 'id' was a name or address
-    let contract_uuid = metadata["contract"]["id"]
+    let contract_uuid = contract["id"]
         .as_str()
         .context("Metadata missing contract ID")?;
-    let contract_address = metadata["contract"]["contract_id"].as_str().unwrap_or(id);
+    let contract_address = contract["contract_id"].as_str().unwrap_or(&id);
 
     // 2. Fetch ABI
     let abi_url = format!("{}/api/contracts/{}/abi", base_url, contract_uuid);
@@ -2494,15 +2983,66 @@ pub async fn info(
         Vec::new()
     };
 
+    // 7. Fetch Badges (with the attesting authority for each)
+    let badges_url = format!("{}/api/contracts/{}/badges", base_url, contract_uuid);
+    let badges_res = client.get(&badges_url).send().await;
+    let badges: Vec<serde_json::Value> = if let Ok(res) = badges_res {
+        if res.status().is_success() {
+            res.json().await.unwrap_or_default()
+        } else {
+            Vec::new()
+        }
+    } else {
+        Vec::new()
+    };
+
+    // 8. Fetch Bug Bounty (404 when the publisher has not listed one)
+    let bounty_url = format!("{}/api/contracts/{}/bug-bounty", base_url, contract_uuid);
+    let bug_bounty: serde_json::Value = match client.get(&bounty_url).send().await {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        _ => serde_json::Value::Null,
+    };
+
+    // 9. Fetch Oracle Feed (404 unless the contract declared a cadence)
+    let oracle_url = format!("{}/api/contracts/{}/oracle", base_url, contract_uuid);
+    let oracle_feed: serde_json::Value = match client.get(&oracle_url).send().await {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        _ => serde_json::Value::Null,
+    };
+
+    // 10. Fetch DeFi pool state (404 unless the contract is a tracked AMM)
+    let defi_url = format!("{}/api/contracts/{}/defi", base_url, contract_uuid);
+    let defi_pool: serde_json::Value = match client.get(&defi_url).send().await {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        _ => serde_json::Value::Null,
+    };
+
+    // 11. Fetch Test Runs and Fuzzing Campaigns for the newest version
+    // (versions come newest first)
+    let latest_version = versions
+        .first()
+        .and_then(|latest| latest["version"].as_str());
+    let runs_url = format!("{}/api/contracts/{}/test-runs", base_url, contract_uuid);
+    let test_runs = fetch_for_version(&client, &runs_url, latest_version).await;
+    let fuzz_url = format!("{}/api/contracts/{}/fuzz-campaigns", base_url, contract_uuid);
+    let fuzz_campaigns = fetch_for_version(&client, &fuzz_url, latest_version).await;
+
     // Aggregate data
     let full_info = json!({
-        "metadata": metadata["contract"],
+        "metadata": contract,
         "current_network_config": metadata["network_config"],
+        "activity": metadata["stats"],
         "abi": abi,
         "deployments": deployments,
         "dependencies": dependencies,
         "dependents": dependents,
         "versions": versions,
+        "badges": badges,
+        "bug_bounty": bug_bounty,
+        "oracle_feed": oracle_feed,
+        "defi_pool": defi_pool,
+        "test_runs": test_runs,
+        "fuzz_campaigns": fuzz_campaigns,
     });
 
     // Render output
@@ -2527,6 +3067,22 @@ pub async fn info(
     Ok(())
 }
 
+/// Records listed at `url` for one contract version; empty when there is no
+/// version or the registry does not answer.
+async fn fetch_for_version(
+    client: &reqwest::Client,
+    url: &str,
+    version: Option<&str>,
+) -> Vec<serde_json::Value> {
+    let Some(version) = version else {
+        return Vec::new();
+    };
+    match client.get(url).query(&[("version", version)]).send().await {
+        Ok(res) if res.status().is_success() => res.json().await.unwrap_or_default(),
+        _ => Vec::new(),
+    }
+}
+
 fn render_info_text(
     info: &serde_json::Value,
     highlight_method: Option<&str>,
@@ -2553,10 +3109,157 @@ fn render_info_text(
             "Unverified".red()
         }
     );
+    if let Some(publisher) = publisher_line(&metadata["publisher"]) {
+        println!("{} {}", "BY:      ".bold(), publisher);
+    }
     println!("{} {}/100", "HEALTH:  ".bold(), health_score);
+    if let Some(quality) = metadata["quality_score"].as_i64() {
+        println!("{} {}/100", "QUALITY: ".bold(), quality);
+    }
+    if info["oracle_feed"].is_object() {
+        println!(
+            "{} {}",
+            "ORACLE:  ".bold(),
+            crate::oracle::freshness_line(&info["oracle_feed"])
+        );
+    }
+    if info["defi_pool"]["refreshed_at"].is_string() {
+        println!(
+            "{} {}",
+            "POOL:    ".bold(),
+            crate::defi::pool_line(&info["defi_pool"])
+        );
+    }
+    // Versions come newest first.
+    if let Some(latest) = info["versions"].as_array().and_then(|v| v.first()) {
+        if let Some(coverage) = latest["docs_coverage"].as_f64() {
+            println!(
+                "{} {:.0}% of functions and types documented ({})",
+                "DOCS:    ".bold(),
+                coverage * 100.0,
+                latest["version"].as_str().unwrap_or("?")
+            );
+        }
+    }
     println!("{} {}", "DESC:    ".bold(), desc);
+    for (i, other) in metadata["also_deployed_on"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+    {
+        let label = if i == 0 { "ALSO ON: " } else { "         " };
+        println!(
+            "{} {} as {}{}",
+            label.bold(),
+            other["network"].as_str().unwrap_or("?"),
+            other["contract_id"].as_str().unwrap_or("?").yellow(),
+            if other["is_verified"].as_bool().unwrap_or(false) {
+                " (verified)".green().to_string()
+            } else {
+                String::new()
+            }
+        );
+    }
     println!("{}", "=".repeat(80).cyan());
 
+    // Badges
+    if let Some(badges) = info["badges"].as_array().filter(|b| !b.is_empty()) {
+        println!("\n{}", "BADGES:".bold().underline());
+        for badge in badges {
+            println!(
+                "  • {} by {} ({}) on {}",
+                badge["kind"].as_str().unwrap_or("?").bright_magenta().bold(),
+                badge["authority_name"].as_str().unwrap_or("?"),
+                badge["authority_address"].as_str().unwrap_or("?").bright_black(),
+                badge["issued_at"]
+                    .as_str()
+                    .and_then(|at| at.get(..10))
+                    .unwrap_or("?"),
+            );
+            if let Some(report) = badge["report_url"].as_str() {
+                println!("      report: {}", report.blue().underline());
+            }
+            if !badge["covers_current_build"].as_bool().unwrap_or(true) {
+                println!(
+                    "      {}",
+                    "attested build differs from the current WASM".yellow()
+                );
+            }
+        }
+    }
+
+    // Test Runs (newest version only, newest run first)
+    if let Some(runs) = info["test_runs"].as_array().filter(|r| !r.is_empty()) {
+        println!("\n{}", "TEST RUNS:".bold().underline());
+        for run in runs {
+            let failed = run["failed"].as_i64().unwrap_or(0);
+            println!(
+                "  • {} {}: {} passed, {} failed, {} skipped{} on {}",
+                run["version"].as_str().unwrap_or("?"),
+                run["suite"].as_str().unwrap_or("?").bold(),
+                run["passed"].as_i64().unwrap_or(0).to_string().green(),
+                if failed > 0 {
+                    failed.to_string().red()
+                } else {
+                    failed.to_string().normal()
+                },
+                run["skipped"].as_i64().unwrap_or(0),
+                run["coverage"]
+                    .as_f64()
+                    .map(|c| format!(", {:.2}% coverage", c))
+                    .unwrap_or_default(),
+                run["run_at"]
+                    .as_str()
+                    .and_then(|at| at.get(..10))
+                    .unwrap_or("?"),
+            );
+        }
+    }
+
+    // Fuzzing Campaigns (newest version only, newest campaign first)
+    if let Some(campaigns) = info["fuzz_campaigns"].as_array().filter(|c| !c.is_empty()) {
+        println!("\n{}", "FUZZING:".bold().underline());
+        for campaign in campaigns {
+            let crashes = campaign["crashes"].as_i64().unwrap_or(0);
+            println!(
+                "  • {} {}: {} targets, {} executions in {}s, {} crashes on {}",
+                campaign["version"].as_str().unwrap_or("?"),
+                campaign["engine"].as_str().unwrap_or("?").bold(),
+                campaign["targets"].as_i64().unwrap_or(0),
+                campaign["executions"].as_i64().unwrap_or(0),
+                campaign["duration_secs"].as_i64().unwrap_or(0),
+                if crashes > 0 {
+                    crashes.to_string().red()
+                } else {
+                    crashes.to_string().green()
+                },
+                campaign["finished_at"]
+                    .as_str()
+                    .and_then(|at| at.get(..10))
+                    .unwrap_or("?"),
+            );
+        }
+    }
+
+    // Bug Bounty
+    let bounty = &info["bug_bounty"];
+    if let Some(program) = bounty["program_url"].as_str() {
+        println!("\n{}", "BUG BOUNTY:".bold().underline());
+        println!("  Program:    {}", program.blue().underline());
+        println!("  Contact:    {}", bounty["contact"].as_str().unwrap_or("?"));
+        if let Some(max) = bounty["max_payout"].as_i64() {
+            println!(
+                "  Max payout: {} {}",
+                max.to_string().green().bold(),
+                bounty["payout_currency"].as_str().unwrap_or("USD")
+            );
+        }
+        if let Some(scope) = bounty["scope"].as_str() {
+            println!("  Scope:      {}", scope);
+        }
+    }
+
     // Explorer Links
     println!("\n{}", "BLOCK EXPLORERS:".bold().underline());
     let explorer_url = match network_str {
@@ -2575,6 +3278,34 @@ fn render_info_text(
     };
     println!("  • StellarExpert: {}", explorer_url.blue().underline());
 
+    // Activity
+    let activity = &info["activity"];
+    if activity.is_object() {
+        println!("\n{}", "ACTIVITY:".bold().underline());
+        let events_24h = activity["events_24h"].as_i64().unwrap_or(0);
+        let events_7d = activity["events_7d"].as_i64().unwrap_or(0);
+        println!("  • Events:         {} (24h) | {} (7d)", events_24h, events_7d);
+        println!(
+            "  • Unique callers: ~{} (24h) | ~{} (7d)",
+            activity["unique_callers_24h"].as_i64().unwrap_or(0),
+            activity["unique_callers_7d"].as_i64().unwrap_or(0)
+        );
+        match activity["last_activity_ledger"].as_i64() {
+            Some(ledger) => println!(
+                "  • Last activity:  ledger {} ({})",
+                ledger,
+                activity["last_activity_at"].as_str().unwrap_or("unknown time")
+            ),
+            None => println!(
+                "  • Last activity:  {}",
+                "no indexed events".bright_black()
+            ),
+        }
+        if events_7d == 0 {
+            println!("  {}", "No events in the last 7 days".yellow());
+        }
+    }
+
     // ABI Methods
     if let Some(abi) = info["abi"].as_array() {
         println!("\n{}", "ABI METHODS:".bold().underline());
@@ -2659,7 +3390,7 @@ pub fn doc(contract_path: &str, output: &str) -> Result<()> {
 }
 
 /// Load ABI JSON string from WASM (soroban bindings) or from a JSON file
-fn load_abi_json(contract_path: &str) -> Result<String> {
+pub(crate) fn load_abi_json(contract_path: &str) -> Result<String> {
     if contract_path.to_lowercase().ends_with(".wasm") {
         let output = std::process::Command::new("soroban")
             .args(["contract", "bindings", "json", "--wasm", contract_path])
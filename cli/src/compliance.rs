@@ -0,0 +1,156 @@
+//! compliance.rs — `soroban-registry compliance <contract>`
+//!
+//! Consolidated license report for legal review before integrating a
+//! contract: the SPDX license expression and copyright notice of the
+//! contract and of every registry contract in its transitive dependency
+//! tree, as the publishers declared them. `--format markdown` (default)
+//! produces a document to attach to a review; `--format json` the raw
+//! report.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+pub const FORMATS: [&str; 2] = ["markdown", "json"];
+
+/// Escapes text for a Markdown table cell.
+fn cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+pub fn render_markdown(report: &Value) -> String {
+    let entries = report["entries"].as_array().cloned().unwrap_or_default();
+    let root = entries.first();
+    let mut md = format!(
+        "# License compliance report: {}\n\n",
+        root.and_then(|e| e["name"].as_str())
+            .or(report["contract_id"].as_str())
+            .unwrap_or("?")
+    );
+    md.push_str(&format!(
+        "- Contract: `{}`\n- Generated: {}\n- Contracts in tree: {}\n- Without a declared license: {}\n",
+        report["contract_id"].as_str().unwrap_or("?"),
+        report["generated_at"].as_str().unwrap_or("?"),
+        entries.len(),
+        report["unlicensed"].as_u64().unwrap_or(0)
+    ));
+
+    let licenses = report["licenses"].as_array().cloned().unwrap_or_default();
+    if !licenses.is_empty() {
+        md.push_str("\n## Licenses\n\n| License | Contracts |\n|---|---|\n");
+        for license in &licenses {
+            md.push_str(&format!(
+                "| `{}` | {} |\n",
+                cell(license["license"].as_str().unwrap_or("?")),
+                license["contracts"].as_u64().unwrap_or(0)
+            ));
+        }
+    }
+
+    md.push_str(
+        "\n## Contracts\n\n| Depth | Contract | Version | License | Copyright | Required by |\n|---|---|---|---|---|---|\n",
+    );
+    for entry in &entries {
+        let required_by: Vec<&str> = entry["required_by"]
+            .as_array()
+            .map(|names| names.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        md.push_str(&format!(
+            "| {} | {} (`{}`) | {} | {} | {} | {} |\n",
+            entry["depth"].as_i64().unwrap_or(0),
+            cell(entry["name"].as_str().unwrap_or("?")),
+            entry["contract_id"].as_str().unwrap_or("?"),
+            entry["version"].as_str().unwrap_or("—"),
+            entry["license"]
+                .as_str()
+                .map(|l| format!("`{}`", cell(l)))
+                .unwrap_or_else(|| "**not declared**".to_string()),
+            cell(entry["copyright"].as_str().unwrap_or("—")),
+            cell(&required_by.join(", "))
+        ));
+    }
+
+    let unresolved = report["unresolved"].as_array().cloned().unwrap_or_default();
+    if !unresolved.is_empty() {
+        md.push_str(
+            "\n## Unresolved dependencies\n\nNot published in the registry; review their terms separately.\n\n| Dependency | Constraint | Required by |\n|---|---|---|\n",
+        );
+        for dep in &unresolved {
+            md.push_str(&format!(
+                "| {} | `{}` | {} |\n",
+                cell(dep["dependency_name"].as_str().unwrap_or("?")),
+                cell(dep["version_constraint"].as_str().unwrap_or("?")),
+                cell(dep["required_by"].as_str().unwrap_or("?"))
+            ));
+        }
+    }
+    md
+}
+
+pub async fn report(
+    api_url: &str,
+    contract_id: &str,
+    format: &str,
+    output: Option<&str>,
+) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/compliance",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    let text = match format {
+        "json" => serde_json::to_string_pretty(&body)?,
+        _ => render_markdown(&body),
+    };
+    match output {
+        Some(path) => {
+            std::fs::write(path, &text).with_context(|| format!("Failed to write {}", path))?;
+            println!("{} {}", "✓ Report written to".green().bold(), path);
+        }
+        None => println!("{}", text.trim_end()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn markdown_lists_every_contract_and_gap() {
+        let report = json!({
+            "contract_id": "CROOT",
+            "generated_at": "2026-04-03T00:00:00Z",
+            "unlicensed": 1,
+            "licenses": [{ "license": "Apache-2.0 OR MIT", "contracts": 1 }],
+            "entries": [
+                { "contract_id": "CROOT", "name": "vault", "version": "1.2.0",
+                  "license": "Apache-2.0 OR MIT", "copyright": "Copyright 2026 Example | Labs",
+                  "depth": 0, "required_by": [] },
+                { "contract_id": "CDEP", "name": "oracle", "version": null,
+                  "license": null, "copyright": null, "depth": 1, "required_by": ["vault"] }
+            ],
+            "unresolved": [
+                { "dependency_name": "price-feed", "version_constraint": "^2", "required_by": "oracle" }
+            ]
+        });
+        let md = render_markdown(&report);
+        assert!(md.starts_with("# License compliance report: vault\n"));
+        assert!(md.contains("| `Apache-2.0 OR MIT` | 1 |"));
+        assert!(md.contains("Copyright 2026 Example \\| Labs"));
+        assert!(md.contains("| 1 | oracle (`CDEP`) | — | **not declared** | — | vault |"));
+        assert!(md.contains("| price-feed | `^2` | oracle |"));
+    }
+}
@@ -2,6 +2,7 @@
 
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -54,6 +55,21 @@ impl FromStr for Network {
 #[derive(Debug, Clone, Deserialize, Default)]
 struct ConfigFile {
     defaults: Option<DefaultsSection>,
+    #[serde(default)]
+    profiles: BTreeMap<String, ProfileSection>,
+}
+
+/// `[profiles.<name>]` — connection settings for one registry, e.g. a
+/// private registry that requires a client certificate (mTLS).
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ProfileSection {
+    pub api_base: Option<String>,
+    /// PEM client certificate presented to registries in mTLS mode.
+    pub client_cert: Option<PathBuf>,
+    /// PEM private key for `client_cert`.
+    pub client_key: Option<PathBuf>,
+    /// PEM CA bundle used to verify a privately issued server certificate.
+    pub ca_cert: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -126,6 +142,22 @@ pub fn show_config() -> Result<()> {
         defaults.timeout.unwrap_or(DEFAULT_TIMEOUT_SECS)
     );
 
+    if path.exists() {
+        let config = load_config_file(&path)?;
+        for (name, profile) in &config.profiles {
+            println!(
+                "profiles.{} = {}{}",
+                name,
+                profile.api_base.as_deref().unwrap_or("(default api_base)"),
+                if profile.client_cert.is_some() {
+                    " [client certificate]"
+                } else {
+                    ""
+                }
+            );
+        }
+    }
+
     Ok(())
 }
 
@@ -147,6 +179,51 @@ pub fn edit_config() -> Result<()> {
     Ok(())
 }
 
+/// Load `[profiles.<name>]`, resolving relative paths against the config
+/// directory.
+pub fn load_profile(name: &str) -> Result<ProfileSection> {
+    migrate_legacy_config()?;
+    let path = config_file_path().context("Could not determine home directory")?;
+    if !path.exists() {
+        anyhow::bail!(
+            "Profile '{}' not found: {} does not exist",
+            name,
+            path.display()
+        );
+    }
+    let config = load_config_file(&path)?;
+    let base = path.parent().unwrap_or_else(|| Path::new("."));
+    select_profile(config, name, base)
+}
+
+fn select_profile(config: ConfigFile, name: &str, base: &Path) -> Result<ProfileSection> {
+    let mut profile = config.profiles.get(name).cloned().with_context(|| {
+        let known: Vec<&str> = config.profiles.keys().map(String::as_str).collect();
+        format!(
+            "Profile '{}' not found in config (available: {})",
+            name,
+            if known.is_empty() {
+                "none".to_string()
+            } else {
+                known.join(", ")
+            }
+        )
+    })?;
+    for path in [
+        &mut profile.client_cert,
+        &mut profile.client_key,
+        &mut profile.ca_cert,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        if path.is_relative() {
+            *path = base.join(&*path);
+        }
+    }
+    Ok(profile)
+}
+
 fn load_defaults_section() -> Result<DefaultsSection> {
     migrate_legacy_config()?;
     let path = match config_file_path() {
@@ -274,6 +351,34 @@ timeout = 55
         assert_eq!(defaults.timeout, Some(55));
     }
 
+    #[test]
+    fn test_select_profile_resolves_relative_paths() {
+        let parsed: ConfigFile = toml::from_str(
+            r#"[profiles.corp]
+api_base = "https://registry.corp.internal"
+client_cert = "certs/laptop.pem"
+client_key = "/etc/registry/laptop.key"
+"#,
+        )
+        .unwrap();
+        let base = Path::new("/home/dev/.soroban-registry");
+
+        let profile = select_profile(parsed.clone(), "corp", base).unwrap();
+        assert_eq!(
+            profile.api_base.as_deref(),
+            Some("https://registry.corp.internal")
+        );
+        assert_eq!(profile.client_cert, Some(base.join("certs/laptop.pem")));
+        assert_eq!(
+            profile.client_key,
+            Some(PathBuf::from("/etc/registry/laptop.key"))
+        );
+        assert!(profile.ca_cert.is_none());
+
+        let err = select_profile(parsed, "missing", base).unwrap_err();
+        assert!(err.to_string().contains("available: corp"));
+    }
+
     #[test]
     fn test_config_file_path_for_base() {
         let dir = tempdir().unwrap();
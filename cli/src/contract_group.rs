@@ -0,0 +1,113 @@
+//! contract_group.rs — `soroban-registry group show|link|unlink`
+//!
+//! A contract group ties together deployments of the same codebase on
+//! different networks, so `info` can say "also deployed on mainnet as C..."
+//! and `search --deployed-on` can find them. Linking and unlinking need the
+//! publisher's bearer token.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub async fn show(api_url: &str, contract_id: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/contracts/{}/group", api_url, contract_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let group = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&group)?);
+        return Ok(());
+    }
+    print_group(&group);
+    Ok(())
+}
+
+pub async fn link(api_url: &str, token: &str, contract_id: &str, other: &str) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!("{}/api/contracts/{}/group", api_url, contract_id))
+        .bearer_auth(token)
+        .json(&json!({ "contract_id": other }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let group = parse_response(response).await?;
+
+    println!(
+        "\n{}",
+        format!("✓ Linked {} with {}", other, contract_id)
+            .green()
+            .bold()
+    );
+    print_group(&group);
+    Ok(())
+}
+
+pub async fn unlink(api_url: &str, token: &str, contract_id: &str) -> Result<()> {
+    let response = crate::http::client()
+        .delete(format!("{}/api/contracts/{}/group", api_url, contract_id))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    parse_response(response).await?;
+
+    println!(
+        "\n{}\n",
+        format!("✓ {} is no longer linked to other deployments", contract_id)
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_group(group: &Value) {
+    println!(
+        "\n{}",
+        format!(
+            "Contract group {}",
+            group["group_id"].as_str().unwrap_or("?")
+        )
+        .bold()
+        .cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+
+    let headers = ["Network", "Contract", "WASM", "Verified"];
+    let col_widths = [10, 56, 12, 8];
+    let rows: Vec<Vec<String>> = group["deployments"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|d| {
+            let wasm = d["wasm_hash"].as_str().unwrap_or("-");
+            vec![
+                d["network"].as_str().unwrap_or("?").to_string(),
+                d["contract_id"].as_str().unwrap_or("?").to_string(),
+                wasm.get(..12).unwrap_or(wasm).to_string(),
+                if d["is_verified"].as_bool().unwrap_or(false) {
+                    "yes".to_string()
+                } else {
+                    "no".to_string()
+                },
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!();
+}
@@ -59,7 +59,7 @@ pub async fn run(api_url: &str, address: &str, network: &str, json: bool) -> Res
         api_url
     );
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     // ── 1. Fetch contract from registry by on-chain address ──────────────────
     let search_url = format!(
@@ -12,6 +12,7 @@ pub struct ContractListItem {
     pub category: Option<String>,
     pub is_verified: bool,
     pub health_score: i32,
+    pub quality_score: Option<i32>,
     pub created_at: String,
     pub tags: Vec<String>,
 }
@@ -34,6 +35,7 @@ pub enum SortBy {
     Name,
     CreatedAt,
     HealthScore,
+    Quality,
     Network,
 }
 
@@ -45,9 +47,10 @@ impl std::str::FromStr for SortBy {
             "name" => Ok(SortBy::Name),
             "created_at" | "created-at" => Ok(SortBy::CreatedAt),
             "health_score" | "health-score" => Ok(SortBy::HealthScore),
+            "quality" => Ok(SortBy::Quality),
             "network" => Ok(SortBy::Network),
             _ => Err(format!(
-                "Invalid sort-by value: {}. Supported: name, created_at, health_score, network",
+                "Invalid sort-by value: {}. Supported: name, created_at, health_score, quality, network",
                 s
             )),
         }
@@ -104,7 +107,7 @@ pub async fn list_contracts(
 
     log::debug!("Fetching contracts from: {}", url);
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let response = client
         .get(&url)
         .send()
@@ -173,6 +176,11 @@ pub async fn list_contracts(
                 .and_then(|v| v.as_i64())
                 .unwrap_or(0) as i32;
 
+            let quality_score = item
+                .get("quality_score")
+                .and_then(|v| v.as_i64())
+                .map(|v| v as i32);
+
             let created_at = item
                 .get("created_at")
                 .and_then(|v| v.as_str())
@@ -197,6 +205,7 @@ pub async fn list_contracts(
                 category,
                 is_verified,
                 health_score,
+                quality_score,
                 created_at,
                 tags,
             })
@@ -231,6 +240,8 @@ fn sort_contracts(contracts: &mut [ContractListItem], sort_by: SortBy, sort_orde
             SortBy::Name => a.name.cmp(&b.name),
             SortBy::CreatedAt => a.created_at.cmp(&b.created_at),
             SortBy::HealthScore => a.health_score.cmp(&b.health_score),
+            // Unscored contracts (None) sort below every score.
+            SortBy::Quality => a.quality_score.cmp(&b.quality_score),
             SortBy::Network => a.network.cmp(&b.network),
         };
 
@@ -250,15 +261,16 @@ fn print_table(contracts: &[ContractListItem]) {
 
     // Header
     println!(
-        "{:<36} {:<30} {:<15} {:<10} {:<15} {:<12}",
+        "{:<36} {:<30} {:<15} {:<10} {:<15} {:<8} {:<12}",
         "ID".bold(),
         "Name".bold(),
         "Network".bold(),
         "Verified".bold(),
         "Health".bold(),
+        "Quality".bold(),
         "Category".bold()
     );
-    println!("{}", "─".repeat(120).cyan());
+    println!("{}", "─".repeat(129).cyan());
 
     // Rows
     for contract in contracts {
@@ -274,6 +286,13 @@ fn print_table(contracts: &[ContractListItem]) {
             _ => contract.health_score.to_string().red(),
         };
 
+        let quality = match contract.quality_score {
+            Some(score @ 70..=100) => score.to_string().green(),
+            Some(score @ 40..=69) => score.to_string().yellow(),
+            Some(score) => score.to_string().red(),
+            None => "—".bright_black(),
+        };
+
         let id = if contract.id.len() > 36 {
             format!("{}...", &contract.id[..33])
         } else {
@@ -283,12 +302,13 @@ fn print_table(contracts: &[ContractListItem]) {
         let category = contract.category.as_deref().unwrap_or("—").to_string();
 
         println!(
-            "{:<36} {:<30} {:<15} {:<10} {:<15} {:<12}",
+            "{:<36} {:<30} {:<15} {:<10} {:<15} {:<8} {:<12}",
             id,
             &contract.name[..contract.name.len().min(29)],
             contract.network,
             verified,
             health_color,
+            quality,
             &category[..category.len().min(11)]
         );
     }
@@ -312,7 +332,7 @@ fn print_json(contracts: &[ContractListItem]) {
 
 fn print_csv(contracts: &[ContractListItem]) {
     // Header
-    println!("id,name,contract_id,network,category,is_verified,health_score,created_at,tags");
+    println!("id,name,contract_id,network,category,is_verified,health_score,quality_score,created_at,tags");
 
     // Rows
     for contract in contracts {
@@ -320,7 +340,7 @@ fn print_csv(contracts: &[ContractListItem]) {
         let category = contract.category.as_deref().unwrap_or("");
 
         println!(
-            "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",{},{},\"{}\",\"{}\"",
+            "\"{}\",\"{}\",\"{}\",\"{}\",\"{}\",{},{},{},\"{}\",\"{}\"",
             contract.id,
             contract.name,
             contract.contract_id,
@@ -328,6 +348,10 @@ fn print_csv(contracts: &[ContractListItem]) {
             category,
             contract.is_verified,
             contract.health_score,
+            contract
+                .quality_score
+                .map(|score| score.to_string())
+                .unwrap_or_default(),
             contract.created_at,
             tags
         );
@@ -1,4 +1,6 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{anyhow, bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
 use std::path::PathBuf;
 use tokio::process::Command;
 
@@ -48,6 +50,110 @@ pub async fn run_dashboard(params: DashboardParams) -> Result<()> {
     Ok(())
 }
 
+/// Health overview of every contract a publisher maintains
+/// (`dashboard --publisher <id>`).
+pub async fn publisher_stats(api_url: &str, publisher_id: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/publishers/{}/stats", api_url, publisher_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let stats: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = stats["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&stats)?);
+        return Ok(());
+    }
+
+    let count = |key: &str| stats[key].as_i64().unwrap_or(0);
+    let coverage = stats["verification_coverage_pct"].as_f64().unwrap_or(0.0);
+    println!("\n{}", "Publisher Dashboard".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    println!("  {}: {}", "Publisher".bold(), publisher_id);
+    println!("  {}: {}", "Contracts".bold(), count("contracts"));
+    println!("  {}: {}", "Downloads".bold(), count("downloads"));
+    println!("  {}: {}", "Dependents".bold(), count("dependents"));
+    let coverage_text = format!(
+        "{}% ({}/{})",
+        coverage,
+        count("verified_contracts"),
+        count("contracts")
+    );
+    println!(
+        "  {}: {}",
+        "Verified".bold(),
+        if coverage >= 100.0 {
+            coverage_text.green()
+        } else {
+            coverage_text.yellow()
+        }
+    );
+    let advisories = count("open_advisories");
+    println!(
+        "  {}: {}",
+        "Open advisories".bold(),
+        if advisories > 0 {
+            advisories.to_string().red().bold()
+        } else {
+            advisories.to_string().green()
+        }
+    );
+    println!(
+        "  {}: {}\n",
+        "Pending proposals".bold(),
+        count("pending_proposals")
+    );
+
+    let contracts = stats["per_contract"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    if contracts.is_empty() {
+        println!("  No contracts published.\n");
+        return Ok(());
+    }
+    let headers = [
+        "Name",
+        "Network",
+        "Verified",
+        "Downloads",
+        "Dependents",
+        "Advisories",
+        "Proposals",
+    ];
+    let col_widths = [24, 9, 8, 9, 10, 10, 9];
+    let rows: Vec<Vec<String>> = contracts
+        .iter()
+        .map(|c| {
+            let n = |key: &str| c[key].as_i64().unwrap_or(0).to_string();
+            vec![
+                c["name"].as_str().unwrap_or("?").to_string(),
+                c["network"].as_str().unwrap_or("?").to_string(),
+                if c["is_verified"].as_bool().unwrap_or(false) {
+                    "yes".to_string()
+                } else {
+                    "no".to_string()
+                },
+                n("downloads"),
+                n("dependents"),
+                n("open_advisories"),
+                n("pending_proposals"),
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!();
+    Ok(())
+}
+
 fn cli_dir() -> PathBuf {
     PathBuf::from(env!("CARGO_MANIFEST_DIR"))
 }
@@ -0,0 +1,101 @@
+//! defi.rs — `soroban-registry defi`
+//!
+//! Shows an AMM pool as the registry last read it from the contract's
+//! instance storage: its token pair, raw reserves, fee and an estimated TVL
+//! in USD. Find pools by liquidity with `search --category defi --sort tvl`.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+/// Formats a USD amount with thousands separators, e.g. "$1,234,567.89".
+pub fn format_usd(amount: f64) -> String {
+    let cents = format!("{:.2}", amount.abs());
+    let (whole, fraction) = cents.split_once('.').unwrap_or((&cents, "00"));
+    let mut grouped = String::new();
+    for (i, digit) in whole.chars().enumerate() {
+        if i > 0 && (whole.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(digit);
+    }
+    let sign = if amount < 0.0 { "-" } else { "" };
+    format!("{}${}.{}", sign, grouped, fraction)
+}
+
+/// One-line pool summary, e.g. "TVL $1,234.00, fee 0.30%".
+pub fn pool_line(pool: &Value) -> String {
+    let tvl = match pool["tvl_usd"].as_f64() {
+        Some(tvl) => format!("TVL {}", format_usd(tvl).green().bold()),
+        None => "TVL unknown".yellow().to_string(),
+    };
+    match pool["fee_bps"].as_i64() {
+        Some(bps) => format!("{}, fee {:.2}%", tvl, bps as f64 / 100.0),
+        None => tvl,
+    }
+}
+
+pub async fn show(api_url: &str, contract_id: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/defi",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let pool = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&pool)?);
+        return Ok(());
+    }
+    println!("\n{}", format!("Pool {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    if pool["refreshed_at"].is_string() {
+        println!("  {}", pool_line(&pool));
+        for (token, reserve) in [("token_a", "reserve_a"), ("token_b", "reserve_b")] {
+            println!(
+                "  {:<58} {}",
+                pool[token].as_str().unwrap_or("?"),
+                pool[reserve].as_str().unwrap_or("?")
+            );
+        }
+        println!(
+            "  {} {}",
+            "Read:".bold(),
+            pool["refreshed_at"].as_str().unwrap_or_default()
+        );
+    } else {
+        println!("  {}", "Pool state has not been read yet.".bright_black());
+    }
+    if let Some(error) = pool["last_error"].as_str() {
+        println!("  {} {}", "Last attempt failed:".red(), error);
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usd_amounts_are_grouped() {
+        assert_eq!(format_usd(0.0), "$0.00");
+        assert_eq!(format_usd(999.5), "$999.50");
+        assert_eq!(format_usd(1_234_567.891), "$1,234,567.89");
+        assert_eq!(format_usd(-1000.0), "-$1,000.00");
+    }
+}
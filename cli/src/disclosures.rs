@@ -0,0 +1,277 @@
+//! disclosures.rs — `soroban-registry disclosures submit|list|read|status`
+//!
+//! Coordinated vulnerability disclosure through the registry. Researchers
+//! `submit` a report for a contract; the registry seals it to the
+//! publisher's Stellar key on arrival. The publisher lists the sealed
+//! reports and opens them locally with `read`, using the secret key that
+//! matches their account; the key never leaves the machine.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use colored::Colorize;
+use serde_json::{json, Value};
+use shared::disclosure::{open, DisclosureMessage, SealedReport};
+
+use crate::claim::{decode_secret_key, encode_strkey, VERSION_ACCOUNT};
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn disclosures_url(api_url: &str, contract_id: &str) -> String {
+    format!(
+        "{}/api/contracts/{}/disclosures",
+        api_url.trim_end_matches('/'),
+        contract_id
+    )
+}
+
+fn fixed<const N: usize>(report: &Value, field: &str) -> Result<[u8; N]> {
+    let bytes = hex::decode(report[field].as_str().unwrap_or_default())
+        .with_context(|| format!("Report {} is not hex", field))?;
+    bytes
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Report {} must be {} bytes", field, N))
+}
+
+/// Rebuilds the sealed report from its API representation.
+fn sealed_report(report: &Value) -> Result<SealedReport> {
+    Ok(SealedReport {
+        ephemeral_public: fixed(report, "ephemeral_public")?,
+        nonce: fixed(report, "nonce")?,
+        ciphertext: BASE64
+            .decode(report["ciphertext"].as_str().unwrap_or_default())
+            .context("Report ciphertext is not base64")?,
+    })
+}
+
+fn open_report(report: &Value, seed: &[u8; 32]) -> Result<DisclosureMessage> {
+    open(seed, &sealed_report(report)?).map_err(anyhow::Error::msg)
+}
+
+async fn fetch_reports(
+    api_url: &str,
+    contract_id: &str,
+    status: Option<&str>,
+    token: &str,
+) -> Result<Vec<Value>> {
+    let mut request = crate::http::client()
+        .get(disclosures_url(api_url, contract_id))
+        .bearer_auth(token);
+    if let Some(status) = status {
+        request = request.query(&[("status", status)]);
+    }
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    Ok(check(response)
+        .await?
+        .as_array()
+        .cloned()
+        .unwrap_or_default())
+}
+
+pub async fn submit(
+    api_url: &str,
+    contract_id: &str,
+    title: &str,
+    file: &str,
+    contact: Option<&str>,
+) -> Result<()> {
+    let body = std::fs::read_to_string(file)
+        .with_context(|| format!("Failed to read report from {}", file))?;
+    let response = crate::http::client()
+        .post(disclosures_url(api_url, contract_id))
+        .json(&json!({ "title": title, "body": body, "contact": contact }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let receipt = check(response).await?;
+
+    println!("{}", "\n✓ Report submitted".green().bold());
+    println!(
+        "  {}: {}",
+        "Receipt".bold(),
+        receipt["id"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Sealed to".bold(),
+        receipt["recipient_address"]
+            .as_str()
+            .unwrap_or("?")
+            .bright_magenta()
+    );
+    println!(
+        "  {}\n",
+        "Only the publisher's key can read it; the registry stores ciphertext.".bright_black()
+    );
+    Ok(())
+}
+
+pub async fn list(
+    api_url: &str,
+    contract_id: &str,
+    status: Option<&str>,
+    token: &str,
+    secret_key: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let reports = fetch_reports(api_url, contract_id, status, token).await?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+        return Ok(());
+    }
+    let seed = secret_key
+        .map(decode_secret_key)
+        .transpose()?
+        .map(|key| key.to_bytes());
+
+    println!(
+        "\n{}",
+        format!("Disclosure reports for {}", contract_id)
+            .bold()
+            .cyan()
+    );
+    println!("{}", "=".repeat(60).cyan());
+    if reports.is_empty() {
+        println!("  {}\n", "No reports.".bright_black());
+        return Ok(());
+    }
+    for report in &reports {
+        let status = report["status"].as_str().unwrap_or("?");
+        let status = match status {
+            "new" => status.yellow().bold(),
+            "resolved" => status.green(),
+            _ => status.normal(),
+        };
+        let title = match &seed {
+            Some(seed) => match open_report(report, seed) {
+                Ok(message) => message.title,
+                Err(_) => "(sealed to another key)".bright_black().to_string(),
+            },
+            None => "(sealed)".bright_black().to_string(),
+        };
+        println!(
+            "  {}  {:<12}  {}  {}",
+            report["id"].as_str().unwrap_or("?").bright_black(),
+            status,
+            report["created_at"]
+                .as_str()
+                .and_then(|at| at.get(..10))
+                .unwrap_or("?"),
+            title
+        );
+    }
+    if seed.is_none() {
+        println!(
+            "\n  {}",
+            "Pass --secret-key to show titles, or `disclosures read` to open one.".bright_black()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn read(
+    api_url: &str,
+    contract_id: &str,
+    report_id: &str,
+    token: &str,
+    secret_key: &str,
+) -> Result<()> {
+    let signing_key = decode_secret_key(secret_key)?;
+    let address = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+
+    let reports = fetch_reports(api_url, contract_id, None, token).await?;
+    let Some(report) = reports.iter().find(|r| r["id"].as_str() == Some(report_id)) else {
+        bail!("No disclosure report {} for {}", report_id, contract_id);
+    };
+    let recipient = report["recipient_address"].as_str().unwrap_or("?");
+    if recipient != address {
+        bail!(
+            "Report was sealed to {}, but the secret key belongs to {}",
+            recipient,
+            address
+        );
+    }
+    let message = open_report(report, &signing_key.to_bytes())?;
+
+    println!("\n{}", message.title.bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    println!(
+        "  {}: {}",
+        "Status".bold(),
+        report["status"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Received".bold(),
+        report["created_at"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Contact".bold(),
+        message.contact.as_deref().unwrap_or("(anonymous)")
+    );
+    println!("\n{}\n", message.body);
+    Ok(())
+}
+
+pub async fn set_status(
+    api_url: &str,
+    contract_id: &str,
+    report_id: &str,
+    status: &str,
+    token: &str,
+) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!(
+            "{}/{}/status",
+            disclosures_url(api_url, contract_id),
+            report_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "status": status }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    check(response).await?;
+
+    println!(
+        "{} {} is now {}",
+        "✓".green().bold(),
+        report_id,
+        status.bold()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_sealed_report_fields() {
+        let report = json!({
+            "ephemeral_public": "11".repeat(32),
+            "nonce": "22".repeat(12),
+            "ciphertext": BASE64.encode(b"sealed"),
+        });
+        let sealed = sealed_report(&report).unwrap();
+        assert_eq!(sealed.ephemeral_public, [0x11; 32]);
+        assert_eq!(sealed.nonce, [0x22; 12]);
+        assert_eq!(sealed.ciphertext, b"sealed");
+
+        let truncated =
+            json!({ "ephemeral_public": "11", "nonce": "22".repeat(12), "ciphertext": "" });
+        assert!(sealed_report(&truncated).is_err());
+    }
+}
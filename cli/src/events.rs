@@ -8,10 +8,16 @@ use serde::{Deserialize, Serialize};
 pub struct ContractEvent {
     pub id: String,
     pub contract_id: String,
+    #[serde(default)]
+    pub contract_name: Option<String>,
+    #[serde(default)]
+    pub registry_id: Option<String>,
     pub topic: String,
     pub data: Option<serde_json::Value>,
     pub ledger_sequence: i64,
     pub transaction_hash: Option<String>,
+    #[serde(default)]
+    pub event_index: Option<i32>,
     pub timestamp: String,
     pub network: String,
     pub created_at: String,
@@ -27,10 +33,114 @@ pub struct EventStats {
     pub events_by_topic: serde_json::Value,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractAnomaly {
+    pub id: String,
+    pub kind: String,
+    pub topic: Option<String>,
+    pub event_id: Option<String>,
+    pub ledger_sequence: Option<i64>,
+    pub details: serde_json::Value,
+    pub detected_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContractAnomalies {
+    pub contract_id: String,
+    pub network: String,
+    pub anomalies: Vec<ContractAnomaly>,
+}
+
+/// Which contracts' events to query. A single contract ID with no tag or
+/// category uses the per-contract endpoint; anything else is merged by the
+/// registry into one stream ordered by ledger.
+pub struct EventSelector<'a> {
+    pub contract_ids: &'a [String],
+    pub tag: Option<&'a str>,
+    pub category: Option<&'a str>,
+    pub network: Option<&'a str>,
+}
+
+impl EventSelector<'_> {
+    pub fn single(&self) -> Option<&str> {
+        match self.contract_ids {
+            [id] if self.tag.is_none() && self.category.is_none() => Some(id.as_str()),
+            _ => None,
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.contract_ids.is_empty() && self.tag.is_none() && self.category.is_none()
+    }
+}
+
+/// Stream every event matching the selector into `path`, as NDJSON when the
+/// file name ends in `.ndjson` or `.jsonl` and as CSV otherwise. The registry
+/// sends the export as it reads it, so `--limit` and `--offset` do not apply.
+async fn export_events(
+    client: &reqwest::Client,
+    api_url: &str,
+    selector: &EventSelector<'_>,
+    topic: Option<&str>,
+    filter: Option<&str>,
+    path: &str,
+) -> Result<()> {
+    let format = if path.ends_with(".ndjson") || path.ends_with(".jsonl") {
+        "ndjson"
+    } else {
+        "csv"
+    };
+    let mut query = vec![("format", format.to_string())];
+    if !selector.contract_ids.is_empty() {
+        query.push(("contracts", selector.contract_ids.join(",")));
+    }
+    let optional = [
+        ("tag", selector.tag),
+        ("category", selector.category),
+        ("network", selector.network),
+        ("topic", topic),
+        ("data_pattern", filter),
+    ];
+    for (key, value) in optional {
+        if let Some(value) = value {
+            query.push((key, value.to_string()));
+        }
+    }
+
+    let response = client
+        .get(format!("{}/api/events/export", api_url))
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to export events: {}", e))?;
+    if !response.status().is_success() {
+        let error = response.text().await?;
+        anyhow::bail!("API error: {}", error);
+    }
+
+    let mut file = std::io::BufWriter::new(std::fs::File::create(path)?);
+    let written = match crate::http::download(response, &mut file, "Exporting events").await {
+        Ok(written) => written,
+        Err(err) => {
+            drop(file);
+            let _ = std::fs::remove_file(path);
+            return Err(err);
+        }
+    };
+    println!(
+        "{} Exported events to {} ({} bytes, {})",
+        "✓".green(),
+        path,
+        written,
+        format
+    );
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments)]
 pub async fn query_events(
     api_url: &str,
-    contract_id: &str,
+    selector: &EventSelector<'_>,
     topic: Option<&str>,
     filter: Option<&str>,
     limit: i64,
@@ -38,12 +148,19 @@ pub async fn query_events(
     export_path: Option<&str>,
     stats_only: bool,
 ) -> Result<()> {
+    if selector.is_empty() {
+        anyhow::bail!("Provide at least one contract ID, --tag or --category");
+    }
+    if stats_only && selector.single().is_none() {
+        anyhow::bail!("--stats is only available for a single contract");
+    }
+
     println!("\n{}", "Contract Events".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
-    if stats_only {
+    if let (true, Some(contract_id)) = (stats_only, selector.single()) {
         let url = format!("{}/api/contracts/{}/events/stats", api_url, contract_id);
 
         let response = client
@@ -93,21 +210,40 @@ pub async fn query_events(
         return Ok(());
     }
 
-    let mut url = format!(
-        "{}/api/contracts/{}/events?limit={}&offset={}",
-        api_url, contract_id, limit, offset
-    );
+    if let Some(path) = export_path {
+        return export_events(&client, api_url, selector, topic, filter, path).await;
+    }
 
+    let mut query = vec![("limit", limit.to_string()), ("offset", offset.to_string())];
     if let Some(t) = topic {
-        url.push_str(&format!("&topic={}", t));
+        query.push(("topic", t.to_string()));
     }
-
     if let Some(f) = filter {
-        url.push_str(&format!("&data_pattern={}", f));
+        query.push(("data_pattern", f.to_string()));
     }
 
+    let url = match selector.single() {
+        Some(contract_id) => format!("{}/api/contracts/{}/events", api_url, contract_id),
+        None => {
+            if !selector.contract_ids.is_empty() {
+                query.push(("contracts", selector.contract_ids.join(",")));
+            }
+            if let Some(tag) = selector.tag {
+                query.push(("tag", tag.to_string()));
+            }
+            if let Some(category) = selector.category {
+                query.push(("category", category.to_string()));
+            }
+            if let Some(network) = selector.network {
+                query.push(("network", network.to_string()));
+            }
+            format!("{}/api/events", api_url)
+        }
+    };
+
     let response = client
         .get(&url)
+        .query(&query)
         .send()
         .await
         .map_err(|e| anyhow::anyhow!("Failed to fetch events: {}", e))?;
@@ -119,45 +255,34 @@ pub async fn query_events(
 
     let events: Vec<ContractEvent> = response.json().await?;
 
-    if let Some(path) = export_path {
-        let mut csv = String::from(
-            "id,contract_id,topic,data,ledger_sequence,transaction_hash,timestamp,network\n",
-        );
-
-        for event in &events {
-            let data_str = event
-                .data
-                .as_ref()
-                .map(|d| serde_json::to_string(d).unwrap_or_default())
-                .unwrap_or_default();
-
-            csv.push_str(&format!(
-                "{},{},{},{},{},{},{},{}\n",
-                event.id,
-                event.contract_id,
-                event.topic,
-                data_str.replace("\"", "\"\""),
-                event.ledger_sequence,
-                event.transaction_hash.as_deref().unwrap_or(""),
-                event.timestamp,
-                event.network
-            ));
-        }
-
-        std::fs::write(path, csv)?;
+    let merged = selector.single().is_none();
+    if merged {
+        let mut contracts: Vec<&str> = events.iter().map(|e| e.contract_id.as_str()).collect();
+        contracts.sort_unstable();
+        contracts.dedup();
         println!(
-            "{} Exported {} events to {}",
-            "✓".green(),
-            events.len(),
-            path
+            "\n{}",
+            format!(
+                "Found {} event(s) across {} contract(s)",
+                events.len(),
+                contracts.len()
+            )
+            .bold()
         );
-        return Ok(());
+    } else {
+        println!("\n{}", format!("Found {} event(s)", events.len()).bold());
     }
 
-    println!("\n{}", format!("Found {} event(s)", events.len()).bold());
-
     for event in &events {
         println!("\n{} {}", "●".cyan(), event.topic.bold().yellow());
+        if merged {
+            println!(
+                "  {}: {} {}",
+                "Contract".bold(),
+                event.contract_name.as_deref().unwrap_or("?"),
+                format!("({})", event.contract_id).bright_black()
+            );
+        }
         println!(
             "  {}: {}",
             "Ledger".bold(),
@@ -191,3 +316,86 @@ pub async fn query_events(
 
     Ok(())
 }
+
+pub async fn query_anomalies(
+    api_url: &str,
+    contract_id: &str,
+    kind: Option<&str>,
+    limit: i64,
+) -> Result<()> {
+    println!("\n{}", "Event Anomalies".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+
+    let mut query = vec![("limit", limit.to_string())];
+    if let Some(kind) = kind {
+        query.push(("kind", kind.to_string()));
+    }
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/anomalies",
+            api_url, contract_id
+        ))
+        .query(&query)
+        .send()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to fetch anomalies: {}", e))?;
+
+    if !response.status().is_success() {
+        let error = response.text().await?;
+        anyhow::bail!("API error: {}", error);
+    }
+
+    let result: ContractAnomalies = response.json().await?;
+    println!(
+        "  {}: {} [{}]",
+        "Contract ID".bold(),
+        result.contract_id.bright_black(),
+        result.network
+    );
+
+    if result.anomalies.is_empty() {
+        println!("\n  {}", "No anomalies flagged.".green());
+    }
+
+    for anomaly in &result.anomalies {
+        let label = match anomaly.kind.as_str() {
+            "rate_spike" => "RATE SPIKE".red().bold(),
+            "large_transfer" => "LARGE TRANSFER".red().bold(),
+            "new_topic" => "NEW TOPIC".yellow().bold(),
+            other => other.to_uppercase().yellow().bold(),
+        };
+        println!("\n{} {}", "●".cyan(), label);
+        println!(
+            "  {}: {}",
+            "Detected".bold(),
+            anomaly.detected_at.bright_black()
+        );
+        if let Some(topic) = &anomaly.topic {
+            println!("  {}: {}", "Topic".bold(), topic.bright_magenta());
+        }
+        if let Some(ledger) = anomaly.ledger_sequence {
+            println!("  {}: {}", "Ledger".bold(), ledger);
+        }
+        match anomaly.kind.as_str() {
+            "rate_spike" => println!(
+                "  {}: {} events in the last hour (usually ~{}/h)",
+                "Volume".bold(),
+                anomaly.details["events_last_hour"],
+                anomaly.details["baseline_per_hour"]
+            ),
+            "large_transfer" => println!(
+                "  {}: {} ({}x the median of {})",
+                "Amount".bold(),
+                anomaly.details["amount"],
+                anomaly.details["multiple_of_median"],
+                anomaly.details["median_amount"]
+            ),
+            _ => {}
+        }
+    }
+
+    println!("\n{}", "=".repeat(80).cyan());
+    println!();
+
+    Ok(())
+}
@@ -9,22 +9,105 @@ use flate2::Compression;
 use tar::Builder;
 
 use crate::io_utils::{compute_sha256_streaming, BUF_SIZE};
-use crate::manifest::{ExportManifest, ManifestEntry};
+use crate::manifest::{Component, ExportManifest, ExportProfile, ManifestEntry, REGISTRY_DIR};
 
+/// A file fetched from the registry, stored under [`REGISTRY_DIR`].
+pub struct RegistryFile {
+    pub path: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Fetches the registry-held components `profile` includes: the ABI,
+/// benchmark results, and audit reports with their documents. A component
+/// the registry cannot provide is left out with a warning, so contracts
+/// without an ABI or audits can still be exported.
+pub async fn fetch_registry_files(
+    api_url: &str,
+    contract_id: &str,
+    profile: ExportProfile,
+) -> Vec<RegistryFile> {
+    let base = format!(
+        "{}/api/contracts/{}",
+        api_url.trim_end_matches('/'),
+        contract_id
+    );
+    let client = crate::http::client();
+    let fetch = |url: String| {
+        let request = client.get(url);
+        async move {
+            let response = request.send().await?.error_for_status()?;
+            anyhow::Ok(response.bytes().await?.to_vec())
+        }
+    };
+    let mut files = Vec::new();
+    let mut keep = |path: &str, result: Result<Vec<u8>>, what: &str| match result {
+        Ok(bytes) => files.push(RegistryFile {
+            path: format!("{}/{}", REGISTRY_DIR, path),
+            bytes,
+        }),
+        Err(err) => eprintln!("  ⚠ {} not exported: {}", what, err),
+    };
+
+    if profile.includes(Component::Abi) {
+        keep("abi.json", fetch(format!("{}/abi", base)).await, "ABI");
+    }
+    if profile.includes(Component::Benchmarks) {
+        let benchmarks = fetch(format!("{}/perf/benchmarks?limit=100", base)).await;
+        keep("benchmarks.json", benchmarks, "Benchmarks");
+    }
+    if profile.includes(Component::AuditReports) {
+        let audits = fetch(format!("{}/audits", base)).await;
+        let listed: Vec<serde_json::Value> = audits
+            .as_ref()
+            .ok()
+            .and_then(|bytes| serde_json::from_slice(bytes).ok())
+            .unwrap_or_default();
+        keep("audits.json", audits, "Audit list");
+        for audit in listed {
+            let Some(id) = audit["id"].as_str() else {
+                continue;
+            };
+            let extension = match audit["report_format"].as_str() {
+                Some("pdf") => "pdf",
+                _ => "md",
+            };
+            let report = fetch(format!("{}/audits/{}/report", base, id)).await;
+            keep(
+                &format!("audits/{}.{}", id, extension),
+                report,
+                &format!("Audit report {}", id),
+            );
+        }
+    }
+    files
+}
+
+/// Writes an export archive of the parts of `contract_dir` that `profile`
+/// includes, plus `registry_files`, signed with `sign_key` if given, and
+/// returns its manifest.
+#[allow(clippy::too_many_arguments)]
 pub fn create_archive(
     contract_dir: &Path,
     output_path: &Path,
     contract_id: &str,
     name: &str,
     network: &str,
-) -> Result<()> {
+    profile: ExportProfile,
+    registry_files: &[RegistryFile],
+    sign_key: Option<&str>,
+) -> Result<ExportManifest> {
     let tmp_dir = tempfile::tempdir().context("failed to create temp dir")?;
     let inner_path = tmp_dir.path().join("contract.tar.gz");
 
-    let mut manifest = ExportManifest::new(contract_id.into(), name.into(), network.into());
+    let mut manifest =
+        ExportManifest::new(contract_id.into(), name.into(), network.into(), profile);
 
-    build_inner_archive(contract_dir, &inner_path, &mut manifest)?;
+    build_inner_archive(contract_dir, &inner_path, &mut manifest, registry_files)?;
     manifest.sha256 = compute_sha256_streaming(&inner_path)?;
+    if let Some(key) = sign_key {
+        let signature = crate::package_signing::sign_archive(&manifest, key)?;
+        manifest = manifest.with_signature(signature);
+    }
 
     let manifest_path = tmp_dir.path().join("manifest.json");
     let manifest_json = serde_json::to_vec_pretty(&manifest)?;
@@ -32,19 +115,32 @@ pub fn create_archive(
 
     build_outer_archive(output_path, &manifest_path, &inner_path)?;
 
-    Ok(())
+    Ok(manifest)
 }
 
 fn build_inner_archive(
     source_dir: &Path,
     archive_path: &Path,
     manifest: &mut ExportManifest,
+    registry_files: &[RegistryFile],
 ) -> Result<()> {
     let file = BufWriter::new(File::create(archive_path)?);
     let encoder = GzEncoder::new(file, Compression::default());
     let mut builder = Builder::new(encoder);
 
     walk_and_append(&mut builder, source_dir, source_dir, manifest)?;
+    for file in registry_files {
+        manifest.contents.push(ManifestEntry {
+            path: file.path.clone(),
+            size: file.bytes.len() as u64,
+            modified_at: Utc::now(),
+        });
+        let mut header = tar::Header::new_gnu();
+        header.set_size(file.bytes.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, &file.path, file.bytes.as_slice())?;
+    }
 
     let encoder = builder.into_inner()?;
     encoder.finish()?;
@@ -63,10 +159,16 @@ fn walk_and_append<W: Write>(
         let entry = entry?;
         let path = entry.path();
         let rel = path.strip_prefix(base).unwrap_or(&path);
+        let rel_name = rel.to_string_lossy().replace('\\', "/");
 
         if path.is_dir() {
-            walk_and_append(builder, base, &path, manifest)?;
-        } else {
+            let skip = rel_name == ".git"
+                || rel_name == REGISTRY_DIR
+                || (rel_name == "target" && !manifest.profile.includes(Component::Wasm));
+            if !skip {
+                walk_and_append(builder, base, &path, manifest)?;
+            }
+        } else if Component::of(&rel_name).is_some_and(|c| manifest.profile.includes(c)) {
             let metadata = entry.metadata()?;
             let modified: DateTime<Utc> = metadata
                 .modified()
@@ -80,7 +182,7 @@ fn walk_and_append<W: Write>(
                 .unwrap_or_else(Utc::now);
 
             manifest.contents.push(ManifestEntry {
-                path: rel.to_string_lossy().replace('\\', "/"),
+                path: rel_name.clone(),
                 size: metadata.len(),
                 modified_at: modified,
             });
@@ -91,7 +193,7 @@ fn walk_and_append<W: Write>(
             header.set_cksum();
 
             let f = BufReader::new(File::open(&path)?);
-            builder.append_data(&mut header, rel.to_string_lossy().replace('\\', "/"), f)?;
+            builder.append_data(&mut header, &rel_name, f)?;
         }
     }
     Ok(())
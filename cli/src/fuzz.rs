@@ -888,7 +888,7 @@ impl Clone for Fuzzer {
     }
 }
 
-fn parse_duration(s: &str) -> Result<Duration> {
+pub(crate) fn parse_duration(s: &str) -> Result<Duration> {
     let s = s.trim();
     let (num, unit) = if let Some(n) = s.strip_suffix("ms") {
         (n, "ms")
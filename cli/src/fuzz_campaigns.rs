@@ -0,0 +1,262 @@
+//! fuzz_campaigns.rs — `soroban-registry fuzz attest <contract>`
+//!
+//! Reports a fuzzing campaign for a contract build. The summary comes from a
+//! `fuzz-report.json` written by `soroban-registry fuzz`, or from flags for
+//! campaigns run with cargo-fuzz; the `shared::fuzz_report` statement is
+//! signed locally with the publisher's secret key and only the signature is
+//! sent. The registry attaches the campaign to the version with that build.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use ed25519_dalek::Signer;
+use serde_json::{json, Value};
+use shared::fuzz_report::FuzzCampaignStatement;
+
+use crate::claim::{decode_secret_key, encode_strkey, VERSION_ACCOUNT};
+use crate::fuzz::FuzzReport;
+
+/// Engine recorded for campaigns read from a `fuzz-report.json`.
+const BUILTIN_ENGINE: &str = "soroban-registry";
+
+pub struct AttestOptions<'a> {
+    pub contract_id: &'a str,
+    /// `fuzz-report.json` from `soroban-registry fuzz`
+    pub from_report: Option<&'a str>,
+    pub engine: Option<&'a str>,
+    pub targets: Option<u32>,
+    pub executions: Option<u64>,
+    /// e.g. "30m" or "2h"
+    pub duration: Option<&'a str>,
+    pub crashes: Option<u32>,
+    pub wasm_hash: Option<&'a str>,
+    pub secret_key: &'a str,
+    pub json: bool,
+}
+
+/// Campaign figures before they are bound to a contract build.
+#[derive(Debug, PartialEq)]
+struct CampaignSummary {
+    engine: String,
+    targets: u32,
+    executions: u64,
+    duration_secs: u64,
+    crashes: u32,
+    finished_at: DateTime<Utc>,
+}
+
+fn from_report(report: &FuzzReport) -> Result<CampaignSummary> {
+    let start = DateTime::parse_from_rfc3339(&report.start_time)
+        .context("Fuzz report has an invalid start_time")?;
+    let end = DateTime::parse_from_rfc3339(&report.end_time)
+        .context("Fuzz report has an invalid end_time")?;
+    Ok(CampaignSummary {
+        engine: BUILTIN_ENGINE.to_string(),
+        targets: u32::try_from(report.functions_tested)?,
+        executions: report.total_cases,
+        duration_secs: (end - start).num_seconds().max(0) as u64,
+        crashes: u32::try_from(report.crashes.len())?,
+        finished_at: end.with_timezone(&Utc),
+    })
+}
+
+/// Figures from the report (if any), overridden by explicit flags.
+fn summarize(opts: &AttestOptions) -> Result<CampaignSummary> {
+    let report = match opts.from_report {
+        Some(path) => {
+            let raw = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read fuzz report from {}", path))?;
+            let report: FuzzReport = serde_json::from_str(&raw)
+                .with_context(|| format!("{} is not a soroban-registry fuzz report", path))?;
+            Some(from_report(&report)?)
+        }
+        None => None,
+    };
+    let duration_secs = match opts.duration {
+        Some(duration) => Some(crate::fuzz::parse_duration(duration)?.as_secs()),
+        None => report.as_ref().map(|r| r.duration_secs),
+    };
+    let (Some(targets), Some(executions), Some(duration_secs)) = (
+        opts.targets.or(report.as_ref().map(|r| r.targets)),
+        opts.executions.or(report.as_ref().map(|r| r.executions)),
+        duration_secs,
+    ) else {
+        bail!("Pass --from-report, or all of --targets, --executions and --duration");
+    };
+    Ok(CampaignSummary {
+        engine: opts
+            .engine
+            .map(str::to_string)
+            .or(report.as_ref().map(|r| r.engine.clone()))
+            .unwrap_or_else(|| "cargo-fuzz".to_string()),
+        targets,
+        executions,
+        duration_secs,
+        crashes: opts
+            .crashes
+            .or(report.as_ref().map(|r| r.crashes))
+            .unwrap_or(0),
+        finished_at: report.map(|r| r.finished_at).unwrap_or_else(Utc::now),
+    })
+}
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+pub async fn attest(api_url: &str, opts: AttestOptions<'_>) -> Result<()> {
+    let summary = summarize(&opts)?;
+
+    let base_url = api_url.trim_end_matches('/');
+    let signing_key = decode_secret_key(opts.secret_key)?;
+    let address = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+    let client = crate::http::client();
+
+    let response = client
+        .get(format!("{}/api/contracts/{}", base_url, opts.contract_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let contract = check(response).await?;
+    let field = |name: &str| -> Result<String> {
+        contract[name]
+            .as_str()
+            .map(str::to_string)
+            .with_context(|| format!("Contract record is missing {}", name))
+    };
+    let (uuid, onchain_id, network) = (field("id")?, field("contract_id")?, field("network")?);
+    // Without --wasm-hash the campaign is for the contract's current build.
+    let wasm_hash = match opts.wasm_hash {
+        Some(hash) => hash.to_string(),
+        None => field("wasm_hash")?,
+    };
+
+    let statement = FuzzCampaignStatement {
+        network: &network,
+        contract_id: &onchain_id,
+        wasm_hash: &wasm_hash,
+        engine: &summary.engine,
+        targets: summary.targets,
+        executions: summary.executions,
+        duration_secs: summary.duration_secs,
+        crashes: summary.crashes,
+        finished_at: summary.finished_at,
+    };
+    statement.validate().map_err(anyhow::Error::msg)?;
+    let signature = hex::encode(
+        signing_key
+            .sign(statement.signing_message().as_bytes())
+            .to_bytes(),
+    );
+
+    let response = client
+        .post(format!(
+            "{}/api/contracts/{}/fuzz-campaigns",
+            base_url, uuid
+        ))
+        .json(&json!({
+            "wasm_hash": wasm_hash,
+            "engine": summary.engine,
+            "targets": summary.targets,
+            "executions": summary.executions,
+            "duration_secs": summary.duration_secs,
+            "crashes": summary.crashes,
+            "finished_at": summary.finished_at,
+            "signature": signature,
+        }))
+        .send()
+        .await
+        .context("Failed to submit fuzzing campaign")?;
+    let campaign = check(response).await?;
+
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&campaign)?);
+        return Ok(());
+    }
+    let outcome = if summary.crashes == 0 {
+        "✓ Fuzzing campaign recorded".green().bold()
+    } else {
+        "✓ Fuzzing campaign recorded (with crashes)".yellow().bold()
+    };
+    println!("\n{}", outcome);
+    println!(
+        "  {}: {} {}",
+        "Version".bold(),
+        campaign["version"].as_str().unwrap_or("?"),
+        wasm_hash.bright_black()
+    );
+    println!(
+        "  {}: {} targets, {} executions in {}s, {} crashes",
+        summary.engine.bold(),
+        summary.targets,
+        summary.executions,
+        summary.duration_secs,
+        if summary.crashes > 0 {
+            summary.crashes.to_string().red()
+        } else {
+            summary.crashes.to_string().green()
+        }
+    );
+    println!("  {}: {}\n", "Signed by".bold(), address.bright_magenta());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> AttestOptions<'static> {
+        AttestOptions {
+            contract_id: "CABC",
+            from_report: None,
+            engine: None,
+            targets: None,
+            executions: None,
+            duration: None,
+            crashes: None,
+            wasm_hash: None,
+            secret_key: "",
+            json: false,
+        }
+    }
+
+    #[test]
+    fn reads_builtin_fuzzer_reports() {
+        let report = FuzzReport {
+            contract_path: "token.wasm".to_string(),
+            start_time: "2026-04-01T00:00:00+00:00".to_string(),
+            end_time: "2026-04-01T00:30:00+00:00".to_string(),
+            total_cases: 50_000,
+            crashes: Vec::new(),
+            coverage_percent: 80.0,
+            functions_tested: 6,
+            total_functions: 10,
+        };
+        let summary = from_report(&report).unwrap();
+        assert_eq!(summary.engine, BUILTIN_ENGINE);
+        assert_eq!(
+            (summary.targets, summary.executions, summary.duration_secs),
+            (6, 50_000, 1800)
+        );
+    }
+
+    #[test]
+    fn flags_need_targets_executions_and_duration() {
+        assert!(summarize(&options()).is_err());
+        let summary = summarize(&AttestOptions {
+            targets: Some(3),
+            executions: Some(1_000_000),
+            duration: Some("1h"),
+            ..options()
+        })
+        .unwrap();
+        assert_eq!(summary.engine, "cargo-fuzz");
+        assert_eq!((summary.duration_secs, summary.crashes), (3600, 0));
+    }
+}
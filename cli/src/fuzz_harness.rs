@@ -0,0 +1,448 @@
+//! fuzz_harness.rs — `soroban-registry fuzz init <contract>`
+//!
+//! Generates a cargo-fuzz project with one fuzz target per public contract
+//! function. Each target derives `Arbitrary` for the function's arguments
+//! through the SDK's `SorobanArbitrary` prototypes, registers the contract in
+//! a fresh `Env` and calls it through the generated client's `try_` method,
+//! so contract errors are ordinary outcomes and only panics are findings.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use contract_abi::{ContractABI, ContractFunction, SorobanType};
+use std::fs;
+use std::path::Path;
+
+pub const DEFAULT_SDK_VERSION: &str = "21";
+
+const GITIGNORE: &str = "target\ncorpus\nartifacts\ncoverage\n";
+
+/// What the harness needs to know about the contract crate under test.
+struct HarnessCrate<'a> {
+    /// Cargo package name, e.g. "my-token"
+    package: &'a str,
+    /// The `#[contract]` struct, e.g. "MyToken"; its client is `MyTokenClient`
+    contract_type: &'a str,
+}
+
+impl HarnessCrate<'_> {
+    fn ident(&self) -> String {
+        self.package.replace('-', "_")
+    }
+}
+
+fn is_ident(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// "my token v2" -> "my-token-v2"
+fn default_package(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_ascii_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// "my-token v2" -> "MyTokenV2"
+fn default_contract_type(name: &str) -> String {
+    name.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Rust type of a contract argument, or `None` when it cannot be generated
+/// (results, void, malformed custom type names).
+fn rust_type(ty: &SorobanType, crate_ident: &str) -> Option<String> {
+    let sdk = |name: &str| format!("soroban_sdk::{}", name);
+    Some(match ty {
+        SorobanType::Bool => "bool".to_string(),
+        SorobanType::I32 => "i32".to_string(),
+        SorobanType::I64 => "i64".to_string(),
+        SorobanType::I128 => "i128".to_string(),
+        SorobanType::U32 => "u32".to_string(),
+        SorobanType::U64 => "u64".to_string(),
+        SorobanType::U128 => "u128".to_string(),
+        SorobanType::I256 => sdk("I256"),
+        SorobanType::U256 => sdk("U256"),
+        SorobanType::Symbol => sdk("Symbol"),
+        SorobanType::String => sdk("String"),
+        SorobanType::Bytes => sdk("Bytes"),
+        SorobanType::BytesN { n } => format!("soroban_sdk::BytesN<{}>", n),
+        SorobanType::Address => sdk("Address"),
+        SorobanType::Timepoint => sdk("Timepoint"),
+        SorobanType::Duration => sdk("Duration"),
+        SorobanType::Option { value_type } => {
+            format!("Option<{}>", rust_type(value_type, crate_ident)?)
+        }
+        SorobanType::Vec { element_type } => {
+            format!(
+                "soroban_sdk::Vec<{}>",
+                rust_type(element_type, crate_ident)?
+            )
+        }
+        SorobanType::Map {
+            key_type,
+            value_type,
+        } => format!(
+            "soroban_sdk::Map<{}, {}>",
+            rust_type(key_type, crate_ident)?,
+            rust_type(value_type, crate_ident)?
+        ),
+        SorobanType::Tuple { elements } => {
+            let elements = elements
+                .iter()
+                .map(|e| rust_type(e, crate_ident))
+                .collect::<Option<Vec<_>>>()?;
+            format!("({},)", elements.join(", "))
+        }
+        SorobanType::Struct { name, .. }
+        | SorobanType::Enum { name, .. }
+        | SorobanType::Custom { name } => {
+            if !is_ident(name) {
+                return None;
+            }
+            format!("{}::{}", crate_ident, name)
+        }
+        SorobanType::Void | SorobanType::Result { .. } => return None,
+    })
+}
+
+fn uses_contract_types(ty: &SorobanType) -> bool {
+    match ty {
+        SorobanType::Struct { .. } | SorobanType::Enum { .. } | SorobanType::Custom { .. } => true,
+        SorobanType::Option { value_type } => uses_contract_types(value_type),
+        SorobanType::Vec { element_type } => uses_contract_types(element_type),
+        SorobanType::Map {
+            key_type,
+            value_type,
+        } => uses_contract_types(key_type) || uses_contract_types(value_type),
+        SorobanType::Tuple { elements } => elements.iter().any(uses_contract_types),
+        _ => false,
+    }
+}
+
+/// Source of `fuzz_targets/<function>.rs`, or `None` if an argument type
+/// cannot be generated.
+fn fuzz_target(func: &ContractFunction, krate: &HarnessCrate) -> Option<String> {
+    let crate_ident = krate.ident();
+    let contract = krate.contract_type;
+    let params = func
+        .params
+        .iter()
+        .map(|p| {
+            let ty = rust_type(&p.param_type, &crate_ident)?;
+            is_ident(&p.name).then_some((p.name.as_str(), ty))
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    let mut src = String::from("#![no_main]\n\nuse libfuzzer_sys::fuzz_target;\n");
+    if params.is_empty() {
+        src.push_str(&format!(
+            "use soroban_sdk::Env;\nuse {crate_ident}::{{{contract}, {contract}Client}};\n\n\
+             fuzz_target!(|_data: &[u8]| {{\n"
+        ));
+    } else {
+        src.push_str(&format!(
+            "use soroban_sdk::testutils::arbitrary::{{arbitrary, Arbitrary, SorobanArbitrary}};\n\
+             use soroban_sdk::{{Env, IntoVal}};\n\
+             use {crate_ident}::{{{contract}, {contract}Client}};\n\n\
+             #[derive(Arbitrary, Debug)]\nstruct Input {{\n"
+        ));
+        for (name, ty) in &params {
+            src.push_str(&format!(
+                "    {}: <{} as SorobanArbitrary>::Prototype,\n",
+                name, ty
+            ));
+        }
+        src.push_str("}\n\nfuzz_target!(|input: Input| {\n");
+    }
+    src.push_str(&format!(
+        "    let env = Env::default();\n    \
+         env.mock_all_auths();\n    \
+         let contract_id = env.register_contract(None, {contract});\n    \
+         let client = {contract}Client::new(&env, &contract_id);\n\n"
+    ));
+    for (name, ty) in &params {
+        src.push_str(&format!(
+            "    let {name}: {ty} = input.{name}.into_val(&env);\n"
+        ));
+    }
+    let args = params
+        .iter()
+        .map(|(name, _)| format!("&{}", name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    src.push_str(&format!(
+        "    // Contract errors are expected outcomes; only panics are findings.\n    \
+         let _ = client.try_{}({});\n}});\n",
+        func.name, args
+    ));
+    Some(src)
+}
+
+fn cargo_manifest(
+    krate: &HarnessCrate,
+    sdk_version: &str,
+    targets: &[&str],
+    with_testutils: bool,
+) -> String {
+    let contract_features = if with_testutils {
+        ", features = [\"testutils\"]"
+    } else {
+        ""
+    };
+    let mut manifest = format!(
+        "[package]\n\
+         name = \"{package}-fuzz\"\n\
+         version = \"0.0.0\"\n\
+         publish = false\n\
+         edition = \"2021\"\n\n\
+         [package.metadata]\n\
+         cargo-fuzz = true\n\n\
+         [dependencies]\n\
+         libfuzzer-sys = \"0.4\"\n\
+         soroban-sdk = {{ version = \"{sdk_version}\", features = [\"testutils\"] }}\n\
+         {package} = {{ path = \"..\"{contract_features} }}\n",
+        package = krate.package,
+    );
+    for target in targets {
+        manifest.push_str(&format!(
+            "\n[[bin]]\n\
+             name = \"{target}\"\n\
+             path = \"fuzz_targets/{target}.rs\"\n\
+             test = false\n\
+             doc = false\n\
+             bench = false\n"
+        ));
+    }
+    manifest
+}
+
+/// The contract's ABI and display name, from a local `.wasm`/JSON spec or
+/// the registry.
+async fn load_abi(api_url: &str, contract: &str, version: Option<&str>) -> Result<ContractABI> {
+    let path = Path::new(contract);
+    if path.is_file() {
+        let name = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .unwrap_or("contract");
+        let json = crate::commands::load_abi_json(contract)?;
+        return contract_abi::parse_json_spec(&json, name)
+            .map_err(|e| anyhow::anyhow!("Failed to parse contract spec: {}", e));
+    }
+
+    let base_url = api_url.trim_end_matches('/');
+    let client = crate::http::client();
+    let response = client
+        .get(format!("{}/api/contracts/{}", base_url, contract))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    if !response.status().is_success() {
+        anyhow::bail!("Contract {} not found ({})", contract, response.status());
+    }
+    let metadata: serde_json::Value = response.json().await?;
+    let name = metadata["name"].as_str().unwrap_or(contract).to_string();
+
+    let mut request = client.get(format!("{}/api/contracts/{}/abi", base_url, contract));
+    if let Some(version) = version {
+        request = request.query(&[("version", version)]);
+    }
+    let response = request.send().await.context("Failed to fetch ABI")?;
+    if !response.status().is_success() {
+        anyhow::bail!(
+            "No ABI is available for {} ({})",
+            contract,
+            response.status()
+        );
+    }
+    let body: serde_json::Value = response.json().await?;
+    contract_abi::parse_json_spec(&body["abi"].to_string(), &name)
+        .map_err(|e| anyhow::anyhow!("Failed to parse contract spec: {}", e))
+}
+
+pub async fn init(
+    api_url: &str,
+    contract: &str,
+    version: Option<&str>,
+    output: &str,
+    package: Option<&str>,
+    contract_type: Option<&str>,
+    sdk_version: &str,
+) -> Result<()> {
+    let out_dir = Path::new(output);
+    if out_dir.exists() {
+        anyhow::bail!("Destination {} already exists", out_dir.display());
+    }
+
+    let abi = load_abi(api_url, contract, version).await?;
+    let package = package
+        .map(str::to_string)
+        .unwrap_or_else(|| default_package(&abi.name));
+    let contract_type = contract_type
+        .map(str::to_string)
+        .unwrap_or_else(|| default_contract_type(&abi.name));
+    if package.is_empty() || !is_ident(&package.replace('-', "_")) {
+        anyhow::bail!(
+            "Cannot derive a crate name from '{}'; pass --crate",
+            abi.name
+        );
+    }
+    if !is_ident(&contract_type) {
+        anyhow::bail!(
+            "Cannot derive the contract type from '{}'; pass --contract-type",
+            abi.name
+        );
+    }
+    let krate = HarnessCrate {
+        package: &package,
+        contract_type: &contract_type,
+    };
+
+    let mut targets = Vec::new();
+    let mut skipped = Vec::new();
+    let mut with_testutils = false;
+    // `__constructor` and other reserved entry points are not fuzzed directly.
+    for func in abi.public_functions().filter(|f| !f.name.starts_with("__")) {
+        match fuzz_target(func, &krate) {
+            Some(source) => {
+                with_testutils |= func
+                    .params
+                    .iter()
+                    .any(|p| uses_contract_types(&p.param_type));
+                targets.push((func.name.as_str(), source));
+            }
+            None => skipped.push(func.name.as_str()),
+        }
+    }
+    if targets.is_empty() {
+        anyhow::bail!(
+            "{} has no functions a fuzz target can be generated for",
+            abi.name
+        );
+    }
+
+    let target_dir = out_dir.join("fuzz_targets");
+    fs::create_dir_all(&target_dir)
+        .with_context(|| format!("Failed to create {}", target_dir.display()))?;
+    let names: Vec<&str> = targets.iter().map(|(name, _)| *name).collect();
+    fs::write(
+        out_dir.join("Cargo.toml"),
+        cargo_manifest(&krate, sdk_version, &names, with_testutils),
+    )?;
+    fs::write(out_dir.join(".gitignore"), GITIGNORE)?;
+    for (name, source) in &targets {
+        fs::write(target_dir.join(format!("{}.rs", name)), source)?;
+    }
+
+    println!("{}", "✓ Fuzz harness created".green().bold());
+    println!("  {}: {}", "Path".bold(), out_dir.display());
+    println!(
+        "  {}: {} ({}Client)",
+        "Contract".bold(),
+        package,
+        contract_type
+    );
+    println!("  {}: {}", "Targets".bold(), names.join(", "));
+    if !skipped.is_empty() {
+        println!(
+            "  {}: {} {}",
+            "Skipped".bold(),
+            skipped.join(", "),
+            "(unsupported argument types)".bright_black()
+        );
+    }
+    println!(
+        "\n  {} The contract crate must build as an `rlib` (crate-type = [\"cdylib\", \"rlib\"]).",
+        "→".bright_black()
+    );
+    if with_testutils {
+        println!(
+            "  {} Custom argument types need a `testutils = [\"soroban-sdk/testutils\"]` feature on the contract crate.",
+            "→".bright_black()
+        );
+    }
+    println!("\n  cargo +nightly fuzz run {}\n", names[0]);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use contract_abi::{FunctionParam, FunctionVisibility};
+
+    fn function(name: &str, params: Vec<(&str, SorobanType)>) -> ContractFunction {
+        ContractFunction {
+            name: name.to_string(),
+            visibility: FunctionVisibility::Public,
+            params: params
+                .into_iter()
+                .map(|(name, param_type)| FunctionParam {
+                    name: name.to_string(),
+                    param_type,
+                    doc: None,
+                })
+                .collect(),
+            return_type: SorobanType::Void,
+            doc: None,
+            is_mutable: true,
+        }
+    }
+
+    #[test]
+    fn generates_arbitrary_input_per_argument() {
+        let krate = HarnessCrate {
+            package: "my-token",
+            contract_type: "MyToken",
+        };
+        let transfer = function(
+            "transfer",
+            vec![
+                ("to", SorobanType::Address),
+                (
+                    "memo",
+                    SorobanType::Option {
+                        value_type: Box::new(SorobanType::Custom {
+                            name: "Memo".to_string(),
+                        }),
+                    },
+                ),
+                ("amount", SorobanType::I128),
+            ],
+        );
+        let src = fuzz_target(&transfer, &krate).unwrap();
+        assert!(src.contains("use my_token::{MyToken, MyTokenClient};"));
+        assert!(src.contains("memo: <Option<my_token::Memo> as SorobanArbitrary>::Prototype,"));
+        assert!(src.contains("let amount: i128 = input.amount.into_val(&env);"));
+        assert!(src.contains("let _ = client.try_transfer(&to, &memo, &amount);"));
+
+        let unsupported = function(
+            "settle",
+            vec![(
+                "outcome",
+                SorobanType::Result {
+                    ok_type: Box::new(SorobanType::U32),
+                    err_type: Box::new(SorobanType::U32),
+                },
+            )],
+        );
+        assert!(fuzz_target(&unsupported, &krate).is_none());
+    }
+
+    #[test]
+    fn derives_crate_and_type_names() {
+        assert_eq!(default_package("My Token v2"), "my-token-v2");
+        assert_eq!(default_contract_type("my-token v2"), "MyTokenV2");
+    }
+}
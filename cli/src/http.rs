@@ -0,0 +1,294 @@
+//! http.rs — shared HTTP client construction
+//!
+//! Commands build their `reqwest` clients through [`client`] / [`builder`] so
+//! the TLS settings of the selected `--profile` (a client certificate for
+//! registries running in mTLS mode, and an optional private CA) apply to
+//! every request the CLI makes.
+//!
+//! Write requests that must not be applied twice go through
+//! [`send_idempotent`], which tags them with an `Idempotency-Key` and retries
+//! transport failures with the same key.
+//!
+//! Responses are negotiated gzip or brotli through `Accept-Encoding` and
+//! decompressed transparently. Large uploads go through [`send_compressed`],
+//! which gzips the body and resends it as-is if the registry answers 415.
+//! Large downloads (registry dumps, event exports) go through [`download`],
+//! which writes the body to disk as it arrives and reports progress.
+//!
+//! [`size_error`] explains the registry's size refusals (413 for a body over
+//! the route's limit, 507 for an upload over the publisher's storage quota).
+
+use crate::config::ProfileSection;
+use anyhow::{Context, Result};
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use std::io::{IsTerminal, Write};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+const IDEMPOTENCY_KEY_HEADER: &str = "Idempotency-Key";
+const IDEMPOTENT_ATTEMPTS: u32 = 3;
+/// Smaller bodies are not worth compressing.
+const COMPRESS_MIN_BYTES: usize = 64 * 1024;
+/// How often [`download`] redraws its progress line.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+#[derive(Clone, Default)]
+struct ClientTls {
+    identity: Option<reqwest::Identity>,
+    ca: Option<reqwest::Certificate>,
+}
+
+static TLS: OnceLock<ClientTls> = OnceLock::new();
+
+/// Load the client certificate / CA referenced by `profile`. Call once, before
+/// any command runs.
+pub fn configure(profile: &ProfileSection) -> Result<()> {
+    let identity = match (&profile.client_cert, &profile.client_key) {
+        (Some(cert), Some(key)) => {
+            let mut pem = std::fs::read(cert)
+                .with_context(|| format!("Failed to read client certificate {:?}", cert))?;
+            pem.push(b'\n');
+            pem.extend(
+                std::fs::read(key)
+                    .with_context(|| format!("Failed to read client key {:?}", key))?,
+            );
+            Some(
+                reqwest::Identity::from_pem(&pem)
+                    .context("Client certificate or key is not valid PEM")?,
+            )
+        }
+        (None, None) => None,
+        _ => anyhow::bail!("Profile must set both client_cert and client_key"),
+    };
+
+    let ca = match &profile.ca_cert {
+        Some(path) => {
+            let pem = std::fs::read(path)
+                .with_context(|| format!("Failed to read CA certificate {:?}", path))?;
+            Some(reqwest::Certificate::from_pem(&pem).context("CA certificate is not valid PEM")?)
+        }
+        None => None,
+    };
+
+    let _ = TLS.set(ClientTls { identity, ca });
+    Ok(())
+}
+
+/// A client builder preloaded with the active profile's TLS settings.
+pub fn builder() -> reqwest::ClientBuilder {
+    let mut builder = reqwest::Client::builder();
+    if let Some(tls) = TLS.get() {
+        if let Some(identity) = &tls.identity {
+            builder = builder.identity(identity.clone());
+        }
+        if let Some(ca) = &tls.ca {
+            builder = builder.add_root_certificate(ca.clone());
+        }
+    }
+    builder
+}
+
+/// Drop-in replacement for `reqwest::Client::new()`.
+pub fn client() -> reqwest::Client {
+    builder()
+        .build()
+        .expect("Failed to build HTTP client from profile TLS settings")
+}
+
+/// Send a create request with a fresh `Idempotency-Key`, retrying timeouts,
+/// connection failures and "still in progress" conflicts with the same key so
+/// the registry applies it at most once.
+pub async fn send_idempotent(
+    request: reqwest::RequestBuilder,
+) -> reqwest::Result<reqwest::Response> {
+    let request = request.header(IDEMPOTENCY_KEY_HEADER, uuid::Uuid::new_v4().to_string());
+    let mut attempt = 1;
+    loop {
+        // Streaming bodies cannot be replayed; send them once.
+        let Some(this_try) = request.try_clone() else {
+            return request.send().await;
+        };
+        let retry_after = match this_try.send().await {
+            Err(err) if attempt < IDEMPOTENT_ATTEMPTS && (err.is_timeout() || err.is_connect()) => {
+                log::warn!(
+                    "Request failed ({}); retrying with the same idempotency key",
+                    err
+                );
+                Duration::from_secs(u64::from(attempt))
+            }
+            Ok(resp)
+                if attempt < IDEMPOTENT_ATTEMPTS
+                    && resp.status() == reqwest::StatusCode::CONFLICT
+                    && resp.headers().contains_key(reqwest::header::RETRY_AFTER) =>
+            {
+                resp.headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|v| v.to_str().ok())
+                    .and_then(|v| v.parse::<u64>().ok())
+                    .map(Duration::from_secs)
+                    .unwrap_or(Duration::from_secs(1))
+            }
+            other => return other,
+        };
+        tokio::time::sleep(retry_after).await;
+        attempt += 1;
+    }
+}
+
+/// Send `body`, gzip-compressed when it is at least [`COMPRESS_MIN_BYTES`].
+pub async fn send_compressed(
+    request: reqwest::RequestBuilder,
+    body: Vec<u8>,
+) -> Result<reqwest::Response> {
+    if body.len() < COMPRESS_MIN_BYTES {
+        return Ok(request.body(body).send().await?);
+    }
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&body)?;
+    let gzipped = encoder.finish()?;
+    send_gzipped(request, gzipped, body).await
+}
+
+/// Send `gzipped`, the gzip encoding of `body`, with `Content-Encoding:
+/// gzip`. Registries that predate compressed uploads refuse them with 415;
+/// `body` then goes out uncompressed.
+pub async fn send_gzipped(
+    request: reqwest::RequestBuilder,
+    gzipped: Vec<u8>,
+    body: Vec<u8>,
+) -> Result<reqwest::Response> {
+    let fallback = request.try_clone();
+    let response = request
+        .header(reqwest::header::CONTENT_ENCODING, "gzip")
+        .body(gzipped)
+        .send()
+        .await?;
+    match fallback {
+        Some(fallback) if response.status() == reqwest::StatusCode::UNSUPPORTED_MEDIA_TYPE => {
+            log::debug!("Registry refused a gzip body; resending it uncompressed");
+            Ok(fallback.body(body).send().await?)
+        }
+        _ => Ok(response),
+    }
+}
+
+/// Write the body of `response` to `out` chunk by chunk, so it is never held
+/// in memory, and return the number of bytes written. While stderr is a
+/// terminal a progress line labelled `label` is shown, with a percentage when
+/// the registry announced the length. A body the registry aborted midway is
+/// an error, never a short file reported as complete.
+pub async fn download(
+    mut response: reqwest::Response,
+    out: &mut impl Write,
+    label: &str,
+) -> Result<u64> {
+    let total = response.content_length();
+    let show_progress = std::io::stderr().is_terminal();
+    let mut written: u64 = 0;
+    let mut last_drawn = Instant::now();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .with_context(|| format!("{} was interrupted after {} bytes", label, written))?
+    {
+        out.write_all(&chunk)?;
+        written += chunk.len() as u64;
+        if show_progress && last_drawn.elapsed() >= PROGRESS_INTERVAL {
+            draw_progress(label, written, total);
+            last_drawn = Instant::now();
+        }
+    }
+    out.flush()?;
+    if show_progress {
+        draw_progress(label, written, total);
+        eprintln!();
+    }
+    Ok(written)
+}
+
+fn draw_progress(label: &str, written: u64, total: Option<u64>) {
+    const MIB: f64 = 1024.0 * 1024.0;
+    let done = written as f64 / MIB;
+    match total.filter(|total| *total > 0) {
+        Some(total) => eprint!(
+            "\r{}: {:.1} / {:.1} MiB ({:.0}%)",
+            label,
+            done,
+            total as f64 / MIB,
+            written as f64 * 100.0 / total as f64
+        ),
+        None => eprint!("\r{}: {:.1} MiB", label, done),
+    }
+    let _ = std::io::stderr().flush();
+}
+
+fn mib(bytes: u64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+/// A readable explanation of a 413 or 507 response with body `body`, `None`
+/// for any other status.
+pub fn size_error(status: reqwest::StatusCode, body: &str) -> Option<String> {
+    let body: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+    let details = &body["details"];
+    match status {
+        reqwest::StatusCode::PAYLOAD_TOO_LARGE => Some(
+            match (
+                details["max_size_bytes"].as_u64(),
+                details["provided_size_bytes"].as_u64(),
+            ) {
+                (Some(max), Some(sent)) => format!(
+                    "Request too large: the registry accepts at most {} here, this one is {}",
+                    mib(max),
+                    mib(sent)
+                ),
+                _ => "Request too large for the registry's size limit on this endpoint".to_string(),
+            },
+        ),
+        reqwest::StatusCode::INSUFFICIENT_STORAGE => Some(
+            match (
+                details["quota_bytes"].as_u64(),
+                details["used_bytes"].as_u64(),
+                details["requested_bytes"].as_u64(),
+            ) {
+                (Some(quota), Some(used), Some(requested)) => format!(
+                    "Storage quota exceeded: {} of {} used, this upload needs {} more \
+                     (see `soroban-registry publisher usage`)",
+                    mib(used),
+                    mib(quota),
+                    mib(requested)
+                ),
+                _ => "Storage quota exceeded (see `soroban-registry publisher usage`)".to_string(),
+            },
+        ),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_size_refusals() {
+        let too_large = r#"{"details":{"max_size_bytes":5242880,"provided_size_bytes":7340032}}"#;
+        assert_eq!(
+            size_error(reqwest::StatusCode::PAYLOAD_TOO_LARGE, too_large).unwrap(),
+            "Request too large: the registry accepts at most 5.0 MiB here, this one is 7.0 MiB"
+        );
+        let over_quota =
+            r#"{"details":{"quota_bytes":1048576,"used_bytes":524288,"requested_bytes":1048576}}"#;
+        assert!(
+            size_error(reqwest::StatusCode::INSUFFICIENT_STORAGE, over_quota)
+                .unwrap()
+                .starts_with("Storage quota exceeded: 0.5 MiB of 1.0 MiB used")
+        );
+        assert!(size_error(
+            reqwest::StatusCode::PAYLOAD_TOO_LARGE,
+            "length limit exceeded"
+        )
+        .is_some());
+        assert!(size_error(reqwest::StatusCode::BAD_REQUEST, "{}").is_none());
+    }
+}
@@ -1,14 +1,219 @@
+//! import.rs — unpacking archives made by `soroban-registry export`
+//!
+//! An export archive is a tar.gz holding `manifest.json` and the contract's
+//! own `contract.tar.gz`, whose SHA-256 the manifest records. Both layers are
+//! scanned for unsafe entries before anything is written. [`preview`] reads
+//! the archive in memory for `import --list`, so an untrusted archive can be
+//! inspected without touching the disk; `--only` globs restrict extraction to
+//! matching paths.
+
+use std::cell::Cell;
+use std::collections::HashMap;
 use std::fs::{self, File};
-use std::io::BufReader;
+use std::io::{BufReader, Read};
 use std::path::Path;
 
 use anyhow::{bail, Context, Result};
 use chrono::Utc;
+use colored::Colorize;
+use flate2::read::GzDecoder;
+use sha2::{Digest, Sha256};
+use shared::archive_scan::{self, ArchiveLimits, ArchiveRejected, ArchiveReport};
+use tar::Archive;
+
+use crate::io_utils::{
+    compute_sha256_streaming, extract_tar_gz, extract_tar_gz_matching, BUF_SIZE,
+};
+use crate::manifest::{AuditEntry, Component, ExportManifest, ExportProfile};
+use crate::trust::{self, RegisteredKey, SignatureCheck, TrustPolicy};
+
+/// A file in the contract tarball.
+pub struct PreviewEntry {
+    pub path: String,
+    pub size: u64,
+    /// Size the manifest records for it; `None` when it is not listed
+    pub manifest_size: Option<u64>,
+    /// Whether the manifest's profile includes it; `import` skips it if not
+    pub in_profile: bool,
+}
+
+pub struct ImportPreview {
+    pub manifest: ExportManifest,
+    pub computed_sha256: String,
+    pub entries: Vec<PreviewEntry>,
+    /// Scan of the contract tarball
+    pub report: ArchiveReport,
+}
+
+impl ImportPreview {
+    pub fn verified(&self) -> bool {
+        self.computed_sha256 == self.manifest.sha256
+    }
+}
+
+/// Whether `path` matches any of the `--only` globs; no globs match
+/// everything. `*` and `?` stay within one path segment and `**` spans
+/// several. A glob without a `/` is matched against the file name alone, so
+/// `*.rs` selects Rust files at any depth.
+pub fn matches_any(globs: &[String], path: &Path) -> bool {
+    if globs.is_empty() {
+        return true;
+    }
+    let path = path.to_string_lossy().replace('\\', "/");
+    let name = path.rsplit('/').next().unwrap_or(&path);
+    globs.iter().any(|glob| {
+        let subject = if glob.contains('/') {
+            path.as_str()
+        } else {
+            name
+        };
+        glob_match(
+            &glob.chars().collect::<Vec<_>>(),
+            &subject.chars().collect::<Vec<_>>(),
+        )
+    })
+}
 
-use crate::io_utils::{compute_sha256_streaming, extract_tar_gz};
-use crate::manifest::{AuditEntry, ExportManifest};
+fn glob_match(pattern: &[char], path: &[char]) -> bool {
+    match pattern {
+        [] => path.is_empty(),
+        // `**/` matches no segments or any run of whole ones.
+        ['*', '*', '/', rest @ ..] => {
+            glob_match(rest, path)
+                || (0..path.len()).any(|i| path[i] == '/' && glob_match(rest, &path[i + 1..]))
+        }
+        ['*', '*', rest @ ..] => (0..=path.len()).any(|i| glob_match(rest, &path[i..])),
+        ['*', rest @ ..] => (0..=path.len())
+            .take_while(|&i| i == 0 || path[i - 1] != '/')
+            .any(|i| glob_match(rest, &path[i..])),
+        ['?', rest @ ..] => {
+            matches!(path.first(), Some(c) if *c != '/') && glob_match(rest, &path[1..])
+        }
+        [c, rest @ ..] => path.first() == Some(c) && glob_match(rest, &path[1..]),
+    }
+}
+
+/// Reads an export archive without extracting it: the manifest, whether the
+/// contract tarball matches its recorded SHA-256, the files in it, and any
+/// entries that would be refused on import.
+pub fn preview(archive_path: &Path) -> Result<ImportPreview> {
+    let limits = ArchiveLimits::default();
+    let open = || -> Result<_> {
+        Ok(GzDecoder::new(BufReader::with_capacity(
+            BUF_SIZE,
+            File::open(archive_path)?,
+        )))
+    };
+    let outer = archive_scan::scan_tar(open()?, &limits)?;
+    if !outer.is_clean() {
+        return Err(ArchiveRejected(outer).into());
+    }
+
+    // The scan bounds both entries' sizes, so they can be held in memory.
+    let (mut manifest, mut inner) = (None, None);
+    let mut archive = Archive::new(open()?);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let path = entry.path()?.into_owned();
+        match path.to_str() {
+            Some("manifest.json") => {
+                let parsed: ExportManifest =
+                    serde_json::from_reader(&mut entry).context("invalid manifest.json")?;
+                manifest = Some(parsed);
+            }
+            Some("contract.tar.gz") => {
+                let mut bytes = Vec::new();
+                entry.read_to_end(&mut bytes)?;
+                inner = Some(bytes);
+            }
+            _ => {}
+        }
+    }
+    let (Some(manifest), Some(inner)) = (manifest, inner) else {
+        bail!("invalid archive: missing manifest.json or contract.tar.gz");
+    };
+
+    let computed_sha256 = format!("{:x}", Sha256::digest(&inner));
+    let report = archive_scan::scan_tar_gz(inner.as_slice(), &limits)?;
+    let listed: HashMap<&str, u64> = manifest
+        .contents
+        .iter()
+        .map(|entry| (entry.path.as_str(), entry.size))
+        .collect();
+    let mut entries = Vec::new();
+    let mut archive = Archive::new(GzDecoder::new(inner.as_slice()));
+    // Stop where the scan stopped, so an oversized tarball is not inflated.
+    for entry in archive.entries()?.take(report.entries as usize) {
+        let entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let path = entry.path()?.to_string_lossy().into_owned();
+        entries.push(PreviewEntry {
+            manifest_size: listed.get(path.as_str()).copied(),
+            in_profile: in_profile(manifest.profile, &path),
+            size: entry.size(),
+            path,
+        });
+    }
 
-pub fn extract_and_verify(archive_path: &Path, output_dir: &Path) -> Result<ExportManifest> {
+    Ok(ImportPreview {
+        manifest,
+        computed_sha256,
+        entries,
+        report,
+    })
+}
+
+/// Reads just the manifest of an export archive, e.g. to look up its
+/// signing key before extracting it.
+pub fn read_manifest(archive_path: &Path) -> Result<ExportManifest> {
+    let open = || -> Result<_> {
+        Ok(GzDecoder::new(BufReader::with_capacity(
+            BUF_SIZE,
+            File::open(archive_path)?,
+        )))
+    };
+    let outer = archive_scan::scan_tar(open()?, &ArchiveLimits::default())?;
+    if !outer.is_clean() {
+        return Err(ArchiveRejected(outer).into());
+    }
+    let mut archive = Archive::new(open()?);
+    for entry in archive.entries()? {
+        let entry = entry?;
+        if entry.path()?.to_str() == Some("manifest.json") {
+            return serde_json::from_reader(entry).context("invalid manifest.json");
+        }
+    }
+    bail!("invalid archive: missing manifest.json")
+}
+
+/// What [`extract_and_verify`] wrote.
+pub struct Extracted {
+    pub manifest: ExportManifest,
+    pub files: u64,
+    /// Files skipped because the manifest's profile does not include them
+    pub outside_profile: u64,
+    pub signature: SignatureCheck,
+}
+
+/// Whether the archive's profile covers `path`; anything else in an archive
+/// was not put there by `export`.
+pub fn in_profile(profile: ExportProfile, path: &str) -> bool {
+    Component::of(path).is_some_and(|component| profile.includes(component))
+}
+
+/// Extracts an export archive into `output_dir` once its checksum verifies
+/// and its signature satisfies `policy`, keeping only the files its profile
+/// includes that match `only` (all of them when empty). `registered` is the
+/// registry's record of the signing key, if it has one.
+pub fn extract_and_verify(
+    archive_path: &Path,
+    output_dir: &Path,
+    only: &[String],
+    policy: TrustPolicy,
+    registered: &[RegisteredKey],
+) -> Result<Extracted> {
     let tmp_dir = tempfile::tempdir().context("failed to create temp dir")?;
 
     extract_tar_gz(archive_path, tmp_dir.path())?;
@@ -31,6 +236,8 @@ pub fn extract_and_verify(archive_path: &Path, output_dir: &Path) -> Result<Expo
             computed_hash
         );
     }
+    let signature = trust::check_manifest(&manifest, registered)?;
+    policy.enforce(&signature, "archive")?;
 
     manifest.audit_trail.push(AuditEntry {
         action: "import_verified".into(),
@@ -39,7 +246,17 @@ pub fn extract_and_verify(archive_path: &Path, output_dir: &Path) -> Result<Expo
     });
 
     fs::create_dir_all(output_dir)?;
-    extract_tar_gz(&inner_path, output_dir)?;
+    let outside_profile = Cell::new(0);
+    let written = extract_tar_gz_matching(&inner_path, output_dir, |path| {
+        if !in_profile(manifest.profile, &path.to_string_lossy().replace('\\', "/")) {
+            outside_profile.set(outside_profile.get() + 1);
+            return false;
+        }
+        matches_any(only, path)
+    })?;
+    if written == 0 && !only.is_empty() {
+        bail!("no files in the archive match {}", only.join(", "));
+    }
 
     manifest.audit_trail.push(AuditEntry {
         action: "import_extracted".into(),
@@ -47,5 +264,71 @@ pub fn extract_and_verify(archive_path: &Path, output_dir: &Path) -> Result<Expo
         actor: "soroban-registry-cli".into(),
     });
 
-    Ok(manifest)
+    Ok(Extracted {
+        manifest,
+        files: written,
+        outside_profile: outside_profile.get(),
+        signature,
+    })
+}
+
+/// Prints the entries that got an archive refused, if that is why `err`
+/// happened, and returns a short error in its place.
+pub fn explain_rejection(err: anyhow::Error) -> anyhow::Error {
+    let Some(ArchiveRejected(report)) = err.downcast_ref::<ArchiveRejected>() else {
+        return err;
+    };
+    print_rejections(report);
+    anyhow::anyhow!("archive rejected; nothing was extracted")
+}
+
+pub fn print_rejections(report: &ArchiveReport) {
+    println!(
+        "\n{} {} of {} entries failed validation\n",
+        "✗ Archive rejected:".red().bold(),
+        report.rejected.len(),
+        report.entries
+    );
+    for entry in &report.rejected {
+        let reason = serde_json::to_value(entry.reason)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_default();
+        println!(
+            "  {:<18} {}  {}",
+            reason.yellow(),
+            entry.path,
+            entry.detail.bright_black()
+        );
+    }
+    println!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn globs(patterns: &[&str]) -> Vec<String> {
+        patterns.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn only_globs_select_paths() {
+        let rust = globs(&["*.rs"]);
+        assert!(matches_any(&rust, Path::new("src/lib.rs")));
+        assert!(matches_any(&rust, Path::new("lib.rs")));
+        assert!(!matches_any(&rust, Path::new("Cargo.toml")));
+
+        let src = globs(&["src/*"]);
+        assert!(matches_any(&src, Path::new("src/lib.rs")));
+        assert!(!matches_any(&src, Path::new("src/bin/main.rs")));
+
+        let deep = globs(&["src/**/*.rs", "Cargo.?oml"]);
+        assert!(matches_any(&deep, Path::new("src/lib.rs")));
+        assert!(matches_any(&deep, Path::new("src/bin/tools/main.rs")));
+        assert!(matches_any(&deep, Path::new("Cargo.toml")));
+        assert!(!matches_any(&deep, Path::new("tests/it.rs")));
+
+        assert!(matches_any(&[], Path::new("anything")));
+    }
 }
@@ -2,9 +2,10 @@ use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::Path;
 
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use flate2::read::GzDecoder;
 use sha2::{Digest, Sha256};
+use shared::archive_scan::{self, ArchiveLimits, ArchiveRejected};
 use tar::Archive;
 
 pub const BUF_SIZE: usize = 65536;
@@ -27,15 +28,54 @@ pub fn compute_sha256_streaming(path: &Path) -> Result<String> {
 }
 
 /// Extract a gzipped tar archive to a destination directory.
+///
+/// The archive is scanned first (see [`shared::archive_scan`]); if any entry
+/// is unsafe, nothing is extracted and the error is an [`ArchiveRejected`]
+/// listing every rejected entry. Only regular files and directories are
+/// written.
 pub fn extract_tar_gz(archive_path: &Path, dest: &Path) -> Result<()> {
+    extract_tar_gz_matching(archive_path, dest, |_| true).map(|_| ())
+}
+
+/// Like [`extract_tar_gz`], but writes only the entries whose path `keep`
+/// accepts, creating their parent directories as needed. Returns the number
+/// of files written.
+pub fn extract_tar_gz_matching(
+    archive_path: &Path,
+    dest: &Path,
+    keep: impl Fn(&Path) -> bool,
+) -> Result<u64> {
+    let reader = BufReader::with_capacity(BUF_SIZE, File::open(archive_path)?);
+    let report = archive_scan::scan_tar_gz(reader, &ArchiveLimits::default())?;
+    if !report.is_clean() {
+        return Err(ArchiveRejected(report).into());
+    }
+
     let reader = BufReader::with_capacity(BUF_SIZE, File::open(archive_path)?);
     let decoder = GzDecoder::new(reader);
     let mut archive = Archive::new(decoder);
+    let mut written = 0;
 
     for entry in archive.entries()? {
         let mut entry = entry?;
+        let kind = entry.header().entry_type();
+        if !kind.is_file() && !kind.is_dir() {
+            continue;
+        }
         let path = entry.path()?.into_owned();
-        let dest_path = dest.join(&path);
+        let Some(relative) = archive_scan::safe_relative_path(&path)
+            .map_err(|_| anyhow!("unsafe path in archive: {}", path.display()))?
+        else {
+            continue;
+        };
+        if !keep(&relative) {
+            continue;
+        }
+        let dest_path = dest.join(relative);
+        if kind.is_dir() {
+            std::fs::create_dir_all(&dest_path)?;
+            continue;
+        }
 
         if let Some(parent) = dest_path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -51,7 +91,8 @@ pub fn extract_tar_gz(archive_path: &Path, dest: &Path) -> Result<()> {
             out.write_all(&buf[..n])?;
         }
         out.flush()?;
+        written += 1;
     }
 
-    Ok(())
+    Ok(written)
 }
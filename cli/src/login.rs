@@ -0,0 +1,226 @@
+//! login.rs — `soroban-registry login`
+//!
+//! Signs in with a Stellar account through SEP-10 web authentication:
+//!
+//! 1. `GET /api/auth/challenge?account=G...` returns a challenge transaction
+//!    signed by the registry;
+//! 2. the challenge is checked (sequence 0, first operation sourced from the
+//!    account, every other one from the registry, signed by the registry)
+//!    and signed locally with the account's secret key;
+//! 3. `POST /api/auth/token` exchanges it for a short-lived bearer token,
+//!    which the commands taking `--token` read from `SOROBAN_REGISTRY_TOKEN`.
+//!
+//! The secret key never leaves the machine.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use ed25519_dalek::{Signer, SigningKey, Verifier, VerifyingKey};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use stellar_xdr::curr::{
+    DecoratedSignature, Hash, Limits, MuxedAccount, ReadXdr, SequenceNumber, Signature,
+    SignatureHint, TransactionEnvelope, TransactionSignaturePayload,
+    TransactionSignaturePayloadTaggedTransaction, Uint256, WriteXdr,
+};
+
+use crate::claim::{decode_secret_key, encode_strkey, VERSION_ACCOUNT};
+
+pub async fn run(api_url: &str, secret_key: &str, json: bool) -> Result<()> {
+    let signing_key = decode_secret_key(secret_key)?;
+    let account = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+
+    let client = crate::http::client();
+    let challenge = send(
+        client
+            .get(format!("{}/api/auth/challenge", api_url))
+            .query(&[("account", account.as_str())]),
+    )
+    .await
+    .context("Failed to request a login challenge")?;
+    let transaction = challenge["transaction"]
+        .as_str()
+        .context("Challenge response is missing the transaction")?;
+    let network_passphrase = challenge["network_passphrase"]
+        .as_str()
+        .context("Challenge response is missing the network passphrase")?;
+
+    let signed = sign_challenge(transaction, &signing_key, network_passphrase)?;
+    let session = send(
+        client
+            .post(format!("{}/api/auth/token", api_url))
+            .json(&json!({ "transaction": signed })),
+    )
+    .await
+    .context("Failed to exchange the signed challenge for a token")?;
+    let token = session["token"]
+        .as_str()
+        .context("Token response is missing the token")?;
+    let expires_in = session["expires_in_seconds"].as_u64().unwrap_or(0);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&json!({
+                "account": account,
+                "token": token,
+                "expires_in_seconds": expires_in,
+            }))?
+        );
+        return Ok(());
+    }
+    println!("\n{}", "✓ Logged in".green().bold());
+    println!("  {}: {}", "Account".bold(), account.bright_magenta());
+    println!("  {}: {} minutes", "Expires In".bold(), expires_in / 60);
+    println!(
+        "  {} export SOROBAN_REGISTRY_TOKEN={}\n",
+        "→".bright_black(),
+        token
+    );
+    Ok(())
+}
+
+/// Add `signing_key`'s signature to a SEP-10 challenge after checking it is
+/// one for that key's account, signed by the server that built it.
+pub fn sign_challenge(
+    transaction: &str,
+    signing_key: &SigningKey,
+    network_passphrase: &str,
+) -> Result<String> {
+    let TransactionEnvelope::Tx(mut envelope) =
+        TransactionEnvelope::from_xdr_base64(transaction.trim(), Limits::none())
+            .context("Challenge is not a valid transaction envelope")?
+    else {
+        bail!("Challenge is not a v1 transaction envelope");
+    };
+    let tx = &envelope.tx;
+    if tx.seq_num != SequenceNumber(0) {
+        bail!("Challenge has a non-zero sequence number; refusing to sign it");
+    }
+    let MuxedAccount::Ed25519(Uint256(server)) = &tx.source_account else {
+        bail!("Challenge is not sourced from a plain server account");
+    };
+    let account = MuxedAccount::Ed25519(Uint256(signing_key.verifying_key().to_bytes()));
+    let Some((first, rest)) = tx.operations.split_first() else {
+        bail!("Challenge has no operations");
+    };
+    if first.source_account.as_ref() != Some(&account) {
+        bail!("Challenge is for a different account");
+    }
+    if rest
+        .iter()
+        .any(|op| op.source_account.as_ref() != Some(&tx.source_account))
+    {
+        bail!(
+            "Challenge has operations on accounts other than the registry's; refusing to sign it"
+        );
+    }
+
+    let payload = TransactionSignaturePayload {
+        network_id: Hash(Sha256::digest(network_passphrase).into()),
+        tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+    };
+    let hash: [u8; 32] = Sha256::digest(payload.to_xdr(Limits::none())?).into();
+    let server_key = VerifyingKey::from_bytes(server).context("Invalid server account key")?;
+    let server_signed = envelope.signatures.iter().any(|sig| {
+        ed25519_dalek::Signature::from_slice(sig.signature.0.as_slice())
+            .map(|sig| server_key.verify(&hash, &sig).is_ok())
+            .unwrap_or(false)
+    });
+    if !server_signed {
+        bail!("Challenge is not signed by the registry's server account");
+    }
+
+    let public_key = signing_key.verifying_key().to_bytes();
+    let mut signatures = envelope.signatures.to_vec();
+    signatures.push(DecoratedSignature {
+        hint: SignatureHint(public_key[28..].try_into()?),
+        signature: Signature(signing_key.sign(&hash).to_bytes().to_vec().try_into()?),
+    });
+    envelope.signatures = signatures.try_into()?;
+    Ok(TransactionEnvelope::Tx(envelope).to_xdr_base64(Limits::none())?)
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<Value> {
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use stellar_xdr::curr::{
+        DataValue, ManageDataOp, Memo, Operation, OperationBody, Preconditions, String64,
+        Transaction, TransactionExt, TransactionV1Envelope,
+    };
+
+    const PASSPHRASE: &str = "Test SDF Network ; September 2015";
+
+    fn challenge(server: &SigningKey, client: &SigningKey) -> String {
+        let op = Operation {
+            source_account: Some(MuxedAccount::Ed25519(Uint256(
+                client.verifying_key().to_bytes(),
+            ))),
+            body: OperationBody::ManageData(ManageDataOp {
+                data_name: String64("registry.example auth".try_into().unwrap()),
+                data_value: Some(DataValue([b'n'; 64].to_vec().try_into().unwrap())),
+            }),
+        };
+        let tx = Transaction {
+            source_account: MuxedAccount::Ed25519(Uint256(server.verifying_key().to_bytes())),
+            fee: 100,
+            seq_num: SequenceNumber(0),
+            cond: Preconditions::None,
+            memo: Memo::None,
+            operations: vec![op].try_into().unwrap(),
+            ext: TransactionExt::V0,
+        };
+        let payload = TransactionSignaturePayload {
+            network_id: Hash(Sha256::digest(PASSPHRASE).into()),
+            tagged_transaction: TransactionSignaturePayloadTaggedTransaction::Tx(tx.clone()),
+        };
+        let hash = Sha256::digest(payload.to_xdr(Limits::none()).unwrap());
+        let signature = DecoratedSignature {
+            hint: SignatureHint([0; 4]),
+            signature: Signature(server.sign(&hash).to_bytes().to_vec().try_into().unwrap()),
+        };
+        TransactionEnvelope::Tx(TransactionV1Envelope {
+            tx,
+            signatures: vec![signature].try_into().unwrap(),
+        })
+        .to_xdr_base64(Limits::none())
+        .unwrap()
+    }
+
+    #[test]
+    fn signs_only_challenges_for_its_account() {
+        let server = SigningKey::from_bytes(&[1u8; 32]);
+        let client = SigningKey::from_bytes(&[2u8; 32]);
+        let other = SigningKey::from_bytes(&[3u8; 32]);
+        let challenge = challenge(&server, &client);
+
+        let signed = sign_challenge(&challenge, &client, PASSPHRASE).unwrap();
+        let TransactionEnvelope::Tx(envelope) =
+            TransactionEnvelope::from_xdr_base64(signed, Limits::none()).unwrap()
+        else {
+            panic!("expected a v1 envelope");
+        };
+        assert_eq!(envelope.signatures.len(), 2);
+
+        assert!(sign_challenge(&challenge, &other, PASSPHRASE).is_err());
+        assert!(sign_challenge(
+            &challenge,
+            &client,
+            "Public Global Stellar Network ; September 2015"
+        )
+        .is_err());
+    }
+}
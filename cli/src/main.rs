@@ -1,43 +1,89 @@
 #![allow(unused_variables)]
 
+mod abi_snapshot;
+mod admin;
+mod alerts;
 mod analyze;
+mod anchor;
+mod audits;
 mod backup;
+mod badges;
 mod batch_register;
 mod batch_verify;
+mod bench;
+mod canary;
+mod claim;
+mod ci_report;
 mod cicd;
 mod commands;
+mod compliance;
 mod config;
+mod contract_group;
 mod contract_verify;
 mod contracts;
 mod conversions;
 mod coverage;
 mod dashboard;
+mod defi;
+mod disclosures;
 mod events;
 mod export;
 mod formal_verification;
 mod fuzz;
+mod fuzz_campaigns;
+mod fuzz_harness;
+mod http;
 mod import;
 mod incident;
 mod io_utils;
+mod login;
 mod manifest;
 mod migration;
+mod migrations;
+mod mock_server;
 mod multisig;
 mod network;
+mod nft;
+mod optimize_report;
+mod oracle;
 mod package_signing;
+mod permissions;
 mod patch;
+mod patch_diff;
+mod patch_rollout;
+mod policy;
 mod profiler;
+mod promote;
+mod publisher;
+mod publisher_keys;
+mod quality;
+mod recovery;
 mod release_notes;
+mod replay;
+mod report;
+mod resolve;
+mod scaffold;
+mod self_update;
 mod sla;
 mod table_format;
+mod telemetry;
 mod test_framework;
+mod test_runs;
+mod tlog;
+mod toolchains;
 mod track_deployment;
+mod trust;
+mod undo;
 mod webhook;
+mod whohas;
 mod wizard;
 mod shell;
+mod source_clone;
 mod track_deployment;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
+use clap::parser::ValueSource;
+use clap::{Args, CommandFactory, FromArgMatches, Parser, Subcommand};
 use colored::Colorize;
 use patch::Severity;
 
@@ -61,6 +107,14 @@ pub struct Cli {
     #[arg(long, short = 'v', global = true)]
     pub verbose: bool,
 
+    /// Serve canned responses from a built-in fixture registry (no backend or network)
+    #[arg(long, global = true, env = "SOROBAN_REGISTRY_MOCK")]
+    pub mock: bool,
+
+    /// Named `[profiles.<name>]` from the config file (API URL, client certificate for mTLS)
+    #[arg(long, global = true, env = "SOROBAN_REGISTRY_PROFILE")]
+    pub profile: Option<String>,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -80,6 +134,24 @@ pub enum Commands {
         /// Filter by contract category (e.g. DEX, token, lending, oracle)
         #[arg(long)]
         category: Option<String>,
+        /// Only contracts whose codebase is also deployed on this network
+        #[arg(long, value_parser = promote::NETWORKS)]
+        deployed_on: Option<String>,
+        /// Only contracts holding this badge (e.g. audited-by, verified-source)
+        #[arg(long, value_parser = shared::attestation::BADGE_KINDS)]
+        badge: Option<String>,
+        /// Only oracle feeds that are fresh or stale against their declared cadence
+        #[arg(long, value_parser = oracle::FRESHNESS)]
+        oracle_freshness: Option<String>,
+        /// Only contracts built with this soroban-sdk version or a prefix of it (e.g. 22, 22.0.7)
+        #[arg(long)]
+        sdk_version: Option<String>,
+        /// Only contracts targeting this protocol version
+        #[arg(long)]
+        protocol: Option<u32>,
+        /// Order results by this field, highest first (tvl ranks AMM pools by liquidity)
+        #[arg(long, value_parser = commands::SEARCH_SORTS)]
+        sort: Option<String>,
         /// Maximum number of results to return
         #[arg(long, default_value = "20")]
         limit: usize,
@@ -105,6 +177,59 @@ pub enum Commands {
         highlight_method: Option<String>,
     },
 
+    /// Query events emitted by a contract
+    #[command(args_conflicts_with_subcommands = true)]
+    Events {
+        #[command(subcommand)]
+        action: Option<EventCommands>,
+
+        /// Contract registry identifiers (UUID or contract address); several
+        /// are merged into one stream ordered by ledger
+        contract_ids: Vec<String>,
+
+        /// Also include every contract with this tag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Also include every contract in this category
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Only events with this topic
+        #[arg(long)]
+        topic: Option<String>,
+
+        /// Only events whose data matches this pattern
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Maximum number of results
+        #[arg(long, default_value_t = 50)]
+        limit: i64,
+
+        /// Number of results to skip
+        #[arg(long, default_value_t = 0)]
+        offset: i64,
+
+        /// Stream every matching event to a file instead of printing them,
+        /// ignoring --limit and --offset; NDJSON for .ndjson or .jsonl, CSV
+        /// otherwise
+        #[arg(long)]
+        export: Option<String>,
+
+        /// Show aggregate statistics only
+        #[arg(long)]
+        stats: bool,
+
+        /// Show anomalies flagged on the contract's events instead
+        #[arg(long, conflicts_with_all = ["stats", "export"])]
+        anomalies: bool,
+
+        /// With --anomalies: only rate_spike, new_topic or large_transfer
+        #[arg(long, requires = "anomalies")]
+        kind: Option<String>,
+    },
+
     /// Publish a new contract to the registry
     Publish {
         /// On-chain contract ID
@@ -151,11 +276,55 @@ pub enum Commands {
         #[arg(long, default_value_t = 0.0)]
         coverage_threshold: f64,
 
+        /// Warn when fewer than this percentage (0-100) of the spec's
+        /// functions and types have doc comments
+        #[arg(
+            long,
+            env = "SOROBAN_REGISTRY_MIN_DOCS_COVERAGE",
+            default_value_t = abi_snapshot::DEFAULT_MIN_DOCS_COVERAGE
+        )]
+        min_docs_coverage: f64,
+
         /// Skip pre-submission contract tests
         #[arg(long)]
         skip_tests: bool,
     },
 
+    /// Claim ownership of an auto-indexed contract as its deployer
+    Claim {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Deployer's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
+    },
+
+    /// Log in with a Stellar account (SEP-10) and print a bearer token
+    Login {
+        /// Account's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Check that a contract's registry record matches its on-chain anchor
+    VerifyAnchor {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Trusted anchor account (defaults to the one the registry reports)
+        #[arg(long, env = "SOROBAN_REGISTRY_ANCHOR_ACCOUNT")]
+        anchor_account: Option<String>,
+
+        /// Horizon URL (defaults to the public instance for the contract's network)
+        #[arg(long)]
+        horizon_url: Option<String>,
+    },
+
     /// List recent contracts
     List {
         /// Maximum number of contracts to show
@@ -177,6 +346,12 @@ pub enum Commands {
         /// WebSocket URL (or set SOROBAN_REGISTRY_WS_URL)
         #[arg(long, env = "SOROBAN_REGISTRY_WS_URL")]
         ws_url: Option<String>,
+        /// Show this publisher's statistics instead of the live feed
+        #[arg(long, conflicts_with = "category")]
+        publisher: Option<String>,
+        /// Output publisher statistics as JSON
+        #[arg(long, requires = "publisher")]
+        json: bool,
     },
 
     /// Detect breaking changes between contract versions
@@ -195,6 +370,246 @@ pub enum Commands {
         #[command(subcommand)]
         action: MigrateCommands,
     },
+    /// Migration history recorded in the registry
+    Migrations {
+        #[command(subcommand)]
+        action: MigrationsCommands,
+    },
+    /// Deployments of the same codebase on different networks
+    Group {
+        #[command(subcommand)]
+        action: GroupCommands,
+    },
+    /// Capabilities granted on a contract to accounts other than its publisher
+    Permissions {
+        #[command(subcommand)]
+        action: PermissionsCommands,
+    },
+    /// Cancel your most recent yank, deprecation or contract deletion while
+    /// it is still in its grace period
+    Undo {
+        /// List pending actions instead of undoing one
+        #[arg(long)]
+        list: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// The logged-in publisher's account: email verification, profile and
+    /// storage usage
+    Publisher {
+        #[command(subcommand)]
+        action: PublisherCommands,
+    },
+    /// Clear a WASM build for another network (e.g. testnet → mainnet)
+    Promote {
+        /// Contract UUID or on-chain contract ID (any network)
+        contract_id: String,
+
+        /// Network the build has been running on
+        #[arg(long, value_parser = promote::NETWORKS)]
+        from: String,
+
+        /// Network to promote the build to
+        #[arg(long, value_parser = promote::NETWORKS)]
+        to: String,
+
+        /// Build to promote; defaults to the source contract's current WASM
+        #[arg(long, conflicts_with = "wasm")]
+        wasm_hash: Option<String>,
+
+        /// Local WASM file whose hash is promoted
+        #[arg(long)]
+        wasm: Option<String>,
+
+        /// Require a longer soak period than the registry's minimum
+        #[arg(long)]
+        min_soak_hours: Option<i32>,
+
+        /// Contract already deployed from the build on the target network to link
+        #[arg(long)]
+        link: Option<String>,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Update this CLI to the latest signed release published by the registry
+    SelfUpdate {
+        /// Release channel to follow
+        #[arg(long, default_value = "stable", value_parser = self_update::CHANNELS)]
+        channel: String,
+
+        /// Only report whether an update is available
+        #[arg(long)]
+        check: bool,
+
+        /// Base64 Ed25519 release signing key, for builds that do not embed one
+        #[arg(long)]
+        release_key: Option<String>,
+    },
+    /// Check a project against its organization's publish policy
+    Policy {
+        /// Bearer token; needed for private organizations
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+
+        #[command(subcommand)]
+        action: PolicyCommands,
+    },
+    /// Audit reports attached to a contract's versions
+    Audits {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Only reports for this version
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Accounting report of a token contract's transfers over a date range
+    Report {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// First day of the report (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        from: String,
+
+        /// Last day of the report, inclusive (YYYY-MM-DD, UTC)
+        #[arg(long)]
+        to: String,
+
+        /// table, json or csv
+        #[arg(long, default_value = "table", value_parser = report::FORMATS)]
+        format: String,
+
+        /// Write the report to this file instead of printing it
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+    /// License report for a contract and its transitive registry dependencies
+    Compliance {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// markdown or json
+        #[arg(long, default_value = "markdown", value_parser = compliance::FORMATS)]
+        format: String,
+
+        /// Write the report to this file instead of printing it
+        #[arg(long, short)]
+        output: Option<String>,
+    },
+    /// Badges granted to contracts by attestation authorities
+    Badges {
+        #[command(subcommand)]
+        action: BadgeCommands,
+    },
+    /// Report vulnerabilities to a contract's publisher, and read reports
+    Disclosures {
+        #[command(subcommand)]
+        action: DisclosureCommands,
+    },
+    /// Show a contract's quality score and its per-component breakdown
+    Quality {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// List every registry contract built from a WASM hash
+    Whohas {
+        /// WASM hash (64 hexadecimal characters)
+        hash: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show an AMM pool's tokens, reserves, fee and estimated TVL
+    Defi {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Browse an NFT collection's tokens and their owners
+    Nft {
+        #[command(subcommand)]
+        action: NftCommands,
+    },
+    /// Declare an oracle feed's update cadence and check its freshness
+    Oracle {
+        #[command(subcommand)]
+        action: OracleCommands,
+    },
+    /// Submit a signed CI test run for a contract build from a JUnit report
+    AttestTests {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// JUnit XML report to summarize (e.g. results.xml)
+        #[arg(long)]
+        from_junit: String,
+
+        /// Suite name (defaults to the report's first <testsuite> name)
+        #[arg(long)]
+        suite: Option<String>,
+
+        /// Line coverage of the run, in percent
+        #[arg(long)]
+        coverage: Option<f64>,
+
+        /// Build that was tested (defaults to the contract's current WASM hash)
+        #[arg(long)]
+        wasm_hash: Option<String>,
+
+        /// Publisher's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Opt in to (or out of) anonymized usage reporting
+    Telemetry {
+        #[command(subcommand)]
+        action: TelemetryCommands,
+    },
+    /// Check releases against the registry's transparency log
+    Tlog {
+        #[command(subcommand)]
+        action: TlogCommands,
+    },
+    /// Manage the publisher keys `import` and `clone` trust
+    Trust {
+        #[command(subcommand)]
+        action: TrustCommands,
+    },
+    /// List approved builder toolchains for reproducible verification
+    Toolchains {
+        /// Include deprecated toolchains
+        #[arg(long)]
+        all: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
     /// Analyze upgrades between two contract versions or schema files
     UpgradeAnalyze {
         /// Old contract version ID or local schema JSON file
@@ -221,6 +636,15 @@ pub enum Commands {
         /// Path to contract source directory
         #[arg(long, default_value = ".")]
         contract_dir: String,
+
+        /// What to bundle: `minimal` (source, ABI), `audit` (source, WASM,
+        /// ABI, audit reports) or `full` (also docs and benchmarks)
+        #[arg(long, default_value = "full", value_parser = manifest::EXPORT_PROFILES)]
+        profile: String,
+
+        /// Sign the archive with this base64 Ed25519 private key
+        #[arg(long, env = "SOROBAN_REGISTRY_SIGNING_KEY", hide_env_values = true)]
+        sign_key: Option<String>,
     },
 
     /// Import a contract from an archive
@@ -231,6 +655,24 @@ pub enum Commands {
         /// Directory to extract into
         #[arg(long, default_value = "./imported")]
         output_dir: String,
+
+        /// Show the archive's manifest, files and checksum result without
+        /// extracting anything
+        #[arg(long)]
+        list: bool,
+
+        /// Only extract (or list) paths matching this glob, e.g. `src/**/*.rs`
+        /// or `*.toml` (repeatable)
+        #[arg(long = "only", value_name = "GLOB")]
+        only: Vec<String>,
+
+        /// Refuse archives without a valid signature
+        #[arg(long)]
+        require_signed: bool,
+
+        /// Refuse archives not signed by a key in your trust store
+        #[arg(long)]
+        require_trusted: bool,
     },
 
     /// Generate documentation from a contract WASM
@@ -257,8 +699,102 @@ pub enum Commands {
         format: String,
     },
 
-    /// Launch the interactive setup wizard
-    Wizard {},
+    /// Snapshot the contract ABI and check builds against it
+    Abi {
+        #[command(subcommand)]
+        action: AbiCommands,
+    },
+
+    /// Scaffold a new contract project from a registry template
+    New {
+        /// Project (and package) name
+        name: String,
+
+        /// Template to scaffold from
+        #[arg(long, default_value = "token", value_parser = scaffold::TEMPLATE_KINDS)]
+        template: String,
+
+        /// Parent directory for the new project (defaults to the current directory)
+        #[arg(long)]
+        path: Option<String>,
+
+        /// Bearer token from the registry's wallet login; when set, the
+        /// install is counted for the template
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+
+    /// Download the verified source of a contract with provenance metadata
+    #[command(alias = "pull")]
+    Clone {
+        /// Contract registry identifier (UUID, contract address, or name)
+        contract: String,
+
+        /// Version to clone (defaults to the latest version)
+        #[arg(long)]
+        version: Option<String>,
+
+        /// Destination directory (defaults to <name>-<version>)
+        #[arg(long)]
+        dir: Option<String>,
+
+        /// Refuse sources without a valid publisher signature
+        #[arg(long)]
+        require_signed: bool,
+
+        /// Refuse sources not signed by a key in your trust store
+        #[arg(long)]
+        require_trusted: bool,
+    },
+
+    /// Benchmark per-function CPU, memory, and fee costs via simulation
+    Bench {
+        /// Path to a WASM file (deployed for simulation) or a deployed contract address
+        target: String,
+
+        /// Network to simulate against
+        #[arg(long, default_value = "testnet")]
+        network: String,
+
+        /// Source identity used to deploy and simulate
+        #[arg(long, default_value = "default")]
+        source: String,
+
+        /// JSON file mapping function names to argument lists, overriding generated ones
+        #[arg(long)]
+        args: Option<String>,
+
+        /// Registry contract (UUID or address) to store or compare results against
+        #[arg(long)]
+        contract: Option<String>,
+
+        /// Store results as the benchmarks of this version (requires --contract)
+        #[arg(long, requires = "contract")]
+        version: Option<String>,
+
+        /// Show deltas against the benchmarks stored for this version (requires --contract)
+        #[arg(long, requires = "contract")]
+        compare: Option<String>,
+
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Bearer token from the registry's wallet login; needed to store results with --version
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+
+    /// Guided pipeline: build, preflight, deploy, publish, and verify a contract
+    Wizard {
+        /// Path to the contract project directory
+        #[arg(long, default_value = ".")]
+        path: String,
+
+        /// Resume the last partially completed wizard session
+        #[arg(long)]
+        resume: bool,
+    },
 
     /// Launch the interactive shell
     Shell {
@@ -297,21 +833,13 @@ pub enum Commands {
     },
 
     /// Fuzz testing for contracts
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
     Fuzz {
-        #[arg(long)]
-        contract_path: String,
-        #[arg(long)]
-        duration: u64,
-        #[arg(long)]
-        timeout: u64,
-        #[arg(long)]
-        threads: u32,
-        #[arg(long)]
-        max_cases: u32,
-        #[arg(long)]
-        output: String,
-        #[arg(long)]
-        minimize: bool,
+        #[command(subcommand)]
+        action: Option<FuzzCommands>,
+
+        #[command(flatten)]
+        run: Option<FuzzRunArgs>,
     },
 
     /// Profile contract execution performance
@@ -470,6 +998,18 @@ pub enum Commands {
         /// Signature (base64, optional - will lookup from registry if not provided)
         #[arg(long)]
         signature: Option<String>,
+
+        /// Emit a CI report (junit | github) covering verification, audit, lint, and ABI checks
+        #[arg(long)]
+        report: Option<String>,
+
+        /// Write the report to a file instead of stdout
+        #[arg(long)]
+        report_output: Option<String>,
+
+        /// Contract project directory used for audit, lint, and ABI checks
+        #[arg(long, default_value = ".")]
+        contract_path: String,
     },
 
     /// Verify a contract binary against an Ed25519 signature locally
@@ -500,6 +1040,12 @@ pub enum Commands {
         action: KeysCommands,
     },
 
+    /// Recover a publisher account whose key was lost
+    Recovery {
+        #[command(subcommand)]
+        action: RecoveryCommands,
+    },
+
     /// Contract deployment verification and security scan (#522)
     Contract {
         #[command(subcommand)]
@@ -582,521 +1128,1525 @@ pub enum Commands {
         #[arg(long, short = 'o')]
         output: Option<String>,
     },
-}
 
-/// Sub-commands for the `network` group
-#[derive(Debug, Subcommand)]
-pub enum NetworkCommands {
-    /// Show status of all supported Stellar networks
-    Status {
-        /// Output results as machine-readable JSON
+    /// Report how a contract WASM could be made smaller (and cheaper to deploy)
+    OptimizeReport {
+        /// Path to the compiled WASM file
+        wasm: String,
+
+        /// Write a copy with debug, name, and other non-Soroban custom sections stripped
+        #[arg(long, short = 'o')]
+        output: Option<String>,
+
+        /// Output the report as JSON
         #[arg(long)]
         json: bool,
     },
+
+    /// Registry administration (requires an admin token)
+    Admin {
+        /// Admin bearer token
+        #[arg(long, env = "SOROBAN_REGISTRY_ADMIN_TOKEN", hide_env_values = true)]
+        token: String,
+
+        #[command(subcommand)]
+        action: AdminCommands,
+    },
+
+    /// Alert rules on contract events, e.g. "alert me when admin_change is emitted"
+    Alerts {
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+
+        #[command(subcommand)]
+        action: AlertCommands,
+    },
 }
 
-/// Sub-commands for the `release-notes` group
+/// Sub-commands for the `events` group
 #[derive(Debug, Subcommand)]
-pub enum ReleaseNotesCommands {
-    /// Auto-generate release notes from code diff and changelog
-    Generate {
-        /// Contract registry ID (UUID or on-chain ID)
-        #[arg(long)]
+pub enum EventCommands {
+    /// Replay a contract's recorded invocations against a local sandbox
+    Replay {
+        /// Contract UUID or on-chain contract ID whose traffic is replayed
         contract_id: String,
 
-        /// Version to generate notes for (semver, e.g. 1.2.0)
+        /// RPC URL of the local sandbox (e.g. http://localhost:8000/soroban/rpc)
         #[arg(long)]
-        version: String,
+        target: String,
 
-        /// Previous version to diff against (auto-detected if omitted)
+        /// Address of the contract already deployed in the sandbox
+        #[arg(long, conflicts_with = "wasm", required_unless_present_any = ["wasm", "dry_run"])]
+        local_id: Option<String>,
+
+        /// Deploy this WASM to the sandbox and replay against it
         #[arg(long)]
-        previous_version: Option<String>,
+        wasm: Option<String>,
 
-        /// Path to CHANGELOG.md file (auto-detected if present in cwd)
+        /// Network passphrase of the sandbox
+        #[arg(long, default_value = "Standalone Network ; February 2017")]
+        network_passphrase: String,
+
+        /// Source identity used to deploy and invoke
+        #[arg(long, default_value = "default")]
+        source: String,
+
+        /// Only replay events from this ledger onwards
         #[arg(long)]
-        changelog: Option<String>,
+        from_ledger: Option<i64>,
 
-        /// On-chain contract address to include in notes
+        /// Maximum number of historical events to fetch
+        #[arg(long, default_value_t = 200)]
+        limit: i64,
+
+        /// Print the reconstructed invocations without sending them
         #[arg(long)]
-        contract_address: Option<String>,
+        dry_run: bool,
 
-        /// Output results as JSON
+        /// Stop at the first failed invocation
         #[arg(long)]
-        json: bool,
+        fail_fast: bool,
     },
+}
 
-    /// View generated release notes for a version
-    View {
-        /// Contract registry ID
-        #[arg(long)]
+/// Sub-commands for the `alerts` group
+#[derive(Debug, Subcommand)]
+pub enum AlertCommands {
+    /// Create an alert rule
+    Create {
+        /// Contract UUID or on-chain contract ID
         contract_id: String,
 
-        /// Version to view
+        /// Event topic to watch, e.g. admin_change
         #[arg(long)]
-        version: String,
+        topic: String,
+
+        /// Only count events whose payload matches path=value (e.g. new_admin=GABC...)
+        #[arg(long = "match")]
+        matching: Option<String>,
+
+        /// Events needed within the window to fire
+        #[arg(long, default_value_t = 1)]
+        threshold: i32,
 
+        /// Window length: seconds or a duration like 5m, 1h, 1d
+        #[arg(long, default_value = "5m")]
+        window: String,
+
+        /// HTTPS endpoint to POST to when the rule fires
+        #[arg(long)]
+        webhook: Option<String>,
+
+        /// Display name for the rule
+        #[arg(long)]
+        name: Option<String>,
+    },
+    /// List your alert rules
+    List {
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
+    /// Delete an alert rule
+    Delete {
+        /// Alert rule ID
+        alert_id: String,
+    },
+}
 
-    /// Edit draft release notes before publishing
-    Edit {
-        /// Contract registry ID
-        #[arg(long)]
-        contract_id: String,
+/// Sub-commands for the `telemetry` group
+#[derive(Debug, Subcommand)]
+pub enum TelemetryCommands {
+    /// Report command names, durations and outcomes (never arguments)
+    On,
+    /// Stop reporting and delete queued events
+    Off,
+    /// Show whether telemetry is on and what is queued
+    Status,
+}
 
-        /// Version to edit
-        #[arg(long)]
-        version: String,
+/// Sub-commands for the `tlog` group
+#[derive(Debug, Subcommand)]
+pub enum TlogCommands {
+    /// Prove a release is in the log and the log has only been appended to
+    Verify {
+        /// Contract registry UUID or on-chain contract ID
+        contract: String,
 
-        /// Path to a file containing the new release notes text
+        /// Version to check (defaults to the latest logged release)
         #[arg(long)]
-        file: Option<String>,
+        version: Option<String>,
 
-        /// Inline text for the release notes
+        /// Local WASM file that must match the logged hash
         #[arg(long)]
-        text: Option<String>,
+        wasm: Option<String>,
+    },
+}
 
+/// Sub-commands for the `trust` group
+#[derive(Debug, Subcommand)]
+pub enum TrustCommands {
+    /// Trust a publisher's public key
+    Add {
+        /// Base64 Ed25519 public key
+        public_key: String,
+
+        /// Name to show for the key
+        #[arg(long)]
+        label: Option<String>,
+    },
+    /// Stop trusting a key
+    Remove {
+        /// Public key, address, fingerprint or label of the key
+        key: String,
+    },
+    /// List trusted keys
+    List {
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
+}
 
-    /// Publish (finalize) release notes
-    Publish {
-        /// Contract registry ID
-        #[arg(long)]
-        contract_id: String,
+/// Sub-commands for the `policy` group
+#[derive(Debug, Subcommand)]
+pub enum PolicyCommands {
+    /// Show an organization's publish policy
+    Show {
+        /// Organization slug or UUID
+        org: String,
 
-        /// Version to publish
+        /// Output as JSON
         #[arg(long)]
-        version: String,
+        json: bool,
+    },
+    /// Evaluate the local project; exits non-zero on any violation
+    Check {
+        /// Organization slug or UUID
+        #[arg(long)]
+        org: String,
 
-        /// Skip updating the contract_versions.release_notes column
+        /// Contract project directory
+        #[arg(long, default_value = ".")]
+        contract_path: String,
+
+        /// Built WASM to size-check (defaults to the release build)
         #[arg(long)]
-        skip_version_update: bool,
+        wasm: Option<String>,
 
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
+}
 
-    /// List all release notes for a contract
+/// Sub-commands for the `badges` group
+#[derive(Debug, Subcommand)]
+pub enum BadgeCommands {
+    /// Show a contract's badges and who attested them
     List {
-        /// Contract registry ID
-        #[arg(long)]
+        /// Contract registry UUID or on-chain contract ID
         contract_id: String,
 
+        /// Include revoked and expired badges
+        #[arg(long)]
+        all: bool,
+
         /// Output as JSON
         #[arg(long)]
         json: bool,
     },
-}
+    /// Sign and submit an attestation for the contract's current build
+    Attest {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-/// Sub-commands for the `cicd` group
-#[derive(Debug, Subcommand)]
-pub enum CicdCommands {
-    /// Run a full CI/CD pipeline (validate, scan, build, publish, verify)
-    Run {
-        /// Path to contract directory
-        #[arg(long, default_value = ".")]
-        contract_path: String,
+        /// Badge to grant
+        #[arg(long, value_parser = shared::attestation::BADGE_KINDS)]
+        kind: String,
 
-        /// Network to target (testnet|mainnet)
-        #[arg(long, default_value = "testnet")]
-        network: String,
+        /// Authority's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
 
-        /// Skip security scans
+        /// HTTPS link to the audit report or other evidence
         #[arg(long)]
-        skip_scan: bool,
-
-        /// Auto-register contract if not found in registry
-        #[arg(long, default_value_t = true)]
-        auto_register: bool,
+        report_url: Option<String>,
 
-        /// Output results as JSON
+        /// Let the badge lapse after this many days
         #[arg(long)]
-        json: bool,
+        expires_in_days: Option<i64>,
     },
+    /// Withdraw a badge (as its authority or an admin)
+    Revoke {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-    /// Validate the current environment for CI/CD integration
-    Validate {
-        /// Path to contract directory
-        #[arg(long, default_value = ".")]
-        contract_path: String,
+        /// Badge ID, as shown by `badges list`
+        badge_id: String,
+
+        /// Why the badge is withdrawn
+        #[arg(long)]
+        reason: Option<String>,
+
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
     },
 }
 
+/// Arguments of a `fuzz` run against a local WASM build
+#[derive(Debug, Args)]
+pub struct FuzzRunArgs {
+    #[arg(long)]
+    contract_path: String,
+    #[arg(long)]
+    duration: u64,
+    #[arg(long)]
+    timeout: u64,
+    #[arg(long)]
+    threads: u32,
+    #[arg(long)]
+    max_cases: u32,
+    #[arg(long)]
+    output: String,
+    #[arg(long)]
+    minimize: bool,
+}
+
+/// Sub-commands for the `fuzz` group
 #[derive(Debug, Subcommand)]
-pub enum ConfigSubcommands {
-    Get {
+pub enum FuzzCommands {
+    /// Generate a cargo-fuzz harness with one target per contract function
+    Init {
+        /// Contract registry UUID or on-chain contract ID, or a local .wasm or JSON spec
+        contract: String,
+
+        /// Contract version whose ABI is used (defaults to the latest)
         #[arg(long)]
-        contract_id: String,
+        version: Option<String>,
+
+        /// Directory to create the harness in
+        #[arg(long, default_value = "fuzz")]
+        output: String,
+
+        /// Cargo package name of the contract crate (defaults to the contract name)
+        #[arg(long = "crate")]
+        crate_name: Option<String>,
+
+        /// The `#[contract]` struct (defaults to the contract name in PascalCase)
         #[arg(long)]
-        environment: String,
+        contract_type: Option<String>,
+
+        /// soroban-sdk version the harness depends on
+        #[arg(long, default_value = fuzz_harness::DEFAULT_SDK_VERSION)]
+        sdk_version: String,
     },
-    Set {
-        #[arg(long)]
+    /// Submit a signed fuzzing campaign summary for a contract build
+    Attest {
+        /// Contract registry UUID or on-chain contract ID
         contract_id: String,
+
+        /// fuzz-report.json written by `soroban-registry fuzz`
         #[arg(long)]
-        environment: String,
-        #[arg(long)]
-        config_data: String,
-        #[arg(long)]
-        secrets_data: Option<String>,
+        from_report: Option<String>,
+
+        /// Fuzzer that ran the campaign (default: cargo-fuzz)
         #[arg(long)]
-        created_by: String,
-    },
-    History {
+        engine: Option<String>,
+
+        /// Number of fuzz targets exercised
         #[arg(long)]
-        contract_id: String,
+        targets: Option<u32>,
+
+        /// Total inputs executed across all targets
         #[arg(long)]
-        environment: String,
-    },
-    Rollback {
+        executions: Option<u64>,
+
+        /// How long the campaign ran, e.g. 30m or 2h
         #[arg(long)]
-        contract_id: String,
+        duration: Option<String>,
+
+        /// Distinct crashing inputs found
         #[arg(long)]
-        environment: String,
+        crashes: Option<u32>,
+
+        /// Build that was fuzzed (defaults to the contract's current WASM hash)
         #[arg(long)]
-        version: i32,
+        wasm_hash: Option<String>,
+
+        /// Publisher's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
+
+        /// Output as JSON
         #[arg(long)]
-        created_by: String,
+        json: bool,
     },
 }
 
-/// Sub-commands for the `contracts` group
+/// Sub-commands for the `nft` group
 #[derive(Debug, Subcommand)]
-pub enum ContractsCommands {
-    /// List contracts with filtering and pagination
-    List {
-        /// Filter by network (mainnet, testnet, futurenet)
-        #[arg(long)]
-        network: Option<String>,
+pub enum NftCommands {
+    /// List the collection's tokens, optionally those held by one owner
+    Tokens {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-        /// Filter by category (e.g., DEX, token, lending, oracle)
+        /// Only tokens currently held by this address
         #[arg(long)]
-        category: Option<String>,
-
-        /// Maximum number of contracts to return
-        #[arg(long, default_value = "20")]
-        limit: usize,
-
-        /// Number of contracts to skip (for pagination)
-        #[arg(long, default_value = "0")]
-        offset: usize,
+        owner: Option<String>,
 
-        /// Sort by field: name, created_at, health_score, network
-        #[arg(long, default_value = "created_at")]
-        sort_by: String,
+        /// Include burned tokens
+        #[arg(long)]
+        include_burned: bool,
 
-        /// Sort order: asc or desc
-        #[arg(long, default_value = "desc")]
-        sort_order: String,
+        /// Continue after this token ID (printed at the end of the previous page)
+        #[arg(long)]
+        after: Option<String>,
 
-        /// Output format: table, json, or csv
-        #[arg(long, default_value = "table")]
-        format: String,
+        /// Tokens per page (max 500)
+        #[arg(long, default_value = "100")]
+        limit: u32,
 
-        /// Output results as JSON (shorthand for --format json)
+        /// Output as JSON
         #[arg(long)]
         json: bool,
+    },
+    /// Show one token: its owner, metadata URI and history
+    Show {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-        /// Output results as CSV (shorthand for --format csv)
+        /// Token ID
+        token_id: String,
+
+        /// Output as JSON
         #[arg(long)]
-        csv: bool,
+        json: bool,
     },
 }
 
-/// Sub-commands for the `sla` group
+/// Sub-commands for the `oracle` group
 #[derive(Debug, Subcommand)]
-pub enum SlaCommands {
-    /// Record hourly SLA metrics for a contract
-    Record {
-        /// Contract identifier
-        id: String,
-        /// Uptime percentage (0-100)
-        uptime: f64,
-        /// Average latency in milliseconds
-        latency: f64,
-        /// Error rate percentage (0-100)
-        error_rate: f64,
+pub enum OracleCommands {
+    /// Show the feed's cadence, last update and freshness
+    Show {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
-    /// Show real-time SLA compliance dashboard
-    Status {
-        /// Contract identifier
-        id: String,
+    /// Declare how often the feed updates (publisher only)
+    Declare {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Expected update interval (e.g. 30s, 5m, 1h, 1d)
+        #[arg(long)]
+        every: String,
+
+        /// Extra slack before the feed counts as stale (e.g. 1m)
+        #[arg(long)]
+        grace: Option<String>,
+
+        /// Event topic that counts as an update (repeatable; any event when omitted)
+        #[arg(long = "topic")]
+        topics: Vec<String>,
+
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Stop tracking the feed's freshness (publisher only)
+    Remove {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
     },
 }
 
-/// Sub-commands for the `multisig` group
+/// Sub-commands for the `disclosures` group
 #[derive(Debug, Subcommand)]
-pub enum MultisigCommands {
-    /// Create a new multi-sig policy (defines signers and required threshold)
-    CreatePolicy {
-        #[arg(long)]
-        name: String,
-        #[arg(long)]
-        threshold: u32,
+pub enum DisclosureCommands {
+    /// Send a vulnerability report to the contract's publisher
+    Submit {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+
+        /// One-line summary of the issue
         #[arg(long)]
-        signers: String,
+        title: String,
+
+        /// File with the full report (e.g. Markdown)
         #[arg(long)]
-        expiry_secs: Option<u32>,
+        file: String,
+
+        /// How the publisher can reach you; omit to stay anonymous
         #[arg(long)]
-        created_by: String,
+        contact: Option<String>,
     },
+    /// List reports submitted for your contract
+    List {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-    /// Create an unsigned deployment proposal
-    CreateProposal {
-        #[arg(long)]
-        contract_name: String,
+        /// Only reports in this status
+        #[arg(long, value_parser = ["new", "acknowledged", "resolved"])]
+        status: Option<String>,
+
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+
+        /// Publisher's secret key, to show report titles
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: Option<String>,
+
+        /// Output the sealed reports as JSON
         #[arg(long)]
+        json: bool,
+    },
+    /// Decrypt and show a report
+    Read {
+        /// Contract registry UUID or on-chain contract ID
         contract_id: String,
-        #[arg(long)]
-        wasm_hash: String,
-        #[arg(long, default_value = "testnet")]
-        network: String,
-        #[arg(long)]
-        policy_id: String,
-        #[arg(long)]
-        proposer: String,
-        #[arg(long)]
-        description: Option<String>,
-    },
 
-    /// Sign a deployment proposal (add your approval)
-    Sign {
-        proposal_id: String,
-        #[arg(long)]
-        signer: String,
-        #[arg(long)]
-        signature_data: Option<String>,
+        /// Report ID, as shown by `disclosures list`
+        report_id: String,
+
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+
+        /// Publisher's secret key (Stellar `S...` seed or base64 ed25519 seed)
+        #[arg(long, env = "SOROBAN_SECRET_KEY", hide_env_values = true)]
+        secret_key: String,
     },
+    /// Mark a report acknowledged or resolved
+    Status {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
 
-    /// Execute an approved deployment proposal
-    Execute { proposal_id: String },
+        /// Report ID, as shown by `disclosures list`
+        report_id: String,
 
-    /// Show full info for a proposal (signatures, policy, status)
-    Info { proposal_id: String },
+        #[arg(value_parser = ["new", "acknowledged", "resolved"])]
+        status: String,
 
-    /// List deployment proposals
-    ListProposals {
-        #[arg(long)]
-        status: Option<String>,
-        #[arg(long, default_value = "20")]
-        limit: usize,
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
     },
 }
 
-/// Sub-commands for the `incident` group
+/// Sub-commands for the `admin` group
 #[derive(Debug, Subcommand)]
-pub enum IncidentCommands {
-    /// Trigger a new incident for a contract
-    Trigger {
-        /// On-chain contract ID
-        contract_id: String,
-        /// Incident severity (critical|high|medium|low)
-        #[arg(long)]
-        severity: String,
+pub enum AdminCommands {
+    /// Export the entire registry to a portable archive
+    Dump {
+        /// Archive path (gzip'd JSON)
+        #[arg(long, short = 'o', default_value = "registry-dump.json.gz")]
+        output: String,
     },
-    /// Update the state of an existing incident
-    Update {
-        /// Incident UUID returned by trigger
-        incident_id: String,
-        /// New state (detected|responding|contained|recovered|post_review)
+    /// Import an archive produced by `admin dump`
+    Restore {
+        /// Archive path (gzip'd or plain JSON)
+        file: String,
+
+        /// Restore even if the archive was exported at a different schema version
         #[arg(long)]
-        state: String,
+        force: bool,
     },
 }
 
-/// Sub-commands for the `patch` group
+/// Sub-commands for the `abi` group
 #[derive(Debug, Subcommand)]
-pub enum PatchCommands {
-    /// Create a new security patch
-    Create {
-        #[arg(long)]
-        version: String,
-        #[arg(long)]
-        hash: String,
+pub enum AbiCommands {
+    /// Write the current contract spec to the snapshot file
+    Snapshot {
+        /// Path to the contract project directory
+        #[arg(long, default_value = ".")]
+        contract_path: String,
+        /// WASM to read the spec from (defaults to the release build artifact)
         #[arg(long)]
-        severity: String,
-        #[arg(long, default_value = "100")]
-        rollout: u8,
-    },
-    /// Notify subscribers about a patch
-    Notify {
+        wasm: Option<String>,
+        /// Snapshot file to write (defaults to <contract_path>/abi.snapshot.json)
         #[arg(long)]
-        patch_id: String,
+        output: Option<String>,
     },
-    /// Apply a patch to a specific contract
-    Apply {
+    /// Fail if the ABI changed without a matching version bump
+    Check {
+        /// Path to the contract project directory
+        #[arg(long, default_value = ".")]
+        contract_path: String,
+        /// WASM to read the spec from (defaults to the release build artifact)
         #[arg(long)]
-        contract_id: String,
+        wasm: Option<String>,
+        /// Snapshot file to compare against
         #[arg(long)]
-        patch_id: String,
-    },
-    /// Manage contract dependencies
-    Deps {
-        #[command(subcommand)]
-        command: DepsCommands,
+        snapshot: Option<String>,
     },
 }
 
+/// Sub-commands for the `network` group
 #[derive(Debug, Subcommand)]
-pub enum DepsCommands {
-    /// List dependencies for a contract
-    List {
-        /// Contract ID
-        contract_id: String,
+pub enum NetworkCommands {
+    /// Show status of all supported Stellar networks
+    Status {
+        /// Output results as machine-readable JSON
+        #[arg(long)]
+        json: bool,
     },
 }
 
+/// Sub-commands for the `release-notes` group
 #[derive(Debug, Subcommand)]
-pub enum KeysCommands {
-    /// Generate a new Ed25519 keypair for signing
-    Generate {},
+pub enum ReleaseNotesCommands {
+    /// Auto-generate release notes from code diff and changelog
+    Generate {
+        /// Contract registry ID (UUID or on-chain ID)
+        #[arg(long)]
+        contract_id: String,
 
-    /// Revoke a signature
-    Revoke {
-        /// Signature ID to revoke
-        signature_id: String,
-        /// Address of the revoker
+        /// Version to generate notes for (semver, e.g. 1.2.0)
         #[arg(long)]
-        revoked_by: String,
-        /// Reason for revocation
+        version: String,
+
+        /// Previous version to diff against (auto-detected if omitted)
         #[arg(long)]
-        reason: String,
-    },
+        previous_version: Option<String>,
 
-    /// Show chain of custody for a contract
-    Custody {
-        /// Contract ID
-        contract_id: String,
-    },
+        /// Path to CHANGELOG.md file (auto-detected if present in cwd)
+        #[arg(long)]
+        changelog: Option<String>,
 
-    /// View transparency log
-    Log {
-        /// Filter by contract ID
+        /// On-chain contract address to include in notes
         #[arg(long)]
-        contract_id: Option<String>,
-        /// Filter by entry type
+        contract_address: Option<String>,
+
+        /// Output results as JSON
         #[arg(long)]
-        entry_type: Option<String>,
-        /// Maximum entries to show
-        #[arg(long, default_value = "20")]
-        limit: usize,
+        json: bool,
     },
-}
 
-/// Sub-commands for the `contract` group (#522)
-#[derive(Debug, Subcommand)]
-pub enum ContractCommands {
-    /// Verify a deployed contract's authenticity against the on-chain registry
-    ///
-    /// Usage: soroban-registry contract verify <address> --network <network> [--json]
-    Verify {
-        /// On-chain contract address to verify
-        address: String,
+    /// View generated release notes for a version
+    View {
+        /// Contract registry ID
+        #[arg(long)]
+        contract_id: String,
 
-        /// Stellar network (mainnet | testnet | futurenet)
-        #[arg(long, default_value = "mainnet")]
-        network: String,
+        /// Version to view
+        #[arg(long)]
+        version: String,
 
-        /// Output results as machine-readable JSON
+        /// Output as JSON
         #[arg(long)]
         json: bool,
     },
-}
 
-/// Sub-commands for the `webhook` group
-#[derive(Debug, Subcommand)]
-pub enum WebhookCommands {
-    /// Register a new webhook subscription
-    Create {
-        /// Endpoint URL to receive events (must be HTTPS in production)
+    /// Edit draft release notes before publishing
+    Edit {
+        /// Contract registry ID
         #[arg(long)]
-        url: String,
+        contract_id: String,
 
-        /// Comma-separated list of events to subscribe to.
-        /// Valid: contract.published, contract.verified,
-        ///        contract.failed_verification, version.created
+        /// Version to edit
         #[arg(long)]
-        events: String,
+        version: String,
 
-        /// Optional HMAC-SHA256 secret key (auto-generated if omitted)
+        /// Path to a file containing the new release notes text
         #[arg(long)]
-        secret: Option<String>,
-    },
-
-    /// List all registered webhooks
-    List {},
+        file: Option<String>,
 
-    /// Delete a webhook by ID
-    Delete {
-        /// Webhook ID to delete
-        webhook_id: String,
-    },
+        /// Inline text for the release notes
+        #[arg(long)]
+        text: Option<String>,
 
-    /// Send a test event to a webhook
-    Test {
-        /// Webhook ID to test
-        webhook_id: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
     },
 
-    /// View delivery logs for a webhook
-    Logs {
-        /// Webhook ID
-        webhook_id: String,
+    /// Publish (finalize) release notes
+    Publish {
+        /// Contract registry ID
+        #[arg(long)]
+        contract_id: String,
 
-        /// Maximum number of log entries to show
-        #[arg(long, default_value = "20")]
-        limit: usize,
-    },
+        /// Version to publish
+        #[arg(long)]
+        version: String,
 
-    /// Manually retry a dead-letter delivery
-    Retry {
-        /// Delivery ID to retry
-        delivery_id: String,
-    },
+        /// Skip updating the contract_versions.release_notes column
+        #[arg(long)]
+        skip_version_update: bool,
 
-    /// Verify a webhook payload signature locally
-    VerifySig {
-        /// HMAC secret key used for signing
+        /// Output as JSON
         #[arg(long)]
-        secret: String,
+        json: bool,
+    },
 
-        /// Raw JSON payload body
+    /// List all release notes for a contract
+    List {
+        /// Contract registry ID
         #[arg(long)]
-        payload: String,
+        contract_id: String,
 
-        /// Signature header value (e.g. sha256=abc123...)
+        /// Output as JSON
         #[arg(long)]
-        signature: String,
+        json: bool,
     },
 }
 
-/// Sub-commands for the `migrate` group
+/// Sub-commands for the `cicd` group
 #[derive(Debug, Subcommand)]
-pub enum MigrateCommands {
-    /// Preview migration outcome (dry-run)
-    Preview { old_id: String, new_id: String },
-    /// Analyze schema differences between versions
-    Analyze { old_id: String, new_id: String },
-    /// Generate migration script template (rust|js)
-    Generate {
-        old_id: String,
-        new_id: String,
-        #[arg(long, default_value = "rust")]
-        language: String,
-        #[arg(long)]
-        output: Option<String>,
-    },
-    /// Validate migration for data loss risks
-    Validate { old_id: String, new_id: String },
-    /// Apply migration and record history
-    Apply { old_id: String, new_id: String },
-    /// Rollback a migration by migration ID
+pub enum CicdCommands {
+    /// Run a full CI/CD pipeline (validate, scan, build, publish, verify)
+    Run {
+        /// Path to contract directory
+        #[arg(long, default_value = ".")]
+        contract_path: String,
+
+        /// Network to target (testnet|mainnet)
+        #[arg(long, default_value = "testnet")]
+        network: String,
+
+        /// Skip security scans
+        #[arg(long)]
+        skip_scan: bool,
+
+        /// Auto-register contract if not found in registry
+        #[arg(long, default_value_t = true)]
+        auto_register: bool,
+
+        /// Output results as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Validate the current environment for CI/CD integration
+    Validate {
+        /// Path to contract directory
+        #[arg(long, default_value = ".")]
+        contract_path: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ConfigSubcommands {
+    Get {
+        #[arg(long)]
+        contract_id: String,
+        #[arg(long)]
+        environment: String,
+    },
+    Set {
+        #[arg(long)]
+        contract_id: String,
+        #[arg(long)]
+        environment: String,
+        #[arg(long)]
+        config_data: String,
+        #[arg(long)]
+        secrets_data: Option<String>,
+        #[arg(long)]
+        created_by: String,
+    },
+    History {
+        #[arg(long)]
+        contract_id: String,
+        #[arg(long)]
+        environment: String,
+    },
+    Rollback {
+        #[arg(long)]
+        contract_id: String,
+        #[arg(long)]
+        environment: String,
+        #[arg(long)]
+        version: i32,
+        #[arg(long)]
+        created_by: String,
+    },
+}
+
+/// Sub-commands for the `contracts` group
+#[derive(Debug, Subcommand)]
+pub enum ContractsCommands {
+    /// List contracts with filtering and pagination
+    List {
+        /// Filter by network (mainnet, testnet, futurenet)
+        #[arg(long)]
+        network: Option<String>,
+
+        /// Filter by category (e.g., DEX, token, lending, oracle)
+        #[arg(long)]
+        category: Option<String>,
+
+        /// Maximum number of contracts to return
+        #[arg(long, default_value = "20")]
+        limit: usize,
+
+        /// Number of contracts to skip (for pagination)
+        #[arg(long, default_value = "0")]
+        offset: usize,
+
+        /// Sort by field: name, created_at, health_score, quality, network
+        #[arg(long, default_value = "created_at")]
+        sort_by: String,
+
+        /// Sort order: asc or desc
+        #[arg(long, default_value = "desc")]
+        sort_order: String,
+
+        /// Output format: table, json, or csv
+        #[arg(long, default_value = "table")]
+        format: String,
+
+        /// Output results as JSON (shorthand for --format json)
+        #[arg(long)]
+        json: bool,
+
+        /// Output results as CSV (shorthand for --format csv)
+        #[arg(long)]
+        csv: bool,
+    },
+}
+
+/// Sub-commands for the `sla` group
+#[derive(Debug, Subcommand)]
+pub enum SlaCommands {
+    /// Record hourly SLA metrics for a contract
+    Record {
+        /// Contract identifier
+        id: String,
+        /// Uptime percentage (0-100)
+        uptime: f64,
+        /// Average latency in milliseconds
+        latency: f64,
+        /// Error rate percentage (0-100)
+        error_rate: f64,
+    },
+    /// Show real-time SLA compliance dashboard
+    Status {
+        /// Contract identifier
+        id: String,
+    },
+}
+
+/// Sub-commands for the `multisig` group
+#[derive(Debug, Subcommand)]
+pub enum MultisigCommands {
+    /// Create a new multi-sig policy (defines signers and required threshold)
+    CreatePolicy {
+        #[arg(long)]
+        name: String,
+        /// Required approvals (defaults to the template's or cloned policy's)
+        #[arg(long, required_unless_present_any = ["template", "clone"])]
+        threshold: Option<u32>,
+        /// Comma-separated signer addresses (defaults to the cloned policy's)
+        #[arg(long, required_unless_present = "clone")]
+        signers: Option<String>,
+        #[arg(long)]
+        expiry_secs: Option<u32>,
+        /// Delay between approval and execution of a proposal
+        #[arg(long)]
+        timelock_secs: Option<u32>,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account becomes the policy's owner
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        /// Start from a built-in template (see `multisig templates`)
+        #[arg(long, conflicts_with = "clone")]
+        template: Option<String>,
+        /// Copy the threshold, signers, expiry and timelock of an existing policy
+        #[arg(long, value_name = "POLICY_ID")]
+        clone: Option<String>,
+    },
+
+    /// List the built-in policy templates
+    Templates,
+
+    /// Create an unsigned deployment proposal
+    CreateProposal {
+        #[arg(long)]
+        contract_name: String,
+        #[arg(long)]
+        contract_id: String,
+        #[arg(long)]
+        wasm_hash: String,
+        #[arg(long, default_value = "testnet")]
+        network: String,
+        #[arg(long)]
+        policy_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account is the proposer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// Create a proposal deploying or migrating several contracts in order
+    CreateBatch {
+        /// Label for the batch
+        #[arg(long)]
+        name: String,
+        #[arg(long, default_value = "testnet")]
+        network: String,
+        #[arg(long)]
+        policy_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account is the proposer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        /// JSON array of {action: deploy|migrate, contract_id, wasm_hash, description}
+        #[arg(long)]
+        steps_file: String,
+        #[arg(long)]
+        description: Option<String>,
+    },
+
+    /// Report the outcome of one step of an approved batch proposal
+    Step {
+        proposal_id: String,
+        /// Step index, starting at 0
+        index: u32,
+        /// executed, failed or rolled-back
+        #[arg(long)]
+        outcome: String,
+        #[arg(long)]
+        tx_hash: Option<String>,
+        /// What went wrong, for a failed step
+        #[arg(long)]
+        error: Option<String>,
+        /// Bearer token of one of the policy's signers
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Propose a privileged registry action for M-of-N approval
+    ProposeAction {
+        /// apply-patch, deprecate-contract, transfer-ownership or amend-policy
+        action: String,
+        #[arg(long)]
+        policy_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account is the proposer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        #[arg(long)]
+        description: Option<String>,
+        /// Contract acted on (UUID or on-chain ID)
+        #[arg(long)]
+        contract: Option<String>,
+        /// Security patch to apply (apply-patch)
+        #[arg(long)]
+        patch_id: Option<String>,
+        /// Retirement time: RFC 3339 or a span such as 30d (deprecate-contract)
+        #[arg(long)]
+        retire_at: Option<String>,
+        /// Contract replacing the deprecated one
+        #[arg(long)]
+        replacement: Option<String>,
+        /// Migration guide URL for the deprecated contract
+        #[arg(long)]
+        migration_guide: Option<String>,
+        #[arg(long)]
+        notes: Option<String>,
+        /// Stellar address taking over the contract (transfer-ownership)
+        #[arg(long)]
+        new_owner: Option<String>,
+        /// New threshold (amend-policy)
+        #[arg(long)]
+        threshold: Option<u32>,
+        /// New comma-separated signer addresses (amend-policy)
+        #[arg(long)]
+        signers: Option<String>,
+        /// New proposal expiry (amend-policy)
+        #[arg(long)]
+        expiry_secs: Option<u32>,
+        /// New timelock (amend-policy)
+        #[arg(long)]
+        timelock_secs: Option<u32>,
+    },
+
+    /// Sign a deployment proposal (add your approval)
+    Sign {
+        proposal_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account signs, for itself or a signer who delegated to it
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        #[arg(long)]
+        signature_data: Option<String>,
+    },
+
+    /// Comment on a deployment proposal's discussion thread
+    Comment {
+        proposal_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account must be the proposer or a signer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        #[arg(long)]
+        body: String,
+        /// ID of the comment being answered
+        #[arg(long)]
+        reply_to: Option<String>,
+    },
+
+    /// Execute an approved deployment proposal (policy signers only)
+    Execute {
+        proposal_id: String,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Show full info for a proposal (signatures, policy, status)
+    Info { proposal_id: String },
+
+    /// List deployment proposals
+    ListProposals {
+        #[arg(long)]
+        status: Option<String>,
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Let another address sign in your place for a limited time
+    Delegate {
+        /// Policy the delegation applies to
+        policy_id: String,
+        /// Bearer token from the registry's wallet login; the signed-in
+        /// account must be a signer and hands over its seat
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        /// Address that may sign on their behalf
+        #[arg(long)]
+        to: String,
+        /// End of the delegation: RFC 3339 time or a span such as 14d or 36h
+        #[arg(long)]
+        until: String,
+    },
+
+    /// List a policy's signing delegations
+    Delegations { policy_id: String },
+
+    /// Revoke a signing delegation before it expires
+    RevokeDelegation {
+        delegation_id: String,
+        /// Bearer token of the delegating signer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Register where a signer is notified when a proposal awaits them
+    Channels {
+        #[arg(long)]
+        signer: String,
+        /// Bearer token from the registry's wallet login for the same signer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        /// HTTPS webhook to call; omit to receive inbox notifications only
+        #[arg(long)]
+        webhook_url: Option<String>,
+    },
+
+    /// Show a signer's notification inbox
+    Notifications {
+        #[arg(long)]
+        signer: String,
+        /// Bearer token from the registry's wallet login for the same signer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+        /// Only notifications not yet acknowledged
+        #[arg(long)]
+        unread: bool,
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Mark a signer notification as read
+    Ack {
+        notification_id: String,
+        /// Bearer token of the notified signer
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Require a multisig policy for an organization's deployments on a network
+    SetNetworkPolicy {
+        /// Organization slug or UUID
+        #[arg(long)]
+        org: String,
+        #[arg(long)]
+        network: String,
+        #[arg(long)]
+        policy_id: String,
+        /// Bearer token of an organization admin
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Show which networks an organization gates behind multisig
+    NetworkPolicies {
+        /// Organization slug or UUID
+        #[arg(long)]
+        org: String,
+        /// Bearer token; needed for private organizations
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+
+    /// Let an organization's deployments on a network run without approval again
+    ClearNetworkPolicy {
+        /// Organization slug or UUID
+        #[arg(long)]
+        org: String,
+        #[arg(long)]
+        network: String,
+        /// Bearer token of an organization admin
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+}
+
+/// Sub-commands for the `incident` group
+#[derive(Debug, Subcommand)]
+pub enum IncidentCommands {
+    /// Trigger a new incident for a contract
+    Trigger {
+        /// On-chain contract ID
+        contract_id: String,
+        /// Incident severity (critical|high|medium|low)
+        #[arg(long)]
+        severity: String,
+    },
+    /// Update the state of an existing incident
+    Update {
+        /// Incident UUID returned by trigger
+        incident_id: String,
+        /// New state (detected|responding|contained|recovered|post_review)
+        #[arg(long)]
+        state: String,
+    },
+}
+
+/// Sub-commands for the `patch` group
+#[derive(Debug, Subcommand)]
+pub enum PatchCommands {
+    /// Create a new security patch
+    Create {
+        #[arg(long)]
+        version: String,
+        #[arg(long)]
+        hash: String,
+        #[arg(long)]
+        severity: String,
+        #[arg(long, default_value = "100")]
+        rollout: u8,
+    },
+    /// Notify subscribers about a patch
+    Notify {
+        #[arg(long)]
+        patch_id: String,
+    },
+    /// Show the ABI and function-size changes a patch makes to a contract
+    Diff {
+        /// Security patch ID
+        patch_id: String,
+        /// Contract (UUID or on-chain ID) whose current build is compared
+        #[arg(long)]
+        contract: String,
+        /// Local copy of the patched WASM, if it is not uploaded to the network yet
+        #[arg(long)]
+        wasm: Option<String>,
+        /// Output the diff as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Apply a patch to a specific contract after a preflight check, or
+    /// schedule it for every affected contract with --all
+    Apply {
+        #[arg(long, required_unless_present = "all")]
+        contract_id: Option<String>,
+        #[arg(long)]
+        patch_id: String,
+        /// Patched WASM build, to diff its ABI against the current version
+        #[arg(long)]
+        wasm: Option<String>,
+        /// Apply even if preflight checks fail (the rollout quota still applies)
+        #[arg(long)]
+        force: bool,
+        /// Schedule a rollout to every contract on the patch's target build
+        #[arg(long, conflicts_with_all = ["contract_id", "wasm", "force"])]
+        all: bool,
+        /// With --all: maintenance window, e.g. "Sat 02:00-04:00 UTC" (default: now)
+        #[arg(long, requires = "all")]
+        window: Option<String>,
+        /// Admin bearer token (required with --all)
+        #[arg(long, env = "SOROBAN_REGISTRY_ADMIN_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+    /// Show the progress of a patch's scheduled rollout
+    Status {
+        #[arg(long)]
+        patch_id: String,
+        /// Output the rollout as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Pause a patch's scheduled rollout
+    Pause {
+        #[arg(long)]
+        patch_id: String,
+        /// Admin bearer token
+        #[arg(long, env = "SOROBAN_REGISTRY_ADMIN_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Resume a paused rollout
+    Resume {
+        #[arg(long)]
+        patch_id: String,
+        /// Admin bearer token
+        #[arg(long, env = "SOROBAN_REGISTRY_ADMIN_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Manage contract dependencies
+    Deps {
+        #[command(subcommand)]
+        command: DepsCommands,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum DepsCommands {
+    /// List dependencies for a contract
+    List {
+        /// Contract registry UUID or on-chain contract ID
+        contract_id: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum KeysCommands {
+    /// Generate a new Ed25519 keypair for signing
+    Generate {},
+
+    /// Revoke a signature
+    Revoke {
+        /// Signature ID to revoke
+        signature_id: String,
+        /// Address of the revoker
+        #[arg(long)]
+        revoked_by: String,
+        /// Reason for revocation
+        #[arg(long)]
+        reason: String,
+    },
+
+    /// Show chain of custody for a contract
+    Custody {
+        /// Contract ID
+        contract_id: String,
+    },
+
+    /// View transparency log
+    Log {
+        /// Filter by contract ID
+        #[arg(long)]
+        contract_id: Option<String>,
+        /// Filter by entry type
+        #[arg(long)]
+        entry_type: Option<String>,
+        /// Maximum entries to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// List a publisher's registered signing keys (yours by default)
+    List {
+        /// Publisher UUID
+        #[arg(long)]
+        publisher: Option<String>,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Register a signing key for your publisher account
+    Register {
+        /// Base64 Ed25519 private key to register; signs the proof of possession
+        #[arg(long, env = "SOROBAN_REGISTRY_SIGNING_KEY", hide_env_values = true)]
+        secret_key: String,
+        /// Name to show for the key
+        #[arg(long)]
+        label: Option<String>,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Replace a signing key, keeping the old one valid for an overlap
+    Rotate {
+        /// Key to replace: its fingerprint, public key or ID
+        key: String,
+        /// Base64 Ed25519 private key of the replacement
+        #[arg(long, env = "SOROBAN_REGISTRY_SIGNING_KEY", hide_env_values = true)]
+        secret_key: String,
+        /// Name to show for the new key; the old key's when omitted
+        #[arg(long)]
+        label: Option<String>,
+        /// Hours the old key stays valid [default: 168]
+        #[arg(long)]
+        overlap_hours: Option<i64>,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Revoke a signing key, and the signatures made with it since
+    RevokeKey {
+        /// Key to revoke: its fingerprint, public key or ID
+        key: String,
+        /// Why the key is revoked
+        #[arg(long)]
+        reason: String,
+        /// When the key was compromised (RFC 3339), if earlier than now
+        #[arg(long)]
+        since: Option<chrono::DateTime<chrono::Utc>>,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum RecoveryCommands {
+    /// Ask to move an account whose key was lost to a new key
+    Start {
+        /// Stellar address of the account whose key was lost
+        #[arg(long)]
+        account: String,
+        /// Base64 Ed25519 private key of the new account
+        #[arg(long, env = "SOROBAN_REGISTRY_RECOVERY_KEY", hide_env_values = true)]
+        secret_key: String,
+        /// Why the account is being recovered
+        #[arg(long)]
+        reason: String,
+        /// When the old key may have been compromised (RFC 3339); what it
+        /// signed since is revoked
+        #[arg(long)]
+        since: Option<chrono::DateTime<chrono::Utc>>,
+    },
+
+    /// Approve or reject a recovery, with a signing key or as an org admin
+    Approve {
+        /// Recovery ID
+        id: String,
+        /// Reject the recovery instead
+        #[arg(long)]
+        reject: bool,
+        /// Comment recorded with the decision
+        #[arg(long)]
+        comment: Option<String>,
+        /// Base64 Ed25519 private key of one of the publisher's other keys
+        #[arg(long, env = "SOROBAN_REGISTRY_SIGNING_KEY", hide_env_values = true)]
+        secret_key: Option<String>,
+        /// Bearer token of an org admin, used without --secret-key
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+
+    /// Show a recovery and its approvals
+    Status {
+        /// Recovery ID
+        id: String,
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Cancel a recovery of your account
+    Cancel {
+        /// Recovery ID
+        id: String,
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+
+    /// Carry out an approved recovery once its timelock has passed
+    Execute {
+        /// Recovery ID
+        id: String,
+    },
+}
+
+/// Sub-commands for the `contract` group (#522)
+#[derive(Debug, Subcommand)]
+pub enum ContractCommands {
+    /// Verify a deployed contract's authenticity against the on-chain registry
+    ///
+    /// Usage: soroban-registry contract verify <address> --network <network> [--json]
+    Verify {
+        /// On-chain contract address to verify
+        address: String,
+
+        /// Stellar network (mainnet | testnet | futurenet)
+        #[arg(long, default_value = "mainnet")]
+        network: String,
+
+        /// Output results as machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+/// Sub-commands for the `webhook` group
+#[derive(Debug, Subcommand)]
+pub enum WebhookCommands {
+    /// Register a new webhook subscription
+    Create {
+        /// Endpoint URL to receive events (must be HTTPS in production)
+        #[arg(long)]
+        url: String,
+
+        /// Comma-separated list of events to subscribe to.
+        /// Valid: contract.published, contract.verified,
+        ///        contract.failed_verification, version.created
+        #[arg(long)]
+        events: String,
+
+        /// Optional HMAC-SHA256 secret key (auto-generated if omitted)
+        #[arg(long)]
+        secret: Option<String>,
+    },
+
+    /// List all registered webhooks
+    List {},
+
+    /// Delete a webhook by ID
+    Delete {
+        /// Webhook ID to delete
+        webhook_id: String,
+    },
+
+    /// Send a test event to a webhook
+    Test {
+        /// Webhook ID to test
+        webhook_id: String,
+    },
+
+    /// View delivery logs for a webhook
+    Logs {
+        /// Webhook ID
+        webhook_id: String,
+
+        /// Maximum number of log entries to show
+        #[arg(long, default_value = "20")]
+        limit: usize,
+    },
+
+    /// Manually retry a dead-letter delivery
+    Retry {
+        /// Delivery ID to retry
+        delivery_id: String,
+    },
+
+    /// Verify a webhook payload signature locally
+    VerifySig {
+        /// HMAC secret key used for signing
+        #[arg(long)]
+        secret: String,
+
+        /// Raw JSON payload body
+        #[arg(long)]
+        payload: String,
+
+        /// Signature header value (e.g. sha256=abc123...)
+        #[arg(long)]
+        signature: String,
+    },
+}
+
+/// Sub-commands for the `migrate` group
+#[derive(Debug, Subcommand)]
+pub enum MigrateCommands {
+    /// Preview migration outcome (dry-run)
+    Preview { old_id: String, new_id: String },
+    /// Analyze schema differences between versions
+    Analyze { old_id: String, new_id: String },
+    /// Generate migration script template (rust|js)
+    Generate {
+        old_id: String,
+        new_id: String,
+        #[arg(long, default_value = "rust")]
+        language: String,
+        #[arg(long)]
+        output: Option<String>,
+    },
+    /// Validate migration for data loss risks
+    Validate { old_id: String, new_id: String },
+    /// Apply migration and record history
+    Apply {
+        old_id: String,
+        new_id: String,
+        /// Deploy the new WASM to a canary and run smoke tests first; the
+        /// migration only proceeds if they all pass
+        #[arg(long, requires_all = ["wasm", "smoke_tests"])]
+        canary: bool,
+        /// New contract WASM for the canary
+        #[arg(long, requires = "canary")]
+        wasm: Option<String>,
+        /// JSON file listing the smoke-test invocations
+        #[arg(long, requires = "canary")]
+        smoke_tests: Option<String>,
+        /// Upgrade this staging contract instead of deploying a fresh canary
+        #[arg(long, requires = "canary")]
+        staging: Option<String>,
+        /// RPC URL the canary is deployed to
+        #[arg(long, default_value = "http://localhost:8000/soroban/rpc")]
+        rpc_url: String,
+        /// Network passphrase of the canary network
+        #[arg(long, default_value = "Standalone Network ; February 2017")]
+        network_passphrase: String,
+        /// Source identity used to deploy and invoke
+        #[arg(long, default_value = "default")]
+        source: String,
+    },
+    /// Swap a contract's WASM and record the migration in the registry
+    Upgrade {
+        contract_id: String,
+        /// New contract WASM
+        #[arg(long)]
+        wasm: String,
+        /// Function to invoke right after the swap, e.g. migrate or init_v2
+        #[arg(long)]
+        post_upgrade: Option<String>,
+        /// Named arguments for the post-upgrade function as a JSON object
+        #[arg(long, requires = "post_upgrade")]
+        post_upgrade_args: Option<String>,
+        /// Simulate a failed WASM swap
+        #[arg(long)]
+        simulate_fail: bool,
+        /// Simulate a failed post-upgrade hook
+        #[arg(long, requires = "post_upgrade")]
+        simulate_hook_fail: bool,
+        /// Hash the WASM without contacting the registry
+        #[arg(long)]
+        dry_run: bool,
+        /// Bearer token of the publisher or an account granted run_migrations
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: Option<String>,
+    },
+    /// Rollback a migration by migration ID
     Rollback { migration_id: String },
     /// Show migration history
     History {
@@ -1105,9 +2655,188 @@ pub enum MigrateCommands {
     },
 }
 
+/// Sub-commands for the `migrations` group
+#[derive(Debug, Subcommand)]
+pub enum MigrationsCommands {
+    /// List a contract's migrations, newest first
+    List {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+        /// Filter by status (pending, success, failed, rolled_back)
+        #[arg(long)]
+        status: Option<String>,
+        /// Only migrations started at or after this RFC 3339 time
+        #[arg(long)]
+        since: Option<String>,
+        /// Only migrations started before this RFC 3339 time
+        #[arg(long)]
+        until: Option<String>,
+        #[arg(long, default_value_t = 1)]
+        page: i64,
+        #[arg(long, default_value_t = 20)]
+        limit: i64,
+        /// Output results as machine-readable JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a migration's log output
+    Logs {
+        migration_id: String,
+        /// Stream new output live until the migration finishes
+        #[arg(long, short = 'f')]
+        follow: bool,
+    },
+}
+
+/// Sub-commands for the `group` group
+#[derive(Debug, Subcommand)]
+pub enum GroupCommands {
+    /// List every deployment in a contract's group
+    Show {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Add another network's deployment to a contract's group
+    Link {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Deployment of the same codebase on another network
+        other: String,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Take a contract out of its group
+    Unlink {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+}
+
+/// Sub-commands for the `permissions` group
+#[derive(Debug, Subcommand)]
+pub enum PermissionsCommands {
+    /// List who holds which capability on a contract
+    List {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Grant an account capabilities on a contract
+    Grant {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Stellar address receiving the capabilities
+        principal: String,
+
+        /// Capabilities to grant
+        #[arg(long = "capability", required = true, value_parser = permissions::CAPABILITIES)]
+        capabilities: Vec<String>,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Take a capability back from an account
+    Revoke {
+        /// Contract UUID or on-chain contract ID
+        contract_id: String,
+
+        /// Stellar address holding the capability
+        principal: String,
+
+        /// Capability to revoke
+        #[arg(long, value_parser = permissions::CAPABILITIES)]
+        capability: String,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+}
+
+/// Sub-commands for the `publisher` group
+#[derive(Debug, Subcommand)]
+pub enum PublisherCommands {
+    /// Show the publisher's email and whether it is verified
+    Email {
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Email a verification code, or confirm one with --code
+    VerifyEmail {
+        /// Verify this address instead of the current one (it replaces the
+        /// current email once confirmed)
+        #[arg(long, conflicts_with = "code")]
+        email: Option<String>,
+
+        /// Code from the verification email
+        #[arg(long)]
+        code: Option<String>,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Set the display name, pronouns and avatar shown on your contracts
+    /// (pass an empty string to clear one)
+    Profile {
+        /// Name shown instead of the username
+        #[arg(long)]
+        display_name: Option<String>,
+
+        /// e.g. "she/her", "they/them"
+        #[arg(long)]
+        pronouns: Option<String>,
+
+        /// HTTPS image URL; without one, the Gravatar of your verified email
+        /// is used
+        #[arg(long)]
+        avatar_url: Option<String>,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+    },
+    /// Show the storage your sources take up against your quota
+    Usage {
+        /// Publisher ID or Stellar address
+        publisher: String,
+
+        /// Bearer token from the registry's wallet login
+        #[arg(long, env = "SOROBAN_REGISTRY_TOKEN", hide_env_values = true)]
+        token: String,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+    },
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    let cli = Cli::parse();
+    let matches = Cli::command().get_matches();
+    let api_url_is_default = matches.value_source("api_url") == Some(ValueSource::DefaultValue);
+    let mut cli = Cli::from_arg_matches(&matches).unwrap_or_else(|e| e.exit());
 
     // ── Initialise logger ─────────────────────────────────────────────────────
     // --verbose / -v  →  DEBUG level (shows HTTP calls, payloads, timing)
@@ -1120,9 +2849,40 @@ async fn main() -> Result<()> {
         .init();
 
     log::debug!("Verbose mode enabled");
+
+    if let Some(name) = cli.profile.as_deref() {
+        let profile = config::load_profile(name)?;
+        // An explicit --api-url / SOROBAN_REGISTRY_API_URL wins over the profile.
+        if let (true, Some(api_base)) = (api_url_is_default, &profile.api_base) {
+            cli.api_url = api_base.clone();
+        }
+        http::configure(&profile)?;
+        log::debug!("Using profile '{}'", name);
+    }
+
+    if cli.mock {
+        cli.api_url = mock_server::start().await?;
+        eprintln!(
+            "{}",
+            "Mock mode: responses come from built-in fixtures; nothing is sent or stored."
+                .yellow()
+        );
+    }
     log::debug!("API URL: {}", cli.api_url);
 
-    handle_command(cli).await
+    if !cli.mock && !matches!(cli.command, Commands::SelfUpdate { .. }) {
+        self_update::startup_check(&cli.api_url).await;
+    }
+
+    let command = telemetry::command_path(&matches);
+    let report = !cli.mock && !matches!(cli.command, Commands::Telemetry { .. });
+    let api_url = cli.api_url.clone();
+    let started = std::time::Instant::now();
+    let result = handle_command(cli).await;
+    if report {
+        telemetry::record(&api_url, &command, started.elapsed(), result.is_ok()).await;
+    }
+    result
 }
 
 pub async fn handle_command(cli: Cli) -> Result<()> {
@@ -1154,11 +2914,105 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
             println!("{}", "Warning: Shell already running".yellow());
             return Ok(());
         }
+        Commands::Events {
+            action: Some(action),
+            ..
+        } => match action {
+            EventCommands::Replay {
+                contract_id,
+                target,
+                local_id,
+                wasm,
+                network_passphrase,
+                source,
+                from_ledger,
+                limit,
+                dry_run,
+                fail_fast,
+            } => {
+                log::debug!(
+                    "Command: events replay | contract_id={} target={} dry_run={}",
+                    contract_id,
+                    target,
+                    dry_run
+                );
+                replay::run(
+                    &cli.api_url,
+                    replay::ReplayOptions {
+                        contract: &contract_id,
+                        target: &target,
+                        network_passphrase: &network_passphrase,
+                        local_id: local_id.as_deref(),
+                        wasm: wasm.as_deref(),
+                        source: &source,
+                        from_ledger,
+                        limit,
+                        dry_run,
+                        fail_fast,
+                    },
+                )
+                .await?;
+            }
+        },
+        Commands::Events {
+            action: None,
+            contract_ids,
+            tag,
+            category,
+            topic,
+            filter,
+            limit,
+            offset,
+            export,
+            stats,
+            anomalies,
+            kind,
+        } => {
+            log::debug!(
+                "Command: events | contract_ids={:?} tag={:?} category={:?} topic={:?} limit={} anomalies={}",
+                contract_ids,
+                tag,
+                category,
+                topic,
+                limit,
+                anomalies
+            );
+            let selector = events::EventSelector {
+                contract_ids: &contract_ids,
+                tag: tag.as_deref(),
+                category: category.as_deref(),
+                network: cli.network.as_deref(),
+            };
+            if anomalies {
+                let Some(contract_id) = selector.single() else {
+                    anyhow::bail!("--anomalies is only available for a single contract");
+                };
+                events::query_anomalies(&cli.api_url, contract_id, kind.as_deref(), limit).await?;
+            } else {
+                events::query_events(
+                    &cli.api_url,
+                    &selector,
+                    topic.as_deref(),
+                    filter.as_deref(),
+                    limit,
+                    offset,
+                    export.as_deref(),
+                    stats,
+                )
+                .await?;
+            }
+        }
         Commands::Search {
             query,
             verified_only,
             network: filter_networks,
             category,
+            deployed_on,
+            badge,
+            oracle_freshness,
+            sdk_version,
+            protocol,
+            sort,
             limit,
             offset,
             json,
@@ -1180,6 +3034,12 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 verified_only,
                 networks_vec,
                 category.as_deref(),
+                deployed_on.as_deref(),
+                badge.as_deref(),
+                oracle_freshness.as_deref(),
+                sdk_version.as_deref(),
+                protocol,
+                sort.as_deref(),
                 limit,
                 offset,
                 json,
@@ -1218,6 +3078,7 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
             test_command,
             require_coverage,
             coverage_threshold,
+            min_docs_coverage,
             skip_tests,
         } => {
             let tags_vec = tags
@@ -1243,18 +3104,53 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 test_command.as_deref(),
                 require_coverage,
                 coverage_threshold,
+                min_docs_coverage,
                 skip_tests,
             )
             .await?;
         }
+        Commands::Claim {
+            contract_id,
+            secret_key,
+        } => {
+            log::debug!("Command: claim | contract_id={}", contract_id);
+            claim::run(&cli.api_url, &contract_id, &secret_key).await?;
+        }
+        Commands::Login { secret_key, json } => {
+            log::debug!("Command: login");
+            login::run(&cli.api_url, &secret_key, json).await?;
+        }
+        Commands::VerifyAnchor {
+            contract_id,
+            anchor_account,
+            horizon_url,
+        } => {
+            log::debug!("Command: verify-anchor | contract_id={}", contract_id);
+            anchor::verify(
+                &cli.api_url,
+                &contract_id,
+                anchor_account.as_deref(),
+                horizon_url.as_deref(),
+            )
+            .await?;
+        }
         Commands::List { limit, json } => {
             log::debug!("Command: list | limit={}", limit);
             commands::list(&cli.api_url, limit, network, json).await?;
         }
+        Commands::Dashboard {
+            publisher: Some(publisher_id),
+            json,
+            ..
+        } => {
+            log::debug!("Command: dashboard | publisher={}", publisher_id);
+            dashboard::publisher_stats(&cli.api_url, &publisher_id, json).await?;
+        }
         Commands::Dashboard {
             refresh_rate,
             category,
             ws_url,
+            ..
         } => {
             log::debug!(
                 "Command: dashboard | refresh_rate={} network={:?} category={:?}",
@@ -1313,49 +3209,670 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 );
                 migration::generate_template(&old_id, &new_id, &language, output.as_deref())?;
             }
-            MigrateCommands::Validate { old_id, new_id } => {
-                log::debug!(
-                    "Command: migrate validate | old_id={} new_id={}",
-                    old_id,
-                    new_id
-                );
-                migration::validate(&old_id, &new_id)?;
+            MigrateCommands::Validate { old_id, new_id } => {
+                log::debug!(
+                    "Command: migrate validate | old_id={} new_id={}",
+                    old_id,
+                    new_id
+                );
+                migration::validate(&old_id, &new_id)?;
+            }
+            MigrateCommands::Apply {
+                old_id,
+                new_id,
+                canary,
+                wasm,
+                smoke_tests,
+                staging,
+                rpc_url,
+                network_passphrase,
+                source,
+            } => {
+                log::debug!(
+                    "Command: migrate apply | old_id={} new_id={} canary={}",
+                    old_id,
+                    new_id,
+                    canary
+                );
+                match (canary, wasm, smoke_tests) {
+                    (true, Some(wasm), Some(smoke_tests)) => canary::run(
+                        &old_id,
+                        &new_id,
+                        canary::CanaryOptions {
+                            wasm: &wasm,
+                            smoke_tests: &smoke_tests,
+                            staging: staging.as_deref(),
+                            rpc_url: &rpc_url,
+                            network_passphrase: &network_passphrase,
+                            source: &source,
+                        },
+                    )?,
+                    _ => migration::apply(&old_id, &new_id)?,
+                }
+            }
+            MigrateCommands::Upgrade {
+                contract_id,
+                wasm,
+                post_upgrade,
+                post_upgrade_args,
+                simulate_fail,
+                simulate_hook_fail,
+                dry_run,
+                token,
+            } => {
+                log::debug!(
+                    "Command: migrate upgrade | contract_id={} wasm={} post_upgrade={:?}",
+                    contract_id,
+                    wasm,
+                    post_upgrade
+                );
+                let args = post_upgrade_args
+                    .map(|raw| serde_json::from_str(&raw))
+                    .transpose()
+                    .map_err(|e| anyhow::anyhow!("Invalid --post-upgrade-args JSON: {}", e))?;
+                let hook = post_upgrade
+                    .map(|function| shared::models::PostUpgradeHook { function, args });
+                commands::migrate(
+                    &cli.api_url,
+                    &contract_id,
+                    &wasm,
+                    hook,
+                    simulate_fail,
+                    simulate_hook_fail,
+                    dry_run,
+                    token.as_deref(),
+                )
+                .await?;
+            }
+            MigrateCommands::Rollback { migration_id } => {
+                log::debug!("Command: migrate rollback | migration_id={}", migration_id);
+                migration::rollback(&migration_id)?;
+            }
+            MigrateCommands::History { limit } => {
+                log::debug!("Command: migrate history | limit={}", limit);
+                migration::history(limit)?;
+            }
+        },
+        Commands::Migrations { action } => match action {
+            MigrationsCommands::List {
+                contract_id,
+                status,
+                since,
+                until,
+                page,
+                limit,
+                json,
+            } => {
+                log::debug!(
+                    "Command: migrations list | contract_id={} status={:?}",
+                    contract_id,
+                    status
+                );
+                migrations::list(
+                    &cli.api_url,
+                    &contract_id,
+                    migrations::MigrationFilter {
+                        status: status.as_deref(),
+                        since: since.as_deref(),
+                        until: until.as_deref(),
+                        page,
+                        limit,
+                    },
+                    json,
+                )
+                .await?;
+            }
+            MigrationsCommands::Logs {
+                migration_id,
+                follow,
+            } => {
+                log::debug!(
+                    "Command: migrations logs | migration_id={} follow={}",
+                    migration_id,
+                    follow
+                );
+                migrations::logs(&cli.api_url, &migration_id, follow).await?;
+            }
+        },
+        Commands::Group { action } => match action {
+            GroupCommands::Show { contract_id, json } => {
+                log::debug!("Command: group show | contract_id={}", contract_id);
+                contract_group::show(&cli.api_url, &contract_id, json).await?;
+            }
+            GroupCommands::Link {
+                contract_id,
+                other,
+                token,
+            } => {
+                log::debug!(
+                    "Command: group link | contract_id={} other={}",
+                    contract_id,
+                    other
+                );
+                contract_group::link(&cli.api_url, &token, &contract_id, &other).await?;
+            }
+            GroupCommands::Unlink { contract_id, token } => {
+                log::debug!("Command: group unlink | contract_id={}", contract_id);
+                contract_group::unlink(&cli.api_url, &token, &contract_id).await?;
+            }
+        },
+        Commands::Permissions { action } => match action {
+            PermissionsCommands::List { contract_id, json } => {
+                log::debug!("Command: permissions list | contract_id={}", contract_id);
+                permissions::list(&cli.api_url, &contract_id, json).await?;
+            }
+            PermissionsCommands::Grant {
+                contract_id,
+                principal,
+                capabilities,
+                token,
+            } => {
+                log::debug!(
+                    "Command: permissions grant | contract_id={} principal={} capabilities={:?}",
+                    contract_id,
+                    principal,
+                    capabilities
+                );
+                permissions::grant(&cli.api_url, &token, &contract_id, &principal, &capabilities)
+                    .await?;
+            }
+            PermissionsCommands::Revoke {
+                contract_id,
+                principal,
+                capability,
+                token,
+            } => {
+                log::debug!(
+                    "Command: permissions revoke | contract_id={} principal={} capability={}",
+                    contract_id,
+                    principal,
+                    capability
+                );
+                permissions::revoke(&cli.api_url, &token, &contract_id, &principal, &capability)
+                    .await?;
+            }
+        },
+        Commands::Undo { list, json, token } => {
+            log::debug!("Command: undo | list={}", list);
+            if list {
+                undo::list(&cli.api_url, &token, json).await?;
+            } else {
+                undo::undo(&cli.api_url, &token, json).await?;
+            }
+        }
+        Commands::Publisher { action } => match action {
+            PublisherCommands::Email { token, json } => {
+                log::debug!("Command: publisher email");
+                publisher::email_status(&cli.api_url, &token, json).await?;
+            }
+            PublisherCommands::VerifyEmail { email, code, token } => {
+                log::debug!(
+                    "Command: publisher verify-email | email={:?} code_given={}",
+                    email,
+                    code.is_some()
+                );
+                publisher::verify_email(&cli.api_url, &token, email.as_deref(), code.as_deref())
+                    .await?;
+            }
+            PublisherCommands::Profile {
+                display_name,
+                pronouns,
+                avatar_url,
+                token,
+            } => {
+                log::debug!("Command: publisher profile");
+                publisher::update_profile(
+                    &cli.api_url,
+                    &token,
+                    display_name.as_deref(),
+                    pronouns.as_deref(),
+                    avatar_url.as_deref(),
+                )
+                .await?;
+            }
+            PublisherCommands::Usage {
+                publisher,
+                token,
+                json,
+            } => {
+                log::debug!("Command: publisher usage | publisher={}", publisher);
+                publisher::usage(&cli.api_url, &token, &publisher, json).await?;
+            }
+        },
+        Commands::Promote {
+            contract_id,
+            from,
+            to,
+            wasm_hash,
+            wasm,
+            min_soak_hours,
+            link,
+            token,
+        } => {
+            log::debug!(
+                "Command: promote | contract_id={} from={} to={}",
+                contract_id,
+                from,
+                to
+            );
+            let wasm_hash = match wasm {
+                Some(path) => Some(promote::wasm_hash(&path)?),
+                None => wasm_hash,
+            };
+            promote::run(
+                &cli.api_url,
+                &token,
+                &contract_id,
+                promote::PromoteOptions {
+                    from: &from,
+                    to: &to,
+                    wasm_hash,
+                    min_soak_hours,
+                    link: link.as_deref(),
+                },
+            )
+            .await?;
+        }
+        Commands::SelfUpdate {
+            channel,
+            check,
+            release_key,
+        } => {
+            log::debug!("Command: self-update | channel={} check={}", channel, check);
+            self_update::run(&cli.api_url, &channel, check, release_key.as_deref()).await?;
+        }
+        Commands::Policy { token, action } => match action {
+            PolicyCommands::Show { org, json } => {
+                log::debug!("Command: policy show | org={}", org);
+                policy::show(&cli.api_url, &org, token.as_deref(), json).await?;
+            }
+            PolicyCommands::Check {
+                org,
+                contract_path,
+                wasm,
+                json,
+            } => {
+                log::debug!(
+                    "Command: policy check | org={} contract_path={}",
+                    org,
+                    contract_path
+                );
+                policy::check(
+                    &cli.api_url,
+                    &org,
+                    &contract_path,
+                    wasm.as_deref(),
+                    token.as_deref(),
+                    json,
+                )
+                .await?;
+            }
+        },
+
+        Commands::Audits {
+            contract_id,
+            version,
+            json,
+        } => {
+            log::debug!("Command: audits | contract_id={}", contract_id);
+            audits::show(&cli.api_url, &contract_id, version.as_deref(), json).await?;
+        }
+        Commands::Report {
+            contract_id,
+            from,
+            to,
+            format,
+            output,
+        } => {
+            log::debug!(
+                "Command: report | contract_id={} from={} to={} format={}",
+                contract_id,
+                from,
+                to,
+                format
+            );
+            report::accounting(
+                &cli.api_url,
+                &contract_id,
+                &from,
+                &to,
+                &format,
+                output.as_deref(),
+            )
+            .await?;
+        }
+        Commands::Compliance {
+            contract_id,
+            format,
+            output,
+        } => {
+            log::debug!(
+                "Command: compliance | contract_id={} format={}",
+                contract_id,
+                format
+            );
+            compliance::report(&cli.api_url, &contract_id, &format, output.as_deref()).await?;
+        }
+        Commands::Badges { action } => match action {
+            BadgeCommands::List {
+                contract_id,
+                all,
+                json,
+            } => {
+                log::debug!("Command: badges list | contract_id={}", contract_id);
+                badges::list(&cli.api_url, &contract_id, all, json).await?;
+            }
+            BadgeCommands::Attest {
+                contract_id,
+                kind,
+                secret_key,
+                report_url,
+                expires_in_days,
+            } => {
+                log::debug!(
+                    "Command: badges attest | contract_id={} kind={}",
+                    contract_id,
+                    kind
+                );
+                badges::attest(
+                    &cli.api_url,
+                    &contract_id,
+                    &kind,
+                    &secret_key,
+                    report_url.as_deref(),
+                    expires_in_days,
+                )
+                .await?;
+            }
+            BadgeCommands::Revoke {
+                contract_id,
+                badge_id,
+                reason,
+                token,
+            } => {
+                log::debug!(
+                    "Command: badges revoke | contract_id={} badge_id={}",
+                    contract_id,
+                    badge_id
+                );
+                badges::revoke(
+                    &cli.api_url,
+                    &contract_id,
+                    &badge_id,
+                    reason.as_deref(),
+                    &token,
+                )
+                .await?;
+            }
+        },
+
+        Commands::Disclosures { action } => match action {
+            DisclosureCommands::Submit {
+                contract_id,
+                title,
+                file,
+                contact,
+            } => {
+                log::debug!("Command: disclosures submit | contract_id={}", contract_id);
+                disclosures::submit(&cli.api_url, &contract_id, &title, &file, contact.as_deref())
+                    .await?;
+            }
+            DisclosureCommands::List {
+                contract_id,
+                status,
+                token,
+                secret_key,
+                json,
+            } => {
+                log::debug!("Command: disclosures list | contract_id={}", contract_id);
+                disclosures::list(
+                    &cli.api_url,
+                    &contract_id,
+                    status.as_deref(),
+                    &token,
+                    secret_key.as_deref(),
+                    json,
+                )
+                .await?;
+            }
+            DisclosureCommands::Read {
+                contract_id,
+                report_id,
+                token,
+                secret_key,
+            } => {
+                log::debug!(
+                    "Command: disclosures read | contract_id={} report_id={}",
+                    contract_id,
+                    report_id
+                );
+                disclosures::read(&cli.api_url, &contract_id, &report_id, &token, &secret_key)
+                    .await?;
+            }
+            DisclosureCommands::Status {
+                contract_id,
+                report_id,
+                status,
+                token,
+            } => {
+                log::debug!(
+                    "Command: disclosures status | contract_id={} report_id={} status={}",
+                    contract_id,
+                    report_id,
+                    status
+                );
+                disclosures::set_status(&cli.api_url, &contract_id, &report_id, &status, &token)
+                    .await?;
+            }
+        },
+        Commands::Quality { contract_id, json } => {
+            log::debug!("Command: quality | contract_id={}", contract_id);
+            quality::show(&cli.api_url, &contract_id, json).await?;
+        }
+        Commands::Whohas { hash, json } => {
+            log::debug!("Command: whohas | hash={}", hash);
+            whohas::show(&cli.api_url, &hash, json).await?;
+        }
+        Commands::Defi { contract_id, json } => {
+            log::debug!("Command: defi | contract_id={}", contract_id);
+            defi::show(&cli.api_url, &contract_id, json).await?;
+        }
+        Commands::Nft { action } => match action {
+            NftCommands::Tokens {
+                contract_id,
+                owner,
+                include_burned,
+                after,
+                limit,
+                json,
+            } => {
+                log::debug!(
+                    "Command: nft tokens | contract_id={} owner={:?}",
+                    contract_id,
+                    owner
+                );
+                nft::tokens(
+                    &cli.api_url,
+                    &contract_id,
+                    owner.as_deref(),
+                    include_burned,
+                    after.as_deref(),
+                    limit,
+                    json,
+                )
+                .await?;
+            }
+            NftCommands::Show {
+                contract_id,
+                token_id,
+                json,
+            } => {
+                log::debug!(
+                    "Command: nft show | contract_id={} token_id={}",
+                    contract_id,
+                    token_id
+                );
+                nft::show(&cli.api_url, &contract_id, &token_id, json).await?;
+            }
+        },
+        Commands::Oracle { action } => match action {
+            OracleCommands::Show { contract_id, json } => {
+                log::debug!("Command: oracle show | contract_id={}", contract_id);
+                oracle::show(&cli.api_url, &contract_id, json).await?;
+            }
+            OracleCommands::Declare {
+                contract_id,
+                every,
+                grace,
+                topics,
+                token,
+            } => {
+                log::debug!(
+                    "Command: oracle declare | contract_id={} every={}",
+                    contract_id,
+                    every
+                );
+                oracle::declare(
+                    &cli.api_url,
+                    &contract_id,
+                    &every,
+                    grace.as_deref(),
+                    &topics,
+                    &token,
+                )
+                .await?;
+            }
+            OracleCommands::Remove { contract_id, token } => {
+                log::debug!("Command: oracle remove | contract_id={}", contract_id);
+                oracle::remove(&cli.api_url, &contract_id, &token).await?;
+            }
+        },
+        Commands::AttestTests {
+            contract_id,
+            from_junit,
+            suite,
+            coverage,
+            wasm_hash,
+            secret_key,
+            json,
+        } => {
+            log::debug!(
+                "Command: attest-tests | contract_id={} from_junit={}",
+                contract_id,
+                from_junit
+            );
+            test_runs::attest(
+                &cli.api_url,
+                &contract_id,
+                &from_junit,
+                suite.as_deref(),
+                coverage,
+                wasm_hash.as_deref(),
+                &secret_key,
+                json,
+            )
+            .await?;
+        }
+
+        Commands::Telemetry { action } => match action {
+            TelemetryCommands::On => {
+                log::debug!("Command: telemetry on");
+                telemetry::enable()?;
+            }
+            TelemetryCommands::Off => {
+                log::debug!("Command: telemetry off");
+                telemetry::disable()?;
+            }
+            TelemetryCommands::Status => {
+                log::debug!("Command: telemetry status");
+                telemetry::status(&cli.api_url)?;
             }
-            MigrateCommands::Apply { old_id, new_id } => {
+        },
+        Commands::Tlog { action } => match action {
+            TlogCommands::Verify {
+                contract,
+                version,
+                wasm,
+            } => {
                 log::debug!(
-                    "Command: migrate apply | old_id={} new_id={}",
-                    old_id,
-                    new_id
+                    "Command: tlog verify | contract={} version={:?}",
+                    contract,
+                    version
                 );
-                migration::apply(&old_id, &new_id)?;
+                tlog::verify(&cli.api_url, &contract, version.as_deref(), wasm.as_deref()).await?;
             }
-            MigrateCommands::Rollback { migration_id } => {
-                log::debug!("Command: migrate rollback | migration_id={}", migration_id);
-                migration::rollback(&migration_id)?;
+        },
+        Commands::Trust { action } => match action {
+            TrustCommands::Add { public_key, label } => {
+                log::debug!("Command: trust add | label={:?}", label);
+                trust::add(&public_key, label.as_deref())?;
             }
-            MigrateCommands::History { limit } => {
-                log::debug!("Command: migrate history | limit={}", limit);
-                migration::history(limit)?;
+            TrustCommands::Remove { key } => {
+                log::debug!("Command: trust remove | key={}", key);
+                trust::remove(&key)?;
+            }
+            TrustCommands::List { json } => {
+                log::debug!("Command: trust list");
+                trust::list(json)?;
             }
         },
+        Commands::Toolchains { all, json } => {
+            log::debug!("Command: toolchains | all={}", all);
+            toolchains::list(&cli.api_url, all, json).await?;
+        }
         Commands::Export {
             id,
             output,
             contract_dir,
+            profile,
+            sign_key,
         } => {
-            log::debug!("Command: export | id={} output={}", id, output);
-            commands::export(&cli.api_url, &id, &output, &contract_dir).await?;
+            log::debug!(
+                "Command: export | id={} output={} profile={} signed={}",
+                id,
+                output,
+                profile,
+                sign_key.is_some()
+            );
+            commands::export(
+                &cli.api_url,
+                &id,
+                &output,
+                &contract_dir,
+                &profile,
+                sign_key.as_deref(),
+            )
+            .await?;
         }
         Commands::Import {
             archive,
             output_dir,
+            list,
+            only,
+            require_signed,
+            require_trusted,
         } => {
             log::debug!(
-                "Command: import | archive={} output_dir={}",
+                "Command: import | archive={} output_dir={} list={} only={:?}",
                 archive,
-                output_dir
+                output_dir,
+                list,
+                only
             );
-            commands::import(&cli.api_url, &archive, network, &output_dir).await?;
+            let policy = trust::TrustPolicy {
+                require_signed,
+                require_trusted,
+            };
+            commands::import(
+                &cli.api_url,
+                &archive,
+                network,
+                &output_dir,
+                list,
+                &only,
+                policy,
+            )
+            .await?;
         }
         Commands::Doc {
             contract_path,
@@ -1381,9 +3898,98 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
             );
             commands::openapi(&contract_path, &output, &format)?;
         }
-        Commands::Wizard {} => {
-            log::debug!("Command: wizard");
-            wizard::run(&cli.api_url).await?;
+        Commands::Abi { action } => match action {
+            AbiCommands::Snapshot {
+                contract_path,
+                wasm,
+                output,
+            } => {
+                log::debug!("Command: abi snapshot | contract_path={}", contract_path);
+                abi_snapshot::snapshot(&contract_path, wasm.as_deref(), output.as_deref())?;
+            }
+            AbiCommands::Check {
+                contract_path,
+                wasm,
+                snapshot,
+            } => {
+                log::debug!("Command: abi check | contract_path={}", contract_path);
+                abi_snapshot::check(&contract_path, wasm.as_deref(), snapshot.as_deref())?;
+            }
+        },
+        Commands::New {
+            name,
+            template,
+            path,
+            token,
+        } => {
+            log::debug!("Command: new | name={} template={}", name, template);
+            scaffold::run(
+                &cli.api_url,
+                &name,
+                &template,
+                path.as_deref(),
+                token.as_deref(),
+            )
+            .await?;
+        }
+        Commands::Clone {
+            contract,
+            version,
+            dir,
+            require_signed,
+            require_trusted,
+        } => {
+            log::debug!("Command: clone | contract={} version={:?}", contract, version);
+            let policy = trust::TrustPolicy {
+                require_signed,
+                require_trusted,
+            };
+            source_clone::run(
+                &cli.api_url,
+                &contract,
+                version.as_deref(),
+                dir.as_deref(),
+                policy,
+            )
+            .await?;
+        }
+        Commands::Bench {
+            target,
+            network,
+            source,
+            args,
+            contract,
+            version,
+            compare,
+            json,
+            token,
+        } => {
+            log::debug!(
+                "Command: bench | target={} network={} version={:?} compare={:?}",
+                target,
+                network,
+                version,
+                compare
+            );
+            bench::run(
+                &cli.api_url,
+                bench::BenchOptions {
+                    target: &target,
+                    network: &network,
+                    source: &source,
+                    args_file: args.as_deref(),
+                    contract: contract.as_deref(),
+                    version: version.as_deref(),
+                    compare: compare.as_deref(),
+                    json,
+                    token: token.as_deref(),
+                },
+            )
+            .await?;
+        }
+        Commands::Wizard { path, resume } => {
+            log::debug!("Command: wizard | path={} resume={}", path, resume);
+            wizard::run(&cli.api_url, &path, resume).await?;
         }
         Commands::History { search, limit } => {
             log::debug!("Command: history | search={:?} limit={}", search, limit);
@@ -1429,16 +4035,69 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 log::debug!("Command: patch notify | patch_id={}", patch_id);
                 commands::patch_notify(&cli.api_url, &patch_id).await?;
             }
+            PatchCommands::Diff {
+                patch_id,
+                contract,
+                wasm,
+                json,
+            } => {
+                log::debug!(
+                    "Command: patch diff | patch_id={} contract={}",
+                    patch_id,
+                    contract
+                );
+                patch_diff::diff(&cli.api_url, &patch_id, &contract, wasm.as_deref(), json)
+                    .await?;
+            }
             PatchCommands::Apply {
                 contract_id,
                 patch_id,
+                wasm,
+                force,
+                all,
+                window,
+                token,
             } => {
                 log::debug!(
-                    "Command: patch apply | contract_id={} patch_id={}",
+                    "Command: patch apply | contract_id={:?} patch_id={} all={} window={:?}",
                     contract_id,
-                    patch_id
+                    patch_id,
+                    all,
+                    window
                 );
-                commands::patch_apply(&cli.api_url, &contract_id, &patch_id).await?;
+                if all {
+                    let Some(token) = token else {
+                        anyhow::bail!(
+                            "--all requires an admin token (--token or SOROBAN_REGISTRY_ADMIN_TOKEN)"
+                        );
+                    };
+                    patch_rollout::schedule(&cli.api_url, &token, &patch_id, window.as_deref())
+                        .await?;
+                } else {
+                    let Some(contract_id) = contract_id else {
+                        anyhow::bail!("--contract-id is required unless --all is set");
+                    };
+                    commands::patch_apply(
+                        &cli.api_url,
+                        &contract_id,
+                        &patch_id,
+                        wasm.as_deref(),
+                        force,
+                    )
+                    .await?;
+                }
+            }
+            PatchCommands::Status { patch_id, json } => {
+                log::debug!("Command: patch status | patch_id={}", patch_id);
+                patch_rollout::status(&cli.api_url, &patch_id, json).await?;
+            }
+            PatchCommands::Pause { patch_id, token } => {
+                log::debug!("Command: patch pause | patch_id={}", patch_id);
+                patch_rollout::set_paused(&cli.api_url, &token, &patch_id, true).await?;
+            }
+            PatchCommands::Resume { patch_id, token } => {
+                log::debug!("Command: patch resume | patch_id={}", patch_id);
+                patch_rollout::set_paused(&cli.api_url, &token, &patch_id, false).await?;
             }
             PatchCommands::Deps { command } => match command {
                 DepsCommands::List { contract_id } => {
@@ -1453,33 +4112,47 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 threshold,
                 signers,
                 expiry_secs,
-                created_by,
+                timelock_secs,
+                token,
+                template,
+                clone,
             } => {
-                let signer_vec: Vec<String> =
-                    signers.split(',').map(|s| s.trim().to_string()).collect();
+                let signer_vec: Option<Vec<String>> = signers
+                    .map(|signers| signers.split(',').map(|s| s.trim().to_string()).collect());
                 log::debug!(
-                    "Command: multisig create-policy | name={} threshold={} signers={:?}",
+                    "Command: multisig create-policy | name={} threshold={:?} signers={:?} template={:?} clone={:?}",
                     name,
                     threshold,
-                    signer_vec
+                    signer_vec,
+                    template,
+                    clone
                 );
                 multisig::create_policy(
                     &cli.api_url,
-                    &name,
-                    threshold,
-                    signer_vec,
-                    expiry_secs,
-                    &created_by,
+                    multisig::CreatePolicyOptions {
+                        name: &name,
+                        threshold,
+                        signers: signer_vec,
+                        expiry_secs,
+                        timelock_secs,
+                        token: &token,
+                        template: template.as_deref(),
+                        clone: clone.as_deref(),
+                    },
                 )
                 .await?;
             }
+            MultisigCommands::Templates => {
+                log::debug!("Command: multisig templates");
+                multisig::list_templates();
+            }
             MultisigCommands::CreateProposal {
                 contract_name,
                 contract_id,
                 wasm_hash,
                 network: net_str,
                 policy_id,
-                proposer,
+                token,
                 description,
             } => {
                 log::debug!(
@@ -1494,28 +4167,143 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                     &wasm_hash,
                     &net_str,
                     &policy_id,
-                    &proposer,
+                    &token,
+                    description.as_deref(),
+                )
+                .await?;
+            }
+            MultisigCommands::ProposeAction {
+                action,
+                policy_id,
+                token,
+                description,
+                contract,
+                patch_id,
+                retire_at,
+                replacement,
+                migration_guide,
+                notes,
+                new_owner,
+                threshold,
+                signers,
+                expiry_secs,
+                timelock_secs,
+            } => {
+                log::debug!(
+                    "Command: multisig propose-action | action={} policy_id={}",
+                    action,
+                    policy_id
+                );
+                multisig::propose_action(
+                    &cli.api_url,
+                    multisig::ProposeActionOptions {
+                        action: &action,
+                        policy_id: &policy_id,
+                        token: &token,
+                        description: description.as_deref(),
+                        contract: contract.as_deref(),
+                        patch_id: patch_id.as_deref(),
+                        retire_at: retire_at.as_deref(),
+                        replacement: replacement.as_deref(),
+                        migration_guide: migration_guide.as_deref(),
+                        notes: notes.as_deref(),
+                        new_owner: new_owner.as_deref(),
+                        threshold,
+                        signers: signers.map(|signers| {
+                            signers.split(',').map(|s| s.trim().to_string()).collect()
+                        }),
+                        expiry_secs,
+                        timelock_secs,
+                    },
+                )
+                .await?;
+            }
+            MultisigCommands::CreateBatch {
+                name,
+                network: net_str,
+                policy_id,
+                token,
+                steps_file,
+                description,
+            } => {
+                log::debug!(
+                    "Command: multisig create-batch | name={} policy_id={}",
+                    name,
+                    policy_id
+                );
+                multisig::create_batch_proposal(
+                    &cli.api_url,
+                    &name,
+                    &net_str,
+                    &policy_id,
+                    &token,
+                    &steps_file,
                     description.as_deref(),
                 )
                 .await?;
             }
+            MultisigCommands::Step {
+                proposal_id,
+                index,
+                outcome,
+                tx_hash,
+                error,
+                token,
+            } => {
+                log::debug!(
+                    "Command: multisig step | proposal_id={} index={} outcome={}",
+                    proposal_id,
+                    index,
+                    outcome
+                );
+                multisig::report_step(
+                    &cli.api_url,
+                    &proposal_id,
+                    index,
+                    &outcome,
+                    tx_hash.as_deref(),
+                    error.as_deref(),
+                    &token,
+                )
+                .await?;
+            }
             MultisigCommands::Sign {
                 proposal_id,
-                signer,
+                token,
                 signature_data,
             } => {
                 log::debug!("Command: multisig sign | proposal_id={}", proposal_id);
                 multisig::sign_proposal(
                     &cli.api_url,
                     &proposal_id,
-                    &signer,
+                    &token,
                     signature_data.as_deref(),
                 )
                 .await?;
             }
-            MultisigCommands::Execute { proposal_id } => {
+            MultisigCommands::Comment {
+                proposal_id,
+                token,
+                body,
+                reply_to,
+            } => {
+                log::debug!(
+                    "Command: multisig comment | proposal_id={} reply_to={:?}",
+                    proposal_id,
+                    reply_to
+                );
+                multisig::comment_on_proposal(
+                    &cli.api_url,
+                    &proposal_id,
+                    &token,
+                    &body,
+                    reply_to.as_deref(),
+                )
+                .await?;
+            }
+            MultisigCommands::Execute { proposal_id, token } => {
                 log::debug!("Command: multisig execute | proposal_id={}", proposal_id);
-                multisig::execute_proposal(&cli.api_url, &proposal_id).await?;
+                multisig::execute_proposal(&cli.api_url, &proposal_id, &token).await?;
             }
             MultisigCommands::Info { proposal_id } => {
                 log::debug!("Command: multisig info | proposal_id={}", proposal_id);
@@ -1529,16 +4317,173 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 );
                 multisig::list_proposals(&cli.api_url, status.as_deref(), limit).await?;
             }
+            MultisigCommands::Delegate {
+                policy_id,
+                token,
+                to,
+                until,
+            } => {
+                log::debug!(
+                    "Command: multisig delegate | policy_id={} to={} until={}",
+                    policy_id,
+                    to,
+                    until
+                );
+                multisig::delegate(&cli.api_url, &policy_id, &token, &to, &until).await?;
+            }
+            MultisigCommands::Delegations { policy_id } => {
+                log::debug!("Command: multisig delegations | policy_id={}", policy_id);
+                multisig::list_delegations(&cli.api_url, &policy_id).await?;
+            }
+            MultisigCommands::RevokeDelegation {
+                delegation_id,
+                token,
+            } => {
+                log::debug!(
+                    "Command: multisig revoke-delegation | delegation_id={}",
+                    delegation_id
+                );
+                multisig::revoke_delegation(&cli.api_url, &delegation_id, &token).await?;
+            }
+            MultisigCommands::Channels {
+                signer,
+                token,
+                webhook_url,
+            } => {
+                log::debug!("Command: multisig channels | signer={}", signer);
+                multisig::set_channels(&cli.api_url, &signer, &token, webhook_url.as_deref())
+                    .await?;
+            }
+            MultisigCommands::Notifications {
+                signer,
+                token,
+                unread,
+                limit,
+            } => {
+                log::debug!(
+                    "Command: multisig notifications | signer={} unread={}",
+                    signer,
+                    unread
+                );
+                multisig::list_notifications(&cli.api_url, &signer, &token, unread, limit).await?;
+            }
+            MultisigCommands::Ack {
+                notification_id,
+                token,
+            } => {
+                log::debug!("Command: multisig ack | notification_id={}", notification_id);
+                multisig::acknowledge_notification(&cli.api_url, &notification_id, &token).await?;
+            }
+            MultisigCommands::SetNetworkPolicy {
+                org,
+                network,
+                policy_id,
+                token,
+            } => {
+                log::debug!(
+                    "Command: multisig set-network-policy | org={} network={} policy_id={}",
+                    org,
+                    network,
+                    policy_id
+                );
+                multisig::set_network_policy(&cli.api_url, &org, &network, &policy_id, &token)
+                    .await?;
+            }
+            MultisigCommands::NetworkPolicies { org, token } => {
+                log::debug!("Command: multisig network-policies | org={}", org);
+                multisig::list_network_policies(&cli.api_url, &org, token.as_deref()).await?;
+            }
+            MultisigCommands::ClearNetworkPolicy {
+                org,
+                network,
+                token,
+            } => {
+                log::debug!(
+                    "Command: multisig clear-network-policy | org={} network={}",
+                    org,
+                    network
+                );
+                multisig::clear_network_policy(&cli.api_url, &org, &network, &token).await?;
+            }
         },
         Commands::Fuzz {
-            contract_path,
-            duration,
-            timeout,
-            threads,
-            max_cases,
-            output,
-            minimize,
-        } => {
+            action: Some(action),
+            ..
+        } => match action {
+            FuzzCommands::Init {
+                contract,
+                version,
+                output,
+                crate_name,
+                contract_type,
+                sdk_version,
+            } => {
+                log::debug!(
+                    "Command: fuzz init | contract={} version={:?} output={}",
+                    contract,
+                    version,
+                    output
+                );
+                fuzz_harness::init(
+                    &cli.api_url,
+                    &contract,
+                    version.as_deref(),
+                    &output,
+                    crate_name.as_deref(),
+                    contract_type.as_deref(),
+                    &sdk_version,
+                )
+                .await?;
+            }
+            FuzzCommands::Attest {
+                contract_id,
+                from_report,
+                engine,
+                targets,
+                executions,
+                duration,
+                crashes,
+                wasm_hash,
+                secret_key,
+                json,
+            } => {
+                log::debug!(
+                    "Command: fuzz attest | contract_id={} from_report={:?}",
+                    contract_id,
+                    from_report
+                );
+                fuzz_campaigns::attest(
+                    &cli.api_url,
+                    fuzz_campaigns::AttestOptions {
+                        contract_id: &contract_id,
+                        from_report: from_report.as_deref(),
+                        engine: engine.as_deref(),
+                        targets,
+                        executions,
+                        duration: duration.as_deref(),
+                        crashes,
+                        wasm_hash: wasm_hash.as_deref(),
+                        secret_key: &secret_key,
+                        json,
+                    },
+                )
+                .await?;
+            }
+        },
+        Commands::Fuzz { action: None, run } => {
+            // clap requires the run options whenever no subcommand is given.
+            let Some(FuzzRunArgs {
+                contract_path,
+                duration,
+                timeout,
+                threads,
+                max_cases,
+                output,
+                minimize,
+            }) = run
+            else {
+                anyhow::bail!("Pass --contract-path and the other run options, or a fuzz subcommand");
+            };
             fuzz::run_fuzzer(
                 &contract_path,
                 &duration.to_string(),
@@ -1725,12 +4670,33 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
             contract_id,
             version,
             signature,
+            report,
+            report_output,
+            contract_path,
         } => {
             log::debug!(
-                "Command: verify | package={} contract_id={}",
+                "Command: verify | package={} contract_id={} report={:?}",
                 package,
-                contract_id
+                contract_id,
+                report
             );
+            if let Some(format) = report {
+                let format: ci_report::ReportFormat = format.parse()?;
+                ci_report::run(
+                    ci_report::ReportInput {
+                        api_url: &cli.api_url,
+                        package: &package,
+                        contract_id: &contract_id,
+                        version: version.as_deref(),
+                        signature: signature.as_deref(),
+                        contract_path: &contract_path,
+                    },
+                    format,
+                    report_output.as_deref(),
+                )
+                .await?;
+                return Ok(());
+            }
             package_signing::verify_package(
                 &cli.api_url,
                 &package,
@@ -1798,6 +4764,96 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
                 )
                 .await?;
             }
+            KeysCommands::List {
+                publisher,
+                token,
+                json,
+            } => {
+                log::debug!("Command: keys list | publisher={:?}", publisher);
+                publisher_keys::list(&cli.api_url, token.as_deref(), publisher.as_deref(), json)
+                    .await?;
+            }
+            KeysCommands::Register {
+                secret_key,
+                label,
+                token,
+            } => {
+                log::debug!("Command: keys register | label={:?}", label);
+                publisher_keys::register(&cli.api_url, &token, &secret_key, label.as_deref())
+                    .await?;
+            }
+            KeysCommands::Rotate {
+                key,
+                secret_key,
+                label,
+                overlap_hours,
+                token,
+            } => {
+                log::debug!(
+                    "Command: keys rotate | key={} overlap_hours={:?}",
+                    key,
+                    overlap_hours
+                );
+                publisher_keys::rotate(
+                    &cli.api_url,
+                    &token,
+                    &key,
+                    &secret_key,
+                    label.as_deref(),
+                    overlap_hours,
+                )
+                .await?;
+            }
+            KeysCommands::RevokeKey {
+                key,
+                reason,
+                since,
+                token,
+            } => {
+                log::debug!("Command: keys revoke-key | key={}", key);
+                publisher_keys::revoke(&cli.api_url, &token, &key, &reason, since).await?;
+            }
+        },
+        Commands::Recovery { action } => match action {
+            RecoveryCommands::Start {
+                account,
+                secret_key,
+                reason,
+                since,
+            } => {
+                log::debug!("Command: recovery start | account={}", account);
+                recovery::start(&cli.api_url, &account, &secret_key, &reason, since).await?;
+            }
+            RecoveryCommands::Approve {
+                id,
+                reject,
+                comment,
+                secret_key,
+                token,
+            } => {
+                log::debug!("Command: recovery approve | id={} reject={}", id, reject);
+                recovery::approve(
+                    &cli.api_url,
+                    &id,
+                    reject,
+                    comment.as_deref(),
+                    secret_key.as_deref(),
+                    token.as_deref(),
+                )
+                .await?;
+            }
+            RecoveryCommands::Status { id, json } => {
+                log::debug!("Command: recovery status | id={}", id);
+                recovery::status(&cli.api_url, &id, json).await?;
+            }
+            RecoveryCommands::Cancel { id, token } => {
+                log::debug!("Command: recovery cancel | id={}", id);
+                recovery::cancel(&cli.api_url, &id, &token).await?;
+            }
+            RecoveryCommands::Execute { id } => {
+                log::debug!("Command: recovery execute | id={}", id);
+                recovery::execute(&cli.api_url, &id).await?;
+            }
         },
         Commands::BatchVerify {
             contracts,
@@ -2016,6 +5072,62 @@ pub async fn dispatch_command(cli: Cli, network: commands::Network, cfg_network:
             )
             .await?;
         }
+        Commands::OptimizeReport { wasm, output, json } => {
+            log::debug!("Command: optimize-report | wasm={} output={:?}", wasm, output);
+            optimize_report::run(&wasm, output.as_deref(), json)?;
+        }
+        Commands::Admin { token, action } => match action {
+            AdminCommands::Dump { output } => {
+                log::debug!("Command: admin dump | output={}", output);
+                admin::dump(&cli.api_url, &token, &output).await?;
+            }
+            AdminCommands::Restore { file, force } => {
+                log::debug!("Command: admin restore | file={} force={}", file, force);
+                admin::restore(&cli.api_url, &token, &file, force).await?;
+            }
+        },
+
+        Commands::Alerts { token, action } => match action {
+            AlertCommands::Create {
+                contract_id,
+                topic,
+                matching,
+                threshold,
+                window,
+                webhook,
+                name,
+            } => {
+                log::debug!(
+                    "Command: alerts create | contract_id={} topic={} threshold={} window={}",
+                    contract_id,
+                    topic,
+                    threshold,
+                    window
+                );
+                alerts::create(
+                    &cli.api_url,
+                    &token,
+                    alerts::NewAlert {
+                        contract_id: &contract_id,
+                        topic: &topic,
+                        name: name.as_deref(),
+                        matching: matching.as_deref(),
+                        threshold,
+                        window: &window,
+                        webhook: webhook.as_deref(),
+                    },
+                )
+                .await?;
+            }
+            AlertCommands::List { json } => {
+                log::debug!("Command: alerts list | json={}", json);
+                alerts::list(&cli.api_url, &token, json).await?;
+            }
+            AlertCommands::Delete { alert_id } => {
+                log::debug!("Command: alerts delete | id={}", alert_id);
+                alerts::delete(&cli.api_url, &token, &alert_id).await?;
+            }
+        },
 
         // ── Bulk contract registration (issue #525) ──────────────────────────
         Commands::BatchRegister {
@@ -9,6 +9,9 @@ pub struct ExportManifest {
     pub contract_id: String,
     pub name: String,
     pub network: String,
+    /// Archives from before profiles hold everything, like `full`.
+    #[serde(default)]
+    pub profile: ExportProfile,
     pub exported_at: DateTime<Utc>,
     pub sha256: String,
     pub contents: Vec<ManifestEntry>,
@@ -16,6 +19,116 @@ pub struct ExportManifest {
     pub signature: Option<PackageSignatureInfo>,
 }
 
+pub const EXPORT_PROFILES: [&str; 3] = ["minimal", "full", "audit"];
+
+/// Files fetched from the registry rather than the contract directory live
+/// under this directory in the contract tarball.
+pub const REGISTRY_DIR: &str = ".registry";
+
+/// Which components an export archive holds. The manifest records it, and
+/// `import` extracts nothing outside it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportProfile {
+    /// Source and ABI: enough to rebuild and call the contract
+    Minimal,
+    #[default]
+    Full,
+    /// What an auditor needs: source, WASM, ABI and audit reports
+    Audit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Component {
+    Source,
+    Wasm,
+    Abi,
+    Docs,
+    Benchmarks,
+    AuditReports,
+}
+
+impl ExportProfile {
+    pub fn components(self) -> &'static [Component] {
+        use Component::*;
+        match self {
+            ExportProfile::Minimal => &[Source, Abi],
+            ExportProfile::Full => &[Source, Wasm, Abi, Docs, Benchmarks, AuditReports],
+            ExportProfile::Audit => &[Source, Wasm, Abi, AuditReports],
+        }
+    }
+
+    pub fn includes(self, component: Component) -> bool {
+        self.components().contains(&component)
+    }
+}
+
+impl std::str::FromStr for ExportProfile {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "minimal" => Ok(ExportProfile::Minimal),
+            "full" => Ok(ExportProfile::Full),
+            "audit" => Ok(ExportProfile::Audit),
+            other => anyhow::bail!(
+                "unknown export profile '{}' (expected {})",
+                other,
+                EXPORT_PROFILES.join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for ExportProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ExportProfile::Minimal => "minimal",
+            ExportProfile::Full => "full",
+            ExportProfile::Audit => "audit",
+        })
+    }
+}
+
+impl Component {
+    pub fn label(self) -> &'static str {
+        match self {
+            Component::Source => "source",
+            Component::Wasm => "wasm",
+            Component::Abi => "abi",
+            Component::Docs => "docs",
+            Component::Benchmarks => "benchmarks",
+            Component::AuditReports => "audit reports",
+        }
+    }
+
+    /// The component a path in the contract tarball belongs to, or `None`
+    /// for VCS metadata and build output other than WASM, which are never
+    /// exported.
+    pub fn of(path: &str) -> Option<Self> {
+        let path = path.trim_start_matches("./");
+        let (top, rest) = path.split_once('/').unwrap_or((path, ""));
+        let wasm = path.ends_with(".wasm");
+        match top {
+            REGISTRY_DIR => match rest {
+                "abi.json" => Some(Component::Abi),
+                "benchmarks.json" => Some(Component::Benchmarks),
+                _ if rest == "audits.json" || rest.starts_with("audits/") => {
+                    Some(Component::AuditReports)
+                }
+                _ => None,
+            },
+            ".git" => None,
+            "target" => wasm.then_some(Component::Wasm),
+            _ if wasm => Some(Component::Wasm),
+            "docs" if !rest.is_empty() => Some(Component::Docs),
+            "benches" if !rest.is_empty() => Some(Component::Benchmarks),
+            _ => Some(Component::Source),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PackageSignatureInfo {
     pub signature: String,
@@ -42,12 +155,13 @@ pub struct AuditEntry {
 }
 
 impl ExportManifest {
-    pub fn new(contract_id: String, name: String, network: String) -> Self {
+    pub fn new(contract_id: String, name: String, network: String, profile: ExportProfile) -> Self {
         Self {
             schema_version: "1.0".into(),
             contract_id,
             name,
             network,
+            profile,
             exported_at: Utc::now(),
             sha256: String::new(),
             contents: Vec::new(),
@@ -70,3 +184,108 @@ impl ExportManifest {
         self
     }
 }
+
+/// Project-level `Registry.toml`, written next to `Cargo.toml` when a project
+/// is scaffolded and read by commands that publish from a project directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryToml {
+    pub contract: RegistryContractSection,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<RegistryTemplateSection>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub build: Option<RegistryBuildSection>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryContractSection {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default = "default_registry_network")]
+    pub network: String,
+    /// Organization whose publish policy `publish` checks first.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub organization: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegistryTemplateSection {
+    pub slug: String,
+    pub version: String,
+    /// On-chain address of the verified contract the template was derived from.
+    pub source_contract: String,
+}
+
+/// How the registry should rebuild the project when verifying it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RegistryBuildSection {
+    /// Catalog toolchain to build with (`soroban-registry toolchains`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toolchain: Option<String>,
+}
+
+fn default_registry_network() -> String {
+    "testnet".into()
+}
+
+pub const REGISTRY_TOML_FILE: &str = "Registry.toml";
+
+impl RegistryToml {
+    pub fn load(project_dir: &std::path::Path) -> anyhow::Result<Option<Self>> {
+        let path = project_dir.join(REGISTRY_TOML_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let raw = std::fs::read_to_string(&path)?;
+        Ok(Some(toml::from_str(&raw)?))
+    }
+
+    pub fn save(&self, project_dir: &std::path::Path) -> anyhow::Result<()> {
+        std::fs::write(
+            project_dir.join(REGISTRY_TOML_FILE),
+            toml::to_string_pretty(self)?,
+        )?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paths_are_sorted_into_components() {
+        assert_eq!(Component::of("src/lib.rs"), Some(Component::Source));
+        assert_eq!(Component::of("./Cargo.toml"), Some(Component::Source));
+        assert_eq!(
+            Component::of("target/wasm32-unknown-unknown/release/c.wasm"),
+            Some(Component::Wasm)
+        );
+        assert_eq!(Component::of("target/release/deps/c.rlib"), None);
+        assert_eq!(Component::of(".git/HEAD"), None);
+        assert_eq!(Component::of("docs/usage.md"), Some(Component::Docs));
+        assert_eq!(
+            Component::of("benches/swap.rs"),
+            Some(Component::Benchmarks)
+        );
+        assert_eq!(Component::of(".registry/abi.json"), Some(Component::Abi));
+        assert_eq!(
+            Component::of(".registry/audits/1f0c.pdf"),
+            Some(Component::AuditReports)
+        );
+    }
+
+    #[test]
+    fn profiles_choose_components() {
+        let minimal: ExportProfile = "minimal".parse().unwrap();
+        assert!(minimal.includes(Component::Abi));
+        assert!(!minimal.includes(Component::Wasm));
+        assert!(ExportProfile::Audit.includes(Component::AuditReports));
+        assert!(!ExportProfile::Audit.includes(Component::Docs));
+        assert!("everything".parse::<ExportProfile>().is_err());
+    }
+}
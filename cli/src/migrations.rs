@@ -0,0 +1,315 @@
+//! migrations.rs — `soroban-registry migrations list|logs`
+//!
+//! Lists the migrations recorded for a contract (by `migrate upgrade`),
+//! newest first, with their WASM and transaction hashes, how long each took
+//! and where to fetch its log output. `logs` prints a migration's chunked
+//! log, and with `--follow` streams it live (server-sent events) until the
+//! migration finishes.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+use std::io::Write;
+
+/// Times a dropped `--follow` stream is reopened before giving up.
+const FOLLOW_RECONNECTS: u32 = 5;
+
+pub struct MigrationFilter<'a> {
+    /// pending, success, failed or rolled_back
+    pub status: Option<&'a str>,
+    /// RFC 3339 lower bound on the creation time
+    pub since: Option<&'a str>,
+    /// RFC 3339 upper bound on the creation time
+    pub until: Option<&'a str>,
+    pub page: i64,
+    pub limit: i64,
+}
+
+pub async fn list(
+    api_url: &str,
+    contract_id: &str,
+    filter: MigrationFilter<'_>,
+    json_output: bool,
+) -> Result<()> {
+    let mut query = vec![
+        ("page", filter.page.to_string()),
+        ("page_size", filter.limit.to_string()),
+    ];
+    for (key, value) in [
+        ("status", filter.status),
+        ("since", filter.since),
+        ("until", filter.until),
+    ] {
+        if let Some(value) = value {
+            query.push((key, value.to_string()));
+        }
+    }
+
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/migrations",
+            api_url, contract_id
+        ))
+        .query(&query)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    let items = body["items"].as_array().cloned().unwrap_or_default();
+    println!("\n{}", format!("Migrations: {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    if items.is_empty() {
+        println!("  No migrations recorded.\n");
+        return Ok(());
+    }
+
+    let headers = [
+        "ID", "Status", "WASM", "Tx", "Started", "Duration", "Hook", "Log",
+    ];
+    let col_widths = [8, 11, 12, 12, 16, 9, 8, 40];
+    let rows: Vec<Vec<String>> = items
+        .iter()
+        .map(|m| {
+            vec![
+                short(m["id"].as_str().unwrap_or("?"), 8),
+                colored_status(m["status"].as_str().unwrap_or("?")),
+                short(m["wasm_hash"].as_str().unwrap_or("-"), 12),
+                short(m["transaction_hash"].as_str().unwrap_or("-"), 12),
+                m["created_at"]
+                    .as_str()
+                    .map(|t| t.get(..16).unwrap_or(t).replace('T', " "))
+                    .unwrap_or_default(),
+                m["duration_ms"]
+                    .as_i64()
+                    .map(format_duration)
+                    .unwrap_or_else(|| "-".to_string()),
+                m["post_upgrade_status"].as_str().unwrap_or("-").to_string(),
+                m["log_url"]
+                    .as_str()
+                    .map(|path| format!("{}{}", api_url, path))
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!(
+        "\n  Page {} of {} ({} migration(s))\n",
+        body["page"].as_i64().unwrap_or(filter.page),
+        body["total_pages"].as_i64().unwrap_or(1).max(1),
+        body["total"].as_i64().unwrap_or(items.len() as i64)
+    );
+    Ok(())
+}
+
+pub async fn logs(api_url: &str, migration_id: &str, follow: bool) -> Result<()> {
+    let url = format!("{}/api/migrations/{}/logs", api_url, migration_id);
+    let client = crate::http::client();
+
+    if !follow {
+        let mut after = 0;
+        let mut printed = false;
+        loop {
+            let response = client
+                .get(&url)
+                .query(&[("after", after)])
+                .send()
+                .await
+                .context("Failed to reach registry API")?;
+            let status = response.status();
+            let body: Value = response.json().await.unwrap_or(Value::Null);
+            if !status.is_success() {
+                let err = body["message"].as_str().unwrap_or("unknown error");
+                bail!("API error ({}): {}", status, err);
+            }
+            let chunks = body["chunks"].as_array().cloned().unwrap_or_default();
+            if chunks.is_empty() {
+                if !printed {
+                    println!("No log output recorded.");
+                }
+                println!(
+                    "\n{} {}",
+                    "Status:".bold(),
+                    colored_status(body["status"].as_str().unwrap_or("?"))
+                );
+                return Ok(());
+            }
+            for chunk in &chunks {
+                print!("{}", chunk["content"].as_str().unwrap_or_default());
+            }
+            printed = true;
+            after = body["next_after"].as_i64().unwrap_or(after);
+        }
+    }
+
+    let mut last_seq: Option<String> = None;
+    let mut stdout = std::io::stdout();
+    for attempt in 0..=FOLLOW_RECONNECTS {
+        let mut request = client
+            .get(&url)
+            .query(&[("follow", "true")])
+            .header(reqwest::header::ACCEPT, "text/event-stream");
+        if let Some(seq) = &last_seq {
+            request = request.header("Last-Event-ID", seq);
+        }
+        let mut response = request
+            .send()
+            .await
+            .context("Failed to reach registry API")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let body: Value = response.json().await.unwrap_or(Value::Null);
+            let err = body["message"].as_str().unwrap_or("unknown error");
+            bail!("API error ({}): {}", status, err);
+        }
+
+        let mut parser = SseParser::default();
+        while let Some(bytes) = response.chunk().await.unwrap_or(None) {
+            for event in parser.push(&String::from_utf8_lossy(&bytes)) {
+                match event.event.as_str() {
+                    "log" => {
+                        print!("{}", event.data);
+                        stdout.flush().ok();
+                        if event.id.is_some() {
+                            last_seq = event.id;
+                        }
+                    }
+                    "end" => {
+                        println!("\n{} {}", "Finished:".bold(), colored_status(&event.data));
+                        return Ok(());
+                    }
+                    "error" => bail!("Log stream failed: {}", event.data),
+                    _ => {}
+                }
+            }
+        }
+        log::debug!("log stream closed; reconnect attempt {}", attempt + 1);
+    }
+    bail!("Lost the log stream for migration {}", migration_id)
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct SseEvent {
+    event: String,
+    id: Option<String>,
+    data: String,
+}
+
+/// Incremental parser for a `text/event-stream` body. Comment lines (such
+/// as keep-alives) are ignored.
+#[derive(Default)]
+struct SseParser {
+    buffer: String,
+    current: SseEvent,
+    data_lines: Vec<String>,
+}
+
+impl SseParser {
+    fn push(&mut self, text: &str) -> Vec<SseEvent> {
+        self.buffer.push_str(text);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.find('\n') {
+            let line: String = self.buffer.drain(..=end).collect();
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.is_empty() {
+                if !self.data_lines.is_empty() || !self.current.event.is_empty() {
+                    let mut event = std::mem::take(&mut self.current);
+                    event.data = std::mem::take(&mut self.data_lines).join("\n");
+                    if event.event.is_empty() {
+                        event.event = "message".to_string();
+                    }
+                    events.push(event);
+                }
+                continue;
+            }
+            if line.starts_with(':') {
+                continue;
+            }
+            let (field, value) = line.split_once(':').unwrap_or((line, ""));
+            let value = value.strip_prefix(' ').unwrap_or(value);
+            match field {
+                "event" => self.current.event = value.to_string(),
+                "id" => self.current.id = Some(value.to_string()),
+                "data" => self.data_lines.push(value.to_string()),
+                _ => {}
+            }
+        }
+        events
+    }
+}
+
+fn short(value: &str, len: usize) -> String {
+    value.get(..len).unwrap_or(value).to_string()
+}
+
+fn colored_status(status: &str) -> String {
+    match status {
+        "success" => status.green().to_string(),
+        "failed" => status.red().to_string(),
+        "rolled_back" => status.yellow().to_string(),
+        _ => status.bright_black().to_string(),
+    }
+}
+
+fn format_duration(ms: i64) -> String {
+    match ms {
+        ms if ms < 1_000 => format!("{}ms", ms),
+        ms if ms < 60_000 => format!("{:.1}s", ms as f64 / 1_000.0),
+        ms if ms < 3_600_000 => format!("{}m{}s", ms / 60_000, ms % 60_000 / 1_000),
+        ms => format!("{}h{}m", ms / 3_600_000, ms % 3_600_000 / 60_000),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(250), "250ms");
+        assert_eq!(format_duration(1_500), "1.5s");
+        assert_eq!(format_duration(125_000), "2m5s");
+        assert_eq!(format_duration(5_400_000), "1h30m");
+    }
+
+    #[test]
+    fn parses_event_stream_across_reads() {
+        let mut parser = SseParser::default();
+        assert!(parser.push("event: log\nid: 1\ndata: line one").is_empty());
+        let events = parser.push("\ndata: \n\n: keep-alive\n\nevent: end\ndata: success\n\n");
+        assert_eq!(
+            events,
+            vec![
+                SseEvent {
+                    event: "log".into(),
+                    id: Some("1".into()),
+                    data: "line one\n".into(),
+                },
+                SseEvent {
+                    event: "end".into(),
+                    id: None,
+                    data: "success".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn shortens_hashes() {
+        assert_eq!(short("abcdef0123456789", 12), "abcdef012345");
+        assert_eq!(short("-", 12), "-");
+    }
+}
@@ -0,0 +1,339 @@
+//! mock_server.rs — offline demo mode (`--mock` / `SOROBAN_REGISTRY_MOCK=1`)
+//!
+//! Starts a tiny HTTP server on a loopback port that answers registry API
+//! requests from the embedded `fixtures/mock_registry.json`, then points the
+//! CLI's `--api-url` at it. Every command therefore runs unchanged with no
+//! backend or network: reads return canned data, writes are acknowledged
+//! without being stored.
+
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+const FIXTURES: &str = include_str!("../fixtures/mock_registry.json");
+const TOKEN_TEMPLATE: &str = include_str!("../templates/token.rs");
+const MAX_REQUEST_BYTES: usize = 1024 * 1024;
+
+/// Start the mock server in the background and return its base URL.
+pub async fn start() -> Result<String> {
+    let fixtures: Arc<Value> =
+        Arc::new(serde_json::from_str(FIXTURES).context("Embedded mock fixtures are invalid")?);
+    let listener = TcpListener::bind("127.0.0.1:0")
+        .await
+        .context("Failed to bind mock server")?;
+    let addr = listener.local_addr()?;
+
+    tokio::spawn(async move {
+        loop {
+            let Ok((stream, _)) = listener.accept().await else {
+                continue;
+            };
+            let fixtures = fixtures.clone();
+            tokio::spawn(async move {
+                if let Err(e) = serve(stream, &fixtures).await {
+                    log::debug!("mock server: {}", e);
+                }
+            });
+        }
+    });
+
+    log::debug!("Mock registry listening on {}", addr);
+    Ok(format!("http://{}", addr))
+}
+
+async fn serve(mut stream: TcpStream, fixtures: &Value) -> Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+        anyhow::ensure!(buf.len() < MAX_REQUEST_BYTES, "request headers too large");
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .filter_map(|l| l.split_once(':'))
+        .find(|(k, _)| k.eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, v)| v.trim().parse::<usize>().ok())
+        .unwrap_or(0)
+        .min(MAX_REQUEST_BYTES);
+    // Drain the body so the client sees a clean response; its contents are ignored.
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or("GET");
+    let target = request_line.next().unwrap_or("/");
+    log::debug!("mock {} {}", method, target);
+
+    let (status, body) = route(fixtures, method, target);
+    let body = body.to_string();
+    let response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        reason(status),
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
+
+/// Resolve a request against the fixtures. Pure so it can be unit tested.
+fn route(fixtures: &Value, method: &str, target: &str) -> (u16, Value) {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+
+    if method != "GET" {
+        return (
+            200,
+            json!({
+                "mock": true,
+                "message": format!("{} {} accepted by the mock registry (nothing was stored)", method, path),
+            }),
+        );
+    }
+
+    match segments.as_slice() {
+        ["health", ..] => (200, json!({ "status": "healthy", "mock": true })),
+        ["api", "stats"] => (200, fixtures["stats"].clone()),
+        ["api", "contracts"] => (200, list_contracts(fixtures, query)),
+        ["api", "contracts", id] => match find_contract(fixtures, id) {
+            Some(c) => (200, json!({ "contract": c })),
+            None => not_found("Contract", id),
+        },
+        ["api", "contracts", id, rest @ ..] => {
+            let Some(contract) = find_contract(fixtures, id) else {
+                return not_found("Contract", id);
+            };
+            let uuid = contract["id"].as_str().unwrap_or_default();
+            match rest {
+                ["versions"] => (200, lookup(fixtures, "versions", uuid)),
+                ["abi"] => (200, json!({ "abi": lookup(fixtures, "abi", uuid) })),
+                ["dependencies"] => (
+                    200,
+                    json!({ "dependencies": lookup(fixtures, "dependencies", uuid) }),
+                ),
+                ["dependents"] => (200, json!({ "dependents": dependents(fixtures, uuid) })),
+                ["deployments"] => (200, json!([])),
+                _ => not_found("Resource", path),
+            }
+        }
+        ["api", "templates"] => (200, json!([token_template(false)])),
+        ["api", "templates", "token"] => (200, token_template(true)),
+        ["api", "templates", slug] => not_found("Template", slug),
+        _ => not_found("Route", path),
+    }
+}
+
+fn list_contracts(fixtures: &Value, query: &str) -> Value {
+    let params: Vec<(String, String)> = query
+        .split('&')
+        .filter_map(|p| p.split_once('='))
+        .map(|(k, v)| (k.to_string(), decode(v)))
+        .collect();
+    let param = |key: &str| {
+        params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.to_lowercase())
+    };
+    let text = param("query").or_else(|| param("q")).unwrap_or_default();
+    let category = param("category");
+    let verified_only = param("verified_only").as_deref() == Some("true");
+
+    let items: Vec<Value> = fixtures["contracts"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|c| {
+            let haystack = format!(
+                "{} {}",
+                c["name"].as_str().unwrap_or_default(),
+                c["description"].as_str().unwrap_or_default()
+            )
+            .to_lowercase();
+            haystack.contains(&text)
+                && category.as_deref().map_or(true, |cat| {
+                    c["category"].as_str().unwrap_or_default().to_lowercase() == cat
+                })
+                && (!verified_only || c["is_verified"].as_bool() == Some(true))
+        })
+        .collect();
+
+    json!({
+        "total": items.len(),
+        "page": 1,
+        "page_size": items.len().max(1),
+        "total_pages": 1,
+        "next_cursor": null,
+        "prev_cursor": null,
+        "items": items,
+    })
+}
+
+fn find_contract<'a>(fixtures: &'a Value, id: &str) -> Option<&'a Value> {
+    let id = decode(id);
+    fixtures["contracts"].as_array()?.iter().find(|c| {
+        ["id", "contract_id", "name", "slug"]
+            .iter()
+            .any(|k| c[*k].as_str() == Some(id.as_str()))
+    })
+}
+
+fn lookup(fixtures: &Value, section: &str, uuid: &str) -> Value {
+    fixtures[section]
+        .get(uuid)
+        .cloned()
+        .unwrap_or_else(|| json!([]))
+}
+
+fn dependents(fixtures: &Value, uuid: &str) -> Vec<Value> {
+    let Some(deps) = fixtures["dependencies"].as_object() else {
+        return Vec::new();
+    };
+    deps.iter()
+        .filter(|(_, list)| {
+            list.as_array()
+                .is_some_and(|l| l.iter().any(|d| d["contract_id"].as_str() == Some(uuid)))
+        })
+        .filter_map(|(dependent, _)| find_contract(fixtures, dependent).cloned())
+        .collect()
+}
+
+fn token_template(with_source: bool) -> Value {
+    let mut template = json!({
+        "id": "00000000-0000-4000-8000-000000000001",
+        "slug": "token",
+        "name": "Token",
+        "description": "SEP-41 fungible token",
+        "category": "token",
+        "version": "1.1.0",
+        "install_count": 0,
+        "contract_id": "9f1c2b6e-3a4d-4c8e-9b1a-2d3e4f5a6b01",
+        "contract_address": "CDMOCKTOKEN000000000000000000000000000000000000000000000",
+        "network": "testnet",
+        "updated_at": "2026-03-12T09:30:00Z",
+    });
+    if with_source {
+        template["source_code"] = json!(TOKEN_TEMPLATE);
+        template["cargo_toml"] = Value::Null;
+        template["parameters"] = json!([]);
+    }
+    template
+}
+
+fn not_found(kind: &str, what: &str) -> (u16, Value) {
+    (
+        404,
+        json!({
+            "error": format!("{}NotFound", kind),
+            "message": format!("{} '{}' does not exist in the mock registry", kind, what),
+        }),
+    )
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        404 => "Not Found",
+        _ => "Unknown",
+    }
+}
+
+/// Minimal percent-decoding for query values and path segments.
+fn decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => out.push(b' '),
+            b'%' if i + 2 < bytes.len() => {
+                let hex = std::str::from_utf8(&bytes[i + 1..i + 3]).ok();
+                match hex.and_then(|h| u8::from_str_radix(h, 16).ok()) {
+                    Some(b) => {
+                        out.push(b);
+                        i += 2;
+                    }
+                    None => out.push(b'%'),
+                }
+            }
+            b => out.push(b),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixtures() -> Value {
+        serde_json::from_str(FIXTURES).unwrap()
+    }
+
+    #[test]
+    fn search_filters_by_text_and_verification() {
+        let (status, body) = route(
+            &fixtures(),
+            "GET",
+            "/api/contracts?query=mock&verified_only=true",
+        );
+        assert_eq!(status, 200);
+        let names: Vec<&str> = body["items"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["mock-token", "mock-amm"]);
+    }
+
+    #[test]
+    fn contract_lookup_accepts_name_address_or_uuid() {
+        let f = fixtures();
+        for id in ["mock-amm", "9f1c2b6e-3a4d-4c8e-9b1a-2d3e4f5a6b02"] {
+            let (status, body) = route(&f, "GET", &format!("/api/contracts/{}", id));
+            assert_eq!(status, 200);
+            assert_eq!(body["contract"]["name"], "mock-amm");
+        }
+        assert_eq!(route(&f, "GET", "/api/contracts/nope").0, 404);
+    }
+
+    #[test]
+    fn dependents_are_derived_from_dependencies() {
+        let (_, body) = route(&fixtures(), "GET", "/api/contracts/mock-token/dependents");
+        assert_eq!(body["dependents"][0]["name"], "mock-amm");
+    }
+
+    #[test]
+    fn writes_are_acknowledged() {
+        let (status, body) = route(&fixtures(), "POST", "/api/contracts");
+        assert_eq!(status, 200);
+        assert_eq!(body["mock"], true);
+    }
+
+    #[test]
+    fn decodes_percent_escapes() {
+        assert_eq!(decode("hello%20world+x"), "hello world x");
+        assert_eq!(decode("100%"), "100%");
+    }
+}
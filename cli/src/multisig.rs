@@ -2,36 +2,239 @@
 // CLI functions for Multi-Signature Contract Deployment (issue #47)
 
 use anyhow::{Context, Result};
+use chrono::{DateTime, Duration, Utc};
 use colored::Colorize;
 use serde_json::json;
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Policy templates
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A reusable policy shape; the signers are still supplied per policy.
+pub struct PolicyTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub threshold: u32,
+    pub signers: usize,
+    pub expiry_secs: u32,
+    pub timelock_secs: u32,
+}
+
+pub const POLICY_TEMPLATES: &[PolicyTemplate] = &[
+    PolicyTemplate {
+        name: "ops-2of3",
+        description: "2-of-3 ops team",
+        threshold: 2,
+        signers: 3,
+        expiry_secs: 24 * 3600,
+        timelock_secs: 0,
+    },
+    PolicyTemplate {
+        name: "council-3of5",
+        description: "3-of-5 council with a 48h timelock",
+        threshold: 3,
+        signers: 5,
+        expiry_secs: 7 * 24 * 3600,
+        timelock_secs: 48 * 3600,
+    },
+];
+
+fn find_template(name: &str) -> Result<&'static PolicyTemplate> {
+    POLICY_TEMPLATES
+        .iter()
+        .find(|t| t.name.eq_ignore_ascii_case(name))
+        .with_context(|| {
+            let names: Vec<&str> = POLICY_TEMPLATES.iter().map(|t| t.name).collect();
+            format!(
+                "Unknown policy template '{}' (available: {})",
+                name,
+                names.join(", ")
+            )
+        })
+}
+
+pub fn list_templates() {
+    println!("\n{}", "Multi-sig Policy Templates:".bold().cyan());
+    println!("{}", "=".repeat(70).cyan());
+    for t in POLICY_TEMPLATES {
+        println!("\n  {} — {}", t.name.bold(), t.description);
+        println!(
+            "     {}-of-{} | proposals expire after {} | timelock {}",
+            t.threshold,
+            t.signers,
+            format_secs(t.expiry_secs),
+            if t.timelock_secs == 0 {
+                "none".to_string()
+            } else {
+                format_secs(t.timelock_secs)
+            }
+        );
+    }
+    println!(
+        "\n  {} multisig create-policy --template <name> --signers <a,b,c> ...\n",
+        "→".bright_black()
+    );
+}
+
+fn format_secs(secs: u32) -> String {
+    match secs {
+        s if s > 0 && s % 86_400 == 0 => format!("{}d", s / 86_400),
+        s if s > 0 && s % 3600 == 0 => format!("{}h", s / 3600),
+        s => format!("{}s", s),
+    }
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Create a new multi-sig policy
 // ─────────────────────────────────────────────────────────────────────────────
 
-pub async fn create_policy(
-    api_url: &str,
-    name: &str,
+pub struct CreatePolicyOptions<'a> {
+    pub name: &'a str,
+    pub threshold: Option<u32>,
+    pub signers: Option<Vec<String>>,
+    pub expiry_secs: Option<u32>,
+    pub timelock_secs: Option<u32>,
+    /// Bearer token of the creator, recorded as the policy's owner
+    pub token: &'a str,
+    /// Built-in template to start from
+    pub template: Option<&'a str>,
+    /// Existing policy to copy
+    pub clone: Option<&'a str>,
+}
+
+/// Settings inherited from a template or a cloned policy.
+#[derive(Debug, Default)]
+struct PolicyBase {
+    threshold: Option<u32>,
+    signers: Option<Vec<String>>,
+    /// Number of signers a template is designed for
+    signer_count: Option<usize>,
+    expiry_secs: Option<u32>,
+    timelock_secs: Option<u32>,
+    ordered_approvals: bool,
+}
+
+impl From<&PolicyTemplate> for PolicyBase {
+    fn from(t: &PolicyTemplate) -> Self {
+        Self {
+            threshold: Some(t.threshold),
+            signer_count: Some(t.signers),
+            expiry_secs: Some(t.expiry_secs),
+            timelock_secs: Some(t.timelock_secs),
+            ..Self::default()
+        }
+    }
+}
+
+impl PolicyBase {
+    fn from_policy(policy: &serde_json::Value) -> Self {
+        let number = |field: &str| policy[field].as_u64().and_then(|v| u32::try_from(v).ok());
+        Self {
+            threshold: number("threshold"),
+            signers: policy["signer_addresses"].as_array().map(|signers| {
+                signers
+                    .iter()
+                    .filter_map(|s| s.as_str().map(str::to_string))
+                    .collect()
+            }),
+            signer_count: None,
+            expiry_secs: number("expiry_seconds"),
+            timelock_secs: number("timelock_seconds"),
+            ordered_approvals: policy["ordered_approvals"].as_bool().unwrap_or(false),
+        }
+    }
+}
+
+/// The policy to create, after flags override the base.
+#[derive(Debug, PartialEq)]
+struct PolicySpec {
     threshold: u32,
     signers: Vec<String>,
     expiry_secs: Option<u32>,
-    created_by: &str,
-) -> Result<()> {
-    let client = reqwest::Client::new();
-    let url = format!("{}/api/multisig/policies", api_url);
+    timelock_secs: u32,
+    ordered_approvals: bool,
+}
+
+fn resolve_policy(base: PolicyBase, opts: &CreatePolicyOptions) -> Result<PolicySpec> {
+    let threshold = opts
+        .threshold
+        .or(base.threshold)
+        .context("--threshold is required without --template or --clone")?;
+    let signers = opts
+        .signers
+        .clone()
+        .or(base.signers)
+        .context("--signers is required without --clone")?;
+
+    if let Some(count) = base.signer_count {
+        if signers.len() != count {
+            anyhow::bail!(
+                "Template '{}' is for {} signers, but {} were given",
+                opts.template.unwrap_or_default(),
+                count,
+                signers.len()
+            );
+        }
+    }
+    if signers.iter().any(|s| s.is_empty()) {
+        anyhow::bail!("Signer addresses cannot be empty");
+    }
+    let unique: std::collections::HashSet<&String> = signers.iter().collect();
+    if unique.len() != signers.len() {
+        anyhow::bail!("Signer addresses must not repeat");
+    }
+    if threshold == 0 || threshold as usize > signers.len() {
+        anyhow::bail!(
+            "Threshold must be between 1 and the number of signers ({})",
+            signers.len()
+        );
+    }
+
+    Ok(PolicySpec {
+        threshold,
+        signers,
+        expiry_secs: opts.expiry_secs.or(base.expiry_secs),
+        timelock_secs: opts.timelock_secs.or(base.timelock_secs).unwrap_or(0),
+        ordered_approvals: base.ordered_approvals,
+    })
+}
+
+pub async fn create_policy(api_url: &str, opts: CreatePolicyOptions<'_>) -> Result<()> {
+    let client = crate::http::client();
+
+    let base = match (opts.template, opts.clone) {
+        (Some(name), _) => PolicyBase::from(find_template(name)?),
+        (None, Some(policy_id)) => {
+            let response = client
+                .get(format!("{}/api/multisig/policies/{}", api_url, policy_id))
+                .send()
+                .await
+                .context("Failed to reach registry API")?;
+            if !response.status().is_success() {
+                let err = response.text().await?;
+                anyhow::bail!("Failed to load policy {}: {}", policy_id, err);
+            }
+            PolicyBase::from_policy(&response.json().await?)
+        }
+        (None, None) => PolicyBase::default(),
+    };
+    let spec = resolve_policy(base, &opts)?;
 
+    let url = format!("{}/api/multisig/policies", api_url);
     let payload = json!({
-        "name": name,
-        "threshold": threshold,
-        "signer_addresses": signers,
-        "expiry_seconds": expiry_secs,
-        "created_by": created_by,
+        "name": opts.name,
+        "threshold": spec.threshold,
+        "signer_addresses": spec.signers,
+        "expiry_seconds": spec.expiry_secs,
+        "timelock_seconds": spec.timelock_secs,
+        "ordered_approvals": spec.ordered_approvals,
     });
 
     println!("\n{}", "Creating multi-sig policy...".bold().cyan());
 
     let response = client
         .post(&url)
+        .bearer_auth(opts.token)
         .json(&payload)
         .send()
         .await
@@ -55,6 +258,9 @@ pub async fn create_policy(
         "Name".bold(),
         policy["name"].as_str().unwrap_or("?")
     );
+    if let Some(source) = opts.template.or(opts.clone) {
+        println!("  {}: {}", "Based on".bold(), source.bright_black());
+    }
     println!(
         "  {}: {}-of-{}",
         "Threshold".bold(),
@@ -69,6 +275,14 @@ pub async fn create_policy(
         "Expiry".bold(),
         policy["expiry_seconds"].as_i64().unwrap_or(86400)
     );
+    let timelock = policy["timelock_seconds"].as_i64().unwrap_or(0);
+    if timelock > 0 {
+        println!(
+            "  {}: {} seconds after approval",
+            "Timelock".bold(),
+            timelock
+        );
+    }
 
     if let Some(signers) = policy["signer_addresses"].as_array() {
         println!("\n  {} Authorized signers:", "→".bright_black());
@@ -93,10 +307,10 @@ pub async fn create_proposal(
     wasm_hash: &str,
     network: &str,
     policy_id: &str,
-    proposer: &str,
+    token: &str,
     description: Option<&str>,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/deploy-proposal", api_url);
 
     let payload = json!({
@@ -105,18 +319,15 @@ pub async fn create_proposal(
         "wasm_hash": wasm_hash,
         "network": network,
         "policy_id": policy_id,
-        "proposer": proposer,
         "description": description,
     });
 
     println!("\n{}", "Creating deployment proposal...".bold().cyan());
 
-    let response = client
-        .post(&url)
-        .json(&payload)
-        .send()
-        .await
-        .context("Failed to create deployment proposal")?;
+    let response =
+        crate::http::send_idempotent(client.post(&url).bearer_auth(token).json(&payload))
+            .await
+            .context("Failed to create deployment proposal")?;
 
     if !response.status().is_success() {
         let err = response.text().await?;
@@ -174,23 +385,22 @@ pub async fn create_proposal(
 pub async fn sign_proposal(
     api_url: &str,
     proposal_id: &str,
-    signer_address: &str,
+    token: &str,
     signature_data: Option<&str>,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/sign", api_url, proposal_id);
 
     let payload = json!({
-        "signer_address": signer_address,
         "signature_data": signature_data,
     });
 
     println!("\n{}", "Signing proposal...".bold().cyan());
     println!("  Proposal: {}", proposal_id.bright_black());
-    println!("  Signer:   {}", signer_address.bright_magenta());
 
     let response = client
         .post(&url)
+        .bearer_auth(token)
         .json(&payload)
         .send()
         .await
@@ -235,8 +445,8 @@ pub async fn sign_proposal(
 // Execute a proposal
 // ─────────────────────────────────────────────────────────────────────────────
 
-pub async fn execute_proposal(api_url: &str, proposal_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+pub async fn execute_proposal(api_url: &str, proposal_id: &str, token: &str) -> Result<()> {
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/execute", api_url, proposal_id);
 
     println!("\n{}", "Executing deployment proposal...".bold().cyan());
@@ -244,6 +454,7 @@ pub async fn execute_proposal(api_url: &str, proposal_id: &str) -> Result<()> {
 
     let response = client
         .post(&url)
+        .bearer_auth(token)
         .send()
         .await
         .context("Failed to execute proposal")?;
@@ -256,17 +467,27 @@ pub async fn execute_proposal(api_url: &str, proposal_id: &str) -> Result<()> {
         anyhow::bail!("API error ({}): {}", status, err);
     }
 
-    println!("{}", "✓ Deployment executed successfully!".green().bold());
+    let action = body["action"].as_str().unwrap_or("deploy");
+    if action == "deploy" {
+        println!("{}", "✓ Deployment executed successfully!".green().bold());
+    } else {
+        println!("{} {}", "✓ Action executed:".green().bold(), action.bold());
+    }
     println!(
         "  {}: {}",
         "Contract".bold(),
         body["contract_id"].as_str().unwrap_or("?").bright_black()
     );
-    println!(
-        "  {}: {}",
-        "WASM Hash".bold(),
-        body["wasm_hash"].as_str().unwrap_or("?").bright_black()
-    );
+    if let Some(wasm_hash) = body["wasm_hash"].as_str() {
+        println!("  {}: {}", "WASM Hash".bold(), wasm_hash.bright_black());
+    }
+    if !body["result"].is_null() {
+        println!(
+            "  {}: {}",
+            "Result".bold(),
+            serde_json::to_string_pretty(&body["result"])?
+        );
+    }
     println!(
         "  {}: {}",
         "Executed at".bold(),
@@ -282,7 +503,7 @@ pub async fn execute_proposal(api_url: &str, proposal_id: &str) -> Result<()> {
 // ─────────────────────────────────────────────────────────────────────────────
 
 pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/contracts/{}/proposal", api_url, proposal_id);
 
     let response = client
@@ -309,7 +530,7 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
     let status_colored = match status {
         "approved" => status.green().bold(),
         "executed" => status.bright_green().bold(),
-        "expired" | "rejected" => status.red().bold(),
+        "expired" | "rejected" | "failed" | "rolled_back" => status.red().bold(),
         _ => status.yellow().bold(),
     };
 
@@ -331,6 +552,16 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
         "Contract Name".bold(),
         proposal["contract_name"].as_str().unwrap_or("?")
     );
+    let action = proposal["action"].as_str().unwrap_or("deploy");
+    if action != "deploy" {
+        println!("  {}: {}", "Action".bold(), action.bright_blue());
+        let details = &proposal["action_payload"];
+        for (key, value) in details.as_object().into_iter().flatten() {
+            if key != "type" && !value.is_null() {
+                println!("    {}: {}", key, value);
+            }
+        }
+    }
     println!(
         "  {}: {}",
         "WASM Hash".bold(),
@@ -373,6 +604,24 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
             .unwrap_or(0),
     );
 
+    if let Some(executable_at) = data["executable_at"].as_str() {
+        if status == "approved" {
+            println!(
+                "  {} Executable from: {}",
+                "→".bright_black(),
+                executable_at
+            );
+        }
+    }
+
+    let steps = data["steps"].as_array().cloned().unwrap_or_default();
+    if !steps.is_empty() {
+        println!("\n  {} Steps:", "→".bright_black());
+        for step in &steps {
+            print_step(step);
+        }
+    }
+
     println!(
         "\n  {} Signatures: {}/{} collected{}",
         "→".bright_black(),
@@ -386,16 +635,65 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
     );
 
     for sig in &signatures {
+        let via = sig["delegate_address"]
+            .as_str()
+            .map(|delegate| format!(" (via {})", delegate))
+            .unwrap_or_default();
         println!(
-            "    ✓ {} at {}",
+            "    ✓ {}{} at {}",
             sig["signer_address"]
                 .as_str()
                 .unwrap_or("?")
                 .bright_magenta(),
+            via.bright_black(),
             sig["signed_at"].as_str().unwrap_or("?")
         );
     }
 
+    let delegations = data["delegations"].as_array().cloned().unwrap_or_default();
+    if !delegations.is_empty() {
+        println!("\n  {} Delegations:", "→".bright_black());
+        for d in &delegations {
+            println!(
+                "    {} → {} until {}",
+                d["delegator_address"]
+                    .as_str()
+                    .unwrap_or("?")
+                    .bright_magenta(),
+                d["delegate_address"]
+                    .as_str()
+                    .unwrap_or("?")
+                    .bright_magenta(),
+                d["expires_at"].as_str().unwrap_or("?")
+            );
+        }
+    }
+
+    let comments = data["comments"].as_array().cloned().unwrap_or_default();
+    if !comments.is_empty() {
+        println!(
+            "\n  {} Discussion: {} comment(s)",
+            "→".bright_black(),
+            comments.len()
+        );
+        for (depth, comment) in thread_order(&comments) {
+            let indent = "  ".repeat(depth + 2);
+            println!(
+                "{}💬 {} at {} {}",
+                indent,
+                comment["author_address"]
+                    .as_str()
+                    .unwrap_or("?")
+                    .bright_magenta(),
+                comment["created_at"].as_str().unwrap_or("?"),
+                comment["id"].as_str().unwrap_or("?").bright_black()
+            );
+            for line in comment["body"].as_str().unwrap_or("").lines() {
+                println!("{}   {}", indent, line);
+            }
+        }
+    }
+
     println!("\n{}", "=".repeat(70).cyan());
     println!();
 
@@ -403,76 +701,1092 @@ pub async fn proposal_info(api_url: &str, proposal_id: &str) -> Result<()> {
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
-// List proposals
+// Proposal discussion
 // ─────────────────────────────────────────────────────────────────────────────
 
-pub async fn list_proposals(
+/// Orders a proposal's comments (oldest first, as returned by the API) into
+/// threads: each comment is followed by its replies, paired with its depth.
+/// Replies whose parent is unknown are shown as top-level comments.
+fn thread_order(comments: &[serde_json::Value]) -> Vec<(usize, &serde_json::Value)> {
+    let ids: std::collections::HashSet<&str> =
+        comments.iter().filter_map(|c| c["id"].as_str()).collect();
+    let parents: Vec<Option<&str>> = comments
+        .iter()
+        .map(|c| c["parent_id"].as_str().filter(|p| ids.contains(p)))
+        .collect();
+    // Pushed newest first so the oldest comment is popped first.
+    let children = |parent: Option<&str>, depth: usize| -> Vec<(usize, usize)> {
+        (0..comments.len())
+            .rev()
+            .filter(|&i| parents[i] == parent)
+            .map(|i| (depth, i))
+            .collect()
+    };
+
+    let mut ordered = Vec::with_capacity(comments.len());
+    let mut stack = children(None, 0);
+    while let Some((depth, index)) = stack.pop() {
+        let comment = &comments[index];
+        ordered.push((depth, comment));
+        if let Some(id) = comment["id"].as_str() {
+            stack.extend(children(Some(id), depth + 1));
+        }
+    }
+    ordered
+}
+
+pub async fn comment_on_proposal(
     api_url: &str,
-    status_filter: Option<&str>,
-    limit: usize,
+    proposal_id: &str,
+    token: &str,
+    body: &str,
+    reply_to: Option<&str>,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
-    let mut url = format!("{}/api/multisig/proposals?limit={}", api_url, limit);
-    if let Some(s) = status_filter {
-        url.push_str(&format!("&status={}", s));
+    let client = crate::http::client();
+    let url = format!("{}/api/proposals/{}/comments", api_url, proposal_id);
+
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .json(&json!({
+            "body": body,
+            "parent_id": reply_to,
+        }))
+        .send()
+        .await
+        .context("Failed to post comment")?;
+
+    let status = response.status();
+    let comment: serde_json::Value = response.json().await?;
+
+    if !status.is_success() {
+        let err = comment["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    println!("\n{}", "✓ Comment added!".green().bold());
+    println!(
+        "  {}: {}",
+        "Comment ID".bold(),
+        comment["id"].as_str().unwrap_or("?")
+    );
+    if let Some(parent) = comment["parent_id"].as_str() {
+        println!("  {}: {}", "In reply to".bold(), parent.bright_black());
+    }
+    println!(
+        "\n  {} Reply with --reply-to, or view the thread with `multisig info {}`.\n",
+        "→".bright_black(),
+        proposal_id
+    );
+
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Signing delegations
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// A future point in time: an RFC 3339 timestamp, or a span from `now` such
+/// as `14d` or `36h`.
+fn parse_until(value: &str, now: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let span = |digits: &str| -> Result<i64> {
+        digits
+            .parse::<i64>()
+            .ok()
+            .filter(|n| *n > 0)
+            .with_context(|| format!("Invalid time '{}'", value))
+    };
+    if let Some(days) = value.strip_suffix('d') {
+        Ok(now + Duration::days(span(days)?))
+    } else if let Some(hours) = value.strip_suffix('h') {
+        Ok(now + Duration::hours(span(hours)?))
+    } else {
+        anyhow::bail!(
+            "Invalid time '{}': use an RFC 3339 time or a span like 14d or 36h",
+            value
+        )
+    }
+}
+
+pub async fn delegate(
+    api_url: &str,
+    policy_id: &str,
+    token: &str,
+    to: &str,
+    until: &str,
+) -> Result<()> {
+    let expires_at = parse_until(until, Utc::now())?;
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/multisig/policies/{}/delegations",
+        api_url, policy_id
+    );
+
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .json(&json!({
+            "delegate_address": to,
+            "expires_at": expires_at,
+        }))
+        .send()
+        .await
+        .context("Failed to create delegation")?;
+
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
     }
 
+    println!("\n{}", "✓ Signing right delegated!".green().bold());
+    println!(
+        "  {}: {}",
+        "Delegation ID".bold(),
+        body["id"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {} → {}",
+        body["delegator_address"]
+            .as_str()
+            .unwrap_or("?")
+            .bright_magenta(),
+        to.bright_magenta()
+    );
+    println!(
+        "  {}: {} to {}",
+        "Window".bold(),
+        body["starts_at"].as_str().unwrap_or("?"),
+        body["expires_at"].as_str().unwrap_or("?")
+    );
+    println!(
+        "\n  {} {} can sign this policy's proposals in your place until then.\n",
+        "→".bright_black(),
+        to
+    );
+
+    Ok(())
+}
+
+pub async fn list_delegations(api_url: &str, policy_id: &str) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/multisig/policies/{}/delegations",
+        api_url, policy_id
+    );
+
     let response = client
         .get(&url)
         .send()
         .await
-        .context("Failed to list proposals")?;
+        .context("Failed to list delegations")?;
 
     if !response.status().is_success() {
         let err = response.text().await?;
         anyhow::bail!("API error: {}", err);
     }
 
-    let data: serde_json::Value = response.json().await?;
-    let items = data["items"].as_array().cloned().unwrap_or_default();
+    let items: Vec<serde_json::Value> = response.json().await?;
 
-    println!("\n{}", "Deployment Proposals:".bold().cyan());
+    println!("\n{}", "Signing Delegations:".bold().cyan());
     println!("{}", "=".repeat(70).cyan());
 
     if items.is_empty() {
-        println!("{}", "\n  No proposals found.\n".yellow());
+        println!("{}", "\n  No delegations.\n".yellow());
         return Ok(());
     }
 
-    for (i, p) in items.iter().enumerate() {
-        let status = p["status"].as_str().unwrap_or("?");
-        let status_colored = match status {
-            "approved" => status.green(),
-            "executed" => status.bright_green(),
-            "expired" | "rejected" => status.red(),
-            _ => status.yellow(),
+    let now = Utc::now();
+    for d in &items {
+        let expires_at = d["expires_at"]
+            .as_str()
+            .and_then(|at| DateTime::parse_from_rfc3339(at).ok());
+        let state = if !d["revoked_at"].is_null() {
+            "revoked".red()
+        } else if expires_at.is_some_and(|at| at <= now) {
+            "expired".bright_black()
+        } else {
+            "active".green()
         };
-
-        println!(
-            "\n  {}. {} [{}]",
-            i + 1,
-            p["contract_name"].as_str().unwrap_or("Unknown").bold(),
-            status_colored
-        );
         println!(
-            "     ID: {} | Network: {}",
-            p["id"].as_str().unwrap_or("?").bright_black(),
-            p["network"].as_str().unwrap_or("?").bright_blue()
+            "\n  {} → {} [{}]",
+            d["delegator_address"]
+                .as_str()
+                .unwrap_or("?")
+                .bright_magenta(),
+            d["delegate_address"]
+                .as_str()
+                .unwrap_or("?")
+                .bright_magenta(),
+            state
         );
         println!(
-            "     Contract: {} | Expires: {}",
-            p["contract_id"].as_str().unwrap_or("?").bright_black(),
-            p["expires_at"].as_str().unwrap_or("?")
+            "     ID: {} | {} to {}",
+            d["id"].as_str().unwrap_or("?").bright_black(),
+            d["starts_at"].as_str().unwrap_or("?"),
+            d["expires_at"].as_str().unwrap_or("?")
         );
     }
+    println!();
 
-    let total = data["total"].as_i64().unwrap_or(items.len() as i64);
-    println!(
-        "\n{}\nShowing {} of {} proposal(s)\n",
-        "=".repeat(70).cyan(),
-        items.len(),
-        total
+    Ok(())
+}
+
+pub async fn revoke_delegation(api_url: &str, delegation_id: &str, token: &str) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/multisig/delegations/{}/revoke",
+        api_url, delegation_id
     );
 
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to revoke delegation")?;
+
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    println!("{}", "✓ Delegation revoked".green().bold());
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Signer notifications
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn set_channels(
+    api_url: &str,
+    signer: &str,
+    token: &str,
+    webhook_url: Option<&str>,
+) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!("{}/api/multisig/signers/{}/channels", api_url, signer);
+
+    let response = client
+        .put(&url)
+        .bearer_auth(token)
+        .json(&json!({ "webhook_url": webhook_url }))
+        .send()
+        .await
+        .context("Failed to register notification channels")?;
+
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    println!("\n{}", "✓ Notification channels saved!".green().bold());
+    println!("  {}: {}", "Signer".bold(), signer.bright_magenta());
+    match body["webhook_url"].as_str() {
+        Some(webhook) => println!("  {}: {}", "Webhook".bold(), webhook),
+        None => println!(
+            "  {}: {}",
+            "Webhook".bold(),
+            "none (inbox only)".bright_black()
+        ),
+    }
+    println!();
+
     Ok(())
 }
+
+pub async fn list_notifications(
+    api_url: &str,
+    signer: &str,
+    token: &str,
+    unread: bool,
+    limit: usize,
+) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/multisig/signers/{}/notifications?unread={}&limit={}",
+        api_url, signer, unread, limit
+    );
+
+    let response = client
+        .get(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to fetch notifications")?;
+
+    if !response.status().is_success() {
+        let err = response.text().await?;
+        anyhow::bail!("API error: {}", err);
+    }
+
+    let items: Vec<serde_json::Value> = response.json().await?;
+
+    println!("\n{}", "Signer Notifications:".bold().cyan());
+    println!("{}", "=".repeat(70).cyan());
+
+    if items.is_empty() {
+        println!("{}", "\n  No notifications.\n".yellow());
+        return Ok(());
+    }
+
+    for n in &items {
+        let payload = &n["payload"];
+        let marker = if n["acknowledged_at"].is_null() {
+            "●".yellow()
+        } else {
+            "○".bright_black()
+        };
+        println!(
+            "\n  {} {} — {} on {} ({}/{} approvals)",
+            marker,
+            n["notification_type"].as_str().unwrap_or("?").bold(),
+            payload["contract_name"].as_str().unwrap_or("?"),
+            payload["network"].as_str().unwrap_or("?").bright_blue(),
+            payload["signatures_collected"].as_i64().unwrap_or(0),
+            payload["required_approvals"].as_i64().unwrap_or(0)
+        );
+        println!(
+            "     ID: {} | Proposal: {} | {}",
+            n["id"].as_str().unwrap_or("?").bright_black(),
+            n["proposal_id"].as_str().unwrap_or("?").bright_black(),
+            n["created_at"].as_str().unwrap_or("?")
+        );
+        if let Some(command) = payload["sign_command"].as_str() {
+            println!("     {} {}", "→".bright_black(), command.green());
+        }
+    }
+    println!();
+
+    Ok(())
+}
+
+pub async fn acknowledge_notification(
+    api_url: &str,
+    notification_id: &str,
+    token: &str,
+) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/multisig/notifications/{}/ack",
+        api_url, notification_id
+    );
+
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to acknowledge notification")?;
+
+    if !response.status().is_success() {
+        let err = response.text().await?;
+        anyhow::bail!("API error: {}", err);
+    }
+
+    println!("{}", "✓ Notification acknowledged".green().bold());
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Governance action proposals
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub struct ProposeActionOptions<'a> {
+    /// apply-patch, deprecate-contract, transfer-ownership or amend-policy
+    pub action: &'a str,
+    pub policy_id: &'a str,
+    /// Bearer token of the proposer
+    pub token: &'a str,
+    pub description: Option<&'a str>,
+    pub contract: Option<&'a str>,
+    pub patch_id: Option<&'a str>,
+    pub retire_at: Option<&'a str>,
+    pub replacement: Option<&'a str>,
+    pub migration_guide: Option<&'a str>,
+    pub notes: Option<&'a str>,
+    pub new_owner: Option<&'a str>,
+    pub threshold: Option<u32>,
+    pub signers: Option<Vec<String>>,
+    pub expiry_secs: Option<u32>,
+    pub timelock_secs: Option<u32>,
+}
+
+/// Builds the typed action payload the API expects from the flags.
+fn action_payload(opts: &ProposeActionOptions, now: DateTime<Utc>) -> Result<serde_json::Value> {
+    let required = |value: Option<&str>, flag: &str| -> Result<String> {
+        value
+            .map(str::to_string)
+            .with_context(|| format!("{} requires --{}", opts.action, flag))
+    };
+    let payload = match opts.action {
+        "apply-patch" => json!({
+            "type": "apply_patch",
+            "contract_id": required(opts.contract, "contract")?,
+            "patch_id": required(opts.patch_id, "patch-id")?,
+        }),
+        "deprecate-contract" => {
+            let retire_at = parse_until(&required(opts.retire_at, "retire-at")?, now)?;
+            if opts.replacement.is_none() && opts.migration_guide.is_none() {
+                anyhow::bail!("deprecate-contract requires --replacement or --migration-guide");
+            }
+            json!({
+                "type": "deprecate_contract",
+                "contract_id": required(opts.contract, "contract")?,
+                "retirement_at": retire_at.to_rfc3339(),
+                "replacement_contract_id": opts.replacement,
+                "migration_guide_url": opts.migration_guide,
+                "notes": opts.notes,
+            })
+        }
+        "transfer-ownership" => json!({
+            "type": "transfer_ownership",
+            "contract_id": required(opts.contract, "contract")?,
+            "new_owner": required(opts.new_owner, "new-owner")?,
+        }),
+        "amend-policy" => {
+            if opts.threshold.is_none()
+                && opts.signers.is_none()
+                && opts.expiry_secs.is_none()
+                && opts.timelock_secs.is_none()
+            {
+                anyhow::bail!(
+                    "amend-policy needs at least one of --threshold, --signers, --expiry-secs or --timelock-secs"
+                );
+            }
+            json!({
+                "type": "amend_policy",
+                "threshold": opts.threshold,
+                "signer_addresses": opts.signers,
+                "expiry_seconds": opts.expiry_secs,
+                "timelock_seconds": opts.timelock_secs,
+            })
+        }
+        other => anyhow::bail!(
+            "Unknown action '{}': use apply-patch, deprecate-contract, transfer-ownership or amend-policy",
+            other
+        ),
+    };
+    Ok(payload)
+}
+
+/// Puts a privileged registry action behind the policy's approval.
+pub async fn propose_action(api_url: &str, opts: ProposeActionOptions<'_>) -> Result<()> {
+    let action = action_payload(&opts, Utc::now())?;
+
+    let client = crate::http::client();
+    let url = format!("{}/api/multisig/action-proposals", api_url);
+    let payload = json!({
+        "policy_id": opts.policy_id,
+        "description": opts.description,
+        "action": action,
+    });
+
+    println!("\n{}", "Creating action proposal...".bold().cyan());
+
+    let response =
+        crate::http::send_idempotent(client.post(&url).bearer_auth(opts.token).json(&payload))
+            .await
+            .context("Failed to create action proposal")?;
+
+    let status = response.status();
+    let proposal: serde_json::Value = response.json().await?;
+    if !status.is_success() {
+        let err = proposal["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    println!("{}", "✓ Proposal created!".green().bold());
+    println!(
+        "  {}: {}",
+        "Proposal ID".bold(),
+        proposal["id"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Action".bold(),
+        proposal["action"].as_str().unwrap_or("?").bright_blue()
+    );
+    println!(
+        "  {}: {} {}",
+        "Subject".bold(),
+        proposal["contract_name"].as_str().unwrap_or("?"),
+        proposal["contract_id"]
+            .as_str()
+            .unwrap_or("?")
+            .bright_black()
+    );
+    println!(
+        "  {}: {}",
+        "Expires at".bold(),
+        proposal["expires_at"].as_str().unwrap_or("?")
+    );
+    println!(
+        "\n  {} The action runs when the approved proposal is executed.\n",
+        "→".bright_black()
+    );
+
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Batch proposals
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn print_step(step: &serde_json::Value) {
+    let status = step["status"].as_str().unwrap_or("?");
+    let status_colored = match status {
+        "executed" => status.bright_green(),
+        "failed" => status.red(),
+        "skipped" | "rolled_back" => status.bright_black(),
+        _ => status.yellow(),
+    };
+    println!(
+        "    {}. {} {} → {} [{}]",
+        step["step_index"].as_i64().unwrap_or(0),
+        step["action"].as_str().unwrap_or("?").bold(),
+        step["contract_id"].as_str().unwrap_or("?").bright_black(),
+        step["wasm_hash"].as_str().unwrap_or("?").bright_black(),
+        status_colored
+    );
+    if let Some(hash) = step["rollback_wasm_hash"].as_str() {
+        println!("       rollback to {}", hash.bright_black());
+    }
+    if let Some(error) = step["error"].as_str() {
+        println!("       {}", error.red());
+    }
+}
+
+/// Creates a proposal covering several deployments and migrations. The steps
+/// file holds a JSON array of `{action, contract_id, wasm_hash, description}`
+/// objects, executed in order once the proposal is approved.
+pub async fn create_batch_proposal(
+    api_url: &str,
+    name: &str,
+    network: &str,
+    policy_id: &str,
+    token: &str,
+    steps_file: &str,
+    description: Option<&str>,
+) -> Result<()> {
+    let raw = std::fs::read_to_string(steps_file)
+        .with_context(|| format!("Failed to read steps file {}", steps_file))?;
+    let steps: serde_json::Value =
+        serde_json::from_str(&raw).with_context(|| format!("{} is not valid JSON", steps_file))?;
+    if !steps.is_array() {
+        anyhow::bail!("{} must contain a JSON array of steps", steps_file);
+    }
+
+    let client = crate::http::client();
+    let url = format!("{}/api/multisig/batch-proposals", api_url);
+    let payload = json!({
+        "name": name,
+        "network": network,
+        "policy_id": policy_id,
+        "description": description,
+        "steps": steps,
+    });
+
+    println!("\n{}", "Creating batch proposal...".bold().cyan());
+
+    let response =
+        crate::http::send_idempotent(client.post(&url).bearer_auth(token).json(&payload))
+            .await
+            .context("Failed to create batch proposal")?;
+
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    let proposal = &body["proposal"];
+    println!("{}", "✓ Batch proposal created!".green().bold());
+    println!(
+        "  {}: {}",
+        "Proposal ID".bold(),
+        proposal["id"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Network".bold(),
+        proposal["network"].as_str().unwrap_or("?").bright_blue()
+    );
+    println!(
+        "  {}: {}",
+        "Expires at".bold(),
+        proposal["expires_at"].as_str().unwrap_or("?")
+    );
+    println!("\n  {} Steps:", "→".bright_black());
+    for step in body["steps"].as_array().into_iter().flatten() {
+        print_step(step);
+    }
+    println!(
+        "\n  {} Share the Proposal ID to start collecting signatures.\n",
+        "→".bright_black()
+    );
+
+    Ok(())
+}
+
+/// Reports the outcome of one step of an approved batch proposal.
+pub async fn report_step(
+    api_url: &str,
+    proposal_id: &str,
+    index: u32,
+    outcome: &str,
+    tx_hash: Option<&str>,
+    error: Option<&str>,
+    token: &str,
+) -> Result<()> {
+    let outcome = outcome.replace('-', "_");
+    if !["executed", "failed", "rolled_back"].contains(&outcome.as_str()) {
+        anyhow::bail!("--outcome must be one of: executed, failed, rolled-back");
+    }
+
+    let client = crate::http::client();
+    let url = format!("{}/api/proposals/{}/steps/{}", api_url, proposal_id, index);
+    let payload = json!({
+        "outcome": outcome,
+        "tx_hash": tx_hash,
+        "error": error,
+    });
+
+    let response = client
+        .post(&url)
+        .bearer_auth(token)
+        .json(&payload)
+        .send()
+        .await
+        .context("Failed to report step result")?;
+
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    println!("{}", "✓ Step result recorded".green().bold());
+    for step in body["steps"].as_array().into_iter().flatten() {
+        print_step(step);
+    }
+    let proposal_status = body["proposal_status"].as_str().unwrap_or("?");
+    println!("  Proposal status: {}", proposal_status.yellow());
+    match proposal_status {
+        "rolling_back" => println!(
+            "  {} Roll back the executed steps, last first, and report each with --outcome rolled-back",
+            "→".bright_black()
+        ),
+        "executing" => println!(
+            "  {} Run the next pending step and report its outcome",
+            "→".bright_black()
+        ),
+        _ => {}
+    }
+    println!();
+
+    Ok(())
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Network policies
+// ─────────────────────────────────────────────────────────────────────────────
+
+fn print_network_policies(body: &serde_json::Value) {
+    let org = body["organization_slug"].as_str().unwrap_or("?");
+    println!(
+        "\n{} {}",
+        "Network Policies:".bold().cyan(),
+        org.bright_magenta()
+    );
+    println!("{}", "=".repeat(70).cyan());
+
+    let policies = body["policies"].as_array().cloned().unwrap_or_default();
+    if policies.is_empty() {
+        println!(
+            "{}",
+            "\n  No gated networks; deployments run directly.\n".yellow()
+        );
+        return;
+    }
+    for p in &policies {
+        println!(
+            "\n  {} → {} (threshold {})",
+            p["network"].as_str().unwrap_or("?").bright_blue().bold(),
+            p["policy_name"].as_str().unwrap_or("?"),
+            p["threshold"].as_i64().unwrap_or(0)
+        );
+        println!(
+            "     Policy: {} | updated {}",
+            p["policy_id"].as_str().unwrap_or("?").bright_black(),
+            p["updated_at"].as_str().unwrap_or("?")
+        );
+    }
+    println!();
+}
+
+async fn network_policy_response(response: reqwest::Response, done: Option<String>) -> Result<()> {
+    let status = response.status();
+    let body: serde_json::Value = response.json().await?;
+
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        anyhow::bail!("API error ({}): {}", status, err);
+    }
+
+    if let Some(done) = done {
+        println!("{}", done.green().bold());
+    }
+    print_network_policies(&body);
+    Ok(())
+}
+
+/// Requires deployments and migrations of `org`'s contracts on `network` to
+/// pass `policy_id`. The registry enforces it: gated publishes open a
+/// proposal instead of running.
+pub async fn set_network_policy(
+    api_url: &str,
+    org: &str,
+    network: &str,
+    policy_id: &str,
+    token: &str,
+) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/organizations/{}/network-policies/{}",
+        api_url, org, network
+    );
+
+    let response = client
+        .put(&url)
+        .bearer_auth(token)
+        .json(&json!({ "policy_id": policy_id }))
+        .send()
+        .await
+        .context("Failed to set network policy")?;
+
+    let done = format!("✓ {} deployments now require multisig approval", network);
+    network_policy_response(response, Some(done)).await
+}
+
+pub async fn list_network_policies(api_url: &str, org: &str, token: Option<&str>) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!("{}/api/organizations/{}/network-policies", api_url, org);
+
+    let mut request = client.get(&url);
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request
+        .send()
+        .await
+        .context("Failed to list network policies")?;
+
+    network_policy_response(response, None).await
+}
+
+pub async fn clear_network_policy(
+    api_url: &str,
+    org: &str,
+    network: &str,
+    token: &str,
+) -> Result<()> {
+    let client = crate::http::client();
+    let url = format!(
+        "{}/api/organizations/{}/network-policies/{}",
+        api_url, org, network
+    );
+
+    let response = client
+        .delete(&url)
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to clear network policy")?;
+
+    let done = format!(
+        "✓ {} deployments no longer require multisig approval",
+        network
+    );
+    network_policy_response(response, Some(done)).await
+}
+
+/// Reports a publish or migration the registry held back because its network
+/// requires multisig approval (a `202 Accepted` carrying the proposal).
+pub fn print_approval_required(body: &serde_json::Value) {
+    let proposal = &body["proposal"];
+    let id = proposal["id"].as_str().unwrap_or("?");
+
+    println!("{}", "⏸ Multisig approval required".yellow().bold());
+    if let Some(message) = body["message"].as_str() {
+        println!("  {}", message);
+    }
+    println!("\n  {}: {}", "Proposal".bold(), id.bright_black());
+    println!(
+        "  {}: {}",
+        "Status".bold(),
+        proposal["status"].as_str().unwrap_or("?").yellow()
+    );
+    println!(
+        "  {}: {}",
+        "Required Approvals".bold(),
+        proposal["required_approvals"].as_i64().unwrap_or(0)
+    );
+    println!(
+        "\n  {} soroban-registry multisig sign {} --signer <ADDRESS>",
+        "→".bright_black(),
+        id
+    );
+    println!(
+        "  {} soroban-registry multisig execute {}, then run this command again\n",
+        "→".bright_black(),
+        id
+    );
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// List proposals
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub async fn list_proposals(
+    api_url: &str,
+    status_filter: Option<&str>,
+    limit: usize,
+) -> Result<()> {
+    let client = crate::http::client();
+    let mut url = format!("{}/api/multisig/proposals?limit={}", api_url, limit);
+    if let Some(s) = status_filter {
+        url.push_str(&format!("&status={}", s));
+    }
+
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .context("Failed to list proposals")?;
+
+    if !response.status().is_success() {
+        let err = response.text().await?;
+        anyhow::bail!("API error: {}", err);
+    }
+
+    let data: serde_json::Value = response.json().await?;
+    let items = data["items"].as_array().cloned().unwrap_or_default();
+
+    println!("\n{}", "Deployment Proposals:".bold().cyan());
+    println!("{}", "=".repeat(70).cyan());
+
+    if items.is_empty() {
+        println!("{}", "\n  No proposals found.\n".yellow());
+        return Ok(());
+    }
+
+    for (i, p) in items.iter().enumerate() {
+        let status = p["status"].as_str().unwrap_or("?");
+        let status_colored = match status {
+            "approved" => status.green(),
+            "executed" => status.bright_green(),
+            "expired" | "rejected" | "failed" | "rolled_back" => status.red(),
+            _ => status.yellow(),
+        };
+
+        let action = match p["action"].as_str() {
+            Some(action) if action != "deploy" => format!(" ({})", action),
+            _ => String::new(),
+        };
+        println!(
+            "\n  {}. {}{} [{}]",
+            i + 1,
+            p["contract_name"].as_str().unwrap_or("Unknown").bold(),
+            action.bright_blue(),
+            status_colored
+        );
+        println!(
+            "     ID: {} | Network: {}",
+            p["id"].as_str().unwrap_or("?").bright_black(),
+            p["network"].as_str().unwrap_or("?").bright_blue()
+        );
+        println!(
+            "     Contract: {} | Expires: {}",
+            p["contract_id"].as_str().unwrap_or("?").bright_black(),
+            p["expires_at"].as_str().unwrap_or("?")
+        );
+    }
+
+    let total = data["total"].as_i64().unwrap_or(items.len() as i64);
+    println!(
+        "\n{}\nShowing {} of {} proposal(s)\n",
+        "=".repeat(70).cyan(),
+        items.len(),
+        total
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> CreatePolicyOptions<'static> {
+        CreatePolicyOptions {
+            name: "council",
+            threshold: None,
+            signers: None,
+            expiry_secs: None,
+            timelock_secs: None,
+            token: "token",
+            template: None,
+            clone: None,
+        }
+    }
+
+    fn signers(n: usize) -> Option<Vec<String>> {
+        Some((0..n).map(|i| format!("G{}", i)).collect())
+    }
+
+    #[test]
+    fn templates_fill_in_threshold_and_timelock() {
+        let opts = CreatePolicyOptions {
+            template: Some("council-3of5"),
+            signers: signers(5),
+            ..options()
+        };
+        let spec = resolve_policy(
+            PolicyBase::from(find_template("council-3of5").unwrap()),
+            &opts,
+        )
+        .unwrap();
+        assert_eq!((spec.threshold, spec.timelock_secs), (3, 48 * 3600));
+
+        let short = CreatePolicyOptions {
+            signers: signers(3),
+            ..opts
+        };
+        let base = PolicyBase::from(find_template("council-3of5").unwrap());
+        assert!(resolve_policy(base, &short).is_err());
+        assert!(find_template("council-4of7").is_err());
+    }
+
+    #[test]
+    fn replies_follow_the_comment_they_answer() {
+        let comments = vec![
+            json!({"id": "a", "parent_id": null}),
+            json!({"id": "b", "parent_id": null}),
+            json!({"id": "c", "parent_id": "a"}),
+            json!({"id": "d", "parent_id": "c"}),
+            json!({"id": "e", "parent_id": "missing"}),
+            json!({"id": "f", "parent_id": "a"}),
+        ];
+        let order: Vec<(usize, &str)> = thread_order(&comments)
+            .into_iter()
+            .map(|(depth, c)| (depth, c["id"].as_str().unwrap()))
+            .collect();
+        assert_eq!(
+            order,
+            vec![(0, "a"), (1, "c"), (2, "d"), (1, "f"), (0, "b"), (0, "e")]
+        );
+    }
+
+    #[test]
+    fn delegation_ends_accept_timestamps_and_spans() {
+        let now = DateTime::parse_from_rfc3339("2026-04-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            parse_until("14d", now).unwrap().to_rfc3339(),
+            "2026-04-15T00:00:00+00:00"
+        );
+        assert_eq!(
+            parse_until("36h", now).unwrap().to_rfc3339(),
+            "2026-04-02T12:00:00+00:00"
+        );
+        assert_eq!(
+            parse_until("2026-05-01T09:00:00+02:00", now)
+                .unwrap()
+                .to_rfc3339(),
+            "2026-05-01T07:00:00+00:00"
+        );
+        assert!(parse_until("0d", now).is_err());
+        assert!(parse_until("next week", now).is_err());
+    }
+
+    #[test]
+    fn clones_copy_signers_unless_overridden() {
+        let policy = json!({
+            "threshold": 2,
+            "signer_addresses": ["GA", "GB", "GC"],
+            "expiry_seconds": 3600,
+            "timelock_seconds": 600,
+            "ordered_approvals": true,
+        });
+        let spec = resolve_policy(PolicyBase::from_policy(&policy), &options()).unwrap();
+        assert_eq!(spec.signers, vec!["GA", "GB", "GC"]);
+        assert_eq!((spec.expiry_secs, spec.timelock_secs), (Some(3600), 600));
+        assert!(spec.ordered_approvals);
+
+        let overridden = CreatePolicyOptions {
+            signers: Some(vec!["GA".to_string()]),
+            ..options()
+        };
+        // The cloned 2-of-n threshold no longer fits a single signer.
+        assert!(resolve_policy(PolicyBase::from_policy(&policy), &overridden).is_err());
+    }
+
+    #[test]
+    fn action_flags_become_typed_payloads() {
+        let now = DateTime::parse_from_rfc3339("2026-04-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        let opts = ProposeActionOptions {
+            action: "deprecate-contract",
+            policy_id: "policy",
+            token: "token",
+            description: None,
+            contract: Some("CABC"),
+            patch_id: None,
+            retire_at: Some("30d"),
+            replacement: None,
+            migration_guide: Some("https://example.com/migrate"),
+            notes: None,
+            new_owner: None,
+            threshold: None,
+            signers: None,
+            expiry_secs: None,
+            timelock_secs: None,
+        };
+        let payload = action_payload(&opts, now).unwrap();
+        assert_eq!(payload["type"], "deprecate_contract");
+        assert_eq!(payload["retirement_at"], "2026-05-01T00:00:00+00:00");
+
+        let missing_owner = ProposeActionOptions {
+            action: "transfer-ownership",
+            ..opts
+        };
+        assert!(action_payload(&missing_owner, now).is_err());
+
+        let amend = ProposeActionOptions {
+            action: "amend-policy",
+            threshold: Some(3),
+            ..missing_owner
+        };
+        let payload = action_payload(&amend, now).unwrap();
+        assert_eq!(payload["threshold"], 3);
+        assert!(payload["signer_addresses"].is_null());
+    }
+}
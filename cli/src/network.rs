@@ -217,7 +217,7 @@ async fn fetch_close_time(def: &NetworkDef, client: &reqwest::Client) -> Option<
 }
 
 pub async fn status(json: bool) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     // Check all three networks concurrently
     let (mainnet, testnet, futurenet) = tokio::join!(
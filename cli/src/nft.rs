@@ -0,0 +1,156 @@
+//! nft.rs — `soroban-registry nft tokens|show`
+//!
+//! Browse an NFT collection as the registry indexes it from the contract's
+//! mint, transfer and burn events: who holds each token, and where its
+//! metadata lives (`metadata_url` is the mint URI resolved to HTTPS).
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_token(token: &Value) {
+    let owner = match token["owner"].as_str() {
+        Some(owner) => owner.normal(),
+        None => "burned".red(),
+    };
+    println!(
+        "  {:<12} {}",
+        token["token_id"].as_str().unwrap_or("?").bold(),
+        owner
+    );
+    if let Some(url) = token["metadata_url"]
+        .as_str()
+        .or_else(|| token["token_uri"].as_str())
+    {
+        println!("  {:<12} {}", "", url.blue());
+    }
+}
+
+pub async fn tokens(
+    api_url: &str,
+    contract_id: &str,
+    owner: Option<&str>,
+    include_burned: bool,
+    after: Option<&str>,
+    limit: u32,
+    json: bool,
+) -> Result<()> {
+    let mut params: Vec<(&str, String)> = vec![
+        ("limit", limit.to_string()),
+        ("include_burned", include_burned.to_string()),
+    ];
+    if let Some(owner) = owner {
+        params.push(("owner", owner.to_string()));
+    }
+    if let Some(after) = after {
+        params.push(("after", after.to_string()));
+    }
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/nft/tokens",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .query(&params)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let collection = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&collection)?);
+        return Ok(());
+    }
+    println!(
+        "\n{}",
+        format!(
+            "NFT collection {} ({})",
+            contract_id,
+            collection["network"].as_str().unwrap_or("?")
+        )
+        .bold()
+        .cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+    println!(
+        "  {} tokens held by {} addresses",
+        collection["total_supply"].as_i64().unwrap_or(0),
+        collection["holders"].as_i64().unwrap_or(0)
+    );
+    if let Some(owner) = owner {
+        println!("  {} {}", "Owner:".bold(), owner);
+    }
+    println!();
+
+    let tokens = collection["tokens"].as_array().cloned().unwrap_or_default();
+    if tokens.is_empty() {
+        println!("  {}\n", "No tokens.".bright_black());
+        return Ok(());
+    }
+    for token in &tokens {
+        print_token(token);
+    }
+    if let Some(next) = collection["next_after"].as_str() {
+        println!(
+            "\n  {} --after {}",
+            "More tokens:".bright_black(),
+            next.bright_black()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn show(api_url: &str, contract_id: &str, token_id: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/nft/tokens/{}",
+            api_url.trim_end_matches('/'),
+            contract_id,
+            token_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let token = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&token)?);
+        return Ok(());
+    }
+    println!("\n{}", format!("Token {}", token_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    println!(
+        "  {:<10} {}",
+        "Owner:".bold(),
+        token["owner"].as_str().unwrap_or("(burned)")
+    );
+    for (label, key) in [
+        ("URI:", "token_uri"),
+        ("Metadata:", "metadata_url"),
+        ("Minted:", "minted_at"),
+        ("Moved:", "last_transfer_at"),
+        ("Burned:", "burned_at"),
+    ] {
+        if let Some(value) = token[key].as_str() {
+            println!("  {:<10} {}", label.bold(), value);
+        }
+    }
+    println!(
+        "  {:<10} {}",
+        "Transfers:".bold(),
+        token["transfers"].as_i64().unwrap_or(0)
+    );
+    println!();
+    Ok(())
+}
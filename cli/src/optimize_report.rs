@@ -0,0 +1,447 @@
+//! optimize_report.rs — `soroban-registry optimize-report <wasm>`
+//!
+//! Ledger rent and upload fees scale with code size, so this command inspects
+//! a contract WASM for bytes that do not need to be deployed: debug and name
+//! sections, exports that are not part of the contract interface, duplicated
+//! data segments, and panic/formatting machinery pulled in by `format!`,
+//! `unwrap()` and friends. Each finding carries an estimated saving, and
+//! `--output` writes a copy with the safely removable custom sections stripped.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use wasmparser::{ExternalKind, KnownCustom, Name, Parser, Payload, TypeRef};
+
+/// Custom sections read by the Soroban host and tooling; never stripped.
+const SOROBAN_SECTIONS: &[&str] = &["contractspecv0", "contractenvmetav0", "contractmetav0"];
+
+/// Symbol fragments (demangled and legacy-mangled) that identify the
+/// panic and `core::fmt` machinery.
+const PANIC_FMT_MARKERS: &[&str] = &[
+    "core::fmt",
+    "core::panicking",
+    "alloc::fmt",
+    "4core3fmt",
+    "4core9panicking",
+    "5alloc3fmt",
+    "unwrap_failed",
+    "expect_failed",
+];
+
+/// Strings that only appear in the data section when panic messages are kept.
+const PANIC_STRINGS: &[&str] = &[
+    "panicked at",
+    "called `Option::unwrap()`",
+    "called `Result::unwrap()`",
+];
+
+// ── Report types ─────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+pub struct OptimizeReport {
+    pub wasm_path: String,
+    pub total_bytes: usize,
+    pub findings: Vec<Finding>,
+    pub estimated_savings_bytes: usize,
+    /// Size of the stripped binary, when `--output` was given.
+    pub stripped_bytes: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub kind: FindingKind,
+    pub detail: String,
+    pub estimated_savings_bytes: usize,
+    pub suggestion: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FindingKind {
+    CustomSection,
+    UnusedExport,
+    DuplicateData,
+    PanicMachinery,
+}
+
+impl FindingKind {
+    fn label(self) -> &'static str {
+        match self {
+            FindingKind::CustomSection => "custom section",
+            FindingKind::UnusedExport => "unused export",
+            FindingKind::DuplicateData => "duplicate data",
+            FindingKind::PanicMachinery => "panic/fmt code",
+        }
+    }
+}
+
+// ── Command entry point ──────────────────────────────────────────────────────
+
+pub fn run(wasm_path: &str, output: Option<&str>, json: bool) -> Result<()> {
+    let bytes = std::fs::read(wasm_path)
+        .with_context(|| format!("Failed to read WASM file {}", wasm_path))?;
+
+    // The ABI tells us which exports are real contract functions. It needs the
+    // soroban CLI, so fall back to name-based heuristics when it is missing.
+    let abi_functions = crate::commands::load_abi_json(wasm_path)
+        .ok()
+        .and_then(|json| contract_abi::parse_json_spec(&json, "contract").ok())
+        .map(|abi| {
+            abi.functions
+                .iter()
+                .map(|f| f.name.clone())
+                .collect::<HashSet<_>>()
+        });
+
+    let mut report = analyze(&bytes, abi_functions.as_ref())?;
+    report.wasm_path = wasm_path.to_string();
+
+    if let Some(out) = output {
+        let stripped = strip_custom_sections(&bytes)?;
+        std::fs::write(out, &stripped).with_context(|| format!("Failed to write {}", out))?;
+        report.stripped_bytes = Some(stripped.len());
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report)?);
+    } else {
+        print_report(&report, output);
+    }
+    Ok(())
+}
+
+// ── Analysis ─────────────────────────────────────────────────────────────────
+
+pub fn analyze(bytes: &[u8], abi_functions: Option<&HashSet<String>>) -> Result<OptimizeReport> {
+    let mut findings = Vec::new();
+    let mut imported_funcs = 0u32;
+    let mut code_index = 0u32;
+    let mut body_sizes: HashMap<u32, usize> = HashMap::new();
+    let mut function_names: HashMap<u32, String> = HashMap::new();
+    let mut exports = Vec::new();
+    let mut segments: HashMap<[u8; 32], (usize, usize)> = HashMap::new();
+    let mut panic_strings = Vec::new();
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload.context("Invalid WASM binary")? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if matches!(import?.ty, TypeRef::Func(_)) {
+                        imported_funcs += 1;
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export?;
+                    exports.push((export.name.to_string(), export.kind));
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                body_sizes.insert(imported_funcs + code_index, body.range().len());
+                code_index += 1;
+            }
+            Payload::DataSection(reader) => {
+                for data in reader {
+                    let data = data?;
+                    let text = String::from_utf8_lossy(data.data);
+                    for marker in PANIC_STRINGS {
+                        if text.contains(marker) && !panic_strings.contains(marker) {
+                            panic_strings.push(*marker);
+                        }
+                    }
+                    if data.data.len() < 16 {
+                        continue;
+                    }
+                    let digest: [u8; 32] = Sha256::digest(data.data).into();
+                    let entry = segments.entry(digest).or_insert((data.data.len(), 0));
+                    entry.1 += 1;
+                }
+            }
+            Payload::CustomSection(reader) => {
+                let name = reader.name().to_string();
+                if let KnownCustom::Name(names) = reader.as_known() {
+                    for name in names.into_iter().flatten() {
+                        if let Name::Function(map) = name {
+                            for naming in map.into_iter().flatten() {
+                                function_names.insert(naming.index, naming.name.to_string());
+                            }
+                        }
+                    }
+                }
+                if !SOROBAN_SECTIONS.contains(&name.as_str()) {
+                    findings.push(Finding {
+                        kind: FindingKind::CustomSection,
+                        detail: format!("`{}` ({} bytes)", name, reader.data().len()),
+                        // Section id byte + size LEB + name are removed too.
+                        estimated_savings_bytes: reader.data().len() + name.len() + 2,
+                        suggestion: custom_section_suggestion(&name).to_string(),
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    for (name, kind) in &exports {
+        if is_unused_export(name, *kind, abi_functions) {
+            findings.push(Finding {
+                kind: FindingKind::UnusedExport,
+                detail: format!("`{}` ({:?})", name, kind),
+                // Export entry: name length prefix, name, kind byte, index LEB.
+                estimated_savings_bytes: name.len() + 3,
+                suggestion: "Remove the `#[no_mangle] pub` item or mark it private".into(),
+            });
+        }
+    }
+
+    for (len, count) in segments.values().filter(|(_, count)| *count > 1) {
+        findings.push(Finding {
+            kind: FindingKind::DuplicateData,
+            detail: format!("{} identical {}-byte data segments", count, len),
+            estimated_savings_bytes: len * (count - 1),
+            suggestion: "Share the constant (e.g. a `static`) instead of repeating it".into(),
+        });
+    }
+
+    let fmt_bytes: usize = function_names
+        .iter()
+        .filter(|(_, name)| PANIC_FMT_MARKERS.iter().any(|m| name.contains(m)))
+        .filter_map(|(index, _)| body_sizes.get(index))
+        .sum();
+    if fmt_bytes > 0 || !panic_strings.is_empty() {
+        let detail = if fmt_bytes > 0 {
+            format!("{} bytes of code in panic/formatting functions", fmt_bytes)
+        } else {
+            format!(
+                "panic messages present ({}); build without a name section to size them",
+                panic_strings.join(", ")
+            )
+        };
+        findings.push(Finding {
+            kind: FindingKind::PanicMachinery,
+            detail,
+            estimated_savings_bytes: fmt_bytes,
+            suggestion: "Use `panic_with_error!` / contract errors instead of `unwrap`, \
+                         `expect` and `format!`, and build with `panic = \"abort\"`"
+                .into(),
+        });
+    }
+
+    let estimated_savings_bytes = findings.iter().map(|f| f.estimated_savings_bytes).sum();
+    Ok(OptimizeReport {
+        wasm_path: String::new(),
+        total_bytes: bytes.len(),
+        findings,
+        estimated_savings_bytes,
+        stripped_bytes: None,
+    })
+}
+
+fn is_unused_export(
+    name: &str,
+    kind: ExternalKind,
+    abi_functions: Option<&HashSet<String>>,
+) -> bool {
+    match kind {
+        // The host only needs linear memory; exported globals such as
+        // `__data_end` / `__heap_base` are linker leftovers.
+        ExternalKind::Memory => false,
+        ExternalKind::Func => match abi_functions {
+            // `_` is emitted by the SDK and must stay.
+            Some(abi) => name != "_" && !abi.contains(name),
+            None => name.starts_with("__"),
+        },
+        _ => true,
+    }
+}
+
+fn custom_section_suggestion(name: &str) -> &'static str {
+    if name.starts_with(".debug") {
+        "Build with `debug = 0` / `strip = true` in the release profile"
+    } else if name == "name" {
+        "Build with `strip = \"symbols\"` in the release profile"
+    } else {
+        "Not needed on-chain; strip it with --output"
+    }
+}
+
+// ── Stripping ────────────────────────────────────────────────────────────────
+
+/// Copy `bytes` without any custom section other than the Soroban metadata
+/// sections. Non-custom sections are copied verbatim.
+pub fn strip_custom_sections(bytes: &[u8]) -> Result<Vec<u8>> {
+    anyhow::ensure!(
+        bytes.len() >= 8 && &bytes[..4] == b"\0asm",
+        "Not a WASM binary"
+    );
+    let mut out = bytes[..8].to_vec();
+    let mut pos = 8;
+    while pos < bytes.len() {
+        let start = pos;
+        let id = bytes[pos];
+        pos += 1;
+        let size = read_leb_u32(bytes, &mut pos)? as usize;
+        let end = pos.checked_add(size).filter(|e| *e <= bytes.len());
+        let end = end.context("Section extends past end of file")?;
+
+        let keep = if id == 0 {
+            let mut name_pos = pos;
+            let name_len = read_leb_u32(bytes, &mut name_pos)? as usize;
+            let name = bytes
+                .get(name_pos..name_pos + name_len)
+                .context("Truncated custom section name")?;
+            SOROBAN_SECTIONS.iter().any(|s| s.as_bytes() == name)
+        } else {
+            true
+        };
+        if keep {
+            out.extend_from_slice(&bytes[start..end]);
+        }
+        pos = end;
+    }
+    Ok(out)
+}
+
+fn read_leb_u32(bytes: &[u8], pos: &mut usize) -> Result<u32> {
+    let mut result = 0u32;
+    for shift in (0..35).step_by(7) {
+        let byte = *bytes.get(*pos).context("Truncated LEB128 value")?;
+        *pos += 1;
+        result |= ((byte & 0x7f) as u32) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    anyhow::bail!("LEB128 value too long")
+}
+
+// ── Output ───────────────────────────────────────────────────────────────────
+
+fn print_report(report: &OptimizeReport, output: Option<&str>) {
+    let file = Path::new(&report.wasm_path)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .unwrap_or(&report.wasm_path);
+    println!("\n{} {}", "WASM Size Report:".bold().cyan(), file);
+    println!("{}", "=".repeat(80).cyan());
+    println!("  Total size: {} bytes", report.total_bytes);
+
+    if report.findings.is_empty() {
+        println!("\n{} No size optimisations found", "✓".green());
+    } else {
+        println!();
+        for f in &report.findings {
+            println!(
+                "  {} {:<16} {:<44} ~{} bytes",
+                "⚠".yellow(),
+                f.kind.label(),
+                f.detail,
+                f.estimated_savings_bytes
+            );
+            println!("      {}", f.suggestion.bright_black());
+        }
+        let pct = report.estimated_savings_bytes as f64 / report.total_bytes.max(1) as f64 * 100.0;
+        println!(
+            "\n  Estimated savings: {} bytes ({:.1}%)",
+            report.estimated_savings_bytes.to_string().bold(),
+            pct
+        );
+    }
+
+    if let (Some(out), Some(size)) = (output, report.stripped_bytes) {
+        println!(
+            "\n{} Wrote stripped binary to {} ({} → {} bytes)",
+            "✓".green(),
+            out,
+            report.total_bytes,
+            size
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn custom_section(name: &str, payload: &[u8]) -> Vec<u8> {
+        let mut body = vec![name.len() as u8];
+        body.extend_from_slice(name.as_bytes());
+        body.extend_from_slice(payload);
+        let mut section = vec![0u8, body.len() as u8];
+        section.extend(body);
+        section
+    }
+
+    fn module(sections: &[Vec<u8>]) -> Vec<u8> {
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        for s in sections {
+            wasm.extend_from_slice(s);
+        }
+        wasm
+    }
+
+    #[test]
+    fn strip_keeps_soroban_metadata() {
+        let wasm = module(&[
+            custom_section("contractspecv0", b"spec"),
+            custom_section(".debug_info", &[0u8; 40]),
+            custom_section("producers", b"rustc"),
+        ]);
+        let stripped = strip_custom_sections(&wasm).unwrap();
+        assert_eq!(
+            stripped,
+            module(&[custom_section("contractspecv0", b"spec")])
+        );
+    }
+
+    #[test]
+    fn reports_custom_sections_except_metadata() {
+        let wasm = module(&[
+            custom_section("contractmetav0", b"meta"),
+            custom_section(".debug_line", &[0u8; 20]),
+        ]);
+        let report = analyze(&wasm, None).unwrap();
+        assert_eq!(report.findings.len(), 1);
+        assert_eq!(report.findings[0].kind, FindingKind::CustomSection);
+        assert!(report.findings[0].detail.contains(".debug_line"));
+    }
+
+    #[test]
+    fn detects_duplicate_data_segments() {
+        let payload = [7u8; 20];
+        let mut body = vec![2u8];
+        for offset in [0u8, 32] {
+            // Active segment, memory 0: i32.const <offset>; end; len; bytes.
+            body.extend_from_slice(&[0x00, 0x41, offset, 0x0b, payload.len() as u8]);
+            body.extend_from_slice(&payload);
+        }
+        let mut section = vec![11u8, body.len() as u8];
+        section.extend(body);
+
+        let report = analyze(&module(&[section]), None).unwrap();
+        let dup = report
+            .findings
+            .iter()
+            .find(|f| f.kind == FindingKind::DuplicateData)
+            .expect("duplicate data finding");
+        assert_eq!(dup.estimated_savings_bytes, 20);
+    }
+
+    #[test]
+    fn unused_exports_follow_the_abi() {
+        let abi: HashSet<String> = ["transfer".to_string()].into_iter().collect();
+        assert!(!is_unused_export(
+            "transfer",
+            ExternalKind::Func,
+            Some(&abi)
+        ));
+        assert!(!is_unused_export("_", ExternalKind::Func, Some(&abi)));
+        assert!(is_unused_export("helper", ExternalKind::Func, Some(&abi)));
+        assert!(is_unused_export("__heap_base", ExternalKind::Global, None));
+        assert!(!is_unused_export("memory", ExternalKind::Memory, None));
+    }
+}
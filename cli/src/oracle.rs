@@ -0,0 +1,186 @@
+//! oracle.rs — `soroban-registry oracle show|declare|remove`
+//!
+//! Oracle contracts declare how often their feed updates. The registry
+//! watches the contract's ingested events for updates, reports whether the
+//! feed is fresh or stale, and alerts subscribers when it misses its cadence
+//! plus grace. Declaring or removing a cadence is publisher only.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub const FRESHNESS: [&str; 2] = ["fresh", "stale"];
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+/// Parses a cadence such as `90`, `90s`, `5m`, `1h` or `1d` into seconds.
+pub fn parse_interval(value: &str) -> Result<i64> {
+    let value = value.trim();
+    let (digits, unit) = match value.find(|c: char| !c.is_ascii_digit()) {
+        Some(at) => value.split_at(at),
+        None => (value, "s"),
+    };
+    let amount: i64 = digits
+        .parse()
+        .with_context(|| format!("'{}' is not a duration like 30s, 5m, 1h or 1d", value))?;
+    let unit_seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86_400,
+        other => bail!("unknown duration unit '{}' (use s, m, h or d)", other),
+    };
+    Ok(amount * unit_seconds)
+}
+
+/// One-line freshness summary, e.g.
+/// "stale (last update 2026-04-02 11:58 UTC, expected every 300s)".
+pub fn freshness_line(feed: &Value) -> String {
+    let last = feed["last_update_at"]
+        .as_str()
+        .and_then(|at| chrono::DateTime::parse_from_rfc3339(at).ok())
+        .map(|at| format!("last update {}", at.format("%Y-%m-%d %H:%M UTC")))
+        .unwrap_or_else(|| "no update seen yet".to_string());
+    let state = match feed["freshness"].as_str() {
+        Some("fresh") => "fresh".green().bold(),
+        Some("stale") => "stale".red().bold(),
+        _ => "unknown".yellow().bold(),
+    };
+    format!(
+        "{} ({}, expected every {}s)",
+        state,
+        last,
+        feed["update_interval_seconds"].as_i64().unwrap_or(0)
+    )
+}
+
+fn print_feed(feed: &Value) {
+    println!("  {:<12} {}", "Freshness:".bold(), freshness_line(feed));
+    println!(
+        "  {:<12} {}s",
+        "Grace:".bold(),
+        feed["grace_seconds"].as_i64().unwrap_or(0)
+    );
+    let topics: Vec<&str> = feed["update_topics"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    println!(
+        "  {:<12} {}",
+        "Topics:".bold(),
+        if topics.is_empty() {
+            "any event".to_string()
+        } else {
+            topics.join(", ")
+        }
+    );
+    if let Some(ledger) = feed["last_update_ledger"].as_i64() {
+        println!("  {:<12} {}", "Ledger:".bold(), ledger);
+    }
+    println!(
+        "  {:<12} {}",
+        "Stale after:".bold(),
+        feed["stale_after"].as_str().unwrap_or("?")
+    );
+}
+
+pub async fn show(api_url: &str, contract_id: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/oracle",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let feed = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&feed)?);
+        return Ok(());
+    }
+    println!("\n{}", format!("Oracle feed {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    print_feed(&feed);
+    println!();
+    Ok(())
+}
+
+pub async fn declare(
+    api_url: &str,
+    contract_id: &str,
+    every: &str,
+    grace: Option<&str>,
+    topics: &[String],
+    token: &str,
+) -> Result<()> {
+    let interval = parse_interval(every)?;
+    let grace = grace.map(parse_interval).transpose()?;
+    let response = crate::http::client()
+        .put(format!(
+            "{}/api/contracts/{}/oracle",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({
+            "update_interval_seconds": interval,
+            "grace_seconds": grace,
+            "update_topics": (!topics.is_empty()).then_some(topics),
+        }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let feed = check(response).await?;
+
+    println!("{}", "\n✓ Oracle cadence declared".green().bold());
+    print_feed(&feed);
+    println!();
+    Ok(())
+}
+
+pub async fn remove(api_url: &str, contract_id: &str, token: &str) -> Result<()> {
+    let response = crate::http::client()
+        .delete(format!(
+            "{}/api/contracts/{}/oracle",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    check(response).await?;
+    println!(
+        "{} {}",
+        "✓ No longer tracking the oracle feed of".green().bold(),
+        contract_id
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intervals_accept_units() {
+        assert_eq!(parse_interval("90").unwrap(), 90);
+        assert_eq!(parse_interval("5m").unwrap(), 300);
+        assert_eq!(parse_interval("2h").unwrap(), 7200);
+        assert_eq!(parse_interval("1d").unwrap(), 86_400);
+        assert!(parse_interval("5w").is_err());
+        assert!(parse_interval("m").is_err());
+    }
+}
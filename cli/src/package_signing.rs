@@ -45,7 +45,7 @@ pub async fn sign_package(
     println!("  {}: {}", "Contract ID".bold(), contract_id.bright_black());
     println!("  {}: {}", "Version".bold(), version);
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/signatures", api_url);
 
     let expires_dt = expires_at
@@ -115,7 +115,7 @@ pub async fn verify_package(
     println!("  {}: {}", "Package".bold(), package_path.bright_black());
     println!("  {}: {}", "Hash".bold(), package_hash.bright_black());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     if let Some(sig_b64) = signature_arg {
         verify_with_signature(
@@ -293,7 +293,7 @@ pub async fn revoke_signature(
 ) -> Result<()> {
     println!("\n{}", "Revoking signature...".bold().cyan());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/signatures/{}/revoke", api_url, signature_id);
 
     let payload = json!({
@@ -330,7 +330,7 @@ pub async fn get_chain_of_custody(api_url: &str, contract_id: &str) -> Result<()
     println!("\n{}", "Chain of Custody".bold().cyan());
     println!("{}", "=".repeat(70).cyan());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let url = format!("{}/api/signatures/custody/{}", api_url, contract_id);
 
     let response = client
@@ -393,7 +393,7 @@ pub async fn get_transparency_log(
     println!("\n{}", "Transparency Log".bold().cyan());
     println!("{}", "=".repeat(70).cyan());
 
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
     let mut url = format!("{}/api/signatures/transparency?limit={}", api_url, limit);
 
     if let Some(cid) = contract_id {
@@ -534,7 +534,97 @@ fn create_signing_message(hash: &str, contract_id: &str, version: &str) -> Vec<u
     format!("{}:{}:{}", contract_id, version, hash).into_bytes()
 }
 
-fn derive_stellar_address(public_key_bytes: &[u8; 32]) -> String {
+/// Decodes a base64 Ed25519 public key.
+pub fn decode_public_key(public_key_b64: &str) -> Result<ed25519_dalek::VerifyingKey> {
+    let pk_bytes = BASE64
+        .decode(public_key_b64.trim())
+        .context("Invalid public key (expected base64-encoded Ed25519 key)")?;
+    let pk_array: [u8; 32] = pk_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Public key must decode to 32 bytes"))?;
+    ed25519_dalek::VerifyingKey::from_bytes(&pk_array)
+        .map_err(|_| anyhow::anyhow!("Public key is not a valid Ed25519 key"))
+}
+
+/// Whether `signature_b64` is `public_key_b64`'s Ed25519 signature of
+/// `message`. Errors only when the key or signature cannot be decoded.
+pub fn verify_signature(message: &[u8], signature_b64: &str, public_key_b64: &str) -> Result<bool> {
+    let verifying_key = decode_public_key(public_key_b64)?;
+    let sig_bytes = BASE64
+        .decode(signature_b64.trim())
+        .context("Invalid signature (expected base64-encoded Ed25519 signature)")?;
+    let sig_array: [u8; 64] = sig_bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Signature must decode to 64 bytes"))?;
+    let signature = ed25519_dalek::Signature::from_bytes(&sig_array);
+    Ok(verifying_key.verify(message, &signature).is_ok())
+}
+
+/// What an export archive's signature covers: the contract, the archive's
+/// profile and the SHA-256 of its contract tarball.
+pub fn archive_signing_message(contract_id: &str, profile: &str, sha256: &str) -> Vec<u8> {
+    format!("{}:export:{}:{}", contract_id, profile, sha256).into_bytes()
+}
+
+/// Signs an export archive's manifest with a base64 Ed25519 private key.
+pub fn sign_archive(
+    manifest: &crate::manifest::ExportManifest,
+    private_key: &str,
+) -> Result<crate::manifest::PackageSignatureInfo> {
+    let signing_key = decode_private_key(private_key)?;
+    let public_key_bytes = signing_key.verifying_key().to_bytes();
+    let message = archive_signing_message(
+        &manifest.contract_id,
+        &manifest.profile.to_string(),
+        &manifest.sha256,
+    );
+    Ok(crate::manifest::PackageSignatureInfo {
+        signature: BASE64.encode(signing_key.sign(&message).to_bytes()),
+        signing_address: derive_stellar_address(&public_key_bytes),
+        public_key: BASE64.encode(public_key_bytes),
+        algorithm: "ed25519".into(),
+        signed_at: Utc::now(),
+        expires_at: None,
+        key_fingerprint: Some(key_fingerprint(&public_key_bytes)),
+    })
+}
+
+/// Signs the message `message_for(public_key)` with `private_key`, returning
+/// the base64 public key and signature.
+pub fn sign_registry_message(
+    private_key: &str,
+    message_for: impl FnOnce(&str) -> String,
+) -> Result<(String, String)> {
+    let signing_key = decode_private_key(private_key)?;
+    let public_key = BASE64.encode(signing_key.verifying_key().to_bytes());
+    let message = message_for(&public_key);
+    let signature = BASE64.encode(signing_key.sign(message.as_bytes()).to_bytes());
+    Ok((public_key, signature))
+}
+
+/// Signs the registration message for the key `private_key` under the
+/// publisher `publisher_address`, returning the base64 public key and proof.
+pub fn key_registration_proof(
+    private_key: &str,
+    publisher_address: &str,
+) -> Result<(String, String)> {
+    sign_registry_message(private_key, |public_key| {
+        format!(
+            "soroban-registry:register-key:{}:{}",
+            publisher_address, public_key
+        )
+    })
+}
+
+/// Short, stable identifier of a public key: the first 16 hex digits of its
+/// SHA-256.
+pub fn key_fingerprint(public_key_bytes: &[u8; 32]) -> String {
+    compute_hash(public_key_bytes)[..16].to_string()
+}
+
+pub fn derive_stellar_address(public_key_bytes: &[u8; 32]) -> String {
     use ripemd::Ripemd160;
     use sha2::{Digest as _, Sha256};
 
@@ -572,31 +662,10 @@ pub fn verify_contract_local(
     println!("  {}: {}", "Contract ID".bold(), contract_id);
     println!("  {}: {}", "Version".bold(), version);
 
-    // Decode public key
-    let pk_bytes = BASE64
-        .decode(public_key_b64.trim())
-        .context("Invalid public key (expected base64-encoded Ed25519 key)")?;
-    let pk_array: [u8; 32] = pk_bytes
-        .as_slice()
-        .try_into()
-        .map_err(|_| anyhow::anyhow!("Public key must decode to 32 bytes"))?;
-    let verifying_key = ed25519_dalek::VerifyingKey::from_bytes(&pk_array)
-        .map_err(|_| anyhow::anyhow!("Public key is not a valid Ed25519 key"))?;
-
-    // Decode signature
-    let sig_bytes = BASE64
-        .decode(signature_b64.trim())
-        .context("Invalid signature (expected base64-encoded Ed25519 signature)")?;
-    let sig_array: [u8; 64] = sig_bytes
-        .as_slice()
-        .try_into()
-        .map_err(|_| anyhow::anyhow!("Signature must decode to 64 bytes"))?;
-    let signature = ed25519_dalek::Signature::from_bytes(&sig_array);
-
     let message = create_signing_message(&wasm_hash, contract_id, version);
 
     let start = std::time::Instant::now();
-    let ok = verifying_key.verify(&message, &signature).is_ok();
+    let ok = verify_signature(&message, signature_b64, public_key_b64)?;
     let elapsed = start.elapsed();
 
     if ok {
@@ -1,11 +1,13 @@
 use std::fmt;
 use std::str::FromStr;
 
-use anyhow::{bail, Result};
+use anyhow::{bail, Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::abi_snapshot::{self, AbiChange, AbiSnapshot};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
@@ -62,6 +64,43 @@ pub struct PatchAudit {
     pub applied_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreflightCheck {
+    pub name: &'static str,
+    pub status: CheckStatus,
+    pub detail: String,
+}
+
+impl PreflightCheck {
+    fn new(name: &'static str, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// Consolidated go/no-go report gathered before a patch is applied.
+#[derive(Debug, Clone)]
+pub struct PreflightReport {
+    pub patch: SecurityPatch,
+    pub checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    pub fn is_go(&self) -> bool {
+        self.checks.iter().all(|c| c.status != CheckStatus::Fail)
+    }
+}
+
 pub struct PatchManager;
 
 impl PatchManager {
@@ -80,7 +119,7 @@ impl PatchManager {
         severity: Severity,
         rollout: u8,
     ) -> Result<SecurityPatch> {
-        let client = reqwest::Client::new();
+        let client = crate::http::client();
         let payload = serde_json::json!({
             "target_version": version,
             "severity": severity,
@@ -105,7 +144,7 @@ impl PatchManager {
         api_url: &str,
         patch_id: &str,
     ) -> Result<(SecurityPatch, Vec<serde_json::Value>)> {
-        let client = reqwest::Client::new();
+        let client = crate::http::client();
 
         let patch_resp = client
             .get(format!("{}/api/patches/{}", api_url, patch_id))
@@ -133,7 +172,7 @@ impl PatchManager {
     }
 
     pub async fn apply(api_url: &str, contract_id: &str, patch_id: &str) -> Result<PatchAudit> {
-        let client = reqwest::Client::new();
+        let client = crate::http::client();
 
         let patch_resp = client
             .get(format!("{}/api/patches/{}", api_url, patch_id))
@@ -192,6 +231,290 @@ impl PatchManager {
 
         Ok(resp.json().await?)
     }
+
+    /// Checks the contract's current build, its ABI against the patched WASM
+    /// (when `wasm` is given), recorded protocol compatibility and the
+    /// rollout quota, without changing anything.
+    pub async fn preflight(
+        api_url: &str,
+        contract_id: &str,
+        patch_id: &str,
+        wasm: Option<&str>,
+    ) -> Result<PreflightReport> {
+        let client = crate::http::client();
+
+        let patch_resp = client
+            .get(format!("{}/api/patches/{}", api_url, patch_id))
+            .send()
+            .await?;
+        if !patch_resp.status().is_success() {
+            bail!("patch not found: {}", patch_id);
+        }
+        let patch: SecurityPatch = patch_resp.json().await?;
+
+        let contract_resp = client
+            .get(format!("{}/api/contracts/{}", api_url, contract_id))
+            .send()
+            .await?;
+        if !contract_resp.status().is_success() {
+            bail!("contract not found: {}", contract_id);
+        }
+        let contract: serde_json::Value = contract_resp.json().await?;
+        let current_hash = contract["wasm_hash"].as_str().unwrap_or_default();
+
+        let mut checks = vec![version_check(&patch, current_hash)];
+
+        checks.push(if contract["is_maintenance"].as_bool().unwrap_or(false) {
+            PreflightCheck::new(
+                "Contract state",
+                CheckStatus::Warn,
+                "contract is in maintenance mode",
+            )
+        } else {
+            PreflightCheck::new("Contract state", CheckStatus::Pass, "contract is live")
+        });
+
+        checks.push(match wasm {
+            Some(path) => abi_check(api_url, contract_id, &patch, path).await?,
+            None => PreflightCheck::new(
+                "ABI diff",
+                CheckStatus::Warn,
+                "skipped; pass --wasm with the patched build to compare ABIs",
+            ),
+        });
+
+        let network = contract["network"].as_str().unwrap_or_default();
+        let matrix: serde_json::Value = match contract["id"].as_str() {
+            Some(uuid) => {
+                let resp = client
+                    .get(format!(
+                        "{}/api/contracts/{}/compatibility-matrix",
+                        api_url, uuid
+                    ))
+                    .send()
+                    .await?;
+                if resp.status().is_success() {
+                    resp.json().await?
+                } else {
+                    serde_json::Value::Null
+                }
+            }
+            None => serde_json::Value::Null,
+        };
+        let entries = matrix["entries"].as_array().cloned().unwrap_or_default();
+        checks.push(compatibility_check(&entries, network));
+
+        let audits: serde_json::Value = client
+            .get(format!("{}/api/patches/{}/audits", api_url, patch_id))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let applied = audits["total"].as_u64().unwrap_or(0) as usize;
+        let eligible: serde_json::Value = client
+            .get(format!(
+                "{}/api/contracts?wasm_hash={}",
+                api_url, patch.target_version
+            ))
+            .send()
+            .await?
+            .json()
+            .await?;
+        let total = eligible["total"].as_u64().unwrap_or(0) as usize;
+        checks.push(
+            if Self::check_rollout(applied, total, patch.rollout_percentage) {
+                PreflightCheck::new(
+                    "Rollout quota",
+                    CheckStatus::Pass,
+                    format!(
+                        "{} of {} eligible contract(s) patched ({}% rollout)",
+                        applied, total, patch.rollout_percentage
+                    ),
+                )
+            } else {
+                PreflightCheck::new(
+                    "Rollout quota",
+                    CheckStatus::Fail,
+                    format!(
+                        "quota reached: {} of {} eligible contract(s) patched ({}% rollout)",
+                        applied, total, patch.rollout_percentage
+                    ),
+                )
+            },
+        );
+
+        Ok(PreflightReport { patch, checks })
+    }
+}
+
+fn version_check(patch: &SecurityPatch, current_hash: &str) -> PreflightCheck {
+    const NAME: &str = "Current version";
+    if current_hash.eq_ignore_ascii_case(&patch.new_wasm_hash) {
+        PreflightCheck::new(
+            NAME,
+            CheckStatus::Fail,
+            "contract already runs the patched build",
+        )
+    } else if current_hash.eq_ignore_ascii_case(&patch.target_version) {
+        PreflightCheck::new(
+            NAME,
+            CheckStatus::Pass,
+            format!(
+                "contract runs the targeted build {}",
+                short_hash(current_hash)
+            ),
+        )
+    } else {
+        PreflightCheck::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "contract runs {}, but the patch targets {}",
+                short_hash(current_hash),
+                short_hash(&patch.target_version)
+            ),
+        )
+    }
+}
+
+async fn abi_check(
+    api_url: &str,
+    contract_id: &str,
+    patch: &SecurityPatch,
+    wasm_path: &str,
+) -> Result<PreflightCheck> {
+    use sha2::{Digest, Sha256};
+    const NAME: &str = "ABI diff";
+
+    let bytes = std::fs::read(wasm_path)
+        .with_context(|| format!("Failed to read WASM file at {}", wasm_path))?;
+    let hash = hex::encode(Sha256::digest(&bytes));
+    if !hash.eq_ignore_ascii_case(&patch.new_wasm_hash) {
+        return Ok(PreflightCheck::new(
+            NAME,
+            CheckStatus::Fail,
+            format!(
+                "{} hashes to {}, but the patch ships {}",
+                wasm_path,
+                short_hash(&hash),
+                short_hash(&patch.new_wasm_hash)
+            ),
+        ));
+    }
+
+    let resp = crate::http::client()
+        .get(format!("{}/api/contracts/{}/abi", api_url, contract_id))
+        .send()
+        .await?;
+    if !resp.status().is_success() {
+        return Ok(PreflightCheck::new(
+            NAME,
+            CheckStatus::Warn,
+            "no ABI recorded for the current version; nothing to compare",
+        ));
+    }
+    let body: serde_json::Value = resp.json().await?;
+    let current = contract_abi::parse_json_spec(&body["abi"].to_string(), contract_id)
+        .map_err(|e| anyhow::anyhow!("Failed to parse current ABI: {}", e))?;
+    let patched =
+        contract_abi::parse_json_spec(&crate::commands::load_abi_json(wasm_path)?, contract_id)
+            .map_err(|e| anyhow::anyhow!("Failed to parse patched ABI: {}", e))?;
+
+    let changes = abi_snapshot::diff(
+        &AbiSnapshot::from_abi(current, String::new()),
+        &AbiSnapshot::from_abi(patched, String::new()),
+    );
+    Ok(abi_changes_check(&changes))
+}
+
+fn abi_changes_check(changes: &[AbiChange]) -> PreflightCheck {
+    const NAME: &str = "ABI diff";
+    let breaking: Vec<&str> = changes
+        .iter()
+        .filter(|c| c.breaking)
+        .map(|c| c.item.as_str())
+        .collect();
+    if !breaking.is_empty() {
+        PreflightCheck::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("breaking change(s): {}", breaking.join(", ")),
+        )
+    } else if !changes.is_empty() {
+        PreflightCheck::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("{} compatible addition(s)", changes.len()),
+        )
+    } else {
+        PreflightCheck::new(NAME, CheckStatus::Pass, "ABI unchanged")
+    }
+}
+
+/// Uses the compatibility matrix results recorded for the contract's network.
+fn compatibility_check(entries: &[serde_json::Value], network: &str) -> PreflightCheck {
+    const NAME: &str = "Protocol compatibility";
+    let relevant: Vec<&serde_json::Value> = entries
+        .iter()
+        .filter(|e| match e["network"].as_str() {
+            Some(n) => n.eq_ignore_ascii_case(network),
+            None => true,
+        })
+        .collect();
+    let with_status = |status: &str| -> Vec<String> {
+        relevant
+            .iter()
+            .filter(|e| {
+                e["status"]
+                    .as_str()
+                    .is_some_and(|s| s.eq_ignore_ascii_case(status))
+            })
+            .map(|e| {
+                format!(
+                    "sdk {} / {}",
+                    e["sdk_version"].as_str().unwrap_or("?"),
+                    e["wasm_runtime"].as_str().unwrap_or("?")
+                )
+            })
+            .collect()
+    };
+
+    if relevant.is_empty() {
+        return PreflightCheck::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("no compatibility results recorded for {}", network),
+        );
+    }
+    let incompatible = with_status("incompatible");
+    if !incompatible.is_empty() {
+        return PreflightCheck::new(
+            NAME,
+            CheckStatus::Fail,
+            format!("incompatible with {}", incompatible.join(", ")),
+        );
+    }
+    let warnings = with_status("warning");
+    if !warnings.is_empty() {
+        return PreflightCheck::new(
+            NAME,
+            CheckStatus::Warn,
+            format!("warnings on {}", warnings.join(", ")),
+        );
+    }
+    PreflightCheck::new(
+        NAME,
+        CheckStatus::Pass,
+        format!(
+            "{} combination(s) compatible on {}",
+            relevant.len(),
+            network
+        ),
+    )
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(12)]
 }
 
 #[cfg(test)]
@@ -255,4 +578,66 @@ mod tests {
         assert!(PatchManager::check_rollout(0, 1, 1));
         assert!(!PatchManager::check_rollout(1, 1, 1));
     }
+
+    fn patch() -> SecurityPatch {
+        SecurityPatch {
+            id: Uuid::nil(),
+            target_version: "aaaa".into(),
+            severity: Severity::High,
+            new_wasm_hash: "bbbb".into(),
+            rollout_percentage: 100,
+            description: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn version_check_requires_targeted_build() {
+        assert_eq!(version_check(&patch(), "AAAA").status, CheckStatus::Pass);
+        assert_eq!(version_check(&patch(), "bbbb").status, CheckStatus::Fail);
+        assert_eq!(version_check(&patch(), "cccc").status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn breaking_abi_changes_fail() {
+        use crate::abi_snapshot::AbiChangeKind;
+        let added = AbiChange {
+            kind: AbiChangeKind::Added,
+            item: "fn mint".into(),
+            breaking: false,
+        };
+        let removed = AbiChange {
+            kind: AbiChangeKind::Removed,
+            item: "fn burn".into(),
+            breaking: true,
+        };
+        assert_eq!(abi_changes_check(&[]).status, CheckStatus::Pass);
+        assert_eq!(
+            abi_changes_check(&[added.clone()]).status,
+            CheckStatus::Warn
+        );
+        let check = abi_changes_check(&[added, removed]);
+        assert_eq!(check.status, CheckStatus::Fail);
+        assert!(check.detail.contains("fn burn"));
+    }
+
+    #[test]
+    fn compatibility_uses_contract_network() {
+        let entries = vec![
+            serde_json::json!({ "network": "testnet", "status": "Incompatible", "sdk_version": "21.0.0" }),
+            serde_json::json!({ "network": "mainnet", "status": "Compatible", "sdk_version": "21.0.0" }),
+        ];
+        assert_eq!(
+            compatibility_check(&entries, "mainnet").status,
+            CheckStatus::Pass
+        );
+        assert_eq!(
+            compatibility_check(&entries, "testnet").status,
+            CheckStatus::Fail
+        );
+        assert_eq!(
+            compatibility_check(&entries, "futurenet").status,
+            CheckStatus::Warn
+        );
+    }
 }
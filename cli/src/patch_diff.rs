@@ -0,0 +1,402 @@
+//! patch_diff.rs — `soroban-registry patch diff <patch_id> --contract <id>`
+//!
+//! Shows what a security patch changes before anyone applies it. The
+//! contract's current build and the patch's build are downloaded from the
+//! registry (which reads them from the contract's network), their ABIs are
+//! compared as in `abi check`, and the code size of every exported function
+//! is compared between the two builds.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use wasmparser::{ExternalKind, Parser, Payload, TypeRef};
+
+use crate::abi_snapshot::{self, AbiChange, AbiChangeKind, AbiSnapshot};
+use crate::patch::SecurityPatch;
+
+/// Sizes of one build's code.
+#[derive(Debug, Default, PartialEq)]
+struct CodeSizes {
+    total_bytes: usize,
+    /// Sum of all function bodies, exported or not
+    code_bytes: usize,
+    /// Body size of each exported function
+    functions: BTreeMap<String, usize>,
+}
+
+/// An exported function whose body was added, removed or resized.
+#[derive(Debug, PartialEq, Serialize)]
+struct FunctionSizeChange {
+    function: String,
+    before: Option<usize>,
+    after: Option<usize>,
+}
+
+fn code_sizes(bytes: &[u8]) -> Result<CodeSizes> {
+    let mut imported_funcs = 0u32;
+    let mut code_index = 0u32;
+    let mut body_sizes: BTreeMap<u32, usize> = BTreeMap::new();
+    let mut exports = Vec::new();
+
+    for payload in Parser::new(0).parse_all(bytes) {
+        match payload.context("Invalid WASM binary")? {
+            Payload::ImportSection(reader) => {
+                for import in reader {
+                    if matches!(import?.ty, TypeRef::Func(_)) {
+                        imported_funcs += 1;
+                    }
+                }
+            }
+            Payload::ExportSection(reader) => {
+                for export in reader {
+                    let export = export?;
+                    if export.kind == ExternalKind::Func {
+                        exports.push((export.name.to_string(), export.index));
+                    }
+                }
+            }
+            Payload::CodeSectionEntry(body) => {
+                body_sizes.insert(imported_funcs + code_index, body.range().len());
+                code_index += 1;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(CodeSizes {
+        total_bytes: bytes.len(),
+        code_bytes: body_sizes.values().sum(),
+        functions: exports
+            .into_iter()
+            .filter_map(|(name, index)| Some((name, *body_sizes.get(&index)?)))
+            .collect(),
+    })
+}
+
+/// Exported functions that differ between the builds, by name.
+fn size_changes(before: &CodeSizes, after: &CodeSizes) -> Vec<FunctionSizeChange> {
+    let names: BTreeSet<&String> = before
+        .functions
+        .keys()
+        .chain(after.functions.keys())
+        .collect();
+    names
+        .into_iter()
+        .filter_map(|name| {
+            let (old, new) = (
+                before.functions.get(name).copied(),
+                after.functions.get(name).copied(),
+            );
+            (old != new).then(|| FunctionSizeChange {
+                function: name.clone(),
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+fn byte_delta(before: usize, after: usize) -> colored::ColoredString {
+    let delta = after as i64 - before as i64;
+    let text = format!("{:+}", delta);
+    match delta {
+        d if d > 0 => text.red(),
+        d if d < 0 => text.green(),
+        _ => text.normal(),
+    }
+}
+
+fn short_hash(hash: &str) -> &str {
+    &hash[..hash.len().min(12)]
+}
+
+fn check_hash(bytes: &[u8], expected: &str, source: &str) -> Result<()> {
+    let hash = hex::encode(Sha256::digest(bytes));
+    if !hash.eq_ignore_ascii_case(expected) {
+        bail!(
+            "{} hashes to {}, expected {}",
+            source,
+            short_hash(&hash),
+            short_hash(expected)
+        );
+    }
+    Ok(())
+}
+
+async fn download_wasm(base_url: &str, contract: &str, hash: &str) -> Result<Vec<u8>> {
+    let response = crate::http::client()
+        .get(format!("{}/api/contracts/{}/wasm", base_url, contract))
+        .query(&[("hash", hash)])
+        .send()
+        .await
+        .with_context(|| format!("Failed to download build {}", short_hash(hash)))?;
+    let status = response.status();
+    if !status.is_success() {
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        bail!(
+            "API error ({}): {}",
+            status,
+            body["message"].as_str().unwrap_or("unknown error")
+        );
+    }
+    let bytes = response.bytes().await?.to_vec();
+    check_hash(&bytes, hash, "Downloaded WASM")?;
+    Ok(bytes)
+}
+
+/// ABI changes between two builds, via the `soroban` CLI's spec extraction.
+fn abi_changes(before: &[u8], after: &[u8], name: &str) -> Result<Vec<AbiChange>> {
+    let dir = tempfile::tempdir()?;
+    let mut snapshots = Vec::new();
+    for (file, bytes) in [("current.wasm", before), ("patched.wasm", after)] {
+        let path = dir.path().join(file);
+        std::fs::write(&path, bytes)?;
+        let abi_json = crate::commands::load_abi_json(&path.to_string_lossy())?;
+        let abi = contract_abi::parse_json_spec(&abi_json, name)
+            .map_err(|e| anyhow::anyhow!("Failed to parse ABI of {}: {}", file, e))?;
+        snapshots.push(AbiSnapshot::from_abi(abi, String::new()));
+    }
+    Ok(abi_snapshot::diff(&snapshots[0], &snapshots[1]))
+}
+
+pub async fn diff(
+    api_url: &str,
+    patch_id: &str,
+    contract: &str,
+    wasm: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let base_url = api_url.trim_end_matches('/');
+    let client = crate::http::client();
+
+    let response = client
+        .get(format!("{}/api/patches/{}", base_url, patch_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    if !response.status().is_success() {
+        bail!("patch not found: {}", patch_id);
+    }
+    let patch: SecurityPatch = response.json().await?;
+
+    let response = client
+        .get(format!("{}/api/contracts/{}", base_url, contract))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    if !response.status().is_success() {
+        bail!("contract not found: {}", contract);
+    }
+    let record: Value = response.json().await?;
+    let current_hash = record["wasm_hash"]
+        .as_str()
+        .context("Contract record is missing wasm_hash")?
+        .to_string();
+    let name = record["name"].as_str().unwrap_or(contract).to_string();
+
+    let current = download_wasm(base_url, contract, &current_hash).await?;
+    // A patched build that is not uploaded to the network yet can be
+    // compared from a local file.
+    let patched = match wasm {
+        Some(path) => {
+            let bytes = std::fs::read(path)
+                .with_context(|| format!("Failed to read WASM file at {}", path))?;
+            check_hash(&bytes, &patch.new_wasm_hash, path)?;
+            bytes
+        }
+        None => download_wasm(base_url, contract, &patch.new_wasm_hash).await?,
+    };
+
+    let before = code_sizes(&current)?;
+    let after = code_sizes(&patched)?;
+    let functions = size_changes(&before, &after);
+    let abi = abi_changes(&current, &patched, &name);
+    let targeted = current_hash.eq_ignore_ascii_case(&patch.target_version);
+
+    if json {
+        let (changes, abi_error) = match &abi {
+            Ok(changes) => (
+                changes
+                    .iter()
+                    .map(|c| {
+                        json!({
+                            "kind": format!("{:?}", c.kind).to_lowercase(),
+                            "item": c.item,
+                            "breaking": c.breaking,
+                        })
+                    })
+                    .collect::<Vec<_>>(),
+                None,
+            ),
+            Err(err) => (Vec::new(), Some(err.to_string())),
+        };
+        let report = json!({
+            "patch_id": patch.id,
+            "severity": patch.severity,
+            "contract": contract,
+            "current_wasm_hash": current_hash,
+            "patched_wasm_hash": patch.new_wasm_hash,
+            "targets_current_build": targeted,
+            "abi_changes": changes,
+            "abi_error": abi_error,
+            "size": {
+                "before_bytes": before.total_bytes,
+                "after_bytes": after.total_bytes,
+                "code_before_bytes": before.code_bytes,
+                "code_after_bytes": after.code_bytes,
+            },
+            "functions": functions,
+        });
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
+    println!("\n{}", "Patch Diff".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    println!(
+        "  {}: {} [{}]",
+        "Patch".bold(),
+        patch.id,
+        patch.severity.to_string().red()
+    );
+    println!(
+        "  {}: {} ({})",
+        "Contract".bold(),
+        name,
+        contract.bright_black()
+    );
+    println!(
+        "  {}: {} → {}",
+        "Build".bold(),
+        short_hash(&current_hash).bright_black(),
+        short_hash(&patch.new_wasm_hash).bright_black()
+    );
+    if !targeted {
+        println!(
+            "  {} the patch targets {}, not the contract's current build",
+            "!".yellow(),
+            short_hash(&patch.target_version)
+        );
+    }
+
+    println!("\n  {}", "ABI".bold());
+    match &abi {
+        Ok(changes) if changes.is_empty() => println!("    {} ABI unchanged", "✓".green()),
+        Ok(changes) => {
+            for change in changes {
+                let marker = match change.kind {
+                    AbiChangeKind::Added => "+".green(),
+                    AbiChangeKind::Removed => "-".red(),
+                    AbiChangeKind::Changed => "~".yellow(),
+                };
+                let note = if change.breaking {
+                    " (breaking)".red().to_string()
+                } else {
+                    String::new()
+                };
+                println!("    {} {}{}", marker, change.item, note);
+            }
+        }
+        Err(err) => println!("    {} ABI diff unavailable: {}", "!".yellow(), err),
+    }
+
+    println!("\n  {}", "Code size".bold());
+    println!(
+        "    Module: {} → {} bytes ({})",
+        before.total_bytes,
+        after.total_bytes,
+        byte_delta(before.total_bytes, after.total_bytes)
+    );
+    println!(
+        "    Code:   {} → {} bytes ({})",
+        before.code_bytes,
+        after.code_bytes,
+        byte_delta(before.code_bytes, after.code_bytes)
+    );
+    if functions.is_empty() {
+        println!("    No exported function changed size");
+    } else {
+        for change in &functions {
+            let detail = match (change.before, change.after) {
+                (Some(old), Some(new)) => {
+                    format!("{} → {} bytes ({})", old, new, byte_delta(old, new))
+                }
+                (None, Some(new)) => format!("{} bytes {}", new, "(new)".green()),
+                (Some(old), None) => format!("{} bytes {}", old, "(removed)".red()),
+                (None, None) => continue,
+            };
+            println!("    {:<24} {}", change.function, detail);
+        }
+    }
+    println!("{}", "=".repeat(80).cyan());
+
+    let abi_summary = match &abi {
+        Ok(changes) => {
+            let breaking = changes.iter().filter(|c| c.breaking).count();
+            format!(
+                "{} breaking / {} compatible ABI change(s)",
+                breaking,
+                changes.len() - breaking
+            )
+        }
+        Err(_) => "ABI not compared".to_string(),
+    };
+    println!(
+        "  {}; {} exported function(s) changed size\n",
+        abi_summary,
+        functions.len()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sizes(functions: &[(&str, usize)]) -> CodeSizes {
+        CodeSizes {
+            total_bytes: 0,
+            code_bytes: functions.iter().map(|(_, size)| size).sum(),
+            functions: functions
+                .iter()
+                .map(|(name, size)| (name.to_string(), *size))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn lists_added_removed_and_resized_functions() {
+        let before = sizes(&[("transfer", 120), ("mint", 80), ("burn", 40)]);
+        let after = sizes(&[("transfer", 150), ("mint", 80), ("pause", 16)]);
+        let change = |function: &str, before, after| FunctionSizeChange {
+            function: function.to_string(),
+            before,
+            after,
+        };
+        assert_eq!(
+            size_changes(&before, &after),
+            vec![
+                change("burn", Some(40), None),
+                change("pause", None, Some(16)),
+                change("transfer", Some(120), Some(150)),
+            ]
+        );
+    }
+
+    #[test]
+    fn sizes_exported_function_bodies() {
+        // One imported function, two local ones; only the second is exported.
+        let mut wasm = b"\0asm\x01\0\0\0".to_vec();
+        wasm.extend_from_slice(&[1, 4, 1, 0x60, 0, 0]); // type: () -> ()
+        wasm.extend_from_slice(&[2, 7, 1, 1, b'e', 1, b'f', 0, 0]); // import e.f
+        wasm.extend_from_slice(&[3, 3, 2, 0, 0]); // two functions of type 0
+        wasm.extend_from_slice(&[7, 6, 1, 2, b'g', b'o', 0, 2]); // export "go" = func 2
+        wasm.extend_from_slice(&[10, 8, 2, 2, 0, 0x0b, 3, 0, 0x01, 0x0b]); // bodies
+        let sizes = code_sizes(&wasm).unwrap();
+        assert_eq!(sizes.code_bytes, 2 + 3);
+        assert_eq!(sizes.functions, BTreeMap::from([("go".to_string(), 3)]));
+    }
+}
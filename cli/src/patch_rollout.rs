@@ -0,0 +1,149 @@
+//! patch_rollout.rs — `soroban-registry patch apply --all|status|pause|resume`
+//!
+//! Schedules a security patch for every contract still running its target
+//! build. The registry's scheduler applies it in batches inside the given
+//! maintenance window (e.g. `Sat 02:00-04:00 UTC`) up to the patch's rollout
+//! percentage. Scheduling, pausing and resuming need an admin bearer token;
+//! `status` is public.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub async fn schedule(
+    api_url: &str,
+    token: &str,
+    patch_id: &str,
+    window: Option<&str>,
+) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!("{}/api/patches/{}/rollout", api_url, patch_id))
+        .bearer_auth(token)
+        .json(&json!({ "window": window }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let rollout = parse_response(response).await?;
+
+    println!("\n{}", "✓ Patch rollout scheduled".green().bold());
+    print_rollout(&rollout);
+    println!(
+        "\n  Follow progress with `soroban-registry patch status --patch-id {}`\n",
+        patch_id
+    );
+    Ok(())
+}
+
+pub async fn status(api_url: &str, patch_id: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/patches/{}/rollout", api_url, patch_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let rollout = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&rollout)?);
+        return Ok(());
+    }
+    println!("\n{}", "Patch Rollout".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    print_rollout(&rollout);
+    println!("{}\n", "=".repeat(80).cyan());
+    Ok(())
+}
+
+/// Pauses (`paused = true`) or resumes a rollout.
+pub async fn set_paused(api_url: &str, token: &str, patch_id: &str, paused: bool) -> Result<()> {
+    let action = if paused { "pause" } else { "resume" };
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/patches/{}/rollout/{}",
+            api_url, patch_id, action
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let rollout = parse_response(response).await?;
+
+    let message = if paused {
+        "✓ Rollout paused"
+    } else {
+        "✓ Rollout resumed"
+    };
+    println!("\n{}", message.green().bold());
+    print_rollout(&rollout);
+    println!();
+    Ok(())
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_rollout(rollout: &Value) {
+    let status = rollout["status"].as_str().unwrap_or("?");
+    let label = match status {
+        "completed" => status.green().bold(),
+        "paused" => status.yellow().bold(),
+        _ => status.cyan().bold(),
+    };
+    println!(
+        "  {}: {}",
+        "Patch".bold(),
+        rollout["patch_id"].as_str().unwrap_or("?")
+    );
+    println!("  {}: {}", "Status".bold(), label);
+    println!(
+        "  {}: {}",
+        "Window".bold(),
+        rollout["window"].as_str().unwrap_or("any time")
+    );
+    if let Some(next) = rollout["next_window_at"].as_str() {
+        let when = if rollout["window_open"].as_bool().unwrap_or(false) {
+            "open now".to_string()
+        } else {
+            format!("next opens {}", next)
+        };
+        println!("  {}: {}", "Schedule".bold(), when);
+    }
+
+    let applied = rollout["applied"].as_i64().unwrap_or(0);
+    let quota = rollout["quota"].as_i64().unwrap_or(0);
+    println!(
+        "  {}: {} {}/{} ({}% of {} eligible at {}% rollout)",
+        "Progress".bold(),
+        progress_bar(applied, quota, 30),
+        applied,
+        quota,
+        rollout["progress_pct"].as_f64().unwrap_or(0.0),
+        rollout["eligible"].as_i64().unwrap_or(0),
+        rollout["rollout_percentage"].as_i64().unwrap_or(100)
+    );
+    if let Some(last_run) = rollout["last_run_at"].as_str() {
+        println!("  {}: {}", "Last batch".bold(), last_run);
+    }
+    if let Some(err) = rollout["last_error"].as_str() {
+        println!("  {}: {}", "Last error".bold(), err.red());
+    }
+}
+
+fn progress_bar(done: i64, total: i64, width: usize) -> String {
+    let filled = if total > 0 {
+        ((done.min(total) as f64 / total as f64) * width as f64).round() as usize
+    } else {
+        width
+    };
+    format!(
+        "[{}{}]",
+        "#".repeat(filled).green(),
+        "-".repeat(width - filled).bright_black()
+    )
+}
@@ -0,0 +1,134 @@
+//! permissions.rs — `soroban-registry permissions list|grant|revoke`
+//!
+//! Lets a contract's publisher grant other accounts specific capabilities on
+//! it without handing over ownership: `publish_versions`, `apply_patches`,
+//! `run_migrations` and `edit_metadata`. Granting and revoking need the
+//! publisher's bearer token.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub const CAPABILITIES: [&str; 4] = [
+    "publish_versions",
+    "apply_patches",
+    "run_migrations",
+    "edit_metadata",
+];
+
+pub async fn list(api_url: &str, contract_id: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/permissions",
+            api_url, contract_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let grants = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&grants)?);
+        return Ok(());
+    }
+    println!(
+        "\n{}",
+        format!("Permissions on {}", contract_id).bold().cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+    print_grants(&grants);
+    Ok(())
+}
+
+pub async fn grant(
+    api_url: &str,
+    token: &str,
+    contract_id: &str,
+    principal: &str,
+    capabilities: &[String],
+) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/contracts/{}/permissions",
+            api_url, contract_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "principal": principal, "capabilities": capabilities }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let grants = parse_response(response).await?;
+
+    println!(
+        "\n{}",
+        format!("✓ Granted {} on {}", capabilities.join(", "), contract_id)
+            .green()
+            .bold()
+    );
+    print_grants(&grants);
+    Ok(())
+}
+
+pub async fn revoke(
+    api_url: &str,
+    token: &str,
+    contract_id: &str,
+    principal: &str,
+    capability: &str,
+) -> Result<()> {
+    let response = crate::http::client()
+        .delete(format!(
+            "{}/api/contracts/{}/permissions/{}/{}",
+            api_url, contract_id, principal, capability
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    parse_response(response).await?;
+
+    println!(
+        "\n{}\n",
+        format!("✓ {} no longer holds {}", principal, capability)
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_grants(grants: &Value) {
+    let headers = ["Principal", "Capability", "Granted"];
+    let col_widths = [56, 18, 20];
+    let rows: Vec<Vec<String>> = grants
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|g| {
+            let granted = g["created_at"].as_str().unwrap_or("?");
+            vec![
+                g["principal"].as_str().unwrap_or("?").to_string(),
+                g["capability"].as_str().unwrap_or("?").to_string(),
+                granted.get(..19).unwrap_or(granted).replace('T', " "),
+            ]
+        })
+        .collect();
+    if rows.is_empty() {
+        println!("  Only the publisher can change this contract.\n");
+        return;
+    }
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!();
+}
@@ -0,0 +1,286 @@
+//! policy.rs — `soroban-registry policy show|check`
+//!
+//! Fetches an organization's publish policy from the registry and evaluates
+//! the local project against it with the same `shared::policy` rules the
+//! registry runs, so CI fails before publish with the codes the registry
+//! would report (`LicenseNotAllowed`, `WasmTooLarge`, `MissingAbiSnapshot`,
+//! ...). `publish` runs the check automatically when `Registry.toml` names an
+//! `organization`.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use shared::policy::{OrgPolicy, PolicyViolation, ProjectFacts};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+#[derive(Debug, Deserialize)]
+struct PolicyResponse {
+    organization_slug: String,
+    policy: OrgPolicy,
+    updated_at: Option<String>,
+}
+
+async fn fetch(api_url: &str, org: &str, token: Option<&str>) -> Result<PolicyResponse> {
+    let mut request = crate::http::client().get(format!(
+        "{}/api/organizations/{}/policy",
+        api_url.trim_end_matches('/'),
+        org
+    ));
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    response
+        .json()
+        .await
+        .context("Registry returned an invalid policy")
+}
+
+fn package_license(project: &Path) -> Result<(String, Option<String>)> {
+    let raw = fs::read_to_string(project.join("Cargo.toml"))
+        .with_context(|| format!("No Cargo.toml in {}", project.display()))?;
+    let manifest: toml::Value = toml::from_str(&raw).context("Failed to parse Cargo.toml")?;
+    let package = manifest
+        .get("package")
+        .context("Cargo.toml has no [package]")?;
+    let name = package
+        .get("name")
+        .and_then(|v| v.as_str())
+        .context("Cargo.toml has no package name")?;
+    let license = package
+        .get("license")
+        .and_then(|v| v.as_str())
+        .map(str::to_string);
+    Ok((name.to_string(), license))
+}
+
+fn clippy_passes(project: &Path) -> Option<bool> {
+    println!("{}", "Running cargo clippy...".bright_black());
+    match Command::new("cargo")
+        .args(["clippy", "--quiet", "--", "-D", "warnings"])
+        .current_dir(project)
+        .status()
+    {
+        Ok(status) => Some(status.success()),
+        Err(err) => {
+            log::debug!("cargo clippy did not start: {}", err);
+            None
+        }
+    }
+}
+
+/// Collects what `policy` needs to know about the project. Clippy only runs
+/// when the policy requires a clean lint.
+fn gather_facts(project: &Path, wasm: Option<&str>, policy: &OrgPolicy) -> Result<ProjectFacts> {
+    let (name, license) = package_license(project)?;
+    let wasm_path = match wasm {
+        Some(w) => PathBuf::from(w),
+        None => crate::abi_snapshot::default_wasm_path(project, &name),
+    };
+    Ok(ProjectFacts {
+        license,
+        wasm_bytes: fs::metadata(&wasm_path).ok().map(|m| m.len()),
+        has_abi_snapshot: project
+            .join(crate::abi_snapshot::ABI_SNAPSHOT_FILE)
+            .is_file(),
+        lint_passed: if policy.require_clean_lint {
+            clippy_passes(project)
+        } else {
+            None
+        },
+    })
+}
+
+fn print_policy(policy: &OrgPolicy) {
+    let licenses = if policy.allowed_licenses.is_empty() {
+        "any".to_string()
+    } else {
+        policy.allowed_licenses.join(", ")
+    };
+    let size = policy
+        .max_wasm_bytes
+        .map(|b| format!("{} bytes", b))
+        .unwrap_or_else(|| "none".to_string());
+    println!("  {}: {}", "Allowed licenses".bold(), licenses);
+    println!("  {}: {}", "WASM size limit".bold(), size);
+    println!(
+        "  {}: {}",
+        "ABI snapshot required".bold(),
+        policy.require_abi_snapshot
+    );
+    println!(
+        "  {}: {}",
+        "Clean lint required".bold(),
+        policy.require_clean_lint
+    );
+}
+
+pub async fn show(api_url: &str, org: &str, token: Option<&str>, json: bool) -> Result<()> {
+    let response = fetch(api_url, org, token).await?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&response.policy)?);
+        return Ok(());
+    }
+    println!(
+        "\n{}",
+        format!("Publish policy for {}", response.organization_slug)
+            .bold()
+            .cyan()
+    );
+    println!("{}", "=".repeat(40).cyan());
+    print_policy(&response.policy);
+    match response.updated_at {
+        Some(at) => println!("  {}: {}\n", "Updated".bold(), at),
+        None => println!("\n  {}\n", "No policy set; every project passes.".yellow()),
+    }
+    Ok(())
+}
+
+pub async fn check(
+    api_url: &str,
+    org: &str,
+    contract_path: &str,
+    wasm: Option<&str>,
+    token: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let response = fetch(api_url, org, token).await?;
+    let facts = gather_facts(Path::new(contract_path), wasm, &response.policy)?;
+    let violations = response.policy.evaluate(&facts);
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({
+                "organization": response.organization_slug,
+                "passed": violations.is_empty(),
+                "violations": violations,
+            }))?
+        );
+    } else {
+        print_report(&response.organization_slug, &violations);
+    }
+    if !violations.is_empty() {
+        bail!(
+            "{} violates the {} publish policy ({})",
+            contract_path,
+            response.organization_slug,
+            codes(&violations)
+        );
+    }
+    Ok(())
+}
+
+fn codes(violations: &[PolicyViolation]) -> String {
+    violations
+        .iter()
+        .map(|v| v.code.as_str())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn print_report(org: &str, violations: &[PolicyViolation]) {
+    if violations.is_empty() {
+        println!(
+            "{} Project satisfies the {} publish policy",
+            "✓".green(),
+            org
+        );
+        return;
+    }
+    println!("\n{}", format!("{} publish policy violations:", org).bold());
+    for violation in violations {
+        println!(
+            "  {} {}",
+            format!("error[{}]", violation.code).red().bold(),
+            violation.message
+        );
+    }
+    println!();
+}
+
+/// Publish-time hook: a no-op unless `Registry.toml` names an organization.
+pub async fn check_before_publish(api_url: &str, contract_path: &str) -> Result<()> {
+    let Some(org) = crate::manifest::RegistryToml::load(Path::new(contract_path))?
+        .and_then(|manifest| manifest.contract.organization)
+    else {
+        return Ok(());
+    };
+    println!(
+        "\n{}",
+        format!("Checking the {} publish policy...", org)
+            .bold()
+            .cyan()
+    );
+    let token = std::env::var("SOROBAN_REGISTRY_TOKEN").ok();
+    check(api_url, &org, contract_path, None, token.as_deref(), false)
+        .await
+        .context("Organization policy check failed; refusing to publish")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn project(license: Option<&str>) -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        let license = license
+            .map(|l| format!("license = \"{}\"\n", l))
+            .unwrap_or_default();
+        fs::write(
+            dir.path().join("Cargo.toml"),
+            format!(
+                "[package]\nname = \"my-token\"\nversion = \"0.1.0\"\n{}",
+                license
+            ),
+        )
+        .unwrap();
+        dir
+    }
+
+    #[test]
+    fn gathers_license_size_and_snapshot() {
+        let dir = project(Some("MIT"));
+        let wasm = crate::abi_snapshot::default_wasm_path(dir.path(), "my-token");
+        fs::create_dir_all(wasm.parent().unwrap()).unwrap();
+        fs::write(&wasm, vec![0u8; 1234]).unwrap();
+        fs::write(
+            dir.path().join(crate::abi_snapshot::ABI_SNAPSHOT_FILE),
+            "{}",
+        )
+        .unwrap();
+
+        let facts = gather_facts(dir.path(), None, &OrgPolicy::default()).unwrap();
+        assert_eq!(facts.license.as_deref(), Some("MIT"));
+        assert_eq!(facts.wasm_bytes, Some(1234));
+        assert!(facts.has_abi_snapshot);
+        assert_eq!(facts.lint_passed, None);
+    }
+
+    #[test]
+    fn unbuilt_project_reports_server_codes() {
+        let dir = project(None);
+        let policy = OrgPolicy {
+            allowed_licenses: vec!["Apache-2.0".to_string()],
+            max_wasm_bytes: Some(64 * 1024),
+            require_abi_snapshot: true,
+            require_clean_lint: false,
+        };
+        let facts = gather_facts(dir.path(), None, &policy).unwrap();
+        assert_eq!(
+            codes(&policy.evaluate(&facts)),
+            "MissingLicense, WasmNotBuilt, MissingAbiSnapshot"
+        );
+    }
+}
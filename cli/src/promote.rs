@@ -0,0 +1,133 @@
+//! promote.rs — `soroban-registry promote <contract> --from testnet --to mainnet`
+//!
+//! Asks the registry to clear a WASM build for another network. The build
+//! must have been exercised on the source network for the minimum soak
+//! period with no failed migrations; each check is printed with its result.
+//! With `--link`, the contract already deployed from that build on the
+//! target network is joined to the same logical contract.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+pub const NETWORKS: [&str; 3] = ["mainnet", "testnet", "futurenet"];
+
+pub struct PromoteOptions<'a> {
+    pub from: &'a str,
+    pub to: &'a str,
+    /// Build to promote; the source contract's current WASM when unset
+    pub wasm_hash: Option<String>,
+    pub min_soak_hours: Option<i32>,
+    /// Contract on the target network to link once approved
+    pub link: Option<&'a str>,
+}
+
+pub async fn run(
+    api_url: &str,
+    token: &str,
+    contract_id: &str,
+    opts: PromoteOptions<'_>,
+) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/contracts/{}/promotions",
+            api_url, contract_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({
+            "from_network": opts.from,
+            "to_network": opts.to,
+            "wasm_hash": opts.wasm_hash,
+            "min_soak_hours": opts.min_soak_hours,
+        }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let promotion = parse_response(response).await?;
+
+    println!(
+        "\n{}",
+        format!("Promotion: {} → {}", opts.from, opts.to)
+            .bold()
+            .cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+    println!("  {}: {}", "Contract".bold(), contract_id);
+    println!(
+        "  {}: {}",
+        "WASM".bold(),
+        promotion["wasm_hash"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}h\n",
+        "Soak".bold(),
+        promotion["min_soak_hours"].as_i64().unwrap_or(0)
+    );
+    for check in promotion["checks"].as_array().into_iter().flatten() {
+        let mark = if check["passed"].as_bool().unwrap_or(false) {
+            "✓".green()
+        } else {
+            "✗".red()
+        };
+        println!(
+            "  {} {}: {}",
+            mark,
+            check["name"].as_str().unwrap_or("?"),
+            check["detail"].as_str().unwrap_or_default()
+        );
+    }
+    println!("{}", "=".repeat(80).cyan());
+
+    if promotion["status"].as_str() != Some("approved") {
+        bail!(
+            "Promotion to {} rejected; the build is not cleared for deployment",
+            opts.to
+        );
+    }
+    let promotion_id = promotion["id"].as_str().unwrap_or_default();
+    println!("{}", "✓ Promotion approved".green().bold());
+
+    let Some(target) = opts.link else {
+        println!(
+            "\n  Deploy the build to {}, register it, then link it with\n  `soroban-registry promote {} --from {} --to {} --link <{}-contract-id>`\n",
+            opts.to, contract_id, opts.from, opts.to, opts.to
+        );
+        return Ok(());
+    };
+
+    let response = crate::http::client()
+        .post(format!(
+            "{}/api/promotions/{}/complete",
+            api_url, promotion_id
+        ))
+        .bearer_auth(token)
+        .json(&json!({ "contract_id": target }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    parse_response(response).await?;
+    println!(
+        "{}\n",
+        format!("✓ Linked {} on {}", target, opts.to).green().bold()
+    );
+    Ok(())
+}
+
+/// SHA-256 of a local WASM file, as recorded by the registry.
+pub fn wasm_hash(path: &str) -> Result<String> {
+    use sha2::{Digest, Sha256};
+
+    let bytes =
+        std::fs::read(path).with_context(|| format!("Failed to read WASM file at {}", path))?;
+    Ok(hex::encode(Sha256::digest(&bytes)))
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
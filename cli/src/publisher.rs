@@ -0,0 +1,215 @@
+//! publisher.rs — `soroban-registry publisher email|verify-email|profile|usage`
+//!
+//! Email verification for the logged-in publisher. `verify-email` sends a
+//! six-digit code (and a link) to the publisher's email, or to `--email` when
+//! changing it; `verify-email --code <code>` confirms it. Email notification
+//! channels can only be enabled once the address is verified. `profile` sets
+//! the display name, pronouns and avatar returned with the publisher's
+//! contracts. `usage` shows the storage the publisher's sources take up
+//! against its quota. All commands need the publisher's bearer token.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let text = response.text().await.unwrap_or_default();
+    if let Some(message) = crate::http::size_error(status, &text) {
+        bail!(message);
+    }
+    let body: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn print_status(status: &Value) {
+    match status["email"].as_str() {
+        Some(email) if status["verified"].as_bool() == Some(true) => {
+            println!("  {} {} {}", "Email:".bold(), email, "(verified)".green())
+        }
+        Some(email) => println!(
+            "  {} {} {}",
+            "Email:".bold(),
+            email,
+            "(not verified)".yellow()
+        ),
+        None => println!("  {} {}", "Email:".bold(), "none".bright_black()),
+    }
+    if let Some(pending) = status["pending_email"].as_str() {
+        println!(
+            "  {} code sent to {}; confirm with `publisher verify-email --code <code>`",
+            "Pending:".bold(),
+            pending
+        );
+    }
+}
+
+pub async fn email_status(api_url: &str, token: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/me/email", api_url.trim_end_matches('/')))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+        return Ok(());
+    }
+    println!();
+    print_status(&status);
+    println!();
+    Ok(())
+}
+
+pub async fn verify_email(
+    api_url: &str,
+    token: &str,
+    email: Option<&str>,
+    code: Option<&str>,
+) -> Result<()> {
+    let base_url = api_url.trim_end_matches('/');
+    let client = crate::http::client();
+
+    if let Some(code) = code {
+        let response = client
+            .post(format!("{}/api/me/email/verify", base_url))
+            .bearer_auth(token)
+            .json(&json!({ "code": code }))
+            .send()
+            .await
+            .context("Failed to reach registry API")?;
+        let status = check(response).await?;
+        println!("\n{}", "✓ Email verified".green().bold());
+        print_status(&status);
+        println!();
+        return Ok(());
+    }
+
+    let response = client
+        .post(format!("{}/api/me/email/challenge", base_url))
+        .bearer_auth(token)
+        .json(&json!({ "email": email }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let challenge = check(response).await?;
+    println!(
+        "\n{} {}",
+        "✓ Verification code sent to".green().bold(),
+        challenge["email"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  Confirm with `soroban-registry publisher verify-email --code <code>` before {}\n",
+        challenge["expires_at"].as_str().unwrap_or("it expires")
+    );
+    Ok(())
+}
+
+pub async fn update_profile(
+    api_url: &str,
+    token: &str,
+    display_name: Option<&str>,
+    pronouns: Option<&str>,
+    avatar_url: Option<&str>,
+) -> Result<()> {
+    if display_name.is_none() && pronouns.is_none() && avatar_url.is_none() {
+        bail!("Nothing to update: pass --display-name, --pronouns or --avatar-url");
+    }
+    let response = crate::http::client()
+        .patch(format!("{}/api/me/profile", api_url.trim_end_matches('/')))
+        .bearer_auth(token)
+        .json(&json!({
+            "display_name": display_name,
+            "pronouns": pronouns,
+            "avatar_url": avatar_url,
+        }))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let publisher = check(response).await?;
+
+    println!("\n{}", "✓ Profile updated".green().bold());
+    let none = || "none".bright_black().to_string();
+    for (label, key) in [
+        ("Display name:", "display_name"),
+        ("Pronouns:", "pronouns"),
+        ("Avatar:", "avatar_url"),
+    ] {
+        println!(
+            "  {} {}",
+            label.bold(),
+            publisher[key]
+                .as_str()
+                .map(str::to_string)
+                .unwrap_or_else(none)
+        );
+    }
+    println!();
+    Ok(())
+}
+
+fn mib(bytes: i64) -> String {
+    format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+}
+
+pub async fn usage(api_url: &str, token: &str, publisher: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/publishers/{}/usage",
+            api_url.trim_end_matches('/'),
+            publisher
+        ))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let usage = check(response).await?;
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&usage)?);
+        return Ok(());
+    }
+    let used = usage["used_bytes"].as_i64().unwrap_or(0);
+    println!("\n{}", "Storage Usage".bold().cyan());
+    match usage["quota_bytes"].as_i64() {
+        Some(quota) => {
+            let pct = usage["used_pct"].as_f64().unwrap_or(0.0);
+            let line = format!("{} of {} ({:.1}%)", mib(used), mib(quota), pct);
+            let line = if pct >= 90.0 {
+                line.red()
+            } else if pct >= 75.0 {
+                line.yellow()
+            } else {
+                line.green()
+            };
+            println!("  {} {}", "Used:".bold(), line);
+            println!(
+                "  {} {}",
+                "Remaining:".bold(),
+                mib(usage["remaining_bytes"].as_i64().unwrap_or(0))
+            );
+        }
+        None => println!("  {} {} (no quota)", "Used:".bold(), mib(used)),
+    }
+    println!(
+        "  {} {}",
+        "Files:".bold(),
+        usage["files"].as_i64().unwrap_or(0)
+    );
+    for format in usage["by_format"].as_array().into_iter().flatten() {
+        println!(
+            "    {:<8} {:>6} files {:>12}",
+            format["source_format"].as_str().unwrap_or("?"),
+            format["files"].as_i64().unwrap_or(0),
+            mib(format["bytes"].as_i64().unwrap_or(0))
+        );
+    }
+    println!();
+    Ok(())
+}
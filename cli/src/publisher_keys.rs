@@ -0,0 +1,221 @@
+//! publisher_keys.rs — `soroban-registry keys list|register|rotate|revoke-key`
+//!
+//! Manages the signing keys registered for the caller's publisher account.
+//! Once a publisher has registered keys, the registry only accepts releases
+//! signed with one valid at the time:
+//!
+//! - `rotate` registers a replacement and keeps the old key valid for an
+//!   overlap, so releases in flight still verify
+//! - `revoke-key` ends a key at once, or from an earlier time when it was
+//!   compromised before anyone noticed; signatures the registry received
+//!   since then are revoked with it
+//!
+//! Registering a key signs a proof with it, so the private key is needed
+//! (`--secret-key` or `SOROBAN_REGISTRY_SIGNING_KEY`).
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde_json::{json, Value};
+use shared::{KeyValidity, SigningKey};
+
+use crate::package_signing;
+
+/// The account a registry token was issued to, which keys are registered
+/// under.
+fn token_subject(token: &str) -> Result<String> {
+    let payload = token
+        .split('.')
+        .nth(1)
+        .context("Token is not a JWT from the registry's wallet login")?;
+    let claims: Value = serde_json::from_slice(
+        &URL_SAFE_NO_PAD
+            .decode(payload)
+            .context("Token payload is not base64url")?,
+    )
+    .context("Token payload is not JSON")?;
+    claims["sub"]
+        .as_str()
+        .map(str::to_string)
+        .context("Token has no subject")
+}
+
+pub async fn list(
+    api_url: &str,
+    token: Option<&str>,
+    publisher: Option<&str>,
+    json: bool,
+) -> Result<()> {
+    let request = match (publisher, token) {
+        (Some(publisher), _) => {
+            crate::http::client().get(format!("{}/api/publishers/{}/keys", api_url, publisher))
+        }
+        (None, Some(token)) => crate::http::client()
+            .get(format!("{}/api/me/keys", api_url))
+            .bearer_auth(token),
+        (None, None) => bail!("Pass --publisher, or --token to list your own keys"),
+    };
+    let keys: Vec<SigningKey> =
+        serde_json::from_value(send(request).await?).context("Unexpected signing key list")?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&keys)?);
+        return Ok(());
+    }
+
+    println!("\n{}", "Signing Keys".bold().cyan());
+    if keys.is_empty() {
+        println!(
+            "  {}\n",
+            "None registered; releases may be signed with any key".bright_black()
+        );
+        return Ok(());
+    }
+    let now = Utc::now();
+    for key in &keys {
+        let status = match key.window().validity_at(now) {
+            KeyValidity::Valid => match key.valid_until {
+                Some(until) => format!("valid until {}", until.format("%Y-%m-%d %H:%M"))
+                    .yellow()
+                    .to_string(),
+                None => "valid".green().to_string(),
+            },
+            KeyValidity::Revoked => format!(
+                "revoked {}",
+                key.revoked_at.unwrap_or(now).format("%Y-%m-%d %H:%M")
+            )
+            .red()
+            .to_string(),
+            KeyValidity::Expired => "rotated out".bright_black().to_string(),
+            KeyValidity::NotYetValid => format!("valid from {}", key.valid_from.format("%Y-%m-%d"))
+                .yellow()
+                .to_string(),
+        };
+        println!(
+            "  {:<16} {}  {}",
+            key.label.as_deref().unwrap_or("-").bold(),
+            short(key),
+            status
+        );
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn register(
+    api_url: &str,
+    token: &str,
+    secret_key: &str,
+    label: Option<&str>,
+) -> Result<()> {
+    let (public_key, proof) =
+        package_signing::key_registration_proof(secret_key, &token_subject(token)?)?;
+    let key: SigningKey = serde_json::from_value(
+        send(
+            crate::http::client()
+                .post(format!("{}/api/me/keys", api_url))
+                .bearer_auth(token)
+                .json(&json!({ "public_key": public_key, "proof": proof, "label": label })),
+        )
+        .await?,
+    )
+    .context("Unexpected signing key response")?;
+
+    println!("\n{} {}", "✓ Registered key".green().bold(), short(&key));
+    println!("  {}: {}", "Public Key".bold(), key.public_key);
+    println!(
+        "  {} releases must now be signed with one of your registered keys\n",
+        "→".bright_black()
+    );
+    Ok(())
+}
+
+pub async fn rotate(
+    api_url: &str,
+    token: &str,
+    key: &str,
+    secret_key: &str,
+    label: Option<&str>,
+    overlap_hours: Option<i64>,
+) -> Result<()> {
+    let (public_key, proof) =
+        package_signing::key_registration_proof(secret_key, &token_subject(token)?)?;
+    let new: SigningKey = serde_json::from_value(
+        send(
+            crate::http::client()
+                .post(format!("{}/api/me/keys/{}/rotate", api_url, key))
+                .bearer_auth(token)
+                .json(&json!({
+                    "public_key": public_key,
+                    "proof": proof,
+                    "label": label,
+                    "overlap_hours": overlap_hours,
+                })),
+        )
+        .await?,
+    )
+    .context("Unexpected signing key response")?;
+
+    println!("\n{} {} → {}", "✓ Rotated".green().bold(), key, short(&new));
+    println!(
+        "  {} the old key stays valid for {} more hour(s); sign new releases with {}\n",
+        "→".bright_black(),
+        overlap_hours.unwrap_or(24 * 7),
+        short(&new)
+    );
+    Ok(())
+}
+
+pub async fn revoke(
+    api_url: &str,
+    token: &str,
+    key: &str,
+    reason: &str,
+    since: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let revoked = send(
+        crate::http::client()
+            .post(format!("{}/api/me/keys/{}/revoke", api_url, key))
+            .bearer_auth(token)
+            .json(&json!({ "reason": reason, "revoked_at": since })),
+    )
+    .await?;
+
+    println!("\n{} {}", "✓ Revoked key".green().bold(), key);
+    println!(
+        "  {}: {}",
+        "Revoked From".bold(),
+        revoked["key"]["revoked_at"].as_str().unwrap_or("?")
+    );
+    println!(
+        "  {}: {}",
+        "Signatures Revoked".bold(),
+        revoked["signatures_revoked"].as_u64().unwrap_or(0)
+    );
+    println!(
+        "  {}: {}\n",
+        "Badges Revoked".bold(),
+        revoked["badges_revoked"].as_u64().unwrap_or(0)
+    );
+    Ok(())
+}
+
+fn short(key: &SigningKey) -> &str {
+    key.key_fingerprint
+        .get(..16)
+        .unwrap_or(&key.key_fingerprint)
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<Value> {
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
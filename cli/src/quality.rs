@@ -0,0 +1,73 @@
+//! quality.rs — `soroban-registry quality <contract>`
+//!
+//! Shows a contract's composite quality score and how each component —
+//! verified source, docs coverage, ABI stability, tests (CI runs or an
+//! attestation) and audit presence — contributed to it.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+/// "18/20" coloured by how much of the weight was earned.
+fn points_label(points: i64, weight: i64) -> String {
+    let label = format!("{:>2}/{}", points, weight);
+    if weight > 0 && points == weight {
+        label.green().to_string()
+    } else if points > 0 {
+        label.yellow().to_string()
+    } else {
+        label.red().to_string()
+    }
+}
+
+pub async fn show(api_url: &str, contract_id: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/quality",
+            api_url.trim_end_matches('/'),
+            contract_id
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    let score = body["score"].as_i64().unwrap_or(0);
+    let score_label = match score {
+        70..=100 => score.to_string().green().bold(),
+        40..=69 => score.to_string().yellow().bold(),
+        _ => score.to_string().red().bold(),
+    };
+    println!("\n{}", format!("Quality of {}", contract_id).bold().cyan());
+    println!("{}", "=".repeat(60).cyan());
+    println!("  {}: {}/100", "Score".bold(), score_label);
+    println!(
+        "  {}: {}\n",
+        "Computed".bold(),
+        body["computed_at"].as_str().unwrap_or("?")
+    );
+
+    for component in body["components"].as_array().into_iter().flatten() {
+        println!(
+            "  {}  {:<18} {}",
+            points_label(
+                component["points"].as_i64().unwrap_or(0),
+                component["weight"].as_i64().unwrap_or(0)
+            ),
+            component["name"].as_str().unwrap_or("?"),
+            component["detail"].as_str().unwrap_or("").bright_black()
+        );
+    }
+    println!();
+    Ok(())
+}
@@ -0,0 +1,220 @@
+//! recovery.rs — `soroban-registry recovery start|approve|status|cancel|execute`
+//!
+//! Moves a publisher whose account key was lost to a new account. The new
+//! account's key opens the recovery (`--secret-key` or
+//! `SOROBAN_REGISTRY_RECOVERY_KEY`), and approvers sign off on it:
+//!
+//! - the publisher's other registered signing keys, with `approve
+//!   --secret-key` (or `SOROBAN_REGISTRY_SIGNING_KEY`)
+//! - admins of the publisher's organizations, with `approve --token`
+//!
+//! Once approved, a recovery waits out a timelock during which whoever
+//! still holds the old key can `cancel` it; after that anyone can `execute`
+//! it.
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde_json::{json, Value};
+
+use crate::package_signing;
+
+pub async fn start(
+    api_url: &str,
+    account: &str,
+    secret_key: &str,
+    reason: &str,
+    since: Option<DateTime<Utc>>,
+) -> Result<()> {
+    let (public_key, proof) = package_signing::sign_registry_message(secret_key, |public_key| {
+        format!(
+            "soroban-registry:recover-account:{}:{}",
+            account, public_key
+        )
+    })?;
+    let recovery = send(
+        crate::http::client()
+            .post(format!("{}/api/recovery", api_url))
+            .json(&json!({
+                "publisher_address": account,
+                "new_public_key": public_key,
+                "proof": proof,
+                "reason": reason,
+                "compromised_since": since,
+            })),
+    )
+    .await?;
+
+    println!("\n{}", "✓ Recovery requested".green().bold());
+    println!("  {}: {}", "ID".bold(), str_field(&recovery, "id"));
+    println!(
+        "  {}: {}",
+        "New Account".bold(),
+        str_field(&recovery, "new_address")
+    );
+    println!(
+        "  {}: {} by {}",
+        "Approvals Needed".bold(),
+        recovery["required_approvals"].as_i64().unwrap_or(0),
+        str_field(&recovery, "expires_at")
+    );
+    println!(
+        "  {} ask your other keys' holders or your organization admins to run\n    soroban-registry recovery approve {}\n",
+        "→".bright_black(),
+        str_field(&recovery, "id")
+    );
+    Ok(())
+}
+
+pub async fn approve(
+    api_url: &str,
+    id: &str,
+    reject: bool,
+    comment: Option<&str>,
+    secret_key: Option<&str>,
+    token: Option<&str>,
+) -> Result<()> {
+    // The signed message names the recovery as the registry prints it.
+    let id = uuid::Uuid::parse_str(id.trim())
+        .context("Recovery ID must be a UUID")?
+        .to_string();
+    let decision = if reject { "rejected" } else { "approved" };
+    let mut body = json!({ "decision": decision, "comment": comment });
+    let mut request =
+        crate::http::client().post(format!("{}/api/recovery/{}/approve", api_url, id));
+    match (secret_key, token) {
+        (Some(secret_key), _) => {
+            let (public_key, signature) =
+                package_signing::sign_registry_message(secret_key, |_| {
+                    format!("soroban-registry:recovery-decision:{}:{}", id, decision)
+                })?;
+            body["public_key"] = json!(public_key);
+            body["signature"] = json!(signature);
+        }
+        (None, Some(token)) => request = request.bearer_auth(token),
+        (None, None) => {
+            bail!("Pass --secret-key to decide with a signing key, or --token as an org admin")
+        }
+    }
+    let response = send(request.json(&body)).await?;
+
+    let status = str_field(&response, "status");
+    let headline = match status {
+        "rejected" => "✗ Recovery rejected".red().bold(),
+        "approved" => "✓ Recovery approved".green().bold(),
+        _ => "✓ Approval recorded".green().bold(),
+    };
+    println!("\n{}", headline);
+    println!(
+        "  {}: {} collected, {} more needed",
+        "Approvals".bold(),
+        response["approvals_collected"].as_i64().unwrap_or(0),
+        response["approvals_needed"].as_i64().unwrap_or(0)
+    );
+    if let Some(at) = response["executable_at"].as_str() {
+        println!("  {}: {}", "Executable From".bold(), at);
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn status(api_url: &str, id: &str, json: bool) -> Result<()> {
+    let info = send(crate::http::client().get(format!("{}/api/recovery/{}", api_url, id))).await?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+        return Ok(());
+    }
+
+    let recovery = &info["recovery"];
+    println!("\n{}", "Account Recovery".bold().cyan());
+    println!("  {}: {}", "ID".bold(), str_field(recovery, "id"));
+    println!("  {}: {}", "Status".bold(), str_field(recovery, "status"));
+    println!(
+        "  {}: {} → {}",
+        "Account".bold(),
+        str_field(recovery, "lost_address"),
+        str_field(recovery, "new_address")
+    );
+    println!("  {}: {}", "Reason".bold(), str_field(recovery, "reason"));
+    println!(
+        "  {}: {} more needed",
+        "Approvals".bold(),
+        info["approvals_needed"].as_i64().unwrap_or(0)
+    );
+    for approval in info["approvals"].as_array().into_iter().flatten() {
+        let decision = match str_field(approval, "decision") {
+            "approved" => "approved".green(),
+            other => other.red(),
+        };
+        println!(
+            "    {} {} ({})",
+            decision,
+            str_field(approval, "approver"),
+            str_field(approval, "approver_kind")
+        );
+    }
+    if let Some(at) = info["executable_at"].as_str() {
+        println!("  {}: {}", "Executable From".bold(), at);
+    }
+    println!();
+    Ok(())
+}
+
+pub async fn cancel(api_url: &str, id: &str, token: &str) -> Result<()> {
+    send(
+        crate::http::client()
+            .post(format!("{}/api/recovery/{}/cancel", api_url, id))
+            .bearer_auth(token),
+    )
+    .await?;
+    println!("\n{} {}\n", "✓ Cancelled recovery".green().bold(), id);
+    Ok(())
+}
+
+pub async fn execute(api_url: &str, id: &str) -> Result<()> {
+    let executed =
+        send(crate::http::client().post(format!("{}/api/recovery/{}/execute", api_url, id)))
+            .await?;
+
+    let recovery = &executed["recovery"];
+    println!("\n{}", "✓ Account recovered".green().bold());
+    println!(
+        "  {}: {}",
+        "Account".bold(),
+        str_field(recovery, "new_address")
+    );
+    if !executed["revoked"].is_null() {
+        println!(
+            "  {}: {} (signatures revoked: {}, badges revoked: {})",
+            "Old Key Revoked From".bold(),
+            str_field(&executed["revoked"]["key"], "revoked_at"),
+            executed["revoked"]["signatures_revoked"]
+                .as_u64()
+                .unwrap_or(0),
+            executed["revoked"]["badges_revoked"].as_u64().unwrap_or(0)
+        );
+    }
+    println!(
+        "  {} log in with the new account's key from now on\n",
+        "→".bright_black()
+    );
+    Ok(())
+}
+
+fn str_field<'a>(value: &'a Value, field: &str) -> &'a str {
+    value[field].as_str().unwrap_or("?")
+}
+
+async fn send(request: reqwest::RequestBuilder) -> Result<Value> {
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
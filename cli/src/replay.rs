@@ -0,0 +1,410 @@
+//! replay.rs — `soroban-registry events replay <contract> --target <rpc-url>`
+//!
+//! Replays a contract's recorded traffic against a local sandbox deployment,
+//! e.g. to regression-test an upgrade before shipping it. Historical events
+//! are fetched from the registry in ledger order and matched by transaction
+//! hash to the recorded invocations (method and parameters); each
+//! reconstructed invocation is then sent to the target RPC with
+//! `soroban contract invoke`. Transactions without a recorded invocation
+//! cannot be reconstructed and are reported as skipped.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::process::Command;
+
+use crate::events::ContractEvent;
+
+/// Interactions are fetched in pages of this size (the API maximum).
+const INTERACTION_PAGE_SIZE: i64 = 100;
+const MAX_INTERACTION_PAGES: usize = 50;
+
+pub struct ReplayOptions<'a> {
+    /// Registry contract (UUID or on-chain address) whose traffic is replayed
+    pub contract: &'a str,
+    /// RPC URL of the local sandbox
+    pub target: &'a str,
+    pub network_passphrase: &'a str,
+    /// Already deployed local contract to invoke
+    pub local_id: Option<&'a str>,
+    /// WASM to deploy to the sandbox first (instead of `local_id`)
+    pub wasm: Option<&'a str>,
+    pub source: &'a str,
+    pub from_ledger: Option<i64>,
+    /// Maximum number of historical events to fetch
+    pub limit: i64,
+    pub dry_run: bool,
+    pub fail_fast: bool,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordedInvocation {
+    transaction_hash: Option<String>,
+    method: Option<String>,
+    parameters: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InteractionPage {
+    items: Vec<RecordedInvocation>,
+    next_cursor: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Invocation {
+    ledger: i64,
+    tx_hash: String,
+    method: String,
+    args: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Skipped {
+    ledger: i64,
+    tx_hash: String,
+    reason: &'static str,
+}
+
+pub async fn run(api_url: &str, opts: ReplayOptions<'_>) -> Result<()> {
+    let events = fetch_events(api_url, &opts).await?;
+    if events.is_empty() {
+        println!(
+            "No events recorded for {}; nothing to replay.",
+            opts.contract
+        );
+        return Ok(());
+    }
+
+    let registry_id = events[0]
+        .registry_id
+        .clone()
+        .context("Registry did not return the contract's registry ID")?;
+    let recorded = fetch_invocations(api_url, &registry_id, &events).await?;
+    let (invocations, skipped) = reconstruct(&events, &recorded);
+
+    println!("\n{}", "Event Replay".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    println!(
+        "  {} event(s) in {} transaction(s): {} reconstructed, {} skipped",
+        events.len(),
+        invocations.len() + skipped.len(),
+        invocations.len(),
+        skipped.len()
+    );
+    for skip in &skipped {
+        log::debug!(
+            "skipping tx {} (ledger {}): {}",
+            skip.tx_hash,
+            skip.ledger,
+            skip.reason
+        );
+    }
+
+    if opts.dry_run {
+        for inv in &invocations {
+            println!(
+                "  {} ledger {} {}({})",
+                "→".bright_black(),
+                inv.ledger,
+                inv.method.bold(),
+                inv.args.join(" ")
+            );
+        }
+        println!("{}", "=".repeat(80).cyan());
+        return Ok(());
+    }
+
+    let local_id = match (opts.local_id, opts.wasm) {
+        (Some(id), _) => id.to_string(),
+        (None, Some(wasm)) => {
+            println!("{}", "Deploying WASM to the sandbox...".bright_black());
+            soroban(&[
+                "contract",
+                "deploy",
+                "--wasm",
+                wasm,
+                "--source",
+                opts.source,
+                "--rpc-url",
+                opts.target,
+                "--network-passphrase",
+                opts.network_passphrase,
+            ])
+            .context("Failed to deploy WASM to the sandbox")?
+        }
+        (None, None) => anyhow::bail!("Provide --local-id or --wasm for the sandbox contract"),
+    };
+    println!("  {}: {}", "Target".bold(), local_id);
+
+    let mut failed = 0usize;
+    let mut replayed = 0usize;
+    for inv in &invocations {
+        let mut cmd = vec![
+            "contract",
+            "invoke",
+            "--id",
+            &local_id,
+            "--source",
+            opts.source,
+            "--rpc-url",
+            opts.target,
+            "--network-passphrase",
+            opts.network_passphrase,
+            "--",
+            &inv.method,
+        ];
+        cmd.extend(inv.args.iter().map(String::as_str));
+
+        replayed += 1;
+        match soroban(&cmd) {
+            Ok(_) => println!("  {} ledger {} {}", "✓".green(), inv.ledger, inv.method),
+            Err(e) => {
+                failed += 1;
+                println!(
+                    "  {} ledger {} {} ({}): {}",
+                    "✗".red(),
+                    inv.ledger,
+                    inv.method,
+                    &inv.tx_hash[..inv.tx_hash.len().min(12)],
+                    e
+                );
+                if opts.fail_fast {
+                    break;
+                }
+            }
+        }
+    }
+
+    println!("{}", "=".repeat(80).cyan());
+    println!(
+        "  {} replayed, {} failed, {} skipped\n",
+        replayed,
+        failed,
+        skipped.len()
+    );
+    if failed > 0 {
+        anyhow::bail!("{} of {} replayed invocation(s) failed", failed, replayed);
+    }
+    Ok(())
+}
+
+async fn fetch_events(api_url: &str, opts: &ReplayOptions<'_>) -> Result<Vec<ContractEvent>> {
+    let mut query = vec![
+        ("order", "asc".to_string()),
+        ("limit", opts.limit.to_string()),
+    ];
+    if let Some(ledger) = opts.from_ledger {
+        query.push(("from_ledger", ledger.to_string()));
+    }
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/events",
+            api_url, opts.contract
+        ))
+        .query(&query)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    if !response.status().is_success() {
+        let status = response.status();
+        let error = response.text().await.unwrap_or_default();
+        anyhow::bail!("API error ({}): {}", status, error);
+    }
+    Ok(response.json().await?)
+}
+
+/// Recorded invocations keyed by lowercase transaction hash. Pages newest
+/// first and stops once every replayed transaction has been found.
+async fn fetch_invocations(
+    api_url: &str,
+    registry_id: &str,
+    events: &[ContractEvent],
+) -> Result<HashMap<String, RecordedInvocation>> {
+    let mut wanted: Vec<String> = events
+        .iter()
+        .filter_map(|e| e.transaction_hash.as_deref())
+        .map(str::to_ascii_lowercase)
+        .collect();
+    wanted.sort_unstable();
+    wanted.dedup();
+
+    let client = crate::http::client();
+    let mut recorded = HashMap::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_INTERACTION_PAGES {
+        let mut query = vec![("limit", INTERACTION_PAGE_SIZE.to_string())];
+        if let Some(c) = &cursor {
+            query.push(("cursor", c.clone()));
+        }
+        let response = client
+            .get(format!(
+                "{}/api/contracts/{}/interactions",
+                api_url, registry_id
+            ))
+            .query(&query)
+            .send()
+            .await
+            .context("Failed to reach registry API")?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let error = response.text().await.unwrap_or_default();
+            anyhow::bail!("API error ({}): {}", status, error);
+        }
+        let page: InteractionPage = response.json().await?;
+
+        for item in page.items {
+            if let Some(hash) = item.transaction_hash.as_deref() {
+                let hash = hash.to_ascii_lowercase();
+                if wanted.binary_search(&hash).is_ok() {
+                    recorded.entry(hash).or_insert(item);
+                }
+            }
+        }
+        if recorded.len() == wanted.len() {
+            break;
+        }
+        match page.next_cursor {
+            Some(next) => cursor = Some(next),
+            None => break,
+        }
+    }
+    Ok(recorded)
+}
+
+/// One invocation per transaction, in the order the events were emitted.
+fn reconstruct(
+    events: &[ContractEvent],
+    recorded: &HashMap<String, RecordedInvocation>,
+) -> (Vec<Invocation>, Vec<Skipped>) {
+    let mut invocations = Vec::new();
+    let mut skipped = Vec::new();
+    let mut seen: Vec<String> = Vec::new();
+
+    for event in events {
+        let Some(hash) = event.transaction_hash.as_deref() else {
+            continue;
+        };
+        let hash = hash.to_ascii_lowercase();
+        if seen.contains(&hash) {
+            continue;
+        }
+        seen.push(hash.clone());
+
+        let skip = |reason| Skipped {
+            ledger: event.ledger_sequence,
+            tx_hash: hash.clone(),
+            reason,
+        };
+        let Some(recorded) = recorded.get(&hash) else {
+            skipped.push(skip("no recorded invocation"));
+            continue;
+        };
+        let Some(method) = recorded.method.clone() else {
+            skipped.push(skip("invocation has no method"));
+            continue;
+        };
+        let Some(args) = invocation_args(recorded.parameters.as_ref()) else {
+            skipped.push(skip("parameters are not named"));
+            continue;
+        };
+        invocations.push(Invocation {
+            ledger: event.ledger_sequence,
+            tx_hash: hash,
+            method,
+            args,
+        });
+    }
+    (invocations, skipped)
+}
+
+/// `{"to": "G...", "amount": 100}` to `--to G... --amount 100`. Positional
+/// (array) parameters are rejected since `soroban contract invoke` needs
+/// argument names.
+pub(crate) fn invocation_args(parameters: Option<&Value>) -> Option<Vec<String>> {
+    match parameters {
+        None | Some(Value::Null) => Some(Vec::new()),
+        Some(Value::Object(map)) => Some(
+            map.iter()
+                .flat_map(|(name, value)| {
+                    let value = match value {
+                        Value::String(s) => s.clone(),
+                        other => other.to_string(),
+                    };
+                    [format!("--{}", name), value]
+                })
+                .collect(),
+        ),
+        Some(_) => None,
+    }
+}
+
+fn soroban(args: &[&str]) -> Result<String> {
+    let out = Command::new("soroban")
+        .args(args)
+        .output()
+        .context("soroban CLI not found; install it to replay events")?;
+    if !out.status.success() {
+        anyhow::bail!("{}", String::from_utf8_lossy(&out.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn event(ledger: i64, tx: &str) -> ContractEvent {
+        serde_json::from_value(json!({
+            "id": format!("{}-{}", ledger, tx),
+            "contract_id": "CABC",
+            "topic": "transfer",
+            "data": null,
+            "ledger_sequence": ledger,
+            "transaction_hash": tx,
+            "timestamp": "2026-01-01T00:00:00Z",
+            "network": "testnet",
+            "created_at": "2026-01-01T00:00:00Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn converts_named_parameters() {
+        assert_eq!(
+            invocation_args(Some(&json!({ "amount": 100, "to": "GABC" }))).unwrap(),
+            vec!["--amount", "100", "--to", "GABC"]
+        );
+        assert_eq!(invocation_args(None).unwrap(), Vec::<String>::new());
+        assert!(invocation_args(Some(&json!(["GABC", 100]))).is_none());
+    }
+
+    #[test]
+    fn reconstructs_one_invocation_per_transaction() {
+        let events = vec![event(10, "AA"), event(10, "aa"), event(11, "bb")];
+        let mut recorded = HashMap::new();
+        recorded.insert(
+            "aa".to_string(),
+            RecordedInvocation {
+                transaction_hash: Some("AA".into()),
+                method: Some("transfer".into()),
+                parameters: Some(json!({ "amount": 5 })),
+            },
+        );
+
+        let (invocations, skipped) = reconstruct(&events, &recorded);
+        assert_eq!(
+            invocations,
+            vec![Invocation {
+                ledger: 10,
+                tx_hash: "aa".into(),
+                method: "transfer".into(),
+                args: vec!["--amount".into(), "5".into()],
+            }]
+        );
+        assert_eq!(skipped.len(), 1);
+        assert_eq!(skipped[0].tx_hash, "bb");
+    }
+}
@@ -0,0 +1,156 @@
+//! report.rs — `soroban-registry report <contract> --from <date> --to <date>`
+//!
+//! Accounting reports for token contracts: per-address inflow, outflow, fees
+//! and invocation counts over a date range, aggregated by the registry from
+//! the contract's decoded transfer, mint, burn and clawback events. `--format
+//! csv` produces the sheet finance teams import; amounts are the token's raw
+//! integer units.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+pub const FORMATS: [&str; 3] = ["table", "json", "csv"];
+
+/// Net flow with a sign and colour: green for net inflow, red for outflow.
+fn net_cell(net: &str) -> String {
+    match net.strip_prefix('-') {
+        Some(_) => net.red().to_string(),
+        None if net == "0" => net.bright_black().to_string(),
+        None => format!("+{}", net).green().to_string(),
+    }
+}
+
+fn print_report(body: &Value) {
+    let totals = &body["totals"];
+    println!(
+        "\n{}",
+        format!(
+            "Accounting report for {} ({})",
+            body["contract_name"].as_str().unwrap_or("?"),
+            body["network"].as_str().unwrap_or("?")
+        )
+        .bold()
+        .cyan()
+    );
+    println!(
+        "  {} to {}",
+        body["from"].as_str().unwrap_or("?"),
+        body["to"].as_str().unwrap_or("?")
+    );
+    println!("{}", "=".repeat(80).cyan());
+    for (label, key) in [
+        ("Transferred", "transferred"),
+        ("Minted", "minted"),
+        ("Burned", "burned"),
+        ("Fees", "fees"),
+    ] {
+        println!(
+            "  {:<12} {}",
+            label.bold(),
+            totals[key].as_str().unwrap_or("0")
+        );
+    }
+    println!(
+        "  {:<12} {}",
+        "Events".bold(),
+        totals["events"].as_u64().unwrap_or(0)
+    );
+    let skipped = totals["skipped_events"].as_u64().unwrap_or(0);
+    if skipped > 0 {
+        println!(
+            "  {}",
+            format!("{} token events had no readable amount", skipped).yellow()
+        );
+    }
+
+    let addresses = body["addresses"].as_array().cloned().unwrap_or_default();
+    if addresses.is_empty() {
+        println!("\n  {}\n", "No activity in this range.".bright_black());
+        return;
+    }
+    println!(
+        "\n  {:<56} {:>14} {:>14} {:>14} {:>10}",
+        "Address".bold(),
+        "Inflow".bold(),
+        "Outflow".bold(),
+        "Fees".bold(),
+        "Invokes".bold()
+    );
+    for row in &addresses {
+        println!(
+            "  {:<56} {:>14} {:>14} {:>14} {:>10}",
+            row["address"].as_str().unwrap_or("?"),
+            row["inflow"].as_str().unwrap_or("0"),
+            row["outflow"].as_str().unwrap_or("0"),
+            row["fees"].as_str().unwrap_or("0"),
+            row["invocations"].as_i64().unwrap_or(0)
+        );
+        println!(
+            "  {:<56} {:>14}",
+            "",
+            net_cell(row["net"].as_str().unwrap_or("0"))
+        );
+    }
+    println!();
+}
+
+pub async fn accounting(
+    api_url: &str,
+    contract_id: &str,
+    from: &str,
+    to: &str,
+    format: &str,
+    output: Option<&str>,
+) -> Result<()> {
+    let base_url = api_url.trim_end_matches('/');
+    let wire_format = if format == "csv" { "csv" } else { "json" };
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/contracts/{}/reports/accounting",
+            base_url, contract_id
+        ))
+        .query(&[("from", from), ("to", to), ("format", wire_format)])
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body: Value = response.json().await.unwrap_or(Value::Null);
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    let text = match format {
+        "csv" => response.text().await?,
+        _ => {
+            let body: Value = response.json().await?;
+            if format == "table" && output.is_none() {
+                print_report(&body);
+                return Ok(());
+            }
+            serde_json::to_string_pretty(&body)?
+        }
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, &text).with_context(|| format!("Failed to write {}", path))?;
+            println!("{} {}", "✓ Report written to".green().bold(), path);
+        }
+        None => println!("{}", text.trim_end()),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn net_flow_is_signed() {
+        assert!(net_cell("250").contains("+250"));
+        assert!(net_cell("-40").contains("-40"));
+        assert!(!net_cell("0").contains('+'));
+    }
+}
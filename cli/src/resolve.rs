@@ -0,0 +1,81 @@
+//! resolve.rs — contract references on the command line
+//!
+//! Commands take a contract as its registry UUID or its on-chain address
+//! (`C…`). Not every registry endpoint accepts both, so commands that call
+//! UUID-only endpoints resolve the reference first with [`contract_uuid`],
+//! through `GET /api/contracts/by-address/:address`. Anything that is not an
+//! address is passed through unchanged.
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+
+/// Whether `reference` looks like a Stellar contract address: 56 characters
+/// of upper-case base32 starting with `C`.
+pub fn is_contract_address(reference: &str) -> bool {
+    reference.len() == 56
+        && reference.starts_with('C')
+        && reference
+            .bytes()
+            .all(|b| b.is_ascii_uppercase() || (b'2'..=b'7').contains(&b))
+}
+
+/// Registry UUID of the contract `reference` names. `network` picks the
+/// deployment when one address is registered on several networks.
+pub async fn contract_uuid(
+    api_url: &str,
+    reference: &str,
+    network: Option<&str>,
+) -> Result<String> {
+    if !is_contract_address(reference) {
+        return Ok(reference.to_string());
+    }
+    let mut request = crate::http::client().get(format!(
+        "{}/api/contracts/by-address/{}",
+        api_url.trim_end_matches('/'),
+        reference
+    ));
+    if let Some(network) = network {
+        request = request.query(&[("network", network)]);
+    }
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!(
+            "Could not resolve contract {} ({}): {}",
+            reference,
+            status,
+            err
+        );
+    }
+    // The contract record is flattened into the response body.
+    let contract = body.get("contract").unwrap_or(&body);
+    contract["id"]
+        .as_str()
+        .map(str::to_string)
+        .with_context(|| format!("Registry returned no id for contract {}", reference))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recognises_contract_addresses_only() {
+        assert!(is_contract_address(
+            "CDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+        ));
+        assert!(!is_contract_address(
+            "GDLZFC3SYJYDZT7K67VZ75HPJVIEUVNIXF47ZG2FB2RMQQVU2HHGCYSC"
+        ));
+        assert!(!is_contract_address(
+            "cdlzfc3syjydzt7k67vz75hpjvieuvnixf47zg2fb2rmqqvu2hhgcysc"
+        ));
+        assert!(!is_contract_address("6f1c2a9e-8d4b-4c3e-9a7f-2b1d0e5c4a3b"));
+        assert!(!is_contract_address("my-token"));
+    }
+}
@@ -0,0 +1,197 @@
+//! scaffold.rs — `soroban-registry new <name> --template <kind>`
+//!
+//! Scaffolds a buildable contract project from the registry's template
+//! catalog. Templates are verified contracts hosted in the registry, so the
+//! generated project records which contract it was derived from in
+//! `Registry.toml`.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+use crate::manifest::{RegistryContractSection, RegistryTemplateSection, RegistryToml};
+
+pub const TEMPLATE_KINDS: [&str; 4] = ["token", "nft", "dao", "escrow"];
+
+const DEFAULT_CARGO_TOML: &str = r#"[package]
+name = "{{name}}"
+version = "0.1.0"
+edition = "2021"
+
+[lib]
+crate-type = ["cdylib"]
+
+[dependencies]
+soroban-sdk = "21"
+
+[dev-dependencies]
+soroban-sdk = { version = "21", features = ["testutils"] }
+
+[profile.release]
+opt-level = "z"
+overflow-checks = true
+debug = 0
+strip = "symbols"
+debug-assertions = false
+panic = "abort"
+codegen-units = 1
+lto = true
+"#;
+
+const GITIGNORE: &str = "/target\n*.wasm\n";
+
+#[derive(Debug, Deserialize)]
+struct TemplateDetail {
+    slug: String,
+    name: String,
+    category: String,
+    version: String,
+    contract_address: String,
+    source_code: String,
+    cargo_toml: Option<String>,
+}
+
+pub async fn run(
+    api_url: &str,
+    name: &str,
+    template: &str,
+    path: Option<&str>,
+    token: Option<&str>,
+) -> Result<()> {
+    let package_name = package_name(name)?;
+    let project_dir = Path::new(path.unwrap_or(".")).join(name);
+    if project_dir.exists() {
+        anyhow::bail!("Destination {} already exists", project_dir.display());
+    }
+
+    println!(
+        "\n{} {} {}",
+        "Scaffolding".bold().cyan(),
+        name.bold(),
+        format!("from the '{}' template...", template).bright_black()
+    );
+
+    let client = crate::http::client();
+    let url = format!("{}/api/templates/{}", api_url, template);
+    log::debug!("GET {}", url);
+    let response = client
+        .get(&url)
+        .send()
+        .await
+        .context("Failed to reach the registry template catalog")?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        anyhow::bail!(
+            "Template '{}' is not available in this registry's catalog",
+            template
+        );
+    }
+    if !response.status().is_success() {
+        anyhow::bail!("Failed to fetch template: {}", response.status());
+    }
+    let detail: TemplateDetail = response.json().await?;
+
+    fs::create_dir_all(project_dir.join("src"))
+        .with_context(|| format!("Failed to create {}", project_dir.display()))?;
+
+    let cargo_toml = detail
+        .cargo_toml
+        .as_deref()
+        .unwrap_or(DEFAULT_CARGO_TOML)
+        .replace("{{name}}", &package_name);
+    fs::write(project_dir.join("Cargo.toml"), cargo_toml)?;
+    fs::write(project_dir.join("src/lib.rs"), &detail.source_code)?;
+    fs::write(project_dir.join(".gitignore"), GITIGNORE)?;
+
+    let registry_toml = RegistryToml {
+        contract: RegistryContractSection {
+            name: name.to_string(),
+            description: Some(format!("Scaffolded from the {} template", detail.name)),
+            category: Some(detail.category.clone()),
+            tags: vec![detail.slug.clone()],
+            network: "testnet".into(),
+            organization: None,
+        },
+        template: Some(RegistryTemplateSection {
+            slug: detail.slug.clone(),
+            version: detail.version.clone(),
+            source_contract: detail.contract_address.clone(),
+        }),
+        build: None,
+    };
+    registry_toml.save(&project_dir)?;
+
+    // Install tracking is best-effort and counted per account, so it needs
+    // a token; a failure here shouldn't undo the scaffold.
+    if let Some(token) = token {
+        let _ = client
+            .post(format!("{}/api/templates/{}/install", api_url, detail.slug))
+            .bearer_auth(token)
+            .send()
+            .await;
+    }
+
+    println!("{}", "✓ Project created".green().bold());
+    println!("  {}: {}", "Path".bold(), project_dir.display());
+    println!(
+        "  {}: {} v{} ({})",
+        "Template".bold(),
+        detail.name,
+        detail.version,
+        detail.contract_address.bright_black()
+    );
+    println!(
+        "\n  cd {} && cargo build --target wasm32-unknown-unknown --release\n",
+        name
+    );
+
+    Ok(())
+}
+
+/// Derive a Cargo package name from the requested project name.
+fn package_name(name: &str) -> Result<String> {
+    let trimmed = name.trim();
+    let valid = !trimmed.is_empty()
+        && trimmed
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        && trimmed
+            .chars()
+            .next()
+            .map(|c| c.is_ascii_alphabetic())
+            .unwrap_or(false);
+    if !valid {
+        anyhow::bail!(
+            "Invalid project name '{}': use letters, digits, '-' or '_' and start with a letter",
+            name
+        );
+    }
+    Ok(trimmed.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn package_name_lowercases_valid_names() {
+        assert_eq!(package_name("MyToken").unwrap(), "mytoken");
+        assert_eq!(package_name("escrow_v2").unwrap(), "escrow_v2");
+    }
+
+    #[test]
+    fn package_name_rejects_invalid_names() {
+        assert!(package_name("").is_err());
+        assert!(package_name("1token").is_err());
+        assert!(package_name("my token").is_err());
+    }
+
+    #[test]
+    fn default_cargo_toml_substitutes_name() {
+        let rendered = DEFAULT_CARGO_TOML.replace("{{name}}", "demo");
+        assert!(rendered.contains("name = \"demo\""));
+        assert!(rendered.contains("cdylib"));
+    }
+}
@@ -0,0 +1,487 @@
+//! self_update.rs — `soroban-registry self-update`
+//!
+//! Reads the release manifest the registry serves at
+//! `/api/cli/releases/latest`, downloads the binary for the target this CLI
+//! was built for, checks its SHA-256 and the Ed25519 signature over its
+//! version, target and digest against the release signing key, and replaces
+//! the running executable in place. Releases older than the installed CLI
+//! are refused, so an old signed release cannot be replayed as an update.
+//!
+//! [`startup_check`] runs before other commands: at most once a day it asks
+//! the registry for its minimum supported CLI version, and warns on every run
+//! while the installed CLI is older than that.
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use shared::semver::SemVer;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+pub const CHANNELS: [&str; 2] = ["stable", "beta"];
+
+const INSTALLED_VERSION: &str = env!("CARGO_PKG_VERSION");
+const BUILD_TARGET: &str = env!("BUILD_TARGET");
+/// Base64 Ed25519 public key release artifacts are signed with; embedded by
+/// release builds.
+const EMBEDDED_RELEASE_KEY: Option<&str> = option_env!("SOROBAN_REGISTRY_RELEASE_KEY");
+
+const CHECK_CACHE_FILE: &str = "update-check.json";
+const CHECK_INTERVAL_HOURS: i64 = 24;
+const CHECK_TIMEOUT_SECS: u64 = 3;
+const DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+/// Set to any value to skip the startup version check.
+const NO_CHECK_ENV: &str = "SOROBAN_REGISTRY_NO_UPDATE_CHECK";
+
+#[derive(Debug, Deserialize)]
+struct Manifest {
+    min_supported_version: Option<String>,
+    latest: Option<Release>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    version: String,
+    notes: Option<String>,
+    #[serde(default)]
+    artifacts: Vec<Artifact>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Artifact {
+    target: String,
+    url: String,
+    sha256: String,
+    signature: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct CheckCache {
+    api_url: String,
+    checked_at: DateTime<Utc>,
+    min_supported_version: Option<String>,
+    latest_version: Option<String>,
+}
+
+/// Whether `version` is strictly older than `other`. Unparseable versions
+/// never compare older.
+fn is_older(version: &str, other: &str) -> bool {
+    match (SemVer::parse(version), SemVer::parse(other)) {
+        (Some(version), Some(other)) => version < other,
+        _ => false,
+    }
+}
+
+async fn fetch_manifest(api_url: &str, channel: &str, timeout: Duration) -> Result<Manifest> {
+    let client = crate::http::builder()
+        .timeout(timeout)
+        .build()
+        .context("Failed to build HTTP client")?;
+    let response = client
+        .get(format!(
+            "{}/api/cli/releases/latest",
+            api_url.trim_end_matches('/')
+        ))
+        .query(&[("channel", channel), ("target", BUILD_TARGET)])
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    if !status.is_success() {
+        let body: serde_json::Value = response.json().await.unwrap_or_default();
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    response
+        .json()
+        .await
+        .context("Registry returned an invalid release manifest")
+}
+
+fn decode_key(key_b64: &str) -> Result<ed25519_dalek::VerifyingKey> {
+    let bytes = BASE64
+        .decode(key_b64.trim())
+        .context("Invalid release key (expected base64-encoded Ed25519 key)")?;
+    let bytes: [u8; 32] = bytes
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Release key must decode to 32 bytes"))?;
+    ed25519_dalek::VerifyingKey::from_bytes(&bytes)
+        .map_err(|_| anyhow::anyhow!("Release key is not a valid Ed25519 key"))
+}
+
+/// The release manifest entry a signature covers. Signing the version and
+/// target along with the digest keeps a signed binary from being offered
+/// under another version or for another platform.
+fn signed_message(version: &str, target: &str, sha256: &str) -> String {
+    format!(
+        "soroban-registry release\nversion: {}\ntarget: {}\nsha256: {}\n",
+        version.trim(),
+        target.trim(),
+        sha256.trim().to_ascii_lowercase()
+    )
+}
+
+/// Fails unless `candidate` is a newer version than `installed`.
+fn ensure_upgrade(installed: &str, candidate: &str) -> Result<()> {
+    let Some(candidate_version) = SemVer::parse(candidate) else {
+        bail!("Release version {:?} is not a semantic version", candidate);
+    };
+    match SemVer::parse(installed) {
+        Some(installed_version) if candidate_version > installed_version => Ok(()),
+        Some(_) => bail!(
+            "Refusing to install {}: it is not newer than the installed {}",
+            candidate,
+            installed
+        ),
+        None => bail!(
+            "Installed version {:?} is not a semantic version",
+            installed
+        ),
+    }
+}
+
+/// Checks a downloaded binary of release `version` against the artifact's
+/// digest and signature, and that the release is newer than `installed`.
+fn verify_artifact(
+    binary: &[u8],
+    installed: &str,
+    version: &str,
+    artifact: &Artifact,
+    key: &ed25519_dalek::VerifyingKey,
+) -> Result<()> {
+    ensure_upgrade(installed, version)?;
+    let digest = hex::encode(Sha256::digest(binary));
+    if !digest.eq_ignore_ascii_case(artifact.sha256.trim()) {
+        bail!(
+            "Downloaded binary has SHA-256 {}, expected {}",
+            digest,
+            artifact.sha256
+        );
+    }
+    let signature = BASE64
+        .decode(artifact.signature.trim())
+        .context("Release signature is not valid base64")?;
+    let signature: [u8; 64] = signature
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Release signature must decode to 64 bytes"))?;
+    let message = signed_message(version, &artifact.target, &digest);
+    key.verify_strict(
+        message.as_bytes(),
+        &ed25519_dalek::Signature::from_bytes(&signature),
+    )
+    .map_err(|_| anyhow::anyhow!("Release signature does not verify against the release key"))
+}
+
+/// Replaces the running executable with `binary`, keeping the old one until
+/// the new one is in place.
+fn install(binary: &[u8]) -> Result<PathBuf> {
+    let current = std::env::current_exe().context("Cannot locate the installed binary")?;
+    let current = fs::canonicalize(&current).unwrap_or(current);
+    let dir = current
+        .parent()
+        .context("Installed binary has no parent directory")?;
+    let name = current
+        .file_name()
+        .context("Installed binary has no file name")?
+        .to_string_lossy()
+        .into_owned();
+
+    let staged = dir.join(format!(".{}.new", name));
+    fs::write(&staged, binary).with_context(|| {
+        format!(
+            "Failed to write {:?}; is the install directory writable?",
+            staged
+        )
+    })?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to make {:?} executable", staged))?;
+    }
+
+    let backup = dir.join(format!(".{}.old", name));
+    let _ = fs::remove_file(&backup);
+    fs::rename(&current, &backup).with_context(|| format!("Failed to move {:?} aside", current))?;
+    if let Err(err) = fs::rename(&staged, &current) {
+        let _ = fs::rename(&backup, &current);
+        let _ = fs::remove_file(&staged);
+        return Err(err).context("Failed to replace the installed binary");
+    }
+    // Windows keeps a running executable locked; the next update removes it.
+    let _ = fs::remove_file(&backup);
+    Ok(current)
+}
+
+pub async fn run(
+    api_url: &str,
+    channel: &str,
+    check_only: bool,
+    release_key: Option<&str>,
+) -> Result<()> {
+    println!(
+        "\n{}",
+        format!("Checking for updates ({} channel)...", channel)
+            .bold()
+            .cyan()
+    );
+    let manifest = fetch_manifest(api_url, channel, Duration::from_secs(30)).await?;
+    save_check(api_url, &manifest);
+
+    println!("  {}: {}", "Installed".bold(), INSTALLED_VERSION);
+    if let Some(min) = &manifest.min_supported_version {
+        println!("  {}: {}", "Minimum supported".bold(), min);
+    }
+    let Some(release) = manifest.latest else {
+        println!("\n{}\n", "No releases published on this channel.".yellow());
+        return Ok(());
+    };
+    println!("  {}: {}", "Latest".bold(), release.version);
+
+    if !is_older(INSTALLED_VERSION, &release.version) {
+        println!(
+            "\n{} soroban-registry {} is up to date\n",
+            "✓".green(),
+            INSTALLED_VERSION
+        );
+        return Ok(());
+    }
+    if let Some(notes) = &release.notes {
+        println!("\n{}\n{}", "Release notes:".bold(), notes);
+    }
+    if check_only {
+        println!(
+            "\nRun {} to install {}.\n",
+            "soroban-registry self-update".bold(),
+            release.version
+        );
+        return Ok(());
+    }
+
+    let key = match (EMBEDDED_RELEASE_KEY, release_key) {
+        (Some(_), Some(_)) => bail!(
+            "This build embeds the release signing key; --release-key is only for builds without one"
+        ),
+        (Some(key), None) | (None, Some(key)) => decode_key(key)?,
+        (None, None) => bail!(
+            "This build has no release signing key; pass the registry's key with --release-key"
+        ),
+    };
+    let artifact = release
+        .artifacts
+        .iter()
+        .find(|a| a.target == BUILD_TARGET)
+        .with_context(|| {
+            format!(
+                "Release {} has no build for {}",
+                release.version, BUILD_TARGET
+            )
+        })?;
+
+    println!("\n{} {}", "Downloading".bold(), artifact.url.bright_black());
+    let binary = crate::http::builder()
+        .timeout(Duration::from_secs(DOWNLOAD_TIMEOUT_SECS))
+        .build()
+        .context("Failed to build HTTP client")?
+        .get(&artifact.url)
+        .send()
+        .await
+        .and_then(|r| r.error_for_status())
+        .context("Failed to download the release")?
+        .bytes()
+        .await
+        .context("Failed to download the release")?;
+
+    verify_artifact(&binary, INSTALLED_VERSION, &release.version, artifact, &key)?;
+    println!("{} Signature verified", "✓".green());
+
+    let path = install(&binary)?;
+    println!(
+        "{} Updated soroban-registry {} → {} at {}\n",
+        "✓".green().bold(),
+        INSTALLED_VERSION,
+        release.version,
+        path.display()
+    );
+    Ok(())
+}
+
+fn cache_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".soroban-registry").join(CHECK_CACHE_FILE))
+}
+
+fn load_check(api_url: &str) -> Option<CheckCache> {
+    let content = fs::read_to_string(cache_path()?).ok()?;
+    let cache: CheckCache = serde_json::from_str(&content).ok()?;
+    let fresh = Utc::now() - cache.checked_at < ChronoDuration::hours(CHECK_INTERVAL_HOURS);
+    (cache.api_url == api_url && fresh).then_some(cache)
+}
+
+fn save_check(api_url: &str, manifest: &Manifest) {
+    let Some(path) = cache_path() else { return };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    let cache = CheckCache {
+        api_url: api_url.to_string(),
+        checked_at: Utc::now(),
+        min_supported_version: manifest.min_supported_version.clone(),
+        latest_version: manifest.latest.as_ref().map(|r| r.version.clone()),
+    };
+    if let Ok(json) = serde_json::to_string_pretty(&cache) {
+        let _ = fs::write(&path, json);
+    }
+}
+
+/// Warns when the installed CLI is older than the registry's minimum
+/// supported version. Never fails: an unreachable registry skips the check.
+pub async fn startup_check(api_url: &str) {
+    if std::env::var_os(NO_CHECK_ENV).is_some() {
+        return;
+    }
+    let (min_supported, newer) = match load_check(api_url) {
+        Some(cache) => (cache.min_supported_version, None),
+        None => {
+            let manifest =
+                match fetch_manifest(api_url, "stable", Duration::from_secs(CHECK_TIMEOUT_SECS))
+                    .await
+                {
+                    Ok(manifest) => manifest,
+                    Err(err) => {
+                        log::debug!("Skipping CLI version check: {:#}", err);
+                        return;
+                    }
+                };
+            save_check(api_url, &manifest);
+            let newer = manifest
+                .latest
+                .map(|r| r.version)
+                .filter(|v| is_older(INSTALLED_VERSION, v));
+            (manifest.min_supported_version, newer)
+        }
+    };
+
+    match min_supported {
+        Some(min) if is_older(INSTALLED_VERSION, &min) => eprintln!(
+            "{}",
+            format!(
+                "warning: soroban-registry {} is older than {}, the oldest version this registry supports. \
+                 Run `soroban-registry self-update`.",
+                INSTALLED_VERSION, min
+            )
+            .yellow()
+        ),
+        _ => {
+            if let Some(version) = newer {
+                eprintln!(
+                    "{}",
+                    format!(
+                        "soroban-registry {} is available (installed {}); run `soroban-registry self-update`.",
+                        version, INSTALLED_VERSION
+                    )
+                    .dimmed()
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn signed_artifact(binary: &[u8], version: &str, key: &SigningKey) -> Artifact {
+        let sha256 = hex::encode(Sha256::digest(binary));
+        let message = signed_message(version, BUILD_TARGET, &sha256);
+        Artifact {
+            target: BUILD_TARGET.to_string(),
+            url: "https://example.invalid/soroban-registry".to_string(),
+            sha256,
+            signature: BASE64.encode(key.sign(message.as_bytes()).to_bytes()),
+        }
+    }
+
+    #[test]
+    fn compares_versions() {
+        assert!(is_older("0.1.0", "0.2.0"));
+        assert!(is_older("0.2.0-beta.1", "0.2.0"));
+        assert!(!is_older("0.2.0", "0.2.0"));
+        assert!(!is_older("0.1.0", "not-a-version"));
+    }
+
+    #[test]
+    fn accepts_a_correctly_signed_binary() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let binary = b"new soroban-registry binary";
+        let artifact = signed_artifact(binary, "0.2.0", &key);
+        assert!(verify_artifact(binary, "0.1.0", "0.2.0", &artifact, &key.verifying_key()).is_ok());
+    }
+
+    #[test]
+    fn rejects_tampered_or_foreign_binaries() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let binary = b"new soroban-registry binary";
+        let verify = |binary: &[u8], artifact: &Artifact| {
+            verify_artifact(binary, "0.1.0", "0.2.0", artifact, &key.verifying_key())
+        };
+
+        let mut artifact = signed_artifact(binary, "0.2.0", &key);
+        assert!(verify(b"tampered", &artifact).is_err());
+
+        // Digest updated to match, but the signature is still over the original.
+        artifact.sha256 = hex::encode(Sha256::digest(b"tampered"));
+        assert!(verify(b"tampered", &artifact).is_err());
+
+        let other = SigningKey::from_bytes(&[9; 32]);
+        let artifact = signed_artifact(binary, "0.2.0", &other);
+        assert!(verify(binary, &artifact).is_err());
+    }
+
+    #[test]
+    fn signature_is_bound_to_version_and_target() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let binary = b"new soroban-registry binary";
+
+        // A binary signed for 0.2.0 offered as 0.3.0.
+        let artifact = signed_artifact(binary, "0.2.0", &key);
+        assert!(
+            verify_artifact(binary, "0.1.0", "0.3.0", &artifact, &key.verifying_key()).is_err()
+        );
+
+        // Signed for this target, offered for another.
+        let mut artifact = signed_artifact(binary, "0.2.0", &key);
+        artifact.target = "wasm32-unknown-unknown".to_string();
+        assert!(
+            verify_artifact(binary, "0.1.0", "0.2.0", &artifact, &key.verifying_key()).is_err()
+        );
+    }
+
+    #[test]
+    fn refuses_releases_that_are_not_newer() {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let binary = b"old soroban-registry binary";
+
+        // Correctly signed, but older than or the same as what is installed.
+        for version in ["0.1.0", "0.2.0", "0.2.0-beta.1"] {
+            let artifact = signed_artifact(binary, version, &key);
+            assert!(
+                verify_artifact(binary, "0.2.0", version, &artifact, &key.verifying_key()).is_err(),
+                "{}",
+                version
+            );
+        }
+
+        let artifact = signed_artifact(binary, "latest", &key);
+        assert!(
+            verify_artifact(binary, "0.2.0", "latest", &artifact, &key.verifying_key()).is_err()
+        );
+        assert!(ensure_upgrade("0.2.0", "0.2.1").is_ok());
+    }
+}
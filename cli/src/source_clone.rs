@@ -0,0 +1,281 @@
+//! source_clone.rs — `soroban-registry clone <contract>`
+//!
+//! Downloads the verified source bundle for a contract version into a local
+//! directory and writes `PROVENANCE.json` alongside it, so what is checked
+//! out can be traced back to the exact on-chain deployment it was verified
+//! against. With `--require-signed` or `--require-trusted` the version's
+//! package signatures are checked against the local trust store before
+//! anything is written.
+
+use anyhow::{Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::trust::{Keyring, SignatureCheck, TrustPolicy};
+
+pub const PROVENANCE_FILE: &str = "PROVENANCE.json";
+
+#[derive(Debug, Deserialize)]
+struct ContractVersionRow {
+    version: String,
+    wasm_hash: String,
+    source_url: Option<String>,
+    commit_hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SourceResponse {
+    source_hash: String,
+    source_base64: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignatureRow {
+    wasm_hash: String,
+    signature: String,
+    public_key: String,
+    status: String,
+    expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignatureLookup {
+    signatures: Vec<SignatureRow>,
+}
+
+/// Provenance record written next to the cloned sources.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Provenance {
+    pub registry: String,
+    pub registry_id: String,
+    pub contract_id: String,
+    pub name: String,
+    pub network: String,
+    pub version: String,
+    pub wasm_hash: String,
+    pub source_url: Option<String>,
+    pub commit_hash: Option<String>,
+    pub source_sha256: String,
+    pub cloned_at: chrono::DateTime<chrono::Utc>,
+}
+
+pub async fn run(
+    api_url: &str,
+    contract: &str,
+    version: Option<&str>,
+    dir: Option<&str>,
+    policy: TrustPolicy,
+) -> Result<()> {
+    let client = crate::http::client();
+    let base = api_url.trim_end_matches('/');
+
+    println!("\n{}", "Fetching verified source...".bold().cyan());
+
+    let meta: serde_json::Value = client
+        .get(format!("{}/api/contracts/{}", base, contract))
+        .send()
+        .await
+        .context("Failed to reach the registry")?
+        .error_for_status()
+        .context("Contract not found in registry")?
+        .json()
+        .await?;
+    let meta = &meta["contract"];
+
+    let registry_id = meta["id"]
+        .as_str()
+        .context("Registry response missing contract id")?;
+    let name = meta["name"].as_str().unwrap_or(contract);
+    let address = meta["contract_id"].as_str().unwrap_or(contract);
+    if !meta["is_verified"].as_bool().unwrap_or(false) {
+        anyhow::bail!(
+            "{} is not verified; only verified sources can be cloned",
+            name
+        );
+    }
+
+    let versions: Vec<ContractVersionRow> = client
+        .get(format!("{}/api/contracts/{}/versions", base, registry_id))
+        .send()
+        .await?
+        .error_for_status()
+        .context("Failed to list contract versions")?
+        .json()
+        .await?;
+    let selected = match version {
+        Some(v) => versions
+            .into_iter()
+            .find(|row| row.version == v)
+            .with_context(|| format!("Version {} not found for {}", v, name))?,
+        // The versions endpoint returns newest first.
+        None => versions
+            .into_iter()
+            .next()
+            .with_context(|| format!("{} has no published versions", name))?,
+    };
+
+    let source: SourceResponse = client
+        .get(format!(
+            "{}/api/contracts/{}/versions/{}/source",
+            base, registry_id, selected.version
+        ))
+        .query(&[("source_format", "rust")])
+        .send()
+        .await?
+        .error_for_status()
+        .context("No source bundle stored for this version")?
+        .json()
+        .await?;
+    let bytes = BASE64
+        .decode(source.source_base64.unwrap_or_default())
+        .context("Source bundle is not valid base64")?;
+
+    let digest = format!("{:x}", Sha256::digest(&bytes));
+    if digest != source.source_hash {
+        anyhow::bail!(
+            "Source integrity check failed: expected {}, got {}",
+            source.source_hash,
+            digest
+        );
+    }
+
+    if policy.is_active() {
+        let ids = [registry_id, address, contract];
+        let check = check_signatures(&client, base, &ids, &selected).await?;
+        println!("  {}: {}", "Signature".bold(), check.describe());
+        policy.enforce(&check, &format!("{} {}", name, selected.version))?;
+    }
+
+    let dest = destination(dir, name, &selected.version);
+    if dest.exists() && fs::read_dir(&dest)?.next().is_some() {
+        anyhow::bail!("Destination {} is not empty", dest.display());
+    }
+    fs::create_dir_all(&dest)?;
+    write_bundle(&bytes, &dest).map_err(crate::import::explain_rejection)?;
+
+    let provenance = Provenance {
+        registry: base.to_string(),
+        registry_id: registry_id.to_string(),
+        contract_id: address.to_string(),
+        name: name.to_string(),
+        network: meta["network"].as_str().unwrap_or("unknown").to_string(),
+        version: selected.version.clone(),
+        wasm_hash: selected.wasm_hash.clone(),
+        source_url: selected.source_url.clone(),
+        commit_hash: selected.commit_hash.clone(),
+        source_sha256: digest,
+        cloned_at: chrono::Utc::now(),
+    };
+    fs::write(
+        dest.join(PROVENANCE_FILE),
+        serde_json::to_string_pretty(&provenance)?,
+    )?;
+
+    println!("{}", "✓ Source cloned".green().bold());
+    println!("  {}: {}", "Path".bold(), dest.display());
+    println!("  {}: {}", "Version".bold(), selected.version);
+    if let Some(commit) = &selected.commit_hash {
+        println!("  {}: {}", "Commit".bold(), commit.bright_black());
+    }
+    println!(
+        "  {}: {}",
+        "WASM hash".bold(),
+        selected.wasm_hash.bright_black()
+    );
+    println!();
+
+    Ok(())
+}
+
+/// The best of the version's package signatures over its WASM hash: trusted
+/// beats merely valid, and valid beats invalid. The signed message names the
+/// contract however the publisher did, so each of `ids` is tried.
+async fn check_signatures(
+    client: &reqwest::Client,
+    base: &str,
+    ids: &[&str],
+    selected: &ContractVersionRow,
+) -> Result<SignatureCheck> {
+    let lookup: SignatureLookup = client
+        .get(format!("{}/api/signatures/lookup", base))
+        .query(&[
+            ("contract_id", ids[0]),
+            ("version", selected.version.as_str()),
+        ])
+        .send()
+        .await?
+        .error_for_status()
+        .context("Failed to look up package signatures")?
+        .json()
+        .await?;
+
+    let keyring = Keyring::load()?;
+    let mut best = SignatureCheck::Unsigned;
+    for row in lookup
+        .signatures
+        .iter()
+        .filter(|row| row.wasm_hash == selected.wasm_hash)
+    {
+        let check = if !row.status.eq_ignore_ascii_case("valid") {
+            SignatureCheck::Invalid(format!("signature is {}", row.status.to_lowercase()))
+        } else if row.expires_at.is_some_and(|at| at < chrono::Utc::now()) {
+            SignatureCheck::Invalid("signature has expired".into())
+        } else {
+            ids.iter()
+                .map(|id| {
+                    let message = format!("{}:{}:{}", id, selected.version, row.wasm_hash);
+                    SignatureCheck::verify(
+                        &keyring,
+                        message.as_bytes(),
+                        &row.signature,
+                        &row.public_key,
+                    )
+                })
+                .max_by_key(rank)
+                .unwrap_or(SignatureCheck::Unsigned)
+        };
+        if rank(&check) > rank(&best) {
+            best = check;
+        }
+    }
+    Ok(best)
+}
+
+fn rank(check: &SignatureCheck) -> u8 {
+    match check {
+        SignatureCheck::Unsigned => 0,
+        SignatureCheck::Invalid(_) => 1,
+        SignatureCheck::Valid { trusted: None, .. } => 2,
+        SignatureCheck::Valid { .. } => 3,
+    }
+}
+
+fn destination(dir: Option<&str>, name: &str, version: &str) -> PathBuf {
+    match dir {
+        Some(d) => PathBuf::from(d),
+        None => PathBuf::from(format!(
+            "{}-{}",
+            name.to_lowercase().replace(' ', "-"),
+            version
+        )),
+    }
+}
+
+/// Source bundles are either a gzipped tarball of the repository snapshot or,
+/// for single-file contracts, the raw `lib.rs`.
+fn write_bundle(bytes: &[u8], dest: &Path) -> Result<()> {
+    if bytes.starts_with(&[0x1f, 0x8b]) {
+        let tmp = tempfile::NamedTempFile::new()?;
+        fs::write(tmp.path(), bytes)?;
+        crate::io_utils::extract_tar_gz(tmp.path(), dest)
+            .context("Failed to extract source bundle")?;
+    } else {
+        fs::create_dir_all(dest.join("src"))?;
+        fs::write(dest.join("src/lib.rs"), bytes)?;
+    }
+    Ok(())
+}
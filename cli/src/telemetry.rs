@@ -0,0 +1,325 @@
+//! telemetry.rs — `soroban-registry telemetry on|off|status`
+//!
+//! Opt-in usage reporting. While enabled, each command run appends one event
+//! — the subcommand path (`events replay`, never its arguments), how long it
+//! took and whether it succeeded — to a local queue under
+//! `~/.soroban-registry/`. Queued events are posted to the registry's
+//! `/api/telemetry` in batches; if the registry is unreachable they stay
+//! queued for the next run. Reports carry a random install ID created when
+//! telemetry is turned on and discarded when it is turned off.
+//!
+//! Telemetry is off until `telemetry on`. `SOROBAN_REGISTRY_TELEMETRY=0` or
+//! `DO_NOT_TRACK=1` turns it off regardless of the saved setting.
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use clap::ArgMatches;
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use uuid::Uuid;
+
+const STATE_FILE: &str = "telemetry.json";
+const QUEUE_FILE: &str = "telemetry-queue.jsonl";
+/// Send once this many events are queued...
+const FLUSH_AT: usize = 20;
+/// ...or once the oldest queued event is this old.
+const FLUSH_AFTER_HOURS: i64 = 24;
+/// Events per request; matches the registry's batch limit.
+const BATCH_SIZE: usize = 100;
+/// Oldest events are discarded beyond this, e.g. while offline for weeks.
+const MAX_QUEUED: usize = 1_000;
+const SEND_TIMEOUT_SECS: u64 = 2;
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct TelemetryState {
+    enabled: bool,
+    install_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Event {
+    command: String,
+    duration_ms: i64,
+    success: bool,
+    occurred_at: DateTime<Utc>,
+}
+
+fn dir() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".soroban-registry"))
+}
+
+fn load_state() -> TelemetryState {
+    dir()
+        .and_then(|dir| fs::read_to_string(dir.join(STATE_FILE)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_state(state: &TelemetryState) -> Result<()> {
+    let dir = dir().context("Could not determine home directory")?;
+    fs::create_dir_all(&dir).with_context(|| format!("Failed to create {:?}", dir))?;
+    let path = dir.join(STATE_FILE);
+    fs::write(&path, serde_json::to_string_pretty(state)?)
+        .with_context(|| format!("Failed to write {:?}", path))
+}
+
+/// Why telemetry is forced off by the environment, if it is.
+fn env_override() -> Option<&'static str> {
+    let set = |name: &str, values: &[&str]| {
+        std::env::var(name)
+            .map(|v| values.contains(&v.trim().to_ascii_lowercase().as_str()))
+            .unwrap_or(false)
+    };
+    if set("SOROBAN_REGISTRY_TELEMETRY", &["0", "false", "off"]) {
+        Some("SOROBAN_REGISTRY_TELEMETRY")
+    } else if set("DO_NOT_TRACK", &["1", "true"]) {
+        Some("DO_NOT_TRACK")
+    } else {
+        None
+    }
+}
+
+/// The subcommand path of a parsed command line, e.g. `events replay`.
+/// Only subcommand names are included, never argument values.
+pub fn command_path(matches: &ArgMatches) -> String {
+    let mut names = Vec::new();
+    let mut current = matches;
+    while let Some((name, sub)) = current.subcommand() {
+        names.push(name);
+        current = sub;
+    }
+    names.join(" ")
+}
+
+fn read_queue(path: &Path) -> Vec<Event> {
+    fs::read_to_string(path)
+        .map(|content| {
+            content
+                .lines()
+                .filter_map(|line| serde_json::from_str(line).ok())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn write_queue(path: &Path, events: &[Event]) -> Result<()> {
+    let mut out = String::new();
+    for event in events {
+        out.push_str(&serde_json::to_string(event)?);
+        out.push('\n');
+    }
+    fs::write(path, out).with_context(|| format!("Failed to write {:?}", path))
+}
+
+fn append_event(path: &Path, event: &Event) -> Result<()> {
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open {:?}", path))?;
+    writeln!(file, "{}", serde_json::to_string(event)?)?;
+    Ok(())
+}
+
+fn due(queue: &[Event]) -> bool {
+    queue.len() >= FLUSH_AT
+        || queue.first().is_some_and(|oldest| {
+            Utc::now() - oldest.occurred_at >= ChronoDuration::hours(FLUSH_AFTER_HOURS)
+        })
+}
+
+/// Posts queued events, oldest first, until the queue is empty or a send
+/// fails. Returns how many were sent.
+async fn flush(api_url: &str, install_id: Uuid, path: &Path) -> Result<usize> {
+    let mut queue = read_queue(path);
+    if queue.len() > MAX_QUEUED {
+        queue.drain(..queue.len() - MAX_QUEUED);
+    }
+    let client = crate::http::builder()
+        .timeout(Duration::from_secs(SEND_TIMEOUT_SECS))
+        .build()
+        .context("Failed to build HTTP client")?;
+
+    let mut sent = 0;
+    while !queue.is_empty() {
+        let batch: Vec<Event> = queue.iter().take(BATCH_SIZE).cloned().collect();
+        let response = client
+            .post(format!("{}/api/telemetry", api_url.trim_end_matches('/')))
+            .json(&serde_json::json!({
+                "install_id": install_id,
+                "cli_version": env!("CARGO_PKG_VERSION"),
+                "os": std::env::consts::OS,
+                "arch": std::env::consts::ARCH,
+                "events": batch,
+            }))
+            .send()
+            .await;
+        match response {
+            Ok(resp) if resp.status().is_success() => sent += batch.len(),
+            // The registry will never accept this batch; drop it rather than
+            // resend it forever.
+            Ok(resp) if resp.status().is_client_error() => {
+                log::debug!("Telemetry batch rejected ({})", resp.status());
+            }
+            Ok(resp) => {
+                log::debug!("Telemetry not sent ({})", resp.status());
+                break;
+            }
+            Err(err) => {
+                log::debug!("Telemetry not sent: {}", err);
+                break;
+            }
+        }
+        queue.drain(..batch.len());
+    }
+    write_queue(path, &queue)?;
+    Ok(sent)
+}
+
+/// Queues one command run and sends the queue when it is due. Does nothing
+/// unless telemetry is on, and never fails the command.
+pub async fn record(api_url: &str, command: &str, duration: Duration, success: bool) {
+    if command.is_empty() || env_override().is_some() {
+        return;
+    }
+    let state = load_state();
+    let (true, Some(install_id), Some(dir)) = (state.enabled, state.install_id, dir()) else {
+        return;
+    };
+    let path = dir.join(QUEUE_FILE);
+    let event = Event {
+        command: command.to_string(),
+        duration_ms: duration.as_millis().try_into().unwrap_or(i64::MAX),
+        success,
+        occurred_at: Utc::now(),
+    };
+    if let Err(err) = append_event(&path, &event) {
+        log::debug!("Could not queue telemetry: {:#}", err);
+        return;
+    }
+    if due(&read_queue(&path)) {
+        if let Err(err) = flush(api_url, install_id, &path).await {
+            log::debug!("Could not send telemetry: {:#}", err);
+        }
+    }
+}
+
+pub fn enable() -> Result<()> {
+    let mut state = load_state();
+    state.enabled = true;
+    state.install_id.get_or_insert_with(Uuid::new_v4);
+    save_state(&state)?;
+
+    println!("{} Telemetry enabled. Thank you!", "✓".green());
+    println!(
+        "  Reported per command: its name (no arguments), duration and success.\n  \
+         Turn it off any time with {}.",
+        "soroban-registry telemetry off".bold()
+    );
+    if let Some(var) = env_override() {
+        println!(
+            "  {}",
+            format!("Note: {} currently keeps telemetry off.", var).yellow()
+        );
+    }
+    Ok(())
+}
+
+pub fn disable() -> Result<()> {
+    save_state(&TelemetryState::default())?;
+    // Nothing queued is sent after opting out.
+    if let Some(dir) = dir() {
+        let _ = fs::remove_file(dir.join(QUEUE_FILE));
+    }
+    println!(
+        "{} Telemetry disabled; queued events and the install ID were deleted.",
+        "✓".green()
+    );
+    Ok(())
+}
+
+pub fn status(api_url: &str) -> Result<()> {
+    let state = load_state();
+    let queued = dir()
+        .map(|dir| read_queue(&dir.join(QUEUE_FILE)).len())
+        .unwrap_or(0);
+
+    println!("\n{}", "Telemetry".bold().cyan());
+    println!("{}", "=".repeat(40).cyan());
+    let status = match (state.enabled, env_override()) {
+        (_, Some(var)) => format!("off (forced by {})", var).yellow(),
+        (true, None) => "on".green(),
+        (false, None) => "off".normal(),
+    };
+    println!("  {}: {}", "Status".bold(), status);
+    if let Some(id) = state.install_id {
+        println!("  {}: {}", "Install ID".bold(), id);
+    }
+    println!("  {}: {}", "Queued events".bold(), queued);
+    println!(
+        "  {}: {}/api/telemetry",
+        "Endpoint".bold(),
+        api_url.trim_end_matches('/')
+    );
+    println!("\n  Collected: command name (no arguments), duration, success, CLI version, OS.\n");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn path_of(args: &[&str]) -> String {
+        command_path(&crate::Cli::command().get_matches_from(args))
+    }
+
+    #[test]
+    fn command_path_omits_arguments() {
+        assert_eq!(
+            path_of(&["soroban-registry", "telemetry", "status"]),
+            "telemetry status"
+        );
+        assert_eq!(
+            path_of(&["soroban-registry", "info", "CABC", "--format", "json"]),
+            "info"
+        );
+    }
+
+    #[test]
+    fn queue_round_trips_and_becomes_due() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(QUEUE_FILE);
+        let event = Event {
+            command: "verify".to_string(),
+            duration_ms: 42,
+            success: true,
+            occurred_at: Utc::now(),
+        };
+        for _ in 0..FLUSH_AT - 1 {
+            append_event(&path, &event).unwrap();
+        }
+        let queue = read_queue(&path);
+        assert_eq!(queue.len(), FLUSH_AT - 1);
+        assert_eq!(queue[0], event);
+        assert!(!due(&queue));
+
+        append_event(&path, &event).unwrap();
+        assert!(due(&read_queue(&path)));
+    }
+
+    #[test]
+    fn old_events_make_the_queue_due() {
+        let stale = Event {
+            command: "search".to_string(),
+            duration_ms: 5,
+            success: false,
+            occurred_at: Utc::now() - ChronoDuration::hours(FLUSH_AFTER_HOURS + 1),
+        };
+        assert!(due(&[stale]));
+    }
+}
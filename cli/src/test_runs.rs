@@ -0,0 +1,249 @@
+//! test_runs.rs — `soroban-registry attest-tests`
+//!
+//! Lets CI report a test run for a contract build. The pass/fail/skip counts
+//! come from a JUnit XML report; the `shared::test_report` statement is
+//! signed locally with the publisher's secret key and only the signature is
+//! sent. The registry attaches the run to the version with that build.
+
+use anyhow::{bail, Context, Result};
+use chrono::Utc;
+use colored::Colorize;
+use ed25519_dalek::Signer;
+use serde_json::{json, Value};
+use shared::test_report::TestReportStatement;
+
+use crate::claim::{decode_secret_key, encode_strkey, VERSION_ACCOUNT};
+
+/// Totals of a JUnit report, summed over its `<testsuite>` elements.
+#[derive(Debug, Default, PartialEq)]
+struct JunitSummary {
+    /// Name of the first suite, if it has one
+    name: Option<String>,
+    passed: u32,
+    failed: u32,
+    skipped: u32,
+}
+
+/// The value of `name="..."` (or single-quoted) in an element's tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let mut rest = tag;
+    while let Some(at) = rest.find(name) {
+        let before = rest[..at].chars().next_back();
+        let after = &rest[at + name.len()..];
+        rest = after;
+        if !before.is_some_and(char::is_whitespace) {
+            continue;
+        }
+        let Some(value) = after.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let value = value.trim_start();
+        let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        let value = &value[1..];
+        return value.find(quote).map(|end| value[..end].to_string());
+    }
+    None
+}
+
+fn count(tag: &str, name: &str) -> Result<u32> {
+    match attribute(tag, name) {
+        Some(value) => value
+            .trim()
+            .parse()
+            .with_context(|| format!("JUnit attribute {}=\"{}\" is not a count", name, value)),
+        None => Ok(0),
+    }
+}
+
+/// Opening tags of `element`, e.g. `<testsuite name="unit" tests="3"`.
+fn opening_tags<'a>(xml: &'a str, element: &str) -> Vec<&'a str> {
+    let open = format!("<{}", element);
+    xml.match_indices(open.as_str())
+        .filter_map(|(at, _)| {
+            let tag = &xml[at..];
+            let next = tag[open.len()..].chars().next()?;
+            let is_element = next.is_whitespace() || next == '>' || next == '/';
+            tag.find('>').filter(|_| is_element).map(|end| &tag[..end])
+        })
+        .collect()
+}
+
+fn parse_junit(xml: &str) -> Result<JunitSummary> {
+    let mut tags = opening_tags(xml, "testsuite");
+    if tags.is_empty() {
+        // A bare <testsuites> root still carries the totals.
+        tags = opening_tags(xml, "testsuites");
+    }
+    if tags.is_empty() {
+        bail!("No <testsuite> element found; is this a JUnit XML report?");
+    }
+
+    let mut summary = JunitSummary {
+        name: attribute(tags[0], "name"),
+        ..JunitSummary::default()
+    };
+    for tag in tags {
+        let tests = count(tag, "tests")?;
+        // JUnit counts errors (unexpected exceptions) apart from failures.
+        let failed = count(tag, "failures")? + count(tag, "errors")?;
+        let skipped = count(tag, "skipped")?.max(count(tag, "disabled")?);
+        summary.failed += failed;
+        summary.skipped += skipped;
+        summary.passed += tests.saturating_sub(failed + skipped);
+    }
+    Ok(summary)
+}
+
+async fn check(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn attest(
+    api_url: &str,
+    contract_id: &str,
+    junit_path: &str,
+    suite: Option<&str>,
+    coverage: Option<f64>,
+    wasm_hash: Option<&str>,
+    secret_key: &str,
+    json_output: bool,
+) -> Result<()> {
+    let xml = std::fs::read_to_string(junit_path)
+        .with_context(|| format!("Failed to read JUnit report from {}", junit_path))?;
+    let summary = parse_junit(&xml)?;
+    let suite = suite
+        .map(str::to_string)
+        .or(summary.name)
+        .unwrap_or_else(|| "tests".to_string());
+
+    let base_url = api_url.trim_end_matches('/');
+    let signing_key = decode_secret_key(secret_key)?;
+    let address = encode_strkey(VERSION_ACCOUNT, &signing_key.verifying_key().to_bytes());
+    let client = crate::http::client();
+
+    let response = client
+        .get(format!("{}/api/contracts/{}", base_url, contract_id))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let contract = check(response).await?;
+    let field = |name: &str| -> Result<String> {
+        contract[name]
+            .as_str()
+            .map(str::to_string)
+            .with_context(|| format!("Contract record is missing {}", name))
+    };
+    let (uuid, onchain_id, network) = (field("id")?, field("contract_id")?, field("network")?);
+    // Without --wasm-hash the run is for the contract's current build.
+    let wasm_hash = match wasm_hash {
+        Some(hash) => hash.to_string(),
+        None => field("wasm_hash")?,
+    };
+
+    let statement = TestReportStatement {
+        network: &network,
+        contract_id: &onchain_id,
+        wasm_hash: &wasm_hash,
+        suite: &suite,
+        passed: summary.passed,
+        failed: summary.failed,
+        skipped: summary.skipped,
+        coverage,
+        run_at: Utc::now(),
+    };
+    statement.validate().map_err(anyhow::Error::msg)?;
+    let signature = hex::encode(
+        signing_key
+            .sign(statement.signing_message().as_bytes())
+            .to_bytes(),
+    );
+
+    let response = client
+        .post(format!("{}/api/contracts/{}/test-runs", base_url, uuid))
+        .json(&json!({
+            "wasm_hash": wasm_hash,
+            "suite": suite,
+            "passed": statement.passed,
+            "failed": statement.failed,
+            "skipped": statement.skipped,
+            "coverage": coverage,
+            "run_at": statement.run_at,
+            "signature": signature,
+        }))
+        .send()
+        .await
+        .context("Failed to submit test run")?;
+    let run = check(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&run)?);
+        return Ok(());
+    }
+    let outcome = if summary.failed == 0 {
+        "✓ Test run recorded".green().bold()
+    } else {
+        "✓ Test run recorded (with failures)".yellow().bold()
+    };
+    println!("\n{}", outcome);
+    println!(
+        "  {}: {} {}",
+        "Version".bold(),
+        run["version"].as_str().unwrap_or("?"),
+        wasm_hash.bright_black()
+    );
+    println!(
+        "  {}: {} passed, {} failed, {} skipped",
+        suite.bold(),
+        summary.passed.to_string().green(),
+        if summary.failed > 0 {
+            summary.failed.to_string().red()
+        } else {
+            summary.failed.to_string().normal()
+        },
+        summary.skipped
+    );
+    if let Some(coverage) = run["coverage"].as_f64() {
+        println!("  {}: {:.2}%", "Coverage".bold(), coverage);
+    }
+    println!("  {}: {}\n", "Signed by".bold(), address.bright_magenta());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_suites_and_reads_counts() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <testsuites tests="9" failures="1">
+              <testsuite name="unit" tests="6" failures="1" errors="0" skipped="1">
+                <testcase name="a"/>
+              </testsuite>
+              <testsuite name='integration' tests='3' errors='1'></testsuite>
+            </testsuites>"#;
+        assert_eq!(
+            parse_junit(xml).unwrap(),
+            JunitSummary {
+                name: Some("unit".to_string()),
+                passed: 6,
+                failed: 2,
+                skipped: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn ignores_lookalike_attributes_and_rejects_other_xml() {
+        let tag = r#"<testsuite name="x" subtests="40" tests="4""#;
+        assert_eq!(attribute(tag, "tests").as_deref(), Some("4"));
+        assert!(parse_junit("<coverage line-rate=\"0.8\"/>").is_err());
+    }
+}
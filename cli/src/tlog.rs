@@ -0,0 +1,290 @@
+//! tlog.rs — `soroban-registry tlog verify <contract>`
+//!
+//! Checks a release against the registry's transparency log. The leaf hash
+//! is recomputed locally from the logged fields and proven to be in the tree
+//! the registry reports; the logged WASM hash is compared with what the
+//! registry serves for the version now (and, with `--wasm`, with a local
+//! build). The tree head seen is pinned in
+//! `~/.soroban-registry/tlog_heads.json`, per registry, and every later run
+//! requires a consistency proof from it, so a registry that rewrites or
+//! drops an earlier release is caught by anyone who saw it before.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use shared::tlog::{self, Hash, ReleaseLeaf};
+
+const HEADS_FILE: &str = "tlog_heads.json";
+
+#[derive(Debug, Deserialize)]
+struct Entry {
+    leaf_index: u64,
+    contract_id: String,
+    contract_address: String,
+    network: String,
+    name: String,
+    version: String,
+    wasm_hash: String,
+    signature: Option<String>,
+    publisher_key: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct InclusionProof {
+    entry: Entry,
+    leaf_hash: String,
+    tree_size: u64,
+    root_hash: String,
+    proof: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ConsistencyProof {
+    root_hash: String,
+    proof: Vec<String>,
+}
+
+/// The last tree head seen from a registry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PinnedHead {
+    tree_size: u64,
+    root_hash: String,
+    seen_at: DateTime<Utc>,
+}
+
+fn heads_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".soroban-registry").join(HEADS_FILE))
+}
+
+fn load_heads() -> HashMap<String, PinnedHead> {
+    heads_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+fn save_heads(heads: &HashMap<String, PinnedHead>) -> Result<()> {
+    let path = heads_path().context("Could not determine home directory")?;
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, serde_json::to_string_pretty(heads)?)
+        .with_context(|| format!("Failed to write {}", path.display()))
+}
+
+fn parse(hex_hash: &str) -> Result<Hash> {
+    tlog::parse_hash(hex_hash)
+        .with_context(|| format!("Malformed hash from registry: {}", hex_hash))
+}
+
+fn parse_all(hashes: &[String]) -> Result<Vec<Hash>> {
+    hashes.iter().map(|hash| parse(hash)).collect()
+}
+
+pub async fn verify(
+    api_url: &str,
+    contract: &str,
+    version: Option<&str>,
+    wasm: Option<&str>,
+) -> Result<()> {
+    let client = crate::http::client();
+    let base = api_url.trim_end_matches('/');
+
+    let mut query = vec![("contract_id", contract)];
+    if let Some(version) = version {
+        query.push(("version", version));
+    }
+    let response: InclusionProof = serde_json::from_value(
+        get_json(client.get(format!("{}/api/tlog/proof", base)).query(&query))
+            .await
+            .context("Failed to fetch inclusion proof")?,
+    )
+    .context("Unexpected inclusion proof response")?;
+    let entry = &response.entry;
+
+    println!(
+        "\n{}",
+        "Verifying release transparency log...".bold().cyan()
+    );
+    println!("  {}: {} {}", "Release".bold(), entry.name, entry.version);
+    println!("  {}: {}", "Contract".bold(), entry.contract_address);
+    println!("  {}: {}", "Network".bold(), entry.network);
+    println!(
+        "  {}: {}",
+        "WASM hash".bold(),
+        entry.wasm_hash.bright_black()
+    );
+    match &entry.publisher_key {
+        Some(key) if entry.signature.is_some() => {
+            println!("  {}: {}", "Signed by".bold(), key.bright_magenta())
+        }
+        _ => println!("  {}: {}", "Signed by".bold(), "unsigned".bright_black()),
+    }
+    println!(
+        "  {}: {} of {}",
+        "Leaf".bold(),
+        entry.leaf_index,
+        response.tree_size
+    );
+
+    let leaf = ReleaseLeaf {
+        contract_id: &entry.contract_id,
+        contract_address: &entry.contract_address,
+        network: &entry.network,
+        name: &entry.name,
+        version: &entry.version,
+        wasm_hash: &entry.wasm_hash,
+        signature: entry.signature.as_deref(),
+        publisher_key: entry.publisher_key.as_deref(),
+    }
+    .hash();
+    if hex::encode(leaf) != response.leaf_hash {
+        bail!("The logged fields do not hash to the leaf the registry reports");
+    }
+    let root = parse(&response.root_hash)?;
+    if !tlog::verify_inclusion(
+        &leaf,
+        entry.leaf_index,
+        response.tree_size,
+        &parse_all(&response.proof)?,
+        &root,
+    ) {
+        bail!(
+            "Inclusion proof does not lead to root {}",
+            response.root_hash
+        );
+    }
+    println!("  {} included in tree {}", "✓".green(), response.root_hash);
+
+    // The registry's record of the version must still say what it logged.
+    let versions = get_json(client.get(format!(
+        "{}/api/contracts/{}/versions",
+        base, entry.contract_id
+    )))
+    .await
+    .context("Failed to fetch contract versions")?;
+    let served = versions
+        .as_array()
+        .and_then(|rows| {
+            rows.iter()
+                .find(|row| row["version"].as_str() == Some(entry.version.as_str()))
+        })
+        .and_then(|row| row["wasm_hash"].as_str());
+    match served {
+        Some(hash) if hash == entry.wasm_hash => {
+            println!("  {} registry serves the logged WASM hash", "✓".green())
+        }
+        Some(hash) => bail!(
+            "The registry now serves WASM hash {} for {} {}, but logged {}",
+            hash,
+            entry.name,
+            entry.version,
+            entry.wasm_hash
+        ),
+        None => println!(
+            "  {} version {} is no longer served by the registry",
+            "!".yellow().bold(),
+            entry.version
+        ),
+    }
+
+    if let Some(path) = wasm {
+        let bytes = fs::read(path).with_context(|| format!("Failed to read {}", path))?;
+        let local = format!("{:x}", Sha256::digest(&bytes));
+        if local != entry.wasm_hash {
+            bail!(
+                "{} has hash {}, but the log records {}",
+                path,
+                local,
+                entry.wasm_hash
+            );
+        }
+        println!("  {} {} matches the logged WASM hash", "✓".green(), path);
+    }
+
+    let mut heads = load_heads();
+    match heads.get(base) {
+        Some(pinned) if pinned.tree_size > response.tree_size => bail!(
+            "The log shrank from {} to {} entries since {}; the registry has rewritten history",
+            pinned.tree_size,
+            response.tree_size,
+            pinned.seen_at.format("%Y-%m-%d %H:%M UTC")
+        ),
+        Some(pinned) => {
+            let consistency: ConsistencyProof = serde_json::from_value(
+                get_json(
+                    client
+                        .get(format!("{}/api/tlog/consistency", base))
+                        .query(&[("first", pinned.tree_size), ("second", response.tree_size)]),
+                )
+                .await
+                .context("Failed to fetch consistency proof")?,
+            )
+            .context("Unexpected consistency proof response")?;
+            if consistency.root_hash != response.root_hash
+                || !tlog::verify_consistency(
+                    pinned.tree_size,
+                    response.tree_size,
+                    &parse(&pinned.root_hash)?,
+                    &root,
+                    &parse_all(&consistency.proof)?,
+                )
+            {
+                bail!(
+                    "The log is not an extension of the tree of {} entries seen {}; \
+                     the registry has rewritten history",
+                    pinned.tree_size,
+                    pinned.seen_at.format("%Y-%m-%d %H:%M UTC")
+                );
+            }
+            println!(
+                "  {} consistent with the tree of {} entries seen {}",
+                "✓".green(),
+                pinned.tree_size,
+                pinned.seen_at.format("%Y-%m-%d")
+            );
+        }
+        None => println!(
+            "  {} first check against this registry; pinning its tree head",
+            "→".bright_black()
+        ),
+    }
+    heads.insert(
+        base.to_string(),
+        PinnedHead {
+            tree_size: response.tree_size,
+            root_hash: response.root_hash.clone(),
+            seen_at: Utc::now(),
+        },
+    );
+    save_heads(&heads)?;
+
+    println!(
+        "{}",
+        "\n✓ Release verified against the transparency log\n"
+            .green()
+            .bold()
+    );
+    Ok(())
+}
+
+async fn get_json(request: reqwest::RequestBuilder) -> Result<Value> {
+    let response = request
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
@@ -0,0 +1,66 @@
+//! toolchains.rs — `soroban-registry toolchains`
+//!
+//! Lists the registry's approved builder toolchains. A project pins one for
+//! verification builds with `[build] toolchain = "<name>"` in `Registry.toml`,
+//! so its source keeps rebuilding to the same WASM as toolchains evolve.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+pub async fn list(api_url: &str, include_deprecated: bool, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/toolchains", api_url))
+        .query(&[("include_deprecated", include_deprecated)])
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    let toolchains = body.as_array().cloned().unwrap_or_default();
+    println!("\n{}", "Approved toolchains".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+    if toolchains.is_empty() {
+        println!("{}\n", "No toolchains in the catalog.".yellow());
+        return Ok(());
+    }
+
+    let headers = ["Name", "Rust", "soroban-sdk", "Target", "Status"];
+    let col_widths = [28, 8, 12, 24, 10];
+    let rows: Vec<Vec<String>> = toolchains
+        .iter()
+        .map(|t| {
+            vec![
+                t["name"].as_str().unwrap_or("?").to_string(),
+                t["rust_version"].as_str().unwrap_or("?").to_string(),
+                t["soroban_sdk_version"].as_str().unwrap_or("?").to_string(),
+                t["target"].as_str().unwrap_or("?").to_string(),
+                if t["deprecated_at"].is_null() {
+                    "active".to_string()
+                } else {
+                    "deprecated".to_string()
+                },
+            ]
+        })
+        .collect();
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!(
+        "\nPin one in Registry.toml:\n  {}\n  {}\n",
+        "[build]".dimmed(),
+        format!("toolchain = \"{}\"", rows[0][0]).dimmed()
+    );
+    Ok(())
+}
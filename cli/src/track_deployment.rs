@@ -122,7 +122,7 @@ pub async fn run(
         println!();
     }
 
-    let client = reqwest::Client::builder()
+    let client = crate::http::builder()
         .timeout(Duration::from_secs(10))
         .build()
         .context("Failed to build HTTP client")?;
@@ -0,0 +1,488 @@
+//! trust.rs — `soroban-registry trust add|remove|list`
+//!
+//! A local keyring of publisher signing keys the user trusts, kept in
+//! `~/.soroban-registry/trusted_keys.json`. `import` and `clone` check the
+//! signature on what they fetch against it when asked to:
+//!
+//! - `--require-signed` refuses archives and sources without a valid
+//!   signature
+//! - `--require-trusted` also refuses ones signed by a key not in the
+//!   keyring
+//!
+//! A signature that does not verify is refused either way, since the
+//! artifact was changed after it was signed. So is one by a key the
+//! registry records as revoked, or made outside the key's validity window
+//! (see `keys`).
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use shared::KeyValidity;
+pub use shared::SigningKey as RegisteredKey;
+
+use crate::manifest::ExportManifest;
+use crate::package_signing;
+
+const KEYRING_FILE: &str = "trusted_keys.json";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrustedKey {
+    /// Base64 Ed25519 public key
+    pub public_key: String,
+    pub address: String,
+    pub fingerprint: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    pub added_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Keyring {
+    #[serde(default)]
+    pub keys: Vec<TrustedKey>,
+}
+
+fn keyring_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home| home.join(".soroban-registry").join(KEYRING_FILE))
+}
+
+impl Keyring {
+    pub fn load() -> Result<Self> {
+        let Some(path) = keyring_path() else {
+            return Ok(Self::default());
+        };
+        match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse {}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err).with_context(|| format!("Failed to read {}", path.display())),
+        }
+    }
+
+    fn save(&self) -> Result<()> {
+        let path = keyring_path().context("Could not determine home directory")?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&path, serde_json::to_string_pretty(self)?)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// The trusted key with this public key, if any.
+    pub fn find(&self, public_key: &str) -> Option<&TrustedKey> {
+        let public_key = public_key.trim();
+        self.keys.iter().find(|key| key.public_key == public_key)
+    }
+
+    /// Position of the key `selector` names: its public key, address,
+    /// fingerprint or label.
+    fn position(&self, selector: &str) -> Option<usize> {
+        let selector = selector.trim();
+        self.keys.iter().position(|key| {
+            key.public_key == selector
+                || key.address == selector
+                || key.fingerprint == selector
+                || key.label.as_deref() == Some(selector)
+        })
+    }
+}
+
+// ── Policy ───────────────────────────────────────────────────────────────────
+
+/// The outcome of checking an artifact's signature.
+#[derive(Debug, Clone)]
+pub enum SignatureCheck {
+    Unsigned,
+    /// Present but not acceptable: it does not verify, or it has expired or
+    /// been revoked
+    Invalid(String),
+    Valid {
+        address: String,
+        /// The keyring entry for the signing key
+        trusted: Option<TrustedKey>,
+    },
+}
+
+impl SignatureCheck {
+    /// Checks `signature_b64` over `message` and looks the key up in the
+    /// keyring.
+    pub fn verify(
+        keyring: &Keyring,
+        message: &[u8],
+        signature_b64: &str,
+        public_key_b64: &str,
+    ) -> Self {
+        match package_signing::verify_signature(message, signature_b64, public_key_b64) {
+            Ok(true) => Self::Valid {
+                address: address_of(public_key_b64).unwrap_or_default(),
+                trusted: keyring.find(public_key_b64).cloned(),
+            },
+            Ok(false) => Self::Invalid("signature does not match the content".into()),
+            Err(err) => Self::Invalid(err.to_string()),
+        }
+    }
+
+    /// One-line description for command output.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::Unsigned => "unsigned".yellow().to_string(),
+            Self::Invalid(reason) => format!("{} ({})", "invalid".red().bold(), reason),
+            Self::Valid {
+                address,
+                trusted: Some(key),
+            } => format!(
+                "{} by {}",
+                "✓ trusted".green(),
+                key.label.as_deref().unwrap_or(address)
+            ),
+            Self::Valid {
+                address,
+                trusted: None,
+            } => format!("{} by {} (not in your keyring)", "valid".yellow(), address),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TrustPolicy {
+    pub require_signed: bool,
+    pub require_trusted: bool,
+}
+
+impl TrustPolicy {
+    pub fn is_active(&self) -> bool {
+        self.require_signed || self.require_trusted
+    }
+
+    /// Refuses `what` unless `check` satisfies the policy.
+    pub fn enforce(&self, check: &SignatureCheck, what: &str) -> Result<()> {
+        match check {
+            SignatureCheck::Invalid(reason) => {
+                bail!("{} has an invalid signature: {}", what, reason)
+            }
+            SignatureCheck::Unsigned if self.is_active() => {
+                bail!("{} is not signed (--require-signed)", what)
+            }
+            SignatureCheck::Valid {
+                address,
+                trusted: None,
+            } if self.require_trusted => bail!(
+                "{} is signed by {}, which is not in your keyring (--require-trusted); \
+                 add it with `soroban-registry trust add <public-key>` if you trust it",
+                what,
+                address
+            ),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Checks the signature embedded in an export archive's manifest, and holds
+/// it to `registered`, the registry's record of the key.
+pub fn check_manifest(
+    manifest: &ExportManifest,
+    registered: &[RegisteredKey],
+) -> Result<SignatureCheck> {
+    let Some(signature) = &manifest.signature else {
+        return Ok(SignatureCheck::Unsigned);
+    };
+    if signature.algorithm != "ed25519" {
+        return Ok(SignatureCheck::Invalid(format!(
+            "unsupported algorithm {}",
+            signature.algorithm
+        )));
+    }
+    if signature.expires_at.is_some_and(|at| at < Utc::now()) {
+        return Ok(SignatureCheck::Invalid("signature has expired".into()));
+    }
+    let message = package_signing::archive_signing_message(
+        &manifest.contract_id,
+        &manifest.profile.to_string(),
+        &manifest.sha256,
+    );
+    let check = SignatureCheck::verify(
+        &Keyring::load()?,
+        &message,
+        &signature.signature,
+        &signature.public_key,
+    );
+    Ok(against_registry(check, registered, signature.signed_at))
+}
+
+/// Refuses a valid signature made at `signed_at` by a key the registry has
+/// revoked, or made outside the key's window. Archives carry their own
+/// signing time, which whoever holds the key chooses, so a revoked key is
+/// refused whenever the signature claims it signed.
+pub fn against_registry(
+    check: SignatureCheck,
+    registered: &[RegisteredKey],
+    signed_at: DateTime<Utc>,
+) -> SignatureCheck {
+    if !matches!(check, SignatureCheck::Valid { .. }) {
+        return check;
+    }
+    for key in registered {
+        let reason = match (key.revoked_at, key.window().validity_at(signed_at)) {
+            (Some(revoked_at), _) => format!(
+                "signing key was revoked at {}{}",
+                revoked_at.format("%Y-%m-%d %H:%M UTC"),
+                key.revocation_reason
+                    .as_deref()
+                    .map(|reason| format!(" ({})", reason))
+                    .unwrap_or_default()
+            ),
+            (None, KeyValidity::Expired) => format!(
+                "signed {} after the key was rotated out at {}",
+                signed_at.format("%Y-%m-%d %H:%M UTC"),
+                key.valid_until
+                    .unwrap_or(signed_at)
+                    .format("%Y-%m-%d %H:%M UTC")
+            ),
+            (None, KeyValidity::NotYetValid) => format!(
+                "signed {} before the key became valid at {}",
+                signed_at.format("%Y-%m-%d %H:%M UTC"),
+                key.valid_from.format("%Y-%m-%d %H:%M UTC")
+            ),
+            (None, _) => continue,
+        };
+        return SignatureCheck::Invalid(reason);
+    }
+    check
+}
+
+/// The registry's record of the key that signed `manifest`. Best effort:
+/// an unreachable registry leaves the signature to the local checks.
+pub async fn registered_keys(api_url: &str, manifest: &ExportManifest) -> Vec<RegisteredKey> {
+    let Some(signature) = &manifest.signature else {
+        return Vec::new();
+    };
+    let response = crate::http::client()
+        .get(format!("{}/api/keys/lookup", api_url.trim_end_matches('/')))
+        .query(&[("public_key", signature.public_key.as_str())])
+        .send()
+        .await;
+    match response {
+        Ok(response) if response.status().is_success() => {
+            response.json().await.unwrap_or_else(|err| {
+                log::warn!("Unexpected signing key lookup response: {}", err);
+                Vec::new()
+            })
+        }
+        Ok(response) => {
+            log::warn!("Signing key lookup failed: {}", response.status());
+            Vec::new()
+        }
+        Err(err) => {
+            log::warn!("Could not check the signing key with the registry: {}", err);
+            Vec::new()
+        }
+    }
+}
+
+fn address_of(public_key_b64: &str) -> Result<String> {
+    let key = package_signing::decode_public_key(public_key_b64)?;
+    Ok(package_signing::derive_stellar_address(&key.to_bytes()))
+}
+
+// ── Commands ─────────────────────────────────────────────────────────────────
+
+pub fn add(public_key: &str, label: Option<&str>) -> Result<()> {
+    let key = package_signing::decode_public_key(public_key)?;
+    let public_key = BASE64.encode(key.to_bytes());
+    let mut keyring = Keyring::load()?;
+    if let Some(existing) = keyring.find(&public_key) {
+        bail!(
+            "Key {} is already trusted{}",
+            existing.address,
+            existing
+                .label
+                .as_deref()
+                .map(|label| format!(" as '{}'", label))
+                .unwrap_or_default()
+        );
+    }
+    if let Some(label) = label {
+        if keyring.position(label).is_some() {
+            bail!("Label '{}' is already used by another key", label);
+        }
+    }
+
+    let trusted = TrustedKey {
+        address: package_signing::derive_stellar_address(&key.to_bytes()),
+        fingerprint: package_signing::key_fingerprint(&key.to_bytes()),
+        public_key,
+        label: label.map(str::to_string),
+        added_at: Utc::now(),
+    };
+    println!(
+        "\n{} {}",
+        "✓ Trusted".green().bold(),
+        trusted.label.as_deref().unwrap_or(&trusted.address)
+    );
+    println!("  {}: {}", "Address".bold(), trusted.address);
+    println!("  {}: {}\n", "Fingerprint".bold(), trusted.fingerprint);
+    keyring.keys.push(trusted);
+    keyring.save()
+}
+
+pub fn remove(selector: &str) -> Result<()> {
+    let mut keyring = Keyring::load()?;
+    let Some(index) = keyring.position(selector) else {
+        bail!("No trusted key matches '{}'", selector);
+    };
+    let removed = keyring.keys.remove(index);
+    keyring.save()?;
+    println!(
+        "\n{} {}\n",
+        "✓ No longer trusted:".green().bold(),
+        removed.label.as_deref().unwrap_or(&removed.address)
+    );
+    Ok(())
+}
+
+pub fn list(json: bool) -> Result<()> {
+    let keyring = Keyring::load()?;
+    if json {
+        println!("{}", serde_json::to_string_pretty(&keyring.keys)?);
+        return Ok(());
+    }
+    println!("\n{}", "Trusted Keys".bold().cyan());
+    if keyring.keys.is_empty() {
+        println!(
+            "  {}\n",
+            "None yet; add one with `soroban-registry trust add <public-key>`".bright_black()
+        );
+        return Ok(());
+    }
+    for key in &keyring.keys {
+        println!(
+            "  {:<16} {}  {}",
+            key.label.as_deref().unwrap_or("-").bold(),
+            key.address,
+            format!(
+                "{} · added {}",
+                key.fingerprint,
+                key.added_at.format("%Y-%m-%d")
+            )
+            .bright_black()
+        );
+    }
+    println!();
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Signer, SigningKey};
+
+    fn keypair(seed: u8) -> (SigningKey, String) {
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let public = BASE64.encode(key.verifying_key().to_bytes());
+        (key, public)
+    }
+
+    #[test]
+    fn policy_follows_the_keyring() {
+        let (signer, public) = keypair(7);
+        let message = b"contract:export:full:abc";
+        let signature = BASE64.encode(signer.sign(message).to_bytes());
+
+        let mut keyring = Keyring::default();
+        let untrusted = SignatureCheck::verify(&keyring, message, &signature, &public);
+        assert!(matches!(
+            untrusted,
+            SignatureCheck::Valid { trusted: None, .. }
+        ));
+        let signed_only = TrustPolicy {
+            require_signed: true,
+            require_trusted: false,
+        };
+        let trusted_only = TrustPolicy {
+            require_signed: false,
+            require_trusted: true,
+        };
+        assert!(signed_only.enforce(&untrusted, "archive").is_ok());
+        assert!(trusted_only.enforce(&untrusted, "archive").is_err());
+
+        keyring.keys.push(TrustedKey {
+            public_key: public.clone(),
+            address: address_of(&public).unwrap(),
+            fingerprint: String::new(),
+            label: Some("alice".into()),
+            added_at: Utc::now(),
+        });
+        let trusted = SignatureCheck::verify(&keyring, message, &signature, &public);
+        assert!(trusted_only.enforce(&trusted, "archive").is_ok());
+        assert_eq!(keyring.position("alice"), Some(0));
+
+        let tampered = SignatureCheck::verify(&keyring, b"other", &signature, &public);
+        assert!(TrustPolicy::default()
+            .enforce(&tampered, "archive")
+            .is_err());
+        assert!(TrustPolicy::default()
+            .enforce(&SignatureCheck::Unsigned, "archive")
+            .is_ok());
+        assert!(trusted_only
+            .enforce(&SignatureCheck::Unsigned, "archive")
+            .is_err());
+    }
+
+    #[test]
+    fn registry_record_limits_the_key() {
+        let (signer, public) = keypair(9);
+        let message = b"contract:export:full:abc";
+        let signature = BASE64.encode(signer.sign(message).to_bytes());
+        let valid = SignatureCheck::verify(&Keyring::default(), message, &signature, &public);
+        let now = Utc::now();
+        let record = RegisteredKey {
+            id: uuid::Uuid::nil(),
+            publisher_id: uuid::Uuid::nil(),
+            public_key: public.clone(),
+            key_fingerprint: String::new(),
+            algorithm: "ed25519".into(),
+            is_active: true,
+            created_at: now - chrono::Duration::days(30),
+            deactivated_at: None,
+            label: None,
+            valid_from: now - chrono::Duration::days(30),
+            valid_until: Some(now - chrono::Duration::days(1)),
+            revoked_at: None,
+            revocation_reason: None,
+            replaced_by: None,
+        };
+
+        let during_overlap = now - chrono::Duration::days(2);
+        assert!(matches!(
+            against_registry(valid.clone(), std::slice::from_ref(&record), during_overlap),
+            SignatureCheck::Valid { .. }
+        ));
+        assert!(matches!(
+            against_registry(valid.clone(), std::slice::from_ref(&record), now),
+            SignatureCheck::Invalid(_)
+        ));
+
+        let revoked = RegisteredKey {
+            revoked_at: Some(now - chrono::Duration::days(10)),
+            ..record
+        };
+        assert!(matches!(
+            against_registry(
+                valid.clone(),
+                &[revoked],
+                during_overlap - chrono::Duration::days(15)
+            ),
+            SignatureCheck::Invalid(_)
+        ));
+        assert!(matches!(
+            against_registry(valid, &[], now),
+            SignatureCheck::Valid { .. }
+        ));
+    }
+}
@@ -0,0 +1,105 @@
+//! undo.rs — `soroban-registry undo [--list]`
+//!
+//! Yanks, deprecations and contract deletions are applied by the registry
+//! only after a short grace period. Until then `undo` cancels the caller's
+//! most recent one, and `undo --list` shows everything still pending.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+pub async fn list(api_url: &str, token: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!("{}/api/me/pending-actions", api_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let pending = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&pending)?);
+        return Ok(());
+    }
+    println!("\n{}", "Pending actions".bold().cyan());
+    println!("{}", "=".repeat(80).cyan());
+
+    let headers = ["Action", "Contract", "Applies at"];
+    let col_widths = [24, 38, 20];
+    let rows: Vec<Vec<String>> = pending
+        .as_array()
+        .into_iter()
+        .flatten()
+        .map(|p| {
+            vec![
+                describe(&p["action"]),
+                p["action"]["contract_id"]
+                    .as_str()
+                    .unwrap_or("?")
+                    .to_string(),
+                timestamp(&p["apply_at"]),
+            ]
+        })
+        .collect();
+    if rows.is_empty() {
+        println!("  Nothing to undo.\n");
+        return Ok(());
+    }
+    print!(
+        "{}",
+        crate::table_format::render_table(&headers, &col_widths, &rows)
+    );
+    println!();
+    Ok(())
+}
+
+pub async fn undo(api_url: &str, token: &str, json_output: bool) -> Result<()> {
+    let response = crate::http::client()
+        .post(format!("{}/api/me/undo", api_url))
+        .bearer_auth(token)
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let undone = parse_response(response).await?;
+
+    if json_output {
+        println!("{}", serde_json::to_string_pretty(&undone)?);
+        return Ok(());
+    }
+    println!(
+        "\n{}\n",
+        format!(
+            "✓ Undid {} of contract {} (was due {})",
+            describe(&undone["action"]),
+            undone["action"]["contract_id"].as_str().unwrap_or("?"),
+            timestamp(&undone["apply_at"])
+        )
+        .green()
+        .bold()
+    );
+    Ok(())
+}
+
+async fn parse_response(response: reqwest::Response) -> Result<Value> {
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+    Ok(body)
+}
+
+fn describe(action: &Value) -> String {
+    match action["type"].as_str() {
+        Some("yank") => format!("yank {}", action["version"].as_str().unwrap_or("?")),
+        Some("deprecate") => "deprecation".to_string(),
+        Some("delete") => "deletion".to_string(),
+        other => other.unwrap_or("?").to_string(),
+    }
+}
+
+fn timestamp(value: &Value) -> String {
+    let raw = value.as_str().unwrap_or("?");
+    raw.get(..19).unwrap_or(raw).replace('T', " ")
+}
@@ -128,7 +128,7 @@ pub async fn deliver_with_retry(
     let payload_bytes = serde_json::to_vec(&payload)?;
     let signature = sign_payload(&webhook.secret_key, &payload_bytes)?;
 
-    let client = reqwest::Client::builder()
+    let client = crate::http::builder()
         .timeout(Duration::from_secs(TIMEOUT_SECS))
         .build()?;
 
@@ -211,7 +211,7 @@ pub async fn create_webhook(
     events: Vec<String>,
     secret_key: Option<&str>,
 ) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     // Generate a secret key if not provided
     let secret = secret_key.map(|s| s.to_string()).unwrap_or_else(|| {
@@ -266,7 +266,7 @@ pub async fn create_webhook(
 
 /// List all webhook subscriptions.
 pub async fn list_webhooks(api_url: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let response = client
         .get(format!("{}/api/webhooks", api_url))
@@ -306,7 +306,7 @@ pub async fn list_webhooks(api_url: &str) -> Result<()> {
 
 /// Delete a webhook by ID.
 pub async fn delete_webhook(api_url: &str, webhook_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let response = client
         .delete(format!("{}/api/webhooks/{}", api_url, webhook_id))
@@ -330,7 +330,7 @@ pub async fn delete_webhook(api_url: &str, webhook_id: &str) -> Result<()> {
 
 /// Send a test event to a webhook.
 pub async fn test_webhook(api_url: &str, webhook_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let response = client
         .post(format!("{}/api/webhooks/{}/test", api_url, webhook_id))
@@ -355,7 +355,7 @@ pub async fn test_webhook(api_url: &str, webhook_id: &str) -> Result<()> {
 
 /// View delivery logs for a webhook, including dead-letter entries.
 pub async fn webhook_logs(api_url: &str, webhook_id: &str, limit: usize) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let response = client
         .get(format!(
@@ -421,7 +421,7 @@ pub async fn webhook_logs(api_url: &str, webhook_id: &str, limit: usize) -> Resu
 
 /// Manually retry a dead-letter delivery.
 pub async fn retry_delivery(api_url: &str, delivery_id: &str) -> Result<()> {
-    let client = reqwest::Client::new();
+    let client = crate::http::client();
 
     let response = client
         .post(format!(
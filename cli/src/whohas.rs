@@ -0,0 +1,82 @@
+//! whohas.rs — `soroban-registry whohas <hash>`
+//!
+//! Lists every registry contract built from a WASM hash, as its current
+//! build or in one of its versions, so all deployments of a vulnerable
+//! binary can be found at once.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+
+pub async fn show(api_url: &str, hash: &str, json: bool) -> Result<()> {
+    let response = crate::http::client()
+        .get(format!(
+            "{}/api/wasm/{}/contracts",
+            api_url.trim_end_matches('/'),
+            hash.trim()
+        ))
+        .send()
+        .await
+        .context("Failed to reach registry API")?;
+    let status = response.status();
+    let body: Value = response.json().await.unwrap_or(Value::Null);
+    if !status.is_success() {
+        let err = body["message"].as_str().unwrap_or("unknown error");
+        bail!("API error ({}): {}", status, err);
+    }
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&body)?);
+        return Ok(());
+    }
+
+    let contracts = body["contracts"].as_array().cloned().unwrap_or_default();
+    let wasm_hash = body["wasm_hash"].as_str().unwrap_or(hash);
+    if contracts.is_empty() {
+        println!("No registry contract is built from {}.", wasm_hash);
+        return Ok(());
+    }
+
+    println!(
+        "\n{}",
+        format!("{} contract(s) built from {}", contracts.len(), wasm_hash)
+            .bold()
+            .cyan()
+    );
+    println!("{}", "=".repeat(80).cyan());
+    for contract in &contracts {
+        let verified = if contract["is_verified"].as_bool().unwrap_or(false) {
+            "verified".green()
+        } else {
+            "unverified".yellow()
+        };
+        println!(
+            "  {} ({}) [{}] {}",
+            contract["name"].as_str().unwrap_or("?").bold(),
+            contract["network"].as_str().unwrap_or("?"),
+            contract["contract_id"].as_str().unwrap_or("?"),
+            verified
+        );
+        let mut builds = Vec::new();
+        if contract["is_current"].as_bool().unwrap_or(false) {
+            builds.push("current build".red().bold().to_string());
+        }
+        let versions: Vec<&str> = contract["versions"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        if !versions.is_empty() {
+            builds.push(format!("versions {}", versions.join(", ")));
+        }
+        println!("      {}", builds.join("; "));
+        println!(
+            "      {} {}",
+            "publisher".bright_black(),
+            contract["publisher"].as_str().unwrap_or("?")
+        );
+    }
+    println!();
+    Ok(())
+}
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use std::fs::{create_dir_all, File, OpenOptions};
 use std::io::{self, BufRead, BufReader, Write};
@@ -7,11 +8,261 @@ use std::path::{Path, PathBuf};
 use std::process::Command;
 
 const HISTORY_FILE_NAME: &str = "deployments.ndjson";
+const SESSION_FILE_NAME: &str = "wizard-session.json";
+
+/// Steps of the guided deploy pipeline, in execution order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WizardStep {
+    Detect,
+    Build,
+    Preflight,
+    Deploy,
+    Publish,
+    Verify,
+}
+
+impl WizardStep {
+    const ALL: [WizardStep; 6] = [
+        WizardStep::Detect,
+        WizardStep::Build,
+        WizardStep::Preflight,
+        WizardStep::Deploy,
+        WizardStep::Publish,
+        WizardStep::Verify,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            WizardStep::Detect => "Detect contract project",
+            WizardStep::Build => "Build WASM",
+            WizardStep::Preflight => "Preflight checks",
+            WizardStep::Deploy => "Deploy to network",
+            WizardStep::Publish => "Publish to registry",
+            WizardStep::Verify => "Start verification",
+        }
+    }
+}
+
+/// Persisted wizard progress so an interrupted session can be resumed.
+///
+/// The signer is never written to disk; it is prompted for again when a
+/// resumed session still has to deploy.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WizardSession {
+    pub project_dir: String,
+    pub network: String,
+    pub package_name: Option<String>,
+    pub wasm_path: Option<String>,
+    pub contract_id: Option<String>,
+    pub contract_name: Option<String>,
+    pub publisher: Option<String>,
+    pub max_fee: u64,
+    pub completed: Vec<WizardStep>,
+    pub updated_at: u64,
+}
+
+impl WizardSession {
+    fn is_done(&self, step: WizardStep) -> bool {
+        self.completed.contains(&step)
+    }
+
+    fn mark_done(&mut self, step: WizardStep) -> Result<()> {
+        if !self.is_done(step) {
+            self.completed.push(step);
+        }
+        self.updated_at = now_ts();
+        save_session(self)
+    }
+
+    /// The first step still to run, or `None` when the pipeline is finished.
+    fn next_step(&self) -> Option<WizardStep> {
+        WizardStep::ALL
+            .iter()
+            .copied()
+            .find(|step| !self.is_done(*step))
+    }
+
+    /// Why the session cannot be resumed at all, if it cannot.
+    fn stale_reason(&self) -> Option<String> {
+        if Path::new(&self.project_dir).is_dir() {
+            None
+        } else {
+            Some(format!(
+                "project directory {} no longer exists",
+                self.project_dir
+            ))
+        }
+    }
+
+    /// Whether what `step` produced is still there to build on.
+    fn output_present(&self, step: WizardStep) -> bool {
+        match step {
+            WizardStep::Detect => self.package_name.is_some(),
+            WizardStep::Build => self
+                .wasm_path
+                .as_deref()
+                .is_some_and(|wasm| Path::new(wasm).is_file()),
+            WizardStep::Deploy => self.contract_id.is_some(),
+            WizardStep::Publish => self.contract_name.is_some() && self.publisher.is_some(),
+            WizardStep::Preflight | WizardStep::Verify => true,
+        }
+    }
+
+    /// Keeps the completed steps that ran in order and whose output is still
+    /// present; the first one that fails either test, and every step after
+    /// it, is run again. Returns the steps dropped.
+    fn revalidate(&mut self) -> Vec<WizardStep> {
+        let valid = WizardStep::ALL
+            .iter()
+            .take_while(|step| self.is_done(**step) && self.output_present(**step))
+            .count();
+        let (kept, dropped): (Vec<_>, Vec<_>) = self
+            .completed
+            .iter()
+            .partition(|step| WizardStep::ALL[..valid].contains(step));
+        self.completed = kept;
+        dropped
+    }
+}
 
-pub async fn run(_api_url: &str) -> Result<()> {
-    println!("\n{}", "Contract Instantiation Wizard".bold().cyan());
+pub async fn run(api_url: &str, project_dir: &str, resume: bool) -> Result<()> {
+    println!("\n{}", "Contract Deploy Wizard".bold().cyan());
     println!("{}", "=".repeat(80).cyan());
 
+    let mut session = match (resume, load_session()?) {
+        (true, Some(existing)) if existing.stale_reason().is_some() => {
+            println!(
+                "{} {}; starting a new one.",
+                "Saved wizard session is stale:".yellow(),
+                existing.stale_reason().unwrap_or_default()
+            );
+            new_session(project_dir)?
+        }
+        (true, Some(mut existing)) => {
+            let dropped = existing.revalidate();
+            if !dropped.is_empty() {
+                println!(
+                    "{} {}",
+                    "Outputs of earlier steps are missing; redoing:".yellow(),
+                    dropped
+                        .iter()
+                        .map(|step| step.label())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                );
+                save_session(&existing)?;
+            }
+            println!(
+                "{} {} ({} of {} steps completed)",
+                "Resuming session for".bright_black(),
+                existing.project_dir.bright_blue(),
+                existing.completed.len(),
+                WizardStep::ALL.len()
+            );
+            if let Some(step) = existing.next_step() {
+                println!("{} {}", "Continuing at:".bright_black(), step.label());
+            }
+            existing
+        }
+        (true, None) => {
+            println!(
+                "{}",
+                "No saved wizard session found; starting a new one.".yellow()
+            );
+            new_session(project_dir)?
+        }
+        (false, _) => new_session(project_dir)?,
+    };
+
+    let mut signer: Option<String> = None;
+
+    for (idx, step) in WizardStep::ALL.iter().enumerate() {
+        println!(
+            "\n[{}/{}] {}",
+            idx + 1,
+            WizardStep::ALL.len(),
+            step.label().bold()
+        );
+        if session.is_done(*step) {
+            println!("  {} already completed", "✓".green());
+            continue;
+        }
+
+        let outcome = match step {
+            WizardStep::Detect => step_detect(&mut session),
+            WizardStep::Build => step_build(&mut session),
+            WizardStep::Preflight => step_preflight(&session),
+            WizardStep::Deploy => {
+                if signer.is_none() {
+                    signer = Some(prompt_signer()?);
+                }
+                step_deploy(&mut session, signer.as_deref().unwrap_or_default())
+            }
+            WizardStep::Publish => step_publish(api_url, &mut session).await,
+            WizardStep::Verify => step_verify(api_url, &session).await,
+        };
+
+        if let Err(e) = outcome {
+            println!("  {} {}", "✗".red().bold(), e);
+            let _ = record_history(json!({
+                "status": "failed",
+                "step": step,
+                "network": session.network,
+                "wasm": session.wasm_path,
+                "error": e.to_string(),
+                "ts": now_ts(),
+            }));
+            println!(
+                "\n{}",
+                "Session saved. Fix the issue and run `soroban-registry wizard --resume` to continue."
+                    .yellow()
+            );
+            return Ok(());
+        }
+
+        session.mark_done(*step)?;
+
+        if *step == WizardStep::Preflight {
+            print_plan(&session);
+            if !confirm("Deploy and publish now? [y/N]", false)? {
+                let _ = record_history(json!({
+                    "status": "planned",
+                    "network": session.network,
+                    "wasm": session.wasm_path,
+                    "max_fee": session.max_fee,
+                    "ts": now_ts(),
+                }));
+                println!(
+                    "{}",
+                    "Saved plan without deploying. Resume later with `wizard --resume`.".yellow()
+                );
+                return Ok(());
+            }
+        }
+    }
+
+    let _ = record_history(json!({
+        "status": "success",
+        "network": session.network,
+        "signer_masked": signer.as_deref().map(mask_secret),
+        "wasm": session.wasm_path,
+        "contract_id": session.contract_id,
+        "max_fee": session.max_fee,
+        "ts": now_ts(),
+    }));
+    clear_session()?;
+
+    println!("\n{}", "=".repeat(80).cyan());
+    println!("{}", "✓ Wizard completed".green().bold());
+    if let Some(id) = &session.contract_id {
+        println!("{}: {}", "Contract".bold(), id.bright_blue());
+    }
+    println!();
+    Ok(())
+}
+
+fn new_session(project_dir: &str) -> Result<WizardSession> {
     let network = prompt_with_validation(
         "Select network [mainnet|testnet|futurenet] (default: testnet)",
         Some("testnet".to_string()),
@@ -24,160 +275,298 @@ pub async fn run(_api_url: &str) -> Result<()> {
         "Invalid network. Choose mainnet, testnet, or futurenet.",
     )?;
 
-    let signer = prompt_with_validation(
-        "Enter signer address or secret (starts with G… or S…)",
-        None::<String>,
-        |s: &str| {
-            let s = s.trim();
-            (s.starts_with('G') || s.starts_with('S')) && s.len() >= 56
-        },
-        "Invalid signer. Provide a Stellar address (G...) or secret (S...).",
-    )?;
-
-    let wasm_path = prompt_with_validation(
-        "Path to contract WASM (.wasm)",
-        None::<String>,
-        |s: &str| {
-            let p = Path::new(s.trim());
-            p.exists() && p.is_file() && p.extension().map(|e| e == "wasm").unwrap_or(false)
-        },
-        "File not found or not a .wasm file.",
-    )?;
-
-    println!(
-        "{}",
-        "Enter constructor params as JSON object (e.g., {\"admin\":\"G...\"}). Leave blank for {}"
-            .replace("{}", "none")
-            .bright_black()
-    );
-    let params_raw = prompt("Params JSON", Some("".into()))?;
-    let params_value = if params_raw.trim().is_empty() {
-        serde_json::Value::Object(Default::default())
-    } else {
-        serde_json::from_str::<serde_json::Value>(params_raw.trim())
-            .context("Invalid JSON for params")?
-    };
-
     let max_fee_str = prompt_with_validation(
         "Max fee (stroops), integer",
         Some("100000".to_string()),
         |s| s.trim().parse::<u64>().is_ok(),
         "Provide a positive integer.",
     )?;
-    let max_fee: u64 = max_fee_str.trim().parse().unwrap_or(100_000);
 
+    let session = WizardSession {
+        project_dir: project_dir.to_string(),
+        network: network.to_lowercase(),
+        max_fee: max_fee_str.trim().parse().unwrap_or(100_000),
+        updated_at: now_ts(),
+        ..Default::default()
+    };
+    save_session(&session)?;
+    Ok(session)
+}
+
+fn prompt_signer() -> Result<String> {
+    prompt_with_validation(
+        "Enter signer identity or secret (soroban identity name or S…)",
+        None::<String>,
+        |s: &str| {
+            let s = s.trim();
+            !s.is_empty() && !(s.starts_with('S') && s.len() < 56)
+        },
+        "Invalid signer. Provide a soroban identity name or a secret key (S...).",
+    )
+}
+
+fn print_plan(session: &WizardSession) {
     println!("\n{}", "Deployment Plan Preview".bold().cyan());
     println!("{}", "-".repeat(80).cyan());
+    println!("{}: {}", "Network".bold(), session.network.bright_blue());
     println!(
         "{}: {}",
-        "Network".bold(),
-        network.to_lowercase().bright_blue()
-    );
-    println!(
-        "{}: {}",
-        "Signer".bold(),
-        mask_secret(&signer).bright_black()
-    );
-    println!("{}: {}", "WASM".bold(), wasm_path.as_str().bright_black());
-    println!("{}: {}", "Max Fee".bold(), max_fee);
-    println!("{}:", "Params".bold());
-    println!(
-        "{}",
-        serde_json::to_string_pretty(&params_value).unwrap_or_default()
+        "Project".bold(),
+        session.project_dir.as_str().bright_black()
     );
+    if let Some(wasm) = &session.wasm_path {
+        println!("{}: {}", "WASM".bold(), wasm.as_str().bright_black());
+    }
+    println!("{}: {}", "Max Fee".bold(), session.max_fee);
     println!("{}", "-".repeat(80).cyan());
+}
 
-    let proceed = confirm("Proceed to dry-run? [y/N]", false)?;
-    if !proceed {
-        println!("{}", "Aborted.".yellow());
-        return Ok(());
+fn step_detect(session: &mut WizardSession) -> Result<()> {
+    let dir = Path::new(&session.project_dir);
+    let manifest_path = dir.join("Cargo.toml");
+    let manifest = std::fs::read_to_string(&manifest_path)
+        .with_context(|| format!("No Cargo.toml found in {}", dir.display()))?;
+    let manifest: toml::Value = toml::from_str(&manifest).context("Failed to parse Cargo.toml")?;
+
+    let package_name = manifest
+        .get("package")
+        .and_then(|p| p.get("name"))
+        .and_then(|n| n.as_str())
+        .context("Cargo.toml has no [package] name")?
+        .to_string();
+
+    let uses_sdk = manifest
+        .get("dependencies")
+        .and_then(|d| d.get("soroban-sdk"))
+        .is_some();
+    if !uses_sdk {
+        anyhow::bail!(
+            "{} does not depend on soroban-sdk; not a Soroban contract project",
+            manifest_path.display()
+        );
     }
 
-    match dry_run(&wasm_path, &params_value) {
-        Ok(_) => println!("{}", "✓ Dry-run passed".green().bold()),
-        Err(e) => {
-            println!("{} {}", "✗ Dry-run failed:".red().bold(), e);
-            let _ = record_history(json!({
-                "status": "dry_run_failed",
-                "network": network.to_lowercase(),
-                "signer_masked": mask_secret(&signer),
-                "wasm": wasm_path,
-                "params": params_value,
-                "max_fee": max_fee,
-                "ts": now_ts(),
-            }));
-            return Ok(());
-        }
+    println!(
+        "  {} found contract crate {}",
+        "✓".green(),
+        package_name.bold()
+    );
+    session
+        .contract_name
+        .get_or_insert_with(|| package_name.clone());
+    session.package_name = Some(package_name);
+    Ok(())
+}
+
+fn step_build(session: &mut WizardSession) -> Result<()> {
+    let package_name = session
+        .package_name
+        .clone()
+        .context("Project has not been detected yet")?;
+
+    println!("  {} Compiling to wasm32-unknown-unknown...", "●".blue());
+    let status = Command::new("cargo")
+        .args(["build", "--target", "wasm32-unknown-unknown", "--release"])
+        .current_dir(&session.project_dir)
+        .status()
+        .context("Failed to run cargo build")?;
+    if !status.success() {
+        anyhow::bail!("cargo build failed for {}", session.project_dir);
     }
 
-    let execute = confirm("Execute deployment? [y/N]", false)?;
-    if !execute {
-        let _ = record_history(json!({
-            "status": "planned",
-            "network": network.to_lowercase(),
-            "signer_masked": mask_secret(&signer),
-            "wasm": wasm_path,
-            "params": params_value,
-            "max_fee": max_fee,
-            "ts": now_ts(),
-        }));
-        println!("{}", "Saved plan without executing.".yellow());
-        return Ok(());
+    let wasm = Path::new(&session.project_dir)
+        .join("target/wasm32-unknown-unknown/release")
+        .join(format!("{}.wasm", package_name.replace('-', "_")));
+    if !wasm.is_file() {
+        anyhow::bail!("Expected build artifact not found at {}", wasm.display());
     }
 
-    let soroban_available = detect_soroban();
-    let status = "success";
-    let error_msg: Option<String> = None;
+    println!("  {} {}", "✓".green(), wasm.display());
+    session.wasm_path = Some(wasm.to_string_lossy().into_owned());
+    Ok(())
+}
 
-    if soroban_available {
-        println!(
-            "{}",
-            "soroban CLI detected. Simulating deployment...".bright_black()
-        );
+fn step_preflight(session: &WizardSession) -> Result<()> {
+    let wasm_path = session
+        .wasm_path
+        .as_deref()
+        .context("No WASM artifact; run the build step first")?;
+
+    dry_run(wasm_path, &serde_json::Value::Object(Default::default()))?;
+    println!("  {} size within limits", "✓".green());
+
+    if detect_soroban() {
+        let out = Command::new("soroban")
+            .args(["contract", "bindings", "json", "--wasm", wasm_path])
+            .output()
+            .context("Failed to run soroban bindings")?;
+        if !out.status.success() {
+            anyhow::bail!(
+                "ABI extraction failed: {}",
+                String::from_utf8_lossy(&out.stderr).trim()
+            );
+        }
+        println!("  {} ABI extracted", "✓".green());
     } else {
         println!(
-            "{}",
-            "soroban CLI not found; performing simulated deployment only.".bright_black()
+            "  {} soroban CLI not found, skipping ABI check",
+            "⚠".yellow()
         );
     }
 
-    if status == "failed" {
-        println!("{}", "✗ Deployment failed".red().bold());
-        let _ = record_history(json!({
-            "status": "failed",
-            "network": network.to_lowercase(),
-            "signer_masked": mask_secret(&signer),
-            "wasm": wasm_path,
-            "params": params_value,
-            "max_fee": max_fee,
-            "error": error_msg,
-            "ts": now_ts(),
-        }));
-        println!("{}", "Attempting rollback...".yellow());
-        println!("{}", "Rollback completed.".yellow());
-        let _ = record_history(json!({
-            "status": "rolled_back",
-            "network": network.to_lowercase(),
-            "signer_masked": mask_secret(&signer),
-            "wasm": wasm_path,
-            "params": params_value,
-            "max_fee": max_fee,
-            "ts": now_ts(),
-        }));
-    } else {
-        println!("{}", "✓ Deployment executed".green().bold());
-        let _ = record_history(json!({
-            "status": "success",
-            "network": network.to_lowercase(),
-            "signer_masked": mask_secret(&signer),
-            "wasm": wasm_path,
-            "params": params_value,
-            "max_fee": max_fee,
-            "ts": now_ts(),
-        }));
+    if Path::new(&session.project_dir)
+        .join(crate::abi_snapshot::ABI_SNAPSHOT_FILE)
+        .exists()
+    {
+        crate::abi_snapshot::check(&session.project_dir, Some(wasm_path), None)?;
     }
 
-    println!();
+    let lint = Command::new("cargo")
+        .args(["clippy", "--quiet", "--", "-D", "warnings"])
+        .current_dir(&session.project_dir)
+        .output();
+    match lint {
+        Ok(out) if out.status.success() => println!("  {} lint passed", "✓".green()),
+        Ok(out) => anyhow::bail!(
+            "Lint failed:\n{}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        ),
+        Err(_) => println!(
+            "  {} cargo clippy not installed, skipping lint",
+            "⚠".yellow()
+        ),
+    }
+
+    Ok(())
+}
+
+fn step_deploy(session: &mut WizardSession, signer: &str) -> Result<()> {
+    if !detect_soroban() {
+        anyhow::bail!("soroban CLI is required to deploy; install it and resume");
+    }
+    let wasm_path = session
+        .wasm_path
+        .as_deref()
+        .context("No WASM artifact; run the build step first")?;
+
+    let out = Command::new("soroban")
+        .args([
+            "contract",
+            "deploy",
+            "--wasm",
+            wasm_path,
+            "--source",
+            signer.trim(),
+            "--network",
+            &session.network,
+            "--fee",
+            &session.max_fee.to_string(),
+        ])
+        .output()
+        .context("Failed to run soroban contract deploy")?;
+    if !out.status.success() {
+        anyhow::bail!(
+            "Deployment failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        );
+    }
+
+    let contract_id = String::from_utf8_lossy(&out.stdout).trim().to_string();
+    if contract_id.is_empty() {
+        anyhow::bail!("soroban contract deploy did not return a contract ID");
+    }
+
+    println!(
+        "  {} deployed as {}",
+        "✓".green(),
+        contract_id.bright_blue()
+    );
+    session.contract_id = Some(contract_id);
+    Ok(())
+}
+
+async fn step_publish(api_url: &str, session: &mut WizardSession) -> Result<()> {
+    let contract_id = session
+        .contract_id
+        .clone()
+        .context("Contract has not been deployed yet")?;
+
+    let name = prompt(
+        "Registry name",
+        session
+            .contract_name
+            .clone()
+            .or(session.package_name.clone()),
+    )?;
+    let publisher = match session.publisher.clone() {
+        Some(p) => p,
+        None => prompt_with_validation(
+            "Publisher Stellar address (G…)",
+            None::<String>,
+            |s: &str| s.trim().starts_with('G') && s.trim().len() == 56,
+            "Invalid publisher. Provide a Stellar address (G...).",
+        )?,
+    };
+    session.contract_name = Some(name.clone());
+    session.publisher = Some(publisher.clone());
+
+    let network: crate::commands::Network = session.network.parse()?;
+    crate::commands::publish(
+        api_url,
+        &contract_id,
+        &name,
+        None,
+        network,
+        None,
+        Vec::new(),
+        &publisher,
+        false,
+        &session.project_dir,
+        None,
+        false,
+        0.0,
+        crate::abi_snapshot::DEFAULT_MIN_DOCS_COVERAGE,
+        true,
+    )
+    .await
+}
+
+async fn step_verify(api_url: &str, session: &WizardSession) -> Result<()> {
+    let contract_id = session
+        .contract_id
+        .as_deref()
+        .context("Contract has not been deployed yet")?;
+    let source_code = std::fs::read_to_string(Path::new(&session.project_dir).join("src/lib.rs"))
+        .context("Failed to read src/lib.rs for verification")?;
+    let compiler_version = Command::new("rustc")
+        .arg("--version")
+        .output()
+        .ok()
+        .map(|o| String::from_utf8_lossy(&o.stdout).trim().to_string())
+        .unwrap_or_else(|| "unknown".to_string());
+    let toolchain = crate::manifest::RegistryToml::load(Path::new(&session.project_dir))?
+        .and_then(|manifest| manifest.build)
+        .and_then(|build| build.toolchain);
+
+    let response = crate::http::client()
+        .post(format!("{}/api/contracts/verify", api_url))
+        .json(&json!({
+            "contract_id": contract_id,
+            "source_code": source_code,
+            "build_params": { "target": "wasm32-unknown-unknown", "profile": "release" },
+            "compiler_version": compiler_version,
+            "toolchain": toolchain,
+        }))
+        .send()
+        .await
+        .context("Failed to submit verification request")?;
+
+    if !response.status().is_success() {
+        let body = response.text().await.unwrap_or_default();
+        anyhow::bail!("Verification request rejected: {}", body);
+    }
+
+    println!("  {} verification submitted", "✓".green());
     Ok(())
 }
 
@@ -384,6 +773,44 @@ fn record_history(entry: serde_json::Value) -> Result<()> {
     Ok(())
 }
 
+fn session_path() -> Result<PathBuf> {
+    Ok(ensure_history_path()?.with_file_name(SESSION_FILE_NAME))
+}
+
+fn load_session() -> Result<Option<WizardSession>> {
+    let path = session_path()?;
+    if !path.exists() {
+        return Ok(None);
+    }
+    let raw = std::fs::read_to_string(&path).context("Failed to read wizard session")?;
+    let session = parse_session(&raw);
+    if session.is_none() {
+        println!(
+            "{}",
+            "Saved wizard session is unreadable and will be replaced.".yellow()
+        );
+    }
+    Ok(session)
+}
+
+fn parse_session(raw: &str) -> Option<WizardSession> {
+    serde_json::from_str(raw).ok()
+}
+
+fn save_session(session: &WizardSession) -> Result<()> {
+    let path = session_path()?;
+    std::fs::write(&path, serde_json::to_string_pretty(session)?)
+        .context("Failed to write wizard session")
+}
+
+fn clear_session() -> Result<()> {
+    let path = session_path()?;
+    if path.exists() {
+        std::fs::remove_file(&path).context("Failed to remove wizard session")?;
+    }
+    Ok(())
+}
+
 fn home_dir() -> Option<PathBuf> {
     if cfg!(windows) {
         std::env::var_os("USERPROFILE").map(PathBuf::from)
@@ -391,3 +818,106 @@ fn home_dir() -> Option<PathBuf> {
         std::env::var_os("HOME").map(PathBuf::from)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A session that has completed the first `done` steps, with every
+    /// output those steps produce present under `dir`.
+    fn session_after(dir: &Path, done: usize) -> WizardSession {
+        let wasm = dir.join("contract.wasm");
+        std::fs::write(&wasm, b"\0asm").unwrap();
+        WizardSession {
+            project_dir: dir.to_string_lossy().into_owned(),
+            network: "testnet".to_string(),
+            package_name: Some("contract".to_string()),
+            wasm_path: Some(wasm.to_string_lossy().into_owned()),
+            contract_id: Some("CCONTRACT".to_string()),
+            contract_name: Some("contract".to_string()),
+            publisher: Some("GPUBLISHER".to_string()),
+            max_fee: 100_000,
+            completed: WizardStep::ALL[..done].to_vec(),
+            updated_at: 0,
+        }
+    }
+
+    #[test]
+    fn resumes_from_each_saved_step() {
+        let dir = tempfile::tempdir().unwrap();
+        for done in 0..=WizardStep::ALL.len() {
+            let mut session = session_after(dir.path(), done);
+            assert!(session.stale_reason().is_none());
+            assert!(session.revalidate().is_empty(), "after {} steps", done);
+            assert_eq!(session.completed, WizardStep::ALL[..done]);
+            assert_eq!(session.next_step(), WizardStep::ALL.get(done).copied());
+        }
+    }
+
+    #[test]
+    fn saved_session_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let session = session_after(dir.path(), 3);
+        let raw = serde_json::to_string_pretty(&session).unwrap();
+        let parsed = parse_session(&raw).unwrap();
+        assert_eq!(parsed.completed, session.completed);
+        assert_eq!(parsed.next_step(), Some(WizardStep::Deploy));
+    }
+
+    #[test]
+    fn missing_build_output_redoes_the_build_and_later_steps() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = session_after(dir.path(), 4);
+        std::fs::remove_file(session.wasm_path.as_deref().unwrap()).unwrap();
+
+        assert_eq!(
+            session.revalidate(),
+            [WizardStep::Build, WizardStep::Preflight, WizardStep::Deploy]
+        );
+        assert_eq!(session.completed, [WizardStep::Detect]);
+        assert_eq!(session.next_step(), Some(WizardStep::Build));
+    }
+
+    #[test]
+    fn steps_recorded_out_of_order_are_redone() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = session_after(dir.path(), 0);
+        session.completed = vec![WizardStep::Detect, WizardStep::Deploy];
+
+        assert_eq!(session.revalidate(), [WizardStep::Deploy]);
+        assert_eq!(session.next_step(), Some(WizardStep::Build));
+    }
+
+    #[test]
+    fn deploy_without_contract_id_is_redone() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = session_after(dir.path(), 5);
+        session.contract_id = None;
+
+        assert_eq!(
+            session.revalidate(),
+            [WizardStep::Deploy, WizardStep::Publish]
+        );
+        assert_eq!(session.next_step(), Some(WizardStep::Deploy));
+    }
+
+    #[test]
+    fn session_for_a_removed_project_is_stale() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut session = session_after(dir.path(), 2);
+        session.project_dir = dir.path().join("gone").to_string_lossy().into_owned();
+        assert!(session.stale_reason().is_some());
+    }
+
+    #[test]
+    fn unreadable_session_is_discarded() {
+        assert!(parse_session("").is_none());
+        assert!(parse_session("{ not json").is_none());
+        assert!(parse_session(r#"{"project_dir": "."}"#).is_none());
+        assert!(parse_session(
+            r#"{"project_dir": ".", "network": "testnet", "max_fee": 1,
+                "completed": ["detect", "launch"], "updated_at": 0}"#
+        )
+        .is_none());
+    }
+}
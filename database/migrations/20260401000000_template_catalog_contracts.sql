@@ -0,0 +1,7 @@
+-- Back each project template with a registry-hosted contract so the catalog
+-- served to `soroban-registry new` only exposes verified sources.
+ALTER TABLE contract_templates
+    ADD COLUMN IF NOT EXISTS contract_id UUID REFERENCES contracts(id) ON DELETE SET NULL,
+    ADD COLUMN IF NOT EXISTS cargo_toml TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_contract_templates_contract_id ON contract_templates(contract_id);
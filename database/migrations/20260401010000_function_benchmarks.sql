@@ -0,0 +1,18 @@
+-- Per-function resource/fee benchmarks recorded by `soroban-registry bench`
+-- against a specific contract version.
+CREATE TABLE IF NOT EXISTS contract_function_benchmarks (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_version_id UUID NOT NULL REFERENCES contract_versions(id) ON DELETE CASCADE,
+    function_name VARCHAR(255) NOT NULL,
+    cpu_instructions BIGINT NOT NULL CHECK (cpu_instructions >= 0),
+    memory_bytes BIGINT NOT NULL CHECK (memory_bytes >= 0),
+    fee_stroops BIGINT NOT NULL CHECK (fee_stroops >= 0),
+    args JSONB,
+    tool_version VARCHAR(64),
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_version_id, function_name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_function_benchmarks_contract
+    ON contract_function_benchmarks(contract_id, function_name);
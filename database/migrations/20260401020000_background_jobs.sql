@@ -0,0 +1,28 @@
+-- Persistent background job queue. Workers claim jobs with
+-- `FOR UPDATE SKIP LOCKED`. A failed attempt moves the job to 'failed' with a
+-- backed-off run_at; once max_attempts is exhausted it is dead-lettered
+-- (status = 'dead') and kept for inspection or manual retry.
+CREATE TABLE IF NOT EXISTS background_jobs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind VARCHAR(100) NOT NULL,
+    payload JSONB NOT NULL DEFAULT '{}'::jsonb,
+    status VARCHAR(20) NOT NULL DEFAULT 'queued'
+        CHECK (status IN ('queued', 'running', 'failed', 'succeeded', 'dead')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5 CHECK (max_attempts > 0),
+    run_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    locked_at TIMESTAMPTZ,
+    locked_by VARCHAR(100),
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ
+);
+
+-- Claim query: next runnable job.
+CREATE INDEX IF NOT EXISTS idx_background_jobs_runnable
+    ON background_jobs(run_at)
+    WHERE status IN ('queued', 'failed');
+
+CREATE INDEX IF NOT EXISTS idx_background_jobs_status_kind
+    ON background_jobs(status, kind, created_at DESC);
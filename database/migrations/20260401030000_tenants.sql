@@ -0,0 +1,68 @@
+-- Tenants: isolated teams served by one deployment. Every scoped row carries a
+-- tenant_id; existing data belongs to the built-in 'default' tenant.
+-- Requests are mapped to a tenant by the `tenant` JWT claim or by Host header
+-- (see backend/api/src/tenant.rs).
+CREATE TABLE IF NOT EXISTS tenants (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    slug VARCHAR(64) NOT NULL UNIQUE CHECK (slug ~ '^[a-z0-9][a-z0-9-]*$'),
+    name VARCHAR(255) NOT NULL,
+    hostnames TEXT[] NOT NULL DEFAULT '{}',
+    -- NULL means unlimited.
+    quota_contracts INTEGER CHECK (quota_contracts >= 0),
+    quota_publishers INTEGER CHECK (quota_publishers >= 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_tenants_hostnames ON tenants USING GIN (hostnames);
+
+INSERT INTO tenants (id, slug, name)
+VALUES ('00000000-0000-0000-0000-000000000001', 'default', 'Default')
+ON CONFLICT (id) DO NOTHING;
+
+CREATE TRIGGER update_tenants_updated_at
+    BEFORE UPDATE ON tenants
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000001' REFERENCES tenants(id);
+ALTER TABLE publishers
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000001' REFERENCES tenants(id);
+ALTER TABLE deploy_proposals
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000001' REFERENCES tenants(id);
+ALTER TABLE contract_patches
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000001' REFERENCES tenants(id);
+ALTER TABLE governance_proposals
+    ADD COLUMN IF NOT EXISTS tenant_id UUID NOT NULL
+        DEFAULT '00000000-0000-0000-0000-000000000001' REFERENCES tenants(id);
+
+CREATE INDEX IF NOT EXISTS idx_contracts_tenant_id ON contracts(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_publishers_tenant_id ON publishers(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_deploy_proposals_tenant_id ON deploy_proposals(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_contract_patches_tenant_id ON contract_patches(tenant_id);
+CREATE INDEX IF NOT EXISTS idx_governance_proposals_tenant_id ON governance_proposals(tenant_id);
+
+-- Rows that hang off a contract inherit its tenant, so writers do not need to
+-- pass it explicitly.
+CREATE OR REPLACE FUNCTION inherit_contract_tenant()
+RETURNS TRIGGER AS $$
+BEGIN
+    SELECT tenant_id INTO NEW.tenant_id FROM contracts WHERE id = NEW.contract_id;
+    IF NEW.tenant_id IS NULL THEN
+        NEW.tenant_id := '00000000-0000-0000-0000-000000000001';
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER contract_patches_inherit_tenant
+    BEFORE INSERT ON contract_patches
+    FOR EACH ROW EXECUTE FUNCTION inherit_contract_tenant();
+
+CREATE TRIGGER governance_proposals_inherit_tenant
+    BEFORE INSERT ON governance_proposals
+    FOR EACH ROW EXECUTE FUNCTION inherit_contract_tenant();
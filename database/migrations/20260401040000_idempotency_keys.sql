@@ -0,0 +1,19 @@
+-- Idempotency-Key records for write endpoints. A retried request with the same
+-- key and body replays the stored response instead of running again
+-- (see backend/api/src/idempotency.rs).
+CREATE TABLE IF NOT EXISTS idempotency_keys (
+    idempotency_key VARCHAR(255) NOT NULL,
+    request_path TEXT NOT NULL,
+    -- SHA-256 of method, path, credentials and body.
+    request_hash CHAR(64) NOT NULL,
+    -- NULL while the original request is still running.
+    status_code SMALLINT,
+    content_type TEXT,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ,
+    PRIMARY KEY (idempotency_key, request_path)
+);
+
+CREATE INDEX IF NOT EXISTS idx_idempotency_keys_created_at
+    ON idempotency_keys(created_at);
@@ -0,0 +1,9 @@
+-- Optimistic concurrency tokens for records maintainers edit. Clients read
+-- lock_version (exposed as the ETag) and send it back in If-Match; an update
+-- only applies if the row still carries that version.
+--
+-- The version is bumped explicitly by user-facing update statements rather
+-- than by trigger, so background writers (view counts, health scores) do not
+-- invalidate an editor's ETag.
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS lock_version BIGINT NOT NULL DEFAULT 1;
@@ -0,0 +1,12 @@
+-- Soft-delete for contracts and publishers. Deleting sets deleted_at; read
+-- endpoints filter these rows out, admins can restore them, and the
+-- purge_soft_deleted background job hard-deletes them after the retention
+-- window (SOFT_DELETE_RETENTION_DAYS).
+ALTER TABLE contracts ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+ALTER TABLE publishers ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+-- Trash listing and purge scans.
+CREATE INDEX IF NOT EXISTS idx_contracts_deleted_at
+    ON contracts(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX IF NOT EXISTS idx_publishers_deleted_at
+    ON publishers(deleted_at) WHERE deleted_at IS NOT NULL;
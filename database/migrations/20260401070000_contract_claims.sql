@@ -0,0 +1,25 @@
+-- Ownership claims. Contracts the crawler indexed (or that a third party
+-- registered) can be claimed by their on-chain deployer, who proves control of
+-- the deploying key by signing a one-time challenge. Metadata of auto-indexed
+-- contracts stays read-only until they are claimed.
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS auto_indexed BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS claimed_at TIMESTAMPTZ;
+
+-- Rows written by the indexer before this column existed: it names them after
+-- the contract id and stores "<contract_id>_<op_id>" as a placeholder hash.
+UPDATE contracts
+SET auto_indexed = TRUE
+WHERE name = contract_id
+  AND wasm_hash LIKE contract_id || '\_%';
+
+-- At most one outstanding challenge per contract; requesting a new one
+-- replaces it.
+CREATE TABLE IF NOT EXISTS contract_claim_challenges (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    deployer_address VARCHAR(56) NOT NULL,
+    nonce VARCHAR(64) NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
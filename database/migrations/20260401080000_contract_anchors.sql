@@ -0,0 +1,16 @@
+-- On-chain anchors of contract records. Each row is one ManageData
+-- transaction that wrote the record's digest (see shared::anchor) into the
+-- anchor account's data entry `sr:<contract_id>` on the contract's network.
+CREATE TABLE IF NOT EXISTS contract_anchors (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    network network_type NOT NULL,
+    digest CHAR(64) NOT NULL,
+    anchor_account VARCHAR(56) NOT NULL,
+    data_name VARCHAR(64) NOT NULL,
+    transaction_hash VARCHAR(64) NOT NULL,
+    anchored_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_anchors_contract
+    ON contract_anchors(contract_id, anchored_at DESC);
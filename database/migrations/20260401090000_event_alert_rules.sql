@@ -0,0 +1,49 @@
+-- User-defined alert rules over indexed contract events
+--
+-- A rule fires when at least `threshold` events with `topic` (and, when
+-- `data_value` is set, whose payload at `data_path` equals it) were ingested
+-- for the contract within the last `window_seconds`. Only events ingested
+-- after the rule's last trigger (or creation) count, so each event can fire a
+-- rule at most once.
+
+CREATE TABLE IF NOT EXISTS event_alert_rules (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- Stellar address of the user who owns the rule
+    owner_address TEXT NOT NULL,
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    topic TEXT NOT NULL,
+    -- Dot-separated path into the event payload; NULL compares the whole payload
+    data_path TEXT,
+    data_value JSONB,
+    threshold INTEGER NOT NULL DEFAULT 1 CHECK (threshold > 0),
+    window_seconds INTEGER NOT NULL DEFAULT 300 CHECK (window_seconds > 0),
+    webhook_url TEXT,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    last_triggered_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (data_path IS NULL OR data_value IS NOT NULL)
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_alert_rules_owner ON event_alert_rules(owner_address);
+CREATE INDEX IF NOT EXISTS idx_event_alert_rules_active
+    ON event_alert_rules(contract_id) WHERE is_active;
+
+CREATE TABLE IF NOT EXISTS event_alert_triggers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    rule_id UUID NOT NULL REFERENCES event_alert_rules(id) ON DELETE CASCADE,
+    event_count INTEGER NOT NULL,
+    first_ledger BIGINT NOT NULL,
+    last_ledger BIGINT NOT NULL,
+    -- 'skipped' when the rule has no webhook, else 'delivered' or 'failed'
+    delivery_status VARCHAR(20) NOT NULL,
+    delivery_error TEXT,
+    triggered_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_event_alert_triggers_rule
+    ON event_alert_triggers(rule_id, triggered_at DESC);
+
+-- Alert windows scan recent events per contract and topic
+CREATE INDEX IF NOT EXISTS idx_contract_events_ingested
+    ON contract_events(contract_id, topic, created_at DESC);
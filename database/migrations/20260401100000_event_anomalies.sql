@@ -0,0 +1,38 @@
+-- Anomalies flagged on newly ingested contract events
+--
+-- `dedupe_key` identifies one occurrence of an anomaly (a topic's first
+-- appearance, an hour of elevated volume, a single oversized transfer) so
+-- re-scanning the same events never records it twice.
+
+CREATE TABLE IF NOT EXISTS contract_event_anomalies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    -- On-chain contract address, as in contract_events
+    contract_id TEXT NOT NULL,
+    network network_type NOT NULL,
+    -- 'rate_spike', 'new_topic' or 'large_transfer'
+    kind VARCHAR(32) NOT NULL,
+    topic TEXT,
+    event_id UUID,
+    ledger_sequence BIGINT,
+    details JSONB NOT NULL DEFAULT '{}',
+    dedupe_key TEXT NOT NULL,
+    detected_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT unique_event_anomaly UNIQUE (contract_id, network, dedupe_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_event_anomalies_contract
+    ON contract_event_anomalies(contract_id, network, detected_at DESC);
+
+-- Position of the anomaly scan in contract_events (ingestion order)
+CREATE TABLE IF NOT EXISTS event_anomaly_cursor (
+    id SMALLINT PRIMARY KEY CHECK (id = 1),
+    last_created_at TIMESTAMPTZ NOT NULL,
+    last_event_id UUID NOT NULL
+);
+
+INSERT INTO event_anomaly_cursor (id, last_created_at, last_event_id)
+VALUES (1, NOW(), '00000000-0000-0000-0000-000000000000')
+ON CONFLICT (id) DO NOTHING;
+
+CREATE INDEX IF NOT EXISTS idx_contract_events_ingestion_order
+    ON contract_events(created_at, id);
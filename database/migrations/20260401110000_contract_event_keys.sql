@@ -0,0 +1,14 @@
+-- Key contract events by (network, transaction hash, event index)
+--
+-- Bulk ingestion upserts on this key so indexers can safely retry. The old
+-- (contract_id, ledger_sequence, transaction_hash) constraint is dropped: one
+-- transaction may emit several events from the same contract.
+
+ALTER TABLE contract_events ADD COLUMN IF NOT EXISTS event_index INTEGER;
+
+ALTER TABLE contract_events DROP CONSTRAINT IF EXISTS unique_event_per_ledger;
+
+CREATE UNIQUE INDEX IF NOT EXISTS unique_contract_event_key
+    ON contract_events(network, transaction_hash, event_index);
+
+COMMENT ON COLUMN contract_events.event_index IS 'Position of the event within its transaction';
@@ -0,0 +1,29 @@
+-- Scheduled rollouts of security patches
+--
+-- A rollout applies a patch to every contract still running the patch's
+-- target build, up to the patch's rollout percentage. The scheduler only
+-- works inside the maintenance window; a NULL window means "any time".
+
+CREATE TABLE IF NOT EXISTS patch_rollouts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    patch_id UUID NOT NULL UNIQUE REFERENCES security_patches(id) ON DELETE CASCADE,
+    -- Original window expression, e.g. 'Sat 02:00-04:00 UTC'
+    window_spec TEXT,
+    -- ISO weekdays the window opens on (1 = Monday); NULL or empty = daily
+    window_days SMALLINT[],
+    window_start TIME,
+    window_end TIME,
+    -- 'scheduled', 'running', 'paused' or 'completed'
+    status VARCHAR(16) NOT NULL DEFAULT 'scheduled',
+    applied_count INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    last_run_at TIMESTAMPTZ,
+    completed_at TIMESTAMPTZ,
+    created_by VARCHAR(56),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT patch_rollout_status CHECK (status IN ('scheduled', 'running', 'paused', 'completed'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_patch_rollouts_active
+    ON patch_rollouts(status) WHERE status IN ('scheduled', 'running');
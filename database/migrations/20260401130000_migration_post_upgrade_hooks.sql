@@ -0,0 +1,18 @@
+-- Post-upgrade initialization calls on contract migrations
+--
+-- Many contracts need a call such as migrate() or init_v2() right after
+-- their WASM is swapped. A migration may declare one; its outcome is
+-- recorded alongside the migration and gates the migration's success.
+
+ALTER TABLE migrations
+    ADD COLUMN IF NOT EXISTS post_upgrade_function VARCHAR(32),
+    ADD COLUMN IF NOT EXISTS post_upgrade_args JSONB,
+    -- 'pending', 'success', 'failed' or 'skipped'; NULL when no hook is declared
+    ADD COLUMN IF NOT EXISTS post_upgrade_status VARCHAR(16),
+    ADD COLUMN IF NOT EXISTS post_upgrade_output TEXT;
+
+ALTER TABLE migrations
+    DROP CONSTRAINT IF EXISTS migrations_post_upgrade_status;
+ALTER TABLE migrations
+    ADD CONSTRAINT migrations_post_upgrade_status
+    CHECK (post_upgrade_status IN ('pending', 'success', 'failed', 'skipped'));
@@ -0,0 +1,15 @@
+-- Migration history listing
+--
+-- Records the upgrade transaction and completion time of each migration so
+-- per-contract history can show tx hashes and durations.
+
+ALTER TABLE migrations
+    ADD COLUMN IF NOT EXISTS transaction_hash VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS completed_at TIMESTAMPTZ;
+
+UPDATE migrations
+SET completed_at = updated_at
+WHERE status <> 'pending' AND completed_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_migrations_contract_created
+    ON migrations(contract_id, created_at DESC);
@@ -0,0 +1,15 @@
+-- Chunked migration logs
+--
+-- Long-running migrations append their output as it is produced so it can
+-- be followed live (GET /api/migrations/:id/logs?follow=true) instead of
+-- arriving as a single log_output blob at the end.
+
+CREATE TABLE IF NOT EXISTS migration_log_chunks (
+    id BIGSERIAL PRIMARY KEY,
+    migration_id UUID NOT NULL REFERENCES migrations(id) ON DELETE CASCADE,
+    -- 1-based position within the migration's log
+    seq INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (migration_id, seq)
+);
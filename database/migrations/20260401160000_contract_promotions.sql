@@ -0,0 +1,31 @@
+-- Environment promotions (e.g. testnet -> mainnet)
+--
+-- A promotion records that a WASM build was cleared to move from one network
+-- to another within a logical contract (contracts.logical_id): it must have
+-- been exercised on the source network for a minimum soak period with no
+-- failed migrations. Rejected requests are kept with their failed checks.
+-- Once the build is deployed and registered on the target network, the
+-- promotion is completed and the new row joins the logical contract.
+
+CREATE TABLE IF NOT EXISTS contract_promotions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    logical_id UUID NOT NULL,
+    source_contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    from_network network_type NOT NULL,
+    to_network network_type NOT NULL,
+    wasm_hash VARCHAR(64) NOT NULL,
+    min_soak_hours INTEGER NOT NULL,
+    -- 'approved', 'rejected' or 'completed'
+    status VARCHAR(16) NOT NULL,
+    -- [{ "name", "passed", "detail" }]
+    checks JSONB NOT NULL DEFAULT '[]',
+    target_contract_id UUID REFERENCES contracts(id) ON DELETE SET NULL,
+    requested_by VARCHAR(56),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at TIMESTAMPTZ,
+    CONSTRAINT contract_promotion_status CHECK (status IN ('approved', 'rejected', 'completed')),
+    CONSTRAINT contract_promotion_networks CHECK (from_network <> to_network)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_promotions_logical
+    ON contract_promotions(logical_id, created_at DESC);
@@ -0,0 +1,28 @@
+-- Contract groups: deployments of the same codebase on different networks
+--
+-- contracts.logical_id (036_network_configs) already ties rows of one logical
+-- contract together; it is now the contract group ID. Rows registered by the
+-- crawler, clones and older code paths could leave it NULL, which made every
+-- such deployment look unrelated. Backfill those and default new rows to
+-- their own single-member group.
+
+UPDATE contracts SET logical_id = id WHERE logical_id IS NULL;
+
+CREATE OR REPLACE FUNCTION contracts_default_logical_id()
+RETURNS TRIGGER AS $$
+BEGIN
+    NEW.logical_id := COALESCE(NEW.logical_id, NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS trg_contracts_default_logical_id ON contracts;
+CREATE TRIGGER trg_contracts_default_logical_id
+    BEFORE INSERT OR UPDATE OF logical_id ON contracts
+    FOR EACH ROW EXECUTE FUNCTION contracts_default_logical_id();
+
+CREATE INDEX IF NOT EXISTS idx_contracts_logical_network
+    ON contracts(logical_id, network) WHERE deleted_at IS NULL;
+
+COMMENT ON COLUMN contracts.logical_id IS
+    'Contract group: deployments of the same codebase across networks';
@@ -0,0 +1,14 @@
+-- Verification pipeline metrics
+--
+-- Record when each verification build finished, whether it succeeded or
+-- failed, so build times can be measured (verified_at is only set on
+-- success). Rows written by manual status overrides keep it NULL and are left
+-- out of the pipeline metrics.
+
+ALTER TABLE verifications ADD COLUMN IF NOT EXISTS completed_at TIMESTAMPTZ;
+
+UPDATE verifications SET completed_at = verified_at
+WHERE completed_at IS NULL AND verified_at IS NOT NULL AND source_code IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_verifications_completed_at
+    ON verifications(completed_at) WHERE completed_at IS NOT NULL;
@@ -0,0 +1,8 @@
+-- Sandboxed verification builds
+--
+-- Record the content digest of the toolchain image each verification build
+-- ran in (e.g. "registry/soroban-build@sha256:…"), so a result can be
+-- reproduced with exactly the same compiler. NULL for builds that predate the
+-- sandbox or ran on the host.
+
+ALTER TABLE verifications ADD COLUMN IF NOT EXISTS toolchain_digest TEXT;
@@ -0,0 +1,25 @@
+-- Pinned toolchain catalog
+--
+-- Approved builder images for verification. Each entry pins a digest-addressed
+-- image together with the Rust and soroban-sdk versions it builds with and the
+-- cargo target/flags, so a verification that names a toolchain can be rebuilt
+-- identically later. Entries are deprecated rather than deleted: old
+-- verifications keep pointing at them and they stay selectable for
+-- reproducing old builds, but are hidden from the catalog by default.
+
+CREATE TABLE IF NOT EXISTS toolchains (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(64) NOT NULL UNIQUE,
+    rust_version VARCHAR(32) NOT NULL,
+    soroban_sdk_version VARCHAR(32) NOT NULL,
+    image TEXT NOT NULL,
+    target VARCHAR(64) NOT NULL DEFAULT 'wasm32-unknown-unknown',
+    cargo_flags TEXT[] NOT NULL DEFAULT '{}',
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    deprecated_at TIMESTAMPTZ,
+    CONSTRAINT toolchain_image_pinned CHECK (image LIKE '%@sha256:%')
+);
+
+ALTER TABLE verifications
+    ADD COLUMN IF NOT EXISTS toolchain_id UUID REFERENCES toolchains(id);
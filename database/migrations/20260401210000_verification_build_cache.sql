@@ -0,0 +1,29 @@
+-- Verification build cache
+--
+-- A verification build is fully determined by its inputs (source, build
+-- params, SDK version / catalog toolchain, hashed into source_digest) and the
+-- toolchain image it runs in. Each successful build's WASM hash is stored
+-- under that pair so repeat requests -- for the same contract or any other
+-- registry entry built from the same source -- are answered without
+-- rebuilding. Only sandboxed builds, whose toolchain digest is known, are
+-- cached.
+
+CREATE TABLE IF NOT EXISTS verification_build_cache (
+    source_digest VARCHAR(64) NOT NULL,
+    toolchain_digest TEXT NOT NULL,
+    compiled_wasm_hash VARCHAR(64) NOT NULL,
+    -- Verification whose build populated the entry
+    verification_id UUID REFERENCES verifications(id) ON DELETE SET NULL,
+    hits INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_hit_at TIMESTAMPTZ,
+    PRIMARY KEY (source_digest, toolchain_digest)
+);
+
+CREATE INDEX IF NOT EXISTS idx_verification_build_cache_wasm
+    ON verification_build_cache(compiled_wasm_hash);
+
+ALTER TABLE verifications ADD COLUMN IF NOT EXISTS source_digest VARCHAR(64);
+-- Set when the result reused another verification's build
+ALTER TABLE verifications
+    ADD COLUMN IF NOT EXISTS reused_build_from UUID REFERENCES verifications(id) ON DELETE SET NULL;
@@ -0,0 +1,31 @@
+-- Differential re-verification when advisories land
+--
+-- An advisory may name a build input (`soroban-sdk`, or `rust` for catalog
+-- toolchains) whose `affected_versions` are vulnerable or miscompile.
+-- Publishing one re-evaluates verified contracts whose latest build used an
+-- affected version: they move to the new `stale` verification status until
+-- rebuilt, and their publishers are notified.
+
+ALTER TYPE verification_status ADD VALUE IF NOT EXISTS 'stale';
+
+ALTER TABLE security_advisories ADD COLUMN IF NOT EXISTS affected_package TEXT;
+
+ALTER TABLE security_advisories DROP CONSTRAINT IF EXISTS security_advisory_affected_package;
+ALTER TABLE security_advisories ADD CONSTRAINT security_advisory_affected_package
+    CHECK (affected_package IS NULL OR affected_package IN ('soroban-sdk', 'rust'));
+
+-- Notices sent to publishers whose contracts an advisory made stale
+CREATE TABLE IF NOT EXISTS advisory_notification_log (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    advisory_id UUID NOT NULL REFERENCES security_advisories(id) ON DELETE CASCADE,
+    contract_id UUID REFERENCES contracts(id) ON DELETE SET NULL,
+    recipient TEXT NOT NULL,   -- publisher stellar address
+    channel TEXT NOT NULL DEFAULT 'in_app',
+    message TEXT NOT NULL,
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_advisory_notification_log_advisory
+    ON advisory_notification_log(advisory_id);
+CREATE INDEX IF NOT EXISTS idx_advisory_notification_log_recipient
+    ON advisory_notification_log(recipient);
@@ -0,0 +1,12 @@
+-- Source bundle mirroring
+--
+-- When a verification succeeds, the exact project tree it compiled is
+-- archived as a tar.zst in source storage and registered as the 'bundle'
+-- source of the matching contract version, so verified source stays
+-- available after the upstream repository disappears. verification_id links
+-- the bundle to the build it reproduces.
+
+ALTER TYPE source_format_type ADD VALUE IF NOT EXISTS 'bundle';
+
+ALTER TABLE contract_sources
+    ADD COLUMN IF NOT EXISTS verification_id UUID REFERENCES verifications(id) ON DELETE SET NULL;
@@ -0,0 +1,28 @@
+-- CLI release channel
+--
+-- Releases of the soroban-registry CLI offered to `self-update`. Each
+-- artifact is the binary for one target triple, with its SHA-256 and an
+-- Ed25519 signature over the binary made with the release signing key the
+-- CLI embeds; the CLI refuses artifacts whose signature does not verify.
+-- The beta channel also sees stable releases. Yanked releases are no longer
+-- offered.
+
+CREATE TABLE IF NOT EXISTS cli_releases (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    version VARCHAR(64) NOT NULL UNIQUE,
+    channel VARCHAR(16) NOT NULL DEFAULT 'stable'
+        CHECK (channel IN ('stable', 'beta')),
+    notes TEXT,
+    published_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    yanked_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS cli_release_artifacts (
+    release_id UUID NOT NULL REFERENCES cli_releases(id) ON DELETE CASCADE,
+    target VARCHAR(64) NOT NULL,
+    url TEXT NOT NULL,
+    sha256 VARCHAR(64) NOT NULL,
+    -- base64 Ed25519 signature over the binary
+    signature TEXT NOT NULL,
+    PRIMARY KEY (release_id, target)
+);
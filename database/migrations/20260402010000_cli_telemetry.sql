@@ -0,0 +1,25 @@
+-- Opt-in CLI usage telemetry
+--
+-- CLIs that have run `soroban-registry telemetry on` report which commands
+-- ran, how long they took and whether they succeeded -- never arguments,
+-- paths, addresses or tokens. install_id is a random UUID generated when
+-- telemetry is turned on and is not linked to any account; request IPs are
+-- not stored.
+
+CREATE TABLE IF NOT EXISTS cli_telemetry_events (
+    id BIGSERIAL PRIMARY KEY,
+    install_id UUID NOT NULL,
+    cli_version VARCHAR(32) NOT NULL,
+    os VARCHAR(16) NOT NULL,
+    arch VARCHAR(16) NOT NULL,
+    command VARCHAR(64) NOT NULL,
+    duration_ms BIGINT NOT NULL CHECK (duration_ms >= 0),
+    success BOOLEAN NOT NULL,
+    occurred_at TIMESTAMPTZ NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_cli_telemetry_events_occurred
+    ON cli_telemetry_events(occurred_at DESC);
+CREATE INDEX IF NOT EXISTS idx_cli_telemetry_events_command
+    ON cli_telemetry_events(command, occurred_at DESC);
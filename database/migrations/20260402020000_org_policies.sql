@@ -0,0 +1,13 @@
+-- Organization publish policies
+--
+-- One rule set per organization: allowed licenses, a WASM size limit, and
+-- whether an ABI snapshot and a clean clippy run are required. The rules are
+-- stored as the JSON the shared policy evaluator reads, so the registry and
+-- `soroban-registry policy check` judge a project the same way.
+
+CREATE TABLE IF NOT EXISTS org_policies (
+    organization_id UUID PRIMARY KEY REFERENCES organizations(id) ON DELETE CASCADE,
+    rules JSONB NOT NULL DEFAULT '{}'::jsonb,
+    updated_by UUID REFERENCES publishers(id) ON DELETE SET NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
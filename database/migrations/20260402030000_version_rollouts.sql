@@ -0,0 +1,26 @@
+-- Staged rollouts of contract versions
+--
+-- A publisher can make a new version the default for a percentage of
+-- resolvers before everyone gets it. GET /api/contracts/:id/versions/resolve
+-- hashes the contract, version and consumer into a bucket 0-99, so a consumer
+-- keeps its answer while the percentage only grows. At most one rollout per
+-- contract is active; reaching 100% completes it and makes the version the
+-- contract's current_version.
+
+CREATE TABLE IF NOT EXISTS contract_version_rollouts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    version TEXT NOT NULL,
+    percentage INT NOT NULL CHECK (percentage BETWEEN 0 AND 100),
+    status TEXT NOT NULL DEFAULT 'active'
+        CHECK (status IN ('active', 'completed', 'aborted')),
+    started_by TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMPTZ
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_contract_version_rollouts_active
+    ON contract_version_rollouts(contract_id) WHERE status = 'active';
+CREATE INDEX IF NOT EXISTS idx_contract_version_rollouts_contract
+    ON contract_version_rollouts(contract_id, created_at DESC);
@@ -0,0 +1,38 @@
+-- Registered consumers of contracts
+--
+-- Downstream apps register as consumers of a contract with the version they
+-- pin. Deprecations, security patches for the pinned build and releases that
+-- break the pin then record a notification for exactly the consumers they
+-- affect, delivered to the consumer's webhook when it has one.
+
+CREATE TABLE IF NOT EXISTS contract_consumers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    owner_address TEXT NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    pinned_version TEXT NOT NULL,
+    webhook_url TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_id, owner_address, name)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_consumers_pin
+    ON contract_consumers(contract_id, pinned_version);
+CREATE INDEX IF NOT EXISTS idx_contract_consumers_owner
+    ON contract_consumers(owner_address);
+
+CREATE TABLE IF NOT EXISTS consumer_notifications (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    consumer_id UUID NOT NULL REFERENCES contract_consumers(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (kind IN ('deprecation', 'patch', 'breaking_release')),
+    -- The version the consumer had pinned when notified
+    pinned_version TEXT NOT NULL,
+    message TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    delivered_at TIMESTAMPTZ,
+    delivery_error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_consumer_notifications_consumer
+    ON consumer_notifications(consumer_id, created_at DESC);
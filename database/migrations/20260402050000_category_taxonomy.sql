@@ -0,0 +1,97 @@
+-- Hierarchical category taxonomy
+--
+-- Categories get a materialised `path` ("defi", "defi/amm", "nft/collection")
+-- built from their slugs up the parent chain, and contracts.category becomes
+-- a foreign key to that path instead of free text. Renaming or re-parenting
+-- a category rewrites the paths below it and ON UPDATE CASCADE carries the
+-- change into contracts. Searching by a parent path matches its whole subtree.
+--
+-- Existing values are migrated by matching a category's name, slug or path
+-- case-insensitively; anything unrecognised is filed under "other".
+
+-- Names and slugs only need to be unique among siblings now; the path
+-- constraint added below enforces that.
+ALTER TABLE contract_categories
+    DROP CONSTRAINT IF EXISTS uq_contract_categories_name,
+    DROP CONSTRAINT IF EXISTS uq_contract_categories_slug,
+    ADD COLUMN IF NOT EXISTS path VARCHAR(100);
+
+-- ── Seed the taxonomy ────────────────────────────────────────────────────────
+-- The original flat defaults (DEX, Lending, Oracle, Bridge) are folded into
+-- the tree rather than duplicated; Token and Other stay top-level.
+
+INSERT INTO contract_categories (name, slug, description, is_default)
+SELECT v.name, v.slug, v.description, TRUE
+  FROM (VALUES
+        ('DeFi',           'defi',       'Decentralised finance protocols'),
+        ('NFT',            'nft',        'Non-fungible token contracts'),
+        ('Infrastructure', 'infra',      'Oracles, bridges and other shared services'),
+        ('Governance',     'governance', 'DAOs, voting and treasury contracts'),
+        ('Payments',       'payments',   'Payment, streaming and escrow contracts'),
+        ('Identity',       'identity',   'Identity, credential and attestation contracts'),
+        ('Gaming',         'gaming',     'Game logic and in-game asset contracts')
+       ) AS v(name, slug, description)
+ WHERE NOT EXISTS (
+       SELECT 1 FROM contract_categories c WHERE c.parent_id IS NULL AND c.slug = v.slug
+ );
+
+UPDATE contract_categories child
+   SET parent_id = parent.id
+  FROM contract_categories parent
+ WHERE parent.parent_id IS NULL
+   AND child.is_default
+   AND (parent.slug, child.slug) IN (
+       ('defi', 'dex'), ('defi', 'lending'), ('infra', 'oracle'), ('infra', 'bridge')
+   );
+
+INSERT INTO contract_categories (name, slug, description, parent_id, is_default)
+SELECT v.name, v.slug, v.description, p.id, TRUE
+  FROM (VALUES
+        ('defi', 'AMM',         'amm',         'Automated market makers and liquidity pools'),
+        ('defi', 'Stablecoin',  'stablecoin',  'Stablecoin issuance and peg mechanisms'),
+        ('nft',  'Collection',  'collection',  'NFT collections and minting contracts'),
+        ('nft',  'Marketplace', 'marketplace', 'NFT trading and auction contracts')
+       ) AS v(parent_slug, name, slug, description)
+  JOIN contract_categories p ON p.slug = v.parent_slug AND p.parent_id IS NULL
+ WHERE NOT EXISTS (
+       SELECT 1 FROM contract_categories c WHERE c.parent_id = p.id AND c.slug = v.slug
+ );
+
+-- ── Materialise paths ────────────────────────────────────────────────────────
+
+WITH RECURSIVE tree AS (
+    SELECT id, slug::TEXT AS path
+      FROM contract_categories
+     WHERE parent_id IS NULL
+    UNION ALL
+    SELECT c.id, tree.path || '/' || c.slug
+      FROM contract_categories c
+      JOIN tree ON c.parent_id = tree.id
+)
+UPDATE contract_categories cc
+   SET path = tree.path
+  FROM tree
+ WHERE cc.id = tree.id;
+
+ALTER TABLE contract_categories
+    ALTER COLUMN path SET NOT NULL,
+    ADD CONSTRAINT uq_contract_categories_path UNIQUE (path);
+
+-- ── Migrate contracts.category ───────────────────────────────────────────────
+-- An exact path wins; otherwise a top-level match beats a nested one.
+
+UPDATE contracts c
+   SET category = COALESCE(
+       (SELECT cc.path
+          FROM contract_categories cc
+         WHERE lower(trim(c.category)) IN (cc.path, cc.slug, lower(cc.name))
+         ORDER BY (lower(trim(c.category)) = cc.path) DESC, (cc.parent_id IS NULL) DESC
+         LIMIT 1),
+       'other'
+   )
+ WHERE c.category IS NOT NULL;
+
+ALTER TABLE contracts
+    ADD CONSTRAINT fk_contracts_category
+    FOREIGN KEY (category) REFERENCES contract_categories(path)
+    ON UPDATE CASCADE ON DELETE SET NULL;
@@ -0,0 +1,48 @@
+-- Contract badges and the attestations behind them
+--
+-- Admins recognise publishers (auditors, the registry team, ...) as
+-- attestation authorities for a set of badge kinds. An authority grants a
+-- badge by signing an attestation over one build of a contract (see
+-- shared::attestation); the signature and statement are kept as provenance.
+-- A badge is shown while it is neither revoked nor expired and its
+-- authority is still recognised.
+
+CREATE TABLE IF NOT EXISTS attestation_authorities (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    publisher_id UUID NOT NULL UNIQUE REFERENCES publishers(id) ON DELETE CASCADE,
+    name VARCHAR(100) NOT NULL,
+    website TEXT,
+    badge_kinds TEXT[] NOT NULL CHECK (
+        cardinality(badge_kinds) > 0
+        AND badge_kinds <@ ARRAY['audited-by', 'verified-source', 'interface-conformant', 'team-verified']
+    ),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE TABLE IF NOT EXISTS contract_badges (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    authority_id UUID NOT NULL REFERENCES attestation_authorities(id) ON DELETE CASCADE,
+    kind TEXT NOT NULL CHECK (
+        kind IN ('audited-by', 'verified-source', 'interface-conformant', 'team-verified')
+    ),
+    -- The build the attestation covers
+    wasm_hash VARCHAR(64) NOT NULL,
+    report_url TEXT,
+    issued_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ,
+    -- Hex ed25519 signature of the attestation message by the authority
+    signature TEXT NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMPTZ,
+    revocation_reason TEXT
+);
+
+-- One live badge of each kind per authority; a new attestation replaces it.
+CREATE UNIQUE INDEX IF NOT EXISTS uq_contract_badges_active
+    ON contract_badges(contract_id, kind, authority_id)
+    WHERE revoked_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contract_badges_contract
+    ON contract_badges(contract_id);
@@ -0,0 +1,32 @@
+-- Audit reports attached to contract versions
+--
+-- Auditors (attestation authorities recognised for the `audited-by` badge)
+-- attach their report for a specific version: the PDF or Markdown document,
+-- kept in source storage, plus a findings summary with counts per severity
+-- that the registry can show without opening the report.
+
+CREATE TABLE IF NOT EXISTS contract_audits (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_version_id UUID NOT NULL REFERENCES contract_versions(id) ON DELETE CASCADE,
+    authority_id UUID NOT NULL REFERENCES attestation_authorities(id) ON DELETE CASCADE,
+    title VARCHAR(200) NOT NULL,
+    summary TEXT,
+    report_format TEXT NOT NULL CHECK (report_format IN ('pdf', 'markdown')),
+    storage_backend TEXT NOT NULL,
+    storage_key TEXT NOT NULL,
+    report_sha256 VARCHAR(64) NOT NULL,
+    report_size BIGINT NOT NULL,
+    critical_count INTEGER NOT NULL DEFAULT 0 CHECK (critical_count >= 0),
+    high_count INTEGER NOT NULL DEFAULT 0 CHECK (high_count >= 0),
+    medium_count INTEGER NOT NULL DEFAULT 0 CHECK (medium_count >= 0),
+    low_count INTEGER NOT NULL DEFAULT 0 CHECK (low_count >= 0),
+    informational_count INTEGER NOT NULL DEFAULT 0 CHECK (informational_count >= 0),
+    -- When the audit was completed, as stated by the auditor
+    audited_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_version_id, authority_id, report_sha256)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_audits_contract
+    ON contract_audits(contract_id, audited_at DESC);
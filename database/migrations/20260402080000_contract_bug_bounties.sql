@@ -0,0 +1,21 @@
+-- Bug bounty programs for contracts
+--
+-- A publisher can record where whitehats should report vulnerabilities in
+-- a contract: the program page, what is in scope, the largest payout and a
+-- disclosure contact. The registry serves it as JSON and as a security.txt
+-- (RFC 9116) document.
+
+CREATE TABLE IF NOT EXISTS contract_bug_bounties (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    program_url TEXT NOT NULL,
+    -- mailto:, https: or tel: URI
+    contact TEXT NOT NULL,
+    scope TEXT,
+    max_payout BIGINT CHECK (max_payout IS NULL OR max_payout > 0),
+    payout_currency VARCHAR(10) NOT NULL DEFAULT 'USD',
+    -- Optional disclosure policy page, if separate from the program page
+    policy_url TEXT,
+    expires_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,23 @@
+-- Coordinated disclosure inbox
+--
+-- Researchers submit vulnerability reports for a contract through the
+-- registry. Each report is sealed on arrival to the publisher's Stellar
+-- account key (X25519 + AES-256-GCM, see shared::disclosure), so only
+-- ciphertext is stored; the publisher opens reports locally with the CLI.
+
+CREATE TABLE IF NOT EXISTS disclosure_reports (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    -- Stellar address the report was sealed to; only its key can open it
+    recipient_address VARCHAR(56) NOT NULL,
+    ephemeral_public VARCHAR(64) NOT NULL,
+    nonce VARCHAR(24) NOT NULL,
+    ciphertext BYTEA NOT NULL,
+    status TEXT NOT NULL DEFAULT 'new'
+        CHECK (status IN ('new', 'acknowledged', 'resolved')),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_disclosure_reports_contract
+    ON disclosure_reports(contract_id, created_at DESC);
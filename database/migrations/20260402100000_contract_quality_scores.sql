@@ -0,0 +1,37 @@
+-- Contract quality score
+--
+-- A 0-100 score combining verified source, documentation coverage of the
+-- ABI, ABI stability across versions, a `tested` attestation and audit
+-- presence. The `quality_score` background job computes it; the score is
+-- kept on `contracts` for sorting and its per-component breakdown in
+-- `contract_quality_scores`. NULL until the job first scores a contract.
+
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS quality_score INTEGER
+        CHECK (quality_score IS NULL OR quality_score BETWEEN 0 AND 100);
+
+CREATE INDEX IF NOT EXISTS idx_contracts_quality_score
+    ON contracts(quality_score DESC NULLS LAST);
+
+CREATE TABLE IF NOT EXISTS contract_quality_scores (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    score INTEGER NOT NULL CHECK (score BETWEEN 0 AND 100),
+    -- [{ "name", "weight", "points", "detail" }, ...]
+    components JSONB NOT NULL,
+    computed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- The test attestation component is granted through a new badge kind.
+ALTER TABLE attestation_authorities
+    DROP CONSTRAINT IF EXISTS attestation_authorities_badge_kinds_check;
+ALTER TABLE attestation_authorities
+    ADD CONSTRAINT attestation_authorities_badge_kinds_check CHECK (
+        cardinality(badge_kinds) > 0
+        AND badge_kinds <@ ARRAY['audited-by', 'verified-source', 'interface-conformant', 'team-verified', 'tested']
+    );
+
+ALTER TABLE contract_badges DROP CONSTRAINT IF EXISTS contract_badges_kind_check;
+ALTER TABLE contract_badges
+    ADD CONSTRAINT contract_badges_kind_check CHECK (
+        kind IN ('audited-by', 'verified-source', 'interface-conformant', 'team-verified', 'tested')
+    );
@@ -0,0 +1,29 @@
+-- Documentation coverage per contract version
+--
+-- Fraction (0-1) of the version's spec functions and user-defined types
+-- (structs, unions, enums, error enums) that carry a doc string, measured
+-- when the ABI is extracted at publish time. NULL when the spec has nothing
+-- to document. Feeds the documentation component of the quality score.
+
+ALTER TABLE contract_versions
+    ADD COLUMN IF NOT EXISTS docs_coverage DOUBLE PRECISION
+        CHECK (docs_coverage IS NULL OR docs_coverage BETWEEN 0 AND 1);
+
+-- Backfill from the ABIs already stored.
+UPDATE contract_versions cv
+SET docs_coverage = measured.coverage
+FROM (
+    SELECT ca.contract_id,
+           ca.version,
+           AVG(CASE WHEN btrim(COALESCE(item->>'doc', '')) <> '' THEN 1.0 ELSE 0.0 END)
+               AS coverage
+    FROM contract_abis ca,
+         jsonb_array_elements(
+             CASE WHEN jsonb_typeof(ca.abi) = 'array' THEN ca.abi ELSE '[]'::jsonb END
+         ) AS item
+    WHERE item->>'type' IN ('function', 'struct', 'union', 'enum', 'error_enum')
+    GROUP BY ca.contract_id, ca.version
+) measured
+WHERE cv.contract_id = measured.contract_id
+  AND cv.version = measured.version
+  AND cv.docs_coverage IS NULL;
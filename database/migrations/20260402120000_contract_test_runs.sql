@@ -0,0 +1,29 @@
+-- Signed CI test results
+--
+-- A publisher's CI posts a summary of a test run (suite, pass/fail/skip
+-- counts, optional coverage) for one build of a contract, signed with the
+-- publisher's Stellar key over the `shared::test_report` statement. Runs
+-- are attached to the version with that build and feed the test component
+-- of the quality score.
+
+CREATE TABLE IF NOT EXISTS contract_test_runs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_version_id UUID NOT NULL REFERENCES contract_versions(id) ON DELETE CASCADE,
+    wasm_hash VARCHAR(64) NOT NULL,
+    suite VARCHAR(100) NOT NULL,
+    passed INTEGER NOT NULL CHECK (passed >= 0),
+    failed INTEGER NOT NULL CHECK (failed >= 0),
+    skipped INTEGER NOT NULL DEFAULT 0 CHECK (skipped >= 0),
+    -- Line coverage in percent, rounded to two decimals as signed
+    coverage DOUBLE PRECISION CHECK (coverage IS NULL OR coverage BETWEEN 0 AND 100),
+    -- When CI ran the suite, as signed
+    run_at TIMESTAMPTZ NOT NULL,
+    signer_address VARCHAR(56) NOT NULL,
+    signature VARCHAR(128) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_version_id, suite, run_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_test_runs_build
+    ON contract_test_runs(contract_id, wasm_hash, suite, run_at DESC);
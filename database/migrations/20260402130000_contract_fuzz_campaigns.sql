@@ -0,0 +1,27 @@
+-- Signed fuzzing campaign summaries
+--
+-- A publisher's CI posts a summary of a fuzzing campaign (engine, targets,
+-- executions, duration, crashes found) for one build of a contract, signed
+-- with the publisher's Stellar key over the `shared::fuzz_report` statement.
+-- Campaigns are attached to the version with that build.
+
+CREATE TABLE IF NOT EXISTS contract_fuzz_campaigns (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    contract_version_id UUID NOT NULL REFERENCES contract_versions(id) ON DELETE CASCADE,
+    wasm_hash VARCHAR(64) NOT NULL,
+    engine VARCHAR(50) NOT NULL,
+    targets INTEGER NOT NULL CHECK (targets > 0),
+    executions BIGINT NOT NULL CHECK (executions > 0),
+    duration_secs BIGINT NOT NULL CHECK (duration_secs >= 0),
+    crashes INTEGER NOT NULL CHECK (crashes >= 0),
+    -- When the campaign ended, as signed
+    finished_at TIMESTAMPTZ NOT NULL,
+    signer_address VARCHAR(56) NOT NULL,
+    signature VARCHAR(128) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_version_id, engine, finished_at)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_fuzz_campaigns_contract
+    ON contract_fuzz_campaigns(contract_id, finished_at DESC);
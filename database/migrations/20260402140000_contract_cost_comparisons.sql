@@ -0,0 +1,19 @@
+-- Gas regression detection between versions
+--
+-- Per-function benchmarks of each version are compared with those of the
+-- previous version (SemVer order) by the `cost_regressions` job. One row per
+-- compared version; `regressions` lists the functions whose CPU, memory or fee
+-- estimate grew beyond the threshold in force when it was compared.
+
+CREATE TABLE IF NOT EXISTS contract_cost_comparisons (
+    contract_version_id UUID PRIMARY KEY REFERENCES contract_versions(id) ON DELETE CASCADE,
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    baseline_version_id UUID NOT NULL REFERENCES contract_versions(id) ON DELETE CASCADE,
+    threshold_percent DOUBLE PRECISION NOT NULL,
+    -- [{ function_name, metric, baseline, current, increase_percent }]
+    regressions JSONB NOT NULL DEFAULT '[]'::jsonb,
+    compared_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_cost_comparisons_contract
+    ON contract_cost_comparisons(contract_id);
@@ -0,0 +1,9 @@
+-- Multisig policy timelocks
+--
+-- An approved deployment proposal under a policy with a timelock cannot be
+-- executed until `timelock_seconds` have passed since it was approved, giving
+-- signers and watchers a window to react before the change lands.
+
+ALTER TABLE multisig_policies
+    ADD COLUMN IF NOT EXISTS timelock_seconds INTEGER NOT NULL DEFAULT 0
+        CHECK (timelock_seconds >= 0);
@@ -0,0 +1,17 @@
+-- Deployment proposal discussion threads
+--
+-- Signers and the proposer record rationale and questions on a proposal next
+-- to its approvals. A comment may reply to another comment on the same
+-- proposal, forming a thread.
+
+CREATE TABLE IF NOT EXISTS proposal_comments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    proposal_id UUID NOT NULL REFERENCES deploy_proposals(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES proposal_comments(id) ON DELETE CASCADE,
+    author_address VARCHAR(56) NOT NULL,
+    body TEXT NOT NULL CHECK (length(body) > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_proposal_comments_proposal
+    ON proposal_comments(proposal_id, created_at);
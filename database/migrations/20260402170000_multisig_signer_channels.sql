@@ -0,0 +1,12 @@
+-- Multisig signer notification channels
+--
+-- Signers may register a webhook that is called whenever a deployment
+-- proposal is waiting on their decision. Notifications are always recorded
+-- in `multisig_approval_notifications`, which doubles as the signer's inbox.
+
+CREATE TABLE IF NOT EXISTS multisig_signer_channels (
+    signer_address VARCHAR(56) PRIMARY KEY,
+    webhook_url TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,25 @@
+-- Delegated multisig signing
+--
+-- A policy signer may hand their signing right to another address for a
+-- bounded window (e.g. vacation coverage). While the delegation is active
+-- the delegate signs on the delegator's behalf: the signature occupies the
+-- delegator's seat and records who actually signed.
+
+CREATE TABLE IF NOT EXISTS multisig_signer_delegations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    policy_id UUID NOT NULL REFERENCES multisig_policies(id) ON DELETE CASCADE,
+    delegator_address VARCHAR(56) NOT NULL,
+    delegate_address VARCHAR(56) NOT NULL,
+    starts_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (expires_at > starts_at),
+    CHECK (delegator_address <> delegate_address)
+);
+
+CREATE INDEX IF NOT EXISTS idx_multisig_delegations_policy_delegate
+    ON multisig_signer_delegations(policy_id, delegate_address, expires_at);
+
+ALTER TABLE proposal_signatures
+    ADD COLUMN IF NOT EXISTS delegate_address VARCHAR(56);
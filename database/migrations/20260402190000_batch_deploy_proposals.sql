@@ -0,0 +1,32 @@
+-- Batch deployment proposals
+--
+-- A proposal may carry an ordered list of steps, each deploying or migrating
+-- one contract. Steps are executed in order once the proposal is approved;
+-- when one fails, the steps after it are skipped and the executed ones are
+-- rolled back in reverse order. A migrate step records the build it replaces
+-- so the rollback knows what to restore.
+
+ALTER TYPE proposal_status ADD VALUE IF NOT EXISTS 'executing';
+ALTER TYPE proposal_status ADD VALUE IF NOT EXISTS 'rolling_back';
+ALTER TYPE proposal_status ADD VALUE IF NOT EXISTS 'rolled_back';
+ALTER TYPE proposal_status ADD VALUE IF NOT EXISTS 'failed';
+
+CREATE TABLE IF NOT EXISTS deploy_proposal_steps (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    proposal_id UUID NOT NULL REFERENCES deploy_proposals(id) ON DELETE CASCADE,
+    step_index INT NOT NULL CHECK (step_index >= 0),
+    action VARCHAR(16) NOT NULL CHECK (action IN ('deploy', 'migrate')),
+    contract_id VARCHAR(56) NOT NULL,
+    wasm_hash VARCHAR(64) NOT NULL,
+    rollback_wasm_hash VARCHAR(64),
+    description TEXT,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'executed', 'failed', 'skipped', 'rollback_pending', 'rolled_back')),
+    tx_hash VARCHAR(64),
+    rollback_tx_hash VARCHAR(64),
+    error TEXT,
+    executed_at TIMESTAMPTZ,
+    rolled_back_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (proposal_id, step_index)
+);
@@ -0,0 +1,17 @@
+-- Governance actions behind multisig approval
+--
+-- Besides deployments, a proposal may carry another privileged registry
+-- action with a typed payload: applying a security patch, deprecating a
+-- contract, transferring its ownership, or amending the approving policy.
+-- contract_id holds the action's subject (the policy id for amend_policy);
+-- actions without a build or network leave those columns empty.
+
+ALTER TABLE deploy_proposals
+    ADD COLUMN IF NOT EXISTS action VARCHAR(32) NOT NULL DEFAULT 'deploy'
+        CHECK (action IN ('deploy', 'apply_patch', 'deprecate_contract', 'transfer_ownership', 'amend_policy')),
+    ADD COLUMN IF NOT EXISTS action_payload JSONB;
+
+ALTER TABLE deploy_proposals ALTER COLUMN wasm_hash DROP NOT NULL;
+ALTER TABLE deploy_proposals ALTER COLUMN network DROP NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_deploy_proposals_action ON deploy_proposals(action);
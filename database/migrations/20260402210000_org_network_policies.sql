@@ -0,0 +1,28 @@
+-- Per-network deployment policies
+--
+-- An organization can require that every deployment or migration of its
+-- contracts on a network goes through a multisig policy. The registry
+-- enforces this itself: a gated publish or migration opens a deploy proposal
+-- instead of running, and is only let through once a matching proposal has
+-- been executed. `fulfilled_at` marks the proposal as spent so one approval
+-- clears exactly one operation.
+
+CREATE TABLE IF NOT EXISTS org_network_policies (
+    organization_id UUID NOT NULL REFERENCES organizations(id) ON DELETE CASCADE,
+    network network_type NOT NULL,
+    policy_id UUID NOT NULL REFERENCES multisig_policies(id) ON DELETE CASCADE,
+    updated_by UUID REFERENCES publishers(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organization_id, network)
+);
+
+CREATE INDEX IF NOT EXISTS idx_org_network_policies_policy
+    ON org_network_policies(policy_id);
+
+ALTER TABLE deploy_proposals
+    ADD COLUMN IF NOT EXISTS fulfilled_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_deploy_proposals_unfulfilled
+    ON deploy_proposals(policy_id, contract_id, network)
+    WHERE status = 'executed' AND fulfilled_at IS NULL;
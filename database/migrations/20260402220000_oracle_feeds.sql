@@ -0,0 +1,29 @@
+-- Oracle feed freshness
+--
+-- Publishers of oracle contracts (the `infra/oracle` category and below)
+-- declare how often the feed updates. The oracle monitor copies the newest
+-- matching ingested event into `last_update_at` and sets `stale_since` once
+-- the feed misses its cadence plus grace; subscribers following
+-- `oracle_stale` are notified once per stale spell.
+
+ALTER TYPE notification_type ADD VALUE IF NOT EXISTS 'oracle_stale';
+
+CREATE TABLE IF NOT EXISTS oracle_feeds (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    update_interval_seconds INTEGER NOT NULL CHECK (update_interval_seconds > 0),
+    grace_seconds INTEGER NOT NULL DEFAULT 0 CHECK (grace_seconds >= 0),
+    -- Event topics that count as an update; empty means any event
+    update_topics TEXT[] NOT NULL DEFAULT '{}',
+    last_update_at TIMESTAMPTZ,
+    last_update_ledger BIGINT,
+    stale_since TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_oracle_feeds_stale
+    ON oracle_feeds (stale_since)
+    WHERE stale_since IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contract_events_contract_timestamp
+    ON contract_events (contract_id, timestamp DESC);
@@ -0,0 +1,45 @@
+-- NFT collection index
+--
+-- The NFT indexer follows contract_events in ingestion order (tracked in
+-- `nft_index_cursor`) and folds `mint`, `transfer` and `burn` events that
+-- carry a `token_id` into one row per token: its current owner, the URI it
+-- was minted with and when it last moved. `last_ledger` guards against
+-- events ingested out of ledger order overwriting newer state.
+
+CREATE TABLE IF NOT EXISTS nft_tokens (
+    -- On-chain contract address, as in contract_events
+    contract_id TEXT NOT NULL,
+    network network_type NOT NULL,
+    token_id TEXT NOT NULL,
+    -- NULL once burned
+    owner TEXT,
+    token_uri TEXT,
+    minted_at TIMESTAMPTZ,
+    minted_ledger BIGINT,
+    last_transfer_at TIMESTAMPTZ,
+    last_ledger BIGINT NOT NULL,
+    transfer_count INTEGER NOT NULL DEFAULT 0,
+    burned_at TIMESTAMPTZ,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (contract_id, network, token_id)
+);
+
+-- Collection pages are ordered numerically for numeric ids: by length, then text
+CREATE INDEX IF NOT EXISTS idx_nft_tokens_order
+    ON nft_tokens (contract_id, network, length(token_id), token_id);
+
+CREATE INDEX IF NOT EXISTS idx_nft_tokens_owner
+    ON nft_tokens (contract_id, network, owner)
+    WHERE owner IS NOT NULL;
+
+-- Position of the indexer in contract_events (ingestion order). It starts at
+-- the beginning so existing history is indexed.
+CREATE TABLE IF NOT EXISTS nft_index_cursor (
+    id SMALLINT PRIMARY KEY CHECK (id = 1),
+    last_created_at TIMESTAMPTZ NOT NULL,
+    last_event_id UUID NOT NULL
+);
+
+INSERT INTO nft_index_cursor (id, last_created_at, last_event_id)
+VALUES (1, '1970-01-01T00:00:00Z', '00000000-0000-0000-0000-000000000000')
+ON CONFLICT (id) DO NOTHING;
@@ -0,0 +1,28 @@
+-- AMM pool state
+--
+-- The DeFi refresher reads the instance storage of contracts in the
+-- `defi/amm` category over Soroban RPC and records each pool's tokens,
+-- reserves and fee here, with a USD TVL estimate used to sort search
+-- results by liquidity. A failed read keeps the last good state and records
+-- why in `last_error`.
+
+CREATE TABLE IF NOT EXISTS defi_pools (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    -- Token contract addresses; NULL until the pool has been read once
+    token_a TEXT,
+    token_b TEXT,
+    -- Raw i128 reserves in the tokens' smallest units
+    reserve_a NUMERIC(39, 0),
+    reserve_b NUMERIC(39, 0),
+    fee_bps INTEGER CHECK (fee_bps BETWEEN 0 AND 10000),
+    -- NULL when neither token could be priced
+    tvl_usd DOUBLE PRECISION,
+    -- Last successful read
+    refreshed_at TIMESTAMPTZ,
+    -- Last attempt, successful or not
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_defi_pools_tvl
+    ON defi_pools (tvl_usd DESC NULLS LAST);
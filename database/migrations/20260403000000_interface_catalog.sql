@@ -0,0 +1,31 @@
+-- Interface catalog
+--
+-- Admin-managed descriptions of known contract interfaces (SEP-41 tokens,
+-- NFT standards, …), each a set of function signatures. Published ABIs are
+-- matched against the catalog, so new ecosystem standards are recognised
+-- without changes to the detection code.
+
+CREATE TABLE IF NOT EXISTS contract_interfaces (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name TEXT NOT NULL,
+    version TEXT NOT NULL,
+    description TEXT,
+    -- [{ "name": "transfer", "params": ["Address", "Address", "i128"], "returns": "void" }]
+    functions JSONB NOT NULL CHECK (jsonb_typeof(functions) = 'array'),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (name, version)
+);
+
+-- Interfaces each contract's newest ABI conforms to
+CREATE TABLE IF NOT EXISTS contract_interface_matches (
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    interface_id UUID NOT NULL REFERENCES contract_interfaces(id) ON DELETE CASCADE,
+    -- Version whose ABI was matched
+    contract_version TEXT NOT NULL,
+    matched_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (contract_id, interface_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_contract_interface_matches_interface
+    ON contract_interface_matches (interface_id);
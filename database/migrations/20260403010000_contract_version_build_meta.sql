@@ -0,0 +1,22 @@
+-- Build metadata per contract version
+--
+-- The soroban-sdk and rustc versions a version was built with (from the
+-- WASM's `contractmeta`) and the protocol it targets (from
+-- `contractenvmeta`). Filled in by the build metadata reader after the code
+-- is fetched over RPC; `build_meta_read_at` stays NULL until then. Backs the
+-- SDK compatibility matrix and the `sdk_version` / `protocol_version`
+-- search filters.
+
+ALTER TABLE contract_versions
+    ADD COLUMN IF NOT EXISTS sdk_version TEXT,
+    ADD COLUMN IF NOT EXISTS rustc_version TEXT,
+    ADD COLUMN IF NOT EXISTS protocol_version INTEGER,
+    ADD COLUMN IF NOT EXISTS build_meta_read_at TIMESTAMPTZ;
+
+CREATE INDEX IF NOT EXISTS idx_contract_versions_build_meta_unread
+    ON contract_versions (created_at DESC)
+    WHERE build_meta_read_at IS NULL;
+
+CREATE INDEX IF NOT EXISTS idx_contract_versions_sdk
+    ON contract_versions (sdk_version, protocol_version)
+    WHERE build_meta_read_at IS NOT NULL;
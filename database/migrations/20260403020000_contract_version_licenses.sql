@@ -0,0 +1,9 @@
+-- License and copyright per contract version
+--
+-- Declared by the publisher when the version is created: `license` is an
+-- SPDX expression (`Apache-2.0 OR MIT`), `copyright` a free-form notice.
+-- Consolidated across a contract's dependency tree by the compliance report.
+
+ALTER TABLE contract_versions
+    ADD COLUMN IF NOT EXISTS license TEXT,
+    ADD COLUMN IF NOT EXISTS copyright TEXT;
@@ -0,0 +1,26 @@
+-- Publisher email verification. An email counts as verified once the
+-- publisher proves they receive mail there, either by following the link or
+-- by entering the code from the challenge email (`publisher verify-email`).
+-- Email notification channels can only be enabled for a verified address.
+ALTER TABLE publishers
+    ADD COLUMN IF NOT EXISTS email_verified_at TIMESTAMPTZ;
+
+-- At most one outstanding challenge per publisher; requesting a new one
+-- replaces it. Only SHA-256 hashes of the code and link token are stored.
+CREATE TABLE IF NOT EXISTS publisher_email_challenges (
+    publisher_id UUID PRIMARY KEY REFERENCES publishers(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    code_hash VARCHAR(64) NOT NULL,
+    link_token_hash VARCHAR(64) NOT NULL UNIQUE,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    expires_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- No address has been verified yet, so nobody can keep email delivery on.
+ALTER TABLE user_preferences
+    ALTER COLUMN email_notifications_enabled SET DEFAULT FALSE;
+UPDATE user_preferences SET email_notifications_enabled = FALSE;
+UPDATE contract_subscriptions
+SET channels = array_remove(channels, 'email'::notification_channel)
+WHERE 'email'::notification_channel = ANY(channels);
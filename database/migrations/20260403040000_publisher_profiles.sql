@@ -0,0 +1,7 @@
+-- Publisher display metadata, returned with contracts so listings can show
+-- who maintains them. `avatar_url` overrides the Gravatar image derived
+-- from a verified email.
+ALTER TABLE publishers
+    ADD COLUMN IF NOT EXISTS display_name VARCHAR(100),
+    ADD COLUMN IF NOT EXISTS pronouns VARCHAR(32),
+    ADD COLUMN IF NOT EXISTS avatar_url VARCHAR(500);
@@ -0,0 +1,20 @@
+-- Token metadata
+--
+-- The token metadata reader fetches the SEP-41 `METADATA` entry (name,
+-- symbol, decimals) from the instance storage of token contracts over
+-- Soroban RPC and records it here for the contract summary served to
+-- wallets. A failed read keeps the last good metadata and records why in
+-- `last_error`.
+
+CREATE TABLE IF NOT EXISTS token_metadata (
+    contract_id UUID PRIMARY KEY REFERENCES contracts(id) ON DELETE CASCADE,
+    -- NULL until the metadata has been read once
+    name TEXT,
+    symbol TEXT,
+    decimals INTEGER CHECK (decimals BETWEEN 0 AND 38),
+    -- Last successful read
+    refreshed_at TIMESTAMPTZ,
+    -- Last attempt, successful or not
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_error TEXT
+);
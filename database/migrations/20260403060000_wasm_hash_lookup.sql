@@ -0,0 +1,11 @@
+-- WASM hash reverse lookup
+--
+-- `GET /api/wasm/:hash/contracts` finds every contract whose current build,
+-- or one of whose versions, has a given hash. Hashes are compared
+-- case-insensitively, so the indexes are on the lower-cased hash.
+
+CREATE INDEX IF NOT EXISTS idx_contracts_wasm_hash_lower
+    ON contracts (LOWER(wasm_hash));
+
+CREATE INDEX IF NOT EXISTS idx_contract_versions_wasm_hash_lower
+    ON contract_versions (LOWER(wasm_hash));
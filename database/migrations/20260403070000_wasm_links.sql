@@ -0,0 +1,25 @@
+-- Links between uploads that reuse a WASM hash
+--
+-- A publish or version upload whose WASM hash the registry already knows is
+-- linked to the earliest registered build with that hash and inherits what
+-- was extracted for it (ABI, docs coverage, benchmarks). `version` is NULL
+-- when the link is for a contract's own build at publish time; likewise
+-- `source_version` when the source is a contract's own build.
+
+CREATE TABLE IF NOT EXISTS wasm_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    version VARCHAR(50),
+    wasm_hash VARCHAR(64) NOT NULL,
+    source_contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    source_version VARCHAR(50),
+    -- What was taken over: 'abi', 'docs_coverage', 'benchmarks'
+    inherited TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_wasm_links_target
+    ON wasm_links (contract_id, COALESCE(version, ''));
+
+CREATE INDEX IF NOT EXISTS idx_wasm_links_source
+    ON wasm_links (source_contract_id);
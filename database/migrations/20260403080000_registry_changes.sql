@@ -0,0 +1,137 @@
+-- Registry change feed and version yanks
+--
+-- `GET /api/changes?since=<seq>` lets indexers and mirrors follow the
+-- registry without re-crawling it. Triggers append one row per change, so
+-- every write path is covered:
+--
+--   contract_created, contract_updated, contract_hidden (made private),
+--   contract_deleted, contract_restored, version_published, version_yanked,
+--   version_unyanked, patch_published, patch_applied
+--
+-- `seq` orders the feed. It is not assigned by the trigger: a value handed
+-- out before commit could become visible after a higher one, and a reader
+-- that had moved past it would never see it. Rows are instead inserted with
+-- their transaction ID and numbered later, by the reader, once every
+-- transaction that could still add an earlier row has finished (see
+-- `change_feed_handlers`). Numbers are therefore gap-free and final.
+--
+-- The contract's address and network are copied into the row so entries stay
+-- meaningful after the contract is removed. contract_updated only fires for
+-- columns mirrors care about, not for counters and timestamps.
+
+ALTER TABLE contract_versions
+    ADD COLUMN IF NOT EXISTS yanked_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS yank_reason TEXT;
+
+CREATE TABLE IF NOT EXISTS registry_changes (
+    id BIGSERIAL PRIMARY KEY,
+    -- Position in the feed; NULL until numbered
+    seq BIGINT UNIQUE,
+    kind VARCHAR(32) NOT NULL,
+    -- No foreign keys: entries outlive what they describe
+    tenant_id UUID,
+    contract_id UUID,
+    contract_address VARCHAR(56),
+    network network_type,
+    version VARCHAR(50),
+    patch_id UUID,
+    tx_id xid8 NOT NULL DEFAULT pg_current_xact_id(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_registry_changes_tenant_seq
+    ON registry_changes (tenant_id, seq);
+CREATE INDEX IF NOT EXISTS idx_registry_changes_unnumbered
+    ON registry_changes (tx_id, id) WHERE seq IS NULL;
+
+CREATE OR REPLACE FUNCTION record_contract_change()
+RETURNS TRIGGER AS $$
+DECLARE
+    change_kind VARCHAR(32);
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        change_kind := 'contract_created';
+    ELSIF OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN
+        change_kind := 'contract_deleted';
+    ELSIF OLD.deleted_at IS NOT NULL AND NEW.deleted_at IS NULL THEN
+        change_kind := 'contract_restored';
+    ELSIF OLD.visibility = 'public' AND NEW.visibility <> 'public' THEN
+        change_kind := 'contract_hidden';
+    ELSIF (OLD.name, OLD.slug, OLD.description, OLD.category, OLD.tags, OLD.wasm_hash,
+           OLD.is_verified, OLD.visibility, OLD.current_version, OLD.publisher_id,
+           OLD.is_maintenance)
+          IS DISTINCT FROM
+          (NEW.name, NEW.slug, NEW.description, NEW.category, NEW.tags, NEW.wasm_hash,
+           NEW.is_verified, NEW.visibility, NEW.current_version, NEW.publisher_id,
+           NEW.is_maintenance) THEN
+        change_kind := 'contract_updated';
+    ELSE
+        RETURN NEW;
+    END IF;
+
+    INSERT INTO registry_changes (kind, tenant_id, contract_id, contract_address, network)
+    VALUES (change_kind, NEW.tenant_id, NEW.id, NEW.contract_id, NEW.network);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS contracts_record_change ON contracts;
+CREATE TRIGGER contracts_record_change
+    AFTER INSERT OR UPDATE ON contracts
+    FOR EACH ROW EXECUTE FUNCTION record_contract_change();
+
+CREATE OR REPLACE FUNCTION record_version_change()
+RETURNS TRIGGER AS $$
+DECLARE
+    change_kind VARCHAR(32);
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        change_kind := 'version_published';
+    ELSIF OLD.yanked_at IS NULL AND NEW.yanked_at IS NOT NULL THEN
+        change_kind := 'version_yanked';
+    ELSIF OLD.yanked_at IS NOT NULL AND NEW.yanked_at IS NULL THEN
+        change_kind := 'version_unyanked';
+    ELSE
+        RETURN NEW;
+    END IF;
+
+    INSERT INTO registry_changes (kind, tenant_id, contract_id, contract_address, network, version)
+    SELECT change_kind, c.tenant_id, c.id, c.contract_id, c.network, NEW.version
+    FROM contracts c WHERE c.id = NEW.contract_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS contract_versions_record_change ON contract_versions;
+CREATE TRIGGER contract_versions_record_change
+    AFTER INSERT OR UPDATE OF yanked_at ON contract_versions
+    FOR EACH ROW EXECUTE FUNCTION record_version_change();
+
+-- Security patches are not tenant-scoped; their entries reach every tenant.
+CREATE OR REPLACE FUNCTION record_patch_published()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO registry_changes (kind, patch_id) VALUES ('patch_published', NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS security_patches_record_change ON security_patches;
+CREATE TRIGGER security_patches_record_change
+    AFTER INSERT ON security_patches
+    FOR EACH ROW EXECUTE FUNCTION record_patch_published();
+
+CREATE OR REPLACE FUNCTION record_patch_applied()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO registry_changes (kind, tenant_id, contract_id, contract_address, network, patch_id)
+    SELECT 'patch_applied', c.tenant_id, c.id, c.contract_id, c.network, NEW.patch_id
+    FROM contracts c WHERE c.id = NEW.contract_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS patch_audits_record_change ON patch_audits;
+CREATE TRIGGER patch_audits_record_change
+    AFTER INSERT ON patch_audits
+    FOR EACH ROW EXECUTE FUNCTION record_patch_applied();
@@ -0,0 +1,10 @@
+-- Per-publisher storage quotas
+--
+-- Sources stored for a publisher's contracts count against a quota (see
+-- `publisher_usage_handlers`). NULL uses the registry-wide default,
+-- PUBLISHER_STORAGE_QUOTA_MB; a value here raises or lowers it for one
+-- publisher.
+
+ALTER TABLE publishers
+    ADD COLUMN IF NOT EXISTS storage_quota_bytes BIGINT
+        CHECK (storage_quota_bytes IS NULL OR storage_quota_bytes >= 0);
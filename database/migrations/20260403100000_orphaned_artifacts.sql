@@ -0,0 +1,18 @@
+-- Orphaned artifact garbage collection
+--
+-- Objects in source storage (uploaded sources, verification bundles, audit
+-- reports) that no `contract_sources` or `contract_audits` row references
+-- any more. The `artifact_gc` job records each one the first time it sees it
+-- and deletes it once it has stayed unreferenced for the grace period (see
+-- `artifact_gc`); one referenced again in the meantime is forgotten.
+
+CREATE TABLE IF NOT EXISTS orphaned_artifacts (
+    storage_backend VARCHAR(50) NOT NULL,
+    storage_key TEXT NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    first_seen_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (storage_backend, storage_key)
+);
+
+CREATE INDEX IF NOT EXISTS idx_orphaned_artifacts_first_seen
+    ON orphaned_artifacts (first_seen_at);
@@ -0,0 +1,95 @@
+-- Release transparency log
+--
+-- Every published version is appended to `release_tlog`, the leaves of a
+-- Merkle tree served by `GET /api/tlog/head`, `/api/tlog/proof` and
+-- `/api/tlog/consistency` (see `tlog_handlers` and `shared::tlog`). A trigger
+-- on `contract_versions` appends the entry, so every publish path (publish,
+-- revert, federation clone) is covered, and the logged fields are copied so
+-- a later edit to the version or contract does not change them.
+--
+-- As with `registry_changes`, the trigger cannot assign the leaf position:
+-- a transaction committing after a reader has fetched the tree would insert
+-- a leaf before ones the reader already saw. Rows are numbered, and their
+-- leaf hash filled in, by readers once every transaction that could still
+-- add an earlier row has finished. After that a row never changes.
+
+CREATE TABLE IF NOT EXISTS release_tlog (
+    id BIGSERIAL PRIMARY KEY,
+    -- Position in the tree; NULL until numbered
+    leaf_index BIGINT UNIQUE,
+    -- Hex SHA-256 leaf hash, set with leaf_index
+    leaf_hash VARCHAR(64),
+    -- No foreign keys: entries outlive what they describe
+    tenant_id UUID,
+    contract_id UUID NOT NULL,
+    contract_address VARCHAR(56) NOT NULL,
+    network TEXT NOT NULL,
+    name TEXT NOT NULL,
+    version VARCHAR(50) NOT NULL,
+    wasm_hash VARCHAR(64) NOT NULL,
+    signature TEXT,
+    publisher_key TEXT,
+    tx_id xid8 NOT NULL DEFAULT pg_current_xact_id(),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_release_tlog_contract_version
+    ON release_tlog (contract_id, version);
+CREATE INDEX IF NOT EXISTS idx_release_tlog_address_version
+    ON release_tlog (contract_address, version);
+CREATE INDEX IF NOT EXISTS idx_release_tlog_unnumbered
+    ON release_tlog (tx_id, id) WHERE leaf_index IS NULL;
+
+CREATE OR REPLACE FUNCTION append_release_tlog()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO release_tlog
+        (tenant_id, contract_id, contract_address, network, name, version, wasm_hash,
+         signature, publisher_key)
+    SELECT c.tenant_id, c.id, c.contract_id, c.network::TEXT, c.name, NEW.version,
+           NEW.wasm_hash, NEW.signature, NEW.publisher_key
+    FROM contracts c WHERE c.id = NEW.contract_id;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS contract_versions_append_release_tlog ON contract_versions;
+CREATE TRIGGER contract_versions_append_release_tlog
+    AFTER INSERT ON contract_versions
+    FOR EACH ROW EXECUTE FUNCTION append_release_tlog();
+
+-- Numbering is the only update allowed, and only once.
+CREATE OR REPLACE FUNCTION enforce_release_tlog_immutability()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP = 'UPDATE'
+       AND OLD.leaf_index IS NULL
+       AND NEW.leaf_index IS NOT NULL
+       AND NEW.leaf_hash IS NOT NULL
+       AND (NEW.id, NEW.tenant_id, NEW.contract_id, NEW.contract_address, NEW.network,
+            NEW.name, NEW.version, NEW.wasm_hash, NEW.signature, NEW.publisher_key,
+            NEW.tx_id, NEW.created_at)
+           IS NOT DISTINCT FROM
+           (OLD.id, OLD.tenant_id, OLD.contract_id, OLD.contract_address, OLD.network,
+            OLD.name, OLD.version, OLD.wasm_hash, OLD.signature, OLD.publisher_key,
+            OLD.tx_id, OLD.created_at) THEN
+        RETURN NEW;
+    END IF;
+    RAISE EXCEPTION 'release_tlog entries cannot be changed or deleted once numbered';
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER IF EXISTS prevent_release_tlog_modification ON release_tlog;
+CREATE TRIGGER prevent_release_tlog_modification
+    BEFORE UPDATE OR DELETE ON release_tlog
+    FOR EACH ROW EXECUTE FUNCTION enforce_release_tlog_immutability();
+
+-- Versions published before the log existed, oldest first.
+INSERT INTO release_tlog
+    (tenant_id, contract_id, contract_address, network, name, version, wasm_hash,
+     signature, publisher_key, created_at)
+SELECT c.tenant_id, c.id, c.contract_id, c.network::TEXT, c.name, v.version, v.wasm_hash,
+       v.signature, v.publisher_key, v.created_at
+FROM contract_versions v
+JOIN contracts c ON c.id = v.contract_id
+ORDER BY v.created_at, v.id;
@@ -0,0 +1,39 @@
+-- Publisher signing key rotation and revocation
+--
+-- Publishers register their signing keys (`POST /api/me/keys`, see
+-- `publisher_key_handlers`), proving they hold each one. A key is usable
+-- from `valid_from` until `valid_until` or `revoked_at`, whichever is
+-- first:
+--
+-- - rotating a key registers its replacement and sets the old key's
+--   `valid_until` a little ahead, so releases can move to the new key
+--   without a window where neither verifies
+-- - revoking a key sets `revoked_at`, possibly in the past when the key was
+--   compromised earlier; package signatures and badges the registry received
+--   from then on are revoked with it
+--
+-- Publishers with registered keys must sign releases with one of them, and
+-- logins and attestations by a key outside its window are refused.
+
+ALTER TABLE signing_keys
+    ADD COLUMN IF NOT EXISTS label VARCHAR(64),
+    ADD COLUMN IF NOT EXISTS valid_from TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    ADD COLUMN IF NOT EXISTS valid_until TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS revoked_at TIMESTAMPTZ,
+    ADD COLUMN IF NOT EXISTS revocation_reason TEXT,
+    ADD COLUMN IF NOT EXISTS replaced_by UUID REFERENCES signing_keys(id) ON DELETE SET NULL;
+
+UPDATE signing_keys SET valid_from = created_at WHERE valid_from > created_at;
+
+-- A key belongs to one publisher, so revoking it cannot lock out another.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_signing_keys_fingerprint_unique
+    ON signing_keys (key_fingerprint);
+
+ALTER TABLE signing_keys
+    DROP CONSTRAINT IF EXISTS signing_keys_window_check;
+ALTER TABLE signing_keys
+    ADD CONSTRAINT signing_keys_window_check
+    CHECK (valid_until IS NULL OR valid_until >= valid_from);
+
+ALTER TYPE transparency_entry_type ADD VALUE IF NOT EXISTS 'key_registered';
+ALTER TYPE transparency_entry_type ADD VALUE IF NOT EXISTS 'key_revoked';
@@ -0,0 +1,53 @@
+-- Account recovery
+--
+-- A publisher who loses their account key opens a recovery naming a new
+-- key (`POST /api/recovery`, see `account_recovery_handlers`). Like a
+-- multisig proposal it collects approvals, here from the publisher's other
+-- registered signing keys or from admins of their organizations, then waits
+-- out a timelock during which the old key's holder can cancel it. Executing
+-- it moves the publisher to the new account and revokes the old key.
+
+CREATE TABLE IF NOT EXISTS account_recoveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    tenant_id UUID NOT NULL,
+    publisher_id UUID NOT NULL REFERENCES publishers(id) ON DELETE CASCADE,
+    -- The account whose key was lost, as it was when the recovery opened
+    lost_address VARCHAR(56) NOT NULL,
+    new_address VARCHAR(56) NOT NULL,
+    -- Base64 Ed25519 key of new_address
+    new_public_key TEXT NOT NULL,
+    reason TEXT NOT NULL,
+    -- The old key is revoked from here; from execution when NULL
+    compromised_since TIMESTAMPTZ,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending'
+        CHECK (status IN ('pending', 'approved', 'executed', 'rejected', 'cancelled', 'expired')),
+    required_approvals INT NOT NULL CHECK (required_approvals > 0),
+    expires_at TIMESTAMPTZ NOT NULL,
+    approved_at TIMESTAMPTZ,
+    executed_at TIMESTAMPTZ,
+    rejected_at TIMESTAMPTZ,
+    rejection_reason TEXT,
+    cancelled_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- One open recovery per publisher.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_account_recoveries_open
+    ON account_recoveries (publisher_id) WHERE status IN ('pending', 'approved');
+
+CREATE TABLE IF NOT EXISTS account_recovery_approvals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    recovery_id UUID NOT NULL REFERENCES account_recoveries(id) ON DELETE CASCADE,
+    -- Key fingerprint for key approvals, Stellar address for org admins
+    approver TEXT NOT NULL,
+    approver_kind VARCHAR(16) NOT NULL CHECK (approver_kind IN ('key', 'org_admin')),
+    -- The key's signature over the approval message; NULL for org admins
+    signature TEXT,
+    decision approval_decision_type NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (recovery_id, approver)
+);
+
+ALTER TYPE transparency_entry_type ADD VALUE IF NOT EXISTS 'account_recovered';
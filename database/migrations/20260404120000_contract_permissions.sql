@@ -0,0 +1,19 @@
+-- Fine-grained per-contract permissions
+--
+-- Besides its publisher, a contract can grant other principals (Stellar
+-- accounts) specific capabilities on it. Each row is one capability held by
+-- one principal; revoking a grant deletes its row. Checked by
+-- backend/api/src/contract_permissions.rs.
+
+CREATE TABLE contract_permissions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    contract_id UUID NOT NULL REFERENCES contracts(id) ON DELETE CASCADE,
+    principal VARCHAR(56) NOT NULL,
+    capability VARCHAR(32) NOT NULL
+        CHECK (capability IN ('publish_versions', 'apply_patches', 'run_migrations', 'edit_metadata')),
+    granted_by VARCHAR(56) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (contract_id, principal, capability)
+);
+
+CREATE INDEX idx_contract_permissions_principal ON contract_permissions(principal);
@@ -0,0 +1,15 @@
+-- On-chain deployer of each contract: the source account of the deploying
+-- transaction as read from the network by the indexer. Only the indexer
+-- writes it; the claim flow trusts nothing else, in particular not the
+-- client-writable contract_interactions table.
+ALTER TABLE contracts
+    ADD COLUMN IF NOT EXISTS deployer_address VARCHAR(56);
+
+-- The indexer created each auto-indexed contract under a publisher for the
+-- deployer it observed, so that publisher is the deployer.
+UPDATE contracts c
+SET deployer_address = p.stellar_address
+FROM publishers p
+WHERE p.id = c.publisher_id
+  AND c.auto_indexed
+  AND c.deployer_address IS NULL;
@@ -0,0 +1,19 @@
+-- Installs are recorded for the signed-in account, once per template, so
+-- install_count (which orders the catalog) counts accounts rather than
+-- requests.
+
+-- Keep the first install of each template by each account.
+DELETE FROM template_installs t
+USING template_installs earlier
+WHERE t.template_id = earlier.template_id
+  AND t.user_address = earlier.user_address
+  AND (earlier.created_at, earlier.id) < (t.created_at, t.id);
+
+-- Older anonymous installs have no address and stay as they are.
+CREATE UNIQUE INDEX IF NOT EXISTS idx_template_installs_template_user
+    ON template_installs(template_id, user_address);
+
+UPDATE contract_templates t
+SET install_count = (
+    SELECT COUNT(*) FROM template_installs i WHERE i.template_id = t.id
+);
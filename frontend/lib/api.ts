@@ -84,6 +84,8 @@ export interface Contract {
   logical_id?: string;
   /** Per-network configs: { mainnet: {...}, testnet: {...} } */
   network_configs?: Record<Network, NetworkConfig>;
+  /** Who maintains the contract */
+  publisher?: PublisherProfile;
 }
 
 /** GET /contracts/:id response when ?network= is used (Issue #43) */
@@ -224,11 +226,24 @@ export interface Publisher {
   email?: string;
   github_url?: string;
   website?: string;
+  display_name?: string;
+  pronouns?: string;
   // Image fields for publisher avatar
   avatar_url?: string;
   created_at: string;
 }
 
+/** Publisher display metadata embedded in contract listings */
+export interface PublisherProfile {
+  id: string;
+  stellar_address: string;
+  /** Display name, falling back to the username */
+  name?: string;
+  pronouns?: string;
+  /** Explicit avatar, or the Gravatar of the publisher's verified email */
+  avatar_url?: string;
+}
+
 export type AnalyticsEventType = 
   | 'contract_published' 
   | 'contract_verified' 
@@ -1184,6 +1199,8 @@ export const api = {
   async getContractGraph(network?: string): Promise<GraphResponse> {
     const queryParams = new URLSearchParams();
     if (network) queryParams.append("network", network);
+    // Nodes are paginated; the explorer renders the first (largest) page
+    queryParams.append("limit", "5000");
     const qs = queryParams.toString();
     return handleApiCall<GraphResponse>(
       () => fetch(`${API_URL}/api/contracts/graph${qs ? `?${qs}` : ""}`),
@@ -0,0 +1,109 @@
+import { createApiError, extractErrorData } from "@/lib/errors";
+
+const API_URL = process.env.NEXT_PUBLIC_API_URL || "http://localhost:3001";
+const SESSION_KEY = "soroban-registry.session";
+
+// ---------------------------------------------------------------------------
+// SEP-10 wallet sign-in
+//
+// The registry returns a challenge transaction for the account; the wallet
+// signs it and the registry exchanges it for a short-lived bearer token. The
+// token is kept in sessionStorage, so it ends with the tab and never outlives
+// its expiry.
+// ---------------------------------------------------------------------------
+
+export interface WebAuthChallenge {
+  /** Base64 XDR transaction envelope for the wallet to sign */
+  transaction: string;
+  network_passphrase: string;
+}
+
+export interface WebAuthToken {
+  token: string;
+  token_type: "Bearer";
+  expires_in_seconds: number;
+}
+
+export interface Session {
+  account: string;
+  token: string;
+  /** Epoch milliseconds after which the token is rejected */
+  expiresAt: number;
+}
+
+/**
+ * Signs a base64 XDR transaction with the wallet, e.g. Freighter's
+ * `signTransaction(xdr, { networkPassphrase })`, returning the signed XDR.
+ */
+export type SignTransaction = (
+  transaction: string,
+  networkPassphrase: string
+) => Promise<string>;
+
+export async function requestChallenge(account: string): Promise<WebAuthChallenge> {
+  const endpoint = `${API_URL}/api/auth/challenge?account=${encodeURIComponent(account)}`;
+  const res = await fetch(endpoint);
+  if (!res.ok) {
+    throw createApiError(res.status, await extractErrorData(res), endpoint);
+  }
+  return res.json();
+}
+
+export async function exchangeChallenge(transaction: string): Promise<WebAuthToken> {
+  const endpoint = `${API_URL}/api/auth/token`;
+  const res = await fetch(endpoint, {
+    method: "POST",
+    headers: { "Content-Type": "application/json" },
+    body: JSON.stringify({ transaction }),
+  });
+  if (!res.ok) {
+    throw createApiError(res.status, await extractErrorData(res), endpoint);
+  }
+  return res.json();
+}
+
+/** Sign in as `account` with the wallet and remember the session. */
+export async function signIn(
+  account: string,
+  signTransaction: SignTransaction
+): Promise<Session> {
+  const challenge = await requestChallenge(account);
+  const signed = await signTransaction(challenge.transaction, challenge.network_passphrase);
+  const { token, expires_in_seconds } = await exchangeChallenge(signed);
+  const session = {
+    account,
+    token,
+    expiresAt: Date.now() + expires_in_seconds * 1000,
+  };
+  if (typeof window !== "undefined") {
+    window.sessionStorage.setItem(SESSION_KEY, JSON.stringify(session));
+  }
+  return session;
+}
+
+/** The current session, or `null` when signed out or expired. */
+export function getSession(): Session | null {
+  if (typeof window === "undefined") return null;
+  const stored = window.sessionStorage.getItem(SESSION_KEY);
+  if (!stored) return null;
+  try {
+    const session = JSON.parse(stored) as Session;
+    if (session.expiresAt > Date.now()) return session;
+  } catch {
+    // Fall through and drop the unreadable entry.
+  }
+  window.sessionStorage.removeItem(SESSION_KEY);
+  return null;
+}
+
+export function signOut(): void {
+  if (typeof window !== "undefined") {
+    window.sessionStorage.removeItem(SESSION_KEY);
+  }
+}
+
+/** `Authorization` header for the current session, if any. */
+export function authHeaders(): Record<string, string> {
+  const session = getSession();
+  return session ? { Authorization: `Bearer ${session.token}` } : {};
+}